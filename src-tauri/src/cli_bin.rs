@@ -0,0 +1,383 @@
+//! `personas-cli` — run and inspect personas without the windowed app.
+//!
+//! Companion binary for cron jobs and CI. Opens the same `personas.db` the
+//! app uses and executes through the daemon's headless path
+//! (`app_lib::daemon::cli`), so runs land in the app's execution history.
+//! Credentials are unlocked the same way as in the app and the daemon and
+//! never leave the machine.
+//!
+//! # Usage
+//!
+//! ```text
+//! personas-cli [--db-path <path>] list [--json]
+//! personas-cli [--db-path <path>] execute <persona> [--input <file.json|->] [--json]
+//! personas-cli [--db-path <path>] export <persona> [--output <file>]
+//! ```
+//!
+//! `<persona>` is a persona id or its (unique) name. `execute` prints the
+//! run's output on stdout and exits non-zero when the run fails.
+//!
+//! # Build
+//!
+//! ```text
+//! cargo build --bin personas-cli --features cli
+//! ```
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use app_lib::daemon::cli;
+
+/// Exit codes — stable so scripts can branch on them.
+const EXIT_OK: u8 = 0;
+const EXIT_BAD_ARGS: u8 = 1;
+const EXIT_FAILED: u8 = 2;
+const EXIT_DB_MISSING: u8 = 3;
+const EXIT_EXECUTION_FAILED: u8 = 4;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    List {
+        json: bool,
+    },
+    Execute {
+        persona: String,
+        input: Option<String>,
+        json: bool,
+    },
+    Export {
+        persona: String,
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug)]
+struct ParsedArgs {
+    db_path: Option<PathBuf>,
+    command: Command,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    init_tracing();
+
+    let args = match parse_args(std::env::args().collect()) {
+        Ok(a) => a,
+        Err(msg) => {
+            eprintln!("{msg}");
+            eprintln!();
+            print_usage();
+            return ExitCode::from(EXIT_BAD_ARGS);
+        }
+    };
+
+    let db_path = args.db_path.unwrap_or_else(default_db_path);
+    if !db_path.exists() {
+        eprintln!(
+            "Database not found at: {}\n\
+             Use --db-path <path> to specify the location or run the windowed \
+             app once to create it.",
+            db_path.display()
+        );
+        return ExitCode::from(EXIT_DB_MISSING);
+    }
+    let Some(app_data_dir) = db_path.parent().map(|p| p.to_path_buf()) else {
+        eprintln!(
+            "Database path has no parent directory: {}",
+            db_path.display()
+        );
+        return ExitCode::from(EXIT_BAD_ARGS);
+    };
+    let pool = match app_lib::daemon::init_db(&app_data_dir, None) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("Failed to initialize database: {e}");
+            return ExitCode::from(EXIT_DB_MISSING);
+        }
+    };
+
+    match args.command {
+        Command::List { json } => {
+            let personas = match cli::list_personas(&pool) {
+                Ok(p) => p,
+                Err(e) => return fail(e),
+            };
+            if json {
+                print_json(&personas);
+            } else {
+                for p in personas {
+                    let mut flags = Vec::new();
+                    if !p.enabled {
+                        flags.push("disabled");
+                    }
+                    if p.headless {
+                        flags.push("headless");
+                    }
+                    let flags = if flags.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" [{}]", flags.join(", "))
+                    };
+                    println!("{}\t{}{}", p.id, p.name, flags);
+                }
+            }
+            ExitCode::from(EXIT_OK)
+        }
+        Command::Execute {
+            persona,
+            input,
+            json,
+        } => {
+            let input_data = match input.as_deref().map(read_input).transpose() {
+                Ok(v) => v,
+                Err(msg) => {
+                    eprintln!("{msg}");
+                    return ExitCode::from(EXIT_BAD_ARGS);
+                }
+            };
+            let log_dir = app_data_dir.join("logs");
+            if let Err(e) = std::fs::create_dir_all(&log_dir) {
+                tracing::warn!(error = %e, "failed to create log directory");
+            }
+            let run = match cli::execute_persona(&pool, &log_dir, &persona, input_data).await {
+                Ok(r) => r,
+                Err(e) => return fail(e),
+            };
+            if json {
+                print_json(&run);
+            } else {
+                if let Some(output) = &run.output {
+                    println!("{output}");
+                }
+                eprintln!(
+                    "{} ({}) {} in {:.1}s, ${:.4}",
+                    run.persona_name,
+                    run.execution_id,
+                    run.status,
+                    run.duration_ms as f64 / 1000.0,
+                    run.cost_usd
+                );
+                if let Some(error) = &run.error {
+                    eprintln!("error: {error}");
+                }
+            }
+            ExitCode::from(if run.succeeded() {
+                EXIT_OK
+            } else {
+                EXIT_EXECUTION_FAILED
+            })
+        }
+        Command::Export { persona, output } => {
+            let bundle = match cli::export_persona(&pool, &persona) {
+                Ok(b) => b,
+                Err(e) => return fail(e),
+            };
+            match output {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, bundle) {
+                        eprintln!("Failed to write {}: {e}", path.display());
+                        return ExitCode::from(EXIT_FAILED);
+                    }
+                    eprintln!("Exported to {}", path.display());
+                }
+                None => println!("{bundle}"),
+            }
+            ExitCode::from(EXIT_OK)
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+fn parse_args(args: Vec<String>) -> Result<ParsedArgs, String> {
+    let mut db_path: Option<PathBuf> = None;
+    let mut positional: Vec<String> = Vec::new();
+    let mut input: Option<String> = None;
+    let mut output: Option<PathBuf> = None;
+    let mut json = false;
+
+    let mut iter = args.into_iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--db-path" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| "--db-path requires a value".to_string())?;
+                db_path = Some(PathBuf::from(val));
+            }
+            "--input" | "-i" => {
+                input = Some(
+                    iter.next()
+                        .ok_or_else(|| "--input requires a file path or -".to_string())?,
+                );
+            }
+            "--output" | "-o" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| "--output requires a file path".to_string())?;
+                output = Some(PathBuf::from(val));
+            }
+            "--json" => json = true,
+            "--help" | "-h" => {
+                print_usage();
+                std::process::exit(EXIT_OK as i32);
+            }
+            other if other.starts_with("--") => {
+                return Err(format!("unknown argument: {other}"));
+            }
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let command = match positional.next().as_deref() {
+        Some("list") => Command::List { json },
+        Some("execute") => Command::Execute {
+            persona: positional
+                .next()
+                .ok_or_else(|| "execute requires a persona id or name".to_string())?,
+            input,
+            json,
+        },
+        Some("export") => Command::Export {
+            persona: positional
+                .next()
+                .ok_or_else(|| "export requires a persona id or name".to_string())?,
+            output,
+        },
+        Some(other) => return Err(format!("unknown command: {other}")),
+        None => return Err("missing command".into()),
+    };
+    if let Some(extra) = positional.next() {
+        return Err(format!("unexpected argument: {extra}"));
+    }
+
+    Ok(ParsedArgs { db_path, command })
+}
+
+/// Read the `--input` JSON from a file, or from stdin for `-`.
+fn read_input(source: &str) -> Result<serde_json::Value, String> {
+    let text = if source == "-" {
+        std::io::read_to_string(std::io::stdin())
+            .map_err(|e| format!("Failed to read input from stdin: {e}"))?
+    } else {
+        std::fs::read_to_string(source).map_err(|e| format!("Failed to read {source}: {e}"))?
+    };
+    serde_json::from_str(&text).map_err(|e| format!("Input is not valid JSON: {e}"))
+}
+
+fn print_json(value: &impl serde::Serialize) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("Failed to serialize output: {e}"),
+    }
+}
+
+fn fail(e: impl std::fmt::Display) -> ExitCode {
+    eprintln!("{e}");
+    ExitCode::from(EXIT_FAILED)
+}
+
+fn print_usage() {
+    eprintln!(
+        "Usage: personas-cli [--db-path <path>] <command>\n\n\
+         Commands:\n  \
+         list [--json]                      List personas\n  \
+         execute <persona> [--input <file>] [--json]\n                                     \
+         Run a persona and print its output. <file> is\n                                     \
+         a JSON input file, or - for stdin\n  \
+         export <persona> [--output <file>] Write the persona's .persona.json bundle\n\n\
+         <persona> is a persona id or its unique name.\n\n\
+         Options:\n  \
+         --db-path <path>   Path to personas.db (default: platform app-data dir)\n  \
+         --help, -h         Print this help\n\n\
+         Env:\n  \
+         PERSONAS_ALLOW_FALLBACK_KEY=1    allow DPAPI-wrapped fallback credential key\n"
+    );
+}
+
+fn default_db_path() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("com.personas.desktop")
+        .join("personas.db")
+}
+
+fn init_tracing() {
+    // Logs go to stderr so stdout stays clean for output and --json.
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(false)
+        .with_ansi(false)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<ParsedArgs, String> {
+        parse_args(
+            std::iter::once("personas-cli")
+                .chain(args.iter().copied())
+                .map(String::from)
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn parse_args_list() {
+        let args = parse(&["list"]).unwrap();
+        assert!(args.db_path.is_none());
+        assert_eq!(args.command, Command::List { json: false });
+        let args = parse(&["--db-path", "/tmp/p.db", "list", "--json"]).unwrap();
+        assert_eq!(args.db_path, Some(PathBuf::from("/tmp/p.db")));
+        assert_eq!(args.command, Command::List { json: true });
+    }
+
+    #[test]
+    fn parse_args_execute_with_input() {
+        let args = parse(&["execute", "Daily Digest", "--input", "in.json"]).unwrap();
+        assert_eq!(
+            args.command,
+            Command::Execute {
+                persona: "Daily Digest".into(),
+                input: Some("in.json".into()),
+                json: false,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_export_with_output() {
+        let args = parse(&["export", "p-1", "-o", "p.persona.json"]).unwrap();
+        assert_eq!(
+            args.command,
+            Command::Export {
+                persona: "p-1".into(),
+                output: Some(PathBuf::from("p.persona.json")),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_args_rejects_bad_input() {
+        assert!(parse(&[]).unwrap_err().contains("missing command"));
+        assert!(parse(&["execute"]).unwrap_err().contains("persona"));
+        assert!(parse(&["run", "p-1"])
+            .unwrap_err()
+            .contains("unknown command"));
+        assert!(parse(&["list", "extra"])
+            .unwrap_err()
+            .contains("unexpected"));
+        assert!(parse(&["list", "--verbose"])
+            .unwrap_err()
+            .contains("unknown argument"));
+        assert!(parse(&["execute", "p-1", "--input"]).is_err());
+    }
+}
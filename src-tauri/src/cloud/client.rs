@@ -87,6 +87,14 @@ pub struct CloudExecutionPoll {
     /// Pending human-in-the-loop review requests.
     #[serde(default)]
     pub pending_reviews: Option<Vec<serde_json::Value>>,
+    /// Token usage, reported once the execution has finished.
+    #[serde(default)]
+    pub input_tokens: Option<u64>,
+    #[serde(default)]
+    pub output_tokens: Option<u64>,
+    /// Worker-reported error for a failed execution.
+    #[serde(default)]
+    pub error_message: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -368,6 +376,17 @@ pub struct UpdateCloudTriggerBody {
     pub enabled: Option<bool>,
 }
 
+/// Body of `POST /api/push/notify`. See `cloud::push`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudPushNotification {
+    pub category: String,
+    pub title: String,
+    pub body: String,
+    /// `personas://` URL the phone hands back to the desktop app when tapped.
+    pub deep_link: String,
+}
+
 // ============================================================================
 // CloudClient
 // ============================================================================
@@ -494,6 +513,31 @@ impl CloudClient {
             .await
     }
 
+    /// `GET /api/executions/{id}?offset=0` -- an execution's state and all
+    /// retained output. `None` when the orchestrator no longer knows the id.
+    pub async fn fetch_execution(
+        &self,
+        execution_id: &str,
+    ) -> Result<Option<CloudExecutionPoll>, AppError> {
+        validate_path_segment(execution_id, "execution_id")?;
+        let path = format!("/api/executions/{execution_id}?offset=0");
+        let resp = self
+            .authed(reqwest::Method::GET, &path)
+            .await
+            .send()
+            .await
+            .map_err(cloud_err)?;
+        if resp.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        resp.error_for_status()
+            .map_err(cloud_err)?
+            .json()
+            .await
+            .map(Some)
+            .map_err(cloud_err)
+    }
+
     /// `POST /api/executions/{id}/cancel` -- cancel a running execution.
     pub async fn cancel_execution(&self, execution_id: &str) -> Result<(), AppError> {
         validate_path_segment(execution_id, "execution_id")?;
@@ -851,4 +895,18 @@ impl CloudClient {
             .query(&params);
         self.send_json(req).await
     }
+
+    // --------------------------------------------------------------------
+    // Push notifications
+    // --------------------------------------------------------------------
+
+    /// `POST /api/push/notify` -- relay a notification to the user's paired
+    /// mobile devices through the orchestrator's push service.
+    pub async fn send_push(&self, notification: &CloudPushNotification) -> Result<(), AppError> {
+        let req = self
+            .authed(reqwest::Method::POST, "/api/push/notify")
+            .await
+            .json(notification);
+        self.send_ok(req).await
+    }
 }
@@ -1,5 +1,7 @@
 pub mod client;
 pub mod config;
+pub mod push;
+pub mod reconcile;
 pub mod remote_commands;
 pub mod runner;
 pub mod sync;
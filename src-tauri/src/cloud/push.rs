@@ -0,0 +1,173 @@
+//! Mobile push relay for critical notifications.
+//!
+//! While the app is connected to the cloud orchestrator, the categories the
+//! user opted into (`cloud_push_categories`) are forwarded to the
+//! orchestrator's push service, which delivers them to the user's phone. Each
+//! push carries a `personas://open/<tab>` deep link; tapping it on a device
+//! with the desktop app installed opens the matching overview tab.
+//!
+//! Relaying is best-effort and fire-and-forget: the local OS notification and
+//! external channels are unaffected, and a missing connection or a failed
+//! request is only logged. Only the title and a short body are sent — never
+//! prompts, outputs or credentials.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::cloud::client::{CloudClient, CloudPushNotification};
+use crate::db::settings_keys;
+use crate::db::DbPool;
+
+/// The same category + body is not pushed again within this window, so a
+/// flapping breaker or a budget re-check cannot buzz the phone repeatedly.
+const PUSH_COOLDOWN: Duration = Duration::from_secs(10 * 60);
+
+/// Notification categories that may be relayed to the phone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PushCategory {
+    /// A persona or the workspace hit its spend budget and was paused.
+    BudgetExceeded,
+    /// The global provider circuit breaker tripped.
+    CircuitBreaker,
+    /// A manual review is waiting for a decision.
+    PendingApproval,
+}
+
+impl PushCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BudgetExceeded => "budget_exceeded",
+            Self::CircuitBreaker => "circuit_breaker",
+            Self::PendingApproval => "pending_approval",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "budget_exceeded" => Some(Self::BudgetExceeded),
+            "circuit_breaker" => Some(Self::CircuitBreaker),
+            "pending_approval" => Some(Self::PendingApproval),
+            _ => None,
+        }
+    }
+
+    /// Deep link back into the desktop app: the overview tab where the
+    /// notification can be acted on.
+    pub fn deep_link(self) -> &'static str {
+        match self {
+            Self::BudgetExceeded | Self::CircuitBreaker => "personas://open/health",
+            Self::PendingApproval => "personas://open/manual-review",
+        }
+    }
+}
+
+/// Categories enabled by a `cloud_push_categories` value; unknown entries
+/// are ignored.
+fn parse_categories(value: &str) -> Vec<PushCategory> {
+    value
+        .split(',')
+        .filter_map(|t| PushCategory::parse(t.trim()))
+        .collect()
+}
+
+struct Relay {
+    pool: DbPool,
+    cloud_client: Arc<tokio::sync::Mutex<Option<Arc<CloudClient>>>>,
+}
+
+static RELAY: OnceLock<Relay> = OnceLock::new();
+
+static LAST_SENT: LazyLock<Mutex<HashMap<(PushCategory, String), Instant>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Wire the relay to the app's pool and cloud client. Called once at startup;
+/// until then (daemon, tests) [`relay`] is a no-op.
+pub fn init(pool: DbPool, cloud_client: Arc<tokio::sync::Mutex<Option<Arc<CloudClient>>>>) {
+    let _ = RELAY.set(Relay { pool, cloud_client });
+}
+
+fn category_enabled(pool: &DbPool, category: PushCategory) -> bool {
+    crate::db::repos::core::settings::get(pool, settings_keys::CLOUD_PUSH_CATEGORIES)
+        .ok()
+        .flatten()
+        .is_some_and(|v| parse_categories(&v).contains(&category))
+}
+
+/// Whether `category`/`body` is outside its cooldown; records the send.
+fn take_cooldown_slot(category: PushCategory, body: &str) -> bool {
+    let mut last = LAST_SENT.lock().unwrap_or_else(|e| e.into_inner());
+    let now = Instant::now();
+    last.retain(|_, at| now.duration_since(*at) < PUSH_COOLDOWN);
+    match last.entry((category, body.to_string())) {
+        std::collections::hash_map::Entry::Occupied(_) => false,
+        std::collections::hash_map::Entry::Vacant(slot) => {
+            slot.insert(now);
+            true
+        }
+    }
+}
+
+/// Forward a notification to the user's phone if its category is opted in
+/// and the orchestrator is connected. Never blocks the caller.
+pub fn relay(category: PushCategory, title: &str, body: &str) {
+    let Some(relay) = RELAY.get() else {
+        return;
+    };
+    if !category_enabled(&relay.pool, category) || !take_cooldown_slot(category, body) {
+        return;
+    }
+    let notification = CloudPushNotification {
+        category: category.as_str().to_string(),
+        title: title.to_string(),
+        body: body.to_string(),
+        deep_link: category.deep_link().to_string(),
+    };
+    let cloud_client = relay.cloud_client.clone();
+    tauri::async_runtime::spawn(async move {
+        let Some(client) = cloud_client.lock().await.clone() else {
+            tracing::debug!(
+                category = %notification.category,
+                "Push relay: not connected to the cloud orchestrator, skipping"
+            );
+            return;
+        };
+        if let Err(e) = client.send_push(&notification).await {
+            tracing::warn!(
+                category = %notification.category,
+                error = %e,
+                "Push relay: failed to send notification"
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categories_parse_and_ignore_unknown_entries() {
+        assert_eq!(
+            parse_categories("budget_exceeded, chat ,pending_approval"),
+            vec![PushCategory::BudgetExceeded, PushCategory::PendingApproval]
+        );
+        assert!(parse_categories("").is_empty());
+        for category in [
+            PushCategory::BudgetExceeded,
+            PushCategory::CircuitBreaker,
+            PushCategory::PendingApproval,
+        ] {
+            assert_eq!(PushCategory::parse(category.as_str()), Some(category));
+            assert!(category.deep_link().starts_with("personas://open/"));
+        }
+    }
+
+    #[test]
+    fn repeated_pushes_are_held_back_during_the_cooldown() {
+        let slot = |category| take_cooldown_slot(category, "cooldown test");
+        assert!(slot(PushCategory::CircuitBreaker));
+        assert!(!slot(PushCategory::CircuitBreaker));
+        assert!(slot(PushCategory::BudgetExceeded));
+    }
+}
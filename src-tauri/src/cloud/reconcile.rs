@@ -0,0 +1,244 @@
+//! Backfilling cloud executions that finished while nothing was polling them.
+//!
+//! `submit_cloud_execution` stores the orchestrator's execution id on the
+//! local row. If the app closes (or loses the orchestrator) mid-run, the
+//! poller dies and local recovery -- startup stale recovery, the zombie
+//! sweep -- closes the row as failed. [`reconcile_executions`] runs after
+//! every (re)connect: it fetches each such run from the orchestrator and
+//! writes its real outcome (status, cost, tokens, output log) over the local
+//! recovery result. A row that reached a final state on its own -- a user
+//! cancel, a live poller result -- is never overwritten.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::client::{CloudClient, CloudExecutionPoll};
+use crate::db::models::{CloudExecutionLink, UpdateExecutionStatus};
+use crate::db::repos::execution::executions;
+use crate::db::DbPool;
+use crate::engine::logger::ExecutionLogger;
+use crate::engine::types::ExecutionState;
+use crate::error::AppError;
+
+/// Set while a pass runs, so a reconnect and a manual sync don't both
+/// backfill the same rows.
+static RECONCILING: AtomicBool = AtomicBool::new(false);
+
+/// A local execution updated from the orchestrator.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciledCloudExecution {
+    pub execution_id: String,
+    pub persona_id: String,
+    pub status: ExecutionState,
+    pub error: Option<String>,
+    #[ts(type = "number | null")]
+    pub duration_ms: Option<i64>,
+    pub cost_usd: Option<f64>,
+}
+
+/// Outcome of one reconciliation pass.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudReconcileReport {
+    /// Cloud executions whose local row might be stale.
+    #[ts(type = "number")]
+    pub checked: usize,
+    /// Rows rewritten with the orchestrator's result.
+    pub reconciled: Vec<ReconciledCloudExecution>,
+    /// Still running on the orchestrator; left for the next pass.
+    #[ts(type = "number")]
+    pub still_running: usize,
+    /// Unknown to the orchestrator (expired or purged); closed as failed.
+    #[ts(type = "number")]
+    pub missing: usize,
+    /// Could not be fetched; left for the next pass.
+    #[ts(type = "number")]
+    pub errors: usize,
+}
+
+/// Fetch and backfill every reconcilable cloud execution. `live` holds the
+/// local ids a poller is tracking in this session; those are skipped.
+pub async fn reconcile_executions(
+    pool: &DbPool,
+    client: &CloudClient,
+    log_dir: &Path,
+    live: &HashSet<String>,
+) -> Result<CloudReconcileReport, AppError> {
+    if RECONCILING.swap(true, Ordering::AcqRel) {
+        return Err(AppError::Cloud(
+            "Cloud execution reconciliation is already running".into(),
+        ));
+    }
+    let result = reconcile_pass(pool, client, log_dir, live).await;
+    RECONCILING.store(false, Ordering::Release);
+    result
+}
+
+async fn reconcile_pass(
+    pool: &DbPool,
+    client: &CloudClient,
+    log_dir: &Path,
+    live: &HashSet<String>,
+) -> Result<CloudReconcileReport, AppError> {
+    let pending: Vec<CloudExecutionLink> = executions::get_cloud_reconcilable(pool)?
+        .into_iter()
+        .filter(|link| !live.contains(&link.execution_id))
+        .collect();
+    let mut report = CloudReconcileReport {
+        checked: pending.len(),
+        ..Default::default()
+    };
+
+    for link in pending {
+        let update = match client.fetch_execution(&link.cloud_execution_id).await {
+            Ok(Some(poll)) => match final_status(&poll.status) {
+                Some(status) => result_update(&link, &poll, status, log_dir),
+                None => {
+                    report.still_running += 1;
+                    continue;
+                }
+            },
+            Ok(None) => {
+                report.missing += 1;
+                UpdateExecutionStatus {
+                    status: ExecutionState::Failed,
+                    error_message: Some(
+                        "Cloud execution is no longer available on the orchestrator".into(),
+                    ),
+                    ..Default::default()
+                }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    execution_id = %link.execution_id,
+                    cloud_execution_id = %link.cloud_execution_id,
+                    error = %e,
+                    "Failed to fetch cloud execution for reconciliation",
+                );
+                report.errors += 1;
+                continue;
+            }
+        };
+
+        let reconciled = ReconciledCloudExecution {
+            execution_id: link.execution_id.clone(),
+            persona_id: link.persona_id.clone(),
+            status: update.status,
+            error: update.error_message.clone(),
+            duration_ms: update.duration_ms,
+            cost_usd: update.cost_usd,
+        };
+        match executions::reconcile_cloud_result(pool, &link.execution_id, update) {
+            Ok(true) => report.reconciled.push(reconciled),
+            Ok(false) => {}
+            Err(e) => {
+                tracing::warn!(
+                    execution_id = %link.execution_id,
+                    error = %e,
+                    "Failed to store reconciled cloud execution",
+                );
+                report.errors += 1;
+            }
+        }
+    }
+
+    tracing::info!(
+        checked = report.checked,
+        reconciled = report.reconciled.len(),
+        still_running = report.still_running,
+        missing = report.missing,
+        errors = report.errors,
+        "Cloud execution reconciliation finished",
+    );
+    Ok(report)
+}
+
+/// Local state for a terminal orchestrator status; `None` while it runs.
+fn final_status(status: &str) -> Option<ExecutionState> {
+    match status {
+        "completed" => Some(ExecutionState::Completed),
+        "cancelled" => Some(ExecutionState::Cancelled),
+        "failed" | "error" => Some(ExecutionState::Failed),
+        _ => None,
+    }
+}
+
+/// The status update for a finished run. The retained output is written to
+/// the execution's log file, as the live poller's stream would have been.
+fn result_update(
+    link: &CloudExecutionLink,
+    poll: &CloudExecutionPoll,
+    status: ExecutionState,
+    log_dir: &Path,
+) -> UpdateExecutionStatus {
+    let error_message = (status != ExecutionState::Completed).then(|| {
+        poll.error_message
+            .clone()
+            .unwrap_or_else(|| format!("Cloud execution {}", poll.status))
+    });
+    UpdateExecutionStatus {
+        status,
+        error_message,
+        duration_ms: poll.duration_ms.map(|ms| ms as i64),
+        cost_usd: poll.cost_usd,
+        input_tokens: poll.input_tokens.map(|t| t as i64),
+        output_tokens: poll.output_tokens.map(|t| t as i64),
+        claude_session_id: poll.session_id.clone(),
+        log_file_path: write_log(link, poll, log_dir),
+        ..Default::default()
+    }
+}
+
+fn write_log(
+    link: &CloudExecutionLink,
+    poll: &CloudExecutionPoll,
+    log_dir: &Path,
+) -> Option<String> {
+    if poll.output.is_empty() {
+        return None;
+    }
+    let mut logger = match ExecutionLogger::new(log_dir, &link.execution_id) {
+        Ok(logger) => logger,
+        Err(e) => {
+            tracing::warn!(
+                execution_id = %link.execution_id,
+                error = %e,
+                "Failed to open log for reconciled cloud execution",
+            );
+            return None;
+        }
+    };
+    logger.log(&format!(
+        "Backfilled from cloud execution {} after reconnect",
+        link.cloud_execution_id
+    ));
+    if poll.output_evicted == Some(true) {
+        logger.log("Earlier output was evicted on the orchestrator; only the tail is kept");
+    }
+    for line in &poll.output {
+        logger.log(line);
+    }
+    logger.close();
+    Some(logger.path().to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_terminal_orchestrator_states_are_final() {
+        assert_eq!(final_status("completed"), Some(ExecutionState::Completed));
+        assert_eq!(final_status("error"), Some(ExecutionState::Failed));
+        assert_eq!(final_status("cancelled"), Some(ExecutionState::Cancelled));
+        assert_eq!(final_status("running"), None);
+        assert_eq!(final_status("queued"), None);
+    }
+}
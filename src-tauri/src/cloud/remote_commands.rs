@@ -1,12 +1,19 @@
-//! Phase 2: approval-gated remote run requests.
+//! Phase 2: the remote command channel.
 //!
-//! The web dashboard inserts a `pending_commands` row (a *request* to run a
-//! persona). This module polls those rows for THIS device and surfaces each to
-//! the desktop user as an explicit approval prompt — it NEVER auto-executes.
-//! On approval the persona runs **locally** via `execute_persona_inner` (the
-//! same path as a normal run), and the result syncs back up through the Phase-1a
-//! writer. Credentials and execution never leave the device; the web only ever
-//! sent a `persona_id` + prompt.
+//! The web dashboard (or the mobile app) inserts a `pending_commands` row for
+//! a device. This module polls those rows for THIS device and handles the
+//! actions listed in [`RemoteAction`] — but only those the user allowed in the
+//! `remote_actions_allowed` setting; anything else is rejected back to the
+//! dashboard.
+//!
+//! A `run_persona` request is surfaced to the desktop user as an explicit
+//! approval prompt — it NEVER auto-executes. On approval the persona runs
+//! **locally** via `execute_persona_inner` (the same path as a normal run), and
+//! the result syncs back up through the Phase-1a writer. Credentials and
+//! execution never leave the device; the web only ever sent a `persona_id` +
+//! prompt. The scheduler and status actions touch no persona or credential,
+//! so allowing one is consent enough: they run as soon as they are polled and
+//! write their outcome back to the row.
 
 use std::collections::HashSet;
 use std::sync::{Arc, LazyLock};
@@ -21,6 +28,7 @@ use ts_rs::TS;
 
 use crate::cloud::sync::client::SyncClient;
 use crate::cloud::sync::cursor;
+use crate::db::settings_keys;
 use crate::db::DbPool;
 use crate::error::AppError;
 use crate::AppState;
@@ -35,6 +43,64 @@ fn expiry_window() -> chrono::Duration {
     chrono::Duration::hours(1)
 }
 
+/// Actions the remote channel can carry out (`pending_commands.command_type`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteAction {
+    /// Run a persona locally, after the desktop user approves the request.
+    RunPersona,
+    /// Stop the background scheduler (triggers, event bus, polling).
+    PauseScheduler,
+    /// Start the background scheduler again.
+    ResumeScheduler,
+    /// Report scheduler state and the app version back to the dashboard.
+    FetchStatus,
+}
+
+impl RemoteAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::RunPersona => "run_persona",
+            Self::PauseScheduler => "pause_scheduler",
+            Self::ResumeScheduler => "resume_scheduler",
+            Self::FetchStatus => "fetch_status",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "run_persona" => Some(Self::RunPersona),
+            "pause_scheduler" => Some(Self::PauseScheduler),
+            "resume_scheduler" => Some(Self::ResumeScheduler),
+            "fetch_status" => Some(Self::FetchStatus),
+            _ => None,
+        }
+    }
+
+    fn needs_approval(self) -> bool {
+        matches!(self, Self::RunPersona)
+    }
+}
+
+/// Parse a `remote_actions_allowed` value; unknown names are ignored.
+fn parse_allowlist(value: &str) -> Vec<RemoteAction> {
+    value
+        .split(',')
+        .filter_map(|t| RemoteAction::parse(t.trim()))
+        .collect()
+}
+
+/// Remote actions the user allowed on this device.
+pub fn allowed_actions(pool: &DbPool) -> Vec<RemoteAction> {
+    let value = crate::db::repos::core::settings::get(pool, settings_keys::REMOTE_ACTIONS_ALLOWED)
+        .ok()
+        .flatten();
+    parse_allowlist(
+        value
+            .as_deref()
+            .unwrap_or(settings_keys::REMOTE_ACTIONS_ALLOWED_DEFAULT),
+    )
+}
+
 /// Raw `pending_commands` row (subset we select).
 #[derive(Debug, Clone, Deserialize)]
 struct CommandRow {
@@ -138,6 +204,7 @@ async fn poll_once(app: &AppHandle, state: &Arc<AppState>) -> Result<(), AppErro
     );
     let cmds: Vec<CommandRow> = client.get(&path).await?;
 
+    let allowed = allowed_actions(&pool);
     let mut surfaced = SURFACED.lock().await;
     for c in cmds {
         if is_expired(&c.requested_at) {
@@ -149,19 +216,90 @@ async fn poll_once(app: &AppHandle, state: &Arc<AppState>) -> Result<(), AppErro
                 .await;
             continue;
         }
-        // v1 only surfaces run_persona; other types are ignored until supported.
-        if c.command_type != "run_persona" {
+        // Types this build doesn't know are left for a newer client.
+        let Some(action) = RemoteAction::parse(&c.command_type) else {
             continue;
-        }
+        };
         if surfaced.contains(&c.id) {
             continue;
         }
+        if !allowed.contains(&action) {
+            set_command_status(
+                &client,
+                &c.id,
+                "rejected",
+                json!({
+                    "error_message": format!("'{}' is not allowed on this device", action.as_str()),
+                    "resolved_at": now(),
+                }),
+            )
+            .await;
+            continue;
+        }
         surfaced.insert(c.id.clone());
-        let _ = app.emit("remote-command-pending", to_remote(c, &pool));
+        if action.needs_approval() {
+            let _ = app.emit("remote-command-pending", to_remote(c, &pool));
+        } else {
+            run_unattended(app, state, &client, c, action).await;
+        }
     }
     Ok(())
 }
 
+/// Scheduler state and app version, as reported to the dashboard.
+fn status_report(state: &AppState) -> serde_json::Value {
+    let stats = state.scheduler.stats();
+    json!({
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "scheduler_running": stats.running,
+        "events_processed": stats.events_processed,
+        "events_failed": stats.events_failed,
+        "triggers_fired": stats.triggers_fired,
+        "subscriptions_crashed": stats.subscriptions_crashed,
+        "reported_at": now(),
+    })
+}
+
+/// Carry out an allowed action that needs no approval and write the outcome
+/// back to the command row.
+async fn run_unattended(
+    app: &AppHandle,
+    state: &Arc<AppState>,
+    client: &SyncClient,
+    cmd: CommandRow,
+    action: RemoteAction,
+) {
+    match action {
+        RemoteAction::PauseScheduler => crate::engine::background::stop_loops(&state.scheduler),
+        RemoteAction::ResumeScheduler => {
+            crate::commands::execution::scheduler::start_scheduler_loops(state, app.clone());
+        }
+        RemoteAction::FetchStatus => {}
+        RemoteAction::RunPersona => return,
+    }
+    set_command_status(
+        client,
+        &cmd.id,
+        "completed",
+        json!({ "resolved_at": now() }),
+    )
+    .await;
+    // Separate write so a dashboard whose table predates the `result` column
+    // still sees the command complete.
+    let _ = client
+        .patch(
+            &format!("pending_commands?id=eq.{}", cmd.id),
+            &json!({ "result": status_report(state) }),
+        )
+        .await;
+    tracing::info!(
+        command_id = %cmd.id,
+        action = action.as_str(),
+        "Remote command carried out"
+    );
+    let _ = app.emit("remote-command-executed", to_remote(cmd, &state.db));
+}
+
 /// Spawn the 15s poll loop. Leader-gated + sync-enabled-gated, so it only runs
 /// on one instance and only when the user has opted into cloud sync.
 pub fn spawn_poll_loop(app: AppHandle, state: Arc<AppState>) {
@@ -191,6 +329,9 @@ pub fn spawn_poll_loop(app: AppHandle, state: Arc<AppState>) {
 pub async fn remote_command_list_pending(
     state: State<'_, Arc<AppState>>,
 ) -> Result<Vec<RemoteCommand>, AppError> {
+    if !allowed_actions(&state.db).contains(&RemoteAction::RunPersona) {
+        return Ok(vec![]);
+    }
     let jwt = match read_token(&state).await {
         Some(t) => t,
         None => return Ok(vec![]),
@@ -260,6 +401,12 @@ pub async fn remote_command_approve(
     if cmd.command_type != "run_persona" {
         return Err(AppError::Validation("Unsupported command type".into()));
     }
+    // The allowlist may have changed since the prompt was surfaced.
+    if !allowed_actions(&state.db).contains(&RemoteAction::RunPersona) {
+        return Err(AppError::Validation(
+            "Remote runs are not allowed on this device".into(),
+        ));
+    }
     let persona_id = cmd
         .persona_id
         .ok_or_else(|| AppError::Validation("Request is missing a persona".into()))?;
@@ -339,4 +486,25 @@ mod tests {
         // a malformed requested_at shouldn't silently drop a real request).
         assert!(!is_expired("not-a-timestamp"));
     }
+
+    #[test]
+    fn allowlist_parsing_ignores_unknown_actions() {
+        assert_eq!(
+            parse_allowlist(" run_persona,fetch_status ,delete_persona"),
+            vec![RemoteAction::RunPersona, RemoteAction::FetchStatus]
+        );
+        assert!(parse_allowlist("").is_empty());
+        assert_eq!(
+            parse_allowlist(settings_keys::REMOTE_ACTIONS_ALLOWED_DEFAULT),
+            vec![RemoteAction::RunPersona]
+        );
+        for action in [
+            RemoteAction::RunPersona,
+            RemoteAction::PauseScheduler,
+            RemoteAction::ResumeScheduler,
+            RemoteAction::FetchStatus,
+        ] {
+            assert_eq!(RemoteAction::parse(action.as_str()), Some(action));
+        }
+    }
 }
@@ -8,12 +8,14 @@ use crate::engine::event_registry::event_name;
 use super::client::CloudClient;
 
 /// Result of a cloud execution polling loop.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct CloudRunResult {
     pub success: bool,
     pub error: Option<String>,
     pub duration_ms: u64,
     pub cost_usd: Option<f64>,
+    pub input_tokens: Option<u64>,
+    pub output_tokens: Option<u64>,
 }
 
 /// Poll the cloud orchestrator for execution output and emit Tauri events.
@@ -52,6 +54,7 @@ pub async fn run_cloud_execution(
                 error: Some("Cancelled".into()),
                 duration_ms: 0,
                 cost_usd: None,
+                ..Default::default()
             };
         }
 
@@ -100,6 +103,7 @@ pub async fn run_cloud_execution(
                         )),
                         duration_ms: 0,
                         cost_usd: None,
+                        ..Default::default()
                     };
                 }
 
@@ -186,6 +190,8 @@ pub async fn run_cloud_execution(
                     error: None,
                     duration_ms: poll.duration_ms.unwrap_or(0),
                     cost_usd: poll.cost_usd,
+                    input_tokens: poll.input_tokens,
+                    output_tokens: poll.output_tokens,
                 };
             }
             "failed" | "cancelled" | "error" => {
@@ -201,6 +207,8 @@ pub async fn run_cloud_execution(
                     error: Some(format!("Cloud execution {}", poll.status)),
                     duration_ms: poll.duration_ms.unwrap_or(0),
                     cost_usd: poll.cost_usd,
+                    input_tokens: poll.input_tokens,
+                    output_tokens: poll.output_tokens,
                 };
             }
             _ => {
@@ -222,5 +230,6 @@ pub async fn run_cloud_execution(
         error: Some("Cloud execution timed out after polling limit".into()),
         duration_ms: 0,
         cost_usd: None,
+        ..Default::default()
     }
 }
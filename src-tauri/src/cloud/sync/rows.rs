@@ -19,8 +19,9 @@ use rusqlite::{params, Row};
 use serde::Serialize;
 
 use crate::db::DbPool;
-use crate::engine::crypto;
+use crate::engine::project_keys;
 use crate::error::AppError;
+use crate::utils::sanitization::{key_is_secret, value_looks_secret};
 
 // ---------------------------------------------------------------------------
 // Event-payload sanitization (v2)
@@ -36,39 +37,6 @@ use crate::error::AppError;
 /// Max serialized payload pushed to the cloud; larger → a bounded marker.
 const MAX_PAYLOAD_BYTES: usize = 4096;
 
-/// Key substrings (case-insensitive) whose values are always redacted.
-const SECRET_KEY_NEEDLES: &[&str] = &[
-    "token", "secret", "password", "passwd", "api_key", "apikey", "authorization",
-    "credential", "cookie", "private_key", "access_key", "client_secret", "bearer",
-];
-
-fn key_is_secret(key: &str) -> bool {
-    let k = key.to_ascii_lowercase();
-    SECRET_KEY_NEEDLES.iter().any(|n| k.contains(n))
-}
-
-/// A string value that looks like a credential even under an innocuous key:
-/// known token prefixes, or a long whitespace-free high-base64/hex-density run.
-fn value_looks_secret(s: &str) -> bool {
-    const PREFIXES: &[&str] = &[
-        "sk-", "sk_", "ghp_", "gho_", "ghs_", "github_pat_", "glpat-", "xox", "AKIA",
-        "ASIA", "eyJ", "Bearer ", "-----BEGIN",
-    ];
-    if PREFIXES.iter().any(|p| s.starts_with(p)) {
-        return true;
-    }
-    if s.len() >= 60 && !s.chars().any(|c| c.is_whitespace()) {
-        let dense = s
-            .chars()
-            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_' | '='))
-            .count();
-        if dense * 100 / s.len() >= 90 {
-            return true;
-        }
-    }
-    false
-}
-
 fn redact_secrets(value: &mut serde_json::Value) {
     match value {
         serde_json::Value::Object(map) => {
@@ -112,7 +80,7 @@ fn sanitize_event_payload(plaintext: &str) -> Option<String> {
 /// `None` (never leak ciphertext).
 fn project_event_payload(raw: Option<String>, iv: Option<String>) -> Option<String> {
     let plaintext = match (raw, iv) {
-        (Some(ct), Some(iv)) if !iv.is_empty() => crypto::decrypt_from_db(&ct, &iv).ok()?,
+        (Some(ct), Some(iv)) if !iv.is_empty() => project_keys::decrypt(&ct, &iv).ok()?,
         (Some(pt), _) => pt,
         _ => return None,
     };
@@ -10,9 +10,10 @@ use tauri::AppHandle;
 
 use crate::db::models::{
     CreateEventSubscriptionInput, CreatePersonaEventInput, CreateTriggerInput, EventFilterInput,
-    PaginatedEvents, PersonaEvent, PersonaEventStatus, PersonaEventSubscription,
-    UpdateEventSubscriptionInput,
+    EventSchema, PaginatedEvents, PersonaEvent, PersonaEventStatus, PersonaEventSubscription,
+    UpdateEventSubscriptionInput, UpsertEventSchemaInput,
 };
+use crate::db::repos::communication::event_schemas as schema_repo;
 use crate::db::repos::communication::events as repo;
 // NOTE: emit_event_bus calls removed — CDC update_hook auto-emits on persona_events INSERT/UPDATE
 use crate::engine::rate_limiter::EVENT_SOURCE_WINDOW;
@@ -85,6 +86,10 @@ pub fn publish_event(
     // otherwise silently never match any listener.
     crate::engine::event_vocabulary::validate_and_warn(&input.event_type);
 
+    // Unlike the vocabulary check, a registered payload schema is enforced:
+    // subscribers of that type rely on its shape.
+    schema_repo::validate_payload(&state.db, &input.event_type, input.payload.as_deref())?;
+
     let event = repo::publish(&state.db, input)?;
     // CDC auto-emits on persona_events INSERT
     Ok(event)
@@ -301,6 +306,47 @@ pub fn bulk_discard_dead_letter_events(
     repo::bulk_discard_dead_letter(&state.db, &ids)
 }
 
+/// Re-dispatch one event through the event bus: a dead-lettered event is
+/// retried in place, any other finished event is published again as a copy.
+#[tauri::command]
+pub fn replay_event(
+    state: State<'_, Arc<AppState>>,
+    event_id: String,
+) -> Result<PersonaEvent, AppError> {
+    require_auth_sync(&state)?;
+    let event = repo::replay(&state.db, &event_id)?;
+    crate::engine::subscription::event_bus_wake_signal().notify_one();
+    Ok(event)
+}
+
+/// Retry every dead-lettered event matching `filter` (its `status` is
+/// ignored), newest first, up to `MAX_BULK_DLQ_BATCH` per call.
+#[tauri::command]
+pub fn replay_dead_letters(
+    state: State<'_, Arc<AppState>>,
+    filter: EventFilterInput,
+) -> Result<repo::BulkDeadLetterOutcome, AppError> {
+    require_auth_sync(&state)?;
+    let limit = filter
+        .limit
+        .unwrap_or(MAX_BULK_DLQ_BATCH as i64)
+        .clamp(1, MAX_BULK_DLQ_BATCH as i64);
+    let (events, _) = repo::search(
+        &state.db,
+        &EventFilterInput {
+            status: Some(PersonaEventStatus::DeadLetter.as_str().into()),
+            limit: Some(limit),
+            ..filter
+        },
+    )?;
+    let ids: Vec<String> = events.into_iter().map(|e| e.id).collect();
+    let outcome = repo::bulk_retry_dead_letter(&state.db, &ids)?;
+    if !outcome.succeeded.is_empty() {
+        crate::engine::subscription::event_bus_wake_signal().notify_one();
+    }
+    Ok(outcome)
+}
+
 /// Configuration values the dead-letter UI needs to mirror exactly.
 ///
 /// Exposed as a runtime ts-rs binding so the frontend never hardcodes a
@@ -316,6 +362,10 @@ pub struct DeadLetterConfig {
     /// Maximum number of *manual* retries from the DLQ. Mirrors
     /// `repo::MAX_MANUAL_RETRIES`.
     pub max_manual_retries: i32,
+    /// How many copies `replay_event` may make of one event. Mirrors
+    /// `repo::MAX_EVENT_REPLAYS`.
+    #[ts(type = "number")]
+    pub max_event_replays: i64,
 }
 
 #[tauri::command]
@@ -325,9 +375,47 @@ pub fn get_dead_letter_config(
     require_auth_sync(&state)?;
     Ok(DeadLetterConfig {
         max_manual_retries: repo::MAX_MANUAL_RETRIES,
+        max_event_replays: repo::MAX_EVENT_REPLAYS,
     })
 }
 
+// -- Event payload schemas -------------------------------------------------------
+
+#[tauri::command]
+pub fn list_event_schemas(state: State<'_, Arc<AppState>>) -> Result<Vec<EventSchema>, AppError> {
+    require_auth_sync(&state)?;
+    schema_repo::list(&state.db)
+}
+
+#[tauri::command]
+pub fn get_event_schema(
+    state: State<'_, Arc<AppState>>,
+    event_type: String,
+) -> Result<Option<EventSchema>, AppError> {
+    require_auth_sync(&state)?;
+    schema_repo::get(&state.db, &event_type)
+}
+
+/// Register or replace the JSON Schema for an event type. The schema is
+/// compiled before it is stored, so an invalid one is rejected here.
+#[tauri::command]
+pub fn upsert_event_schema(
+    state: State<'_, Arc<AppState>>,
+    input: UpsertEventSchemaInput,
+) -> Result<EventSchema, AppError> {
+    require_auth_sync(&state)?;
+    schema_repo::upsert(&state.db, input)
+}
+
+#[tauri::command]
+pub fn delete_event_schema(
+    state: State<'_, Arc<AppState>>,
+    event_type: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    schema_repo::delete(&state.db, &event_type)
+}
+
 // -- Dev seed: mock event -------------------------------------------------------
 
 #[tauri::command]
@@ -3,5 +3,6 @@ pub mod messages;
 pub mod mock_seed;
 pub mod notifications;
 pub mod observability;
+pub mod report_sinks;
 pub mod shared_events;
 pub mod sla;
@@ -3,9 +3,13 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::db::models::{
-    CreateNotificationSubscriptionInput, NotificationSubscription, NotificationTestResult,
-    UpdateNotificationSubscriptionInput,
+    CreateNotificationRuleInput, CreateNotificationSubscriptionInput, NotificationRule,
+    NotificationSubscription, NotificationTemplate, NotificationTemplatePreview,
+    NotificationTestResult, PreviewNotificationTemplateInput, UpdateNotificationRuleInput,
+    UpdateNotificationSubscriptionInput, UpsertNotificationTemplateInput,
 };
+use crate::db::repos::communication::notification_rules as rules_repo;
+use crate::db::repos::communication::notification_templates as templates_repo;
 use crate::db::repos::resources::notification_subscriptions as repo;
 use crate::engine::webhook_notifier;
 use crate::error::AppError;
@@ -71,3 +75,84 @@ pub async fn test_notification_subscription(
         error: outcome.error,
     })
 }
+
+// ---------------------------------------------------------------------------
+// Notification routing rules
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn list_notification_rules(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<NotificationRule>, AppError> {
+    require_auth_sync(&state)?;
+    rules_repo::list_all(&state.db)
+}
+
+#[tauri::command]
+pub fn create_notification_rule(
+    state: State<'_, Arc<AppState>>,
+    input: CreateNotificationRuleInput,
+) -> Result<NotificationRule, AppError> {
+    require_auth_sync(&state)?;
+    rules_repo::create(&state.db, input)
+}
+
+#[tauri::command]
+pub fn update_notification_rule(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+    input: UpdateNotificationRuleInput,
+) -> Result<NotificationRule, AppError> {
+    require_auth_sync(&state)?;
+    rules_repo::update(&state.db, &id, input)
+}
+
+#[tauri::command]
+pub fn delete_notification_rule(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    rules_repo::delete(&state.db, &id)
+}
+
+// ---------------------------------------------------------------------------
+// Notification templates
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn list_notification_templates(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<NotificationTemplate>, AppError> {
+    require_auth_sync(&state)?;
+    templates_repo::list_all(&state.db)
+}
+
+#[tauri::command]
+pub fn upsert_notification_template(
+    state: State<'_, Arc<AppState>>,
+    input: UpsertNotificationTemplateInput,
+) -> Result<NotificationTemplate, AppError> {
+    require_auth_sync(&state)?;
+    templates_repo::upsert(&state.db, input)
+}
+
+#[tauri::command]
+pub fn delete_notification_template(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    templates_repo::delete(&state.db, &id)
+}
+
+/// Render a template with sample values without saving it. Fails with the
+/// same validation errors a save would.
+#[tauri::command]
+pub fn preview_notification_template(
+    state: State<'_, Arc<AppState>>,
+    input: PreviewNotificationTemplateInput,
+) -> Result<NotificationTemplatePreview, AppError> {
+    require_auth_sync(&state)?;
+    crate::notifications::preview_template(&input)
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::engine::digest::{self, DigestConfig, PerformanceDigest};
+use crate::engine::fleet_hygiene::{self, FleetHygieneReport};
 use crate::error::AppError;
 use crate::ipc_auth::require_auth_sync;
 use crate::AppState;
@@ -24,15 +25,41 @@ pub fn set_digest_config(
     Ok(config)
 }
 
-/// Generate a digest preview without delivering it.
+/// Generate a digest preview without delivering it. `group_id` limits it to
+/// one group; all personas when omitted.
 #[tauri::command]
 pub fn preview_digest(
     state: State<'_, Arc<AppState>>,
     days: Option<i64>,
+    group_id: Option<String>,
 ) -> Result<PerformanceDigest, AppError> {
     require_auth_sync(&state)?;
     let period_days = days.unwrap_or(7).clamp(1, 30);
-    Ok(digest::generate_digest(&state.db, period_days))
+    Ok(digest::generate_digest(
+        &state.db,
+        period_days,
+        group_id.as_deref(),
+    ))
+}
+
+/// Generate a digest and deliver it immediately (persona message + configured
+/// channels), even when the scheduled digest is disabled. `days` and
+/// `group_id` default to the configured cadence and scope.
+#[tauri::command]
+pub fn generate_digest_now(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<AppState>>,
+    days: Option<i64>,
+    group_id: Option<String>,
+) -> Result<PerformanceDigest, AppError> {
+    require_auth_sync(&state)?;
+    let period_days = days.map(|d| d.clamp(1, 30));
+    Ok(digest::generate_digest_now(
+        &state.db,
+        &app,
+        period_days,
+        group_id.as_deref(),
+    ))
 }
 
 /// Manually trigger a digest delivery (bypasses schedule check).
@@ -45,3 +72,13 @@ pub fn send_digest_now(
     digest::deliver_digest(&state.db, &app);
     Ok(())
 }
+
+/// Run the fleet hygiene review now (bypasses the weekly schedule).
+#[tauri::command]
+pub fn run_fleet_hygiene_now(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<AppState>>,
+) -> Result<FleetHygieneReport, AppError> {
+    require_auth_sync(&state)?;
+    fleet_hygiene::run(&state.db, Some(&app))
+}
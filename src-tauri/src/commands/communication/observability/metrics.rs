@@ -7,13 +7,16 @@ use tracing::{info, instrument};
 use ts_rs::TS;
 
 use crate::db::models::{
-    AnomalyDrilldownData, ErrorCategoryBreakdown, ExecutionDashboardData, ExecutionHeatmapData,
-    MetricsChartData, MetricsSummary, PersonaHealingIssue, ValueRollup,
+    AnomalyDrilldownData, CostAnomaly, CostBreakdown, CostGroupBy, ErrorCategoryBreakdown,
+    ExecutionDashboardData, ExecutionHeatmapData, FailureHeatmapData, MetricsChartData,
+    MetricsRollupGranularity, MetricsRollupPoint, MetricsSnapshotRebuild, MetricsSummary,
+    PersonaHealingIssue, TriggerRoi, ValueRollup,
 };
 use crate::db::repos::communication::sla as sla_repo;
 use crate::db::repos::communication::sla::{PersonaDailyReliability, PersonaReliability};
 use crate::db::repos::execution::healing as healing_repo;
 use crate::db::repos::execution::metrics as repo;
+use crate::db::repos::execution::metrics_rollups;
 use crate::db::repos::execution::provider_audit::{self, ProviderUsageStats};
 use crate::engine::byom::ByomPolicy;
 use crate::error::AppError;
@@ -90,6 +93,96 @@ pub fn get_value_rollup(
     repo::get_value_rollup(&state.db, days, persona_id.as_deref())
 }
 
+/// Cost and outcome per trigger of one persona over the window (default 30
+/// days): firings, success rate, cost per firing and per success. Runs no
+/// trigger started are grouped under an `untriggered` row. Sorted most
+/// expensive first so low-value automations are easy to spot.
+#[tauri::command]
+#[instrument(skip(state), fields(persona_id, days))]
+pub fn get_trigger_roi(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    days: Option<i64>,
+) -> Result<Vec<TriggerRoi>, AppError> {
+    require_auth_sync(&state)?;
+    repo::get_trigger_roi(&state.db, &persona_id, days)
+}
+
+/// Spend over the last `days` days (default 30) grouped by persona, team,
+/// model, connector or use case (default persona), each with a daily series.
+#[tauri::command]
+#[instrument(skip(state), fields(days))]
+pub fn get_cost_breakdown(
+    state: State<'_, Arc<AppState>>,
+    days: Option<i64>,
+    group_by: Option<CostGroupBy>,
+) -> Result<CostBreakdown, AppError> {
+    require_auth_sync(&state)?;
+    repo::get_cost_breakdown(&state.db, days, group_by.unwrap_or_default())
+}
+
+/// Days in the last `days` days (default 30) whose spend is more than two
+/// standard deviations off the trailing average, with the top spender of each.
+#[tauri::command]
+#[instrument(skip(state), fields(days, persona_id))]
+pub fn get_cost_anomalies(
+    state: State<'_, Arc<AppState>>,
+    days: Option<i64>,
+    persona_id: Option<String>,
+) -> Result<Vec<CostAnomaly>, AppError> {
+    require_auth_sync(&state)?;
+    repo::get_cost_anomalies(&state.db, days, persona_id.as_deref())
+}
+
+/// Backfill the daily metrics snapshots for `start_date..=end_date`
+/// (`YYYY-MM-DD`, UTC; `end_date` defaults to today) from raw executions and
+/// refresh the weekly/monthly rollups overlapping that range.
+#[tauri::command]
+#[instrument(skip(state), fields(start_date, end_date))]
+pub fn rebuild_metrics_snapshots(
+    state: State<'_, Arc<AppState>>,
+    start_date: String,
+    end_date: Option<String>,
+) -> Result<MetricsSnapshotRebuild, AppError> {
+    require_auth_sync(&state)?;
+    let start = parse_snapshot_date("start_date", &start_date)?;
+    let end = match end_date.as_deref() {
+        Some(d) => parse_snapshot_date("end_date", d)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let report = metrics_rollups::rebuild_range(&state.db, start, end)?;
+    info!(
+        days = report.days,
+        snapshots = report.snapshots_written,
+        "Rebuilt metrics snapshots"
+    );
+    Ok(report)
+}
+
+fn parse_snapshot_date(field: &str, value: &str) -> Result<chrono::NaiveDate, AppError> {
+    chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| AppError::Validation(format!("{field} must be a YYYY-MM-DD date")))
+}
+
+/// The last `periods` weeks or months (default 12) from the materialized
+/// rollups, across all personas or just `persona_id`.
+#[tauri::command]
+#[instrument(skip(state), fields(persona_id))]
+pub fn get_metrics_rollups(
+    state: State<'_, Arc<AppState>>,
+    granularity: MetricsRollupGranularity,
+    periods: Option<i64>,
+    persona_id: Option<String>,
+) -> Result<Vec<MetricsRollupPoint>, AppError> {
+    require_auth_sync(&state)?;
+    metrics_rollups::get_rollup_series(
+        &state.db,
+        granularity,
+        periods.unwrap_or(12),
+        persona_id.as_deref(),
+    )
+}
+
 /// Category-aware error analytics over the window. Classifies each failed
 /// execution's stored `error_message` through the shared `error_taxonomy` at
 /// aggregation time and returns per-category failure counts for the current
@@ -135,6 +228,61 @@ pub fn get_all_monthly_spend(
     Ok(result)
 }
 
+/// Per-persona budget position for the current UTC billing period: spend,
+/// remaining budget, projected end-of-month spend, and whether enforcement has
+/// paused the persona. `persona_id = None` returns every persona.
+#[tauri::command]
+#[instrument(skip(state), fields(persona_id))]
+pub fn get_budget_status(
+    state: State<'_, Arc<AppState>>,
+    persona_id: Option<String>,
+) -> Result<Vec<crate::engine::budget::BudgetStatus>, AppError> {
+    require_auth_sync(&state)?;
+    crate::engine::budget::get_budget_status(&state.db, persona_id.as_deref())
+}
+
+/// Month-to-date spend rolled up per workspace (team), against the workspace's
+/// `monthly_budget_usd` cap. `team_id = None` returns every team.
+#[tauri::command]
+#[instrument(skip(state), fields(team_id))]
+pub fn get_group_spend(
+    state: State<'_, Arc<AppState>>,
+    team_id: Option<String>,
+) -> Result<Vec<crate::engine::budget::GroupSpend>, AppError> {
+    require_auth_sync(&state)?;
+    crate::engine::budget::get_group_spend(&state.db, team_id.as_deref())
+}
+
+/// Reconcile a provider usage export (`provider` = `anthropic` | `openai`,
+/// `csv` = the file's contents) against recorded execution costs. With
+/// `apply_correction`, the billed/recorded ratio is folded into the
+/// provider's correction factor for future cost estimates.
+#[tauri::command]
+#[instrument(skip(state, csv), fields(provider))]
+pub fn reconcile_provider_usage(
+    state: State<'_, Arc<AppState>>,
+    provider: String,
+    csv: String,
+    apply_correction: Option<bool>,
+) -> Result<crate::engine::cost_reconciliation::CostReconciliationReport, AppError> {
+    require_auth_sync(&state)?;
+    crate::engine::cost_reconciliation::reconcile(
+        &state.db,
+        provider.trim(),
+        &csv,
+        apply_correction.unwrap_or(false),
+    )
+}
+
+/// Correction factor currently applied per provider (absent = `1.0`).
+#[tauri::command]
+pub fn get_cost_correction_factors(
+    state: State<'_, Arc<AppState>>,
+) -> Result<std::collections::HashMap<String, f64>, AppError> {
+    require_auth_sync(&state)?;
+    Ok(crate::engine::cost_reconciliation::load_factors(&state.db))
+}
+
 #[tauri::command]
 #[instrument(skip(state), fields(days, persona_id, utc_offset_minutes))]
 pub fn get_overview_bundle(
@@ -428,6 +576,21 @@ pub fn get_execution_heatmap(
     repo::get_execution_heatmap(&state.db, days, persona_id.as_deref(), tz_offset_minutes)
 }
 
+/// Failures over the last `days` days (default 30) bucketed by local hour of
+/// day × the connector they name, from failed executions and healing issues.
+/// Shows recurring windows such as a service's nightly rate limits.
+#[tauri::command]
+#[instrument(skip(state), fields(days, persona_id))]
+pub fn get_failure_heatmap(
+    state: State<'_, Arc<AppState>>,
+    days: Option<i64>,
+    persona_id: Option<String>,
+    tz_offset_minutes: Option<i64>,
+) -> Result<FailureHeatmapData, AppError> {
+    require_auth_sync(&state)?;
+    repo::get_failure_heatmap(&state.db, days, persona_id.as_deref(), tz_offset_minutes)
+}
+
 /// Returns correlated events and root-cause suggestions for a specific anomaly.
 #[tauri::command]
 #[instrument(skip(state), fields(anomaly_date, anomaly_metric))]
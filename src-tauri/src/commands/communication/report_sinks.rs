@@ -0,0 +1,69 @@
+//! Per-persona file-report sink settings. See `engine::report_sink`.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::db::models::{PersonaReportSink, SaveReportSinkInput};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::resources::report_sinks as repo;
+use crate::engine::report_sink;
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+use personas_macros::requires;
+
+#[tauri::command]
+pub fn get_persona_report_sink(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<Option<PersonaReportSink>, AppError> {
+    require_auth_sync(&state)?;
+    repo::get(&state.db, &persona_id)
+}
+
+/// Privileged: the sink writes files to any directory the caller names.
+#[tauri::command]
+#[requires(privileged)]
+pub fn set_persona_report_sink(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    input: SaveReportSinkInput,
+) -> Result<PersonaReportSink, AppError> {
+    persona_repo::get_by_id(&state.db, &persona_id)?;
+    repo::upsert(&state.db, &persona_id, input)
+}
+
+#[tauri::command]
+pub fn delete_persona_report_sink(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    repo::delete(&state.db, &persona_id)
+}
+
+/// Write a report from the persona's most recent completed run right now,
+/// ignoring `scheduled_only`. Returns the written file path.
+#[tauri::command]
+pub async fn test_persona_report_sink(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<String, AppError> {
+    require_auth_sync(&state)?;
+    let pool = state.db.clone();
+    tokio::task::spawn_blocking(move || {
+        let sink = repo::get(&pool, &persona_id)?
+            .ok_or_else(|| AppError::NotFound(format!("Report sink for persona {persona_id}")))?;
+        let persona = persona_repo::get_by_id(&pool, &persona_id)?;
+        let exec = exec_repo::get_by_persona_id(&pool, &persona_id, Some(50))?
+            .into_iter()
+            .find(|e| e.status == "completed" && !e.is_simulation)
+            .ok_or_else(|| AppError::Validation("Persona has no completed run to report on yet".into()))?;
+        report_sink::write_and_record(&pool, &sink, &persona, &exec)
+            .map(|path| path.to_string_lossy().into_owned())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Report sink task failed: {e}")))?
+}
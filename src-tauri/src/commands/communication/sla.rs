@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use tauri::State;
 
-use crate::db::models::SlaDashboardData;
+use crate::db::models::{PersonaSlo, SlaDashboardData, SloStatus, UpsertPersonaSloInput};
 use crate::db::repos::communication::sla as sla_repo;
+use crate::db::repos::core::personas as persona_repo;
 use crate::error::AppError;
 use crate::ipc_auth::require_auth_sync;
 use crate::AppState;
@@ -29,3 +30,76 @@ pub fn get_sla_dashboard(
         .unwrap_or_else(sla_repo::server_offset_minutes);
     sla_repo::get_sla_dashboard_with_offset(&state.db, days, offset_min)
 }
+
+/// Every per-persona SLO (or just `persona_id`'s) with its live measurement
+/// over the SLO's window. Disabled SLOs are listed but not measured.
+#[tauri::command]
+pub fn get_slo_status(
+    state: State<'_, Arc<AppState>>,
+    persona_id: Option<String>,
+) -> Result<Vec<SloStatus>, AppError> {
+    require_auth_sync(&state)?;
+    let slos = match persona_id {
+        Some(id) => sla_repo::get_persona_slo(&state.db, &id)?
+            .into_iter()
+            .collect(),
+        None => sla_repo::list_persona_slos(&state.db, false)?,
+    };
+    slos.into_iter()
+        .map(|slo| {
+            let persona_name = persona_repo::get_by_id(&state.db, &slo.persona_id)
+                .map(|p| p.name)
+                .unwrap_or_default();
+            if !slo.enabled {
+                return Ok(SloStatus {
+                    slo,
+                    persona_name,
+                    state: "disabled".into(),
+                    violations: Vec::new(),
+                    decided: 0,
+                    success_rate: None,
+                    p95_duration_ms: None,
+                });
+            }
+            let m = sla_repo::measure_slo_window(&state.db, &slo.persona_id, slo.window_hours)?;
+            let violations = sla_repo::slo_violations(&slo, &m);
+            let verdict = if m.decided < slo.min_sample {
+                "insufficient_data"
+            } else if violations.is_empty() {
+                "ok"
+            } else {
+                "breached"
+            };
+            Ok(SloStatus {
+                persona_name,
+                state: verdict.into(),
+                violations: violations.into_iter().map(String::from).collect(),
+                decided: m.decided,
+                success_rate: m.success_rate,
+                p95_duration_ms: m.p95_duration_ms,
+                slo,
+            })
+        })
+        .collect()
+}
+
+/// Create or replace a persona's SLO. The background monitor picks it up on
+/// its next tick.
+#[tauri::command]
+pub fn upsert_persona_slo(
+    state: State<'_, Arc<AppState>>,
+    input: UpsertPersonaSloInput,
+) -> Result<PersonaSlo, AppError> {
+    require_auth_sync(&state)?;
+    persona_repo::get_by_id(&state.db, &input.persona_id)?;
+    sla_repo::upsert_persona_slo(&state.db, input)
+}
+
+#[tauri::command]
+pub fn delete_persona_slo(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    sla_repo::delete_persona_slo(&state.db, &persona_id)
+}
@@ -2490,7 +2490,94 @@ pub async fn export_credentials(
         ciphertext: B64.encode(ciphertext),
     };
 
-    let envelope_json = serde_json::to_string_pretty(&envelope)
+    save_credential_envelope(&app, &envelope).await
+}
+
+/// Export the selected credentials to a passphrase-encrypted `.cred.enc`
+/// bundle for handing over to a team member. Every exported credential gets
+/// an `export` audit entry once the file is written.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn export_credentials_bundle(
+    state: State<'_, Arc<AppState>>,
+    app: AppHandle,
+    ids: Vec<String>,
+    passphrase: String,
+) -> Result<bool, AppError> {
+    if passphrase.len() < 8 {
+        return Err(AppError::Validation(
+            "Passphrase must be at least 8 characters".into(),
+        ));
+    }
+    let mut ids = ids;
+    ids.sort();
+    ids.dedup();
+    if ids.is_empty() {
+        return Err(AppError::Validation(
+            "Select at least one credential".into(),
+        ));
+    }
+
+    let pool = &state.db;
+    let selected: Vec<_> = cred_repo::get_all(pool)?
+        .into_iter()
+        .filter(|c| ids.contains(&c.id))
+        .collect();
+    if selected.len() != ids.len() {
+        let missing: Vec<&str> = ids
+            .iter()
+            .filter(|id| !selected.iter().any(|c| &c.id == *id))
+            .map(String::as_str)
+            .collect();
+        return Err(AppError::NotFound(format!(
+            "Credential(s) not found: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let envelope = build_encrypted_credentials(pool, &passphrase, Some(&ids))?;
+    let saved = save_credential_envelope(&app, &envelope).await?;
+    if saved {
+        for cred in &selected {
+            audit_log::insert_warn(
+                pool,
+                &cred.id,
+                &cred.name,
+                "export",
+                Some("credential bundle"),
+            );
+        }
+    }
+    Ok(saved)
+}
+
+/// Import a `.cred.enc` bundle from `file`. Same conflict protocol as
+/// [`import_credentials`]: a first call without `resolutions_json` returns the
+/// name conflicts; every created credential gets an `import` audit entry.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn import_credentials_bundle(
+    state: State<'_, Arc<AppState>>,
+    file: String,
+    passphrase: String,
+    resolutions_json: Option<String>,
+) -> Result<CredentialImportResult, AppError> {
+    let path = std::path::PathBuf::from(file);
+    let bundle = read_credential_file(&path, &passphrase).await?;
+    let resolutions: std::collections::HashMap<String, String> = resolutions_json
+        .as_deref()
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default();
+    import_credential_entries(&state.db, &bundle, &resolutions, &path)
+}
+
+/// Prompt for a save location and write the envelope there. `false` when the
+/// user cancels the dialog.
+async fn save_credential_envelope(
+    app: &AppHandle,
+    envelope: &CredentialExportEnvelope,
+) -> Result<bool, AppError> {
+    let envelope_json = serde_json::to_string_pretty(envelope)
         .map_err(|e| AppError::Internal(format!("Envelope serialization failed: {e}")))?;
 
     let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
@@ -2555,11 +2642,24 @@ pub async fn import_credentials(
             .map_err(|e| AppError::Internal(format!("Invalid file path: {e}")))?
     };
 
+    let bundle = read_credential_file(&path, &passphrase).await?;
+    let resolutions: std::collections::HashMap<String, String> = resolutions_json
+        .as_deref()
+        .and_then(|s| serde_json::from_str(s).ok())
+        .unwrap_or_default();
+    import_credential_entries(&state.db, &bundle, &resolutions, &path).map(Some)
+}
+
+/// Read a `.cred.enc` file and decrypt it with `passphrase`.
+async fn read_credential_file(
+    path: &std::path::Path,
+    passphrase: &str,
+) -> Result<CredentialExportBundle, AppError> {
     // Cap the file size before read_to_string so a multi-GB pick (accidental
     // or socially engineered) cannot OOM the process. Mirrors the guard in
     // `import_persona`, with a tighter ceiling because credential bundles
     // are tiny (JSON envelope + base64 ciphertext).
-    let metadata = tokio::fs::metadata(&path)
+    let metadata = tokio::fs::metadata(path)
         .await
         .map_err(|e| AppError::Internal(format!("Failed to read file metadata: {e}")))?;
     if metadata.len() > MAX_CREDENTIAL_IMPORT_BYTES {
@@ -2570,7 +2670,7 @@ pub async fn import_credentials(
         )));
     }
 
-    let content = tokio::fs::read_to_string(&path)
+    let content = tokio::fs::read_to_string(path)
         .await
         .map_err(|e| AppError::Internal(format!("Failed to read file: {e}")))?;
 
@@ -2594,7 +2694,7 @@ pub async fn import_credentials(
         .decode(&envelope.ciphertext)
         .map_err(|e| AppError::Validation(format!("Invalid ciphertext: {e}")))?;
 
-    let key = derive_key(&passphrase, &salt);
+    let key = derive_key(passphrase, &salt);
     let cipher = Aes256Gcm::new_from_slice(&key)
         .map_err(|e| AppError::Internal(format!("Cipher init failed: {e}")))?;
     let nonce = Nonce::from_slice(&nonce_bytes);
@@ -2603,16 +2703,20 @@ pub async fn import_credentials(
         AppError::Validation("Decryption failed -- wrong passphrase or corrupted file".into())
     })?;
 
-    let bundle: CredentialExportBundle = serde_json::from_slice(&plaintext)
-        .map_err(|e| AppError::Validation(format!("Invalid inner data: {e}")))?;
-
-    let pool = &state.db;
+    serde_json::from_slice(&plaintext)
+        .map_err(|e| AppError::Validation(format!("Invalid inner data: {e}")))
+}
 
-    // Parse resolutions from frontend (second pass after conflict detection)
-    let resolutions: std::collections::HashMap<String, String> = resolutions_json
-        .as_deref()
-        .and_then(|s| serde_json::from_str(s).ok())
-        .unwrap_or_default();
+/// Create the credentials in a decrypted bundle, honouring per-name conflict
+/// `resolutions` (`skip` / `replace` / `keep_both`). Without resolutions, any
+/// name conflict returns early with `conflicts` filled and nothing written.
+fn import_credential_entries(
+    pool: &DbPool,
+    bundle: &CredentialExportBundle,
+    resolutions: &std::collections::HashMap<String, String>,
+    path: &std::path::Path,
+) -> Result<CredentialImportResult, AppError> {
+    // Resolutions come from the frontend's second pass after conflict detection
     let has_resolutions = !resolutions.is_empty();
 
     // Load existing credentials for conflict detection
@@ -2651,10 +2755,12 @@ pub async fn import_credentials(
         if !result.conflicts.is_empty() {
             // Include file path so frontend can re-use it for resolution pass
             result.file_path = Some(path_str);
-            return Ok(Some(result));
+            return Ok(result);
         }
     }
 
+    let mut imported: Vec<(String, String)> = Vec::new();
+
     // Wrap the entire import in a single transaction so that a failed create
     // after a delete does not permanently lose the original credential.
     let mut conn = pool.get()?;
@@ -2769,11 +2875,17 @@ pub async fn import_credentials(
             )?;
         }
 
+        imported.push((cred_id, final_name));
         result.created += 1;
     }
 
     tx.commit().map_err(AppError::Database)?;
-    Ok(Some(result))
+
+    // Audit only after commit so a rolled-back import leaves no entries.
+    for (cred_id, name) in &imported {
+        audit_log::insert_warn(pool, cred_id, name, "import", Some("credential bundle"));
+    }
+    Ok(result)
 }
 
 #[cfg(test)]
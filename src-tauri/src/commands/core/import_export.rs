@@ -162,8 +162,8 @@ pub struct ImportResult {
 // ============================================================================
 
 /// Build the versioned `.persona.json` export bundle for one persona. Shared by
-/// the file-export command and the gallery publish command so both produce an
-/// identical, round-trippable envelope.
+/// the file-export command, the gallery publish command and `personas-cli
+/// export` so all of them produce an identical, round-trippable envelope.
 pub(crate) fn build_persona_bundle(
     pool: &crate::db::DbPool,
     persona_id: &str,
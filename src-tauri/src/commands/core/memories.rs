@@ -3,7 +3,9 @@ use tauri::State;
 
 use ts_rs::TS;
 
-use crate::db::models::{CreatePersonaMemoryInput, MemoryCategoryInfo, PersonaMemory};
+use crate::db::models::{
+    CreatePersonaMemoryInput, MemoryCategoryInfo, PersonaMemory, UpdatePersonaMemoryInput,
+};
 use crate::db::repos::core::memories as repo;
 use crate::db::repos::core::memory_claims::{self as claims_repo, DisputedMemoryRow, MemoryClaim};
 use crate::db::repos::core::memory_review_proposal::{
@@ -237,6 +239,17 @@ pub fn update_memory_content(
     )
 }
 
+/// Edit any subset of a memory's fields, including its expiry.
+#[tauri::command]
+pub fn update_memory(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+    input: UpdatePersonaMemoryInput,
+) -> Result<PersonaMemory, AppError> {
+    require_auth_sync(&state)?;
+    repo::update(&state.db, &id, input)
+}
+
 #[tauri::command]
 pub fn batch_delete_memories(
     state: State<'_, Arc<AppState>>,
@@ -1195,6 +1208,53 @@ pub async fn reflect_team_memories_with_cli(
     })
 }
 
+/// Outcome of [`reindex_memories`].
+#[derive(Debug, serde::Serialize, TS)]
+#[ts(export)]
+pub struct MemoryReindexResult {
+    /// Stored vectors dropped before re-embedding.
+    pub cleared: usize,
+    /// Memories embedded by this run.
+    pub embedded: usize,
+}
+
+/// Rebuild the task-recall embeddings for one persona's memories, or for all
+/// personas when `persona_id` is omitted. Needed after an embedding-model
+/// change; embed-on-write and the startup backfill cover everything else.
+#[tauri::command]
+pub async fn reindex_memories(
+    state: State<'_, Arc<AppState>>,
+    persona_id: Option<String>,
+) -> Result<MemoryReindexResult, AppError> {
+    require_auth(&state).await?;
+    reindex_memories_inner(&state, persona_id.as_deref()).await
+}
+
+#[cfg(feature = "ml")]
+async fn reindex_memories_inner(
+    state: &AppState,
+    persona_id: Option<&str>,
+) -> Result<MemoryReindexResult, AppError> {
+    let embedder = state
+        .embedding_manager
+        .clone()
+        .ok_or_else(|| AppError::Internal("Embedding model is not initialized".into()))?;
+    let (cleared, embedded) =
+        repo::reindex_memory_embeddings(&state.db, &state.user_db, &embedder, persona_id).await?;
+    tracing::info!(?persona_id, cleared, embedded, "memory embeddings reindexed");
+    Ok(MemoryReindexResult { cleared, embedded })
+}
+
+#[cfg(not(feature = "ml"))]
+async fn reindex_memories_inner(
+    _state: &AppState,
+    _persona_id: Option<&str>,
+) -> Result<MemoryReindexResult, AppError> {
+    Err(AppError::Validation(
+        "Memory embeddings are not available in this build".into(),
+    ))
+}
+
 // -- Dev seed: mock memory (debug builds only) -----------------------------------
 
 #[tauri::command]
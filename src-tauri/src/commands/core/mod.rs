@@ -15,6 +15,8 @@ pub mod persona_icons;
 pub mod persona_icon_gen;
 pub mod persona_jobs;
 pub mod personas;
+pub mod prompt_blocks;
 pub mod saved_views;
+pub mod template_variables;
 pub mod use_cases;
 pub mod validation;
@@ -163,6 +163,7 @@ pub fn update_persona(
     input: UpdatePersonaInput,
 ) -> Result<Persona, AppError> {
     validate_update_persona(&input)?;
+    check_backend_compatibility(&state.db, &id, &input)?;
     let result = repo::update(&state.db, &id, input)?;
     // Invalidate cached session AFTER successful DB update
     let pool = state.session_pool.clone();
@@ -235,6 +236,52 @@ pub fn update_persona(
     Ok(result)
 }
 
+/// Run the provider capability check against the persona as it would look
+/// after `input` is applied. Only updates touching the backend selection or a
+/// backend-dependent limit are checked; ignored features are logged, a
+/// blocking mismatch rejects the save.
+fn check_backend_compatibility(
+    pool: &crate::db::DbPool,
+    id: &str,
+    input: &UpdatePersonaInput,
+) -> Result<(), AppError> {
+    if input.model_profile.is_none()
+        && input.engine.is_none()
+        && input.max_budget_usd.is_none()
+        && input.max_turns.is_none()
+    {
+        return Ok(());
+    }
+    let mut prospective = repo::get_by_id(pool, id)?;
+    if let Some(ref v) = input.model_profile {
+        prospective.model_profile = v.clone();
+    }
+    if let Some(ref v) = input.engine {
+        prospective.engine = v.clone();
+    }
+    if let Some(v) = input.max_budget_usd {
+        prospective.max_budget_usd = v;
+    }
+    if let Some(v) = input.max_turns {
+        prospective.max_turns = v;
+    }
+    let has_tools = !tool_repo::get_tools_for_persona(pool, id)?.is_empty();
+    let backend = engine::provider::capabilities::Backend::for_persona(pool, &prospective);
+    let report = engine::provider::capabilities::check(pool, &prospective, has_tools, backend);
+    if let Some(blocking) = report.blocking() {
+        return Err(AppError::Validation(blocking.message.clone()));
+    }
+    for finding in &report.findings {
+        tracing::warn!(
+            persona_id = %id,
+            feature = %finding.feature,
+            "{}",
+            finding.message
+        );
+    }
+    Ok(())
+}
+
 /// Newest-first field-level change history for a persona. Powers the editor
 /// Settings → Change history list. `limit` is clamped to `[1, 500]` server-side.
 #[tauri::command]
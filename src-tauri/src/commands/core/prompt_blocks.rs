@@ -0,0 +1,48 @@
+//! CRUD for the reusable prompt block library. See `engine::prompt_blocks`.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::db::models::{PromptBlock, PromptBlockUsage, SavePromptBlockInput};
+use crate::db::repos::resources::prompt_blocks as repo;
+use crate::engine::prompt_blocks;
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+#[tauri::command]
+pub fn list_prompt_blocks(state: State<'_, Arc<AppState>>) -> Result<Vec<PromptBlock>, AppError> {
+    require_auth_sync(&state)?;
+    repo::list_all(&state.db)
+}
+
+#[tauri::command]
+pub fn save_prompt_block(
+    state: State<'_, Arc<AppState>>,
+    input: SavePromptBlockInput,
+) -> Result<PromptBlock, AppError> {
+    require_auth_sync(&state)?;
+    let saved = repo::save(&state.db, input)?;
+    prompt_blocks::reload(&state.db);
+    Ok(saved)
+}
+
+/// Referencing personas keep their `blockId` entry; the section is skipped
+/// at run time until it is removed or pointed at another block.
+#[tauri::command]
+pub fn delete_prompt_block(state: State<'_, Arc<AppState>>, id: String) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    let deleted = repo::delete(&state.db, &id)?;
+    prompt_blocks::reload(&state.db);
+    Ok(deleted)
+}
+
+/// Personas referencing each block, including references to deleted blocks.
+#[tauri::command]
+pub fn get_prompt_block_usage(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<PromptBlockUsage>, AppError> {
+    require_auth_sync(&state)?;
+    prompt_blocks::usage(&state.db)
+}
@@ -0,0 +1,51 @@
+//! CRUD for `{{var.<name>}}` prompt variables. See `engine::template_variables`.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::db::models::{SaveTemplateVariableInput, TemplateVariable, TemplateVariableUsage};
+use crate::db::repos::resources::template_variables as repo;
+use crate::engine::template_variables;
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+#[tauri::command]
+pub fn list_template_variables(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<TemplateVariable>, AppError> {
+    require_auth_sync(&state)?;
+    repo::list_all(&state.db)
+}
+
+#[tauri::command]
+pub fn save_template_variable(
+    state: State<'_, Arc<AppState>>,
+    input: SaveTemplateVariableInput,
+) -> Result<TemplateVariable, AppError> {
+    require_auth_sync(&state)?;
+    let saved = repo::save(&state.db, input)?;
+    template_variables::reload(&state.db);
+    Ok(saved)
+}
+
+#[tauri::command]
+pub fn delete_template_variable(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    let deleted = repo::delete(&state.db, &id)?;
+    template_variables::reload(&state.db);
+    Ok(deleted)
+}
+
+/// Personas referencing each variable, including undefined references.
+#[tauri::command]
+pub fn get_template_variable_usage(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<TemplateVariableUsage>, AppError> {
+    require_auth_sync(&state)?;
+    template_variables::usage(&state.db)
+}
@@ -178,7 +178,10 @@ pub fn spawn_ai_artifact_task(params: AiArtifactParams) {
 /// 3. Check cancellation
 /// 4. Extract result via `params.extractor`
 /// 5. Emit final status (completed/failed)
-pub async fn run_ai_artifact_task(params: AiArtifactParams) {
+///
+/// Returns the extracted artifact, or `None` when the task failed or was
+/// cancelled.
+pub async fn run_ai_artifact_task(params: AiArtifactParams) -> Option<serde_json::Value> {
     let AiArtifactParams {
         app,
         task_id,
@@ -286,7 +289,7 @@ pub async fn run_ai_artifact_task(params: AiArtifactParams) {
             outcome = "cancelled",
             "AI artifact task cancelled"
         );
-        return;
+        return None;
     }
 
     let duration_ms = started_at.elapsed().as_millis() as u64;
@@ -321,6 +324,7 @@ pub async fn run_ai_artifact_task(params: AiArtifactParams) {
                 None,
                 Some(error_msg),
             );
+            None
         }
         Ok(spawn_result) => {
             // tiger #1: record headless spend (best-effort) before extraction.
@@ -376,9 +380,10 @@ pub async fn run_ai_artifact_task(params: AiArtifactParams) {
                         messages.id_field,
                         &task_id,
                         "completed",
-                        Some(extracted),
+                        Some(extracted.clone()),
                         None,
                     );
+                    Some(extracted)
                 }
                 None => {
                     registry.clear_id_if(&domain, &task_id);
@@ -408,6 +413,7 @@ pub async fn run_ai_artifact_task(params: AiArtifactParams) {
                         None,
                         Some(messages.extraction_failed_error.into()),
                     );
+                    None
                 }
             }
         }
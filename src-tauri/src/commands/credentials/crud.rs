@@ -447,6 +447,74 @@ pub fn migrate_plaintext_credentials(
     }))
 }
 
+#[tauri::command]
+pub fn vault_backend_status(
+    state: State<'_, Arc<AppState>>,
+) -> Result<crate::engine::vault_backend::VaultBackendStatus, AppError> {
+    // Public command -- the settings UI needs it to prompt for an unlock.
+    Ok(crate::engine::vault_backend::status(&state.db))
+}
+
+/// Unlock a passphrase- or token-protected vault backend for this session.
+/// The secret is used once and never stored.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn unlock_vault(
+    state: State<'_, Arc<AppState>>,
+    secret: String,
+) -> Result<crate::engine::vault_backend::VaultBackendStatus, AppError> {
+    crate::engine::vault_backend::unlock(&state.db, &secret).await?;
+    // Startup skipped these while the vault was locked.
+    if let Err(e) = crypto::migrate_plaintext_credentials(&state.db) {
+        tracing::warn!("Credential migration after unlock skipped: {}", e);
+    }
+    Ok(crate::engine::vault_backend::status(&state.db))
+}
+
+/// Copy the master key into another vault backend and select it for the next
+/// start. `secret` is the new passphrase or the Vault token.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn configure_vault_backend(
+    state: State<'_, Arc<AppState>>,
+    config: crate::engine::vault_backend::VaultBackendConfig,
+    secret: Option<String>,
+) -> Result<crate::engine::vault_backend::VaultBackendStatus, AppError> {
+    crate::engine::vault_backend::configure(&state.db, config, secret.as_deref()).await?;
+    Ok(crate::engine::vault_backend::status(&state.db))
+}
+
+/// Seal the master key with a passphrase so the vault can be moved to another
+/// machine. Returns the sealed key as base64.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn export_vault_key(
+    state: State<'_, Arc<AppState>>,
+    passphrase: String,
+) -> Result<String, AppError> {
+    let _ = &state;
+    tokio::task::spawn_blocking(move || crate::engine::vault_migration::export_key(&passphrase))
+        .await
+        .map_err(|e| AppError::Internal(format!("Key export task failed: {e}")))?
+}
+
+/// Import a key exported on another machine and re-encrypt the vault under
+/// this machine's master key.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn import_vault_key(
+    state: State<'_, Arc<AppState>>,
+    exported_key: String,
+    passphrase: String,
+) -> Result<crate::engine::vault_migration::VaultKeyImportReport, AppError> {
+    let pool = state.db.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::engine::vault_migration::import_key(&pool, &exported_key, &passphrase)
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Key import task failed: {e}")))?
+}
+
 /// Get field-level metadata for a credential (field keys, types, sensitivity).
 /// Returns field metadata without decrypted values -- safe for frontend display.
 #[tauri::command]
@@ -6,11 +6,16 @@
 use std::sync::Arc;
 use tauri::State;
 
-use crate::db::models::{ApiKeyAuditEntry, CreateApiKeyResponse, ExternalApiKey};
+use crate::db::models::{
+    ApiKeyAuditEntry, CreateApiKeyResponse, CreateExecuteHookResponse, ExternalApiKey,
+    PersonaExecuteHook,
+};
+use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::resources::api_key_audit;
+use crate::db::repos::resources::execute_hooks as hook_repo;
 use crate::db::repos::resources::external_api_keys as repo;
 use crate::db::repos::resources::settings_audit_log;
-use crate::engine::management_api;
+use crate::engine::{execute_hook, management_api, webhook};
 use crate::engine::pairing::{self, PendingPairingView};
 use crate::error::AppError;
 use crate::AppState;
@@ -131,6 +136,103 @@ pub fn list_api_key_audit(
     api_key_audit::list_for_key(&state.db, &key_id, limit.unwrap_or(100))
 }
 
+// ============================================================================
+// Execute hooks (`/hooks/execute/<token>`)
+// ============================================================================
+
+/// Default seconds an execute-hook caller waits for the result.
+const DEFAULT_HOOK_WAIT_SECS: i64 = 30;
+
+fn validate_hook_wait(secs: i64) -> Result<i64, AppError> {
+    if !(0..=execute_hook::MAX_WAIT_SECS).contains(&secs) {
+        return Err(AppError::Validation(format!(
+            "Wait timeout must be between 0 and {} seconds",
+            execute_hook::MAX_WAIT_SECS
+        )));
+    }
+    Ok(secs)
+}
+
+/// Issue (or rotate) a persona's execute-hook token. The previous token stops
+/// working immediately; the new plaintext and URL are returned only here.
+#[tauri::command]
+#[requires(privileged)]
+pub fn create_persona_execute_hook(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    wait_timeout_secs: Option<i64>,
+) -> Result<CreateExecuteHookResponse, AppError> {
+    let persona = persona_repo::get_by_id(&state.db, &persona_id)?;
+    let wait = validate_hook_wait(wait_timeout_secs.unwrap_or(DEFAULT_HOOK_WAIT_SECS))?;
+    let (hook, plaintext_token) = hook_repo::rotate(&state.db, &persona_id, wait)?;
+    tracing::info!(persona_id = %persona_id, prefix = %hook.token_prefix, "execute hook issued");
+    let after = serde_json::json!({ "prefix": hook.token_prefix }).to_string();
+    if let Err(e) = settings_audit_log::insert(
+        &state.db,
+        "execute_hooks",
+        &persona.name,
+        "create",
+        None,
+        Some(&after),
+        Some("ui"),
+    ) {
+        tracing::warn!(error = %e, "settings_audit_log insert failed for execute hook create");
+    }
+    let url = format!(
+        "http://127.0.0.1:{}/hooks/execute/{plaintext_token}",
+        webhook::webhook_port()
+    );
+    Ok(CreateExecuteHookResponse {
+        hook,
+        plaintext_token,
+        url,
+    })
+}
+
+#[tauri::command]
+#[requires(privileged)]
+pub fn get_persona_execute_hook(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<Option<PersonaExecuteHook>, AppError> {
+    hook_repo::get(&state.db, &persona_id)
+}
+
+#[tauri::command]
+#[requires(privileged)]
+pub fn set_persona_execute_hook_wait(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    wait_timeout_secs: i64,
+) -> Result<PersonaExecuteHook, AppError> {
+    hook_repo::set_wait_timeout(&state.db, &persona_id, validate_hook_wait(wait_timeout_secs)?)
+}
+
+/// Delete a persona's execute hook. Returns whether one existed.
+#[tauri::command]
+#[requires(privileged)]
+pub fn revoke_persona_execute_hook(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<bool, AppError> {
+    let removed = hook_repo::revoke(&state.db, &persona_id)?;
+    if removed {
+        tracing::info!(persona_id = %persona_id, "execute hook revoked");
+        if let Err(e) = settings_audit_log::insert(
+            &state.db,
+            "execute_hooks",
+            &persona_id,
+            "revoke",
+            None,
+            None,
+            Some("ui"),
+        ) {
+            tracing::warn!(error = %e, "settings_audit_log insert failed for execute hook revoke");
+        }
+    }
+    Ok(removed)
+}
+
 // ============================================================================
 // Pairing ceremony (Direction 1)
 // ============================================================================
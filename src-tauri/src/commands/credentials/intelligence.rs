@@ -1,8 +1,14 @@
+use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
-use tauri::State;
 
-use crate::db::models::{CredentialAuditEntry, CredentialDependent, CredentialUsageStats};
-use crate::db::repos::resources::audit_log;
+use chrono::{Duration, Local, NaiveDateTime, Timelike, Utc};
+use tauri::{AppHandle, State};
+
+use crate::db::models::{
+    CredentialAnomaly, CredentialAuditEntry, CredentialDependent, CredentialUsageStats,
+};
+use crate::db::repos::resources::{audit_log, credential_anomalies as anomaly_repo};
+use crate::db::DbPool;
 use crate::error::AppError;
 use crate::AppState;
 use personas_macros::requires;
@@ -47,3 +53,259 @@ pub fn credential_dependents(
 ) -> Result<Vec<CredentialDependent>, AppError> {
     audit_log::get_dependents(&state.db, &credential_id)
 }
+
+// ---------------------------------------------------------------------------
+// Usage anomaly detection
+// ---------------------------------------------------------------------------
+//
+// Three patterns are flagged from `credential_audit_log` injections and
+// execution history:
+// - `new_persona`: a persona uses an established credential for the first time;
+// - `off_hours_burst`: an hour of heavy use at night (local time), well above
+//   the credential's usual hourly rate;
+// - `use_after_rotation`: a run injected the credential before a rotation and
+//   kept running past it, i.e. on the superseded secret. A run that still
+//   completed suggests the old secret was never revoked.
+//
+// Each scan looks back `SCAN_LOOKBACK_HOURS`; `dedupe_key` keeps overlapping
+// scans from flagging the same event twice.
+
+const SCAN_LOOKBACK_HOURS: i64 = 24;
+/// Hourly-rate baseline window for burst detection.
+const BASELINE_DAYS: i64 = 14;
+/// A credential first used more recently than this has no "usual" personas yet.
+const ESTABLISHED_DAYS: i64 = 7;
+/// Minimum injections in one hour before it can count as a burst.
+const BURST_MIN_USES: i64 = 15;
+/// How far above the baseline hourly rate a burst must be.
+const BURST_BASELINE_FACTOR: f64 = 4.0;
+
+/// Local hours (22:00–05:59) treated as off-hours.
+fn is_off_hour(hour: u32) -> bool {
+    !(6..22).contains(&hour)
+}
+
+/// Local hour of a `YYYY-MM-DD?HH` UTC bucket (`?` is `T` or a space).
+fn local_hour(bucket: &str) -> Option<u32> {
+    let (date, hour) = (bucket.get(..10)?, bucket.get(11..13)?);
+    let utc =
+        NaiveDateTime::parse_from_str(&format!("{date} {hour}:00:00"), "%Y-%m-%d %H:%M:%S").ok()?;
+    Some(utc.and_utc().with_timezone(&Local).hour())
+}
+
+/// An open anomaly with the identifying fields set; callers fill in the rest.
+fn base_anomaly(
+    credential_id: &str,
+    credential_name: &str,
+    kind: &str,
+    severity: &str,
+    dedupe_key: String,
+) -> CredentialAnomaly {
+    CredentialAnomaly {
+        id: uuid::Uuid::new_v4().to_string(),
+        credential_id: credential_id.to_string(),
+        credential_name: credential_name.to_string(),
+        persona_id: None,
+        persona_name: None,
+        kind: kind.to_string(),
+        severity: severity.to_string(),
+        title: String::new(),
+        description: String::new(),
+        evidence: "{}".to_string(),
+        dedupe_key,
+        status: "open".to_string(),
+        detected_at: Utc::now().to_rfc3339(),
+        acknowledged_at: None,
+    }
+}
+
+/// Run every detector over the lookback window and store what they find.
+/// Returns only anomalies not flagged by an earlier scan.
+pub fn detect_usage_anomalies(pool: &DbPool) -> Result<Vec<CredentialAnomaly>, AppError> {
+    let now = Utc::now();
+    let since = (now - Duration::hours(SCAN_LOOKBACK_HOURS)).to_rfc3339();
+    let mut found = Vec::new();
+
+    let established_before = (now - Duration::days(ESTABLISHED_DAYS)).to_rfc3339();
+    for u in anomaly_repo::first_persona_uses(pool, &since, &established_before)? {
+        let persona = u
+            .persona_name
+            .clone()
+            .unwrap_or_else(|| u.persona_id.clone());
+        found.push(CredentialAnomaly {
+            persona_id: Some(u.persona_id.clone()),
+            persona_name: u.persona_name.clone(),
+            title: format!("{persona} used {} for the first time", u.credential_name),
+            description: format!(
+                "{persona} had never used {} before {}. Confirm the agent is meant to have this credential.",
+                u.credential_name, u.first_used_at
+            ),
+            evidence: serde_json::json!({ "firstUsedAt": u.first_used_at }).to_string(),
+            ..base_anomaly(
+                &u.credential_id,
+                &u.credential_name,
+                "new_persona",
+                "medium",
+                format!("new_persona:{}:{}", u.credential_id, u.persona_id),
+            )
+        });
+    }
+
+    let baseline_since = (now - Duration::days(BASELINE_DAYS)).to_rfc3339();
+    for b in anomaly_repo::hourly_uses(pool, &since, &baseline_since, BURST_MIN_USES)? {
+        let Some(hour) = local_hour(&b.hour).filter(|h| is_off_hour(*h)) else {
+            continue;
+        };
+        if (b.uses as f64) < b.baseline_per_hour * BURST_BASELINE_FACTOR {
+            continue;
+        }
+        found.push(CredentialAnomaly {
+            title: format!("Burst of {} uses of {} at {hour:02}:00", b.uses, b.credential_name),
+            description: format!(
+                "{} was injected {} times by {} persona(s) in one off-hours hour, against a usual {:.1} per active hour.",
+                b.credential_name, b.uses, b.personas, b.baseline_per_hour
+            ),
+            evidence: serde_json::json!({
+                "hourUtc": b.hour,
+                "localHour": hour,
+                "uses": b.uses,
+                "personas": b.personas,
+                "baselinePerHour": b.baseline_per_hour,
+            })
+            .to_string(),
+            ..base_anomaly(
+                &b.credential_id,
+                &b.credential_name,
+                "off_hours_burst",
+                "high",
+                format!("off_hours_burst:{}:{}", b.credential_id, b.hour),
+            )
+        });
+    }
+
+    for r in anomaly_repo::runs_across_rotations(pool, &since)? {
+        let persona = r
+            .persona_name
+            .clone()
+            .unwrap_or_else(|| r.persona_id.clone());
+        let completed = r.execution_status == "completed";
+        let description = if completed {
+            format!(
+                "Execution {} of {persona} injected {} before it was rotated at {} and still completed — the previous secret may not have been revoked.",
+                r.execution_id, r.credential_name, r.rotated_at
+            )
+        } else {
+            format!(
+                "Execution {} of {persona} injected {} before it was rotated at {} and was still running on the previous secret ({}).",
+                r.execution_id, r.credential_name, r.rotated_at, r.execution_status
+            )
+        };
+        found.push(CredentialAnomaly {
+            persona_id: Some(r.persona_id.clone()),
+            persona_name: r.persona_name.clone(),
+            title: format!("{} used after rotation", r.credential_name),
+            description,
+            evidence: serde_json::json!({
+                "executionId": r.execution_id,
+                "executionStatus": r.execution_status,
+                "rotationId": r.rotation_id,
+                "rotatedAt": r.rotated_at,
+                "injectedAt": r.injected_at,
+            })
+            .to_string(),
+            ..base_anomaly(
+                &r.credential_id,
+                &r.credential_name,
+                "use_after_rotation",
+                if completed { "high" } else { "medium" },
+                format!("use_after_rotation:{}:{}", r.rotation_id, r.execution_id),
+            )
+        });
+    }
+
+    let mut recorded = Vec::new();
+    for a in found {
+        if anomaly_repo::insert_if_new(pool, &a)? {
+            recorded.push(a);
+        }
+    }
+    Ok(recorded)
+}
+
+/// Unix seconds of the last background scan. The rotation tick runs every
+/// minute; usage anomalies only need checking hourly.
+static LAST_ANOMALY_SCAN: AtomicI64 = AtomicI64::new(0);
+const ANOMALY_SCAN_INTERVAL_SECS: i64 = 3600;
+
+/// Background scan from the rotation tick: detect, then notify once per new
+/// anomaly.
+pub async fn scan_usage_anomalies(pool: &DbPool, app: &AppHandle) {
+    let now_secs = Utc::now().timestamp();
+    let last = LAST_ANOMALY_SCAN.load(Ordering::Relaxed);
+    if now_secs - last < ANOMALY_SCAN_INTERVAL_SECS
+        || LAST_ANOMALY_SCAN
+            .compare_exchange(last, now_secs, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+    {
+        return;
+    }
+
+    let recorded = match detect_usage_anomalies(pool) {
+        Ok(recorded) => recorded,
+        Err(e) => {
+            tracing::warn!("Credential usage anomaly scan failed: {}", e);
+            return;
+        }
+    };
+    for a in &recorded {
+        tracing::warn!(
+            credential_id = %a.credential_id,
+            kind = %a.kind,
+            severity = %a.severity,
+            "Credential usage anomaly"
+        );
+        crate::notifications::notify_credential_anomaly(app, a);
+    }
+}
+
+/// List flagged usage anomalies, newest first. `status` is `"open"` or
+/// `"acknowledged"`; omit for both.
+#[tauri::command]
+#[requires(privileged)]
+pub fn list_credential_anomalies(
+    state: State<'_, Arc<AppState>>,
+    status: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<CredentialAnomaly>, AppError> {
+    if let Some(s) = status.as_deref() {
+        if !matches!(s, "open" | "acknowledged") {
+            return Err(AppError::Validation(format!(
+                "Unknown anomaly status '{s}' (expected open or acknowledged)"
+            )));
+        }
+    }
+    anomaly_repo::list(
+        &state.db,
+        status.as_deref(),
+        limit.unwrap_or(100).min(500) as i64,
+    )
+}
+
+/// Run the usage anomaly detector now; returns newly flagged anomalies.
+#[tauri::command]
+#[requires(privileged)]
+pub fn scan_credential_anomalies(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<CredentialAnomaly>, AppError> {
+    detect_usage_anomalies(&state.db)
+}
+
+/// Mark an anomaly as reviewed so it leaves the open list.
+#[tauri::command]
+#[requires(privileged)]
+pub fn acknowledge_credential_anomaly(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<CredentialAnomaly, AppError> {
+    anomaly_repo::acknowledge(&state.db, &id)
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::engine::mcp::{self, McpServerStatus};
+use crate::error::AppError;
+use crate::AppState;
+use personas_macros::requires;
+
+/// Every connector that declares a stdio MCP server, with the live state of
+/// the ones that have been started.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn list_mcp_servers(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<McpServerStatus>, AppError> {
+    mcp::list_servers(&state.db).await
+}
+
+#[tauri::command]
+#[requires(privileged)]
+pub async fn install_mcp_server(
+    state: State<'_, Arc<AppState>>,
+    connector: String,
+) -> Result<McpServerStatus, AppError> {
+    mcp::install(&state.db, &connector).await
+}
+
+#[tauri::command]
+#[requires(privileged)]
+pub async fn start_mcp_server(
+    state: State<'_, Arc<AppState>>,
+    connector: String,
+) -> Result<McpServerStatus, AppError> {
+    mcp::start(&state.db, &connector).await
+}
+
+#[tauri::command]
+#[requires(privileged)]
+pub async fn stop_mcp_server(
+    state: State<'_, Arc<AppState>>,
+    connector: String,
+) -> Result<McpServerStatus, AppError> {
+    mcp::stop(&connector).await;
+    mcp::status(&state.db, &connector).await
+}
+
+#[tauri::command]
+#[requires(privileged)]
+pub async fn restart_mcp_server(
+    state: State<'_, Arc<AppState>>,
+    connector: String,
+) -> Result<McpServerStatus, AppError> {
+    mcp::restart(&state.db, &connector).await
+}
+
+/// Probe a started server with `tools/list`; unresponsive servers are marked
+/// unhealthy and stopped.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn check_mcp_server_health(
+    state: State<'_, Arc<AppState>>,
+    connector: String,
+) -> Result<McpServerStatus, AppError> {
+    let _ = state; // auth gate only
+    mcp::health_check(&connector).await
+}
@@ -17,6 +17,7 @@ pub mod external_api_keys;
 pub mod foraging;
 pub mod intelligence;
 pub mod mcp_gateways;
+pub mod mcp_servers;
 pub mod mcp_tools;
 pub mod negotiator;
 pub mod nl_query;
@@ -3,9 +3,15 @@ use std::sync::Arc;
 use serde_json::json;
 use tauri::State;
 
+use crate::db::models::{
+    CredentialNegotiation, NegotiationTranscript, RecordNegotiationEventInput,
+};
 use crate::db::repos::resources::audit_log;
+use crate::db::repos::resources::credential_negotiations as negotiation_repo;
+use crate::db::DbPool;
 use crate::engine::credential_negotiator;
 use crate::error::AppError;
+use crate::utils::sanitization::sanitize_secrets;
 
 use crate::AppState;
 
@@ -32,6 +38,62 @@ const NEGOTIATION_MESSAGES: AiArtifactMessages = AiArtifactMessages {
     timeout_secs: 300,
 };
 
+/// Longest step title or help text kept in a transcript entry.
+const MAX_TRANSCRIPT_TEXT: usize = 2_000;
+
+/// Secret-masked, length-capped text for a transcript entry.
+fn transcript_text(text: &str) -> String {
+    sanitize_secrets(text)
+        .chars()
+        .take(MAX_TRANSCRIPT_TEXT)
+        .collect()
+}
+
+/// Best-effort transcript write; a failure never interrupts the negotiation.
+fn record_event(
+    pool: &DbPool,
+    negotiation_id: &str,
+    kind: &str,
+    step_index: Option<u32>,
+    detail: Option<serde_json::Value>,
+) {
+    if let Err(e) =
+        negotiation_repo::append_event(pool, negotiation_id, kind, step_index, detail.as_ref())
+    {
+        tracing::warn!(negotiation_id, kind, error = %e, "Failed to record negotiation event");
+    }
+}
+
+/// Persist how plan generation ended. A cancelled run was already closed by
+/// `cancel_credential_negotiation`, so it is not marked failed here.
+fn record_plan_outcome(pool: &DbPool, negotiation_id: &str, plan: Option<serde_json::Value>) {
+    match plan {
+        Some(plan) => {
+            if let Err(e) = negotiation_repo::set_plan(pool, negotiation_id, &plan) {
+                tracing::warn!(negotiation_id, error = %e, "Failed to store negotiation plan");
+            }
+            let step_count = plan["steps"].as_array().map_or(0, Vec::len);
+            record_event(
+                pool,
+                negotiation_id,
+                "plan_ready",
+                None,
+                Some(json!({ "step_count": step_count })),
+            );
+        }
+        None => match negotiation_repo::close(
+            pool,
+            negotiation_id,
+            "failed",
+            Some(NEGOTIATION_MESSAGES.extraction_failed_error),
+        ) {
+            Ok(true) => record_event(pool, negotiation_id, "plan_failed", None, None),
+            Ok(false) => {}
+            Err(e) => tracing::warn!(negotiation_id, error = %e, "Failed to close negotiation"),
+        },
+    }
+}
+
 // -- Commands ----------------------------------------------------
 
 /// Start a credential negotiation -- generates a step-by-step provisioning plan.
@@ -83,12 +145,29 @@ pub async fn start_credential_negotiation(
         Some(&format!("provisioning plan for '{service_name}'")),
     );
 
+    // The transcript keeps field keys and which services were already
+    // signed in -- never connector secrets.
+    match negotiation_repo::create(&state.db, &negotiation_id, &service_name) {
+        Ok(()) => record_event(
+            &state.db,
+            &negotiation_id,
+            "plan_requested",
+            None,
+            Some(json!({
+                "field_keys": field_keys,
+                "authenticated_services": auth_services.len(),
+            })),
+        ),
+        Err(e) => tracing::warn!(error = %e, "Failed to open negotiation transcript"),
+    }
+
     let neg_id = negotiation_id.clone();
+    let pool = state.db.clone();
 
     tokio::spawn(async move {
-        run_ai_artifact_task(AiArtifactParams {
+        let plan = run_ai_artifact_task(AiArtifactParams {
             app,
-            task_id: neg_id,
+            task_id: neg_id.clone(),
             prompt_text: negotiation_prompt,
             cli_args,
             registry,
@@ -99,15 +178,28 @@ pub async fn start_credential_negotiation(
             spend: None,
         })
         .await;
+        record_plan_outcome(&pool, &neg_id, plan);
     });
 
     Ok(json!({ "negotiation_id": negotiation_id }))
 }
 
-/// Cancel an active credential negotiation.
+/// Cancel an active credential negotiation. `negotiation_id` identifies a
+/// negotiation past its planning phase (no CLI process left to kill) so its
+/// transcript is closed as cancelled too.
 #[tauri::command]
 #[requires(privileged)]
-pub fn cancel_credential_negotiation(state: State<'_, Arc<AppState>>) -> Result<(), AppError> {
+pub fn cancel_credential_negotiation(
+    state: State<'_, Arc<AppState>>,
+    negotiation_id: Option<String>,
+) -> Result<(), AppError> {
+    let active_id = state.process_registry.get_id("negotiation");
+    if let Some(id) = negotiation_id.or(active_id) {
+        if negotiation_repo::close(&state.db, &id, "cancelled", None).unwrap_or(false) {
+            record_event(&state.db, &id, "cancelled", None, None);
+        }
+    }
+
     // Cancel the active negotiation and kill the CLI child process.
     if let Some(pid) = state.process_registry.cancel("negotiation") {
         tracing::info!(
@@ -120,7 +212,8 @@ pub fn cancel_credential_negotiation(state: State<'_, Arc<AppState>>) -> Result<
     Ok(())
 }
 
-/// Get contextual help for a specific provisioning step.
+/// Get contextual help for a specific provisioning step. With a
+/// `negotiation_id` the exchange is added to that negotiation's transcript.
 #[tauri::command]
 #[requires(privileged)]
 pub async fn get_negotiation_step_help(
@@ -129,6 +222,7 @@ pub async fn get_negotiation_step_help(
     step_index: u32,
     step_title: String,
     user_question: String,
+    negotiation_id: Option<String>,
 ) -> Result<serde_json::Value, AppError> {
     let prompt_text = credential_negotiator::build_step_help_prompt(
         &service_name,
@@ -152,5 +246,127 @@ pub async fn get_negotiation_step_help(
             AppError::Internal("Failed to extract step help from Claude output".into())
         })?;
 
+    if let Some(id) = negotiation_id.as_deref() {
+        record_event(
+            &state.db,
+            id,
+            "help_requested",
+            Some(step_index),
+            Some(json!({
+                "step_title": transcript_text(&step_title),
+                "question": transcript_text(&user_question),
+                "answer": transcript_text(help_result["answer"].as_str().unwrap_or_default()),
+            })),
+        );
+    }
+
     Ok(help_result)
 }
+
+// -- Transcripts -------------------------------------------------
+
+/// Open a transcript for a negotiation that skipped plan generation because
+/// a saved playbook supplied the plan. Returns the new negotiation id.
+#[tauri::command]
+#[requires(privileged)]
+pub fn open_negotiation_transcript(
+    state: State<'_, Arc<AppState>>,
+    service_name: String,
+    plan: serde_json::Value,
+) -> Result<String, AppError> {
+    let negotiation_id = uuid::Uuid::new_v4().to_string();
+    negotiation_repo::create(&state.db, &negotiation_id, &service_name)?;
+    negotiation_repo::set_plan(&state.db, &negotiation_id, &plan)?;
+    let step_count = plan["steps"].as_array().map_or(0, Vec::len);
+    negotiation_repo::append_event(
+        &state.db,
+        &negotiation_id,
+        "plan_ready",
+        None,
+        Some(&json!({ "step_count": step_count, "source": "playbook" })),
+    )?;
+    Ok(negotiation_id)
+}
+
+/// Record a step the user completed in the negotiator. Only field keys are
+/// stored; captured values never reach the transcript.
+#[tauri::command]
+#[requires(privileged)]
+pub fn record_negotiation_event(
+    state: State<'_, Arc<AppState>>,
+    input: RecordNegotiationEventInput,
+) -> Result<(), AppError> {
+    if !matches!(input.kind.as_str(), "step_completed" | "fields_captured") {
+        return Err(AppError::Validation(format!(
+            "Unknown negotiation event '{}' (expected step_completed or fields_captured)",
+            input.kind
+        )));
+    }
+    let detail = json!({
+        "step_title": input.step_title.as_deref().map(transcript_text),
+        "field_keys": input.field_keys,
+    });
+    negotiation_repo::append_event(
+        &state.db,
+        &input.negotiation_id,
+        &input.kind,
+        input.step_index,
+        Some(&detail),
+    )
+}
+
+/// Link the credential saved from a negotiation, completing its transcript.
+#[tauri::command]
+#[requires(privileged)]
+pub fn link_negotiation_credential(
+    state: State<'_, Arc<AppState>>,
+    negotiation_id: String,
+    credential_id: String,
+) -> Result<(), AppError> {
+    negotiation_repo::link_credential(&state.db, &negotiation_id, &credential_id)?;
+    negotiation_repo::append_event(
+        &state.db,
+        &negotiation_id,
+        "credential_linked",
+        None,
+        Some(&json!({ "credential_id": credential_id })),
+    )
+}
+
+/// A negotiation transcript by negotiation id, or the latest one that
+/// produced `credential_id`.
+#[tauri::command]
+#[requires(privileged)]
+pub fn get_negotiation_transcript(
+    state: State<'_, Arc<AppState>>,
+    negotiation_id: Option<String>,
+    credential_id: Option<String>,
+) -> Result<NegotiationTranscript, AppError> {
+    match (negotiation_id, credential_id) {
+        (Some(id), _) => negotiation_repo::get_transcript(&state.db, &id),
+        (None, Some(credential_id)) => {
+            negotiation_repo::get_for_credential(&state.db, &credential_id)
+        }
+        (None, None) => Err(AppError::Validation(
+            "Either negotiation_id or credential_id is required".into(),
+        )),
+    }
+}
+
+/// Search negotiation transcripts, newest first. `query` matches service
+/// names, plans, step titles and help exchanges.
+#[tauri::command]
+#[requires(privileged)]
+pub fn search_negotiation_transcripts(
+    state: State<'_, Arc<AppState>>,
+    query: Option<String>,
+    credential_id: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<CredentialNegotiation>, AppError> {
+    negotiation_repo::search(
+        &state.db,
+        query.as_deref(),
+        credential_id.as_deref(),
+        limit.unwrap_or(50).min(500) as i64,
+    )
+}
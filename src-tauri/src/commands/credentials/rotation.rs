@@ -130,6 +130,28 @@ pub fn get_all_rotation_statuses(
     rotation_engine::get_all_rotation_statuses(&state.db)
 }
 
+/// Credentials whose `expires_at` falls within `days` (default: the
+/// `credential_expiry_warning_days` setting), including already-expired ones.
+#[tauri::command]
+#[requires(privileged)]
+pub fn list_expiring_credentials(
+    state: State<'_, Arc<AppState>>,
+    days: Option<u32>,
+) -> Result<Vec<rotation_engine::ExpiringCredential>, AppError> {
+    use crate::db::settings_keys;
+    let days = match days {
+        // Ten years is already "everything with an expiry".
+        Some(d) => d.min(3650),
+        None => crate::db::repos::core::settings::get(
+            &state.db,
+            settings_keys::CREDENTIAL_EXPIRY_WARNING_DAYS,
+        )?
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(settings_keys::CREDENTIAL_EXPIRY_WARNING_DAYS_DEFAULT),
+    };
+    rotation_engine::list_expiring(&state.db, days)
+}
+
 #[tauri::command]
 #[requires(privileged)]
 pub async fn rotate_credential_now(
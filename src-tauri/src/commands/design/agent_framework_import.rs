@@ -0,0 +1,660 @@
+//! Import agents from Python agent-framework configs.
+//!
+//! Supported sources:
+//! - **CrewAI** — `agents.yaml` (role/goal/backstory/tools/llm) plus an
+//!   optional `tasks.yaml` (description/expected_output/agent/context), or a
+//!   single document with `agents:` and `tasks:` sections;
+//! - **LangChain** — agent JSON: one agent object, `{"agents": [...],
+//!   "edges": [...]}` for multi-agent graphs, or a serialized (`"lc": 1`)
+//!   agent whose prompt template is nested under `kwargs`.
+//!
+//! Each agent becomes an [`N8nPersonaOutput`] draft with a team role; with
+//! more than one agent the confirm step also creates a team whose
+//! connections follow the task order (CrewAI) or declared edges (LangChain).
+//! Parsing is deterministic; personas are created through the same atomic
+//! path as n8n imports.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::State;
+
+use crate::db::models::CreateTeamInput;
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::resources::teams as team_repo;
+use crate::db::DbPool;
+use crate::engine::topology_types::compute_dag_layout;
+use crate::engine::types::{providers, ModelProfile};
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+use super::gpt_import::{builtin, str_at, tool_name};
+use super::n8n_transform::confirmation::create_persona_atomically;
+use super::n8n_transform::types::N8nToolDraft;
+use super::n8n_transform::N8nPersonaOutput;
+use super::team_synthesis::normalize_team_role;
+
+const MAX_CONFIG_BYTES: usize = 1024 * 1024;
+
+/// One imported agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkAgentDraft {
+    /// Agent key in the source config (CrewAI id / LangChain name).
+    pub key: String,
+    /// `orchestrator | worker | reviewer | router`.
+    pub team_role: String,
+    pub draft: N8nPersonaOutput,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FrameworkConnection {
+    pub source_index: usize,
+    pub target_index: usize,
+}
+
+/// Parsed config, reviewed in the UI and sent back to confirm.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentFrameworkImport {
+    /// `"crewai"` or `"langchain"`.
+    pub source_format: String,
+    pub team_name: Option<String>,
+    pub agents: Vec<FrameworkAgentDraft>,
+    pub connections: Vec<FrameworkConnection>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentFrameworkImportResult {
+    pub persona_ids: Vec<String>,
+    /// Set when more than one agent was imported.
+    pub team_id: Option<String>,
+}
+
+// -- Parsing ----------------------------------------------------------------
+
+fn load(content: &str) -> Result<serde_yaml::Value, AppError> {
+    if content.len() > MAX_CONFIG_BYTES {
+        return Err(AppError::Validation("Config exceeds 1 MB".into()));
+    }
+    // JSON is valid YAML, and YAML mappings keep their order (CrewAI task
+    // order drives the team's connections).
+    serde_yaml::from_str(content)
+        .map_err(|e| AppError::Validation(format!("Config is neither JSON nor YAML: {e}")))
+}
+
+fn to_json(v: &serde_yaml::Value) -> Value {
+    serde_json::to_value(v).unwrap_or(Value::Null)
+}
+
+/// `(key, value)` pairs of a mapping, in document order.
+fn entries(v: Option<&serde_yaml::Value>) -> Vec<(String, Value)> {
+    v.and_then(serde_yaml::Value::as_mapping)
+        .into_iter()
+        .flatten()
+        .filter_map(|(k, v)| Some((k.as_str()?.to_string(), to_json(v))))
+        .collect()
+}
+
+fn detect_format(doc: &Value) -> Option<&'static str> {
+    let crew_agents = doc
+        .get("agents")
+        .filter(|a| a.is_object())
+        .unwrap_or(doc)
+        .as_object()
+        .is_some_and(|m| !m.is_empty() && m.values().all(|a| a.get("role").is_some()));
+    if crew_agents {
+        Some("crewai")
+    } else if doc.get("lc").is_some()
+        || doc.get("agents").is_some_and(Value::is_array)
+        || find_prompt(doc, 0).is_some()
+    {
+        Some("langchain")
+    } else {
+        None
+    }
+}
+
+/// Map a framework model id (LiteLLM-style `provider/model` or a bare name)
+/// onto a model profile. `None` keeps the default engine.
+fn model_profile_for(llm: &str, warnings: &mut Vec<String>) -> Option<String> {
+    let llm = llm.trim();
+    let (prefix, model) = llm.split_once('/').unwrap_or(("", llm));
+    let profile = match prefix {
+        "ollama" | "ollama_chat" => ModelProfile {
+            provider: Some(providers::OLLAMA.into()),
+            model: Some(model.into()),
+            ..Default::default()
+        },
+        "openrouter" => ModelProfile {
+            provider: Some(providers::OPENAI_API.into()),
+            model: Some(model.into()),
+            base_url: Some("https://openrouter.ai/api/v1".into()),
+            ..Default::default()
+        },
+        "openai" | "" if ["gpt-", "o1", "o3", "o4"].iter().any(|p| model.starts_with(p)) => {
+            ModelProfile {
+                provider: Some(providers::OPENAI_API.into()),
+                model: Some(model.into()),
+                ..Default::default()
+            }
+        }
+        "anthropic" | "" if model.starts_with("claude") => return None,
+        _ => {
+            warnings.push(format!("Model '{llm}' has no direct equivalent; using the default engine."));
+            return None;
+        }
+    };
+    serde_json::to_string(&profile).ok()
+}
+
+fn map_tool(name: &str, source: &str) -> N8nToolDraft {
+    let key = name.to_lowercase();
+    let has = |needles: &[&str]| needles.iter().any(|n| key.contains(n));
+    if has(&["fileread", "file_read", "read_file", "directoryread"]) {
+        builtin("file_read", "filesystem", "Read file contents from disk")
+    } else if has(&["filewrite", "file_write", "write_file"]) {
+        builtin("file_write", "filesystem", "Write content to files on disk")
+    } else if has(&[
+        "search", "serper", "tavily", "scrape", "website", "browser", "request", "http",
+        "wikipedia",
+    ]) {
+        builtin("http_request", "web", "Make HTTP requests")
+    } else {
+        N8nToolDraft {
+            name: tool_name(name),
+            category: "custom".into(),
+            description: format!("Imported {source} tool '{name}'"),
+            requires_credential_type: None,
+            input_schema: None,
+            implementation_guide: Some("Provide an implementation before use.".into()),
+        }
+    }
+}
+
+fn tools_from(list: Option<&Value>, source: &str) -> Option<Vec<N8nToolDraft>> {
+    let mut seen = std::collections::HashSet::new();
+    let tools: Vec<N8nToolDraft> = list
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|t| {
+            let name = t.as_str().or_else(|| str_at(t, &["/name"]))?;
+            let mut tool = map_tool(name, source);
+            if tool.category == "custom" {
+                if let Some(d) = str_at(t, &["/description"]) {
+                    tool.description = d.to_string();
+                }
+                tool.input_schema = t.get("args_schema").or_else(|| t.get("parameters")).cloned();
+            }
+            Some(tool)
+        })
+        .filter(|t| !t.name.is_empty() && seen.insert(t.name.clone()))
+        .collect();
+    (!tools.is_empty()).then_some(tools)
+}
+
+fn persona_draft(
+    name: &str,
+    description: Option<String>,
+    system_prompt: String,
+    tools: Option<Vec<N8nToolDraft>>,
+    model_profile: Option<String>,
+) -> N8nPersonaOutput {
+    N8nPersonaOutput {
+        name: Some(name.to_string()),
+        description,
+        system_prompt,
+        structured_prompt: None,
+        icon: Some("Sparkles".into()),
+        color: Some("#8b5cf6".into()),
+        model_profile,
+        max_budget_usd: None,
+        max_turns: None,
+        design_context: None,
+        notification_channels: None,
+        template_category: None,
+        triggers: None,
+        tools,
+        required_connectors: None,
+    }
+}
+
+fn push_edge(edges: &mut Vec<FrameworkConnection>, source_index: usize, target_index: usize) {
+    let edge = FrameworkConnection { source_index, target_index };
+    if source_index != target_index && !edges.contains(&edge) {
+        edges.push(edge);
+    }
+}
+
+fn parse_crewai(
+    doc: &serde_yaml::Value,
+    tasks_doc: Option<&serde_yaml::Value>,
+) -> AgentFrameworkImport {
+    let mut warnings = Vec::new();
+    let agents_src = doc.get("agents").filter(|a| a.is_mapping()).unwrap_or(doc);
+    let agents = entries(Some(agents_src));
+    let tasks = entries(doc.get("tasks").or(tasks_doc));
+    let index: HashMap<&str, usize> =
+        agents.iter().enumerate().map(|(i, (k, _))| (k.as_str(), i)).collect();
+
+    // Tasks per agent, and the task → agent map for `context` edges.
+    let mut agent_tasks: Vec<Vec<String>> = vec![Vec::new(); agents.len()];
+    let mut task_agent: HashMap<&str, usize> = HashMap::new();
+    let mut connections = Vec::new();
+    let mut previous: Option<usize> = None;
+    for (key, task) in &tasks {
+        let Some(&ai) = str_at(task, &["/agent"]).and_then(|a| index.get(a)) else {
+            warnings.push(format!("Task '{key}' names no known agent and was skipped."));
+            continue;
+        };
+        let mut section = format!("### {key}\n{}", str_at(task, &["/description"]).unwrap_or(""));
+        if let Some(out) = str_at(task, &["/expected_output"]) {
+            section.push_str(&format!("\n\nExpected output: {out}"));
+        }
+        agent_tasks[ai].push(section);
+        let context: Vec<usize> = task
+            .get("context")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.as_str().and_then(|c| task_agent.get(c)).copied())
+            .collect();
+        if context.is_empty() {
+            if let Some(prev) = previous {
+                push_edge(&mut connections, prev, ai);
+            }
+        }
+        for source in context {
+            push_edge(&mut connections, source, ai);
+        }
+        task_agent.insert(key, ai);
+        previous = Some(ai);
+    }
+
+    let mut has_orchestrator = false;
+    let drafts = agents
+        .iter()
+        .zip(agent_tasks)
+        .map(|((key, a), tasks)| {
+            let role = str_at(a, &["/role"]).unwrap_or(key);
+            let goal = str_at(a, &["/goal"]);
+            let mut prompt = format!("You are {role}.");
+            if let Some(goal) = goal {
+                prompt.push_str(&format!("\n\n## Goal\n{goal}"));
+            }
+            if let Some(backstory) = str_at(a, &["/backstory"]) {
+                prompt.push_str(&format!("\n\n## Backstory\n{backstory}"));
+            }
+            if !tasks.is_empty() {
+                prompt.push_str(&format!("\n\n## Tasks\n{}", tasks.join("\n\n")));
+            }
+            let delegates = a.get("allow_delegation").and_then(Value::as_bool) == Some(true);
+            let team_role = if delegates && !has_orchestrator {
+                has_orchestrator = true;
+                "orchestrator".to_string()
+            } else {
+                normalize_team_role(role)
+            };
+            let model_profile = str_at(a, &["/llm"]).and_then(|l| model_profile_for(l, &mut warnings));
+            FrameworkAgentDraft {
+                key: key.clone(),
+                team_role,
+                draft: persona_draft(
+                    role,
+                    goal.map(str::to_string),
+                    prompt,
+                    tools_from(a.get("tools"), "CrewAI"),
+                    model_profile,
+                ),
+            }
+        })
+        .collect();
+
+    AgentFrameworkImport {
+        source_format: "crewai".into(),
+        team_name: doc
+            .get("name")
+            .and_then(serde_yaml::Value::as_str)
+            .map(str::to_string),
+        agents: drafts,
+        connections,
+        warnings,
+    }
+}
+
+/// The agent's instructions: an explicit system-message field, or the first
+/// prompt `template` nested under a serialized LangChain object.
+fn find_prompt(v: &Value, depth: usize) -> Option<&str> {
+    if depth > 6 {
+        return None;
+    }
+    if let Some(p) = str_at(v, &["/system_message", "/system_prompt", "/instructions", "/prompt"]) {
+        return Some(p);
+    }
+    if let Some(t) = str_at(v, &["/template"]) {
+        return Some(t);
+    }
+    match v {
+        Value::Object(m) => m.values().find_map(|c| find_prompt(c, depth + 1)),
+        Value::Array(a) => a.iter().find_map(|c| find_prompt(c, depth + 1)),
+        _ => None,
+    }
+}
+
+fn parse_langchain(doc: &Value) -> AgentFrameworkImport {
+    let mut warnings = Vec::new();
+    let agents: Vec<&Value> = match doc.get("agents").and_then(Value::as_array) {
+        Some(list) => list.iter().collect(),
+        None => vec![doc],
+    };
+    let names: Vec<String> = agents
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            str_at(a, &["/name", "/kwargs/name"])
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Agent {}", i + 1))
+        })
+        .collect();
+    let index: HashMap<&str, usize> =
+        names.iter().enumerate().map(|(i, n)| (n.as_str(), i)).collect();
+    let supervisor = str_at(doc, &["/supervisor"]);
+
+    let drafts = agents
+        .iter()
+        .zip(&names)
+        .map(|(a, name)| {
+            let prompt = find_prompt(a, 0).map(str::to_string).unwrap_or_else(|| {
+                warnings.push(format!("Agent '{name}' has no prompt; a placeholder was used."));
+                format!("You are {name}.")
+            });
+            let team_role = if supervisor == Some(name.as_str()) {
+                "orchestrator".to_string()
+            } else {
+                normalize_team_role(str_at(a, &["/role"]).unwrap_or("worker"))
+            };
+            let llm = str_at(
+                a,
+                &["/llm/model_name", "/llm/model", "/llm", "/model", "/kwargs/llm/kwargs/model_name"],
+            );
+            let model_profile = llm.and_then(|l| model_profile_for(l, &mut warnings));
+            FrameworkAgentDraft {
+                key: name.clone(),
+                team_role,
+                draft: persona_draft(
+                    name,
+                    str_at(a, &["/description"]).map(str::to_string),
+                    prompt,
+                    tools_from(a.get("tools").or_else(|| a.pointer("/kwargs/tools")), "LangChain"),
+                    model_profile,
+                ),
+            }
+        })
+        .collect();
+
+    let mut connections = Vec::new();
+    match doc.get("edges").and_then(Value::as_array) {
+        Some(edges) => {
+            for e in edges {
+                let (from, to) = match e {
+                    Value::Array(pair) if pair.len() == 2 => (pair[0].as_str(), pair[1].as_str()),
+                    _ => (str_at(e, &["/source", "/from"]), str_at(e, &["/target", "/to"])),
+                };
+                match (from.and_then(|f| index.get(f)), to.and_then(|t| index.get(t))) {
+                    (Some(&s), Some(&t)) => push_edge(&mut connections, s, t),
+                    _ => warnings.push(format!("Edge {e} references an unknown agent.")),
+                }
+            }
+        }
+        None => {
+            for i in 1..names.len() {
+                push_edge(&mut connections, i - 1, i);
+            }
+        }
+    }
+
+    AgentFrameworkImport {
+        source_format: "langchain".into(),
+        team_name: str_at(doc, &["/team_name", "/graph_name"]).map(str::to_string),
+        agents: drafts,
+        connections,
+        warnings,
+    }
+}
+
+/// Parse a config (and optional CrewAI tasks file). `format` forces
+/// `"crewai"` or `"langchain"`; otherwise it is detected.
+pub fn parse_config(
+    content: &str,
+    tasks_content: Option<&str>,
+    format: Option<&str>,
+) -> Result<AgentFrameworkImport, AppError> {
+    let doc = load(content)?;
+    let json = to_json(&doc);
+    let format = match format.map(str::trim).filter(|f| !f.is_empty()) {
+        Some(f @ ("crewai" | "langchain")) => f,
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "Unknown format '{other}' (expected 'crewai' or 'langchain')"
+            )))
+        }
+        None => detect_format(&json).ok_or_else(|| {
+            AppError::Validation("Not a recognizable CrewAI or LangChain agent config".into())
+        })?,
+    };
+    let import = if format == "crewai" {
+        let tasks = tasks_content.map(load).transpose()?;
+        parse_crewai(&doc, tasks.as_ref())
+    } else {
+        parse_langchain(&json)
+    };
+    if import.agents.is_empty() {
+        return Err(AppError::Validation("Config defines no agents".into()));
+    }
+    Ok(import)
+}
+
+// -- Commands ---------------------------------------------------------------
+
+#[tauri::command]
+pub fn parse_agent_framework_config(
+    state: State<'_, Arc<AppState>>,
+    content: String,
+    tasks_content: Option<String>,
+    format: Option<String>,
+) -> Result<AgentFrameworkImport, AppError> {
+    require_auth_sync(&state)?;
+    parse_config(&content, tasks_content.as_deref(), format.as_deref())
+}
+
+fn commit_import(
+    pool: &DbPool,
+    import: &AgentFrameworkImport,
+    persona_ids: &mut Vec<String>,
+    team_id: &mut Option<String>,
+) -> Result<(), AppError> {
+    for agent in &import.agents {
+        let (response, _) = create_persona_atomically(pool, &agent.draft, None)?;
+        let id = response
+            .pointer("/persona/id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| AppError::Internal("Imported persona has no id".into()))?;
+        persona_ids.push(id.to_string());
+    }
+    if import.agents.len() < 2 {
+        return Ok(());
+    }
+
+    let team = team_repo::create(
+        pool,
+        CreateTeamInput {
+            name: import
+                .team_name
+                .clone()
+                .unwrap_or_else(|| format!("Imported {} crew", import.source_format)),
+            project_id: None,
+            parent_team_id: None,
+            description: Some(format!("Imported from a {} config", import.source_format)),
+            canvas_data: None,
+            team_config: None,
+            icon: None,
+            color: None,
+            enabled: Some(true),
+        },
+    )?;
+    *team_id = Some(team.id.clone());
+
+    let edges: Vec<(usize, usize)> = import
+        .connections
+        .iter()
+        .filter(|c| c.source_index < persona_ids.len() && c.target_index < persona_ids.len())
+        .map(|c| (c.source_index, c.target_index))
+        .collect();
+    let positions = compute_dag_layout(persona_ids.len(), &edges, 180.0, 70.0, 60.0, 100.0);
+    let mut member_ids = Vec::new();
+    for (i, (persona_id, agent)) in persona_ids.iter().zip(&import.agents).enumerate() {
+        let (px, py) = positions.get(i).copied().unwrap_or((0.0, 0.0));
+        let member = team_repo::add_member(
+            pool,
+            &team.id,
+            persona_id,
+            Some(normalize_team_role(&agent.team_role)),
+            Some(px),
+            Some(py),
+            None,
+        )?;
+        member_ids.push(member.id);
+    }
+    for (s, t) in edges {
+        team_repo::create_connection(
+            pool,
+            &team.id,
+            &member_ids[s],
+            &member_ids[t],
+            Some("sequential".into()),
+            None,
+            None,
+        )?;
+    }
+    if let Err(e) = crate::engine::team_handoff::wire_team_handoff(pool, &team.id) {
+        tracing::warn!(team_id = %team.id, error = %e, "agent import: handoff wiring failed (continuing)");
+    }
+    Ok(())
+}
+
+/// Create the reviewed personas (and a team for multi-agent configs). Any
+/// failure deletes what was already created.
+#[tauri::command]
+pub fn confirm_agent_framework_import(
+    state: State<'_, Arc<AppState>>,
+    import_json: String,
+) -> Result<AgentFrameworkImportResult, AppError> {
+    require_auth_sync(&state)?;
+    let import: AgentFrameworkImport = serde_json::from_str(&import_json)
+        .map_err(|e| AppError::Validation(format!("Invalid import JSON: {e}")))?;
+    if import.agents.is_empty() {
+        return Err(AppError::Validation("Import contains no agents".into()));
+    }
+    if let Some(a) = import.agents.iter().find(|a| a.draft.system_prompt.trim().is_empty()) {
+        return Err(AppError::Validation(format!("Agent '{}' has an empty prompt", a.key)));
+    }
+
+    let mut persona_ids = Vec::new();
+    let mut team_id = None;
+    match commit_import(&state.db, &import, &mut persona_ids, &mut team_id) {
+        Ok(()) => Ok(AgentFrameworkImportResult { persona_ids, team_id }),
+        Err(e) => {
+            tracing::warn!(error = %e, personas = persona_ids.len(), "agent import failed; rolling back");
+            for pid in &persona_ids {
+                let _ = persona_repo::delete(&state.db, pid);
+            }
+            if let Some(tid) = team_id {
+                let _ = team_repo::delete(&state.db, &tid);
+            }
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AGENTS: &str = r#"
+researcher:
+  role: Senior Research Analyst
+  goal: Find the latest AI news
+  backstory: You dig deep.
+  tools: [SerperDevTool, ScrapeWebsiteTool, SentimentTool]
+  llm: ollama/llama3.1
+writer:
+  role: Content Editor
+  goal: Write a clear summary
+  backstory: You write well.
+  allow_delegation: false
+"#;
+
+    const TASKS: &str = r#"
+research_task:
+  description: Research {topic}.
+  expected_output: 10 bullet points
+  agent: researcher
+write_task:
+  description: Write the article.
+  expected_output: A markdown article
+  agent: writer
+  context: [research_task]
+"#;
+
+    #[test]
+    fn parses_crewai_agents_and_tasks() {
+        let import = parse_config(AGENTS, Some(TASKS), None).unwrap();
+        assert_eq!(import.source_format, "crewai");
+        let keys: Vec<_> = import.agents.iter().map(|a| a.key.as_str()).collect();
+        assert_eq!(keys, vec!["researcher", "writer"]);
+
+        let researcher = &import.agents[0];
+        assert_eq!(researcher.draft.name.as_deref(), Some("Senior Research Analyst"));
+        assert!(researcher.draft.system_prompt.contains("### research_task\nResearch {topic}."));
+        assert!(researcher.draft.system_prompt.contains("Expected output: 10 bullet points"));
+        let tools: Vec<_> =
+            researcher.draft.tools.iter().flatten().map(|t| t.name.as_str()).collect();
+        assert_eq!(tools, vec!["http_request", "sentimenttool"]);
+        assert!(researcher.draft.model_profile.as_deref().unwrap().contains("\"ollama\""));
+
+        assert_eq!(import.agents[1].team_role, "reviewer");
+        assert_eq!(
+            import.connections,
+            vec![FrameworkConnection { source_index: 0, target_index: 1 }]
+        );
+    }
+
+    #[test]
+    fn parses_langchain_agents_with_edges_and_serialized_prompt() {
+        let doc = r#"{
+            "agents": [
+                { "name": "planner", "role": "coordinator", "system_message": "Plan the work.",
+                  "tools": [{ "name": "calendar_lookup", "description": "Look up events" }],
+                  "llm": { "model_name": "gpt-4o" } },
+                { "lc": 1, "type": "constructor", "kwargs": { "name": "coder",
+                  "prompt": { "lc": 1, "kwargs": { "template": "Write the code." } } } }
+            ],
+            "edges": [["planner", "coder"], ["coder", "ghost"]]
+        }"#;
+        let import = parse_config(doc, None, None).unwrap();
+        assert_eq!(import.source_format, "langchain");
+        assert_eq!(import.agents[0].team_role, "orchestrator");
+        assert_eq!(import.agents[0].draft.tools.as_ref().unwrap()[0].description, "Look up events");
+        assert!(import.agents[0].draft.model_profile.as_deref().unwrap().contains("openai_api"));
+        assert_eq!(import.agents[1].key, "coder");
+        assert_eq!(import.agents[1].draft.system_prompt, "Write the code.");
+        assert_eq!(import.connections.len(), 1);
+        assert_eq!(import.warnings.len(), 1);
+
+        assert!(parse_config("just: text", None, None).is_err());
+        assert!(parse_config(doc, None, Some("autogen")).is_err());
+    }
+}
@@ -5,6 +5,7 @@ use serde_json::json;
 use tauri::{Emitter, State};
 use tokio::io::AsyncBufReadExt;
 
+use crate::db::models::CompiledPromptPreview;
 use crate::db::repos::core::design_conversations as conv_repo;
 use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::resources::{connectors as connector_repo, tools as tool_repo};
@@ -549,3 +550,43 @@ pub fn preview_prompt(
         None,
     ))
 }
+
+/// Like [`preview_prompt`], but compiled for a sample execution input and
+/// returned with every variable the prompt can reference, so the editor can
+/// show `{{...}}` placeholders substituted and flag the ones nothing defines.
+#[tauri::command]
+pub fn preview_compiled_prompt(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    input_data: Option<String>,
+    structured_prompt_json: Option<String>,
+) -> Result<CompiledPromptPreview, AppError> {
+    require_auth_sync(&state)?;
+    let mut persona = persona_repo::get_by_id(&state.db, &persona_id)?;
+    if let Some(ref sp_json) = structured_prompt_json {
+        persona.structured_prompt = Some(sp_json.clone());
+    }
+    let input = input_data
+        .as_deref()
+        .filter(|raw| !raw.trim().is_empty())
+        .map(serde_json::from_str::<serde_json::Value>)
+        .transpose()
+        .map_err(|e| AppError::Validation(format!("Input data is not valid JSON: {e}")))?;
+
+    let tools = tool_repo::get_tools_for_persona(&state.db, &persona_id)?;
+    let compiled = prompt::assemble_prompt(
+        &persona,
+        &tools,
+        input.as_ref(),
+        None,
+        None,
+        None,
+        #[cfg(feature = "desktop")]
+        None,
+    );
+    Ok(CompiledPromptPreview {
+        unresolved: prompt::unresolved_placeholders(&compiled),
+        variables: prompt::resolve_variables(&persona, input.as_ref()),
+        prompt: compiled,
+    })
+}
@@ -0,0 +1,344 @@
+//! Import a persona from an OpenAI GPT / Assistant configuration export.
+//!
+//! Accepts an Assistants API object (`{"object": "assistant", ...}`), a GPT
+//! builder export (`{"gizmo": {...}}`) or a flat `{name, instructions,
+//! capabilities, actions, files}` document, and maps it onto the same
+//! [`N8nPersonaOutput`] draft the n8n importer produces — so the result is
+//! reviewed and committed through `confirm_n8n_persona_draft`. Parsing is
+//! deterministic; no LLM is involved.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::State;
+
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+use super::n8n_transform::types::N8nToolDraft;
+use super::n8n_transform::N8nPersonaOutput;
+
+/// Exports are a few KB; anything this large is not a config file.
+const MAX_EXPORT_BYTES: usize = 2 * 1024 * 1024;
+
+/// Parsed export, ready for the shared draft review step.
+#[derive(Debug, Clone, Serialize)]
+pub struct GptImportPreview {
+    pub draft: N8nPersonaOutput,
+    /// `"assistant"` (Assistants API) or `"gpt"` (GPT builder).
+    pub source_kind: String,
+    /// Knowledge files referenced by the export. Exports carry names or ids
+    /// only, so the UI offers to ingest the originals into a knowledge base
+    /// once the persona is confirmed.
+    pub knowledge_files: Vec<String>,
+    pub conversation_starters: Vec<String>,
+    /// What could not be carried over (image generation, YAML actions, ...).
+    pub warnings: Vec<String>,
+}
+
+#[tauri::command]
+pub fn parse_gpt_export(
+    state: State<'_, Arc<AppState>>,
+    export_json: String,
+) -> Result<GptImportPreview, AppError> {
+    require_auth_sync(&state)?;
+    if export_json.len() > MAX_EXPORT_BYTES {
+        return Err(AppError::Validation(format!(
+            "Export exceeds {} MB",
+            MAX_EXPORT_BYTES / (1024 * 1024)
+        )));
+    }
+    let root: Value = serde_json::from_str(&export_json)
+        .map_err(|e| AppError::Validation(format!("Invalid export JSON: {e}")))?;
+    parse_export(&root)
+}
+
+pub(super) fn str_at<'a>(doc: &'a Value, pointers: &[&str]) -> Option<&'a str> {
+    pointers
+        .iter()
+        .filter_map(|p| doc.pointer(p).and_then(Value::as_str))
+        .map(str::trim)
+        .find(|s| !s.is_empty())
+}
+
+fn strings_at(doc: &Value, pointers: &[&str]) -> Vec<String> {
+    pointers
+        .iter()
+        .filter_map(|p| doc.pointer(p).and_then(Value::as_array))
+        .flatten()
+        .filter_map(|v| match v {
+            Value::String(s) => Some(s.trim().to_string()),
+            // File entries are objects in GPT exports.
+            Value::Object(_) => str_at(v, &["/name", "/filename", "/id"]).map(str::to_string),
+            _ => None,
+        })
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Lowercase snake_case name for `persona_tool_definitions.name`.
+pub(super) fn tool_name(raw: &str) -> String {
+    let name: String = raw
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    name.split('_').filter(|s| !s.is_empty()).collect::<Vec<_>>().join("_")
+}
+
+pub(super) fn builtin(name: &str, category: &str, description: &str) -> N8nToolDraft {
+    N8nToolDraft {
+        name: name.into(),
+        category: category.into(),
+        description: description.into(),
+        requires_credential_type: None,
+        input_schema: None,
+        implementation_guide: None,
+    }
+}
+
+/// Capability names as enabled in `tools[].type` or `capabilities.<name>`.
+#[derive(Default)]
+struct Capabilities {
+    web: bool,
+    code: bool,
+    image: bool,
+    files: bool,
+}
+
+impl Capabilities {
+    fn enable(&mut self, name: &str) -> bool {
+        match name {
+            "browser" | "web_browsing" | "web_search" | "web_search_preview" => self.web = true,
+            "python" | "code_interpreter" => self.code = true,
+            "dalle" | "image_generation" => self.image = true,
+            "file_search" | "retrieval" | "myfiles_browser" => self.files = true,
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// One tool per operation of an OpenAPI action schema (JSON only).
+fn action_tools(action: &Value, warnings: &mut Vec<String>) -> Vec<N8nToolDraft> {
+    let schema = match action.get("schema").unwrap_or(action) {
+        Value::String(raw) => match serde_json::from_str::<Value>(raw) {
+            Ok(v) => v,
+            Err(_) => {
+                warnings.push(
+                    "An action schema is not JSON (YAML is not supported); re-create it as a tool."
+                        .into(),
+                );
+                return Vec::new();
+            }
+        },
+        other => other.clone(),
+    };
+    let server = str_at(&schema, &["/servers/0/url"]).unwrap_or("").trim_end_matches('/');
+    let Some(paths) = schema.get("paths").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut tools = Vec::new();
+    for (path, ops) in paths {
+        let Some(ops) = ops.as_object() else { continue };
+        for (method, op) in ops {
+            if !matches!(method.as_str(), "get" | "post" | "put" | "patch" | "delete") {
+                continue;
+            }
+            let name = tool_name(
+                str_at(op, &["/operationId"]).unwrap_or(&format!("{method}_{path}")),
+            );
+            let http = format!("{} {server}{path}", method.to_uppercase());
+            tools.push(N8nToolDraft {
+                name,
+                category: "api".into(),
+                description: str_at(op, &["/summary", "/description"])
+                    .map(str::to_string)
+                    .unwrap_or_else(|| http.clone()),
+                requires_credential_type: None,
+                input_schema: op.pointer("/requestBody/content/application~1json/schema").cloned(),
+                implementation_guide: Some(format!("Call `{http}` (imported GPT action).")),
+            });
+        }
+    }
+    tools
+}
+
+/// Map an export document onto a persona draft.
+pub fn parse_export(root: &Value) -> Result<GptImportPreview, AppError> {
+    let (source_kind, doc) = if root.get("object").and_then(Value::as_str) == Some("assistant") {
+        ("assistant", root)
+    } else {
+        ("gpt", root.get("gizmo").unwrap_or(root))
+    };
+
+    let instructions = str_at(doc, &["/instructions", "/prompt"]).ok_or_else(|| {
+        AppError::Validation("Export has no instructions to use as the system prompt".into())
+    })?;
+    let mut warnings = Vec::new();
+    let mut caps = Capabilities::default();
+    let mut tools: Vec<N8nToolDraft> = Vec::new();
+
+    for tool in doc.get("tools").and_then(Value::as_array).into_iter().flatten() {
+        let kind = tool.get("type").and_then(Value::as_str).unwrap_or_default();
+        if kind == "function" {
+            let f = tool.get("function").unwrap_or(tool);
+            let Some(name) = str_at(f, &["/name"]) else { continue };
+            tools.push(N8nToolDraft {
+                name: tool_name(name),
+                category: "custom".into(),
+                description: str_at(f, &["/description"]).unwrap_or(name).to_string(),
+                requires_credential_type: None,
+                input_schema: f.get("parameters").cloned(),
+                implementation_guide: Some(
+                    "Imported OpenAI function tool; provide an implementation before use.".into(),
+                ),
+            });
+        } else if !caps.enable(kind) && !kind.is_empty() && kind != "plugins_prototype" {
+            warnings.push(format!("Tool type '{kind}' has no equivalent and was skipped."));
+        }
+    }
+    if let Some(flags) = doc.get("capabilities").and_then(Value::as_object) {
+        for (name, on) in flags {
+            if on.as_bool() == Some(true) {
+                caps.enable(name);
+            }
+        }
+    }
+    for action in doc.get("actions").and_then(Value::as_array).into_iter().flatten() {
+        tools.extend(action_tools(action, &mut warnings));
+    }
+
+    if caps.web {
+        tools.push(builtin("http_request", "web", "Make HTTP requests"));
+    }
+    if caps.code {
+        tools.push(builtin("file_read", "filesystem", "Read file contents from disk"));
+        tools.push(builtin("file_write", "filesystem", "Write content to files on disk"));
+    }
+    if caps.image {
+        warnings.push("Image generation (DALL·E) is not available and was skipped.".into());
+    }
+    let mut seen = std::collections::HashSet::new();
+    tools.retain(|t| !t.name.is_empty() && seen.insert(t.name.clone()));
+
+    let mut knowledge_files = strings_at(
+        doc,
+        &[
+            "/files",
+            "/file_ids",
+            "/tool_resources/code_interpreter/file_ids",
+            "/tool_resources/file_search/vector_store_ids",
+        ],
+    );
+    knowledge_files.dedup();
+    if caps.files && knowledge_files.is_empty() {
+        warnings.push("File search was enabled but the export lists no files.".into());
+    }
+    if let Some(model) = str_at(doc, &["/model"]) {
+        warnings.push(format!(
+            "Originally ran on '{model}'; the persona uses your default engine until a model is chosen."
+        ));
+    }
+
+    let name = str_at(doc, &["/name", "/display/name"]).unwrap_or(match source_kind {
+        "assistant" => "Imported Assistant",
+        _ => "Imported GPT",
+    });
+    let draft = N8nPersonaOutput {
+        name: Some(name.to_string()),
+        description: str_at(doc, &["/description", "/display/description"]).map(str::to_string),
+        system_prompt: instructions.to_string(),
+        structured_prompt: None,
+        icon: Some("Sparkles".into()),
+        color: Some("#10a37f".into()),
+        model_profile: None,
+        max_budget_usd: None,
+        max_turns: None,
+        design_context: None,
+        notification_channels: None,
+        template_category: None,
+        triggers: None,
+        tools: (!tools.is_empty()).then_some(tools),
+        required_connectors: None,
+    };
+
+    Ok(GptImportPreview {
+        draft,
+        source_kind: source_kind.into(),
+        knowledge_files,
+        conversation_starters: strings_at(
+            doc,
+            &["/conversation_starters", "/display/prompt_starters"],
+        ),
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tool_names(p: &GptImportPreview) -> Vec<&str> {
+        p.draft.tools.iter().flatten().map(|t| t.name.as_str()).collect()
+    }
+
+    #[test]
+    fn maps_assistant_object() {
+        let preview = parse_export(&json!({
+            "object": "assistant",
+            "name": "Invoice Helper",
+            "model": "gpt-4o",
+            "instructions": "You reconcile invoices.",
+            "tools": [
+                { "type": "code_interpreter" },
+                { "type": "file_search" },
+                { "type": "function", "function": {
+                    "name": "lookupVendor",
+                    "description": "Find a vendor",
+                    "parameters": { "type": "object", "properties": { "id": { "type": "string" } } }
+                } }
+            ],
+            "tool_resources": { "file_search": { "vector_store_ids": ["vs_1"] } }
+        }))
+        .unwrap();
+        assert_eq!(preview.source_kind, "assistant");
+        assert_eq!(preview.draft.system_prompt, "You reconcile invoices.");
+        assert_eq!(tool_names(&preview), vec!["lookupvendor", "file_read", "file_write"]);
+        assert_eq!(preview.knowledge_files, vec!["vs_1"]);
+        assert!(preview.warnings.iter().any(|w| w.contains("gpt-4o")));
+    }
+
+    #[test]
+    fn maps_gpt_export_with_actions_and_files() {
+        let schema = json!({
+            "servers": [{ "url": "https://api.example.com/" }],
+            "paths": { "/orders/{id}": { "get": { "operationId": "getOrder", "summary": "Fetch an order" } } }
+        });
+        let preview = parse_export(&json!({
+            "gizmo": {
+                "display": { "name": "Shop Bot", "prompt_starters": ["Where is my order?"] },
+                "instructions": "Help shoppers.",
+                "tools": [{ "type": "browser" }, { "type": "dalle" }],
+                "files": [{ "name": "faq.pdf" }],
+                "actions": [{ "schema": schema.to_string() }, { "schema": "openapi: 3.0.0" }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(preview.draft.name.as_deref(), Some("Shop Bot"));
+        assert_eq!(tool_names(&preview), vec!["getorder", "http_request"]);
+        let action = &preview.draft.tools.as_ref().unwrap()[0];
+        assert_eq!(
+            action.implementation_guide.as_deref(),
+            Some("Call `GET https://api.example.com/orders/{id}` (imported GPT action).")
+        );
+        assert_eq!(preview.knowledge_files, vec!["faq.pdf"]);
+        assert_eq!(preview.conversation_starters, vec!["Where is my order?"]);
+        assert_eq!(preview.warnings.len(), 2);
+
+        assert!(parse_export(&json!({ "name": "No prompt" })).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod agent_framework_import;
 pub mod analysis;
 pub mod archetypes;
 pub mod build_sessions;
@@ -5,6 +6,7 @@ pub mod build_simulate;
 pub mod connector_explorer;
 pub mod connector_readiness;
 pub mod conversations;
+pub mod gpt_import;
 pub mod n8n_limits;
 pub mod n8n_sessions;
 pub mod n8n_transform;
@@ -0,0 +1,722 @@
+//! Reverse of the n8n import: a persona's triggers, tools and use-case flows
+//! as an n8n workflow skeleton, so a design can round-trip to a team that is
+//! still on n8n.
+//!
+//! The skeleton imports cleanly into n8n but is not meant to run as-is. HTTP
+//! Request nodes take their method and URL from a tool's `Curl:` guide when it
+//! has one, credentials are named placeholders to bind in n8n, and persona
+//! concepts without an n8n counterpart (chain, event-listener triggers, flow
+//! actions) become placeholder nodes whose notes say what to replace them with.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde_json::{json, Map, Value};
+use tauri::State;
+
+use crate::db::models::{Persona, PersonaToolDefinition, PersonaTrigger, ToolKind, TriggerConfig};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::resources::{tools as tool_repo, triggers as trigger_repo};
+use crate::engine::tool_runner::extract_curl_line;
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+const HTTP_REQUEST: &str = "n8n-nodes-base.httpRequest";
+const NO_OP: &str = "n8n-nodes-base.noOp";
+
+/// Horizontal / vertical spacing of the generated canvas.
+const COLUMN_WIDTH: i64 = 250;
+const ROW_HEIGHT: i64 = 200;
+
+/// Builds the `nodes` + `connections` halves of an n8n workflow, keeping
+/// node names unique (n8n connects nodes by name).
+#[derive(Default)]
+struct WorkflowBuilder {
+    nodes: Vec<Value>,
+    connections: Map<String, Value>,
+    names: HashSet<String>,
+}
+
+impl WorkflowBuilder {
+    fn add(&mut self, name: &str, mut node: Value, position: (i64, i64)) -> String {
+        let mut unique = name.trim().to_string();
+        if unique.is_empty() {
+            unique = "Node".into();
+        }
+        let base = unique.clone();
+        let mut n = 2;
+        while !self.names.insert(unique.clone()) {
+            unique = format!("{base} {n}");
+            n += 1;
+        }
+        node["id"] = json!(uuid::Uuid::new_v4().to_string());
+        node["name"] = json!(unique);
+        node["position"] = json!([position.0, position.1]);
+        self.nodes.push(node);
+        unique
+    }
+
+    /// Connect `from`'s output `output` (0 = main / IF true, 1 = IF false).
+    fn connect(&mut self, from: &str, output: usize, to: &str) {
+        let outputs = self
+            .connections
+            .entry(from.to_string())
+            .or_insert_with(|| json!({ "main": [] }));
+        let Some(main) = outputs["main"].as_array_mut() else {
+            return;
+        };
+        while main.len() <= output {
+            main.push(json!([]));
+        }
+        if let Some(targets) = main[output].as_array_mut() {
+            let target = json!({ "node": to, "type": "main", "index": 0 });
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+}
+
+/// A use-case flow from the persona's last design result.
+struct DesignFlow {
+    name: String,
+    nodes: Vec<Value>,
+    edges: Vec<Value>,
+}
+
+fn design_flows(persona: &Persona) -> Vec<DesignFlow> {
+    let Some(design) = persona
+        .last_design_result
+        .as_deref()
+        .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+    else {
+        return Vec::new();
+    };
+    design["use_case_flows"]
+        .as_array()
+        .map(|flows| {
+            flows
+                .iter()
+                .filter_map(|flow| {
+                    let nodes = flow["nodes"].as_array()?.clone();
+                    (!nodes.is_empty()).then(|| DesignFlow {
+                        name: flow["name"].as_str().unwrap_or("Flow").to_string(),
+                        nodes,
+                        edges: flow["edges"].as_array().cloned().unwrap_or_default(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Build the n8n workflow JSON for a persona.
+pub fn build_n8n_workflow(
+    persona: &Persona,
+    triggers: &[PersonaTrigger],
+    tools: &[PersonaToolDefinition],
+) -> Value {
+    let mut wf = WorkflowBuilder::default();
+
+    if let Some(description) = persona
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        wf.add(
+            "Persona notes",
+            json!({
+                "type": "n8n-nodes-base.stickyNote",
+                "typeVersion": 1,
+                "parameters": {
+                    "content": format!(
+                        "## {}\n{}\n\nExported from Personas. \
+                         Bind the credential placeholders before activating.",
+                        persona.name, description
+                    ),
+                    "width": 440,
+                    "height": 160,
+                },
+            }),
+            (0, 60),
+        );
+    }
+
+    // -- Triggers ------------------------------------------------------------
+    let mut entry_points: Vec<String> = Vec::new();
+    let mut has_poll = false;
+    for (row, trigger) in triggers.iter().filter(|t| t.enabled).enumerate() {
+        let y = 300 + row as i64 * ROW_HEIGHT;
+        let (name, node, poll) = trigger_node(persona, trigger);
+        let name = wf.add(&name, node, (COLUMN_WIDTH, y));
+        match poll {
+            // A polling trigger is a schedule plus the request it polls.
+            Some(poll) => {
+                has_poll = true;
+                let poll = wf.add("Poll URL", poll, (COLUMN_WIDTH * 2, y));
+                wf.connect(&name, 0, &poll);
+                entry_points.push(poll);
+            }
+            None => entry_points.push(name),
+        }
+    }
+    if entry_points.is_empty() {
+        entry_points.push(wf.add(
+            "When clicking 'Test workflow'",
+            json!({
+                "type": "n8n-nodes-base.manualTrigger",
+                "typeVersion": 1,
+                "parameters": {},
+            }),
+            (COLUMN_WIDTH, 300),
+        ));
+    }
+    let first_column = if has_poll { 3 } else { 2 };
+
+    // -- Flows, or a plain tool chain when the persona has none ------------
+    let flows = design_flows(persona);
+    let mut next_row = 0;
+    for flow in &flows {
+        next_row = add_flow(&mut wf, flow, &entry_points, first_column, next_row);
+    }
+
+    let tool_row_y = 300 + next_row as i64 * ROW_HEIGHT;
+    let mut previous: Option<String> = None;
+    for (col, tool) in tools.iter().enumerate() {
+        let position = (
+            COLUMN_WIDTH * (first_column + col as i64),
+            if flows.is_empty() { 300 } else { tool_row_y },
+        );
+        let name = wf.add(&tool.name, tool_node(tool), position);
+        // Without flows, the tools run in sequence after the triggers; with
+        // flows they are laid out below as a toolbox to wire in by hand.
+        if flows.is_empty() {
+            match &previous {
+                Some(prev) => wf.connect(prev, 0, &name),
+                None => {
+                    for entry in &entry_points {
+                        wf.connect(entry, 0, &name);
+                    }
+                }
+            }
+            previous = Some(name);
+        }
+    }
+
+    json!({
+        "name": persona.name,
+        "nodes": wf.nodes,
+        "connections": wf.connections,
+        "active": false,
+        "pinData": {},
+        "settings": { "executionOrder": "v1" },
+        "meta": { "exportedFrom": "personas", "personaId": persona.id },
+    })
+}
+
+/// The n8n trigger node for a persona trigger, plus the HTTP Request node a
+/// polling trigger needs after it.
+fn trigger_node(persona: &Persona, trigger: &PersonaTrigger) -> (String, Value, Option<Value>) {
+    match trigger.parse_config() {
+        TriggerConfig::Schedule {
+            cron,
+            interval_seconds,
+            timezone,
+            ..
+        } => {
+            let rule = match (cron, interval_seconds) {
+                (Some(cron), _) => json!({ "field": "cronExpression", "expression": cron }),
+                (None, Some(secs)) => interval_rule(secs),
+                (None, None) => interval_rule(3600),
+            };
+            let mut node = json!({
+                "type": "n8n-nodes-base.scheduleTrigger",
+                "typeVersion": 1.2,
+                "parameters": { "rule": { "interval": [rule] } },
+            });
+            if let Some(tz) = timezone {
+                node["notes"] = json!(format!(
+                    "Persona schedule timezone: {tz}. Set it under workflow settings."
+                ));
+            }
+            ("Schedule Trigger".into(), node, None)
+        }
+        TriggerConfig::Polling {
+            url,
+            headers,
+            interval_seconds,
+            ..
+        } => {
+            let schedule = json!({
+                "type": "n8n-nodes-base.scheduleTrigger",
+                "typeVersion": 1.2,
+                "parameters": {
+                    "rule": { "interval": [interval_rule(interval_seconds.unwrap_or(300))] }
+                },
+                "notes": "Persona polling trigger: fires when the polled content changes. \
+                          Add a dedupe step after the request.",
+            });
+            let header_params: Vec<Value> = headers
+                .unwrap_or_default()
+                .into_keys()
+                .map(|name| json!({ "name": name, "value": "" }))
+                .collect();
+            let mut poll = json!({
+                "type": HTTP_REQUEST,
+                "typeVersion": 4.2,
+                "parameters": {
+                    "method": "GET",
+                    "url": url.unwrap_or_default(),
+                    "options": {},
+                },
+            });
+            if !header_params.is_empty() {
+                poll["parameters"]["sendHeaders"] = json!(true);
+                poll["parameters"]["headerParameters"] = json!({ "parameters": header_params });
+            }
+            ("Polling Schedule".into(), schedule, Some(poll))
+        }
+        TriggerConfig::Webhook { webhook_secret, .. } => {
+            let mut node = json!({
+                "type": "n8n-nodes-base.webhook",
+                "typeVersion": 2,
+                "parameters": {
+                    "httpMethod": "POST",
+                    "path": slug(&persona.name),
+                    "options": {},
+                },
+            });
+            if webhook_secret.is_some() {
+                node["notes"] =
+                    json!("The persona webhook verifies a signing secret. Add header auth in n8n.");
+            }
+            ("Webhook".into(), node, None)
+        }
+        TriggerConfig::Manual { .. } => (
+            "When clicking 'Test workflow'".into(),
+            json!({
+                "type": "n8n-nodes-base.manualTrigger",
+                "typeVersion": 1,
+                "parameters": {},
+            }),
+            None,
+        ),
+        _ => (
+            format!("{} trigger", trigger.trigger_type),
+            json!({
+                "type": "n8n-nodes-base.manualTrigger",
+                "typeVersion": 1,
+                "parameters": {},
+                "notes": format!(
+                    "Persona '{}' trigger has no n8n equivalent. \
+                     Replace this node with the matching n8n trigger.",
+                    trigger.trigger_type
+                ),
+            }),
+            None,
+        ),
+    }
+}
+
+/// A schedule-trigger interval rule in the largest whole unit.
+fn interval_rule(secs: u64) -> Value {
+    let secs = secs.max(1);
+    if secs % 3600 == 0 {
+        json!({ "field": "hours", "hoursInterval": secs / 3600 })
+    } else if secs % 60 == 0 {
+        json!({ "field": "minutes", "minutesInterval": secs / 60 })
+    } else {
+        json!({ "field": "seconds", "secondsInterval": secs })
+    }
+}
+
+/// An HTTP Request node for an API tool (method + URL from its curl guide),
+/// or a Code node stub for a script tool.
+fn tool_node(tool: &PersonaToolDefinition) -> Value {
+    if tool.tool_kind() == Ok(ToolKind::Script) {
+        return json!({
+            "type": "n8n-nodes-base.code",
+            "typeVersion": 2,
+            "parameters": {
+                "jsCode": format!(
+                    "// Port of the persona script tool `{}`.\nreturn $input.all();",
+                    tool.script_path
+                ),
+            },
+            "notes": tool.description,
+        });
+    }
+    let (method, url) = tool
+        .implementation_guide
+        .as_deref()
+        .and_then(extract_curl_line)
+        .map(curl_method_and_url)
+        .unwrap_or_else(|| ("GET".into(), String::new()));
+    http_request_node(
+        &method,
+        &url,
+        tool.requires_credential_type.as_deref(),
+        &tool.description,
+    )
+}
+
+fn http_request_node(method: &str, url: &str, credential: Option<&str>, notes: &str) -> Value {
+    let mut node = json!({
+        "type": HTTP_REQUEST,
+        "typeVersion": 4.2,
+        "parameters": {
+            "method": method,
+            "url": url,
+            "options": {},
+        },
+    });
+    if !notes.trim().is_empty() {
+        node["notes"] = json!(notes);
+    }
+    if let Some(credential) = credential.filter(|c| !c.trim().is_empty()) {
+        node["parameters"]["authentication"] = json!("genericCredentialType");
+        node["parameters"]["genericAuthType"] = json!("httpHeaderAuth");
+        // n8n matches credentials by id first and falls back to asking the
+        // user, so an empty id with a descriptive name is a clean placeholder.
+        node["credentials"] = json!({
+            "httpHeaderAuth": { "id": "", "name": format!("{credential} (placeholder)") }
+        });
+    }
+    node
+}
+
+/// Method and URL from a curl command line. Defaults to GET, or POST when
+/// the command sends a body without an explicit `-X`.
+fn curl_method_and_url(curl: &str) -> (String, String) {
+    let tokens: Vec<&str> = curl
+        .split_whitespace()
+        .map(|t| t.trim_matches(|c| c == '\'' || c == '"'))
+        .collect();
+    let mut method = None;
+    let mut has_body = false;
+    let mut url = String::new();
+    let mut iter = tokens.iter();
+    while let Some(token) = iter.next() {
+        match *token {
+            "-X" | "--request" => method = iter.next().map(|m| m.to_ascii_uppercase()),
+            "-d" | "--data" | "--data-raw" | "--json" => has_body = true,
+            t if url.is_empty() && (t.starts_with("http://") || t.starts_with("https://")) => {
+                url = t.to_string();
+            }
+            _ => {}
+        }
+    }
+    let method = method.unwrap_or_else(|| if has_body { "POST" } else { "GET" }.into());
+    (method, url)
+}
+
+/// Lay out one use-case flow on its own rows, starting at `row`, and wire it
+/// to the trigger entry points. Returns the next free row.
+fn add_flow(
+    wf: &mut WorkflowBuilder,
+    flow: &DesignFlow,
+    entry_points: &[String],
+    first_column: i64,
+    row: usize,
+) -> usize {
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut starts: HashSet<String> = HashSet::new();
+    let mut ends: HashSet<String> = HashSet::new();
+    let mut column = first_column;
+    let mut rows_used = 1;
+
+    for node in &flow.nodes {
+        let id = node["id"].as_str().unwrap_or_default().to_string();
+        let label = node["label"].as_str().unwrap_or("Step");
+        let detail = node["detail"].as_str().unwrap_or_default();
+        let node_type = node["type"].as_str().unwrap_or("action");
+        let n8n_node = match node_type {
+            "start" => {
+                starts.insert(id);
+                continue;
+            }
+            "end" => {
+                ends.insert(id);
+                continue;
+            }
+            "connector" => {
+                let connector = node["connector"].as_str();
+                let notes = match connector {
+                    Some(c) => format!("{detail}\nConnector: {c}"),
+                    None => detail.to_string(),
+                };
+                http_request_node("GET", "", connector, notes.trim())
+            }
+            "decision" => {
+                let condition = if detail.is_empty() { label } else { detail };
+                json!({
+                    "type": "n8n-nodes-base.if",
+                    "typeVersion": 1,
+                    "parameters": { "conditions": {} },
+                    "notes": format!("Condition to configure: {condition}"),
+                })
+            }
+            other => {
+                let notes = match other {
+                    "error" => node["error_message"].as_str().unwrap_or(detail).to_string(),
+                    _ => detail.to_string(),
+                };
+                json!({ "type": NO_OP, "typeVersion": 1, "parameters": {}, "notes": notes })
+            }
+        };
+        // Error handlers sit one row below their flow.
+        let is_error = node_type == "error";
+        if is_error {
+            rows_used = 2;
+        }
+        let y = 300 + (row as i64 + i64::from(is_error)) * ROW_HEIGHT;
+        let name = wf.add(
+            &format!("{}: {label}", flow.name),
+            n8n_node,
+            (COLUMN_WIDTH * column, y),
+        );
+        column += 1;
+        names.insert(id, name);
+    }
+
+    let mut has_entry = false;
+    for edge in &flow.edges {
+        let source = edge["source"].as_str().unwrap_or_default();
+        let target = edge["target"].as_str().unwrap_or_default();
+        if ends.contains(target) {
+            continue;
+        }
+        let Some(to) = names.get(target) else {
+            continue;
+        };
+        if starts.contains(source) {
+            for entry in entry_points {
+                wf.connect(entry, 0, to);
+            }
+            has_entry = true;
+        } else if let Some(from) = names.get(source) {
+            let output = usize::from(edge["variant"].as_str() == Some("no"));
+            wf.connect(from, output, to);
+        }
+    }
+    // A flow without a start edge still hangs off the triggers, at its first node.
+    if !has_entry {
+        if let Some(first) = flow
+            .nodes
+            .iter()
+            .find_map(|n| names.get(n["id"].as_str().unwrap_or_default()))
+        {
+            for entry in entry_points {
+                wf.connect(entry, 0, first);
+            }
+        }
+    }
+    row + rows_used
+}
+
+fn slug(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+// -- Tauri command --------------------------------------------------------
+
+/// Export a persona as an n8n workflow JSON skeleton (importable via n8n's
+/// "Import from file").
+#[tauri::command]
+pub fn export_persona_to_n8n(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<Value, AppError> {
+    require_auth_sync(&state)?;
+    let persona = persona_repo::get_by_id(&state.db, &persona_id)?;
+    let triggers = trigger_repo::get_by_persona_id(&state.db, &persona_id)?;
+    let tools = tool_repo::get_tools_for_persona(&state.db, &persona_id)?;
+    Ok(build_n8n_workflow(&persona, &triggers, &tools))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::models::{CreatePersonaInput, CreateToolDefinitionInput, CreateTriggerInput};
+
+    fn make_persona(pool: &crate::db::DbPool, name: &str, description: Option<&str>) -> Persona {
+        persona_repo::create(
+            pool,
+            CreatePersonaInput {
+                name: name.into(),
+                system_prompt: "test".into(),
+                project_id: None,
+                description: description.map(str::to_string),
+                structured_prompt: None,
+                icon: None,
+                color: None,
+                enabled: Some(true),
+                max_concurrent: None,
+                timeout_ms: None,
+                model_profile: None,
+                max_budget_usd: None,
+                max_turns: None,
+                design_context: None,
+                notification_channels: None,
+                lifecycle: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn node<'a>(workflow: &'a Value, name: &str) -> &'a Value {
+        workflow["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|n| n["name"] == name)
+            .unwrap_or_else(|| panic!("no node named {name}"))
+    }
+
+    #[test]
+    fn curl_guides_give_method_and_url() {
+        assert_eq!(
+            curl_method_and_url(
+                "curl -s -X patch 'https://api.example.com/v1/items/{id}' -H 'Accept: */*'"
+            ),
+            (
+                "PATCH".into(),
+                "https://api.example.com/v1/items/{id}".into()
+            )
+        );
+        assert_eq!(
+            curl_method_and_url("curl https://hooks.slack.com/x -d '{\"text\":\"hi\"}'"),
+            ("POST".into(), "https://hooks.slack.com/x".into())
+        );
+        assert_eq!(interval_rule(7200)["hoursInterval"], 2);
+        assert_eq!(interval_rule(90)["secondsInterval"], 90);
+    }
+
+    #[test]
+    fn persona_exports_triggers_tools_and_credentials() {
+        let pool = init_test_db().unwrap();
+        let persona = make_persona(&pool, "Release Notes", Some("Posts release notes"));
+        for (trigger_type, config) in [("schedule", r#"{"cron":"0 9 * * 1"}"#), ("webhook", "{}")] {
+            trigger_repo::create(
+                &pool,
+                CreateTriggerInput {
+                    persona_id: persona.id.clone(),
+                    trigger_type: trigger_type.into(),
+                    config: Some(config.into()),
+                    enabled: Some(true),
+                    use_case_id: None,
+                },
+            )
+            .unwrap();
+        }
+        let tool = tool_repo::create_definition(
+            &pool,
+            CreateToolDefinitionInput {
+                name: "slack_post".into(),
+                category: "api".into(),
+                description: "Post to Slack".into(),
+                script_path: String::new(),
+                input_schema: None,
+                output_schema: None,
+                requires_credential_type: Some("slack".into()),
+                implementation_guide: Some(
+                    "Curl: curl -X POST https://slack.com/api/chat.postMessage -d '{}'".into(),
+                ),
+                is_builtin: Some(false),
+            },
+        )
+        .unwrap();
+        tool_repo::assign_tool(&pool, &persona.id, &tool.id, None).unwrap();
+
+        let persona = persona_repo::get_by_id(&pool, &persona.id).unwrap();
+        let triggers = trigger_repo::get_by_persona_id(&pool, &persona.id).unwrap();
+        let tools = tool_repo::get_tools_for_persona(&pool, &persona.id).unwrap();
+        let workflow = build_n8n_workflow(&persona, &triggers, &tools);
+
+        let schedule = node(&workflow, "Schedule Trigger");
+        assert_eq!(
+            schedule["parameters"]["rule"]["interval"][0]["expression"],
+            "0 9 * * 1"
+        );
+        assert_eq!(
+            node(&workflow, "Webhook")["parameters"]["path"],
+            "release-notes"
+        );
+        let slack = node(&workflow, "slack_post");
+        assert_eq!(slack["type"], HTTP_REQUEST);
+        assert_eq!(slack["parameters"]["method"], "POST");
+        assert_eq!(
+            slack["parameters"]["url"],
+            "https://slack.com/api/chat.postMessage"
+        );
+        assert_eq!(
+            slack["credentials"]["httpHeaderAuth"]["name"],
+            "slack (placeholder)"
+        );
+        // Both triggers feed the tool chain.
+        for trigger in ["Schedule Trigger", "Webhook"] {
+            assert_eq!(
+                workflow["connections"][trigger]["main"][0][0]["node"],
+                "slack_post"
+            );
+        }
+    }
+
+    #[test]
+    fn design_flows_become_wired_nodes() {
+        let pool = init_test_db().unwrap();
+        let persona = make_persona(&pool, "Triage", None);
+        let design = json!({ "use_case_flows": [{
+            "name": "Inbox",
+            "nodes": [
+                { "id": "n1", "type": "start", "label": "Mail arrives" },
+                { "id": "n2", "type": "connector", "label": "Read mail", "connector": "gmail" },
+                { "id": "n3", "type": "decision", "label": "Urgent?" },
+                { "id": "n4", "type": "action", "label": "Summarize" },
+                { "id": "n5", "type": "end", "label": "Done" }
+            ],
+            "edges": [
+                { "source": "n1", "target": "n2" },
+                { "source": "n2", "target": "n3" },
+                { "source": "n3", "target": "n4", "variant": "no" },
+                { "source": "n4", "target": "n5" }
+            ]
+        }]});
+        pool.get()
+            .unwrap()
+            .execute(
+                "UPDATE personas SET last_design_result = ?1 WHERE id = ?2",
+                rusqlite::params![design.to_string(), persona.id],
+            )
+            .unwrap();
+        let persona = persona_repo::get_by_id(&pool, &persona.id).unwrap();
+
+        let workflow = build_n8n_workflow(&persona, &[], &[]);
+        let connections = &workflow["connections"];
+        assert_eq!(
+            connections["When clicking 'Test workflow'"]["main"][0][0]["node"],
+            "Inbox: Read mail"
+        );
+        assert_eq!(
+            node(&workflow, "Inbox: Read mail")["credentials"]["httpHeaderAuth"]["name"],
+            "gmail (placeholder)"
+        );
+        assert_eq!(
+            node(&workflow, "Inbox: Urgent?")["type"],
+            "n8n-nodes-base.if"
+        );
+        // The "no" branch leaves the IF node on its second output.
+        assert_eq!(
+            connections["Inbox: Urgent?"]["main"][1][0]["node"],
+            "Inbox: Summarize"
+        );
+        assert!(connections.get("Inbox: Summarize").is_none());
+    }
+}
@@ -1,5 +1,6 @@
 pub mod cli_runner;
 pub mod confirmation;
+pub mod export;
 pub mod job_state;
 mod prompt_sanitizer;
 pub(crate) mod prompts;
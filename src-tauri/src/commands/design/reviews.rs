@@ -1123,6 +1123,22 @@ pub fn update_manual_review_status(
         // held, an APPROVAL resumes the blocked assignment. Shared with the
         // Athena path so resolution reacts identically regardless of who acted.
         react_to_review_decision(&state, &app, &review);
+
+        // Approval gate: an approved tool-approval review continues the run it
+        // paused, with that tool allowed. Other reviews are a no-op.
+        if review.status == crate::db::models::ManualReviewStatus::Approved {
+            let engine = state.engine.clone();
+            let pool = state.db.clone();
+            let app = app.clone();
+            let review = review.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) =
+                    crate::engine::tool_approval::resume_approved(&engine, app, pool, &review).await
+                {
+                    tracing::warn!(review_id = %review.id, error = %e, "tool approval: resume failed");
+                }
+            });
+        }
     }
 
     Ok(review)
@@ -204,7 +204,7 @@ fn build_design_context_from_result(design: &serde_json::Value, template_name: &
 /// handoff wiring (UAT L2 finding). The prompt now requests the four valid
 /// tokens; this is the defensive net for when the model deviates anyway. Maps
 /// common synonyms; defaults to `worker`.
-fn normalize_team_role(role: &str) -> String {
+pub(super) fn normalize_team_role(role: &str) -> String {
     let r = role.trim().to_lowercase();
     if ["orchestrator", "worker", "reviewer", "router"].contains(&r.as_str()) {
         return r;
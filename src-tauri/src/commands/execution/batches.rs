@@ -0,0 +1,344 @@
+//! Batch execution: run a persona once per item of a dataset.
+//!
+//! `start_batch_execution` persists the items, then a driver task claims them
+//! in order and starts each through the same path as a manual run
+//! (`execute_persona_inner`), keeping at most `concurrency` of the batch in
+//! flight. The engine queue still applies its own global and per-persona
+//! limits on top. An item that fails to start or whose run fails is recorded
+//! and the batch moves on; progress goes out as `batch-execution-progress`
+//! after every item, and the consolidated results can be exported as JSON or
+//! CSV.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tauri::{AppHandle, State};
+use tauri_plugin_dialog::DialogExt;
+
+use crate::db::models::{ExecutionBatch, ExecutionBatchItem, ExecutionBatchProgress};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::execution::batches as repo;
+use crate::db::settings_keys::MAX_PARALLEL_EXECUTIONS_MAX;
+use crate::engine::event_registry::{emit_event, event_name};
+use crate::engine::execution_wait;
+use crate::engine::types::ExecutionState;
+use crate::error::AppError;
+use crate::ipc_auth::{require_auth, require_auth_sync};
+use crate::AppState;
+use personas_macros::requires;
+
+use super::executions::execute_persona_inner;
+
+/// Largest dataset a single batch accepts.
+const MAX_BATCH_ITEMS: usize = 5_000;
+/// Concurrency used when the caller does not pass one.
+const DEFAULT_BATCH_CONCURRENCY: u32 = 2;
+
+/// Start one execution of `persona_id` per entry of `items` (each becomes the
+/// run's input data), at most `concurrency` at a time. Returns the batch
+/// immediately; follow it via `batch-execution-progress` or
+/// `get_batch_execution_progress`.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn start_batch_execution(
+    state: State<'_, Arc<AppState>>,
+    app: AppHandle,
+    persona_id: String,
+    items: Vec<serde_json::Value>,
+    concurrency: Option<u32>,
+    use_case_id: Option<String>,
+) -> Result<ExecutionBatch, AppError> {
+    if items.is_empty() {
+        return Err(AppError::Validation(
+            "A batch needs at least one item".into(),
+        ));
+    }
+    if items.len() > MAX_BATCH_ITEMS {
+        return Err(AppError::Validation(format!(
+            "A batch is limited to {MAX_BATCH_ITEMS} items, got {}",
+            items.len()
+        )));
+    }
+    // Fail fast on an unknown persona rather than on every item.
+    persona_repo::get_by_id(&state.db, &persona_id)?;
+
+    let concurrency = concurrency
+        .unwrap_or(DEFAULT_BATCH_CONCURRENCY)
+        .clamp(1, MAX_PARALLEL_EXECUTIONS_MAX as u32);
+    let inputs: Vec<Option<String>> = items
+        .into_iter()
+        .map(|item| match item {
+            serde_json::Value::Null => None,
+            // Plain strings pass through as-is so they reach the prompt as
+            // `user_input`, exactly like a manual run's text input.
+            serde_json::Value::String(s) => Some(s),
+            other => Some(other.to_string()),
+        })
+        .collect();
+    let batch = repo::create(
+        &state.db,
+        &persona_id,
+        use_case_id.as_deref(),
+        concurrency as i32,
+        &inputs,
+    )?;
+    tracing::info!(
+        batch_id = %batch.id,
+        persona_id = %persona_id,
+        items = batch.total_items,
+        concurrency,
+        "Batch execution started"
+    );
+
+    let state = state.inner().clone();
+    let driven = batch.clone();
+    tauri::async_runtime::spawn(async move {
+        drive_batch(state, app, driven).await;
+    });
+    Ok(batch)
+}
+
+/// Claim and run items until none are left (or the batch is cancelled), with
+/// at most `batch.concurrency` in flight.
+async fn drive_batch(state: Arc<AppState>, app: AppHandle, batch: ExecutionBatch) {
+    let slots = Arc::new(tokio::sync::Semaphore::new(
+        batch.concurrency.max(1) as usize
+    ));
+    let mut in_flight = tokio::task::JoinSet::new();
+    loop {
+        let Ok(permit) = slots.clone().acquire_owned().await else {
+            break;
+        };
+        let item = match repo::claim_next(&state.db, &batch.id) {
+            Ok(Some(item)) => item,
+            Ok(None) => break,
+            Err(e) => {
+                tracing::warn!(batch_id = %batch.id, "Batch driver: failed to claim next item: {e}");
+                break;
+            }
+        };
+        let (state, app, batch) = (state.clone(), app.clone(), batch.clone());
+        in_flight.spawn(async move {
+            run_item(&state, &app, &batch, item).await;
+            emit_progress(&state, &app, &batch.id);
+            drop(permit);
+        });
+    }
+    while in_flight.join_next().await.is_some() {}
+
+    if let Err(e) = repo::complete(&state.db, &batch.id) {
+        tracing::warn!(batch_id = %batch.id, "Failed to mark batch completed: {e}");
+    }
+    emit_progress(&state, &app, &batch.id);
+    tracing::info!(batch_id = %batch.id, "Batch execution finished");
+}
+
+/// Start one item's execution and wait for it to settle, recording the
+/// outcome on the item. Never fails the batch.
+async fn run_item(
+    state: &Arc<AppState>,
+    app: &AppHandle,
+    batch: &ExecutionBatch,
+    item: ExecutionBatchItem,
+) {
+    let started = execute_persona_inner(
+        state,
+        app.clone(),
+        batch.persona_id.clone(),
+        None,
+        item.input_data.clone(),
+        batch.use_case_id.clone(),
+        None,
+        // Keyed per item so a retried start can never double-run it.
+        Some(format!("batch:{}:{}", batch.id, item.item_index)),
+        /* is_simulation */ false,
+    )
+    .await;
+    let execution = match started {
+        Ok(execution) => execution,
+        Err(e) => {
+            record_item(state, batch, &item, "failed", Some(&e.to_string()));
+            return;
+        }
+    };
+    if let Err(e) = repo::set_item_execution(&state.db, &batch.id, item.item_index, &execution.id) {
+        tracing::warn!(batch_id = %batch.id, "Failed to link batch item to its execution: {e}");
+    }
+
+    let finished = loop {
+        match execution_wait::wait_for_terminal(
+            &state.db,
+            &execution.id,
+            Duration::from_secs(execution_wait::MAX_WAIT_SECS),
+        )
+        .await
+        {
+            Ok(wait) if wait.completed => break wait.execution,
+            Ok(_) => continue,
+            Err(e) => {
+                record_item(state, batch, &item, "failed", Some(&e.to_string()));
+                return;
+            }
+        }
+    };
+    let status = match finished.state() {
+        ExecutionState::Completed => "completed",
+        ExecutionState::Cancelled => "cancelled",
+        _ => "failed",
+    };
+    record_item(state, batch, &item, status, None);
+}
+
+fn record_item(
+    state: &Arc<AppState>,
+    batch: &ExecutionBatch,
+    item: &ExecutionBatchItem,
+    status: &str,
+    error: Option<&str>,
+) {
+    if let Err(e) = repo::finish_item(&state.db, &batch.id, item.item_index, status, error) {
+        tracing::warn!(
+            batch_id = %batch.id,
+            item_index = item.item_index,
+            "Failed to record batch item outcome: {e}"
+        );
+    }
+}
+
+fn emit_progress(state: &Arc<AppState>, app: &AppHandle, batch_id: &str) {
+    match repo::progress(&state.db, batch_id) {
+        Ok(progress) => emit_event(app, event_name::BATCH_EXECUTION_PROGRESS, &progress),
+        Err(e) => tracing::warn!(batch_id, "Failed to compute batch progress: {e}"),
+    }
+}
+
+#[tauri::command]
+pub fn list_batch_executions(
+    state: State<'_, Arc<AppState>>,
+    persona_id: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<ExecutionBatch>, AppError> {
+    require_auth_sync(&state)?;
+    repo::list(
+        &state.db,
+        persona_id.as_deref(),
+        limit.unwrap_or(50).clamp(1, 500),
+    )
+}
+
+#[tauri::command]
+pub fn get_batch_execution_progress(
+    state: State<'_, Arc<AppState>>,
+    batch_id: String,
+) -> Result<ExecutionBatchProgress, AppError> {
+    require_auth_sync(&state)?;
+    repo::progress(&state.db, &batch_id)
+}
+
+#[tauri::command]
+pub fn list_batch_execution_items(
+    state: State<'_, Arc<AppState>>,
+    batch_id: String,
+) -> Result<Vec<ExecutionBatchItem>, AppError> {
+    require_auth_sync(&state)?;
+    repo::list_items(&state.db, &batch_id)
+}
+
+/// Stop a batch: pending items are cancelled and in-flight executions are
+/// cancelled through the engine. Finished items keep their results.
+#[tauri::command]
+pub async fn cancel_batch_execution(
+    state: State<'_, Arc<AppState>>,
+    app: AppHandle,
+    batch_id: String,
+) -> Result<ExecutionBatchProgress, AppError> {
+    require_auth(&state).await?;
+    let batch = repo::get_by_id(&state.db, &batch_id)?;
+    for execution_id in repo::cancel(&state.db, &batch_id)? {
+        state
+            .engine
+            .cancel_execution(&execution_id, &state.db, Some(&batch.persona_id))
+            .await;
+    }
+    emit_progress(&state, &app, &batch_id);
+    repo::progress(&state.db, &batch_id)
+}
+
+/// Save the batch's per-item results (input, status, output, error, cost,
+/// duration) to a file the user picks. `format` is `json` (default) or
+/// `csv`. Returns `false` if the dialog was dismissed.
+#[tauri::command]
+pub async fn export_batch_results(
+    state: State<'_, Arc<AppState>>,
+    app: AppHandle,
+    batch_id: String,
+    format: Option<String>,
+) -> Result<bool, AppError> {
+    require_auth(&state).await?;
+    let rows = repo::results(&state.db, &batch_id)?;
+    let (content, extension, filter_name) = match format.as_deref().unwrap_or("json") {
+        "json" => (
+            serde_json::to_string_pretty(&rows).map_err(|e| AppError::Internal(e.to_string()))?,
+            "json",
+            "JSON",
+        ),
+        "csv" => (results_csv(&rows), "csv", "CSV"),
+        other => {
+            return Err(AppError::Validation(format!(
+                "Unsupported export format '{other}' (expected json or csv)"
+            )))
+        }
+    };
+
+    let file_name = format!("batch-{batch_id}.{extension}");
+    let save_path = tokio::task::spawn_blocking(move || {
+        app.dialog()
+            .file()
+            .set_file_name(&file_name)
+            .add_filter(filter_name, &[extension])
+            .blocking_save_file()
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Dialog task failed: {e}")))?;
+
+    let Some(file_path) = save_path else {
+        return Ok(false);
+    };
+    let path = file_path
+        .into_path()
+        .map_err(|e| AppError::Internal(format!("Invalid file path: {e}")))?;
+    tokio::fs::write(&path, content)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to write file: {e}")))?;
+    Ok(true)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn results_csv(rows: &[crate::db::models::ExecutionBatchResult]) -> String {
+    let mut out = String::from(
+        "item_index,status,input_data,output_data,error,cost_usd,duration_ms,execution_id\n",
+    );
+    for row in rows {
+        let fields = [
+            row.item_index.to_string(),
+            row.status.clone(),
+            row.input_data.clone().unwrap_or_default(),
+            row.output_data.clone().unwrap_or_default(),
+            row.error.clone().unwrap_or_default(),
+            row.cost_usd.map(|c| c.to_string()).unwrap_or_default(),
+            row.duration_ms.map(|d| d.to_string()).unwrap_or_default(),
+            row.execution_id.clone().unwrap_or_default(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
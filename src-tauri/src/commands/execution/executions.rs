@@ -2,18 +2,24 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::db::models::{
-    ExecutionCounts, ExecutionListItem, ExecutionSearchResult, GlobalExecutionRow, PersonaExecution,
+    ExecutionCounts, ExecutionListItem, ExecutionSearchResult, FiringExecutions, FiringRollup,
+    GlobalExecutionRow, PersonaExecution,
 };
 use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::execution::executions as repo;
+use crate::db::repos::execution::shared_context::{
+    self as shared_context_repo, SharedContextEntry,
+};
 use crate::db::repos::resources::automations as automation_repo;
 use crate::db::repos::resources::{tools as tool_repo, triggers as trigger_repo};
 use crate::engine::automation_runner::automation_to_virtual_tool;
+use crate::engine::execution_wait::{self, ExecutionWaitResult};
 use crate::engine::failover::CircuitBreakerStatus;
+use crate::engine::log_archive;
 use crate::engine::scheduler as sched_logic;
+use crate::engine::shared_context;
 use crate::error::AppError;
 use crate::ipc_auth::{require_auth, require_auth_sync};
-use crate::validation::open_log_file_safely;
 use crate::AppState;
 use personas_macros::requires;
 
@@ -138,6 +144,22 @@ pub async fn execute_persona(
     continuation: Option<crate::engine::types::Continuation>,
     idempotency_key: Option<String>,
 ) -> Result<PersonaExecution, AppError> {
+    // Cloud/local placement (`engine::execution_placement`). Only fresh runs
+    // are routable: session continuations, idempotent retries and capability
+    // runs (use-case expansion happens below) stay on the local engine.
+    if continuation.is_none() && idempotency_key.is_none() && use_case_id.is_none() {
+        if let Some(execution) = try_cloud_placement(
+            &state,
+            app.clone(),
+            &persona_id,
+            trigger_id.clone(),
+            input_data.clone(),
+        )
+        .await?
+        {
+            return Ok(execution);
+        }
+    }
     execute_persona_inner(
         &state,
         app,
@@ -152,6 +174,93 @@ pub async fn execute_persona(
     .await
 }
 
+/// Submit the run to the cloud orchestrator when the persona's placement
+/// policy picks it. `None` means run locally — also when submission fails,
+/// since every policy treats the cloud as a preference, not a requirement.
+async fn try_cloud_placement(
+    state: &Arc<AppState>,
+    app: tauri::AppHandle,
+    persona_id: &str,
+    trigger_id: Option<String>,
+    input_data: Option<String>,
+) -> Result<Option<PersonaExecution>, AppError> {
+    use crate::commands::infrastructure::cloud;
+    use crate::engine::execution_placement::{self, PlacementTarget};
+
+    let Some(client) = cloud::routable_cloud_client(state).await else {
+        return Ok(None);
+    };
+    let persona = persona_repo::get_by_id(&state.db, persona_id)?;
+    if execution_placement::choose(&state.engine, &persona, true).await != PlacementTarget::Cloud {
+        return Ok(None);
+    }
+    // Same budget gate as a local run; the cloud spends the same money.
+    crate::engine::optimizer::check_cost_admission(&state.db, &persona, None)?;
+
+    match cloud::submit_cloud_execution(
+        state,
+        app,
+        client,
+        persona_id.to_string(),
+        trigger_id,
+        input_data,
+    )
+    .await
+    {
+        Ok(execution_id) => {
+            tracing::info!(
+                persona_id = %persona_id,
+                execution_id = %execution_id,
+                placement = persona.execution_placement.as_deref().unwrap_or_default(),
+                "Execution routed to cloud orchestrator",
+            );
+            repo::get_by_id(&state.db, &execution_id).map(Some)
+        }
+        Err(e) => {
+            tracing::warn!(
+                persona_id = %persona_id,
+                error = %e,
+                "Cloud placement failed; running locally",
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Start a persona execution through the normal queue and wait for it to
+/// finish, returning the final row (output, cost, tokens, duration) in one
+/// call. `timeout_secs` defaults to 120 and is capped at 600; on timeout the
+/// run keeps going and the last-seen row comes back with `completed: false`.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn execute_persona_and_wait(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+    persona_id: String,
+    input_data: Option<String>,
+    use_case_id: Option<String>,
+    timeout_secs: Option<i64>,
+) -> Result<ExecutionWaitResult, AppError> {
+    let started = execute_persona_inner(
+        &state,
+        app,
+        persona_id,
+        None,
+        input_data,
+        use_case_id,
+        None,
+        None,
+        /* is_simulation */ false,
+    )
+    .await?;
+    let wait = execution_wait::effective_wait(
+        timeout_secs,
+        execution_wait::DEFAULT_WAIT_SECS,
+        execution_wait::MAX_WAIT_SECS,
+    );
+    execution_wait::wait_for_terminal(&state.db, &started.id, wait).await
+}
+
 /// Shared implementation for `execute_persona` and `simulate_use_case`.
 ///
 /// Phase C3 — `is_simulation=true` flags the execution row so the dispatcher
@@ -314,19 +423,17 @@ pub(crate) async fn execute_persona_inner(
         }
     }
 
-    // 2. Check budget limit (concurrency is handled by the engine's queue)
-    if let Some(budget) = persona.max_budget_usd {
-        if budget > 0.0 {
-            let monthly_spend =
-                crate::db::repos::execution::executions::get_monthly_spend(&state.db, &persona_id)?;
-            if monthly_spend >= budget {
-                pipeline.fail_stage("budget limit exceeded");
-                return Err(AppError::Validation(format!(
-                    "Budget limit exceeded for '{}': ${:.2} spent this month, limit is ${:.2}",
-                    persona.name, monthly_spend, budget
-                )));
-            }
-        }
+    // 2. Check budget limit (concurrency is handled by the engine's queue).
+    //    The gate counts the predicted cost of THIS run from the persona's
+    //    execution_knowledge history, so a run that would carry spend past the
+    //    cap is refused up front rather than flagged after the money is gone.
+    if let Err(e) = crate::engine::optimizer::check_cost_admission(
+        &state.db,
+        &persona,
+        use_case_id.as_deref(),
+    ) {
+        pipeline.fail_stage("budget limit exceeded");
+        return Err(e);
     }
 
     // 3. Parse model from profile
@@ -544,6 +651,20 @@ pub fn list_executions_by_trigger(
     repo::get_by_trigger_id(&state.db, &trigger_id, limit)
 }
 
+/// Every execution one trigger firing fanned out to, with roll-up status and cost.
+#[tauri::command]
+pub fn list_executions_by_firing(
+    state: State<'_, Arc<AppState>>,
+    firing_id: String,
+) -> Result<FiringExecutions, AppError> {
+    require_auth_sync(&state)?;
+    let executions = repo::list_by_firing(&state.db, &firing_id)?;
+    Ok(FiringExecutions {
+        rollup: FiringRollup::from_executions(&firing_id, &executions),
+        executions,
+    })
+}
+
 #[tauri::command]
 pub fn list_executions_for_use_case(
     state: State<'_, Arc<AppState>>,
@@ -583,6 +704,34 @@ pub async fn cancel_execution(
     Ok(())
 }
 
+/// One-click fix from the queue-age monitor: raise a persona's
+/// `max_concurrent` by one and promote its queued executions into the new slot.
+#[tauri::command]
+pub async fn bump_persona_concurrency(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<crate::db::models::Persona, AppError> {
+    require_auth(&state).await?;
+    let persona = persona_repo::get_by_id(&state.db, &persona_id)?;
+    let next = persona.max_concurrent.max(0) + 1;
+    // Validated in the repo against MAX_CONCURRENT_MAX.
+    let updated = persona_repo::update(
+        &state.db,
+        &persona_id,
+        crate::db::models::UpdatePersonaInput {
+            max_concurrent: Some(next),
+            ..Default::default()
+        },
+    )?;
+    state
+        .engine
+        .apply_persona_max_concurrent(app, state.db.clone(), &persona_id, next)
+        .await;
+    tracing::info!(persona_id = %persona_id, max_concurrent = next, "Persona concurrency bumped");
+    Ok(updated)
+}
+
 #[tauri::command]
 pub fn get_execution_log(
     state: State<'_, Arc<AppState>>,
@@ -593,10 +742,10 @@ pub fn get_execution_log(
     let execution = repo::get_by_id(&state.db, &id)?;
     verify_execution_owner(&execution, &caller_persona_id)?;
     if let Some(ref path) = execution.log_file_path {
-        let mut file = open_log_file_safely(path, state.engine.log_dir())?;
+        let mut reader = log_archive::open(path, state.engine.log_dir())?;
         let mut content = String::new();
         use std::io::Read;
-        match file.read_to_string(&mut content) {
+        match reader.read_to_string(&mut content) {
             Ok(_) => Ok(Some(content)),
             Err(_) => Ok(None),
         }
@@ -605,6 +754,33 @@ pub fn get_execution_log(
     }
 }
 
+/// Read one page of an execution log: up to `limit` bytes (default 64 KiB,
+/// at most 1 MiB) from byte `offset`, ending on a line boundary. Works the
+/// same on live, rotated and compressed logs; page with `next_offset` until
+/// `eof`.
+#[tauri::command]
+pub fn get_execution_log_range(
+    state: State<'_, Arc<AppState>>,
+    execution_id: String,
+    caller_persona_id: String,
+    offset: Option<u64>,
+    limit: Option<u64>,
+) -> Result<Option<log_archive::ExecutionLogChunk>, AppError> {
+    require_auth_sync(&state)?;
+    let execution = repo::get_by_id(&state.db, &execution_id)?;
+    verify_execution_owner(&execution, &caller_persona_id)?;
+    let Some(ref path) = execution.log_file_path else {
+        return Ok(None);
+    };
+    let mut reader = log_archive::open(path, state.engine.log_dir())?;
+    let chunk = log_archive::read_range(
+        &mut reader,
+        offset.unwrap_or(0),
+        limit.unwrap_or(log_archive::DEFAULT_RANGE_BYTES),
+    )?;
+    Ok(Some(chunk))
+}
+
 /// Get parsed display lines from the execution log for session recovery (replay after refresh).
 ///
 /// Supports pagination via optional `offset` and `limit` parameters to avoid
@@ -622,11 +798,10 @@ pub fn get_execution_log_lines(
     let execution = repo::get_by_id(&state.db, &id)?;
     verify_execution_owner(&execution, &caller_persona_id)?;
     if let Some(ref path) = execution.log_file_path {
-        let file = match open_log_file_safely(path, state.engine.log_dir()) {
-            Ok(f) => f,
+        let reader = match log_archive::open(path, state.engine.log_dir()) {
+            Ok(r) => r,
             Err(_) => return Ok(vec![]),
         };
-        let reader = std::io::BufReader::new(file);
         use std::io::BufRead;
 
         let max_lines = limit.unwrap_or(500);
@@ -681,6 +856,21 @@ pub fn get_execution_trace(
     crate::db::repos::execution::traces::get_by_execution_id(&state.db, &execution_id)
 }
 
+/// Everything recorded about an execution and its healing retries — status
+/// changes, tool steps, trace spans and stream lines — as one ordered
+/// timeline for step-by-step replay.
+#[tauri::command]
+pub fn get_execution_timeline(
+    state: State<'_, Arc<AppState>>,
+    execution_id: String,
+    caller_persona_id: String,
+) -> Result<crate::engine::execution_timeline::ExecutionTimeline, AppError> {
+    require_auth_sync(&state)?;
+    let execution = repo::get_by_id(&state.db, &execution_id)?;
+    verify_execution_owner(&execution, &caller_persona_id)?;
+    crate::engine::execution_timeline::load(&state.db, state.engine.log_dir(), &execution_id)
+}
+
 /// Build a deterministic dream replay session from stored trace spans.
 ///
 /// Reconstructs frame-by-frame execution state without consuming LLM tokens.
@@ -755,6 +945,40 @@ pub fn get_chain_stop_reasons(
     )
 }
 
+/// Read the shared context blackboard of a pipeline run or chain trace
+/// (`scope_type` = `pipeline` | `chain`). With `key`, returns at most that one
+/// entry; without it, every entry in write order.
+#[tauri::command]
+pub fn get_shared_context(
+    state: State<'_, Arc<AppState>>,
+    scope_type: String,
+    scope_id: String,
+    key: Option<String>,
+) -> Result<Vec<SharedContextEntry>, AppError> {
+    require_auth_sync(&state)?;
+    let scope = shared_context::parse_scope(&scope_type, &scope_id)?;
+    let Some(key) = key else {
+        return shared_context_repo::list(&state.db, scope.scope_type, &scope.scope_id);
+    };
+    let entry = shared_context_repo::get(&state.db, scope.scope_type, &scope.scope_id, key.trim())?;
+    Ok(entry.into_iter().collect())
+}
+
+/// Write one key of a shared context blackboard by hand — e.g. to seed a
+/// pipeline run or correct an upstream artifact before a retry.
+#[tauri::command]
+pub fn set_shared_context(
+    state: State<'_, Arc<AppState>>,
+    scope_type: String,
+    scope_id: String,
+    key: String,
+    value: serde_json::Value,
+) -> Result<SharedContextEntry, AppError> {
+    require_auth_sync(&state)?;
+    let scope = shared_context::parse_scope(&scope_type, &scope_id)?;
+    shared_context::write(&state.db, &scope, &key, &value, None, None)
+}
+
 /// List the chains that currently have in-flight (running/queued) executions —
 /// the answer to "what chains are running right now?", which CascadeMetrics
 /// (log-only) and the retrospective per-run Chain tab could not give. Grouped by
@@ -787,6 +1011,18 @@ pub fn get_circuit_breaker_status(
     Ok(state.engine.circuit_breaker.get_status())
 }
 
+/// Warm-pool counters (hits, misses, idle sessions, estimated saved start
+/// latency) since app start. See `engine::warm_pool`.
+#[tauri::command]
+pub fn get_warm_pool_stats(
+    state: State<'_, Arc<AppState>>,
+) -> Result<crate::engine::warm_pool::WarmPoolSnapshot, AppError> {
+    require_auth_sync(&state)?;
+    Ok(crate::engine::warm_pool::snapshot(
+        crate::engine::warm_pool::pool_size(&state.db),
+    ))
+}
+
 /// Validate a persona end-to-end without spawning the engine subprocess.
 ///
 /// Mirrors the runner's `Validate` stage — credential resolution, capability
@@ -805,6 +1041,20 @@ pub async fn dry_run_persona(
     crate::engine::dry_run::dry_run_persona(&state, &persona_id, input_data, use_case_id).await
 }
 
+/// Predict the cost of one run from the persona's execution history and
+/// project it onto the monthly budget — the same estimate the admission gate
+/// in [`execute_persona_inner`] uses to refuse runs that would overshoot.
+#[tauri::command]
+pub fn estimate_execution_cost(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    use_case_id: Option<String>,
+) -> Result<crate::engine::optimizer::CostEstimate, AppError> {
+    require_auth_sync(&state)?;
+    let persona = persona_repo::get_by_id(&state.db, &persona_id)?;
+    crate::engine::optimizer::estimate_execution_cost(&state.db, &persona, use_case_id.as_deref())
+}
+
 /// Preview an execution without running it: assembles the prompt, estimates
 /// token count and cost, and returns the preview for user inspection.
 #[tauri::command]
@@ -854,6 +1104,8 @@ pub fn preview_execution(
         crate::db::repos::execution::executions::get_monthly_spend(&state.db, &persona_id)
             .unwrap_or(0.0);
     let budget_limit = persona.max_budget_usd.unwrap_or(0.0);
+    let cost_factor =
+        crate::engine::cost_reconciliation::correction_factor(&state.db, Some(&model));
 
     Ok(crate::engine::cost::build_preview(
         &prompt_text,
@@ -862,9 +1114,75 @@ pub fn preview_execution(
         tools.len() as u32,
         monthly_spend,
         budget_limit,
+        cost_factor,
     ))
 }
 
+/// Token budget of the prompt a run with `input_data` would get: per-section
+/// token counts against the persona's model context window, with the
+/// sections a run would drop when the prompt does not fit.
+#[tauri::command]
+pub fn get_prompt_budget(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    input_data: Option<String>,
+) -> Result<crate::engine::prompt::PromptBudget, AppError> {
+    require_auth_sync(&state)?;
+    use crate::db::repos::core::memories as mem_repo;
+    use crate::engine::{memory_recall, prompt};
+
+    let persona = persona_repo::get_by_id(&state.db, &persona_id)?;
+    let tools = tool_repo::get_tools_for_persona(&state.db, &persona_id)?;
+    let input_json: Option<serde_json::Value> = input_data
+        .as_deref()
+        .filter(|s| !s.trim().is_empty())
+        .map(serde_json::from_str)
+        .transpose()
+        .map_err(|e| AppError::Validation(format!("Input data is not valid JSON: {e}")))?;
+
+    let mut prompt_text = prompt::assemble_prompt(
+        &persona,
+        &tools,
+        input_json.as_ref(),
+        None,
+        None,
+        None,
+        #[cfg(feature = "desktop")]
+        None,
+    );
+
+    // Memories as a run would pack them (value-only; ml builds may pick
+    // different memories of about the same size).
+    let tiered = mem_repo::get_for_injection_v2(
+        &state.db,
+        mem_repo::InjectionScope::for_persona(&persona.id)
+            .with_home_team(persona.home_team_id.as_deref()),
+        10,
+        120,
+    )?;
+    if !tiered.core.is_empty() || !tiered.active.is_empty() {
+        let packed = memory_recall::pack_by_budget(
+            tiered.active,
+            memory_recall::ACTIVE_MEMORY_BUDGET_CHARS,
+            chrono::Utc::now(),
+        );
+        prompt_text.push_str(&memory_recall::render_prompt_section(&tiered.core, &packed));
+    }
+    if let Some(block) =
+        crate::engine::shared_context::prompt_block(&state.db, &persona.id, input_json.as_ref())
+    {
+        prompt_text.push_str(&block);
+    }
+    if let Some(contract) = crate::engine::output_contract::load(&state.db, &persona.id) {
+        prompt_text.push_str(&contract.prompt_block());
+    }
+
+    let model = prompt::parse_model_profile(persona.model_profile.as_deref())
+        .and_then(|mp| mp.model)
+        .unwrap_or_else(|| prompt::DEFAULT_CAPABILITY_MODEL.to_string());
+    Ok(prompt::prompt_budget(&prompt_text, &model))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Advisory context builder — enriches chat input with diagnostic data from DB
 // ═══════════════════════════════════════════════════════════════════════════════
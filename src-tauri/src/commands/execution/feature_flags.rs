@@ -0,0 +1,65 @@
+//! Experimental engine flags. See `engine::feature_flags`.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::db::models::EngineFlagStats;
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::settings_keys;
+use crate::engine::feature_flags::{self, EngineFlagInfo};
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+#[tauri::command]
+pub fn list_engine_flags(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<EngineFlagInfo>, AppError> {
+    require_auth_sync(&state)?;
+    Ok(feature_flags::all_flag_info())
+}
+
+#[tauri::command]
+pub fn get_persona_engine_flags(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+) -> Result<Vec<String>, AppError> {
+    require_auth_sync(&state)?;
+    Ok(feature_flags::persona_flags(&state.db, &persona_id)
+        .into_iter()
+        .map(|f| f.as_str().to_string())
+        .collect())
+}
+
+/// Replace the persona's flag set. An empty list clears the setting.
+#[tauri::command]
+pub fn set_persona_engine_flags(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    flags: Vec<String>,
+) -> Result<Vec<String>, AppError> {
+    require_auth_sync(&state)?;
+    let mut parsed = feature_flags::parse_list(&flags.join(",")).map_err(AppError::Validation)?;
+    parsed.sort();
+    parsed.dedup();
+    let names: Vec<String> = parsed.iter().map(|f| f.as_str().to_string()).collect();
+    let key = format!("{}{persona_id}", settings_keys::ENGINE_FLAGS_PREFIX);
+    if names.is_empty() {
+        settings::delete(&state.db, &key)?;
+    } else {
+        settings::set(&state.db, &key, &names.join(","))?;
+    }
+    Ok(names)
+}
+
+/// Run outcomes per flag, optionally scoped to one persona.
+#[tauri::command]
+pub fn get_engine_flag_stats(
+    state: State<'_, Arc<AppState>>,
+    persona_id: Option<String>,
+) -> Result<Vec<EngineFlagStats>, AppError> {
+    require_auth_sync(&state)?;
+    exec_repo::feature_flag_stats(&state.db, persona_id.as_deref())
+}
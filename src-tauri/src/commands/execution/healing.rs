@@ -2,12 +2,14 @@ use std::sync::Arc;
 use tauri::State;
 
 use crate::db::models::{
-    HealingAuditEntry, HealingKnowledge, HealingTimelineEvent, PersonaExecution,
-    PersonaHealingIssue,
+    FleetHealingRecommendation, FleetPatternSyncResult, HealingAuditEntry, HealingKnowledge,
+    HealingKnowledgeExport, HealingKnowledgeImportResult, HealingKnowledgeRecord,
+    HealingTimelineEvent, PersonaExecution, PersonaHealingIssue, UpdateHealingKnowledgeInput,
 };
 use crate::db::repos::execution::executions as exec_repo;
 use crate::db::repos::execution::healing as repo;
 use crate::engine::healing;
+use crate::engine::healing_root_cause;
 use crate::engine::healing_timeline;
 use crate::error::AppError;
 use crate::ipc_auth::{require_auth, require_auth_sync};
@@ -35,6 +37,20 @@ pub fn get_healing_issue(
     Ok(issue)
 }
 
+/// Generate (or regenerate) the root-cause summary of an execution-failure
+/// issue on demand, regardless of the persona's opt-in.
+#[tauri::command]
+pub async fn generate_healing_root_cause(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+    caller_persona_id: String,
+) -> Result<PersonaHealingIssue, AppError> {
+    require_auth(&state).await?;
+    let issue = repo::get_by_id(&state.db, &id)?;
+    healing_timeline::verify_healing_owner(&issue, &caller_persona_id)?;
+    healing_root_cause::analyze_issue(&state.db, state.engine.log_dir(), &id).await
+}
+
 #[tauri::command]
 pub fn update_healing_status(
     state: State<'_, Arc<AppState>>,
@@ -106,6 +122,126 @@ pub fn list_healing_knowledge(
     }
 }
 
+/// Search the knowledge base by service, pattern or description text.
+#[tauri::command]
+pub fn search_healing_knowledge(
+    state: State<'_, Arc<AppState>>,
+    query: String,
+    service_type: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<HealingKnowledge>, AppError> {
+    require_auth_sync(&state)?;
+    repo::search_knowledge(
+        &state.db,
+        &query,
+        service_type.as_deref(),
+        limit.unwrap_or(100),
+    )
+}
+
+/// Correct a learned entry (description and/or recommended delay). Edited
+/// entries are curated: later learning won't overwrite them.
+#[tauri::command]
+pub fn update_healing_knowledge(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+    input: UpdateHealingKnowledgeInput,
+) -> Result<HealingKnowledge, AppError> {
+    require_auth_sync(&state)?;
+    repo::update_knowledge(&state.db, &id, input)
+}
+
+#[tauri::command]
+pub fn delete_healing_knowledge(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    repo::delete_knowledge(&state.db, &id)
+}
+
+/// Export the knowledge base (or one service's entries) as a JSON document
+/// that `import_healing_knowledge` accepts on another install.
+#[tauri::command]
+pub fn export_healing_knowledge(
+    state: State<'_, Arc<AppState>>,
+    service_type: Option<String>,
+) -> Result<String, AppError> {
+    require_auth_sync(&state)?;
+    let export = repo::export_knowledge(&state.db, service_type.as_deref())?;
+    Ok(serde_json::to_string_pretty(&export)?)
+}
+
+/// Merge a JSON knowledge document into the knowledge base. Accepts an
+/// `export_healing_knowledge` document or a bare array of entries (handy
+/// for seeding known provider quirks by hand).
+#[tauri::command]
+pub fn import_healing_knowledge(
+    state: State<'_, Arc<AppState>>,
+    json: String,
+) -> Result<HealingKnowledgeImportResult, AppError> {
+    require_auth_sync(&state)?;
+    let records = parse_knowledge_import(&json)?;
+    let result = repo::import_knowledge(&state.db, &records)?;
+    tracing::info!(
+        created = result.created,
+        updated = result.updated,
+        "Healing knowledge imported"
+    );
+    Ok(result)
+}
+
+fn parse_knowledge_import(json: &str) -> Result<Vec<HealingKnowledgeRecord>, AppError> {
+    let invalid =
+        |e: serde_json::Error| AppError::Validation(format!("Invalid healing knowledge JSON: {e}"));
+    if json.trim_start().starts_with('[') {
+        return serde_json::from_str(json).map_err(invalid);
+    }
+    let doc: HealingKnowledgeExport = serde_json::from_str(json).map_err(invalid)?;
+    if doc.version > repo::KNOWLEDGE_EXPORT_VERSION {
+        return Err(AppError::Validation(format!(
+            "Unsupported healing knowledge format version {}",
+            doc.version
+        )));
+    }
+    Ok(doc.entries)
+}
+
+#[tauri::command]
+pub fn list_fleet_healing_recommendations(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<FleetHealingRecommendation>, AppError> {
+    require_auth_sync(&state)?;
+    repo::list_fleet_recommendations(&state.db)
+}
+
+/// Toggle anonymous fleet pattern sharing. Opting out also drops the cached
+/// fleet recommendations so healing immediately reverts to local-only knowledge.
+#[tauri::command]
+pub fn set_fleet_pattern_sharing(
+    state: State<'_, Arc<AppState>>,
+    enabled: bool,
+) -> Result<(), AppError> {
+    require_auth_sync(&state)?;
+    crate::db::repos::core::settings::set(
+        &state.db,
+        crate::db::settings_keys::FLEET_PATTERN_SHARING_ENABLED,
+        if enabled { "true" } else { "false" },
+    )?;
+    if !enabled {
+        repo::clear_fleet_recommendations(&state.db)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn sync_fleet_patterns_now(
+    state: State<'_, Arc<AppState>>,
+) -> Result<FleetPatternSyncResult, AppError> {
+    require_auth(&state).await?;
+    crate::engine::fleet_learning::sync_patterns(&state.db).await
+}
+
 #[tauri::command]
 pub async fn trigger_ai_healing(
     state: State<'_, Arc<AppState>>,
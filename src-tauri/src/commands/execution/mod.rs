@@ -3,10 +3,12 @@ pub mod ambient;
 pub mod annotations;
 pub mod assertions;
 pub mod audit_incidents;
+pub mod batches;
 #[cfg(all(feature = "desktop", feature = "ml"))]
 pub mod clipboard_intel;
 pub mod evolution;
 pub mod executions;
+pub mod feature_flags;
 pub mod genome;
 pub mod healing;
 pub mod knowledge;
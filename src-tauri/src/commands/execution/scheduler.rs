@@ -7,6 +7,7 @@ use crate::db::models::{CreatePersonaEventInput, TriggerConfig};
 use crate::db::repos::communication::events as event_repo;
 use crate::db::repos::resources::triggers as trigger_repo;
 use crate::engine::background::{self, SchedulerStats, SubscriptionHealth};
+use crate::engine::job_registry::BackgroundJobStatus;
 use crate::engine::{cron, scheduler as sched_logic};
 use crate::error::AppError;
 use crate::ipc_auth::{require_auth, require_auth_sync};
@@ -24,8 +25,14 @@ pub async fn start_scheduler(
     app: tauri::AppHandle,
 ) -> Result<SchedulerStats, AppError> {
     require_auth(&state).await?;
+    Ok(start_scheduler_loops(&state, app))
+}
+
+/// Start the background loops unless they are already running. Shared with
+/// the cloud remote-command channel's `resume_scheduler` action.
+pub(crate) fn start_scheduler_loops(state: &AppState, app: tauri::AppHandle) -> SchedulerStats {
     if state.scheduler.is_running() {
-        return Ok(state.scheduler.stats());
+        return state.scheduler.stats();
     }
 
     background::start_loops(
@@ -46,7 +53,7 @@ pub async fn start_scheduler(
         state.smee_relay_notifier.clone(),
     );
 
-    Ok(state.scheduler.stats())
+    state.scheduler.stats()
 }
 
 #[tauri::command]
@@ -65,6 +72,42 @@ pub fn get_subscription_health(
     Ok(state.scheduler.subscription_health())
 }
 
+/// Jobs dashboard: every background loop with its last/next run, duration,
+/// last error and pause state.
+#[tauri::command]
+pub fn list_background_jobs(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<BackgroundJobStatus>, AppError> {
+    require_auth_sync(&state)?;
+    Ok(state.scheduler.jobs().list())
+}
+
+/// Run a background job now, outside its schedule. Works on paused jobs too.
+#[tauri::command]
+pub fn run_background_job_now(
+    state: State<'_, Arc<AppState>>,
+    name: String,
+) -> Result<(), AppError> {
+    require_auth_sync(&state)?;
+    if !state.scheduler.is_running() {
+        return Err(AppError::Validation(
+            "The scheduler is stopped; start it to run background jobs".into(),
+        ));
+    }
+    state.scheduler.jobs().run_now(&name)
+}
+
+/// Pause or resume a background job's scheduled runs. Pauses reset on restart.
+#[tauri::command]
+pub fn set_background_job_paused(
+    state: State<'_, Arc<AppState>>,
+    name: String,
+    paused: bool,
+) -> Result<BackgroundJobStatus, AppError> {
+    require_auth_sync(&state)?;
+    state.scheduler.jobs().set_paused(&name, paused)
+}
+
 /// Direction 1 (missed-runs visibility): list every schedule trigger that has
 /// scheduled slots discarded while the app was offline. The schedule UI renders
 /// a "missed N while offline" badge with one-click backfill from this.
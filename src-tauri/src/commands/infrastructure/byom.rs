@@ -9,7 +9,7 @@
 //!   it's treated as sensitive activity data and gated behind the privileged
 //!   tier.
 //! - `require_auth_sync` (also) — `test_provider_connection`, since probing
-//!   binary availability is non-sensitive.
+//!   binary availability is non-sensitive, and `get_provider_capabilities`.
 
 use serde::Serialize;
 use std::sync::Arc;
@@ -18,6 +18,9 @@ use ts_rs::TS;
 
 use crate::db::repos::execution::provider_audit;
 use crate::engine::byom::{ByomPolicy, PolicyWarning, ProviderAuditEntry};
+use crate::engine::provider::capabilities::{
+    self, Backend, BackendCapabilities, CompatibilityReport,
+};
 use crate::engine::provider::{resolve_provider, EngineKind};
 use crate::error::AppError;
 use crate::ipc_auth::require_auth_sync;
@@ -187,3 +190,40 @@ pub fn test_provider_connection(
         error: Some(error_msg.into()),
     })
 }
+
+// =============================================================================
+// Provider Capabilities
+// =============================================================================
+
+/// Capability matrix for every backend, plus the compatibility report for a
+/// persona when one is given.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ProviderCapabilities {
+    pub backends: Vec<BackendCapabilities>,
+    pub persona: Option<CompatibilityReport>,
+}
+
+/// Return the provider capability matrix. With `persona_id`, also report
+/// which features that persona relies on are missing on its backend.
+#[tauri::command]
+pub fn get_provider_capabilities(
+    state: State<'_, Arc<AppState>>,
+    persona_id: Option<String>,
+) -> Result<ProviderCapabilities, AppError> {
+    require_auth_sync(&state)?;
+
+    let backends = Backend::ALL.iter().map(|b| b.capabilities()).collect();
+    let persona = match persona_id {
+        Some(id) => {
+            let persona = crate::db::repos::core::personas::get_by_id(&state.db, &id)?;
+            let has_tools =
+                !crate::db::repos::resources::tools::get_tools_for_persona(&state.db, &id)?
+                    .is_empty();
+            let backend = Backend::for_persona(&state.db, &persona);
+            Some(capabilities::check(&state.db, &persona, has_tools, backend))
+        }
+        None => None,
+    };
+    Ok(ProviderCapabilities { backends, persona })
+}
@@ -1,9 +1,11 @@
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
 use crate::engine::background::ZombieExecutionEvent;
 use crate::engine::event_registry::event_name;
+use crate::engine::types::ExecutionStatusEvent;
 use serde::Serialize;
 use tauri::{Emitter, State};
 use ts_rs::TS;
@@ -116,6 +118,7 @@ async fn get_cloud_client(state: &AppState) -> Result<Arc<CloudClient>, AppError
 #[requires(cloud)]
 pub async fn cloud_connect(
     state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
     url: String,
     api_key: String,
 ) -> Result<u64, AppError> {
@@ -170,7 +173,8 @@ pub async fn cloud_connect(
             .await;
     }
 
-    *state.cloud_client.lock().await = Some(client);
+    *state.cloud_client.lock().await = Some(client.clone());
+    spawn_reconciliation(&state, app, client);
 
     tracing::info!(url = %normalized, latency_ms, "Connected to cloud orchestrator");
     Ok(latency_ms)
@@ -184,6 +188,7 @@ pub async fn cloud_connect(
 #[requires(cloud)]
 pub async fn cloud_reconnect_from_keyring(
     state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
 ) -> Result<u64, AppError> {
     // Already connected -- nothing to do
     if state.cloud_client.lock().await.is_some() {
@@ -230,7 +235,8 @@ pub async fn cloud_reconnect_from_keyring(
             .await;
     }
 
-    *state.cloud_client.lock().await = Some(client);
+    *state.cloud_client.lock().await = Some(client.clone());
+    spawn_reconciliation(&state, app, client);
 
     tracing::info!(url = %url, latency_ms, "Auto-reconnected to cloud orchestrator from keyring");
     Ok(latency_ms)
@@ -264,6 +270,55 @@ pub async fn cloud_disconnect(state: State<'_, Arc<AppState>>) -> Result<(), App
     Ok(())
 }
 
+/// Backfill cloud executions that finished while nothing was polling them
+/// (see [`cloud::reconcile`]). Executions tracked by a live poller are left
+/// alone.
+async fn reconcile_cloud_executions(
+    state: &AppState,
+    app: &tauri::AppHandle,
+    client: &CloudClient,
+) -> Result<cloud::reconcile::CloudReconcileReport, AppError> {
+    let live: HashSet<String> = state.cloud_exec_ids.lock().await.keys().cloned().collect();
+    let report =
+        cloud::reconcile::reconcile_executions(&state.db, client, state.engine.log_dir(), &live)
+            .await?;
+    for r in &report.reconciled {
+        let _ = app.emit(
+            event_name::EXECUTION_STATUS,
+            ExecutionStatusEvent {
+                execution_id: r.execution_id.clone(),
+                status: r.status,
+                error: r.error.clone(),
+                duration_ms: r.duration_ms.map(|ms| ms as u64),
+                cost_usd: r.cost_usd,
+            },
+        );
+    }
+    Ok(report)
+}
+
+/// Run [`reconcile_cloud_executions`] in the background after a (re)connect.
+fn spawn_reconciliation(state: &Arc<AppState>, app: tauri::AppHandle, client: Arc<CloudClient>) {
+    let state = Arc::clone(state);
+    tokio::spawn(async move {
+        if let Err(e) = reconcile_cloud_executions(&state, &app, &client).await {
+            tracing::warn!(error = %e, "Cloud execution reconciliation failed");
+        }
+    });
+}
+
+/// Fetch the results of cloud executions that finished while the app was
+/// closed or disconnected, and write them over the local rows.
+#[tauri::command]
+#[requires(cloud)]
+pub async fn cloud_reconcile_executions(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+) -> Result<cloud::reconcile::CloudReconcileReport, AppError> {
+    let client = get_cloud_client(&state).await?;
+    reconcile_cloud_executions(&state, &app, &client).await
+}
+
 /// Return the current cloud connection configuration, if any.
 #[tauri::command]
 #[requires(cloud)]
@@ -554,7 +609,29 @@ pub async fn cloud_execute_persona(
     input_data: Option<String>,
 ) -> Result<String, AppError> {
     let client = get_cloud_client(&state).await?;
+    submit_cloud_execution(&state, app, client, persona_id, None, input_data).await
+}
 
+/// The orchestrator client when cloud routing is possible: connected, and
+/// the user holds a cloud session (what `#[requires(cloud)]` checks).
+pub(crate) async fn routable_cloud_client(state: &AppState) -> Option<Arc<CloudClient>> {
+    if state.auth.read().await.access_token.is_none() {
+        return None;
+    }
+    state.cloud_client.lock().await.clone()
+}
+
+/// Create the local execution row, submit the run to the orchestrator and
+/// track it to completion. Returns the local execution id. Shared by
+/// `cloud_execute_persona` and placement routing in `execute_persona`.
+pub(crate) async fn submit_cloud_execution(
+    state: &Arc<AppState>,
+    app: tauri::AppHandle,
+    client: Arc<CloudClient>,
+    persona_id: String,
+    trigger_id: Option<String>,
+    input_data: Option<String>,
+) -> Result<String, AppError> {
     let persona = personas::get_by_id(&state.db, &persona_id)?;
     let tools = tools::get_tools_for_persona(&state.db, &persona_id)?;
 
@@ -577,7 +654,14 @@ pub async fn cloud_execute_persona(
         None,
     );
 
-    let exec = executions::create(&state.db, &persona_id, None, input_data.clone(), None, None)?;
+    let exec = executions::create(
+        &state.db,
+        &persona_id,
+        trigger_id,
+        input_data.clone(),
+        None,
+        None,
+    )?;
 
     let timeout_ms = if persona.timeout_ms > 0 {
         persona.timeout_ms as u64
@@ -621,6 +705,17 @@ pub async fn cloud_execute_persona(
         .await
         .insert(exec.id.clone(), cloud_resp.execution_id.clone());
 
+    // Record the orchestrator id on the row and mark it running, so restart
+    // recovery and reconciliation know where to fetch its result from.
+    if let Err(e) = executions::mark_cloud_submitted(&state.db, &exec.id, &cloud_resp.execution_id)
+    {
+        tracing::warn!(
+            execution_id = %exec.id,
+            error = %e,
+            "Failed to record cloud execution id",
+        );
+    }
+
     let cancelled = Arc::new(AtomicBool::new(false));
 
     let exec_id = exec.id.clone();
@@ -654,6 +749,8 @@ pub async fn cloud_execute_persona(
                 error_message: result.error,
                 duration_ms: Some(result.duration_ms as i64),
                 cost_usd: result.cost_usd,
+                input_tokens: result.input_tokens.map(|t| t as i64),
+                output_tokens: result.output_tokens.map(|t| t as i64),
                 ..Default::default()
             };
 
@@ -20,7 +20,7 @@ use crate::db::models::{
 };
 use crate::db::repos::dev_tools as repo;
 use crate::error::AppError;
-use crate::ipc_auth::{require_auth, require_auth_sync};
+use crate::ipc_auth::{require_auth, require_auth_sync, require_privileged_sync};
 use crate::AppState;
 
 // ============================================================================
@@ -249,6 +249,29 @@ pub fn dev_tools_delete_project(
     repo::delete_project(&state.db, &id)
 }
 
+/// Revoke the per-project data key of an archived project. Event payloads
+/// written under the key become permanently unreadable; other projects are
+/// untouched. A project that no longer exists can always be revoked. Returns
+/// the number of event payloads sealed.
+#[tauri::command]
+pub fn dev_tools_revoke_project_data_key(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<i64, AppError> {
+    require_privileged_sync(&state, "dev_tools_revoke_project_data_key")?;
+    match repo::get_project_by_id(&state.db, &id) {
+        Ok(project) if project.status != "archived" => {
+            return Err(AppError::Validation(format!(
+                "Archive project '{}' before revoking its data key",
+                project.name
+            )))
+        }
+        Ok(_) | Err(AppError::NotFound(_)) => {}
+        Err(e) => return Err(e),
+    }
+    crate::engine::project_keys::revoke(&state.db, &id)
+}
+
 // ============================================================================
 // Active Project (in-memory session state)
 // ============================================================================
@@ -21,6 +21,7 @@ pub mod kpi_sim;
 pub mod use_case_scan;
 pub mod llm_spend;
 pub mod incremental_scan;
+pub mod ollama_models;
 pub mod qwen_engine;
 pub mod research_lab;
 pub mod scraper;
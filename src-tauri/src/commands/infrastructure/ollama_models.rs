@@ -0,0 +1,98 @@
+//! Local Ollama model management for offline personas.
+//!
+//! Lists and pulls models on the configured Ollama server (`ollama_base_url`
+//! setting, default `http://localhost:11434`) and points a persona's
+//! `model_profile` at one, which routes its runs to `engine::provider::ollama`.
+
+use std::sync::Arc;
+
+use tauri::{Emitter, State};
+
+use crate::db::models::{Persona, UpdatePersonaInput};
+use crate::db::repos::core::{personas as persona_repo, settings as settings_repo};
+use crate::db::settings_keys;
+use crate::engine::event_registry::event_name;
+use crate::engine::provider::ollama::{self, OllamaModel};
+use crate::engine::types::{providers, ModelProfile};
+use crate::error::AppError;
+use crate::ipc_auth::require_auth;
+use crate::AppState;
+
+/// Effective server URL (explicit override → setting → default) and key.
+fn server(state: &AppState, base_url: Option<String>) -> Result<(String, Option<String>), AppError> {
+    let base_url = match base_url.filter(|s| !s.trim().is_empty()) {
+        Some(url) => Some(url),
+        None => settings_repo::get(&state.db, settings_keys::OLLAMA_BASE_URL)?,
+    };
+    let api_key = settings_repo::get(&state.db, settings_keys::OLLAMA_API_KEY)?
+        .filter(|k| !k.is_empty());
+    Ok((ollama::normalize_base_url(base_url.as_deref()), api_key))
+}
+
+/// Models installed on the Ollama server.
+#[tauri::command]
+pub async fn list_ollama_models(
+    state: State<'_, Arc<AppState>>,
+    base_url: Option<String>,
+) -> Result<Vec<OllamaModel>, AppError> {
+    require_auth(&state).await?;
+    let (base_url, api_key) = server(&state, base_url)?;
+    ollama::list_models(&base_url, api_key.as_deref()).await
+}
+
+/// Pull `model`, emitting `ollama-pull-progress` events until it completes.
+#[tauri::command]
+pub async fn pull_ollama_model(
+    app: tauri::AppHandle,
+    state: State<'_, Arc<AppState>>,
+    model: String,
+    base_url: Option<String>,
+) -> Result<(), AppError> {
+    require_auth(&state).await?;
+    let model = model.trim();
+    if model.is_empty() {
+        return Err(AppError::Validation("Model name must not be empty".into()));
+    }
+    let (base_url, api_key) = server(&state, base_url)?;
+    ollama::pull_model(&base_url, api_key.as_deref(), model, |progress| {
+        let _ = app.emit(event_name::OLLAMA_PULL_PROGRESS, progress);
+    })
+    .await
+}
+
+/// Run `persona_id` on the installed Ollama `model`, or clear its model
+/// profile (back to the default engine) when `model` is `None`.
+#[tauri::command]
+pub async fn set_persona_ollama_model(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    model: Option<String>,
+) -> Result<Persona, AppError> {
+    require_auth(&state).await?;
+    let model_profile = match model.map(|m| m.trim().to_string()).filter(|m| !m.is_empty()) {
+        None => None,
+        Some(model) => {
+            let (base_url, api_key) = server(&state, None)?;
+            let installed = ollama::list_models(&base_url, api_key.as_deref()).await?;
+            if !installed.iter().any(|m| m.name == model) {
+                return Err(AppError::Validation(format!(
+                    "Model '{model}' is not installed on {base_url}; pull it first"
+                )));
+            }
+            let profile = ModelProfile {
+                model: Some(model),
+                provider: Some(providers::OLLAMA.to_string()),
+                ..Default::default()
+            };
+            Some(serde_json::to_string(&profile)?)
+        }
+    };
+    persona_repo::update(
+        &state.db,
+        &persona_id,
+        UpdatePersonaInput {
+            model_profile: Some(model_profile),
+            ..Default::default()
+        },
+    )
+}
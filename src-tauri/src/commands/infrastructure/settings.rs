@@ -6,6 +6,8 @@ use crate::db::models::SettingsAuditEntry;
 use crate::db::repos::core::settings as repo;
 use crate::db::repos::resources::settings_audit_log;
 use crate::db::settings_keys;
+use crate::engine::model_registry::{self, ModelDeprecation, ModelProfileMigration};
+use crate::engine::observer_mode::{self, ObserverModeConfig};
 use crate::engine::quality_gate::{self, QualityGateConfig};
 use crate::error::AppError;
 use crate::ipc_auth::require_auth_sync;
@@ -88,6 +90,100 @@ pub fn set_model_routing_rules(
     )
 }
 
+/// Effective model deprecation registry (operator, fetched, then builtin).
+#[tauri::command]
+pub fn get_model_deprecations(
+    state: State<'_, Arc<AppState>>,
+) -> Result<Vec<ModelDeprecation>, AppError> {
+    require_auth_sync(&state)?;
+    Ok(model_registry::load(&state.db))
+}
+
+/// Fetch the published deprecation list and store it as the remote layer.
+/// Returns the number of entries fetched; a failed fetch keeps the old list.
+#[tauri::command]
+pub async fn refresh_model_deprecations(
+    state: State<'_, Arc<AppState>>,
+) -> Result<usize, AppError> {
+    require_auth_sync(&state)?;
+    let entries = model_registry::fetch_remote()
+        .await
+        .map_err(AppError::External)?;
+    let json = serde_json::to_string(&entries).map_err(|e| AppError::Internal(e.to_string()))?;
+    repo::set(&state.db, settings_keys::MODEL_DEPRECATIONS_REMOTE, &json)?;
+    Ok(entries.len())
+}
+
+/// Rewrite every persona whose stored model profile names a deprecated model
+/// (retired or not) to its replacement. With `dry_run`, only report.
+#[tauri::command]
+pub async fn migrate_model_profiles(
+    state: State<'_, Arc<AppState>>,
+    dry_run: Option<bool>,
+) -> Result<Vec<ModelProfileMigration>, AppError> {
+    require_auth_sync(&state)?;
+    let entries = model_registry::load(&state.db);
+    let mut migrated = Vec::new();
+    for persona in crate::db::repos::core::personas::get_all(&state.db)? {
+        let Some(json) = persona.model_profile.as_deref() else {
+            continue;
+        };
+        let Some((new_json, from, to)) = model_registry::migrate_profile_json(&entries, json)
+        else {
+            continue;
+        };
+        if !dry_run.unwrap_or(false) {
+            crate::db::repos::core::personas::update(
+                &state.db,
+                &persona.id,
+                crate::db::models::UpdatePersonaInput {
+                    model_profile: Some(Some(new_json)),
+                    ..Default::default()
+                },
+            )?;
+            state.session_pool.invalidate(&persona.id).await;
+        }
+        migrated.push(ModelProfileMigration {
+            persona_id: persona.id,
+            persona_name: persona.name,
+            from,
+            to,
+        });
+    }
+    if !migrated.is_empty() && !dry_run.unwrap_or(false) {
+        tracing::info!(count = migrated.len(), "Migrated deprecated model profiles");
+    }
+    Ok(migrated)
+}
+
+/// Current observer-mode config (off with no patterns when unset).
+#[tauri::command]
+pub fn get_observer_mode(state: State<'_, Arc<AppState>>) -> Result<ObserverModeConfig, AppError> {
+    require_auth_sync(&state)?;
+    Ok(observer_mode::load(&state.db))
+}
+
+/// Switch observer mode on or off and set its redaction patterns. The one
+/// mutating command observer mode leaves callable, so it can be turned off.
+#[tauri::command]
+pub fn set_observer_mode(
+    app: AppHandle,
+    state: State<'_, Arc<AppState>>,
+    config: ObserverModeConfig,
+) -> Result<(), AppError> {
+    require_auth_sync(&state)?;
+    let diags = observer_mode::validate(&config);
+    if !diags.is_empty() {
+        return Err(AppError::Validation(diags.join("; ")));
+    }
+    let json = serde_json::to_string(&config)?;
+    repo::set(&state.db, settings_keys::OBSERVER_MODE, &json)?;
+    observer_mode::apply(&config);
+    tracing::info!(enabled = config.enabled, "Observer mode updated");
+    emit_settings_changed(&app, settings_keys::OBSERVER_MODE);
+    Ok(())
+}
+
 /// Bulk-read variant of [`get_app_setting`]. Issues a single
 /// `SELECT key, value FROM app_settings WHERE key IN (...)` and returns a map
 /// of `{ key: value | null }`.
@@ -126,6 +222,12 @@ pub fn set_app_setting(
 ) -> Result<(), AppError> {
     require_auth_sync(&state)?;
     require_valid_key(&key)?;
+    if key == settings_keys::VAULT_BACKEND {
+        // Switching backends without copying the key would lock the vault.
+        return Err(AppError::Validation(
+            "Use configure_vault_backend to change the vault backend".into(),
+        ));
+    }
     if value.len() > MAX_SETTING_VALUE_SIZE {
         return Err(AppError::Validation(format!(
             "Setting value for '{}' exceeds maximum size ({} bytes > {} byte limit)",
@@ -140,6 +242,17 @@ pub fn set_app_setting(
     // refresh live. Emitted before the hot-apply block below moves `app`.
     emit_settings_changed(&app, &key);
 
+    // Observer mode written through the generic path must arm the IPC gate
+    // just like `set_observer_mode` does.
+    if key == settings_keys::OBSERVER_MODE {
+        observer_mode::apply(&observer_mode::load(&state.db));
+    }
+
+    // Keep the prompt `{{setting.*}}` variables in step with their sources.
+    if crate::engine::prompt::SETTING_VARIABLE_SOURCES.contains(&key.as_str()) {
+        crate::engine::prompt::reload_setting_variables(&state.db);
+    }
+
     // Hot-apply the global concurrency cap so a change to `max_parallel_executions`
     // takes effect WITHOUT an app restart (the engine otherwise reads this only
     // once at startup). Fire-and-forget: the value is already persisted, so even
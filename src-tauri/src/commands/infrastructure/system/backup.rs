@@ -0,0 +1,85 @@
+//! On-demand database backup, restore, and integrity check. The file handling
+//! lives in `db::backup` / `db::integrity`; these commands resolve the app data
+//! dir and keep the `VACUUM INTO` / PBKDF2 / `quick_check` work off the IPC
+//! thread.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tauri::{Manager, State};
+
+use crate::db::backup::{self, BackupKind, DatabaseBackupInfo, DatabaseRestoreResult};
+use crate::db::integrity::{self, DatabaseHealth};
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+use personas_macros::requires;
+
+fn app_data_dir(app: &tauri::AppHandle) -> Result<PathBuf, AppError> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| AppError::Internal(format!("Failed to resolve app data dir: {e}")))
+}
+
+/// Snapshot `personas.db` into `<app data>/backups/manual-<stamp>.db`. With a
+/// passphrase the snapshot is sealed into a `.pbak` file instead.
+#[tauri::command]
+pub async fn backup_database(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+    passphrase: Option<String>,
+) -> Result<DatabaseBackupInfo, AppError> {
+    require_auth_sync(&state)?;
+    let dir = app_data_dir(&app)?;
+    let pool = state.db.clone();
+    tokio::task::spawn_blocking(move || {
+        backup::create_backup(&pool, &dir, BackupKind::Manual, passphrase.as_deref())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Backup task failed: {e}")))?
+}
+
+/// Every manual, scheduled, and pre-migration backup, newest first.
+#[tauri::command]
+pub fn list_database_backups(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+) -> Result<Vec<DatabaseBackupInfo>, AppError> {
+    require_auth_sync(&state)?;
+    Ok(backup::list_backups(&app_data_dir(&app)?))
+}
+
+/// Validate a backup and stage it to replace `personas.db` on the next launch.
+/// The running database is never swapped underneath open connections.
+#[tauri::command]
+#[requires(privileged)]
+pub async fn restore_database(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+    path: String,
+    passphrase: Option<String>,
+) -> Result<DatabaseRestoreResult, AppError> {
+    let dir = app_data_dir(&app)?;
+    tokio::task::spawn_blocking(move || {
+        backup::stage_restore(&dir, &PathBuf::from(path), passphrase.as_deref())
+    })
+    .await
+    .map_err(|e| AppError::Internal(format!("Restore task failed: {e}")))?
+}
+
+/// Run `PRAGMA quick_check` on the live database and return it together with
+/// what the startup integrity pass found (and repaired) this session.
+#[tauri::command]
+pub async fn check_database_health(
+    state: State<'_, Arc<AppState>>,
+) -> Result<DatabaseHealth, AppError> {
+    require_auth_sync(&state)?;
+    let pool = state.db.clone();
+    let live = tokio::task::spawn_blocking(move || integrity::check_live(&pool))
+        .await
+        .map_err(|e| AppError::Internal(format!("Integrity check task failed: {e}")))??;
+    Ok(DatabaseHealth {
+        live,
+        startup: integrity::startup_report(),
+    })
+}
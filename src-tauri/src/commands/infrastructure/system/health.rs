@@ -57,6 +57,20 @@ pub struct SystemHealthReport {
     pub all_ok: bool,
 }
 
+// =============================================================================
+// Status Narrative
+// =============================================================================
+
+/// Plain-language summary of what is running, what waits on the user and
+/// what needs attention. Cheap enough to poll for status announcements.
+#[tauri::command]
+pub fn get_status_narrative(
+    state: State<'_, Arc<AppState>>,
+) -> Result<crate::engine::status_narrative::StatusNarrative, AppError> {
+    require_auth_sync(&state)?;
+    Ok(crate::engine::status_narrative::build(&state.db, state.scheduler.is_running()))
+}
+
 // =============================================================================
 // Full Health Check Command
 // =============================================================================
@@ -103,6 +117,9 @@ pub async fn system_health_check(
     // -- Section 6: Subscription Health --
     sections.push(build_subscriptions_section(&state));
 
+    // -- Section 6b: Database Pool --
+    sections.push(build_database_pool_section(&state.db));
+
     // -- Section 7: Frontend Stability --
     let crash_count_24h =
         crate::db::repos::core::frontend_crashes::count_since(&state.db, 24).unwrap_or(0);
@@ -337,6 +354,50 @@ fn build_environment_section(db: &crate::db::DbPool) -> HealthCheckSection {
         remediation,
     });
 
+    // 3. Container runtime for sandboxed personas (`execution_sandbox:*`).
+    //    Optional unless a persona is configured to run in a container.
+    let sandboxed = crate::db::repos::core::settings::get_by_prefix(
+        db,
+        settings_keys::EXECUTION_SANDBOX_PREFIX,
+    )
+    .map(|rows| rows.len())
+    .unwrap_or(0);
+    let (status, detail, remediation) = match crate::engine::container_sandbox::detect(
+        crate::engine::container_sandbox::RuntimePreference::Auto,
+    ) {
+        Ok(runtime) => (
+            HealthCheckStatus::Ok,
+            format!(
+                "{} {} available ({sandboxed} sandboxed persona(s))",
+                runtime.kind.label(),
+                runtime.version
+            ),
+            None,
+        ),
+        Err(reason) if sandboxed > 0 => (
+            HealthCheckStatus::Warn,
+            format!("No usable container runtime: {reason}"),
+            Some(format!(
+                "{sandboxed} persona(s) are configured to run in a container. Install Docker or \
+                 Podman and make sure the Docker daemon is running; until then they run on the \
+                 host or fail, per their sandbox fallback setting."
+            )),
+        ),
+        Err(_) => (
+            HealthCheckStatus::Inactive,
+            "No container runtime detected (optional, used by sandboxed personas)".to_string(),
+            None,
+        ),
+    };
+    items.push(HealthCheckItem {
+        id: "container_runtime".into(),
+        label: "Container Sandbox".into(),
+        status,
+        detail: Some(detail),
+        installable: false,
+        remediation,
+    });
+
     HealthCheckSection {
         id: "environment".into(),
         label: "Environment".into(),
@@ -777,6 +838,81 @@ fn build_subscriptions_section(state: &AppState) -> HealthCheckSection {
     }
 }
 
+fn build_database_pool_section(db: &crate::db::DbPool) -> HealthCheckSection {
+    let pool = db.state();
+    HealthCheckSection {
+        id: "database_pool".into(),
+        label: "Database Pool".into(),
+        items: database_pool_items(
+            pool.connections,
+            pool.idle_connections,
+            db.max_size(),
+            &crate::db::pool_budget::snapshot(),
+        ),
+    }
+}
+
+fn database_pool_items(
+    connections: u32,
+    idle: u32,
+    max_size: u32,
+    budget: &crate::db::pool_budget::PoolBudgetSnapshot,
+) -> Vec<HealthCheckItem> {
+    let in_use = connections.saturating_sub(idle);
+    let (status, remediation) = if in_use >= max_size {
+        (
+            HealthCheckStatus::Warn,
+            Some(format!(
+                "Every connection is checked out. Raise `{}` in settings (applies on restart).",
+                settings_keys::DB_POOL_SIZE
+            )),
+        )
+    } else {
+        (HealthCheckStatus::Ok, None)
+    };
+    let mut items = vec![HealthCheckItem {
+        id: "db_pool_utilization".into(),
+        label: "Pool Utilization".into(),
+        status,
+        detail: Some(format!(
+            "{in_use}/{max_size} in use, {idle} idle -- {} reserved for interactive commands",
+            budget.reserved,
+        )),
+        installable: false,
+        remediation,
+    }];
+
+    for (subsystem, usage) in &budget.subsystems {
+        let (status, remediation) = if usage.timed_out > 0 {
+            (
+                HealthCheckStatus::Warn,
+                Some(format!(
+                    "Background work is being shed for lack of connections. Raise `{}` in settings (applies on restart).",
+                    settings_keys::DB_POOL_SIZE
+                )),
+            )
+        } else {
+            (HealthCheckStatus::Ok, None)
+        };
+        items.push(HealthCheckItem {
+            id: format!("db_pool_{}", subsystem.as_str()),
+            label: format!("{} connections", subsystem.as_str()),
+            status,
+            detail: Some(format!(
+                "{}/{} in use -- {} acquired, {} waited, {} timed out",
+                usage.in_use,
+                budget.background_capacity,
+                usage.acquired,
+                usage.waited,
+                usage.timed_out,
+            )),
+            installable: false,
+            remediation,
+        });
+    }
+    items
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -823,6 +959,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn database_pool_items_warn_on_exhaustion_and_shed_work() {
+        use crate::db::pool_budget::{PoolBudgetSnapshot, Subsystem, SubsystemUsage};
+
+        let budget = PoolBudgetSnapshot {
+            pool_size: 12,
+            reserved: 2,
+            background_capacity: 10,
+            subsystems: vec![
+                (Subsystem::Scheduler, SubsystemUsage::default()),
+                (
+                    Subsystem::Webhook,
+                    SubsystemUsage {
+                        timed_out: 3,
+                        ..Default::default()
+                    },
+                ),
+            ],
+        };
+        let items = database_pool_items(12, 4, 12, &budget);
+        let status = |id: &str| items.iter().find(|i| i.id == id).unwrap().status;
+        assert_eq!(status("db_pool_utilization"), HealthCheckStatus::Ok);
+        assert_eq!(status("db_pool_scheduler"), HealthCheckStatus::Ok);
+        assert_eq!(status("db_pool_webhook"), HealthCheckStatus::Warn);
+
+        let items = database_pool_items(12, 0, 12, &budget);
+        assert_eq!(items[0].status, HealthCheckStatus::Warn);
+        assert!(items[0].remediation.is_some());
+    }
+
     #[test]
     fn test_health_check_section_serialization() {
         let section = HealthCheckSection {
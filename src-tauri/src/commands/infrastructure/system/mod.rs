@@ -1,3 +1,4 @@
+pub mod backup;
 pub mod binary_probe;
 pub mod crash_telemetry;
 pub mod health;
@@ -6,6 +7,7 @@ pub mod storage;
 
 // Re-export everything (including Tauri-generated `__cmd__*` items) so that
 // `commands::infrastructure::system::*` paths in lib.rs continue to work.
+pub use backup::*;
 pub use binary_probe::*;
 pub use crash_telemetry::*;
 pub use health::*;
@@ -79,3 +81,40 @@ pub async fn open_local_path(target: String) -> Result<(), AppError> {
 pub fn get_db_performance() -> crate::db::perf::DbPerfSnapshot {
     crate::db::perf::get_snapshot()
 }
+
+/// SQLite busy/locked counters from the shared retry wrapper, plus the main
+/// pool's current occupancy, for validating pool sizing and write batching.
+#[tauri::command]
+pub fn get_db_contention_stats(
+    state: tauri::State<'_, std::sync::Arc<crate::AppState>>,
+) -> Result<crate::db::repos::contention::DbContentionSnapshot, AppError> {
+    crate::ipc_auth::require_auth_sync(&state)?;
+    let mut snapshot = crate::db::repos::contention::snapshot();
+    let pool_state = state.db.state();
+    snapshot.pool_connections = pool_state.connections;
+    snapshot.pool_idle = pool_state.idle_connections;
+    snapshot.pool_max_size = state.db.max_size();
+    Ok(snapshot)
+}
+
+/// Zero the contention counters so a before/after comparison starts clean.
+#[tauri::command]
+pub fn reset_db_contention_stats(
+    state: tauri::State<'_, std::sync::Arc<crate::AppState>>,
+) -> Result<(), AppError> {
+    crate::ipc_auth::require_auth_sync(&state)?;
+    crate::db::repos::contention::reset();
+    Ok(())
+}
+
+/// Sanitized snapshot of the app's state (table counts, queue, background
+/// jobs, circuit breakers, settings minus secrets) to attach to a support
+/// ticket. Privileged: even redacted, it describes the whole install.
+#[tauri::command]
+pub async fn capture_state_snapshot(
+    state: tauri::State<'_, std::sync::Arc<crate::AppState>>,
+) -> Result<crate::engine::state_snapshot::StateSnapshot, AppError> {
+    crate::ipc_auth::require_privileged(&state, "capture_state_snapshot").await?;
+    crate::engine::state_snapshot::capture(&state.db, &state.engine, state.scheduler.jobs().list())
+        .await
+}
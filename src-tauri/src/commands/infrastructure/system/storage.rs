@@ -13,6 +13,7 @@ use serde::Serialize;
 use tauri::{Manager, State};
 use ts_rs::TS;
 
+use crate::engine::retention::{self, RetentionReport};
 use crate::error::AppError;
 use crate::ipc_auth::require_auth_sync;
 use crate::AppState;
@@ -131,3 +132,25 @@ pub fn prune_storage(
 
     Ok(PruneResult { dry_run, pruned_executions, age_hours })
 }
+
+/// Apply the configured retention settings (executions, execution log files,
+/// read messages) now instead of waiting for the hourly cleanup tick, and
+/// report what was reclaimed.
+#[tauri::command]
+pub async fn run_retention_cleanup_now(
+    state: State<'_, Arc<AppState>>,
+) -> Result<RetentionReport, AppError> {
+    require_auth_sync(&state)?;
+    let pool = state.db.clone();
+    let report = tokio::task::spawn_blocking(move || retention::run_retention(&pool))
+        .await
+        .map_err(|e| AppError::Internal(format!("Retention task failed: {e}")))?;
+    tracing::info!(
+        executions = report.executions_deleted,
+        messages = report.messages_deleted,
+        log_files = report.log_files_deleted,
+        log_bytes = report.log_bytes_reclaimed,
+        "Manual retention cleanup finished"
+    );
+    Ok(report)
+}
@@ -32,9 +32,12 @@ pub fn system_ops_list_automations(
     repo::list(&state.db)
 }
 
-/// Create an automation. `trigger_kind` is `"schedule"` (provide `cron`) or
-/// `"event"` (provide `listen_event_type`). For schedule kinds the next fire
-/// time is computed here from the cron.
+/// Create an automation. `trigger_kind` is `"schedule"` (provide `cron`),
+/// `"event"` (provide `listen_event_type`), `"webhook"` (provide a
+/// `webhookSecret` param; deliveries go to `/webhook/<automation id>`) or
+/// `"chain"` (provide the source persona id as `source_filter` and optionally a
+/// `condition` param). For schedule kinds the next fire time is computed here
+/// from the cron.
 #[tauri::command]
 #[allow(clippy::too_many_arguments)]
 pub fn system_ops_create_automation(
@@ -56,7 +59,7 @@ pub fn system_ops_create_automation(
         )));
     }
     // Validate params parse to JSON (the runner reads typed fields out of it).
-    serde_json::from_str::<serde_json::Value>(&params_json)
+    let params = serde_json::from_str::<serde_json::Value>(&params_json)
         .map_err(|e| AppError::Validation(format!("params_json is not valid JSON: {e}")))?;
 
     match trigger_kind.as_str() {
@@ -79,6 +82,32 @@ pub fn system_ops_create_automation(
                 ));
             }
         }
+        "webhook" => {
+            let secret = params.get("webhookSecret").and_then(|v| v.as_str());
+            if secret.map(str::trim).unwrap_or("").is_empty() {
+                return Err(AppError::Validation(
+                    "webhook automations require a webhookSecret param".into(),
+                ));
+            }
+        }
+        "chain" => {
+            if source_filter
+                .as_deref()
+                .map(str::trim)
+                .unwrap_or("")
+                .is_empty()
+            {
+                return Err(AppError::Validation(
+                    "chain automations require the source persona id as source_filter".into(),
+                ));
+            }
+            let condition = params.get("condition").and_then(|v| v.as_str());
+            if !matches!(condition, None | Some("any" | "success" | "failure")) {
+                return Err(AppError::Validation(
+                    "chain condition must be one of: any, success, failure".into(),
+                ));
+            }
+        }
         other => {
             return Err(AppError::Validation(format!(
                 "Unknown trigger kind: {other}"
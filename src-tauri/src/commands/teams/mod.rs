@@ -2,5 +2,6 @@ pub mod assignments;
 pub mod deliberations;
 pub mod team_channel;
 pub mod team_memories;
+pub mod team_spec;
 #[allow(clippy::module_inception)]
 pub mod teams;
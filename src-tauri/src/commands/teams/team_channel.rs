@@ -243,7 +243,7 @@ pub(crate) fn read_channel(
             let payload_iv: Option<String> = r.get(5).unwrap_or(None);
             let extra = match (raw_payload, payload_iv) {
                 (Some(ct), Some(ref iv)) if !iv.is_empty() => {
-                    crate::engine::crypto::decrypt_from_db(&ct, iv).ok()
+                    crate::engine::project_keys::decrypt(&ct, iv).ok()
                 }
                 (p, _) => p, // plaintext or none
             };
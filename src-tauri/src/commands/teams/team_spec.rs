@@ -0,0 +1,554 @@
+//! Team pipeline specs — export a team as a reviewable, shareable artifact
+//! and import it on another install.
+//!
+//! `export_team_spec` returns the same pipeline three ways: a YAML spec
+//! (team settings, member personas, roles, canvas positions, node configs and
+//! connections keyed by member slug), plus Mermaid and Graphviz DOT renders of
+//! the graph for PR descriptions and docs. `import_team_spec` recreates the
+//! personas and the team from the YAML. Model profiles are exported without
+//! their `auth_token`, so a spec never carries credentials.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::State;
+use ts_rs::TS;
+
+use crate::db::models::{
+    CreatePersonaInput, CreateTeamInput, Persona, PersonaTeam, PersonaTeamConnection,
+    PersonaTeamMember, UpdateTeamInput,
+};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::resources::teams as repo;
+use crate::db::DbPool;
+use crate::engine::types::ModelProfile;
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+/// Bumped when the spec shape changes incompatibly.
+const SPEC_VERSION: u32 = 1;
+const MAX_SPEC_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamSpec {
+    pub spec_version: u32,
+    pub team: TeamSpecTeam,
+    pub members: Vec<TeamSpecMember>,
+    #[serde(default)]
+    pub connections: Vec<TeamSpecConnection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamSpecTeam {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_instructions: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamSpecMember {
+    /// Slug referenced by `connections`; unique within the spec.
+    pub key: String,
+    pub role: String,
+    #[serde(default)]
+    pub position: [f64; 2],
+    /// Parsed `NodeConfig` JSON (model override, approval gate, …).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<Value>,
+    pub persona: TeamSpecPersona,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamSpecPersona {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub system_prompt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub structured_prompt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_profile: Option<ModelProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_budget_usd: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_turns: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamSpecConnection {
+    pub from: String,
+    pub to: String,
+    #[serde(rename = "type", default = "default_connection_type")]
+    pub connection_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+fn default_connection_type() -> String {
+    "sequential".into()
+}
+
+/// Result of `export_team_spec`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TeamSpecExport {
+    pub spec_yaml: String,
+    pub mermaid: String,
+    pub dot: String,
+}
+
+// ============================================================================
+// Building and rendering
+// ============================================================================
+
+fn slug(name: &str) -> String {
+    let mut out = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c.to_ascii_lowercase());
+        } else if !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+    let out = out.trim_matches('_');
+    if out.is_empty() {
+        "member".into()
+    } else {
+        out.to_string()
+    }
+}
+
+fn spec_persona(persona: &Persona) -> TeamSpecPersona {
+    let model_profile = persona
+        .model_profile
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<ModelProfile>(json).ok())
+        .map(|p| ModelProfile { auth_token: None, ..p })
+        .filter(|p| *p != ModelProfile::default());
+    TeamSpecPersona {
+        name: persona.name.clone(),
+        description: persona.description.clone(),
+        system_prompt: persona.system_prompt.clone(),
+        structured_prompt: persona.structured_prompt.clone(),
+        icon: persona.icon.clone(),
+        color: persona.color.clone(),
+        model_profile,
+        max_budget_usd: persona.max_budget_usd,
+        max_turns: persona.max_turns,
+    }
+}
+
+/// Assemble the spec from a team's rows. Connections whose endpoints are not
+/// members of the team are dropped.
+pub fn build_spec(
+    team: &PersonaTeam,
+    members: &[PersonaTeamMember],
+    connections: &[PersonaTeamConnection],
+    personas: &HashMap<String, Persona>,
+) -> Result<TeamSpec, AppError> {
+    let mut used = HashSet::new();
+    let mut keys: HashMap<&str, String> = HashMap::new();
+    let mut spec_members = Vec::new();
+    for member in members {
+        let persona = personas.get(&member.persona_id).ok_or_else(|| {
+            AppError::NotFound(format!("Persona {} (team member {})", member.persona_id, member.id))
+        })?;
+        let base = slug(&persona.name);
+        let mut key = base.clone();
+        let mut n = 2;
+        while !used.insert(key.clone()) {
+            key = format!("{base}_{n}");
+            n += 1;
+        }
+        keys.insert(member.id.as_str(), key.clone());
+        spec_members.push(TeamSpecMember {
+            key,
+            role: member.role.clone(),
+            position: [member.position_x, member.position_y],
+            config: member.config.as_deref().and_then(|c| serde_json::from_str(c).ok()),
+            persona: spec_persona(persona),
+        });
+    }
+
+    let spec_connections = connections
+        .iter()
+        .filter_map(|c| {
+            Some(TeamSpecConnection {
+                from: keys.get(c.source_member_id.as_str())?.clone(),
+                to: keys.get(c.target_member_id.as_str())?.clone(),
+                connection_type: c.connection_type.clone(),
+                condition: c.condition.clone(),
+                label: c.label.clone(),
+            })
+        })
+        .collect();
+
+    Ok(TeamSpec {
+        spec_version: SPEC_VERSION,
+        team: TeamSpecTeam {
+            name: team.name.clone(),
+            description: team.description.clone(),
+            icon: team.icon.clone(),
+            color: Some(team.color.clone()),
+            shared_instructions: team.shared_instructions.clone(),
+        },
+        members: spec_members,
+        connections: spec_connections,
+    })
+}
+
+fn edge_text(c: &TeamSpecConnection) -> Option<String> {
+    c.label
+        .clone()
+        .or_else(|| c.condition.clone())
+        .filter(|s| !s.trim().is_empty())
+}
+
+/// Mermaid flowchart: solid arrows for sequential/parallel handoffs, dotted
+/// for conditional ones and thick back-arrows for feedback loops.
+pub fn render_mermaid(spec: &TeamSpec) -> String {
+    let esc = |s: &str| s.replace('"', "#quot;").replace('\n', " ");
+    let mut out = String::from("flowchart LR\n");
+    for m in &spec.members {
+        out.push_str(&format!(
+            "  {}[\"{}<br/><i>{}</i>\"]\n",
+            m.key,
+            esc(&m.persona.name),
+            esc(&m.role)
+        ));
+    }
+    for c in &spec.connections {
+        let arrow = match c.connection_type.as_str() {
+            "conditional" => "-.->",
+            "feedback" => "==>",
+            _ => "-->",
+        };
+        match edge_text(c) {
+            Some(text) => {
+                out.push_str(&format!("  {} {arrow}|\"{}\"| {}\n", c.from, esc(&text), c.to))
+            }
+            None => out.push_str(&format!("  {} {arrow} {}\n", c.from, c.to)),
+        }
+    }
+    out
+}
+
+/// Graphviz DOT digraph with the same edge styling as [`render_mermaid`].
+pub fn render_dot(spec: &TeamSpec) -> String {
+    let esc = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', " ");
+    let mut out = format!(
+        "digraph \"{}\" {{\n  rankdir=LR;\n  node [shape=box, style=rounded];\n",
+        esc(&spec.team.name)
+    );
+    for m in &spec.members {
+        out.push_str(&format!(
+            "  \"{}\" [label=\"{}\\n({})\"];\n",
+            m.key,
+            esc(&m.persona.name),
+            esc(&m.role)
+        ));
+    }
+    for c in &spec.connections {
+        let mut attrs = Vec::new();
+        match c.connection_type.as_str() {
+            "conditional" => attrs.push("style=dashed".to_string()),
+            "feedback" => attrs.push("style=bold, constraint=false".to_string()),
+            _ => {}
+        }
+        if let Some(text) = edge_text(c) {
+            attrs.push(format!("label=\"{}\"", esc(&text)));
+        }
+        let attrs = if attrs.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", attrs.join(", "))
+        };
+        out.push_str(&format!("  \"{}\" -> \"{}\"{attrs};\n", c.from, c.to));
+    }
+    out.push_str("}\n");
+    out
+}
+
+// ============================================================================
+// Parsing and import
+// ============================================================================
+
+/// Parse and validate a YAML spec: supported version, at least one member,
+/// unique member keys and connections between known members.
+pub fn parse_spec(yaml: &str) -> Result<TeamSpec, AppError> {
+    if yaml.len() > MAX_SPEC_BYTES {
+        return Err(AppError::Validation("Team spec exceeds 1 MB".into()));
+    }
+    let spec: TeamSpec = serde_yaml::from_str(yaml)
+        .map_err(|e| AppError::Validation(format!("Invalid team spec: {e}")))?;
+    if spec.spec_version != SPEC_VERSION {
+        return Err(AppError::Validation(format!(
+            "Unsupported team spec version {} (expected {SPEC_VERSION})",
+            spec.spec_version
+        )));
+    }
+    if spec.team.name.trim().is_empty() {
+        return Err(AppError::Validation("Team spec has no team name".into()));
+    }
+    if spec.members.is_empty() {
+        return Err(AppError::Validation("Team spec has no members".into()));
+    }
+    let mut keys = HashSet::new();
+    for m in &spec.members {
+        if !keys.insert(m.key.as_str()) {
+            return Err(AppError::Validation(format!("Duplicate member key '{}'", m.key)));
+        }
+    }
+    for c in &spec.connections {
+        for end in [&c.from, &c.to] {
+            if !keys.contains(end.as_str()) {
+                return Err(AppError::Validation(format!(
+                    "Connection {} -> {} references unknown member '{end}'",
+                    c.from, c.to
+                )));
+            }
+        }
+    }
+    Ok(spec)
+}
+
+fn commit_spec(
+    pool: &DbPool,
+    spec: &TeamSpec,
+    project_id: Option<String>,
+    persona_ids: &mut Vec<String>,
+    team_id: &mut Option<String>,
+) -> Result<PersonaTeam, AppError> {
+    let team = repo::create(
+        pool,
+        CreateTeamInput {
+            name: spec.team.name.clone(),
+            project_id: project_id.clone(),
+            parent_team_id: None,
+            description: spec.team.description.clone(),
+            canvas_data: None,
+            team_config: None,
+            icon: spec.team.icon.clone(),
+            color: spec.team.color.clone(),
+            enabled: Some(true),
+        },
+    )?;
+    *team_id = Some(team.id.clone());
+
+    let mut member_ids: HashMap<&str, String> = HashMap::new();
+    for m in &spec.members {
+        let p = &m.persona;
+        let model_profile = match &p.model_profile {
+            Some(profile) => Some(serde_json::to_string(profile)?),
+            None => None,
+        };
+        let persona = persona_repo::create(
+            pool,
+            CreatePersonaInput {
+                name: p.name.clone(),
+                system_prompt: p.system_prompt.clone(),
+                project_id: project_id.clone(),
+                description: p.description.clone(),
+                structured_prompt: p.structured_prompt.clone(),
+                icon: p.icon.clone(),
+                color: p.color.clone(),
+                enabled: Some(true),
+                max_concurrent: None,
+                timeout_ms: None,
+                model_profile,
+                max_budget_usd: p.max_budget_usd,
+                max_turns: p.max_turns,
+                design_context: None,
+                notification_channels: None,
+                lifecycle: None,
+            },
+        )?;
+        persona_ids.push(persona.id.clone());
+        let member = repo::add_member(
+            pool,
+            &team.id,
+            &persona.id,
+            Some(m.role.clone()),
+            Some(m.position[0]),
+            Some(m.position[1]),
+            m.config.as_ref().map(Value::to_string),
+        )?;
+        member_ids.insert(m.key.as_str(), member.id);
+    }
+
+    for c in &spec.connections {
+        repo::create_connection(
+            pool,
+            &team.id,
+            &member_ids[c.from.as_str()],
+            &member_ids[c.to.as_str()],
+            Some(c.connection_type.clone()),
+            c.condition.clone(),
+            c.label.clone(),
+        )?;
+    }
+
+    let team = match &spec.team.shared_instructions {
+        Some(instructions) => repo::update(
+            pool,
+            &team.id,
+            UpdateTeamInput {
+                name: None,
+                description: None,
+                canvas_data: None,
+                team_config: None,
+                icon: None,
+                color: None,
+                enabled: None,
+                shared_instructions: Some(Some(instructions.clone())),
+                default_model_profile: None,
+                default_max_budget_usd: None,
+                default_max_turns: None,
+                monthly_budget_usd: None,
+            },
+        )?,
+        None => team,
+    };
+
+    if let Err(e) = crate::engine::team_handoff::wire_team_handoff(pool, &team.id) {
+        tracing::warn!(team_id = %team.id, error = %e, "team spec import: handoff wiring failed (continuing)");
+    }
+    Ok(team)
+}
+
+// ============================================================================
+// Commands
+// ============================================================================
+
+/// Export a team as a YAML spec plus Mermaid and DOT diagrams.
+#[tauri::command]
+pub fn export_team_spec(
+    state: State<'_, Arc<AppState>>,
+    team_id: String,
+) -> Result<TeamSpecExport, AppError> {
+    require_auth_sync(&state)?;
+    let team = repo::get_by_id(&state.db, &team_id)?;
+    let members = repo::get_members(&state.db, &team_id)?;
+    let connections = repo::get_connections(&state.db, &team_id)?;
+    let ids: Vec<String> = members.iter().map(|m| m.persona_id.clone()).collect();
+    let personas = persona_repo::get_by_ids(&state.db, &ids)?
+        .into_iter()
+        .map(|p| (p.id.clone(), p))
+        .collect();
+
+    let spec = build_spec(&team, &members, &connections, &personas)?;
+    Ok(TeamSpecExport {
+        spec_yaml: serde_yaml::to_string(&spec)
+            .map_err(|e| AppError::Internal(format!("Team spec serialization failed: {e}")))?,
+        mermaid: render_mermaid(&spec),
+        dot: render_dot(&spec),
+    })
+}
+
+/// Create a new team (and fresh member personas) from a YAML spec. Any
+/// failure deletes what was already created.
+#[tauri::command]
+pub fn import_team_spec(
+    state: State<'_, Arc<AppState>>,
+    spec_yaml: String,
+    project_id: Option<String>,
+) -> Result<PersonaTeam, AppError> {
+    require_auth_sync(&state)?;
+    let spec = parse_spec(&spec_yaml)?;
+
+    let mut persona_ids = Vec::new();
+    let mut team_id = None;
+    match commit_spec(&state.db, &spec, project_id, &mut persona_ids, &mut team_id) {
+        Ok(team) => Ok(team),
+        Err(e) => {
+            tracing::warn!(error = %e, personas = persona_ids.len(), "team spec import failed; rolling back");
+            for pid in &persona_ids {
+                let _ = persona_repo::delete(&state.db, pid);
+            }
+            if let Some(tid) = team_id {
+                let _ = repo::delete(&state.db, &tid);
+            }
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+spec_version: 1
+team:
+  name: Content "crew"
+members:
+  - key: researcher
+    role: orchestrator
+    position: [0, 0]
+    persona:
+      name: Researcher
+      system_prompt: Research the topic.
+      model_profile: { model: claude-sonnet-4-6 }
+  - key: writer
+    role: worker
+    config: { approvalGate: true }
+    persona:
+      name: Writer
+      system_prompt: Write it up.
+connections:
+  - from: researcher
+    to: writer
+  - from: writer
+    to: researcher
+    type: feedback
+    label: needs more sources
+"#;
+
+    #[test]
+    fn parses_spec_and_renders_diagrams() {
+        let spec = parse_spec(SPEC).unwrap();
+        assert_eq!(spec.members.len(), 2);
+        assert_eq!(spec.connections[0].connection_type, "sequential");
+        assert_eq!(spec.members[1].config, Some(serde_json::json!({ "approvalGate": true })));
+
+        let mermaid = render_mermaid(&spec);
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("  researcher --> writer\n"));
+        assert!(mermaid.contains("  writer ==>|\"needs more sources\"| researcher\n"));
+
+        let dot = render_dot(&spec);
+        assert!(dot.starts_with("digraph \"Content \\\"crew\\\"\" {"));
+        assert!(dot.contains(
+            "\"writer\" -> \"researcher\" [style=bold, constraint=false, label=\"needs more sources\"];"
+        ));
+
+        // The exported YAML round-trips through the importer's parser.
+        let yaml = serde_yaml::to_string(&spec).unwrap();
+        assert_eq!(parse_spec(&yaml).unwrap(), spec);
+    }
+
+    #[test]
+    fn rejects_unknown_members_and_versions() {
+        let bad_edge = SPEC.replace("to: writer", "to: editor");
+        assert!(parse_spec(&bad_edge).unwrap_err().to_string().contains("unknown member 'editor'"));
+        assert!(parse_spec(&SPEC.replace("spec_version: 1", "spec_version: 2")).is_err());
+        assert!(parse_spec(&SPEC.replace("key: writer", "key: researcher")).is_err());
+        assert_eq!(slug("  QA / Reviewer!  "), "qa_reviewer");
+        assert_eq!(slug("***"), "member");
+    }
+}
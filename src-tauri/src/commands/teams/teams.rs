@@ -3,11 +3,12 @@ use tauri::State;
 
 use crate::db::models::{
     CreateTeamInput, PersonaTeam, PersonaTeamConnection, PersonaTeamMember, PipelineRun,
-    TeamCounts, UpdateTeamInput,
+    PipelineSimulation, TeamCounts, UpdateTeamInput,
 };
 use crate::db::repos::resources::teams as repo;
 use crate::engine::event_registry::event_name;
 use crate::engine::optimizer::{self, PipelineAnalytics};
+use crate::engine::pipeline_executor::{self, ConditionValidation};
 use crate::engine::topology_heuristic;
 use crate::engine::topology_types::TopologyBlueprint;
 use crate::error::AppError;
@@ -163,6 +164,11 @@ pub fn create_team_connection(
     label: Option<String>,
 ) -> Result<PersonaTeamConnection, AppError> {
     require_auth_sync(&state)?;
+    if let Some(cond) = condition.as_deref().filter(|c| !c.trim().is_empty()) {
+        if let Some(error) = pipeline_executor::validate_condition(cond, None).error {
+            return Err(AppError::Validation(format!("Invalid condition: {error}")));
+        }
+    }
     repo::create_connection(
         &state.db,
         &team_id,
@@ -184,6 +190,21 @@ pub fn update_team_connection(
     repo::update_connection_type(&state.db, &id, &connection_type)
 }
 
+/// Check a conditional connection's expression. With `sample_output`, also
+/// reports whether the branch would be taken for that upstream output.
+#[tauri::command]
+pub fn validate_connection_condition(
+    state: State<'_, Arc<AppState>>,
+    condition: String,
+    sample_output: Option<String>,
+) -> Result<ConditionValidation, AppError> {
+    require_auth_sync(&state)?;
+    Ok(pipeline_executor::validate_condition(
+        &condition,
+        sample_output.as_deref(),
+    ))
+}
+
 #[tauri::command]
 pub fn delete_team_connection(
     state: State<'_, Arc<AppState>>,
@@ -223,8 +244,45 @@ pub async fn execute_team(
     input_data: Option<String>,
 ) -> Result<String, AppError> {
     require_auth(&state).await?;
+    launch_team_pipeline(&state, app, team_id, input_data, None)
+}
+
+/// Dry-run a team's pipeline on `sample_input`. Persona nodes run with their
+/// tools sandboxed -- they report the calls they would make (recorded per
+/// node as `tool_calls`) and continue on the configured `tool_responses` --
+/// command nodes output their configured text instead of running, approval
+/// gates pass automatically, and no team memories are written. The run is
+/// listed with the team's other runs and carries the full node-status trace.
+#[tauri::command]
+pub async fn simulate_team_run(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+    team_id: String,
+    sample_input: Option<String>,
+    simulation: Option<PipelineSimulation>,
+) -> Result<String, AppError> {
+    require_auth(&state).await?;
+    launch_team_pipeline(
+        &state,
+        app,
+        team_id,
+        sample_input,
+        Some(simulation.unwrap_or_default()),
+    )
+}
+
+/// Start a pipeline run for a team and return its run id. Shared by
+/// `execute_team`, `simulate_team_run` and the unattended `team_pipeline`
+/// system op (schedule, webhook and event automations, see
+/// `engine::system_ops`).
+pub(crate) fn launch_team_pipeline(
+    state: &Arc<AppState>,
+    app: tauri::AppHandle,
+    team_id: String,
+    input_data: Option<String>,
+    simulation: Option<PipelineSimulation>,
+) -> Result<String, AppError> {
     use crate::db::repos::resources::teams as team_repo;
-    use crate::engine::pipeline_executor::{self, PipelineContext};
     use tauri::Emitter;
 
     // Fast-path pre-check: reject early (without a write lock) if this team
@@ -240,7 +298,12 @@ pub async fn execute_team(
     }
 
     // Create pipeline run (atomically re-checks the running-pipeline guard).
-    let run_id = team_repo::create_pipeline_run(&state.db, &team_id, input_data.as_deref())?;
+    let run_id = team_repo::create_pipeline_run(
+        &state.db,
+        &team_id,
+        input_data.as_deref(),
+        simulation.as_ref(),
+    )?;
 
     // Load members and connections
     let members = team_repo::get_members(&state.db, &team_id)?;
@@ -257,45 +320,31 @@ pub async fn execute_team(
         return Ok(run_id);
     }
 
-    // Topological sort — exclude feedback edges so the graph is a clean DAG.
-    let member_ids: Vec<String> = members.iter().map(|m| m.id.clone()).collect();
-    let edges: Vec<(&str, &str)> = connections
-        .iter()
-        .filter(|c| c.connection_type != "feedback")
-        .map(|c| (c.source_member_id.as_str(), c.target_member_id.as_str()))
-        .collect();
-    let topo = crate::engine::topology_graph::NamedTopologyGraph::new(&member_ids, &edges);
-    let sort_result = topo.topological_sort();
-
-    if sort_result.has_cycle() {
-        tracing::warn!(
-            team_id = %team_id,
-            cycle_nodes = ?sort_result.cycle_nodes,
-            "Pipeline contains a non-feedback cycle -- refusing to execute",
-        );
-        let _ = app.emit(
-            event_name::PIPELINE_CYCLE_WARNING,
-            serde_json::json!({
-                "team_id": team_id,
-                "pipeline_id": run_id,
-                "cycle_member_ids": sort_result.cycle_nodes,
-            }),
-        );
-        // Hard stop. Appending cycle members to the linear order just ran them
-        // once in arbitrary order with NONE of their true upstream outputs (each
-        // fell back to the global pipeline_input) — a result that looks successful
-        // but is semantically garbage and poisons team memory. Refuse instead.
-        let msg = format!(
-            "Pipeline has a non-feedback cycle ({} members) -- refusing to execute. \
-             Remove the A->...->A edges (only feedback edges may loop).",
-            sort_result.cycle_nodes.len()
-        );
-        team_repo::update_pipeline_run(&state.db, &run_id, "failed", "[]", Some(&msg))?;
-        return Ok(run_id);
-    }
-
-    // Acyclic — execute in topological order.
-    let execution_order = sort_result.order;
+    let execution_order = match pipeline_execution_order(&members, &connections) {
+        Ok(order) => order,
+        Err(cycle_nodes) => {
+            tracing::warn!(
+                team_id = %team_id,
+                cycle_nodes = ?cycle_nodes,
+                "Pipeline contains a non-feedback cycle -- refusing to execute",
+            );
+            let _ = app.emit(
+                event_name::PIPELINE_CYCLE_WARNING,
+                serde_json::json!({
+                    "team_id": team_id,
+                    "pipeline_id": run_id,
+                    "cycle_member_ids": cycle_nodes,
+                }),
+            );
+            // Hard stop. Appending cycle members to the linear order just ran them
+            // once in arbitrary order with NONE of their true upstream outputs (each
+            // fell back to the global pipeline_input) — a result that looks successful
+            // but is semantically garbage and poisons team memory. Refuse instead.
+            let msg = cycle_message(cycle_nodes.len());
+            team_repo::update_pipeline_run(&state.db, &run_id, "failed", "[]", Some(&msg))?;
+            return Ok(run_id);
+        }
+    };
 
     // Build initial node statuses
     let initial_node_statuses: Vec<serde_json::Value> = members
@@ -323,34 +372,103 @@ pub async fn execute_team(
         }),
     );
 
-    // Set up cancellation flag.
+    spawn_pipeline(
+        state,
+        app,
+        PipelineLaunch {
+            run_id: run_id.clone(),
+            team_id,
+            input_data,
+            members,
+            connections,
+            execution_order,
+            initial_node_statuses,
+            simulation,
+        },
+    );
+
+    Ok(run_id)
+}
+
+/// Topological execution order over non-feedback edges, or the members
+/// caught in a cycle.
+fn pipeline_execution_order(
+    members: &[PersonaTeamMember],
+    connections: &[PersonaTeamConnection],
+) -> Result<Vec<String>, Vec<String>> {
+    // Exclude feedback edges so the graph is a clean DAG.
+    let member_ids: Vec<String> = members.iter().map(|m| m.id.clone()).collect();
+    let edges: Vec<(&str, &str)> = connections
+        .iter()
+        .filter(|c| c.connection_type != "feedback")
+        .map(|c| (c.source_member_id.as_str(), c.target_member_id.as_str()))
+        .collect();
+    let topo = crate::engine::topology_graph::NamedTopologyGraph::new(&member_ids, &edges);
+    let sort_result = topo.topological_sort();
+    if sort_result.has_cycle() {
+        Err(sort_result.cycle_nodes)
+    } else {
+        Ok(sort_result.order)
+    }
+}
+
+fn cycle_message(cycle_len: usize) -> String {
+    format!(
+        "Pipeline has a non-feedback cycle ({cycle_len} members) -- refusing to execute. \
+         Remove the A->...->A edges (only feedback edges may loop)."
+    )
+}
+
+/// Everything `spawn_pipeline` needs besides the app state.
+struct PipelineLaunch {
+    run_id: String,
+    team_id: String,
+    input_data: Option<String>,
+    members: Vec<PersonaTeamMember>,
+    connections: Vec<PersonaTeamConnection>,
+    execution_order: Vec<String>,
+    initial_node_statuses: Vec<serde_json::Value>,
+    simulation: Option<PipelineSimulation>,
+}
+
+/// Register the run's cancel and pause flags and spawn `run_pipeline`.
+fn spawn_pipeline(state: &Arc<AppState>, app: tauri::AppHandle, launch: PipelineLaunch) {
+    use crate::engine::pipeline_executor::PipelineContext;
+
     let (cancelled, run_guard) = state
         .process_registry
-        .register_run_guarded("pipeline", &run_id);
+        .register_run_guarded("pipeline", &launch.run_id);
+    let (pause_requested, pause_guard) = state
+        .process_registry
+        .register_run_guarded("pipeline_pause", &launch.run_id);
 
     let ctx = PipelineContext {
         db: state.db.clone(),
         engine: state.engine.clone(),
-        app: app.clone(),
-        run_id: run_id.clone(),
-        team_id: team_id.clone(),
-        input_data,
-        members,
-        connections,
-        execution_order,
-        initial_node_statuses,
+        app,
+        run_id: launch.run_id,
+        team_id: launch.team_id,
+        input_data: launch.input_data,
+        members: launch.members,
+        connections: launch.connections,
+        execution_order: launch.execution_order,
+        initial_node_statuses: launch.initial_node_statuses,
         cancelled,
+        pause_requested,
         process_registry: state.process_registry.clone(),
+        simulation: launch.simulation,
     };
 
-    tokio::spawn(async move {
-        let _guard = run_guard;
+    // Not `tokio::spawn`: unattended launches come in from sync contexts
+    // (`system_ops_run_now`) that have no runtime entered.
+    tauri::async_runtime::spawn(async move {
+        let _guards = (run_guard, pause_guard);
         let db_for_panic = ctx.db.clone();
         let run_id_for_panic = ctx.run_id.clone();
         let result = std::panic::AssertUnwindSafe(pipeline_executor::run_pipeline(ctx));
         if futures_util::FutureExt::catch_unwind(result).await.is_err() {
             tracing::error!(run_id = %run_id_for_panic, "Pipeline task panicked — marking run as failed");
-            let _ = team_repo::update_pipeline_run(
+            let _ = repo::update_pipeline_run(
                 &db_for_panic,
                 &run_id_for_panic,
                 "failed",
@@ -359,14 +477,134 @@ pub async fn execute_team(
             );
         }
     });
+}
 
-    Ok(run_id)
+/// Reopen a stopped run and spawn it again from its persisted node statuses,
+/// re-running `retry_member` and everything downstream of it when given.
+fn continue_pipeline_run(
+    state: &Arc<AppState>,
+    app: tauri::AppHandle,
+    run_id: &str,
+    allowed: &[&str],
+    retry_member: Option<&str>,
+) -> Result<(), AppError> {
+    use tauri::Emitter;
+
+    let run = repo::get_pipeline_run(&state.db, run_id)?;
+    let members = repo::get_members(&state.db, &run.team_id)?;
+    let connections = repo::get_connections(&state.db, &run.team_id)?;
+    if let Some(member_id) = retry_member {
+        if !members.iter().any(|m| m.id == member_id) {
+            return Err(AppError::NotFound(format!("Team member {member_id}")));
+        }
+    }
+    let execution_order = pipeline_execution_order(&members, &connections)
+        .map_err(|cycle_nodes| AppError::Validation(cycle_message(cycle_nodes.len())))?;
+
+    let previous: Vec<serde_json::Value> =
+        serde_json::from_str(&run.node_statuses).unwrap_or_default();
+    let node_statuses =
+        pipeline_executor::reset_for_continuation(&previous, &members, &connections, retry_member);
+    repo::reopen_pipeline_run(
+        &state.db,
+        run_id,
+        allowed,
+        &serde_json::to_string(&node_statuses)?,
+    )?;
+    let _ = app.emit(
+        event_name::PIPELINE_STATUS,
+        serde_json::json!({
+            "pipeline_id": run_id,
+            "team_id": run.team_id,
+            "status": "running",
+            "node_statuses": node_statuses,
+        }),
+    );
+
+    spawn_pipeline(
+        state,
+        app,
+        PipelineLaunch {
+            run_id: run_id.to_string(),
+            team_id: run.team_id,
+            input_data: run.input_data,
+            members,
+            connections,
+            execution_order,
+            initial_node_statuses: node_statuses,
+            simulation: run.simulation.map(|s| s.into_inner()),
+        },
+    );
+    Ok(())
+}
+
+/// Pause a running pipeline. The node in flight finishes; the run then
+/// stops with status `paused` and keeps every node's status and output, so
+/// `resume_pipeline_run` continues where it left off (also after a restart).
+#[tauri::command]
+pub fn pause_pipeline_run(
+    state: State<'_, Arc<AppState>>,
+    run_id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    if !state
+        .process_registry
+        .is_run_registered("pipeline_pause", &run_id)
+    {
+        return Err(AppError::Validation(
+            "This pipeline run is not running.".into(),
+        ));
+    }
+    state.process_registry.cancel_run("pipeline_pause", &run_id);
+    tracing::info!(run_id = %run_id, "Pipeline pause requested");
+    Ok(true)
+}
+
+/// Resume a paused pipeline run from the first node that hasn't finished.
+#[tauri::command]
+pub async fn resume_pipeline_run(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+    run_id: String,
+) -> Result<(), AppError> {
+    require_auth(&state).await?;
+    continue_pipeline_run(&state, app, &run_id, &["paused"], None)
+}
+
+/// Re-run one node of a stopped pipeline run, and everything downstream of
+/// it, reusing the outputs of the nodes upstream. Lets a failed middle node
+/// be retried without paying for the whole chain again.
+#[tauri::command]
+pub async fn retry_pipeline_node(
+    state: State<'_, Arc<AppState>>,
+    app: tauri::AppHandle,
+    run_id: String,
+    member_id: String,
+) -> Result<(), AppError> {
+    require_auth(&state).await?;
+    continue_pipeline_run(
+        &state,
+        app,
+        &run_id,
+        &["failed", "cancelled", "paused", "completed"],
+        Some(&member_id),
+    )
 }
 
 /// Cancel a running pipeline by setting its cancellation flag.
 #[tauri::command]
 pub fn cancel_pipeline(state: State<'_, Arc<AppState>>, run_id: String) -> Result<bool, AppError> {
     require_auth_sync(&state)?;
+    if !state
+        .process_registry
+        .is_run_registered("pipeline", &run_id)
+    {
+        // A paused run has no task to signal — close it out directly.
+        let run = repo::get_pipeline_run(&state.db, &run_id)?;
+        if run.status == "paused" {
+            repo::update_pipeline_run(&state.db, &run_id, "cancelled", &run.node_statuses, None)?;
+        }
+    }
     state.process_registry.cancel_run("pipeline", &run_id);
     tracing::info!(run_id = %run_id, "Pipeline cancellation requested");
     Ok(true)
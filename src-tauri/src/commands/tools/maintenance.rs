@@ -0,0 +1,59 @@
+//! Maintenance windows: pause trigger-driven execution globally or per
+//! persona without disabling anything. See `resources::maintenance_windows`.
+
+use std::sync::Arc;
+
+use tauri::State;
+
+use crate::db::models::{CreateMaintenanceWindowInput, MaintenanceWindow};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::resources::maintenance_windows as repo;
+use crate::error::AppError;
+use crate::ipc_auth::require_auth_sync;
+use crate::AppState;
+
+/// Active and upcoming windows; pass `include_ended` for history too.
+#[tauri::command]
+pub fn list_maintenance_windows(
+    state: State<'_, Arc<AppState>>,
+    include_ended: Option<bool>,
+) -> Result<Vec<MaintenanceWindow>, AppError> {
+    require_auth_sync(&state)?;
+    repo::list(&state.db, include_ended.unwrap_or(false))
+}
+
+#[tauri::command]
+pub fn create_maintenance_window(
+    state: State<'_, Arc<AppState>>,
+    input: CreateMaintenanceWindowInput,
+) -> Result<MaintenanceWindow, AppError> {
+    require_auth_sync(&state)?;
+    if let Some(persona_id) = &input.persona_id {
+        persona_repo::get_by_id(&state.db, persona_id)?;
+    }
+    repo::create(&state.db, input)
+}
+
+/// End a window now. Trigger events it held back become claimable on the
+/// next event-bus tick.
+#[tauri::command]
+pub fn end_maintenance_window(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<MaintenanceWindow, AppError> {
+    require_auth_sync(&state)?;
+    let window = repo::end_now(&state.db, &id)?;
+    crate::engine::subscription::event_bus_wake_signal().notify_one();
+    Ok(window)
+}
+
+#[tauri::command]
+pub fn delete_maintenance_window(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+) -> Result<bool, AppError> {
+    require_auth_sync(&state)?;
+    let deleted = repo::delete(&state.db, &id)?;
+    crate::engine::subscription::event_bus_wake_signal().notify_one();
+    Ok(deleted)
+}
@@ -2,6 +2,7 @@ pub mod automation_design;
 pub mod automations;
 pub mod deploy_automation;
 pub mod github_platform;
+pub mod maintenance;
 pub mod n8n_platform;
 #[allow(clippy::module_inception)]
 pub mod tools;
@@ -9,6 +9,7 @@ use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::execution::tool_usage;
 use crate::db::repos::resources::tool_audit_log;
 use crate::db::repos::resources::tools as repo;
+use crate::engine::tool_policy::{self, ToolPolicy};
 use crate::engine::tool_runner::{self, ToolInvocationResult};
 use crate::error::AppError;
 use crate::ipc_auth::{require_auth_sync};
@@ -80,6 +81,28 @@ pub fn assign_tool(
     repo::assign_tool(&state.db, &persona_id, &tool_id, tool_config)
 }
 
+/// Set (or with `None`, clear) the sandbox policy of an assigned builtin tool.
+/// Other `tool_config` keys are kept.
+#[tauri::command]
+pub fn set_tool_policy(
+    state: State<'_, Arc<AppState>>,
+    persona_id: String,
+    tool_id: String,
+    policy: Option<ToolPolicy>,
+) -> Result<PersonaTool, AppError> {
+    require_auth_sync(&state)?;
+    let definition = repo::get_definition_by_id(&state.db, &tool_id)?;
+    if let Some(ref p) = policy {
+        tool_policy::validate(&definition.name, p)?;
+    }
+    let current = repo::get_tool_configs_for_persona(&state.db, &persona_id)?
+        .into_iter()
+        .find(|(name, _)| *name == definition.name)
+        .map(|(_, config)| config);
+    let config = tool_policy::merge_into_config(current.as_deref(), policy.as_ref());
+    repo::update_tool_config(&state.db, &persona_id, &tool_id, config)
+}
+
 #[tauri::command]
 pub fn unassign_tool(
     state: State<'_, Arc<AppState>>,
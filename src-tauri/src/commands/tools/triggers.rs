@@ -5,14 +5,16 @@ use ts_rs::TS;
 
 use crate::db::models::webhook_log::WebhookRequestLog;
 use crate::db::models::{
-    CreatePersonaEventInput, CreateTriggerInput, PendingTriggerFire, PersonaTrigger,
-    UpdateTriggerInput,
+    CreatePersonaEventInput, CreateTriggerInput, InjectionDetection, PendingTriggerFire,
+    PersonaTrigger, ScheduleExclusion, UpdateTriggerInput,
 };
 use crate::db::repos::communication::events as event_repo;
+use crate::db::repos::communication::injection_detections as injection_repo;
 use crate::db::repos::resources::tools as tool_repo;
 use crate::db::repos::resources::triggers as repo;
 use crate::db::repos::resources::webhook_log as webhook_log_repo;
 use crate::engine::chain;
+use crate::engine::cron::cron_to_human;
 use crate::error::AppError;
 use crate::ipc_auth::{require_auth, require_auth_sync};
 use crate::validation::contract::check;
@@ -44,6 +46,10 @@ fn validate_trigger_input(trigger_type: &str, config: Option<&str>) -> Result<()
         trigger_type,
         config,
     ));
+    errors.extend(tv::validate_schedule_exclusions(trigger_type, config));
+    errors.extend(tv::validate_schedule_catch_up(trigger_type, config));
+    errors.extend(tv::validate_injection_policy(config));
+    errors.extend(tv::validate_output_regression(trigger_type, config));
     check(errors)
 }
 
@@ -141,6 +147,10 @@ pub fn update_trigger(
             trigger_type,
             config,
         ));
+        errors.extend(tv::validate_schedule_exclusions(trigger_type, config));
+        errors.extend(tv::validate_schedule_catch_up(trigger_type, config));
+        errors.extend(tv::validate_injection_policy(config));
+        errors.extend(tv::validate_output_regression(trigger_type, config));
         check(errors)?;
         validate_chain_cycle(
             &state.db,
@@ -195,6 +205,16 @@ pub fn resolve_pending_trigger_fire(
         )));
     }
     let resolved = repo::resolve_pending_fire(&state.db, &id, approved)?;
+    // A fire held by the prompt-injection scan carries its review outcome: an
+    // approval clears the payload so the republished event is not held again.
+    let review = if approved {
+        "false_positive"
+    } else {
+        "confirmed"
+    };
+    if let Err(e) = injection_repo::review_by_pending_fire(&state.db, &id, review) {
+        tracing::warn!(pending_id = %id, "Failed to record injection review: {}", e);
+    }
     if approved {
         // Publish the held event so the normal event-bus flow creates the run.
         event_repo::publish(
@@ -213,6 +233,39 @@ pub fn resolve_pending_trigger_fire(
     Ok(resolved)
 }
 
+/// Inbound payloads flagged by the prompt-injection scan, newest first.
+#[tauri::command]
+pub fn list_injection_detections(
+    state: State<'_, Arc<AppState>>,
+    review_status: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<InjectionDetection>, AppError> {
+    require_auth_sync(&state)?;
+    injection_repo::list(
+        &state.db,
+        review_status.as_deref(),
+        limit.unwrap_or(100).clamp(1, 1000),
+    )
+}
+
+/// Record a human verdict on a detection. `false_positive` lets the same
+/// payload through from then on (replay a dead-lettered event to deliver it).
+#[tauri::command]
+pub fn review_injection_detection(
+    state: State<'_, Arc<AppState>>,
+    id: String,
+    review_status: String,
+) -> Result<InjectionDetection, AppError> {
+    require_auth_sync(&state)?;
+    if !injection_repo::REVIEW_STATUSES.contains(&review_status.as_str()) {
+        return Err(AppError::Validation(format!(
+            "review_status must be one of {}, got {review_status:?}",
+            injection_repo::REVIEW_STATUSES.join("|")
+        )));
+    }
+    injection_repo::set_review_status(&state.db, &id, &review_status)
+}
+
 #[tauri::command]
 pub fn delete_trigger(
     state: State<'_, Arc<AppState>>,
@@ -313,48 +366,34 @@ pub async fn validate_trigger(
                 .or(config.get("cron_expression"))
                 .and_then(|v| v.as_str())
             {
-                match crate::engine::cron::parse_cron_seeded(
+                match crate::engine::cron::describe_cron(
                     cron_expr,
+                    config.get("timezone").and_then(|v| v.as_str()),
                     crate::engine::cron::seed_hash(&trigger.id),
                 ) {
-                    Ok(schedule) => {
-                        let tz_str = config.get("timezone").and_then(|v| v.as_str());
-                        let tz = tz_str.and_then(|s| s.parse::<chrono_tz::Tz>().ok());
-                        let next = match tz {
-                            Some(t) => crate::engine::cron::next_fire_time_in_tz(
-                                &schedule,
-                                chrono::Utc::now(),
-                                t,
+                    Ok(described) => {
+                        let message = match described.next_runs.first() {
+                            Some(t) => format!(
+                                "Valid -- {}; next fire: {}",
+                                described.description,
+                                format_in_zone(*t, &described.timezone)
                             ),
-                            None => crate::engine::cron::next_fire_time_local(
-                                &schedule,
-                                chrono::Utc::now(),
+                            None => format!(
+                                "Valid -- {} (no upcoming fire time)",
+                                described.description
                             ),
                         };
-                        let next_msg = next
-                            .map(|t| match tz {
-                                Some(zone) => format!(
-                                    "Valid -- next fire: {} ({})",
-                                    t.with_timezone(&zone).format("%Y-%m-%d %H:%M"),
-                                    zone.name()
-                                ),
-                                None => format!(
-                                    "Valid -- next fire: {}",
-                                    t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
-                                ),
-                            })
-                            .unwrap_or_else(|| "Valid syntax (no upcoming fire time)".into());
                         checks.push(TriggerValidationCheck {
                             label: "Cron syntax".into(),
                             passed: true,
-                            message: next_msg,
+                            message,
                         });
                     }
                     Err(e) => {
                         checks.push(TriggerValidationCheck {
                             label: "Cron syntax".into(),
                             passed: false,
-                            message: format!("Invalid cron: {e}"),
+                            message: e,
                         });
                     }
                 }
@@ -635,6 +674,48 @@ pub async fn validate_trigger(
                 });
             }
         }
+        "email_inbound" => {
+            let credential_id = config
+                .get("credential_id")
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty());
+            match credential_id {
+                Some(id) => {
+                    match crate::db::repos::resources::credentials::get_by_id(&state.db, id) {
+                        Ok(cred) => {
+                            let supported =
+                                matches!(cred.service_type.as_str(), "imap_smtp" | "gmail");
+                            checks.push(TriggerValidationCheck {
+                                label: "Mailbox credential".into(),
+                                passed: supported,
+                                message: if supported {
+                                    format!("{} ({})", cred.name, cred.service_type)
+                                } else {
+                                    format!(
+                                        "{} is a {} credential (need imap_smtp or gmail)",
+                                        cred.name, cred.service_type
+                                    )
+                                },
+                            });
+                        }
+                        Err(_) => {
+                            checks.push(TriggerValidationCheck {
+                                label: "Mailbox credential".into(),
+                                passed: false,
+                                message: format!("Credential '{id}' not found"),
+                            });
+                        }
+                    }
+                }
+                None => {
+                    checks.push(TriggerValidationCheck {
+                        label: "Mailbox credential".into(),
+                        passed: false,
+                        message: "No credential_id configured".into(),
+                    });
+                }
+            }
+        }
         "clipboard" => {
             let ct = config
                 .get("content_type")
@@ -881,6 +962,159 @@ pub fn preview_cron_schedule(
     })
 }
 
+/// Number of upcoming fire times returned by [`validate_cron`].
+const VALIDATE_CRON_RUNS: usize = 5;
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CronValidation {
+    pub valid: bool,
+    /// Human-readable schedule, including the zone and any exclusions.
+    pub description: String,
+    /// IANA zone the expression is evaluated in (the system zone when none
+    /// was supplied).
+    pub timezone: String,
+    /// Next fire times (RFC3339), with excluded slots already skipped.
+    pub next_runs: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Validate a schedule as the editor would save it: the cron expression, its
+/// IANA timezone and its exclusion windows. Unlike `preview_cron_schedule`,
+/// an unknown timezone is an error rather than a silent fallback to system
+/// local — the scheduler refuses such triggers, so the editor must too.
+///
+/// Returns the next five fire times the scheduler would actually use and a
+/// description such as "Daily at 9:00 AM (Europe/Prague), except weekends".
+#[tauri::command]
+pub fn validate_cron(
+    state: State<'_, Arc<AppState>>,
+    expression: String,
+    timezone: Option<String>,
+    exclusions: Option<Vec<ScheduleExclusion>>,
+    seed: Option<String>,
+) -> Result<CronValidation, AppError> {
+    require_auth_sync(&state)?;
+    let exclusions = exclusions.unwrap_or_default();
+    let invalid = |timezone: String, error: String| CronValidation {
+        valid: false,
+        description: String::new(),
+        timezone,
+        next_runs: vec![],
+        error: Some(error),
+    };
+
+    let tz = match crate::engine::scheduler::resolve_schedule_tz(timezone.as_deref()) {
+        Ok(tz) => tz,
+        Err(err) => {
+            return Ok(invalid(
+                err.raw.clone(),
+                format!("Unknown timezone \"{}\": {}", err.raw, err.message),
+            ));
+        }
+    };
+    let zone_name = match tz {
+        Some(tz) => tz.name().to_string(),
+        None => iana_time_zone::get_timezone().unwrap_or_else(|_| "Local".to_string()),
+    };
+
+    let seed_u64 = seed
+        .as_deref()
+        .map(crate::engine::cron::seed_hash)
+        .unwrap_or(0);
+    let schedule = match crate::engine::cron::parse_cron_seeded(&expression, seed_u64) {
+        Ok(s) => s,
+        Err(e) => return Ok(invalid(zone_name, format!("Invalid cron expression: {e}"))),
+    };
+    if let Some((i, msg)) = exclusions
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| e.validate().err().map(|msg| (i, msg)))
+    {
+        return Ok(invalid(zone_name, format!("Exclusion {}: {msg}", i + 1)));
+    }
+
+    let mut runs = Vec::with_capacity(VALIDATE_CRON_RUNS);
+    let mut from = chrono::Utc::now();
+    while runs.len() < VALIDATE_CRON_RUNS {
+        match crate::engine::scheduler::next_allowed_fire(&schedule, from, tz, &exclusions) {
+            Some(t) => {
+                runs.push(t.to_rfc3339());
+                from = t;
+            }
+            None => break,
+        }
+    }
+    if runs.is_empty() {
+        return Ok(invalid(
+            zone_name,
+            "Schedule never fires outside its exclusion windows".into(),
+        ));
+    }
+
+    let mut description = format!("{} ({zone_name})", cron_to_human(&expression));
+    if !exclusions.is_empty() {
+        let except: Vec<String> = exclusions.iter().map(|e| e.describe()).collect();
+        description.push_str(&format!(", except {}", except.join("; ")));
+    }
+
+    Ok(CronValidation {
+        valid: true,
+        description,
+        timezone: zone_name,
+        next_runs: runs,
+        error: None,
+    })
+}
+
+/// Describe a cron expression and list its next ten fire times, evaluated
+/// exactly as the scheduler will run it, so the editor can show when a
+/// schedule fires before it is saved. Exclusion windows are not applied; use
+/// `validate_cron` for a full schedule.
+#[tauri::command]
+pub fn describe_cron(
+    state: State<'_, Arc<AppState>>,
+    expression: String,
+    timezone: Option<String>,
+    seed: Option<String>,
+) -> Result<CronValidation, AppError> {
+    require_auth_sync(&state)?;
+    let seed_u64 = seed
+        .as_deref()
+        .map(crate::engine::cron::seed_hash)
+        .unwrap_or(0);
+    Ok(
+        match crate::engine::cron::describe_cron(&expression, timezone.as_deref(), seed_u64) {
+            Ok(described) => CronValidation {
+                valid: true,
+                description: described.description,
+                timezone: described.timezone,
+                next_runs: described.next_runs.iter().map(|t| t.to_rfc3339()).collect(),
+                error: None,
+            },
+            Err(error) => CronValidation {
+                valid: false,
+                description: String::new(),
+                timezone: timezone.unwrap_or_default(),
+                next_runs: vec![],
+                error: Some(error),
+            },
+        },
+    )
+}
+
+/// `t` as `YYYY-MM-DD HH:MM (zone)` in the named IANA zone, or system local
+/// when the name doesn't parse.
+fn format_in_zone(t: chrono::DateTime<chrono::Utc>, zone: &str) -> String {
+    match zone.parse::<chrono_tz::Tz>() {
+        Ok(tz) => format!("{} ({zone})", t.with_timezone(&tz).format("%Y-%m-%d %H:%M")),
+        Err(_) => format!(
+            "{} ({zone})",
+            t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
+        ),
+    }
+}
+
 /// Compute every cron fire time in `[start, end)`, evaluated in the supplied
 /// IANA timezone (or system-local when None). Used by the calendar UI which
 /// renders a windowed view of upcoming and past-projected fires.
@@ -969,134 +1203,6 @@ fn describe_schedule(cron: Option<&str>, interval_seconds: Option<u64>) -> Strin
         .unwrap_or_else(|| "No schedule configured".into())
 }
 
-/// Convert a 5-field cron expression to a human-readable string.
-fn cron_to_human(expr: &str) -> String {
-    let fields: Vec<&str> = expr.split_whitespace().collect();
-    if fields.len() != 5 {
-        return format!("Cron: {expr}");
-    }
-    let (min, hour, dom, mon, dow) = (fields[0], fields[1], fields[2], fields[3], fields[4]);
-
-    // Every minute
-    if min == "*" && hour == "*" && dom == "*" && mon == "*" && dow == "*" {
-        return "Every minute".into();
-    }
-
-    // Every N minutes
-    if min.starts_with("*/") && hour == "*" && dom == "*" && mon == "*" && dow == "*" {
-        let n = &min[2..];
-        return format!("Every {n} minutes");
-    }
-
-    // Every N hours
-    if min == "0" && hour.starts_with("*/") && dom == "*" && mon == "*" && dow == "*" {
-        let n = &hour[2..];
-        return format!("Every {n} hours");
-    }
-
-    // Specific time patterns
-    let time_str = format_time_from_cron(min, hour);
-
-    // Daily at specific time
-    if dom == "*" && mon == "*" && dow == "*" {
-        return format!("Daily at {time_str}");
-    }
-
-    // Specific days of week
-    if dom == "*" && mon == "*" && dow != "*" {
-        let days = format_dow(dow);
-        return format!("Every {days} at {time_str}");
-    }
-
-    // Specific day of month
-    if dom != "*" && mon == "*" && dow == "*" {
-        let ordinal = format_dom(dom);
-        return format!("Monthly on the {ordinal} at {time_str}");
-    }
-
-    // Fallback
-    format!("Cron: {expr}")
-}
-
-fn format_time_from_cron(min: &str, hour: &str) -> String {
-    let h: u32 = hour.parse().unwrap_or(0);
-    let m: u32 = min.parse().unwrap_or(0);
-    let (h12, ampm) = if h == 0 {
-        (12, "AM")
-    } else if h < 12 {
-        (h, "AM")
-    } else if h == 12 {
-        (12, "PM")
-    } else {
-        (h - 12, "PM")
-    };
-    if m == 0 {
-        format!("{h12} {ampm}")
-    } else {
-        format!("{h12}:{m:02} {ampm}")
-    }
-}
-
-fn format_dow(dow: &str) -> String {
-    const DAYS: &[&str] = &[
-        "Sunday",
-        "Monday",
-        "Tuesday",
-        "Wednesday",
-        "Thursday",
-        "Friday",
-        "Saturday",
-    ];
-    let parts: Vec<&str> = dow.split(',').collect();
-
-    // Common patterns
-    if dow == "1-5" {
-        return "weekday".into();
-    }
-    if dow == "0,6" || dow == "6,0" {
-        return "weekend".into();
-    }
-
-    let names: Vec<String> = parts
-        .iter()
-        .filter_map(|p| {
-            if p.contains('-') {
-                // Range like 1-5
-                let bounds: Vec<&str> = p.splitn(2, '-').collect();
-                let lo: usize = bounds[0].parse().unwrap_or(0);
-                let hi: usize = bounds[1].parse().unwrap_or(6);
-                if lo <= hi && hi < 7 {
-                    let start = DAYS.get(lo).unwrap_or(&"?");
-                    let end = DAYS.get(hi).unwrap_or(&"?");
-                    Some(format!("{start}-{end}"))
-                } else {
-                    None
-                }
-            } else {
-                let idx: usize = p.trim().parse().unwrap_or(8);
-                DAYS.get(idx).map(|d| d.to_string())
-            }
-        })
-        .collect();
-
-    if names.len() == 1 {
-        names[0].clone()
-    } else {
-        names.join(", ")
-    }
-}
-
-fn format_dom(dom: &str) -> String {
-    let d: u32 = dom.parse().unwrap_or(0);
-    let suffix = match d % 10 {
-        1 if d != 11 => "st",
-        2 if d != 12 => "nd",
-        3 if d != 13 => "rd",
-        _ => "th",
-    };
-    format!("{d}{suffix}")
-}
-
 // =============================================================================
 // Builder: persona <-> event linking
 // See docs/design/event-routing-proposal.md
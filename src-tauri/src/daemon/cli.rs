@@ -0,0 +1,199 @@
+//! Operations behind the `personas-cli` binary: list, export and run personas
+//! against the app's own database while the windowed app is closed -- for
+//! cron jobs and CI.
+//!
+//! Runs go through [`runtime::run_and_record`](super::runtime::run_and_record),
+//! the same headless path the daemon uses, so an execution started here shows
+//! up in the app's history like any other.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use crate::commands::core::import_export::build_persona_bundle;
+use crate::db::models::Persona;
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::resources::tools as tool_repo;
+use crate::db::DbPool;
+use crate::engine::failover::ProviderCircuitBreaker;
+use crate::engine::types::ExecutionState;
+use crate::error::AppError;
+
+/// A row of `personas-cli list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PersonaListing {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub headless: bool,
+}
+
+/// Outcome of `personas-cli execute`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CliExecution {
+    pub execution_id: String,
+    pub persona_id: String,
+    pub persona_name: String,
+    pub status: ExecutionState,
+    pub output: Option<String>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+    pub cost_usd: f64,
+}
+
+impl CliExecution {
+    pub fn succeeded(&self) -> bool {
+        self.status == ExecutionState::Completed
+    }
+}
+
+pub fn list_personas(pool: &DbPool) -> Result<Vec<PersonaListing>, AppError> {
+    Ok(persona_repo::get_all(pool)?
+        .into_iter()
+        .map(|p| PersonaListing {
+            id: p.id,
+            name: p.name,
+            description: p.description,
+            enabled: p.enabled,
+            headless: p.headless,
+        })
+        .collect())
+}
+
+/// Find a persona by id, or else by name (case-insensitive). A name shared
+/// by several personas is rejected; the caller has to pass the id.
+fn resolve_persona(pool: &DbPool, reference: &str) -> Result<Persona, AppError> {
+    if let Ok(persona) = persona_repo::get_by_id(pool, reference) {
+        return Ok(persona);
+    }
+    let mut matches: Vec<Persona> = persona_repo::get_all(pool)?
+        .into_iter()
+        .filter(|p| p.name.eq_ignore_ascii_case(reference.trim()))
+        .collect();
+    match matches.len() {
+        0 => Err(AppError::NotFound(format!("Persona {reference}"))),
+        1 => Ok(matches.remove(0)),
+        n => Err(AppError::Validation(format!(
+            "{n} personas are named '{reference}'; pass the persona id instead"
+        ))),
+    }
+}
+
+/// The persona as a pretty-printed `.persona.json` bundle -- the same format
+/// the app's Export button writes and its importer reads.
+pub fn export_persona(pool: &DbPool, reference: &str) -> Result<String, AppError> {
+    let persona = resolve_persona(pool, reference)?;
+    let bundle = build_persona_bundle(pool, &persona.id)?;
+    serde_json::to_string_pretty(&bundle).map_err(|e| AppError::Internal(e.to_string()))
+}
+
+/// Create an execution for the persona and run it to completion.
+pub async fn execute_persona(
+    pool: &DbPool,
+    log_dir: &Path,
+    reference: &str,
+    input_data: Option<serde_json::Value>,
+) -> Result<CliExecution, AppError> {
+    let persona = resolve_persona(pool, reference)?;
+    if !persona.enabled {
+        return Err(AppError::Validation(format!(
+            "Persona '{}' is disabled",
+            persona.name
+        )));
+    }
+    let tools = tool_repo::get_tools_for_persona(pool, &persona.id).unwrap_or_default();
+    let input_str = input_data.as_ref().map(|v| v.to_string());
+    let exec = exec_repo::create(pool, &persona.id, None, input_str, None, None)?;
+
+    let persona_id = persona.id.clone();
+    let persona_name = persona.name.clone();
+    let child_pids: Arc<Mutex<HashMap<String, u32>>> = Arc::new(Mutex::new(HashMap::new()));
+    let result = super::runtime::run_and_record(
+        pool,
+        log_dir,
+        &Arc::new(ProviderCircuitBreaker::new()),
+        &child_pids,
+        &exec.id,
+        persona,
+        tools,
+        input_data,
+    )
+    .await;
+
+    Ok(CliExecution {
+        execution_id: exec.id,
+        persona_id,
+        persona_name,
+        status: if result.success {
+            ExecutionState::Completed
+        } else {
+            ExecutionState::Failed
+        },
+        output: result.output,
+        error: result.error,
+        duration_ms: result.duration_ms,
+        cost_usd: result.cost_usd,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::models::CreatePersonaInput;
+
+    fn make_persona(pool: &DbPool, name: &str) -> String {
+        persona_repo::create(
+            pool,
+            CreatePersonaInput {
+                name: name.into(),
+                system_prompt: "test".into(),
+                project_id: None,
+                description: None,
+                structured_prompt: None,
+                icon: None,
+                color: None,
+                enabled: Some(true),
+                max_concurrent: None,
+                timeout_ms: None,
+                model_profile: None,
+                max_budget_usd: None,
+                max_turns: None,
+                design_context: None,
+                notification_channels: None,
+                lifecycle: None,
+            },
+        )
+        .unwrap()
+        .id
+    }
+
+    #[test]
+    fn personas_resolve_by_id_or_unique_name() {
+        let pool = init_test_db().unwrap();
+        let digest = make_persona(&pool, "Daily Digest");
+        make_persona(&pool, "Triage");
+        make_persona(&pool, "triage");
+
+        assert_eq!(resolve_persona(&pool, &digest).unwrap().id, digest);
+        assert_eq!(resolve_persona(&pool, "daily digest").unwrap().id, digest);
+        assert!(matches!(
+            resolve_persona(&pool, "Triage"),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            resolve_persona(&pool, "Nobody"),
+            Err(AppError::NotFound(_))
+        ));
+
+        let listed = list_personas(&pool).unwrap();
+        assert_eq!(listed.len(), 3);
+        let exported = export_persona(&pool, "Daily Digest").unwrap();
+        assert!(exported.contains("\"Daily Digest\""));
+    }
+}
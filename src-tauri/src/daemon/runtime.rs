@@ -19,7 +19,9 @@ use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use crate::db::models::{PersonaEventStatus, UpdateExecutionStatus};
+use crate::db::models::{
+    Persona, PersonaEventStatus, PersonaToolDefinition, UpdateExecutionStatus,
+};
 use crate::db::repos::communication::events as event_repo;
 use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::execution::executions as exec_repo;
@@ -29,7 +31,7 @@ use crate::engine::background::SchedulerState;
 use crate::engine::events::NoOpEmitter;
 use crate::engine::failover::ProviderCircuitBreaker;
 use crate::engine::runner;
-use crate::engine::types::ExecutionState;
+use crate::engine::types::{ExecutionResult, ExecutionState};
 
 use super::lock::TriggerKind;
 
@@ -171,71 +173,18 @@ async fn consume_headless_events(
             .as_ref()
             .and_then(|s| serde_json::from_str(s).ok());
 
-        // NoOp emitter — daemon has no UI
-        let emitter: Arc<dyn crate::engine::events::ExecutionEventEmitter> =
-            Arc::new(NoOpEmitter::new());
-        let cancelled = Arc::new(AtomicBool::new(false));
-
-        // Phase 3 c v3: inject ambient desktop signals captured by
-        // the windowed app's clipboard/app_focus monitors. Cross-
-        // process bridge — signals reach the daemon via the
-        // ambient_signal SQL projection (see ambient_signal_repo).
-        // Same shadow shape as the windowed runner's injection in
-        // engine/mod.rs::run_execution_with_ceiling, for byte-
-        // identical prompt rendering between the two paths.
-        //
-        // Phase 5 v1: After ambient injection, also try to inject
-        // the user's active Claude CLI session — gated by both the
-        // persona's `cli_awareness_enabled` and the persisted global
-        // `cli_session_awareness_enabled` flag in app_settings. The
-        // daemon reads the global gate from SQL because it has no
-        // access to AmbientContextFusion's in-memory state.
-        #[cfg(feature = "desktop")]
-        let persona = {
-            let mut persona = persona;
-            inject_ambient_for_daemon(pool, &mut persona);
-            inject_cli_session_for_daemon(pool, &mut persona);
-            persona
-        };
-
-        // Execute synchronously (Phase 0 — serial, one at a time)
-        let result = runner::run_execution(
-            emitter,
-            pool.clone(),
-            exec.id.clone(),
+        let result = run_and_record(
+            pool,
+            log_dir,
+            circuit_breaker,
+            child_pids,
+            &exec.id,
             persona,
             tools,
             input_data,
-            log_dir.to_path_buf(),
-            child_pids.clone(),
-            cancelled,
-            None, // no continuation
-            None, // no chain trace
-            circuit_breaker.clone(),
         )
         .await;
 
-        // Update execution status in DB
-        let status = if result.success {
-            ExecutionState::Completed
-        } else {
-            ExecutionState::Failed
-        };
-        let _ = exec_repo::update_status(
-            pool,
-            &exec.id,
-            UpdateExecutionStatus {
-                status,
-                error_message: result.error.clone(),
-                duration_ms: Some(result.duration_ms as i64),
-                cost_usd: Some(result.cost_usd),
-                output_tokens: Some(result.output_tokens as i64),
-                input_tokens: Some(result.input_tokens as i64),
-                log_file_path: result.log_file_path.clone(),
-                ..Default::default()
-            },
-        );
-
         if result.success {
             tracing::info!(
                 execution_id = %exec.id,
@@ -256,6 +205,88 @@ async fn consume_headless_events(
     executed
 }
 
+/// Run an execution row to completion and write its outcome back, with no UI
+/// attached. Shared by the event consumer above and the `personas-cli`
+/// `execute` command.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_and_record(
+    pool: &DbPool,
+    log_dir: &Path,
+    circuit_breaker: &Arc<ProviderCircuitBreaker>,
+    child_pids: &Arc<Mutex<HashMap<String, u32>>>,
+    execution_id: &str,
+    persona: Persona,
+    tools: Vec<PersonaToolDefinition>,
+    input_data: Option<serde_json::Value>,
+) -> ExecutionResult {
+    // NoOp emitter — the daemon and the CLI have no UI
+    let emitter: Arc<dyn crate::engine::events::ExecutionEventEmitter> =
+        Arc::new(NoOpEmitter::new());
+    let cancelled = Arc::new(AtomicBool::new(false));
+
+    // Phase 3 c v3: inject ambient desktop signals captured by
+    // the windowed app's clipboard/app_focus monitors. Cross-
+    // process bridge — signals reach the daemon via the
+    // ambient_signal SQL projection (see ambient_signal_repo).
+    // Same shadow shape as the windowed runner's injection in
+    // engine/mod.rs::run_execution_with_ceiling, for byte-
+    // identical prompt rendering between the two paths.
+    //
+    // Phase 5 v1: After ambient injection, also try to inject
+    // the user's active Claude CLI session — gated by both the
+    // persona's `cli_awareness_enabled` and the persisted global
+    // `cli_session_awareness_enabled` flag in app_settings. The
+    // daemon reads the global gate from SQL because it has no
+    // access to AmbientContextFusion's in-memory state.
+    #[cfg(feature = "desktop")]
+    let persona = {
+        let mut persona = persona;
+        inject_ambient_for_daemon(pool, &mut persona);
+        inject_cli_session_for_daemon(pool, &mut persona);
+        persona
+    };
+
+    // Execute synchronously (Phase 0 — serial, one at a time)
+    let result = runner::run_execution(
+        emitter,
+        pool.clone(),
+        execution_id.to_string(),
+        persona,
+        tools,
+        input_data,
+        log_dir.to_path_buf(),
+        child_pids.clone(),
+        cancelled,
+        None, // no continuation
+        None, // no chain trace
+        circuit_breaker.clone(),
+    )
+    .await;
+
+    // Update execution status in DB
+    let status = if result.success {
+        ExecutionState::Completed
+    } else {
+        ExecutionState::Failed
+    };
+    let _ = exec_repo::update_status(
+        pool,
+        execution_id,
+        UpdateExecutionStatus {
+            status,
+            output_data: result.output.clone(),
+            error_message: result.error.clone(),
+            duration_ms: Some(result.duration_ms as i64),
+            cost_usd: Some(result.cost_usd),
+            output_tokens: Some(result.output_tokens as i64),
+            input_tokens: Some(result.input_tokens as i64),
+            log_file_path: result.log_file_path.clone(),
+            ..Default::default()
+        },
+    );
+    result
+}
+
 /// Phase 3 c v3 — daemon-side ambient context injection.
 ///
 /// The windowed app's `AmbientContextFusion` is in-memory only, so
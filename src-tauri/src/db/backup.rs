@@ -21,9 +21,36 @@
 //! - Everything here is best-effort: a full disk, locked file, or ACL
 //!   problem logs a warning and boot continues. A failed backup must
 //!   never be worse than the risk it protects against.
+//!
+//! The same directory also holds on-demand snapshots (`backup_database`,
+//! `manual-*`) and scheduled ones (`auto-*`, see [`run_auto_backup`]). Those
+//! are taken from the live pool with `VACUUM INTO`, which yields a compact,
+//! transactionally consistent copy without pausing writers, and may be sealed
+//! with a passphrase (`*.pbak`: PBKDF2-HMAC-SHA256 → AES-256-GCM).
+//!
+//! Restores are staged, never applied to the open database: the chosen backup
+//! is validated and copied to `personas.db.restore-pending`, and the next boot
+//! swaps it in (after the usual pre-migration snapshot of the file it
+//! replaces, so a restore is itself reversible).
 
 use std::path::{Path, PathBuf};
 
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use pbkdf2::pbkdf2_hmac;
+use serde::Serialize;
+use sha2::Sha256;
+use ts_rs::TS;
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys::{
+    AUTO_BACKUP_ENABLED, AUTO_BACKUP_ENABLED_DEFAULT, AUTO_BACKUP_INTERVAL_HOURS,
+    AUTO_BACKUP_INTERVAL_HOURS_DEFAULT, AUTO_BACKUP_LAST,
+};
+use crate::db::DbPool;
+use crate::error::AppError;
+
 /// How many backup sets (newest first) survive rotation.
 const MAX_BACKUPS: usize = 3;
 
@@ -34,7 +61,70 @@ const BACKUP_DIR_NAME: &str = "backups";
 /// `foo.db`'s WAL is `foo.db-wal`, and `Path::with_extension("db-wal")` on
 /// a `.db` path produces exactly that shape — so a restored backup keeps
 /// the sidecar naming SQLite expects.
-const SIDECAR_EXTENSIONS: [&str; 2] = ["db-wal", "db-shm"];
+pub(super) const SIDECAR_EXTENSIONS: [&str; 2] = ["db-wal", "db-shm"];
+
+/// File-name prefixes per backup kind. Pre-migration snapshots keep the
+/// historical `personas-` prefix so [`rotate_backups`] never touches the rest.
+const MANUAL_PREFIX: &str = "manual-";
+const AUTO_PREFIX: &str = "auto-";
+const PRE_MIGRATION_PREFIX: &str = "personas-";
+
+/// How many scheduled backups survive rotation. Manual backups are never
+/// rotated — the user asked for them and deletes them explicitly.
+const MAX_AUTO_BACKUPS: usize = 7;
+
+/// Extension and header of passphrase-sealed backups:
+/// `MAGIC | salt(16) | nonce(12) | AES-256-GCM ciphertext`.
+const ENCRYPTED_EXTENSION: &str = "pbak";
+const ENCRYPTED_MAGIC: &[u8; 8] = b"PBAKv001";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// Same work factor as the credential export bundle.
+const PBKDF2_ITERATIONS: u32 = 600_000;
+const MIN_PASSPHRASE_LEN: usize = 8;
+
+/// Staged restore, swapped over `personas.db` on the next boot.
+const PENDING_RESTORE_NAME: &str = "personas.db.restore-pending";
+
+/// One backup file on disk.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct DatabaseBackupInfo {
+    pub path: String,
+    pub file_name: String,
+    pub size_bytes: u64,
+    /// RFC3339 modification time of the file.
+    pub created_at: String,
+    /// `manual` | `auto` | `pre_migration`.
+    pub kind: String,
+    /// True for passphrase-sealed `.pbak` files.
+    pub encrypted: bool,
+}
+
+/// Outcome of `restore_database`: the backup was validated and staged.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct DatabaseRestoreResult {
+    pub source_path: String,
+    /// Always true — the restore is applied on the next launch.
+    pub restart_required: bool,
+}
+
+/// Which loop or command produced a backup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupKind {
+    Manual,
+    Auto,
+}
+
+impl BackupKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Manual => MANUAL_PREFIX,
+            Self::Auto => AUTO_PREFIX,
+        }
+    }
+}
 
 /// Snapshot `db_path` into `<app_data_dir>/backups/personas-<stamp>-<nn>.db`
 /// (+ WAL/SHM siblings if present), then rotate old sets. Returns the path
@@ -196,3 +286,289 @@ fn rotate_backups(backup_dir: &Path) {
         tracing::debug!(path = %old.display(), "Rotated out old pre-migration backup");
     }
 }
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ITERATIONS, &mut key);
+    key
+}
+
+fn seal(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>, AppError> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let cipher = Aes256Gcm::new_from_slice(&derive_key(passphrase, &salt))
+        .map_err(|e| AppError::Internal(format!("Cipher init failed: {e}")))?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|e| AppError::Internal(format!("Backup encryption failed: {e}")))?;
+
+    let mut out = Vec::with_capacity(ENCRYPTED_MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(ENCRYPTED_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn unseal(sealed: &[u8], passphrase: &str) -> Result<Vec<u8>, AppError> {
+    let header = ENCRYPTED_MAGIC.len() + SALT_LEN + NONCE_LEN;
+    if sealed.len() <= header || &sealed[..ENCRYPTED_MAGIC.len()] != ENCRYPTED_MAGIC {
+        return Err(AppError::Validation("Not an encrypted Personas backup".into()));
+    }
+    let salt = &sealed[ENCRYPTED_MAGIC.len()..ENCRYPTED_MAGIC.len() + SALT_LEN];
+    let nonce = &sealed[ENCRYPTED_MAGIC.len() + SALT_LEN..header];
+    let cipher = Aes256Gcm::new_from_slice(&derive_key(passphrase, salt))
+        .map_err(|e| AppError::Internal(format!("Cipher init failed: {e}")))?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), &sealed[header..])
+        .map_err(|_| AppError::Validation("Wrong passphrase or corrupted backup".into()))
+}
+
+fn backup_info(path: &Path) -> Option<DatabaseBackupInfo> {
+    let file_name = path.file_name()?.to_str()?.to_string();
+    let encrypted = path.extension().is_some_and(|x| x == ENCRYPTED_EXTENSION);
+    if !encrypted && !path.extension().is_some_and(|x| x == "db") {
+        return None;
+    }
+    let kind = if file_name.starts_with(MANUAL_PREFIX) {
+        "manual"
+    } else if file_name.starts_with(AUTO_PREFIX) {
+        "auto"
+    } else if file_name.starts_with(PRE_MIGRATION_PREFIX) {
+        "pre_migration"
+    } else {
+        return None;
+    };
+    let meta = std::fs::metadata(path).ok()?;
+    let created_at = meta
+        .modified()
+        .ok()
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+        .unwrap_or_default();
+    Some(DatabaseBackupInfo {
+        path: path.to_string_lossy().into_owned(),
+        file_name,
+        size_bytes: meta.len(),
+        created_at,
+        kind: kind.to_string(),
+        encrypted,
+    })
+}
+
+/// Snapshot the live database into `<app_data_dir>/backups/` with
+/// `VACUUM INTO`. With a passphrase the snapshot is sealed into a `.pbak` file
+/// and the plaintext copy is removed. Scheduled backups are rotated down to
+/// [`MAX_AUTO_BACKUPS`].
+pub fn create_backup(
+    pool: &DbPool,
+    app_data_dir: &Path,
+    kind: BackupKind,
+    passphrase: Option<&str>,
+) -> Result<DatabaseBackupInfo, AppError> {
+    if let Some(p) = passphrase {
+        if p.len() < MIN_PASSPHRASE_LEN {
+            return Err(AppError::Validation(format!(
+                "Passphrase must be at least {MIN_PASSPHRASE_LEN} characters"
+            )));
+        }
+    }
+    let backup_dir = app_data_dir.join(BACKUP_DIR_NAME);
+    std::fs::create_dir_all(&backup_dir)?;
+
+    // Millisecond stamp keeps names unique and lexicographic == chronological.
+    let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S-%3f");
+    let db_file = backup_dir.join(format!("{}{stamp}.db", kind.prefix()));
+    {
+        let conn = pool.get()?;
+        conn.execute("VACUUM INTO ?1", [db_file.to_string_lossy().as_ref()])?;
+    }
+
+    let final_path = match passphrase {
+        Some(p) => {
+            let sealed = std::fs::read(&db_file)
+                .map_err(AppError::from)
+                .and_then(|bytes| seal(&bytes, p));
+            // Never leave the plaintext copy behind, sealed or not.
+            let _ = std::fs::remove_file(&db_file);
+            let sealed_path = db_file.with_extension(ENCRYPTED_EXTENSION);
+            std::fs::write(&sealed_path, sealed?)?;
+            sealed_path
+        }
+        None => db_file,
+    };
+
+    super::restrict_file_permissions_impl(&final_path);
+
+    if kind == BackupKind::Auto {
+        rotate_prefixed(&backup_dir, AUTO_PREFIX, MAX_AUTO_BACKUPS);
+    }
+    tracing::info!(path = %final_path.display(), kind = kind.prefix(), "Database backup created");
+    backup_info(&final_path)
+        .ok_or_else(|| AppError::Internal("Backup written but could not be read back".into()))
+}
+
+/// Every backup in `<app_data_dir>/backups/`, newest first.
+pub fn list_backups(app_data_dir: &Path) -> Vec<DatabaseBackupInfo> {
+    let mut out: Vec<DatabaseBackupInfo> = std::fs::read_dir(app_data_dir.join(BACKUP_DIR_NAME))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| backup_info(&e.path()))
+                .collect()
+        })
+        .unwrap_or_default();
+    out.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.file_name.cmp(&a.file_name)));
+    out
+}
+
+/// Keep the newest `keep` files starting with `prefix` (either extension).
+fn rotate_prefixed(backup_dir: &Path, prefix: &str, keep: usize) {
+    let Ok(entries) = std::fs::read_dir(backup_dir) else {
+        return;
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(prefix))
+        })
+        .collect();
+    if files.len() <= keep {
+        return;
+    }
+    files.sort_by_key(|p| p.file_name().map(|n| n.to_os_string()));
+    let excess = files.len() - keep;
+    for old in files.into_iter().take(excess) {
+        if let Err(e) = std::fs::remove_file(&old) {
+            tracing::warn!(path = %old.display(), error = %e, "Backup rotation could not delete an old backup (non-fatal)");
+        }
+    }
+}
+
+/// Confirm `path` is a healthy Personas database: `PRAGMA quick_check` passes
+/// and the core `personas` table exists.
+pub(super) fn validate_database_file(path: &Path) -> Result<(), AppError> {
+    let conn = rusqlite::Connection::open_with_flags(
+        path,
+        rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| AppError::Validation(format!("Backup is not a readable SQLite database: {e}")))?;
+    let check: String = conn
+        .query_row("PRAGMA quick_check", [], |r| r.get(0))
+        .map_err(|e| AppError::Validation(format!("Backup integrity check failed: {e}")))?;
+    if check != "ok" {
+        return Err(AppError::Validation(format!(
+            "Backup integrity check failed: {check}"
+        )));
+    }
+    let has_personas: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'personas'",
+        [],
+        |r| r.get(0),
+    )?;
+    if has_personas == 0 {
+        return Err(AppError::Validation(
+            "File is a SQLite database but not a Personas backup".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validate `source` (decrypting `.pbak` files with `passphrase`) and stage it
+/// as `personas.db.restore-pending`. The live database is untouched until the
+/// next boot applies the staged file.
+pub fn stage_restore(
+    app_data_dir: &Path,
+    source: &Path,
+    passphrase: Option<&str>,
+) -> Result<DatabaseRestoreResult, AppError> {
+    if !source.is_file() {
+        return Err(AppError::NotFound(format!(
+            "Backup file not found: {}",
+            source.display()
+        )));
+    }
+    let pending = app_data_dir.join(PENDING_RESTORE_NAME);
+    if source.extension().is_some_and(|x| x == ENCRYPTED_EXTENSION) {
+        let passphrase = passphrase.ok_or_else(|| {
+            AppError::Validation("This backup is encrypted; a passphrase is required".into())
+        })?;
+        let plaintext = unseal(&std::fs::read(source)?, passphrase)?;
+        std::fs::write(&pending, plaintext)?;
+    } else {
+        std::fs::copy(source, &pending)?;
+    }
+
+    if let Err(e) = validate_database_file(&pending) {
+        let _ = std::fs::remove_file(&pending);
+        return Err(e);
+    }
+    tracing::info!(source = %source.display(), "Database restore staged for next launch");
+    Ok(DatabaseRestoreResult {
+        source_path: source.to_string_lossy().into_owned(),
+        restart_required: true,
+    })
+}
+
+/// Swap a staged restore over `db_path`. Called by `init_db` after the
+/// pre-migration snapshot and before any connection opens the file. The old
+/// WAL/SHM sidecars are dropped first — SQLite would otherwise try to replay
+/// the previous database's WAL onto the restored file. Best-effort like the
+/// rest of boot: on failure the current database stays in place.
+pub(super) fn apply_pending_restore(app_data_dir: &Path, db_path: &Path) {
+    let pending = app_data_dir.join(PENDING_RESTORE_NAME);
+    if !pending.exists() {
+        return;
+    }
+    for ext in SIDECAR_EXTENSIONS {
+        let sidecar = db_path.with_extension(ext);
+        if sidecar.exists() {
+            if let Err(e) = std::fs::remove_file(&sidecar) {
+                tracing::warn!(path = %sidecar.display(), error = %e, "Staged restore skipped — could not remove SQLite sidecar");
+                return;
+            }
+        }
+    }
+    match std::fs::rename(&pending, db_path) {
+        Ok(()) => tracing::info!(path = %db_path.display(), "Staged database restore applied"),
+        Err(e) => tracing::warn!(error = %e, "Staged database restore failed — keeping the current database"),
+    }
+}
+
+/// Scheduled-backup entry point for `AutoBackupSubscription`: takes an
+/// unencrypted `auto-*` snapshot when enabled and the configured interval has
+/// elapsed. Returns the backup when one was written.
+pub fn run_auto_backup(pool: &DbPool) -> Result<Option<DatabaseBackupInfo>, AppError> {
+    let enabled = settings::get(pool, AUTO_BACKUP_ENABLED)?
+        .map(|v| v == "true")
+        .unwrap_or(AUTO_BACKUP_ENABLED_DEFAULT);
+    if !enabled {
+        return Ok(None);
+    }
+    let Some(app_data_dir) = super::primary_db_path().and_then(|p| p.parent().map(Path::to_path_buf))
+    else {
+        return Ok(None);
+    };
+    let interval_hours = settings::get(pool, AUTO_BACKUP_INTERVAL_HOURS)?
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(AUTO_BACKUP_INTERVAL_HOURS_DEFAULT);
+    let due = settings::get(pool, AUTO_BACKUP_LAST)?
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+        .map(|prev| {
+            chrono::Utc::now()
+                .signed_duration_since(prev.with_timezone(&chrono::Utc))
+                .num_hours()
+                >= i64::from(interval_hours)
+        })
+        .unwrap_or(true);
+    if !due {
+        return Ok(None);
+    }
+    let info = create_backup(pool, &app_data_dir, BackupKind::Auto, None)?;
+    settings::set(pool, AUTO_BACKUP_LAST, &chrono::Utc::now().to_rfc3339())?;
+    Ok(Some(info))
+}
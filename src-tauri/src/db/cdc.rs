@@ -409,7 +409,7 @@ fn map_persona_event_row(
 
     let (payload, error_message) = match (raw_payload, payload_iv) {
         (Some(ct), Some(ref iv)) if !iv.is_empty() => {
-            match crate::engine::crypto::decrypt_from_db(&ct, iv) {
+            match crate::engine::project_keys::decrypt(&ct, iv) {
                 Ok(pt) => (Some(pt), raw_error),
                 Err(e) => {
                     tracing::warn!("CDC: failed to decrypt event payload: {}", e);
@@ -0,0 +1,265 @@
+//! Builtin connector seeding with upgrade diffing.
+//!
+//! Definitions are auto-generated from `scripts/connectors/builtin/*.json`
+//! into `db/builtin_connectors.rs`. Regenerate with:
+//!   node scripts/generate-connector-seed.mjs
+//!
+//! Each seeded row stores a SHA-256 of the definition it was written from
+//! (`connector_definitions.seed_hash`). On boot only connectors that are new
+//! or whose definition hash changed are written; the rest are skipped. The
+//! pass runs on its own thread alongside the remaining startup work
+//! ([`spawn`]) instead of blocking `init_db`, and its outcome — which
+//! connectors were inserted or upgraded — is logged and kept for
+//! `get_seed_report`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use rusqlite::params;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use ts_rs::TS;
+
+use super::builtin_connectors::{BuiltinConnector, BUILTIN_CONNECTORS};
+use super::DbPool;
+use crate::error::AppError;
+
+/// Connector retired from the builtin set; removed from any DB a prior build
+/// seeded. The embedded-Pumper scraper now talks to the app via Signals
+/// (event bus), not a connector or persona-invoked MCP tools (Phase 1c).
+const RETIRED_CONNECTOR_IDS: &[&str] = &["builtin-local-scraper"];
+
+static LAST_REPORT: Mutex<Option<ConnectorSeedReport>> = Mutex::new(None);
+
+/// Outcome of the last builtin connector seeding pass.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct ConnectorSeedReport {
+    pub started_at: String,
+    #[ts(type = "number")]
+    pub duration_ms: u64,
+    /// Builtin definitions shipped with this build.
+    pub total: usize,
+    /// Connectors seeded for the first time.
+    pub inserted: Vec<String>,
+    /// Connectors whose stored definition was rewritten.
+    pub upgraded: Vec<String>,
+    /// Connectors skipped because their definition is unchanged.
+    pub unchanged: usize,
+    /// Retired connectors removed.
+    pub removed: Vec<String>,
+    /// Set when the pass failed; rows written before the failure are rolled back.
+    pub error: Option<String>,
+}
+
+/// SHA-256 over every seeded column of a definition.
+fn content_hash(c: &BuiltinConnector) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        Some(c.id),
+        Some(c.name),
+        Some(c.label),
+        Some(c.color),
+        Some(c.icon_url),
+        Some(c.category),
+        Some(c.fields),
+        c.healthcheck_config,
+        Some(c.services),
+        Some(c.events),
+        c.metadata,
+        c.resources,
+    ] {
+        // Length-prefixed so adjacent fields cannot run into each other, and
+        // `None` stays distinct from an empty string.
+        match part {
+            Some(s) => {
+                hasher.update((s.len() as u64).to_le_bytes());
+                hasher.update(s.as_bytes());
+            }
+            None => hasher.update(u64::MAX.to_le_bytes()),
+        }
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Seed new builtin connectors and upgrade changed ones in one transaction.
+pub(crate) fn seed(conn: &rusqlite::Connection) -> Result<ConnectorSeedReport, AppError> {
+    let start = Instant::now();
+    let mut report = ConnectorSeedReport {
+        started_at: chrono::Utc::now().to_rfc3339(),
+        total: BUILTIN_CONNECTORS.len(),
+        ..Default::default()
+    };
+    let now = chrono::Utc::now().to_rfc3339();
+    let tx = conn.unchecked_transaction()?;
+
+    for id in RETIRED_CONNECTOR_IDS {
+        if tx.execute(
+            "DELETE FROM connector_definitions WHERE id = ?1 AND is_builtin = 1",
+            params![id],
+        )? > 0
+        {
+            report.removed.push((*id).to_string());
+        }
+    }
+
+    let stored: HashMap<String, Option<String>> = {
+        let mut stmt =
+            tx.prepare("SELECT name, seed_hash FROM connector_definitions WHERE is_builtin = 1")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<_, _>>()?
+    };
+
+    for c in BUILTIN_CONNECTORS {
+        let hash = content_hash(c);
+        match stored.get(c.name) {
+            Some(Some(existing)) if *existing == hash => {
+                report.unchanged += 1;
+            }
+            Some(_) => {
+                tx.execute(
+                    "UPDATE connector_definitions
+                     SET label = ?1, icon_url = ?2, fields = ?3, healthcheck_config = ?4, metadata = ?5,
+                         category = ?6, services = ?7, events = ?8, resources = ?9, seed_hash = ?10,
+                         updated_at = ?11
+                     WHERE name = ?12 AND is_builtin = 1",
+                    params![
+                        c.label,
+                        c.icon_url,
+                        c.fields,
+                        c.healthcheck_config,
+                        c.metadata,
+                        c.category,
+                        c.services,
+                        c.events,
+                        c.resources,
+                        hash,
+                        now,
+                        c.name
+                    ],
+                )?;
+                report.upgraded.push(c.name.to_string());
+            }
+            None => {
+                tx.execute(
+                    "INSERT OR IGNORE INTO connector_definitions
+                     (id, name, label, icon_url, color, category, fields,
+                      healthcheck_config, services, events, metadata, resources, is_builtin,
+                      seed_hash, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, 1, ?13, ?14, ?14)",
+                    params![
+                        c.id,
+                        c.name,
+                        c.label,
+                        c.icon_url,
+                        c.color,
+                        c.category,
+                        c.fields,
+                        c.healthcheck_config,
+                        c.services,
+                        c.events,
+                        c.metadata,
+                        c.resources,
+                        hash,
+                        now
+                    ],
+                )?;
+                report.inserted.push(c.name.to_string());
+            }
+        }
+    }
+
+    tx.commit()?;
+    report.duration_ms = start.elapsed().as_millis() as u64;
+    Ok(report)
+}
+
+/// Run [`seed`] on a background thread and keep its report.
+pub(crate) fn spawn(pool: DbPool) {
+    let spawned = std::thread::Builder::new()
+        .name("connector-seed".into())
+        .spawn(move || {
+            let start = Instant::now();
+            let report = pool
+                .get()
+                .map_err(AppError::from)
+                .and_then(|conn| seed(&conn))
+                .unwrap_or_else(|e| {
+                    tracing::error!(error = %e, "Builtin connector seeding failed");
+                    ConnectorSeedReport {
+                        started_at: chrono::Utc::now().to_rfc3339(),
+                        duration_ms: start.elapsed().as_millis() as u64,
+                        total: BUILTIN_CONNECTORS.len(),
+                        error: Some(e.to_string()),
+                        ..Default::default()
+                    }
+                });
+            if report.error.is_none() {
+                log_report(&report);
+            }
+            *LAST_REPORT.lock().unwrap_or_else(|e| e.into_inner()) = Some(report);
+        });
+    if let Err(e) = spawned {
+        tracing::error!(error = %e, "Failed to spawn builtin connector seeding thread");
+    }
+}
+
+fn log_report(report: &ConnectorSeedReport) {
+    if report.inserted.is_empty() && report.upgraded.is_empty() && report.removed.is_empty() {
+        tracing::debug!(
+            total = report.total,
+            duration_ms = report.duration_ms,
+            "Builtin connectors up to date"
+        );
+        return;
+    }
+    tracing::info!(
+        inserted = ?report.inserted,
+        upgraded = ?report.upgraded,
+        removed = ?report.removed,
+        unchanged = report.unchanged,
+        duration_ms = report.duration_ms,
+        "Builtin connectors seeded"
+    );
+}
+
+/// Report of the last seeding pass; `None` while the first one is running.
+pub fn last_report() -> Option<ConnectorSeedReport> {
+    LAST_REPORT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reseed_skips_unchanged_and_upgrades_edited_rows() {
+        let pool = crate::db::init_test_db().unwrap();
+        let conn = pool.get().unwrap();
+
+        let report = seed(&conn).unwrap();
+        assert_eq!(report.unchanged, BUILTIN_CONNECTORS.len());
+        assert!(report.upgraded.is_empty() && report.inserted.is_empty());
+
+        let first = &BUILTIN_CONNECTORS[0];
+        conn.execute(
+            "UPDATE connector_definitions SET label = 'stale', seed_hash = 'old' WHERE name = ?1",
+            params![first.name],
+        )
+        .unwrap();
+        let report = seed(&conn).unwrap();
+        assert_eq!(report.upgraded, vec![first.name.to_string()]);
+        let label: String = conn
+            .query_row(
+                "SELECT label FROM connector_definitions WHERE name = ?1",
+                params![first.name],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(label, first.label);
+    }
+}
@@ -0,0 +1,202 @@
+//! Startup integrity check and self-repair for the primary database.
+//!
+//! A corrupted `personas.db` (torn write after a power loss, a sync tool
+//! mangling the file, a bad disk sector) otherwise surfaces as every query
+//! failing with `SQLITE_CORRUPT` long after boot. `init_db` instead runs
+//! [`check_and_repair`] before the pool opens the file:
+//!
+//! 1. `PRAGMA quick_check` on a private connection. `ok` → done.
+//! 2. Corrupt index b-trees are the most common recoverable case, so try
+//!    `REINDEX` and check again.
+//! 3. Still corrupt → move the file (and its WAL/SHM sidecars) aside as
+//!    `personas.db.corrupt-<stamp>` and copy in the newest unencrypted backup
+//!    that passes validation. The corrupt file is kept for manual recovery.
+//! 4. No usable backup → leave the file in place and report `corrupt`.
+//!
+//! The outcome is kept in [`startup_report`] so the shell can raise a
+//! notification once the app handle exists, and `check_database_health`
+//! returns it alongside a fresh check of the live database.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::backup;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// How many `quick_check` problem rows are kept in the report detail.
+const MAX_REPORTED_PROBLEMS: usize = 5;
+
+static STARTUP_REPORT: OnceLock<DatabaseIntegrityReport> = OnceLock::new();
+
+/// Result of one integrity pass.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct DatabaseIntegrityReport {
+    /// `ok` | `reindexed` | `restored` | `corrupt`.
+    pub status: String,
+    /// First problems reported by `quick_check`, when there were any.
+    pub detail: Option<String>,
+    /// Backup copied over the corrupt database (status `restored`).
+    pub restored_from: Option<String>,
+    /// Where the corrupt database was moved aside (status `restored`).
+    pub quarantined_to: Option<String>,
+    pub checked_at: String,
+}
+
+/// Response of `check_database_health`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct DatabaseHealth {
+    /// Fresh `quick_check` of the open database.
+    pub live: DatabaseIntegrityReport,
+    /// What the startup pass found and did this session.
+    pub startup: Option<DatabaseIntegrityReport>,
+}
+
+impl DatabaseIntegrityReport {
+    fn new(status: &str, detail: Option<String>) -> Self {
+        Self {
+            status: status.to_string(),
+            detail,
+            restored_from: None,
+            quarantined_to: None,
+            checked_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Anything other than a clean pass is worth telling the user about.
+    pub fn needs_attention(&self) -> bool {
+        self.status != "ok"
+    }
+}
+
+/// Run `PRAGMA quick_check` on `conn`. `Ok(())` when healthy, otherwise the
+/// first few problem rows (or the error that stopped the check).
+fn quick_check(conn: &rusqlite::Connection) -> Result<(), String> {
+    let rows: Result<Vec<String>, rusqlite::Error> = conn
+        .prepare("PRAGMA quick_check")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |r| r.get::<_, String>(0))?
+                .collect::<Result<Vec<_>, _>>()
+        });
+    match rows {
+        Ok(rows) if rows.len() == 1 && rows[0] == "ok" => Ok(()),
+        Ok(rows) => Err(rows
+            .into_iter()
+            .take(MAX_REPORTED_PROBLEMS)
+            .collect::<Vec<_>>()
+            .join("; ")),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// `quick_check`, then `REINDEX` + re-check on failure. Returns the status
+/// (`ok` / `reindexed`) or the remaining problems.
+fn check_file(db_path: &Path) -> Result<&'static str, String> {
+    let conn = rusqlite::Connection::open(db_path).map_err(|e| e.to_string())?;
+    let problems = match quick_check(&conn) {
+        Ok(()) => return Ok("ok"),
+        Err(p) => p,
+    };
+    tracing::warn!(problems = %problems, "Database integrity check failed — attempting REINDEX");
+    if let Err(e) = conn.execute_batch("REINDEX;") {
+        return Err(format!("{problems} (REINDEX failed: {e})"));
+    }
+    quick_check(&conn).map(|()| "reindexed")
+}
+
+/// Move the corrupt database and its sidecars aside, then copy in the newest
+/// unencrypted backup that validates. Returns `(backup, quarantine)` paths.
+fn restore_latest_backup(
+    app_data_dir: &Path,
+    db_path: &Path,
+) -> Result<(PathBuf, PathBuf), AppError> {
+    let candidate = backup::list_backups(app_data_dir)
+        .into_iter()
+        .filter(|b| !b.encrypted)
+        .map(|b| PathBuf::from(b.path))
+        // This boot's pre-migration snapshot is a copy of the corrupt file;
+        // validation rejects it like any other damaged backup.
+        .find(|p| backup::validate_database_file(p).is_ok())
+        .ok_or_else(|| AppError::NotFound("No valid backup to restore from".into()))?;
+
+    let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+    let quarantine = db_path.with_extension(format!("db.corrupt-{stamp}"));
+    std::fs::rename(db_path, &quarantine)?;
+    for ext in backup::SIDECAR_EXTENSIONS {
+        let sidecar = db_path.with_extension(ext);
+        if sidecar.exists() {
+            let _ = std::fs::rename(&sidecar, db_path.with_extension(format!("{ext}.corrupt-{stamp}")));
+        }
+    }
+
+    std::fs::copy(&candidate, db_path)?;
+    for ext in backup::SIDECAR_EXTENSIONS {
+        let src = candidate.with_extension(ext);
+        if src.exists() {
+            std::fs::copy(&src, db_path.with_extension(ext))?;
+        }
+    }
+    Ok((candidate, quarantine))
+}
+
+/// Check `db_path` and repair it if needed. Never returns an error: a failed
+/// repair is reported as `corrupt` and boot continues (the pool and
+/// migrations will surface the underlying error). Fresh installs are `ok`.
+pub(super) fn check_and_repair(app_data_dir: &Path, db_path: &Path) -> DatabaseIntegrityReport {
+    if !db_path.exists() {
+        return DatabaseIntegrityReport::new("ok", None);
+    }
+    let problems = match check_file(db_path) {
+        Ok(status) => {
+            if status != "ok" {
+                tracing::info!("Database indices rebuilt — integrity check passes");
+            }
+            return DatabaseIntegrityReport::new(status, None);
+        }
+        Err(p) => p,
+    };
+
+    match restore_latest_backup(app_data_dir, db_path) {
+        Ok((from, quarantine)) => {
+            tracing::warn!(
+                backup = %from.display(),
+                quarantined = %quarantine.display(),
+                "Corrupt database replaced with the latest valid backup"
+            );
+            DatabaseIntegrityReport {
+                restored_from: Some(from.to_string_lossy().into_owned()),
+                quarantined_to: Some(quarantine.to_string_lossy().into_owned()),
+                ..DatabaseIntegrityReport::new("restored", Some(problems))
+            }
+        }
+        Err(e) => {
+            tracing::error!(problems = %problems, error = %e, "Database is corrupt and could not be repaired");
+            DatabaseIntegrityReport::new("corrupt", Some(format!("{problems} ({e})")))
+        }
+    }
+}
+
+/// Record the startup pass. Only the first call per process sticks.
+pub(super) fn set_startup_report(report: DatabaseIntegrityReport) {
+    let _ = STARTUP_REPORT.set(report);
+}
+
+/// What the startup integrity pass found, if `init_db` has run.
+pub fn startup_report() -> Option<DatabaseIntegrityReport> {
+    STARTUP_REPORT.get().cloned()
+}
+
+/// `quick_check` the live database through the pool. Read-only: repairs only
+/// happen at startup, before anything holds the file open.
+pub fn check_live(pool: &DbPool) -> Result<DatabaseIntegrityReport, AppError> {
+    let conn = pool.get()?;
+    Ok(match quick_check(&conn) {
+        Ok(()) => DatabaseIntegrityReport::new("ok", None),
+        Err(problems) => DatabaseIntegrityReport::new("corrupt", Some(problems)),
+    })
+}
@@ -4209,6 +4209,649 @@ pub(super) fn run_incremental(conn: &Connection) -> Result<(), AppError> {
         },
     )?;
 
+    // Opt-in fleet learning: aggregated healing backoff recommendations
+    // downloaded from the shared pattern endpoint. Cache only — replaced
+    // wholesale on every sync and consulted after local healing_knowledge.
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "fleet_healing_recommendations",
+            description: "Cache of anonymized fleet-wide healing backoff recommendations",
+            already_applied: |conn| has_table(conn, "fleet_healing_recommendations"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS fleet_healing_recommendations (
+                        service_type           TEXT NOT NULL,
+                        pattern_key            TEXT NOT NULL,
+                        recommended_delay_secs INTEGER NOT NULL,
+                        install_count          INTEGER NOT NULL DEFAULT 0,
+                        fetched_at             TEXT NOT NULL,
+                        PRIMARY KEY (service_type, pattern_key)
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    // Hard budget enforcement: per-persona, per-billing-period (UTC `YYYY-MM`)
+    // bookkeeping — when the once-a-month alert fired and whether enforcement
+    // paused the persona, so the month-boundary reset knows what to resume.
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_budget_state",
+            description: "Per-persona budget enforcement state (alert dedup + auto-pause) per billing period",
+            already_applied: |conn| has_table(conn, "persona_budget_state"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS persona_budget_state (
+                        persona_id TEXT PRIMARY KEY REFERENCES personas(id) ON DELETE CASCADE,
+                        period     TEXT NOT NULL,
+                        alerted_at TEXT,
+                        paused_at  TEXT
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_teams.monthly_budget_usd",
+            description: "Workspace-wide monthly spend cap enforced on the sum of every member persona's spend",
+            already_applied: |conn| has_column(conn, "persona_teams", "monthly_budget_usd"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE persona_teams ADD COLUMN monthly_budget_usd REAL;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "team_budget_state",
+            description: "Per-workspace budget alert dedup per billing period",
+            already_applied: |conn| has_table(conn, "team_budget_state"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS team_budget_state (
+                        team_id    TEXT PRIMARY KEY REFERENCES persona_teams(id) ON DELETE CASCADE,
+                        period     TEXT NOT NULL,
+                        alerted_at TEXT
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_execute_hooks",
+            description: "Per-persona tokens for the /hooks/execute webhook route",
+            already_applied: |conn| has_table(conn, "persona_execute_hooks"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS persona_execute_hooks (
+                        persona_id        TEXT PRIMARY KEY REFERENCES personas(id) ON DELETE CASCADE,
+                        token_hash        TEXT NOT NULL UNIQUE,
+                        token_prefix      TEXT NOT NULL,
+                        wait_timeout_secs INTEGER NOT NULL DEFAULT 30,
+                        created_at        TEXT NOT NULL,
+                        last_used_at      TEXT
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_report_sinks",
+            description: "Per-persona file-report sink for scheduled run outputs",
+            already_applied: |conn| has_table(conn, "persona_report_sinks"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS persona_report_sinks (
+                        persona_id        TEXT PRIMARY KEY REFERENCES personas(id) ON DELETE CASCADE,
+                        enabled           INTEGER NOT NULL DEFAULT 1,
+                        directory         TEXT NOT NULL,
+                        format            TEXT NOT NULL DEFAULT 'markdown'
+                                          CHECK(format IN ('markdown','html')),
+                        template          TEXT,
+                        filename_prefix   TEXT,
+                        scheduled_only    INTEGER NOT NULL DEFAULT 1,
+                        pdf               INTEGER NOT NULL DEFAULT 0,
+                        last_written_path TEXT,
+                        last_written_at   TEXT,
+                        last_error        TEXT,
+                        created_at        TEXT NOT NULL,
+                        updated_at        TEXT NOT NULL
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "template_variables",
+            description: "Prompt template variables at global, team and persona scope",
+            already_applied: |conn| has_table(conn, "template_variables"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS template_variables (
+                        id          TEXT PRIMARY KEY,
+                        scope       TEXT NOT NULL CHECK(scope IN ('global','team','persona')),
+                        scope_id    TEXT NOT NULL DEFAULT '',
+                        name        TEXT NOT NULL,
+                        value       TEXT NOT NULL,
+                        description TEXT,
+                        created_at  TEXT NOT NULL,
+                        updated_at  TEXT NOT NULL,
+                        UNIQUE(scope, scope_id, name)
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_executions.feature_flags",
+            description: "Record the engine feature flags active for each execution",
+            already_applied: |conn| has_column(conn, "persona_executions", "feature_flags"),
+            apply: |conn| {
+                ddl_step(conn, "ALTER TABLE persona_executions ADD COLUMN feature_flags TEXT;")?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_memories.expires_at",
+            description: "Per-memory expiry; expired memories are excluded from injection and archived",
+            already_applied: |conn| has_column(conn, "persona_memories", "expires_at"),
+            apply: |conn| {
+                ddl_step(conn, "ALTER TABLE persona_memories ADD COLUMN expires_at TEXT;")?;
+                ddl_step(
+                    conn,
+                    "CREATE INDEX IF NOT EXISTS idx_persona_memories_expires_at
+                     ON persona_memories(expires_at) WHERE expires_at IS NOT NULL;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "connector_definitions.seed_hash",
+            description: "Content hash of the builtin definition a connector row was seeded from; unchanged connectors are skipped on boot",
+            already_applied: |conn| has_column(conn, "connector_definitions", "seed_hash"),
+            apply: |conn| {
+                ddl_step(conn, "ALTER TABLE connector_definitions ADD COLUMN seed_hash TEXT;")?;
+                Ok(())
+            },
+        },
+    )?;
+
+    // Missed-schedule catch-up report: which fires a trigger's `catch_up`
+    // policy replayed (and how many it dropped) the last time the scheduler
+    // found an offline gap. Lives on the per-trigger side-state row next to
+    // the discarded-while-offline count.
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "schedule_missed_runs.catch_up",
+            description: "Last missed-schedule catch-up report per trigger",
+            already_applied: |conn| has_column(conn, "schedule_missed_runs", "catch_up_at"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE schedule_missed_runs ADD COLUMN catch_up_policy TEXT;
+                     ALTER TABLE schedule_missed_runs ADD COLUMN catch_up_at TEXT;
+                     ALTER TABLE schedule_missed_runs ADD COLUMN catch_up_fired TEXT;
+                     ALTER TABLE schedule_missed_runs ADD COLUMN catch_up_skipped INTEGER NOT NULL DEFAULT 0;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    // Event replay lineage: a replayed copy points at the original event so
+    // `MAX_EVENT_REPLAYS` can be enforced across replays of replays.
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_events.replay_of",
+            description: "Original event a replayed persona_event was copied from",
+            already_applied: |conn| has_column(conn, "persona_events", "replay_of"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE persona_events ADD COLUMN replay_of TEXT;
+                     CREATE INDEX IF NOT EXISTS idx_pev_replay_of
+                         ON persona_events(replay_of) WHERE replay_of IS NOT NULL;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "event_schemas",
+            description: "Optional JSON Schema per event type; payloads of a registered type are validated on publish and webhook ingestion",
+            already_applied: |conn| has_table(conn, "event_schemas"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS event_schemas (
+                        event_type  TEXT PRIMARY KEY,
+                        schema      TEXT NOT NULL,
+                        description TEXT,
+                        created_at  TEXT NOT NULL,
+                        updated_at  TEXT NOT NULL
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "execution_batches",
+            description: "Dataset batches: one execution per input item of a persona under a shared batch id, with per-item status",
+            already_applied: |conn| has_table(conn, "execution_batch_items"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS execution_batches (
+                        id           TEXT PRIMARY KEY,
+                        persona_id   TEXT NOT NULL REFERENCES personas(id) ON DELETE CASCADE,
+                        use_case_id  TEXT,
+                        concurrency  INTEGER NOT NULL,
+                        total_items  INTEGER NOT NULL,
+                        status       TEXT NOT NULL DEFAULT 'running',
+                        created_at   TEXT NOT NULL,
+                        completed_at TEXT
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_execution_batches_persona
+                        ON execution_batches(persona_id, created_at);
+                    CREATE TABLE IF NOT EXISTS execution_batch_items (
+                        batch_id     TEXT NOT NULL REFERENCES execution_batches(id) ON DELETE CASCADE,
+                        item_index   INTEGER NOT NULL,
+                        input_data   TEXT,
+                        execution_id TEXT,
+                        status       TEXT NOT NULL DEFAULT 'pending',
+                        error        TEXT,
+                        PRIMARY KEY (batch_id, item_index)
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "notification_rules",
+            description: "Notification routing rules: category/persona/team/severity filters, target channel or silence, quiet hours and hourly rate limit",
+            already_applied: |conn| has_table(conn, "notification_rules"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS notification_rules (
+                        id                TEXT PRIMARY KEY,
+                        name              TEXT NOT NULL,
+                        category          TEXT NOT NULL DEFAULT '*',
+                        persona_id        TEXT REFERENCES personas(id) ON DELETE CASCADE,
+                        team_id           TEXT,
+                        min_severity      TEXT NOT NULL DEFAULT 'info',
+                        action            TEXT NOT NULL DEFAULT 'deliver',
+                        channel_type      TEXT,
+                        credential_id     TEXT,
+                        channel_config    TEXT,
+                        quiet_hours_start TEXT,
+                        quiet_hours_end   TEXT,
+                        max_per_hour      INTEGER,
+                        enabled           INTEGER NOT NULL DEFAULT 1,
+                        created_at        TEXT NOT NULL,
+                        updated_at        TEXT NOT NULL
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_executions.output_ref",
+            description: "Pointer to an execution output offloaded to a compressed file",
+            already_applied: |conn| has_column(conn, "persona_executions", "output_ref"),
+            apply: |conn| {
+                ddl_step(conn, "ALTER TABLE persona_executions ADD COLUMN output_ref TEXT;")?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "shared_context.create",
+            description: "Shared context blackboard: structured key/value artifacts scoped to a pipeline run or chain trace so downstream personas can read what upstream ones produced",
+            already_applied: |conn| has_table(conn, "shared_context"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS shared_context (
+                        id           TEXT PRIMARY KEY,
+                        scope_type   TEXT NOT NULL,
+                        scope_id     TEXT NOT NULL,
+                        key          TEXT NOT NULL,
+                        value        TEXT NOT NULL,
+                        persona_id   TEXT,
+                        execution_id TEXT,
+                        created_at   TEXT NOT NULL,
+                        updated_at   TEXT NOT NULL,
+                        UNIQUE(scope_type, scope_id, key)
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_shared_context_updated ON shared_context(updated_at);",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "project_data_keys.create",
+            description: "Per-project data keys (wrapped by the master key) for event payload encryption, so one project's data can be revoked without touching the rest",
+            already_applied: |conn| has_table(conn, "project_data_keys"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS project_data_keys (
+                        id          TEXT PRIMARY KEY,
+                        project_id  TEXT NOT NULL,
+                        wrapped_key TEXT,
+                        wrap_iv     TEXT,
+                        status      TEXT NOT NULL DEFAULT 'active',
+                        created_at  TEXT NOT NULL,
+                        revoked_at  TEXT
+                    );
+                    CREATE UNIQUE INDEX IF NOT EXISTS idx_project_data_keys_active
+                        ON project_data_keys(project_id) WHERE status = 'active';",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "injection_detections.create",
+            description: "Inbound event payloads flagged by the prompt-injection scan, with the policy action taken and the human review outcome",
+            already_applied: |conn| has_table(conn, "injection_detections"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS injection_detections (
+                        id              TEXT PRIMARY KEY,
+                        event_id        TEXT NOT NULL,
+                        event_type      TEXT NOT NULL,
+                        source_type     TEXT NOT NULL,
+                        source_id       TEXT,
+                        trigger_id      TEXT,
+                        score           REAL NOT NULL,
+                        level           TEXT NOT NULL,
+                        signals         TEXT NOT NULL DEFAULT '[]',
+                        llm_verdict     TEXT,
+                        policy          TEXT NOT NULL,
+                        action          TEXT NOT NULL,
+                        payload_hash    TEXT NOT NULL,
+                        excerpt         TEXT,
+                        pending_fire_id TEXT,
+                        review_status   TEXT NOT NULL DEFAULT 'unreviewed',
+                        created_at      TEXT NOT NULL,
+                        reviewed_at     TEXT
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_injection_detections_created
+                        ON injection_detections(created_at DESC);
+                    CREATE INDEX IF NOT EXISTS idx_injection_detections_hash
+                        ON injection_detections(payload_hash, review_status);",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "credential_anomalies.create",
+            description: "Unusual credential usage flagged from the audit log and execution history, with acknowledgement state",
+            already_applied: |conn| has_table(conn, "credential_anomalies"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS credential_anomalies (
+                        id              TEXT PRIMARY KEY,
+                        credential_id   TEXT NOT NULL,
+                        credential_name TEXT NOT NULL,
+                        persona_id      TEXT,
+                        persona_name    TEXT,
+                        kind            TEXT NOT NULL,
+                        severity        TEXT NOT NULL,
+                        title           TEXT NOT NULL,
+                        description     TEXT NOT NULL,
+                        evidence        TEXT NOT NULL DEFAULT '{}',
+                        dedupe_key      TEXT NOT NULL UNIQUE,
+                        status          TEXT NOT NULL DEFAULT 'open',
+                        detected_at     TEXT NOT NULL,
+                        acknowledged_at TEXT
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_credential_anomalies_status
+                        ON credential_anomalies(status, detected_at DESC);
+                    CREATE INDEX IF NOT EXISTS idx_credential_anomalies_credential
+                        ON credential_anomalies(credential_id);",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_executions.firing_id",
+            description: "Group the executions one trigger firing or event fanned out to",
+            already_applied: |conn| has_column(conn, "persona_executions", "firing_id"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE persona_executions ADD COLUMN firing_id TEXT;
+                    CREATE INDEX IF NOT EXISTS idx_persona_executions_firing
+                        ON persona_executions(firing_id) WHERE firing_id IS NOT NULL;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "prompt_blocks",
+            description: "Reusable prompt blocks referenced from structured prompts",
+            already_applied: |conn| has_table(conn, "prompt_blocks"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS prompt_blocks (
+                        id          TEXT PRIMARY KEY,
+                        name        TEXT NOT NULL UNIQUE,
+                        category    TEXT NOT NULL
+                                    CHECK(category IN ('tone','compliance','output_format','other')),
+                        content     TEXT NOT NULL,
+                        description TEXT,
+                        created_at  TEXT NOT NULL,
+                        updated_at  TEXT NOT NULL
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "pipeline_runs.simulation",
+            description: "Dry-run settings for pipeline runs started by simulate_team_run",
+            already_applied: |conn| has_column(conn, "pipeline_runs", "simulation"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE pipeline_runs ADD COLUMN simulation TEXT;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "personas.execution_placement",
+            description: "Per-persona cloud/local routing policy (NULL = prefer_local)",
+            already_applied: |conn| has_column(conn, "personas", "execution_placement"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE personas ADD COLUMN execution_placement TEXT;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "credential_negotiations.create",
+            description: "Credential negotiation transcripts: plan, step events and the credential each produced",
+            already_applied: |conn| has_table(conn, "credential_negotiation_events"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS credential_negotiations (
+                        id            TEXT PRIMARY KEY,
+                        service_name  TEXT NOT NULL,
+                        status        TEXT NOT NULL DEFAULT 'planning',
+                        plan_json     TEXT,
+                        credential_id TEXT,
+                        error         TEXT,
+                        created_at    TEXT NOT NULL,
+                        updated_at    TEXT NOT NULL
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_credential_negotiations_credential
+                        ON credential_negotiations(credential_id);
+                    CREATE TABLE IF NOT EXISTS credential_negotiation_events (
+                        id             INTEGER PRIMARY KEY AUTOINCREMENT,
+                        negotiation_id TEXT NOT NULL
+                                       REFERENCES credential_negotiations(id) ON DELETE CASCADE,
+                        kind           TEXT NOT NULL,
+                        step_index     INTEGER,
+                        detail_json    TEXT,
+                        created_at     TEXT NOT NULL
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_credential_negotiation_events_negotiation
+                        ON credential_negotiation_events(negotiation_id, id);",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_executions.cloud_execution_id",
+            description: "Orchestrator execution id of cloud runs, for reconciliation after a restart",
+            already_applied: |conn| has_column(conn, "persona_executions", "cloud_execution_id"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE persona_executions ADD COLUMN cloud_execution_id TEXT;
+                    CREATE INDEX IF NOT EXISTS idx_persona_executions_cloud
+                        ON persona_executions(cloud_execution_id)
+                        WHERE cloud_execution_id IS NOT NULL;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "notification_templates.create",
+            description: "User-editable wording per notification type and UI language",
+            already_applied: |conn| has_table(conn, "notification_templates"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS notification_templates (
+                        id                TEXT PRIMARY KEY,
+                        notification_type TEXT NOT NULL,
+                        locale            TEXT NOT NULL DEFAULT '*',
+                        title_template    TEXT NOT NULL,
+                        body_template     TEXT NOT NULL,
+                        enabled           INTEGER NOT NULL DEFAULT 1,
+                        created_at        TEXT NOT NULL,
+                        updated_at        TEXT NOT NULL,
+                        UNIQUE(notification_type, locale)
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
     Ok(())
 }
 
@@ -5937,6 +6580,155 @@ pub fn ensure_composite_fires_table(conn: &Connection) -> Result<(), AppError> {
             ON dev_memories(project_id, source_kind, source_id)
             WHERE source_id IS NOT NULL;",
     )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "personas_engine",
+            description: "Per-persona CLI engine override (NULL = global cli_engine setting)",
+            already_applied: |conn| has_column(conn, "personas", "engine"),
+            apply: |conn| {
+                ddl_step(conn, "ALTER TABLE personas ADD COLUMN engine TEXT;")?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "maintenance_windows",
+            description: "Global (persona_id NULL) and per-persona maintenance windows; trigger events for covered personas stay pending until the window ends",
+            already_applied: |conn| has_table(conn, "maintenance_windows"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS maintenance_windows (
+                        id          TEXT PRIMARY KEY,
+                        persona_id  TEXT REFERENCES personas(id) ON DELETE CASCADE,
+                        starts_at   TEXT NOT NULL,
+                        ends_at     TEXT,
+                        reason      TEXT,
+                        created_at  TEXT NOT NULL
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_mw_window
+                        ON maintenance_windows(starts_at, ends_at);",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_slos",
+            description: "Per-persona SLOs (min success rate, max p95 duration over a rolling window) with breach state for the SLO monitor",
+            already_applied: |conn| has_table(conn, "persona_slos"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "CREATE TABLE IF NOT EXISTS persona_slos (
+                        persona_id          TEXT PRIMARY KEY REFERENCES personas(id) ON DELETE CASCADE,
+                        min_success_rate    REAL,
+                        max_p95_duration_ms INTEGER,
+                        window_hours        INTEGER NOT NULL DEFAULT 24,
+                        min_sample          INTEGER NOT NULL DEFAULT 5,
+                        enabled             INTEGER NOT NULL DEFAULT 1,
+                        breach_reason       TEXT,
+                        breached_at         TEXT,
+                        breach_issue_id     TEXT,
+                        last_evaluated_at   TEXT,
+                        created_at          TEXT NOT NULL,
+                        updated_at          TEXT NOT NULL
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "persona_metrics_rollups",
+            description: "Weekly/monthly metrics rollups, snapshot day coverage and one daily snapshot per persona per day",
+            already_applied: |conn| has_table(conn, "persona_metrics_rollups"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "-- Keep the latest row per persona/day before enforcing it.
+                    DELETE FROM persona_metrics_snapshots WHERE rowid NOT IN (
+                        SELECT MAX(rowid) FROM persona_metrics_snapshots
+                        GROUP BY persona_id, snapshot_date
+                    );
+                    CREATE UNIQUE INDEX IF NOT EXISTS idx_pms_persona_day
+                        ON persona_metrics_snapshots(persona_id, snapshot_date);
+
+                    CREATE TABLE IF NOT EXISTS persona_metrics_rollups (
+                        persona_id            TEXT NOT NULL REFERENCES personas(id) ON DELETE CASCADE,
+                        granularity           TEXT NOT NULL CHECK(granularity IN ('week', 'month')),
+                        period_start          TEXT NOT NULL,
+                        total_executions      INTEGER NOT NULL DEFAULT 0,
+                        successful_executions INTEGER NOT NULL DEFAULT 0,
+                        failed_executions     INTEGER NOT NULL DEFAULT 0,
+                        total_cost_usd        REAL NOT NULL DEFAULT 0,
+                        total_input_tokens    INTEGER NOT NULL DEFAULT 0,
+                        total_output_tokens   INTEGER NOT NULL DEFAULT 0,
+                        avg_duration_ms       REAL NOT NULL DEFAULT 0,
+                        events_emitted        INTEGER NOT NULL DEFAULT 0,
+                        events_consumed       INTEGER NOT NULL DEFAULT 0,
+                        messages_sent         INTEGER NOT NULL DEFAULT 0,
+                        updated_at            TEXT NOT NULL,
+                        PRIMARY KEY (persona_id, granularity, period_start)
+                    );
+                    CREATE INDEX IF NOT EXISTS idx_pmro_period
+                        ON persona_metrics_rollups(granularity, period_start);
+
+                    -- Days whose snapshots were rebuilt; `complete` once rebuilt
+                    -- after the (UTC) day ended.
+                    CREATE TABLE IF NOT EXISTS persona_metrics_snapshot_days (
+                        snapshot_date TEXT PRIMARY KEY,
+                        complete      INTEGER NOT NULL DEFAULT 0,
+                        rebuilt_at    TEXT NOT NULL
+                    );",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "healing_knowledge_curated",
+            description: "Flag hand-curated healing knowledge so learning keeps its delay",
+            already_applied: |conn| has_column(conn, "healing_knowledge", "curated"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE healing_knowledge ADD COLUMN curated INTEGER NOT NULL DEFAULT 0;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
+
+    run_step(
+        conn,
+        IncrementalMigration {
+            id: "healing_issue_root_cause",
+            description: "Store a model-written root-cause summary on healing issues",
+            already_applied: |conn| has_column(conn, "persona_healing_issues", "root_cause"),
+            apply: |conn| {
+                ddl_step(
+                    conn,
+                    "ALTER TABLE persona_healing_issues ADD COLUMN root_cause TEXT;",
+                )?;
+                Ok(())
+            },
+        },
+    )?;
     Ok(())
 }
 
@@ -6397,6 +7189,25 @@ mod tests {
             "dev_llm_spend",
             "dev_use_cases",
             "dev_use_case_contexts",
+            "fleet_healing_recommendations",
+            "persona_budget_state",
+            "team_budget_state",
+            "persona_execute_hooks",
+            "persona_report_sinks",
+            "maintenance_windows",
+            "template_variables",
+            "event_schemas",
+            "execution_batches",
+            "execution_batch_items",
+            "notification_rules",
+            "shared_context",
+            "project_data_keys",
+            "injection_detections",
+            "credential_anomalies",
+            "prompt_blocks",
+            "credential_negotiations",
+            "credential_negotiation_events",
+            "notification_templates",
         ] {
             assert!(
                 has_table(&conn, table).unwrap(),
@@ -6426,6 +7237,15 @@ mod tests {
             ("persona_memories", "derived_from"),
             ("persona_memory_review_proposal", "team_id"),
             ("dev_kpi_measurements", "env"),
+            ("persona_teams", "monthly_budget_usd"),
+            ("persona_executions", "feature_flags"),
+            ("persona_memories", "expires_at"),
+            ("personas", "engine"),
+            ("persona_executions", "output_ref"),
+            ("persona_executions", "firing_id"),
+            ("pipeline_runs", "simulation"),
+            ("personas", "execution_placement"),
+            ("persona_executions", "cloud_execution_id"),
         ] {
             assert!(
                 has_column(&conn, table, column).unwrap(),
@@ -6443,6 +7263,8 @@ mod tests {
             "idx_dev_kpis_context",
             "idx_dev_kpis_use_case",
             "idx_dev_use_cases_project",
+            "idx_persona_memories_expires_at",
+            "idx_persona_executions_firing",
         ] {
             assert!(
                 has_index(&conn, index).unwrap(),
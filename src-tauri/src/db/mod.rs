@@ -1,15 +1,18 @@
 #[macro_use]
 pub mod macros;
-mod backup;
+pub(crate) mod backup;
 pub(crate) mod builtin_connectors;
 pub(crate) mod builtin_shared_events;
 pub mod cdc;
+pub mod connector_seed;
 pub(crate) mod credential_fields;
+pub mod integrity;
 #[allow(dead_code)] // Functions used by Tauri commands in Phase 3
 pub mod migrations;
 #[allow(dead_code)]
 pub mod models;
 pub mod perf;
+pub mod pool_budget;
 pub mod query_builder;
 #[allow(dead_code)]
 pub mod repos;
@@ -219,6 +222,12 @@ pub fn init_db(
     // blocks boot. Fresh installs are skipped inside the helper. Policy
     // (every-boot backup, keep newest 3 sets) is documented in db/backup.rs.
     backup::backup_before_migrations(app_data_dir, &db_path);
+    // A restore staged by `restore_database` is applied here — after the
+    // snapshot above, so the database it replaces stays recoverable.
+    backup::apply_pending_restore(app_data_dir, &db_path);
+    // Catch corruption here rather than as SQLITE_CORRUPT on every query later:
+    // quick_check, then REINDEX, then fall back to the newest valid backup.
+    integrity::set_startup_report(integrity::check_and_repair(app_data_dir, &db_path));
 
     let manager = SqliteConnectionManager::file(&db_path);
     let customizer: Box<dyn CustomizeConnection<rusqlite::Connection, rusqlite::Error>> =
@@ -229,14 +238,19 @@ pub fn init_db(
     // Pool sized for concurrent IPC: settings + executions list + healing +
     // vector search (each can hold a connection for hundreds of ms). At
     // max_size(4) one vector_kb search would serialize every other read
-    // behind it; bump to 12 so realistic concurrent IPC doesn't starve.
-    // connection_timeout converts hangs into recoverable errors so the
-    // IPC worker fails fast instead of locking the UI.
+    // behind it; the default of 12 keeps realistic concurrent IPC from
+    // starving, and `db_pool_size` overrides it. connection_timeout converts
+    // hangs into recoverable errors so the IPC worker fails fast instead of
+    // locking the UI. Background loops (scheduler, webhooks) are capped below
+    // the pool size by `pool_budget` so interactive commands keep a
+    // connection.
+    let pool_size = configured_pool_size(&db_path);
     let pool = Pool::builder()
-        .max_size(12)
+        .max_size(pool_size)
         .connection_timeout(POOL_ACQUIRE_TIMEOUT)
         .connection_customizer(customizer)
         .build(manager)?;
+    pool_budget::configure(pool_size);
 
     // Set WAL journal mode (database-wide, only needs to run once)
     {
@@ -264,11 +278,13 @@ pub fn init_db(
     {
         let conn = pool.get()?;
         seed_builtin_tools(&conn)?;
-        seed_builtin_connectors(&conn)?;
         seed_builtin_shared_events(&conn)?;
         #[cfg(feature = "scraper")]
         seed_example_scrape_config(&conn)?;
     }
+    // Builtin connectors seed in parallel with the rest of startup; unchanged
+    // definitions are skipped by content hash.
+    connector_seed::spawn(pool.clone());
 
     // Defense-in-depth: scrub orphan rows whose parent persona is gone.
     //
@@ -291,6 +307,39 @@ pub fn init_db(
     Ok(pool)
 }
 
+/// Main pool size from the `db_pool_size` setting. The pool does not exist
+/// yet, so the row is read over a throwaway read-only connection; a fresh
+/// install, a missing row or an out-of-range value fall back to the default.
+fn configured_pool_size(db_path: &Path) -> u32 {
+    let stored = || -> Option<u32> {
+        if !db_path.exists() {
+            return None;
+        }
+        let conn = rusqlite::Connection::open_with_flags(
+            db_path,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY | rusqlite::OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .ok()?;
+        let value: String = conn
+            .query_row(
+                "SELECT value FROM app_settings WHERE key = ?1",
+                params![settings_keys::DB_POOL_SIZE],
+                |row| row.get(0),
+            )
+            .ok()?;
+        value.trim().parse().ok()
+    };
+    let range = settings_keys::DB_POOL_SIZE_MIN..=settings_keys::DB_POOL_SIZE_MAX;
+    match stored() {
+        Some(n) if range.contains(&n) => n,
+        Some(n) => {
+            tracing::warn!(value = n, "db_pool_size out of range; using the default");
+            settings_keys::DB_POOL_SIZE_DEFAULT
+        }
+        None => settings_keys::DB_POOL_SIZE_DEFAULT,
+    }
+}
+
 /// Open a pooled connection to an **already-initialized** database file, WITHOUT
 /// running migrations or seeds. This is for out-of-process companions (e.g. the
 /// `personas-mcp` stdio binary) that attach to the DB the windowed app created —
@@ -345,6 +394,7 @@ fn cleanup_orphan_rows(conn: &rusqlite::Connection) {
         "persona_healing_issues",
         "persona_manual_reviews",
         "persona_metrics_snapshots",
+        "persona_metrics_rollups",
         "persona_test_runs",
         "persona_versions",
     ];
@@ -1289,7 +1339,7 @@ fn restrict_windows_permissions(path: &Path) {
     tracing::debug!(path = %path.display(), "Set restrictive Windows permissions (owner-only)");
 }
 
-/// Seed the 7 builtin tool definitions.
+/// Seed the 11 builtin tool definitions.
 fn seed_builtin_tools(conn: &rusqlite::Connection) -> Result<(), AppError> {
     let now = chrono::Utc::now().to_rfc3339();
     let tools = [
@@ -1333,6 +1383,30 @@ fn seed_builtin_tools(conn: &rusqlite::Connection) -> Result<(), AppError> {
             "builtin://gmail_mark_read",
             Some("gmail"),
         ),
+        (
+            "builtin-email-imap-read",
+            "email_imap_read",
+            "email",
+            "Read emails from any IMAP mailbox",
+            "builtin://email_imap_read",
+            Some("imap_smtp"),
+        ),
+        (
+            "builtin-email-imap-search",
+            "email_imap_search",
+            "email",
+            "Search an IMAP mailbox",
+            "builtin://email_imap_search",
+            Some("imap_smtp"),
+        ),
+        (
+            "builtin-email-smtp-send",
+            "email_smtp_send",
+            "email",
+            "Send emails over SMTP",
+            "builtin://email_smtp_send",
+            Some("imap_smtp"),
+        ),
         (
             "builtin-file-read",
             "file_read",
@@ -1349,6 +1423,14 @@ fn seed_builtin_tools(conn: &rusqlite::Connection) -> Result<(), AppError> {
             "builtin://file_write",
             None,
         ),
+        (
+            "builtin-sql-query",
+            "sql_query",
+            "database",
+            "Run parameterized SQL queries against a connected database",
+            "builtin://sql_query",
+            None,
+        ),
     ];
 
     for (id, name, category, description, script_path, cred_type) in &tools {
@@ -1364,67 +1446,6 @@ fn seed_builtin_tools(conn: &rusqlite::Connection) -> Result<(), AppError> {
     Ok(())
 }
 
-/// Seed built-in connector templates that should be available to all users.
-/// Definitions are auto-generated from `scripts/connectors/builtin/*.json`
-/// into `db/builtin_connectors.rs`. Regenerate with:
-///   node scripts/generate-connector-seed.mjs
-fn seed_builtin_connectors(conn: &rusqlite::Connection) -> Result<(), AppError> {
-    let now = chrono::Utc::now().to_rfc3339();
-
-    let connectors = builtin_connectors::BUILTIN_CONNECTORS;
-
-    // === REMOVED: ~900 lines of hardcoded BuiltinConnector structs ===
-    // The connector definitions previously lived inline here as a massive
-    // &[BuiltinConnector] array literal.  They are now auto-generated into
-    // db/builtin_connectors.rs from the JSON source-of-truth files in
-    // scripts/connectors/builtin/*.json.
-    //
-    // To add/edit a connector: edit the JSON file, then run:
-    //   node scripts/generate-connector-seed.mjs
-    // The embedded-Pumper "local-scraper" connector was retired in Phase 1c —
-    // the scraper now talks to the app via Signals (event bus), not a connector
-    // or persona-invoked MCP tools. Remove it from any DB a prior build seeded.
-    let _ = conn.execute(
-        "DELETE FROM connector_definitions WHERE id = 'builtin-local-scraper' AND is_builtin = 1",
-        [],
-    );
-    for c in connectors {
-        conn.execute(
-            "INSERT OR IGNORE INTO connector_definitions
-             (id, name, label, icon_url, color, category, fields,
-              healthcheck_config, services, events, metadata, resources, is_builtin,
-              created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, 1, ?13, ?13)",
-            params![
-                c.id,
-                c.name,
-                c.label,
-                c.icon_url,
-                c.color,
-                c.category,
-                c.fields,
-                c.healthcheck_config,
-                c.services,
-                c.events,
-                c.metadata,
-                c.resources,
-                now
-            ],
-        )?;
-
-        // Update existing rows to refresh fields/metadata/category/services/events/resources on app upgrade
-        conn.execute(
-            "UPDATE connector_definitions
-             SET label = ?1, icon_url = ?2, fields = ?3, healthcheck_config = ?4, metadata = ?5, category = ?6, services = ?7, events = ?8, resources = ?9, updated_at = ?10
-             WHERE name = ?11 AND is_builtin = 1",
-            params![c.label, c.icon_url, c.fields, c.healthcheck_config, c.metadata, c.category, c.services, c.events, c.resources, now, c.name],
-        )?;
-    }
-
-    tracing::debug!("Seeded {} builtin connector definitions", connectors.len());
-    Ok(())
-}
-
 /// Seed the curated shared-event catalog + baked firings that ship with this
 /// release. Definitions are auto-generated from scripts/connectors/builtin/*.json
 /// (catalog) and scripts/events/connector-events.ledger.json (firings) into
@@ -1518,7 +1539,7 @@ pub fn init_test_db() -> Result<DbPool, AppError> {
     migrations::run(&conn)?;
     migrations::run_incremental(&conn)?;
     seed_builtin_tools(&conn)?;
-    seed_builtin_connectors(&conn)?;
+    connector_seed::seed(&conn)?;
     seed_builtin_shared_events(&conn)?;
     drop(conn);
     Ok(pool)
@@ -1730,4 +1751,92 @@ mod boot_tests {
 
         let _ = std::fs::remove_dir_all(&data_dir);
     }
+
+    /// Manual backup → staged restore round trip: an encrypted `VACUUM INTO`
+    /// snapshot is listed, refuses the wrong passphrase, and once staged with
+    /// the right one replaces the live database on the next boot.
+    #[test]
+    fn manual_backup_restores_on_next_boot() {
+        let data_dir =
+            std::env::temp_dir().join(format!("personas_boot_test_{}", uuid::Uuid::new_v4()));
+
+        let backup_path = {
+            let pool = init_db(&data_dir, None).expect("init_db failed");
+            repos::core::settings::set(&pool, settings_keys::CLI_ENGINE, "claude_code").unwrap();
+            let info = backup::create_backup(
+                &pool,
+                &data_dir,
+                backup::BackupKind::Manual,
+                Some("correct horse"),
+            )
+            .expect("manual backup failed");
+            assert!(info.encrypted);
+            assert_eq!(info.kind, "manual");
+            assert!(backup::list_backups(&data_dir)
+                .iter()
+                .any(|b| b.path == info.path));
+
+            // Diverge from the snapshot so the restore is observable.
+            repos::core::settings::set(&pool, settings_keys::CLI_ENGINE, "other").unwrap();
+            PathBuf::from(info.path)
+        };
+
+        assert!(backup::stage_restore(&data_dir, &backup_path, Some("wrong passphrase")).is_err());
+        assert!(backup::stage_restore(&data_dir, &backup_path, None).is_err());
+        let staged = backup::stage_restore(&data_dir, &backup_path, Some("correct horse"))
+            .expect("staging a valid backup failed");
+        assert!(staged.restart_required);
+
+        {
+            let pool = init_db(&data_dir, None).expect("boot with staged restore failed");
+            assert_eq!(
+                repos::core::settings::get(&pool, settings_keys::CLI_ENGINE)
+                    .unwrap()
+                    .as_deref(),
+                Some("claude_code"),
+                "staged restore was not applied on boot"
+            );
+        }
+        assert!(!data_dir.join("personas.db.restore-pending").exists());
+
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
+
+    #[test]
+    fn corrupt_database_falls_back_to_latest_backup() {
+        let data_dir =
+            std::env::temp_dir().join(format!("personas_boot_test_{}", uuid::Uuid::new_v4()));
+        let db_path = data_dir.join("personas.db");
+
+        {
+            let pool = init_db(&data_dir, None).expect("init_db failed");
+            repos::core::settings::set(&pool, settings_keys::CLI_ENGINE, "claude_code").unwrap();
+            backup::create_backup(&pool, &data_dir, backup::BackupKind::Manual, None)
+                .expect("manual backup failed");
+            assert_eq!(integrity::check_live(&pool).unwrap().status, "ok");
+        }
+        assert_eq!(integrity::check_and_repair(&data_dir, &db_path).status, "ok");
+
+        // Clobber the live file (and drop its WAL) so it no longer parses.
+        for ext in ["db-wal", "db-shm"] {
+            let _ = std::fs::remove_file(db_path.with_extension(ext));
+        }
+        std::fs::write(&db_path, vec![0xA5u8; 8192]).unwrap();
+
+        let report = integrity::check_and_repair(&data_dir, &db_path);
+        assert_eq!(report.status, "restored");
+        assert!(report.detail.is_some());
+        assert!(report.restored_from.as_deref().unwrap().contains("manual-"));
+        assert!(std::path::Path::new(report.quarantined_to.as_deref().unwrap()).exists());
+
+        let pool = init_db(&data_dir, None).expect("boot after self-repair failed");
+        assert_eq!(
+            repos::core::settings::get(&pool, settings_keys::CLI_ENGINE)
+                .unwrap()
+                .as_deref(),
+            Some("claude_code")
+        );
+        drop(pool);
+        let _ = std::fs::remove_dir_all(&data_dir);
+    }
 }
@@ -45,3 +45,30 @@ pub struct CredentialDependent {
     pub via_connector: Option<String>,
     pub last_used_at: Option<String>,
 }
+
+/// Unusual credential usage flagged by the detector in
+/// `commands::credentials::intelligence`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct CredentialAnomaly {
+    pub id: String,
+    pub credential_id: String,
+    pub credential_name: String,
+    pub persona_id: Option<String>,
+    pub persona_name: Option<String>,
+    /// "new_persona", "off_hours_burst" or "use_after_rotation"
+    pub kind: String,
+    /// "medium" or "high"
+    pub severity: String,
+    pub title: String,
+    pub description: String,
+    /// JSON object with the counts and timestamps behind the flag.
+    pub evidence: String,
+    /// Identifies the underlying event so repeated scans don't re-flag it.
+    pub dedupe_key: String,
+    /// "open" or "acknowledged"
+    pub status: String,
+    pub detected_at: String,
+    pub acknowledged_at: Option<String>,
+}
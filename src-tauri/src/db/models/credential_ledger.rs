@@ -61,6 +61,7 @@ pub struct LedgerAnomalyScore {
 /// - **Health ring buffer**: `healthcheck_results`, `healthcheck_last_success`,
 ///   `healthcheck_last_success_at`, `anomaly_score`, `anomaly_tolerance`, `environment`
 /// - **OAuth lifecycle**: `oauth_token_expires_at`, `oauth_refresh_count`, etc.
+/// - **Expiry**: `expires_at`, `expiry_alerted`
 /// - **Usage tracking**: `usage_count`, `last_used_at`
 /// - **Custom hints**: any other keys (imported_from, source, auth_type, …)
 #[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
@@ -99,6 +100,18 @@ pub struct CredentialLedger {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub needs_reauth_at: Option<String>,
 
+    // ── Expiry ──────────────────────────────────────────────────────────
+    /// RFC 3339 instant the credential itself stops working: a PAT or API
+    /// key's expiry, or the provider-reported refresh token lifetime for
+    /// OAuth (unlike `oauth_token_expires_at`, which the refresh engine
+    /// renews on its own).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// `"<stage>:<expires_at>"` of the last renewal alert sent (`stage` is
+    /// `warning` or `expired`), so each stage alerts once per expiry date.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_alerted: Option<String>,
+
     // ── Usage tracking ──────────────────────────────────────────────────
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage_count: Option<u64>,
@@ -220,6 +233,14 @@ impl CredentialLedger {
             .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
     }
 
+    /// Parse `expires_at` (or the legacy camelCase `expiresAt` custom hint).
+    pub fn credential_expires_at(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        self.expires_at
+            .as_deref()
+            .or_else(|| self.custom.get("expiresAt").and_then(|v| v.as_str()))
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+    }
+
     /// Check if the credential is in OAuth refresh backoff.
     pub fn is_in_refresh_backoff(&self) -> bool {
         self.oauth_refresh_backoff_until
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// One credential negotiation run: the provisioning plan the negotiator
+/// generated and the credential it ended up producing.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CredentialNegotiation {
+    /// The `negotiation_id` returned by `start_credential_negotiation`.
+    pub id: String,
+    pub service_name: String,
+    /// `planning` | `guiding` | `completed` | `failed` | `cancelled`.
+    pub status: String,
+    /// JSON-serialized provisioning plan, once generated.
+    pub plan_json: Option<String>,
+    /// Credential saved from this negotiation, if any.
+    pub credential_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// A transcript entry. `detail_json` never holds credential values -- only
+/// field keys, step titles and secret-masked help text.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CredentialNegotiationEvent {
+    #[ts(type = "number")]
+    pub id: i64,
+    pub negotiation_id: String,
+    /// `plan_requested` | `plan_ready` | `plan_failed` | `step_completed` |
+    /// `fields_captured` | `help_requested` | `cancelled` | `credential_linked`.
+    pub kind: String,
+    pub step_index: Option<u32>,
+    pub detail_json: Option<String>,
+    pub created_at: String,
+}
+
+/// A negotiation with its entries, oldest first.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct NegotiationTranscript {
+    pub negotiation: CredentialNegotiation,
+    pub events: Vec<CredentialNegotiationEvent>,
+}
+
+/// A step-level event reported by the negotiator UI. Carries field keys,
+/// never their values.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct RecordNegotiationEventInput {
+    pub negotiation_id: String,
+    /// `step_completed` | `fields_captured`.
+    pub kind: String,
+    pub step_index: Option<u32>,
+    pub step_title: Option<String>,
+    /// Keys of the fields the user filled in.
+    #[serde(default)]
+    pub field_keys: Vec<String>,
+}
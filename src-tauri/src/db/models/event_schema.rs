@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Optional JSON Schema for one event type (`event_schemas`). When present,
+/// `publish_event` and webhook ingestion reject payloads that do not match,
+/// so subscribers never see a malformed event of that type.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct EventSchema {
+    pub event_type: String,
+    /// JSON Schema document, stored as text.
+    pub schema: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Arguments to `upsert_event_schema`.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct UpsertEventSchemaInput {
+    pub event_type: String,
+    pub schema: String,
+    pub description: Option<String>,
+}
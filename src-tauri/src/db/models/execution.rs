@@ -81,6 +81,20 @@ pub struct PersonaExecution {
     /// detail modal. `None` until the Director reviews this execution.
     #[serde(default)]
     pub director_review_md: Option<String>,
+    /// Experimental engine flags active for this run (JSON array of names,
+    /// see `engine::feature_flags`). `None` when none were enabled.
+    #[serde(default)]
+    pub feature_flags: Option<String>,
+    /// Set when the output was too large to keep inline: points at the
+    /// gzip file in the log directory holding the full text, and
+    /// `output_data` holds only a preview. `get_execution` reads the file
+    /// back transparently. See `engine::output_store`.
+    #[serde(default)]
+    pub output_ref: Option<String>,
+    /// Groups every execution one trigger firing fanned out to: the id of the
+    /// event the bus dispatched. `None` for runs not started by the bus.
+    #[serde(default)]
+    pub firing_id: Option<String>,
 }
 
 fn default_business_outcome() -> String {
@@ -164,6 +178,100 @@ pub struct ExecutionCounts {
     pub failed: i64,
 }
 
+/// Outcomes of terminal runs that had one engine feature flag active, for
+/// comparing an experimental path against the default.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct EngineFlagStats {
+    pub flag: String,
+    #[ts(type = "number")]
+    pub runs: i64,
+    #[ts(type = "number")]
+    pub completed: i64,
+    #[ts(type = "number")]
+    pub failed: i64,
+    pub avg_cost_usd: f64,
+    pub avg_duration_ms: Option<f64>,
+}
+
+/// Roll-up of every execution one trigger firing fanned out to.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct FiringRollup {
+    pub firing_id: String,
+    /// `running` while any run is active, then `completed` when every run
+    /// completed, `failed` when none did, `partial` otherwise.
+    pub status: String,
+    #[ts(type = "number")]
+    pub total: i64,
+    /// Queued or running.
+    #[ts(type = "number")]
+    pub active: i64,
+    #[ts(type = "number")]
+    pub completed: i64,
+    /// Failed, incomplete or cancelled.
+    #[ts(type = "number")]
+    pub failed: i64,
+    pub cost_usd: f64,
+    #[ts(type = "number")]
+    pub input_tokens: i64,
+    #[ts(type = "number")]
+    pub output_tokens: i64,
+    pub first_created_at: Option<String>,
+    /// Latest completion, once no run is active.
+    pub last_completed_at: Option<String>,
+}
+
+impl FiringRollup {
+    pub fn from_executions(firing_id: &str, executions: &[GlobalExecutionRow]) -> Self {
+        let mut rollup = FiringRollup {
+            firing_id: firing_id.to_string(),
+            total: executions.len() as i64,
+            ..Default::default()
+        };
+        for e in executions.iter().map(|row| &row.base) {
+            match e.state() {
+                ExecutionState::Queued | ExecutionState::Running => rollup.active += 1,
+                ExecutionState::Completed => rollup.completed += 1,
+                _ => rollup.failed += 1,
+            }
+            rollup.cost_usd += e.cost_usd;
+            rollup.input_tokens += e.input_tokens;
+            rollup.output_tokens += e.output_tokens;
+            if rollup
+                .first_created_at
+                .as_deref()
+                .is_none_or(|t| e.created_at.as_str() < t)
+            {
+                rollup.first_created_at = Some(e.created_at.clone());
+            }
+            if e.completed_at > rollup.last_completed_at {
+                rollup.last_completed_at = e.completed_at.clone();
+            }
+        }
+        rollup.status = if rollup.active > 0 {
+            rollup.last_completed_at = None;
+            "running"
+        } else if rollup.completed == rollup.total {
+            "completed"
+        } else if rollup.completed == 0 {
+            "failed"
+        } else {
+            "partial"
+        }
+        .to_string();
+        rollup
+    }
+}
+
+/// The executions one firing fanned out to, with their roll-up.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct FiringExecutions {
+    pub rollup: FiringRollup,
+    pub executions: Vec<GlobalExecutionRow>,
+}
+
 impl PersonaExecution {
     /// Parse the status string into the canonical ExecutionState enum.
     /// Logs an error if the stored status is unrecognised so data corruption
@@ -227,3 +335,11 @@ impl Default for UpdateExecutionStatus {
         }
     }
 }
+
+/// A cloud execution whose local row may not hold its final result.
+#[derive(Debug, Clone)]
+pub struct CloudExecutionLink {
+    pub execution_id: String,
+    pub persona_id: String,
+    pub cloud_execution_id: String,
+}
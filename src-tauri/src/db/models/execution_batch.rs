@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// A dataset batch (`execution_batches`): one execution of a persona per
+/// input item, fanned out through the normal execution queue under a shared
+/// batch id.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExecutionBatch {
+    pub id: String,
+    pub persona_id: String,
+    pub use_case_id: Option<String>,
+    /// Items of this batch allowed in flight at once.
+    pub concurrency: i32,
+    pub total_items: i32,
+    /// `running`, `completed`, `cancelled`, or `interrupted` (the app exited
+    /// before every item was started).
+    pub status: String,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+}
+
+/// One input item of a batch (`execution_batch_items`).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ExecutionBatchItem {
+    pub batch_id: String,
+    pub item_index: i32,
+    pub input_data: Option<String>,
+    /// Set once the item's execution has been created.
+    pub execution_id: Option<String>,
+    /// `pending`, `running`, `completed`, `failed` or `cancelled`.
+    pub status: String,
+    pub error: Option<String>,
+}
+
+/// Aggregated batch progress; also the `batch-execution-progress` payload.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ExecutionBatchProgress {
+    pub batch_id: String,
+    pub persona_id: String,
+    pub status: String,
+    pub total: i32,
+    pub pending: i32,
+    pub running: i32,
+    pub completed: i32,
+    pub failed: i32,
+    pub cancelled: i32,
+    /// Spend across the batch's executions so far.
+    pub cost_usd: f64,
+}
+
+/// One row of a batch results export.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ExecutionBatchResult {
+    pub item_index: i32,
+    pub input_data: Option<String>,
+    pub status: String,
+    pub execution_id: Option<String>,
+    pub output_data: Option<String>,
+    /// The item's start error, or its execution's error message.
+    pub error: Option<String>,
+    pub cost_usd: Option<f64>,
+    #[ts(type = "number | null")]
+    pub duration_ms: Option<i64>,
+}
@@ -84,3 +84,38 @@ pub struct ApiKeyAuditEntry {
     /// Request `Origin` header, if any (browser callers).
     pub origin: Option<String>,
 }
+
+// ============================================================================
+// Execute hooks (per-persona tokens for `/hooks/execute/<token>`)
+// ============================================================================
+
+/// A persona's execute hook. The token is stored hashed like API keys; only the
+/// prefix is ever shown again after creation.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PersonaExecuteHook {
+    pub persona_id: String,
+    /// SHA-256 hex hash of the token. Never sent to the frontend.
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    pub token_hash: String,
+    /// First several chars of the token (e.g. "ph_a1b2c3"); safe to display.
+    pub token_prefix: String,
+    /// Default seconds a caller waits for the result before getting the
+    /// execution id back. `0` = always respond immediately.
+    #[ts(type = "number")]
+    pub wait_timeout_secs: i64,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+}
+
+/// Returned only when a hook token is (re)generated — the one time the
+/// plaintext token leaves the backend.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CreateExecuteHookResponse {
+    pub hook: PersonaExecuteHook,
+    pub plaintext_token: String,
+    /// Full local URL to POST to, e.g. `http://127.0.0.1:9420/hooks/execute/ph_…`.
+    pub url: String,
+}
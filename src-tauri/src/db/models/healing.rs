@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use super::serde_util::double_option;
+use super::Json;
+
 // ============================================================================
 // Healing Audit Log (silent failure surface)
 // ============================================================================
@@ -50,6 +53,30 @@ pub struct PersonaHealingIssue {
     /// and the Director's own dedup against its open issues.
     #[serde(default)]
     pub source: Option<String>,
+    /// Model-written analysis of the failure behind the issue, when the
+    /// persona opted into root-cause summaries (see
+    /// `engine::healing_root_cause`).
+    #[serde(default)]
+    pub root_cause: Option<Json<HealingRootCause>>,
+}
+
+/// Structured root-cause summary of an execution failure, generated from the
+/// run's error and log tail.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HealingRootCause {
+    /// One-sentence account of what went wrong.
+    pub summary: String,
+    /// The underlying cause, as specific as the evidence allows.
+    pub cause: String,
+    /// Log lines or error fragments the analysis rests on.
+    pub evidence: Vec<String>,
+    /// Concrete next step for the user. Also replaces the issue's
+    /// `suggested_fix`.
+    pub suggested_fix: String,
+    /// "low" | "medium" | "high"
+    pub confidence: String,
+    pub generated_at: String,
 }
 
 // ============================================================================
@@ -98,4 +125,84 @@ pub struct HealingKnowledge {
     pub occurrence_count: i64,
     pub last_seen_at: String,
     pub created_at: String,
+    /// Edited or imported by hand. Learning still bumps the occurrence count
+    /// of a curated entry but no longer overwrites its description or delay.
+    pub curated: bool,
+}
+
+/// Manual correction of a knowledge entry. Omitted fields are unchanged;
+/// `recommended_delay_secs: null` clears the learned delay.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UpdateHealingKnowledgeInput {
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "double_option")]
+    #[ts(type = "number | null | undefined")]
+    pub recommended_delay_secs: Option<Option<i64>>,
+}
+
+/// One entry of an exported knowledge base, free of install-specific ids.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HealingKnowledgeRecord {
+    pub service_type: String,
+    pub pattern_key: String,
+    pub description: String,
+    #[serde(default)]
+    #[ts(type = "number | null")]
+    pub recommended_delay_secs: Option<i64>,
+    /// Defaults to 1 when absent, so hand-written seed files stay short.
+    #[serde(default)]
+    #[ts(type = "number | null")]
+    pub occurrence_count: Option<i64>,
+}
+
+/// Portable knowledge base document produced by `export_healing_knowledge`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HealingKnowledgeExport {
+    pub version: u32,
+    pub exported_at: String,
+    pub entries: Vec<HealingKnowledgeRecord>,
+}
+
+/// Outcome of `import_healing_knowledge`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct HealingKnowledgeImportResult {
+    #[ts(type = "number")]
+    pub created: i64,
+    #[ts(type = "number")]
+    pub updated: i64,
+}
+
+// ============================================================================
+// Fleet Healing Recommendations (opt-in cross-install learning)
+// ============================================================================
+
+/// An aggregated backoff recommendation downloaded from the fleet pattern
+/// endpoint. Only consulted when the local knowledge base has no delay of its
+/// own for the same service + pattern.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FleetHealingRecommendation {
+    pub service_type: String,
+    pub pattern_key: String,
+    #[ts(type = "number")]
+    pub recommended_delay_secs: i64,
+    /// How many installs contributed to this aggregate.
+    #[ts(type = "number")]
+    pub install_count: i64,
+    pub fetched_at: String,
+}
+
+/// Outcome of one fleet pattern exchange (upload + download).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct FleetPatternSyncResult {
+    #[ts(type = "number")]
+    pub uploaded: i64,
+    #[ts(type = "number")]
+    pub downloaded: i64,
+    pub synced_at: String,
 }
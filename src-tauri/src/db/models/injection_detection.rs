@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+// ============================================================================
+// InjectionDetection — inbound payloads flagged by the prompt-injection scan
+// ============================================================================
+//
+// `engine::injection_scan` screens event payloads before the event bus turns
+// them into executions. Every payload it flags is recorded here together with
+// what the trigger's policy did about it, so a human can review the call and
+// mark false positives (which lets the same payload through from then on).
+
+/// One flagged inbound payload.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionDetection {
+    pub id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub source_type: String,
+    pub source_id: Option<String>,
+    /// Trigger whose policy applied; `None` when the global default did.
+    pub trigger_id: Option<String>,
+    /// Heuristic score in `0.0..=1.0`.
+    pub score: f64,
+    /// `"medium"` | `"high"`.
+    pub level: String,
+    /// JSON array of the heuristic signal names that matched.
+    pub signals: String,
+    /// `"injection"` | `"benign"` when the LLM check ran.
+    pub llm_verdict: Option<String>,
+    /// Policy in force: `"flag"` | `"sanitize"` | `"block"` | `"require_approval"`.
+    pub policy: String,
+    /// What happened: `"flagged"` | `"sanitized"` | `"blocked"` | `"held"`.
+    pub action: String,
+    /// SHA-256 of the raw payload; a reviewed false positive clears the hash.
+    pub payload_hash: String,
+    /// First few hundred characters of the payload, for review.
+    pub excerpt: Option<String>,
+    /// Pending trigger fire created when the policy held the event.
+    pub pending_fire_id: Option<String>,
+    /// `"unreviewed"` | `"false_positive"` | `"confirmed"`.
+    pub review_status: String,
+    pub created_at: String,
+    pub reviewed_at: Option<String>,
+}
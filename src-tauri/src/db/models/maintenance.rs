@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// A maintenance window (`maintenance_windows`). While one is active, trigger
+/// fires for the covered personas are still received and recorded as pending
+/// events, but the event bus leaves them unclaimed until the window ends.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MaintenanceWindow {
+    pub id: String,
+    /// `None` = global: every persona is in maintenance.
+    pub persona_id: Option<String>,
+    pub starts_at: String,
+    /// `None` = open-ended; lasts until `end_maintenance_window` is called.
+    pub ends_at: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: String,
+}
+
+/// Arguments to `create_maintenance_window`.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct CreateMaintenanceWindowInput {
+    /// `None` for a global window.
+    pub persona_id: Option<String>,
+    /// RFC 3339; `None` starts the window now.
+    pub starts_at: Option<String>,
+    /// RFC 3339; `None` for an open-ended window.
+    pub ends_at: Option<String>,
+    pub reason: Option<String>,
+}
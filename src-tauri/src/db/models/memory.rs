@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use super::serde_util::double_option;
 use super::Json;
 
 // ============================================================================
@@ -329,6 +330,11 @@ pub struct PersonaMemory {
     /// never silently removed — resolution is a human decision.
     #[serde(default)]
     pub open_claim_count: i32,
+    /// When set (RFC 3339, UTC), the memory stops being injected once this
+    /// instant passes and the hourly cleanup sweep moves it to the archive
+    /// tier. For facts with a shelf life (prices, endpoints, deadlines).
+    #[serde(default)]
+    pub expires_at: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -344,3 +350,23 @@ pub struct CreatePersonaMemoryInput {
     #[serde(default)]
     pub use_case_id: Option<String>,
 }
+
+/// Partial edit of an existing memory. Omitted fields are left unchanged;
+/// `expires_at: null` clears the expiry.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UpdatePersonaMemoryInput {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub importance: Option<i32>,
+    /// Replaces the tag set wholesale.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "double_option")]
+    pub expires_at: Option<Option<String>>,
+}
@@ -8,15 +8,18 @@ mod chat;
 mod connector;
 mod credential;
 mod credential_ledger;
+mod credential_negotiation;
 mod credential_recipe;
 mod db_schema;
 mod deliberation;
 mod design_conversation;
 mod dev_tools;
 mod event;
+mod event_schema;
 mod evolution;
 mod execution;
 mod execution_annotation;
+mod execution_batch;
 #[cfg(feature = "p2p")]
 mod exposure;
 mod external_api_key;
@@ -25,16 +28,20 @@ mod genome;
 mod healing;
 #[cfg(feature = "p2p")]
 mod identity;
+mod injection_detection;
 mod json_column;
 mod knowledge;
 #[cfg(feature = "ml")]
 mod knowledge_base;
 pub(crate) mod lab;
 mod llm_spend;
+mod maintenance;
 mod memory;
 mod message;
 mod n8n_session;
+mod notification_rule;
 mod notification_subscription;
+mod notification_template;
 mod oauth_token_metric;
 mod observability;
 mod obsidian_brain;
@@ -45,8 +52,10 @@ mod persona;
 mod persona_change_log;
 mod platform_definition;
 mod policy_event;
+mod prompt_block;
 mod recipe;
 mod recipe_suggestion;
+mod report_sink;
 mod research_lab;
 mod review;
 mod rotation;
@@ -65,6 +74,7 @@ mod team_channel;
 mod team_memory;
 mod team_preset;
 mod template_feedback;
+mod template_variable;
 mod test_run;
 mod test_suite;
 mod tool;
@@ -84,15 +94,18 @@ pub use chat::*;
 pub use connector::*;
 pub use credential::*;
 pub use credential_ledger::*;
+pub use credential_negotiation::*;
 pub use credential_recipe::*;
 pub use db_schema::*;
 pub use deliberation::*;
 pub use design_conversation::*;
 pub use dev_tools::*;
 pub use event::*;
+pub use event_schema::*;
 pub use evolution::*;
 pub use execution::*;
 pub use execution_annotation::*;
+pub use execution_batch::*;
 #[cfg(feature = "p2p")]
 pub use exposure::*;
 pub use external_api_key::*;
@@ -101,16 +114,20 @@ pub use genome::*;
 pub use healing::*;
 #[cfg(feature = "p2p")]
 pub use identity::*;
+pub use injection_detection::*;
 pub use json_column::Json;
 pub use knowledge::*;
 #[cfg(feature = "ml")]
 pub use knowledge_base::*;
 pub use lab::*;
 pub use llm_spend::*;
+pub use maintenance::*;
 pub use memory::*;
 pub use message::*;
 pub use n8n_session::*;
+pub use notification_rule::*;
 pub use notification_subscription::*;
+pub use notification_template::*;
 pub use oauth_token_metric::*;
 pub use observability::*;
 pub use obsidian_brain::*;
@@ -121,8 +138,10 @@ pub use persona::*;
 pub use persona_change_log::*;
 pub use platform_definition::*;
 pub use policy_event::*;
+pub use prompt_block::*;
 pub use recipe::*;
 pub use recipe_suggestion::*;
+pub use report_sink::*;
 pub use research_lab::*;
 pub use review::*;
 pub use rotation::*;
@@ -140,6 +159,7 @@ pub use team_channel::*;
 pub use team_memory::*;
 pub use team_preset::*;
 pub use template_feedback::*;
+pub use template_variable::*;
 pub use test_run::*;
 pub use test_suite::*;
 pub use tool::*;
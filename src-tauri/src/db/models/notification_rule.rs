@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// A notification routing rule (`notification_rules`). Every notification the
+/// app raises (budget alerts, healing issues, execution completions, manual
+/// reviews, messages) is matched against the enabled rules; a matching
+/// `deliver` rule sends it to the rule's channel, and a matching `silent` rule
+/// suppresses the desktop toast and the persona's own channels for it.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRule {
+    pub id: String,
+    pub name: String,
+    /// `budget`, `healing`, `execution`, `manual_review`, `message`, or `*`
+    /// for every category.
+    pub category: String,
+    /// Only notifications about this persona match. `None` matches all.
+    pub persona_id: Option<String>,
+    /// Only notifications about members of this workspace (team) match.
+    pub team_id: Option<String>,
+    /// Lowest severity that matches: `info`, `low`, `medium`, `high` or
+    /// `critical`.
+    pub min_severity: String,
+    /// `deliver` (send to the rule's channel) or `silent`.
+    pub action: String,
+    /// `slack`, `telegram`, `email`, `discord` or `teams`; required for
+    /// `deliver` rules.
+    pub channel_type: Option<String>,
+    /// Vault credential holding the channel's auth (bot token, webhook URL…).
+    pub credential_id: Option<String>,
+    /// JSON object layered over the credential's fields, e.g.
+    /// `{"channel": "#finance"}` or `{"chat_id": "123"}`.
+    pub channel_config: Option<String>,
+    /// Local-time `HH:MM` window during which the rule does not deliver.
+    /// May wrap midnight (`22:00`–`07:00`).
+    pub quiet_hours_start: Option<String>,
+    pub quiet_hours_end: Option<String>,
+    /// Deliveries allowed per rolling hour; further matches are dropped.
+    pub max_per_hour: Option<i32>,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateNotificationRuleInput {
+    pub name: String,
+    pub category: String,
+    pub persona_id: Option<String>,
+    pub team_id: Option<String>,
+    pub min_severity: Option<String>,
+    pub action: String,
+    pub channel_type: Option<String>,
+    pub credential_id: Option<String>,
+    pub channel_config: Option<String>,
+    pub quiet_hours_start: Option<String>,
+    pub quiet_hours_end: Option<String>,
+    pub max_per_hour: Option<i32>,
+    pub enabled: Option<bool>,
+}
+
+/// Partial update payload. `None` leaves a field untouched; an empty string
+/// clears an optional text field, and `0` clears `max_per_hour`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationRuleInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub persona_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credential_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_config: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours_end: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_per_hour: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
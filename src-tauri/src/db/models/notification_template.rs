@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// User wording for one notification type (`notification_templates`).
+/// `{{variable}}` placeholders are filled from the notification; see
+/// `notifications::TEMPLATE_TYPES` for the variables each type offers.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationTemplate {
+    pub id: String,
+    /// `execution_completed`, `execution_failed`, `healing_issue`,
+    /// `budget_exceeded` or `manual_review`.
+    pub notification_type: String,
+    /// UI language the template applies to (`en`, `pt-br`, ...), or `*` for
+    /// every language without a template of its own.
+    pub locale: String,
+    pub title_template: String,
+    pub body_template: String,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Create the template for a type + locale, or replace the existing one.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertNotificationTemplateInput {
+    pub notification_type: String,
+    /// Defaults to `*`.
+    pub locale: Option<String>,
+    pub title_template: String,
+    pub body_template: String,
+    pub enabled: Option<bool>,
+}
+
+/// A template to render without saving it.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewNotificationTemplateInput {
+    pub notification_type: String,
+    pub title_template: String,
+    pub body_template: String,
+    /// Values to render with; variables left out use sample values.
+    pub variables: Option<HashMap<String, String>>,
+}
+
+/// A rendered template preview.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationTemplatePreview {
+    pub title: String,
+    pub body: String,
+    /// Every variable the notification type offers.
+    pub available_variables: Vec<String>,
+    /// The variables the template references.
+    pub used_variables: Vec<String>,
+}
@@ -147,6 +147,65 @@ pub struct PersonaMetricsSnapshot {
     pub created_at: String,
 }
 
+/// Period length of a `persona_metrics_rollups` row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricsRollupGranularity {
+    /// ISO week, starting Monday.
+    Week,
+    /// Calendar month.
+    Month,
+}
+
+impl MetricsRollupGranularity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+}
+
+/// One week or month of rolled-up metrics, summed over the selected personas.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MetricsRollupPoint {
+    /// First day of the period (UTC, `YYYY-MM-DD`).
+    pub period_start: String,
+    pub cost: f64,
+    #[ts(type = "number")]
+    pub executions: i64,
+    #[ts(type = "number")]
+    pub success: i64,
+    #[ts(type = "number")]
+    pub failed: i64,
+    #[ts(type = "number")]
+    pub tokens: i64,
+    /// Execution-weighted mean of the daily average durations.
+    pub avg_duration_ms: f64,
+    /// Number of distinct personas with executions in the period.
+    #[ts(type = "number")]
+    pub active_personas: i64,
+}
+
+/// Result of `rebuild_metrics_snapshots`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct MetricsSnapshotRebuild {
+    /// First and last day rebuilt (UTC, inclusive).
+    pub start_date: String,
+    pub end_date: String,
+    #[ts(type = "number")]
+    pub days: i64,
+    /// Daily snapshot rows written or refreshed.
+    #[ts(type = "number")]
+    pub snapshots_written: i64,
+    /// Weekly and monthly rollup rows rewritten.
+    #[ts(type = "number")]
+    pub rollups_written: i64,
+}
+
 // ============================================================================
 // Observability: Metrics Summary
 // ============================================================================
@@ -278,6 +337,129 @@ pub struct ModelValueShare {
     pub value_delivered: i64,
 }
 
+/// Cost and outcome of the runs one trigger started over a window — what each
+/// automation costs per firing and how often it succeeds, so expensive
+/// low-value triggers can be pruned. Simulations (dry-run firings) excluded.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerRoi {
+    /// `None` for the persona's runs no trigger started (manual, API, pipeline).
+    pub trigger_id: Option<String>,
+    /// `schedule`, `webhook`, `chain`, … or `untriggered`.
+    pub trigger_type: String,
+    pub enabled: bool,
+    /// Executions the trigger started in the window, healing retries included.
+    #[ts(type = "number")]
+    pub firings: i64,
+    #[ts(type = "number")]
+    pub succeeded: i64,
+    /// Failed or incomplete runs.
+    #[ts(type = "number")]
+    pub failed: i64,
+    /// Runs whose `business_outcome` is `value_delivered`.
+    #[ts(type = "number")]
+    pub value_delivered: i64,
+    /// `succeeded / finished runs` (0.0 when none finished).
+    pub success_rate: f64,
+    pub total_cost_usd: f64,
+    /// `total_cost_usd / firings` (0.0 when it never fired).
+    pub cost_per_firing: f64,
+    /// `total_cost_usd / succeeded`. `None` when nothing succeeded.
+    pub cost_per_success: Option<f64>,
+    #[ts(type = "number | null")]
+    pub avg_duration_ms: Option<i64>,
+    pub last_fired_at: Option<String>,
+}
+
+// ============================================================================
+// Observability: Cost analytics
+// ============================================================================
+
+/// Dimension a cost breakdown is grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum CostGroupBy {
+    #[default]
+    Persona,
+    /// The persona's home team.
+    Group,
+    Model,
+    /// The connector behind the credentialed tools a run used.
+    Connector,
+    UseCase,
+}
+
+/// Spend over a window split along one dimension, with a day-level series for
+/// the whole window and for each group. Simulations are excluded.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CostBreakdown {
+    #[ts(type = "number")]
+    pub period_days: i64,
+    pub group_by: CostGroupBy,
+    pub total_cost_usd: f64,
+    #[ts(type = "number")]
+    pub total_executions: i64,
+    /// One point per day of the window (UTC), oldest first, zero-filled.
+    pub daily: Vec<CostDayPoint>,
+    /// Groups descending by cost.
+    pub groups: Vec<CostGroup>,
+}
+
+/// One group's slice of a [`CostBreakdown`].
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CostGroup {
+    /// Persona / team / use-case id, model name or connector name. `none` for
+    /// runs with nothing to group by (no team, model, use case or connector).
+    pub key: String,
+    /// Display name: persona, team or use-case name; the key otherwise.
+    pub label: String,
+    pub cost_usd: f64,
+    /// Runs in this group. A run that used several connectors counts toward
+    /// each of them, so these can sum past `total_executions`.
+    #[ts(type = "number")]
+    pub executions: i64,
+    /// `cost_usd / total_cost_usd` (0.0 when nothing was spent).
+    pub share: f64,
+    /// Same days as `CostBreakdown::daily`.
+    pub daily: Vec<CostDayPoint>,
+}
+
+/// Spend and run count on one day. `date` is YYYY-MM-DD (UTC).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CostDayPoint {
+    pub date: String,
+    pub cost_usd: f64,
+    #[ts(type = "number")]
+    pub executions: i64,
+}
+
+/// A day whose spend sits more than two standard deviations from the mean of
+/// the days before it.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct CostAnomaly {
+    pub date: String,
+    pub cost_usd: f64,
+    /// Mean daily spend over the trailing window.
+    pub trailing_mean_usd: f64,
+    pub trailing_std_dev_usd: f64,
+    /// `(cost_usd - trailing_mean_usd) / trailing_std_dev_usd`; negative for drops.
+    pub deviation_sigma: f64,
+    /// The persona that spent the most that day, if any spent anything.
+    pub top_persona_id: Option<String>,
+    pub top_persona_name: Option<String>,
+    pub top_persona_cost_usd: f64,
+}
+
 // ============================================================================
 // Observability: Category-aware error analytics
 // ============================================================================
@@ -398,6 +580,56 @@ pub struct ExecutionHeatmapData {
     pub generated_at: String,
 }
 
+// ============================================================================
+// Observability: Failure Heatmap (hour of day × connector)
+// ============================================================================
+
+/// Failures in one local hour of day attributed to one service.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct FailureHeatmapCell {
+    /// Hour of day (0-23) in the client's timezone.
+    pub hour: u8,
+    /// Connector name the failure mentions, `provider` for AI-provider session
+    /// limits and missing CLIs, or `unattributed`.
+    pub service: String,
+    #[ts(type = "number")]
+    pub failures: i64,
+    /// Most frequent snake_case `ErrorCategory` token in this cell.
+    pub top_category: String,
+}
+
+/// One service's failures over the window and its worst hour.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct FailureHeatmapService {
+    pub service: String,
+    #[ts(type = "number")]
+    pub failures: i64,
+    pub peak_hour: u8,
+    #[ts(type = "number")]
+    pub peak_hour_failures: i64,
+}
+
+/// Failures from failed executions and healing issues bucketed by hour of
+/// day × connector, for spotting recurring windows (e.g. nightly rate limits).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct FailureHeatmapData {
+    /// Non-empty cells, sorted by hour then service.
+    pub cells: Vec<FailureHeatmapCell>,
+    /// Per-service totals, descending by failures.
+    pub services: Vec<FailureHeatmapService>,
+    #[ts(type = "number")]
+    pub total_failures: i64,
+    #[ts(type = "number")]
+    pub window_days: i64,
+    pub generated_at: String,
+}
+
 // ============================================================================
 // Observability: Prompt Performance Dashboard
 // ============================================================================
@@ -211,6 +211,55 @@ impl FromStr for PersonaLifecycle {
     }
 }
 
+/// Where `execute_persona` runs a persona when a cloud orchestrator is
+/// connected (stored lowercase on `personas.execution_placement`; NULL means
+/// `PreferLocal`, the behaviour before cloud routing existed).
+///
+/// - `PreferLocal` — always run locally.
+/// - `PreferCloud` — submit to the cloud orchestrator whenever it is
+///                   connected; local when it is not or submission fails.
+/// - `Auto`        — local, except when local concurrency is saturated or
+///                   the machine is running on battery.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionPlacement {
+    #[default]
+    PreferLocal,
+    PreferCloud,
+    Auto,
+}
+
+impl fmt::Display for ExecutionPlacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ExecutionPlacement {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PreferLocal => "prefer_local",
+            Self::PreferCloud => "prefer_cloud",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+impl FromStr for ExecutionPlacement {
+    type Err = AppError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "prefer_local" => Ok(Self::PreferLocal),
+            "prefer_cloud" => Ok(Self::PreferCloud),
+            "auto" => Ok(Self::Auto),
+            _ => Err(AppError::Validation(format!(
+                "Invalid execution_placement '{s}': must be 'prefer_local', 'prefer_cloud', or 'auto'"
+            ))),
+        }
+    }
+}
+
 /// Parameter type discriminator for persona free parameters.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -641,6 +690,17 @@ pub struct Persona {
     /// NULL = no disables; this is the default for fresh + legacy rows.
     #[serde(default)]
     pub disabled_dims_json: Option<String>,
+    /// Per-persona CLI engine override (`EngineKind::as_setting` string).
+    /// NULL = use the global `cli_engine` setting. Resolved per execution by
+    /// `engine::provider::resolve_engine_kind`; an unknown value falls back
+    /// to the global default rather than failing the run.
+    #[serde(default)]
+    pub engine: Option<String>,
+    /// Cloud/local routing policy (`ExecutionPlacement` string). NULL =
+    /// `prefer_local`. Applied by `execute_persona` when a cloud
+    /// orchestrator is connected; an unknown value runs locally.
+    #[serde(default)]
+    pub execution_placement: Option<String>,
     /// First-class lifecycle stage (`draft` | `active` | `archived`). Replaces
     /// the old frontend draft heuristic. Defaults to `active`; the build-stub
     /// creation path stamps `draft`, promote stamps `active`, archive/restore
@@ -909,6 +969,16 @@ pub struct UpdatePersonaInput {
     /// the column with `Some(None)`. Set by the View-mode SigilEditModal.
     #[serde(default, deserialize_with = "double_option")]
     pub disabled_dims_json: Option<Option<String>>,
+    /// Per-persona CLI engine override. `Some(None)` clears it back to the
+    /// global `cli_engine` default; the repo validates the value against
+    /// `EngineKind`.
+    #[serde(default, deserialize_with = "double_option")]
+    pub engine: Option<Option<String>>,
+    /// Cloud/local routing policy. `Some(None)` clears it back to
+    /// `prefer_local`; the repo validates the value against
+    /// `ExecutionPlacement`.
+    #[serde(default, deserialize_with = "double_option")]
+    pub execution_placement: Option<Option<String>>,
     /// Lifecycle stage (`draft` | `active` | `archived`). Partial-update:
     /// `None` = leave unchanged. Promote/archive/restore drive this; the repo
     /// validates the value against `PersonaLifecycle`.
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use super::TemplateVariablePersonaRef;
+
+/// A reusable prompt section (tone guide, compliance disclaimer, output
+/// format) shared across personas. A structured prompt references it as a
+/// `customSections` entry `{"blockId": "<id>"}`; the current content is
+/// inlined at run time.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PromptBlock {
+    pub id: String,
+    /// Unique; used as the section heading unless the reference sets a title.
+    pub name: String,
+    /// `tone` | `compliance` | `output_format` | `other`.
+    pub category: String,
+    pub content: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Fields accepted by `save_prompt_block`. With an `id` the block is updated
+/// in place; without one a new block is created.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct SavePromptBlockInput {
+    pub id: Option<String>,
+    pub name: String,
+    pub category: String,
+    pub content: String,
+    pub description: Option<String>,
+}
+
+/// Which personas reference a block from their structured prompt.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PromptBlockUsage {
+    pub block_id: String,
+    /// `None` when personas reference a block that no longer exists.
+    pub block_name: Option<String>,
+    pub personas: Vec<TemplateVariablePersonaRef>,
+}
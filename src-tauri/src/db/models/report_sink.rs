@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// Where and how a persona's run outputs are written as report files.
+/// One sink per persona (`persona_report_sinks`).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PersonaReportSink {
+    pub persona_id: String,
+    pub enabled: bool,
+    /// Absolute directory the reports are written into (created on demand).
+    pub directory: String,
+    /// `markdown` | `html`.
+    pub format: String,
+    /// Mustache-style template (`{{execution.output}}`, `{{persona.name}}`,
+    /// `{{date}}`, ...). `None` uses the built-in report layout.
+    pub template: Option<String>,
+    /// Filename stem before the date stamp. Defaults to the persona name.
+    pub filename_prefix: Option<String>,
+    /// Only write reports for runs fired by a schedule trigger.
+    pub scheduled_only: bool,
+    /// Also print the report to PDF through a local Chromium-family browser.
+    pub pdf: bool,
+    pub last_written_path: Option<String>,
+    pub last_written_at: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Settings accepted by `set_persona_report_sink`.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct SaveReportSinkInput {
+    pub enabled: bool,
+    pub directory: String,
+    pub format: String,
+    pub template: Option<String>,
+    pub filename_prefix: Option<String>,
+    pub scheduled_only: bool,
+    pub pdf: bool,
+}
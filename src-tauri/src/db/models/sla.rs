@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 #[derive(Debug, Serialize, TS)]
@@ -90,3 +90,71 @@ pub struct SlaDailyPoint {
     /// cancelled executions are excluded (user-initiated, not a reliability signal).
     pub success_rate: f64,
 }
+
+/// A persona's service-level objectives (`persona_slos`). The SLO monitor
+/// checks them every few minutes over a rolling window; crossing into breach
+/// opens a healing issue and sends a notification, once per breach.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PersonaSlo {
+    pub persona_id: String,
+    /// Lowest acceptable success rate (0.0--1.0, same denominator as
+    /// `success_rate` above). `None` = not tracked.
+    pub min_success_rate: Option<f64>,
+    /// Highest acceptable p95 duration of completed runs. `None` = not tracked.
+    #[ts(type = "number | null")]
+    pub max_p95_duration_ms: Option<i64>,
+    #[ts(type = "number")]
+    pub window_hours: i64,
+    /// Fewest decided runs in the window before the SLO is judged at all.
+    #[ts(type = "number")]
+    pub min_sample: i64,
+    pub enabled: bool,
+    /// Violated objectives (`success_rate`, `p95_duration`, comma-separated)
+    /// while in breach.
+    pub breach_reason: Option<String>,
+    /// When the current breach began; `None` while the SLO is met.
+    pub breached_at: Option<String>,
+    /// Healing issue opened for the current breach, resolved on recovery.
+    pub breach_issue_id: Option<String>,
+    pub last_evaluated_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Arguments to `upsert_persona_slo`. At least one objective must be set.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct UpsertPersonaSloInput {
+    pub persona_id: String,
+    pub min_success_rate: Option<f64>,
+    #[ts(type = "number | null")]
+    pub max_p95_duration_ms: Option<i64>,
+    /// Defaults to 24.
+    #[ts(type = "number | null")]
+    pub window_hours: Option<i64>,
+    /// Defaults to 5.
+    #[ts(type = "number | null")]
+    pub min_sample: Option<i64>,
+    /// Defaults to true.
+    pub enabled: Option<bool>,
+}
+
+/// An SLO with its live measurement over the window, for the UI.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct SloStatus {
+    pub slo: PersonaSlo,
+    pub persona_name: String,
+    /// `ok` | `breached` | `insufficient_data` | `disabled`.
+    pub state: String,
+    /// Objectives violated right now (`success_rate`, `p95_duration`).
+    pub violations: Vec<String>,
+    /// Completed + failed runs in the window.
+    #[ts(type = "number")]
+    pub decided: i64,
+    /// `None` when the window has no decided runs.
+    pub success_rate: Option<f64>,
+    /// `None` when the window has no timed completed runs.
+    pub p95_duration_ms: Option<f64>,
+}
@@ -21,7 +21,9 @@ pub struct SystemOpAutomation {
     pub op_kind: String,
     /// JSON-encoded op params (e.g. `{"projectId":"…","deltaMode":true}`).
     pub params_json: String,
-    /// `"schedule"` (cron) | `"event"` (event-bus listener).
+    /// `"schedule"` (cron) | `"event"` (event-bus listener) | `"webhook"`
+    /// (`/webhook/<id>`, secret in the `webhookSecret` param) | `"chain"` (an
+    /// execution of the `source_filter` persona finishing).
     pub trigger_kind: String,
     /// Cron expression — set when `trigger_kind == "schedule"`.
     pub cron: Option<String>,
@@ -29,7 +31,8 @@ pub struct SystemOpAutomation {
     pub timezone: Option<String>,
     /// Event type to listen for — set when `trigger_kind == "event"`.
     pub listen_event_type: Option<String>,
-    /// Optional wildcard filter on the event `source_id`.
+    /// Optional wildcard filter on the event `source_id`; for `chain`, the
+    /// source persona id (required).
     pub source_filter: Option<String>,
     pub enabled: bool,
     /// Next computed fire time (schedule kind only).
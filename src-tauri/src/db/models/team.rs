@@ -1,7 +1,11 @@
+use std::collections::HashMap;
+
 use crate::db::models::serde_util::double_option;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+use super::Json;
+
 // ============================================================================
 // Teams
 // ============================================================================
@@ -26,6 +30,11 @@ pub struct PersonaTeam {
     pub default_model_profile: Option<String>,
     pub default_max_budget_usd: Option<f64>,
     pub default_max_turns: Option<i32>,
+    /// Workspace-wide monthly spend cap (USD) summed across every persona whose
+    /// `home_team_id` is this team. Unlike `default_max_budget_usd` (a default
+    /// copied onto each persona), this is enforced on the aggregate. `None` or
+    /// `0.0` means unlimited.
+    pub monthly_budget_usd: Option<f64>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -68,6 +77,8 @@ pub struct UpdateTeamInput {
     pub default_max_budget_usd: Option<Option<f64>>,
     #[serde(default, deserialize_with = "double_option")]
     pub default_max_turns: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "double_option")]
+    pub monthly_budget_usd: Option<Option<f64>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -145,6 +156,38 @@ pub struct PipelineRun {
     pub started_at: String,
     pub completed_at: Option<String>,
     pub error_message: Option<String>,
+    /// Dry-run settings when the run came from `simulate_team_run`; `None`
+    /// for real runs. Kept on the row so a resumed or retried simulation
+    /// stays simulated.
+    #[serde(default)]
+    pub simulation: Option<Json<PipelineSimulation>>,
+}
+
+/// A canned result a simulated persona node uses in place of calling a tool.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct SimulatedToolResponse {
+    pub tool_name: String,
+    #[ts(type = "unknown")]
+    pub response: serde_json::Value,
+}
+
+/// How `simulate_team_run` runs a pipeline without real side effects.
+///
+/// Persona nodes run flagged as simulations (outbound delivery is skipped)
+/// and are told not to call tools: they use the configured responses and
+/// report every call they would make, which lands on the node status as
+/// `tool_calls`. Command nodes never run their shell command; they output
+/// the configured text, or a placeholder. Approval gates pass automatically
+/// and no team memories are written.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct PipelineSimulation {
+    #[serde(default)]
+    pub tool_responses: Vec<SimulatedToolResponse>,
+    /// Fabricated output per command-node member id.
+    #[serde(default)]
+    pub command_outputs: HashMap<String, String>,
 }
 
 impl PipelineRun {
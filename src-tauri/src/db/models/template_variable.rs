@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+/// A named value substituted into persona prompts as `{{var.<name>}}`.
+/// Resolution order is persona, then the persona's home team, then global.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct TemplateVariable {
+    pub id: String,
+    /// `global` | `team` | `persona`.
+    pub scope: String,
+    /// Team or persona id; empty for `global`.
+    pub scope_id: String,
+    pub name: String,
+    pub value: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Fields accepted by `save_template_variable`. Saving an existing
+/// `(scope, scope_id, name)` replaces its value.
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export)]
+pub struct SaveTemplateVariableInput {
+    pub scope: String,
+    pub scope_id: Option<String>,
+    pub name: String,
+    pub value: String,
+    pub description: Option<String>,
+}
+
+/// Persona reference in a [`TemplateVariableUsage`].
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TemplateVariablePersonaRef {
+    pub id: String,
+    pub name: String,
+}
+
+/// Which personas reference `{{var.<name>}}` in their prompt fields.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TemplateVariableUsage {
+    pub name: String,
+    /// False when personas reference a name no scope defines.
+    pub defined: bool,
+    pub personas: Vec<TemplateVariablePersonaRef>,
+}
+
+/// A variable a persona's prompt can reference as `{{<name>}}`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PromptVariable {
+    /// Placeholder name, e.g. `today`, `param.tone`, `var.company_name`.
+    pub name: String,
+    /// `builtin` | `parameter` | `registry` | `setting` | `input`.
+    pub source: String,
+    /// Substituted value; input values are shown sanitized.
+    pub value: String,
+}
+
+/// Result of `preview_compiled_prompt`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CompiledPromptPreview {
+    /// The assembled prompt with every resolvable placeholder substituted.
+    pub prompt: String,
+    pub variables: Vec<PromptVariable>,
+    /// Placeholders left in the prompt because no source defines them.
+    pub unresolved: Vec<String>,
+}
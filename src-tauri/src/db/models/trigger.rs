@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -260,6 +260,165 @@ impl ActiveWindow {
     }
 }
 
+/// What a schedule does about fire times that passed while the app was closed.
+/// Stored inside the trigger's `config` JSON as `catch_up`. When unset the
+/// legacy behavior applies: fire once, plus up to `max_backfill - 1` extras.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum CatchUpPolicy {
+    /// Drop every missed fire; the schedule resumes at its next slot.
+    Skip,
+    /// Run once for the most recent missed fire.
+    RunOnce,
+    /// Replay every missed fire (capped by the scheduler's backfill limit).
+    RunAllMissed,
+}
+
+impl CatchUpPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::RunOnce => "run_once",
+            Self::RunAllMissed => "run_all_missed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "skip" => Some(Self::Skip),
+            "run_once" => Some(Self::RunOnce),
+            "run_all_missed" => Some(Self::RunAllMissed),
+            _ => None,
+        }
+    }
+}
+
+/// Blackout window for a schedule trigger: cron fire times that land inside it
+/// are skipped. Stored inside the trigger's `config` JSON under the
+/// `exclusions` array and evaluated in the schedule's timezone.
+///
+/// The constraints combine with AND: `{"days": [0, 6]}` is "not on weekends",
+/// `{"days": [1], "start": "00:00", "end": "06:00"}` is "not before 6 AM on
+/// Mondays", `{"dates": ["2026-12-25"]}` is "not on Christmas".
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct ScheduleExclusion {
+    /// Days of the week the exclusion applies on (0 = Sunday .. 6 = Saturday).
+    /// Empty = every day.
+    #[serde(default)]
+    pub days: Vec<u8>,
+    /// Calendar dates ("YYYY-MM-DD") the exclusion applies on. Empty = every date.
+    #[serde(default)]
+    pub dates: Vec<String>,
+    /// Start of the excluded time of day ("HH:MM", inclusive). When both
+    /// `start` and `end` are unset the whole day is excluded.
+    #[serde(default)]
+    pub start: Option<String>,
+    /// End of the excluded time of day ("HH:MM", exclusive). A window whose
+    /// end is before its start wraps past midnight.
+    #[serde(default)]
+    pub end: Option<String>,
+}
+
+fn parse_hh_mm(s: &str) -> Option<u32> {
+    let (h, m) = s.trim().split_once(':')?;
+    let (h, m): (u32, u32) = (h.parse().ok()?, m.parse().ok()?);
+    (h < 24 && m < 60).then_some(h * 60 + m)
+}
+
+impl ScheduleExclusion {
+    /// Reject windows that can never be evaluated: out-of-range days,
+    /// malformed dates or times, and an empty window that would exclude
+    /// every fire time.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(d) = self.days.iter().find(|d| **d > 6) {
+            return Err(format!(
+                "exclusion day {d} is out of range (0 = Sunday .. 6 = Saturday)"
+            ));
+        }
+        if let Some(d) = self
+            .dates
+            .iter()
+            .find(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").is_err())
+        {
+            return Err(format!("exclusion date \"{d}\" is not YYYY-MM-DD"));
+        }
+        for t in [&self.start, &self.end].into_iter().flatten() {
+            if parse_hh_mm(t).is_none() {
+                return Err(format!("exclusion time \"{t}\" is not HH:MM"));
+            }
+        }
+        if self.start.is_some() != self.end.is_some() {
+            return Err("exclusion time range needs both start and end".into());
+        }
+        if self.days.is_empty() && self.dates.is_empty() && self.start.is_none() {
+            return Err("exclusion must set days, dates or a time range".into());
+        }
+        Ok(())
+    }
+
+    /// Whether a fire time, given as wall-clock time in the schedule's zone,
+    /// falls inside this window. Invalid windows never match.
+    pub fn covers(&self, local: NaiveDateTime) -> bool {
+        if self.validate().is_err() {
+            return false;
+        }
+        let weekday = local.weekday().num_days_from_sunday() as u8;
+        if !self.days.is_empty() && !self.days.contains(&weekday) {
+            return false;
+        }
+        if !self.dates.is_empty() {
+            let date = local.date().format("%Y-%m-%d").to_string();
+            if !self.dates.contains(&date) {
+                return false;
+            }
+        }
+        match (
+            self.start.as_deref().and_then(parse_hh_mm),
+            self.end.as_deref().and_then(parse_hh_mm),
+        ) {
+            (Some(start), Some(end)) => {
+                let minute = local.hour() * 60 + local.minute();
+                if start <= end {
+                    minute >= start && minute < end
+                } else {
+                    minute >= start || minute < end
+                }
+            }
+            _ => true,
+        }
+    }
+
+    /// Short English phrase for previews, e.g. "Sat, Sun" or "Mon 00:00–06:00".
+    pub fn describe(&self) -> String {
+        const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+        let mut parts = Vec::new();
+        let mut days = self.days.clone();
+        days.sort_unstable();
+        days.dedup();
+        match days.as_slice() {
+            [] => {}
+            [0, 6] => parts.push("weekends".to_string()),
+            [1, 2, 3, 4, 5] => parts.push("weekdays".to_string()),
+            d => parts.push(
+                d.iter()
+                    .filter_map(|n| DAYS.get(*n as usize))
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        }
+        if !self.dates.is_empty() {
+            parts.push(self.dates.join(", "));
+        }
+        if let (Some(start), Some(end)) = (&self.start, &self.end) {
+            parts.push(format!("{start}–{end}"));
+        }
+        parts.join(" ")
+    }
+}
+
 /// Parsed, typed representation of a trigger's `config` JSON.
 ///
 /// Each variant carries only the fields that trigger type needs, making invalid
@@ -284,6 +443,12 @@ pub enum TriggerConfig {
         /// downtime.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         max_backfill: Option<u32>,
+        /// Blackout windows; cron fire times inside any of them are skipped.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        exclusions: Vec<ScheduleExclusion>,
+        /// Missed-fire handling after downtime; `None` = legacy `max_backfill`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        catch_up: Option<CatchUpPolicy>,
         event_type: Option<String>,
         payload: Option<serde_json::Value>,
     },
@@ -347,6 +512,36 @@ pub enum TriggerConfig {
         recursive: Option<bool>,
         /// Optional glob filter (e.g. "*.py", "*.rs").
         glob_filter: Option<String>,
+        /// Patterns to skip (e.g. "node_modules", "*.tmp"), matched against
+        /// the file name and every directory component of the changed path.
+        ignore_patterns: Option<Vec<String>>,
+        /// Quiet period per path before the trigger may fire again for it.
+        debounce_ms: Option<u64>,
+        /// Attach the first few KB of a created/modified text file to the
+        /// event payload. Defaults to true.
+        include_content: Option<bool>,
+        event_type: Option<String>,
+        payload: Option<serde_json::Value>,
+    },
+    /// Polls a mailbox (`imap_smtp` or `gmail` credential) and fires once per
+    /// new message matching the filters, with the parsed email as payload.
+    #[serde(rename = "email_inbound")]
+    EmailInbound {
+        credential_id: Option<String>,
+        /// IMAP folder (default "INBOX") or Gmail label.
+        #[serde(alias = "label")]
+        mailbox: Option<String>,
+        /// Substring match on the From header.
+        from: Option<String>,
+        /// Substring match on the Subject header.
+        subject: Option<String>,
+        unread_only: Option<bool>,
+        /// Flag processed messages as read on the server.
+        mark_read: Option<bool>,
+        interval_seconds: Option<u64>,
+        /// Engine-maintained position of the last poll; see
+        /// `engine::email::inbound::PollCursor`.
+        cursor: Option<serde_json::Value>,
         event_type: Option<String>,
         payload: Option<serde_json::Value>,
     },
@@ -406,6 +601,7 @@ impl TriggerConfig {
                 listen_event_type, ..
             } => listen_event_type.as_deref(),
             TriggerConfig::FileWatcher { event_type, .. } => event_type.as_deref(),
+            TriggerConfig::EmailInbound { event_type, .. } => event_type.as_deref(),
             TriggerConfig::Clipboard { event_type, .. } => event_type.as_deref(),
             TriggerConfig::AppFocus { event_type, .. } => event_type.as_deref(),
             TriggerConfig::Composite { event_type, .. } => event_type.as_deref(),
@@ -424,6 +620,7 @@ impl TriggerConfig {
             TriggerConfig::Manual { payload, .. } => payload.as_ref(),
             TriggerConfig::EventListener { .. } => None,
             TriggerConfig::FileWatcher { payload, .. } => payload.as_ref(),
+            TriggerConfig::EmailInbound { payload, .. } => payload.as_ref(),
             TriggerConfig::Clipboard { payload, .. } => payload.as_ref(),
             TriggerConfig::AppFocus { payload, .. } => payload.as_ref(),
             TriggerConfig::Composite { payload, .. } => payload.as_ref(),
@@ -431,6 +628,32 @@ impl TriggerConfig {
         };
         opt.map(|p| p.to_string())
     }
+
+    /// Whether `at` falls inside one of a schedule's exclusion windows,
+    /// evaluated in the schedule's timezone (system local when unset). Always
+    /// `false` for other trigger types and for an unparseable timezone, which
+    /// the scheduler refuses separately.
+    pub fn schedule_excluded_at(&self, at: DateTime<Utc>) -> bool {
+        let TriggerConfig::Schedule {
+            timezone,
+            exclusions,
+            ..
+        } = self
+        else {
+            return false;
+        };
+        if exclusions.is_empty() {
+            return false;
+        }
+        let local = match timezone.as_deref() {
+            Some(raw) => match raw.parse::<Tz>() {
+                Ok(tz) => at.with_timezone(&tz).naive_local(),
+                Err(_) => return false,
+            },
+            None => at.with_timezone(&chrono::Local).naive_local(),
+        };
+        exclusions.iter().any(|e| e.covers(local))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -587,6 +810,13 @@ impl TriggerConfig {
                     .get("max_backfill")
                     .and_then(|v| v.as_u64())
                     .and_then(|n| u32::try_from(n).ok()),
+                exclusions: val
+                    .get("exclusions")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                    .unwrap_or_default(),
+                catch_up: val
+                    .get("catch_up")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok()),
                 event_type,
                 payload,
             },
@@ -666,9 +896,33 @@ impl TriggerConfig {
                     .get("glob_filter")
                     .and_then(|v| v.as_str())
                     .map(String::from),
+                ignore_patterns: val.get("ignore_patterns").and_then(|v| {
+                    v.as_array().map(|arr| {
+                        arr.iter()
+                            .filter_map(|s| s.as_str().map(String::from))
+                            .collect()
+                    })
+                }),
+                debounce_ms: val.get("debounce_ms").and_then(|v| v.as_u64()),
+                include_content: val.get("include_content").and_then(|v| v.as_bool()),
                 event_type,
                 payload,
             },
+            "email_inbound" => {
+                let text = |key: &str| val.get(key).and_then(|v| v.as_str()).map(String::from);
+                TriggerConfig::EmailInbound {
+                    credential_id: text("credential_id"),
+                    mailbox: text("mailbox").or_else(|| text("label")),
+                    from: text("from"),
+                    subject: text("subject"),
+                    unread_only: val.get("unread_only").and_then(|v| v.as_bool()),
+                    mark_read: val.get("mark_read").and_then(|v| v.as_bool()),
+                    interval_seconds: val.get("interval_seconds").and_then(|v| v.as_u64()),
+                    cursor: val.get("cursor").cloned(),
+                    event_type,
+                    payload,
+                }
+            }
             "clipboard" => TriggerConfig::Clipboard {
                 content_type: val
                     .get("content_type")
@@ -761,6 +1015,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_schedule_exclusions_evaluated_in_schedule_timezone() {
+        use chrono::TimeZone;
+        let t = make_trigger(
+            "schedule",
+            Some(
+                r#"{"cron":"0 * * * *","timezone":"America/New_York","exclusions":[{"days":[0,6]},{"start":"22:00","end":"06:00"}]}"#,
+            ),
+        );
+        let cfg = t.parse_config();
+        // Saturday 02:00 UTC is still Friday 22:00 in New York: night window.
+        assert!(cfg.schedule_excluded_at(Utc.with_ymd_and_hms(2026, 5, 2, 2, 0, 0).unwrap()));
+        // Saturday 15:00 UTC = Saturday 11:00 EDT: weekend.
+        assert!(cfg.schedule_excluded_at(Utc.with_ymd_and_hms(2026, 5, 2, 15, 0, 0).unwrap()));
+        // Friday 15:00 UTC = Friday 11:00 EDT: allowed.
+        assert!(!cfg.schedule_excluded_at(Utc.with_ymd_and_hms(2026, 5, 1, 15, 0, 0).unwrap()));
+
+        assert!(ScheduleExclusion::default().validate().is_err());
+        let half_range = ScheduleExclusion {
+            start: Some("09:00".into()),
+            ..Default::default()
+        };
+        assert!(half_range.validate().is_err());
+    }
+
     #[test]
     fn test_parse_polling_config() {
         let t = make_trigger(
@@ -961,7 +1240,7 @@ mod tests {
         let t = make_trigger(
             "file_watcher",
             Some(
-                r#"{"watch_paths":["/home/user/src","/tmp"],"events":["create","modify"],"recursive":true,"glob_filter":"*.py","event_type":"file_changed"}"#,
+                r#"{"watch_paths":["/home/user/src","/tmp"],"events":["create","modify"],"recursive":true,"glob_filter":"*.py","ignore_patterns":["node_modules","*.tmp"],"debounce_ms":2000,"include_content":false,"event_type":"file_changed"}"#,
             ),
         );
         match t.parse_config() {
@@ -970,6 +1249,9 @@ mod tests {
                 events,
                 recursive,
                 glob_filter,
+                ignore_patterns,
+                debounce_ms,
+                include_content,
                 event_type,
                 ..
             } => {
@@ -977,12 +1259,48 @@ mod tests {
                 assert_eq!(events.as_ref().map(|v| v.len()), Some(2));
                 assert_eq!(recursive, Some(true));
                 assert_eq!(glob_filter.as_deref(), Some("*.py"));
+                assert_eq!(ignore_patterns.as_ref().map(|v| v.len()), Some(2));
+                assert_eq!(debounce_ms, Some(2000));
+                assert_eq!(include_content, Some(false));
                 assert_eq!(event_type.as_deref(), Some("file_changed"));
             }
             other => panic!("Expected FileWatcher, got {other:?}"),
         }
     }
 
+    #[test]
+    fn test_parse_email_inbound_config() {
+        let t = make_trigger(
+            "email_inbound",
+            Some(
+                r#"{"credential_id":"cred-1","label":"Invoices","from":"billing@example.com","unread_only":true,"interval_seconds":300,"cursor":{"since_ms":1000}}"#,
+            ),
+        );
+        match t.parse_config() {
+            TriggerConfig::EmailInbound {
+                credential_id,
+                mailbox,
+                from,
+                subject,
+                unread_only,
+                mark_read,
+                interval_seconds,
+                cursor,
+                ..
+            } => {
+                assert_eq!(credential_id.as_deref(), Some("cred-1"));
+                assert_eq!(mailbox.as_deref(), Some("Invoices"));
+                assert_eq!(from.as_deref(), Some("billing@example.com"));
+                assert_eq!(subject, None);
+                assert_eq!(unread_only, Some(true));
+                assert_eq!(mark_read, None);
+                assert_eq!(interval_seconds, Some(300));
+                assert_eq!(cursor, Some(serde_json::json!({"since_ms": 1000})));
+            }
+            other => panic!("Expected EmailInbound, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_clipboard_config() {
         let t = make_trigger(
@@ -0,0 +1,281 @@
+//! Connection reservations for the main pool.
+//!
+//! The webhook server and the trigger scheduler run on their own loops and
+//! can, under load, check out every pooled connection at once — leaving IPC
+//! commands waiting out `POOL_ACQUIRE_TIMEOUT` behind them. Background
+//! subsystems therefore take a [`BackgroundPermit`] before touching the
+//! pool; only `pool size - INTERACTIVE_RESERVED` permits exist, so the
+//! reserved connections always stay free for interactive commands. Each
+//! holder is assumed to use one connection at a time, which is how the repo
+//! layer behaves (check out, run, drop).
+//!
+//! Per-subsystem wait/timeout counters feed `system_health_check`.
+
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Connections background subsystems can never take.
+pub const INTERACTIVE_RESERVED: u32 = 2;
+
+/// Background work that must hold a permit while it uses the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subsystem {
+    Scheduler,
+    Webhook,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 2] = [Subsystem::Scheduler, Subsystem::Webhook];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Scheduler => "scheduler",
+            Self::Webhook => "webhook",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubsystemUsage {
+    pub in_use: u32,
+    pub acquired: u64,
+    /// Acquisitions that had to wait for another background holder.
+    pub waited: u64,
+    /// Acquisitions abandoned after the timeout.
+    pub timed_out: u64,
+    pub wait_ms: u64,
+}
+
+const NO_USAGE: SubsystemUsage = SubsystemUsage {
+    in_use: 0,
+    acquired: 0,
+    waited: 0,
+    timed_out: 0,
+    wait_ms: 0,
+};
+
+#[derive(Debug, Clone)]
+pub struct PoolBudgetSnapshot {
+    /// Configured main pool size; 0 before `init_db` ran.
+    pub pool_size: u32,
+    pub reserved: u32,
+    pub background_capacity: u32,
+    pub subsystems: Vec<(Subsystem, SubsystemUsage)>,
+}
+
+struct Budget {
+    pool_size: u32,
+    capacity: u32,
+    in_use: u32,
+    usage: [SubsystemUsage; Subsystem::ALL.len()],
+}
+
+struct Gate {
+    budget: Mutex<Budget>,
+    released: Condvar,
+}
+
+/// Holds one background connection slot; released on drop.
+#[must_use = "the reservation is released as soon as the permit is dropped"]
+pub struct BackgroundPermit<'a> {
+    gate: &'a Gate,
+    subsystem: Subsystem,
+}
+
+impl Drop for BackgroundPermit<'_> {
+    fn drop(&mut self) {
+        let mut budget = self.gate.lock();
+        budget.in_use = budget.in_use.saturating_sub(1);
+        let usage = &mut budget.usage[self.subsystem.index()];
+        usage.in_use = usage.in_use.saturating_sub(1);
+        self.gate.released.notify_one();
+    }
+}
+
+impl Gate {
+    /// Unlimited until [`Gate::configure`] runs.
+    const fn new() -> Self {
+        Self {
+            budget: Mutex::new(Budget {
+                pool_size: 0,
+                capacity: u32::MAX,
+                in_use: 0,
+                usage: [NO_USAGE; Subsystem::ALL.len()],
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Budget> {
+        self.budget.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn configure(&self, pool_size: u32) {
+        let mut budget = self.lock();
+        budget.pool_size = pool_size;
+        budget.capacity = pool_size.saturating_sub(INTERACTIVE_RESERVED).max(1);
+        self.released.notify_all();
+    }
+
+    fn grant(
+        &self,
+        budget: &mut Budget,
+        subsystem: Subsystem,
+        waited: Option<Duration>,
+    ) -> BackgroundPermit<'_> {
+        budget.in_use += 1;
+        let usage = &mut budget.usage[subsystem.index()];
+        usage.in_use += 1;
+        usage.acquired += 1;
+        if let Some(w) = waited {
+            usage.waited += 1;
+            usage.wait_ms += w.as_millis() as u64;
+        }
+        BackgroundPermit {
+            gate: self,
+            subsystem,
+        }
+    }
+
+    fn record_timeout(budget: &mut Budget, subsystem: Subsystem, waited: Duration) {
+        let usage = &mut budget.usage[subsystem.index()];
+        usage.timed_out += 1;
+        usage.wait_ms += waited.as_millis() as u64;
+        tracing::warn!(
+            subsystem = subsystem.as_str(),
+            waited_ms = waited.as_millis() as u64,
+            capacity = budget.capacity,
+            "Background DB connection budget exhausted"
+        );
+    }
+
+    fn try_acquire(
+        &self,
+        subsystem: Subsystem,
+        waited: Option<Duration>,
+    ) -> Option<BackgroundPermit<'_>> {
+        let mut budget = self.lock();
+        if budget.in_use < budget.capacity {
+            Some(self.grant(&mut budget, subsystem, waited))
+        } else {
+            None
+        }
+    }
+
+    fn acquire_blocking(
+        &self,
+        subsystem: Subsystem,
+        timeout: Duration,
+    ) -> Option<BackgroundPermit<'_>> {
+        let started = Instant::now();
+        let mut budget = self.lock();
+        let mut waited = None;
+        loop {
+            if budget.in_use < budget.capacity {
+                return Some(self.grant(&mut budget, subsystem, waited));
+            }
+            let remaining = timeout.saturating_sub(started.elapsed());
+            if remaining.is_zero() {
+                Self::record_timeout(&mut budget, subsystem, started.elapsed());
+                return None;
+            }
+            budget = self
+                .released
+                .wait_timeout(budget, remaining)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+            waited = Some(started.elapsed());
+        }
+    }
+
+    fn snapshot(&self) -> PoolBudgetSnapshot {
+        let budget = self.lock();
+        PoolBudgetSnapshot {
+            pool_size: budget.pool_size,
+            reserved: INTERACTIVE_RESERVED.min(budget.pool_size),
+            background_capacity: budget.capacity.min(budget.pool_size),
+            subsystems: Subsystem::ALL
+                .iter()
+                .map(|s| (*s, budget.usage[s.index()]))
+                .collect(),
+        }
+    }
+}
+
+static GATE: Gate = Gate::new();
+
+/// Size the background share from the main pool. Called by `init_db`; until
+/// then (companion binaries, most tests) acquisition is unlimited.
+pub fn configure(pool_size: u32) {
+    GATE.configure(pool_size);
+}
+
+/// Block the current thread until a permit frees up or `timeout` passes.
+/// For sync background loops (scheduler ticks run on blocking threads).
+pub fn acquire_blocking(
+    subsystem: Subsystem,
+    timeout: Duration,
+) -> Option<BackgroundPermit<'static>> {
+    GATE.acquire_blocking(subsystem, timeout)
+}
+
+/// Async variant of [`acquire_blocking`] for handlers on the tokio runtime;
+/// polls instead of parking a worker thread.
+pub async fn acquire(subsystem: Subsystem, timeout: Duration) -> Option<BackgroundPermit<'static>> {
+    const POLL: Duration = Duration::from_millis(10);
+    let started = Instant::now();
+    let mut waited = None;
+    loop {
+        if let Some(permit) = GATE.try_acquire(subsystem, waited) {
+            return Some(permit);
+        }
+        if started.elapsed() >= timeout {
+            Gate::record_timeout(&mut GATE.lock(), subsystem, started.elapsed());
+            return None;
+        }
+        tokio::time::sleep(POLL).await;
+        waited = Some(started.elapsed());
+    }
+}
+
+pub fn snapshot() -> PoolBudgetSnapshot {
+    GATE.snapshot()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn background_holders_leave_reserved_connections_free() {
+        let gate = Gate::new();
+        gate.configure(4);
+        let a = gate
+            .try_acquire(Subsystem::Webhook, None)
+            .expect("first permit");
+        let b = gate
+            .try_acquire(Subsystem::Scheduler, None)
+            .expect("second permit");
+        // 4 - INTERACTIVE_RESERVED = 2 background slots: a third must wait.
+        assert!(gate
+            .acquire_blocking(Subsystem::Webhook, Duration::from_millis(20))
+            .is_none());
+        drop(a);
+        assert!(gate
+            .acquire_blocking(Subsystem::Webhook, Duration::from_millis(20))
+            .is_some());
+        drop(b);
+
+        let snap = gate.snapshot();
+        assert_eq!((snap.pool_size, snap.background_capacity), (4, 2));
+        let webhook = snap.subsystems[Subsystem::Webhook.index()].1;
+        assert_eq!(
+            (webhook.acquired, webhook.timed_out, webhook.in_use),
+            (2, 1, 0)
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Per-event-type payload schemas (`event_schemas`).
+//!
+//! Registering a JSON Schema for an event type is optional. Types without one
+//! accept any payload, as before. Types with one are checked by
+//! [`validate_payload`] at the ingestion edges (`publish_event`, the webhook
+//! server, the smee relay), so a malformed payload is rejected with the
+//! offending fields named instead of reaching subscribers.
+
+use rusqlite::{params, OptionalExtension};
+
+use crate::db::models::{EventSchema, UpsertEventSchemaInput};
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// How many individual violations are spelled out before the rest are counted.
+const MAX_REPORTED_ERRORS: usize = 3;
+
+row_mapper!(row_to_schema -> EventSchema {
+    event_type, schema, description, created_at, updated_at,
+});
+
+/// Parse and compile a schema document, so a broken schema is refused at save
+/// time rather than silently skipped at publish time.
+fn compile(schema: &str) -> Result<jsonschema::Validator, AppError> {
+    let doc: serde_json::Value = serde_json::from_str(schema)
+        .map_err(|e| AppError::Validation(format!("Event schema is not valid JSON: {e}")))?;
+    jsonschema::validator_for(&doc)
+        .map_err(|e| AppError::Validation(format!("Event schema is not a valid JSON Schema: {e}")))
+}
+
+pub fn get(pool: &DbPool, event_type: &str) -> Result<Option<EventSchema>, AppError> {
+    timed_query!("event_schemas", "event_schemas::get", {
+        let conn = pool.get()?;
+        Ok(conn
+            .query_row(
+                "SELECT * FROM event_schemas WHERE event_type = ?1",
+                params![event_type],
+                row_to_schema,
+            )
+            .optional()?)
+    })
+}
+
+pub fn list(pool: &DbPool) -> Result<Vec<EventSchema>, AppError> {
+    timed_query!("event_schemas", "event_schemas::list", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached("SELECT * FROM event_schemas ORDER BY event_type")?;
+        let rows = stmt.query_map([], row_to_schema)?;
+        Ok(collect_rows(rows, "event_schemas::list"))
+    })
+}
+
+/// Create or replace the schema for `input.event_type`.
+pub fn upsert(pool: &DbPool, input: UpsertEventSchemaInput) -> Result<EventSchema, AppError> {
+    timed_query!("event_schemas", "event_schemas::upsert", {
+        let event_type = input.event_type.trim();
+        if event_type.is_empty() {
+            return Err(AppError::Validation("event_type must not be empty".into()));
+        }
+        compile(&input.schema)?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        Ok(conn.query_row(
+            "INSERT INTO event_schemas (event_type, schema, description, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?4)
+             ON CONFLICT(event_type) DO UPDATE SET
+                 schema = excluded.schema,
+                 description = excluded.description,
+                 updated_at = excluded.updated_at
+             RETURNING *",
+            params![
+                event_type,
+                input.schema,
+                input.description.filter(|d| !d.trim().is_empty()),
+                now,
+            ],
+            row_to_schema,
+        )?)
+    })
+}
+
+pub fn delete(pool: &DbPool, event_type: &str) -> Result<bool, AppError> {
+    timed_query!("event_schemas", "event_schemas::delete", {
+        let conn = pool.get()?;
+        let rows = conn.execute(
+            "DELETE FROM event_schemas WHERE event_type = ?1",
+            params![event_type],
+        )?;
+        Ok(rows > 0)
+    })
+}
+
+/// Check an event payload against the schema registered for its type.
+///
+/// No schema means no check. A missing payload is validated as `null` and a
+/// payload that is not JSON is rejected outright, since neither can satisfy a
+/// schema that describes an object. The error lists the failing instance paths
+/// so the sender can fix the payload.
+pub fn validate_payload(
+    pool: &DbPool,
+    event_type: &str,
+    payload: Option<&str>,
+) -> Result<(), AppError> {
+    let Some(stored) = get(pool, event_type)? else {
+        return Ok(());
+    };
+    let validator = match compile(&stored.schema) {
+        Ok(v) => v,
+        Err(e) => {
+            // Only reachable if the row was written around `upsert`; don't
+            // block the event type over it.
+            tracing::warn!(
+                event_type,
+                "Stored event schema is invalid, skipping validation: {e}"
+            );
+            return Ok(());
+        }
+    };
+
+    let value = match payload {
+        Some(raw) => serde_json::from_str::<serde_json::Value>(raw).map_err(|e| {
+            AppError::Validation(format!(
+                "Payload for event type '{event_type}' is not valid JSON: {e}"
+            ))
+        })?,
+        None => serde_json::Value::Null,
+    };
+
+    let errors: Vec<String> = validator
+        .iter_errors(&value)
+        .map(|err| {
+            let path = err.instance_path.to_string();
+            if path.is_empty() {
+                err.to_string()
+            } else {
+                format!("{path}: {err}")
+            }
+        })
+        .collect();
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let summary = if errors.len() <= MAX_REPORTED_ERRORS {
+        errors.join("; ")
+    } else {
+        format!(
+            "{}; ... and {} more errors",
+            errors[..MAX_REPORTED_ERRORS].join("; "),
+            errors.len() - MAX_REPORTED_ERRORS
+        )
+    };
+    Err(AppError::Validation(format!(
+        "Payload for event type '{event_type}' failed schema validation: {summary}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    const ORDER_SCHEMA: &str = r#"{
+        "type": "object",
+        "required": ["order_id", "amount"],
+        "properties": {
+            "order_id": { "type": "string" },
+            "amount": { "type": "number", "minimum": 0 }
+        }
+    }"#;
+
+    fn register(pool: &DbPool) -> EventSchema {
+        upsert(
+            pool,
+            UpsertEventSchemaInput {
+                event_type: "order.created".into(),
+                schema: ORDER_SCHEMA.into(),
+                description: Some("Checkout orders".into()),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn payloads_are_checked_against_the_registered_schema() {
+        let pool = init_test_db().unwrap();
+        // Unregistered types accept anything.
+        validate_payload(&pool, "order.created", Some("not json")).unwrap();
+
+        register(&pool);
+        validate_payload(
+            &pool,
+            "order.created",
+            Some(r#"{"order_id":"o-1","amount":12.5}"#),
+        )
+        .unwrap();
+
+        let err = validate_payload(&pool, "order.created", Some(r#"{"amount":-1}"#))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("order_id"), "{err}");
+        assert!(err.contains("/amount"), "{err}");
+        assert!(matches!(
+            validate_payload(&pool, "order.created", None),
+            Err(AppError::Validation(_))
+        ));
+        assert!(matches!(
+            validate_payload(&pool, "order.created", Some("{oops")),
+            Err(AppError::Validation(_))
+        ));
+        // Other types are unaffected.
+        validate_payload(&pool, "order.shipped", None).unwrap();
+    }
+
+    #[test]
+    fn upsert_replaces_and_rejects_invalid_schemas() {
+        let pool = init_test_db().unwrap();
+        let first = register(&pool);
+        let replaced = upsert(
+            &pool,
+            UpsertEventSchemaInput {
+                event_type: "order.created".into(),
+                schema: r#"{"type":"object"}"#.into(),
+                description: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(replaced.created_at, first.created_at);
+        assert_eq!(list(&pool).unwrap().len(), 1);
+
+        for schema in ["{", r#"{"type": 42}"#] {
+            let err = upsert(
+                &pool,
+                UpsertEventSchemaInput {
+                    event_type: "order.created".into(),
+                    schema: schema.into(),
+                    description: None,
+                },
+            );
+            assert!(matches!(err, Err(AppError::Validation(_))), "{schema}");
+        }
+
+        assert!(delete(&pool, "order.created").unwrap());
+        assert!(get(&pool, "order.created").unwrap().is_none());
+    }
+}
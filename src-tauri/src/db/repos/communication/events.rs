@@ -8,7 +8,7 @@ use crate::db::query_builder::QueryBuilder;
 use crate::db::repos::resources::triggers::encrypt_config;
 use crate::db::repos::utils::collect_rows;
 use crate::db::DbPool;
-use crate::engine::crypto;
+use crate::engine::project_keys;
 use crate::error::AppError;
 
 // ============================================================================
@@ -83,27 +83,6 @@ fn validate_event_input(input: &CreatePersonaEventInput) -> Result<(), AppError>
     Ok(())
 }
 
-// ============================================================================
-// Helpers
-// ============================================================================
-
-/// Encrypt an optional payload for at-rest storage.
-///
-/// Returns `(stored_payload, payload_iv)`. If encryption fails, the plaintext
-/// is returned with `None` IV and a warning is logged.
-fn encrypt_optional_payload(payload: &Option<String>) -> (Option<String>, Option<String>) {
-    match payload {
-        Some(plaintext) if !plaintext.is_empty() => match crypto::encrypt_for_db(plaintext) {
-            Ok((ct, iv)) => (Some(ct), Some(iv)),
-            Err(e) => {
-                tracing::warn!("Failed to encrypt event payload, storing plaintext: {}", e);
-                (Some(plaintext.clone()), None)
-            }
-        },
-        other => (other.clone(), None),
-    }
-}
-
 // ============================================================================
 // Row Mappers
 // ============================================================================
@@ -117,7 +96,7 @@ fn row_to_event(row: &rusqlite::Row) -> rusqlite::Result<PersonaEvent> {
     // and surface the error in the error_message field.
     let raw_error: Option<String> = row.get("error_message")?;
     let (payload, error_message) = match (raw_payload, payload_iv) {
-        (Some(ct), Some(ref iv)) if !iv.is_empty() => match crypto::decrypt_from_db(&ct, iv) {
+        (Some(ct), Some(ref iv)) if !iv.is_empty() => match project_keys::decrypt(&ct, iv) {
             Ok(pt) => (Some(pt), raw_error),
             Err(e) => {
                 tracing::warn!("Failed to decrypt event payload: {}", e);
@@ -173,8 +152,9 @@ pub fn publish(pool: &DbPool, input: CreatePersonaEventInput) -> Result<PersonaE
         let now = chrono::Utc::now().to_rfc3339();
         let project_id = input.project_id.unwrap_or_else(|| "default".into());
 
-        // Encrypt payload at rest if present
-        let (stored_payload, payload_iv) = encrypt_optional_payload(&input.payload);
+        // Encrypt payload at rest under the project's data key
+        let (stored_payload, payload_iv) =
+            project_keys::encrypt_event_payload(pool, &project_id, &input.payload);
 
         let conn = pool.get()?;
         let mut stmt = conn.prepare_cached(
@@ -238,19 +218,27 @@ pub fn get_pending(
 /// been claimed by a previous tick).
 pub fn claim_pending(pool: &DbPool, limit: i64) -> Result<Vec<PersonaEvent>, AppError> {
     timed_query!("persona_events", "persona_events::claim_pending", {
+        let now = chrono::Utc::now().to_rfc3339();
         let conn = pool.get()?;
+        // Events held by an active maintenance window stay pending — see
+        // `resources::maintenance_windows`.
         let mut stmt = conn.prepare_cached(
             "UPDATE persona_events
              SET status = 'processing'
              WHERE id IN (
-                 SELECT id FROM persona_events
-                 WHERE status = 'pending'
-                 ORDER BY created_at ASC, id ASC
+                 SELECT e.id FROM persona_events e
+                 WHERE e.status = 'pending'
+                   AND NOT EXISTS (
+                       SELECT 1 FROM maintenance_windows m
+                       WHERE (m.persona_id IS NULL OR m.persona_id = e.target_persona_id)
+                         AND m.starts_at <= ?2 AND (m.ends_at IS NULL OR m.ends_at > ?2)
+                   )
+                 ORDER BY e.created_at ASC, e.id ASC
                  LIMIT ?1
              )
              RETURNING *",
         )?;
-        let rows = stmt.query_map(params![limit], row_to_event)?;
+        let rows = stmt.query_map(params![limit, now], row_to_event)?;
         Ok(collect_rows(rows, "claim_pending"))
     })
 }
@@ -265,6 +253,7 @@ pub fn claim_pending(pool: &DbPool, limit: i64) -> Result<Vec<PersonaEvent>, App
 /// headless ones indefinitely while the windowed app was closed.
 pub fn claim_pending_headless(pool: &DbPool, limit: i64) -> Result<Vec<PersonaEvent>, AppError> {
     timed_query!("persona_events", "persona_events::claim_pending_headless", {
+        let now = chrono::Utc::now().to_rfc3339();
         let conn = pool.get()?;
         let mut stmt = conn.prepare_cached(
             "UPDATE persona_events
@@ -274,12 +263,17 @@ pub fn claim_pending_headless(pool: &DbPool, limit: i64) -> Result<Vec<PersonaEv
                  LEFT JOIN personas p ON p.id = e.target_persona_id
                  WHERE e.status = 'pending'
                    AND (e.target_persona_id IS NULL OR p.headless = 1)
+                   AND NOT EXISTS (
+                       SELECT 1 FROM maintenance_windows m
+                       WHERE (m.persona_id IS NULL OR m.persona_id = e.target_persona_id)
+                         AND m.starts_at <= ?2 AND (m.ends_at IS NULL OR m.ends_at > ?2)
+                   )
                  ORDER BY e.created_at ASC, e.id ASC
                  LIMIT ?1
              )
              RETURNING *",
         )?;
-        let rows = stmt.query_map(params![limit], row_to_event)?;
+        let rows = stmt.query_map(params![limit, now], row_to_event)?;
         Ok(collect_rows(rows, "claim_pending_headless"))
     })
 }
@@ -528,7 +522,7 @@ pub fn backfill_slot_times_for_source(
                 let (raw_payload, payload_iv) = row;
                 let plaintext = match (raw_payload, payload_iv) {
                     (Some(ct), Some(ref iv)) if !iv.is_empty() => {
-                        match crypto::decrypt_from_db(&ct, iv) {
+                        match project_keys::decrypt(&ct, iv) {
                             Ok(pt) => pt,
                             Err(_) => continue,
                         }
@@ -805,7 +799,8 @@ pub fn publish_dead_letter(
         let now = chrono::Utc::now().to_rfc3339();
         let project_id = input.project_id.unwrap_or_else(|| "default".into());
 
-        let (stored_payload, payload_iv) = encrypt_optional_payload(&input.payload);
+        let (stored_payload, payload_iv) =
+            project_keys::encrypt_event_payload(pool, &project_id, &input.payload);
 
         let conn = pool.get()?;
         conn.execute(
@@ -958,6 +953,60 @@ pub fn discard_dead_letter(pool: &DbPool, id: &str) -> Result<(), AppError> {
     })
 }
 
+/// How many times one event may be replayed. Every copy records the event it
+/// was replayed from in `replay_of` (always the original, never another
+/// copy), so the cap holds across replays of replays — a handler that keeps
+/// failing on the same input cannot be looped indefinitely.
+pub const MAX_EVENT_REPLAYS: i64 = 5;
+
+/// Re-dispatch a finished event. Dead-lettered events are retried in place
+/// (the `MAX_MANUAL_RETRIES` cap applies); any other finished event is
+/// published again as a fresh `pending` copy that the event bus picks up on
+/// its next tick, with the original left untouched as history.
+pub fn replay(pool: &DbPool, id: &str) -> Result<PersonaEvent, AppError> {
+    let original = get_by_id(pool, id)?;
+    match original.status {
+        PersonaEventStatus::DeadLetter => return retry_dead_letter(pool, id),
+        PersonaEventStatus::Pending | PersonaEventStatus::Processing => {
+            return Err(AppError::Validation(format!(
+                "Event {id} is still {}; only finished events can be replayed",
+                original.status
+            )));
+        }
+        _ => {}
+    }
+
+    timed_query!("persona_events", "persona_events::replay", {
+        let copy_id = uuid::Uuid::new_v4().to_string();
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        let root: String = conn.query_row(
+            "SELECT COALESCE(replay_of, id) FROM persona_events WHERE id = ?1",
+            params![id],
+            |row| row.get(0),
+        )?;
+        // One statement, so the replay cap cannot be raced past. The payload
+        // is copied still encrypted, together with its IV.
+        let rows = conn.execute(
+            "INSERT INTO persona_events
+             (id, project_id, event_type, source_type, source_id, target_persona_id,
+              payload, payload_iv, use_case_id, status, created_at, replay_of)
+             SELECT ?1, project_id, event_type, source_type, source_id, target_persona_id,
+                    payload, payload_iv, use_case_id, 'pending', ?2, ?3
+             FROM persona_events
+             WHERE id = ?4
+               AND (SELECT COUNT(*) FROM persona_events WHERE replay_of = ?3) < ?5",
+            params![copy_id, now, root, id, MAX_EVENT_REPLAYS],
+        )?;
+        if rows == 0 {
+            return Err(AppError::RetryExhausted(format!(
+                "Event {root} has already been replayed {MAX_EVENT_REPLAYS} times"
+            )));
+        }
+        get_by_id(pool, &copy_id)
+    })
+}
+
 /// Per-id outcome returned by the bulk DLQ commands.
 #[derive(Debug, Clone, serde::Serialize, ts_rs::TS)]
 #[ts(export)]
@@ -1741,6 +1790,44 @@ mod tests {
         assert_eq!(fetched.payload, Some(r#"{"path":"src/main.rs"}"#.into()));
     }
 
+    #[test]
+    fn test_replay_copies_finished_events_up_to_the_cap() {
+        let pool = init_test_db().unwrap();
+        let event = publish(
+            &pool,
+            CreatePersonaEventInput {
+                event_type: "file_changed".into(),
+                source_type: "watcher".into(),
+                project_id: None,
+                source_id: None,
+                target_persona_id: None,
+                payload: Some(r#"{"path":"a.rs"}"#.into()),
+                use_case_id: None,
+            },
+        )
+        .unwrap();
+        assert!(matches!(
+            replay(&pool, &event.id),
+            Err(AppError::Validation(_))
+        ));
+
+        update_status(&pool, &event.id, PersonaEventStatus::Completed, None).unwrap();
+        let copy = replay(&pool, &event.id).unwrap();
+        assert_ne!(copy.id, event.id);
+        assert_eq!(copy.status, PersonaEventStatus::Pending);
+        assert_eq!(copy.payload, event.payload);
+
+        // Replays of the copy count against the original.
+        update_status(&pool, &copy.id, PersonaEventStatus::Completed, None).unwrap();
+        for _ in 1..MAX_EVENT_REPLAYS {
+            replay(&pool, &copy.id).unwrap();
+        }
+        assert!(matches!(
+            replay(&pool, &event.id),
+            Err(AppError::RetryExhausted(_))
+        ));
+    }
+
     #[test]
     fn test_get_by_id_not_found() {
         let pool = init_test_db().unwrap();
@@ -0,0 +1,127 @@
+use rusqlite::params;
+
+use crate::db::models::InjectionDetection;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Review outcomes a human can record on a detection.
+pub const REVIEW_STATUSES: &[&str] = &["unreviewed", "false_positive", "confirmed"];
+
+// -- Row mapper -----------------------------------------------
+
+row_mapper!(row_to_detection -> InjectionDetection {
+    id, event_id, event_type, source_type, source_id, trigger_id,
+    score, level, signals, llm_verdict, policy, action,
+    payload_hash, excerpt, pending_fire_id, review_status,
+    created_at, reviewed_at,
+});
+
+crud_get_by_id!(
+    InjectionDetection,
+    "injection_detections",
+    "Injection detection",
+    row_to_detection
+);
+
+// -- CRUD ------------------------------------------------------
+
+pub fn insert(pool: &DbPool, d: &InjectionDetection) -> Result<(), AppError> {
+    timed_query!("injection_detections", "injection_detections::insert", {
+        let conn = pool.get()?;
+        conn.execute(
+            "INSERT INTO injection_detections
+             (id, event_id, event_type, source_type, source_id, trigger_id, score, level,
+              signals, llm_verdict, policy, action, payload_hash, excerpt, pending_fire_id,
+              review_status, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+            params![
+                d.id,
+                d.event_id,
+                d.event_type,
+                d.source_type,
+                d.source_id,
+                d.trigger_id,
+                d.score,
+                d.level,
+                d.signals,
+                d.llm_verdict,
+                d.policy,
+                d.action,
+                d.payload_hash,
+                d.excerpt,
+                d.pending_fire_id,
+                d.review_status,
+                d.created_at,
+            ],
+        )?;
+        Ok(())
+    })
+}
+
+/// Newest first, optionally narrowed to one review status.
+pub fn list(
+    pool: &DbPool,
+    review_status: Option<&str>,
+    limit: i64,
+) -> Result<Vec<InjectionDetection>, AppError> {
+    timed_query!("injection_detections", "injection_detections::list", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT * FROM injection_detections
+             WHERE (?1 IS NULL OR review_status = ?1)
+             ORDER BY created_at DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![review_status, limit], row_to_detection)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)
+    })
+}
+
+pub fn set_review_status(
+    pool: &DbPool,
+    id: &str,
+    review_status: &str,
+) -> Result<InjectionDetection, AppError> {
+    timed_query!("injection_detections", "injection_detections::set_review", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE injection_detections SET review_status = ?1, reviewed_at = ?2
+             WHERE id = ?3",
+            params![review_status, now, id],
+        )?;
+        get_by_id(pool, id)
+    })
+}
+
+/// Record the outcome of a held fire's approval on the detection that held it.
+pub fn review_by_pending_fire(
+    pool: &DbPool,
+    pending_fire_id: &str,
+    review_status: &str,
+) -> Result<usize, AppError> {
+    timed_query!("injection_detections", "injection_detections::review_fire", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        let n = conn.execute(
+            "UPDATE injection_detections SET review_status = ?1, reviewed_at = ?2
+             WHERE pending_fire_id = ?3 AND review_status = 'unreviewed'",
+            params![review_status, now, pending_fire_id],
+        )?;
+        Ok(n)
+    })
+}
+
+/// Whether a payload with this hash was reviewed as a false positive.
+pub fn is_cleared(pool: &DbPool, payload_hash: &str) -> Result<bool, AppError> {
+    timed_query!("injection_detections", "injection_detections::is_cleared", {
+        let conn = pool.get()?;
+        let n: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM injection_detections
+             WHERE payload_hash = ?1 AND review_status = 'false_positive'",
+            params![payload_hash],
+            |r| r.get(0),
+        )?;
+        Ok(n > 0)
+    })
+}
@@ -97,6 +97,27 @@ pub fn get_team_step_by_execution(
     Ok(row)
 }
 
+/// Atomically mark an approved tool-approval review as resumed
+/// (`context_data.resumed_at`). Returns `true` only for the caller that set
+/// it, so the paused run is continued at most once however often the review
+/// is re-approved.
+pub fn claim_approval_resume(pool: &DbPool, id: &str) -> Result<bool, AppError> {
+    timed_query!("manual_reviews", "manual_reviews::claim_approval_resume", {
+        let conn = pool.get()?;
+        let updated = conn.execute(
+            "UPDATE persona_manual_reviews
+             SET context_data = json_set(context_data, '$.resumed_at', ?2)
+             WHERE id = ?1
+               AND CASE WHEN json_valid(context_data)
+                        THEN json_extract(context_data, '$.kind') = 'tool_approval'
+                             AND json_extract(context_data, '$.resumed_at') IS NULL
+                        ELSE 0 END",
+            params![id, chrono::Utc::now().to_rfc3339()],
+        )?;
+        Ok(updated == 1)
+    })
+}
+
 pub fn get_by_persona(
     pool: &DbPool,
     persona_id: &str,
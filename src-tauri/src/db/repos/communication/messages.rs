@@ -524,6 +524,31 @@ pub fn cleanup_old_messages(pool: &DbPool, retention_days: i64) -> Result<usize,
     })
 }
 
+/// Keep only the newest `max_per_persona` READ messages of each persona,
+/// regardless of age. Unread messages are never touched and do not count
+/// toward the cap. `0` = uncapped. Returns the number of rows deleted.
+pub fn cap_read_per_persona(pool: &DbPool, max_per_persona: usize) -> Result<usize, AppError> {
+    timed_query!("persona_messages", "persona_messages::cap_read_per_persona", {
+        if max_per_persona == 0 {
+            return Ok(0);
+        }
+        let conn = pool.get()?;
+        let n = conn.execute(
+            "DELETE FROM persona_messages WHERE id IN (
+                SELECT id FROM (
+                    SELECT id, ROW_NUMBER() OVER (
+                        PARTITION BY persona_id ORDER BY created_at DESC
+                    ) AS rn
+                    FROM persona_messages
+                    WHERE is_read = 1
+                ) WHERE rn > ?1
+             )",
+            params![max_per_persona as i64],
+        )?;
+        Ok(n)
+    })
+}
+
 // ============================================================================
 // Message Deliveries
 // ============================================================================
@@ -1,8 +1,12 @@
 pub mod alert_rules;
 pub mod chat;
+pub mod event_schemas;
 pub mod events;
+pub mod injection_detections;
 pub mod manual_reviews;
 pub mod messages;
+pub mod notification_rules;
+pub mod notification_templates;
 pub mod reviews;
 pub mod shared_events;
 pub mod sla;
@@ -0,0 +1,361 @@
+use chrono::NaiveTime;
+use rusqlite::{params, Row};
+
+use crate::db::models::{
+    CreateNotificationRuleInput, NotificationRule, UpdateNotificationRuleInput,
+};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Notification categories a rule can match (`*` matches all of them).
+pub const CATEGORIES: &[&str] = &[
+    "budget",
+    "healing",
+    "execution",
+    "manual_review",
+    "message",
+    "credential",
+    "*",
+];
+
+/// Severities in ascending order; a rule matches notifications at or above
+/// its `min_severity`.
+pub const SEVERITIES: &[&str] = &["info", "low", "medium", "high", "critical"];
+
+const CHANNEL_TYPES: &[&str] = &["slack", "telegram", "email", "discord", "teams"];
+
+/// Position of `severity` in [`SEVERITIES`]; unknown values rank as `info`.
+pub fn severity_rank(severity: &str) -> usize {
+    SEVERITIES.iter().position(|s| *s == severity).unwrap_or(0)
+}
+
+/// Parse a quiet-hours bound (`HH:MM`, 24h).
+pub fn parse_quiet_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+fn row_to_rule(row: &Row) -> rusqlite::Result<NotificationRule> {
+    Ok(NotificationRule {
+        id: row.get("id")?,
+        name: row.get("name")?,
+        category: row.get("category")?,
+        persona_id: row.get("persona_id")?,
+        team_id: row.get("team_id")?,
+        min_severity: row.get("min_severity")?,
+        action: row.get("action")?,
+        channel_type: row.get("channel_type")?,
+        credential_id: row.get("credential_id")?,
+        channel_config: row.get("channel_config")?,
+        quiet_hours_start: row.get("quiet_hours_start")?,
+        quiet_hours_end: row.get("quiet_hours_end")?,
+        max_per_hour: row.get("max_per_hour")?,
+        enabled: row.get::<_, i64>("enabled")? != 0,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+fn validate(rule: &NotificationRule) -> Result<(), AppError> {
+    if rule.name.trim().is_empty() {
+        return Err(AppError::Validation("Rule name cannot be empty".into()));
+    }
+    if !CATEGORIES.contains(&rule.category.as_str()) {
+        return Err(AppError::Validation(format!(
+            "Unknown notification category '{}': expected one of {}",
+            rule.category,
+            CATEGORIES.join(" | ")
+        )));
+    }
+    if !SEVERITIES.contains(&rule.min_severity.as_str()) {
+        return Err(AppError::Validation(format!(
+            "Unknown severity '{}': expected one of {}",
+            rule.min_severity,
+            SEVERITIES.join(" | ")
+        )));
+    }
+    match rule.action.as_str() {
+        "silent" => {}
+        "deliver" => {
+            let Some(channel_type) = rule.channel_type.as_deref() else {
+                return Err(AppError::Validation(
+                    "A deliver rule needs a channel_type".into(),
+                ));
+            };
+            if !CHANNEL_TYPES.contains(&channel_type) {
+                return Err(AppError::Validation(format!(
+                    "Unknown channel type '{channel_type}': expected one of {}",
+                    CHANNEL_TYPES.join(" | ")
+                )));
+            }
+            if rule.credential_id.is_none() && rule.channel_config.is_none() {
+                return Err(AppError::Validation(
+                    "A deliver rule needs a credential_id or an inline channel_config".into(),
+                ));
+            }
+        }
+        other => {
+            return Err(AppError::Validation(format!(
+                "Unknown rule action '{other}': expected deliver | silent"
+            )))
+        }
+    }
+    if let Some(config) = rule.channel_config.as_deref() {
+        let parsed: serde_json::Value = serde_json::from_str(config)
+            .map_err(|e| AppError::Validation(format!("channel_config is not valid JSON: {e}")))?;
+        if !parsed.is_object() {
+            return Err(AppError::Validation(
+                "channel_config must be a JSON object".into(),
+            ));
+        }
+    }
+    match (
+        rule.quiet_hours_start.as_deref(),
+        rule.quiet_hours_end.as_deref(),
+    ) {
+        (None, None) => {}
+        (Some(start), Some(end)) => {
+            if parse_quiet_time(start).is_none() || parse_quiet_time(end).is_none() {
+                return Err(AppError::Validation(
+                    "Quiet hours must be HH:MM (24h)".into(),
+                ));
+            }
+        }
+        _ => {
+            return Err(AppError::Validation(
+                "Quiet hours need both a start and an end".into(),
+            ))
+        }
+    }
+    if rule.max_per_hour.is_some_and(|n| n < 1) {
+        return Err(AppError::Validation(
+            "max_per_hour must be at least 1".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn list_all(pool: &DbPool) -> Result<Vec<NotificationRule>, AppError> {
+    timed_query!("notification_rules", "notification_rules::list_all", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare("SELECT * FROM notification_rules ORDER BY created_at")?;
+        let rows = stmt.query_map([], row_to_rule)?;
+        let items = rows
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)?;
+        Ok(items)
+    })
+}
+
+pub fn list_enabled(pool: &DbPool) -> Result<Vec<NotificationRule>, AppError> {
+    timed_query!("notification_rules", "notification_rules::list_enabled", {
+        let conn = pool.get()?;
+        let mut stmt =
+            conn.prepare("SELECT * FROM notification_rules WHERE enabled = 1 ORDER BY created_at")?;
+        let rows = stmt.query_map([], row_to_rule)?;
+        let items = rows
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)?;
+        Ok(items)
+    })
+}
+
+crud_get_by_id!(
+    NotificationRule,
+    "notification_rules",
+    "NotificationRule",
+    row_to_rule
+);
+
+fn write(pool: &DbPool, rule: &NotificationRule) -> Result<(), AppError> {
+    let conn = pool.get()?;
+    conn.execute(
+        "INSERT INTO notification_rules
+         (id, name, category, persona_id, team_id, min_severity, action, channel_type,
+          credential_id, channel_config, quiet_hours_start, quiet_hours_end, max_per_hour,
+          enabled, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+         ON CONFLICT(id) DO UPDATE SET
+           name = ?2, category = ?3, persona_id = ?4, team_id = ?5, min_severity = ?6,
+           action = ?7, channel_type = ?8, credential_id = ?9, channel_config = ?10,
+           quiet_hours_start = ?11, quiet_hours_end = ?12, max_per_hour = ?13,
+           enabled = ?14, updated_at = ?16",
+        params![
+            rule.id,
+            rule.name,
+            rule.category,
+            rule.persona_id,
+            rule.team_id,
+            rule.min_severity,
+            rule.action,
+            rule.channel_type,
+            rule.credential_id,
+            rule.channel_config,
+            rule.quiet_hours_start,
+            rule.quiet_hours_end,
+            rule.max_per_hour,
+            rule.enabled as i64,
+            rule.created_at,
+            rule.updated_at,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn create(
+    pool: &DbPool,
+    input: CreateNotificationRuleInput,
+) -> Result<NotificationRule, AppError> {
+    timed_query!("notification_rules", "notification_rules::create", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let rule = NotificationRule {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: input.name,
+            category: input.category,
+            persona_id: input.persona_id,
+            team_id: input.team_id,
+            min_severity: input.min_severity.unwrap_or_else(|| "info".into()),
+            action: input.action,
+            channel_type: input.channel_type,
+            credential_id: input.credential_id,
+            channel_config: input.channel_config,
+            quiet_hours_start: input.quiet_hours_start,
+            quiet_hours_end: input.quiet_hours_end,
+            max_per_hour: input.max_per_hour,
+            enabled: input.enabled.unwrap_or(true),
+            created_at: now.clone(),
+            updated_at: now,
+        };
+        validate(&rule)?;
+        write(pool, &rule)?;
+        get_by_id(pool, &rule.id)
+    })
+}
+
+pub fn update(
+    pool: &DbPool,
+    id: &str,
+    input: UpdateNotificationRuleInput,
+) -> Result<NotificationRule, AppError> {
+    timed_query!("notification_rules", "notification_rules::update", {
+        let current = get_by_id(pool, id)?;
+
+        // Optional text fields: Some("") clears, Some(v) sets, None keeps.
+        fn merge_clearable(new: Option<String>, current: Option<String>) -> Option<String> {
+            match new {
+                Some(v) if v.is_empty() => None,
+                Some(v) => Some(v),
+                None => current,
+            }
+        }
+        let rule = NotificationRule {
+            id: current.id,
+            name: input.name.unwrap_or(current.name),
+            category: input.category.unwrap_or(current.category),
+            persona_id: merge_clearable(input.persona_id, current.persona_id),
+            team_id: merge_clearable(input.team_id, current.team_id),
+            min_severity: input.min_severity.unwrap_or(current.min_severity),
+            action: input.action.unwrap_or(current.action),
+            channel_type: merge_clearable(input.channel_type, current.channel_type),
+            credential_id: merge_clearable(input.credential_id, current.credential_id),
+            channel_config: merge_clearable(input.channel_config, current.channel_config),
+            quiet_hours_start: merge_clearable(input.quiet_hours_start, current.quiet_hours_start),
+            quiet_hours_end: merge_clearable(input.quiet_hours_end, current.quiet_hours_end),
+            max_per_hour: match input.max_per_hour {
+                Some(0) => None,
+                Some(n) => Some(n),
+                None => current.max_per_hour,
+            },
+            enabled: input.enabled.unwrap_or(current.enabled),
+            created_at: current.created_at,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        };
+        validate(&rule)?;
+        write(pool, &rule)?;
+        get_by_id(pool, id)
+    })
+}
+
+crud_delete!("notification_rules");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    fn deliver_input() -> CreateNotificationRuleInput {
+        CreateNotificationRuleInput {
+            name: "Budget to finance".into(),
+            category: "budget".into(),
+            persona_id: None,
+            team_id: None,
+            min_severity: None,
+            action: "deliver".into(),
+            channel_type: Some("slack".into()),
+            credential_id: None,
+            channel_config: Some(
+                r##"{"webhook_url":"https://hooks.example/x","channel":"#finance"}"##.into(),
+            ),
+            quiet_hours_start: Some("22:00".into()),
+            quiet_hours_end: Some("07:00".into()),
+            max_per_hour: Some(5),
+            enabled: None,
+        }
+    }
+
+    #[test]
+    fn create_update_and_delete_round_trip() {
+        let pool = init_test_db().unwrap();
+        let rule = create(&pool, deliver_input()).unwrap();
+        assert_eq!(rule.min_severity, "info");
+        assert!(rule.enabled);
+        assert_eq!(list_enabled(&pool).unwrap().len(), 1);
+
+        let updated = update(
+            &pool,
+            &rule.id,
+            UpdateNotificationRuleInput {
+                quiet_hours_start: Some(String::new()),
+                quiet_hours_end: Some(String::new()),
+                max_per_hour: Some(0),
+                enabled: Some(false),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(updated.quiet_hours_start.is_none() && updated.quiet_hours_end.is_none());
+        assert!(updated.max_per_hour.is_none());
+        assert!(list_enabled(&pool).unwrap().is_empty());
+
+        assert!(delete(&pool, &rule.id).unwrap());
+        assert!(list_all(&pool).unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let pool = init_test_db().unwrap();
+        let mut no_channel = deliver_input();
+        no_channel.channel_type = None;
+        assert!(create(&pool, no_channel).is_err());
+
+        let mut half_quiet = deliver_input();
+        half_quiet.quiet_hours_end = None;
+        assert!(create(&pool, half_quiet).is_err());
+
+        let mut bad_time = deliver_input();
+        bad_time.quiet_hours_start = Some("25:00".into());
+        assert!(create(&pool, bad_time).is_err());
+
+        let mut bad_category = deliver_input();
+        bad_category.category = "everything".into();
+        assert!(create(&pool, bad_category).is_err());
+
+        let silent = CreateNotificationRuleInput {
+            action: "silent".into(),
+            channel_type: None,
+            channel_config: None,
+            category: "execution".into(),
+            ..deliver_input()
+        };
+        assert!(create(&pool, silent).is_ok());
+    }
+}
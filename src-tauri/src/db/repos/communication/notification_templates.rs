@@ -0,0 +1,178 @@
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::db::models::{NotificationTemplate, UpsertNotificationTemplateInput};
+use crate::db::DbPool;
+use crate::error::AppError;
+use crate::notifications::validate_template;
+
+fn row_to_template(row: &Row) -> rusqlite::Result<NotificationTemplate> {
+    Ok(NotificationTemplate {
+        id: row.get("id")?,
+        notification_type: row.get("notification_type")?,
+        locale: row.get("locale")?,
+        title_template: row.get("title_template")?,
+        body_template: row.get("body_template")?,
+        enabled: row.get::<_, i64>("enabled")? != 0,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+/// Lowercased, `_` → `-`; blank means every language (`*`).
+fn normalize_locale(locale: &str) -> String {
+    let locale = locale.trim().to_ascii_lowercase().replace('_', "-");
+    if locale.is_empty() {
+        "*".into()
+    } else {
+        locale
+    }
+}
+
+pub fn list_all(pool: &DbPool) -> Result<Vec<NotificationTemplate>, AppError> {
+    timed_query!(
+        "notification_templates",
+        "notification_templates::list_all",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT * FROM notification_templates ORDER BY notification_type, locale",
+            )?;
+            let rows = stmt.query_map([], row_to_template)?;
+            let items = rows
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)?;
+            Ok(items)
+        }
+    )
+}
+
+crud_get_by_id!(
+    NotificationTemplate,
+    "notification_templates",
+    "NotificationTemplate",
+    row_to_template
+);
+
+/// Save the template for a type + locale, replacing any existing one.
+pub fn upsert(
+    pool: &DbPool,
+    input: UpsertNotificationTemplateInput,
+) -> Result<NotificationTemplate, AppError> {
+    timed_query!(
+        "notification_templates",
+        "notification_templates::upsert",
+        {
+            if input.title_template.trim().is_empty() || input.body_template.trim().is_empty() {
+                return Err(AppError::Validation(
+                    "A notification template needs a title and a body".into(),
+                ));
+            }
+            validate_template(&input.notification_type, &input.title_template)?;
+            validate_template(&input.notification_type, &input.body_template)?;
+            let locale = normalize_locale(input.locale.as_deref().unwrap_or_default());
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            let id: String = conn.query_row(
+                "INSERT INTO notification_templates
+                 (id, notification_type, locale, title_template, body_template, enabled,
+                  created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+                 ON CONFLICT(notification_type, locale) DO UPDATE SET
+                   title_template = ?4, body_template = ?5, enabled = ?6, updated_at = ?7
+                 RETURNING id",
+                params![
+                    uuid::Uuid::new_v4().to_string(),
+                    input.notification_type,
+                    locale,
+                    input.title_template,
+                    input.body_template,
+                    input.enabled.unwrap_or(true) as i64,
+                    now,
+                ],
+                |row| row.get(0),
+            )?;
+            get_by_id(pool, &id)
+        }
+    )
+}
+
+/// The enabled template for `notification_type` in the UI `locale`: an exact
+/// match (`pt-br`), then its language (`pt`), then the `*` fallback.
+pub fn resolve(
+    pool: &DbPool,
+    notification_type: &str,
+    locale: &str,
+) -> Result<Option<NotificationTemplate>, AppError> {
+    timed_query!(
+        "notification_templates",
+        "notification_templates::resolve",
+        {
+            let locale = normalize_locale(locale);
+            let language = locale.split('-').next().unwrap_or("*").to_string();
+            let conn = pool.get()?;
+            let template = conn
+                .query_row(
+                    "SELECT * FROM notification_templates
+                     WHERE notification_type = ?1 AND enabled = 1 AND locale IN (?2, ?3, '*')
+                     ORDER BY CASE locale WHEN ?2 THEN 0 WHEN ?3 THEN 1 ELSE 2 END
+                     LIMIT 1",
+                    params![notification_type, locale, language],
+                    row_to_template,
+                )
+                .optional()?;
+            Ok(template)
+        }
+    )
+}
+
+crud_delete!("notification_templates");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    fn input(locale: Option<&str>, title: &str) -> UpsertNotificationTemplateInput {
+        UpsertNotificationTemplateInput {
+            notification_type: "execution_failed".into(),
+            locale: locale.map(str::to_string),
+            title_template: title.into(),
+            body_template: "{{persona_name}}: {{error}}".into(),
+            enabled: None,
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_and_resolve_falls_back_by_locale() {
+        let pool = init_test_db().unwrap();
+        let fallback = upsert(&pool, input(None, "Failed: {{persona_name}}")).unwrap();
+        assert_eq!(fallback.locale, "*");
+        upsert(&pool, input(Some("pt"), "Falhou: {{persona_name}}")).unwrap();
+        let replaced = upsert(&pool, input(Some("PT"), "Erro: {{persona_name}}")).unwrap();
+        assert_eq!(list_all(&pool).unwrap().len(), 2);
+        assert_eq!(replaced.title_template, "Erro: {{persona_name}}");
+
+        let pick = |locale| {
+            resolve(&pool, "execution_failed", locale)
+                .unwrap()
+                .map(|t| t.locale)
+        };
+        assert_eq!(pick("pt-BR").as_deref(), Some("pt"));
+        assert_eq!(pick("ja").as_deref(), Some("*"));
+        assert!(resolve(&pool, "manual_review", "en").unwrap().is_none());
+
+        assert!(delete(&pool, &fallback.id).unwrap());
+        assert!(pick("ja").is_none());
+    }
+
+    #[test]
+    fn invalid_templates_are_rejected() {
+        let pool = init_test_db().unwrap();
+        assert!(upsert(&pool, input(None, "{{cost}} spent by {{persona}}")).is_err());
+        assert!(upsert(&pool, input(None, "Failed: {{persona_name")).is_err());
+        assert!(upsert(&pool, input(None, "  ")).is_err());
+        let mut unknown_type = input(None, "Failed");
+        unknown_type.notification_type = "execution_started".into();
+        assert!(upsert(&pool, unknown_type).is_err());
+    }
+}
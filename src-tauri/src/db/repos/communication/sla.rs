@@ -5,7 +5,8 @@ use serde::Serialize;
 use ts_rs::TS;
 
 use crate::db::models::{
-    GlobalSlaStats, HealingSummary, PersonaSlaStats, SlaDailyPoint, SlaDashboardData,
+    GlobalSlaStats, HealingSummary, PersonaSlaStats, PersonaSlo, SlaDailyPoint, SlaDashboardData,
+    UpsertPersonaSloInput,
 };
 use crate::db::query_builder::QueryBuilder;
 use crate::db::DbPool;
@@ -919,7 +920,9 @@ fn compute_mtbf(timestamps: &[String]) -> Option<f64> {
 // below are fixed constants tuned to be conservative — the failure mode we
 // guard against is false-positive noise, not missed breaches. A user with a
 // genuinely flaky persona will cross these; a healthy persona having one bad
-// run will not.
+// run will not. Users who do want explicit targets opt in per persona through
+// `persona_slos` (see "Per-persona SLOs" below); that path is independent of
+// this one.
 
 /// How many of a persona's most-recent terminal runs the breach signal
 /// inspects. Bounded so detection is O(1) per completion regardless of history
@@ -1163,6 +1166,215 @@ pub fn close_breach_episode(
     Ok(())
 }
 
+// ============================================================================
+// Per-persona SLOs (opt-in targets, evaluated by `engine::slo_monitor`)
+// ============================================================================
+
+/// Longest rolling window an SLO may use (30 days).
+pub const SLO_MAX_WINDOW_HOURS: i64 = 720;
+const SLO_DEFAULT_WINDOW_HOURS: i64 = 24;
+const SLO_DEFAULT_MIN_SAMPLE: i64 = 5;
+
+/// Objective token for the success-rate target.
+pub const SLO_SUCCESS_RATE: &str = "success_rate";
+/// Objective token for the p95-duration target.
+pub const SLO_P95_DURATION: &str = "p95_duration";
+
+row_mapper!(row_to_slo -> PersonaSlo {
+    persona_id, min_success_rate, max_p95_duration_ms, window_hours, min_sample,
+    enabled [bool], breach_reason, breached_at, breach_issue_id, last_evaluated_at,
+    created_at, updated_at,
+});
+
+/// A persona's SLO, if it has one.
+pub fn get_persona_slo(pool: &DbPool, persona_id: &str) -> Result<Option<PersonaSlo>, AppError> {
+    let conn = pool.get()?;
+    Ok(conn
+        .query_row(
+            "SELECT * FROM persona_slos WHERE persona_id = ?1",
+            params![persona_id],
+            row_to_slo,
+        )
+        .optional()?)
+}
+
+/// Every SLO, or only the enabled ones.
+pub fn list_persona_slos(pool: &DbPool, enabled_only: bool) -> Result<Vec<PersonaSlo>, AppError> {
+    timed_query!("sla", "sla::list_persona_slos", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT * FROM persona_slos WHERE (?1 = 0 OR enabled = 1) ORDER BY created_at",
+        )?;
+        let rows = stmt.query_map(params![enabled_only], row_to_slo)?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    })
+}
+
+/// Create or replace a persona's SLO. Breach state survives an edit; the
+/// monitor re-judges it against the new targets on its next tick.
+pub fn upsert_persona_slo(
+    pool: &DbPool,
+    input: UpsertPersonaSloInput,
+) -> Result<PersonaSlo, AppError> {
+    if input.min_success_rate.is_none() && input.max_p95_duration_ms.is_none() {
+        return Err(AppError::Validation(
+            "An SLO needs a minimum success rate, a maximum p95 duration, or both".into(),
+        ));
+    }
+    if let Some(rate) = input.min_success_rate {
+        if !(0.0..=1.0).contains(&rate) {
+            return Err(AppError::Validation(
+                "min_success_rate must be between 0 and 1".into(),
+            ));
+        }
+    }
+    if input.max_p95_duration_ms.is_some_and(|ms| ms <= 0) {
+        return Err(AppError::Validation(
+            "max_p95_duration_ms must be positive".into(),
+        ));
+    }
+    let window_hours = input.window_hours.unwrap_or(SLO_DEFAULT_WINDOW_HOURS);
+    if !(1..=SLO_MAX_WINDOW_HOURS).contains(&window_hours) {
+        return Err(AppError::Validation(format!(
+            "window_hours must be between 1 and {SLO_MAX_WINDOW_HOURS}"
+        )));
+    }
+    let min_sample = input.min_sample.unwrap_or(SLO_DEFAULT_MIN_SAMPLE).max(1);
+
+    timed_query!("sla", "sla::upsert_persona_slo", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        Ok(conn.query_row(
+            "INSERT INTO persona_slos
+                (persona_id, min_success_rate, max_p95_duration_ms, window_hours, min_sample,
+                 enabled, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+             ON CONFLICT(persona_id) DO UPDATE SET
+                min_success_rate    = excluded.min_success_rate,
+                max_p95_duration_ms = excluded.max_p95_duration_ms,
+                window_hours        = excluded.window_hours,
+                min_sample          = excluded.min_sample,
+                enabled             = excluded.enabled,
+                updated_at          = excluded.updated_at
+             RETURNING *",
+            params![
+                input.persona_id,
+                input.min_success_rate,
+                input.max_p95_duration_ms,
+                window_hours,
+                min_sample,
+                input.enabled.unwrap_or(true),
+                now,
+            ],
+            row_to_slo,
+        )?)
+    })
+}
+
+pub fn delete_persona_slo(pool: &DbPool, persona_id: &str) -> Result<bool, AppError> {
+    let conn = pool.get()?;
+    let rows = conn.execute(
+        "DELETE FROM persona_slos WHERE persona_id = ?1",
+        params![persona_id],
+    )?;
+    Ok(rows > 0)
+}
+
+/// Record one evaluation. `breach` is the violated objectives (`None` when
+/// the SLO is met); `breached_at` keeps the start of an ongoing breach and
+/// clears on recovery. `issue_id` replaces the stored healing issue only when
+/// given, and is dropped on recovery.
+pub fn record_slo_evaluation(
+    pool: &DbPool,
+    persona_id: &str,
+    breach: Option<&str>,
+    issue_id: Option<&str>,
+    at: &str,
+) -> Result<(), AppError> {
+    let conn = pool.get()?;
+    conn.execute(
+        "UPDATE persona_slos
+         SET breach_reason     = ?2,
+             breached_at       = CASE WHEN ?2 IS NULL THEN NULL ELSE COALESCE(breached_at, ?4) END,
+             breach_issue_id   = CASE WHEN ?2 IS NULL THEN NULL ELSE COALESCE(?3, breach_issue_id) END,
+             last_evaluated_at = ?4
+         WHERE persona_id = ?1",
+        params![persona_id, breach, issue_id, at],
+    )?;
+    Ok(())
+}
+
+/// A persona's decided runs over an SLO window.
+#[derive(Debug, Clone, Default)]
+pub struct SloMeasurement {
+    /// Completed + failed runs (cancelled excluded, as for `success_rate`).
+    pub decided: i64,
+    pub success_rate: Option<f64>,
+    /// p95 duration of the completed runs that recorded one.
+    pub p95_duration_ms: Option<f64>,
+}
+
+/// Measure a persona over the last `window_hours`. Simulations are excluded.
+pub fn measure_slo_window(
+    pool: &DbPool,
+    persona_id: &str,
+    window_hours: i64,
+) -> Result<SloMeasurement, AppError> {
+    timed_query!("sla", "sla::measure_slo_window", {
+        let conn = pool.get()?;
+        // created_at mixes `datetime('now')` and RFC 3339 shapes; normalise
+        // before comparing.
+        let mut stmt = conn.prepare_cached(
+            "SELECT status, duration_ms FROM persona_executions
+             WHERE persona_id = ?1
+               AND status IN ('completed', 'failed')
+               AND datetime(created_at) >= datetime('now', ?2)
+               AND COALESCE(is_simulation, 0) = 0",
+        )?;
+        let window = format!("-{} hours", window_hours.clamp(1, SLO_MAX_WINDOW_HOURS));
+        let rows: Vec<(String, Option<i64>)> = stmt
+            .query_map(params![persona_id, window], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        let decided = rows.len() as i64;
+        let successful = rows.iter().filter(|(s, _)| s == "completed").count() as i64;
+        let durations: Vec<f64> = rows
+            .iter()
+            .filter(|(s, _)| s == "completed")
+            .filter_map(|(_, d)| d.map(|ms| ms as f64))
+            .collect();
+        Ok(SloMeasurement {
+            decided,
+            success_rate: (decided > 0).then(|| successful as f64 / decided as f64),
+            p95_duration_ms: percentile(&durations, 95.0),
+        })
+    })
+}
+
+/// The objectives `m` violates. Empty when the SLO is met or when the window
+/// holds fewer than `min_sample` decided runs (too little signal to judge).
+/// Pure function, so the thresholds are unit-testable.
+pub fn slo_violations(slo: &PersonaSlo, m: &SloMeasurement) -> Vec<&'static str> {
+    let mut violations = Vec::new();
+    if m.decided < slo.min_sample {
+        return violations;
+    }
+    if let (Some(min), Some(rate)) = (slo.min_success_rate, m.success_rate) {
+        if rate < min {
+            violations.push(SLO_SUCCESS_RATE);
+        }
+    }
+    if let (Some(max), Some(p95)) = (slo.max_p95_duration_ms, m.p95_duration_ms) {
+        if p95 > max as f64 {
+            violations.push(SLO_P95_DURATION);
+        }
+    }
+    violations
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2024,4 +2236,117 @@ mod tests {
             .expect("sla_breach_episodes table must exist on a fresh schema");
         assert_eq!(n, 0);
     }
+
+    // -- Per-persona SLOs -----------------------------------------------------
+
+    fn slo_input(persona_id: &str) -> UpsertPersonaSloInput {
+        UpsertPersonaSloInput {
+            persona_id: persona_id.into(),
+            min_success_rate: Some(0.9),
+            max_p95_duration_ms: Some(5_000),
+            window_hours: None,
+            min_sample: None,
+            enabled: None,
+        }
+    }
+
+    #[test]
+    fn upsert_persona_slo_validates_and_replaces() {
+        let pool = init_test_db().unwrap();
+        let persona_id = create_test_persona(&pool, "SLO");
+
+        let mut empty = slo_input(&persona_id);
+        empty.min_success_rate = None;
+        empty.max_p95_duration_ms = None;
+        assert!(upsert_persona_slo(&pool, empty).is_err());
+        let mut bad_rate = slo_input(&persona_id);
+        bad_rate.min_success_rate = Some(1.5);
+        assert!(upsert_persona_slo(&pool, bad_rate).is_err());
+        let mut bad_window = slo_input(&persona_id);
+        bad_window.window_hours = Some(SLO_MAX_WINDOW_HOURS + 1);
+        assert!(upsert_persona_slo(&pool, bad_window).is_err());
+
+        let slo = upsert_persona_slo(&pool, slo_input(&persona_id)).unwrap();
+        assert_eq!(slo.window_hours, 24);
+        assert_eq!(slo.min_sample, 5);
+        assert!(slo.enabled);
+
+        let mut edit = slo_input(&persona_id);
+        edit.max_p95_duration_ms = None;
+        edit.enabled = Some(false);
+        let slo = upsert_persona_slo(&pool, edit).unwrap();
+        assert_eq!(slo.max_p95_duration_ms, None);
+        assert!(!slo.enabled);
+        assert!(list_persona_slos(&pool, true).unwrap().is_empty());
+        assert_eq!(list_persona_slos(&pool, false).unwrap().len(), 1);
+
+        assert!(delete_persona_slo(&pool, &persona_id).unwrap());
+        assert!(get_persona_slo(&pool, &persona_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn slo_window_measures_and_flags_violations() {
+        let pool = init_test_db().unwrap();
+        let persona_id = create_test_persona(&pool, "SLO window");
+        let conn = pool.get().unwrap();
+        for i in 0..8 {
+            let id = insert_execution(&pool, &persona_id, "completed", &recent_ts(i * 10));
+            conn.execute(
+                "UPDATE persona_executions SET duration_ms = ?2 WHERE id = ?1",
+                params![id, 1_000 * (i + 1)],
+            )
+            .unwrap();
+        }
+        insert_execution(&pool, &persona_id, "failed", &recent_ts(5));
+        insert_execution(&pool, &persona_id, "failed", &recent_ts(15));
+        // Cancelled runs and runs outside the window do not count.
+        insert_execution(&pool, &persona_id, "cancelled", &recent_ts(5));
+        insert_execution(&pool, &persona_id, "failed", &recent_ts(60 * 30));
+
+        let m = measure_slo_window(&pool, &persona_id, 24).unwrap();
+        assert_eq!(m.decided, 10);
+        assert!((m.success_rate.unwrap() - 0.8).abs() < 1e-9);
+        assert_eq!(m.p95_duration_ms, Some(8_000.0));
+
+        let slo = upsert_persona_slo(&pool, slo_input(&persona_id)).unwrap();
+        assert_eq!(
+            slo_violations(&slo, &m),
+            vec![SLO_SUCCESS_RATE, SLO_P95_DURATION]
+        );
+
+        // Too few runs to judge: no violations however bad they look.
+        let sparse = SloMeasurement {
+            decided: 4,
+            success_rate: Some(0.0),
+            p95_duration_ms: Some(60_000.0),
+        };
+        assert!(slo_violations(&slo, &sparse).is_empty());
+    }
+
+    #[test]
+    fn slo_evaluation_keeps_breach_start_and_clears_on_recovery() {
+        let pool = init_test_db().unwrap();
+        let persona_id = create_test_persona(&pool, "SLO breach");
+        upsert_persona_slo(&pool, slo_input(&persona_id)).unwrap();
+
+        record_slo_evaluation(
+            &pool,
+            &persona_id,
+            Some("success_rate"),
+            Some("issue-1"),
+            "t1",
+        )
+        .unwrap();
+        record_slo_evaluation(&pool, &persona_id, Some("success_rate"), None, "t2").unwrap();
+        let slo = get_persona_slo(&pool, &persona_id).unwrap().unwrap();
+        assert_eq!(slo.breached_at.as_deref(), Some("t1"));
+        assert_eq!(slo.breach_issue_id.as_deref(), Some("issue-1"));
+        assert_eq!(slo.last_evaluated_at.as_deref(), Some("t2"));
+
+        record_slo_evaluation(&pool, &persona_id, None, None, "t3").unwrap();
+        let slo = get_persona_slo(&pool, &persona_id).unwrap().unwrap();
+        assert_eq!(slo.breach_reason, None);
+        assert_eq!(slo.breached_at, None);
+        assert_eq!(slo.breach_issue_id, None);
+    }
 }
@@ -0,0 +1,261 @@
+//! SQLite busy/locked retry wrapper and per-table contention counters.
+//!
+//! `PRAGMA busy_timeout` (see `db::init_db`) already makes a connection wait
+//! for a lock, but a WAL writer can still get `SQLITE_BUSY` straight away —
+//! a deferred transaction that upgrades to a write after another connection
+//! committed cannot be fixed by waiting — and a long burst of streaming
+//! writes can outlast the timeout. [`with_busy_retry`] retries those failures
+//! with jittered exponential backoff and records every occurrence per table,
+//! so pool sizing and write batching changes can be checked against
+//! [`snapshot`] (`get_db_contention_stats`) instead of guessed at.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use rand::Rng;
+use rusqlite::ErrorCode;
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::error::AppError;
+
+/// Total attempts (first try included) before a busy error is returned.
+const MAX_ATTEMPTS: u32 = 4;
+/// Backoff before the first retry; doubles per retry, plus up to 50% jitter.
+const BASE_BACKOFF: Duration = Duration::from_millis(20);
+
+#[derive(Debug, Default)]
+struct Counters {
+    busy_errors: u64,
+    recovered: u64,
+    exhausted: u64,
+    backoff_ms: u64,
+    last_busy_at: Option<String>,
+}
+
+struct State {
+    since: String,
+    tables: HashMap<String, Counters>,
+}
+
+static STATS: Mutex<Option<State>> = Mutex::new(None);
+
+fn with_stats<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    let mut guard = STATS.lock().unwrap_or_else(|e| e.into_inner());
+    let state = guard.get_or_insert_with(|| State {
+        since: chrono::Utc::now().to_rfc3339(),
+        tables: HashMap::new(),
+    });
+    f(state)
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct TableContentionStats {
+    pub table: String,
+    /// Busy/locked errors seen, counting every failed attempt.
+    #[ts(type = "number")]
+    pub busy_errors: u64,
+    /// Operations that hit a busy error and then succeeded on retry.
+    #[ts(type = "number")]
+    pub recovered: u64,
+    /// Operations that were still busy after the last attempt.
+    #[ts(type = "number")]
+    pub exhausted: u64,
+    /// Time spent sleeping between retries.
+    #[ts(type = "number")]
+    pub backoff_ms: u64,
+    pub last_busy_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct DbContentionSnapshot {
+    /// Start of the measurement window (process start or last reset).
+    pub since: String,
+    /// Tables ordered by busy errors, worst first.
+    pub tables: Vec<TableContentionStats>,
+    #[ts(type = "number")]
+    pub total_busy_errors: u64,
+    #[ts(type = "number")]
+    pub total_recovered: u64,
+    #[ts(type = "number")]
+    pub total_exhausted: u64,
+    /// Connections currently open in the main pool.
+    pub pool_connections: u32,
+    /// Open connections not checked out right now.
+    pub pool_idle: u32,
+    pub pool_max_size: u32,
+}
+
+/// Whether `err` is SQLite reporting lock contention (`SQLITE_BUSY` or
+/// `SQLITE_LOCKED`) rather than a real failure.
+pub fn is_busy(err: &AppError) -> bool {
+    matches!(
+        err,
+        AppError::Database(rusqlite::Error::SqliteFailure(e, _))
+            if matches!(e.code, ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked)
+    )
+}
+
+/// Run `op`, retrying up to [`MAX_ATTEMPTS`] times while it fails with a busy
+/// error. Every busy error is counted against `table`. `op` must be safe to
+/// re-run: a busy failure means the statement did not commit, so a single
+/// statement or a transaction that is rolled back on error qualifies.
+pub fn with_busy_retry<T>(
+    table: &str,
+    mut op: impl FnMut() -> Result<T, AppError>,
+) -> Result<T, AppError> {
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => {
+                if attempt > 1 {
+                    with_stats(|s| s.tables.entry(table.to_string()).or_default().recovered += 1);
+                }
+                return Ok(value);
+            }
+            Err(err) if is_busy(&err) => {
+                let exhausted = attempt >= MAX_ATTEMPTS;
+                let backoff = if exhausted {
+                    Duration::ZERO
+                } else {
+                    let base = BASE_BACKOFF * 2u32.pow(attempt - 1);
+                    let jitter = rand::thread_rng().gen_range(0..=base.as_millis() as u64 / 2);
+                    base + Duration::from_millis(jitter)
+                };
+                with_stats(|s| {
+                    let c = s.tables.entry(table.to_string()).or_default();
+                    c.busy_errors += 1;
+                    c.backoff_ms += backoff.as_millis() as u64;
+                    c.last_busy_at = Some(chrono::Utc::now().to_rfc3339());
+                    if exhausted {
+                        c.exhausted += 1;
+                    }
+                });
+                if exhausted {
+                    tracing::warn!(
+                        table,
+                        attempts = attempt,
+                        error = %err,
+                        "SQLite still busy after retries"
+                    );
+                    return Err(err);
+                }
+                tracing::debug!(
+                    table,
+                    attempt,
+                    backoff_ms = backoff.as_millis() as u64,
+                    "SQLite busy, retrying"
+                );
+                std::thread::sleep(backoff);
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Aggregate contention counters for the current window. Pool figures are
+/// filled in by the caller, which owns the pool handle.
+pub fn snapshot() -> DbContentionSnapshot {
+    with_stats(|s| {
+        let mut tables: Vec<TableContentionStats> = s
+            .tables
+            .iter()
+            .map(|(table, c)| TableContentionStats {
+                table: table.clone(),
+                busy_errors: c.busy_errors,
+                recovered: c.recovered,
+                exhausted: c.exhausted,
+                backoff_ms: c.backoff_ms,
+                last_busy_at: c.last_busy_at.clone(),
+            })
+            .collect();
+        tables.sort_by(|a, b| {
+            b.busy_errors
+                .cmp(&a.busy_errors)
+                .then(a.table.cmp(&b.table))
+        });
+        DbContentionSnapshot {
+            since: s.since.clone(),
+            total_busy_errors: tables.iter().map(|t| t.busy_errors).sum(),
+            total_recovered: tables.iter().map(|t| t.recovered).sum(),
+            total_exhausted: tables.iter().map(|t| t.exhausted).sum(),
+            tables,
+            pool_connections: 0,
+            pool_idle: 0,
+            pool_max_size: 0,
+        }
+    })
+}
+
+/// Clear all counters and start a new measurement window.
+pub fn reset() {
+    with_stats(|s| {
+        s.tables.clear();
+        s.since = chrono::Utc::now().to_rfc3339();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn busy() -> AppError {
+        AppError::Database(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+            Some("database is locked".into()),
+        ))
+    }
+
+    fn stats_for(table: &str) -> TableContentionStats {
+        snapshot()
+            .tables
+            .into_iter()
+            .find(|t| t.table == table)
+            .expect("table counted")
+    }
+
+    #[test]
+    fn retries_busy_errors_and_counts_them() {
+        let mut calls = 0;
+        let result = with_busy_retry("contention_test_recover", || {
+            calls += 1;
+            if calls < 3 {
+                Err(busy())
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+        let stats = stats_for("contention_test_recover");
+        assert_eq!(
+            (stats.busy_errors, stats.recovered, stats.exhausted),
+            (2, 1, 0)
+        );
+
+        let mut calls = 0;
+        let result: Result<(), AppError> = with_busy_retry("contention_test_exhaust", || {
+            calls += 1;
+            Err(busy())
+        });
+        assert!(result.as_ref().is_err_and(is_busy));
+        assert_eq!(calls, MAX_ATTEMPTS);
+        assert_eq!(stats_for("contention_test_exhaust").exhausted, 1);
+    }
+
+    #[test]
+    fn other_errors_are_not_retried() {
+        let mut calls = 0;
+        let result: Result<(), AppError> = with_busy_retry("contention_test_other", || {
+            calls += 1;
+            Err(AppError::Validation("nope".into()))
+        });
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(calls, 1);
+    }
+}
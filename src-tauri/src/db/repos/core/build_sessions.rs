@@ -400,6 +400,7 @@ mod tests {
             mode: Some("interactive".into()),
             companion_session_id: None,
             disabled_dims_json: None,
+            engine: None,
             phase_timings_json: None,
             total_cost_usd: None,
             input_tokens: None,
@@ -2,7 +2,7 @@ use rusqlite::params;
 
 use crate::db::models::{
     validate_category, validate_importance, CreatePersonaMemoryInput, PersonaMemory,
-    DEFAULT_MEMORY_CATEGORY,
+    UpdatePersonaMemoryInput, DEFAULT_MEMORY_CATEGORY,
 };
 use crate::db::query_builder::QueryBuilder;
 use crate::db::repos::utils::collect_rows;
@@ -68,6 +68,22 @@ fn escape_like(input: &str) -> String {
         .replace('_', "\\_")
 }
 
+/// Predicate excluding memories whose `expires_at` has passed. Applied on
+/// every injection / recall read so an expired fact stops reaching prompts
+/// immediately, not only after the hourly [`archive_expired`] sweep.
+const NOT_EXPIRED_SQL: &str = "(expires_at IS NULL OR julianday(expires_at) > julianday('now'))";
+
+/// Parse a user-supplied expiry and store it in one canonical UTC form so
+/// SQLite's `julianday` and lexical comparisons agree.
+fn normalize_expiry(raw: &str) -> Result<String, AppError> {
+    chrono::DateTime::parse_from_rfc3339(raw.trim())
+        .map(|t| {
+            t.with_timezone(&chrono::Utc)
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        })
+        .map_err(|e| AppError::Validation(format!("Invalid expires_at '{raw}': {e}")))
+}
+
 /// `tier` filter sentinel meaning "every tier except archive" — the default
 /// Memories list view (archived memories are curated-out but still reachable
 /// via the explicit Archived filter).
@@ -130,6 +146,7 @@ row_mapper!(row_to_memory -> PersonaMemory {
     home_team_id [opt],
     derived_from [opt],
     open_claim_count [opt_i32],
+    expires_at [opt],
 });
 
 /// Map user-provided sort column to a safe SQL column name.
@@ -407,8 +424,10 @@ pub fn get_active_for_decay(
     timed_query!("persona_memories", "persona_memories::get_active_for_decay", {
         let conn = pool.get()?;
         let mut stmt = conn.prepare_cached(
-            "SELECT * FROM persona_memories
-             WHERE persona_id = ?1 AND tier = 'active'",
+            &format!(
+                "SELECT * FROM persona_memories
+                 WHERE persona_id = ?1 AND tier = 'active' AND {NOT_EXPIRED_SQL}"
+            ),
         )?;
         let rows = stmt.query_map(params![persona_id], row_to_memory)?;
         Ok(collect_rows(rows, "memories::get_active_for_decay"))
@@ -784,6 +803,97 @@ pub fn update_content(
     })
 }
 
+/// Apply a partial edit ([`UpdatePersonaMemoryInput`]) and return the row.
+///
+/// Title/content go through the same HTML stripping as `create`, and a
+/// changed title or content re-embeds the memory. Expiry timestamps are
+/// normalized to UTC; `Some(None)` clears the expiry.
+pub fn update(
+    pool: &DbPool,
+    id: &str,
+    input: UpdatePersonaMemoryInput,
+) -> Result<PersonaMemory, AppError> {
+    timed_query!("persona_memories", "persona_memories::update", {
+        let current = get_by_id(pool, id)?;
+        let title = input.title.as_deref().map(strip_html_tags);
+        let content = input.content.as_deref().map(strip_html_tags);
+        if title.as_deref().is_some_and(|t| t.trim().is_empty()) {
+            return Err(AppError::Validation("Title cannot be empty".into()));
+        }
+        if content.as_deref().is_some_and(|c| c.trim().is_empty()) {
+            return Err(AppError::Validation("Content cannot be empty".into()));
+        }
+        if let Some(ref category) = input.category {
+            validate_category(category)?;
+        }
+        let importance = input.importance.map(validate_importance).transpose()?;
+        let tags = input
+            .tags
+            .map(|t| serde_json::to_string(&t).unwrap_or_else(|_| "[]".to_string()));
+        let expires_at = match input.expires_at {
+            Some(Some(raw)) => Some(Some(normalize_expiry(&raw)?)),
+            Some(None) => Some(None),
+            None => None,
+        };
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut sets: Vec<String> = vec!["updated_at = ?1".into()];
+        let mut param_idx = 2u32;
+        let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> = vec![Box::new(now)];
+
+        push_field_param!(title, "title", sets, param_idx, param_values, clone);
+        push_field_param!(content, "content", sets, param_idx, param_values, clone);
+        push_field_param!(input.category, "category", sets, param_idx, param_values, clone);
+        push_field_param!(importance, "importance", sets, param_idx, param_values, copy);
+        push_field_param!(tags, "tags", sets, param_idx, param_values, clone);
+        push_field_param!(expires_at, "expires_at", sets, param_idx, param_values, clone);
+
+        let sql = format!(
+            "UPDATE persona_memories SET {} WHERE id = ?{}",
+            sets.join(", "),
+            param_idx
+        );
+        param_values.push(Box::new(id.to_string()));
+        let params_ref: Vec<&dyn rusqlite::types::ToSql> =
+            param_values.iter().map(|p| p.as_ref()).collect();
+        pool.get()?.execute(&sql, params_ref.as_slice())?;
+
+        if title.is_some() || content.is_some() {
+            // MEMORY CONTRACT (7): content changed → refresh the vector.
+            spawn_embed_memory(
+                id.to_string(),
+                memory_embedding_text_parts(
+                    title.as_deref().unwrap_or(&current.title),
+                    content.as_deref().unwrap_or(&current.content),
+                ),
+            );
+        }
+        get_by_id(pool, id)
+    })
+}
+
+/// Archive every non-archived memory whose `expires_at` has passed and drop
+/// its vector. Runs from the hourly cleanup sweep. Unlike decay, this also
+/// applies to core memories: an expiry is an explicit user decision.
+pub fn archive_expired(pool: &DbPool) -> Result<i64, AppError> {
+    timed_query!("persona_memories", "persona_memories::archive_expired", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "UPDATE persona_memories SET tier = 'archive', updated_at = ?1
+             WHERE tier != 'archive' AND expires_at IS NOT NULL
+               AND julianday(expires_at) <= julianday('now')
+             RETURNING id",
+        )?;
+        let ids: Vec<String> = stmt
+            .query_map(params![now], |r| r.get(0))?
+            .collect::<Result<_, _>>()?;
+        let count = ids.len() as i64;
+        spawn_delete_memory_embeddings(ids);
+        Ok(count)
+    })
+}
+
 /// Batch-update importance for multiple memories in a single transaction.
 /// Each tuple is (id, new_importance).
 pub fn batch_update_importance(pool: &DbPool, updates: &[(String, i32)]) -> Result<i64, AppError> {
@@ -1179,14 +1289,20 @@ pub fn merge(
             .home_team_id
             .clone()
             .or_else(|| mem_b.home_team_id.clone());
+        // Keep an expiry only when BOTH sides expire (the later one wins): a
+        // permanent source means the merged fact is still wanted indefinitely.
+        let expires_at = match (&mem_a.expires_at, &mem_b.expires_at) {
+            (Some(a), Some(b)) => Some(if a >= b { a.clone() } else { b.clone() }),
+            _ => None,
+        };
 
         let conn = pool.get()?;
         let tx = conn.unchecked_transaction()?;
 
         tx.execute(
             "INSERT INTO persona_memories
-             (id, persona_id, title, content, category, source_execution_id, importance, tags, created_at, updated_at, use_case_id, tier, home_team_id)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9, ?10, ?11, ?12)",
+             (id, persona_id, title, content, category, source_execution_id, importance, tags, created_at, updated_at, use_case_id, tier, home_team_id, expires_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9, ?10, ?11, ?12, ?13)",
             params![
                 id,
                 persona_id,
@@ -1200,6 +1316,7 @@ pub fn merge(
                 use_case_id,
                 tier,
                 home_team_id,
+                expires_at,
             ],
         )?;
 
@@ -1414,7 +1531,7 @@ pub fn get_for_injection_v2(
             let sql = format!(
                 "SELECT * FROM (
                  SELECT * FROM persona_memories
-                 WHERE {persona_scope_sql} AND tier = 'core'
+                 WHERE {persona_scope_sql} AND tier = 'core' AND {NOT_EXPIRED_SQL}
                  ORDER BY importance DESC, created_at DESC
                  LIMIT ?2
              )
@@ -1422,6 +1539,7 @@ pub fn get_for_injection_v2(
              SELECT * FROM (
                  SELECT * FROM persona_memories
                  WHERE {persona_scope_sql} AND tier IN ('active', 'working')
+                 AND {NOT_EXPIRED_SQL}
                  {active_uc_sql}
                  ORDER BY (importance * 10.0
                            + MIN(access_count, 9)
@@ -2038,6 +2156,42 @@ pub async fn backfill_memory_embeddings(
     Ok(embedded)
 }
 
+/// Forced re-embed for `reindex_memories`: drop the stored vectors of every
+/// recall-eligible memory (one persona, or all when `persona_id` is `None`),
+/// then backfill until nothing is left to embed. Use after an embedding-model
+/// change or to repair a damaged vec table. Returns `(cleared, embedded)`.
+#[cfg(feature = "ml")]
+pub async fn reindex_memory_embeddings(
+    main_pool: &DbPool,
+    vec_pool: &crate::db::UserDbPool,
+    embedder: &std::sync::Arc<crate::engine::embedder::EmbeddingManager>,
+    persona_id: Option<&str>,
+) -> Result<(usize, usize), AppError> {
+    let ids: Vec<String> = {
+        let conn = main_pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT id FROM persona_memories
+             WHERE tier != 'archive' AND (?1 IS NULL OR persona_id = ?1)",
+        )?;
+        let rows = stmt.query_map(params![persona_id], |r| r.get::<_, String>(0))?;
+        collect_rows(rows, "memories::reindex_memory_embeddings")
+    };
+    let existing = embedded_memory_ids(vec_pool)?;
+    let cleared: Vec<String> = ids.into_iter().filter(|id| existing.contains(id)).collect();
+    delete_memory_embeddings(vec_pool, &cleared)?;
+
+    // Each pass only embeds rows still missing a vector, so this ends once a
+    // pass makes no progress (everything embedded, or the rest keep failing).
+    let mut embedded = 0usize;
+    loop {
+        match backfill_memory_embeddings(main_pool, vec_pool, embedder, 64).await? {
+            0 => break,
+            n => embedded += n,
+        }
+    }
+    Ok((cleared.len(), embedded))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2406,6 +2560,58 @@ mod tests {
         mem.id
     }
 
+    #[test]
+    fn update_patches_fields_and_expiry_hides_then_archives() {
+        let pool = init_test_db().unwrap();
+        let pid = make_persona(&pool, "Expiry Agent");
+        let id = insert_scoped_memory(&pool, &pid, "Price list", "active", None);
+
+        let edited = update(
+            &pool,
+            &id,
+            UpdatePersonaMemoryInput {
+                content: Some("Basic plan is $10/month.".into()),
+                importance: Some(4),
+                expires_at: Some(Some("2020-01-01T02:00:00+02:00".into())),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(edited.title, "Price list");
+        assert_eq!(edited.content, "Basic plan is $10/month.");
+        assert_eq!(edited.importance, 4);
+        assert_eq!(edited.expires_at.as_deref(), Some("2020-01-01T00:00:00Z"));
+
+        // Expired: no longer injected, then archived by the sweep.
+        let tiered = get_for_injection(&pool, &pid, 10, 10).unwrap();
+        assert!(tiered.active.is_empty());
+        assert_eq!(archive_expired(&pool).unwrap(), 1);
+        assert_eq!(get_by_id(&pool, &id).unwrap().tier, "archive");
+        assert_eq!(archive_expired(&pool).unwrap(), 0);
+
+        let cleared = update(
+            &pool,
+            &id,
+            UpdatePersonaMemoryInput {
+                expires_at: Some(None),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(cleared.expires_at, None);
+
+        let bad = |input| update(&pool, &id, input).is_err();
+        assert!(bad(UpdatePersonaMemoryInput {
+            title: Some("  ".into()),
+            ..Default::default()
+        }));
+        assert!(bad(UpdatePersonaMemoryInput {
+            expires_at: Some(Some("next tuesday".into())),
+            ..Default::default()
+        }));
+        assert!(update(&pool, "missing", UpdatePersonaMemoryInput::default()).is_err());
+    }
+
     /// v2 scoping (use_case_id = Some): core ALWAYS injected, active filtered
     /// to capability-scoped + persona-wide.
     #[test]
@@ -6,5 +6,6 @@ pub mod memories;
 pub mod memory_claims;
 pub mod memory_review_proposal;
 pub mod personas;
+pub mod project_keys;
 pub mod saved_views;
 pub mod settings;
@@ -5,8 +5,9 @@ use rusqlite::{params, OptionalExtension, Row};
 use tracing::instrument;
 
 use crate::db::models::{
-    CreatePersonaInput, HealthStatus, Persona, PersonaGatewayExposure, PersonaHealth,
-    PersonaLifecycle, PersonaSummary, PersonaTrustLevel, PersonaTrustOrigin, UpdatePersonaInput,
+    CreatePersonaInput, ExecutionPlacement, HealthStatus, Persona, PersonaGatewayExposure,
+    PersonaHealth, PersonaLifecycle, PersonaSummary, PersonaTrustLevel, PersonaTrustOrigin,
+    UpdatePersonaInput,
 };
 use crate::db::query_builder::QueryBuilder;
 use crate::db::repos::utils::collect_rows;
@@ -422,6 +423,14 @@ fn row_to_persona_with_mode(row: &Row, mode: ProfileMode) -> rusqlite::Result<Pe
             .get::<_, Option<String>>("disabled_dims_json")
             .ok()
             .flatten(),
+        engine: row
+            .get::<_, Option<String>>("engine")
+            .ok()
+            .flatten(),
+        execution_placement: row
+            .get::<_, Option<String>>("execution_placement")
+            .ok()
+            .flatten(),
         lifecycle: row
             .get::<_, Option<String>>("lifecycle")
             .ok()
@@ -514,7 +523,8 @@ const LEAN_LIST_COLUMNS: &str = "id, project_id, name, description, icon, color,
      last_design_result, model_profile, max_budget_usd, max_turns, design_context, \
      home_team_id, source_review_id, trust_level, trust_origin, trust_verified_at, \
      trust_score, gateway_exposure, template_category, cli_awareness_enabled, \
-     setup_status, setup_detail, disabled_dims_json, lifecycle, created_at, updated_at";
+     setup_status, setup_detail, disabled_dims_json, engine, execution_placement, lifecycle, \
+     created_at, updated_at";
 
 /// Map a lean roster row to a `Persona` with the five heavy editor-only fields
 /// left blank. `model_profile` is redacted (list view). Mirrors the light-field
@@ -591,6 +601,14 @@ fn row_to_persona_lean(row: &Row) -> rusqlite::Result<Persona> {
             .get::<_, Option<String>>("disabled_dims_json")
             .ok()
             .flatten(),
+        engine: row
+            .get::<_, Option<String>>("engine")
+            .ok()
+            .flatten(),
+        execution_placement: row
+            .get::<_, Option<String>>("execution_placement")
+            .ok()
+            .flatten(),
         lifecycle: row
             .get::<_, Option<String>>("lifecycle")
             .ok()
@@ -970,6 +988,14 @@ pub fn update(pool: &DbPool, id: &str, input: UpdatePersonaInput) -> Result<Pers
         if let Some(ref lc) = input.lifecycle {
             lc.parse::<PersonaLifecycle>()?;
         }
+        if let Some(Some(ref engine)) = input.engine {
+            if crate::engine::provider::EngineKind::from_str_exact(engine).is_none() {
+                return Err(AppError::Validation(format!("Unknown engine '{engine}'")));
+            }
+        }
+        if let Some(Some(ref placement)) = input.execution_placement {
+            placement.parse::<ExecutionPlacement>()?;
+        }
 
         // Encrypt auth_token inside model_profile before storing
         let encrypted_profile = encrypt_update_profile(&input.model_profile)?;
@@ -1151,6 +1177,22 @@ pub fn update(pool: &DbPool, id: &str, input: UpdatePersonaInput) -> Result<Pers
             param_values,
             clone
         );
+        push_field_param!(
+            input.engine,
+            "engine",
+            sets,
+            param_idx,
+            param_values,
+            clone
+        );
+        push_field_param!(
+            input.execution_placement,
+            "execution_placement",
+            sets,
+            param_idx,
+            param_values,
+            clone
+        );
         push_field_param!(
             input.lifecycle,
             "lifecycle",
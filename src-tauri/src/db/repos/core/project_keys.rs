@@ -0,0 +1,120 @@
+//! Storage for per-project data keys. Key material is only ever stored wrapped
+//! by the master key; wrapping and the cipher cache live in
+//! [`crate::engine::project_keys`].
+
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// A project's data key as stored: AES-256 key material encrypted with the
+/// master key. Revoked keys have their material erased.
+#[derive(Debug, Clone)]
+pub struct WrappedProjectKey {
+    pub id: String,
+    pub project_id: String,
+    pub wrapped_key: String,
+    pub wrap_iv: String,
+}
+
+fn row_to_key(row: &Row) -> rusqlite::Result<WrappedProjectKey> {
+    Ok(WrappedProjectKey {
+        id: row.get("id")?,
+        project_id: row.get("project_id")?,
+        wrapped_key: row.get("wrapped_key")?,
+        wrap_iv: row.get("wrap_iv")?,
+    })
+}
+
+/// The active key of a project, if one has been created.
+pub fn get_active(pool: &DbPool, project_id: &str) -> Result<Option<WrappedProjectKey>, AppError> {
+    timed_query!("project_data_keys", "project_data_keys::get_active", {
+        let conn = pool.get()?;
+        let key = conn
+            .query_row(
+                "SELECT id, project_id, wrapped_key, wrap_iv FROM project_data_keys
+                 WHERE project_id = ?1 AND status = 'active'",
+                params![project_id],
+                row_to_key,
+            )
+            .optional()?;
+        Ok(key)
+    })
+}
+
+/// Every active key, for warming the cipher cache at startup.
+pub fn list_active(pool: &DbPool) -> Result<Vec<WrappedProjectKey>, AppError> {
+    timed_query!("project_data_keys", "project_data_keys::list_active", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, project_id, wrapped_key, wrap_iv FROM project_data_keys
+             WHERE status = 'active'",
+        )?;
+        let rows = stmt.query_map([], row_to_key)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)
+    })
+}
+
+/// Store a new active key for a project. Returns `false` when the project
+/// already has one (a concurrent writer won the race).
+pub fn insert_active(pool: &DbPool, key: &WrappedProjectKey) -> Result<bool, AppError> {
+    timed_query!("project_data_keys", "project_data_keys::insert_active", {
+        let conn = pool.get()?;
+        let n = conn.execute(
+            "INSERT OR IGNORE INTO project_data_keys
+                (id, project_id, wrapped_key, wrap_iv, status, created_at)
+             VALUES (?1, ?2, ?3, ?4, 'active', ?5)",
+            params![
+                key.id,
+                key.project_id,
+                key.wrapped_key,
+                key.wrap_iv,
+                chrono::Utc::now().to_rfc3339(),
+            ],
+        )?;
+        Ok(n > 0)
+    })
+}
+
+/// Revoke a project's active key and erase its material. Returns the ids of
+/// the keys revoked (empty when the project had none).
+pub fn revoke(pool: &DbPool, project_id: &str) -> Result<Vec<String>, AppError> {
+    timed_query!("project_data_keys", "project_data_keys::revoke", {
+        let mut conn = pool.get()?;
+        let tx = conn.transaction()?;
+        let ids = {
+            let mut stmt = tx.prepare(
+                "SELECT id FROM project_data_keys WHERE project_id = ?1 AND status = 'active'",
+            )?;
+            let rows = stmt.query_map(params![project_id], |r| r.get::<_, String>(0))?;
+            rows.collect::<Result<Vec<_>, _>>()?
+        };
+        tx.execute(
+            "UPDATE project_data_keys
+             SET status = 'revoked', wrapped_key = NULL, wrap_iv = NULL, revoked_at = ?2
+             WHERE project_id = ?1 AND status = 'active'",
+            params![project_id, chrono::Utc::now().to_rfc3339()],
+        )?;
+        tx.commit()?;
+        Ok(ids)
+    })
+}
+
+/// Number of event payloads encrypted under a key, found by the key id
+/// embedded in their `payload_iv`.
+pub fn count_event_payloads(pool: &DbPool, iv_prefix: &str) -> Result<i64, AppError> {
+    timed_query!(
+        "persona_events",
+        "project_data_keys::count_event_payloads",
+        {
+            let conn = pool.get()?;
+            let n = conn.query_row(
+                "SELECT COUNT(*) FROM persona_events WHERE substr(payload_iv, 1, length(?1)) = ?1",
+                params![iv_prefix],
+                |r| r.get(0),
+            )?;
+            Ok(n)
+        }
+    )
+}
@@ -0,0 +1,362 @@
+//! Dataset batches (`execution_batches` / `execution_batch_items`).
+//!
+//! A batch is a persona run once per input item. The rows here are the
+//! durable side of `start_batch_execution`: the item list, which execution
+//! each item became, and per-item outcome, so progress and the results export
+//! can be rebuilt from the DB at any time. Pacing (the batch's concurrency)
+//! lives in the command's driver task, which claims items one at a time with
+//! [`claim_next`].
+
+use rusqlite::{params, OptionalExtension};
+
+use crate::db::models::{
+    ExecutionBatch, ExecutionBatchItem, ExecutionBatchProgress, ExecutionBatchResult,
+};
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_batch -> ExecutionBatch {
+    id, persona_id, use_case_id, concurrency, total_items, status,
+    created_at, completed_at,
+});
+
+row_mapper!(row_to_item -> ExecutionBatchItem {
+    batch_id, item_index, input_data, execution_id, status, error,
+});
+
+crud_get_by_id!(
+    ExecutionBatch,
+    "execution_batches",
+    "ExecutionBatch",
+    row_to_batch
+);
+
+/// Create a batch and its items (all `pending`) in one transaction.
+pub fn create(
+    pool: &DbPool,
+    persona_id: &str,
+    use_case_id: Option<&str>,
+    concurrency: i32,
+    items: &[Option<String>],
+) -> Result<ExecutionBatch, AppError> {
+    timed_query!("execution_batches", "execution_batches::create", {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut conn = pool.get()?;
+        let tx = conn.transaction()?;
+        let batch = tx.query_row(
+            "INSERT INTO execution_batches
+                 (id, persona_id, use_case_id, concurrency, total_items, status, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, 'running', ?6)
+             RETURNING *",
+            params![
+                id,
+                persona_id,
+                use_case_id,
+                concurrency,
+                items.len() as i64,
+                now
+            ],
+            row_to_batch,
+        )?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO execution_batch_items (batch_id, item_index, input_data)
+                 VALUES (?1, ?2, ?3)",
+            )?;
+            for (index, input) in items.iter().enumerate() {
+                stmt.execute(params![id, index as i64, input])?;
+            }
+        }
+        tx.commit()?;
+        Ok(batch)
+    })
+}
+
+/// Batches newest first, optionally for one persona.
+pub fn list(
+    pool: &DbPool,
+    persona_id: Option<&str>,
+    limit: i64,
+) -> Result<Vec<ExecutionBatch>, AppError> {
+    timed_query!("execution_batches", "execution_batches::list", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT * FROM execution_batches
+             WHERE ?1 IS NULL OR persona_id = ?1
+             ORDER BY created_at DESC
+             LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![persona_id, limit], row_to_batch)?;
+        Ok(collect_rows(rows, "execution_batches::list"))
+    })
+}
+
+pub fn list_items(pool: &DbPool, batch_id: &str) -> Result<Vec<ExecutionBatchItem>, AppError> {
+    timed_query!("execution_batch_items", "batch_items::list", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT * FROM execution_batch_items WHERE batch_id = ?1 ORDER BY item_index",
+        )?;
+        let rows = stmt.query_map(params![batch_id], row_to_item)?;
+        Ok(collect_rows(rows, "batch_items::list"))
+    })
+}
+
+/// Move the lowest-indexed pending item to `running` and return it. `None`
+/// once every item has been claimed, or when the batch is no longer running
+/// (cancelled), so the driver stops there.
+pub fn claim_next(pool: &DbPool, batch_id: &str) -> Result<Option<ExecutionBatchItem>, AppError> {
+    timed_query!("execution_batch_items", "batch_items::claim_next", {
+        let conn = pool.get()?;
+        Ok(conn
+            .query_row(
+                "UPDATE execution_batch_items SET status = 'running'
+                 WHERE batch_id = ?1
+                   AND item_index = (
+                       SELECT MIN(item_index) FROM execution_batch_items
+                       WHERE batch_id = ?1 AND status = 'pending'
+                   )
+                   AND EXISTS (
+                       SELECT 1 FROM execution_batches WHERE id = ?1 AND status = 'running'
+                   )
+                 RETURNING *",
+                params![batch_id],
+                row_to_item,
+            )
+            .optional()?)
+    })
+}
+
+pub fn set_item_execution(
+    pool: &DbPool,
+    batch_id: &str,
+    item_index: i32,
+    execution_id: &str,
+) -> Result<(), AppError> {
+    timed_query!("execution_batch_items", "batch_items::set_execution", {
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE execution_batch_items SET execution_id = ?3
+             WHERE batch_id = ?1 AND item_index = ?2",
+            params![batch_id, item_index, execution_id],
+        )?;
+        Ok(())
+    })
+}
+
+/// Record an item's outcome (`completed`, `failed` or `cancelled`).
+pub fn finish_item(
+    pool: &DbPool,
+    batch_id: &str,
+    item_index: i32,
+    status: &str,
+    error: Option<&str>,
+) -> Result<(), AppError> {
+    timed_query!("execution_batch_items", "batch_items::finish", {
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE execution_batch_items SET status = ?3, error = ?4
+             WHERE batch_id = ?1 AND item_index = ?2",
+            params![batch_id, item_index, status, error],
+        )?;
+        Ok(())
+    })
+}
+
+/// Mark a running batch `completed` once its driver has drained every item.
+/// Item failures don't fail the batch; they are counted in its progress.
+pub fn complete(pool: &DbPool, batch_id: &str) -> Result<(), AppError> {
+    timed_query!("execution_batches", "execution_batches::complete", {
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE execution_batches SET status = 'completed', completed_at = ?2
+             WHERE id = ?1 AND status = 'running'",
+            params![batch_id, chrono::Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    })
+}
+
+/// Cancel a running batch: no further items start and pending ones are
+/// marked `cancelled`. Returns the executions of items still in flight, for
+/// the caller to cancel through the engine.
+pub fn cancel(pool: &DbPool, batch_id: &str) -> Result<Vec<String>, AppError> {
+    timed_query!("execution_batches", "execution_batches::cancel", {
+        let mut conn = pool.get()?;
+        let tx = conn.transaction()?;
+        let changed = tx.execute(
+            "UPDATE execution_batches SET status = 'cancelled', completed_at = ?2
+             WHERE id = ?1 AND status = 'running'",
+            params![batch_id, chrono::Utc::now().to_rfc3339()],
+        )?;
+        if changed == 0 {
+            return Ok(Vec::new());
+        }
+        tx.execute(
+            "UPDATE execution_batch_items SET status = 'cancelled'
+             WHERE batch_id = ?1 AND status = 'pending'",
+            params![batch_id],
+        )?;
+        let in_flight = {
+            let mut stmt = tx.prepare(
+                "SELECT execution_id FROM execution_batch_items
+                 WHERE batch_id = ?1 AND status = 'running' AND execution_id IS NOT NULL",
+            )?;
+            let rows = stmt.query_map(params![batch_id], |row| row.get::<_, String>(0))?;
+            collect_rows(rows, "execution_batches::cancel")
+        };
+        tx.commit()?;
+        Ok(in_flight)
+    })
+}
+
+/// Counts per item status plus the spend of the batch's executions.
+pub fn progress(pool: &DbPool, batch_id: &str) -> Result<ExecutionBatchProgress, AppError> {
+    timed_query!("execution_batches", "execution_batches::progress", {
+        let conn = pool.get()?;
+        let progress = conn
+            .query_row(
+                "SELECT b.id, b.persona_id, b.status, b.total_items,
+                        COALESCE(SUM(i.status = 'pending'), 0),
+                        COALESCE(SUM(i.status = 'running'), 0),
+                        COALESCE(SUM(i.status = 'completed'), 0),
+                        COALESCE(SUM(i.status = 'failed'), 0),
+                        COALESCE(SUM(i.status = 'cancelled'), 0),
+                        COALESCE(SUM(e.cost_usd), 0.0)
+                 FROM execution_batches b
+                 LEFT JOIN execution_batch_items i ON i.batch_id = b.id
+                 LEFT JOIN persona_executions e ON e.id = i.execution_id
+                 WHERE b.id = ?1
+                 GROUP BY b.id",
+                params![batch_id],
+                |row| {
+                    Ok(ExecutionBatchProgress {
+                        batch_id: row.get(0)?,
+                        persona_id: row.get(1)?,
+                        status: row.get(2)?,
+                        total: row.get(3)?,
+                        pending: row.get(4)?,
+                        running: row.get(5)?,
+                        completed: row.get(6)?,
+                        failed: row.get(7)?,
+                        cancelled: row.get(8)?,
+                        cost_usd: row.get(9)?,
+                    })
+                },
+            )
+            .optional()?;
+        progress.ok_or_else(|| AppError::NotFound(format!("ExecutionBatch {batch_id}")))
+    })
+}
+
+/// Per-item results joined with each item's execution, in item order.
+pub fn results(pool: &DbPool, batch_id: &str) -> Result<Vec<ExecutionBatchResult>, AppError> {
+    timed_query!("execution_batch_items", "batch_items::results", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT i.item_index, i.input_data, i.status, i.execution_id,
+                    e.output_data, COALESCE(i.error, e.error_message), e.cost_usd, e.duration_ms
+             FROM execution_batch_items i
+             LEFT JOIN persona_executions e ON e.id = i.execution_id
+             WHERE i.batch_id = ?1
+             ORDER BY i.item_index",
+        )?;
+        let rows = stmt.query_map(params![batch_id], |row| {
+            Ok(ExecutionBatchResult {
+                item_index: row.get(0)?,
+                input_data: row.get(1)?,
+                status: row.get(2)?,
+                execution_id: row.get(3)?,
+                output_data: row.get(4)?,
+                error: row.get(5)?,
+                cost_usd: row.get(6)?,
+                duration_ms: row.get(7)?,
+            })
+        })?;
+        Ok(collect_rows(rows, "batch_items::results"))
+    })
+}
+
+/// Mark batches whose driver died with the app as `interrupted` and cancel
+/// their unstarted items. Items already handed to the queue are left alone —
+/// their executions are recovered by the engine like any other run. Called
+/// once at startup. Returns the number of batches affected.
+pub fn recover_interrupted(pool: &DbPool) -> Result<usize, AppError> {
+    timed_query!("execution_batches", "batches::recover_interrupted", {
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE execution_batch_items SET status = 'cancelled'
+             WHERE status = 'pending'
+               AND batch_id IN (SELECT id FROM execution_batches WHERE status = 'running')",
+            [],
+        )?;
+        let affected = conn.execute(
+            "UPDATE execution_batches SET status = 'interrupted', completed_at = ?1
+             WHERE status = 'running'",
+            params![chrono::Utc::now().to_rfc3339()],
+        )?;
+        Ok(affected)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::repos::test_fixtures::create_test_persona_id;
+
+    fn items(n: usize) -> Vec<Option<String>> {
+        (0..n).map(|i| Some(format!(r#"{{"row":{i}}}"#))).collect()
+    }
+
+    #[test]
+    fn items_are_claimed_in_order_and_counted() {
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona_id(&pool, "Batcher", "prompt");
+        let batch = create(&pool, &persona, None, 2, &items(3)).unwrap();
+        assert_eq!(batch.total_items, 3);
+
+        let first = claim_next(&pool, &batch.id).unwrap().unwrap();
+        let second = claim_next(&pool, &batch.id).unwrap().unwrap();
+        assert_eq!((first.item_index, second.item_index), (0, 1));
+        finish_item(&pool, &batch.id, 0, "completed", None).unwrap();
+        finish_item(&pool, &batch.id, 1, "failed", Some("not ready")).unwrap();
+
+        let p = progress(&pool, &batch.id).unwrap();
+        assert_eq!((p.pending, p.running, p.completed, p.failed), (1, 0, 1, 1));
+
+        let third = claim_next(&pool, &batch.id).unwrap().unwrap();
+        assert_eq!(third.item_index, 2);
+        assert!(claim_next(&pool, &batch.id).unwrap().is_none());
+        finish_item(&pool, &batch.id, 2, "completed", None).unwrap();
+        complete(&pool, &batch.id).unwrap();
+
+        let rows = results(&pool, &batch.id).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].error.as_deref(), Some("not ready"));
+        assert_eq!(get_by_id(&pool, &batch.id).unwrap().status, "completed");
+    }
+
+    #[test]
+    fn cancel_stops_claims_and_interrupted_batches_are_recovered() {
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona_id(&pool, "Batcher", "prompt");
+
+        let cancelled = create(&pool, &persona, None, 1, &items(3)).unwrap();
+        claim_next(&pool, &cancelled.id).unwrap().unwrap();
+        set_item_execution(&pool, &cancelled.id, 0, "exec-1").unwrap();
+        assert_eq!(cancel(&pool, &cancelled.id).unwrap(), vec!["exec-1"]);
+        assert!(claim_next(&pool, &cancelled.id).unwrap().is_none());
+        assert_eq!(progress(&pool, &cancelled.id).unwrap().cancelled, 2);
+        // A second cancel is a no-op.
+        assert!(cancel(&pool, &cancelled.id).unwrap().is_empty());
+
+        let orphaned = create(&pool, &persona, None, 1, &items(2)).unwrap();
+        assert_eq!(recover_interrupted(&pool).unwrap(), 1);
+        let p = progress(&pool, &orphaned.id).unwrap();
+        assert_eq!((p.status.as_str(), p.cancelled), ("interrupted", 2));
+        assert_eq!(list(&pool, Some(&persona), 10).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,163 @@
+use rusqlite::{params, OptionalExtension};
+
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Per-persona budget enforcement bookkeeping for one billing period
+/// (`YYYY-MM`, UTC). A row from an older period is stale: the month-boundary
+/// reset resumes anything it paused and then deletes it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonaBudgetState {
+    pub persona_id: String,
+    pub period: String,
+    pub alerted_at: Option<String>,
+    pub paused_at: Option<String>,
+}
+
+fn row_to_state(row: &rusqlite::Row) -> rusqlite::Result<PersonaBudgetState> {
+    Ok(PersonaBudgetState {
+        persona_id: row.get("persona_id")?,
+        period: row.get("period")?,
+        alerted_at: row.get("alerted_at")?,
+        paused_at: row.get("paused_at")?,
+    })
+}
+
+pub fn get(pool: &DbPool, persona_id: &str) -> Result<Option<PersonaBudgetState>, AppError> {
+    timed_query!("persona_budget_state", "persona_budget_state::get", {
+        let conn = pool.get()?;
+        Ok(conn
+            .query_row(
+                "SELECT * FROM persona_budget_state WHERE persona_id = ?1",
+                params![persona_id],
+                row_to_state,
+            )
+            .optional()?)
+    })
+}
+
+/// Record that the over-budget alert fired for `period`. Returns `true` only
+/// for the first alert of the period, so callers notify once per month instead
+/// of after every execution. A row left over from an older period is reset.
+pub fn mark_alerted(pool: &DbPool, persona_id: &str, period: &str) -> Result<bool, AppError> {
+    timed_query!("persona_budget_state", "persona_budget_state::mark_alerted", {
+        let conn = pool.get()?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let changed = conn.execute(
+            "INSERT INTO persona_budget_state (persona_id, period, alerted_at)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT(persona_id) DO UPDATE SET
+               alerted_at = excluded.alerted_at,
+               paused_at = CASE WHEN persona_budget_state.period = excluded.period
+                                THEN persona_budget_state.paused_at ELSE NULL END,
+               period = excluded.period
+             WHERE persona_budget_state.period != excluded.period
+                OR persona_budget_state.alerted_at IS NULL",
+            params![persona_id, period, now],
+        )?;
+        Ok(changed > 0)
+    })
+}
+
+/// Workspace counterpart of [`mark_alerted`]: `true` only for the first time a
+/// team's aggregate cap is crossed in `period`.
+pub fn mark_team_alerted(pool: &DbPool, team_id: &str, period: &str) -> Result<bool, AppError> {
+    timed_query!("team_budget_state", "team_budget_state::mark_team_alerted", {
+        let conn = pool.get()?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let changed = conn.execute(
+            "INSERT INTO team_budget_state (team_id, period, alerted_at)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT(team_id) DO UPDATE SET
+               alerted_at = excluded.alerted_at,
+               period = excluded.period
+             WHERE team_budget_state.period != excluded.period
+                OR team_budget_state.alerted_at IS NULL",
+            params![team_id, period, now],
+        )?;
+        Ok(changed > 0)
+    })
+}
+
+/// Record that budget enforcement paused the persona during `period`.
+pub fn mark_paused(pool: &DbPool, persona_id: &str, period: &str) -> Result<(), AppError> {
+    timed_query!("persona_budget_state", "persona_budget_state::mark_paused", {
+        let conn = pool.get()?;
+        let now = chrono::Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT INTO persona_budget_state (persona_id, period, paused_at)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT(persona_id) DO UPDATE SET
+               period = excluded.period,
+               paused_at = excluded.paused_at",
+            params![persona_id, period, now],
+        )?;
+        Ok(())
+    })
+}
+
+/// Personas paused by budget enforcement in a period other than `period`.
+pub fn list_paused_outside(pool: &DbPool, period: &str) -> Result<Vec<String>, AppError> {
+    timed_query!(
+        "persona_budget_state",
+        "persona_budget_state::list_paused_outside",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT persona_id FROM persona_budget_state
+                 WHERE period != ?1 AND paused_at IS NOT NULL",
+            )?;
+            let rows = stmt.query_map(params![period], |row| row.get::<_, String>(0))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)
+        }
+    )
+}
+
+/// Drop every persona and workspace row that does not belong to `period`.
+pub fn clear_outside(pool: &DbPool, period: &str) -> Result<usize, AppError> {
+    timed_query!("persona_budget_state", "persona_budget_state::clear_outside", {
+        let conn = pool.get()?;
+        let personas = conn.execute(
+            "DELETE FROM persona_budget_state WHERE period != ?1",
+            params![period],
+        )?;
+        let teams = conn.execute(
+            "DELETE FROM team_budget_state WHERE period != ?1",
+            params![period],
+        )?;
+        Ok(personas + teams)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::repos::test_fixtures::create_test_persona;
+
+    #[test]
+    fn alert_fires_once_per_period_and_resets_on_rollover() {
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona(&pool, "Spender", "spend");
+
+        assert!(mark_alerted(&pool, &persona.id, "2026-01").unwrap());
+        assert!(!mark_alerted(&pool, &persona.id, "2026-01").unwrap());
+
+        mark_paused(&pool, &persona.id, "2026-01").unwrap();
+        assert_eq!(
+            list_paused_outside(&pool, "2026-02").unwrap(),
+            vec![persona.id.clone()]
+        );
+        assert!(list_paused_outside(&pool, "2026-01").unwrap().is_empty());
+
+        // New period: the alert fires again and the old pause is forgotten.
+        assert!(mark_alerted(&pool, &persona.id, "2026-02").unwrap());
+        let state = get(&pool, &persona.id).unwrap().unwrap();
+        assert_eq!(state.period, "2026-02");
+        assert!(state.paused_at.is_none());
+
+        assert_eq!(clear_outside(&pool, "2026-03").unwrap(), 1);
+        assert!(get(&pool, &persona.id).unwrap().is_none());
+    }
+}
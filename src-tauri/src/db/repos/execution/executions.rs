@@ -1,10 +1,12 @@
 use rusqlite::{params, Row};
 
 use crate::db::models::{
-    ExecutionCounts, ExecutionListItem, ExecutionSearchResult, GlobalExecutionRow,
-    PersonaExecution, UpdateExecutionStatus,
+    CloudExecutionLink, EngineFlagStats, ExecutionCounts, ExecutionListItem,
+    ExecutionSearchResult, GlobalExecutionRow, PersonaExecution, UpdateExecutionStatus,
 };
+use crate::db::repos::contention::with_busy_retry;
 use crate::db::DbPool;
+use crate::engine::output_store;
 use crate::engine::types::ExecutionState;
 use crate::error::AppError;
 
@@ -51,6 +53,9 @@ fn row_to_execution(row: &Row) -> rusqlite::Result<PersonaExecution> {
         director_review_md: row
             .get::<_, Option<String>>("director_review_md")
             .unwrap_or(None),
+        feature_flags: row.get::<_, Option<String>>("feature_flags").unwrap_or(None),
+        output_ref: row.get::<_, Option<String>>("output_ref").unwrap_or(None),
+        firing_id: row.get::<_, Option<String>>("firing_id").unwrap_or(None),
     })
 }
 
@@ -114,6 +119,62 @@ pub fn set_director_review_unscored(
     Ok(())
 }
 
+/// Record the engine feature flags active for a run. An empty set stores NULL.
+pub fn set_feature_flags(
+    pool: &DbPool,
+    execution_id: &str,
+    flags: &[&str],
+) -> Result<(), AppError> {
+    timed_query!("persona_executions", "persona_executions::set_feature_flags", {
+        let value = if flags.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(flags)?)
+        };
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE persona_executions SET feature_flags = ?1 WHERE id = ?2",
+            params![value, execution_id],
+        )?;
+        Ok(())
+    })
+}
+
+/// Per-flag outcome totals over terminal runs, optionally for one persona.
+pub fn feature_flag_stats(
+    pool: &DbPool,
+    persona_id: Option<&str>,
+) -> Result<Vec<EngineFlagStats>, AppError> {
+    timed_query!("persona_executions", "persona_executions::feature_flag_stats", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT f.value AS flag,
+                    COUNT(*) AS runs,
+                    SUM(CASE WHEN e.status = 'completed' THEN 1 ELSE 0 END) AS completed,
+                    SUM(CASE WHEN e.status = 'failed' THEN 1 ELSE 0 END) AS failed,
+                    AVG(e.cost_usd) AS avg_cost_usd,
+                    AVG(e.duration_ms) AS avg_duration_ms
+             FROM persona_executions e, json_each(e.feature_flags) f
+             WHERE e.feature_flags IS NOT NULL
+               AND e.status IN ('completed', 'failed', 'incomplete', 'cancelled')
+               AND (?1 IS NULL OR e.persona_id = ?1)
+             GROUP BY f.value
+             ORDER BY f.value",
+        )?;
+        let rows = stmt.query_map(params![persona_id], |row| {
+            Ok(EngineFlagStats {
+                flag: row.get("flag")?,
+                runs: row.get("runs")?,
+                completed: row.get("completed")?,
+                failed: row.get("failed")?,
+                avg_cost_usd: row.get::<_, Option<f64>>("avg_cost_usd")?.unwrap_or(0.0),
+                avg_duration_ms: row.get("avg_duration_ms")?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(AppError::Database)
+    })
+}
+
 fn build_fts5_query(query: &str) -> String {
     query
         .split(|c: char| !c.is_alphanumeric())
@@ -255,6 +316,48 @@ pub fn get_all_global(
     )
 }
 
+/// Every execution stamped with `firing_id`, oldest first, with persona
+/// metadata for the fan-out view.
+pub fn list_by_firing(pool: &DbPool, firing_id: &str) -> Result<Vec<GlobalExecutionRow>, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::list_by_firing",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare_cached(
+                "SELECT e.*,
+                        COALESCE(p.name, 'Unknown') as persona_name,
+                        p.icon as persona_icon,
+                        p.color as persona_color
+                 FROM persona_executions e
+                 LEFT JOIN personas p ON p.id = e.persona_id
+                 WHERE e.firing_id = ?1
+                 ORDER BY e.created_at ASC",
+            )?;
+            let rows = stmt.query_map(params![firing_id], |row| {
+                Ok(GlobalExecutionRow {
+                    base: row_to_execution(row)?,
+                    persona_name: row.get("persona_name")?,
+                    persona_icon: row.get("persona_icon")?,
+                    persona_color: row.get("persona_color")?,
+                })
+            })?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)
+        }
+    )
+}
+
+/// Stamp the firing that caused an execution (see `PersonaExecution::firing_id`).
+pub fn set_firing_id(pool: &DbPool, execution_id: &str, firing_id: &str) -> Result<(), AppError> {
+    let conn = pool.get()?;
+    conn.execute(
+        "UPDATE persona_executions SET firing_id = ?1 WHERE id = ?2",
+        params![firing_id, execution_id],
+    )?;
+    Ok(())
+}
+
 /// Aggregate execution counts by high-level status bucket, optionally
 /// filtered to a single persona. Returns precise server-side totals so the
 /// Activity filter badges do not depend on how many rows have been paged in.
@@ -372,13 +475,16 @@ pub fn get_by_id(pool: &DbPool, id: &str) -> Result<PersonaExecution, AppError>
     timed_query!("persona_executions", "persona_executions::get_by_id", {
         let conn = pool.get()?;
         let mut stmt = conn.prepare_cached("SELECT * FROM persona_executions WHERE id = ?1")?;
-        stmt.query_row(params![id], row_to_execution)
+        let mut execution = stmt.query_row(params![id], row_to_execution)
             .map_err(|e| match e {
                 rusqlite::Error::QueryReturnedNoRows => {
                     AppError::NotFound(format!("Execution {id}"))
                 }
                 other => AppError::Database(other),
-            })
+            })?;
+        // Large outputs live on disk; hand callers the full text.
+        output_store::hydrate(&mut execution);
+        Ok(execution)
     })
 }
 
@@ -670,10 +776,15 @@ fn redact_execution_fields(input: &mut UpdateExecutionStatus) {
 /// allowed to touch (unguarded / CAS-if-running / CAS-if-not-final) — this
 /// owns the shared SET clause + param binding so the three copies can never
 /// drift out of sync (see refactor-bughunt-2026-07-10 #7).
+///
+/// `output_ref` is the pointer returned by `output_store::offload` when the
+/// output was moved to disk. Writing a new output always replaces the old
+/// pointer, so an inline output clears it.
 fn exec_status_update(
     conn: &rusqlite::Connection,
     id: &str,
     input: &UpdateExecutionStatus,
+    output_ref: Option<&str>,
     where_clause: &str,
 ) -> Result<usize, AppError> {
     let now = chrono::Utc::now().to_rfc3339();
@@ -697,6 +808,7 @@ fn exec_status_update(
         "UPDATE persona_executions SET
             status = ?1,
             output_data = COALESCE(?2, output_data),
+            output_ref = CASE WHEN ?2 IS NULL THEN output_ref ELSE ?18 END,
             error_message = COALESCE(?3, error_message),
             duration_ms = COALESCE(?4, duration_ms),
             log_file_path = COALESCE(?5, log_file_path),
@@ -732,6 +844,7 @@ fn exec_status_update(
         input.execution_config,
         input.log_truncated,
         input.business_outcome,
+        output_ref,
     ])?;
     Ok(rows_changed)
 }
@@ -742,10 +855,13 @@ pub fn update_status(
     mut input: UpdateExecutionStatus,
 ) -> Result<(), AppError> {
     redact_execution_fields(&mut input);
+    let output_ref = output_store::offload(pool, id, &mut input.output_data);
     timed_query!("persona_executions", "persona_executions::update_status", {
-        let conn = pool.get()?;
-        exec_status_update(&conn, id, &input, "WHERE id = ?12")?;
-        Ok(())
+        with_busy_retry("persona_executions", || {
+            let conn = pool.get()?;
+            exec_status_update(&conn, id, &input, output_ref.as_deref(), "WHERE id = ?12")?;
+            Ok(())
+        })
     })
 }
 
@@ -761,20 +877,34 @@ pub fn update_status_if_running(
     mut input: UpdateExecutionStatus,
 ) -> Result<bool, AppError> {
     redact_execution_fields(&mut input);
-    timed_query!(
+    let output_ref = output_store::offload(pool, id, &mut input.output_data);
+    let updated = timed_query!(
         "persona_executions",
         "persona_executions::update_status_if_running",
         {
-            let conn = pool.get()?;
-            let rows_changed = exec_status_update(
-                &conn,
-                id,
-                &input,
-                "WHERE id = ?12 AND status = 'running'",
-            )?;
-            Ok(rows_changed > 0)
+            with_busy_retry("persona_executions", || {
+                let conn = pool.get()?;
+                let rows_changed = exec_status_update(
+                    &conn,
+                    id,
+                    &input,
+                    output_ref.as_deref(),
+                    "WHERE id = ?12 AND status = 'running'",
+                )?;
+                Ok(rows_changed > 0)
+            })
         }
-    )
+    );
+    discard_unwritten_output(&updated, output_ref.as_deref());
+    updated
+}
+
+/// Remove an offloaded output file whose CAS write lost (or failed), so the
+/// winning row's pointer can't be confused with a stray file.
+fn discard_unwritten_output(updated: &Result<bool, AppError>, output_ref: Option<&str>) {
+    if let (Some(pointer), false) = (output_ref, matches!(updated, Ok(true))) {
+        output_store::discard(pointer);
+    }
 }
 
 /// CAS-claim a queued execution for one instance (multi-driver orchestration,
@@ -844,10 +974,11 @@ pub fn update_status_if_not_final(
     mut input: UpdateExecutionStatus,
 ) -> Result<bool, AppError> {
     redact_execution_fields(&mut input);
-    timed_query!(
+    let output_ref = output_store::offload(pool, id, &mut input.output_data);
+    let updated = timed_query!(
         "persona_executions",
         "persona_executions::update_status_if_not_final",
-        {
+        with_busy_retry("persona_executions", || {
             let conn = pool.get()?;
             let status_str = input.status.as_str();
 
@@ -862,13 +993,135 @@ pub fn update_status_if_not_final(
             } else {
                 "WHERE id = ?12 AND status = 'running'"
             };
-            let rows_changed = exec_status_update(&conn, id, &input, where_clause)?;
+            let rows_changed =
+                exec_status_update(&conn, id, &input, output_ref.as_deref(), where_clause)?;
 
             Ok(rows_changed > 0)
+        })
+    );
+    discard_unwritten_output(&updated, output_ref.as_deref());
+    updated
+}
+
+// -- Cloud reconciliation ------------------------------------------
+
+/// Error written by startup recovery onto runs that were mid-flight.
+pub const RESTART_INTERRUPTED_ERROR: &str = "App restarted while execution was running";
+
+/// Error prefixes written by local recovery -- startup stale recovery, the
+/// zombie sweep, the cloud poller giving up -- rather than by the run itself.
+/// A cloud execution closed this way may still have finished on the
+/// orchestrator, so [`reconcile_cloud_result`] may overwrite it.
+const LOCAL_RECOVERY_ERRORS: &[&str] = &[
+    RESTART_INTERRUPTED_ERROR,
+    "Execution stalled:",
+    "Cloud orchestrator unreachable",
+    "Cloud execution timed out",
+];
+
+/// Rows whose stored outcome the orchestrator's result may replace: cloud
+/// runs still open locally, or closed only by local recovery.
+fn cloud_reconcilable_predicate() -> String {
+    let recovered = LOCAL_RECOVERY_ERRORS
+        .iter()
+        .map(|prefix| format!("error_message LIKE '{prefix}%'"))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    format!(
+        "cloud_execution_id IS NOT NULL
+         AND (status IN ('queued', 'running')
+              OR (status IN ('failed', 'incomplete') AND ({recovered})))"
+    )
+}
+
+/// Record the orchestrator id of a submitted cloud run and mark the row
+/// `running`. The id is what lets [`get_cloud_reconcilable`] find the run
+/// again after the app restarts.
+pub fn mark_cloud_submitted(
+    pool: &DbPool,
+    id: &str,
+    cloud_execution_id: &str,
+) -> Result<(), AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::mark_cloud_submitted",
+        {
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            conn.execute(
+                "UPDATE persona_executions SET
+                    cloud_execution_id = ?2,
+                    status = CASE WHEN status = 'queued' THEN 'running' ELSE status END,
+                    started_at = COALESCE(started_at, ?3)
+                 WHERE id = ?1",
+                params![id, cloud_execution_id, now],
+            )?;
+            Ok(())
+        }
+    )
+}
+
+/// Cloud executions whose local row may not hold the final result, oldest
+/// first.
+pub fn get_cloud_reconcilable(pool: &DbPool) -> Result<Vec<CloudExecutionLink>, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::get_cloud_reconcilable",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(&format!(
+                "SELECT id, persona_id, cloud_execution_id FROM persona_executions
+                 WHERE {} ORDER BY created_at ASC",
+                cloud_reconcilable_predicate()
+            ))?;
+            let rows = stmt.query_map([], |row| {
+                Ok(CloudExecutionLink {
+                    execution_id: row.get(0)?,
+                    persona_id: row.get(1)?,
+                    cloud_execution_id: row.get(2)?,
+                })
+            })?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)
         }
     )
 }
 
+/// Write an orchestrator-reported result onto a cloud execution. The
+/// orchestrator wins over local recovery but never over a final state the
+/// row reached on its own (user cancel, live poller result). A local
+/// recovery error is cleared when the result carries none. Returns whether
+/// the row changed.
+pub fn reconcile_cloud_result(
+    pool: &DbPool,
+    id: &str,
+    mut input: UpdateExecutionStatus,
+) -> Result<bool, AppError> {
+    redact_execution_fields(&mut input);
+    let output_ref = output_store::offload(pool, id, &mut input.output_data);
+    let updated = timed_query!(
+        "persona_executions",
+        "persona_executions::reconcile_cloud_result",
+        with_busy_retry("persona_executions", || {
+            let conn = pool.get()?;
+            let tx = conn.unchecked_transaction()?;
+            let where_clause = format!("WHERE id = ?12 AND {}", cloud_reconcilable_predicate());
+            let rows_changed =
+                exec_status_update(&tx, id, &input, output_ref.as_deref(), &where_clause)?;
+            if rows_changed > 0 && input.error_message.is_none() {
+                tx.execute(
+                    "UPDATE persona_executions SET error_message = NULL WHERE id = ?1",
+                    params![id],
+                )?;
+            }
+            tx.commit()?;
+            Ok(rows_changed > 0)
+        })
+    );
+    discard_unwritten_output(&updated, output_ref.as_deref());
+    updated
+}
+
 pub fn get_recent(pool: &DbPool, limit: Option<i64>) -> Result<Vec<PersonaExecution>, AppError> {
     timed_query!("persona_executions", "persona_executions::get_recent", {
         let limit = limit.unwrap_or(20);
@@ -1241,6 +1494,19 @@ pub fn count_for_persona_since(
     )
 }
 
+/// Failed executions (all personas) created at or after `since_rfc3339`.
+pub fn count_failed_since(pool: &DbPool, since_rfc3339: &str) -> Result<i64, AppError> {
+    timed_query!("persona_executions", "persona_executions::count_failed_since", {
+        let conn = pool.get()?;
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM persona_executions WHERE status = 'failed' AND created_at >= ?1",
+            params![since_rfc3339],
+            |row| row.get(0),
+        )?;
+        Ok(count)
+    })
+}
+
 /// Capability-scoped running-count: how many executions are queued/running for
 /// this exact (persona_id, use_case_id) pair. Used by the event-bus cascade
 /// guard so that a UC1→UC2 chain within the same persona isn't blocked by
@@ -1304,7 +1570,7 @@ pub fn touch_last_heartbeat(pool: &DbPool, execution_id: &str) -> Result<(), App
     timed_query!(
         "persona_executions",
         "persona_executions::touch_last_heartbeat",
-        {
+        with_busy_retry("persona_executions", || {
             let conn = pool.get()?;
             let now = chrono::Utc::now().to_rfc3339();
             let mut stmt = conn.prepare_cached(
@@ -1312,7 +1578,7 @@ pub fn touch_last_heartbeat(pool: &DbPool, execution_id: &str) -> Result<(), App
             )?;
             stmt.execute(params![now, execution_id])?;
             Ok(())
-        }
+        })
     )
 }
 
@@ -1369,13 +1635,16 @@ pub fn create_retry(
         // same input. Chain metadata (depth/visited/trace) is also embedded
         // in input_data, which lets the post-retry chain-trigger fix from
         // engine/mod.rs:spawn_delayed_retry read from the retry exec
-        // directly instead of falling back to the original.
+        // directly instead of falling back to the original. `trigger_id` is
+        // copied too so a retry's cost counts against the trigger that fired
+        // the original.
         let conn = pool.get()?;
         let mut stmt = conn.prepare_cached(
             "INSERT INTO persona_executions
-             (id, persona_id, status, input_tokens, output_tokens, cost_usd, retry_of_execution_id, retry_count, created_at, input_data)
+             (id, persona_id, status, input_tokens, output_tokens, cost_usd, retry_of_execution_id, retry_count, created_at, input_data, trigger_id)
              VALUES (?1, ?2, 'queued', 0, 0, 0, ?3, ?4, ?5,
-                     (SELECT input_data FROM persona_executions WHERE id = ?3))",
+                     (SELECT input_data FROM persona_executions WHERE id = ?3),
+                     (SELECT trigger_id FROM persona_executions WHERE id = ?3))",
         )?;
         stmt.execute(params![id, persona_id, original_exec_id, retry_count, now])?;
 
@@ -1603,6 +1872,103 @@ pub fn get_monthly_spend(pool: &DbPool, persona_id: &str) -> Result<f64, AppErro
     )
 }
 
+/// Monthly spend for every persona with any spend this month, keyed by persona
+/// id. Same `MONTHLY_SPEND_PREDICATE` as [`get_monthly_spend`], batched.
+pub fn get_monthly_spend_by_persona(
+    pool: &DbPool,
+) -> Result<std::collections::HashMap<String, f64>, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::get_monthly_spend_by_persona",
+        {
+            let conn = pool.get()?;
+            let sql = format!(
+                "SELECT persona_id, COALESCE(SUM(cost_usd), 0.0) FROM persona_executions
+             WHERE {}
+             GROUP BY persona_id",
+                MONTHLY_SPEND_PREDICATE
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, f64>(1)?))
+            })?;
+            rows.collect::<Result<std::collections::HashMap<_, _>, _>>()
+                .map_err(AppError::Database)
+        }
+    )
+}
+
+/// Month-to-date spend summed across every persona whose workspace
+/// (`home_team_id`) is `team_id`. Same `MONTHLY_SPEND_PREDICATE` as
+/// [`get_monthly_spend`], so a workspace total always equals the sum of its
+/// members' budget figures.
+pub fn get_monthly_spend_by_team(pool: &DbPool, team_id: &str) -> Result<f64, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::get_monthly_spend_by_team",
+        {
+            let conn = pool.get()?;
+            let sql = format!(
+                "SELECT COALESCE(SUM(cost_usd), 0.0) FROM persona_executions
+             WHERE persona_id IN (SELECT id FROM personas WHERE home_team_id = ?1)
+               AND {}",
+                MONTHLY_SPEND_PREDICATE
+            );
+            Ok(conn.query_row(&sql, params![team_id], |row| row.get::<_, f64>(0))?)
+        }
+    )
+}
+
+/// Recorded cost of one persona on one model on one UTC day.
+#[derive(Debug, Clone)]
+pub struct DailyPersonaCost {
+    /// `YYYY-MM-DD` (UTC).
+    pub day: String,
+    pub persona_id: String,
+    pub persona_name: String,
+    pub model_used: Option<String>,
+    pub cost_usd: f64,
+}
+
+/// Recorded execution cost per day, persona and model for `from..=to`
+/// (`YYYY-MM-DD`, UTC), for reconciliation against provider usage exports.
+/// Same status set as [`MONTHLY_SPEND_PREDICATE`], but ops-chat runs are
+/// included: the provider bills them like any other call.
+pub fn get_daily_cost_by_persona(
+    pool: &DbPool,
+    from: &str,
+    to: &str,
+) -> Result<Vec<DailyPersonaCost>, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::get_daily_cost_by_persona",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT substr(e.created_at, 1, 10) AS day, e.persona_id,
+                        COALESCE(p.name, e.persona_id), e.model_used,
+                        COALESCE(SUM(e.cost_usd), 0.0)
+                 FROM persona_executions e
+                 LEFT JOIN personas p ON p.id = e.persona_id
+                 WHERE e.status IN ('completed', 'failed', 'incomplete', 'cancelled')
+                   AND substr(e.created_at, 1, 10) BETWEEN ?1 AND ?2
+                 GROUP BY day, e.persona_id, e.model_used",
+            )?;
+            let rows = stmt.query_map(params![from, to], |row| {
+                Ok(DailyPersonaCost {
+                    day: row.get(0)?,
+                    persona_id: row.get(1)?,
+                    persona_name: row.get(2)?,
+                    model_used: row.get(3)?,
+                    cost_usd: row.get(4)?,
+                })
+            })?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)
+        }
+    )
+}
+
 /// Default zombie threshold for RUNNING executions: 30 minutes.
 const DEFAULT_ZOMBIE_THRESHOLD_SECS: i64 = 30 * 60;
 
@@ -1809,6 +2175,62 @@ pub fn cleanup_old_executions(
     )
 }
 
+/// Keep only the newest `max_per_persona` terminal executions of each persona,
+/// regardless of age. Queued/running rows are never touched. Returns the number
+/// of rows deleted; `max_per_persona == 0` is a no-op (uncapped).
+pub fn cap_terminal_per_persona(pool: &DbPool, max_per_persona: usize) -> Result<usize, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::cap_terminal_per_persona",
+        {
+            if max_per_persona == 0 {
+                return Ok(0);
+            }
+            let conn = pool.get()?;
+            let deleted = conn.execute(
+                "DELETE FROM persona_executions WHERE id IN (
+                    SELECT id FROM (
+                        SELECT id, ROW_NUMBER() OVER (
+                            PARTITION BY persona_id ORDER BY created_at DESC
+                        ) AS rn
+                        FROM persona_executions
+                        WHERE status IN ('completed', 'failed', 'incomplete', 'cancelled')
+                    ) WHERE rn > ?1
+                 )",
+                params![max_per_persona as i64],
+            )?;
+            Ok(deleted)
+        }
+    )
+}
+
+/// Every execution id currently stored. Used by retention to find log files
+/// whose execution row has been pruned.
+pub fn list_all_ids(pool: &DbPool) -> Result<std::collections::HashSet<String>, AppError> {
+    timed_query!("persona_executions", "persona_executions::list_all_ids", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare("SELECT id FROM persona_executions")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<Result<_, _>>().map_err(AppError::Database)
+    })
+}
+
+/// Ids of executions still queued or running.
+pub fn list_active_ids(pool: &DbPool) -> Result<std::collections::HashSet<String>, AppError> {
+    timed_query!(
+        "persona_executions",
+        "persona_executions::list_active_ids",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT id FROM persona_executions WHERE status IN ('queued', 'running')",
+            )?;
+            let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+            rows.collect::<Result<_, _>>().map_err(AppError::Database)
+        }
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1842,6 +2264,32 @@ mod tests {
         .id
     }
 
+    #[test]
+    fn test_cap_terminal_per_persona_keeps_newest_and_active() {
+        let pool = init_test_db().unwrap();
+        let persona_id = make_persona(&pool, "Cap Agent");
+        let mut ids = Vec::new();
+        for i in 0..4 {
+            let exec = create(&pool, &persona_id, None, None, None, None).unwrap();
+            pool.get()
+                .unwrap()
+                .execute(
+                    "UPDATE persona_executions SET status = 'completed', created_at = ?1 WHERE id = ?2",
+                    params![format!("2026-01-0{}T00:00:00Z", i + 1), exec.id],
+                )
+                .unwrap();
+            ids.push(exec.id);
+        }
+        let queued = create(&pool, &persona_id, None, None, None, None).unwrap();
+
+        assert_eq!(cap_terminal_per_persona(&pool, 0).unwrap(), 0);
+        assert_eq!(cap_terminal_per_persona(&pool, 2).unwrap(), 2);
+        let left = list_all_ids(&pool).unwrap();
+        assert!(!left.contains(&ids[0]) && !left.contains(&ids[1]));
+        assert!(left.contains(&ids[2]) && left.contains(&ids[3]));
+        assert!(left.contains(&queued.id));
+    }
+
     #[test]
     fn test_claim_for_instance_cas() {
         let pool = init_test_db().unwrap();
@@ -2129,4 +2577,85 @@ mod tests {
         let pool = init_test_db().unwrap();
         assert!(list_active_chains(&pool).unwrap().is_empty());
     }
+
+    #[test]
+    fn list_by_firing_groups_fan_out_with_rollup() {
+        let pool = init_test_db().unwrap();
+        let a = make_persona(&pool, "Fan-out A");
+        let b = make_persona(&pool, "Fan-out B");
+        let first = create(&pool, &a, None, None, None, None).unwrap();
+        let second = create(&pool, &b, None, None, None, None).unwrap();
+        let unrelated = create(&pool, &a, None, None, None, None).unwrap();
+        set_firing_id(&pool, &first.id, "evt-1").unwrap();
+        set_firing_id(&pool, &second.id, "evt-1").unwrap();
+        pool.get()
+            .unwrap()
+            .execute(
+                "UPDATE persona_executions SET status = 'completed', cost_usd = 0.25 WHERE id = ?1",
+                params![first.id],
+            )
+            .unwrap();
+
+        let rows = list_by_firing(&pool, "evt-1").unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|r| r.base.id != unrelated.id));
+
+        let rollup = crate::db::models::FiringRollup::from_executions("evt-1", &rows);
+        assert_eq!(rollup.status, "running");
+        assert_eq!((rollup.total, rollup.active, rollup.completed), (2, 1, 1));
+        assert!((rollup.cost_usd - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cloud_result_overrides_local_recovery_but_not_a_cancel() {
+        let pool = init_test_db().unwrap();
+        let persona_id = make_persona(&pool, "Cloud Agent");
+        let recovered = create(&pool, &persona_id, None, None, None, None).unwrap();
+        let cancelled = create(&pool, &persona_id, None, None, None, None).unwrap();
+        let local = create(&pool, &persona_id, None, None, None, None).unwrap();
+        mark_cloud_submitted(&pool, &recovered.id, "cloud-1").unwrap();
+        mark_cloud_submitted(&pool, &cancelled.id, "cloud-2").unwrap();
+        assert_eq!(get_by_id(&pool, &recovered.id).unwrap().status, "running");
+
+        // Startup recovery fails one; the user cancelled the other.
+        let close = |id: &str, status, error: Option<&str>| {
+            update_status(
+                &pool,
+                id,
+                UpdateExecutionStatus {
+                    status,
+                    error_message: error.map(Into::into),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+        close(
+            &recovered.id,
+            ExecutionState::Failed,
+            Some(RESTART_INTERRUPTED_ERROR),
+        );
+        close(&cancelled.id, ExecutionState::Cancelled, None);
+
+        let pending = get_cloud_reconcilable(&pool).unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].cloud_execution_id, "cloud-1");
+        assert!(pending.iter().all(|p| p.execution_id != local.id));
+
+        let result = UpdateExecutionStatus {
+            status: ExecutionState::Completed,
+            cost_usd: Some(0.5),
+            output_tokens: Some(42),
+            ..Default::default()
+        };
+        assert!(reconcile_cloud_result(&pool, &recovered.id, result.clone()).unwrap());
+        assert!(!reconcile_cloud_result(&pool, &cancelled.id, result).unwrap());
+
+        let row = get_by_id(&pool, &recovered.id).unwrap();
+        assert_eq!(row.status, "completed");
+        assert_eq!(row.error_message, None);
+        assert_eq!(row.output_tokens, 42);
+        assert_eq!(get_by_id(&pool, &cancelled.id).unwrap().status, "cancelled");
+        assert!(get_cloud_reconcilable(&pool).unwrap().is_empty());
+    }
 }
@@ -1,9 +1,14 @@
-use rusqlite::params;
+use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::db::models::{HealingAuditEntry, HealingKnowledge, PersonaHealingIssue};
+use crate::db::models::{
+    FleetHealingRecommendation, HealingAuditEntry, HealingKnowledge, HealingKnowledgeExport,
+    HealingKnowledgeImportResult, HealingKnowledgeRecord, HealingRootCause, Json,
+    PersonaHealingIssue, UpdateHealingKnowledgeInput,
+};
 use crate::db::query_builder::QueryBuilder;
+use crate::db::repos::utils::escape_like;
 use crate::db::DbPool;
 use crate::error::AppError;
 
@@ -69,7 +74,7 @@ row_mapper!(row_to_healing_issue -> PersonaHealingIssue {
     is_circuit_breaker [bool], severity, category,
     suggested_fix, auto_fixed [bool], status,
     created_at, resolved_at,
-    source [opt],
+    source [opt], root_cause [opt],
 });
 
 crud_get_by_id!(
@@ -382,6 +387,28 @@ pub fn update_status(pool: &DbPool, id: &str, status: &str) -> Result<(), AppErr
     })
 }
 
+/// Store a root-cause summary on an issue and let its suggested fix replace
+/// the generic one the issue was created with.
+pub fn set_root_cause(
+    pool: &DbPool,
+    id: &str,
+    root_cause: &HealingRootCause,
+) -> Result<PersonaHealingIssue, AppError> {
+    timed_query!("healing_events", "healing_events::set_root_cause", {
+        let conn = pool.get()?;
+        let rows = conn.execute(
+            "UPDATE persona_healing_issues
+             SET root_cause = ?1, suggested_fix = COALESCE(NULLIF(TRIM(?2), ''), suggested_fix)
+             WHERE id = ?3",
+            params![Json(root_cause), root_cause.suggested_fix, id],
+        )?;
+        if rows == 0 {
+            return Err(AppError::NotFound(format!("PersonaHealingIssue {id}")));
+        }
+        get_by_id(pool, id)
+    })
+}
+
 /// Mark a healing issue as pending auto-fix. Called at schedule time before the
 /// retry actually runs. The issue stays in `auto_fix_pending` until
 /// [`confirm_auto_fix`] (on success) or [`revert_auto_fix_pending`] (on failure).
@@ -621,10 +648,12 @@ fn row_to_knowledge(row: &rusqlite::Row) -> rusqlite::Result<HealingKnowledge> {
         occurrence_count: row.get::<_, Option<i64>>("occurrence_count")?.unwrap_or(1),
         last_seen_at: row.get("last_seen_at")?,
         created_at: row.get("created_at")?,
+        curated: row.get("curated")?,
     })
 }
 
-/// Upsert a knowledge entry: increment count if exists, create if not.
+/// Upsert a knowledge entry: increment count if exists, create if not. A
+/// curated entry only has its count and `last_seen_at` refreshed.
 pub fn upsert_knowledge(
     pool: &DbPool,
     service_type: &str,
@@ -641,8 +670,9 @@ pub fn upsert_knowledge(
             "UPDATE healing_knowledge SET
             occurrence_count = occurrence_count + 1,
             last_seen_at = ?1,
-            description = ?2,
-            recommended_delay_secs = COALESCE(?3, recommended_delay_secs)
+            description = CASE WHEN curated = 1 THEN description ELSE ?2 END,
+            recommended_delay_secs = CASE WHEN curated = 1 THEN recommended_delay_secs
+                                          ELSE COALESCE(?3, recommended_delay_secs) END
          WHERE service_type = ?4 AND pattern_key = ?5",
             params![
                 now,
@@ -714,6 +744,212 @@ pub fn get_all_knowledge(pool: &DbPool) -> Result<Vec<HealingKnowledge>, AppErro
     })
 }
 
+/// Get one knowledge entry by id.
+pub fn get_knowledge(pool: &DbPool, id: &str) -> Result<HealingKnowledge, AppError> {
+    timed_query!("healing_events", "healing_events::get_knowledge", {
+        let conn = pool.get()?;
+        conn.query_row(
+            "SELECT * FROM healing_knowledge WHERE id = ?1",
+            params![id],
+            row_to_knowledge,
+        )
+        .optional()?
+        .ok_or_else(|| AppError::NotFound(format!("Healing knowledge {id}")))
+    })
+}
+
+/// Knowledge entries whose service, pattern or description contains `query`
+/// (case-insensitive), optionally limited to one service.
+pub fn search_knowledge(
+    pool: &DbPool,
+    query: &str,
+    service_type: Option<&str>,
+    limit: i64,
+) -> Result<Vec<HealingKnowledge>, AppError> {
+    timed_query!("healing_events", "healing_events::search_knowledge", {
+        let conn = pool.get()?;
+        let pattern = format!("%{}%", escape_like(query.trim()));
+        let mut stmt = conn.prepare(
+            "SELECT * FROM healing_knowledge
+             WHERE (service_type LIKE ?1 ESCAPE '\\' OR pattern_key LIKE ?1 ESCAPE '\\'
+                    OR description LIKE ?1 ESCAPE '\\')
+               AND (?2 IS NULL OR service_type = ?2)
+             ORDER BY occurrence_count DESC, last_seen_at DESC
+             LIMIT ?3",
+        )?;
+        let rows = stmt.query_map(
+            params![pattern, service_type, limit.clamp(1, 500)],
+            row_to_knowledge,
+        )?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)
+    })
+}
+
+fn validate_knowledge_fields(
+    description: Option<&str>,
+    recommended_delay_secs: Option<i64>,
+) -> Result<(), AppError> {
+    if let Some(description) = description {
+        if description.trim().is_empty() {
+            return Err(AppError::Validation(
+                "Knowledge description cannot be empty".into(),
+            ));
+        }
+        crate::validation::require_max_len("description", description, 2_000)?;
+    }
+    let max = crate::engine::healing::MAX_BACKOFF_SECS as i64;
+    if let Some(delay) = recommended_delay_secs {
+        if !(0..=max).contains(&delay) {
+            return Err(AppError::Validation(format!(
+                "recommended_delay_secs must be between 0 and {max}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Correct a knowledge entry by hand. The entry becomes curated, so later
+/// learning no longer overwrites the correction.
+pub fn update_knowledge(
+    pool: &DbPool,
+    id: &str,
+    input: UpdateHealingKnowledgeInput,
+) -> Result<HealingKnowledge, AppError> {
+    validate_knowledge_fields(
+        input.description.as_deref(),
+        input.recommended_delay_secs.flatten(),
+    )?;
+    timed_query!("healing_events", "healing_events::update_knowledge", {
+        let conn = pool.get()?;
+        let updated = conn.execute(
+            "UPDATE healing_knowledge SET
+                description = COALESCE(?1, description),
+                recommended_delay_secs = CASE WHEN ?2 THEN ?3 ELSE recommended_delay_secs END,
+                curated = 1
+             WHERE id = ?4",
+            params![
+                input.description.as_deref().map(str::trim),
+                input.recommended_delay_secs.is_some(),
+                input.recommended_delay_secs.flatten(),
+                id
+            ],
+        )?;
+        if updated == 0 {
+            return Err(AppError::NotFound(format!("Healing knowledge {id}")));
+        }
+        Ok(())
+    })?;
+    get_knowledge(pool, id)
+}
+
+/// Delete a knowledge entry. Returns whether it existed.
+pub fn delete_knowledge(pool: &DbPool, id: &str) -> Result<bool, AppError> {
+    timed_query!("healing_events", "healing_events::delete_knowledge", {
+        let conn = pool.get()?;
+        let rows = conn.execute("DELETE FROM healing_knowledge WHERE id = ?1", params![id])?;
+        Ok(rows > 0)
+    })
+}
+
+/// Format version written by [`export_knowledge`].
+pub const KNOWLEDGE_EXPORT_VERSION: u32 = 1;
+/// Most entries one import may carry.
+const MAX_KNOWLEDGE_IMPORT: usize = 5_000;
+
+/// The knowledge base (or one service's slice of it) as a portable document.
+pub fn export_knowledge(
+    pool: &DbPool,
+    service_type: Option<&str>,
+) -> Result<HealingKnowledgeExport, AppError> {
+    let entries = match service_type {
+        Some(st) => get_knowledge_by_service(pool, st)?,
+        None => get_all_knowledge(pool)?,
+    };
+    Ok(HealingKnowledgeExport {
+        version: KNOWLEDGE_EXPORT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        entries: entries
+            .into_iter()
+            .map(|k| HealingKnowledgeRecord {
+                service_type: k.service_type,
+                pattern_key: k.pattern_key,
+                description: k.description,
+                recommended_delay_secs: k.recommended_delay_secs,
+                occurrence_count: Some(k.occurrence_count),
+            })
+            .collect(),
+    })
+}
+
+/// Merge `records` into the knowledge base in one transaction. An existing
+/// service + pattern takes the imported description and delay and keeps the
+/// higher occurrence count. Imported entries are curated.
+pub fn import_knowledge(
+    pool: &DbPool,
+    records: &[HealingKnowledgeRecord],
+) -> Result<HealingKnowledgeImportResult, AppError> {
+    crate::validation::require_max_count("entries", records, MAX_KNOWLEDGE_IMPORT)?;
+    for r in records {
+        if r.service_type.trim().is_empty() || r.pattern_key.trim().is_empty() {
+            return Err(AppError::Validation(
+                "Every entry needs a service_type and a pattern_key".into(),
+            ));
+        }
+        crate::validation::require_max_len("service_type", &r.service_type, 200)?;
+        crate::validation::require_max_len("pattern_key", &r.pattern_key, 200)?;
+        validate_knowledge_fields(Some(&r.description), r.recommended_delay_secs)?;
+    }
+
+    timed_query!("healing_events", "healing_events::import_knowledge", {
+        let mut conn = pool.get()?;
+        let tx = conn.transaction()?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut result = HealingKnowledgeImportResult {
+            created: 0,
+            updated: 0,
+        };
+        for r in records {
+            let (service_type, pattern_key) = (r.service_type.trim(), r.pattern_key.trim());
+            let exists = tx
+                .query_row(
+                    "SELECT 1 FROM healing_knowledge WHERE service_type = ?1 AND pattern_key = ?2",
+                    params![service_type, pattern_key],
+                    |_| Ok(()),
+                )
+                .optional()?
+                .is_some();
+            tx.execute(
+                "INSERT INTO healing_knowledge
+                 (id, service_type, pattern_key, description, recommended_delay_secs,
+                  occurrence_count, last_seen_at, created_at, curated)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7, 1)
+                 ON CONFLICT(service_type, pattern_key) DO UPDATE SET
+                    description = excluded.description,
+                    recommended_delay_secs = excluded.recommended_delay_secs,
+                    occurrence_count = MAX(occurrence_count, excluded.occurrence_count),
+                    curated = 1",
+                params![
+                    uuid::Uuid::new_v4().to_string(),
+                    service_type,
+                    pattern_key,
+                    r.description.trim(),
+                    r.recommended_delay_secs,
+                    r.occurrence_count.unwrap_or(1).max(1),
+                    now,
+                ],
+            )?;
+            if exists {
+                result.updated += 1;
+            } else {
+                result.created += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(result)
+    })
+}
+
 /// Look up a knowledge hint (recommended delay + occurrence count) for a
 /// specific service + pattern combination. Returns `None` if no entry exists.
 pub fn get_knowledge_hint(
@@ -733,17 +969,142 @@ pub fn get_knowledge_hint(
                 Ok((delay, count))
             },
         );
-        match result {
-            Ok((delay, count)) => Ok(Some(crate::engine::healing::KnowledgeHint {
-                recommended_delay_secs: delay.map(|d| d as u64),
+        let local = match result {
+            Ok(pair) => Some(pair),
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(e) => return Err(AppError::Database(e)),
+        };
+        // Local knowledge always wins. The fleet aggregate only fills in a
+        // delay this install hasn't learned yet, and never contributes to the
+        // occurrence count (which drives local preemptive escalation).
+        let fleet_delay = match local {
+            Some((Some(_), _)) => None,
+            _ => conn
+                .query_row(
+                    "SELECT recommended_delay_secs FROM fleet_healing_recommendations
+                     WHERE service_type = ?1 AND pattern_key = ?2",
+                    params![service_type, pattern_key],
+                    |row| row.get::<_, i64>(0),
+                )
+                .optional()?,
+        };
+        match (local, fleet_delay) {
+            (Some((delay, count)), fleet) => Ok(Some(crate::engine::healing::KnowledgeHint {
+                recommended_delay_secs: delay.or(fleet).map(|d| d as u64),
                 occurrence_count: count,
             })),
-            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(AppError::Database(e)),
+            (None, Some(fleet)) => Ok(Some(crate::engine::healing::KnowledgeHint {
+                recommended_delay_secs: Some(fleet as u64),
+                occurrence_count: 0,
+            })),
+            (None, None) => Ok(None),
         }
     })
 }
 
+// ============================================================================
+// Fleet Healing Recommendations
+// ============================================================================
+
+row_mapper!(row_to_fleet_recommendation -> FleetHealingRecommendation {
+    service_type, pattern_key, recommended_delay_secs, install_count, fetched_at,
+});
+
+/// Collect the local knowledge entries eligible for anonymous fleet sharing.
+///
+/// Only patterns on BUILT-IN connectors with a learned delay qualify: custom
+/// connector names are user-chosen and may identify the install, and the
+/// `description` column (which embeds raw error text) is never selected.
+pub fn list_shareable_knowledge(
+    pool: &DbPool,
+) -> Result<Vec<crate::engine::fleet_learning::SharedHealingPattern>, AppError> {
+    timed_query!("healing_events", "healing_events::list_shareable_knowledge", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT hk.service_type, hk.pattern_key, hk.recommended_delay_secs, hk.occurrence_count
+             FROM healing_knowledge hk
+             JOIN connector_definitions cd ON cd.name = hk.service_type AND cd.is_builtin = 1
+             WHERE hk.recommended_delay_secs IS NOT NULL
+             ORDER BY hk.service_type, hk.pattern_key",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(crate::engine::fleet_learning::SharedHealingPattern {
+                service_type: row.get(0)?,
+                pattern_key: row.get(1)?,
+                recommended_delay_secs: row.get(2)?,
+                occurrence_count: row.get::<_, Option<i64>>(3)?.unwrap_or(1),
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)
+    })
+}
+
+/// Replace the cached fleet recommendations with a freshly downloaded set.
+/// Runs in one transaction so a failed download never leaves a half-empty cache.
+pub fn replace_fleet_recommendations(
+    pool: &DbPool,
+    recommendations: &[FleetHealingRecommendation],
+) -> Result<usize, AppError> {
+    timed_query!(
+        "healing_events",
+        "healing_events::replace_fleet_recommendations",
+        {
+            let mut conn = pool.get()?;
+            let tx = conn.transaction()?;
+            tx.execute("DELETE FROM fleet_healing_recommendations", [])?;
+            for rec in recommendations {
+                tx.execute(
+                    "INSERT OR REPLACE INTO fleet_healing_recommendations
+                     (service_type, pattern_key, recommended_delay_secs, install_count, fetched_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        rec.service_type,
+                        rec.pattern_key,
+                        rec.recommended_delay_secs,
+                        rec.install_count,
+                        rec.fetched_at
+                    ],
+                )?;
+            }
+            tx.commit()?;
+            Ok(recommendations.len())
+        }
+    )
+}
+
+/// List cached fleet recommendations, most widely observed first.
+pub fn list_fleet_recommendations(
+    pool: &DbPool,
+) -> Result<Vec<FleetHealingRecommendation>, AppError> {
+    timed_query!(
+        "healing_events",
+        "healing_events::list_fleet_recommendations",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT * FROM fleet_healing_recommendations
+                 ORDER BY install_count DESC, service_type, pattern_key",
+            )?;
+            let rows = stmt.query_map([], row_to_fleet_recommendation)?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)
+        }
+    )
+}
+
+/// Drop every cached fleet recommendation (used when the user opts out).
+pub fn clear_fleet_recommendations(pool: &DbPool) -> Result<usize, AppError> {
+    timed_query!(
+        "healing_events",
+        "healing_events::clear_fleet_recommendations",
+        {
+            let conn = pool.get()?;
+            Ok(conn.execute("DELETE FROM fleet_healing_recommendations", [])?)
+        }
+    )
+}
+
 // ============================================================================
 // Healing Audit Log
 // ============================================================================
@@ -1029,6 +1390,27 @@ mod tests {
         let resolved_list = get_all(&pool, None, Some("resolved")).unwrap();
         assert_eq!(resolved_list.len(), 0);
 
+        // Root cause is stored and its fix replaces the generic one
+        assert!(issue2.root_cause.is_none());
+        let cause = HealingRootCause {
+            summary: "The OpenAI call was rejected.".into(),
+            cause: "No OpenAI credential is bound to the agent.".into(),
+            evidence: vec!["401 Unauthorized".into()],
+            suggested_fix: "Add an OpenAI credential in the vault.".into(),
+            confidence: "high".into(),
+            generated_at: "2026-01-01T00:00:00Z".into(),
+        };
+        let analysed = set_root_cause(&pool, &issue2.id, &cause).unwrap();
+        assert_eq!(
+            analysed.suggested_fix.as_deref(),
+            Some("Add an OpenAI credential in the vault.")
+        );
+        assert_eq!(
+            analysed.root_cause.unwrap().evidence,
+            vec!["401 Unauthorized"]
+        );
+        assert!(set_root_cause(&pool, "missing", &cause).is_err());
+
         // Delete
         let deleted = delete(&pool, &issue1.id).unwrap();
         assert!(deleted);
@@ -1606,4 +1988,93 @@ mod tests {
         let limited = get_for_health(&pool, 7, 1).unwrap();
         assert_eq!(limited.len(), 1);
     }
+
+    #[test]
+    fn knowledge_hint_falls_back_to_fleet_delay_only_when_local_has_none() {
+        let pool = init_test_db().unwrap();
+        let fleet = |service: &str, delay: i64| FleetHealingRecommendation {
+            service_type: service.into(),
+            pattern_key: "rate_limit".into(),
+            recommended_delay_secs: delay,
+            install_count: 12,
+            fetched_at: chrono::Utc::now().to_rfc3339(),
+        };
+        replace_fleet_recommendations(&pool, &[fleet("slack", 90), fleet("gmail", 45)]).unwrap();
+
+        // No local knowledge → fleet delay, zero local occurrences.
+        let hint = get_knowledge_hint(&pool, "slack", "rate_limit").unwrap().unwrap();
+        assert_eq!(hint.recommended_delay_secs, Some(90));
+        assert_eq!(hint.occurrence_count, 0);
+
+        // Local delay wins over the fleet aggregate.
+        upsert_knowledge(&pool, "gmail", "rate_limit", "desc", Some(20)).unwrap();
+        let hint = get_knowledge_hint(&pool, "gmail", "rate_limit").unwrap().unwrap();
+        assert_eq!(hint.recommended_delay_secs, Some(20));
+        assert_eq!(hint.occurrence_count, 1);
+
+        // Nothing anywhere → no hint.
+        assert!(get_knowledge_hint(&pool, "notion", "rate_limit").unwrap().is_none());
+
+        // Replacing the cache is wholesale, not additive.
+        replace_fleet_recommendations(&pool, &[fleet("gmail", 30)]).unwrap();
+        assert!(get_knowledge_hint(&pool, "slack", "rate_limit").unwrap().is_none());
+        assert_eq!(list_fleet_recommendations(&pool).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn curated_knowledge_survives_learning_and_round_trips() {
+        let pool = init_test_db().unwrap();
+        let learned = upsert_knowledge(&pool, "gmail", "rate_limit", "learned", Some(240)).unwrap();
+        assert!(!learned.curated);
+
+        let fixed = update_knowledge(
+            &pool,
+            &learned.id,
+            UpdateHealingKnowledgeInput {
+                description: None,
+                recommended_delay_secs: Some(Some(45)),
+            },
+        )
+        .unwrap();
+        assert!(fixed.curated);
+        assert_eq!(fixed.recommended_delay_secs, Some(45));
+
+        // Learning bumps the count but keeps the correction.
+        let relearned = upsert_knowledge(&pool, "gmail", "rate_limit", "again", Some(240)).unwrap();
+        assert_eq!(relearned.occurrence_count, 2);
+        assert_eq!(relearned.recommended_delay_secs, Some(45));
+        assert_eq!(relearned.description, "learned");
+
+        assert!(update_knowledge(
+            &pool,
+            &learned.id,
+            UpdateHealingKnowledgeInput {
+                description: None,
+                recommended_delay_secs: Some(Some(100_000)),
+            },
+        )
+        .is_err());
+        assert_eq!(search_knowledge(&pool, "GMAIL", None, 10).unwrap().len(), 1);
+        assert!(search_knowledge(&pool, "%", None, 10).unwrap().is_empty());
+
+        let export = export_knowledge(&pool, None).unwrap();
+        assert!(delete_knowledge(&pool, &learned.id).unwrap());
+        let mut records = export.entries;
+        records.push(HealingKnowledgeRecord {
+            service_type: "slack".into(),
+            pattern_key: "timeout".into(),
+            description: "Slack exports are slow".into(),
+            recommended_delay_secs: None,
+            occurrence_count: None,
+        });
+        let result = import_knowledge(&pool, &records).unwrap();
+        assert_eq!((result.created, result.updated), (2, 0));
+        let result = import_knowledge(&pool, &records).unwrap();
+        assert_eq!((result.created, result.updated), (0, 2));
+
+        let gmail = get_knowledge_by_service(&pool, "gmail").unwrap();
+        assert_eq!(gmail[0].recommended_delay_secs, Some(45));
+        assert_eq!(gmail[0].occurrence_count, 2);
+        assert!(gmail[0].curated);
+    }
 }
@@ -536,3 +536,31 @@ pub fn get_summary(
         })
     })
 }
+
+/// Historical `cost_quality` baselines for one persona: `(pattern_key,
+/// avg_cost_usd, sample_count)` per row. `pattern_key` is `use_case:<id>` for
+/// capability-scoped runs and `overall` for persona-wide runs (see
+/// `engine::knowledge::extract_cost_quality`). `avg_cost_usd` is the EMA the
+/// upsert maintains, so it tracks recent spend rather than lifetime spend.
+pub fn get_cost_baselines(
+    pool: &DbPool,
+    persona_id: &str,
+) -> Result<Vec<(String, f64, i64)>, AppError> {
+    timed_query!("knowledge_entries", "knowledge_entries::get_cost_baselines", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT pattern_key, avg_cost_usd, success_count + failure_count
+             FROM execution_knowledge
+             WHERE persona_id = ?1 AND knowledge_type = 'cost_quality'",
+        )?;
+        let rows = stmt.query_map(params![persona_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<f64>>(1)?.unwrap_or(0.0),
+                row.get::<_, Option<i64>>(2)?.unwrap_or(0),
+            ))
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)
+    })
+}
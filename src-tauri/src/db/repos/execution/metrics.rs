@@ -1,16 +1,17 @@
 use rusqlite::{params, Connection, Row};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 use tracing::{info, instrument, warn};
 
 use crate::db::models::{
-    AnomalyDrilldownData, CorrelatedEvent, DashboardCostAnomaly, DashboardDailyPoint,
-    DashboardTopPersona, ErrorCategoryBreakdown, ErrorCategoryCount, ExecutionDashboardData,
-    ExecutionHeatmapData, HeatmapDay, HeatmapInsights, MetricAnomaly, MetricsChartData,
-    MetricsChartPoint, MetricsPersonaBreakdown, ModelValueShare, PersonaCostEntry,
-    PersonaPromptVersion, PersonaTopErrorCategory, PromptPerformanceData, PromptPerformancePoint,
-    RootCauseSuggestion, ValueRollup, VersionMarker,
+    AnomalyDrilldownData, CorrelatedEvent, CostAnomaly, CostBreakdown, CostDayPoint, CostGroup,
+    CostGroupBy, DashboardCostAnomaly, DashboardDailyPoint, DashboardTopPersona,
+    ErrorCategoryBreakdown, ErrorCategoryCount, ExecutionDashboardData, ExecutionHeatmapData,
+    FailureHeatmapCell, FailureHeatmapData, FailureHeatmapService, HeatmapDay, HeatmapInsights,
+    MetricAnomaly, MetricsChartData, MetricsChartPoint, MetricsPersonaBreakdown, ModelValueShare,
+    PersonaCostEntry, PersonaPromptVersion, PersonaTopErrorCategory, PromptPerformanceData,
+    PromptPerformancePoint, RootCauseSuggestion, TriggerRoi, ValueRollup, VersionMarker,
 };
 use crate::db::query_builder::QueryBuilder;
 use crate::db::DbPool;
@@ -566,6 +567,494 @@ pub fn get_value_rollup_with_conn(
     })
 }
 
+// ============================================================================
+// Trigger ROI (cost + outcome per originating trigger)
+// ============================================================================
+
+/// Per-trigger cost and outcome for one persona over a window, most expensive
+/// first. Every trigger of the persona gets a row (zero firings included, so a
+/// trigger that never fires is visible too), plus an `untriggered` row for
+/// runs no trigger started when there are any. Excludes simulations.
+pub fn get_trigger_roi(
+    pool: &DbPool,
+    persona_id: &str,
+    days: Option<i64>,
+) -> Result<Vec<TriggerRoi>, AppError> {
+    timed_query!("execution_metrics", "execution_metrics::get_trigger_roi", {
+        let days = days.unwrap_or(30).clamp(1, 365);
+        let date_expr = format!("-{days} days");
+        let conn = pool.get()?;
+        // The execution filters live in the JOIN so triggers without runs in
+        // the window survive the LEFT JOIN with zero counts. Auto-created
+        // listeners are skipped: runs are attributed to the source trigger
+        // that fired, never to its paired listener.
+        let mut stmt = conn.prepare(
+            "SELECT
+                t.id AS trigger_id,
+                t.trigger_type,
+                t.enabled,
+                COUNT(e.id) AS firings,
+                COALESCE(SUM(CASE WHEN e.status = 'completed' THEN 1 ELSE 0 END), 0) AS succeeded,
+                COALESCE(SUM(CASE WHEN e.status IN ('failed', 'incomplete')
+                                  THEN 1 ELSE 0 END), 0) AS failed,
+                COALESCE(SUM(CASE WHEN e.status = 'cancelled' THEN 1 ELSE 0 END), 0) AS cancelled,
+                COALESCE(SUM(CASE WHEN e.business_outcome = 'value_delivered'
+                                  THEN 1 ELSE 0 END), 0) AS vd,
+                COALESCE(SUM(e.cost_usd), 0.0) AS cost,
+                CAST(AVG(e.duration_ms) AS INTEGER) AS avg_duration_ms,
+                MAX(e.created_at) AS last_fired_at
+             FROM persona_triggers t
+             LEFT JOIN persona_executions e
+               ON e.trigger_id = t.id
+              AND e.created_at >= datetime('now', ?2)
+              AND COALESCE(e.is_simulation, 0) = 0
+             WHERE t.persona_id = ?1
+               AND CASE WHEN json_valid(t.config)
+                        THEN json_extract(t.config, '$._auto_for_trigger') END IS NULL
+             GROUP BY t.id
+             UNION ALL
+             SELECT
+                NULL,
+                'untriggered',
+                1,
+                COUNT(*),
+                COALESCE(SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN status IN ('failed', 'incomplete') THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN status = 'cancelled' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(CASE WHEN business_outcome = 'value_delivered' THEN 1 ELSE 0 END), 0),
+                COALESCE(SUM(cost_usd), 0.0),
+                CAST(AVG(duration_ms) AS INTEGER),
+                MAX(created_at)
+             FROM persona_executions
+             WHERE persona_id = ?1
+               AND trigger_id IS NULL
+               AND created_at >= datetime('now', ?2)
+               AND COALESCE(is_simulation, 0) = 0",
+        )?;
+        let rows = stmt.query_map(params![persona_id, date_expr], |row| {
+            let firings: i64 = row.get(3)?;
+            let succeeded: i64 = row.get(4)?;
+            let failed: i64 = row.get(5)?;
+            let cancelled: i64 = row.get(6)?;
+            let cost: f64 = row.get(8)?;
+            let finished = succeeded + failed + cancelled;
+            Ok(TriggerRoi {
+                trigger_id: row.get(0)?,
+                trigger_type: row.get(1)?,
+                enabled: row.get::<_, i64>(2)? != 0,
+                firings,
+                succeeded,
+                failed,
+                value_delivered: row.get(7)?,
+                success_rate: if finished > 0 {
+                    succeeded as f64 / finished as f64
+                } else {
+                    0.0
+                },
+                total_cost_usd: cost,
+                cost_per_firing: if firings > 0 {
+                    cost / firings as f64
+                } else {
+                    0.0
+                },
+                cost_per_success: (succeeded > 0).then(|| cost / succeeded as f64),
+                avg_duration_ms: row.get(9)?,
+                last_fired_at: row.get(10)?,
+            })
+        })?;
+        let mut roi = rows
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)?;
+        roi.retain(|r| r.trigger_id.is_some() || r.firings > 0);
+        roi.sort_by(|a, b| b.total_cost_usd.total_cmp(&a.total_cost_usd));
+        Ok(roi)
+    })
+}
+
+// ============================================================================
+// Cost analytics (spend by dimension and day, daily spend anomalies)
+// ============================================================================
+
+/// Group key for runs with nothing to group by (no team, model, use case or
+/// connector).
+const NO_GROUP_KEY: &str = "none";
+/// Days before a candidate day that its spend is compared against.
+const COST_ANOMALY_TRAILING_DAYS: usize = 14;
+/// Fewer trailing days of history than this and a day is not judged.
+const COST_ANOMALY_MIN_HISTORY: usize = 7;
+/// Deviation from the trailing mean, in standard deviations, that flags a day.
+const COST_ANOMALY_SIGMA: f64 = 2.0;
+/// Floor for the trailing standard deviation, so a perfectly flat history
+/// doesn't make a one-cent change infinitely anomalous.
+const COST_ANOMALY_MIN_STD_DEV: f64 = 0.01;
+
+/// One run's cost attributed to one group. A run split across connectors
+/// yields a slice per connector.
+struct CostSlice {
+    execution_id: String,
+    date: String,
+    key: String,
+    label: String,
+    cost_usd: f64,
+}
+
+/// Spend over the last `days` calendar days (UTC, today included; default 30)
+/// grouped by `group_by`, with a zero-filled daily series overall and per
+/// group. Connector costs are split across the credentialed connectors a run
+/// used in proportion to their tool invocations; runs that used none land in
+/// the `none` group. Excludes simulations.
+#[instrument(skip(pool), fields(days))]
+pub fn get_cost_breakdown(
+    pool: &DbPool,
+    days: Option<i64>,
+    group_by: CostGroupBy,
+) -> Result<CostBreakdown, AppError> {
+    timed_query!(
+        "execution_metrics",
+        "execution_metrics::get_cost_breakdown",
+        {
+            let days = days.unwrap_or(30).clamp(1, 365);
+            let dates = cost_window_dates(chrono::Utc::now().date_naive(), days as usize);
+            let since = format!("-{} days", days - 1);
+            let conn = pool.get()?;
+            // Use-case titles live in the persona's design context; the CASE keeps
+            // json_each away from personas whose context isn't valid JSON.
+            let mut stmt = conn.prepare(
+                "SELECT e.id,
+                        DATE(e.created_at) AS date,
+                        COALESCE(e.cost_usd, 0.0) AS cost,
+                        e.persona_id,
+                        COALESCE(p.name, e.persona_id) AS persona_name,
+                        p.home_team_id,
+                        t.name AS team_name,
+                        e.model_used,
+                        e.use_case_id,
+                        CASE WHEN e.use_case_id IS NOT NULL AND json_valid(p.design_context)
+                             THEN (SELECT json_extract(uc.value, '$.title')
+                                   FROM json_each(p.design_context, '$.useCases') uc
+                                   WHERE json_extract(uc.value, '$.id') = e.use_case_id)
+                        END AS use_case_title
+                 FROM persona_executions e
+                 LEFT JOIN personas p ON p.id = e.persona_id
+                 LEFT JOIN persona_teams t ON t.id = p.home_team_id
+                 WHERE e.created_at >= DATE('now', ?1)
+                   AND COALESCE(e.is_simulation, 0) = 0",
+            )?;
+            let rows = stmt.query_map(params![since], |row| {
+                let id: String = row.get("id")?;
+                let date: String = row.get("date")?;
+                let cost_usd: f64 = row.get("cost")?;
+                let (key, label): (Option<String>, Option<String>) = match group_by {
+                    CostGroupBy::Persona => (row.get("persona_id")?, row.get("persona_name")?),
+                    // Re-keyed per connector below.
+                    CostGroupBy::Connector => (None, None),
+                    CostGroupBy::Group => (row.get("home_team_id")?, row.get("team_name")?),
+                    CostGroupBy::Model => (row.get("model_used")?, None),
+                    CostGroupBy::UseCase => (row.get("use_case_id")?, row.get("use_case_title")?),
+                };
+                let key = key
+                    .filter(|k| !k.is_empty())
+                    .unwrap_or_else(|| NO_GROUP_KEY.to_string());
+                Ok(CostSlice {
+                    label: label.unwrap_or_else(|| key.clone()),
+                    key,
+                    execution_id: id,
+                    date,
+                    cost_usd,
+                })
+            })?;
+            let mut slices: Vec<CostSlice> = rows
+                .filter_map(|r| match r {
+                    Ok(v) => Some(v),
+                    Err(e) => {
+                        warn!("get_cost_breakdown: row deserialization failed: {e}");
+                        None
+                    }
+                })
+                .collect();
+
+            if group_by == CostGroupBy::Connector {
+                let mut stmt = conn.prepare(
+                    "SELECT u.execution_id, d.requires_credential_type, SUM(u.invocation_count)
+                     FROM persona_tool_usage u
+                     JOIN persona_executions e ON e.id = u.execution_id
+                     JOIN persona_tool_definitions d ON d.name = u.tool_name
+                     WHERE e.created_at >= DATE('now', ?1)
+                       AND COALESCE(e.is_simulation, 0) = 0
+                       AND COALESCE(d.requires_credential_type, '') != ''
+                     GROUP BY u.execution_id, d.requires_credential_type",
+                )?;
+                let mut usage: HashMap<String, Vec<(String, i64)>> = HashMap::new();
+                let rows = stmt.query_map(params![since], |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, i64>(2)?,
+                    ))
+                })?;
+                for (execution_id, connector, invocations) in rows.flatten() {
+                    usage
+                        .entry(execution_id)
+                        .or_default()
+                        .push((connector, invocations));
+                }
+                slices = slices
+                    .into_iter()
+                    .flat_map(|s| {
+                        let split = split_cost_by_connector(
+                            s.cost_usd,
+                            usage.get(&s.execution_id).map_or(&[][..], Vec::as_slice),
+                        );
+                        split
+                            .into_iter()
+                            .map(move |(connector, cost_usd)| CostSlice {
+                                execution_id: s.execution_id.clone(),
+                                date: s.date.clone(),
+                                label: connector.clone(),
+                                key: connector,
+                                cost_usd,
+                            })
+                    })
+                    .collect();
+            }
+
+            Ok(aggregate_cost_breakdown(&slices, &dates, group_by))
+        }
+    )
+}
+
+/// Days over the last `days` days whose total spend deviates more than two
+/// standard deviations (either way) from the mean of the up-to-14 days before
+/// them, oldest first. Days with under a week of spend history before them
+/// are not judged. Excludes simulations.
+#[instrument(skip(pool), fields(days, persona_id))]
+pub fn get_cost_anomalies(
+    pool: &DbPool,
+    days: Option<i64>,
+    persona_id: Option<&str>,
+) -> Result<Vec<CostAnomaly>, AppError> {
+    timed_query!(
+        "execution_metrics",
+        "execution_metrics::get_cost_anomalies",
+        {
+            let days = days.unwrap_or(30).clamp(1, 365) as usize;
+            // Fetch the trailing window of the oldest judged day as well.
+            let span = days + COST_ANOMALY_TRAILING_DAYS;
+            let dates = cost_window_dates(chrono::Utc::now().date_naive(), span);
+            let since = format!("-{} days", span - 1);
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT DATE(e.created_at) AS date,
+                        e.persona_id,
+                        COALESCE(p.name, e.persona_id) AS persona_name,
+                        SUM(COALESCE(e.cost_usd, 0.0)) AS cost
+                 FROM persona_executions e
+                 LEFT JOIN personas p ON p.id = e.persona_id
+                 WHERE e.created_at >= DATE('now', ?1)
+                   AND COALESCE(e.is_simulation, 0) = 0
+                   AND (?2 IS NULL OR e.persona_id = ?2)
+                 GROUP BY DATE(e.created_at), e.persona_id",
+            )?;
+            let rows = stmt.query_map(params![since, persona_id], |row| {
+                Ok((
+                    row.get::<_, String>("date")?,
+                    row.get::<_, String>("persona_id")?,
+                    row.get::<_, String>("persona_name")?,
+                    row.get::<_, f64>("cost")?,
+                ))
+            })?;
+            let persona_days: Vec<(String, String, String, f64)> = rows
+                .filter_map(|r| match r {
+                    Ok(v) => Some(v),
+                    Err(e) => {
+                        warn!("get_cost_anomalies: row deserialization failed: {e}");
+                        None
+                    }
+                })
+                .collect();
+            Ok(detect_cost_anomalies(&dates, &persona_days, days))
+        }
+    )
+}
+
+/// The `days` dates (YYYY-MM-DD) ending with `today`, oldest first.
+fn cost_window_dates(today: chrono::NaiveDate, days: usize) -> Vec<String> {
+    (0..days)
+        .rev()
+        .map(|back| {
+            (today - chrono::Duration::days(back as i64))
+                .format("%Y-%m-%d")
+                .to_string()
+        })
+        .collect()
+}
+
+/// Split a run's cost across connectors by invocation count. A run that used
+/// no credentialed connector goes to [`NO_GROUP_KEY`] whole.
+fn split_cost_by_connector(cost_usd: f64, usage: &[(String, i64)]) -> Vec<(String, f64)> {
+    let invocations: i64 = usage.iter().map(|(_, n)| (*n).max(0)).sum();
+    if invocations == 0 {
+        return vec![(NO_GROUP_KEY.to_string(), cost_usd)];
+    }
+    usage
+        .iter()
+        .filter(|(_, n)| *n > 0)
+        .map(|(connector, n)| (connector.clone(), cost_usd * *n as f64 / invocations as f64))
+        .collect()
+}
+
+/// Fold cost slices into per-group and overall daily series over `dates`.
+/// Slices dated outside `dates` still count toward the totals.
+fn aggregate_cost_breakdown(
+    slices: &[CostSlice],
+    dates: &[String],
+    group_by: CostGroupBy,
+) -> CostBreakdown {
+    let day_index: HashMap<&str, usize> = dates
+        .iter()
+        .enumerate()
+        .map(|(i, d)| (d.as_str(), i))
+        .collect();
+    let empty_series = || -> Vec<CostDayPoint> {
+        dates
+            .iter()
+            .map(|date| CostDayPoint {
+                date: date.clone(),
+                cost_usd: 0.0,
+                executions: 0,
+            })
+            .collect()
+    };
+
+    struct GroupAcc<'a> {
+        label: &'a str,
+        cost_usd: f64,
+        executions: HashSet<&'a str>,
+        daily: Vec<CostDayPoint>,
+    }
+    let mut groups: HashMap<&str, GroupAcc> = HashMap::new();
+    let mut daily = empty_series();
+    let mut counted: HashSet<&str> = HashSet::new();
+    let mut total_cost_usd = 0.0;
+
+    for slice in slices {
+        let day = day_index.get(slice.date.as_str()).copied();
+        let first_for_run = counted.insert(&slice.execution_id);
+        total_cost_usd += slice.cost_usd;
+        if let Some(i) = day {
+            daily[i].cost_usd += slice.cost_usd;
+            if first_for_run {
+                daily[i].executions += 1;
+            }
+        }
+
+        let group = groups.entry(&slice.key).or_insert_with(|| GroupAcc {
+            label: &slice.label,
+            cost_usd: 0.0,
+            executions: HashSet::new(),
+            daily: empty_series(),
+        });
+        group.cost_usd += slice.cost_usd;
+        let first_for_group = group.executions.insert(&slice.execution_id);
+        if let Some(i) = day {
+            group.daily[i].cost_usd += slice.cost_usd;
+            if first_for_group {
+                group.daily[i].executions += 1;
+            }
+        }
+    }
+
+    let mut groups: Vec<CostGroup> = groups
+        .into_iter()
+        .map(|(key, g)| CostGroup {
+            key: key.to_string(),
+            label: g.label.to_string(),
+            cost_usd: g.cost_usd,
+            executions: g.executions.len() as i64,
+            share: if total_cost_usd > 0.0 {
+                g.cost_usd / total_cost_usd
+            } else {
+                0.0
+            },
+            daily: g.daily,
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.cost_usd
+            .total_cmp(&a.cost_usd)
+            .then_with(|| a.key.cmp(&b.key))
+    });
+
+    CostBreakdown {
+        period_days: dates.len() as i64,
+        group_by,
+        total_cost_usd,
+        total_executions: counted.len() as i64,
+        daily,
+        groups,
+    }
+}
+
+/// Flag days among the last `judged_days` of `dates` whose spend (summed
+/// from `(date, persona_id, persona_name, cost)` rows) deviates more than
+/// [`COST_ANOMALY_SIGMA`] from the trailing window. The trailing window never
+/// reaches back past the first day with any spend, so a fresh install's
+/// empty history doesn't make its first busy day an outlier.
+fn detect_cost_anomalies(
+    dates: &[String],
+    persona_days: &[(String, String, String, f64)],
+    judged_days: usize,
+) -> Vec<CostAnomaly> {
+    let day_index: HashMap<&str, usize> = dates
+        .iter()
+        .enumerate()
+        .map(|(i, d)| (d.as_str(), i))
+        .collect();
+    let mut totals = vec![0.0_f64; dates.len()];
+    // Per day: (persona_id, persona_name, cost) of the top spender.
+    let mut top: Vec<Option<(&str, &str, f64)>> = vec![None; dates.len()];
+    for (date, persona_id, persona_name, cost) in persona_days {
+        let Some(&i) = day_index.get(date.as_str()) else {
+            continue;
+        };
+        totals[i] += cost;
+        if *cost > 0.0 && !top[i].is_some_and(|(_, _, c)| c >= *cost) {
+            top[i] = Some((persona_id, persona_name, *cost));
+        }
+    }
+
+    let Some(first_spend) = totals.iter().position(|c| *c > 0.0) else {
+        return Vec::new();
+    };
+    let mut anomalies = Vec::new();
+    for i in dates.len().saturating_sub(judged_days)..dates.len() {
+        let start = i
+            .saturating_sub(COST_ANOMALY_TRAILING_DAYS)
+            .max(first_spend);
+        if i < start + COST_ANOMALY_MIN_HISTORY {
+            continue;
+        }
+        let trailing = &totals[start..i];
+        let n = trailing.len() as f64;
+        let mean = trailing.iter().sum::<f64>() / n;
+        let std_dev = (trailing.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / n).sqrt();
+        let deviation_sigma = (totals[i] - mean) / std_dev.max(COST_ANOMALY_MIN_STD_DEV);
+        if deviation_sigma.abs() > COST_ANOMALY_SIGMA {
+            anomalies.push(CostAnomaly {
+                date: dates[i].clone(),
+                cost_usd: totals[i],
+                trailing_mean_usd: mean,
+                trailing_std_dev_usd: std_dev,
+                deviation_sigma,
+                top_persona_id: top[i].map(|(id, _, _)| id.to_string()),
+                top_persona_name: top[i].map(|(_, name, _)| name.to_string()),
+                top_persona_cost_usd: top[i].map_or(0.0, |(_, _, c)| c),
+            });
+        }
+    }
+    anomalies
+}
+
 // ============================================================================
 // Category-aware error analytics (error_taxonomy aggregation)
 // ============================================================================
@@ -750,6 +1239,20 @@ pub fn get_chart_data_with_conn(
     days: i64,
     persona_id: Option<&str>,
 ) -> Result<MetricsChartData, AppError> {
+    // Long windows read the daily snapshots once they cover every day.
+    if days >= super::metrics_rollups::SNAPSHOT_CHART_MIN_DAYS {
+        if let Some((chart_points, persona_breakdown)) =
+            super::metrics_rollups::chart_from_snapshots(conn, days, persona_id)?
+        {
+            let anomalies = detect_chart_anomalies(&chart_points);
+            return Ok(MetricsChartData {
+                chart_points,
+                persona_breakdown,
+                anomalies,
+            });
+        }
+    }
+
     let qb = persona_filter_qb(format!("-{days} days"), persona_id);
     let pid_clause = if qb.has_conditions() {
         format!(" AND {}", qb.where_clause().trim_start_matches("WHERE "))
@@ -2284,6 +2787,194 @@ fn quartile_thresholds(sorted_counts: &[i64]) -> [i64; 4] {
     [q1, q2, q3, q4]
 }
 
+// ============================================================================
+// Failure Heatmap (hour of day × connector)
+// ============================================================================
+
+/// Service bucket for AI-provider failures (session limits, missing CLI).
+const PROVIDER_SERVICE: &str = "provider";
+/// Service bucket for failures that name no known connector.
+const UNATTRIBUTED_SERVICE: &str = "unattributed";
+
+/// Failed executions (with their healing issue's text, when one exists) and
+/// healing issues not tied to a failed execution, bucketed by the client's
+/// local hour of day × the connector the failure text names. Simulations are
+/// excluded. `tz_offset_minutes` works as in [`get_execution_heatmap`].
+#[instrument(skip(pool), fields(days, persona_id))]
+pub fn get_failure_heatmap(
+    pool: &DbPool,
+    days: Option<i64>,
+    persona_id: Option<&str>,
+    tz_offset_minutes: Option<i64>,
+) -> Result<FailureHeatmapData, AppError> {
+    let window_days = days.unwrap_or(30).clamp(1, 365);
+    let connectors = crate::engine::api_proxy::cached_connector_keywords(pool).unwrap_or_default();
+    timed_query!(
+        "execution_metrics",
+        "execution_metrics::get_failure_heatmap",
+        {
+            let conn = pool.get()?;
+            // Same local-time shift as the execution heatmap; inlined from a
+            // clamped integer, so injection-safe.
+            let tz_arg = heatmap_tz_modifier(tz_offset_minutes);
+            let sql = format!(
+                "SELECT CAST(strftime('%H', e.created_at{tz_arg}) AS INTEGER) AS hour,
+                        COALESCE(e.error_message, '') || ' ' || COALESCE(h.title, '') || ' '
+                            || COALESCE(h.description, '') AS detail
+                 FROM persona_executions e
+                 LEFT JOIN persona_healing_issues h ON h.execution_id = e.id
+                 WHERE e.status = 'failed'
+                   AND e.created_at >= datetime('now', ?1)
+                   AND COALESCE(e.is_simulation, 0) = 0
+                   AND (?2 IS NULL OR e.persona_id = ?2)
+                 UNION ALL
+                 SELECT CAST(strftime('%H', h.created_at{tz_arg}) AS INTEGER) AS hour,
+                        h.title || ' ' || h.description AS detail
+                 FROM persona_healing_issues h
+                 WHERE h.created_at >= datetime('now', ?1)
+                   AND (?2 IS NULL OR h.persona_id = ?2)
+                   AND NOT EXISTS (SELECT 1 FROM persona_executions e
+                                   WHERE e.id = h.execution_id AND e.status = 'failed')"
+            );
+            let mut stmt = conn.prepare(&sql)?;
+            let window = format!("-{window_days} days");
+            let rows = stmt.query_map(params![window, persona_id], |row| {
+                Ok((
+                    row.get::<_, Option<i64>>("hour")?,
+                    row.get::<_, String>("detail")?,
+                ))
+            })?;
+            let failures: Vec<(u8, String)> = rows
+                .filter_map(|r| match r {
+                    Ok((Some(hour), detail)) => Some((hour.clamp(0, 23) as u8, detail)),
+                    Ok((None, _)) => None,
+                    Err(e) => {
+                        warn!("get_failure_heatmap: row deserialization failed: {e}");
+                        None
+                    }
+                })
+                .collect();
+            Ok(aggregate_failure_heatmap(
+                &failures,
+                &connectors,
+                window_days,
+            ))
+        }
+    )
+}
+
+/// Bucket `(hour, failure text)` pairs by hour × service.
+fn aggregate_failure_heatmap(
+    failures: &[(u8, String)],
+    connectors: &[String],
+    window_days: i64,
+) -> FailureHeatmapData {
+    use crate::engine::error_taxonomy::classify_error_str;
+
+    // Longest names first, so `google_sheets` wins over `google`.
+    let mut connectors: Vec<&str> = connectors
+        .iter()
+        .map(String::as_str)
+        .filter(|name| name.len() >= 3)
+        .collect();
+    connectors.sort_by_key(|name| std::cmp::Reverse(name.len()));
+
+    // (hour, service) → category token → count
+    let mut buckets: HashMap<(u8, String), HashMap<String, i64>> = HashMap::new();
+    for (hour, detail) in failures {
+        let category = classify_error_str(detail);
+        let service = failure_service(detail, &connectors, category);
+        let token = serde_json::to_value(category)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| "unknown".to_string());
+        *buckets
+            .entry((*hour, service))
+            .or_default()
+            .entry(token)
+            .or_insert(0) += 1;
+    }
+
+    let mut cells: Vec<FailureHeatmapCell> = buckets
+        .into_iter()
+        .map(|((hour, service), categories)| {
+            let failures = categories.values().sum();
+            let top_category = categories
+                .into_iter()
+                .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+                .map(|(category, _)| category)
+                .unwrap_or_default();
+            FailureHeatmapCell {
+                hour,
+                service,
+                failures,
+                top_category,
+            }
+        })
+        .collect();
+    cells.sort_by(|a, b| a.hour.cmp(&b.hour).then_with(|| a.service.cmp(&b.service)));
+
+    let mut services: Vec<FailureHeatmapService> = Vec::new();
+    for cell in &cells {
+        match services.iter_mut().find(|s| s.service == cell.service) {
+            Some(s) => {
+                s.failures += cell.failures;
+                if cell.failures > s.peak_hour_failures {
+                    s.peak_hour = cell.hour;
+                    s.peak_hour_failures = cell.failures;
+                }
+            }
+            None => services.push(FailureHeatmapService {
+                service: cell.service.clone(),
+                failures: cell.failures,
+                peak_hour: cell.hour,
+                peak_hour_failures: cell.failures,
+            }),
+        }
+    }
+    services.sort_by(|a, b| {
+        b.failures
+            .cmp(&a.failures)
+            .then_with(|| a.service.cmp(&b.service))
+    });
+
+    FailureHeatmapData {
+        total_failures: cells.iter().map(|c| c.failures).sum(),
+        cells,
+        services,
+        window_days,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    }
+}
+
+/// The first connector (of `connectors`, longest first) whose name appears as
+/// a whole word in the failure text -- with `_`/`-` also matching a space.
+/// Without one, provider-side failures go to [`PROVIDER_SERVICE`].
+fn failure_service(
+    detail: &str,
+    connectors: &[&str],
+    category: crate::engine::error_taxonomy::ErrorCategory,
+) -> String {
+    use crate::engine::error_taxonomy::ErrorCategory;
+
+    let text = detail.to_lowercase();
+    let mentions = |needle: &str| {
+        text.match_indices(needle).any(|(i, m)| {
+            let before = text[..i].chars().next_back();
+            let after = text[i + m.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+    };
+    for name in connectors {
+        if mentions(name) || mentions(&name.replace(['_', '-'], " ")) {
+            return (*name).to_string();
+        }
+    }
+    match category {
+        ErrorCategory::SessionLimit | ErrorCategory::ProviderNotFound => PROVIDER_SERVICE.into(),
+        _ => UNATTRIBUTED_SERVICE.into(),
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2368,4 +3059,219 @@ mod tests {
         assert_eq!(summary.total_executions, 0);
         assert_eq!(summary.active_personas, 0);
     }
+
+    #[test]
+    fn trigger_roi_attributes_cost_and_outcomes_per_trigger() {
+        use crate::db::models::CreateTriggerInput;
+        use crate::db::repos::execution::executions;
+        use crate::db::repos::resources::triggers;
+
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona(&pool, "roi");
+        let trigger = |kind: &str, config: &str| {
+            triggers::create(
+                &pool,
+                CreateTriggerInput {
+                    persona_id: persona.clone(),
+                    trigger_type: kind.into(),
+                    config: Some(config.into()),
+                    enabled: Some(true),
+                    use_case_id: None,
+                },
+            )
+            .unwrap()
+            .id
+        };
+        let schedule = trigger("schedule", r#"{"interval_seconds":3600}"#);
+        let webhook = trigger("webhook", r#"{"webhook_secret":"s3cret"}"#);
+        let run = |trigger_id: Option<&str>, status: &str, cost: f64| {
+            let exec = executions::create(
+                &pool,
+                &persona,
+                trigger_id.map(String::from),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            pool.get()
+                .unwrap()
+                .execute(
+                    "UPDATE persona_executions SET status = ?2, cost_usd = ?3,
+                            created_at = datetime('now') WHERE id = ?1",
+                    params![exec.id, status, cost],
+                )
+                .unwrap();
+        };
+        run(Some(&schedule), "completed", 0.5);
+        run(Some(&schedule), "failed", 1.5);
+        run(None, "completed", 0.1);
+
+        let roi = get_trigger_roi(&pool, &persona, None).unwrap();
+        assert_eq!(roi.len(), 3);
+        let sched = &roi[0];
+        assert_eq!(sched.trigger_id.as_deref(), Some(schedule.as_str()));
+        assert_eq!((sched.firings, sched.succeeded, sched.failed), (2, 1, 1));
+        assert_eq!(sched.success_rate, 0.5);
+        assert_eq!(sched.cost_per_firing, 1.0);
+        assert_eq!(sched.cost_per_success, Some(2.0));
+        assert_eq!(roi[1].trigger_type, "untriggered");
+        let hook = &roi[2];
+        assert_eq!(hook.trigger_id.as_deref(), Some(webhook.as_str()));
+        assert_eq!((hook.firings, hook.cost_per_success), (0, None));
+    }
+
+    #[test]
+    fn failure_heatmap_buckets_by_hour_and_connector() {
+        let connectors: Vec<String> = ["slack", "google_sheets", "google", "ai"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let failures = vec![
+            (2, "Slack API returned 429 Too Many Requests".to_string()),
+            (2, "slack: rate limit exceeded".to_string()),
+            (2, "Google Sheets write failed: 403 forbidden".to_string()),
+            (14, "Session limit reached".to_string()),
+            (14, "Slackbot crashed mid-run".to_string()),
+            (23, "slack connection refused (network)".to_string()),
+        ];
+        let data = aggregate_failure_heatmap(&failures, &connectors, 30);
+
+        assert_eq!(data.total_failures, 6);
+        let cell = |hour: u8, service: &str| {
+            data.cells
+                .iter()
+                .find(|c| c.hour == hour && c.service == service)
+                .map(|c| (c.failures, c.top_category.as_str()))
+        };
+        assert_eq!(cell(2, "slack"), Some((2, "rate_limit")));
+        // The longest matching name wins; `_` also matches a space.
+        assert_eq!(cell(2, "google_sheets"), Some((1, "credential_error")));
+        assert_eq!(cell(14, PROVIDER_SERVICE), Some((1, "session_limit")));
+        // `Slackbot` is not a whole-word mention of `slack`, and the short
+        // `ai` name is ignored.
+        assert_eq!(cell(14, UNATTRIBUTED_SERVICE).map(|c| c.0), Some(1));
+
+        let slack = &data.services[0];
+        assert_eq!(slack.service, "slack");
+        assert_eq!(
+            (slack.failures, slack.peak_hour, slack.peak_hour_failures),
+            (3, 2, 2)
+        );
+    }
+
+    #[test]
+    fn cost_breakdown_groups_runs_by_model_and_day() {
+        use crate::db::repos::execution::executions;
+
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona(&pool, "spender");
+        let run = |model: Option<&str>, cost: f64, simulation: bool| {
+            let exec = executions::create(&pool, &persona, None, None, None, None).unwrap();
+            pool.get()
+                .unwrap()
+                .execute(
+                    "UPDATE persona_executions SET model_used = ?2, cost_usd = ?3,
+                            is_simulation = ?4, created_at = datetime('now') WHERE id = ?1",
+                    params![exec.id, model, cost, simulation],
+                )
+                .unwrap();
+        };
+        run(Some("opus"), 1.5, false);
+        run(Some("opus"), 0.5, false);
+        run(Some("haiku"), 0.5, false);
+        run(None, 0.25, false);
+        run(Some("opus"), 9.0, true);
+
+        let data = get_cost_breakdown(&pool, Some(7), CostGroupBy::Model).unwrap();
+        assert_eq!((data.period_days, data.total_executions), (7, 4));
+        assert_eq!(data.total_cost_usd, 2.75);
+        assert_eq!(data.daily.len(), 7);
+        assert_eq!(data.daily[6].cost_usd, 2.75);
+        let keys: Vec<&str> = data.groups.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(keys, ["opus", "haiku", NO_GROUP_KEY]);
+        let opus = &data.groups[0];
+        assert_eq!((opus.executions, opus.cost_usd), (2, 2.0));
+        assert_eq!(opus.daily[6].executions, 2);
+
+        let by_persona = get_cost_breakdown(&pool, Some(7), CostGroupBy::Persona).unwrap();
+        assert_eq!(by_persona.groups.len(), 1);
+        assert_eq!(by_persona.groups[0].label, "spender");
+        assert_eq!(by_persona.groups[0].share, 1.0);
+    }
+
+    #[test]
+    fn cost_breakdown_splits_connector_cost_by_invocations() {
+        let dates = vec!["2026-03-01".to_string(), "2026-03-02".to_string()];
+        let slice = |id: &str, date: &str, key: &str, cost: f64| CostSlice {
+            execution_id: id.into(),
+            date: date.into(),
+            key: key.into(),
+            label: key.into(),
+            cost_usd: cost,
+        };
+        let usage = [("slack".to_string(), 3), ("github".to_string(), 1)];
+        let mut slices: Vec<CostSlice> = split_cost_by_connector(2.0, &usage)
+            .into_iter()
+            .map(|(connector, cost)| slice("a", "2026-03-02", &connector, cost))
+            .collect();
+        slices.extend(
+            split_cost_by_connector(1.0, &[])
+                .into_iter()
+                .map(|(connector, cost)| slice("b", "2026-03-01", &connector, cost)),
+        );
+
+        let data = aggregate_cost_breakdown(&slices, &dates, CostGroupBy::Connector);
+        assert_eq!((data.total_cost_usd, data.total_executions), (3.0, 2));
+        let group = |key: &str| data.groups.iter().find(|g| g.key == key).unwrap();
+        assert_eq!(group("slack").cost_usd, 1.5);
+        assert_eq!(group("github").cost_usd, 0.5);
+        assert_eq!(group(NO_GROUP_KEY).daily[0].cost_usd, 1.0);
+        assert_eq!(data.groups[0].key, "slack");
+        assert_eq!(data.groups[0].share, 0.5);
+        // A run split across connectors is one run of the day overall.
+        assert_eq!(data.daily[1].executions, 1);
+        assert_eq!(data.daily[1].cost_usd, 2.0);
+    }
+
+    #[test]
+    fn cost_anomalies_flag_days_off_the_trailing_mean() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        let dates = cost_window_dates(today, 30);
+        assert_eq!(
+            (dates[0].as_str(), dates[29].as_str()),
+            ("2026-03-02", "2026-03-31")
+        );
+
+        // No spend for four days, then $1 ± 10c a day with a $5 spike on day
+        // 12 and nothing spent on the last day.
+        let mut rows = Vec::new();
+        for (i, date) in dates.iter().enumerate().skip(4) {
+            let cost = match i {
+                12 => 5.0,
+                29 => 0.0,
+                _ if i % 2 == 0 => 1.1,
+                _ => 0.9,
+            };
+            rows.push((date.clone(), "p1".to_string(), "Main".to_string(), cost));
+        }
+        rows.push((dates[12].clone(), "p2".to_string(), "Side".to_string(), 0.5));
+
+        let anomalies = detect_cost_anomalies(&dates, &rows, 30);
+        let flagged: Vec<&str> = anomalies.iter().map(|a| a.date.as_str()).collect();
+        assert_eq!(flagged, [dates[12].as_str(), dates[29].as_str()]);
+        let spike = &anomalies[0];
+        assert_eq!(spike.cost_usd, 5.5);
+        assert!(spike.deviation_sigma > 2.0);
+        assert_eq!(spike.top_persona_name.as_deref(), Some("Main"));
+        assert!(anomalies[1].deviation_sigma < -2.0);
+        assert_eq!(anomalies[1].top_persona_id, None);
+
+        // A spike less than a week after spending starts is not judged.
+        let young = [
+            (dates[4].clone(), "p1".to_string(), "Main".to_string(), 1.0),
+            (dates[9].clone(), "p1".to_string(), "Main".to_string(), 50.0),
+        ];
+        assert!(detect_cost_anomalies(&dates, &young, 30).is_empty());
+    }
 }
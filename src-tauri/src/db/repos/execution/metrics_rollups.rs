@@ -0,0 +1,626 @@
+//! Materialized metrics: daily `persona_metrics_snapshots` rebuilt from raw
+//! executions, and weekly/monthly `persona_metrics_rollups` re-aggregated
+//! from those snapshots.
+//!
+//! Snapshots are upserted, never deleted: a rebuild rewrites the days that
+//! still have executions on disk and leaves older days alone, so history
+//! survives execution retention. Rollups are maintained incrementally — only
+//! the weeks and months overlapping a rebuilt range are recomputed, and only
+//! from snapshot rows. `persona_metrics_snapshot_days` records which days have
+//! been rebuilt since they ended; long-range charts read snapshots only when
+//! every day in their window is covered.
+
+use chrono::{Datelike, Months, NaiveDate, Utc};
+use rusqlite::{params, Connection};
+
+use crate::db::models::{
+    MetricsChartPoint, MetricsPersonaBreakdown, MetricsRollupGranularity, MetricsRollupPoint,
+    MetricsSnapshotRebuild,
+};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Longest range one rebuild may cover (two years).
+pub const MAX_REBUILD_DAYS: i64 = 731;
+/// Chart windows at least this long read snapshots instead of executions.
+pub const SNAPSHOT_CHART_MIN_DAYS: i64 = 31;
+/// How far back the periodic refresh catches up after the app was closed.
+const REFRESH_CATCH_UP_DAYS: i64 = 35;
+/// Longest rollup series returned.
+const MAX_ROLLUP_PERIODS: i64 = 260;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+fn fmt_date(d: NaiveDate) -> String {
+    d.format(DATE_FORMAT).to_string()
+}
+
+/// First day of the period containing `d`.
+fn period_start(granularity: MetricsRollupGranularity, d: NaiveDate) -> NaiveDate {
+    match granularity {
+        MetricsRollupGranularity::Week => {
+            d - chrono::Duration::days(d.weekday().num_days_from_monday() as i64)
+        }
+        MetricsRollupGranularity::Month => d.with_day(1).unwrap_or(d),
+    }
+}
+
+/// Last day of the period containing `d`.
+fn period_end(granularity: MetricsRollupGranularity, d: NaiveDate) -> NaiveDate {
+    let start = period_start(granularity, d);
+    match granularity {
+        MetricsRollupGranularity::Week => start + chrono::Duration::days(6),
+        MetricsRollupGranularity::Month => start
+            .checked_add_months(Months::new(1))
+            .map(|next| next - chrono::Duration::days(1))
+            .unwrap_or(d),
+    }
+}
+
+/// SQLite expression mapping `snapshot_date` to its period start; must agree
+/// with [`period_start`].
+fn period_start_sql(granularity: MetricsRollupGranularity) -> &'static str {
+    match granularity {
+        MetricsRollupGranularity::Week => "date(snapshot_date, 'weekday 0', '-6 days')",
+        MetricsRollupGranularity::Month => "date(snapshot_date, 'start of month')",
+    }
+}
+
+// ============================================================================
+// Rebuild
+// ============================================================================
+
+/// Rebuild the daily snapshots for `start..=end` (UTC days) from raw
+/// executions, then the weekly and monthly rollups overlapping that range.
+pub fn rebuild_range(
+    pool: &DbPool,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<MetricsSnapshotRebuild, AppError> {
+    if end < start {
+        return Err(AppError::Validation(
+            "end_date must not be before start_date".into(),
+        ));
+    }
+    let days = (end - start).num_days() + 1;
+    if days > MAX_REBUILD_DAYS {
+        return Err(AppError::Validation(format!(
+            "A rebuild covers at most {MAX_REBUILD_DAYS} days"
+        )));
+    }
+
+    timed_query!("metrics_rollups", "metrics_rollups::rebuild_range", {
+        let mut conn = pool.get()?;
+        let tx = conn.transaction()?;
+        let now = Utc::now();
+        let snapshots_written = upsert_daily_snapshots(&tx, start, end, &now.to_rfc3339())?;
+        mark_days_rebuilt(&tx, start, end, now.date_naive(), &now.to_rfc3339())?;
+        let mut rollups_written = 0;
+        for granularity in [
+            MetricsRollupGranularity::Week,
+            MetricsRollupGranularity::Month,
+        ] {
+            rollups_written += refresh_rollups(&tx, granularity, start, end, &now.to_rfc3339())?;
+        }
+        tx.commit()?;
+
+        Ok(MetricsSnapshotRebuild {
+            start_date: fmt_date(start),
+            end_date: fmt_date(end),
+            days,
+            snapshots_written,
+            rollups_written,
+        })
+    })
+}
+
+/// Periodic refresh: rebuild today and every day since the last complete one
+/// (at least yesterday, at most [`REFRESH_CATCH_UP_DAYS`] back).
+pub fn refresh_recent(pool: &DbPool) -> Result<MetricsSnapshotRebuild, AppError> {
+    let today = Utc::now().date_naive();
+    let yesterday = today - chrono::Duration::days(1);
+    let last_complete: Option<String> = pool.get()?.query_row(
+        "SELECT MAX(snapshot_date) FROM persona_metrics_snapshot_days WHERE complete = 1",
+        [],
+        |row| row.get(0),
+    )?;
+    let start = last_complete
+        .and_then(|d| NaiveDate::parse_from_str(&d, DATE_FORMAT).ok())
+        .map(|d| {
+            (d + chrono::Duration::days(1))
+                .max(today - chrono::Duration::days(REFRESH_CATCH_UP_DAYS))
+                .min(yesterday)
+        })
+        .unwrap_or(yesterday);
+    rebuild_range(pool, start, today)
+}
+
+struct SnapshotRow {
+    persona_id: String,
+    day: String,
+    total: i64,
+    successful: i64,
+    failed: i64,
+    cost: f64,
+    input_tokens: i64,
+    output_tokens: i64,
+    avg_duration_ms: f64,
+    events_emitted: i64,
+    events_consumed: i64,
+    messages_sent: i64,
+}
+
+fn upsert_daily_snapshots(
+    conn: &Connection,
+    start: NaiveDate,
+    end: NaiveDate,
+    now: &str,
+) -> Result<i64, AppError> {
+    // `created_at` holds both `YYYY-MM-DD HH:MM:SS` and RFC 3339; both sort
+    // correctly against a bare date, so the range stays index-friendly.
+    let from = fmt_date(start);
+    let until = fmt_date(end + chrono::Duration::days(1));
+    let rows: Vec<SnapshotRow> = {
+        let mut stmt = conn.prepare(
+            "WITH ex AS (
+                SELECT persona_id, DATE(created_at) AS day,
+                       COUNT(*) AS total,
+                       SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END) AS successful,
+                       SUM(CASE WHEN status = 'failed' THEN 1 ELSE 0 END) AS failed,
+                       COALESCE(SUM(cost_usd), 0.0) AS cost,
+                       COALESCE(SUM(input_tokens), 0) AS input_tokens,
+                       COALESCE(SUM(output_tokens), 0) AS output_tokens,
+                       COALESCE(AVG(duration_ms), 0.0) AS avg_duration_ms
+                FROM persona_executions
+                WHERE created_at >= ?1 AND created_at < ?2
+                GROUP BY persona_id, day
+            ),
+            emitted AS (
+                SELECT source_id AS persona_id, DATE(created_at) AS day, COUNT(*) AS n
+                FROM persona_events
+                WHERE source_type LIKE 'persona:%' AND created_at >= ?1 AND created_at < ?2
+                GROUP BY source_id, day
+            ),
+            consumed AS (
+                SELECT target_persona_id AS persona_id, DATE(created_at) AS day, COUNT(*) AS n
+                FROM persona_events
+                WHERE target_persona_id IS NOT NULL AND created_at >= ?1 AND created_at < ?2
+                GROUP BY target_persona_id, day
+            ),
+            sent AS (
+                SELECT persona_id, DATE(created_at) AS day, COUNT(*) AS n
+                FROM persona_messages
+                WHERE created_at >= ?1 AND created_at < ?2
+                GROUP BY persona_id, day
+            )
+            SELECT ex.*,
+                   COALESCE(emitted.n, 0), COALESCE(consumed.n, 0), COALESCE(sent.n, 0)
+            FROM ex
+            JOIN personas p ON p.id = ex.persona_id
+            LEFT JOIN emitted ON emitted.persona_id = ex.persona_id AND emitted.day = ex.day
+            LEFT JOIN consumed ON consumed.persona_id = ex.persona_id AND consumed.day = ex.day
+            LEFT JOIN sent ON sent.persona_id = ex.persona_id AND sent.day = ex.day",
+        )?;
+        let rows = stmt.query_map(params![from, until], |row| {
+            Ok(SnapshotRow {
+                persona_id: row.get(0)?,
+                day: row.get(1)?,
+                total: row.get(2)?,
+                successful: row.get(3)?,
+                failed: row.get(4)?,
+                cost: row.get(5)?,
+                input_tokens: row.get(6)?,
+                output_tokens: row.get(7)?,
+                avg_duration_ms: row.get(8)?,
+                events_emitted: row.get(9)?,
+                events_consumed: row.get(10)?,
+                messages_sent: row.get(11)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()?
+    };
+
+    let mut stmt = conn.prepare(
+        "INSERT INTO persona_metrics_snapshots
+            (id, persona_id, snapshot_date, total_executions, successful_executions,
+             failed_executions, total_cost_usd, total_input_tokens, total_output_tokens,
+             avg_duration_ms, events_emitted, events_consumed, messages_sent, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+         ON CONFLICT(persona_id, snapshot_date) DO UPDATE SET
+            total_executions      = excluded.total_executions,
+            successful_executions = excluded.successful_executions,
+            failed_executions     = excluded.failed_executions,
+            total_cost_usd        = excluded.total_cost_usd,
+            total_input_tokens    = excluded.total_input_tokens,
+            total_output_tokens   = excluded.total_output_tokens,
+            avg_duration_ms       = excluded.avg_duration_ms,
+            events_emitted        = excluded.events_emitted,
+            events_consumed       = excluded.events_consumed,
+            messages_sent         = excluded.messages_sent",
+    )?;
+    for r in &rows {
+        stmt.execute(params![
+            uuid::Uuid::new_v4().to_string(),
+            r.persona_id,
+            r.day,
+            r.total,
+            r.successful,
+            r.failed,
+            r.cost,
+            r.input_tokens,
+            r.output_tokens,
+            r.avg_duration_ms,
+            r.events_emitted,
+            r.events_consumed,
+            r.messages_sent,
+            now,
+        ])?;
+    }
+    Ok(rows.len() as i64)
+}
+
+/// Record `start..=end` as rebuilt; days before `today` are complete.
+fn mark_days_rebuilt(
+    conn: &Connection,
+    start: NaiveDate,
+    end: NaiveDate,
+    today: NaiveDate,
+    now: &str,
+) -> Result<(), AppError> {
+    let mut stmt = conn.prepare(
+        "INSERT INTO persona_metrics_snapshot_days (snapshot_date, complete, rebuilt_at)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(snapshot_date) DO UPDATE SET
+            complete   = excluded.complete,
+            rebuilt_at = excluded.rebuilt_at",
+    )?;
+    for day in start.iter_days().take_while(|d| *d <= end) {
+        stmt.execute(params![fmt_date(day), day < today, now])?;
+    }
+    Ok(())
+}
+
+/// Recompute the `granularity` rollups for every period overlapping
+/// `start..=end` from the daily snapshots. Returns the rows written.
+fn refresh_rollups(
+    conn: &Connection,
+    granularity: MetricsRollupGranularity,
+    start: NaiveDate,
+    end: NaiveDate,
+    now: &str,
+) -> Result<i64, AppError> {
+    let from = fmt_date(period_start(granularity, start));
+    let to = fmt_date(period_end(granularity, end));
+    conn.execute(
+        "DELETE FROM persona_metrics_rollups
+         WHERE granularity = ?1 AND period_start >= ?2 AND period_start <= ?3",
+        params![granularity.as_str(), from, to],
+    )?;
+    let written = conn.execute(
+        &format!(
+            "INSERT INTO persona_metrics_rollups
+                (persona_id, granularity, period_start, total_executions, successful_executions,
+                 failed_executions, total_cost_usd, total_input_tokens, total_output_tokens,
+                 avg_duration_ms, events_emitted, events_consumed, messages_sent, updated_at)
+             SELECT persona_id, ?1, {} AS period,
+                    SUM(total_executions), SUM(successful_executions), SUM(failed_executions),
+                    SUM(total_cost_usd), SUM(total_input_tokens), SUM(total_output_tokens),
+                    CASE WHEN SUM(total_executions) > 0
+                         THEN SUM(avg_duration_ms * total_executions) / SUM(total_executions)
+                         ELSE 0 END,
+                    SUM(events_emitted), SUM(events_consumed), SUM(messages_sent), ?4
+             FROM persona_metrics_snapshots
+             WHERE snapshot_date >= ?2 AND snapshot_date <= ?3
+             GROUP BY persona_id, period",
+            period_start_sql(granularity)
+        ),
+        params![granularity.as_str(), from, to, now],
+    )?;
+    Ok(written as i64)
+}
+
+// ============================================================================
+// Reads
+// ============================================================================
+
+/// The last `periods` weeks or months (current one included), summed over
+/// every persona or just `persona_id`. Periods without executions are absent.
+pub fn get_rollup_series(
+    pool: &DbPool,
+    granularity: MetricsRollupGranularity,
+    periods: i64,
+    persona_id: Option<&str>,
+) -> Result<Vec<MetricsRollupPoint>, AppError> {
+    let periods = periods.clamp(1, MAX_ROLLUP_PERIODS);
+    let current = period_start(granularity, Utc::now().date_naive());
+    let cutoff = match granularity {
+        MetricsRollupGranularity::Week => current - chrono::Duration::weeks(periods - 1),
+        MetricsRollupGranularity::Month => current
+            .checked_sub_months(Months::new((periods - 1) as u32))
+            .unwrap_or(current),
+    };
+
+    timed_query!("metrics_rollups", "metrics_rollups::get_rollup_series", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT period_start,
+                    COALESCE(SUM(total_cost_usd), 0.0),
+                    COALESCE(SUM(total_executions), 0),
+                    COALESCE(SUM(successful_executions), 0),
+                    COALESCE(SUM(failed_executions), 0),
+                    COALESCE(SUM(total_input_tokens + total_output_tokens), 0),
+                    CASE WHEN SUM(total_executions) > 0
+                         THEN SUM(avg_duration_ms * total_executions) / SUM(total_executions)
+                         ELSE 0.0 END,
+                    COUNT(DISTINCT CASE WHEN total_executions > 0 THEN persona_id END)
+             FROM persona_metrics_rollups
+             WHERE granularity = ?1 AND period_start >= ?2
+               AND (?3 IS NULL OR persona_id = ?3)
+             GROUP BY period_start
+             ORDER BY period_start ASC",
+        )?;
+        let rows = stmt.query_map(
+            params![granularity.as_str(), fmt_date(cutoff), persona_id],
+            |row| {
+                Ok(MetricsRollupPoint {
+                    period_start: row.get(0)?,
+                    cost: row.get(1)?,
+                    executions: row.get(2)?,
+                    success: row.get(3)?,
+                    failed: row.get(4)?,
+                    tokens: row.get(5)?,
+                    avg_duration_ms: row.get(6)?,
+                    active_personas: row.get(7)?,
+                })
+            },
+        )?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    })
+}
+
+/// Whether every day in `from..until` has been rebuilt since it ended.
+fn snapshots_cover(
+    conn: &Connection,
+    from: &str,
+    until: &str,
+    days: i64,
+) -> Result<bool, AppError> {
+    let covered: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM persona_metrics_snapshot_days
+         WHERE complete = 1 AND snapshot_date >= ?1 AND snapshot_date < ?2",
+        params![from, until],
+        |row| row.get(0),
+    )?;
+    Ok(covered >= days)
+}
+
+/// Chart points and per-persona totals for the last `days` whole days read
+/// from snapshots, with today added live from executions. `None` when the
+/// snapshots do not cover the window yet; callers then scan executions.
+pub fn chart_from_snapshots(
+    conn: &Connection,
+    days: i64,
+    persona_id: Option<&str>,
+) -> Result<Option<(Vec<MetricsChartPoint>, Vec<MetricsPersonaBreakdown>)>, AppError> {
+    let today = Utc::now().date_naive();
+    let from = fmt_date(today - chrono::Duration::days(days));
+    let until = fmt_date(today);
+    if !snapshots_cover(conn, &from, &until, days)? {
+        return Ok(None);
+    }
+
+    let rows = "SELECT snapshot_date AS date, persona_id, total_cost_usd AS cost,
+                       total_executions AS executions, successful_executions AS success,
+                       failed_executions AS failed,
+                       total_input_tokens + total_output_tokens AS tokens
+                FROM persona_metrics_snapshots
+                WHERE snapshot_date >= ?1 AND snapshot_date < ?2
+                  AND (?3 IS NULL OR persona_id = ?3)
+                UNION ALL
+                SELECT DATE(created_at), persona_id, COALESCE(cost_usd, 0.0), 1,
+                       CASE WHEN status = 'completed' THEN 1 ELSE 0 END,
+                       CASE WHEN status = 'failed' THEN 1 ELSE 0 END,
+                       COALESCE(input_tokens, 0) + COALESCE(output_tokens, 0)
+                FROM persona_executions
+                WHERE created_at >= ?2 AND (?3 IS NULL OR persona_id = ?3)";
+    let args: &[&dyn rusqlite::ToSql] = &[&from, &until, &persona_id];
+
+    let chart_points = {
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT date, SUM(cost), SUM(executions), SUM(success), SUM(failed), SUM(tokens),
+                    COUNT(DISTINCT persona_id)
+             FROM ({rows})
+             GROUP BY date
+             ORDER BY date ASC"
+        ))?;
+        let points = stmt.query_map(args, |row| {
+            Ok(MetricsChartPoint {
+                date: row.get(0)?,
+                cost: row.get(1)?,
+                executions: row.get(2)?,
+                success: row.get(3)?,
+                failed: row.get(4)?,
+                tokens: row.get(5)?,
+                active_personas: row.get(6)?,
+            })
+        })?;
+        points.collect::<Result<Vec<_>, _>>()?
+    };
+
+    let persona_breakdown = {
+        let mut stmt = conn.prepare_cached(&format!(
+            "SELECT persona_id, SUM(executions) AS executions, SUM(cost)
+             FROM ({rows})
+             GROUP BY persona_id
+             HAVING executions > 0"
+        ))?;
+        let breakdown = stmt.query_map(args, |row| {
+            Ok(MetricsPersonaBreakdown {
+                persona_id: row.get(0)?,
+                executions: row.get(1)?,
+                cost: row.get(2)?,
+            })
+        })?;
+        breakdown.collect::<Result<Vec<_>, _>>()?
+    };
+
+    Ok(Some((chart_points, persona_breakdown)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::models::CreatePersonaInput;
+    use crate::db::repos::core::personas;
+
+    fn create_persona(pool: &DbPool, name: &str) -> String {
+        personas::create(
+            pool,
+            CreatePersonaInput {
+                name: name.into(),
+                system_prompt: "test".into(),
+                project_id: None,
+                description: None,
+                structured_prompt: None,
+                icon: None,
+                color: None,
+                enabled: Some(true),
+                max_concurrent: None,
+                timeout_ms: None,
+                model_profile: None,
+                max_budget_usd: None,
+                max_turns: None,
+                design_context: None,
+                notification_channels: None,
+                lifecycle: None,
+            },
+        )
+        .unwrap()
+        .id
+    }
+
+    fn insert_execution(pool: &DbPool, persona_id: &str, status: &str, created_at: &str) {
+        pool.get()
+            .unwrap()
+            .execute(
+                "INSERT INTO persona_executions
+                 (id, persona_id, status, input_tokens, output_tokens, cost_usd, duration_ms, created_at)
+                 VALUES (?1, ?2, ?3, 10, 5, 0.25, 1000, ?4)",
+                params![uuid::Uuid::new_v4().to_string(), persona_id, status, created_at],
+            )
+            .unwrap();
+    }
+
+    fn days_ago(n: i64) -> NaiveDate {
+        Utc::now().date_naive() - chrono::Duration::days(n)
+    }
+
+    #[test]
+    fn period_bounds_match_sqlite() {
+        let pool = init_test_db().unwrap();
+        let conn = pool.get().unwrap();
+        // 2026-10-18 is a Sunday.
+        for day in ["2026-10-12", "2026-10-15", "2026-10-18", "2026-03-01"] {
+            let d = NaiveDate::parse_from_str(day, DATE_FORMAT).unwrap();
+            for g in [
+                MetricsRollupGranularity::Week,
+                MetricsRollupGranularity::Month,
+            ] {
+                let sql: String = conn
+                    .query_row(
+                        &format!(
+                            "SELECT {} FROM (SELECT ?1 AS snapshot_date)",
+                            period_start_sql(g)
+                        ),
+                        params![day],
+                        |row| row.get(0),
+                    )
+                    .unwrap();
+                assert_eq!(sql, fmt_date(period_start(g, d)), "{day} {g:?}");
+            }
+        }
+        let d = NaiveDate::parse_from_str("2026-02-10", DATE_FORMAT).unwrap();
+        assert_eq!(
+            fmt_date(period_end(MetricsRollupGranularity::Month, d)),
+            "2026-02-28"
+        );
+    }
+
+    #[test]
+    fn rebuild_backfills_snapshots_and_rollups() {
+        let pool = init_test_db().unwrap();
+        let persona_id = create_persona(&pool, "Metrics");
+        let two_days_ago = format!("{} 09:00:00", fmt_date(days_ago(2)));
+        insert_execution(&pool, &persona_id, "completed", &two_days_ago);
+        insert_execution(&pool, &persona_id, "failed", &two_days_ago);
+        let rfc3339 = format!("{}T10:00:00+00:00", fmt_date(days_ago(1)));
+        insert_execution(&pool, &persona_id, "completed", &rfc3339);
+
+        let report = rebuild_range(&pool, days_ago(3), days_ago(1)).unwrap();
+        assert_eq!(report.days, 3);
+        assert_eq!(report.snapshots_written, 2);
+
+        let conn = pool.get().unwrap();
+        let (total, failed, cost): (i64, i64, f64) = conn
+            .query_row(
+                "SELECT total_executions, failed_executions, total_cost_usd
+                 FROM persona_metrics_snapshots WHERE snapshot_date = ?1",
+                params![fmt_date(days_ago(2))],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!((total, failed), (2, 1));
+        assert!((cost - 0.5).abs() < 1e-9);
+
+        // Rebuilding again refreshes rather than duplicates, and a day whose
+        // executions are gone keeps its snapshot.
+        conn.execute(
+            "DELETE FROM persona_executions WHERE created_at = ?1",
+            params![two_days_ago],
+        )
+        .unwrap();
+        rebuild_range(&pool, days_ago(3), days_ago(1)).unwrap();
+        let snapshots: i64 = conn
+            .query_row("SELECT COUNT(*) FROM persona_metrics_snapshots", [], |r| {
+                r.get(0)
+            })
+            .unwrap();
+        assert_eq!(snapshots, 2);
+
+        let months = get_rollup_series(&pool, MetricsRollupGranularity::Month, 2, None).unwrap();
+        assert_eq!(months.iter().map(|p| p.executions).sum::<i64>(), 3);
+        assert_eq!(months.iter().map(|p| p.failed).sum::<i64>(), 1);
+        let weeks =
+            get_rollup_series(&pool, MetricsRollupGranularity::Week, 2, Some(&persona_id)).unwrap();
+        assert_eq!(weeks.iter().map(|p| p.executions).sum::<i64>(), 3);
+        assert!(weeks
+            .iter()
+            .all(|p| (p.avg_duration_ms - 1000.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn charts_use_snapshots_only_when_the_window_is_covered() {
+        let pool = init_test_db().unwrap();
+        let persona_id = create_persona(&pool, "Chart");
+        insert_execution(
+            &pool,
+            &persona_id,
+            "completed",
+            &format!("{} 12:00:00", fmt_date(days_ago(5))),
+        );
+        let now = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        insert_execution(&pool, &persona_id, "failed", &now);
+
+        let conn = pool.get().unwrap();
+        assert!(chart_from_snapshots(&conn, 40, None).unwrap().is_none());
+
+        rebuild_range(&pool, days_ago(40), days_ago(1)).unwrap();
+        let (points, breakdown) = chart_from_snapshots(&conn, 40, None).unwrap().unwrap();
+        assert_eq!(points.len(), 2, "snapshot day plus live today");
+        assert_eq!(points.iter().map(|p| p.executions).sum::<i64>(), 2);
+        assert_eq!(points.last().unwrap().failed, 1);
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown[0].executions, 2);
+    }
+}
@@ -1,15 +1,19 @@
 pub mod annotations;
 pub mod assertions;
 pub mod audit_incidents;
+pub mod batches;
+pub mod budget_state;
 pub mod chain_stop_reasons;
 pub mod circuit_breaker;
 pub mod executions;
 pub mod healing;
 pub mod knowledge;
 pub mod metrics;
+pub mod metrics_rollups;
 pub mod policy_events;
 pub mod provider_audit;
 pub mod scheduled_retries;
+pub mod shared_context;
 pub mod test_runs;
 pub mod test_suites;
 pub mod tool_usage;
@@ -0,0 +1,181 @@
+//! Shared context — the key/value blackboard personas in one pipeline run or
+//! chain trace use to hand structured artifacts downstream.
+//!
+//! Rows are keyed by `(scope_type, scope_id, key)`; writing an existing key
+//! replaces its value. Scope resolution and prompt injection live in
+//! [`crate::engine::shared_context`].
+
+use rusqlite::{params, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// One artifact on a scope's blackboard.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedContextEntry {
+    pub id: String,
+    /// `pipeline`, `chain`, or `execution` (a chain root not yet adopted).
+    pub scope_type: String,
+    /// Pipeline run id, chain trace id, or execution id.
+    pub scope_id: String,
+    pub key: String,
+    pub value: serde_json::Value,
+    /// Persona that last wrote the key (None when set by the user).
+    pub persona_id: Option<String>,
+    pub execution_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+const COLUMNS: &str =
+    "id, scope_type, scope_id, key, value, persona_id, execution_id, created_at, updated_at";
+
+fn row_to_entry(row: &Row) -> rusqlite::Result<SharedContextEntry> {
+    let raw: String = row.get("value")?;
+    Ok(SharedContextEntry {
+        id: row.get("id")?,
+        scope_type: row.get("scope_type")?,
+        scope_id: row.get("scope_id")?,
+        key: row.get("key")?,
+        value: serde_json::from_str(&raw).unwrap_or(serde_json::Value::String(raw)),
+        persona_id: row.get("persona_id")?,
+        execution_id: row.get("execution_id")?,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    })
+}
+
+/// Write `key` in a scope, replacing any previous value.
+pub fn set(
+    pool: &DbPool,
+    scope_type: &str,
+    scope_id: &str,
+    key: &str,
+    value: &serde_json::Value,
+    persona_id: Option<&str>,
+    execution_id: Option<&str>,
+) -> Result<SharedContextEntry, AppError> {
+    timed_query!("shared_context", "shared_context::set", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        conn.execute(
+            "INSERT INTO shared_context
+                (id, scope_type, scope_id, key, value, persona_id, execution_id, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)
+             ON CONFLICT(scope_type, scope_id, key) DO UPDATE SET
+                value = excluded.value,
+                persona_id = excluded.persona_id,
+                execution_id = excluded.execution_id,
+                updated_at = excluded.updated_at",
+            params![
+                uuid::Uuid::new_v4().to_string(),
+                scope_type,
+                scope_id,
+                key,
+                value.to_string(),
+                persona_id,
+                execution_id,
+                now,
+            ],
+        )?;
+        let entry = conn.query_row(
+            &format!(
+                "SELECT {COLUMNS} FROM shared_context
+                 WHERE scope_type = ?1 AND scope_id = ?2 AND key = ?3"
+            ),
+            params![scope_type, scope_id, key],
+            row_to_entry,
+        )?;
+        Ok(entry)
+    })
+}
+
+/// One key of a scope, if it has been written.
+pub fn get(
+    pool: &DbPool,
+    scope_type: &str,
+    scope_id: &str,
+    key: &str,
+) -> Result<Option<SharedContextEntry>, AppError> {
+    timed_query!("shared_context", "shared_context::get", {
+        let conn = pool.get()?;
+        let entry = conn
+            .query_row(
+                &format!(
+                    "SELECT {COLUMNS} FROM shared_context
+                     WHERE scope_type = ?1 AND scope_id = ?2 AND key = ?3"
+                ),
+                params![scope_type, scope_id, key],
+                row_to_entry,
+            )
+            .optional()?;
+        Ok(entry)
+    })
+}
+
+/// Every key of a scope, oldest first (the order upstream wrote them).
+pub fn list(
+    pool: &DbPool,
+    scope_type: &str,
+    scope_id: &str,
+) -> Result<Vec<SharedContextEntry>, AppError> {
+    timed_query!("shared_context", "shared_context::list", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {COLUMNS} FROM shared_context
+             WHERE scope_type = ?1 AND scope_id = ?2
+             ORDER BY created_at ASC, key ASC"
+        ))?;
+        let rows = stmt.query_map(params![scope_type, scope_id], row_to_entry)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(AppError::Database)
+    })
+}
+
+/// Number of keys in a scope.
+pub fn count(pool: &DbPool, scope_type: &str, scope_id: &str) -> Result<i64, AppError> {
+    timed_query!("shared_context", "shared_context::count", {
+        let conn = pool.get()?;
+        let n = conn.query_row(
+            "SELECT COUNT(*) FROM shared_context WHERE scope_type = ?1 AND scope_id = ?2",
+            params![scope_type, scope_id],
+            |r| r.get(0),
+        )?;
+        Ok(n)
+    })
+}
+
+/// Move every key of one scope into another. Keys already present in the
+/// target keep their value. Returns the number of keys moved.
+pub fn rescope(pool: &DbPool, from: (&str, &str), to: (&str, &str)) -> Result<usize, AppError> {
+    timed_query!("shared_context", "shared_context::rescope", {
+        let conn = pool.get()?;
+        let n = conn.execute(
+            "UPDATE OR IGNORE shared_context SET scope_type = ?3, scope_id = ?4
+             WHERE scope_type = ?1 AND scope_id = ?2",
+            params![from.0, from.1, to.0, to.1],
+        )?;
+        conn.execute(
+            "DELETE FROM shared_context WHERE scope_type = ?1 AND scope_id = ?2",
+            params![from.0, from.1],
+        )?;
+        Ok(n)
+    })
+}
+
+/// Delete entries not written for `retention_days`. Returns rows deleted.
+pub fn cleanup_older_than(pool: &DbPool, retention_days: i64) -> Result<usize, AppError> {
+    timed_query!("shared_context", "shared_context::cleanup_older_than", {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::days(retention_days)).to_rfc3339();
+        let conn = pool.get()?;
+        let n = conn.execute(
+            "DELETE FROM shared_context WHERE updated_at < ?1",
+            params![cutoff],
+        )?;
+        Ok(n)
+    })
+}
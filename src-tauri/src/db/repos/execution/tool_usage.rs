@@ -4,6 +4,7 @@ use crate::db::models::{
     PersonaToolUsage, PersonaUsageSummary, ToolUsageOverTime, ToolUsageSummary,
 };
 use crate::db::query_builder::QueryBuilder;
+use crate::db::repos::contention::with_busy_retry;
 use crate::db::DbPool;
 use crate::error::AppError;
 
@@ -63,13 +64,16 @@ pub fn record(
         let id = uuid::Uuid::new_v4().to_string();
         let now = chrono::Utc::now().to_rfc3339();
 
-        let conn = pool.get()?;
-        conn.execute(
-            "INSERT INTO persona_tool_usage
-             (id, execution_id, persona_id, tool_name, invocation_count, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![id, execution_id, persona_id, tool_name, count, now],
-        )?;
+        with_busy_retry("tool_usage", || {
+            let conn = pool.get()?;
+            conn.execute(
+                "INSERT INTO persona_tool_usage
+                 (id, execution_id, persona_id, tool_name, invocation_count, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![id, execution_id, persona_id, tool_name, count, now],
+            )?;
+            Ok(())
+        })?;
 
         Ok(PersonaToolUsage {
             id,
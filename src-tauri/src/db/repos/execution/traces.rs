@@ -1,5 +1,6 @@
 use rusqlite::params;
 
+use crate::db::repos::contention::with_busy_retry;
 use crate::db::DbPool;
 use crate::engine::trace::ExecutionTrace;
 use crate::error::AppError;
@@ -12,21 +13,24 @@ pub fn save(pool: &DbPool, trace: &ExecutionTrace) -> Result<(), AppError> {
             serde_json::to_string(&trace.spans).map_err(|e| AppError::Internal(e.to_string()))?;
 
         let conn = pool.get()?;
-        conn.execute(
-            "INSERT INTO execution_traces (id, execution_id, trace_id, persona_id, chain_trace_id, spans, total_duration_ms, evicted_span_count, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-            params![
-                id,
-                trace.execution_id,
-                trace.trace_id,
-                trace.persona_id,
-                trace.chain_trace_id,
-                spans_json,
-                trace.total_duration_ms.map(|d| d as i64),
-                trace.evicted_span_count as i64,
-                trace.created_at,
-            ],
-        )?;
+        with_busy_retry("execution_traces", || {
+            conn.execute(
+                "INSERT INTO execution_traces (id, execution_id, trace_id, persona_id, chain_trace_id, spans, total_duration_ms, evicted_span_count, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    id,
+                    trace.execution_id,
+                    trace.trace_id,
+                    trace.persona_id,
+                    trace.chain_trace_id,
+                    spans_json,
+                    trace.total_duration_ms.map(|d| d as i64),
+                    trace.evicted_span_count as i64,
+                    trace.created_at,
+                ],
+            )?;
+            Ok(())
+        })?;
 
         Ok(())
     })
@@ -1,4 +1,5 @@
 pub mod communication;
+pub mod contention;
 pub mod core;
 pub mod dev_memories;
 pub mod dev_run_checkpoints;
@@ -0,0 +1,231 @@
+use rusqlite::params;
+
+use crate::db::models::CredentialAnomaly;
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+// -- Row mapper -----------------------------------------------
+
+row_mapper!(row_to_anomaly -> CredentialAnomaly {
+    id, credential_id, credential_name, persona_id, persona_name,
+    kind, severity, title, description, evidence, dedupe_key,
+    status, detected_at, acknowledged_at,
+});
+
+crud_get_by_id!(
+    CredentialAnomaly,
+    "credential_anomalies",
+    "Credential anomaly",
+    row_to_anomaly
+);
+
+// -- CRUD ------------------------------------------------------
+
+/// Store an anomaly unless one with the same `dedupe_key` exists.
+/// Returns whether it was new.
+pub fn insert_if_new(pool: &DbPool, a: &CredentialAnomaly) -> Result<bool, AppError> {
+    timed_query!("credential_anomalies", "credential_anomalies::insert_if_new", {
+        let conn = pool.get()?;
+        let n = conn.execute(
+            "INSERT OR IGNORE INTO credential_anomalies
+             (id, credential_id, credential_name, persona_id, persona_name, kind, severity,
+              title, description, evidence, dedupe_key, status, detected_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            params![
+                a.id,
+                a.credential_id,
+                a.credential_name,
+                a.persona_id,
+                a.persona_name,
+                a.kind,
+                a.severity,
+                a.title,
+                a.description,
+                a.evidence,
+                a.dedupe_key,
+                a.status,
+                a.detected_at,
+            ],
+        )?;
+        Ok(n > 0)
+    })
+}
+
+/// Newest first, optionally narrowed to one status.
+pub fn list(
+    pool: &DbPool,
+    status: Option<&str>,
+    limit: i64,
+) -> Result<Vec<CredentialAnomaly>, AppError> {
+    timed_query!("credential_anomalies", "credential_anomalies::list", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT * FROM credential_anomalies
+             WHERE (?1 IS NULL OR status = ?1)
+             ORDER BY detected_at DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![status, limit], row_to_anomaly)?;
+        Ok(collect_rows(rows, "credential_anomalies::list"))
+    })
+}
+
+pub fn acknowledge(pool: &DbPool, id: &str) -> Result<CredentialAnomaly, AppError> {
+    timed_query!("credential_anomalies", "credential_anomalies::acknowledge", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE credential_anomalies SET status = 'acknowledged', acknowledged_at = ?1
+             WHERE id = ?2 AND status = 'open'",
+            params![now, id],
+        )?;
+        get_by_id(pool, id)
+    })
+}
+
+// -- Detection queries -----------------------------------------
+
+/// A persona's first injection of a credential.
+pub struct FirstUse {
+    pub credential_id: String,
+    pub credential_name: String,
+    pub persona_id: String,
+    pub persona_name: Option<String>,
+    pub first_used_at: String,
+}
+
+/// Personas whose first-ever injection of a credential happened at or after
+/// `since`, limited to credentials already in use before `established_before`
+/// (a brand-new credential's first users are expected, not anomalous).
+pub fn first_persona_uses(
+    pool: &DbPool,
+    since: &str,
+    established_before: &str,
+) -> Result<Vec<FirstUse>, AppError> {
+    timed_query!("credential_audit_log", "credential_anomalies::first_persona_uses", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT cal.credential_id, MAX(cal.credential_name), cal.persona_id,
+                    MAX(cal.persona_name), MIN(cal.created_at) AS first_used_at
+             FROM credential_audit_log cal
+             WHERE cal.operation = 'decrypt' AND cal.persona_id IS NOT NULL
+             GROUP BY cal.credential_id, cal.persona_id
+             HAVING first_used_at >= ?1
+                AND (SELECT MIN(c2.created_at) FROM credential_audit_log c2
+                     WHERE c2.credential_id = cal.credential_id) < ?2",
+        )?;
+        let rows = stmt.query_map(params![since, established_before], |row| {
+            Ok(FirstUse {
+                credential_id: row.get(0)?,
+                credential_name: row.get(1)?,
+                persona_id: row.get(2)?,
+                persona_name: row.get(3)?,
+                first_used_at: row.get(4)?,
+            })
+        })?;
+        Ok(collect_rows(rows, "credential_anomalies::first_persona_uses"))
+    })
+}
+
+/// Injections of one credential within one UTC clock hour.
+pub struct HourlyUses {
+    pub credential_id: String,
+    pub credential_name: String,
+    /// `YYYY-MM-DD?HH` prefix of the timestamps in the bucket.
+    pub hour: String,
+    pub uses: i64,
+    pub personas: i64,
+    /// Mean injections per active hour between `baseline_since` and `since`.
+    pub baseline_per_hour: f64,
+}
+
+/// Hourly injection counts since `since` with at least `min_uses` entries,
+/// each alongside the credential's usual hourly rate.
+pub fn hourly_uses(
+    pool: &DbPool,
+    since: &str,
+    baseline_since: &str,
+    min_uses: i64,
+) -> Result<Vec<HourlyUses>, AppError> {
+    timed_query!("credential_audit_log", "credential_anomalies::hourly_uses", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT cal.credential_id, MAX(cal.credential_name), substr(cal.created_at, 1, 13) AS hour,
+                    COUNT(*), COUNT(DISTINCT cal.persona_id),
+                    COALESCE((SELECT CAST(COUNT(*) AS REAL) / COUNT(DISTINCT substr(b.created_at, 1, 13))
+                              FROM credential_audit_log b
+                              WHERE b.credential_id = cal.credential_id AND b.operation = 'decrypt'
+                                AND b.created_at >= ?2 AND b.created_at < ?1), 0.0)
+             FROM credential_audit_log cal
+             WHERE cal.operation = 'decrypt' AND cal.created_at >= ?1
+             GROUP BY cal.credential_id, hour
+             HAVING COUNT(*) >= ?3",
+        )?;
+        let rows = stmt.query_map(params![since, baseline_since, min_uses], |row| {
+            Ok(HourlyUses {
+                credential_id: row.get(0)?,
+                credential_name: row.get(1)?,
+                hour: row.get(2)?,
+                uses: row.get(3)?,
+                personas: row.get(4)?,
+                baseline_per_hour: row.get(5)?,
+            })
+        })?;
+        Ok(collect_rows(rows, "credential_anomalies::hourly_uses"))
+    })
+}
+
+/// An execution that injected a credential before it was rotated and was
+/// still running when the rotation landed.
+pub struct RunAcrossRotation {
+    pub credential_id: String,
+    pub credential_name: String,
+    pub persona_id: String,
+    pub persona_name: Option<String>,
+    pub execution_id: String,
+    pub execution_status: String,
+    pub rotation_id: String,
+    pub rotated_at: String,
+    pub injected_at: String,
+}
+
+/// Runs that kept using a pre-rotation secret after successful rotations
+/// recorded since `since`. Token refreshes are routine and excluded.
+pub fn runs_across_rotations(
+    pool: &DbPool,
+    since: &str,
+) -> Result<Vec<RunAcrossRotation>, AppError> {
+    timed_query!("credential_audit_log", "credential_anomalies::runs_across_rotations", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare(
+            "SELECT h.credential_id, MAX(cal.credential_name), e.persona_id, MAX(cal.persona_name),
+                    e.id, e.status, h.id, h.created_at, MAX(cal.created_at)
+             FROM credential_rotation_history h
+             INNER JOIN credential_audit_log cal
+                 ON cal.credential_id = h.credential_id AND cal.operation = 'decrypt'
+                AND cal.persona_id IS NOT NULL AND cal.created_at < h.created_at
+             INNER JOIN persona_executions e
+                 ON e.persona_id = cal.persona_id
+                AND e.created_at <= cal.created_at
+                AND (e.completed_at IS NULL OR e.completed_at > h.created_at)
+                AND e.status NOT IN ('queued', 'cancelled')
+             WHERE h.status = 'success' AND h.rotation_type <> 'token_refresh'
+               AND h.created_at >= ?1
+             GROUP BY h.id, e.id",
+        )?;
+        let rows = stmt.query_map(params![since], |row| {
+            Ok(RunAcrossRotation {
+                credential_id: row.get(0)?,
+                credential_name: row.get(1)?,
+                persona_id: row.get(2)?,
+                persona_name: row.get(3)?,
+                execution_id: row.get(4)?,
+                execution_status: row.get(5)?,
+                rotation_id: row.get(6)?,
+                rotated_at: row.get(7)?,
+                injected_at: row.get(8)?,
+            })
+        })?;
+        Ok(collect_rows(rows, "credential_anomalies::runs_across_rotations"))
+    })
+}
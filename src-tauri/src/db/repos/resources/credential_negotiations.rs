@@ -0,0 +1,278 @@
+//! Credential negotiation transcripts (`credential_negotiations` and
+//! `credential_negotiation_events`). Callers strip secrets before anything
+//! reaches these tables; see `commands::credentials::negotiator`.
+
+use rusqlite::params;
+
+use crate::db::models::{CredentialNegotiation, CredentialNegotiationEvent, NegotiationTranscript};
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_negotiation -> CredentialNegotiation {
+    id, service_name, status, plan_json, credential_id, error, created_at, updated_at,
+});
+
+row_mapper!(row_to_event -> CredentialNegotiationEvent {
+    id, negotiation_id, kind, step_index, detail_json, created_at,
+});
+
+crud_get_by_id!(
+    CredentialNegotiation,
+    "credential_negotiations",
+    "Credential negotiation",
+    row_to_negotiation
+);
+
+/// Start a transcript in `planning` state. Re-creating an existing id is a
+/// no-op.
+pub fn create(pool: &DbPool, id: &str, service_name: &str) -> Result<(), AppError> {
+    timed_query!(
+        "credential_negotiations",
+        "credential_negotiations::create",
+        {
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            conn.execute(
+                "INSERT OR IGNORE INTO credential_negotiations
+                 (id, service_name, status, created_at, updated_at)
+                 VALUES (?1, ?2, 'planning', ?3, ?3)",
+                params![id, service_name, now],
+            )?;
+            Ok(())
+        }
+    )
+}
+
+/// Append an entry to a transcript and bump its `updated_at`.
+pub fn append_event(
+    pool: &DbPool,
+    negotiation_id: &str,
+    kind: &str,
+    step_index: Option<u32>,
+    detail: Option<&serde_json::Value>,
+) -> Result<(), AppError> {
+    timed_query!(
+        "credential_negotiation_events",
+        "credential_negotiations::append_event",
+        {
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            let touched = conn.execute(
+                "UPDATE credential_negotiations SET updated_at = ?2 WHERE id = ?1",
+                params![negotiation_id, now],
+            )?;
+            if touched == 0 {
+                return Err(AppError::NotFound(format!(
+                    "Credential negotiation {negotiation_id}"
+                )));
+            }
+            conn.execute(
+                "INSERT INTO credential_negotiation_events
+                 (negotiation_id, kind, step_index, detail_json, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    negotiation_id,
+                    kind,
+                    step_index,
+                    detail.map(|d| d.to_string()),
+                    now
+                ],
+            )?;
+            Ok(())
+        }
+    )
+}
+
+/// Store the generated plan and move the transcript to `guiding`.
+pub fn set_plan(pool: &DbPool, id: &str, plan: &serde_json::Value) -> Result<(), AppError> {
+    timed_query!(
+        "credential_negotiations",
+        "credential_negotiations::set_plan",
+        {
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            conn.execute(
+                "UPDATE credential_negotiations
+                 SET plan_json = ?2, status = 'guiding', updated_at = ?3
+                 WHERE id = ?1 AND status = 'planning'",
+                params![id, plan.to_string(), now],
+            )?;
+            Ok(())
+        }
+    )
+}
+
+/// Close a transcript that has not produced a credential (`failed` or
+/// `cancelled`). Returns whether it was still open.
+pub fn close(pool: &DbPool, id: &str, status: &str, error: Option<&str>) -> Result<bool, AppError> {
+    timed_query!(
+        "credential_negotiations",
+        "credential_negotiations::close",
+        {
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            let n = conn.execute(
+                "UPDATE credential_negotiations SET status = ?2, error = ?3, updated_at = ?4
+                 WHERE id = ?1 AND status IN ('planning', 'guiding')",
+                params![id, status, error, now],
+            )?;
+            Ok(n > 0)
+        }
+    )
+}
+
+/// Link the credential saved from a negotiation and mark it `completed`.
+pub fn link_credential(pool: &DbPool, id: &str, credential_id: &str) -> Result<(), AppError> {
+    timed_query!(
+        "credential_negotiations",
+        "credential_negotiations::link_credential",
+        {
+            let now = chrono::Utc::now().to_rfc3339();
+            let conn = pool.get()?;
+            let n = conn.execute(
+                "UPDATE credential_negotiations
+                 SET credential_id = ?2, status = 'completed', error = NULL, updated_at = ?3
+                 WHERE id = ?1",
+                params![id, credential_id, now],
+            )?;
+            if n == 0 {
+                return Err(AppError::NotFound(format!("Credential negotiation {id}")));
+            }
+            Ok(())
+        }
+    )
+}
+
+/// A negotiation with all of its entries, oldest first.
+pub fn get_transcript(pool: &DbPool, id: &str) -> Result<NegotiationTranscript, AppError> {
+    let negotiation = get_by_id(pool, id)?;
+    timed_query!(
+        "credential_negotiation_events",
+        "credential_negotiations::get_transcript",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT * FROM credential_negotiation_events
+                 WHERE negotiation_id = ?1 ORDER BY id",
+            )?;
+            let rows = stmt.query_map(params![id], row_to_event)?;
+            Ok(NegotiationTranscript {
+                negotiation,
+                events: collect_rows(rows, "credential_negotiations::get_transcript"),
+            })
+        }
+    )
+}
+
+/// The most recent negotiation that produced `credential_id`.
+pub fn get_for_credential(
+    pool: &DbPool,
+    credential_id: &str,
+) -> Result<NegotiationTranscript, AppError> {
+    let id: String = timed_query!(
+        "credential_negotiations",
+        "credential_negotiations::get_for_credential",
+        {
+            let conn = pool.get()?;
+            conn.query_row(
+                "SELECT id FROM credential_negotiations
+                 WHERE credential_id = ?1 ORDER BY created_at DESC LIMIT 1",
+                params![credential_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => AppError::NotFound(format!(
+                    "No negotiation transcript for credential {credential_id}"
+                )),
+                other => AppError::Database(other),
+            })
+        }
+    )?;
+    get_transcript(pool, &id)
+}
+
+/// Newest first. `query` matches the service name, error, plan and entry
+/// details (case-insensitive substring).
+pub fn search(
+    pool: &DbPool,
+    query: Option<&str>,
+    credential_id: Option<&str>,
+    limit: i64,
+) -> Result<Vec<CredentialNegotiation>, AppError> {
+    timed_query!(
+        "credential_negotiations",
+        "credential_negotiations::search",
+        {
+            let pattern = query
+                .map(str::trim)
+                .filter(|q| !q.is_empty())
+                .map(|q| format!("%{}%", q.replace('%', "\\%").replace('_', "\\_")));
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT * FROM credential_negotiations n
+                 WHERE (?2 IS NULL OR n.credential_id = ?2)
+                   AND (?1 IS NULL
+                        OR n.service_name LIKE ?1 ESCAPE '\\'
+                        OR n.error LIKE ?1 ESCAPE '\\'
+                        OR n.plan_json LIKE ?1 ESCAPE '\\'
+                        OR EXISTS (SELECT 1 FROM credential_negotiation_events e
+                                   WHERE e.negotiation_id = n.id
+                                     AND e.detail_json LIKE ?1 ESCAPE '\\'))
+                 ORDER BY n.created_at DESC LIMIT ?3",
+            )?;
+            let rows =
+                stmt.query_map(params![pattern, credential_id, limit], row_to_negotiation)?;
+            Ok(collect_rows(rows, "credential_negotiations::search"))
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    #[test]
+    fn transcript_lifecycle_and_search() {
+        let pool = init_test_db().unwrap();
+        create(&pool, "neg-1", "Stripe").unwrap();
+        append_event(
+            &pool,
+            "neg-1",
+            "plan_requested",
+            None,
+            Some(&serde_json::json!({ "field_keys": ["api_key"] })),
+        )
+        .unwrap();
+        set_plan(&pool, "neg-1", &serde_json::json!({ "steps": [] })).unwrap();
+        append_event(
+            &pool,
+            "neg-1",
+            "step_completed",
+            Some(0),
+            Some(&serde_json::json!({ "step_title": "Open the webhooks dashboard" })),
+        )
+        .unwrap();
+        link_credential(&pool, "neg-1", "cred-1").unwrap();
+
+        let t = get_for_credential(&pool, "cred-1").unwrap();
+        assert_eq!(t.negotiation.status, "completed");
+        assert_eq!(t.events.len(), 2);
+        assert_eq!(t.events[1].step_index, Some(0));
+        // A completed negotiation can no longer be closed as cancelled.
+        assert!(!close(&pool, "neg-1", "cancelled", None).unwrap());
+
+        create(&pool, "neg-2", "GitHub").unwrap();
+        assert!(close(&pool, "neg-2", "failed", Some("timed out")).unwrap());
+
+        assert_eq!(search(&pool, Some("webhooks"), None, 10).unwrap().len(), 1);
+        assert_eq!(
+            search(&pool, Some("github"), None, 10).unwrap()[0].id,
+            "neg-2"
+        );
+        assert_eq!(search(&pool, None, Some("cred-1"), 10).unwrap().len(), 1);
+        assert_eq!(search(&pool, None, None, 10).unwrap().len(), 2);
+        assert!(append_event(&pool, "missing", "step_completed", None, None).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+//! Per-persona execute-hook tokens for the `/hooks/execute/<token>` route.
+//!
+//! One token per persona. Like external API keys, only the SHA-256 hash is
+//! stored: the plaintext is returned once by [`rotate`], and rotating replaces
+//! the previous token outright.
+
+use rand::RngCore;
+use rusqlite::{params, OptionalExtension};
+
+use super::external_api_keys::hash_token;
+use crate::db::models::PersonaExecuteHook;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_hook -> PersonaExecuteHook {
+    persona_id, token_hash, token_prefix, wait_timeout_secs,
+    created_at, last_used_at,
+});
+
+/// Fresh `ph_<32 hex chars>` token and its display prefix (`ph_` + 6 hex).
+fn generate_token() -> (String, String) {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    let token = format!("ph_{}", hex::encode(bytes));
+    let prefix = token.chars().take(9).collect::<String>();
+    (token, prefix)
+}
+
+/// Issue a new token for `persona_id`, replacing any existing one. Returns the
+/// stored hook and the plaintext token.
+pub fn rotate(
+    pool: &DbPool,
+    persona_id: &str,
+    wait_timeout_secs: i64,
+) -> Result<(PersonaExecuteHook, String), AppError> {
+    timed_query!("persona_execute_hooks", "persona_execute_hooks::rotate", {
+        let (token, prefix) = generate_token();
+        let conn = pool.get()?;
+        let hook = conn.query_row(
+            "INSERT INTO persona_execute_hooks
+                (persona_id, token_hash, token_prefix, wait_timeout_secs, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(persona_id) DO UPDATE SET
+               token_hash = excluded.token_hash,
+               token_prefix = excluded.token_prefix,
+               wait_timeout_secs = excluded.wait_timeout_secs,
+               created_at = excluded.created_at,
+               last_used_at = NULL
+             RETURNING *",
+            params![
+                persona_id,
+                hash_token(&token),
+                prefix,
+                wait_timeout_secs,
+                chrono::Utc::now().to_rfc3339()
+            ],
+            row_to_hook,
+        )?;
+        Ok((hook, token))
+    })
+}
+
+pub fn get(pool: &DbPool, persona_id: &str) -> Result<Option<PersonaExecuteHook>, AppError> {
+    timed_query!("persona_execute_hooks", "persona_execute_hooks::get", {
+        let conn = pool.get()?;
+        Ok(conn
+            .query_row(
+                "SELECT * FROM persona_execute_hooks WHERE persona_id = ?1",
+                params![persona_id],
+                row_to_hook,
+            )
+            .optional()?)
+    })
+}
+
+/// Resolve a plaintext token to its hook, touching `last_used_at` on a hit.
+pub fn find_by_token(
+    pool: &DbPool,
+    plaintext_token: &str,
+) -> Result<Option<PersonaExecuteHook>, AppError> {
+    timed_query!("persona_execute_hooks", "persona_execute_hooks::find_by_token", {
+        if plaintext_token.is_empty() {
+            return Ok(None);
+        }
+        let conn = pool.get()?;
+        let hook = conn
+            .query_row(
+                "SELECT * FROM persona_execute_hooks WHERE token_hash = ?1",
+                params![hash_token(plaintext_token)],
+                row_to_hook,
+            )
+            .optional()?;
+        if let Some(ref h) = hook {
+            // Best-effort touch — failure here should not block the hook.
+            let _ = conn.execute(
+                "UPDATE persona_execute_hooks SET last_used_at = ?1 WHERE persona_id = ?2",
+                params![chrono::Utc::now().to_rfc3339(), h.persona_id],
+            );
+        }
+        Ok(hook)
+    })
+}
+
+pub fn set_wait_timeout(
+    pool: &DbPool,
+    persona_id: &str,
+    wait_timeout_secs: i64,
+) -> Result<PersonaExecuteHook, AppError> {
+    timed_query!("persona_execute_hooks", "persona_execute_hooks::set_wait_timeout", {
+        let conn = pool.get()?;
+        conn.query_row(
+            "UPDATE persona_execute_hooks SET wait_timeout_secs = ?1
+             WHERE persona_id = ?2 RETURNING *",
+            params![wait_timeout_secs, persona_id],
+            row_to_hook,
+        )
+        .optional()?
+        .ok_or_else(|| AppError::NotFound(format!("Execute hook for persona {persona_id}")))
+    })
+}
+
+/// Delete the persona's hook. Returns whether one existed.
+pub fn revoke(pool: &DbPool, persona_id: &str) -> Result<bool, AppError> {
+    timed_query!("persona_execute_hooks", "persona_execute_hooks::revoke", {
+        let conn = pool.get()?;
+        let rows = conn.execute(
+            "DELETE FROM persona_execute_hooks WHERE persona_id = ?1",
+            params![persona_id],
+        )?;
+        Ok(rows > 0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::repos::test_fixtures::create_test_persona;
+
+    #[test]
+    fn rotate_replaces_token_and_revoke_disables_it() {
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona(&pool, "Hooked", "hook");
+
+        let (hook, first) = rotate(&pool, &persona.id, 30).unwrap();
+        assert!(first.starts_with(&hook.token_prefix));
+        assert_eq!(
+            find_by_token(&pool, &first).unwrap().unwrap().persona_id,
+            persona.id
+        );
+        assert!(get(&pool, &persona.id).unwrap().unwrap().last_used_at.is_some());
+
+        let (_, second) = rotate(&pool, &persona.id, 0).unwrap();
+        assert!(find_by_token(&pool, &first).unwrap().is_none());
+        assert!(find_by_token(&pool, &second).unwrap().is_some());
+        assert_eq!(set_wait_timeout(&pool, &persona.id, 90).unwrap().wait_timeout_secs, 90);
+
+        assert!(revoke(&pool, &persona.id).unwrap());
+        assert!(find_by_token(&pool, &second).unwrap().is_none());
+        assert!(!revoke(&pool, &persona.id).unwrap());
+    }
+}
@@ -28,7 +28,7 @@ row_mapper!(row_to_external_api_key -> ExternalApiKey {
 // ============================================================================
 
 /// SHA-256 hex digest of a token. Used for both storage and lookup.
-fn hash_token(token: &str) -> String {
+pub(crate) fn hash_token(token: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(token.as_bytes());
     format!("{:x}", hasher.finalize())
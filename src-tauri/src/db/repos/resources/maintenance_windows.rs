@@ -0,0 +1,275 @@
+//! Global and per-persona maintenance windows (`maintenance_windows`).
+//!
+//! Nothing here blocks a trigger from firing: the scheduler, polling loop and
+//! webhook server keep publishing events as usual. Deferral happens at the
+//! claim — `events::claim_pending` skips events whose target persona is
+//! covered by an active window (or any event while a global window is
+//! active), so they sit in `pending` and run once the window ends.
+
+use std::collections::HashSet;
+
+use rusqlite::params;
+
+use crate::db::models::{CreateMaintenanceWindowInput, MaintenanceWindow};
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_window -> MaintenanceWindow {
+    id, persona_id, starts_at, ends_at, reason, created_at,
+});
+
+crud_get_by_id!(
+    MaintenanceWindow,
+    "maintenance_windows",
+    "MaintenanceWindow",
+    row_to_window
+);
+crud_delete!("maintenance_windows");
+
+/// Parse an RFC 3339 timestamp and re-emit it in the UTC form `now` strings
+/// use, so windows compare correctly as plain text in SQL.
+fn normalize_ts(field: &str, value: &str) -> Result<String, AppError> {
+    chrono::DateTime::parse_from_rfc3339(value.trim())
+        .map(|dt| dt.with_timezone(&chrono::Utc).to_rfc3339())
+        .map_err(|e| AppError::Validation(format!("{field} must be an RFC 3339 timestamp: {e}")))
+}
+
+pub fn create(
+    pool: &DbPool,
+    input: CreateMaintenanceWindowInput,
+) -> Result<MaintenanceWindow, AppError> {
+    timed_query!("maintenance_windows", "maintenance_windows::create", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let starts_at = match input.starts_at.as_deref() {
+            Some(s) => normalize_ts("starts_at", s)?,
+            None => now.clone(),
+        };
+        let ends_at = input
+            .ends_at
+            .as_deref()
+            .map(|s| normalize_ts("ends_at", s))
+            .transpose()?;
+        if let Some(end) = &ends_at {
+            if *end <= starts_at {
+                return Err(AppError::Validation(
+                    "Maintenance window must end after it starts".into(),
+                ));
+            }
+            if *end <= now {
+                return Err(AppError::Validation(
+                    "Maintenance window ends in the past".into(),
+                ));
+            }
+        }
+        let id = uuid::Uuid::new_v4().to_string();
+        let conn = pool.get()?;
+        Ok(conn.query_row(
+            "INSERT INTO maintenance_windows (id, persona_id, starts_at, ends_at, reason, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             RETURNING *",
+            params![
+                id,
+                input.persona_id,
+                starts_at,
+                ends_at,
+                input.reason.filter(|r| !r.trim().is_empty()),
+                now,
+            ],
+            row_to_window,
+        )?)
+    })
+}
+
+/// Windows that are active now or still upcoming, soonest first. With
+/// `include_ended`, finished windows are listed too.
+pub fn list(pool: &DbPool, include_ended: bool) -> Result<Vec<MaintenanceWindow>, AppError> {
+    timed_query!("maintenance_windows", "maintenance_windows::list", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT * FROM maintenance_windows
+             WHERE ?1 OR ends_at IS NULL OR ends_at > ?2
+             ORDER BY starts_at ASC, created_at ASC",
+        )?;
+        let rows = stmt.query_map(params![include_ended, now], row_to_window)?;
+        Ok(collect_rows(rows, "maintenance_windows::list"))
+    })
+}
+
+/// End a window now. Upcoming windows are cut to zero length, which leaves
+/// them inert but keeps the row for the record.
+pub fn end_now(pool: &DbPool, id: &str) -> Result<MaintenanceWindow, AppError> {
+    timed_query!("maintenance_windows", "maintenance_windows::end_now", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        conn.execute(
+            "UPDATE maintenance_windows
+             SET ends_at = ?1, starts_at = MIN(starts_at, ?1)
+             WHERE id = ?2 AND (ends_at IS NULL OR ends_at > ?1)",
+            params![now, id],
+        )?;
+        drop(conn);
+        get_by_id(pool, id)
+    })
+}
+
+/// Which personas are in maintenance at `now`.
+#[derive(Debug, Default)]
+pub struct ActiveMaintenance {
+    pub global: bool,
+    pub persona_ids: HashSet<String>,
+}
+
+impl ActiveMaintenance {
+    pub fn covers(&self, persona_id: &str) -> bool {
+        self.global || self.persona_ids.contains(persona_id)
+    }
+}
+
+pub fn active_at(pool: &DbPool, now: &str) -> Result<ActiveMaintenance, AppError> {
+    timed_query!("maintenance_windows", "maintenance_windows::active_at", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT persona_id FROM maintenance_windows
+             WHERE starts_at <= ?1 AND (ends_at IS NULL OR ends_at > ?1)",
+        )?;
+        let mut active = ActiveMaintenance::default();
+        for persona_id in stmt.query_map(params![now], |row| row.get::<_, Option<String>>(0))? {
+            match persona_id? {
+                Some(id) => {
+                    active.persona_ids.insert(id);
+                }
+                None => active.global = true,
+            }
+        }
+        Ok(active)
+    })
+}
+
+/// Whether a fire of `trigger_id` is already waiting out a maintenance window
+/// for `persona_id`. The scheduler uses this to coalesce a schedule's fires
+/// into one catch-up run instead of queueing a run per slot.
+pub fn has_deferred_trigger_fire(
+    pool: &DbPool,
+    trigger_id: &str,
+    persona_id: &str,
+    now: &str,
+) -> Result<bool, AppError> {
+    timed_query!("maintenance_windows", "maintenance_windows::deferred", {
+        let conn = pool.get()?;
+        let deferred = conn.query_row(
+            "SELECT EXISTS (
+                 SELECT 1 FROM maintenance_windows
+                 WHERE (persona_id IS NULL OR persona_id = ?2)
+                   AND starts_at <= ?3 AND (ends_at IS NULL OR ends_at > ?3)
+             ) AND EXISTS (
+                 SELECT 1 FROM persona_events
+                 WHERE status = 'pending' AND source_type = 'trigger'
+                   AND source_id = ?1
+             )",
+            params![trigger_id, persona_id, now],
+            |row| row.get(0),
+        )?;
+        Ok(deferred)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::models::CreatePersonaEventInput;
+    use crate::db::repos::communication::events as event_repo;
+    use crate::db::repos::test_fixtures::create_test_persona_id;
+
+    fn publish_for(pool: &DbPool, persona_id: &str) -> String {
+        event_repo::publish(
+            pool,
+            CreatePersonaEventInput {
+                event_type: "maintenance_test".into(),
+                source_type: "trigger".into(),
+                source_id: Some("t1".into()),
+                target_persona_id: Some(persona_id.into()),
+                project_id: None,
+                payload: None,
+                use_case_id: None,
+            },
+        )
+        .unwrap()
+        .id
+    }
+
+    fn claimed_ids(pool: &DbPool) -> Vec<String> {
+        event_repo::claim_pending(pool, 50)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.id)
+            .collect()
+    }
+
+    #[test]
+    fn active_windows_defer_claims_until_they_end() {
+        let pool = init_test_db().unwrap();
+        let paused = create_test_persona_id(&pool, "Paused", "prompt");
+        let running = create_test_persona_id(&pool, "Running", "prompt");
+
+        let window = create(
+            &pool,
+            CreateMaintenanceWindowInput {
+                persona_id: Some(paused.clone()),
+                starts_at: None,
+                ends_at: None,
+                reason: Some("API migration".into()),
+            },
+        )
+        .unwrap();
+        let deferred = publish_for(&pool, &paused);
+        let live = publish_for(&pool, &running);
+        assert_eq!(claimed_ids(&pool), vec![live]);
+        let now = chrono::Utc::now().to_rfc3339();
+        assert!(has_deferred_trigger_fire(&pool, "t1", &paused, &now).unwrap());
+
+        // A global window holds everything back.
+        let global = create(
+            &pool,
+            CreateMaintenanceWindowInput {
+                persona_id: None,
+                starts_at: None,
+                ends_at: Some((chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339()),
+                reason: None,
+            },
+        )
+        .unwrap();
+        let held = publish_for(&pool, &running);
+        assert!(claimed_ids(&pool).is_empty());
+        assert!(active_at(&pool, &chrono::Utc::now().to_rfc3339())
+            .unwrap()
+            .covers(&running));
+
+        end_now(&pool, &global.id).unwrap();
+        end_now(&pool, &window.id).unwrap();
+        let mut claimed = claimed_ids(&pool);
+        claimed.sort();
+        let mut expected = vec![deferred, held];
+        expected.sort();
+        assert_eq!(claimed, expected);
+        assert!(list(&pool, false).unwrap().is_empty());
+        assert_eq!(list(&pool, true).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_windows_that_end_before_they_start() {
+        let pool = init_test_db().unwrap();
+        let err = create(
+            &pool,
+            CreateMaintenanceWindowInput {
+                persona_id: None,
+                starts_at: Some("2030-01-02T00:00:00Z".into()),
+                ends_at: Some("2030-01-01T00:00:00Z".into()),
+                reason: None,
+            },
+        );
+        assert!(matches!(err, Err(AppError::Validation(_))));
+    }
+}
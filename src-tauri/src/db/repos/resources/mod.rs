@@ -4,16 +4,20 @@ pub mod audit_log;
 pub mod automations;
 pub mod cloud_webhook_watermarks;
 pub mod connectors;
+pub mod credential_anomalies;
+pub mod credential_negotiations;
 pub mod credential_recipes;
 pub mod credentials;
 pub mod db_schema;
 pub mod deliberation;
 pub mod deployment_history;
+pub mod execute_hooks;
 #[cfg(feature = "p2p")]
 pub mod exposure;
 pub mod external_api_keys;
 #[cfg(feature = "p2p")]
 pub mod identity;
+pub mod maintenance_windows;
 pub mod mcp_gateways;
 pub mod n8n_sessions;
 pub mod notification_subscriptions;
@@ -23,14 +27,17 @@ pub mod ocr;
 pub mod owned_devices;
 pub mod persona_change_log;
 pub mod playwright_procedures;
+pub mod prompt_blocks;
 pub mod recipe_suggestions;
 pub mod recipes;
+pub mod report_sinks;
 pub mod rotation;
 pub mod settings_audit_log;
 #[cfg(feature = "p2p")]
 pub mod signing;
 pub mod team_channel;
 pub mod team_memories;
+pub mod template_variables;
 pub mod teams;
 pub mod tool_audit_log;
 pub mod tools;
@@ -164,6 +164,20 @@ fn compute_changes(existing: &Persona, input: &UpdatePersonaInput) -> Vec<Change
             changes.push(("parameters", disp_opt(&existing.parameters), disp_opt(v)));
         }
     }
+    if let Some(ref v) = input.engine {
+        if *v != existing.engine {
+            changes.push(("engine", disp_opt(&existing.engine), disp_opt(v)));
+        }
+    }
+    if let Some(ref v) = input.execution_placement {
+        if *v != existing.execution_placement {
+            changes.push((
+                "execution_placement",
+                disp_opt(&existing.execution_placement),
+                disp_opt(v),
+            ));
+        }
+    }
     if let Some(ref v) = input.lifecycle {
         if *v != existing.lifecycle {
             changes.push(("lifecycle", disp(&existing.lifecycle), disp(v)));
@@ -0,0 +1,157 @@
+//! Reusable prompt blocks (`prompt_blocks`). Run-time inlining and usage
+//! scanning live in `engine::prompt_blocks`.
+
+use rusqlite::params;
+
+use crate::db::models::{PromptBlock, SavePromptBlockInput};
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_block -> PromptBlock {
+    id, name, category, content, description, created_at, updated_at,
+});
+
+const CATEGORIES: &[&str] = &["tone", "compliance", "output_format", "other"];
+const MAX_NAME_LEN: usize = 120;
+const MAX_CONTENT_LEN: usize = 20_000;
+
+fn validate(input: &SavePromptBlockInput) -> Result<(), AppError> {
+    let name = input.name.trim();
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        return Err(AppError::Validation(format!(
+            "Block name must be 1-{MAX_NAME_LEN} characters"
+        )));
+    }
+    if !CATEGORIES.contains(&input.category.as_str()) {
+        return Err(AppError::Validation(format!(
+            "Block category must be one of {}, got '{}'",
+            CATEGORIES.join(", "),
+            input.category
+        )));
+    }
+    if input.content.trim().is_empty() {
+        return Err(AppError::Validation("Block content is empty".into()));
+    }
+    if input.content.len() > MAX_CONTENT_LEN {
+        return Err(AppError::Validation(format!(
+            "Block content exceeds {MAX_CONTENT_LEN} bytes"
+        )));
+    }
+    Ok(())
+}
+
+pub fn list_all(pool: &DbPool) -> Result<Vec<PromptBlock>, AppError> {
+    timed_query!("prompt_blocks", "prompt_blocks::list_all", {
+        let conn = pool.get()?;
+        let mut stmt =
+            conn.prepare_cached("SELECT * FROM prompt_blocks ORDER BY category, name")?;
+        let rows = stmt.query_map([], row_to_block)?;
+        Ok(collect_rows(rows, "prompt_blocks::list_all"))
+    })
+}
+
+crud_get_by_id!(PromptBlock, "prompt_blocks", "PromptBlock", row_to_block);
+
+/// Create a block, or update the one with `input.id`. Names are unique.
+pub fn save(pool: &DbPool, input: SavePromptBlockInput) -> Result<PromptBlock, AppError> {
+    timed_query!("prompt_blocks", "prompt_blocks::save", {
+        validate(&input)?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let description = input.description.filter(|d| !d.trim().is_empty());
+        let name = input.name.trim();
+        let conn = pool.get()?;
+        let result = match &input.id {
+            Some(id) => conn.query_row(
+                "UPDATE prompt_blocks
+                 SET name = ?2, category = ?3, content = ?4, description = ?5, updated_at = ?6
+                 WHERE id = ?1
+                 RETURNING *",
+                params![id, name, input.category, input.content, description, now],
+                row_to_block,
+            ),
+            None => conn.query_row(
+                "INSERT INTO prompt_blocks
+                    (id, name, category, content, description, created_at, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)
+                 RETURNING *",
+                params![
+                    uuid::Uuid::new_v4().to_string(),
+                    name,
+                    input.category,
+                    input.content,
+                    description,
+                    now,
+                ],
+                row_to_block,
+            ),
+        };
+        result.map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => AppError::NotFound(format!(
+                "PromptBlock {}",
+                input.id.as_deref().unwrap_or_default()
+            )),
+            rusqlite::Error::SqliteFailure(err, _)
+                if err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE =>
+            {
+                AppError::Validation(format!("A prompt block named '{name}' already exists"))
+            }
+            other => AppError::Database(other),
+        })
+    })
+}
+
+/// Delete a block by id. Returns whether it existed.
+pub fn delete(pool: &DbPool, id: &str) -> Result<bool, AppError> {
+    timed_query!("prompt_blocks", "prompt_blocks::delete", {
+        let conn = pool.get()?;
+        let rows = conn.execute("DELETE FROM prompt_blocks WHERE id = ?1", params![id])?;
+        Ok(rows > 0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    fn input(id: Option<&str>, name: &str, category: &str, content: &str) -> SavePromptBlockInput {
+        SavePromptBlockInput {
+            id: id.map(Into::into),
+            name: name.into(),
+            category: category.into(),
+            content: content.into(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn save_creates_updates_and_validates() {
+        let pool = init_test_db().unwrap();
+        let tone = save(&pool, input(None, "Friendly tone", "tone", "Be warm.")).unwrap();
+        let updated = save(
+            &pool,
+            input(
+                Some(&tone.id),
+                "Friendly tone",
+                "tone",
+                "Be warm and brief.",
+            ),
+        )
+        .unwrap();
+        assert_eq!(updated.id, tone.id);
+        assert_eq!(updated.content, "Be warm and brief.");
+        assert_eq!(
+            get_by_id(&pool, &tone.id).unwrap().content,
+            "Be warm and brief."
+        );
+
+        assert!(save(&pool, input(None, "Friendly tone", "tone", "dup")).is_err());
+        assert!(save(&pool, input(None, "x", "style", "c")).is_err());
+        assert!(save(&pool, input(None, "x", "tone", "  ")).is_err());
+        assert!(save(&pool, input(Some("missing"), "x", "tone", "c")).is_err());
+
+        assert!(delete(&pool, &tone.id).unwrap());
+        assert!(list_all(&pool).unwrap().is_empty());
+    }
+}
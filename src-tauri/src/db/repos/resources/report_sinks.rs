@@ -0,0 +1,122 @@
+//! Per-persona file-report sink settings (`persona_report_sinks`). The writer
+//! itself lives in `engine::report_sink`.
+
+use rusqlite::{params, OptionalExtension};
+
+use crate::db::models::{PersonaReportSink, SaveReportSinkInput};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_sink -> PersonaReportSink {
+    persona_id, enabled [bool], directory, format, template, filename_prefix,
+    scheduled_only [bool], pdf [bool],
+    last_written_path, last_written_at, last_error,
+    created_at, updated_at,
+});
+
+fn validate(input: &SaveReportSinkInput) -> Result<(), AppError> {
+    if !matches!(input.format.as_str(), "markdown" | "html") {
+        return Err(AppError::Validation(format!(
+            "Report format must be 'markdown' or 'html', got '{}'",
+            input.format
+        )));
+    }
+    if input.directory.trim().is_empty() || !std::path::Path::new(&input.directory).is_absolute() {
+        return Err(AppError::Validation(
+            "Report directory must be an absolute path".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn get(pool: &DbPool, persona_id: &str) -> Result<Option<PersonaReportSink>, AppError> {
+    timed_query!("persona_report_sinks", "persona_report_sinks::get", {
+        let conn = pool.get()?;
+        Ok(conn
+            .query_row(
+                "SELECT * FROM persona_report_sinks WHERE persona_id = ?1",
+                params![persona_id],
+                row_to_sink,
+            )
+            .optional()?)
+    })
+}
+
+/// Create or replace the persona's sink settings. Delivery history
+/// (`last_*`) is kept across edits.
+pub fn upsert(
+    pool: &DbPool,
+    persona_id: &str,
+    input: SaveReportSinkInput,
+) -> Result<PersonaReportSink, AppError> {
+    timed_query!("persona_report_sinks", "persona_report_sinks::upsert", {
+        validate(&input)?;
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        Ok(conn.query_row(
+            "INSERT INTO persona_report_sinks
+                (persona_id, enabled, directory, format, template, filename_prefix,
+                 scheduled_only, pdf, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9)
+             ON CONFLICT(persona_id) DO UPDATE SET
+               enabled = excluded.enabled,
+               directory = excluded.directory,
+               format = excluded.format,
+               template = excluded.template,
+               filename_prefix = excluded.filename_prefix,
+               scheduled_only = excluded.scheduled_only,
+               pdf = excluded.pdf,
+               updated_at = excluded.updated_at
+             RETURNING *",
+            params![
+                persona_id,
+                input.enabled as i32,
+                input.directory.trim(),
+                input.format,
+                input.template.filter(|t| !t.trim().is_empty()),
+                input.filename_prefix.filter(|p| !p.trim().is_empty()),
+                input.scheduled_only as i32,
+                input.pdf as i32,
+                now,
+            ],
+            row_to_sink,
+        )?)
+    })
+}
+
+/// Record the outcome of a write: the file path on success, the error otherwise.
+pub fn record_result(
+    pool: &DbPool,
+    persona_id: &str,
+    result: Result<&str, &str>,
+) -> Result<(), AppError> {
+    timed_query!("persona_report_sinks", "persona_report_sinks::record_result", {
+        let conn = pool.get()?;
+        let now = chrono::Utc::now().to_rfc3339();
+        match result {
+            Ok(path) => conn.execute(
+                "UPDATE persona_report_sinks
+                 SET last_written_path = ?1, last_written_at = ?2, last_error = NULL
+                 WHERE persona_id = ?3",
+                params![path, now, persona_id],
+            )?,
+            Err(error) => conn.execute(
+                "UPDATE persona_report_sinks SET last_error = ?1 WHERE persona_id = ?2",
+                params![error, persona_id],
+            )?,
+        };
+        Ok(())
+    })
+}
+
+/// Remove the persona's sink. Returns whether one existed.
+pub fn delete(pool: &DbPool, persona_id: &str) -> Result<bool, AppError> {
+    timed_query!("persona_report_sinks", "persona_report_sinks::delete", {
+        let conn = pool.get()?;
+        let rows = conn.execute(
+            "DELETE FROM persona_report_sinks WHERE persona_id = ?1",
+            params![persona_id],
+        )?;
+        Ok(rows > 0)
+    })
+}
@@ -2,7 +2,7 @@ use rusqlite::params;
 
 use crate::db::models::{
     CreateTeamInput, PersonaTeam, PersonaTeamConnection, PersonaTeamMember, PipelineRun,
-    TeamCounts, UpdateTeamInput,
+    PipelineSimulation, TeamCounts, UpdateTeamInput,
 };
 use crate::db::DbPool;
 use crate::error::AppError;
@@ -19,6 +19,7 @@ row_mapper!(row_to_team -> PersonaTeam {
     default_model_profile [opt],
     default_max_budget_usd [opt],
     default_max_turns [opt],
+    monthly_budget_usd [opt],
     created_at, updated_at,
 });
 
@@ -34,7 +35,7 @@ row_mapper!(row_to_connection -> PersonaTeamConnection {
 
 row_mapper!(row_to_pipeline_run -> PipelineRun {
     id, team_id, status, node_statuses,
-    input_data, started_at, completed_at, error_message,
+    input_data, started_at, completed_at, error_message, simulation,
 });
 
 // ============================================================================
@@ -60,6 +61,7 @@ crud_update! {
         default_model_profile: clone,
         default_max_budget_usd: copy,
         default_max_turns: copy,
+        monthly_budget_usd: copy,
     }
 }
 
@@ -757,6 +759,7 @@ pub fn create_pipeline_run(
     pool: &DbPool,
     team_id: &str,
     input_data: Option<&str>,
+    simulation: Option<&PipelineSimulation>,
 ) -> Result<String, AppError> {
     timed_query!("teams", "teams::create_pipeline_run", {
         let id = uuid::Uuid::new_v4().to_string();
@@ -785,15 +788,80 @@ pub fn create_pipeline_run(
         }
 
         tx.execute(
-            "INSERT INTO pipeline_runs (id, team_id, status, node_statuses, input_data, started_at)
-             VALUES (?1, ?2, 'running', '[]', ?3, ?4)",
-            params![id, team_id, input_data, now],
+            "INSERT INTO pipeline_runs
+                (id, team_id, status, node_statuses, input_data, started_at, simulation)
+             VALUES (?1, ?2, 'running', '[]', ?3, ?4, ?5)",
+            params![
+                id,
+                team_id,
+                input_data,
+                now,
+                simulation.map(serde_json::to_string).transpose()?,
+            ],
         )?;
         tx.commit().map_err(AppError::Database)?;
         Ok(id)
     })
 }
 
+/// Put a stopped run (`paused`, `failed`, …) back to `running` with the node
+/// statuses prepared for another attempt. `allowed` lists the statuses the
+/// run may be reopened from. Shares `create_pipeline_run`'s
+/// one-running-pipeline-per-team guard, inside the same kind of
+/// `BEGIN IMMEDIATE` transaction.
+pub fn reopen_pipeline_run(
+    pool: &DbPool,
+    id: &str,
+    allowed: &[&str],
+    node_statuses: &str,
+) -> Result<(), AppError> {
+    timed_query!("teams", "teams::reopen_pipeline_run", {
+        let mut conn = pool.get()?;
+        let tx = conn
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+            .map_err(AppError::Database)?;
+
+        let (team_id, status): (String, String) = tx
+            .query_row(
+                "SELECT team_id, status FROM pipeline_runs WHERE id = ?1",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => {
+                    AppError::NotFound(format!("PipelineRun {id}"))
+                }
+                other => AppError::Database(other),
+            })?;
+        if !allowed.contains(&status.as_str()) {
+            return Err(AppError::Validation(format!(
+                "This pipeline run is {status}; only {} runs can be continued.",
+                allowed.join(" / ")
+            )));
+        }
+        let running: i64 = tx.query_row(
+            "SELECT COUNT(*) FROM pipeline_runs WHERE team_id = ?1 AND status = 'running'",
+            params![team_id],
+            |row| row.get(0),
+        )?;
+        if running > 0 {
+            return Err(AppError::Validation(
+                "This team already has a pipeline running. Wait for it to complete or cancel it first."
+                    .into(),
+            ));
+        }
+
+        tx.execute(
+            "UPDATE pipeline_runs
+                SET status = 'running', node_statuses = ?1, error_message = NULL, completed_at = NULL
+              WHERE id = ?2",
+            params![node_statuses, id],
+        )?;
+        tx.commit().map_err(AppError::Database)?;
+        Ok(())
+    })
+}
+
 pub fn update_pipeline_run(
     pool: &DbPool,
     id: &str,
@@ -910,6 +978,7 @@ mod tests {
                 default_model_profile: None,
                 default_max_budget_usd: None,
                 default_max_turns: None,
+                monthly_budget_usd: None,
             },
         )
         .unwrap();
@@ -993,4 +1062,49 @@ mod tests {
         assert_eq!(get_members(&pool, &team.id).unwrap().len(), 0);
         assert_eq!(get_connections(&pool, &team.id).unwrap().len(), 0);
     }
+
+    #[test]
+    fn test_reopen_pipeline_run_guards_status_and_team() {
+        let pool = init_test_db().unwrap();
+        let team = create(
+            &pool,
+            CreateTeamInput {
+                name: "Pipeline Team".into(),
+                project_id: None,
+                parent_team_id: None,
+                description: None,
+                canvas_data: None,
+                team_config: None,
+                icon: None,
+                color: None,
+                enabled: Some(true),
+            },
+        )
+        .unwrap();
+
+        let run = create_pipeline_run(&pool, &team.id, None, None).unwrap();
+        // A running run can't be reopened.
+        assert!(reopen_pipeline_run(&pool, &run, &["paused"], "[]").is_err());
+
+        update_pipeline_run(&pool, &run, "paused", "[]", None).unwrap();
+        let paused = get_pipeline_run(&pool, &run).unwrap();
+        assert!(paused.completed_at.is_none());
+        // Another run started meanwhile blocks the resume.
+        let other = create_pipeline_run(&pool, &team.id, None, None).unwrap();
+        assert!(reopen_pipeline_run(&pool, &run, &["paused"], "[]").is_err());
+        update_pipeline_run(&pool, &other, "failed", "[]", Some("boom")).unwrap();
+
+        let statuses = r#"[{"member_id":"m1","status":"idle"}]"#;
+        reopen_pipeline_run(&pool, &run, &["paused"], statuses).unwrap();
+        let reopened = get_pipeline_run(&pool, &run).unwrap();
+        assert_eq!(reopened.status, "running");
+        assert_eq!(reopened.node_statuses, statuses);
+
+        // Failed runs reopen for a node retry, clearing the old error.
+        reopen_pipeline_run(&pool, &other, &["failed", "cancelled"], "[]").unwrap_err();
+        update_pipeline_run(&pool, &run, "completed", "[]", None).unwrap();
+        reopen_pipeline_run(&pool, &other, &["failed", "cancelled"], "[]").unwrap();
+        let retried = get_pipeline_run(&pool, &other).unwrap();
+        assert!(retried.error_message.is_none() && retried.completed_at.is_none());
+    }
 }
@@ -0,0 +1,142 @@
+//! Prompt template variables (`template_variables`). Resolution and usage
+//! scanning live in `engine::template_variables`.
+
+use rusqlite::params;
+
+use crate::db::models::{SaveTemplateVariableInput, TemplateVariable};
+use crate::db::repos::utils::collect_rows;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+row_mapper!(row_to_variable -> TemplateVariable {
+    id, scope, scope_id, name, value, description, created_at, updated_at,
+});
+
+const MAX_NAME_LEN: usize = 64;
+const MAX_VALUE_LEN: usize = 10_000;
+
+/// Names are what follows `var.` in `{{var.<name>}}`.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_NAME_LEN
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Validate and normalize the scope pair; `global` always stores an empty id.
+fn normalized_scope(input: &SaveTemplateVariableInput) -> Result<String, AppError> {
+    let scope_id = input.scope_id.as_deref().map(str::trim).unwrap_or_default();
+    match input.scope.as_str() {
+        "global" => Ok(String::new()),
+        "team" | "persona" if !scope_id.is_empty() => Ok(scope_id.to_string()),
+        "team" | "persona" => Err(AppError::Validation(format!(
+            "A {} variable needs a scope_id",
+            input.scope
+        ))),
+        other => Err(AppError::Validation(format!(
+            "Variable scope must be 'global', 'team' or 'persona', got '{other}'"
+        ))),
+    }
+}
+
+pub fn list_all(pool: &DbPool) -> Result<Vec<TemplateVariable>, AppError> {
+    timed_query!("template_variables", "template_variables::list_all", {
+        let conn = pool.get()?;
+        let mut stmt = conn.prepare_cached(
+            "SELECT * FROM template_variables ORDER BY name, scope, scope_id",
+        )?;
+        let rows = stmt.query_map([], row_to_variable)?;
+        Ok(collect_rows(rows, "template_variables::list_all"))
+    })
+}
+
+/// Create or update the variable identified by `(scope, scope_id, name)`.
+pub fn save(
+    pool: &DbPool,
+    input: SaveTemplateVariableInput,
+) -> Result<TemplateVariable, AppError> {
+    timed_query!("template_variables", "template_variables::save", {
+        let scope_id = normalized_scope(&input)?;
+        let name = input.name.trim();
+        if !is_valid_name(name) {
+            return Err(AppError::Validation(format!(
+                "Variable name must be 1-{MAX_NAME_LEN} letters, digits or underscores, got '{name}'"
+            )));
+        }
+        if input.value.len() > MAX_VALUE_LEN {
+            return Err(AppError::Validation(format!(
+                "Variable value exceeds {MAX_VALUE_LEN} bytes"
+            )));
+        }
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        Ok(conn.query_row(
+            "INSERT INTO template_variables
+                (id, scope, scope_id, name, value, description, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)
+             ON CONFLICT(scope, scope_id, name) DO UPDATE SET
+               value = excluded.value,
+               description = excluded.description,
+               updated_at = excluded.updated_at
+             RETURNING *",
+            params![
+                uuid::Uuid::new_v4().to_string(),
+                input.scope,
+                scope_id,
+                name,
+                input.value,
+                input.description.filter(|d| !d.trim().is_empty()),
+                now,
+            ],
+            row_to_variable,
+        )?)
+    })
+}
+
+/// Delete a variable by id. Returns whether it existed.
+pub fn delete(pool: &DbPool, id: &str) -> Result<bool, AppError> {
+    timed_query!("template_variables", "template_variables::delete", {
+        let conn = pool.get()?;
+        let rows = conn.execute("DELETE FROM template_variables WHERE id = ?1", params![id])?;
+        Ok(rows > 0)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    fn input(
+        scope: &str,
+        scope_id: Option<&str>,
+        name: &str,
+        value: &str,
+    ) -> SaveTemplateVariableInput {
+        SaveTemplateVariableInput {
+            scope: scope.into(),
+            scope_id: scope_id.map(Into::into),
+            name: name.into(),
+            value: value.into(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn save_upserts_per_scope_and_validates() {
+        let pool = init_test_db().unwrap();
+        let first = save(&pool, input("global", Some("ignored"), "company_name", "Acme")).unwrap();
+        assert_eq!(first.scope_id, "");
+        let updated = save(&pool, input("global", None, "company_name", "Acme Inc")).unwrap();
+        assert_eq!(updated.id, first.id);
+        assert_eq!(updated.value, "Acme Inc");
+        save(&pool, input("persona", Some("p1"), "company_name", "Acme EU")).unwrap();
+        assert_eq!(list_all(&pool).unwrap().len(), 2);
+
+        assert!(save(&pool, input("persona", None, "x", "v")).is_err());
+        assert!(save(&pool, input("global", None, "bad-name", "v")).is_err());
+        assert!(save(&pool, input("org", None, "x", "v")).is_err());
+
+        assert!(delete(&pool, &first.id).unwrap());
+        assert!(!delete(&pool, &first.id).unwrap());
+    }
+}
@@ -431,6 +431,74 @@ pub fn assign_tool(
     )
 }
 
+/// Replace the `tool_config` of an existing assignment. `assign_tool` returns
+/// an existing row untouched, so config edits go through here.
+pub fn update_tool_config(
+    pool: &DbPool,
+    persona_id: &str,
+    tool_id: &str,
+    tool_config: Option<String>,
+) -> Result<PersonaTool, AppError> {
+    timed_query!(
+        "persona_tool_definitions",
+        "persona_tool_definitions::update_tool_config",
+        {
+            let conn = pool.get()?;
+            let rows = conn.execute(
+                "UPDATE persona_tools SET tool_config = ?3 WHERE persona_id = ?1 AND tool_id = ?2",
+                params![persona_id, tool_id, tool_config],
+            )?;
+            if rows == 0 {
+                return Err(AppError::NotFound(format!(
+                    "Tool {tool_id} is not assigned to persona {persona_id}"
+                )));
+            }
+            conn.query_row(
+                "SELECT id, persona_id, tool_id, tool_config, created_at
+             FROM persona_tools WHERE persona_id = ?1 AND tool_id = ?2",
+                params![persona_id, tool_id],
+                |row| {
+                    Ok(PersonaTool {
+                        id: row.get(0)?,
+                        persona_id: row.get(1)?,
+                        tool_id: row.get(2)?,
+                        tool_config: row.get(3)?,
+                        created_at: row.get(4)?,
+                    })
+                },
+            )
+            .map_err(AppError::Database)
+        }
+    )
+}
+
+/// `(tool name, tool_config)` for every tool assigned to a persona that has a
+/// non-empty config. Used by the runner to load tool sandbox policies.
+pub fn get_tool_configs_for_persona(
+    pool: &DbPool,
+    persona_id: &str,
+) -> Result<Vec<(String, String)>, AppError> {
+    timed_query!(
+        "persona_tool_definitions",
+        "persona_tool_definitions::get_tool_configs_for_persona",
+        {
+            let conn = pool.get()?;
+            let mut stmt = conn.prepare(
+                "SELECT d.name, pt.tool_config FROM persona_tools pt
+             INNER JOIN persona_tool_definitions d ON d.id = pt.tool_id
+             WHERE pt.persona_id = ?1 AND pt.tool_config IS NOT NULL AND pt.tool_config != ''",
+            )?;
+            let rows = stmt.query_map(params![persona_id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?;
+            let configs = rows
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(AppError::Database)?;
+            Ok(configs)
+        }
+    )
+}
+
 pub fn unassign_tool(pool: &DbPool, persona_id: &str, tool_id: &str) -> Result<bool, AppError> {
     timed_query!(
         "persona_tool_definitions",
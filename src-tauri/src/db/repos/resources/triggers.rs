@@ -1,6 +1,8 @@
 use rusqlite::{params, OptionalExtension};
 
-use crate::db::models::{CreateTriggerInput, PersonaTrigger, TriggerConfig, UpdateTriggerInput};
+use crate::db::models::{
+    CatchUpPolicy, CreateTriggerInput, PersonaTrigger, TriggerConfig, UpdateTriggerInput,
+};
 use crate::db::query_builder::QueryBuilder;
 use crate::db::DbPool;
 use crate::engine::{chain, crypto, scheduler};
@@ -1014,7 +1016,8 @@ pub fn delete_orphaned_triggers(pool: &DbPool) -> Result<u32, AppError> {
 /// `NoListener { reason }`, so adding a new trigger type without making this
 /// decision fails the test rather than silently producing events nothing
 /// listens for.
-pub(crate) const AUTO_LISTENER_SOURCE_TYPES: &[&str] = &["schedule", "polling", "webhook"];
+pub(crate) const AUTO_LISTENER_SOURCE_TYPES: &[&str] =
+    &["schedule", "polling", "webhook", "email_inbound"];
 
 /// Build the JSON config string for an auto-listener. Stores advisory fields
 /// so cleanup can identify it later.
@@ -1826,6 +1829,31 @@ pub fn mark_triggered_with_hash(
     )
 }
 
+/// Store an `email_inbound` trigger's mailbox cursor and advance its schedule
+/// in one CAS on `trigger_version`. Returns `false` when another poll cycle
+/// got there first, in which case the caller must not publish.
+pub fn mark_email_polled(
+    pool: &DbPool,
+    id: &str,
+    cursor_json: &str,
+    next_trigger_at: Option<String>,
+    expected_version: i32,
+) -> Result<bool, AppError> {
+    timed_query!("persona_triggers", "persona_triggers::mark_email_polled", {
+        let now = chrono::Utc::now().to_rfc3339();
+        let conn = pool.get()?;
+        let rows = conn.execute(
+            "UPDATE persona_triggers
+             SET config = json_set(COALESCE(config, '{}'), '$.cursor', json(?1)),
+                 last_triggered_at = ?2, next_trigger_at = ?3, updated_at = ?2,
+                 trigger_version = trigger_version + 1
+             WHERE id = ?4 AND trigger_version = ?5",
+            params![cursor_json, now, next_trigger_at, id, expected_version],
+        )?;
+        Ok(rows > 0)
+    })
+}
+
 /// Set the `enabled` flag on a trigger. Used as a safety valve to disable
 /// triggers that fail to mark as triggered, preventing cascade re-fire loops.
 /// Also updates the `status` column to stay in sync.
@@ -1892,6 +1920,16 @@ pub struct ScheduleMissedRuns {
     /// Human-facing detail for `status_reason` (e.g. the offending timezone +
     /// parser error). Not translated — a diagnostic string.
     pub status_reason_detail: Option<String>,
+    /// Catch-up report: the trigger's `catch_up` policy the last time the
+    /// scheduler found a missed-fire gap. `None` when no report is stored.
+    pub catch_up_policy: Option<CatchUpPolicy>,
+    pub catch_up_at: Option<String>,
+    /// Fire times of the runs the catch-up started: replayed slots first, then
+    /// the catch-up fire itself. Empty for `skip`.
+    pub catch_up_fired: Vec<String>,
+    /// Missed slots the catch-up dropped.
+    #[ts(type = "number")]
+    pub catch_up_skipped: i64,
 }
 
 /// Accumulate `delta` discarded slots for a trigger. Idempotent-friendly:
@@ -1924,17 +1962,50 @@ pub fn record_missed_runs(
     })
 }
 
-/// List every trigger with a non-zero discarded-while-offline count, for the
-/// schedule UI's "missed N while offline" badge.
+/// Store the report of a missed-schedule catch-up, replacing the previous one.
+/// `fired` holds the fire times of the runs it started; `skipped` the missed
+/// slots it dropped.
+pub fn record_catch_up(
+    pool: &DbPool,
+    trigger_id: &str,
+    policy: CatchUpPolicy,
+    fired: &[String],
+    skipped: i64,
+    now: &str,
+) -> Result<(), AppError> {
+    timed_query!("schedule_missed_runs", "schedule_missed_runs::catch_up", {
+        let fired_json = serde_json::to_string(fired).unwrap_or_else(|_| "[]".into());
+        let conn = pool.get()?;
+        conn.execute(
+            "INSERT INTO schedule_missed_runs
+                 (trigger_id, catch_up_policy, catch_up_at, catch_up_fired, catch_up_skipped,
+                  updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?3)
+             ON CONFLICT(trigger_id) DO UPDATE SET
+                 catch_up_policy  = excluded.catch_up_policy,
+                 catch_up_at      = excluded.catch_up_at,
+                 catch_up_fired   = excluded.catch_up_fired,
+                 catch_up_skipped = excluded.catch_up_skipped,
+                 updated_at       = excluded.updated_at",
+            params![trigger_id, policy.as_str(), now, fired_json, skipped],
+        )?;
+        Ok(())
+    })
+}
+
+/// List every trigger with a non-zero discarded-while-offline count, a pause
+/// reason or a catch-up report, for the schedule UI's "missed N while
+/// offline" badge.
 pub fn list_missed_runs(pool: &DbPool) -> Result<Vec<ScheduleMissedRuns>, AppError> {
     timed_query!("schedule_missed_runs", "schedule_missed_runs::list", {
         let conn = pool.get()?;
         let mut stmt = conn.prepare_cached(
             "SELECT trigger_id, missed_count, first_missed_at, last_missed_at,
-                    status_reason, status_reason_detail
+                    status_reason, status_reason_detail,
+                    catch_up_policy, catch_up_at, catch_up_fired, catch_up_skipped
              FROM schedule_missed_runs
-             WHERE missed_count > 0 OR status_reason IS NOT NULL
-             ORDER BY last_missed_at DESC",
+             WHERE missed_count > 0 OR status_reason IS NOT NULL OR catch_up_at IS NOT NULL
+             ORDER BY COALESCE(last_missed_at, catch_up_at) DESC",
         )?;
         let rows = stmt.query_map([], |row| {
             Ok(ScheduleMissedRuns {
@@ -1944,14 +2015,25 @@ pub fn list_missed_runs(pool: &DbPool) -> Result<Vec<ScheduleMissedRuns>, AppErr
                 last_missed_at: row.get(3)?,
                 status_reason: row.get(4)?,
                 status_reason_detail: row.get(5)?,
+                catch_up_policy: row
+                    .get::<_, Option<String>>(6)?
+                    .as_deref()
+                    .and_then(CatchUpPolicy::parse),
+                catch_up_at: row.get(7)?,
+                catch_up_fired: row
+                    .get::<_, Option<String>>(8)?
+                    .and_then(|json| serde_json::from_str(&json).ok())
+                    .unwrap_or_default(),
+                catch_up_skipped: row.get(9)?,
             })
         })?;
         rows.collect::<Result<Vec<_>, _>>().map_err(AppError::Database)
     })
 }
 
-/// Clear a trigger's discarded-while-offline count after the user backfills or
-/// dismisses it. Idempotent — clearing an absent row is a no-op.
+/// Clear a trigger's discarded-while-offline count and catch-up report after
+/// the user backfills or dismisses it. Idempotent — clearing an absent row is
+/// a no-op.
 ///
 /// Preserves any `status_reason` (a bad-timezone pause is independent of the
 /// missed-count badge): the row is deleted only when no reason remains.
@@ -1961,7 +2043,8 @@ pub fn clear_missed_runs(pool: &DbPool, trigger_id: &str) -> Result<(), AppError
         conn.execute(
             "UPDATE schedule_missed_runs
              SET missed_count = 0, first_missed_at = NULL, last_missed_at = NULL,
-                 updated_at = ?2
+                 catch_up_policy = NULL, catch_up_at = NULL, catch_up_fired = NULL,
+                 catch_up_skipped = 0, updated_at = ?2
              WHERE trigger_id = ?1",
             params![trigger_id, chrono::Utc::now().to_rfc3339()],
         )?;
@@ -2005,7 +2088,8 @@ pub fn set_schedule_status_reason(
 }
 
 /// Direction 3: clear a schedule's pause reason once it is healthy again (e.g.
-/// the timezone was corrected). Deletes the row if no missed-count remains.
+/// the timezone was corrected). Deletes the row if no missed-count or catch-up
+/// report remains.
 pub fn clear_schedule_status_reason(pool: &DbPool, trigger_id: &str) -> Result<(), AppError> {
     timed_query!(
         "schedule_missed_runs",
@@ -2020,7 +2104,8 @@ pub fn clear_schedule_status_reason(pool: &DbPool, trigger_id: &str) -> Result<(
             )?;
             conn.execute(
                 "DELETE FROM schedule_missed_runs
-                 WHERE trigger_id = ?1 AND missed_count = 0 AND status_reason IS NULL",
+                 WHERE trigger_id = ?1 AND missed_count = 0 AND status_reason IS NULL
+                   AND catch_up_at IS NULL",
                 params![trigger_id],
             )?;
             Ok(())
@@ -2115,6 +2200,7 @@ mod tests {
                 "schedule" => Decision::Auto,
                 "polling" => Decision::Auto,
                 "webhook" => Decision::Auto,
+                "email_inbound" => Decision::Auto,
 
                 // No listener — each carries an explicit reason:
                 "manual" => {
@@ -2401,6 +2487,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mark_email_polled_stores_cursor_once() {
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona(&pool);
+
+        let trigger = create(
+            &pool,
+            CreateTriggerInput {
+                persona_id: persona.id.clone(),
+                trigger_type: "email_inbound".into(),
+                config: Some(r#"{"credential_id":"cred-1","interval_seconds":120}"#.into()),
+                enabled: Some(true),
+                use_case_id: None,
+            },
+        )
+        .unwrap();
+        assert!(trigger.next_trigger_at.is_some());
+
+        let cursor = r#"{"uid_validity":7,"last_uid":42}"#;
+        let version = trigger.trigger_version;
+        assert!(mark_email_polled(&pool, &trigger.id, cursor, None, version).unwrap());
+        // A racing cycle holding the old version must not advance it again.
+        assert!(!mark_email_polled(&pool, &trigger.id, cursor, None, version).unwrap());
+
+        let stored = get_by_persona_id(&pool, &persona.id)
+            .unwrap()
+            .into_iter()
+            .find(|t| t.id == trigger.id)
+            .unwrap();
+        match stored.parse_config() {
+            crate::db::models::TriggerConfig::EmailInbound {
+                credential_id,
+                cursor,
+                ..
+            } => {
+                assert_eq!(credential_id.as_deref(), Some("cred-1"));
+                assert_eq!(
+                    cursor,
+                    Some(serde_json::json!({"uid_validity": 7, "last_uid": 42}))
+                );
+            }
+            other => panic!("Expected EmailInbound, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_create_manual_trigger_next_trigger_at_is_null() {
         let pool = init_test_db().unwrap();
@@ -3356,6 +3487,40 @@ mod tests {
         assert!(!event_type_in_use(&pool, "totally.unseen.event").unwrap());
     }
 
+    #[test]
+    fn test_catch_up_report_round_trips_and_clears() {
+        let pool = init_test_db().unwrap();
+        let now = "2026-01-05T09:00:00+00:00";
+        let fired = vec!["2026-01-04T09:00:00+00:00".to_string(), now.to_string()];
+        record_missed_runs(&pool, "t-catch-up", 1, now).unwrap();
+        record_catch_up(
+            &pool,
+            "t-catch-up",
+            CatchUpPolicy::RunAllMissed,
+            &fired,
+            1,
+            now,
+        )
+        .unwrap();
+
+        let rows = list_missed_runs(&pool).unwrap();
+        let row = rows.iter().find(|r| r.trigger_id == "t-catch-up").unwrap();
+        assert_eq!(row.catch_up_policy, Some(CatchUpPolicy::RunAllMissed));
+        assert_eq!(row.catch_up_fired, fired);
+        assert_eq!((row.missed_count, row.catch_up_skipped), (1, 1));
+
+        // A report alone (no missed count) is still listed.
+        record_catch_up(&pool, "t-skip", CatchUpPolicy::Skip, &[], 0, now).unwrap();
+        assert!(list_missed_runs(&pool)
+            .unwrap()
+            .iter()
+            .any(|r| r.trigger_id == "t-skip" && r.catch_up_fired.is_empty()));
+
+        clear_missed_runs(&pool, "t-catch-up").unwrap();
+        clear_missed_runs(&pool, "t-skip").unwrap();
+        assert!(list_missed_runs(&pool).unwrap().is_empty());
+    }
+
     // Placate the "unused import" lint for helpers used only by specific tests.
     #[allow(dead_code)]
     fn _seed_handler_keeper(pool: &DbPool, persona_id: &str) {
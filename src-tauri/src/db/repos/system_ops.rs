@@ -153,3 +153,14 @@ pub fn list_enabled_event_automations(pool: &DbPool) -> Result<Vec<SystemOpAutom
     let rows = stmt.query_map([], row_to_automation)?;
     rows.collect::<Result<Vec<_>, _>>().map_err(AppError::Database)
 }
+
+/// All enabled chain automations (matched against finished executions).
+pub fn list_enabled_chain_automations(pool: &DbPool) -> Result<Vec<SystemOpAutomation>, AppError> {
+    let conn = pool.get()?;
+    let mut stmt = conn.prepare(
+        "SELECT * FROM system_op_automations
+          WHERE trigger_kind = 'chain' AND enabled = 1 AND source_filter IS NOT NULL",
+    )?;
+    let rows = stmt.query_map([], row_to_automation)?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(AppError::Database)
+}
@@ -27,6 +27,10 @@
 /// Ollama Cloud API key (free tier models like Qwen3, GLM-5, Kimi K2.5).
 pub const OLLAMA_API_KEY: &str = "ollama_api_key";
 
+/// Ollama server for `provider = "ollama"` personas and model discovery
+/// (default `http://localhost:11434`).
+pub const OLLAMA_BASE_URL: &str = "ollama_base_url";
+
 /// LiteLLM proxy base URL (e.g., `http://localhost:4000`).
 pub const LITELLM_BASE_URL: &str = "litellm_base_url";
 
@@ -45,6 +49,15 @@ pub const QWEN_MODEL: &str = "qwen_model";
 /// to the remote provider. Phase 3b-connectors.
 pub const QWEN_CONNECTOR_TOOLS: &str = "qwen_connector_tools";
 
+/// Base URL for the generic OpenAI-compatible backend (`provider = "openai_api"`),
+/// e.g. `https://openrouter.ai/api/v1` or `http://localhost:11434/v1`.
+/// Defaults to the OpenAI API when unset.
+pub const OPENAI_API_BASE_URL: &str = "openai_api_base_url";
+
+/// API key for the generic OpenAI-compatible backend. Optional for loopback
+/// endpoints; falls back to `OPENAI_API_KEY` / `OPENROUTER_API_KEY` env.
+pub const OPENAI_API_KEY: &str = "openai_api_key";
+
 /// Athena autonomous wake window in minutes (docs/plans/athena-wake-window.md).
 /// 0 / unset = reactive (every tick); 30/60/120 = signals accumulate until the
 /// surface's last wake is older than the window (queue-size and priority
@@ -62,6 +75,12 @@ pub const DELEGATE_BASE_URL: &str = "delegate_base_url";
 /// Active CLI engine: `"claude_code"` or `"codex_cli"`.
 pub const CLI_ENGINE: &str = "cli_engine";
 
+/// Ordered provider failover chain, comma-separated (e.g.
+/// `"claude_code,openai_api,ollama"`). After the persona's own engine fails
+/// to spawn or is breaker-blocked, the runner tries the listed CLI engines
+/// and then the listed HTTP backends. See `engine::failover::FailoverTarget`.
+pub const PROVIDER_FAILOVER_CHAIN: &str = "provider_failover_chain";
+
 /// Browser-bridge pairing token — the secret the Athena Browser Bridge
 /// extension presents on its WebSocket handshake. Persisted so the extension
 /// pairs once and survives app restarts; regenerated from the Companion
@@ -98,6 +117,24 @@ pub const DRAFT_RETENTION_DAYS: &str = "draft_retention_days";
 /// Default draft retention in days. `0` means the sweep is disabled (opt-in).
 pub const DRAFT_RETENTION_DAYS_DEFAULT: i64 = 0;
 
+/// Cap on *terminal* executions kept per persona, independent of age. The
+/// newest N survive; `0` = no cap (age-based retention only). Execution log
+/// files are removed along with their rows.
+pub const EXECUTION_RETENTION_MAX_PER_PERSONA: &str = "execution_retention_max_per_persona";
+/// Default per-persona execution cap. `0` means uncapped.
+pub const EXECUTION_RETENTION_MAX_PER_PERSONA_DEFAULT: i64 = 0;
+
+/// Read-message retention in days. Read `persona_messages` older than this are
+/// pruned; unread messages are always kept. `0` disables age-based pruning.
+pub const MESSAGE_RETENTION_DAYS: &str = "message_retention_days";
+/// Default read-message retention in days.
+pub const MESSAGE_RETENTION_DAYS_DEFAULT: i64 = 90;
+
+/// Cap on *read* messages kept per persona, independent of age. `0` = no cap.
+pub const MESSAGE_RETENTION_MAX_PER_PERSONA: &str = "message_retention_max_per_persona";
+/// Default per-persona read-message cap. `0` means uncapped.
+pub const MESSAGE_RETENTION_MAX_PER_PERSONA_DEFAULT: i64 = 0;
+
 /// Per-persona ceiling for scheduled executions in a rolling hour.
 pub const SCHEDULE_EXECUTIONS_PER_PERSONA_HOUR: &str = "schedule_executions_per_persona_hour";
 /// Default per-persona hourly ceiling for scheduled executions.
@@ -116,6 +153,54 @@ pub const EXECUTION_RETENTION_MONTHS_PREFIX: &str = "execution_retention_months:
 /// prompt version's error rate exceeds 2x the previous version's rate.
 pub const AUTO_ROLLBACK_PREFIX: &str = "auto_rollback:";
 
+/// Per-persona automatic memory extraction prefix. The full key is
+/// `memory_extraction:<persona_id>`, with value `"true"` or `"false"`.
+/// When enabled, completed runs are mined for durable facts which are stored
+/// as memories (see `engine::memory_extraction`).
+pub const MEMORY_EXTRACTION_PREFIX: &str = "memory_extraction:";
+
+/// Per-persona root-cause analysis prefix. The full key is
+/// `healing_root_cause:<persona_id>`, with value `"true"` or `"false"`.
+/// When enabled, each new execution-failure healing issue gets a model-written
+/// root-cause summary (see `engine::healing_root_cause`).
+pub const HEALING_ROOT_CAUSE_PREFIX: &str = "healing_root_cause:";
+
+/// Per-persona experimental engine flags prefix. The full key is
+/// `engine_flags:<persona_id>`, with a comma-separated list of flag names
+/// (see `engine::feature_flags`).
+pub const ENGINE_FLAGS_PREFIX: &str = "engine_flags:";
+
+/// Per-persona oversized-input handling prefix. The full key is
+/// `input_overflow:<persona_id>`, with value `"reject"` (the default) or
+/// `"chunk"` — condense the input with a map-reduce summarization pre-pass
+/// before the run (see `engine::input_budget`).
+pub const INPUT_OVERFLOW_PREFIX: &str = "input_overflow:";
+
+/// Per-persona provider failover chain prefix. The full key is
+/// `provider_failover_chain:<persona_id>`; overrides
+/// [`PROVIDER_FAILOVER_CHAIN`] for that persona.
+pub const PROVIDER_FAILOVER_CHAIN_PREFIX: &str = "provider_failover_chain:";
+
+/// Per-persona output contract prefix. The full key is
+/// `output_contract:<persona_id>`, with value JSON
+/// `{"schema":{...},"on_violation":"repair"|"fail"}` — a JSON Schema the run's
+/// final output is validated against (see `engine::output_contract`).
+pub const OUTPUT_CONTRACT_PREFIX: &str = "output_contract:";
+
+/// Per-persona container sandbox prefix. The full key is
+/// `execution_sandbox:<persona_id>`, with value JSON
+/// `{"image":"...","runtime":"auto","network":"bridge","fallback":"host"}` —
+/// runs the persona's CLI inside a Docker/Podman container (see
+/// `engine::container_sandbox`).
+pub const EXECUTION_SANDBOX_PREFIX: &str = "execution_sandbox:";
+
+/// Per-connector shared rate limit prefix. The full key is
+/// `connector_rate_limit:<connector_name>`, with value requests per minute
+/// shared by every credential and persona using that connector (`0` turns
+/// off a limit set in the connector's metadata). See
+/// `engine::connector_rate_limit`.
+pub const CONNECTOR_RATE_LIMIT_PREFIX: &str = "connector_rate_limit:";
+
 /// Global default model profile (JSON-encoded ModelProfile).
 /// Used as the lowest-priority fallback in the hierarchical config cascade:
 /// global → workspace → agent.
@@ -188,6 +273,19 @@ pub const BYOM_POLICY: &str = "byom_policy";
 /// Must match `engine::model_routing::MODEL_ROUTING_RULES_KEY` — asserted by test.
 pub const MODEL_ROUTING_RULES: &str = "model_routing_rules";
 
+/// Operator model deprecations (JSON-encoded Vec<ModelDeprecation>). Override
+/// the fetched and builtin entries in `engine::model_registry`.
+pub const MODEL_DEPRECATIONS: &str = "model_deprecations";
+
+/// Last successfully fetched published deprecation list (same shape as
+/// [`MODEL_DEPRECATIONS`]), written by `refresh_model_deprecations`.
+pub const MODEL_DEPRECATIONS_REMOTE: &str = "model_deprecations_remote";
+
+/// Per-provider cost correction factors (JSON object, e.g. `{"anthropic": 1.08}`)
+/// applied to recorded execution costs and previews. Written by
+/// `reconcile_provider_usage` from imported usage exports.
+pub const COST_CORRECTION_FACTORS: &str = "cost_correction_factors";
+
 /// GitLab pipeline notification preferences (JSON-encoded).
 pub const GITLAB_PIPELINE_NOTIFICATION_PREFS: &str = "gitlab_pipeline_notification_prefs";
 
@@ -332,6 +430,23 @@ pub const MONTHLY_COST_CEILING_USD: &str = "monthly_cost_ceiling_usd";
 /// Default monthly cost ceiling in USD. `0.0` means no ceiling.
 pub const MONTHLY_COST_CEILING_USD_DEFAULT: f64 = 0.0;
 
+/// What happens when a persona reaches its monthly `max_budget_usd`:
+///   • `"warn"` — runs keep going; a budget alert is posted once per month.
+///   • `"block"` (default) — new executions are refused at admission until the
+///     month rolls over or the cap is raised. Matches the pre-existing gate.
+///   • `"pause"` — as `block`, and the persona is also disabled so triggers
+///     stop firing. The month-boundary reset re-enables personas it paused.
+/// Read by `engine::budget`. Stored as the mode string; unknown → default.
+pub const BUDGET_ENFORCEMENT_MODE: &str = "budget_enforcement_mode";
+/// Default for [`BUDGET_ENFORCEMENT_MODE`].
+pub const BUDGET_ENFORCEMENT_MODE_DEFAULT: &str = "block";
+
+/// The UTC billing period (`YYYY-MM`) the budget month-boundary reset last ran
+/// for. When the current month differs, `engine::budget::monthly_reset_tick`
+/// resumes budget-paused personas and clears last month's alert state.
+/// Free-form value (no typed validation).
+pub const BUDGET_PERIOD_LAST_RESET: &str = "budget_period_last_reset";
+
 /// Whether the autonomous goal-advancement tick may, unattended, turn a
 /// goal-linked team's active goal into a running `team_assignment`. Default OFF
 /// — nothing spends tokens autonomously until the user opts in from Settings.
@@ -530,6 +645,36 @@ pub const MAX_PARALLEL_EXECUTIONS_MIN: usize = 1;
 /// per slot), so keep this aligned with `STRIP_SLOTS` in fleetStripModel.ts.
 pub const MAX_PARALLEL_EXECUTIONS_MAX: usize = 20;
 
+/// How long (seconds) an execution may sit in the admission queue before the
+/// queue-age monitor (`engine::queue_monitor`) warns about it with a persona
+/// message + healing issue naming the runs that block it. `0` disables the
+/// monitor. Stored as a non-negative integer string.
+pub const QUEUE_STUCK_WAIT_SECS: &str = "queue_stuck_wait_secs";
+/// Default for [`QUEUE_STUCK_WAIT_SECS`] — 10 minutes.
+pub const QUEUE_STUCK_WAIT_SECS_DEFAULT: u64 = 600;
+
+/// Comma-separated tool names whose calls need human approval before they run
+/// (`engine::tool_approval`), e.g. `gmail_send,file_write`. A persona run that
+/// reaches one pauses on a manual review and resumes once it is approved.
+/// Applies to every persona, on top of tools flagged `requires_approval` in
+/// their `tool_config`. Empty (the default) gates nothing.
+pub const TOOL_APPROVAL_TOOLS: &str = "tool_approval_tools";
+
+/// Size of the main SQLite connection pool (`db::init_db`). Read straight
+/// from the database file before the pool exists, so a change takes effect on
+/// the next launch. Background loops get the pool minus
+/// `db::pool_budget::INTERACTIVE_RESERVED` connections. Stored as an integer
+/// between [`DB_POOL_SIZE_MIN`] and [`DB_POOL_SIZE_MAX`].
+pub const DB_POOL_SIZE: &str = "db_pool_size";
+/// Default for [`DB_POOL_SIZE`].
+pub const DB_POOL_SIZE_DEFAULT: u32 = 12;
+/// Smallest pool that still leaves background loops a connection after the
+/// interactive reservation.
+pub const DB_POOL_SIZE_MIN: u32 = 4;
+/// SQLite serializes writers anyway; past this, extra connections only add
+/// memory and WAL reader slots.
+pub const DB_POOL_SIZE_MAX: u32 = 64;
+
 /// Whether each team-member persona execution runs inside its own per-execution
 /// git worktree (on branch `personas/exec/<execution_id>`) instead of the shared
 /// per-persona scratch dir. Default OFF — opt-in only, because it mutates the
@@ -558,10 +703,59 @@ pub const CLOUD_SYNC_LAST_AT: &str = "cloud_sync_last_at";
 /// Surfaced in the Settings sync panel. Value: a non-negative integer string.
 pub const CLOUD_SYNC_TOTAL_ROWS: &str = "cloud_sync_total_rows";
 
+/// Comma-separated remote actions the cloud dashboard may send to this device
+/// (`cloud::remote_commands::RemoteAction`): `run_persona`, `pause_scheduler`,
+/// `resume_scheduler`, `fetch_status`. Runs still need approval on the
+/// desktop; the other actions are carried out as soon as they arrive. Empty
+/// disables the channel. Default: [`REMOTE_ACTIONS_ALLOWED_DEFAULT`].
+pub const REMOTE_ACTIONS_ALLOWED: &str = "remote_actions_allowed";
+/// Default for [`REMOTE_ACTIONS_ALLOWED`] — approval-gated runs only.
+pub const REMOTE_ACTIONS_ALLOWED_DEFAULT: &str = "run_persona";
+
+/// Comma-separated notification categories relayed to the user's phone through
+/// the cloud orchestrator's push service (`cloud::push::PushCategory`):
+/// `budget_exceeded`, `circuit_breaker`, `pending_approval`. Only applies while
+/// connected to the orchestrator. Default: empty — nothing leaves the device
+/// until the user opts a category in.
+pub const CLOUD_PUSH_CATEGORIES: &str = "cloud_push_categories";
+
 /// Per-table incremental sync watermark. Full key: `cloud_sync_cursor:<table>`
 /// (e.g. `cloud_sync_cursor:executions`), value: RFC3339 timestamp.
 pub const CLOUD_SYNC_CURSOR_PREFIX: &str = "cloud_sync_cursor:";
 
+/// Whether this install takes part in anonymous fleet learning: uploads its
+/// healing backoff patterns (built-in service name, pattern key, learned delay,
+/// occurrence count — never prompts, payloads, or error text) and downloads the
+/// aggregated recommendations other installs contributed. Default OFF — the
+/// user opts in from Settings. Read by `engine::fleet_learning`. Stored
+/// `"true"` / `"false"`.
+pub const FLEET_PATTERN_SHARING_ENABLED: &str = "fleet_pattern_sharing_enabled";
+/// Default for [`FLEET_PATTERN_SHARING_ENABLED`] — off (opt-in).
+pub const FLEET_PATTERN_SHARING_ENABLED_DEFAULT: bool = false;
+
+/// RFC3339 timestamp of the last successful fleet pattern exchange. Gates the
+/// daily cadence in `engine::fleet_learning`. Free-form value (no typed validation).
+pub const FLEET_PATTERN_SHARING_LAST: &str = "fleet_pattern_sharing_last";
+
+/// Whether the background loop snapshots `personas.db` into
+/// `<data_dir>/backups/` on a schedule (see `db::backup::run_auto_backup`).
+/// Default OFF — manual `backup_database` works regardless. Stored
+/// `"true"` / `"false"`.
+pub const AUTO_BACKUP_ENABLED: &str = "auto_backup_enabled";
+/// Default for [`AUTO_BACKUP_ENABLED`] — off (opt-in).
+pub const AUTO_BACKUP_ENABLED_DEFAULT: bool = false;
+
+/// Hours between scheduled backups when [`AUTO_BACKUP_ENABLED`] is on.
+/// Integer in `1..=720`.
+pub const AUTO_BACKUP_INTERVAL_HOURS: &str = "auto_backup_interval_hours";
+/// Default for [`AUTO_BACKUP_INTERVAL_HOURS`] — daily.
+pub const AUTO_BACKUP_INTERVAL_HOURS_DEFAULT: u32 = 24;
+/// Upper bound for [`AUTO_BACKUP_INTERVAL_HOURS`] (30 days).
+pub const AUTO_BACKUP_INTERVAL_HOURS_MAX: u32 = 720;
+
+/// RFC3339 timestamp of the last scheduled backup. Free-form bookkeeping.
+pub const AUTO_BACKUP_LAST: &str = "auto_backup_last";
+
 /// Per-project **autopilot** mode. Full key: `autopilot_mode:<project_id>`,
 /// value ∈ {`off`, `measure`, `suggest`, `full`}. Owns whether that project's
 /// KPI → goal → team loop runs unattended (see `engine/autopilot.rs`). Absent =
@@ -645,9 +839,98 @@ pub const SCRATCHPAD_ENABLED: &str = "scratchpad_enabled";
 /// Default for [`SCRATCHPAD_ENABLED`] — ON (the scratchpad ships enabled).
 pub const SCRATCHPAD_ENABLED_DEFAULT: bool = true;
 
+/// Minimum task similarity (`0.0`–`1.0`) a memory needs before embedding recall
+/// treats it as relevant. Below it a memory gets no relevance lift, and in
+/// top-k mode ([`MEMORY_RECALL_TOP_K`]) it is not injected at all.
+pub const MEMORY_SIMILARITY_THRESHOLD: &str = "memory_similarity_threshold";
+/// Default for [`MEMORY_SIMILARITY_THRESHOLD`] — only the shared distance floor applies.
+pub const MEMORY_SIMILARITY_THRESHOLD_DEFAULT: f64 = 0.0;
+
+/// Inject only the `k` most task-relevant active memories instead of
+/// budget-packing every candidate. `0` keeps the blended value/relevance pack.
+/// Core memories are always injected either way.
+pub const MEMORY_RECALL_TOP_K: &str = "memory_recall_top_k";
+/// Default for [`MEMORY_RECALL_TOP_K`] — off.
+pub const MEMORY_RECALL_TOP_K_DEFAULT: i64 = 0;
+
+/// Maximum number of idle pre-spawned CLI sessions the engine keeps warm for
+/// frequently-run personas (see [`crate::engine::warm_pool`]). Each one is a
+/// live CLI process waiting on stdin, so this is a memory-for-latency trade.
+/// `0` turns the pool off.
+pub const ENGINE_WARM_POOL_SIZE: &str = "engine_warm_pool_size";
+/// Default for [`ENGINE_WARM_POOL_SIZE`] — off.
+pub const ENGINE_WARM_POOL_SIZE_DEFAULT: u32 = 0;
+/// Upper bound for [`ENGINE_WARM_POOL_SIZE`].
+pub const ENGINE_WARM_POOL_SIZE_MAX: u32 = 8;
+
+/// Estimated token size above which an execution's input data counts as
+/// oversized and is rejected or chunked per [`INPUT_OVERFLOW_PREFIX`].
+/// `0` turns the check off.
+pub const INPUT_TOKEN_LIMIT: &str = "input_token_limit";
+/// Default for [`INPUT_TOKEN_LIMIT`].
+pub const INPUT_TOKEN_LIMIT_DEFAULT: u64 = 100_000;
+
+/// Size in bytes above which an execution's output is stored as a gzip file
+/// in the log directory instead of inline in `persona_executions`; the row
+/// keeps a preview plus a pointer. `0` keeps every output inline.
+pub const EXECUTION_OUTPUT_OFFLOAD_BYTES: &str = "execution_output_offload_bytes";
+/// Default for [`EXECUTION_OUTPUT_OFFLOAD_BYTES`] — 256 KiB.
+pub const EXECUTION_OUTPUT_OFFLOAD_BYTES_DEFAULT: u64 = 256 * 1024;
+
+/// Observer mode for screen sharing (JSON-encoded ObserverModeConfig).
+/// Must match `engine::observer_mode::OBSERVER_MODE_KEY` — asserted by test.
+pub const OBSERVER_MODE: &str = "observer_mode";
+
+/// Whether the weekly fleet hygiene review runs (see `engine::fleet_hygiene`).
+/// Stored `"true"` / `"false"`; `run_fleet_hygiene_now` works regardless.
+pub const FLEET_HYGIENE_ENABLED: &str = "fleet_hygiene_enabled";
+/// Default for [`FLEET_HYGIENE_ENABLED`] — on. The review only suggests.
+pub const FLEET_HYGIENE_ENABLED_DEFAULT: bool = true;
+
+/// Weeks without activity after which a persona or credential is flagged by
+/// the fleet hygiene review. Integer in `1..=52`.
+pub const FLEET_HYGIENE_IDLE_WEEKS: &str = "fleet_hygiene_idle_weeks";
+/// Default for [`FLEET_HYGIENE_IDLE_WEEKS`].
+pub const FLEET_HYGIENE_IDLE_WEEKS_DEFAULT: u32 = 4;
+/// Upper bound for [`FLEET_HYGIENE_IDLE_WEEKS`] (a year).
+pub const FLEET_HYGIENE_IDLE_WEEKS_MAX: u32 = 52;
+
+/// RFC3339 timestamp of the last scheduled fleet hygiene review. Free-form bookkeeping.
+pub const FLEET_HYGIENE_LAST: &str = "fleet_hygiene_last";
+
+/// Days before a credential's `expires_at` that the rotation loop raises a
+/// renewal alert. Integer in `1..=90`.
+pub const CREDENTIAL_EXPIRY_WARNING_DAYS: &str = "credential_expiry_warning_days";
+/// Default for [`CREDENTIAL_EXPIRY_WARNING_DAYS`].
+pub const CREDENTIAL_EXPIRY_WARNING_DAYS_DEFAULT: u32 = 14;
+/// Upper bound for [`CREDENTIAL_EXPIRY_WARNING_DAYS`].
+pub const CREDENTIAL_EXPIRY_WARNING_DAYS_MAX: u32 = 90;
+
+/// Default prompt-injection policy for inbound event payloads, used when the
+/// firing trigger's config has no `injection_policy`. One of
+/// `off|flag|sanitize|block|require_approval` (see `engine::injection_scan`).
+pub const INJECTION_SCAN_POLICY: &str = "injection_scan_policy";
+/// Default for [`INJECTION_SCAN_POLICY`] — record detections, change nothing.
+pub const INJECTION_SCAN_POLICY_DEFAULT: &str = "flag";
+
+/// Whether ambiguous injection-scan hits are confirmed by a one-shot LLM
+/// classifier before the policy acts. Stored `"true"` / `"false"`.
+pub const INJECTION_SCAN_LLM_CHECK: &str = "injection_scan_llm_check";
+
+/// Global network policy for every persona's HTTP calls: JSON
+/// `engine::tool_policy::NetworkPolicy` (domain allow/deny lists, allowed
+/// methods, `allow_private_network`). Unset denies private addresses only.
+pub const NETWORK_POLICY: &str = "network_policy";
+
+/// Where the credential master key is stored: JSON
+/// `engine::vault_backend::VaultBackendConfig`. Written only through
+/// `configure_vault_backend`, which copies the key first; unset = keychain.
+pub const VAULT_BACKEND: &str = "vault_backend";
+
 /// Exact keys allowed in the settings store.
 const ALLOWED_KEYS: &[&str] = &[
     OLLAMA_API_KEY,
+    OLLAMA_BASE_URL,
     DELEGATE_MODEL,
     ATHENA_WAKE_WINDOW_MINUTES,
     DELEGATE_BASE_URL,
@@ -656,12 +939,18 @@ const ALLOWED_KEYS: &[&str] = &[
     QWEN_BASE_URL,
     QWEN_MODEL,
     QWEN_CONNECTOR_TOOLS,
+    OPENAI_API_BASE_URL,
+    OPENAI_API_KEY,
     CLI_ENGINE,
+    PROVIDER_FAILOVER_CHAIN,
     BROWSER_BRIDGE_PAIRING_TOKEN,
     EVENT_RETENTION_DAYS,
     EVENT_RETENTION_MAX_COUNT,
     EXECUTION_RETENTION_DAYS,
     DRAFT_RETENTION_DAYS,
+    EXECUTION_RETENTION_MAX_PER_PERSONA,
+    MESSAGE_RETENTION_DAYS,
+    MESSAGE_RETENTION_MAX_PER_PERSONA,
     SCHEDULE_EXECUTIONS_PER_PERSONA_HOUR,
     GLOBAL_MODEL_PROFILE,
     FILE_WATCHER_DEBOUNCE_MS,
@@ -677,6 +966,9 @@ const ALLOWED_KEYS: &[&str] = &[
     ENGINE_CAPABILITIES,
     BYOM_POLICY,
     MODEL_ROUTING_RULES,
+    MODEL_DEPRECATIONS,
+    MODEL_DEPRECATIONS_REMOTE,
+    COST_CORRECTION_FACTORS,
     GITLAB_PIPELINE_NOTIFICATION_PREFS,
     OBSIDIAN_BRAIN_CONFIG,
     OBSIDIAN_MIRROR_CONFIG,
@@ -695,6 +987,8 @@ const ALLOWED_KEYS: &[&str] = &[
     COMPANION_MSG_TRIAGE_CURSOR,
     DIRECTOR_BRAIN_ENABLED,
     MONTHLY_COST_CEILING_USD,
+    BUDGET_ENFORCEMENT_MODE,
+    BUDGET_PERIOD_LAST_RESET,
     AUTONOMOUS_GOAL_ADVANCEMENT,
     COMPANION_DAILY_ROLLUP,
     COMPANION_DAILY_ROLLUP_HOUR,
@@ -716,17 +1010,41 @@ const ALLOWED_KEYS: &[&str] = &[
     // write and the autonomous-deliberation toggle could never be enabled.
     AUTONOMOUS_DELIBERATION,
     MAX_PARALLEL_EXECUTIONS,
+    QUEUE_STUCK_WAIT_SECS,
+    TOOL_APPROVAL_TOOLS,
+    DB_POOL_SIZE,
     EXECUTION_WORKTREE_ISOLATION,
     CLOUD_SYNC_ENABLED,
     CLOUD_SYNC_DEVICE_ID,
     CLOUD_SYNC_LAST_AT,
     CLOUD_SYNC_TOTAL_ROWS,
+    REMOTE_ACTIONS_ALLOWED,
+    CLOUD_PUSH_CATEGORIES,
+    FLEET_PATTERN_SHARING_ENABLED,
+    FLEET_PATTERN_SHARING_LAST,
+    AUTO_BACKUP_ENABLED,
+    AUTO_BACKUP_INTERVAL_HOURS,
+    AUTO_BACKUP_LAST,
     APPEARANCE_PREFERENCES,
     APP_LANGUAGE,
     CHAIN_MAX_COST_USD,
     CHAIN_MAX_LINKS,
     SCRATCHPAD_ENABLED,
     SKILLS_SIDECAR_ENABLED,
+    MEMORY_SIMILARITY_THRESHOLD,
+    MEMORY_RECALL_TOP_K,
+    ENGINE_WARM_POOL_SIZE,
+    INPUT_TOKEN_LIMIT,
+    EXECUTION_OUTPUT_OFFLOAD_BYTES,
+    OBSERVER_MODE,
+    FLEET_HYGIENE_ENABLED,
+    FLEET_HYGIENE_IDLE_WEEKS,
+    FLEET_HYGIENE_LAST,
+    CREDENTIAL_EXPIRY_WARNING_DAYS,
+    INJECTION_SCAN_POLICY,
+    INJECTION_SCAN_LLM_CHECK,
+    NETWORK_POLICY,
+    VAULT_BACKEND,
 ];
 
 /// Prefix patterns for per-persona dynamic keys (e.g. `auto_rollback:<persona_id>`).
@@ -745,7 +1063,14 @@ const ALLOWED_PREFIXES: &[&str] = &[
     AUTO_OPTIMIZE_PREFIX,
     HEALTH_WATCH_PREFIX,
     CLOUD_SYNC_CURSOR_PREFIX,
+    MEMORY_EXTRACTION_PREFIX,
+    HEALING_ROOT_CAUSE_PREFIX,
+    ENGINE_FLAGS_PREFIX,
+    PROVIDER_FAILOVER_CHAIN_PREFIX,
     AUTOPILOT_MODE_PREFIX,
+    INPUT_OVERFLOW_PREFIX,
+    OUTPUT_CONTRACT_PREFIX,
+    EXECUTION_SANDBOX_PREFIX,
 ];
 
 /// Returns true if `suffix` is a syntactically acceptable persona_id-shaped
@@ -809,16 +1134,66 @@ pub fn validate_value(key: &str, value: &str) -> Result<(), String> {
     if key.starts_with(AUTO_OPTIMIZE_PREFIX) || key.starts_with(HEALTH_WATCH_PREFIX) {
         return validate_json_wellformed(key, value);
     }
+    if key.starts_with(ENGINE_FLAGS_PREFIX) {
+        return crate::engine::feature_flags::parse_list(value)
+            .map(|_| ())
+            .map_err(|e| format!("value for '{key}': {e}"));
+    }
+    if key == PROVIDER_FAILOVER_CHAIN || key.starts_with(PROVIDER_FAILOVER_CHAIN_PREFIX) {
+        return crate::engine::failover::parse_failover_chain(value)
+            .map(|_| ())
+            .map_err(|e| format!("value for '{key}': {e}"));
+    }
+    if key.starts_with(INPUT_OVERFLOW_PREFIX) {
+        return match value {
+            "reject" | "chunk" => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be \"reject\" or \"chunk\", got {value:?}"
+            )),
+        };
+    }
+    if key.starts_with(OUTPUT_CONTRACT_PREFIX) {
+        return crate::engine::output_contract::parse(value)
+            .map(|_| ())
+            .map_err(|e| format!("value for '{key}': {e}"));
+    }
+    if key.starts_with(EXECUTION_SANDBOX_PREFIX) {
+        return crate::engine::container_sandbox::parse(value)
+            .map(|_| ())
+            .map_err(|e| format!("value for '{key}': {e}"));
+    }
+    if key.starts_with(MEMORY_EXTRACTION_PREFIX) || key.starts_with(HEALING_ROOT_CAUSE_PREFIX) {
+        return match value {
+            "true" | "false" => Ok(()),
+            _ => Err(format!("value for '{key}' must be \"true\" or \"false\", got {value:?}")),
+        };
+    }
     match key {
+        BUDGET_ENFORCEMENT_MODE => match value {
+            "warn" | "block" | "pause" => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be one of warn|block|pause, got {value:?}"
+            )),
+        },
+        INJECTION_SCAN_POLICY => match value {
+            "off" | "flag" | "sanitize" | "block" | "require_approval" => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be one of off|flag|sanitize|block|require_approval, got {value:?}"
+            )),
+        },
         COMPANION_FLEET_BOLDNESS => match value {
             "cautious" | "balanced" | "bold" => Ok(()),
             _ => Err(format!(
                 "value for '{key}' must be one of cautious|balanced|bold, got {value:?}"
             )),
         },
-        EVENT_RETENTION_DAYS | EXECUTION_RETENTION_DAYS | DRAFT_RETENTION_DAYS => {
+        EVENT_RETENTION_DAYS | EXECUTION_RETENTION_DAYS | DRAFT_RETENTION_DAYS
+        | MESSAGE_RETENTION_DAYS => value.parse::<u32>().map(|_| ()).map_err(|_| {
+            format!("value for '{key}' must be a non-negative integer (days), got {value:?}")
+        }),
+        EXECUTION_RETENTION_MAX_PER_PERSONA | MESSAGE_RETENTION_MAX_PER_PERSONA => {
             value.parse::<u32>().map(|_| ()).map_err(|_| {
-                format!("value for '{key}' must be a non-negative integer (days), got {value:?}")
+                format!("value for '{key}' must be a non-negative integer (rows per persona, 0 = no cap), got {value:?}")
             })
         }
         EVENT_RETENTION_MAX_COUNT => match value.parse::<u32>() {
@@ -839,6 +1214,52 @@ pub fn validate_value(key: &str, value: &str) -> Result<(), String> {
                 "value for '{key}' must be an integer between {MAX_PARALLEL_EXECUTIONS_MIN} and {MAX_PARALLEL_EXECUTIONS_MAX}, got {value:?}"
             )),
         },
+        QUEUE_STUCK_WAIT_SECS => match value.parse::<u64>() {
+            Ok(n) if n <= 86_400 => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be an integer between 0 and 86400 (seconds), got {value:?}"
+            )),
+        },
+        TOOL_APPROVAL_TOOLS => {
+            let is_name = |t: &str| {
+                t.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            };
+            match value.split(',').map(str::trim).find(|t| !is_name(t)) {
+                None => Ok(()),
+                Some(bad) => Err(format!(
+                    "value for '{key}' must be a comma-separated list of tool names, got {bad:?}"
+                )),
+            }
+        }
+        REMOTE_ACTIONS_ALLOWED => match value
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .find(|t| crate::cloud::remote_commands::RemoteAction::parse(t).is_none())
+        {
+            None => Ok(()),
+            Some(bad) => Err(format!(
+                "value for '{key}' must be a comma-separated list of run_persona, pause_scheduler, resume_scheduler, fetch_status, got {bad:?}"
+            )),
+        },
+        CLOUD_PUSH_CATEGORIES => match value
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .find(|t| crate::cloud::push::PushCategory::parse(t).is_none())
+        {
+            None => Ok(()),
+            Some(bad) => Err(format!(
+                "value for '{key}' must be a comma-separated list of budget_exceeded, circuit_breaker, pending_approval, got {bad:?}"
+            )),
+        },
+        DB_POOL_SIZE => match value.parse::<u32>() {
+            Ok(n) if (DB_POOL_SIZE_MIN..=DB_POOL_SIZE_MAX).contains(&n) => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be an integer between {DB_POOL_SIZE_MIN} and {DB_POOL_SIZE_MAX}, got {value:?}"
+            )),
+        },
         FILE_WATCHER_DEBOUNCE_MS => value.parse::<u32>().map(|_| ()).map_err(|_| {
             format!(
                 "value for '{key}' must be a non-negative integer (milliseconds), got {value:?}"
@@ -851,6 +1272,9 @@ pub fn validate_value(key: &str, value: &str) -> Result<(), String> {
         | COMPANION_AUTONOMOUS_MODE
         | COMPANION_DEV_MODE
         | CLOUD_SYNC_ENABLED
+        | FLEET_PATTERN_SHARING_ENABLED
+        | AUTO_BACKUP_ENABLED
+        | FLEET_HYGIENE_ENABLED
         | AUTONOMOUS_MESSAGE_TRIAGE
         | AUTONOMOUS_GOAL_ADVANCEMENT
         | COMPANION_DAILY_ROLLUP
@@ -869,7 +1293,8 @@ pub fn validate_value(key: &str, value: &str) -> Result<(), String> {
         | AUTONOMOUS_DIRECTOR_STORM
         | SCRATCHPAD_ENABLED
         | SKILLS_SIDECAR_ENABLED
-        | EXECUTION_WORKTREE_ISOLATION => {
+        | EXECUTION_WORKTREE_ISOLATION
+        | INJECTION_SCAN_LLM_CHECK => {
             match value {
                 "true" | "false" => Ok(()),
                 _ => Err(format!(
@@ -877,12 +1302,51 @@ pub fn validate_value(key: &str, value: &str) -> Result<(), String> {
                 )),
             }
         }
+        AUTO_BACKUP_INTERVAL_HOURS => match value.parse::<u32>() {
+            Ok(h) if (1..=AUTO_BACKUP_INTERVAL_HOURS_MAX).contains(&h) => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be an integer between 1 and {AUTO_BACKUP_INTERVAL_HOURS_MAX} (hours), got {value:?}"
+            )),
+        },
+        FLEET_HYGIENE_IDLE_WEEKS => match value.parse::<u32>() {
+            Ok(w) if (1..=FLEET_HYGIENE_IDLE_WEEKS_MAX).contains(&w) => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be an integer between 1 and {FLEET_HYGIENE_IDLE_WEEKS_MAX} (weeks), got {value:?}"
+            )),
+        },
+        CREDENTIAL_EXPIRY_WARNING_DAYS => match value.parse::<u32>() {
+            Ok(d) if (1..=CREDENTIAL_EXPIRY_WARNING_DAYS_MAX).contains(&d) => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be an integer between 1 and {CREDENTIAL_EXPIRY_WARNING_DAYS_MAX} (days), got {value:?}"
+            )),
+        },
         MONTHLY_COST_CEILING_USD | CHAIN_MAX_COST_USD => match value.parse::<f64>() {
             Ok(n) if n.is_finite() && n >= 0.0 => Ok(()),
             _ => Err(format!(
                 "value for '{key}' must be a non-negative decimal USD amount, got {value:?}"
             )),
         },
+        MEMORY_SIMILARITY_THRESHOLD => match value.parse::<f64>() {
+            Ok(n) if (0.0..=1.0).contains(&n) => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be a similarity between 0 and 1, got {value:?}"
+            )),
+        },
+        MEMORY_RECALL_TOP_K => value.parse::<u32>().map(|_| ()).map_err(|_| {
+            format!("value for '{key}' must be a non-negative integer (memories per run, 0 = off), got {value:?}")
+        }),
+        ENGINE_WARM_POOL_SIZE => match value.parse::<u32>() {
+            Ok(n) if n <= ENGINE_WARM_POOL_SIZE_MAX => Ok(()),
+            _ => Err(format!(
+                "value for '{key}' must be an integer between 0 and {ENGINE_WARM_POOL_SIZE_MAX}, got {value:?}"
+            )),
+        },
+        INPUT_TOKEN_LIMIT => value.parse::<u64>().map(|_| ()).map_err(|_| {
+            format!("value for '{key}' must be a non-negative integer (tokens, 0 = off), got {value:?}")
+        }),
+        EXECUTION_OUTPUT_OFFLOAD_BYTES => value.parse::<u64>().map(|_| ()).map_err(|_| {
+            format!("value for '{key}' must be a non-negative integer (bytes, 0 = off), got {value:?}")
+        }),
         // Non-negative integer link count; `0` disables the breadth cap.
         CHAIN_MAX_LINKS => value.parse::<u32>().map(|_| ()).map_err(|_| {
             format!("value for '{key}' must be a non-negative integer (max links per chain), got {value:?}")
@@ -902,13 +1366,46 @@ pub fn validate_value(key: &str, value: &str) -> Result<(), String> {
         // also fail to load — no valid write is newly blocked.
         // -------------------------------------------------------------------
         BYOM_POLICY => validate_json_as::<crate::engine::byom::ByomPolicy>(key, value),
+        NETWORK_POLICY => {
+            let policy: crate::engine::tool_policy::NetworkPolicy = serde_json::from_str(value)
+                .map_err(|e| format!("value for '{key}' is not valid JSON for its schema: {e}"))?;
+            crate::engine::tool_policy::validate_network(&policy)
+                .map_err(|e| format!("value for '{key}': {e}"))
+        }
+        VAULT_BACKEND => {
+            let config: crate::engine::vault_backend::VaultBackendConfig =
+                serde_json::from_str(value).map_err(|e| {
+                    format!("value for '{key}' is not valid JSON for its schema: {e}")
+                })?;
+            crate::engine::vault_backend::validate_config(&config)
+                .map_err(|e| format!("value for '{key}': {e}"))
+        }
         MODEL_ROUTING_RULES => {
             validate_json_as::<Vec<crate::engine::model_routing::ModelRoutingRule>>(key, value)
         }
+        MODEL_DEPRECATIONS | MODEL_DEPRECATIONS_REMOTE => {
+            let entries: Vec<crate::engine::model_registry::ModelDeprecation> =
+                serde_json::from_str(value).map_err(|e| {
+                    format!("value for '{key}' is not valid JSON for its schema: {e}")
+                })?;
+            let diags = crate::engine::model_registry::validate(&entries);
+            if diags.is_empty() {
+                Ok(())
+            } else {
+                Err(format!("value for '{key}': {}", diags.join("; ")))
+            }
+        }
+        COST_CORRECTION_FACTORS => {
+            let factors: std::collections::HashMap<String, f64> = serde_json::from_str(value)
+                .map_err(|e| format!("value for '{key}' is not valid JSON for its schema: {e}"))?;
+            crate::engine::cost_reconciliation::validate_factors(&factors)
+                .map_err(|e| format!("value for '{key}': {e}"))
+        }
         QUALITY_GATE_CONFIG => {
             validate_json_as::<crate::engine::quality_gate::QualityGateConfig>(key, value)
         }
         PERFORMANCE_DIGEST => validate_json_as::<crate::engine::digest::DigestConfig>(key, value),
+        OBSERVER_MODE => crate::engine::observer_mode::validate_setting(value),
         GLOBAL_MODEL_PROFILE => {
             validate_json_as::<crate::engine::types::ModelProfile>(key, value)
         }
@@ -1032,6 +1529,14 @@ const AUDIT_EXCLUDED_KEYS: &[&str] = &[
     CLOUD_SYNC_DEVICE_ID,
     CLOUD_SYNC_LAST_AT,
     CLOUD_SYNC_TOTAL_ROWS,
+    // Fleet-learning exchange cadence stamp.
+    FLEET_PATTERN_SHARING_LAST,
+    // Budget month-boundary reset watermark.
+    BUDGET_PERIOD_LAST_RESET,
+    // Scheduled backup cadence stamp.
+    AUTO_BACKUP_LAST,
+    // Fleet hygiene review cadence stamp.
+    FLEET_HYGIENE_LAST,
     // Disk-content version stamp (engine-managed on app start, not user-set).
     COMPANION_CONSTITUTION_VERSION,
     // Mastermind canvas layout: written debounced on every island drag / group
@@ -1067,24 +1572,42 @@ pub fn audit_category(key: &str) -> Option<&'static str> {
     if key.starts_with(AUTO_ROLLBACK_PREFIX)
         || key.starts_with(AUTO_OPTIMIZE_PREFIX)
         || key.starts_with(AUTOPILOT_MODE_PREFIX)
+        || key.starts_with(MEMORY_EXTRACTION_PREFIX)
+        || key.starts_with(HEALING_ROOT_CAUSE_PREFIX)
     {
         return Some("autonomy");
     }
     if key.starts_with(HEALTH_WATCH_PREFIX) {
         return Some("notifications");
     }
+    if key.starts_with(OUTPUT_CONTRACT_PREFIX) {
+        return Some("quality_gates");
+    }
     if key.starts_with(EXECUTION_RETENTION_MONTHS_PREFIX) {
         return Some("retention");
     }
+    if key.starts_with(ENGINE_FLAGS_PREFIX)
+        || key.starts_with(PROVIDER_FAILOVER_CHAIN_PREFIX)
+        || key.starts_with(INPUT_OVERFLOW_PREFIX)
+        || key.starts_with(EXECUTION_SANDBOX_PREFIX)
+    {
+        return Some("engine");
+    }
 
     let category = match key {
         // Secrets / credentials.
-        OLLAMA_API_KEY | LITELLM_MASTER_KEY | BROWSER_BRIDGE_PAIRING_TOKEN => "api_keys",
+        OLLAMA_API_KEY
+        | LITELLM_MASTER_KEY
+        | OPENAI_API_KEY
+        | BROWSER_BRIDGE_PAIRING_TOKEN => "api_keys",
         // Engine wiring: which CLI/remote engine, routing, capabilities, concurrency.
         CLI_ENGINE
+        | PROVIDER_FAILOVER_CHAIN
         | QWEN_BASE_URL
         | QWEN_MODEL
         | QWEN_CONNECTOR_TOOLS
+        | OPENAI_API_BASE_URL
+        | OLLAMA_BASE_URL
         | DELEGATE_MODEL
         | DELEGATE_BASE_URL
         | LITELLM_BASE_URL
@@ -1093,25 +1616,48 @@ pub fn audit_category(key: &str) -> Option<&'static str> {
         | SMART_SEARCH_MODEL
         | SEMANTIC_LINT_MODEL
         | MAX_PARALLEL_EXECUTIONS
+        | QUEUE_STUCK_WAIT_SECS
+        | TOOL_APPROVAL_TOOLS
+        | NETWORK_POLICY
+        | VAULT_BACKEND
         | EXECUTION_WORKTREE_ISOLATION
         | SCRATCHPAD_ENABLED
         | SKILLS_SIDECAR_ENABLED
+        | MEMORY_SIMILARITY_THRESHOLD
+        | MEMORY_RECALL_TOP_K
+        | ENGINE_WARM_POOL_SIZE
+        | INPUT_TOKEN_LIMIT
         | FILE_WATCHER_DEBOUNCE_MS => "engine",
         // Numeric ceilings / rate limits.
         MONTHLY_COST_CEILING_USD
+        | BUDGET_ENFORCEMENT_MODE
         | SCHEDULE_EXECUTIONS_PER_PERSONA_HOUR
-        | EVENT_RETENTION_MAX_COUNT => "limits",
+        | EVENT_RETENTION_MAX_COUNT
+        | DB_POOL_SIZE => "limits",
         // Data-retention windows.
-        EVENT_RETENTION_DAYS | EXECUTION_RETENTION_DAYS => "retention",
-        // Bring-your-own-model policy + routing rules.
-        BYOM_POLICY | MODEL_ROUTING_RULES => "byom",
+        EVENT_RETENTION_DAYS
+        | EXECUTION_RETENTION_DAYS
+        | EXECUTION_RETENTION_MAX_PER_PERSONA
+        | EXECUTION_OUTPUT_OFFLOAD_BYTES
+        | MESSAGE_RETENTION_DAYS
+        | MESSAGE_RETENTION_MAX_PER_PERSONA => "retention",
+        // Bring-your-own-model policy, routing rules, model deprecations,
+        // provider cost corrections.
+        BYOM_POLICY
+        | MODEL_ROUTING_RULES
+        | MODEL_DEPRECATIONS
+        | MODEL_DEPRECATIONS_REMOTE
+        | COST_CORRECTION_FACTORS => "byom",
         // Notification / digest preferences.
         NOTIFICATION_PREFS
         | GITLAB_PIPELINE_NOTIFICATION_PREFS
         | PERFORMANCE_DIGEST
-        | HEALTH_DIGEST_ENABLED => "notifications",
+        | HEALTH_DIGEST_ENABLED
+        | FLEET_HYGIENE_ENABLED
+        | FLEET_HYGIENE_IDLE_WEEKS
+        | CREDENTIAL_EXPIRY_WARNING_DAYS => "notifications",
         // Quality gates.
-        QUALITY_GATE_CONFIG => "quality_gates",
+        QUALITY_GATE_CONFIG | INJECTION_SCAN_POLICY | INJECTION_SCAN_LLM_CHECK => "quality_gates",
         // Autonomy / companion behaviour toggles.
         ATHENA_WAKE_WINDOW_MINUTES
         | CLI_SESSION_AWARENESS_ENABLED
@@ -1142,9 +1688,14 @@ pub fn audit_category(key: &str) -> Option<&'static str> {
         | OBSIDIAN_BRAIN_SAVED_VAULTS
         | DEV_TOOLS_CROSS_PROJECT_METADATA => "integrations",
         // Cloud sync (user-facing toggle only; bookkeeping excluded above).
-        CLOUD_SYNC_ENABLED => "sync",
+        CLOUD_SYNC_ENABLED
+        | FLEET_PATTERN_SHARING_ENABLED
+        | REMOTE_ACTIONS_ALLOWED
+        | CLOUD_PUSH_CATEGORIES => "sync",
         // UI / onboarding state.
         ONBOARDING_QUEST_STATE => "config",
+        // Screen-sharing / demo redaction.
+        OBSERVER_MODE => "privacy",
         // Any registered-but-uncategorized key → generic bucket (still audited).
         _ => "config",
     };
@@ -1231,6 +1782,29 @@ mod tests {
         assert!(validate_value(MAX_PARALLEL_EXECUTIONS, "-1").is_err());
         assert!(validate_value(MAX_PARALLEL_EXECUTIONS, "").is_err());
         assert!(validate_value(MAX_PARALLEL_EXECUTIONS, " 5 ").is_err());
+        assert!(validate_value(QUEUE_STUCK_WAIT_SECS, "0").is_ok());
+        assert!(validate_value(QUEUE_STUCK_WAIT_SECS, "600").is_ok());
+        assert!(validate_value(QUEUE_STUCK_WAIT_SECS, "86401").is_err());
+        assert!(validate_value(QUEUE_STUCK_WAIT_SECS, "-5").is_err());
+        assert!(validate_value(TOOL_APPROVAL_TOOLS, "").is_ok());
+        assert!(validate_value(TOOL_APPROVAL_TOOLS, "gmail_send, file_write").is_ok());
+        assert!(validate_value(TOOL_APPROVAL_TOOLS, "gmail send").is_err());
+        assert!(validate_key(DB_POOL_SIZE).is_ok());
+        assert!(validate_value(DB_POOL_SIZE, "4").is_ok());
+        assert!(validate_value(DB_POOL_SIZE, "64").is_ok());
+        assert!(validate_value(DB_POOL_SIZE, "3").is_err());
+        assert!(validate_value(DB_POOL_SIZE, "65").is_err());
+        assert!(validate_value(DB_POOL_SIZE, "twelve").is_err());
+        assert!(validate_key(REMOTE_ACTIONS_ALLOWED).is_ok());
+        assert!(validate_value(REMOTE_ACTIONS_ALLOWED, "").is_ok());
+        assert!(validate_value(REMOTE_ACTIONS_ALLOWED, "run_persona, fetch_status").is_ok());
+        assert!(validate_value(REMOTE_ACTIONS_ALLOWED, "run_persona,delete_persona").is_err());
+        assert_eq!(audit_category(REMOTE_ACTIONS_ALLOWED), Some("sync"));
+        assert!(validate_key(CLOUD_PUSH_CATEGORIES).is_ok());
+        assert!(validate_value(CLOUD_PUSH_CATEGORIES, "").is_ok());
+        assert!(validate_value(CLOUD_PUSH_CATEGORIES, "budget_exceeded, pending_approval").is_ok());
+        assert!(validate_value(CLOUD_PUSH_CATEGORIES, "budget_exceeded,chat").is_err());
+        assert_eq!(audit_category(CLOUD_PUSH_CATEGORIES), Some("sync"));
     }
 
     #[test]
@@ -1256,6 +1830,59 @@ mod tests {
         assert_eq!(audit_category(SCRATCHPAD_ENABLED), Some("engine"));
     }
 
+    #[test]
+    fn memory_recall_keys_validate_ranges() {
+        assert!(validate_value(MEMORY_SIMILARITY_THRESHOLD, "0.35").is_ok());
+        assert!(validate_value(MEMORY_SIMILARITY_THRESHOLD, "1").is_ok());
+        assert!(validate_value(MEMORY_SIMILARITY_THRESHOLD, "1.5").is_err());
+        assert!(validate_value(MEMORY_SIMILARITY_THRESHOLD, "-0.1").is_err());
+        assert!(validate_value(MEMORY_RECALL_TOP_K, "0").is_ok());
+        assert!(validate_value(MEMORY_RECALL_TOP_K, "8").is_ok());
+        assert!(validate_value(MEMORY_RECALL_TOP_K, "-1").is_err());
+        assert_eq!(audit_category(MEMORY_RECALL_TOP_K), Some("engine"));
+    }
+
+    #[test]
+    fn engine_warm_pool_size_bounds() {
+        assert!(validate_key(ENGINE_WARM_POOL_SIZE).is_ok());
+        assert!(validate_value(ENGINE_WARM_POOL_SIZE, "0").is_ok());
+        assert!(validate_value(ENGINE_WARM_POOL_SIZE, "8").is_ok());
+        assert!(validate_value(ENGINE_WARM_POOL_SIZE, "9").is_err());
+        assert!(validate_value(ENGINE_WARM_POOL_SIZE, "-1").is_err());
+        assert_eq!(audit_category(ENGINE_WARM_POOL_SIZE), Some("engine"));
+        assert!(validate_key("memory_extraction:persona-1").is_ok());
+        assert!(validate_value("memory_extraction:persona-1", "true").is_ok());
+        assert!(validate_value("memory_extraction:persona-1", "yes").is_err());
+        assert_eq!(audit_category("memory_extraction:persona-1"), Some("autonomy"));
+        assert!(validate_value("healing_root_cause:persona-1", "false").is_ok());
+        assert!(validate_value("healing_root_cause:persona-1", "1").is_err());
+    }
+
+    #[test]
+    fn input_size_keys_validate() {
+        assert!(validate_key(INPUT_TOKEN_LIMIT).is_ok());
+        assert!(validate_value(INPUT_TOKEN_LIMIT, "0").is_ok());
+        assert!(validate_value(INPUT_TOKEN_LIMIT, "200000").is_ok());
+        assert!(validate_value(INPUT_TOKEN_LIMIT, "-5").is_err());
+        assert_eq!(audit_category(INPUT_TOKEN_LIMIT), Some("engine"));
+        assert!(validate_key("input_overflow:persona-1").is_ok());
+        assert!(validate_value("input_overflow:persona-1", "chunk").is_ok());
+        assert!(validate_value("input_overflow:persona-1", "truncate").is_err());
+        assert_eq!(audit_category("input_overflow:persona-1"), Some("engine"));
+    }
+
+    #[test]
+    fn execution_output_offload_key_validates() {
+        assert!(validate_key(EXECUTION_OUTPUT_OFFLOAD_BYTES).is_ok());
+        assert!(validate_value(EXECUTION_OUTPUT_OFFLOAD_BYTES, "0").is_ok());
+        assert!(validate_value(EXECUTION_OUTPUT_OFFLOAD_BYTES, "1048576").is_ok());
+        assert!(validate_value(EXECUTION_OUTPUT_OFFLOAD_BYTES, "1MB").is_err());
+        assert_eq!(
+            audit_category(EXECUTION_OUTPUT_OFFLOAD_BYTES),
+            Some("retention")
+        );
+    }
+
     #[test]
     fn execution_worktree_isolation_key_and_value_validation() {
         assert!(validate_key(EXECUTION_WORKTREE_ISOLATION).is_ok());
@@ -1303,6 +1930,34 @@ mod tests {
         assert!(validate_value(MODEL_ROUTING_RULES, "[]").is_ok());
     }
 
+    #[test]
+    fn observer_mode_key_registered_and_matches_engine_constant() {
+        assert_eq!(
+            OBSERVER_MODE,
+            crate::engine::observer_mode::OBSERVER_MODE_KEY
+        );
+        assert!(validate_key(OBSERVER_MODE).is_ok());
+        let valid = r#"{"enabled":true,"redactPatterns":["acme"]}"#;
+        assert!(validate_value(OBSERVER_MODE, valid).is_ok());
+        let broken = r#"{"enabled":true,"redactPatterns":["("]}"#;
+        assert!(validate_value(OBSERVER_MODE, broken).is_err());
+        assert_eq!(audit_category(OBSERVER_MODE), Some("privacy"));
+    }
+
+    #[test]
+    fn fleet_hygiene_keys_validated() {
+        assert!(validate_value(FLEET_HYGIENE_ENABLED, "false").is_ok());
+        assert!(validate_value(FLEET_HYGIENE_ENABLED, "no").is_err());
+        assert!(validate_value(FLEET_HYGIENE_IDLE_WEEKS, "4").is_ok());
+        assert!(validate_value(FLEET_HYGIENE_IDLE_WEEKS, "0").is_err());
+        assert!(validate_value(FLEET_HYGIENE_IDLE_WEEKS, "53").is_err());
+        assert_eq!(
+            audit_category(FLEET_HYGIENE_IDLE_WEEKS),
+            Some("notifications")
+        );
+        assert_eq!(audit_category(FLEET_HYGIENE_LAST), None);
+    }
+
     #[test]
     fn json_blob_keys_reject_malformed() {
         // Direction 2: each JSON-blob key rejects a truncated/garbage blob that
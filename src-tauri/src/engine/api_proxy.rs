@@ -3,7 +3,9 @@
 //! Proxies arbitrary HTTP requests through a credential's auth strategy,
 //! resolving base URLs and applying authentication automatically.
 //! Enforces per-credential token-bucket rate limiting to prevent runaway
-//! API consumption from compromised or misconfigured automations.
+//! API consumption from compromised or misconfigured automations, plus the
+//! connector-wide budget shared by all personas
+//! ([`super::connector_rate_limit`]).
 //!
 //! Maintains per-credential aggregate metrics (latency percentiles, error
 //! rates) via an in-memory ring buffer, exposed through
@@ -642,6 +644,30 @@ pub async fn execute_api_request(
     path: &str,
     custom_headers: HashMap<String, String>,
     body: Option<String>,
+) -> Result<ApiProxyResponse, AppError> {
+    execute_api_request_guarded(
+        pool,
+        credential_id,
+        method,
+        path,
+        custom_headers,
+        body,
+        &|_| Ok(()),
+    )
+    .await
+}
+
+/// [`execute_api_request`] with a caller check on the resolved URL, run
+/// before any auth is resolved or anything is sent. The native `http_request`
+/// tool uses it to hold connector calls to the persona's network policy.
+pub(crate) async fn execute_api_request_guarded(
+    pool: &DbPool,
+    credential_id: &str,
+    method: &str,
+    path: &str,
+    custom_headers: HashMap<String, String>,
+    body: Option<String>,
+    url_guard: &(dyn Fn(&str) -> Result<(), AppError> + Sync),
 ) -> Result<ApiProxyResponse, AppError> {
     let credential = cred_repo::get_by_id(pool, credential_id)?;
     let fields = cred_repo::get_decrypted_fields(pool, &credential)?;
@@ -701,6 +727,7 @@ pub async fn execute_api_request(
     } else {
         format!("{trimmed_base}/{trimmed_path}")
     };
+    url_guard(&full_url)?;
 
     // Resolve the connector definition up front — its metadata decides both the
     // auth strategy (below) and whether this connector may target a private /
@@ -775,6 +802,9 @@ pub async fn execute_api_request(
 
     // Per-credential rate limiting (token-bucket, default 60 req/min)
     check_rate_limit(credential_id, connector_metadata).await?;
+    // Connector-wide budget shared across credentials and personas; queues
+    // instead of failing while the wait is short.
+    super::connector_rate_limit::acquire(pool, &credential.service_type).await?;
 
     let strategy =
         connector_strategy::registry()?.get(&credential.service_type, connector_metadata);
@@ -936,6 +966,16 @@ pub async fn execute_api_request(
     }
 
     let duration_ms = start.elapsed().as_millis() as u64;
+    if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok());
+        super::connector_rate_limit::note_retry_after(
+            &credential.service_type,
+            super::connector_rate_limit::parse_retry_after(retry_after),
+        );
+    }
     let status = resp.status().as_u16();
     let status_text = resp.status().canonical_reason().unwrap_or("").to_string();
     let content_type = resp
@@ -9,16 +9,17 @@ use ts_rs::TS;
 use std::collections::HashMap;
 
 use super::event_registry::{emit_event_bus, event_name};
+use super::job_registry::JobRegistry;
 use crate::daemon::lock::{default_data_dir, trigger_type_to_kind, DaemonLock, LockFileContents};
 use crate::db::models::{
-    CreatePersonaEventInput, PersonaEvent, PersonaEventStatus, UpdateExecutionStatus,
+    CatchUpPolicy, CreatePersonaEventInput, PersonaEvent, PersonaEventStatus, UpdateExecutionStatus,
 };
 use crate::db::repos::communication::events as event_repo;
 use crate::db::repos::core::{personas as persona_repo, settings};
-use crate::db::repos::communication::messages as messages_repo;
 use crate::db::repos::execution::executions as exec_repo;
 use crate::db::repos::execution::healing as healing_repo;
 use crate::db::repos::resources::audit_log;
+use crate::db::repos::resources::maintenance_windows as maintenance_repo;
 use crate::db::repos::resources::{tools as tool_repo, triggers as trigger_repo};
 use crate::db::settings_keys;
 use crate::db::DbPool;
@@ -114,6 +115,8 @@ pub struct SchedulerState {
     /// Retained JoinHandles for spawned subscription tasks. Prevents silent
     /// task drops and enables future graceful-shutdown awaits.
     subscription_handles: std::sync::Mutex<Vec<tokio::task::JoinHandle<()>>>,
+    /// Jobs dashboard registry: last/next run and pause/run-now controls.
+    jobs: JobRegistry,
 }
 
 impl Default for SchedulerState {
@@ -139,6 +142,7 @@ impl SchedulerState {
             trace_continuity_breaks: AtomicU64::new(0),
             subscription_health: std::sync::Mutex::new(HashMap::new()),
             subscription_handles: std::sync::Mutex::new(Vec::new()),
+            jobs: JobRegistry::new(),
         }
     }
 
@@ -342,6 +346,11 @@ impl SchedulerState {
         }
     }
 
+    /// Registry of the background loops for the jobs dashboard.
+    pub fn jobs(&self) -> &JobRegistry {
+        &self.jobs
+    }
+
     /// Store retained JoinHandles for spawned subscription tasks.
     pub fn store_subscription_handles(&self, handles: Vec<tokio::task::JoinHandle<()>>) {
         let mut h = self
@@ -467,7 +476,34 @@ pub fn start_loops(
             pool: pool.clone(),
             app: app.clone(),
         }),
+        // Warn (message + healing issue) about executions parked in the
+        // admission queue past `queue_stuck_wait_secs`.
+        Box::new(subscription::QueueAgeMonitorSubscription {
+            pool: pool.clone(),
+            app: app.clone(),
+            engine: engine.clone(),
+        }),
+        // Evaluate opt-in per-persona SLOs; breaches open healing issues.
+        Box::new(subscription::SloMonitorSubscription {
+            pool: pool.clone(),
+            app: app.clone(),
+        }),
+        // Keep daily metrics snapshots and weekly/monthly rollups current.
+        Box::new(subscription::MetricsSnapshotSubscription { pool: pool.clone() }),
         Box::new(subscription::HealingTtlSubscription { pool: pool.clone() }),
+        // Opt-in fleet learning (default OFF): daily anonymized exchange of
+        // healing backoff patterns with the shared fleet endpoint.
+        Box::new(subscription::FleetLearningSubscription { pool: pool.clone() }),
+        // Budget month-boundary reset: resumes personas paused by budget
+        // enforcement once the UTC billing period rolls over.
+        Box::new(subscription::BudgetPeriodSubscription { pool: pool.clone() }),
+        // Kill engine-spawned CLIs orphaned by a crash or lost tracking.
+        Box::new(subscription::OrphanProcessReaperSubscription {
+            pool: pool.clone(),
+            engine: engine.clone(),
+        }),
+        // Opt-in scheduled database backups (default OFF).
+        Box::new(subscription::AutoBackupSubscription { pool: pool.clone() }),
         Box::new(CloudWebhookRelaySubscription {
             cloud_client: cloud_client.clone(),
             pool: pool.clone(),
@@ -493,6 +529,10 @@ pub fn start_loops(
             pool: pool.clone(),
             app: app.clone(),
         }),
+        Box::new(subscription::FleetHygieneSubscription {
+            pool: pool.clone(),
+            app: app.clone(),
+        }),
         // Autonomous goal advancement — default-OFF; gated on the
         // AUTONOMOUS_GOAL_ADVANCEMENT setting inside its tick.
         Box::new(subscription::GoalAdvanceSubscription {
@@ -778,6 +818,7 @@ pub fn start_loops(
                     pool,
                     rate_limiter,
                     tier_config,
+                    scheduler.clone(),
                     app_for_mgmt,
                     registry,
                     webhook_shutdown_rx,
@@ -789,6 +830,7 @@ pub fn start_loops(
                     pool,
                     rate_limiter,
                     tier_config,
+                    scheduler.clone(),
                     webhook_shutdown_rx,
                 )
                 .await
@@ -1018,6 +1060,14 @@ pub(crate) async fn event_bus_tick(
         "Event bus: batch persona/tool fetch complete"
     );
 
+    // Personas under a maintenance window (1 query). Targeted events for them
+    // were never claimed; this covers fan-out events that reach them.
+    let maintenance = maintenance_repo::active_at(pool, &chrono::Utc::now().to_rfc3339())
+        .unwrap_or_else(|e| {
+            tracing::warn!("Event bus: maintenance window lookup failed: {}", e);
+            Default::default()
+        });
+
     // 8. Dispatch executions using the pre-fetched maps
     for (idx, matches) in &event_matches {
         let event = &events[*idx];
@@ -1062,6 +1112,27 @@ pub(crate) async fn event_bus_tick(
             }
         }
 
+        // Prompt-injection screen of the inbound payload, once per event and
+        // before any match dispatches. The firing trigger's `injection_policy`
+        // (or the global default) decides whether a hit is only recorded, has
+        // its payload sanitized, is dead-lettered, or is held for approval.
+        let sanitize_payload = match super::injection_scan::screen_event(pool, event).await {
+            super::injection_scan::Screening::Pass => false,
+            super::injection_scan::Screening::Sanitize => true,
+            super::injection_scan::Screening::Held => continue,
+            super::injection_scan::Screening::Block(note) => {
+                let _ = event_repo::update_status(
+                    pool,
+                    &event.id,
+                    PersonaEventStatus::DeadLetter,
+                    Some(note),
+                );
+                emit_event_to_frontend(app, event, PersonaEventStatus::DeadLetter);
+                scheduler.events_processed.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+        };
+
         // Breadcrumb: set when a handoff EXPLICITLY targeted at a persona is
         // dropped because that persona is disabled. The bus marks the event
         // `delivered` either way, so without this a stalled cascade is invisible
@@ -1111,6 +1182,40 @@ pub(crate) async fn event_bus_tick(
                 continue;
             }
 
+            // Maintenance window: park a copy of the event targeted at this
+            // persona. The claim skips it until the window ends, so the run is
+            // deferred rather than dropped, without holding up other matches.
+            if maintenance.covers(&persona.id) {
+                match event_repo::publish(
+                    pool,
+                    CreatePersonaEventInput {
+                        event_type: event.event_type.clone(),
+                        source_type: event.source_type.clone(),
+                        project_id: Some(event.project_id.clone()),
+                        source_id: event.source_id.clone(),
+                        target_persona_id: Some(persona.id.clone()),
+                        payload: event.payload.clone(),
+                        use_case_id: m.use_case_id.clone(),
+                    },
+                ) {
+                    Ok(deferred) => tracing::info!(
+                        persona_id = %persona.id,
+                        event_id = %event.id,
+                        deferred_event_id = %deferred.id,
+                        "Event bus: persona in maintenance — dispatch deferred until the window ends"
+                    ),
+                    Err(e) => {
+                        tracing::error!(
+                            persona_id = %persona.id,
+                            event_id = %event.id,
+                            "Event bus: failed to defer event for maintenance: {}", e
+                        );
+                        any_failed = true;
+                    }
+                }
+                continue;
+            }
+
             // Cross-team bleed guard. Adoption wires intra-team subscriptions
             // with source_filter "*"; in a multi-team / multi-repo deployment
             // that lets one team's event (e.g. ai-bookkeeper's release.published)
@@ -1164,6 +1269,24 @@ pub(crate) async fn event_bus_tick(
                 continue;
             }
 
+            // Pre-admission budget gate — the same decision the manual path
+            // makes in execute_persona_inner, including the predicted cost of
+            // this run. Skipped (not DLQ'd): retrying cannot fix an exhausted
+            // monthly budget.
+            if let Err(e) = crate::engine::optimizer::check_cost_admission(
+                pool,
+                &persona,
+                m.use_case_id.as_deref(),
+            ) {
+                tracing::warn!(
+                    persona_id = %persona.id,
+                    persona_name = %persona.name,
+                    event_type = %event.event_type,
+                    "Event bus: skipping — {}", e
+                );
+                continue;
+            }
+
             // Destructive-action gate (UAT P5 + F-MAJOR-11): a trigger in
             // `dry_run` mode launches the run as a SIMULATION so outbound
             // side-effects are suppressed (dispatch skips real notification/
@@ -1172,21 +1295,51 @@ pub(crate) async fn event_bus_tick(
             // fires (source_type == "webhook") — each carries the firing
             // trigger id in source_id. (`approval` for webhook is held above,
             // before this point; for the scheduler it's held at tick time.)
-            let dry_run = matches!(event.source_type.as_str(), "trigger" | "webhook")
-                && event
+            let firing_trigger = if matches!(event.source_type.as_str(), "trigger" | "webhook") {
+                event
                     .source_id
                     .as_deref()
                     .and_then(|sid| trigger_repo::get_by_id(pool, sid).ok())
-                    .map(|t| t.unattended_mode == "dry_run")
-                    .unwrap_or(false);
+            } else {
+                None
+            };
+            let dry_run = firing_trigger
+                .as_ref()
+                .map(|t| t.unattended_mode == "dry_run")
+                .unwrap_or(false);
+
+            // A trigger with `deadline_secs` stamps an absolute `_deadline`
+            // onto its firing so the run (and anything it chains into) is
+            // refused or trimmed once that time is past. Stamped before the
+            // execution row is created so chain evaluation reads it back.
+            let raw_payload = if sanitize_payload {
+                m.payload.as_deref().map(super::injection_scan::sanitize)
+            } else {
+                m.payload.clone()
+            };
+            let payload = super::deadline::stamp_trigger_deadline(
+                raw_payload,
+                firing_trigger.as_ref().and_then(|t| t.config.as_deref()),
+                &event.created_at,
+            );
+
+            // Attribute the run to the trigger that fired it (schedule,
+            // polling, webhook or chain link) so cost and outcomes roll up
+            // per trigger. Plain emitted events have no originating trigger.
+            let origin_trigger_id =
+                if matches!(event.source_type.as_str(), "trigger" | "webhook" | "chain") {
+                    event.source_id.clone()
+                } else {
+                    None
+                };
 
             // Create execution record (must be per-match, not batchable)
             let create_result = if dry_run {
                 exec_repo::create_with_idempotency(
                     pool,
                     &persona.id,
-                    None,
-                    m.payload.clone(),
+                    origin_trigger_id,
+                    payload.clone(),
                     None,
                     m.use_case_id.clone(),
                     None,
@@ -1196,8 +1349,8 @@ pub(crate) async fn event_bus_tick(
                 exec_repo::create(
                     pool,
                     &persona.id,
-                    None,
-                    m.payload.clone(),
+                    origin_trigger_id,
+                    payload.clone(),
                     None,
                     m.use_case_id.clone(),
                 )
@@ -1210,6 +1363,15 @@ pub(crate) async fn event_bus_tick(
                     continue;
                 }
             };
+            // Every run this event fans out to shares the event id as its
+            // firing id, so the whole fan-out can be viewed and costed together.
+            if let Err(e) = exec_repo::set_firing_id(pool, &exec.id, &event.id) {
+                tracing::warn!(
+                    execution_id = %exec.id,
+                    event_id = %event.id,
+                    "Event bus: failed to stamp firing id: {}", e
+                );
+            }
             if dry_run {
                 tracing::info!(
                     persona_id = %persona.id,
@@ -1225,7 +1387,7 @@ pub(crate) async fn event_bus_tick(
             // A parse failure here means the chain trace ID is lost and downstream
             // chain executions will create orphaned trace roots.
             let parsed_payload: Option<serde_json::Value> =
-                m.payload
+                payload
                     .as_deref()
                     .and_then(|s| match serde_json::from_str(s) {
                         Ok(v) => Some(v),
@@ -1515,6 +1677,11 @@ const BACKFILL_HARD_CAP: usize = crate::engine::limits::BACKFILL_HARD_CAP;
 /// dropped (the same semantics as the per-trigger drop-oldest).
 const GLOBAL_BACKFILL_PER_TICK: usize = 50;
 
+/// How late a schedule's pending fire may be before a trigger with a
+/// `catch_up` policy treats it as missed. Comfortably above the scheduler tick
+/// interval, so a busy tick never counts as downtime.
+const CATCH_UP_GRACE: chrono::Duration = chrono::Duration::minutes(2);
+
 pub(crate) fn schedule_executions_per_persona_hour(pool: &DbPool) -> i64 {
     match settings::get(pool, settings_keys::SCHEDULE_EXECUTIONS_PER_PERSONA_HOUR)
         .ok()
@@ -1783,6 +1950,79 @@ pub(crate) fn record_and_emit_missed_runs(
     }
 }
 
+/// Apply-the-policy half of the missed-schedule catch-up: persist the report
+/// (and `skipped` into the discarded count the "missed N" badge reads) and
+/// publish an informational `schedule.catch_up` event carrying the same
+/// report for the UI. Like `schedule.missed.offline`, it never spawns a run.
+fn record_and_emit_catch_up(
+    pool: &DbPool,
+    trigger: &crate::db::models::PersonaTrigger,
+    policy: CatchUpPolicy,
+    fired: &[String],
+    skipped: i64,
+    now_str: &str,
+) {
+    if let Err(err) = trigger_repo::record_missed_runs(pool, &trigger.id, skipped, now_str)
+        .and_then(|_| {
+            trigger_repo::record_catch_up(pool, &trigger.id, policy, fired, skipped, now_str)
+        })
+    {
+        tracing::warn!(
+            trigger_id = %trigger.id,
+            persona_id = %trigger.persona_id,
+            error = %err,
+            "failed to persist schedule catch-up report"
+        );
+    }
+
+    let payload = serde_json::json!({
+        "trigger_id": trigger.id,
+        "target_persona_id": trigger.persona_id,
+        "policy": policy.as_str(),
+        "fired": fired,
+        "skipped_count": skipped,
+        "detected_at": now_str,
+    });
+    match event_repo::publish(
+        pool,
+        CreatePersonaEventInput {
+            event_type: "schedule.catch_up".into(),
+            source_type: "scheduler".into(),
+            source_id: Some(trigger.id.clone()),
+            target_persona_id: Some(trigger.persona_id.clone()),
+            project_id: None,
+            payload: Some(payload.to_string()),
+            use_case_id: trigger.use_case_id.clone(),
+        },
+    ) {
+        Ok(_) => tracing::info!(
+            trigger_id = %trigger.id,
+            persona_id = %trigger.persona_id,
+            policy = policy.as_str(),
+            fired = fired.len(),
+            skipped,
+            "Missed schedule caught up"
+        ),
+        Err(e) => tracing::warn!(
+            trigger_id = %trigger.id,
+            "failed to publish schedule.catch_up event: {}", e
+        ),
+    }
+}
+
+/// Whether the trigger's pending fire is late enough that the app must have
+/// been closed (or asleep) over it, rather than just waiting out a tick.
+fn schedule_fire_overdue(
+    trigger: &crate::db::models::PersonaTrigger,
+    now: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    trigger
+        .next_trigger_at
+        .as_deref()
+        .and_then(|iso| chrono::DateTime::parse_from_rfc3339(iso).ok())
+        .is_some_and(|due| now - due.with_timezone(&chrono::Utc) > CATCH_UP_GRACE)
+}
+
 /// Direction 2 (overlap policy): is a previous run from THIS schedule trigger
 /// still in flight?
 ///
@@ -1902,6 +2142,7 @@ fn compute_missed_backfill_slots(
         TriggerConfig::Schedule {
             cron: Some(expr),
             timezone,
+            exclusions,
             ..
         } => {
             let Ok(schedule) = crate::engine::cron::parse_cron_seeded(expr, seed) else {
@@ -1916,11 +2157,7 @@ fn compute_missed_backfill_slots(
             };
             let mut from = last_fire;
             while slots.len() < BACKFILL_HARD_CAP {
-                let next = match tz {
-                    Some(zone) => crate::engine::cron::next_fire_time_in_tz(&schedule, from, zone),
-                    None => crate::engine::cron::next_fire_time_local(&schedule, from),
-                };
-                match next {
+                match sched_logic::next_allowed_fire(&schedule, from, tz, exclusions) {
                     Some(t) if t <= now => {
                         slots.push(t);
                         from = t;
@@ -2036,8 +2273,12 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
     for trigger in triggers {
         // Skip polling triggers -- they are handled by the PollingSubscription
         // which does HTTP content-hash diffing before deciding whether to fire.
+        // Same for email_inbound, which fires once per new message.
         // Skip event_listener triggers -- they are event-driven, not time-based.
-        if trigger.trigger_type == "polling" || trigger.trigger_type == "event_listener" {
+        if matches!(
+            trigger.trigger_type.as_str(),
+            "polling" | "email_inbound" | "event_listener"
+        ) {
             continue;
         }
 
@@ -2050,9 +2291,14 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
             continue;
         }
 
-        // Active window gate: skip triggers outside their configured active hours.
-        // The schedule still advances so triggers don't pile up as overdue.
-        if !trigger.is_within_active_window(now) {
+        // Active window gate: skip triggers outside their configured active hours,
+        // or inside a schedule exclusion window (cron schedules already skip
+        // excluded slots when computing next_trigger_at; this catches interval
+        // schedules). The schedule still advances so triggers don't pile up as
+        // overdue.
+        let excluded =
+            trigger.trigger_type == "schedule" && trigger.parse_config().schedule_excluded_at(now);
+        if excluded || !trigger.is_within_active_window(now) {
             // Anchored on the trigger's prior scheduled fire so intervals keep
             // their cadence even when this slot is skipped (drift fix).
             let next = sched_logic::compute_next_trigger_at(&trigger, now);
@@ -2066,7 +2312,11 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
                 next,
                 trigger.trigger_version,
             );
-            tracing::debug!(trigger_id = %trigger.id, "Trigger outside active window, skipping");
+            tracing::debug!(
+                trigger_id = %trigger.id,
+                excluded,
+                "Trigger outside active window or inside exclusion window, skipping"
+            );
             continue;
         }
 
@@ -2106,13 +2356,20 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
             // monthly spend for the unlimited case (None or 0.0) is wasted
             // work, so short-circuit before touching the DB. schedule_over_budget
             // re-applies the same guard so it is correct in isolation too.
-            let over_budget = if matches!(max_budget, Some(b) if b > 0.0) {
+            // The `warn` enforcement mode never holds back a run.
+            let over_budget = if matches!(max_budget, Some(b) if b > 0.0)
+                && super::budget::enforcement_mode(pool).blocks_admission()
+            {
                 let spend =
                     exec_repo::get_monthly_spend(pool, &trigger.persona_id).unwrap_or(0.0);
                 schedule_over_budget(max_budget, spend)
             } else {
                 false
             };
+            // Workspace cap: the sum of every member's spend. Same skip path,
+            // so backfill replays the slot once the cap is raised or resets.
+            let over_budget =
+                over_budget || super::budget::workspace_blocks(pool, &trigger.persona_id);
 
             if over_budget {
                 tracing::warn!(persona_id = %trigger.persona_id, "Cron agent paused due to exceeded budget");
@@ -2166,6 +2423,36 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
             continue;
         }
 
+        // Maintenance window: fires are still recorded, but a schedule that
+        // already has a fire parked behind the window doesn't need another —
+        // one catch-up run when the window ends, not one per missed slot. The
+        // slot is consumed for the same reasons as the overlap skip above.
+        if trigger.trigger_type == "schedule"
+            && maintenance_repo::has_deferred_trigger_fire(
+                pool,
+                &trigger.id,
+                &trigger.persona_id,
+                &now_str,
+            )
+            .unwrap_or(false)
+        {
+            let next = sched_logic::compute_next_trigger_at(&trigger, now);
+            if let Err(e) =
+                trigger_repo::mark_triggered(pool, &trigger.id, next, trigger.trigger_version)
+            {
+                tracing::error!(
+                    trigger_id = %trigger.id,
+                    "Maintenance coalesce: failed to advance schedule: {}", e
+                );
+            }
+            tracing::debug!(
+                trigger_id = %trigger.id,
+                persona_id = %trigger.persona_id,
+                "Scheduled fire coalesced — an earlier fire is already deferred by maintenance"
+            );
+            continue;
+        }
+
         // Direction 1 (missed-runs visibility): enumerate the full set of slots
         // missed strictly between (last_triggered_at, now], independent of the
         // backfill policy. In the DEFAULT single-catch-up case (backfill_cap ==
@@ -2196,16 +2483,62 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
         // reflects only slots that were genuinely dropped.
         let mut backfill_emitted_for_trigger: usize = 0;
 
+        // Missed-schedule catch-up policy. A gap is either older slots missed
+        // outright or a pending fire well past due — in practice the startup
+        // overdue sweep (the first tick after launch) is where gaps surface.
+        // Triggers without a policy keep the max_backfill behavior below.
+        let catch_up = match &cfg {
+            crate::db::models::TriggerConfig::Schedule { catch_up, .. }
+                if trigger.trigger_type == "schedule" =>
+            {
+                *catch_up
+            }
+            _ => None,
+        }
+        .filter(|_| missed_total > 0 || schedule_fire_overdue(&trigger, now));
+        let mut backfilled_slots: Vec<String> = Vec::new();
+
+        if catch_up == Some(CatchUpPolicy::Skip) {
+            // Every missed fire is dropped, the overdue one included. The slot
+            // is consumed (mark_triggered) so neither backfill nor the missed
+            // count sees it again.
+            let next = sched_logic::compute_next_trigger_at(&trigger, now);
+            match trigger_repo::mark_triggered(pool, &trigger.id, next, trigger.trigger_version) {
+                Ok(true) => record_and_emit_catch_up(
+                    pool,
+                    &trigger,
+                    CatchUpPolicy::Skip,
+                    &[],
+                    missed_total as i64 + 1,
+                    &now_str,
+                ),
+                Ok(false) => tracing::debug!(
+                    trigger_id = %trigger.id,
+                    "Catch-up skip: trigger already claimed by another tick"
+                ),
+                Err(e) => tracing::error!(
+                    trigger_id = %trigger.id,
+                    "Catch-up skip: failed to advance schedule: {}", e
+                ),
+            }
+            continue;
+        }
+
         // 2.5. Backfill catch-up: when max_backfill > 1 AND the trigger has
         // an explicit last_triggered_at, emit catch-up events for any older
         // missed slots strictly between (last_triggered_at, now]. The
         // existing mark_triggered + publish path below handles the most-
         // recent slot as the "live" fire — backfill only emits the EXTRAS.
-        let backfill_cap: usize = match &cfg {
-            crate::db::models::TriggerConfig::Schedule {
-                max_backfill: Some(n),
-                ..
-            } if trigger.trigger_type == "schedule" => crate::engine::limits::cap_with_log(
+        let backfill_cap: usize = match (catch_up, &cfg) {
+            (Some(CatchUpPolicy::RunAllMissed), _) => BACKFILL_HARD_CAP,
+            (Some(_), _) => 1,
+            (
+                None,
+                crate::db::models::TriggerConfig::Schedule {
+                    max_backfill: Some(n),
+                    ..
+                },
+            ) if trigger.trigger_type == "schedule" => crate::engine::limits::cap_with_log(
                 "backfill_hard_cap",
                 *n as usize,
                 BACKFILL_HARD_CAP,
@@ -2259,11 +2592,13 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
 
                         // Per-slot active-window check: don't emit catch-up
                         // events for slots that fell outside the window.
-                        if !trigger.is_within_active_window(*slot) {
+                        if !trigger.is_within_active_window(*slot)
+                            || cfg.schedule_excluded_at(*slot)
+                        {
                             tracing::debug!(
                                 trigger_id = %trigger.id,
                                 slot = %slot,
-                                "Backfill slot skipped — outside active window"
+                                "Backfill slot skipped — outside active window or excluded"
                             );
                             continue;
                         }
@@ -2315,6 +2650,7 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
                                 fired += 1;
                                 backfill_emitted_this_tick += 1;
                                 backfill_emitted_for_trigger += 1;
+                                backfilled_slots.push(slot_iso.clone());
                             }
                             Err(e) => {
                                 tracing::error!(
@@ -2401,7 +2737,18 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
         // gap (missed_total > emitted); a continuously-running scheduler sees
         // missed_total == 0 and records nothing.
         let discarded_missed = missed_total.saturating_sub(backfill_emitted_for_trigger);
-        if discarded_missed > 0 {
+        if let Some(policy) = catch_up {
+            // The live fire below is the catch-up run itself.
+            backfilled_slots.push(now_str.clone());
+            record_and_emit_catch_up(
+                pool,
+                &trigger,
+                policy,
+                &backfilled_slots,
+                discarded_missed as i64,
+                &now_str,
+            );
+        } else if discarded_missed > 0 {
             record_and_emit_missed_runs(pool, &trigger, discarded_missed as i64, &now_str);
         }
 
@@ -2490,7 +2837,7 @@ pub fn trigger_scheduler_tick_counted(scheduler: &SchedulerState, pool: &DbPool)
 /// `default` if the row is absent OR unparseable. Unparseable values emit a
 /// `warn!` so corrupt/legacy values are visible in observability — without
 /// this, a user setting `"90d"` or `"  45 "` silently reverts to the default.
-fn parse_retention_setting(pool: &DbPool, key: &str, default: i64) -> i64 {
+pub(super) fn parse_retention_setting(pool: &DbPool, key: &str, default: i64) -> i64 {
     match settings::get(pool, key).ok().flatten() {
         None => default,
         Some(raw) => match raw.parse::<i64>() {
@@ -2602,31 +2949,18 @@ pub(crate) fn cleanup_tick(pool: &DbPool) {
         }
     }
 
-    // Execution log: configurable retention (default 60 days / 2 months), keep at least 50 per persona
-    let exec_retention_days = parse_retention_setting(
-        pool,
-        settings_keys::EXECUTION_RETENTION_DAYS,
-        settings_keys::EXECUTION_RETENTION_DAYS_DEFAULT,
-    );
-    match exec_repo::cleanup_old_executions(pool, exec_retention_days, 50) {
-        Ok(n) if n > 0 => tracing::info!(
-            "Cleaned up {} old execution records (retention={}d, min_keep=50/persona)",
-            n,
-            exec_retention_days
-        ),
-        Ok(_) => {}
-        Err(e) => tracing::error!("Execution log cleanup error: {}", e),
-    }
-
-    // Message log: prune READ messages older than 90 days (unread are always
-    // kept). persona_messages previously had no retention, so read
-    // notifications grew unbounded.
-    match messages_repo::cleanup_old_messages(pool, 90) {
-        Ok(n) if n > 0 => {
-            tracing::info!("Cleaned up {} old read messages (retention=90d)", n)
-        }
-        Ok(_) => {}
-        Err(e) => tracing::error!("Message log cleanup error: {}", e),
+    // Execution history, execution log files, and read messages: every
+    // retention setting (age + per-persona caps) in one pass. Runs after the
+    // SLA rollup above so pruned rows are already aggregated.
+    let report = super::retention::run_retention(pool);
+    if report.executions_deleted + report.messages_deleted + report.log_files_deleted > 0 {
+        tracing::info!(
+            executions = report.executions_deleted,
+            messages = report.messages_deleted,
+            log_files = report.log_files_deleted,
+            log_bytes = report.log_bytes_reclaimed,
+            "Retention cleanup removed old records"
+        );
     }
 
     // Fix 2: orphan trigger sweep — delete triggers whose owning persona no
@@ -2684,6 +3018,15 @@ pub(crate) fn cleanup_tick(pool: &DbPool) {
         }
     }
 
+    // Memory expiry: archive memories whose `expires_at` has passed. They are
+    // already excluded from injection; this moves them out of the live tiers
+    // and drops their vectors.
+    match crate::db::repos::core::memories::archive_expired(pool) {
+        Ok(n) if n > 0 => tracing::info!("Memory expiry: archived {} expired memory(ies)", n),
+        Ok(_) => {}
+        Err(e) => tracing::error!("Memory expiry sweep error: {}", e),
+    }
+
     // Stuck build-session GC: real, already-promoted personas (e.g. GitHub
     // Issue Sentinel, Tech News Brief) were observed carrying build sessions
     // parked forever at a non-terminal phase (draft_ready / testing / …). Those
@@ -2895,6 +3238,8 @@ mod tests {
             interval_seconds: None,
             timezone: None,
             max_backfill: None,
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -2962,6 +3307,8 @@ mod tests {
             interval_seconds: None,
             timezone: None,
             max_backfill: None,
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -2985,6 +3332,8 @@ mod tests {
             interval_seconds: Some(3600),
             timezone: None,
             max_backfill: Some(10),
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3005,6 +3354,8 @@ mod tests {
             interval_seconds: Some(3600),
             timezone: None,
             max_backfill: Some(10),
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3027,6 +3378,8 @@ mod tests {
             interval_seconds: None,
             timezone: Some("UTC".into()),
             max_backfill: Some(10),
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3058,6 +3411,8 @@ mod tests {
             interval_seconds: None,
             timezone: Some("UTC".into()),
             max_backfill: None, // default → cap 1 → 0 extras
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3082,6 +3437,8 @@ mod tests {
             interval_seconds: None,
             timezone: Some("UTC".into()),
             max_backfill: Some(4),
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3105,6 +3462,8 @@ mod tests {
             interval_seconds: None,
             timezone: Some("UTC".into()),
             max_backfill: None,
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3128,6 +3487,8 @@ mod tests {
             interval_seconds: Some(60),
             timezone: None,
             max_backfill: Some(500),
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -3161,6 +3522,8 @@ mod tests {
             interval_seconds: None,
             timezone: None,
             max_backfill: Some(5),
+            exclusions: Vec::new(),
+            catch_up: None,
             event_type: None,
             payload: None,
         };
@@ -0,0 +1,703 @@
+//! Monthly per-persona budget enforcement.
+//!
+//! Spend is measured with the shared `MONTHLY_SPEND_PREDICATE` (UTC calendar
+//! month, terminal statuses, ops-chat excluded), so every number here matches
+//! the budget UI and the admission gate in `engine::optimizer`.
+//!
+//! [`BUDGET_ENFORCEMENT_MODE`] decides what crossing the cap does:
+//! - `warn`  — nothing is refused; a budget alert is posted once per month.
+//! - `block` — new executions are refused at admission (the default, and the
+//!   behaviour this app always had).
+//! - `pause` — as `block`, and the persona is disabled the moment a run pushes
+//!   it over the cap. [`monthly_reset_tick`] re-enables personas it paused once
+//!   the month rolls over; personas the user disabled themselves are untouched.
+//!
+//! A workspace (team, via `personas.home_team_id`) can additionally carry a
+//! `monthly_budget_usd` cap enforced on the sum of its members' spend. The same
+//! mode applies: admission refuses a member run that would cross the workspace
+//! cap, and in `pause` mode every enabled member is paused when it is crossed.
+
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use ts_rs::TS;
+
+use crate::cloud::push::{self, PushCategory};
+use crate::db::models::{CreateMessageInput, Persona, PersonaTeam, UpdatePersonaInput};
+use crate::db::repos::communication::messages as message_repo;
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::budget_state as budget_repo;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::repos::resources::teams as team_repo;
+use crate::db::settings_keys::{
+    BUDGET_ENFORCEMENT_MODE, BUDGET_ENFORCEMENT_MODE_DEFAULT, BUDGET_PERIOD_LAST_RESET,
+};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::event_registry::event_name;
+use super::types::HealingEventPayload;
+
+/// How a persona's monthly budget cap is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetEnforcementMode {
+    Warn,
+    Block,
+    Pause,
+}
+
+impl BudgetEnforcementMode {
+    /// Parse a stored setting value. Unknown values fall back to `block` (the
+    /// default) so a corrupted row can never silently disable enforcement.
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "warn" => Self::Warn,
+            "pause" => Self::Pause,
+            _ => Self::Block,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warn => "warn",
+            Self::Block => "block",
+            Self::Pause => "pause",
+        }
+    }
+
+    /// Whether an over-budget persona is refused new executions.
+    pub fn blocks_admission(self) -> bool {
+        !matches!(self, Self::Warn)
+    }
+}
+
+/// The configured enforcement mode.
+pub fn enforcement_mode(pool: &DbPool) -> BudgetEnforcementMode {
+    settings::get(pool, BUDGET_ENFORCEMENT_MODE)
+        .ok()
+        .flatten()
+        .map(|v| BudgetEnforcementMode::parse(&v))
+        .unwrap_or_else(|| BudgetEnforcementMode::parse(BUDGET_ENFORCEMENT_MODE_DEFAULT))
+}
+
+/// Billing period label (`YYYY-MM`, UTC) containing `now`.
+pub fn billing_period(now: DateTime<Utc>) -> String {
+    now.format("%Y-%m").to_string()
+}
+
+/// `[start, end)` of the UTC calendar month containing `now`.
+fn month_bounds(now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = Utc
+        .with_ymd_and_hms(now.year(), now.month(), 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now);
+    let (ny, nm) = if now.month() == 12 {
+        (now.year() + 1, 1)
+    } else {
+        (now.year(), now.month() + 1)
+    };
+    let end = Utc
+        .with_ymd_and_hms(ny, nm, 1, 0, 0, 0)
+        .single()
+        .unwrap_or(now);
+    (start, end)
+}
+
+/// Linear end-of-month projection of `spend` observed so far this month. The
+/// elapsed window is floored at one day so a handful of runs in the first
+/// hours of a month don't extrapolate into an absurd projection.
+pub(crate) fn project_month_end(spend: f64, now: DateTime<Utc>) -> f64 {
+    let (start, end) = month_bounds(now);
+    let total = (end - start).num_seconds().max(1) as f64;
+    let elapsed = (now - start).num_seconds().max(86_400) as f64;
+    if elapsed >= total {
+        return spend;
+    }
+    spend * total / elapsed
+}
+
+/// Budget position of one persona for the current billing period.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct BudgetStatus {
+    pub persona_id: String,
+    pub persona_name: String,
+    /// Billing period (`YYYY-MM`, UTC).
+    pub period: String,
+    /// Monthly cap. `None` or `0.0` means unlimited.
+    pub budget_usd: Option<f64>,
+    pub spend_usd: f64,
+    /// `budget - spend`, floored at zero. `None` when unlimited.
+    pub remaining_usd: Option<f64>,
+    /// Linear projection of this month's spend to the end of the month.
+    pub projected_month_end_usd: f64,
+    pub over_budget: bool,
+    /// True when the projection crosses the cap before the month ends.
+    pub projected_over_budget: bool,
+    /// True when budget enforcement (mode `pause`) disabled this persona.
+    pub paused_by_budget: bool,
+    /// `warn` | `block` | `pause`.
+    pub enforcement_mode: String,
+}
+
+fn positive_budget(budget: Option<f64>) -> Option<f64> {
+    budget.filter(|b| *b > 0.0)
+}
+
+/// Budget status for one persona, or every persona when `persona_id` is `None`.
+pub fn get_budget_status(
+    pool: &DbPool,
+    persona_id: Option<&str>,
+) -> Result<Vec<BudgetStatus>, AppError> {
+    let now = Utc::now();
+    let period = billing_period(now);
+    let mode = enforcement_mode(pool);
+    let personas = match persona_id {
+        Some(id) => vec![persona_repo::get_by_id(pool, id)?],
+        None => persona_repo::get_all_lean(pool)?,
+    };
+    let spend_by_persona = exec_repo::get_monthly_spend_by_persona(pool)?;
+
+    personas
+        .into_iter()
+        .map(|p| {
+            let spend = spend_by_persona.get(&p.id).copied().unwrap_or(0.0);
+            let budget = positive_budget(p.max_budget_usd);
+            let projected = project_month_end(spend, now);
+            let paused_by_budget = !p.enabled
+                && budget_repo::get(pool, &p.id)?
+                    .map(|s| s.period == period && s.paused_at.is_some())
+                    .unwrap_or(false);
+            Ok(BudgetStatus {
+                persona_id: p.id,
+                persona_name: p.name,
+                period: period.clone(),
+                budget_usd: p.max_budget_usd,
+                spend_usd: spend,
+                remaining_usd: budget.map(|b| (b - spend).max(0.0)),
+                projected_month_end_usd: projected,
+                over_budget: budget.map(|b| spend >= b).unwrap_or(false),
+                projected_over_budget: budget.map(|b| projected >= b).unwrap_or(false),
+                paused_by_budget,
+                enforcement_mode: mode.as_str().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Post-execution enforcement: once a run has pushed the persona (or its
+/// workspace) over its cap, alert once per billing period and, in `pause`
+/// mode, disable the affected personas.
+pub fn enforce_after_execution(pool: &DbPool, app: &AppHandle, persona_id: &str, exec_id: &str) {
+    let Ok(persona) = persona_repo::get_by_id(pool, persona_id) else {
+        return;
+    };
+    enforce_persona_budget(pool, app, &persona, exec_id);
+    enforce_workspace_budget(pool, app, &persona, exec_id);
+}
+
+fn enforce_persona_budget(pool: &DbPool, app: &AppHandle, persona: &Persona, exec_id: &str) {
+    let persona_id = persona.id.as_str();
+    let Some(budget) = positive_budget(persona.max_budget_usd) else {
+        return;
+    };
+    let monthly_spend = exec_repo::get_monthly_spend(pool, persona_id).unwrap_or(0.0);
+    if monthly_spend < budget {
+        return;
+    }
+
+    let period = billing_period(Utc::now());
+    let mode = enforcement_mode(pool);
+    tracing::warn!(
+        persona_id = %persona_id,
+        monthly_spend = monthly_spend,
+        budget = budget,
+        mode = mode.as_str(),
+        "Budget exceeded for persona"
+    );
+
+    if budget_repo::mark_alerted(pool, persona_id, &period).unwrap_or(false) {
+        let consequence = match mode {
+            BudgetEnforcementMode::Warn => "Runs will continue (enforcement mode: warn).",
+            BudgetEnforcementMode::Block => {
+                "New runs are blocked until the month resets or the budget is raised."
+            }
+            BudgetEnforcementMode::Pause => {
+                "The agent has been paused and will resume automatically next month."
+            }
+        };
+        let content = format!(
+            "Budget alert: {} has spent ${:.4} this month (budget: ${:.2}). {}",
+            persona.name, monthly_spend, budget, consequence
+        );
+        crate::notifications::notify_budget_exceeded(
+            app,
+            persona_id,
+            &persona.name,
+            monthly_spend,
+            budget,
+            "Budget Exceeded",
+            &content,
+        );
+        let _ = message_repo::create(
+            pool,
+            CreateMessageInput {
+                persona_id: persona_id.into(),
+                execution_id: Some(exec_id.into()),
+                title: Some("Budget Exceeded".into()),
+                content,
+                content_type: Some("budget_alert".into()),
+                priority: Some("critical".into()),
+                metadata: None,
+                thread_id: None,
+                use_case_id: None,
+            },
+        );
+    }
+
+    if mode != BudgetEnforcementMode::Pause || !persona.enabled {
+        return;
+    }
+    if let Err(e) = persona_repo::update(
+        pool,
+        persona_id,
+        UpdatePersonaInput {
+            enabled: Some(false),
+            ..Default::default()
+        },
+    ) {
+        tracing::warn!(persona_id = %persona_id, error = %e, "budget: failed to pause persona");
+        return;
+    }
+    let _ = budget_repo::mark_paused(pool, persona_id, &period);
+
+    let fix = "Raise the monthly budget and re-enable the agent, or wait for the next month — it resumes automatically.";
+    let description = format!(
+        "Monthly spend ${monthly_spend:.2} reached the ${budget:.2} budget. The agent was paused by budget enforcement."
+    );
+    let issue = healing_repo::create(
+        pool,
+        persona_id,
+        "Paused: monthly budget exceeded",
+        &description,
+        false,
+        Some("high"),
+        Some("budget"),
+        Some(exec_id),
+        Some(fix),
+    )
+    .ok()
+    .flatten();
+    push::relay(
+        PushCategory::BudgetExceeded,
+        "Paused: monthly budget exceeded",
+        &format!("{}: {description}", persona.name),
+    );
+    let _ = app.emit(
+        event_name::HEALING_EVENT,
+        HealingEventPayload {
+            issue_id: issue.map(|i| i.id).unwrap_or_default(),
+            persona_id: persona_id.into(),
+            execution_id: exec_id.into(),
+            title: "Paused: monthly budget exceeded".into(),
+            action: "budget_paused".into(),
+            auto_fixed: false,
+            severity: "high".into(),
+            suggested_fix: Some(fix.into()),
+            persona_name: persona.name.clone(),
+            description: Some(description),
+            strategy: Some("Persona paused until the budget resets".into()),
+            backoff_seconds: None,
+            retry_number: None,
+            max_retries: None,
+        },
+    );
+}
+
+/// Month-to-date spend rolled up across one workspace (team).
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct GroupSpend {
+    pub team_id: String,
+    pub team_name: String,
+    /// Billing period (`YYYY-MM`, UTC).
+    pub period: String,
+    /// Workspace cap. `None` or `0.0` means unlimited.
+    pub budget_usd: Option<f64>,
+    /// Sum of every member persona's spend this month.
+    pub spend_usd: f64,
+    /// `budget - spend`, floored at zero. `None` when unlimited.
+    pub remaining_usd: Option<f64>,
+    pub projected_month_end_usd: f64,
+    /// Personas whose `home_team_id` is this team.
+    pub member_count: i64,
+    pub over_budget: bool,
+    pub projected_over_budget: bool,
+    /// `warn` | `block` | `pause`.
+    pub enforcement_mode: String,
+}
+
+/// Workspace spend for one team, or every team when `team_id` is `None`.
+pub fn get_group_spend(pool: &DbPool, team_id: Option<&str>) -> Result<Vec<GroupSpend>, AppError> {
+    let now = Utc::now();
+    let period = billing_period(now);
+    let mode = enforcement_mode(pool);
+    let teams = match team_id {
+        Some(id) => vec![team_repo::get_by_id(pool, id)?],
+        None => team_repo::get_all(pool)?,
+    };
+    let personas = persona_repo::get_all_lean(pool)?;
+    let spend_by_persona = exec_repo::get_monthly_spend_by_persona(pool)?;
+
+    Ok(teams
+        .into_iter()
+        .map(|team| {
+            let members = personas
+                .iter()
+                .filter(|p| p.home_team_id.as_deref() == Some(team.id.as_str()));
+            let (member_count, spend) = members.fold((0i64, 0.0f64), |(n, total), p| {
+                (n + 1, total + spend_by_persona.get(&p.id).copied().unwrap_or(0.0))
+            });
+            let budget = positive_budget(team.monthly_budget_usd);
+            let projected = project_month_end(spend, now);
+            GroupSpend {
+                team_id: team.id,
+                team_name: team.name,
+                period: period.clone(),
+                budget_usd: team.monthly_budget_usd,
+                spend_usd: spend,
+                remaining_usd: budget.map(|b| (b - spend).max(0.0)),
+                projected_month_end_usd: projected,
+                member_count,
+                over_budget: budget.map(|b| spend >= b).unwrap_or(false),
+                projected_over_budget: budget.map(|b| projected >= b).unwrap_or(false),
+                enforcement_mode: mode.as_str().to_string(),
+            }
+        })
+        .collect())
+}
+
+/// The persona's workspace and its positive monthly cap, if it has one.
+fn workspace_cap(pool: &DbPool, persona: &Persona) -> Option<(PersonaTeam, f64)> {
+    let team = team_repo::get_by_id(pool, persona.home_team_id.as_deref()?).ok()?;
+    let cap = positive_budget(team.monthly_budget_usd)?;
+    Some((team, cap))
+}
+
+/// Admission gate for the workspace cap: refuse a run when the workspace has
+/// already reached its cap or `estimated_cost` would carry it past. A no-op in
+/// `warn` mode and for personas without a capped workspace.
+pub fn check_workspace_admission(
+    pool: &DbPool,
+    persona: &Persona,
+    estimated_cost: f64,
+) -> Result<(), AppError> {
+    if !enforcement_mode(pool).blocks_admission() {
+        return Ok(());
+    }
+    let Some((team, cap)) = workspace_cap(pool, persona) else {
+        return Ok(());
+    };
+    let spend = exec_repo::get_monthly_spend_by_team(pool, &team.id)?;
+    if !super::optimizer::exceeds_budget(Some(cap), spend, estimated_cost) {
+        return Ok(());
+    }
+    Err(AppError::Validation(if spend >= cap {
+        format!(
+            "Workspace budget exceeded for '{}': ${:.2} spent this month across the workspace, limit is ${:.2}",
+            team.name, spend, cap
+        )
+    } else {
+        format!(
+            "Workspace budget would be exceeded for '{}': ${:.2} spent this month plus an estimated ${:.2} for this run exceeds the ${:.2} limit",
+            team.name, spend, estimated_cost, cap
+        )
+    }))
+}
+
+/// Whether the persona's workspace is at or over its cap under a blocking
+/// mode. Used by the scheduler, which has no per-run estimate.
+pub fn workspace_blocks(pool: &DbPool, persona_id: &str) -> bool {
+    let Ok(persona) = persona_repo::get_by_id(pool, persona_id) else {
+        return false;
+    };
+    check_workspace_admission(pool, &persona, 0.0).is_err()
+}
+
+fn enforce_workspace_budget(pool: &DbPool, app: &AppHandle, persona: &Persona, exec_id: &str) {
+    let Some((team, cap)) = workspace_cap(pool, persona) else {
+        return;
+    };
+    let spend = exec_repo::get_monthly_spend_by_team(pool, &team.id).unwrap_or(0.0);
+    if spend < cap {
+        return;
+    }
+    let period = billing_period(Utc::now());
+    if !budget_repo::mark_team_alerted(pool, &team.id, &period).unwrap_or(false) {
+        return;
+    }
+    let mode = enforcement_mode(pool);
+    tracing::warn!(
+        team_id = %team.id,
+        spend = spend,
+        cap = cap,
+        mode = mode.as_str(),
+        "Workspace budget exceeded"
+    );
+
+    let mut paused = 0usize;
+    if mode == BudgetEnforcementMode::Pause {
+        let members = persona_repo::get_all_lean(pool).unwrap_or_default();
+        for member in members
+            .iter()
+            .filter(|p| p.enabled && p.home_team_id.as_deref() == Some(team.id.as_str()))
+        {
+            let result = persona_repo::update(
+                pool,
+                &member.id,
+                UpdatePersonaInput {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            );
+            if let Err(e) = result {
+                tracing::warn!(persona_id = %member.id, error = %e, "budget: failed to pause workspace member");
+                continue;
+            }
+            let _ = budget_repo::mark_paused(pool, &member.id, &period);
+            paused += 1;
+        }
+    }
+
+    let title = format!("Workspace budget exceeded: {}", team.name);
+    let (strategy, fix) = match mode {
+        BudgetEnforcementMode::Warn => (
+            "Runs continue (enforcement mode: warn)",
+            "Raise the workspace's monthly budget or reduce member activity.",
+        ),
+        BudgetEnforcementMode::Block => (
+            "New runs for workspace members are blocked until the month resets",
+            "Raise the workspace's monthly budget, or wait for the next month.",
+        ),
+        BudgetEnforcementMode::Pause => (
+            "Workspace members paused until the budget resets",
+            "Raise the workspace's monthly budget and re-enable its agents, or wait for the next month — they resume automatically.",
+        ),
+    };
+    let description = format!(
+        "Workspace '{}' spent ${spend:.2} this month across its agents, reaching its ${cap:.2} budget.{}",
+        team.name,
+        if paused > 0 {
+            format!(" {paused} agent(s) were paused by budget enforcement.")
+        } else {
+            String::new()
+        }
+    );
+    let issue = healing_repo::create(
+        pool,
+        &persona.id,
+        &title,
+        &description,
+        false,
+        Some("high"),
+        Some("budget"),
+        Some(exec_id),
+        Some(fix),
+    )
+    .ok()
+    .flatten();
+    push::relay(PushCategory::BudgetExceeded, &title, &description);
+    crate::notifications::notify_budget_exceeded(
+        app,
+        &persona.id,
+        &persona.name,
+        spend,
+        cap,
+        &title,
+        &description,
+    );
+    let _ = app.emit(
+        event_name::HEALING_EVENT,
+        HealingEventPayload {
+            issue_id: issue.map(|i| i.id).unwrap_or_default(),
+            persona_id: persona.id.clone(),
+            execution_id: exec_id.into(),
+            title,
+            action: "workspace_budget_exceeded".into(),
+            auto_fixed: false,
+            severity: "high".into(),
+            suggested_fix: Some(fix.into()),
+            persona_name: persona.name.clone(),
+            description: Some(description),
+            strategy: Some(strategy.into()),
+            backoff_seconds: None,
+            retry_number: None,
+            max_retries: None,
+        },
+    );
+}
+
+/// Month-boundary reset. When the UTC billing period has changed since the
+/// last run, re-enable personas that budget enforcement paused in an earlier
+/// period (if they are still disabled) and drop last period's alert state.
+/// Returns how many personas were resumed.
+pub fn monthly_reset_tick(pool: &DbPool) -> Result<usize, AppError> {
+    let period = billing_period(Utc::now());
+    if settings::get(pool, BUDGET_PERIOD_LAST_RESET)?.as_deref() == Some(period.as_str()) {
+        return Ok(0);
+    }
+
+    let mut resumed = 0;
+    for persona_id in budget_repo::list_paused_outside(pool, &period)? {
+        let Ok(persona) = persona_repo::get_by_id(pool, &persona_id) else {
+            continue;
+        };
+        if persona.enabled {
+            continue;
+        }
+        persona_repo::update(
+            pool,
+            &persona_id,
+            UpdatePersonaInput {
+                enabled: Some(true),
+                ..Default::default()
+            },
+        )?;
+        resumed += 1;
+        tracing::info!(persona_id = %persona_id, period = %period, "budget: resumed persona for new billing period");
+    }
+    budget_repo::clear_outside(pool, &period)?;
+    settings::set(pool, BUDGET_PERIOD_LAST_RESET, &period)?;
+    Ok(resumed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn mode_parsing_defaults_to_block() {
+        assert_eq!(BudgetEnforcementMode::parse("warn"), BudgetEnforcementMode::Warn);
+        assert_eq!(BudgetEnforcementMode::parse("pause"), BudgetEnforcementMode::Pause);
+        assert_eq!(BudgetEnforcementMode::parse("bogus"), BudgetEnforcementMode::Block);
+        assert!(!BudgetEnforcementMode::Warn.blocks_admission());
+        assert!(BudgetEnforcementMode::Pause.blocks_admission());
+    }
+
+    #[test]
+    fn projection_scales_linearly_over_the_month() {
+        // Halfway through a 30-day month → double.
+        let p = project_month_end(10.0, at(2026, 4, 16, 0));
+        assert!((p - 20.0).abs() < 1e-9);
+        // First hours of the month are floored at one elapsed day.
+        let p = project_month_end(1.0, at(2026, 4, 1, 2));
+        assert!((p - 30.0).abs() < 1e-9);
+        // December rolls into the next year.
+        assert_eq!(billing_period(at(2026, 12, 31, 23)), "2026-12");
+        let (start, end) = month_bounds(at(2026, 12, 5, 0));
+        assert_eq!(start, at(2026, 12, 1, 0));
+        assert_eq!(end, at(2027, 1, 1, 0));
+    }
+
+    #[test]
+    fn reset_resumes_only_budget_paused_personas() {
+        let pool = crate::db::init_test_db().unwrap();
+        let paused = crate::db::repos::test_fixtures::create_test_persona(&pool, "Paused", "p");
+        let manual = crate::db::repos::test_fixtures::create_test_persona(&pool, "Manual", "m");
+        for id in [&paused.id, &manual.id] {
+            persona_repo::update(
+                &pool,
+                id,
+                UpdatePersonaInput {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+        budget_repo::mark_paused(&pool, &paused.id, "2000-01").unwrap();
+
+        assert_eq!(monthly_reset_tick(&pool).unwrap(), 1);
+        assert!(persona_repo::get_by_id(&pool, &paused.id).unwrap().enabled);
+        assert!(!persona_repo::get_by_id(&pool, &manual.id).unwrap().enabled);
+        assert!(budget_repo::get(&pool, &paused.id).unwrap().is_none());
+
+        // Same period again is a no-op.
+        assert_eq!(monthly_reset_tick(&pool).unwrap(), 0);
+    }
+
+    #[test]
+    fn workspace_cap_rolls_up_member_spend() {
+        use crate::db::models::CreateTeamInput;
+        use crate::db::repos::test_fixtures::create_test_persona;
+
+        let pool = crate::db::init_test_db().unwrap();
+        let team = team_repo::create(
+            &pool,
+            CreateTeamInput {
+                name: "Ops".into(),
+                project_id: None,
+                parent_team_id: None,
+                description: None,
+                canvas_data: None,
+                team_config: None,
+                icon: None,
+                color: None,
+                enabled: None,
+            },
+        )
+        .unwrap();
+        pool.get()
+            .unwrap()
+            .execute(
+                "UPDATE persona_teams SET monthly_budget_usd = 5.0 WHERE id = ?1",
+                rusqlite::params![team.id],
+            )
+            .unwrap();
+
+        let a = create_test_persona(&pool, "A", "a");
+        let b = create_test_persona(&pool, "B", "b");
+        let outsider = create_test_persona(&pool, "Outsider", "o");
+        for id in [&a.id, &b.id] {
+            persona_repo::update(
+                &pool,
+                id,
+                UpdatePersonaInput {
+                    home_team_id: Some(Some(team.id.clone())),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+        for (persona_id, cost) in [(&a.id, 3.0), (&b.id, 1.0), (&outsider.id, 10.0)] {
+            let exec = exec_repo::create(&pool, persona_id, None, None, None, None).unwrap();
+            pool.get()
+                .unwrap()
+                .execute(
+                    "UPDATE persona_executions SET status = 'completed', cost_usd = ?2 WHERE id = ?1",
+                    rusqlite::params![exec.id, cost],
+                )
+                .unwrap();
+        }
+
+        let rollup = get_group_spend(&pool, Some(&team.id)).unwrap();
+        assert_eq!(rollup.len(), 1);
+        assert_eq!(rollup[0].member_count, 2);
+        assert!((rollup[0].spend_usd - 4.0).abs() < 1e-9);
+        assert!(!rollup[0].over_budget);
+        assert!((exec_repo::get_monthly_spend_by_team(&pool, &team.id).unwrap() - 4.0).abs() < 1e-9);
+
+        let a = persona_repo::get_by_id(&pool, &a.id).unwrap();
+        assert!(check_workspace_admission(&pool, &a, 0.5).is_ok());
+        assert!(check_workspace_admission(&pool, &a, 2.0).is_err());
+        assert!(!workspace_blocks(&pool, &a.id));
+        // The outsider's own spend never touches the workspace cap.
+        assert!(check_workspace_admission(&pool, &outsider, 100.0).is_ok());
+    }
+}
@@ -199,6 +199,7 @@ impl BuildSessionManager {
             mode: normalized_mode,
             companion_session_id: companion_session_id.clone(),
             disabled_dims_json: None,
+            engine: None,
             // Build telemetry (Phase 0) — populated as the build runs.
             phase_timings_json: None,
             total_cost_usd: None,
@@ -29,6 +29,7 @@ use std::time::Instant;
 use crate::db::models::ChainConditionType;
 use crate::db::models::CreatePersonaEventInput;
 use crate::db::repos::communication::events as event_repo;
+use crate::db::repos::execution::executions as exec_repo;
 use crate::db::repos::resources::triggers as trigger_repo;
 use crate::db::DbPool;
 use crate::engine::lifecycle::TriggerStatus;
@@ -79,6 +80,9 @@ pub mod stop_reason {
     /// budget (`healing::MAX_RETRY_COUNT`) without recovering the link — the
     /// capped counterpart of [`HEALING_ABANDONED`].
     pub const HEALING_CAPPED: &str = "healing_capped";
+    /// The chain's absolute deadline (`_deadline`, see `engine::deadline`) had
+    /// already passed when the source link completed, so no further link fires.
+    pub const DEADLINE_EXCEEDED: &str = "deadline_exceeded";
 }
 
 use crate::db::repos::execution::chain_stop_reasons::{self, ChainStopReasonInput};
@@ -238,6 +242,17 @@ pub fn evaluate_chain_triggers(
         metrics.duration_ms = hop_start.elapsed().as_millis() as u64;
         return metrics;
     }
+    // Team pipelines chained off this persona (system-op chain automations).
+    // A pipeline chained off one of its own members can't re-enter: a team
+    // runs one pipeline at a time, so the launch is refused while it runs.
+    super::system_ops::fire_chain_automations(
+        pool,
+        source_persona_id,
+        execution_status,
+        execution_output,
+        execution_id,
+    );
+
     // Get only enabled chain triggers matching this source persona (filtered at SQL level)
     let chain_triggers = match trigger_repo::get_chain_triggers_for_source(pool, source_persona_id)
     {
@@ -256,6 +271,34 @@ pub fn evaluate_chain_triggers(
 
     metrics.triggers_evaluated = chain_triggers.len() as u32;
 
+    // Absolute deadline inherited from the firing that started this chain. It
+    // rides in the source execution's input; once passed, no downstream link
+    // could finish in time, so halt here instead of queueing doomed work.
+    let deadline = exec_repo::get_by_id(pool, execution_id)
+        .ok()
+        .and_then(|e| super::deadline::from_input_str(e.input_data.as_deref()));
+    if let Some(d) = deadline {
+        if super::deadline::remaining_ms(d, chrono::Utc::now()).is_none() {
+            tracing::warn!(
+                source_persona_id = %source_persona_id,
+                chain_depth,
+                deadline = %d.to_rfc3339(),
+                "Chain deadline passed; halting cascade"
+            );
+            record_stop(
+                None,
+                None,
+                stop_reason::DEADLINE_EXCEEDED,
+                Some(format!(
+                    "chain deadline {} passed before this link completed",
+                    d.to_rfc3339()
+                )),
+            );
+            metrics.duration_ms = hop_start.elapsed().as_millis() as u64;
+            return metrics;
+        }
+    }
+
     // Direction 3: chain COST ceiling. The depth-8 limit bounds hop COUNT; this
     // is the only brake on runaway SPEND. If the running total (which already
     // includes the just-completed hop) has reached the configured ceiling, halt
@@ -501,6 +544,9 @@ pub fn evaluate_chain_triggers(
                 }
                 // Propagate the running chain cost so the next hop accumulates.
                 val["_chain_cost_usd"] = serde_json::json!(chain_cost_usd);
+                if let Some(d) = deadline {
+                    super::deadline::stamp(&mut val, d);
+                }
                 val.to_string()
             })
         } else {
@@ -516,6 +562,9 @@ pub fn evaluate_chain_triggers(
             }
             // Propagate the running chain cost so the next hop accumulates.
             val["_chain_cost_usd"] = serde_json::json!(chain_cost_usd);
+            if let Some(d) = deadline {
+                super::deadline::stamp(&mut val, d);
+            }
             Some(val.to_string())
         };
 
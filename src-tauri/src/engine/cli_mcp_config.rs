@@ -133,8 +133,11 @@ pub fn note_sidecar_missing(persona_id: &str) -> usize {
 /// targets it (found in the 2026-07-10 bug-hunt scan). Project-specific MCP
 /// servers should go through the credential-managed `mcp_gateways` flow,
 /// which the user explicitly configures per-server.
+#[allow(clippy::too_many_arguments)]
 pub fn install_mcp_sidecar(
     exec_dir: &Path,
+    persona_id: &str,
+    execution_id: &str,
     drive_root: Option<&Path>,
     _project_root: Option<&Path>,
     api_key: Option<&str>,
@@ -189,6 +192,17 @@ pub fn install_mcp_sidecar(
             "PERSONAS_API_KEY".to_string(),
             serde_json::Value::String(key.to_string()),
         );
+        // The sidecar's `http_request` and `sql_query` forward to the bridge
+        // under this persona and run, so the engine applies the persona's
+        // policies and the run's approvals.
+        env_map.insert(
+            "PERSONAS_PERSONA_ID".to_string(),
+            serde_json::Value::String(persona_id.to_string()),
+        );
+        env_map.insert(
+            "PERSONAS_EXECUTION_ID".to_string(),
+            serde_json::Value::String(execution_id.to_string()),
+        );
     }
     // Codebase pin: the executing persona's `design_context.dev_project_id`.
     // The sidecar's `resolve_context_project` reads this env first so a persona
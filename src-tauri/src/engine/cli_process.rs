@@ -308,6 +308,7 @@ pub fn spawn_headless_claude(
     }
     // Mandatory — see doc comment above. No caller may opt out.
     force_subscription_auth(&mut cmd);
+    super::process_reaper::tag_command(&mut cmd);
 
     let mut child = cmd.spawn().map_err(|e| {
         if e.kind() == std::io::ErrorKind::NotFound {
@@ -388,6 +389,7 @@ pub async fn run_claude_cli(
     cmd.env_remove("CLAUDE_CODE");
     // Subscription-only billing safety invariant -- never bill the API account.
     force_subscription_auth(&mut cmd);
+    super::process_reaper::tag_command(&mut cmd);
 
     let mut child = cmd.spawn().map_err(|e| {
         if e.kind() == std::io::ErrorKind::NotFound {
@@ -560,8 +562,14 @@ impl CliProcessDriver {
         // Always force the subscription path — strip any API-account auth that
         // was inherited from the OS env or slipped through env_overrides.
         force_subscription_auth(&mut cmd);
+        // Spawn tag lets the orphan reaper find this CLI if tracking is lost.
+        super::process_reaper::tag_command(&mut cmd);
+        // Own process group / job object so cancellation kills the whole tree.
+        super::process_tree::isolate(&mut cmd);
 
-        cmd.spawn()
+        let child = cmd.spawn()?;
+        super::process_tree::attach(&child);
+        Ok(child)
     }
 
     /// Build and spawn with stderr discarded (piped to null).
@@ -638,8 +646,11 @@ impl CliProcessDriver {
         cancelled.load(Ordering::Acquire)
     }
 
-    /// Kill the child process and wait for it to exit.
+    /// Kill the child process (and its descendants) and wait for it to exit.
     pub async fn kill(&mut self) {
+        if let Some(pid) = self.pid {
+            super::process_tree::kill_tree(pid);
+        }
         let _ = self.child.kill().await;
         let _ = self.child.wait().await;
     }
@@ -692,6 +703,14 @@ impl CliProcessDriver {
     }
 }
 
+impl Drop for CliProcessDriver {
+    fn drop(&mut self) {
+        if let Some(pid) = self.pid {
+            super::process_tree::release(pid);
+        }
+    }
+}
+
 // =============================================================================
 // Tests — pin the subscription-auth guarantee (user directive 2026-06-11):
 // no spawned CLI may inherit or be handed API-account auth env vars.
@@ -20,7 +20,7 @@ use crate::db::models::{CreatePersonaEventInput, PersonaEvent};
 use crate::db::repos::communication::events as event_repo;
 use crate::db::repos::resources::cloud_webhook_watermarks as watermark_repo;
 use crate::db::DbPool;
-use crate::engine::crypto;
+use crate::engine::project_keys;
 use crate::error::AppError;
 
 // ---------------------------------------------------------------------------
@@ -434,17 +434,9 @@ fn publish_and_upsert_watermark(
     let now = chrono::Utc::now().to_rfc3339();
     let project_id = input.project_id.unwrap_or_else(|| "default".into());
 
-    // Encrypt payload at rest if present
-    let (stored_payload, payload_iv) = match &input.payload {
-        Some(plaintext) if !plaintext.is_empty() => match crypto::encrypt_for_db(plaintext) {
-            Ok((ct, iv)) => (Some(ct), Some(iv)),
-            Err(e) => {
-                tracing::warn!("Failed to encrypt event payload, storing plaintext: {}", e);
-                (Some(plaintext.clone()), None)
-            }
-        },
-        other => (other.clone(), None),
-    };
+    // Encrypt payload at rest under the project's data key
+    let (stored_payload, payload_iv) =
+        project_keys::encrypt_event_payload(pool, &project_id, &input.payload);
 
     let mut conn = pool.get()?;
     let tx = conn.transaction().map_err(AppError::Database)?;
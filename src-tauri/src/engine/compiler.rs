@@ -229,6 +229,8 @@ mod tests {
             setup_status: "ready".to_string(),
             setup_detail: None,
             disabled_dims_json: None,
+            engine: None,
+            execution_placement: None,
             created_at: "2026-01-01T00:00:00Z".into(),
             updated_at: "2026-01-01T00:00:00Z".into(),
         }
@@ -0,0 +1,229 @@
+//! Connector-scoped request budgets shared by every persona.
+//!
+//! `api_proxy` already caps each credential on its own; this caps each
+//! connector (`service_type`) across all of its credentials and all personas,
+//! so five personas working the same Slack workspace draw from one budget
+//! instead of five. A caller over budget is queued rather than rejected: it
+//! reserves the next free slot and sleeps until it comes up. Only a wait
+//! longer than [`MAX_QUEUE_WAIT`] fails with `RateLimited`.
+//!
+//! The budget is requests per minute from the `connector_rate_limit:<name>`
+//! setting, falling back to `shared_rate_limit_rpm` in the connector's
+//! metadata. Neither set means no shared limit. An upstream 429 pauses the
+//! whole connector for its `Retry-After` ([`note_retry_after`]).
+//!
+//! Scheduling is GCRA (a token bucket expressed as one timestamp per
+//! connector): each request pushes the connector's theoretical arrival time
+//! forward by `60s / rpm`, with a burst allowance of [`BURST_SECS`] worth of
+//! requests.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys::CONNECTOR_RATE_LIMIT_PREFIX;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Longest a call will queue for a slot before it is refused.
+pub const MAX_QUEUE_WAIT: Duration = Duration::from_secs(30);
+/// Seconds of budget that may be spent back to back before calls are spaced.
+const BURST_SECS: u64 = 10;
+/// Pause applied on a 429 without a usable `Retry-After`.
+pub const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(5);
+/// Longest upstream `Retry-After` honoured; anything larger is clamped.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(600);
+/// Schedules idle this long are dropped on the next sweep.
+const IDLE_EVICTION: Duration = Duration::from_secs(600);
+/// Sweep once the map grows past this many connectors.
+const SWEEP_THRESHOLD: usize = 256;
+
+struct Schedule {
+    /// Theoretical arrival time of the next request at the steady rate.
+    tat: Instant,
+    /// Set by an upstream 429; folded into `tat` on the next reservation,
+    /// once the connector's rate (and so its burst allowance) is known.
+    paused_until: Option<Instant>,
+}
+
+static SCHEDULES: LazyLock<Mutex<HashMap<String, Schedule>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The shared requests-per-minute budget for `connector`, if any.
+pub fn limit_for(pool: &DbPool, connector: &str) -> Option<u32> {
+    let key = format!("{CONNECTOR_RATE_LIMIT_PREFIX}{connector}");
+    if let Some(value) = settings::get(pool, &key).ok().flatten() {
+        // An explicit `0` turns off a limit the connector metadata sets.
+        return value.trim().parse::<u32>().ok().filter(|rpm| *rpm > 0);
+    }
+    let connectors = super::api_proxy::get_all_connectors_cached(pool).ok()?;
+    let metadata = connectors
+        .iter()
+        .find(|c| c.name == connector)?
+        .metadata
+        .as_deref();
+    metadata_limit(metadata)
+}
+
+/// `shared_rate_limit_rpm` from connector metadata JSON.
+fn metadata_limit(metadata: Option<&str>) -> Option<u32> {
+    metadata
+        .and_then(|m| serde_json::from_str::<serde_json::Value>(m).ok())
+        .and_then(|v| v.get("shared_rate_limit_rpm")?.as_u64())
+        .filter(|rpm| *rpm > 0)
+        .map(|rpm| rpm.min(10_000) as u32)
+}
+
+/// Wait for a slot in `connector`'s shared budget. Returns immediately when
+/// the connector has no shared limit.
+pub async fn acquire(pool: &DbPool, connector: &str) -> Result<(), AppError> {
+    let Some(rpm) = limit_for(pool, connector) else {
+        return Ok(());
+    };
+    match reserve(connector, rpm, Instant::now()) {
+        Ok(wait) if wait.is_zero() => Ok(()),
+        Ok(wait) => {
+            tracing::debug!(
+                connector = %connector,
+                wait_ms = wait.as_millis() as u64,
+                rpm,
+                "Connector rate limit: queued"
+            );
+            tokio::time::sleep(wait).await;
+            Ok(())
+        }
+        Err(retry_after) => {
+            tracing::warn!(
+                connector = %connector,
+                retry_after_secs = retry_after,
+                rpm,
+                "Connector rate limit: queue full, request refused"
+            );
+            Err(AppError::RateLimited(format!(
+                "Connector '{connector}' is at its shared limit ({rpm} req/min). \
+                 Retry after {retry_after} second(s)."
+            )))
+        }
+    }
+}
+
+/// Pause every caller of `connector` for `retry_after` after the upstream
+/// API answered 429. Pauses only ever extend, never shorten, the schedule.
+pub fn note_retry_after(connector: &str, retry_after: Duration) {
+    let now = Instant::now();
+    let mut schedules = SCHEDULES.lock().unwrap_or_else(|e| e.into_inner());
+    let schedule = schedules.entry(connector.to_string()).or_insert(Schedule {
+        tat: now,
+        paused_until: None,
+    });
+    let until = now + retry_after.min(MAX_RETRY_AFTER);
+    schedule.paused_until = Some(schedule.paused_until.map_or(until, |p| p.max(until)));
+    tracing::info!(
+        connector = %connector,
+        retry_after_secs = retry_after.as_secs(),
+        "Connector rate limit: upstream 429, pausing connector"
+    );
+}
+
+/// Seconds from a `Retry-After` header, when it holds a delay. HTTP-date
+/// values are ignored; callers fall back to [`DEFAULT_RETRY_AFTER`].
+pub fn parse_retry_after(value: Option<&str>) -> Duration {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RETRY_AFTER)
+}
+
+/// Reserve the next slot for `connector` at `rpm`. `Ok` carries how long the
+/// caller must wait for it; `Err` the seconds until a slot is within
+/// [`MAX_QUEUE_WAIT`] (nothing is reserved in that case).
+fn reserve(connector: &str, rpm: u32, now: Instant) -> Result<Duration, u64> {
+    let interval = Duration::from_secs(60) / rpm.max(1);
+    let burst = (u64::from(rpm) * BURST_SECS / 60).max(1) as u32;
+    let tolerance = interval * (burst - 1);
+
+    let mut schedules = SCHEDULES.lock().unwrap_or_else(|e| e.into_inner());
+    if schedules.len() > SWEEP_THRESHOLD {
+        schedules.retain(|_, s| s.tat + IDLE_EVICTION > now);
+    }
+    let schedule = schedules.entry(connector.to_string()).or_insert(Schedule {
+        tat: now,
+        paused_until: None,
+    });
+    if let Some(until) = schedule.paused_until.take() {
+        // Resume without a burst: the first call after the pause waits for
+        // it to end and later ones are spaced at the steady rate.
+        schedule.tat = schedule.tat.max(until + tolerance);
+    }
+
+    let tat = schedule.tat.max(now);
+    let wait = tat.saturating_duration_since(now + tolerance);
+    if wait > MAX_QUEUE_WAIT {
+        return Err((wait - MAX_QUEUE_WAIT).as_secs().max(1));
+    }
+    schedule.tat = tat + interval;
+    Ok(wait)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bursts_then_spaces_requests() {
+        let now = Instant::now();
+        // 60 rpm: a 10-request burst, then one slot per second.
+        for _ in 0..10 {
+            assert_eq!(reserve("test-burst", 60, now), Ok(Duration::ZERO));
+        }
+        assert_eq!(reserve("test-burst", 60, now), Ok(Duration::from_secs(1)));
+        assert_eq!(reserve("test-burst", 60, now), Ok(Duration::from_secs(2)));
+        // Other connectors are unaffected.
+        assert_eq!(reserve("test-burst-other", 60, now), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn refuses_past_the_queue_limit_without_reserving() {
+        let now = Instant::now();
+        // 2 rpm: one slot every 30s, burst of one.
+        assert_eq!(reserve("test-full", 2, now), Ok(Duration::ZERO));
+        assert_eq!(reserve("test-full", 2, now), Ok(Duration::from_secs(30)));
+        assert!(reserve("test-full", 2, now).is_err());
+        // The refusal reserved nothing: 30s on, the next slot is 30s out.
+        let later = now + Duration::from_secs(30);
+        assert_eq!(reserve("test-full", 2, later), Ok(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn upstream_429_pauses_the_connector() {
+        let now = Instant::now();
+        note_retry_after("test-429", Duration::from_secs(20));
+        let wait = reserve("test-429", 600, now).unwrap();
+        assert!(wait >= Duration::from_secs(20) && wait < Duration::from_secs(21));
+        // Resumes at the steady rate rather than with a fresh burst.
+        let next = reserve("test-429", 600, now).unwrap();
+        assert_eq!(next - wait, Duration::from_millis(100));
+    }
+
+    #[test]
+    fn reads_limits_from_metadata_and_retry_after() {
+        assert_eq!(
+            metadata_limit(Some(r#"{"shared_rate_limit_rpm": 120}"#)),
+            Some(120)
+        );
+        assert_eq!(
+            metadata_limit(Some(r#"{"shared_rate_limit_rpm": 0}"#)),
+            None
+        );
+        assert_eq!(metadata_limit(Some(r#"{"rate_limit_rpm": 60}"#)), None);
+        assert_eq!(metadata_limit(None), None);
+
+        assert_eq!(parse_retry_after(Some(" 12 ")), Duration::from_secs(12));
+        assert_eq!(
+            parse_retry_after(Some("Wed, 21 Oct 2026 07:28:00 GMT")),
+            DEFAULT_RETRY_AFTER
+        );
+        assert_eq!(parse_retry_after(None), DEFAULT_RETRY_AFTER);
+    }
+}
@@ -27,6 +27,9 @@ pub struct ResolvedToken {
     /// alongside the access_token. Must be persisted to avoid credential death
     /// when providers enforce refresh token rotation (RFC 6749 Section 6).
     pub refresh_token: Option<String>,
+    /// Seconds until the refresh token itself expires (`refresh_token_expires_in`),
+    /// after which the user must re-authorize. Only some providers report it.
+    pub refresh_token_expires_in_secs: Option<u64>,
 }
 
 impl ResolvedToken {
@@ -35,6 +38,7 @@ impl ResolvedToken {
             token,
             expires_in_secs: None,
             refresh_token: None,
+            refresh_token_expires_in_secs: None,
         }
     }
 
@@ -44,6 +48,7 @@ impl ResolvedToken {
             token,
             expires_in_secs: Some(expires_in_secs),
             refresh_token: None,
+            refresh_token_expires_in_secs: None,
         }
     }
 }
@@ -576,10 +581,15 @@ async fn exchange_oauth_refresh_token(
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
+    let refresh_token_expires_in = value
+        .get("refresh_token_expires_in")
+        .and_then(|v| v.as_u64());
+
     Ok(ResolvedToken {
         token,
         expires_in_secs: expires_in,
         refresh_token: new_refresh_token,
+        refresh_token_expires_in_secs: refresh_token_expires_in,
     })
 }
 
@@ -0,0 +1,444 @@
+//! Container sandboxing for persona executions.
+//!
+//! A risky persona can run its CLI inside a Docker or Podman container instead
+//! of on the host. The setting lives under `execution_sandbox:<persona_id>`:
+//!
+//! ```json
+//! {"image": "ghcr.io/acme/claude-cli:latest", "runtime": "auto",
+//!  "network": "bridge", "memory_mb": 2048, "cpus": 2, "fallback": "host"}
+//! ```
+//!
+//! The container sees only the execution's working directory (mounted at the
+//! same path, so absolute paths in CLI args keep working) and the CLI's own
+//! config directory, runs as the host user with every capability dropped, and
+//! joins `network` rather than the host's network namespace. `"none"` cuts the
+//! container off entirely — including from the AI provider, so it only suits
+//! CLIs pointed at an endpoint reachable without a network.
+//!
+//! When no runtime is usable the `fallback` decides: `host` (the default) runs
+//! the CLI on the host and says so in the execution log, `fail` fails the run
+//! before any provider is tried. Runtime availability is probed lazily and
+//! cached briefly; `system_health_check` reports it.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::types::CliArgs;
+use crate::db::repos::core::settings;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+
+/// How long a runtime probe result is reused.
+const PROBE_TTL: Duration = Duration::from_secs(60);
+/// Home directory of the CLI inside the container.
+const CONTAINER_HOME: &str = "/personas-home";
+/// Process cap inside the container (fork-bomb guard).
+const PIDS_LIMIT: u32 = 512;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimePreference {
+    /// Docker, then Podman.
+    #[default]
+    Auto,
+    Docker,
+    Podman,
+}
+
+/// What to do when no container runtime is usable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SandboxFallback {
+    #[default]
+    Host,
+    Fail,
+}
+
+/// A persona's container sandbox as stored in settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SandboxConfig {
+    /// Image providing the CLI on its `PATH`.
+    pub image: String,
+    #[serde(default)]
+    pub runtime: RuntimePreference,
+    /// Container network: `bridge`, `none`, or a user-defined network.
+    #[serde(default = "default_network")]
+    pub network: String,
+    #[serde(default)]
+    pub memory_mb: Option<u32>,
+    #[serde(default)]
+    pub cpus: Option<f32>,
+    #[serde(default)]
+    pub fallback: SandboxFallback,
+}
+
+fn default_network() -> String {
+    "bridge".into()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuntimeKind {
+    Docker,
+    Podman,
+}
+
+impl RuntimeKind {
+    pub fn binary(self) -> &'static str {
+        match self {
+            RuntimeKind::Docker => "docker",
+            RuntimeKind::Podman => "podman",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RuntimeKind::Docker => "Docker",
+            RuntimeKind::Podman => "Podman",
+        }
+    }
+}
+
+/// A container runtime that answered its probe.
+#[derive(Debug, Clone)]
+pub struct ContainerRuntime {
+    pub kind: RuntimeKind,
+    pub version: String,
+}
+
+/// How a run's CLI is spawned.
+#[derive(Debug, Clone)]
+pub enum SandboxPlan {
+    /// No sandbox configured.
+    Host,
+    /// Sandbox configured but unavailable; running on the host instead.
+    HostFallback(String),
+    Container(SandboxConfig, ContainerRuntime),
+}
+
+/// Container names of running sandboxed executions, for cancellation.
+static RUNNING: LazyLock<Mutex<HashMap<String, (RuntimeKind, String)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static PROBES: LazyLock<Mutex<HashMap<RuntimeKind, (Instant, Result<String, String>)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn is_plain_name(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with('-')
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | ':' | '@'))
+}
+
+/// Parse a stored sandbox config, rejecting values that would inject runtime
+/// flags or set unusable limits.
+pub fn parse(raw: &str) -> Result<SandboxConfig, String> {
+    let config: SandboxConfig =
+        serde_json::from_str(raw).map_err(|e| format!("invalid sandbox config: {e}"))?;
+    if !is_plain_name(&config.image) {
+        return Err(format!(
+            "sandbox image {:?} is not a valid image reference",
+            config.image
+        ));
+    }
+    if !is_plain_name(&config.network) || config.network.contains(['/', ':', '@']) {
+        return Err(format!(
+            "sandbox network {:?} is not a valid network name",
+            config.network
+        ));
+    }
+    if config.network == "host" {
+        return Err("sandbox network \"host\" would share the host's network".into());
+    }
+    if config.memory_mb.is_some_and(|m| m < 256) {
+        return Err("sandbox memory_mb must be at least 256".into());
+    }
+    if config.cpus.is_some_and(|c| c <= 0.0 || c > 64.0) {
+        return Err("sandbox cpus must be between 0 and 64".into());
+    }
+    Ok(config)
+}
+
+/// The persona's sandbox config, if one is set. A stored value that no longer
+/// parses is logged and ignored.
+pub fn load(pool: &DbPool, persona_id: &str) -> Option<SandboxConfig> {
+    let key = format!("{}{persona_id}", settings_keys::EXECUTION_SANDBOX_PREFIX);
+    let raw = settings::get(pool, &key).ok().flatten()?;
+    match parse(&raw) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            tracing::warn!(persona_id = %persona_id, "Ignoring execution sandbox: {e}");
+            None
+        }
+    }
+}
+
+/// Ask a runtime for its version. Docker must reach its daemon; Podman is
+/// daemonless, so a working client is enough.
+fn probe_uncached(kind: RuntimeKind) -> Result<String, String> {
+    let format = match kind {
+        RuntimeKind::Docker => "{{.Server.Version}}",
+        RuntimeKind::Podman => "{{.Client.Version}}",
+    };
+    let output = std::process::Command::new(kind.binary())
+        .args(["version", "--format", format])
+        .output()
+        .map_err(|e| format!("{} is not installed ({e})", kind.binary()))?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if output.status.success() && !stdout.is_empty() {
+        Ok(stdout)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().next().unwrap_or("no output").trim();
+        Err(format!(
+            "{} is installed but not usable: {reason}",
+            kind.binary()
+        ))
+    }
+}
+
+fn probe(kind: RuntimeKind) -> Result<String, String> {
+    if let Some((at, result)) = PROBES.lock().unwrap_or_else(|e| e.into_inner()).get(&kind) {
+        if at.elapsed() < PROBE_TTL {
+            return result.clone();
+        }
+    }
+    let result = probe_uncached(kind);
+    PROBES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(kind, (Instant::now(), result.clone()));
+    result
+}
+
+/// First usable runtime for `preference`, or the reasons none is. Blocking.
+pub fn detect(preference: RuntimePreference) -> Result<ContainerRuntime, String> {
+    if !cfg!(unix) {
+        return Err("container sandboxing is only supported on macOS and Linux".into());
+    }
+    let candidates: &[RuntimeKind] = match preference {
+        RuntimePreference::Auto => &[RuntimeKind::Docker, RuntimeKind::Podman],
+        RuntimePreference::Docker => &[RuntimeKind::Docker],
+        RuntimePreference::Podman => &[RuntimeKind::Podman],
+    };
+    let mut reasons = Vec::new();
+    for &kind in candidates {
+        match probe(kind) {
+            Ok(version) => return Ok(ContainerRuntime { kind, version }),
+            Err(reason) => reasons.push(reason),
+        }
+    }
+    Err(reasons.join("; "))
+}
+
+/// Decide how the persona's CLI is spawned. `Err` means the persona requires a
+/// container and none is usable.
+pub async fn resolve(pool: &DbPool, persona_id: &str) -> Result<SandboxPlan, String> {
+    let Some(config) = load(pool, persona_id) else {
+        return Ok(SandboxPlan::Host);
+    };
+    let preference = config.runtime;
+    let detected = tokio::task::spawn_blocking(move || detect(preference))
+        .await
+        .unwrap_or_else(|e| Err(format!("runtime probe panicked: {e}")));
+    match detected {
+        Ok(runtime) => Ok(SandboxPlan::Container(config, runtime)),
+        Err(reason) => match config.fallback {
+            SandboxFallback::Host => Ok(SandboxPlan::HostFallback(reason)),
+            SandboxFallback::Fail => Err(format!(
+                "Container sandbox unavailable and this persona is set to fail closed ({reason})"
+            )),
+        },
+    }
+}
+
+/// Removes the execution from the running-container registry when the run
+/// ends, however it ends.
+pub struct ContainerGuard {
+    execution_id: String,
+}
+
+impl Drop for ContainerGuard {
+    fn drop(&mut self) {
+        RUNNING
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&self.execution_id);
+    }
+}
+
+#[cfg(unix)]
+fn user_args(kind: RuntimeKind) -> Vec<String> {
+    match kind {
+        // Rootless Podman maps the host user into the container itself.
+        RuntimeKind::Podman => vec!["--userns=keep-id".into()],
+        RuntimeKind::Docker => {
+            let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+            vec!["--user".into(), format!("{uid}:{gid}")]
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn user_args(_kind: RuntimeKind) -> Vec<String> {
+    Vec::new()
+}
+
+/// Rewrite `cli_args` to run the same CLI inside a container. Environment
+/// overrides stay on the runtime client and are forwarded by name only, so
+/// secrets never appear on a command line.
+pub fn wrap(
+    cli_args: &CliArgs,
+    exec_dir: &Path,
+    execution_id: &str,
+    config: &SandboxConfig,
+    runtime: &ContainerRuntime,
+) -> (CliArgs, ContainerGuard) {
+    let name = format!(
+        "personas-exec-{}-{}",
+        execution_id,
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    );
+    let dir = exec_dir.to_string_lossy().to_string();
+    let mut args: Vec<String> = vec![
+        "run".into(),
+        "--rm".into(),
+        "-i".into(),
+        "--name".into(),
+        name.clone(),
+        "--network".into(),
+        config.network.clone(),
+        "--cap-drop".into(),
+        "ALL".into(),
+        "--security-opt".into(),
+        "no-new-privileges".into(),
+        "--pids-limit".into(),
+        PIDS_LIMIT.to_string(),
+        "-v".into(),
+        format!("{dir}:{dir}"),
+        "-w".into(),
+        dir,
+        "-e".into(),
+        format!("HOME={CONTAINER_HOME}"),
+    ];
+    args.extend(user_args(runtime.kind));
+    if let Some(mb) = config.memory_mb {
+        args.push("--memory".into());
+        args.push(format!("{mb}m"));
+    }
+    if let Some(cpus) = config.cpus {
+        args.push("--cpus".into());
+        args.push(cpus.to_string());
+    }
+    // The CLI's login state: `~/.claude/` and `~/.claude.json`.
+    if let Some(home) = dirs::home_dir() {
+        for entry in [".claude", ".claude.json"] {
+            let host = home.join(entry);
+            if host.exists() {
+                args.push("-v".into());
+                args.push(format!(
+                    "{}:{CONTAINER_HOME}/{entry}",
+                    host.to_string_lossy()
+                ));
+            }
+        }
+    }
+    for (key, _) in &cli_args.env_overrides {
+        args.push("-e".into());
+        args.push(key.clone());
+    }
+    args.push(config.image.clone());
+    // The host may have resolved the CLI to an absolute path; inside the
+    // image it is found on PATH.
+    let command = Path::new(&cli_args.command)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| cli_args.command.clone());
+    args.push(command);
+    args.extend(cli_args.args.iter().cloned());
+
+    RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(execution_id.to_string(), (runtime.kind, name));
+
+    let wrapped = CliArgs {
+        command: runtime.kind.binary().to_string(),
+        args,
+        env_overrides: cli_args.env_overrides.clone(),
+        env_removals: cli_args.env_removals.clone(),
+        cwd: cli_args.cwd.clone(),
+    };
+    (
+        wrapped,
+        ContainerGuard {
+            execution_id: execution_id.to_string(),
+        },
+    )
+}
+
+/// Force-remove a cancelled execution's container. Killing the runtime client
+/// does not stop the container it started. Best-effort, non-blocking.
+pub fn kill(execution_id: &str) {
+    let entry = RUNNING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(execution_id);
+    if let Some((kind, name)) = entry {
+        tracing::info!(execution_id = %execution_id, container = %name, "Removing sandbox container");
+        if let Err(e) = std::process::Command::new(kind.binary())
+            .args(["rm", "-f", &name])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+        {
+            tracing::warn!(execution_id = %execution_id, "Failed to remove sandbox container: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sandbox_config_validates_and_wraps_cli_args() {
+        assert!(parse(r#"{"image": "--privileged"}"#).is_err());
+        assert!(parse(r#"{"image": "cli:1", "network": "host --privileged"}"#).is_err());
+        assert!(parse(r#"{"image": "cli:1", "network": "host"}"#).is_err());
+        assert!(parse(r#"{"image": "cli:1", "memory_mb": 16}"#).is_err());
+        let config = parse(r#"{"image": "ghcr.io/acme/cli:1", "network": "none"}"#).unwrap();
+        assert_eq!(config.runtime, RuntimePreference::Auto);
+        assert_eq!(config.fallback, SandboxFallback::Host);
+
+        let cli_args = CliArgs {
+            command: "/usr/local/bin/claude".into(),
+            args: vec!["-p".into(), "--verbose".into()],
+            env_overrides: vec![("API_TOKEN".into(), "secret".into())],
+            env_removals: vec![],
+            cwd: None,
+        };
+        let runtime = ContainerRuntime {
+            kind: RuntimeKind::Podman,
+            version: "5.0.0".into(),
+        };
+        let dir = std::env::temp_dir().join("sandbox-exec");
+        let (wrapped, guard) = wrap(&cli_args, &dir, "exec-1", &config, &runtime);
+        assert_eq!(wrapped.command, "podman");
+        assert!(wrapped.args.windows(2).any(|w| w == ["--network", "none"]));
+        assert!(wrapped.args.windows(2).any(|w| w == ["-e", "API_TOKEN"]));
+        assert!(!wrapped.args.iter().any(|a| a.contains("secret")));
+        let image_at = wrapped
+            .args
+            .iter()
+            .position(|a| a == "ghcr.io/acme/cli:1")
+            .unwrap();
+        assert_eq!(wrapped.args[image_at + 1..], ["claude", "-p", "--verbose"]);
+        assert!(RUNNING.lock().unwrap().contains_key("exec-1"));
+        drop(guard);
+        assert!(!RUNNING.lock().unwrap().contains_key("exec-1"));
+    }
+}
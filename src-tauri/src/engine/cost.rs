@@ -102,7 +102,8 @@ pub struct ExecutionPreview {
     pub budget_limit: f64,
 }
 
-/// Build an execution preview without running the execution.
+/// Build an execution preview without running the execution. `cost_factor`
+/// is the provider correction from usage reconciliation (`1.0` when none).
 pub fn build_preview(
     prompt_text: &str,
     model: &str,
@@ -110,13 +111,14 @@ pub fn build_preview(
     tool_count: u32,
     monthly_spend: f64,
     budget_limit: f64,
+    cost_factor: f64,
 ) -> ExecutionPreview {
     let input_tokens = estimate_tokens(prompt_text);
     // Estimate output tokens as ~40% of input (typical for task-oriented agents)
     let output_tokens = (input_tokens as f64 * 0.4).ceil() as u64;
 
-    let input_cost = estimate_input_cost(input_tokens, model);
-    let output_cost = estimate_output_cost(output_tokens, model);
+    let input_cost = estimate_input_cost(input_tokens, model) * cost_factor;
+    let output_cost = estimate_output_cost(output_tokens, model) * cost_factor;
 
     ExecutionPreview {
         prompt_preview: if prompt_text.len() > 5000 {
@@ -0,0 +1,405 @@
+//! Reconcile recorded execution costs against provider usage exports.
+//!
+//! Execution costs are local estimates (the CLI's list-price math, or the
+//! HTTP backends' token counts times a price table), so they drift from the
+//! actual bill: contract discounts, cache pricing, batch rates. [`reconcile`]
+//! takes a provider's usage CSV (Anthropic Console or OpenAI usage export),
+//! sums it per day, compares it to the recorded costs for the same days and
+//! attributes each day's billed total to personas in proportion to their
+//! recorded share.
+//!
+//! Optionally the billed/recorded ratio becomes the provider's correction
+//! factor ([`settings_keys::COST_CORRECTION_FACTORS`]), which the runner and
+//! the execution preview apply to future costs via [`correction_factor`].
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Providers whose usage exports can be reconciled.
+pub const PROVIDERS: &[&str] = &["anthropic", "openai"];
+
+const MIN_FACTOR: f64 = 0.1;
+const MAX_FACTOR: f64 = 10.0;
+
+/// A year of per-model daily rows is a few MB; guards against pasting the
+/// wrong file.
+const MAX_CSV_BYTES: usize = 20 * 1024 * 1024;
+
+/// Header names (lowercased) recognised as the day and cost columns across
+/// the Anthropic and OpenAI export formats.
+const DATE_COLUMNS: &[&str] = &[
+    "date",
+    "day",
+    "usage_date",
+    "usage_date_utc",
+    "start_time",
+    "start_time_iso",
+    "bucket_start",
+];
+const COST_COLUMNS: &[&str] = &[
+    "cost_usd",
+    "cost",
+    "amount",
+    "amount_usd",
+    "amount_value",
+    "total_cost",
+    "total_cost_usd",
+];
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct DailyCostReconciliation {
+    /// `YYYY-MM-DD` (UTC).
+    pub date: String,
+    pub billed_usd: f64,
+    pub recorded_usd: f64,
+    /// `billed - recorded`; positive means costs were under-estimated.
+    pub delta_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PersonaCostDiscrepancy {
+    pub persona_id: String,
+    pub persona_name: String,
+    pub recorded_usd: f64,
+    /// The persona's share of the billed total, by its recorded share per day.
+    pub attributed_usd: f64,
+    pub delta_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CostReconciliationReport {
+    pub provider: String,
+    /// First and last day covered by the export.
+    pub from: String,
+    pub to: String,
+    pub billed_usd: f64,
+    pub recorded_usd: f64,
+    /// `billed / recorded`; `None` when nothing was recorded for the range.
+    pub ratio: Option<f64>,
+    pub days: Vec<DailyCostReconciliation>,
+    /// Largest absolute discrepancy first.
+    pub personas: Vec<PersonaCostDiscrepancy>,
+    /// CSV rows without a parseable day or cost.
+    pub skipped_rows: u32,
+    /// Billed cost on days with no recorded executions (usage outside the
+    /// app, or executions since pruned by retention).
+    pub unattributed_usd: f64,
+    pub previous_factor: f64,
+    /// The factor now in effect, when the correction was applied.
+    pub applied_factor: Option<f64>,
+}
+
+/// Provider that bills a model, from its ID. The CLI omits the model on some
+/// paths; an unknown model there is Anthropic's default.
+pub fn provider_for_model(model: Option<&str>) -> Option<&'static str> {
+    let lower = model.unwrap_or_default().trim().to_lowercase();
+    if lower.is_empty()
+        || ["claude", "opus", "sonnet", "haiku"]
+            .iter()
+            .any(|f| lower.contains(f))
+    {
+        Some("anthropic")
+    } else if lower.contains("gpt")
+        || ["o1", "o3", "o4", "chatgpt"]
+            .iter()
+            .any(|p| lower.starts_with(p))
+    {
+        Some("openai")
+    } else {
+        None
+    }
+}
+
+pub fn validate_factors(factors: &HashMap<String, f64>) -> Result<(), String> {
+    for (provider, factor) in factors {
+        if !PROVIDERS.contains(&provider.as_str()) {
+            return Err(format!(
+                "unknown provider '{provider}': expected one of {}",
+                PROVIDERS.join(", ")
+            ));
+        }
+        if !factor.is_finite() || !(MIN_FACTOR..=MAX_FACTOR).contains(factor) {
+            return Err(format!(
+                "factor for '{provider}' must be between {MIN_FACTOR} and {MAX_FACTOR}, got {factor}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn load_factors(pool: &DbPool) -> HashMap<String, f64> {
+    settings::get(pool, settings_keys::COST_CORRECTION_FACTORS)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Multiplier for a cost estimated for `model`; `1.0` when no correction has
+/// been applied for its provider.
+pub fn correction_factor(pool: &DbPool, model: Option<&str>) -> f64 {
+    provider_for_model(model)
+        .and_then(|p| load_factors(pool).get(p).copied())
+        .unwrap_or(1.0)
+}
+
+/// Split one CSV record, honouring double-quoted fields and `""` escapes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// `YYYY-MM-DD` from an ISO date/datetime or unix seconds (OpenAI buckets).
+fn parse_day(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    if let Some(prefix) = raw.get(..10) {
+        if chrono::NaiveDate::parse_from_str(prefix, "%Y-%m-%d").is_ok() {
+            return Some(prefix.to_string());
+        }
+    }
+    let secs = raw.parse::<i64>().ok()?;
+    chrono::DateTime::from_timestamp(secs, 0).map(|dt| dt.format("%Y-%m-%d").to_string())
+}
+
+fn parse_cost(raw: &str) -> Option<f64> {
+    raw.trim()
+        .trim_start_matches('$')
+        .replace(',', "")
+        .parse::<f64>()
+        .ok()
+        .filter(|c| c.is_finite())
+}
+
+/// Billed cost per day from a usage export, plus the number of data rows
+/// skipped for lacking a parseable day or cost.
+pub fn parse_usage_csv(csv: &str) -> Result<(BTreeMap<String, f64>, u32), String> {
+    let mut lines = csv
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .filter(|l| !l.trim().is_empty());
+    let header: Vec<String> = split_csv_line(lines.next().ok_or("the file is empty")?)
+        .into_iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    let find = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|n| header.iter().position(|h| h == n))
+    };
+    let date_col = find(DATE_COLUMNS).ok_or_else(|| {
+        format!(
+            "no date column found (expected one of: {})",
+            DATE_COLUMNS.join(", ")
+        )
+    })?;
+    let cost_col = find(COST_COLUMNS).ok_or_else(|| {
+        format!(
+            "no cost column found (expected one of: {})",
+            COST_COLUMNS.join(", ")
+        )
+    })?;
+
+    let mut per_day = BTreeMap::new();
+    let mut skipped = 0u32;
+    for line in lines {
+        let fields = split_csv_line(line);
+        let day = fields.get(date_col).and_then(|d| parse_day(d));
+        let cost = fields.get(cost_col).and_then(|c| parse_cost(c));
+        match (day, cost) {
+            (Some(day), Some(cost)) => *per_day.entry(day).or_insert(0.0) += cost,
+            _ => skipped += 1,
+        }
+    }
+    if per_day.is_empty() {
+        return Err("no usage rows with a date and cost were found".into());
+    }
+    Ok((per_day, skipped))
+}
+
+/// Compare a provider usage export against recorded costs for the days it
+/// covers. With `apply`, the provider's correction factor is multiplied by
+/// the billed/recorded ratio: recorded costs already include the previous
+/// factor, so the product converges on the true rate.
+pub fn reconcile(
+    pool: &DbPool,
+    provider: &str,
+    csv: &str,
+    apply: bool,
+) -> Result<CostReconciliationReport, AppError> {
+    if !PROVIDERS.contains(&provider) {
+        return Err(AppError::Validation(format!(
+            "Unknown provider '{provider}': expected one of {}",
+            PROVIDERS.join(", ")
+        )));
+    }
+    if csv.len() > MAX_CSV_BYTES {
+        return Err(AppError::Validation(format!(
+            "Usage export is too large ({} bytes, max {MAX_CSV_BYTES})",
+            csv.len()
+        )));
+    }
+    let (billed, skipped_rows) =
+        parse_usage_csv(csv).map_err(|e| AppError::Validation(format!("Usage export: {e}")))?;
+    let from = billed.keys().next().cloned().unwrap_or_default();
+    let to = billed.keys().next_back().cloned().unwrap_or_default();
+
+    // day -> persona_id -> recorded cost, for this provider's models only.
+    let mut recorded: BTreeMap<String, HashMap<String, f64>> = BTreeMap::new();
+    let mut names: HashMap<String, String> = HashMap::new();
+    for row in exec_repo::get_daily_cost_by_persona(pool, &from, &to)? {
+        if provider_for_model(row.model_used.as_deref()) != Some(provider) {
+            continue;
+        }
+        names.insert(row.persona_id.clone(), row.persona_name);
+        *recorded
+            .entry(row.day)
+            .or_default()
+            .entry(row.persona_id)
+            .or_insert(0.0) += row.cost_usd;
+    }
+
+    let mut days = Vec::new();
+    let mut personas: HashMap<String, (f64, f64)> = HashMap::new();
+    let mut unattributed_usd = 0.0;
+    let all_days: std::collections::BTreeSet<&String> =
+        billed.keys().chain(recorded.keys()).collect();
+    for day in all_days {
+        let billed_usd = billed.get(day).copied().unwrap_or(0.0);
+        let by_persona = recorded.get(day);
+        let recorded_usd: f64 = by_persona.map(|m| m.values().sum()).unwrap_or(0.0);
+        if recorded_usd > 0.0 {
+            for (persona_id, cost) in by_persona.into_iter().flatten() {
+                let entry = personas.entry(persona_id.clone()).or_insert((0.0, 0.0));
+                entry.0 += cost;
+                entry.1 += billed_usd * cost / recorded_usd;
+            }
+        } else {
+            unattributed_usd += billed_usd;
+        }
+        days.push(DailyCostReconciliation {
+            date: day.clone(),
+            billed_usd,
+            recorded_usd,
+            delta_usd: billed_usd - recorded_usd,
+        });
+    }
+
+    let mut personas: Vec<PersonaCostDiscrepancy> = personas
+        .into_iter()
+        .map(
+            |(persona_id, (recorded_usd, attributed_usd))| PersonaCostDiscrepancy {
+                persona_name: names.get(&persona_id).cloned().unwrap_or_default(),
+                persona_id,
+                recorded_usd,
+                attributed_usd,
+                delta_usd: attributed_usd - recorded_usd,
+            },
+        )
+        .collect();
+    personas.sort_by(|a, b| b.delta_usd.abs().total_cmp(&a.delta_usd.abs()));
+
+    let billed_usd: f64 = days.iter().map(|d| d.billed_usd).sum();
+    let recorded_usd: f64 = days.iter().map(|d| d.recorded_usd).sum();
+    let ratio = (recorded_usd > 0.0).then(|| billed_usd / recorded_usd);
+
+    let mut factors = load_factors(pool);
+    let previous_factor = factors.get(provider).copied().unwrap_or(1.0);
+    let applied_factor = if apply {
+        let ratio = ratio.ok_or_else(|| {
+            AppError::Validation(
+                "No recorded executions for this provider in the export's date range; \
+                 nothing to correct against"
+                    .into(),
+            )
+        })?;
+        let factor = (previous_factor * ratio).clamp(MIN_FACTOR, MAX_FACTOR);
+        factors.insert(provider.to_string(), factor);
+        settings::set(
+            pool,
+            settings_keys::COST_CORRECTION_FACTORS,
+            &serde_json::to_string(&factors)?,
+        )?;
+        tracing::info!(
+            provider,
+            previous_factor,
+            factor,
+            "Applied cost correction factor"
+        );
+        Some(factor)
+    } else {
+        None
+    };
+
+    Ok(CostReconciliationReport {
+        provider: provider.to_string(),
+        from,
+        to,
+        billed_usd,
+        recorded_usd,
+        ratio,
+        days,
+        personas,
+        skipped_rows,
+        unattributed_usd,
+        previous_factor,
+        applied_factor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_both_export_shapes() {
+        let anthropic = "usage_date_utc,model,cost_usd\n\
+                         2026-05-01,claude-sonnet-4-6,1.50\n\
+                         2026-05-01,claude-haiku-4-5,\"0.25\"\n\
+                         2026-05-02,claude-sonnet-4-6,2\n\
+                         total,,3.75\n";
+        let (days, skipped) = parse_usage_csv(anthropic).unwrap();
+        assert_eq!(days.get("2026-05-01"), Some(&1.75));
+        assert_eq!(days.get("2026-05-02"), Some(&2.0));
+        assert_eq!(skipped, 1);
+
+        // OpenAI cost buckets: unix start_time, quoted line items with commas.
+        let openai = "start_time,line_item,amount_value\n\
+                      1777593600,\"gpt-4o, input\",0.4\n\
+                      1777593600,\"gpt-4o, output\",0.6\n";
+        let (days, skipped) = parse_usage_csv(openai).unwrap();
+        assert_eq!(days.len(), 1);
+        assert!((days.values().next().unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(skipped, 0);
+
+        assert!(parse_usage_csv("model,tokens\nx,1\n").is_err());
+        assert_eq!(provider_for_model(Some("gpt-4o-mini")), Some("openai"));
+        assert_eq!(provider_for_model(None), Some("anthropic"));
+        assert_eq!(provider_for_model(Some("qwen-max")), None);
+    }
+}
@@ -602,6 +602,190 @@ pub fn next_fire_time_in_tz(
     next_fire_time_in_zone(schedule, from, &tz)
 }
 
+/// Convert a 5-field cron expression to a human-readable string.
+pub fn cron_to_human(expr: &str) -> String {
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+    if fields.len() != 5 {
+        return format!("Cron: {expr}");
+    }
+    let (min, hour, dom, mon, dow) = (fields[0], fields[1], fields[2], fields[3], fields[4]);
+
+    // Every minute
+    if min == "*" && hour == "*" && dom == "*" && mon == "*" && dow == "*" {
+        return "Every minute".into();
+    }
+
+    // Every N minutes
+    if min.starts_with("*/") && hour == "*" && dom == "*" && mon == "*" && dow == "*" {
+        let n = &min[2..];
+        return format!("Every {n} minutes");
+    }
+
+    // Every N hours
+    if min == "0" && hour.starts_with("*/") && dom == "*" && mon == "*" && dow == "*" {
+        let n = &hour[2..];
+        return format!("Every {n} hours");
+    }
+
+    // Specific time patterns
+    let time_str = format_time_from_cron(min, hour);
+
+    // Daily at specific time
+    if dom == "*" && mon == "*" && dow == "*" {
+        return format!("Daily at {time_str}");
+    }
+
+    // Specific days of week
+    if dom == "*" && mon == "*" && dow != "*" {
+        let days = format_dow(dow);
+        return format!("Every {days} at {time_str}");
+    }
+
+    // Specific day of month
+    if dom != "*" && mon == "*" && dow == "*" {
+        let ordinal = format_dom(dom);
+        return format!("Monthly on the {ordinal} at {time_str}");
+    }
+
+    // Fallback
+    format!("Cron: {expr}")
+}
+
+fn format_time_from_cron(min: &str, hour: &str) -> String {
+    let h: u32 = hour.parse().unwrap_or(0);
+    let m: u32 = min.parse().unwrap_or(0);
+    let (h12, ampm) = if h == 0 {
+        (12, "AM")
+    } else if h < 12 {
+        (h, "AM")
+    } else if h == 12 {
+        (12, "PM")
+    } else {
+        (h - 12, "PM")
+    };
+    if m == 0 {
+        format!("{h12} {ampm}")
+    } else {
+        format!("{h12}:{m:02} {ampm}")
+    }
+}
+
+fn format_dow(dow: &str) -> String {
+    const DAYS: &[&str] = &[
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ];
+    let parts: Vec<&str> = dow.split(',').collect();
+
+    // Common patterns
+    if dow == "1-5" {
+        return "weekday".into();
+    }
+    if dow == "0,6" || dow == "6,0" {
+        return "weekend".into();
+    }
+
+    let names: Vec<String> = parts
+        .iter()
+        .filter_map(|p| {
+            if p.contains('-') {
+                // Range like 1-5
+                let bounds: Vec<&str> = p.splitn(2, '-').collect();
+                let lo: usize = bounds[0].parse().unwrap_or(0);
+                let hi: usize = bounds[1].parse().unwrap_or(6);
+                if lo <= hi && hi < 7 {
+                    let start = DAYS.get(lo).unwrap_or(&"?");
+                    let end = DAYS.get(hi).unwrap_or(&"?");
+                    Some(format!("{start}-{end}"))
+                } else {
+                    None
+                }
+            } else {
+                let idx: usize = p.trim().parse().unwrap_or(8);
+                DAYS.get(idx).map(|d| d.to_string())
+            }
+        })
+        .collect();
+
+    if names.len() == 1 {
+        names[0].clone()
+    } else {
+        names.join(", ")
+    }
+}
+
+fn format_dom(dom: &str) -> String {
+    let d: u32 = dom.parse().unwrap_or(0);
+    let suffix = match d % 10 {
+        1 if d != 11 => "st",
+        2 if d != 12 => "nd",
+        3 if d != 13 => "rd",
+        _ => "th",
+    };
+    format!("{d}{suffix}")
+}
+
+/// Upcoming fire times returned by [`describe_cron`].
+pub const DESCRIBE_CRON_RUNS: usize = 10;
+
+/// What a cron expression means and when it next fires.
+#[derive(Debug, Clone)]
+pub struct CronDescription {
+    /// e.g. "Every weekday at 9 AM (Europe/Prague)".
+    pub description: String,
+    /// IANA zone the expression is evaluated in (the system zone when none
+    /// was supplied).
+    pub timezone: String,
+    /// The next [`DESCRIBE_CRON_RUNS`] fire times.
+    pub next_runs: Vec<DateTime<Utc>>,
+}
+
+/// Parse `expr` (with `H` tokens expanded from `seed`, see
+/// [`parse_cron_seeded`]) and compute its next fire times in `timezone`, or
+/// system local when `None`. An unknown timezone is an error, as it is for
+/// the scheduler.
+pub fn describe_cron(
+    expr: &str,
+    timezone: Option<&str>,
+    seed: u64,
+) -> Result<CronDescription, String> {
+    let tz = timezone
+        .map(|raw| {
+            raw.parse::<Tz>()
+                .map_err(|e| format!("Unknown timezone \"{raw}\": {e}"))
+        })
+        .transpose()?;
+    let schedule =
+        parse_cron_seeded(expr, seed).map_err(|e| format!("Invalid cron expression: {e}"))?;
+    let zone_name = match tz {
+        Some(tz) => tz.name().to_string(),
+        None => iana_time_zone::get_timezone().unwrap_or_else(|_| "Local".to_string()),
+    };
+
+    let mut next_runs = Vec::with_capacity(DESCRIBE_CRON_RUNS);
+    let mut from = Utc::now();
+    while next_runs.len() < DESCRIBE_CRON_RUNS {
+        let next = match tz {
+            Some(zone) => next_fire_time_in_tz(&schedule, from, zone),
+            None => next_fire_time_local(&schedule, from),
+        };
+        let Some(t) = next else { break };
+        next_runs.push(t);
+        from = t;
+    }
+
+    Ok(CronDescription {
+        description: format!("{} ({zone_name})", cron_to_human(expr)),
+        timezone: zone_name,
+        next_runs,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1110,4 +1294,23 @@ mod tests {
         let next = next_fire_time_in_tz(&s, from, chrono_tz::America::New_York).unwrap();
         assert_eq!(next, Utc.with_ymd_and_hms(2026, 1, 15, 7, 30, 0).unwrap());
     }
+
+    #[test]
+    fn describe_cron_lists_ten_runs_in_the_zone() {
+        let d = describe_cron("0 9 * * 1-5", Some("Europe/Prague"), 0).unwrap();
+        assert_eq!(d.description, "Every weekday at 9 AM (Europe/Prague)");
+        assert_eq!(d.timezone, "Europe/Prague");
+        assert_eq!(d.next_runs.len(), DESCRIBE_CRON_RUNS);
+        assert!(d.next_runs.windows(2).all(|w| w[0] < w[1]));
+        for t in &d.next_runs {
+            let local = t.with_timezone(&chrono_tz::Europe::Prague);
+            assert_eq!((local.hour(), local.minute()), (9, 0));
+            assert!(local.weekday().num_days_from_sunday() % 6 != 0);
+        }
+
+        assert!(describe_cron("0 9 * * *", Some("Mars/Olympus"), 0)
+            .unwrap_err()
+            .contains("Unknown timezone"));
+        assert!(describe_cron("61 * * * *", None, 0).is_err());
+    }
 }
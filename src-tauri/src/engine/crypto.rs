@@ -414,6 +414,10 @@ pub enum KeySource {
     Keychain,
     /// Key loaded from DPAPI-protected local file (fallback for missing keychain).
     LocalFallback,
+    /// Key unsealed from the passphrase-protected file backend.
+    EncryptedFile,
+    /// Key fetched from an external secret manager (HashiCorp Vault KV).
+    ExternalVault,
 }
 
 static KEY_SOURCE: OnceLock<KeySource> = OnceLock::new();
@@ -479,6 +483,10 @@ fn legacy_key_migration_allowed() -> bool {
     std::env::var("PERSONAS_MIGRATE_LEGACY_KEY").unwrap_or_default() == "1"
 }
 
+/// The cached master key. Only ever holds a successfully-derived key; see
+/// [`get_master_key`] for why failures are never cached.
+static KEY_STORE: OnceLock<ProtectedKey> = OnceLock::new();
+
 /// Get or create the 32-byte master key. Cached in OnceLock after first call.
 ///
 /// The key is stored in a `ProtectedKey` wrapper that:
@@ -494,6 +502,10 @@ fn legacy_key_migration_allowed() -> bool {
 ///    backfill it into the keychain (handled inside `try_keychain`).
 /// 2. `PERSONAS_ALLOW_FALLBACK_KEY=1` is explicitly set -- for CI, headless
 ///    environments, or tests where no keychain daemon is available.
+///
+/// When a non-keychain vault backend is selected (see `engine::vault_backend`)
+/// the key is never derived here: it is installed by that backend's unlock
+/// step, and until then this returns a "vault is locked" error.
 pub fn get_master_key() -> Result<&'static [u8; 32], CryptoError> {
     // Cache only a SUCCESSFULLY-derived key. A transient keychain failure (e.g.
     // the OS backend briefly unavailable during startup) must NOT be cached: the
@@ -501,12 +513,18 @@ pub fn get_master_key() -> Result<&'static [u8; 32], CryptoError> {
     // early failure returned the stale `Err` on every later call and bricked all
     // credential encrypt/decrypt for the whole process, recoverable only by
     // restart. Storing only on success lets a later call retry and succeed.
-    static KEY_STORE: OnceLock<ProtectedKey> = OnceLock::new();
-
     if let Some(protected) = KEY_STORE.get() {
         return Ok(protected.expose_key());
     }
 
+    let backend = super::vault_backend::active();
+    if backend != super::vault_backend::VaultBackendKind::Keychain {
+        return Err(CryptoError::KeyManagement(format!(
+            "Vault is locked: the {} backend has not been unlocked yet",
+            backend.label()
+        )));
+    }
+
     // Not yet cached (first call, or a prior attempt failed) — try to derive now.
     let derived: Result<ProtectedKey, String> = match try_keychain() {
         Ok(key) => {
@@ -562,6 +580,30 @@ pub fn get_master_key() -> Result<&'static [u8; 32], CryptoError> {
     }
 }
 
+/// Install a master key obtained by a vault backend's unlock step.
+///
+/// Fails if a different key is already cached: swapping keys under a running
+/// process would leave every credential it already encrypted unreadable.
+pub(crate) fn install_master_key(key: [u8; 32], source: KeySource) -> Result<(), CryptoError> {
+    let protected = ProtectedKey::new(key);
+    match KEY_STORE.set(protected) {
+        Ok(()) => {
+            let _ = KEY_SOURCE.set(source);
+            Ok(())
+        }
+        Err(rejected) => {
+            let current = KEY_STORE.get().expect("KEY_STORE is set").expose_key();
+            if current == rejected.expose_key() {
+                Ok(())
+            } else {
+                Err(CryptoError::KeyManagement(
+                    "A different master key is already in use by this process".into(),
+                ))
+            }
+        }
+    }
+}
+
 /// Try to load or create the master key via OS keychain.
 #[cfg(feature = "desktop")]
 fn try_keychain() -> Result<[u8; 32], CryptoError> {
@@ -649,12 +691,16 @@ fn derive_fallback_key() -> [u8; 32] {
     key
 }
 
-fn local_fallback_key_path() -> Option<PathBuf> {
+/// Directory holding local key material (`master.key`, `vault.key`).
+pub(crate) fn local_key_dir() -> Option<PathBuf> {
     let appdata = std::env::var("APPDATA")
         .or_else(|_| std::env::var("HOME"))
         .ok()?;
-    let dir = PathBuf::from(appdata).join("com.personas.desktop");
-    Some(dir.join("master.key"))
+    Some(PathBuf::from(appdata).join("com.personas.desktop"))
+}
+
+fn local_fallback_key_path() -> Option<PathBuf> {
+    Some(local_key_dir()?.join("master.key"))
 }
 
 /// Prefix added to DPAPI-protected key files so we can distinguish them from
@@ -805,7 +851,7 @@ fn save_local_fallback_key(key: &[u8; 32]) -> Result<(), CryptoError> {
 /// Returns an error if permissions cannot be set -- the caller must not leave the
 /// key file world-readable.
 #[cfg(windows)]
-fn restrict_file_permissions(path: &std::path::Path) -> Result<(), CryptoError> {
+pub(crate) fn restrict_file_permissions(path: &std::path::Path) -> Result<(), CryptoError> {
     let path_str = path.to_string_lossy();
     let username = whoami::username();
 
@@ -841,7 +887,7 @@ fn restrict_file_permissions(path: &std::path::Path) -> Result<(), CryptoError>
 }
 
 #[cfg(unix)]
-fn restrict_file_permissions(path: &std::path::Path) -> Result<(), CryptoError> {
+pub(crate) fn restrict_file_permissions(path: &std::path::Path) -> Result<(), CryptoError> {
     use std::os::unix::fs::PermissionsExt;
     fs::set_permissions(path, fs::Permissions::from_mode(0o600)).map_err(|e| {
         CryptoError::KeyManagement(format!("Failed to set key file permissions to 0600: {}", e))
@@ -851,7 +897,7 @@ fn restrict_file_permissions(path: &std::path::Path) -> Result<(), CryptoError>
 }
 
 #[cfg(not(any(windows, unix)))]
-fn restrict_file_permissions(_path: &std::path::Path) -> Result<(), CryptoError> {
+pub(crate) fn restrict_file_permissions(_path: &std::path::Path) -> Result<(), CryptoError> {
     Err(CryptoError::KeyManagement(
         "Cannot restrict key file permissions on this platform -- refusing to store key".into(),
     ))
@@ -939,6 +985,24 @@ pub fn try_upgrade_to_keychain() -> Result<bool, CryptoError> {
     Ok(false)
 }
 
+/// Write the given master key into the OS keychain, replacing any entry.
+/// Used when switching the vault backend back to the keychain.
+#[cfg(feature = "desktop")]
+pub(crate) fn store_key_in_keychain(key: &[u8; 32]) -> Result<(), CryptoError> {
+    let entry = keyring::Entry::new("personas-desktop", "credential-master-key")
+        .map_err(|e| CryptoError::KeyManagement(format!("Keychain entry error: {e}")))?;
+    entry
+        .set_password(&B64.encode(key))
+        .map_err(|e| CryptoError::KeyManagement(format!("Failed storing key in keychain: {e}")))
+}
+
+#[cfg(not(feature = "desktop"))]
+pub(crate) fn store_key_in_keychain(_key: &[u8; 32]) -> Result<(), CryptoError> {
+    Err(CryptoError::KeyManagement(
+        "Keychain not available on this platform".into(),
+    ))
+}
+
 // ---------------------------------------------------------------------------
 // Platform-specific key protection (DPAPI on Windows, passthrough elsewhere)
 // ---------------------------------------------------------------------------
@@ -1269,11 +1333,14 @@ fn dpapi_unprotect(ciphertext: &[u8]) -> Result<Vec<u8>, CryptoError> {
     }
 }
 
-/// Returns the key source as a string for IPC/serialization: "keychain", "local_fallback", or "unknown".
+/// Returns the key source as a string for IPC/serialization: "keychain",
+/// "local_fallback", "encrypted_file", "external_vault", or "unknown".
 pub fn key_source_label() -> &'static str {
     match key_source() {
         Some(KeySource::Keychain) => "keychain",
         Some(KeySource::LocalFallback) => "local_fallback",
+        Some(KeySource::EncryptedFile) => "encrypted_file",
+        Some(KeySource::ExternalVault) => "external_vault",
         None => "unknown",
     }
 }
@@ -1285,23 +1352,34 @@ pub fn key_source_label() -> &'static str {
 /// Return a cached AES-256-GCM cipher initialised from the master key.
 ///
 /// The master key never changes at runtime, so the cipher can be constructed
-/// once and reused for every `encrypt_for_db` / `decrypt_from_db` call.
+/// once and reused for every `encrypt_for_db` / `decrypt_from_db` call. Like
+/// the key itself, only success is cached: a vault that is still locked when
+/// the first credential is touched must start working once it is unlocked.
 fn get_cipher() -> Result<&'static Aes256Gcm, CryptoError> {
-    static CIPHER: OnceLock<Result<Aes256Gcm, String>> = OnceLock::new();
-    let result = CIPHER.get_or_init(|| {
-        let key = get_master_key().map_err(|e| e.to_string())?;
-        Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)))
-    });
-    match result {
-        Ok(cipher) => Ok(cipher),
-        Err(e) => Err(CryptoError::KeyManagement(e.clone())),
+    static CIPHER: OnceLock<Aes256Gcm> = OnceLock::new();
+    if let Some(cipher) = CIPHER.get() {
+        return Ok(cipher);
     }
+    let key = get_master_key()?;
+    Ok(CIPHER.get_or_init(|| Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key))))
 }
 
 /// Encrypt plaintext string, returning `(base64_ciphertext, base64_nonce)` for DB storage.
 pub fn encrypt_for_db(plaintext: &str) -> Result<(String, String), CryptoError> {
-    let cipher = get_cipher()?;
+    encrypt_with(get_cipher()?, plaintext)
+}
+
+/// Decrypt from DB columns (base64 ciphertext + base64 nonce) back to plaintext.
+pub fn decrypt_from_db(ciphertext_b64: &str, nonce_b64: &str) -> Result<String, CryptoError> {
+    decrypt_with(get_cipher()?, ciphertext_b64, nonce_b64)
+}
 
+/// [`encrypt_for_db`] under an arbitrary key (per-project data keys, see
+/// `engine::project_keys`).
+pub(crate) fn encrypt_with(
+    cipher: &Aes256Gcm,
+    plaintext: &str,
+) -> Result<(String, String), CryptoError> {
     let mut nonce_bytes = [0u8; 12];
     OsRng.fill_bytes(&mut nonce_bytes);
     let nonce = Nonce::from_slice(&nonce_bytes);
@@ -1313,10 +1391,12 @@ pub fn encrypt_for_db(plaintext: &str) -> Result<(String, String), CryptoError>
     Ok((B64.encode(ciphertext), B64.encode(nonce_bytes)))
 }
 
-/// Decrypt from DB columns (base64 ciphertext + base64 nonce) back to plaintext.
-pub fn decrypt_from_db(ciphertext_b64: &str, nonce_b64: &str) -> Result<String, CryptoError> {
-    let cipher = get_cipher()?;
-
+/// [`decrypt_from_db`] under an arbitrary key.
+pub(crate) fn decrypt_with(
+    cipher: &Aes256Gcm,
+    ciphertext_b64: &str,
+    nonce_b64: &str,
+) -> Result<String, CryptoError> {
     let ciphertext = B64.decode(ciphertext_b64)?;
     let nonce_bytes = B64.decode(nonce_b64)?;
 
@@ -141,7 +141,7 @@ fn http_client() -> reqwest::Client {
 /// in common header patterns, and basic auth credentials.
 ///
 /// Regexes are compiled once and cached via `OnceLock` to avoid per-call overhead.
-fn sanitize_error(msg: &str, fields: &HashMap<String, String>) -> String {
+pub(crate) fn sanitize_error(msg: &str, fields: &HashMap<String, String>) -> String {
     static RE_CONNSTR: OnceLock<regex::Regex> = OnceLock::new();
     static RE_BEARER: OnceLock<regex::Regex> = OnceLock::new();
     static RE_BASIC: OnceLock<regex::Regex> = OnceLock::new();
@@ -248,7 +248,7 @@ fn finalize_result(
 /// Returns `None` if the query is empty or consists entirely of comments.
 /// Returns `Some("__UNCLOSED_COMMENT__")` for an unclosed block comment so
 /// callers can apply a fail-safe policy.
-fn extract_first_keyword(query_text: &str) -> Option<String> {
+pub(crate) fn extract_first_keyword(query_text: &str) -> Option<String> {
     let mut s = query_text.trim();
     loop {
         s = s.trim_start();
@@ -325,7 +325,7 @@ fn strip_sql_literals(sql: &str) -> String {
 /// a quoted value never false-positives). Trailing semicolons are fine. Used by
 /// the safe-mode guard in [`execute_query`]: the first-keyword classifier only
 /// sees statement one, while Neon/PlanetScale forward the raw payload verbatim.
-fn has_multiple_statements(query_text: &str) -> bool {
+pub(crate) fn has_multiple_statements(query_text: &str) -> bool {
     let stripped = strip_sql_literals(query_text);
     stripped
         .trim()
@@ -0,0 +1,151 @@
+//! Absolute execution deadlines propagated through chains and pipelines.
+//!
+//! A deadline is an RFC 3339 timestamp carried as `_deadline` in an
+//! execution's input, next to the `_chain_*` metadata. It is set by:
+//! - a trigger whose config has `deadline_secs` — the event bus stamps
+//!   `event.created_at + deadline_secs` onto the payload of each firing
+//!   ([`stamp_trigger_deadline`]), unless the payload already carries one;
+//! - the caller directly (manual runs, team pipeline input).
+//!
+//! Downstream work inherits it unchanged: chain payloads copy the source's
+//! deadline and pipeline node inputs copy the run's. The engine then:
+//! - refuses to start an execution whose deadline has passed
+//!   (error prefixed [`DEADLINE_EXCEEDED`], no healing retry),
+//! - trims the stream timeout of one that would outlive it,
+//! - stops a chain cascade (`deadline_exceeded` stop reason) or pipeline
+//!   (remaining nodes skipped with that reason) once it is past.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Input key carrying the deadline.
+pub const DEADLINE_KEY: &str = "_deadline";
+
+/// Prefix of the error recorded on executions refused or cut short by their
+/// deadline. Matched by [`is_deadline_error`].
+pub const DEADLINE_EXCEEDED: &str = "Deadline exceeded";
+
+/// Trigger config key: seconds after a firing by which its work must finish.
+const TRIGGER_DEADLINE_SECS_KEY: &str = "deadline_secs";
+
+/// Deadline in an execution input, top-level or under the event-bus `payload`
+/// wrapper (same tolerance as `chain::chain_trace_id_from_input`).
+pub fn from_input(input: &Value) -> Option<DateTime<Utc>> {
+    input
+        .get(DEADLINE_KEY)
+        .or_else(|| input.get("payload").and_then(|p| p.get(DEADLINE_KEY)))
+        .and_then(Value::as_str)
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// [`from_input`] over a raw JSON string (an execution row's `input_data`).
+pub fn from_input_str(input: Option<&str>) -> Option<DateTime<Utc>> {
+    input
+        .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+        .and_then(|v| from_input(&v))
+}
+
+/// Write `deadline` into a JSON-object input. Non-object inputs are left as
+/// they are — there is nowhere to put the key without changing their shape.
+pub fn stamp(input: &mut Value, deadline: DateTime<Utc>) {
+    if let Some(obj) = input.as_object_mut() {
+        obj.insert(DEADLINE_KEY.into(), Value::String(deadline.to_rfc3339()));
+    }
+}
+
+/// Apply a firing trigger's `deadline_secs` to an event payload. Returns the
+/// payload unchanged when the trigger has no deadline, the payload already
+/// carries one (propagated from upstream), or it is not a JSON object.
+pub fn stamp_trigger_deadline(
+    payload: Option<String>,
+    trigger_config: Option<&str>,
+    fired_at: &str,
+) -> Option<String> {
+    let Some(secs) = trigger_config
+        .and_then(|c| serde_json::from_str::<Value>(c).ok())
+        .and_then(|c| c.get(TRIGGER_DEADLINE_SECS_KEY).and_then(Value::as_u64))
+        .filter(|s| *s > 0)
+    else {
+        return payload;
+    };
+    let Ok(fired_at) = DateTime::parse_from_rfc3339(fired_at) else {
+        return payload;
+    };
+    let deadline = fired_at.with_timezone(&Utc) + chrono::Duration::seconds(secs as i64);
+    let mut value = match payload.as_deref() {
+        None => Value::Object(Default::default()),
+        Some(raw) => match serde_json::from_str::<Value>(raw) {
+            Ok(v) if v.is_object() => v,
+            _ => return payload,
+        },
+    };
+    if value.get(DEADLINE_KEY).is_some() {
+        return payload;
+    }
+    stamp(&mut value, deadline);
+    Some(value.to_string())
+}
+
+/// Milliseconds left before `deadline`, or `None` once it has passed.
+pub fn remaining_ms(deadline: DateTime<Utc>, now: DateTime<Utc>) -> Option<i64> {
+    let ms = (deadline - now).num_milliseconds();
+    (ms > 0).then_some(ms)
+}
+
+/// The stream timeout to use so a run ends by its deadline: `Some(trimmed)`
+/// when the remaining time is shorter than the configured timeout (or the
+/// persona has none), `None` when the configured timeout already fits.
+pub fn trim_timeout_ms(timeout_ms: i32, remaining_ms: i64) -> Option<i32> {
+    let remaining = remaining_ms.clamp(1, i32::MAX as i64) as i32;
+    (timeout_ms <= 0 || remaining < timeout_ms).then_some(remaining)
+}
+
+pub fn is_deadline_error(error: &str) -> bool {
+    error.starts_with(DEADLINE_EXCEEDED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_and_stamps_deadlines() {
+        let wrapped = json!({ "_event": {}, "payload": { "_deadline": "2026-01-01T00:10:00Z" } });
+        assert_eq!(
+            from_input(&wrapped).unwrap().to_rfc3339(),
+            "2026-01-01T00:10:00+00:00"
+        );
+        assert!(from_input(&json!({ "_deadline": "soon" })).is_none());
+
+        let config = Some(r#"{"cron":"* * * * *","deadline_secs":600}"#);
+        let stamped =
+            stamp_trigger_deadline(Some(r#"{"a":1}"#.into()), config, "2026-01-01T00:00:00Z");
+        assert_eq!(
+            from_input_str(stamped.as_deref()).unwrap().to_rfc3339(),
+            "2026-01-01T00:10:00+00:00"
+        );
+        // An inherited deadline wins over the trigger's own.
+        let inherited = Some(r#"{"_deadline":"2026-01-01T00:01:00Z"}"#.to_string());
+        assert_eq!(
+            stamp_trigger_deadline(inherited.clone(), config, "2026-01-01T00:00:00Z"),
+            inherited
+        );
+        assert_eq!(
+            stamp_trigger_deadline(None, Some("{}"), "2026-01-01T00:00:00Z"),
+            None
+        );
+    }
+
+    #[test]
+    fn trims_timeout_to_remaining_time() {
+        assert_eq!(trim_timeout_ms(600_000, 30_000), Some(30_000));
+        assert_eq!(trim_timeout_ms(600_000, 900_000), None);
+        assert_eq!(trim_timeout_ms(0, 900_000), Some(900_000));
+
+        let now = Utc::now();
+        assert!(remaining_ms(now - chrono::Duration::seconds(1), now).is_none());
+        assert!(is_deadline_error("Deadline exceeded before start (…)"));
+    }
+}
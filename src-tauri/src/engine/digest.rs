@@ -1,17 +1,25 @@
 //! Scheduled performance digest.
 //!
-//! Periodically queries execution metrics, healing issues, credential health,
-//! and cost data to produce a structured digest delivered via the existing
-//! notification channel system (OS notification + Slack/Telegram/Email).
+//! Periodically queries execution metrics, healing activity, pending manual
+//! reviews, credential health, and cost data to produce a structured digest.
+//! Each delivery is filed as a `digest` message on the Director persona and
+//! sent through the existing notification channel system (OS notification +
+//! Slack/Telegram/Email). A digest covers every persona or a single group
+//! (the personas' home team).
 
 use rusqlite::params;
 use serde::{Deserialize, Serialize};
 use tauri::AppHandle;
 use ts_rs::TS;
 
+use crate::db::models::CreateMessageInput;
+use crate::db::repos::communication::messages as message_repo;
 use crate::db::repos::core::settings;
 use crate::db::DbPool;
 
+/// `content_type` of the persona messages a delivered digest is filed as.
+const MESSAGE_CONTENT_TYPE: &str = "digest";
+
 // ---------------------------------------------------------------------------
 // Digest configuration (persisted in app_settings as JSON)
 // ---------------------------------------------------------------------------
@@ -30,6 +38,10 @@ pub struct DigestConfig {
     /// When empty, only OS notifications are sent.
     #[serde(default)]
     pub channels: Option<String>,
+    /// Limit the digest to one group (the personas' home team). `None` covers
+    /// every persona.
+    #[serde(default)]
+    pub group_id: Option<String>,
 }
 
 impl Default for DigestConfig {
@@ -38,6 +50,18 @@ impl Default for DigestConfig {
             enabled: false,
             cadence: "weekly".to_string(),
             channels: None,
+            group_id: None,
+        }
+    }
+}
+
+impl DigestConfig {
+    /// Days covered by one digest at this cadence.
+    pub fn period_days(&self) -> i64 {
+        if self.cadence == "daily" {
+            1
+        } else {
+            7
         }
     }
 }
@@ -111,6 +135,25 @@ pub struct DigestAnomaly {
     pub deviation_pct: f64,
 }
 
+/// Healing activity over the period.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestHealingSummary {
+    /// Issues raised in the period.
+    #[ts(type = "number")]
+    pub opened: i64,
+    /// Of those, issues the engine fixed on its own.
+    #[ts(type = "number")]
+    pub auto_fixed: i64,
+    /// Issues resolved in the period, whenever they were raised.
+    #[ts(type = "number")]
+    pub resolved: i64,
+    /// Issues still open when the digest was generated.
+    #[ts(type = "number")]
+    pub still_open: i64,
+}
+
 /// The full performance digest payload.
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
@@ -123,6 +166,9 @@ pub struct PerformanceDigest {
     /// Number of days covered (1 or 7).
     #[ts(type = "number")]
     pub period_days: i64,
+    /// Group the digest is limited to; `None` when it covers every persona.
+    pub group_id: Option<String>,
+    pub group_name: Option<String>,
     /// Total executions in the period.
     #[ts(type = "number")]
     pub total_executions: i64,
@@ -146,7 +192,13 @@ pub struct PerformanceDigest {
     pub persona_trends: Vec<DigestPersonaTrend>,
     /// Top failure categories.
     pub top_failures: Vec<DigestFailureCategory>,
-    /// Credential health changes.
+    /// Healing issues raised, auto-fixed and resolved.
+    pub healing: DigestHealingSummary,
+    /// Manual reviews waiting on a decision.
+    #[ts(type = "number")]
+    pub pending_reviews: i64,
+    /// Credential health changes. Credentials are shared, so this is never
+    /// limited to the group.
     pub credential_health: Vec<DigestCredentialHealth>,
     /// Anomaly highlights.
     pub anomalies: Vec<DigestAnomaly>,
@@ -156,16 +208,27 @@ pub struct PerformanceDigest {
 // Digest generation (queries existing tables)
 // ---------------------------------------------------------------------------
 
-/// Generate a performance digest for the given period.
-pub fn generate_digest(pool: &DbPool, period_days: i64) -> PerformanceDigest {
+/// `AND`-able clause limiting `column` (a persona id) to the group bound at
+/// parameter `?idx`; a NULL group matches every persona.
+fn group_filter(column: &str, idx: usize) -> String {
+    format!("(?{idx} IS NULL OR {column} IN (SELECT id FROM personas WHERE home_team_id = ?{idx}))")
+}
+
+/// Generate a performance digest for the given period, limited to `group_id`
+/// when set.
+pub fn generate_digest(
+    pool: &DbPool,
+    period_days: i64,
+    group_id: Option<&str>,
+) -> PerformanceDigest {
     let now = chrono::Utc::now();
     let period = if period_days <= 1 { "daily" } else { "weekly" };
 
     // Current period summary
-    let (total, success, failed, cost) = query_period_summary(pool, period_days);
+    let (total, success, failed, cost) = query_period_summary(pool, period_days, group_id);
     // Previous period summary (for trend)
     let (_prev_total, prev_success, prev_failed, prev_cost) =
-        query_prev_period_summary(pool, period_days);
+        query_prev_period_summary(pool, period_days, group_id);
 
     // Denominator is the terminal (completed + failed) universe, not the raw
     // row count -- rows still `queued`/`running`/`cancelled` in the window are
@@ -191,15 +254,19 @@ pub fn generate_digest(pool: &DbPool, period_days: i64) -> PerformanceDigest {
     };
     let projected_monthly_cost = daily_cost * 30.0;
 
-    let persona_trends = query_persona_trends(pool, period_days);
-    let top_failures = query_top_failures(pool, period_days);
+    let persona_trends = query_persona_trends(pool, period_days, group_id);
+    let top_failures = query_top_failures(pool, period_days, group_id);
+    let healing = query_healing_summary(pool, period_days, group_id);
+    let pending_reviews = query_pending_reviews(pool, group_id);
     let credential_health = query_credential_health(pool);
-    let anomalies = query_anomalies(pool, period_days);
+    let anomalies = query_anomalies(pool, period_days, group_id);
 
     PerformanceDigest {
         generated_at: now.to_rfc3339(),
         period: period.to_string(),
         period_days,
+        group_id: group_id.map(str::to_string),
+        group_name: group_id.and_then(|id| query_group_name(pool, id)),
         total_executions: total,
         successful_executions: success,
         failed_executions: failed,
@@ -211,36 +278,45 @@ pub fn generate_digest(pool: &DbPool, period_days: i64) -> PerformanceDigest {
         budget_limit: None,
         persona_trends,
         top_failures,
+        healing,
+        pending_reviews,
         credential_health,
         anomalies,
     }
 }
 
-fn query_period_summary(pool: &DbPool, days: i64) -> (i64, i64, i64, f64) {
+fn query_period_summary(pool: &DbPool, days: i64, group_id: Option<&str>) -> (i64, i64, i64, f64) {
     let conn = match pool.get() {
         Ok(c) => c,
         Err(_) => return (0, 0, 0, 0.0),
     };
-    conn.query_row(
+    let sql = format!(
         "SELECT
             COUNT(*),
             COALESCE(SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END), 0),
             COALESCE(SUM(CASE WHEN status = 'failed' THEN 1 ELSE 0 END), 0),
             COALESCE(SUM(cost_usd), 0.0)
          FROM persona_executions
-         WHERE created_at >= datetime('now', ?1)",
-        params![format!("-{days} days")],
-        |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
-    )
+         WHERE created_at >= datetime('now', ?1)
+           AND {}",
+        group_filter("persona_id", 2)
+    );
+    conn.query_row(&sql, params![format!("-{days} days"), group_id], |row| {
+        Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+    })
     .unwrap_or((0, 0, 0, 0.0))
 }
 
-fn query_prev_period_summary(pool: &DbPool, days: i64) -> (i64, i64, i64, f64) {
+fn query_prev_period_summary(
+    pool: &DbPool,
+    days: i64,
+    group_id: Option<&str>,
+) -> (i64, i64, i64, f64) {
     let conn = match pool.get() {
         Ok(c) => c,
         Err(_) => return (0, 0, 0, 0.0),
     };
-    conn.query_row(
+    let sql = format!(
         "SELECT
             COUNT(*),
             COALESCE(SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END), 0),
@@ -248,21 +324,35 @@ fn query_prev_period_summary(pool: &DbPool, days: i64) -> (i64, i64, i64, f64) {
             COALESCE(SUM(cost_usd), 0.0)
          FROM persona_executions
          WHERE created_at >= datetime('now', ?1)
-           AND created_at < datetime('now', ?2)",
-        params![format!("-{} days", days * 2), format!("-{days} days")],
+           AND created_at < datetime('now', ?2)
+           AND {}",
+        group_filter("persona_id", 3)
+    );
+    conn.query_row(
+        &sql,
+        params![
+            format!("-{} days", days * 2),
+            format!("-{days} days"),
+            group_id
+        ],
         |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
     )
     .unwrap_or((0, 0, 0, 0.0))
 }
 
-fn query_persona_trends(pool: &DbPool, days: i64) -> Vec<DigestPersonaTrend> {
+fn query_persona_trends(
+    pool: &DbPool,
+    days: i64,
+    group_id: Option<&str>,
+) -> Vec<DigestPersonaTrend> {
     let conn = match pool.get() {
         Ok(c) => c,
         Err(_) => return vec![],
     };
 
     // Current period per-persona
-    let current_sql = "SELECT
+    let current_sql = format!(
+        "SELECT
             e.persona_id,
             COALESCE(p.name, e.persona_id) as persona_name,
             COUNT(*) as total,
@@ -271,17 +361,20 @@ fn query_persona_trends(pool: &DbPool, days: i64) -> Vec<DigestPersonaTrend> {
          FROM persona_executions e
          LEFT JOIN personas p ON p.id = e.persona_id
          WHERE e.created_at >= datetime('now', ?1)
+           AND {}
          GROUP BY e.persona_id
          ORDER BY total DESC
-         LIMIT 20";
+         LIMIT 20",
+        group_filter("e.persona_id", 2)
+    );
 
-    let mut stmt = match conn.prepare(current_sql) {
+    let mut stmt = match conn.prepare(&current_sql) {
         Ok(s) => s,
         Err(_) => return vec![],
     };
 
     let current_rows: Vec<(String, String, i64, i64, f64)> = stmt
-        .query_map(params![format!("-{days} days")], |row| {
+        .query_map(params![format!("-{days} days"), group_id], |row| {
             Ok((
                 row.get(0)?,
                 row.get(1)?,
@@ -295,21 +388,29 @@ fn query_persona_trends(pool: &DbPool, days: i64) -> Vec<DigestPersonaTrend> {
         .unwrap_or_default();
 
     // Previous period per-persona
-    let prev_sql = "SELECT
+    let prev_sql = format!(
+        "SELECT
             persona_id,
             COUNT(*) as total,
             COALESCE(SUM(CASE WHEN status = 'completed' THEN 1 ELSE 0 END), 0) as success
          FROM persona_executions
          WHERE created_at >= datetime('now', ?1)
            AND created_at < datetime('now', ?2)
-         GROUP BY persona_id";
+           AND {}
+         GROUP BY persona_id",
+        group_filter("persona_id", 3)
+    );
 
     let prev_map: std::collections::HashMap<String, (i64, i64)> = conn
-        .prepare(prev_sql)
+        .prepare(&prev_sql)
         .ok()
         .map(|mut s| {
             s.query_map(
-                params![format!("-{} days", days * 2), format!("-{days} days")],
+                params![
+                    format!("-{} days", days * 2),
+                    format!("-{days} days"),
+                    group_id
+                ],
                 |row| {
                     Ok((
                         row.get::<_, String>(0)?,
@@ -355,23 +456,31 @@ fn query_persona_trends(pool: &DbPool, days: i64) -> Vec<DigestPersonaTrend> {
         .collect()
 }
 
-fn query_top_failures(pool: &DbPool, days: i64) -> Vec<DigestFailureCategory> {
+fn query_top_failures(
+    pool: &DbPool,
+    days: i64,
+    group_id: Option<&str>,
+) -> Vec<DigestFailureCategory> {
     let conn = match pool.get() {
         Ok(c) => c,
         Err(_) => return vec![],
     };
-    let sql = "SELECT
+    let sql = format!(
+        "SELECT
             COALESCE(category, 'unknown') as cat,
             COUNT(*) as cnt
          FROM persona_healing_issues
          WHERE created_at >= datetime('now', ?1)
+           AND {}
          GROUP BY cat
          ORDER BY cnt DESC
-         LIMIT 10";
-    conn.prepare(sql)
+         LIMIT 10",
+        group_filter("persona_id", 2)
+    );
+    conn.prepare(&sql)
         .ok()
         .map(|mut s| {
-            s.query_map(params![format!("-{days} days")], |row| {
+            s.query_map(params![format!("-{days} days"), group_id], |row| {
                 Ok(DigestFailureCategory {
                     category: row.get(0)?,
                     count: row.get(1)?,
@@ -384,6 +493,58 @@ fn query_top_failures(pool: &DbPool, days: i64) -> Vec<DigestFailureCategory> {
         .unwrap_or_default()
 }
 
+fn query_healing_summary(pool: &DbPool, days: i64, group_id: Option<&str>) -> DigestHealingSummary {
+    let conn = match pool.get() {
+        Ok(c) => c,
+        Err(_) => return DigestHealingSummary::default(),
+    };
+    // `created_at` defaults to `datetime('now')` while `resolved_at` is
+    // RFC 3339; normalise both before comparing.
+    let sql = format!(
+        "SELECT
+            COALESCE(SUM(CASE WHEN datetime(created_at) >= datetime('now', ?1) THEN 1 ELSE 0 END), 0),
+            COALESCE(SUM(CASE WHEN datetime(created_at) >= datetime('now', ?1) AND auto_fixed = 1 THEN 1 ELSE 0 END), 0),
+            COALESCE(SUM(CASE WHEN status = 'resolved' AND datetime(resolved_at) >= datetime('now', ?1) THEN 1 ELSE 0 END), 0),
+            COALESCE(SUM(CASE WHEN status = 'open' THEN 1 ELSE 0 END), 0)
+         FROM persona_healing_issues
+         WHERE {}",
+        group_filter("persona_id", 2)
+    );
+    conn.query_row(&sql, params![format!("-{days} days"), group_id], |row| {
+        Ok(DigestHealingSummary {
+            opened: row.get(0)?,
+            auto_fixed: row.get(1)?,
+            resolved: row.get(2)?,
+            still_open: row.get(3)?,
+        })
+    })
+    .unwrap_or_default()
+}
+
+fn query_pending_reviews(pool: &DbPool, group_id: Option<&str>) -> i64 {
+    let conn = match pool.get() {
+        Ok(c) => c,
+        Err(_) => return 0,
+    };
+    let sql = format!(
+        "SELECT COUNT(*) FROM persona_manual_reviews
+         WHERE status = 'pending' AND {}",
+        group_filter("persona_id", 1)
+    );
+    conn.query_row(&sql, params![group_id], |row| row.get(0))
+        .unwrap_or(0)
+}
+
+fn query_group_name(pool: &DbPool, group_id: &str) -> Option<String> {
+    let conn = pool.get().ok()?;
+    conn.query_row(
+        "SELECT name FROM persona_teams WHERE id = ?1",
+        params![group_id],
+        |row| row.get(0),
+    )
+    .ok()
+}
+
 fn query_credential_health(pool: &DbPool) -> Vec<DigestCredentialHealth> {
     let conn = match pool.get() {
         Ok(c) => c,
@@ -416,26 +577,30 @@ fn query_credential_health(pool: &DbPool) -> Vec<DigestCredentialHealth> {
         .unwrap_or_default()
 }
 
-fn query_anomalies(pool: &DbPool, days: i64) -> Vec<DigestAnomaly> {
+fn query_anomalies(pool: &DbPool, days: i64, group_id: Option<&str>) -> Vec<DigestAnomaly> {
     let conn = match pool.get() {
         Ok(c) => c,
         Err(_) => return vec![],
     };
 
     // Get daily cost data and detect anomalies via rolling average
-    let sql = "SELECT
+    let sql = format!(
+        "SELECT
             DATE(created_at) as date,
             COALESCE(SUM(cost_usd), 0.0) as daily_cost
          FROM persona_executions
          WHERE created_at >= datetime('now', ?1)
+           AND {}
          GROUP BY DATE(created_at)
-         ORDER BY date ASC";
+         ORDER BY date ASC",
+        group_filter("persona_id", 2)
+    );
 
     let points: Vec<(String, f64)> = conn
-        .prepare(sql)
+        .prepare(&sql)
         .ok()
         .map(|mut s| {
-            s.query_map(params![format!("-{} days", days * 2)], |row| {
+            s.query_map(params![format!("-{} days", days * 2), group_id], |row| {
                 Ok((row.get(0)?, row.get(1)?))
             })
             .ok()
@@ -489,14 +654,15 @@ fn query_anomalies(pool: &DbPool, days: i64) -> Vec<DigestAnomaly> {
 
 /// Format the digest as a human-readable text notification.
 fn format_digest_text(digest: &PerformanceDigest) -> (String, String) {
-    let title = format!(
-        "Performance Digest ({})",
-        if digest.period == "daily" {
-            "Daily"
-        } else {
-            "Weekly"
-        }
-    );
+    let cadence = if digest.period == "daily" {
+        "Daily"
+    } else {
+        "Weekly"
+    };
+    let title = match &digest.group_name {
+        Some(group) => format!("Performance Digest ({cadence}) - {group}"),
+        None => format!("Performance Digest ({cadence})"),
+    };
 
     let mut body = String::new();
 
@@ -562,6 +728,21 @@ fn format_digest_text(digest: &PerformanceDigest) -> (String, String) {
         }
     }
 
+    // Healing and reviews
+    let h = &digest.healing;
+    if h.opened > 0 || h.resolved > 0 || h.still_open > 0 {
+        body.push_str(&format!(
+            "\nHealing: {} opened ({} auto-fixed), {} resolved, {} still open\n",
+            h.opened, h.auto_fixed, h.resolved, h.still_open,
+        ));
+    }
+    if digest.pending_reviews > 0 {
+        body.push_str(&format!(
+            "Pending manual reviews: {}\n",
+            digest.pending_reviews
+        ));
+    }
+
     // Credential health
     if !digest.credential_health.is_empty() {
         body.push_str("\nCredential Issues:\n");
@@ -584,6 +765,68 @@ fn format_digest_text(digest: &PerformanceDigest) -> (String, String) {
     (title, body)
 }
 
+/// File `digest` as a message on the Director persona and send it to the OS
+/// and `channels`.
+fn publish_digest(
+    pool: &DbPool,
+    app: &AppHandle,
+    digest: &PerformanceDigest,
+    channels: Option<&str>,
+) {
+    let (title, body) = format_digest_text(digest);
+
+    // persona_messages rows need a persona; the system-owned Director is the
+    // fleet-level inbox.
+    match super::director::get_director_persona_id(pool) {
+        Ok(director_id) => {
+            if let Err(e) = message_repo::create(
+                pool,
+                CreateMessageInput {
+                    persona_id: director_id,
+                    execution_id: None,
+                    title: Some(title.clone()),
+                    content: body.clone(),
+                    content_type: Some(MESSAGE_CONTENT_TYPE.into()),
+                    priority: Some("low".into()),
+                    metadata: serde_json::to_string(digest).ok(),
+                    thread_id: None,
+                    use_case_id: None,
+                },
+            ) {
+                tracing::warn!(error = %e, "failed to store performance digest message");
+            }
+        }
+        Err(e) => {
+            tracing::debug!(error = %e, "No Director persona; digest not stored as a message")
+        }
+    }
+
+    // OS notification
+    crate::notifications::send(app, &title, &body);
+
+    // External channels
+    if let Some(channels_json) = channels {
+        deliver_digest_to_channels(app, channels_json, &title, &body);
+    }
+}
+
+/// Generate and deliver a digest right away, regardless of whether the
+/// scheduled digest is enabled or due. `group_id` overrides the configured
+/// scope. Does not move the schedule.
+pub fn generate_digest_now(
+    pool: &DbPool,
+    app: &AppHandle,
+    period_days: Option<i64>,
+    group_id: Option<&str>,
+) -> PerformanceDigest {
+    let config = load_config(pool);
+    let period_days = period_days.unwrap_or_else(|| config.period_days());
+    let group_id = group_id.or(config.group_id.as_deref());
+    let digest = generate_digest(pool, period_days, group_id);
+    publish_digest(pool, app, &digest, config.channels.as_deref());
+    digest
+}
+
 /// Generate and deliver the performance digest.
 pub fn deliver_digest(pool: &DbPool, app: &AppHandle) {
     let config = load_config(pool);
@@ -591,8 +834,7 @@ pub fn deliver_digest(pool: &DbPool, app: &AppHandle) {
         return;
     }
 
-    let period_days = if config.cadence == "daily" { 1 } else { 7 };
-    let digest = generate_digest(pool, period_days);
+    let digest = generate_digest(pool, config.period_days(), config.group_id.as_deref());
 
     // Skip sending if there were no executions
     if digest.total_executions == 0 {
@@ -600,15 +842,7 @@ pub fn deliver_digest(pool: &DbPool, app: &AppHandle) {
         return;
     }
 
-    let (title, body) = format_digest_text(&digest);
-
-    // OS notification
-    crate::notifications::send(app, &title, &body);
-
-    // External channels
-    if let Some(ref channels_json) = config.channels {
-        deliver_digest_to_channels(app, channels_json, &title, &body);
-    }
+    publish_digest(pool, app, &digest, config.channels.as_deref());
 
     // Store last digest timestamp. A failure here is logged loudly because
     // the scheduled `digest_tick` path uses this row to gate the next tick;
@@ -627,6 +861,7 @@ pub fn deliver_digest(pool: &DbPool, app: &AppHandle) {
 
     tracing::info!(
         period = %config.cadence,
+        group_id = ?config.group_id,
         total_executions = digest.total_executions,
         success_rate = %format!("{:.1}%", digest.success_rate * 100.0),
         "Performance digest delivered"
@@ -796,3 +1031,108 @@ mod tick_tests {
         assert!(d.corrupted);
     }
 }
+
+#[cfg(test)]
+mod scope_tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::models::CreatePersonaInput;
+    use crate::db::repos::core::personas;
+
+    fn create_persona(pool: &DbPool, name: &str) -> String {
+        personas::create(
+            pool,
+            CreatePersonaInput {
+                name: name.into(),
+                system_prompt: "test".into(),
+                project_id: None,
+                description: None,
+                structured_prompt: None,
+                icon: None,
+                color: None,
+                enabled: Some(true),
+                max_concurrent: None,
+                timeout_ms: None,
+                model_profile: None,
+                max_budget_usd: None,
+                max_turns: None,
+                design_context: None,
+                notification_channels: None,
+                lifecycle: None,
+            },
+        )
+        .unwrap()
+        .id
+    }
+
+    fn insert_execution(pool: &DbPool, persona_id: &str, status: &str) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        pool.get()
+            .unwrap()
+            .execute(
+                "INSERT INTO persona_executions
+                 (id, persona_id, status, input_tokens, output_tokens, cost_usd, created_at)
+                 VALUES (?1, ?2, ?3, 0, 0, 0.5, datetime('now', '-1 hour'))",
+                params![id, persona_id, status],
+            )
+            .unwrap();
+        id
+    }
+
+    #[test]
+    fn group_scope_limits_executions_healing_and_reviews() {
+        let pool = init_test_db().unwrap();
+        let in_group = create_persona(&pool, "In group");
+        let outside = create_persona(&pool, "Outside");
+        let conn = pool.get().unwrap();
+        conn.execute(
+            "INSERT INTO persona_teams (id, name, created_at, updated_at)
+             VALUES ('team-1', 'Support', datetime('now'), datetime('now'))",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE personas SET home_team_id = 'team-1' WHERE id = ?1",
+            params![in_group],
+        )
+        .unwrap();
+
+        let exec = insert_execution(&pool, &in_group, "completed");
+        insert_execution(&pool, &in_group, "failed");
+        insert_execution(&pool, &outside, "completed");
+        for (persona_id, auto_fixed) in [(&in_group, 1), (&outside, 0)] {
+            conn.execute(
+                "INSERT INTO persona_healing_issues (id, persona_id, title, description, auto_fixed)
+                 VALUES (?1, ?2, 'Issue', 'desc', ?3)",
+                params![uuid::Uuid::new_v4().to_string(), persona_id, auto_fixed],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "INSERT INTO persona_manual_reviews
+             (id, execution_id, persona_id, title, created_at, updated_at)
+             VALUES ('review-1', ?1, ?2, 'Approve?', datetime('now'), datetime('now'))",
+            params![exec, in_group],
+        )
+        .unwrap();
+
+        let all = generate_digest(&pool, 7, None);
+        assert_eq!(all.total_executions, 3);
+        assert_eq!(all.healing.opened, 2);
+        assert_eq!(all.healing.still_open, 2);
+        assert_eq!(all.pending_reviews, 1);
+        assert_eq!(all.group_name, None);
+
+        let group = generate_digest(&pool, 7, Some("team-1"));
+        assert_eq!(group.group_name.as_deref(), Some("Support"));
+        assert_eq!(group.total_executions, 2);
+        assert_eq!(group.failed_executions, 1);
+        assert_eq!(group.persona_trends.len(), 1);
+        assert_eq!(group.healing.opened, 1);
+        assert_eq!(group.healing.auto_fixed, 1);
+        assert_eq!(group.pending_reviews, 1);
+        let (title, body) = format_digest_text(&group);
+        assert_eq!(title, "Performance Digest (Weekly) - Support");
+        assert!(body.contains("Pending manual reviews: 1"));
+    }
+}
@@ -861,6 +861,30 @@ pub fn dispatch(ctx: &mut DispatchContext<'_>, msg: &ProtocolMessage) {
                 }
             }
         }
+        ProtocolMessage::SharedContext { key, value } => {
+            if ctx.is_simulation {
+                ctx.logger
+                    .log("[SIM] shared_context skipped (simulation run)");
+            } else {
+                match crate::engine::shared_context::record_from_execution(
+                    ctx.pool,
+                    ctx.execution_id,
+                    ctx.persona_id,
+                    key,
+                    value,
+                ) {
+                    Ok((scope, _)) => ctx.logger.log(&format!(
+                        "[SHARED-CTX] Set '{}' for {} {}",
+                        key.trim(),
+                        scope.scope_type,
+                        scope.scope_id
+                    )),
+                    Err(e) => ctx
+                        .logger
+                        .log(&format!("[SHARED-CTX] Failed to set '{key}': {e}")),
+                }
+            }
+        }
         ProtocolMessage::ProposeBacklog {
             title,
             description,
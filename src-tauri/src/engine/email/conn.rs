@@ -0,0 +1,127 @@
+//! Line-oriented TCP/TLS connection shared by the IMAP and SMTP clients.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::{self, pki_types::ServerName};
+use tokio_rustls::TlsConnector;
+
+use crate::error::AppError;
+
+/// Per-operation network timeout (connect, each read, each write).
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+/// Longest protocol line accepted from a server.
+const MAX_LINE_BYTES: u64 = 64 * 1024;
+
+trait MailIo: AsyncRead + AsyncWrite + Unpin + Send {}
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> MailIo for T {}
+
+pub(super) struct MailConn {
+    host: String,
+    stream: BufReader<Box<dyn MailIo>>,
+}
+
+impl MailConn {
+    /// Open a connection; `implicit_tls` negotiates TLS before the greeting
+    /// (IMAPS on 993, SMTPS on 465).
+    pub(super) async fn connect(
+        host: &str,
+        port: u16,
+        implicit_tls: bool,
+    ) -> Result<Self, AppError> {
+        let tcp = timed("connect", TcpStream::connect((host, port)))
+            .await
+            .map_err(|e| AppError::External(format!("Could not connect to {host}:{port}: {e}")))?;
+        let conn = Self {
+            host: host.to_string(),
+            stream: BufReader::new(Box::new(tcp)),
+        };
+        if implicit_tls {
+            conn.upgrade_tls().await
+        } else {
+            Ok(conn)
+        }
+    }
+
+    /// Switch the connection to TLS (after the server accepted STARTTLS, or
+    /// right after connecting for implicit TLS). The certificate is checked
+    /// against the bundled web PKI roots.
+    pub(super) async fn upgrade_tls(self) -> Result<Self, AppError> {
+        let server_name = ServerName::try_from(self.host.clone()).map_err(|_| {
+            AppError::Validation(format!("Invalid mail server host '{}'", self.host))
+        })?;
+        let roots: rustls::RootCertStore = webpki_roots::TLS_SERVER_ROOTS.iter().cloned().collect();
+        let config = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::ring::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .map_err(|e| AppError::Internal(format!("TLS setup failed: {e}")))?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+        let tls = timed(
+            "TLS handshake",
+            TlsConnector::from(Arc::new(config)).connect(server_name, self.stream.into_inner()),
+        )
+        .await
+        .map_err(|e| AppError::External(format!("TLS handshake with {} failed: {e}", self.host)))?;
+        Ok(Self {
+            host: self.host,
+            stream: BufReader::new(Box::new(tls)),
+        })
+    }
+
+    /// Read one line, without its CRLF.
+    pub(super) async fn read_line(&mut self) -> Result<String, AppError> {
+        let mut buf = Vec::new();
+        let read = timed(
+            "read",
+            (&mut self.stream)
+                .take(MAX_LINE_BYTES)
+                .read_until(b'\n', &mut buf),
+        )
+        .await?;
+        if read == 0 {
+            return Err(AppError::External(format!(
+                "{} closed the connection",
+                self.host
+            )));
+        }
+        if !buf.ends_with(b"\n") {
+            return Err(AppError::External(format!(
+                "{} sent a line longer than {MAX_LINE_BYTES} bytes",
+                self.host
+            )));
+        }
+        while matches!(buf.last(), Some(b'\n' | b'\r')) {
+            buf.pop();
+        }
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    pub(super) async fn read_exact(&mut self, len: usize) -> Result<Vec<u8>, AppError> {
+        let mut buf = vec![0; len];
+        timed("read", self.stream.read_exact(&mut buf)).await?;
+        Ok(buf)
+    }
+
+    pub(super) async fn write(&mut self, data: &[u8]) -> Result<(), AppError> {
+        let stream = self.stream.get_mut();
+        timed("write", stream.write_all(data)).await?;
+        timed("write", stream.flush()).await
+    }
+}
+
+async fn timed<T>(
+    what: &str,
+    fut: impl Future<Output = std::io::Result<T>>,
+) -> Result<T, AppError> {
+    match tokio::time::timeout(IO_TIMEOUT, fut).await {
+        Ok(result) => Ok(result?),
+        Err(_) => Err(AppError::External(format!(
+            "Mail server timed out during {what}"
+        ))),
+    }
+}
@@ -0,0 +1,488 @@
+//! Minimal IMAP4rev1 client: LOGIN, SELECT/EXAMINE, UID SEARCH, UID FETCH,
+//! UID STORE.
+//!
+//! Only what the read/search tools and the `email_inbound` trigger need. Responses are parsed into a small
+//! [`Node`] tree (atoms, strings, literals, lists), which is enough to pull
+//! `UID`, `FLAGS`, `RFC822.SIZE` and `BODY[...]` out of FETCH responses.
+
+use crate::error::AppError;
+
+use super::conn::MailConn;
+use super::{MailAccount, Security};
+
+/// Largest literal accepted from the server (a fetched message is capped
+/// below this by a partial FETCH).
+const MAX_LITERAL_BYTES: usize = 4 * 1024 * 1024;
+
+/// An argument of an outgoing command.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Arg {
+    /// Sent verbatim (keywords, numbers, already-quoted strings).
+    Atom(String),
+    /// Sent as a synchronizing literal (`{n}` + continuation).
+    Literal(Vec<u8>),
+}
+
+impl Arg {
+    pub(super) fn atom(s: impl Into<String>) -> Self {
+        Arg::Atom(s.into())
+    }
+
+    /// A string argument: quoted when it is printable ASCII, otherwise a
+    /// literal (non-ASCII search terms, passwords with odd characters).
+    pub(super) fn string(s: &str) -> Self {
+        if s.bytes().all(|b| (0x20..0x7f).contains(&b)) {
+            Arg::Atom(format!(
+                "\"{}\"",
+                s.replace('\\', "\\\\").replace('"', "\\\"")
+            ))
+        } else {
+            Arg::Literal(s.as_bytes().to_vec())
+        }
+    }
+}
+
+/// One piece of a server response: a line, or the literal that followed it.
+#[derive(Debug)]
+enum Part {
+    Line(String),
+    Literal(Vec<u8>),
+}
+
+/// Parsed response data.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Node {
+    Atom(String),
+    Str(Vec<u8>),
+    Nil,
+    List(Vec<Node>),
+}
+
+/// The parts of one FETCH response the tools use.
+#[derive(Debug, Default)]
+pub(super) struct Fetched {
+    pub uid: u32,
+    pub flags: Vec<String>,
+    pub size: Option<u64>,
+    /// `BODY[HEADER.FIELDS (...)]` or `BODY[]` contents.
+    pub body: Option<Vec<u8>>,
+}
+
+/// The `OK` response codes of a SELECT/EXAMINE that the trigger poller uses.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(super) struct Selected {
+    pub uid_validity: Option<u32>,
+    pub uid_next: Option<u32>,
+}
+
+impl Fetched {
+    pub(super) fn seen(&self) -> bool {
+        self.flags.iter().any(|f| f.eq_ignore_ascii_case("\\Seen"))
+    }
+}
+
+pub(super) struct ImapSession {
+    conn: MailConn,
+    next_tag: u32,
+}
+
+impl ImapSession {
+    /// Connect, upgrade to TLS as configured and log in.
+    pub(super) async fn login(account: &MailAccount) -> Result<Self, AppError> {
+        let conn = MailConn::connect(
+            &account.imap_host,
+            account.imap_port,
+            account.imap_security == Security::Tls,
+        )
+        .await?;
+        let mut session = Self { conn, next_tag: 1 };
+        let greeting = session.read_response().await?;
+        let first = match greeting.first() {
+            Some(Part::Line(line)) => line.clone(),
+            _ => String::new(),
+        };
+        if first.starts_with("* BYE") || !first.starts_with('*') {
+            return Err(AppError::External(format!(
+                "IMAP server refused the connection: {first}"
+            )));
+        }
+        if account.imap_security == Security::StartTls {
+            session.run(&[Arg::atom("STARTTLS")]).await?;
+            session.conn = session.conn.upgrade_tls().await?;
+        }
+        if !first.starts_with("* PREAUTH") {
+            session
+                .run(&[
+                    Arg::atom("LOGIN"),
+                    Arg::string(&account.username),
+                    Arg::string(&account.password),
+                ])
+                .await?;
+        }
+        Ok(session)
+    }
+
+    /// Open a mailbox; `read_only` uses EXAMINE so nothing gets flagged.
+    pub(super) async fn select(
+        &mut self,
+        mailbox: &str,
+        read_only: bool,
+    ) -> Result<Selected, AppError> {
+        let verb = if read_only { "EXAMINE" } else { "SELECT" };
+        let responses = self.run(&[Arg::atom(verb), Arg::string(mailbox)]).await?;
+        let mut selected = Selected::default();
+        for parts in &responses {
+            let nodes = parse_nodes(parts);
+            match response_code(&nodes) {
+                Some(("UIDVALIDITY", n)) => selected.uid_validity = Some(n),
+                Some(("UIDNEXT", n)) => selected.uid_next = Some(n),
+                _ => {}
+            }
+        }
+        Ok(selected)
+    }
+
+    /// `UID SEARCH`; returns matching UIDs in ascending order.
+    pub(super) async fn search(&mut self, criteria: Vec<Arg>) -> Result<Vec<u32>, AppError> {
+        let mut args = vec![Arg::atom("UID"), Arg::atom("SEARCH")];
+        args.extend(criteria);
+        let mut uids: Vec<u32> = self
+            .run(&args)
+            .await?
+            .iter()
+            .map(|parts| parse_nodes(parts))
+            .filter(|nodes| is_untagged(nodes, "SEARCH"))
+            .flat_map(|nodes| {
+                nodes
+                    .into_iter()
+                    .skip(2)
+                    .filter_map(|n| match n {
+                        Node::Atom(a) => a.parse().ok(),
+                        _ => None,
+                    })
+                    .collect::<Vec<u32>>()
+            })
+            .collect();
+        uids.sort_unstable();
+        uids.dedup();
+        Ok(uids)
+    }
+
+    /// `UID FETCH <uids> <items>`.
+    pub(super) async fn fetch(
+        &mut self,
+        uids: &[u32],
+        items: &str,
+    ) -> Result<Vec<Fetched>, AppError> {
+        if uids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let set = uids
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let responses = self
+            .run(&[
+                Arg::atom("UID"),
+                Arg::atom("FETCH"),
+                Arg::atom(set),
+                Arg::atom(items),
+            ])
+            .await?;
+        Ok(responses
+            .iter()
+            .filter_map(|parts| parse_fetch(&parse_nodes(parts)))
+            .collect())
+    }
+
+    /// Flag messages as read (`UID STORE <uids> +FLAGS.SILENT (\Seen)`).
+    /// Needs a mailbox opened with `select(.., false)`.
+    pub(super) async fn mark_seen(&mut self, uids: &[u32]) -> Result<(), AppError> {
+        if uids.is_empty() {
+            return Ok(());
+        }
+        let set = uids
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        self.run(&[
+            Arg::atom("UID"),
+            Arg::atom("STORE"),
+            Arg::atom(set),
+            Arg::atom("+FLAGS.SILENT"),
+            Arg::atom("(\\Seen)"),
+        ])
+        .await
+        .map(|_| ())
+    }
+
+    /// Best-effort LOGOUT; the connection is dropped either way.
+    pub(super) async fn logout(mut self) {
+        let _ = self.run(&[Arg::atom("LOGOUT")]).await;
+    }
+
+    /// Send a tagged command and collect its untagged responses. A `NO` or
+    /// `BAD` completion becomes an error carrying the server's text.
+    async fn run(&mut self, args: &[Arg]) -> Result<Vec<Vec<Part>>, AppError> {
+        let tag = format!("a{}", self.next_tag);
+        self.next_tag += 1;
+        let verb = match args {
+            [Arg::Atom(uid), Arg::Atom(cmd), ..] if uid == "UID" => format!("UID {cmd}"),
+            [Arg::Atom(cmd), ..] => cmd.clone(),
+            _ => String::new(),
+        };
+
+        let mut line = tag.clone();
+        for arg in args {
+            line.push(' ');
+            match arg {
+                Arg::Atom(a) => line.push_str(a),
+                Arg::Literal(bytes) => {
+                    line.push_str(&format!("{{{}}}\r\n", bytes.len()));
+                    self.conn.write(line.as_bytes()).await?;
+                    line.clear();
+                    let reply = self.conn.read_line().await?;
+                    if !reply.starts_with('+') {
+                        return Err(AppError::External(format!("IMAP {verb} failed: {reply}")));
+                    }
+                    self.conn.write(bytes).await?;
+                }
+            }
+        }
+        line.push_str("\r\n");
+        self.conn.write(line.as_bytes()).await?;
+
+        let mut untagged = Vec::new();
+        loop {
+            let response = self.read_response().await?;
+            let Some(Part::Line(first)) = response.first() else {
+                continue;
+            };
+            if let Some(rest) = first.strip_prefix(&format!("{tag} ")) {
+                let (status, text) = rest.split_once(' ').unwrap_or((rest, ""));
+                if status.eq_ignore_ascii_case("OK") {
+                    return Ok(untagged);
+                }
+                return Err(AppError::External(format!("IMAP {verb} failed: {text}")));
+            }
+            if first.starts_with('*') {
+                untagged.push(response);
+            }
+        }
+    }
+
+    /// Read one response: a line, plus any literals it announces and the
+    /// lines that continue it.
+    async fn read_response(&mut self) -> Result<Vec<Part>, AppError> {
+        let mut parts = Vec::new();
+        loop {
+            let line = self.conn.read_line().await?;
+            let literal = literal_len(&line);
+            parts.push(Part::Line(line));
+            match literal {
+                Some(len) if len > MAX_LITERAL_BYTES => {
+                    return Err(AppError::External(format!(
+                        "IMAP server sent a {len}-byte literal (limit {MAX_LITERAL_BYTES})"
+                    )));
+                }
+                Some(len) => parts.push(Part::Literal(self.conn.read_exact(len).await?)),
+                None => return Ok(parts),
+            }
+        }
+    }
+}
+
+/// `{123}` at the end of a line announces a literal of that many bytes.
+fn literal_len(line: &str) -> Option<usize> {
+    let open = line.strip_suffix('}')?.rfind('{')?;
+    line[open + 1..line.len() - 1].parse().ok()
+}
+
+fn is_untagged(nodes: &[Node], kind: &str) -> bool {
+    matches!(nodes.first(), Some(Node::Atom(a)) if a == "*")
+        && matches!(nodes.get(1), Some(Node::Atom(a)) if a.eq_ignore_ascii_case(kind))
+}
+
+/// A numeric response code such as `* OK [UIDVALIDITY 3857529045] UIDs
+/// valid`, with the name upper-cased.
+fn response_code(nodes: &[Node]) -> Option<(&'static str, u32)> {
+    if !is_untagged(nodes, "OK") {
+        return None;
+    }
+    let Some(Node::Atom(code)) = nodes.get(2) else {
+        return None;
+    };
+    let rest = code.strip_prefix('[')?.strip_suffix(']')?;
+    let (name, value) = rest.split_once(' ')?;
+    let name = ["UIDVALIDITY", "UIDNEXT"]
+        .into_iter()
+        .find(|known| name.eq_ignore_ascii_case(known))?;
+    Some((name, value.trim().parse().ok()?))
+}
+
+/// Tokenize a response into nodes. Bracketed sections stay part of their
+/// atom, so `BODY[HEADER.FIELDS (FROM)]` is a single key.
+fn parse_nodes(parts: &[Part]) -> Vec<Node> {
+    let mut stack: Vec<Vec<Node>> = vec![Vec::new()];
+    let push = |stack: &mut Vec<Vec<Node>>, node: Node| {
+        if let Some(top) = stack.last_mut() {
+            top.push(node);
+        }
+    };
+    for part in parts {
+        let line = match part {
+            Part::Literal(bytes) => {
+                push(&mut stack, Node::Str(bytes.clone()));
+                continue;
+            }
+            Part::Line(line) => line.as_bytes(),
+        };
+        let mut i = 0;
+        while i < line.len() {
+            match line[i] {
+                b' ' => i += 1,
+                b'(' => {
+                    stack.push(Vec::new());
+                    i += 1;
+                }
+                b')' => {
+                    if stack.len() > 1 {
+                        let list = stack.pop().unwrap_or_default();
+                        push(&mut stack, Node::List(list));
+                    }
+                    i += 1;
+                }
+                b'"' => {
+                    let mut value = Vec::new();
+                    i += 1;
+                    while i < line.len() && line[i] != b'"' {
+                        if line[i] == b'\\' && i + 1 < line.len() {
+                            i += 1;
+                        }
+                        value.push(line[i]);
+                        i += 1;
+                    }
+                    i += 1;
+                    push(&mut stack, Node::Str(value));
+                }
+                // Literal marker; the literal itself is the next part.
+                b'{' if literal_len(&String::from_utf8_lossy(&line[i..])).is_some() => {
+                    i = line.len();
+                }
+                _ => {
+                    let start = i;
+                    let mut depth = 0usize;
+                    while i < line.len() {
+                        match line[i] {
+                            b'[' => depth += 1,
+                            b']' => depth = depth.saturating_sub(1),
+                            b' ' | b'(' | b')' if depth == 0 => break,
+                            _ => {}
+                        }
+                        i += 1;
+                    }
+                    let atom = String::from_utf8_lossy(&line[start..i]).into_owned();
+                    let node = if atom.eq_ignore_ascii_case("NIL") {
+                        Node::Nil
+                    } else {
+                        Node::Atom(atom)
+                    };
+                    push(&mut stack, node);
+                }
+            }
+        }
+    }
+    // Unbalanced input: fold any open lists back in.
+    while stack.len() > 1 {
+        let list = stack.pop().unwrap_or_default();
+        push(&mut stack, Node::List(list));
+    }
+    stack.pop().unwrap_or_default()
+}
+
+/// Pull the fields out of `* <seq> FETCH (...)`.
+fn parse_fetch(nodes: &[Node]) -> Option<Fetched> {
+    if !matches!(nodes.get(2), Some(Node::Atom(a)) if a.eq_ignore_ascii_case("FETCH")) {
+        return None;
+    }
+    let Some(Node::List(items)) = nodes.get(3) else {
+        return None;
+    };
+    let mut fetched = Fetched::default();
+    for pair in items.chunks(2) {
+        let [Node::Atom(key), value] = pair else {
+            continue;
+        };
+        let key = key.to_ascii_uppercase();
+        match (key.as_str(), value) {
+            ("UID", Node::Atom(v)) => fetched.uid = v.parse().unwrap_or(0),
+            ("RFC822.SIZE", Node::Atom(v)) => fetched.size = v.parse().ok(),
+            ("FLAGS", Node::List(flags)) => {
+                fetched.flags = flags
+                    .iter()
+                    .filter_map(|f| match f {
+                        Node::Atom(a) => Some(a.clone()),
+                        _ => None,
+                    })
+                    .collect();
+            }
+            (k, Node::Str(bytes)) if k.starts_with("BODY[") => fetched.body = Some(bytes.clone()),
+            _ => {}
+        }
+    }
+    (fetched.uid != 0).then_some(fetched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fetch_response_with_literal_parses() {
+        let parts = vec![
+            Part::Line(
+                "* 12 FETCH (UID 345 FLAGS (\\Seen \\Answered) RFC822.SIZE 2048 \
+                 BODY[HEADER.FIELDS (FROM SUBJECT)] {36}"
+                    .into(),
+            ),
+            Part::Literal(b"From: a@example.com\r\nSubject: Hi\r\n\r\n".to_vec()),
+            Part::Line(")".into()),
+        ];
+        let fetched = parse_fetch(&parse_nodes(&parts)).unwrap();
+        assert_eq!(fetched.uid, 345);
+        assert!(fetched.seen());
+        assert_eq!(fetched.size, Some(2048));
+        assert!(fetched.body.unwrap().starts_with(b"From: a@example.com"));
+    }
+
+    #[test]
+    fn search_and_string_arguments() {
+        let nodes = parse_nodes(&[Part::Line("* SEARCH 4 9 12".into())]);
+        assert!(is_untagged(&nodes, "SEARCH"));
+        assert_eq!(nodes.len(), 5);
+        assert_eq!(Arg::string("a \"b\""), Arg::Atom("\"a \\\"b\\\"\"".into()));
+        assert_eq!(
+            Arg::string("café"),
+            Arg::Literal("café".as_bytes().to_vec())
+        );
+        assert_eq!(literal_len("* 1 FETCH (BODY[] {120}"), Some(120));
+        assert_eq!(literal_len("a1 OK done"), None);
+    }
+
+    #[test]
+    fn select_response_codes_parse() {
+        let line = |l: &str| parse_nodes(&[Part::Line(l.into())]);
+        assert_eq!(
+            response_code(&line("* OK [UIDVALIDITY 3857529045] UIDs valid")),
+            Some(("UIDVALIDITY", 3857529045))
+        );
+        assert_eq!(
+            response_code(&line("* OK [uidnext 4392] Predicted next UID")),
+            Some(("UIDNEXT", 4392))
+        );
+        assert_eq!(response_code(&line("* OK [UNSEEN 12] First unseen")), None);
+        assert_eq!(response_code(&line("* 172 EXISTS")), None);
+    }
+}
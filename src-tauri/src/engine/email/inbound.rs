@@ -0,0 +1,525 @@
+//! Mailbox polling for `email_inbound` triggers.
+//!
+//! Each poll lists the messages that arrived after the trigger's cursor and
+//! match its filters, and parses them into [`InboundEmail`]s. The polling loop
+//! then stores the new cursor (CAS on `trigger_version`) before publishing one
+//! event per message, so a message fires at most once across restarts.
+//!
+//! Two backends, picked by the credential's service type:
+//! - `imap_smtp` — UID watermark per mailbox, reset when `UIDVALIDITY` changes
+//! - `gmail`     — Gmail API search; message ids are not ordered, so the ids of
+//!   recently processed messages are kept next to an `internalDate` watermark
+//!
+//! A trigger starts from "now": its first poll only records the cursor, so
+//! enabling one never replays the mailbox's history.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::db::repos::resources::credentials as cred_repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::imap::ImapSession;
+use super::{decrypt_fields, mime, MailAccount, SearchRequest, MAX_BODY_CHARS};
+
+/// Messages handed to the persona per poll; the rest wait for the next one.
+pub const MAX_MESSAGES_PER_POLL: usize = 10;
+/// Processed Gmail ids remembered for de-duplication.
+const MAX_SEEN_IDS: usize = 200;
+const GMAIL_API: &str = "https://gmail.googleapis.com/gmail/v1/users/me";
+const GMAIL_TIMEOUT_SECS: u64 = 30;
+
+/// What an `email_inbound` trigger watches.
+#[derive(Debug, Clone, Default)]
+pub struct InboundFilter {
+    pub credential_id: String,
+    /// IMAP folder or Gmail label. IMAP defaults to `INBOX`; Gmail searches
+    /// all mail when unset.
+    pub mailbox: Option<String>,
+    pub from: Option<String>,
+    pub subject: Option<String>,
+    pub unread_only: bool,
+}
+
+/// Where the previous poll stopped. Stored in the trigger config as `cursor`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PollCursor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid_validity: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_uid: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since_ms: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub seen_ids: Vec<String>,
+}
+
+/// A received message, as published in the trigger event payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct InboundEmail {
+    /// `"imap"` or `"gmail"`.
+    pub provider: &'static str,
+    pub mailbox: Option<String>,
+    /// IMAP UID or Gmail message id.
+    pub id: String,
+    pub message_id: Option<String>,
+    pub from: String,
+    pub to: String,
+    pub cc: Option<String>,
+    pub subject: String,
+    pub date: Option<String>,
+    pub body: String,
+    pub body_truncated: bool,
+    /// File names of non-text parts.
+    pub attachments: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct PollResult {
+    /// New matching messages, oldest first.
+    pub messages: Vec<InboundEmail>,
+    pub cursor: PollCursor,
+}
+
+enum Mailbox {
+    Imap(MailAccount),
+    Gmail { access_token: String },
+}
+
+fn open(pool: &DbPool, credential_id: &str) -> Result<Mailbox, AppError> {
+    let credential = cred_repo::get_by_id(pool, credential_id)?;
+    let service = credential.service_type.clone();
+    if service != "imap_smtp" && service != "gmail" {
+        return Err(AppError::Validation(format!(
+            "Credential '{}' is a {service} credential; email triggers need imap_smtp or gmail",
+            credential.name
+        )));
+    }
+    let fields = decrypt_fields(pool, &credential, "email_inbound_trigger")?;
+    if service == "imap_smtp" {
+        return Ok(Mailbox::Imap(MailAccount::from_fields(&fields)?));
+    }
+    let access_token = fields
+        .get("access_token")
+        .filter(|t| !t.is_empty())
+        .cloned()
+        .ok_or_else(|| {
+            AppError::Validation(format!(
+                "Gmail credential '{}' has no access token; reconnect it",
+                credential.name
+            ))
+        })?;
+    Ok(Mailbox::Gmail { access_token })
+}
+
+/// List the messages that arrived after `cursor` and match `filter`.
+/// Without a cursor this only establishes one.
+pub async fn poll(
+    pool: &DbPool,
+    http: &reqwest::Client,
+    filter: &InboundFilter,
+    cursor: Option<PollCursor>,
+) -> Result<PollResult, AppError> {
+    match open(pool, &filter.credential_id)? {
+        Mailbox::Imap(account) => poll_imap(&account, filter, cursor).await,
+        Mailbox::Gmail { access_token } => {
+            poll_gmail(http, &access_token, filter, cursor, now_ms()).await
+        }
+    }
+}
+
+/// Flag processed messages as read on the server.
+pub async fn mark_read(
+    pool: &DbPool,
+    http: &reqwest::Client,
+    filter: &InboundFilter,
+    ids: &[String],
+) -> Result<(), AppError> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    match open(pool, &filter.credential_id)? {
+        Mailbox::Imap(account) => {
+            account.require_imap()?;
+            let uids: Vec<u32> = ids.iter().filter_map(|id| id.parse().ok()).collect();
+            let mut session = ImapSession::login(&account).await?;
+            let result = async {
+                session.select(imap_mailbox(filter), false).await?;
+                session.mark_seen(&uids).await
+            }
+            .await;
+            session.logout().await;
+            result
+        }
+        Mailbox::Gmail { access_token } => {
+            let body = serde_json::json!({ "ids": ids, "removeLabelIds": ["UNREAD"] });
+            let response = http
+                .post(format!("{GMAIL_API}/messages/batchModify"))
+                .bearer_auth(&access_token)
+                .timeout(std::time::Duration::from_secs(GMAIL_TIMEOUT_SECS))
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| AppError::External(format!("Gmail request failed: {e}")))?;
+            gmail_check(response).await.map(|_| ())
+        }
+    }
+}
+
+// -- IMAP --------------------------------------------------------------------
+
+fn imap_mailbox(filter: &InboundFilter) -> &str {
+    filter
+        .mailbox
+        .as_deref()
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .unwrap_or("INBOX")
+}
+
+async fn poll_imap(
+    account: &MailAccount,
+    filter: &InboundFilter,
+    cursor: Option<PollCursor>,
+) -> Result<PollResult, AppError> {
+    account.require_imap()?;
+    let mailbox = imap_mailbox(filter);
+    let mut session = ImapSession::login(account).await?;
+    let result = async {
+        let selected = session.select(mailbox, true).await?;
+        let (Some(validity), Some(uid_next)) = (selected.uid_validity, selected.uid_next) else {
+            return Err(AppError::External(format!(
+                "IMAP server did not report UIDVALIDITY/UIDNEXT for {mailbox}"
+            )));
+        };
+        // Messages delivered after SELECT get UIDs >= uid_next; they belong
+        // to the next poll.
+        let newest = uid_next.saturating_sub(1);
+        // First poll, or the mailbox was rebuilt: start from now.
+        let last = match &cursor {
+            Some(c) if c.uid_validity == Some(validity) => c.last_uid.unwrap_or(newest),
+            _ => newest,
+        };
+        let mut result = PollResult {
+            messages: Vec::new(),
+            cursor: PollCursor {
+                uid_validity: Some(validity),
+                last_uid: Some(last),
+                ..Default::default()
+            },
+        };
+        if last >= newest {
+            return Ok(result);
+        }
+
+        let request = SearchRequest {
+            mailbox: mailbox.to_string(),
+            from: filter.from.clone(),
+            to: None,
+            subject: filter.subject.clone(),
+            text: None,
+            since: None,
+            before: None,
+            unseen_only: filter.unread_only,
+            limit: None,
+            uids: Some(format!("{}:{newest}", last + 1)),
+        };
+        let matches: Vec<u32> = session
+            .search(super::search_criteria(&request)?)
+            .await?
+            .into_iter()
+            .filter(|uid| (last + 1..=newest).contains(uid))
+            .collect();
+        let (batch, high_water) = split_batch(&matches, newest);
+        let mut fetched = session
+            .fetch(batch, "(UID FLAGS RFC822.SIZE BODY.PEEK[]<0.1048576>)")
+            .await?;
+        fetched.sort_by_key(|f| f.uid);
+        result.messages = fetched
+            .iter()
+            .map(|f| {
+                inbound_from_raw(
+                    "imap",
+                    Some(mailbox),
+                    f.uid.to_string(),
+                    f.body.as_deref().unwrap_or_default(),
+                )
+            })
+            .collect();
+        result.cursor.last_uid = Some(high_water);
+        Ok(result)
+    }
+    .await;
+    session.logout().await;
+    result
+}
+
+/// The oldest [`MAX_MESSAGES_PER_POLL`] matches, and how far the UID cursor
+/// may advance: to the last message handed out when matches remain,
+/// otherwise past every message seen this poll.
+fn split_batch(matches: &[u32], newest: u32) -> (&[u32], u32) {
+    if matches.len() > MAX_MESSAGES_PER_POLL {
+        let batch = &matches[..MAX_MESSAGES_PER_POLL];
+        (batch, batch[batch.len() - 1])
+    } else {
+        (matches, newest)
+    }
+}
+
+// -- Gmail -------------------------------------------------------------------
+
+async fn poll_gmail(
+    http: &reqwest::Client,
+    access_token: &str,
+    filter: &InboundFilter,
+    cursor: Option<PollCursor>,
+    now_ms: i64,
+) -> Result<PollResult, AppError> {
+    let Some(since_ms) = cursor.as_ref().and_then(|c| c.since_ms) else {
+        return Ok(PollResult {
+            messages: Vec::new(),
+            cursor: PollCursor {
+                since_ms: Some(now_ms),
+                ..Default::default()
+            },
+        });
+    };
+    let mut seen_ids = cursor.map(|c| c.seen_ids).unwrap_or_default();
+
+    let list = gmail_get(
+        http,
+        access_token,
+        &format!("{GMAIL_API}/messages"),
+        &[
+            ("q", gmail_query(filter, since_ms)),
+            ("maxResults", "100".into()),
+        ],
+    )
+    .await?;
+    // The list is newest first; hand out the oldest unseen ones.
+    let ids: Vec<String> = list["messages"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|m| m["id"].as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    let batch: Vec<String> = ids
+        .into_iter()
+        .rev()
+        .filter(|id| !seen_ids.contains(id))
+        .take(MAX_MESSAGES_PER_POLL)
+        .collect();
+
+    let mut messages = Vec::with_capacity(batch.len());
+    let mut high_water = since_ms;
+    for id in batch {
+        let message = gmail_get(
+            http,
+            access_token,
+            &format!("{GMAIL_API}/messages/{id}"),
+            &[("format", "raw".into())],
+        )
+        .await?;
+        let raw = message["raw"]
+            .as_str()
+            .and_then(|r| URL_SAFE_NO_PAD.decode(r.trim_end_matches('=')).ok())
+            .unwrap_or_default();
+        if let Some(internal) = message["internalDate"]
+            .as_str()
+            .and_then(|d| d.parse::<i64>().ok())
+        {
+            high_water = high_water.max(internal);
+        }
+        messages.push(inbound_from_raw(
+            "gmail",
+            filter.mailbox.as_deref(),
+            id.clone(),
+            &raw,
+        ));
+        seen_ids.push(id);
+    }
+    let overflow = seen_ids.len().saturating_sub(MAX_SEEN_IDS);
+    seen_ids.drain(..overflow);
+
+    Ok(PollResult {
+        messages,
+        cursor: PollCursor {
+            since_ms: Some(high_water),
+            seen_ids,
+            ..Default::default()
+        },
+    })
+}
+
+/// Gmail search for the filters, limited to mail received since the
+/// watermark (`after:` takes epoch seconds).
+fn gmail_query(filter: &InboundFilter, since_ms: i64) -> String {
+    let quoted = |v: &str| format!("\"{}\"", v.replace('"', ""));
+    let mut terms = Vec::new();
+    for (op, value) in [
+        ("from", &filter.from),
+        ("subject", &filter.subject),
+        ("label", &filter.mailbox),
+    ] {
+        if let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            terms.push(format!("{op}:{}", quoted(value)));
+        }
+    }
+    if filter.unread_only {
+        terms.push("is:unread".into());
+    }
+    terms.push(format!("after:{}", since_ms.div_euclid(1000)));
+    terms.join(" ")
+}
+
+async fn gmail_get(
+    http: &reqwest::Client,
+    access_token: &str,
+    url: &str,
+    query: &[(&str, String)],
+) -> Result<serde_json::Value, AppError> {
+    let response = http
+        .get(url)
+        .bearer_auth(access_token)
+        .query(query)
+        .timeout(std::time::Duration::from_secs(GMAIL_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| AppError::External(format!("Gmail request failed: {e}")))?;
+    gmail_check(response).await
+}
+
+async fn gmail_check(response: reqwest::Response) -> Result<serde_json::Value, AppError> {
+    let status = response.status();
+    let text = response
+        .text()
+        .await
+        .map_err(|e| AppError::External(format!("Gmail response unreadable: {e}")))?;
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(AppError::External(
+            "Gmail rejected the access token; reconnect the credential".into(),
+        ));
+    }
+    if !status.is_success() {
+        return Err(AppError::External(format!(
+            "Gmail API returned {status}: {}",
+            crate::utils::text::truncate_on_char_boundary(&text, 300)
+        )));
+    }
+    if text.trim().is_empty() {
+        return Ok(serde_json::Value::Null);
+    }
+    Ok(serde_json::from_str(&text)?)
+}
+
+// -- Parsing -----------------------------------------------------------------
+
+fn inbound_from_raw(
+    provider: &'static str,
+    mailbox: Option<&str>,
+    id: String,
+    raw: &[u8],
+) -> InboundEmail {
+    let parsed = mime::parse_message(raw);
+    let get = |name: &str| parsed.header(name).map(str::to_string);
+    let body_truncated = parsed.text.chars().count() > MAX_BODY_CHARS;
+    let body = if body_truncated {
+        parsed.text.chars().take(MAX_BODY_CHARS).collect()
+    } else {
+        parsed.text.clone()
+    };
+    InboundEmail {
+        provider,
+        mailbox: mailbox.map(str::to_string),
+        id,
+        message_id: get("Message-ID"),
+        from: get("From").unwrap_or_default(),
+        to: get("To").unwrap_or_default(),
+        cc: get("Cc"),
+        subject: get("Subject").unwrap_or_default(),
+        date: get("Date"),
+        body,
+        body_truncated,
+        attachments: parsed.attachments,
+    }
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batches_hold_back_the_overflow() {
+        let few = [11, 12, 15];
+        assert_eq!(split_batch(&few, 20), (&few[..], 20));
+
+        let many: Vec<u32> = (100..125).collect();
+        let (batch, high_water) = split_batch(&many, 130);
+        assert_eq!(batch.len(), MAX_MESSAGES_PER_POLL);
+        assert_eq!(high_water, 109);
+    }
+
+    #[test]
+    fn gmail_query_combines_filters() {
+        let filter = InboundFilter {
+            credential_id: "c1".into(),
+            mailbox: Some("Invoices".into()),
+            from: Some("billing@example.com".into()),
+            subject: Some("Your \"receipt\"".into()),
+            unread_only: true,
+        };
+        assert_eq!(
+            gmail_query(&filter, 1_767_225_600_999),
+            "from:\"billing@example.com\" subject:\"Your receipt\" label:\"Invoices\" \
+             is:unread after:1767225600"
+        );
+        assert_eq!(gmail_query(&InboundFilter::default(), 5_000), "after:5");
+    }
+
+    #[test]
+    fn raw_messages_become_structured_input() {
+        let raw = b"From: Ana <ana@example.com>\r\nTo: me@example.com\r\n\
+                    Subject: =?UTF-8?Q?Caf=C3=A9?=\r\nMessage-ID: <m1@example.com>\r\n\
+                    Content-Type: text/plain; charset=utf-8\r\n\r\nSee you at noon.\r\n";
+        let email = inbound_from_raw("imap", Some("INBOX"), "42".into(), raw);
+        assert_eq!(email.id, "42");
+        assert_eq!(email.from, "Ana <ana@example.com>");
+        assert_eq!(email.subject, "Café");
+        assert_eq!(email.message_id.as_deref(), Some("<m1@example.com>"));
+        assert!(email.body.starts_with("See you at noon."));
+        assert!(!email.body_truncated);
+    }
+
+    #[tokio::test]
+    async fn first_gmail_poll_only_sets_the_watermark() {
+        let http = reqwest::Client::new();
+        let result = poll_gmail(&http, "unused", &InboundFilter::default(), None, 1_000)
+            .await
+            .unwrap();
+        assert!(result.messages.is_empty());
+        assert_eq!(result.cursor.since_ms, Some(1_000));
+    }
+
+    #[test]
+    fn cursor_round_trips_through_config_json() {
+        let cursor = PollCursor {
+            uid_validity: Some(7),
+            last_uid: Some(4021),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&cursor).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"uid_validity": 7, "last_uid": 4021})
+        );
+        assert_eq!(serde_json::from_value::<PollCursor>(json).unwrap(), cursor);
+    }
+}
@@ -0,0 +1,496 @@
+//! Just enough MIME for the email tools: read headers (RFC 2047 encoded
+//! words included), pull the readable text out of a message, and compose a
+//! plain-text (optionally HTML-alternative) message for SMTP.
+
+use std::sync::OnceLock;
+
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, STANDARD};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use regex::Regex;
+
+use crate::error::AppError;
+
+/// Multipart nesting deeper than this is treated as opaque.
+const MAX_DEPTH: usize = 8;
+
+/// Readable content of a message.
+#[derive(Debug, Default)]
+pub(super) struct ParsedMessage {
+    pub headers: Vec<(String, String)>,
+    /// The text/plain parts, or the text of the HTML parts when there are none.
+    pub text: String,
+    /// File names (or content types) of the parts that aren't text.
+    pub attachments: Vec<String>,
+}
+
+impl ParsedMessage {
+    pub(super) fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+}
+
+pub(super) fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+pub(super) fn parse_message(raw: &[u8]) -> ParsedMessage {
+    let (head, _) = split_head(raw);
+    let mut collected = Collected::default();
+    collect_part(raw, 0, &mut collected);
+    let text = if collected.plain.is_empty() {
+        collected
+            .html
+            .iter()
+            .map(|h| crate::validation::strip_html_tags(h))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    } else {
+        collected.plain.join("\n\n")
+    };
+    ParsedMessage {
+        headers: parse_headers(head),
+        text: text.trim().to_string(),
+        attachments: collected.attachments,
+    }
+}
+
+/// Header fields in order, unfolded and decoded.
+pub(super) fn parse_headers(head: &[u8]) -> Vec<(String, String)> {
+    let text = String::from_utf8_lossy(head);
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    for (_, value) in &mut headers {
+        *value = decode_encoded_words(value);
+    }
+    headers
+}
+
+#[derive(Default)]
+struct Collected {
+    plain: Vec<String>,
+    html: Vec<String>,
+    attachments: Vec<String>,
+}
+
+fn collect_part(raw: &[u8], depth: usize, out: &mut Collected) {
+    let (head, body) = split_head(raw);
+    let headers = parse_headers(head);
+    let content_type = header(&headers, "Content-Type").unwrap_or("text/plain");
+    let (mime, params) = parse_content_type(content_type);
+    let disposition = header(&headers, "Content-Disposition").unwrap_or("");
+    let (disposition_kind, disposition_params) = parse_content_type(disposition);
+    let filename = param(&disposition_params, "filename").or_else(|| param(&params, "name"));
+
+    if mime.starts_with("multipart/") && depth < MAX_DEPTH {
+        if let Some(boundary) = param(&params, "boundary") {
+            for part in split_multipart(body, &boundary) {
+                collect_part(part, depth + 1, out);
+            }
+            return;
+        }
+    }
+    let is_text = mime == "text/plain" || mime == "text/html";
+    if disposition_kind == "attachment" || !is_text {
+        out.attachments.push(filename.unwrap_or(mime));
+        return;
+    }
+
+    let encoding = header(&headers, "Content-Transfer-Encoding").unwrap_or("7bit");
+    let decoded = decode_transfer(body, encoding);
+    let charset = param(&params, "charset").unwrap_or_else(|| "utf-8".into());
+    let text = decode_charset(&decoded, &charset);
+    if mime == "text/html" {
+        out.html.push(text);
+    } else {
+        out.plain.push(text);
+    }
+}
+
+/// Split at the blank line between header and body.
+fn split_head(raw: &[u8]) -> (&[u8], &[u8]) {
+    for (i, window) in raw.windows(2).enumerate() {
+        if window == b"\n\n" {
+            return (&raw[..i + 1], &raw[i + 2..]);
+        }
+        if window == b"\n\r" && raw.get(i + 2) == Some(&b'\n') {
+            return (&raw[..i + 1], &raw[i + 3..]);
+        }
+    }
+    (raw, &[])
+}
+
+/// `type/subtype; key=value; ...` → lowercase type plus its parameters.
+/// RFC 2231 `key*=charset''value` parameters are decoded.
+fn parse_content_type(value: &str) -> (String, Vec<(String, String)>) {
+    let mut pieces = value.split(';');
+    let kind = pieces.next().unwrap_or("").trim().to_ascii_lowercase();
+    let params = pieces
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| {
+            let key = k.trim().to_ascii_lowercase();
+            let value = v.trim().trim_matches('"').to_string();
+            match key.strip_suffix('*') {
+                Some(key) => {
+                    let encoded = value.split("''").last().unwrap_or(&value);
+                    let decoded = urlencoding::decode(encoded)
+                        .map(|d| d.into_owned())
+                        .unwrap_or_else(|_| encoded.to_string());
+                    (key.to_string(), decoded)
+                }
+                None => (key, value),
+            }
+        })
+        .collect();
+    (kind, params)
+}
+
+fn param(params: &[(String, String)], name: &str) -> Option<String> {
+    params
+        .iter()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.clone())
+}
+
+fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{boundary}");
+    let mut parts = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut pos = 0;
+    for line in body.split_inclusive(|&b| b == b'\n') {
+        let trimmed = line.trim_ascii_end();
+        if let Some(rest) = trimmed.strip_prefix(delimiter.as_bytes()) {
+            if rest.is_empty() || rest.starts_with(b"--") {
+                if let Some(s) = start {
+                    parts.push(body[s..pos].trim_ascii_end());
+                }
+                if rest.starts_with(b"--") {
+                    return parts;
+                }
+                start = Some(pos + line.len());
+            }
+        }
+        pos += line.len();
+    }
+    // No closing delimiter (a truncated fetch): keep what arrived.
+    if let Some(s) = start {
+        parts.push(&body[s..]);
+    }
+    parts
+}
+
+fn decode_transfer(body: &[u8], encoding: &str) -> Vec<u8> {
+    match encoding.trim().to_ascii_lowercase().as_str() {
+        "base64" => decode_base64(body).unwrap_or_else(|| body.to_vec()),
+        "quoted-printable" => decode_quoted_printable(body),
+        _ => body.to_vec(),
+    }
+}
+
+fn decode_base64(data: &[u8]) -> Option<Vec<u8>> {
+    let clean: Vec<u8> = data
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+    let lenient = GeneralPurpose::new(
+        &base64::alphabet::STANDARD,
+        GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+    );
+    lenient.decode(clean).ok()
+}
+
+fn decode_quoted_printable(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut i = 0;
+    while i < data.len() {
+        if data[i] != b'=' {
+            out.push(data[i]);
+            i += 1;
+            continue;
+        }
+        let rest = &data[i + 1..];
+        if rest.starts_with(b"\r\n") {
+            i += 3;
+        } else if rest.starts_with(b"\n") {
+            i += 2;
+        } else if let Some(byte) = rest
+            .get(..2)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok())
+        {
+            out.push(byte);
+            i += 3;
+        } else {
+            out.push(b'=');
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Decode text in the charsets mail actually uses; anything unknown is read
+/// as (lossy) UTF-8.
+fn decode_charset(bytes: &[u8], charset: &str) -> String {
+    match charset.trim().to_ascii_lowercase().as_str() {
+        "iso-8859-1" | "latin1" | "latin-1" | "windows-1252" | "cp1252" => {
+            bytes.iter().map(|&b| b as char).collect()
+        }
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+static ENCODED_WORD: OnceLock<Regex> = OnceLock::new();
+static ENCODED_WORD_GAP: OnceLock<Regex> = OnceLock::new();
+
+/// Decode RFC 2047 `=?charset?B|Q?...?=` words in a header value.
+fn decode_encoded_words(value: &str) -> String {
+    if !value.contains("=?") {
+        return value.to_string();
+    }
+    // Whitespace between two adjacent encoded words is not part of the text.
+    let gap = ENCODED_WORD_GAP.get_or_init(|| Regex::new(r"\?=\s+=\?").unwrap());
+    let joined = gap.replace_all(value, "?==?");
+    let word =
+        ENCODED_WORD.get_or_init(|| Regex::new(r"=\?([^?\s]+)\?([BbQq])\?([^?\s]*)\?=").unwrap());
+    word.replace_all(&joined, |caps: &regex::Captures| {
+        let charset = caps[1].split('*').next().unwrap_or("utf-8");
+        let bytes = if caps[2].eq_ignore_ascii_case("b") {
+            decode_base64(caps[3].as_bytes())
+        } else {
+            Some(decode_quoted_printable(
+                caps[3].replace('_', " ").as_bytes(),
+            ))
+        };
+        match bytes {
+            Some(bytes) => decode_charset(&bytes, charset),
+            None => caps[0].to_string(),
+        }
+    })
+    .into_owned()
+}
+
+/// A header value as an RFC 2047 word when it isn't plain ASCII.
+pub(super) fn encode_header_value(value: &str) -> String {
+    if value.bytes().all(|b| (0x20..0x7f).contains(&b)) {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+/// A display name for a `From` header: quoted when ASCII, an encoded word
+/// otherwise.
+pub(super) fn encode_display_name(name: &str) -> String {
+    let name = name.replace(['"', '\\', '\r', '\n'], "");
+    if name.is_ascii() {
+        format!("\"{name}\"")
+    } else {
+        encode_header_value(&name)
+    }
+}
+
+/// Reject header values that could inject extra header lines.
+pub(super) fn validate_header_value(field: &str, value: &str) -> Result<(), AppError> {
+    if value.contains(['\r', '\n']) {
+        return Err(AppError::Validation(format!(
+            "{field} must not contain line breaks"
+        )));
+    }
+    Ok(())
+}
+
+/// `Name <user@host>` or `user@host` → `user@host`.
+pub(super) fn bare_address(addr: &str) -> &str {
+    let addr = addr.trim();
+    match (addr.rfind('<'), addr.rfind('>')) {
+        (Some(open), Some(close)) if open < close => addr[open + 1..close].trim(),
+        _ => addr,
+    }
+}
+
+/// Check a recipient or sender address before it goes into a header or the
+/// SMTP envelope.
+pub(super) fn validate_address(addr: &str) -> Result<(), AppError> {
+    validate_header_value("Email address", addr)?;
+    let bare = bare_address(addr);
+    let valid = bare
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && !domain.is_empty())
+        && !bare.contains([' ', '<', '>', ','])
+        && bare.is_ascii();
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::Validation(format!(
+            "'{addr}' is not a valid email address"
+        )))
+    }
+}
+
+/// Everything needed to compose an outgoing message.
+pub(super) struct OutgoingMessage<'a> {
+    pub from: &'a str,
+    pub to: &'a [String],
+    pub cc: &'a [String],
+    pub subject: &'a str,
+    pub text: &'a str,
+    pub html: Option<&'a str>,
+    pub in_reply_to: Option<&'a str>,
+    pub message_id: &'a str,
+    pub date: &'a str,
+}
+
+/// Render the message (CRLF line endings, base64 bodies so any UTF-8 and
+/// any line length survive transport). Bcc recipients never appear here.
+pub(super) fn compose(msg: &OutgoingMessage<'_>) -> Vec<u8> {
+    let mut out = String::new();
+    let mut push_header = |name: &str, value: &str| {
+        out.push_str(name);
+        out.push_str(": ");
+        out.push_str(value);
+        out.push_str("\r\n");
+    };
+    push_header("From", msg.from);
+    push_header("To", &msg.to.join(", "));
+    if !msg.cc.is_empty() {
+        push_header("Cc", &msg.cc.join(", "));
+    }
+    push_header("Subject", &encode_header_value(msg.subject));
+    push_header("Date", msg.date);
+    push_header("Message-ID", msg.message_id);
+    if let Some(parent) = msg.in_reply_to {
+        push_header("In-Reply-To", parent);
+        push_header("References", parent);
+    }
+    push_header("MIME-Version", "1.0");
+
+    match msg.html {
+        None => {
+            out.push_str(&text_part_headers("text/plain"));
+            out.push_str("\r\n");
+            out.push_str(&base64_lines(msg.text));
+        }
+        Some(html) => {
+            let boundary = format!("=_personas_{}", uuid::Uuid::new_v4().simple());
+            out.push_str(&format!(
+                "Content-Type: multipart/alternative; boundary=\"{boundary}\"\r\n\r\n"
+            ));
+            for (mime, body) in [("text/plain", msg.text), ("text/html", html)] {
+                out.push_str(&format!("--{boundary}\r\n"));
+                out.push_str(&text_part_headers(mime));
+                out.push_str("\r\n");
+                out.push_str(&base64_lines(body));
+            }
+            out.push_str(&format!("--{boundary}--\r\n"));
+        }
+    }
+    out.into_bytes()
+}
+
+fn text_part_headers(mime: &str) -> String {
+    format!("Content-Type: {mime}; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n")
+}
+
+fn base64_lines(text: &str) -> String {
+    let encoded = STANDARD.encode(text);
+    let mut out = String::with_capacity(encoded.len() + encoded.len() / 76 * 2 + 2);
+    for chunk in encoded.as_bytes().chunks(76) {
+        out.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+        out.push_str("\r\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multipart_message_prefers_plain_text_and_lists_attachments() {
+        let raw = b"From: =?UTF-8?B?Sm9zw6k=?= <jose@example.com>\r\n\
+Subject: =?ISO-8859-1?Q?Caf=E9_?= =?UTF-8?Q?menu?=\r\n\
+Content-Type: multipart/mixed; boundary=\"outer\"\r\n\
+\r\n\
+--outer\r\n\
+Content-Type: multipart/alternative; boundary=inner\r\n\
+\r\n\
+--inner\r\n\
+Content-Type: text/plain; charset=utf-8\r\n\
+Content-Transfer-Encoding: quoted-printable\r\n\
+\r\n\
+Soup of the day: lentils =E2=80=94 and a long line that =\r\n\
+continues.\r\n\
+--inner\r\n\
+Content-Type: text/html\r\n\
+\r\n\
+<p>ignored</p>\r\n\
+--inner--\r\n\
+--outer\r\n\
+Content-Type: application/pdf; name=\"menu.pdf\"\r\n\
+Content-Disposition: attachment; filename=\"menu.pdf\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+JVBERi0=\r\n\
+--outer--\r\n";
+        let parsed = parse_message(raw);
+        assert_eq!(parsed.header("from"), Some("José <jose@example.com>"));
+        assert_eq!(parsed.header("Subject"), Some("Café menu"));
+        assert_eq!(
+            parsed.text,
+            "Soup of the day: lentils \u{2014} and a long line that continues."
+        );
+        assert_eq!(parsed.attachments, vec!["menu.pdf".to_string()]);
+    }
+
+    #[test]
+    fn html_only_message_is_stripped_to_text() {
+        let raw = b"Content-Type: text/html; charset=utf-8\r\n\
+Content-Transfer-Encoding: base64\r\n\r\nPGI+SGVsbG88L2I+IHdvcmxk\r\n";
+        assert_eq!(parse_message(raw).text, "Hello world");
+    }
+
+    #[test]
+    fn addresses_are_validated() {
+        assert!(validate_address("Bob Smith <bob@example.com>").is_ok());
+        assert_eq!(
+            bare_address("Bob Smith <bob@example.com>"),
+            "bob@example.com"
+        );
+        assert!(validate_address("bob@example.com\r\nBcc: eve@example.com").is_err());
+        assert!(validate_address("not-an-address").is_err());
+    }
+
+    #[test]
+    fn composed_message_round_trips() {
+        let to = vec!["bob@example.com".to_string()];
+        let raw = compose(&OutgoingMessage {
+            from: "alice@example.com",
+            to: &to,
+            cc: &[],
+            subject: "Grüße",
+            text: "Hi Bob,\nsee you at 5.",
+            html: None,
+            in_reply_to: Some("<parent@example.com>"),
+            message_id: "<1@example.com>",
+            date: "Mon, 5 Oct 2026 10:00:00 +0000",
+        });
+        let parsed = parse_message(&raw);
+        assert_eq!(parsed.header("Subject"), Some("Grüße"));
+        assert_eq!(parsed.header("In-Reply-To"), Some("<parent@example.com>"));
+        assert_eq!(parsed.text, "Hi Bob,\nsee you at 5.");
+        assert!(!String::from_utf8_lossy(&raw).contains("Bcc"));
+    }
+}
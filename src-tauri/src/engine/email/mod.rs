@@ -0,0 +1,598 @@
+//! Native IMAP/SMTP mail access behind the `email_imap_read`,
+//! `email_imap_search` and `email_smtp_send` builtin tools.
+//!
+//! The MCP sidecar holds no secrets, so it forwards each call to the desktop
+//! management API (`POST /api/email/{credential_id}`), which decrypts the
+//! `imap_smtp` credential and runs the operation here. Works with any
+//! standards-compliant mailbox (Outlook, Fastmail, iCloud, self-hosted).
+//!
+//! Module layout:
+//! - [`conn`]    — TCP/TLS line connection with timeouts
+//! - [`imap`]    — minimal IMAP4rev1 client (LOGIN, EXAMINE, UID SEARCH/FETCH/STORE)
+//! - [`smtp`]    — minimal ESMTP submission client (STARTTLS, AUTH, DATA)
+//! - [`mime`]    — header decoding, text extraction, message composition
+//! - [`inbound`] — mailbox polling for `email_inbound` triggers (IMAP or Gmail API)
+
+mod conn;
+mod imap;
+pub mod inbound;
+mod mime;
+mod smtp;
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::models::PersonaCredential;
+use crate::db::repos::resources::audit_log;
+use crate::db::repos::resources::credentials as cred_repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use imap::{Arg, ImapSession};
+
+/// Fetched message bodies are cut to this many characters.
+const MAX_BODY_CHARS: usize = 20_000;
+/// Upper bounds on what a single tool call may return.
+const MAX_READ_LIMIT: usize = 20;
+const MAX_SEARCH_LIMIT: usize = 100;
+const SUMMARY_FIELDS: &str =
+    "(UID FLAGS RFC822.SIZE BODY.PEEK[HEADER.FIELDS (FROM TO CC SUBJECT DATE MESSAGE-ID)])";
+
+// -- Account -----------------------------------------------------------------
+
+/// How a connection is secured.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Security {
+    /// TLS from the first byte (IMAPS 993, SMTPS 465).
+    Tls,
+    /// Plain connect, then upgrade with STARTTLS before authenticating.
+    StartTls,
+    /// No TLS at all. Only allowed for loopback hosts such as a local
+    /// Proton Mail Bridge.
+    Plain,
+}
+
+impl Security {
+    fn parse(field: &str, value: Option<&str>) -> Result<Self, AppError> {
+        match value.unwrap_or("").trim().to_ascii_lowercase().as_str() {
+            "" | "tls" | "ssl" => Ok(Self::Tls),
+            "starttls" => Ok(Self::StartTls),
+            "none" | "plain" => Ok(Self::Plain),
+            other => Err(AppError::Validation(format!(
+                "{field} must be tls, starttls or none (got '{other}')"
+            ))),
+        }
+    }
+}
+
+/// Connection settings from a decrypted `imap_smtp` credential. Not `Debug`:
+/// it carries the password.
+pub(crate) struct MailAccount {
+    pub imap_host: String,
+    pub imap_port: u16,
+    pub imap_security: Security,
+    pub smtp_host: String,
+    pub smtp_port: u16,
+    pub smtp_security: Security,
+    pub username: String,
+    pub password: String,
+    pub from_address: String,
+    pub from_name: Option<String>,
+}
+
+impl MailAccount {
+    pub(crate) fn from_fields(fields: &HashMap<String, String>) -> Result<Self, AppError> {
+        let get = |key: &str| fields.get(key).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let username = get("username")
+            .ok_or_else(|| AppError::Validation("Email credential has no username".into()))?
+            .to_string();
+        let imap_security = Security::parse("imap_security", get("imap_security"))?;
+        let smtp_security = Security::parse("smtp_security", get("smtp_security"))?;
+        let port = |key: &str, default: u16| -> Result<u16, AppError> {
+            match get(key) {
+                None => Ok(default),
+                Some(v) => v
+                    .parse()
+                    .map_err(|_| AppError::Validation(format!("{key} must be a port number"))),
+            }
+        };
+        let from_address = match get("from_address") {
+            Some(addr) => addr.to_string(),
+            None if username.contains('@') => username.clone(),
+            None => return Err(AppError::Validation(
+                "Email credential needs a from_address when the username is not an email address"
+                    .into(),
+            )),
+        };
+        let account = Self {
+            imap_host: get("imap_host").unwrap_or_default().to_string(),
+            imap_port: port(
+                "imap_port",
+                if imap_security == Security::Tls {
+                    993
+                } else {
+                    143
+                },
+            )?,
+            imap_security,
+            smtp_host: get("smtp_host").unwrap_or_default().to_string(),
+            smtp_port: port(
+                "smtp_port",
+                match smtp_security {
+                    Security::Tls => 465,
+                    Security::StartTls => 587,
+                    Security::Plain => 25,
+                },
+            )?,
+            smtp_security,
+            password: fields.get("password").cloned().unwrap_or_default(),
+            username,
+            from_address,
+            from_name: get("from_name").map(str::to_string),
+        };
+        mime::validate_address(&account.from_address)?;
+        Ok(account)
+    }
+
+    fn require_imap(&self) -> Result<(), AppError> {
+        require_host("imap_host", &self.imap_host, self.imap_security)
+    }
+
+    fn require_smtp(&self) -> Result<(), AppError> {
+        require_host("smtp_host", &self.smtp_host, self.smtp_security)
+    }
+
+    fn from_header(&self) -> String {
+        match &self.from_name {
+            Some(name) => format!(
+                "{} <{}>",
+                mime::encode_display_name(name),
+                self.from_address
+            ),
+            None => self.from_address.clone(),
+        }
+    }
+}
+
+fn require_host(field: &str, host: &str, security: Security) -> Result<(), AppError> {
+    if host.is_empty() {
+        return Err(AppError::Validation(format!(
+            "Email credential has no {field}"
+        )));
+    }
+    if security == Security::Plain && !is_loopback(host) {
+        return Err(AppError::Validation(format!(
+            "Unencrypted mail connections are only allowed to localhost (got {host})"
+        )));
+    }
+    Ok(())
+}
+
+fn is_loopback(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .trim_matches(['[', ']'])
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+// -- Requests ----------------------------------------------------------------
+
+/// One mail operation, as posted by the MCP sidecar.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum EmailOp {
+    ImapRead(ReadRequest),
+    ImapSearch(SearchRequest),
+    SmtpSend(SendRequest),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReadRequest {
+    #[serde(default = "default_mailbox")]
+    pub mailbox: String,
+    /// Read this message; otherwise the newest `limit` messages.
+    pub uid: Option<u32>,
+    pub limit: Option<usize>,
+    #[serde(default)]
+    pub unseen_only: bool,
+    /// Flag the returned messages as read. Reads are non-destructive by default.
+    #[serde(default)]
+    pub mark_seen: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchRequest {
+    #[serde(default = "default_mailbox")]
+    pub mailbox: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub subject: Option<String>,
+    /// Matches anywhere in the headers or body.
+    pub text: Option<String>,
+    /// `YYYY-MM-DD`, inclusive.
+    pub since: Option<String>,
+    /// `YYYY-MM-DD`, exclusive.
+    pub before: Option<String>,
+    #[serde(default)]
+    pub unseen_only: bool,
+    pub limit: Option<usize>,
+    /// UID range such as `4021:*`; set by the trigger poller, never by tools.
+    #[serde(skip)]
+    pub uids: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SendRequest {
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub cc: Vec<String>,
+    #[serde(default)]
+    pub bcc: Vec<String>,
+    pub subject: String,
+    /// Plain-text body.
+    pub body: String,
+    /// Optional HTML alternative to `body`.
+    pub html: Option<String>,
+    /// `Message-ID` of the message being replied to.
+    pub in_reply_to: Option<String>,
+}
+
+fn default_mailbox() -> String {
+    "INBOX".into()
+}
+
+// -- Results -----------------------------------------------------------------
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MailSummary {
+    pub uid: u32,
+    pub from: String,
+    pub to: String,
+    pub cc: Option<String>,
+    pub subject: String,
+    pub date: Option<String>,
+    pub message_id: Option<String>,
+    pub seen: bool,
+    pub size_bytes: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MailMessage {
+    #[serde(flatten)]
+    pub summary: MailSummary,
+    pub body: String,
+    pub body_truncated: bool,
+    /// File names of non-text parts.
+    pub attachments: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MailSearchResult {
+    /// Number of matching messages in the mailbox.
+    pub total: usize,
+    /// The newest `limit` matches, newest first.
+    pub messages: Vec<MailSummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MailSendReceipt {
+    pub message_id: String,
+    /// Envelope recipients (to, cc and bcc).
+    pub accepted: Vec<String>,
+}
+
+// -- Entry point -------------------------------------------------------------
+
+/// Run `op` against the mailbox of the `imap_smtp` credential `credential_id`.
+pub async fn execute(
+    pool: &DbPool,
+    credential_id: &str,
+    op: EmailOp,
+) -> Result<serde_json::Value, AppError> {
+    let credential = cred_repo::get_by_id(pool, credential_id)?;
+    if credential.service_type != "imap_smtp" {
+        return Err(AppError::Validation(format!(
+            "Credential '{}' is a {} credential, not imap_smtp",
+            credential.name, credential.service_type
+        )));
+    }
+    let fields = decrypt_fields(pool, &credential, "email_bridge")?;
+    let account = MailAccount::from_fields(&fields)?;
+    // Mail providers throttle per account; share the budget across personas.
+    crate::engine::connector_rate_limit::acquire(pool, &credential.service_type).await?;
+
+    let value = match op {
+        EmailOp::ImapRead(req) => serde_json::to_value(read(&account, req).await?)?,
+        EmailOp::ImapSearch(req) => serde_json::to_value(search(&account, req).await?)?,
+        EmailOp::SmtpSend(req) => serde_json::to_value(send(&account, req).await?)?,
+    };
+    Ok(value)
+}
+
+/// Decrypt a credential's fields and record the access in the audit log
+/// under `accessor`.
+fn decrypt_fields(
+    pool: &DbPool,
+    credential: &PersonaCredential,
+    accessor: &str,
+) -> Result<HashMap<String, String>, AppError> {
+    let fields = cred_repo::get_decrypted_fields(pool, credential)?;
+    if let Err(e) =
+        audit_log::log_decrypt(pool, &credential.id, &credential.name, accessor, None, None)
+    {
+        tracing::warn!(
+            credential_id = %credential.id,
+            error = %e,
+            "Failed to write audit log for credential decrypt"
+        );
+    }
+    Ok(fields)
+}
+
+async fn read(account: &MailAccount, req: ReadRequest) -> Result<Vec<MailMessage>, AppError> {
+    account.require_imap()?;
+    let limit = req.limit.unwrap_or(5).clamp(1, MAX_READ_LIMIT);
+    let mut session = ImapSession::login(account).await?;
+    let result = async {
+        session.select(&req.mailbox, !req.mark_seen).await?;
+        let uids = match req.uid {
+            Some(uid) => vec![uid],
+            None => {
+                let criteria = if req.unseen_only { "UNSEEN" } else { "ALL" };
+                newest(session.search(vec![Arg::atom(criteria)]).await?, limit)
+            }
+        };
+        let body_item = if req.mark_seen {
+            "BODY[]<0.1048576>"
+        } else {
+            "BODY.PEEK[]<0.1048576>"
+        };
+        let mut fetched = session
+            .fetch(&uids, &format!("(UID FLAGS RFC822.SIZE {body_item})"))
+            .await?;
+        if let Some(uid) = req.uid {
+            if fetched.is_empty() {
+                return Err(AppError::NotFound(format!(
+                    "Message {uid} in {}",
+                    req.mailbox
+                )));
+            }
+        }
+        fetched.sort_by(|a, b| b.uid.cmp(&a.uid));
+        Ok::<_, AppError>(fetched.into_iter().map(to_message).collect())
+    }
+    .await;
+    session.logout().await;
+    result
+}
+
+async fn search(account: &MailAccount, req: SearchRequest) -> Result<MailSearchResult, AppError> {
+    account.require_imap()?;
+    let limit = req.limit.unwrap_or(20).clamp(1, MAX_SEARCH_LIMIT);
+    let criteria = search_criteria(&req)?;
+    let mut session = ImapSession::login(account).await?;
+    let result = async {
+        session.select(&req.mailbox, true).await?;
+        let matches = session.search(criteria).await?;
+        let total = matches.len();
+        let mut fetched = session
+            .fetch(&newest(matches, limit), SUMMARY_FIELDS)
+            .await?;
+        fetched.sort_by(|a, b| b.uid.cmp(&a.uid));
+        Ok::<_, AppError>(MailSearchResult {
+            total,
+            messages: fetched.iter().map(to_summary).collect(),
+        })
+    }
+    .await;
+    session.logout().await;
+    result
+}
+
+async fn send(account: &MailAccount, req: SendRequest) -> Result<MailSendReceipt, AppError> {
+    account.require_smtp()?;
+    if req.to.is_empty() {
+        return Err(AppError::Validation(
+            "At least one 'to' recipient is required".into(),
+        ));
+    }
+    for addr in req.to.iter().chain(&req.cc).chain(&req.bcc) {
+        mime::validate_address(addr)?;
+    }
+    if let Some(parent) = &req.in_reply_to {
+        mime::validate_header_value("in_reply_to", parent)?;
+    }
+    let domain = account
+        .from_address
+        .rsplit_once('@')
+        .map(|(_, d)| d)
+        .unwrap_or("localhost");
+    let message_id = format!("<{}@{domain}>", uuid::Uuid::new_v4());
+    let date = chrono::Local::now().to_rfc2822();
+    let in_reply_to = req
+        .in_reply_to
+        .as_deref()
+        .map(|p| format!("<{}>", p.trim_matches(['<', '>'])));
+    let message = mime::compose(&mime::OutgoingMessage {
+        from: &account.from_header(),
+        to: &req.to,
+        cc: &req.cc,
+        subject: &req.subject,
+        text: &req.body,
+        html: req.html.as_deref(),
+        in_reply_to: in_reply_to.as_deref(),
+        message_id: &message_id,
+        date: &date,
+    });
+    let accepted: Vec<String> = req
+        .to
+        .iter()
+        .chain(&req.cc)
+        .chain(&req.bcc)
+        .map(|a| mime::bare_address(a).to_string())
+        .collect();
+    smtp::send(account, &account.from_address, &accepted, &message).await?;
+    Ok(MailSendReceipt {
+        message_id,
+        accepted,
+    })
+}
+
+/// The `limit` highest (newest) UIDs.
+fn newest(mut uids: Vec<u32>, limit: usize) -> Vec<u32> {
+    uids.sort_unstable();
+    let skip = uids.len().saturating_sub(limit);
+    uids.split_off(skip)
+}
+
+fn search_criteria(req: &SearchRequest) -> Result<Vec<Arg>, AppError> {
+    let mut criteria = Vec::new();
+    if let Some(uids) = &req.uids {
+        criteria.push(Arg::atom("UID"));
+        criteria.push(Arg::atom(uids.clone()));
+    }
+    if req.unseen_only {
+        criteria.push(Arg::atom("UNSEEN"));
+    }
+    for (key, value) in [
+        ("FROM", &req.from),
+        ("TO", &req.to),
+        ("SUBJECT", &req.subject),
+        ("TEXT", &req.text),
+    ] {
+        if let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+            criteria.push(Arg::atom(key));
+            criteria.push(Arg::string(value));
+        }
+    }
+    for (key, value) in [("SINCE", &req.since), ("BEFORE", &req.before)] {
+        if let Some(value) = value.as_deref() {
+            let date =
+                chrono::NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|_| {
+                    AppError::Validation(format!("{} must be YYYY-MM-DD", key.to_lowercase()))
+                })?;
+            criteria.push(Arg::atom(key));
+            criteria.push(Arg::atom(date.format("%-d-%b-%Y").to_string()));
+        }
+    }
+    if criteria.is_empty() {
+        criteria.push(Arg::atom("ALL"));
+    }
+    // Non-ASCII terms go out as literals, which need a declared charset.
+    if criteria.iter().any(|c| matches!(c, Arg::Literal(_))) {
+        criteria.splice(0..0, [Arg::atom("CHARSET"), Arg::atom("UTF-8")]);
+    }
+    Ok(criteria)
+}
+
+fn to_summary(fetched: &imap::Fetched) -> MailSummary {
+    let headers = mime::parse_headers(fetched.body.as_deref().unwrap_or_default());
+    summary_from_headers(fetched, &headers)
+}
+
+fn summary_from_headers(fetched: &imap::Fetched, headers: &[(String, String)]) -> MailSummary {
+    let get = |name: &str| mime::header(headers, name).map(str::to_string);
+    MailSummary {
+        uid: fetched.uid,
+        from: get("From").unwrap_or_default(),
+        to: get("To").unwrap_or_default(),
+        cc: get("Cc"),
+        subject: get("Subject").unwrap_or_default(),
+        date: get("Date"),
+        message_id: get("Message-ID"),
+        seen: fetched.seen(),
+        size_bytes: fetched.size,
+    }
+}
+
+fn to_message(fetched: imap::Fetched) -> MailMessage {
+    let parsed = mime::parse_message(fetched.body.as_deref().unwrap_or_default());
+    let body_truncated = parsed.text.chars().count() > MAX_BODY_CHARS;
+    let body = if body_truncated {
+        parsed.text.chars().take(MAX_BODY_CHARS).collect()
+    } else {
+        parsed.text.clone()
+    };
+    MailMessage {
+        summary: summary_from_headers(&fetched, &parsed.headers),
+        body,
+        body_truncated,
+        attachments: parsed.attachments,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn account_defaults_ports_from_security() {
+        let account = MailAccount::from_fields(&fields(&[
+            ("imap_host", "imap.fastmail.com"),
+            ("smtp_host", "smtp.fastmail.com"),
+            ("smtp_security", "starttls"),
+            ("username", "me@fastmail.com"),
+            ("password", "app-password"),
+        ]))
+        .unwrap();
+        assert_eq!(account.imap_port, 993);
+        assert_eq!(account.imap_security, Security::Tls);
+        assert_eq!(account.smtp_port, 587);
+        assert_eq!(account.from_address, "me@fastmail.com");
+        assert!(account.require_smtp().is_ok());
+    }
+
+    #[test]
+    fn plain_connections_are_loopback_only() {
+        let account = MailAccount::from_fields(&fields(&[
+            ("imap_host", "mail.example.com"),
+            ("imap_security", "none"),
+            ("smtp_host", "127.0.0.1"),
+            ("smtp_security", "none"),
+            ("username", "me@example.com"),
+        ]))
+        .unwrap();
+        assert!(matches!(
+            account.require_imap(),
+            Err(AppError::Validation(_))
+        ));
+        assert!(account.require_smtp().is_ok());
+        assert_eq!(account.smtp_port, 25);
+    }
+
+    #[test]
+    fn search_criteria_maps_filters() {
+        let req: SearchRequest = serde_json::from_value(serde_json::json!({
+            "from": "billing@example.com",
+            "subject": "Rechnung März",
+            "since": "2026-03-01",
+            "unseen_only": true,
+        }))
+        .unwrap();
+        let criteria = search_criteria(&req).unwrap();
+        assert_eq!(criteria[0], Arg::atom("CHARSET"));
+        assert_eq!(criteria[2], Arg::atom("UNSEEN"));
+        assert!(criteria.contains(&Arg::Literal("Rechnung März".as_bytes().to_vec())));
+        assert_eq!(criteria[criteria.len() - 1], Arg::atom("1-Mar-2026"));
+
+        let all: SearchRequest = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(search_criteria(&all).unwrap(), vec![Arg::atom("ALL")]);
+        assert_eq!(all.mailbox, "INBOX");
+    }
+
+    #[test]
+    fn newest_keeps_highest_uids() {
+        assert_eq!(newest(vec![9, 2, 14, 5], 2), vec![9, 14]);
+        assert_eq!(newest(vec![3], 10), vec![3]);
+    }
+}
@@ -0,0 +1,274 @@
+//! Minimal ESMTP submission client: EHLO, STARTTLS, AUTH PLAIN/LOGIN, one
+//! message per session.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::error::AppError;
+
+use super::conn::MailConn;
+use super::{MailAccount, Security};
+
+/// A server reply: the code and its text lines.
+struct Reply {
+    code: u16,
+    lines: Vec<String>,
+}
+
+impl Reply {
+    fn text(&self) -> String {
+        self.lines.join(" ")
+    }
+}
+
+struct SmtpSession {
+    conn: MailConn,
+}
+
+/// Deliver one message. `recipients` is the full envelope (to, cc and bcc);
+/// `message` is the rendered RFC 5322 message with CRLF line endings.
+pub(super) async fn send(
+    account: &MailAccount,
+    envelope_from: &str,
+    recipients: &[String],
+    message: &[u8],
+) -> Result<(), AppError> {
+    let conn = MailConn::connect(
+        &account.smtp_host,
+        account.smtp_port,
+        account.smtp_security == Security::Tls,
+    )
+    .await?;
+    let mut session = SmtpSession { conn };
+    session.expect("greeting", &[220]).await?;
+    let mut extensions = session.ehlo().await?;
+    if account.smtp_security == Security::StartTls {
+        if !has_extension(&extensions, "STARTTLS") {
+            return Err(AppError::External(format!(
+                "{} does not offer STARTTLS",
+                account.smtp_host
+            )));
+        }
+        session.command("STARTTLS", &[220]).await?;
+        session.conn = session.conn.upgrade_tls().await?;
+        extensions = session.ehlo().await?;
+    }
+    if !account.username.is_empty() {
+        session.authenticate(account, &extensions).await?;
+    }
+
+    session
+        .command(&format!("MAIL FROM:<{envelope_from}>"), &[250])
+        .await?;
+    for rcpt in recipients {
+        session
+            .command(&format!("RCPT TO:<{rcpt}>"), &[250, 251])
+            .await?;
+    }
+    session.command("DATA", &[354]).await?;
+    let mut data = dot_stuff(message);
+    data.extend_from_slice(b".\r\n");
+    session.conn.write(&data).await?;
+    session.expect("DATA", &[250]).await?;
+
+    let _ = session.command("QUIT", &[221]).await;
+    Ok(())
+}
+
+impl SmtpSession {
+    async fn ehlo(&mut self) -> Result<Vec<String>, AppError> {
+        let reply = self.command("EHLO localhost", &[250]).await?;
+        // The first line is the server's greeting; the rest are extensions.
+        Ok(reply.lines.into_iter().skip(1).collect())
+    }
+
+    async fn authenticate(
+        &mut self,
+        account: &MailAccount,
+        extensions: &[String],
+    ) -> Result<(), AppError> {
+        let auth = extensions
+            .iter()
+            .find_map(|e| e.strip_prefix("AUTH ").or_else(|| e.strip_prefix("AUTH=")))
+            .map(|m| m.to_ascii_uppercase())
+            .unwrap_or_default();
+        let mechanisms: Vec<&str> = auth.split_whitespace().collect();
+        if mechanisms.is_empty() {
+            // Relays that accept mail without AUTH (local bridges, LAN
+            // relays). A server that does need it rejects MAIL FROM.
+            return Ok(());
+        }
+        if mechanisms.contains(&"PLAIN") {
+            let token = STANDARD.encode(format!("\0{}\0{}", account.username, account.password));
+            self.command_redacted(&format!("AUTH PLAIN {token}"), "AUTH PLAIN", &[235])
+                .await?;
+        } else if mechanisms.contains(&"LOGIN") {
+            self.command("AUTH LOGIN", &[334]).await?;
+            let user = STANDARD.encode(&account.username);
+            self.command_redacted(&user, "AUTH LOGIN", &[334]).await?;
+            let pass = STANDARD.encode(&account.password);
+            self.command_redacted(&pass, "AUTH LOGIN", &[235]).await?;
+        } else {
+            return Err(AppError::External(format!(
+                "{} offers no supported AUTH mechanism ({auth})",
+                account.smtp_host
+            )));
+        }
+        Ok(())
+    }
+
+    async fn command(&mut self, line: &str, ok: &[u16]) -> Result<Reply, AppError> {
+        let label = line.split_whitespace().next().unwrap_or(line).to_string();
+        self.command_redacted(line, &label, ok).await
+    }
+
+    /// Send a command line; `label` names it in errors so credentials never
+    /// end up in an error message.
+    async fn command_redacted(
+        &mut self,
+        line: &str,
+        label: &str,
+        ok: &[u16],
+    ) -> Result<Reply, AppError> {
+        self.conn.write(format!("{line}\r\n").as_bytes()).await?;
+        self.expect(label, ok).await
+    }
+
+    async fn expect(&mut self, label: &str, ok: &[u16]) -> Result<Reply, AppError> {
+        let reply = self.read_reply().await?;
+        if ok.contains(&reply.code) {
+            Ok(reply)
+        } else {
+            Err(AppError::External(format!(
+                "SMTP {label} failed: {} {}",
+                reply.code,
+                reply.text()
+            )))
+        }
+    }
+
+    /// Read a (possibly multi-line) reply: `250-...` lines continue,
+    /// `250 ...` ends it.
+    async fn read_reply(&mut self) -> Result<Reply, AppError> {
+        let mut lines = Vec::new();
+        loop {
+            let line = self.conn.read_line().await?;
+            let code = line
+                .get(..3)
+                .and_then(|c| c.parse::<u16>().ok())
+                .ok_or_else(|| AppError::External(format!("Malformed SMTP reply: {line}")))?;
+            let more = line.as_bytes().get(3) == Some(&b'-');
+            lines.push(line.get(4..).unwrap_or("").to_string());
+            if !more {
+                return Ok(Reply { code, lines });
+            }
+        }
+    }
+}
+
+fn has_extension(extensions: &[String], name: &str) -> bool {
+    extensions.iter().any(|e| {
+        e.split_whitespace()
+            .next()
+            .is_some_and(|kw| kw.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Escape lines starting with `.` and make sure the data ends with CRLF.
+fn dot_stuff(message: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(message.len() + 16);
+    let mut line_start = true;
+    for &b in message {
+        if line_start && b == b'.' {
+            out.push(b'.');
+        }
+        out.push(b);
+        line_start = b == b'\n';
+    }
+    if !out.ends_with(b"\r\n") {
+        out.extend_from_slice(b"\r\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    #[test]
+    fn dot_stuffing_escapes_leading_dots() {
+        assert_eq!(
+            dot_stuff(b".hidden\r\nok\r\n..\r\n"),
+            b"..hidden\r\nok\r\n...\r\n"
+        );
+        assert_eq!(dot_stuff(b"no newline"), b"no newline\r\n");
+    }
+
+    #[tokio::test]
+    async fn sends_through_a_plain_loopback_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let (read, mut write) = socket.into_split();
+            let mut lines = BufReader::new(read).lines();
+            let mut transcript = Vec::new();
+            write.write_all(b"220 test ESMTP\r\n").await.unwrap();
+            let mut in_data = false;
+            while let Some(line) = lines.next_line().await.unwrap() {
+                transcript.push(line.clone());
+                let reply: &[u8] = if in_data {
+                    if line != "." {
+                        continue;
+                    }
+                    in_data = false;
+                    b"250 queued\r\n"
+                } else if line.starts_with("EHLO") {
+                    b"250-test\r\n250 AUTH LOGIN PLAIN\r\n"
+                } else if line.starts_with("AUTH PLAIN") {
+                    b"235 ok\r\n"
+                } else if line == "DATA" {
+                    in_data = true;
+                    b"354 go\r\n"
+                } else if line == "QUIT" {
+                    write.write_all(b"221 bye\r\n").await.unwrap();
+                    break;
+                } else {
+                    b"250 ok\r\n"
+                };
+                write.write_all(reply).await.unwrap();
+            }
+            transcript
+        });
+
+        let account = MailAccount {
+            imap_host: String::new(),
+            imap_port: 0,
+            imap_security: Security::Plain,
+            smtp_host: "127.0.0.1".into(),
+            smtp_port: port,
+            smtp_security: Security::Plain,
+            username: "me@example.com".into(),
+            password: "secret".into(),
+            from_address: "me@example.com".into(),
+            from_name: None,
+        };
+        let recipients = vec!["a@example.com".to_string(), "b@example.com".to_string()];
+        send(
+            &account,
+            "me@example.com",
+            &recipients,
+            b"Subject: hi\r\n\r\n.dot\r\n",
+        )
+        .await
+        .unwrap();
+
+        let transcript = server.await.unwrap();
+        assert!(transcript.contains(&"MAIL FROM:<me@example.com>".to_string()));
+        assert!(transcript.contains(&"RCPT TO:<b@example.com>".to_string()));
+        assert!(transcript.contains(&"..dot".to_string()));
+        assert_eq!(transcript.last().map(String::as_str), Some("QUIT"));
+    }
+}
@@ -52,6 +52,10 @@ pub enum ErrorCategory {
     /// healing rule-based path typically resolves these; the persona-level
     /// `consecutive_failures < 3` guard prevents an infinite transient loop.
     TransientProcessFailure,
+    /// The run completed but its output violated the persona's output
+    /// contract (see `engine::output_contract`). Retrying reproduces the same
+    /// output, so healing routes it to a prompt issue.
+    ContractViolation,
     /// No known pattern matched.
     Unknown,
 }
@@ -148,6 +152,13 @@ pub fn classify_error(error: &str, timed_out: bool, session_limit: bool) -> Erro
 
     let lower = error.to_lowercase();
 
+    // Output contract violations carry the offending output's schema errors,
+    // which can contain any of the tokens matched below — so the runner's
+    // prefix is checked first.
+    if lower.starts_with(super::output_contract::VIOLATION_PREFIX) {
+        return ErrorCategory::ContractViolation;
+    }
+
     // Rate limit patterns
     if lower.contains("rate limit")
         || lower.contains("too many requests")
@@ -331,8 +342,9 @@ pub fn is_auto_fixable(category: &ErrorCategory) -> bool {
 /// queue for human resolution because they describe a run that never produced
 /// real output.
 ///
-/// Excludes `ToolError`, `Validation`, and `Unknown` — those can legitimately
-/// surface review-worthy state from the LLM's perspective.
+/// Excludes `ToolError`, `Validation`, `ContractViolation`, and `Unknown` —
+/// those can legitimately surface review-worthy state from the LLM's
+/// perspective.
 pub fn is_technical_failure(category: &ErrorCategory) -> bool {
     matches!(
         category,
@@ -384,6 +396,7 @@ pub fn default_severity(category: &ErrorCategory) -> ErrorSeverity {
         ErrorCategory::Network => ErrorSeverity::Medium,
         ErrorCategory::Validation => ErrorSeverity::Low,
         ErrorCategory::TransientProcessFailure => ErrorSeverity::Low,
+        ErrorCategory::ContractViolation => ErrorSeverity::Medium,
         ErrorCategory::Unknown => ErrorSeverity::Medium,
     }
 }
@@ -401,7 +414,7 @@ pub fn db_category(category: &ErrorCategory) -> &'static str {
         | ErrorCategory::ProviderNotFound
         | ErrorCategory::CredentialError => "config",
         ErrorCategory::ToolError => "tool",
-        ErrorCategory::Validation => "prompt",
+        ErrorCategory::Validation | ErrorCategory::ContractViolation => "prompt",
         ErrorCategory::TransientProcessFailure => "external",
         ErrorCategory::Unknown => "external",
     }
@@ -439,6 +452,7 @@ mod tests {
         for cat in [
             ErrorCategory::ToolError,
             ErrorCategory::Validation,
+            ErrorCategory::ContractViolation,
             ErrorCategory::Unknown,
         ] {
             assert!(
@@ -804,6 +818,10 @@ mod tests {
         ("socket hang up", ErrorCategory::Network),
         ("Execution failed (exit code 137): Killed", ErrorCategory::TransientProcessFailure),
         ("Execution failed (exit code 1): ", ErrorCategory::TransientProcessFailure),
+        (
+            "contract_violation: output does not conform to the persona's output contract: /score: \"429\" is not of type \"integer\"",
+            ErrorCategory::ContractViolation,
+        ),
         ("some entirely novel failure", ErrorCategory::Unknown),
     ];
 
@@ -45,6 +45,7 @@ event_names! {
     EXECUTION_FILE_CHANGE      => "execution-file-change",
     EXECUTION_PROGRESS         => "execution-progress",
     EXECUTION_REVIEW_REQUEST   => "execution-review-request",
+    BATCH_EXECUTION_PROGRESS   => "batch-execution-progress",
 
     // Queue
     QUEUE_STATUS               => "queue-status",
@@ -81,6 +82,7 @@ event_names! {
     MANUAL_REVIEW_RESOLVED     => "manual-review-resolved",
     REVIEW_DISPATCH_BLOCKED    => "review-dispatch-blocked",
     REVIEW_MESSAGE_ADDED       => "review-message-added",
+    TOOL_APPROVAL_REQUIRED     => "tool-approval-required",
     // Persona event-bus signals published when a human review is resolved
     // (by the user OR by Athena). Personas subscribe to drive event-orchestrated
     // continuation after an approval/rejection. Dynamic in code via
@@ -120,6 +122,9 @@ event_names! {
     KB_INGEST_COMPLETE         => "kb:ingest_complete",
     KB_INGEST_ERROR            => "kb:ingest_error",
 
+    // Local models
+    OLLAMA_PULL_PROGRESS       => "ollama-pull-progress",
+
     // Credential automation
     AUTO_CRED_BROWSER_STATUS   => "auto-cred-browser-status",
     AUTO_CRED_BROWSER_PROGRESS => "auto-cred-browser-progress",
@@ -164,6 +169,9 @@ event_names! {
     // shipped findings; a `signal_dispatch_*` op asks for work to start on one.
     HEALTH_INGEST_REQUESTED          => "health-ingest-requested",
     SIGNAL_DISPATCH_REQUESTED        => "signal-dispatch-requested",
+    // A webhook or chain fired a system-op automation (source_id = automation
+    // id); the bus loop runs it. See engine/system_ops.rs.
+    SYSTEM_OP_FIRED                  => "system_op.fired",
     IDEA_SCAN_STATUS           => "idea-scan-status",
     IDEA_SCAN_OUTPUT           => "idea-scan-output",
     IDEA_SCAN_COMPLETE         => "idea-scan-complete",
@@ -217,6 +225,7 @@ event_names! {
     OVERDUE_TRIGGERS_FIRED     => "overdue-triggers-fired",
     ZOMBIE_EXECUTIONS_DETECTED => "zombie-executions-detected",
     EXECUTIONS_SILENT_DETECTED => "executions-silent-detected",
+    QUEUE_STUCK_DETECTED => "queue-stuck-detected",
     AUTO_ROLLBACK_TRIGGERED    => "auto-rollback-triggered",
     SUBSCRIPTION_CRASHED       => "subscription-crashed",
 
@@ -263,6 +272,9 @@ event_names! {
     // Cloud-app pairing request (personas://pair deep link or POST /pair/request)
     PAIRING_REQUESTED          => "pairing-requested",
 
+    // Push notification tapped on the phone (personas://open/<tab> deep link)
+    OPEN_TAB_REQUESTED         => "open-tab-requested",
+
     // Engine fallback (unrecognized engine setting)
     ENGINE_FALLBACK            => "engine-fallback",
 
@@ -117,6 +117,7 @@ const BUILTIN_EVENT_TYPES: &[(&str, &str)] = &[
     // Scheduler reliability signals (never listener-matched — informational)
     ("schedule.missed.offline", "scheduler"),
     ("schedule.skipped.overlap", "scheduler"),
+    ("schedule.catch_up", "scheduler"),
 ];
 
 /// Lazily-built set of the *canonical* forms of every builtin type, for O(1)
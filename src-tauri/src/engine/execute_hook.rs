@@ -0,0 +1,178 @@
+//! `POST /hooks/execute/{token}` — trigger a persona from n8n, Zapier, curl.
+//!
+//! Each persona can hold one execute-hook token (`persona_execute_hooks`,
+//! hashed at rest). The token in the path is the only credential, so the URL
+//! itself is the secret — unlike `/webhook/{trigger_id}`, callers don't need to
+//! sign bodies, which most no-code tools can't do.
+//!
+//! The JSON body (if any) becomes the execution's `input_data`. The caller then
+//! waits up to the hook's `wait_timeout_secs` (override per request with
+//! `?wait=<secs>`, capped at [`MAX_WAIT_SECS`]) for the run to finish:
+//!
+//! - finished in time → `200` with status, output, error, and cost;
+//! - still running    → `202` with the `execution_id`, to poll later through
+//!   `/api/executions/{id}` or the app.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State as AxumState},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+
+use super::execution_wait::{self, ExecutionWaitResult};
+use super::management_api::{start_persona_execution, ManagementState};
+use crate::db::repos::resources::execute_hooks as hook_repo;
+use crate::engine::rate_limiter::WEBHOOK_TRIGGER_WINDOW;
+
+/// Upper bound on how long one request may hold the connection open.
+pub const MAX_WAIT_SECS: i64 = 300;
+/// Executions per persona per minute accepted through its hook.
+const HOOK_RATE_MAX: usize = 30;
+
+#[derive(Deserialize)]
+struct HookQuery {
+    wait: Option<i64>,
+}
+
+#[derive(Serialize)]
+struct HookResponse {
+    execution_id: Option<String>,
+    status: String,
+    /// True when the execution reached a terminal state within the wait.
+    completed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cost_usd: Option<f64>,
+}
+
+impl HookResponse {
+    fn rejected(status: StatusCode, msg: impl Into<String>) -> Response {
+        (
+            status,
+            Json(Self {
+                execution_id: None,
+                status: "rejected".into(),
+                completed: false,
+                output: None,
+                error: Some(msg.into()),
+                cost_usd: None,
+            }),
+        )
+            .into_response()
+    }
+}
+
+/// Router for the execute hook. Merged into the webhook server next to the
+/// management API, but outside its API-key middleware: the path token is the
+/// credential.
+pub fn execute_hook_router(state: ManagementState) -> Router {
+    Router::new()
+        .route("/hooks/execute/{token}", post(handle_execute_hook))
+        .with_state(Arc::new(state))
+}
+
+/// Seconds to wait for this request: the `?wait=` override if given, else the
+/// hook's configured default, clamped to `0..=MAX_WAIT_SECS`.
+fn effective_wait(requested: Option<i64>, configured: i64) -> Duration {
+    execution_wait::effective_wait(requested, configured.max(0) as u64, MAX_WAIT_SECS as u64)
+}
+
+async fn handle_execute_hook(
+    AxumState(state): AxumState<Arc<ManagementState>>,
+    Path(token): Path<String>,
+    Query(query): Query<HookQuery>,
+    body: Bytes,
+) -> Response {
+    let hook = match hook_repo::find_by_token(&state.pool, &token) {
+        Ok(Some(h)) => h,
+        Ok(None) => return HookResponse::rejected(StatusCode::NOT_FOUND, "Unknown hook"),
+        Err(e) => {
+            tracing::error!(error = %e, "execute hook lookup failed");
+            return HookResponse::rejected(StatusCode::INTERNAL_SERVER_ERROR, "Hook lookup failed");
+        }
+    };
+
+    if let Err(retry_after) = state.rate_limiter.check(
+        &format!("execute_hook:{}", hook.persona_id),
+        HOOK_RATE_MAX,
+        WEBHOOK_TRIGGER_WINDOW,
+    ) {
+        return HookResponse::rejected(
+            StatusCode::TOO_MANY_REQUESTS,
+            format!("Rate limited: max {HOOK_RATE_MAX} executions/minute. Retry after {retry_after}s"),
+        );
+    }
+
+    // JSON bodies pass through as-is; anything else is wrapped like /webhook does.
+    let input = if body.is_empty() {
+        None
+    } else {
+        Some(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap_or_else(|_| {
+                serde_json::json!({ "raw": String::from_utf8_lossy(&body).to_string() })
+            }),
+        )
+    };
+
+    let execution_id = match start_persona_execution(&state, &hook.persona_id, input).await {
+        Ok(id) => id,
+        Err((status, msg)) => return HookResponse::rejected(status, msg),
+    };
+    tracing::info!(
+        persona_id = %hook.persona_id,
+        execution_id = %execution_id,
+        prefix = %hook.token_prefix,
+        "Execute hook started a run"
+    );
+
+    let wait = effective_wait(query.wait, hook.wait_timeout_secs);
+    match execution_wait::wait_for_terminal(&state.pool, &execution_id, wait).await {
+        Ok(ExecutionWaitResult { completed: true, execution: exec }) => (
+            StatusCode::OK,
+            Json(HookResponse {
+                execution_id: Some(exec.id),
+                status: exec.status,
+                completed: true,
+                output: exec.output_data,
+                error: exec.error_message,
+                cost_usd: Some(exec.cost_usd),
+            }),
+        )
+            .into_response(),
+        not_done => (
+            StatusCode::ACCEPTED,
+            Json(HookResponse {
+                execution_id: Some(execution_id),
+                status: not_done.map_or_else(|_| "queued".into(), |r| r.execution.status),
+                completed: false,
+                output: None,
+                error: None,
+                cost_usd: None,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_override_is_clamped() {
+        assert_eq!(effective_wait(None, 30), Duration::from_secs(30));
+        assert_eq!(effective_wait(Some(0), 30), Duration::ZERO);
+        assert_eq!(effective_wait(Some(-5), 30), Duration::ZERO);
+        assert_eq!(effective_wait(Some(10_000), 30), Duration::from_secs(MAX_WAIT_SECS as u64));
+    }
+}
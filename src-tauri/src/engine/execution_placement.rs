@@ -0,0 +1,218 @@
+//! Cloud/local routing for `execute_persona`.
+//!
+//! A persona's `execution_placement` ([`ExecutionPlacement`]) decides whether
+//! a run goes to the connected cloud orchestrator or the local engine.
+//! [`decide`] is the pure policy; [`choose`] gathers its inputs — whether the
+//! orchestrator is usable, whether a local slot is free, and whether the
+//! machine is on battery (`auto` only).
+
+use crate::db::models::{ExecutionPlacement, Persona};
+use crate::engine::ExecutionEngine;
+
+/// Where a run goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementTarget {
+    Local,
+    Cloud,
+}
+
+/// What the policy looks at.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlacementSignals {
+    /// A cloud orchestrator is connected and the user holds a cloud session.
+    pub cloud_available: bool,
+    /// No per-persona or global slot is free; a local run would queue.
+    pub local_saturated: bool,
+    pub on_battery: bool,
+}
+
+/// Apply a placement policy. Without a usable orchestrator everything runs
+/// locally.
+pub fn decide(placement: ExecutionPlacement, signals: PlacementSignals) -> PlacementTarget {
+    if !signals.cloud_available {
+        return PlacementTarget::Local;
+    }
+    match placement {
+        ExecutionPlacement::PreferLocal => PlacementTarget::Local,
+        ExecutionPlacement::PreferCloud => PlacementTarget::Cloud,
+        ExecutionPlacement::Auto if signals.local_saturated || signals.on_battery => {
+            PlacementTarget::Cloud
+        }
+        ExecutionPlacement::Auto => PlacementTarget::Local,
+    }
+}
+
+/// Resolve where `persona` runs right now. An unknown stored value is
+/// treated as `prefer_local`.
+pub async fn choose(
+    engine: &ExecutionEngine,
+    persona: &Persona,
+    cloud_available: bool,
+) -> PlacementTarget {
+    let placement: ExecutionPlacement = persona
+        .execution_placement
+        .as_deref()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_default();
+    if !cloud_available || placement != ExecutionPlacement::Auto {
+        return decide(
+            placement,
+            PlacementSignals {
+                cloud_available,
+                ..Default::default()
+            },
+        );
+    }
+    let local_saturated = {
+        let tracker = engine.tracker().lock().await;
+        !(tracker.has_capacity(&persona.id, persona.max_concurrent)
+            && tracker.has_global_capacity())
+    };
+    let on_battery = !local_saturated
+        && tokio::task::spawn_blocking(on_battery)
+            .await
+            .unwrap_or(false);
+    decide(
+        placement,
+        PlacementSignals {
+            cloud_available,
+            local_saturated,
+            on_battery,
+        },
+    )
+}
+
+/// Whether the machine is running on battery. `false` when it can't tell
+/// (desktops, unsupported platforms, probe failures).
+pub fn on_battery() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+        let mut status = SYSTEM_POWER_STATUS::default();
+        // ACLineStatus: 0 = offline (battery), 1 = online, 255 = unknown.
+        unsafe { GetSystemPowerStatus(&mut status) }.is_ok() && status.ACLineStatus == 0
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("pmset")
+            .args(["-g", "batt"])
+            .output()
+            .map(|out| String::from_utf8_lossy(&out.stdout).contains("'Battery Power'"))
+            .unwrap_or(false)
+    }
+    #[cfg(target_os = "linux")]
+    {
+        linux_on_battery(std::path::Path::new("/sys/class/power_supply"))
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        false
+    }
+}
+
+/// On battery when the machine has a mains supply and none is online, or —
+/// without a mains entry — when a battery reports `Discharging`.
+#[cfg(target_os = "linux")]
+fn linux_on_battery(root: &std::path::Path) -> bool {
+    let read = |dir: &std::path::Path, file: &str| {
+        std::fs::read_to_string(dir.join(file))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default()
+    };
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return false;
+    };
+    let mut mains_seen = false;
+    let mut discharging = false;
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        match read(&dir, "type").as_str() {
+            "Mains" => {
+                if read(&dir, "online") == "1" {
+                    return false;
+                }
+                mains_seen = true;
+            }
+            "Battery" => discharging |= read(&dir, "status") == "Discharging",
+            _ => {}
+        }
+    }
+    mains_seen || discharging
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_routes_by_placement_and_signals() {
+        let connected = PlacementSignals {
+            cloud_available: true,
+            ..Default::default()
+        };
+        let saturated = PlacementSignals {
+            local_saturated: true,
+            ..connected
+        };
+        let battery = PlacementSignals {
+            on_battery: true,
+            ..connected
+        };
+
+        assert_eq!(
+            decide(ExecutionPlacement::PreferLocal, saturated),
+            PlacementTarget::Local
+        );
+        assert_eq!(
+            decide(ExecutionPlacement::PreferCloud, connected),
+            PlacementTarget::Cloud
+        );
+        assert_eq!(
+            decide(ExecutionPlacement::Auto, connected),
+            PlacementTarget::Local
+        );
+        assert_eq!(
+            decide(ExecutionPlacement::Auto, saturated),
+            PlacementTarget::Cloud
+        );
+        assert_eq!(
+            decide(ExecutionPlacement::Auto, battery),
+            PlacementTarget::Cloud
+        );
+
+        // No orchestrator: always local.
+        let offline = PlacementSignals {
+            cloud_available: false,
+            ..saturated
+        };
+        assert_eq!(
+            decide(ExecutionPlacement::PreferCloud, offline),
+            PlacementTarget::Local
+        );
+        assert_eq!(
+            decide(ExecutionPlacement::Auto, offline),
+            PlacementTarget::Local
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn linux_power_supply_probe() {
+        let dir = tempfile::tempdir().unwrap();
+        let supply = |name: &str, files: &[(&str, &str)]| {
+            let d = dir.path().join(name);
+            std::fs::create_dir(&d).unwrap();
+            for (file, value) in files {
+                std::fs::write(d.join(file), format!("{value}\n")).unwrap();
+            }
+        };
+        supply("BAT0", &[("type", "Battery"), ("status", "Discharging")]);
+        supply("AC", &[("type", "Mains"), ("online", "0")]);
+        assert!(linux_on_battery(dir.path()));
+
+        std::fs::write(dir.path().join("AC/online"), "1\n").unwrap();
+        assert!(!linux_on_battery(dir.path()));
+        assert!(!linux_on_battery(&dir.path().join("missing")));
+    }
+}
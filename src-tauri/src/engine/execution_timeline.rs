@@ -0,0 +1,433 @@
+//! Execution timeline replay.
+//!
+//! Merges everything recorded about one execution — its status transitions,
+//! tool steps, trace spans and raw stream lines — into a single time-ordered
+//! list, so the UI can step through what the agent did, with the gaps between
+//! steps. Healing retries of the same work (the execution's retry chain) are
+//! folded in as further attempts, each opened by a `retry` entry.
+//!
+//! Sources use different clocks: tool steps and spans are offsets from the
+//! attempt's start, stream lines and status changes are wall-clock stamps.
+//! Everything is converted to wall-clock time (offsets are anchored on the
+//! attempt's `started_at`) and reported as `at_ms` from the first entry.
+//! `ToolCall` spans are not listed separately — the tool step for the same
+//! call carries its input and output.
+
+use std::io::BufRead;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::models::PersonaExecution;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::traces as trace_repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::trace::{SpanType, TraceSpan};
+use super::types::ToolCallStep;
+
+/// Stream lines kept per attempt; later lines are dropped and the timeline
+/// is flagged `stream_truncated`.
+const MAX_STREAM_LINES: usize = 2000;
+
+/// Longest stream line kept, in characters.
+const MAX_LINE_CHARS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum TimelineEntryKind {
+    /// The execution moved to `detail` (queued, running, or a final status).
+    Status,
+    /// A healing retry of the previous attempt was created.
+    Retry,
+    ToolStep,
+    Span,
+    StreamLine,
+}
+
+/// One step of the replay.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct TimelineEntry {
+    pub index: usize,
+    /// Milliseconds since the first entry.
+    #[ts(type = "number")]
+    pub at_ms: u64,
+    /// Milliseconds since the previous entry.
+    #[ts(type = "number")]
+    pub gap_ms: u64,
+    pub timestamp: String,
+    pub kind: TimelineEntryKind,
+    /// Execution (attempt) the entry belongs to.
+    pub execution_id: String,
+    /// 0 for the original run, then one per healing retry.
+    #[ts(type = "number")]
+    pub attempt: i64,
+    pub title: String,
+    pub detail: Option<String>,
+    #[ts(type = "number | null")]
+    pub duration_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ExecutionTimeline {
+    pub execution_id: String,
+    pub persona_id: String,
+    /// Attempt execution ids, original first.
+    pub attempts: Vec<String>,
+    pub entries: Vec<TimelineEntry>,
+    /// From the first entry to the last.
+    #[ts(type = "number")]
+    pub total_duration_ms: u64,
+    /// Some stream lines were left out (see `MAX_STREAM_LINES`).
+    pub stream_truncated: bool,
+}
+
+/// What one attempt recorded, ready to merge.
+#[derive(Debug, Clone, Default)]
+pub struct AttemptRecord {
+    pub execution_id: String,
+    pub retry_count: i64,
+    pub status: String,
+    pub error_message: Option<String>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub tool_steps: Vec<ToolCallStep>,
+    pub spans: Vec<TraceSpan>,
+    pub stream_lines: Vec<(DateTime<Utc>, String)>,
+    pub stream_truncated: bool,
+}
+
+impl AttemptRecord {
+    fn from_execution(exec: &PersonaExecution) -> Self {
+        Self {
+            execution_id: exec.id.clone(),
+            retry_count: exec.retry_count,
+            status: exec.status.clone(),
+            error_message: exec.error_message.clone(),
+            created_at: exec.created_at.clone(),
+            started_at: exec.started_at.clone(),
+            completed_at: exec.completed_at.clone(),
+            tool_steps: exec
+                .tool_steps
+                .as_ref()
+                .map(|s| s.0.clone())
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Load the timeline of `execution_id` and its retry chain.
+pub fn load(
+    pool: &DbPool,
+    log_dir: &Path,
+    execution_id: &str,
+) -> Result<ExecutionTimeline, AppError> {
+    let execution = exec_repo::get_by_id(pool, execution_id)?;
+    let chain = exec_repo::get_retry_chain(pool, execution_id)?;
+    let attempts = chain
+        .iter()
+        .map(|exec| {
+            let mut record = AttemptRecord::from_execution(exec);
+            record.spans = trace_repo::get_by_execution_id(pool, &exec.id)?
+                .map(|t| t.spans)
+                .unwrap_or_default();
+            if let Some(path) = exec.log_file_path.as_deref() {
+                (record.stream_lines, record.stream_truncated) = read_stream_lines(path, log_dir);
+            }
+            Ok(record)
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+    Ok(build(&execution.id, &execution.persona_id, attempts))
+}
+
+/// `[STDOUT]` lines of an execution log with their logger timestamps. A
+/// missing or unreadable log yields none — the rest of the timeline stands.
+fn read_stream_lines(path: &str, log_dir: &Path) -> (Vec<(DateTime<Utc>, String)>, bool) {
+    let Ok(reader) = super::log_archive::open(path, log_dir) else {
+        return (Vec::new(), false);
+    };
+    let mut lines = Vec::new();
+    let mut truncated = false;
+    for line in reader.lines().map_while(Result::ok) {
+        let Some(parsed) = parse_log_line(&line) else {
+            continue;
+        };
+        if lines.len() == MAX_STREAM_LINES {
+            truncated = true;
+            break;
+        }
+        lines.push(parsed);
+    }
+    (lines, truncated)
+}
+
+/// Split a logger line (`[<rfc3339>] [STDOUT] <text>`) into its parts.
+fn parse_log_line(line: &str) -> Option<(DateTime<Utc>, String)> {
+    let rest = line.strip_prefix('[')?;
+    let (stamp, rest) = rest.split_once("] ")?;
+    let text = rest.strip_prefix("[STDOUT] ")?;
+    let at = DateTime::parse_from_rfc3339(stamp)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((at, text.chars().take(MAX_LINE_CHARS).collect()))
+}
+
+fn parse_ts(ts: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(ts)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Merge the attempts into one ordered timeline.
+pub fn build(
+    execution_id: &str,
+    persona_id: &str,
+    attempts: Vec<AttemptRecord>,
+) -> ExecutionTimeline {
+    // (time, source order, entry) — the source order keeps a status change
+    // ahead of the work it starts when both share a timestamp.
+    let mut raw: Vec<(DateTime<Utc>, u8, TimelineEntry)> = Vec::new();
+    let mut stream_truncated = false;
+
+    for attempt in &attempts {
+        stream_truncated |= attempt.stream_truncated;
+        let entry = |kind, title: String| TimelineEntry {
+            index: 0,
+            at_ms: 0,
+            gap_ms: 0,
+            timestamp: String::new(),
+            kind,
+            execution_id: attempt.execution_id.clone(),
+            attempt: attempt.retry_count,
+            title,
+            detail: None,
+            duration_ms: None,
+            error: None,
+        };
+        let Some(created) = parse_ts(&attempt.created_at) else {
+            continue;
+        };
+        let started = attempt.started_at.as_deref().and_then(parse_ts);
+        let offset =
+            |ms: u64| started.unwrap_or(created) + chrono::Duration::milliseconds(ms as i64);
+
+        if attempt.retry_count > 0 {
+            raw.push((
+                created,
+                0,
+                TimelineEntry {
+                    detail: Some(format!("Retry #{}", attempt.retry_count)),
+                    ..entry(TimelineEntryKind::Retry, "Healing retry created".into())
+                },
+            ));
+        }
+        raw.push((
+            created,
+            1,
+            TimelineEntry {
+                detail: Some("queued".into()),
+                ..entry(TimelineEntryKind::Status, "Queued".into())
+            },
+        ));
+        if let Some(started) = started {
+            raw.push((
+                started,
+                1,
+                TimelineEntry {
+                    detail: Some("running".into()),
+                    ..entry(TimelineEntryKind::Status, "Running".into())
+                },
+            ));
+        }
+        if let Some(completed) = attempt.completed_at.as_deref().and_then(parse_ts) {
+            raw.push((
+                completed,
+                4,
+                TimelineEntry {
+                    detail: Some(attempt.status.clone()),
+                    duration_ms: started.map(|s| (completed - s).num_milliseconds().max(0) as u64),
+                    error: attempt.error_message.clone(),
+                    ..entry(TimelineEntryKind::Status, status_title(&attempt.status))
+                },
+            ));
+        }
+
+        for step in &attempt.tool_steps {
+            raw.push((
+                offset(step.started_at_ms),
+                2,
+                TimelineEntry {
+                    detail: Some(step.input_preview.clone()).filter(|s| !s.is_empty()),
+                    duration_ms: step.duration_ms,
+                    ..entry(TimelineEntryKind::ToolStep, step.tool_name.clone())
+                },
+            ));
+        }
+        for span in &attempt.spans {
+            if span.span_type == SpanType::ToolCall {
+                continue;
+            }
+            raw.push((
+                offset(span.start_ms),
+                2,
+                TimelineEntry {
+                    duration_ms: span.duration_ms,
+                    error: span.error.clone(),
+                    ..entry(TimelineEntryKind::Span, span.name.clone())
+                },
+            ));
+        }
+        for (at, text) in &attempt.stream_lines {
+            raw.push((
+                *at,
+                3,
+                TimelineEntry {
+                    detail: Some(text.clone()),
+                    ..entry(TimelineEntryKind::StreamLine, "Output".into())
+                },
+            ));
+        }
+    }
+
+    raw.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+    let origin = raw.first().map(|(t, _, _)| *t);
+    let mut previous = origin;
+    let entries: Vec<TimelineEntry> = raw
+        .into_iter()
+        .enumerate()
+        .map(|(index, (at, _, mut entry))| {
+            let since = |t: Option<DateTime<Utc>>| {
+                t.map(|t| (at - t).num_milliseconds().max(0) as u64)
+                    .unwrap_or(0)
+            };
+            entry.index = index;
+            entry.at_ms = since(origin);
+            entry.gap_ms = since(previous);
+            entry.timestamp = at.to_rfc3339();
+            previous = Some(at);
+            entry
+        })
+        .collect();
+
+    ExecutionTimeline {
+        execution_id: execution_id.to_string(),
+        persona_id: persona_id.to_string(),
+        attempts: attempts.into_iter().map(|a| a.execution_id).collect(),
+        total_duration_ms: entries.last().map(|e| e.at_ms).unwrap_or(0),
+        entries,
+        stream_truncated,
+    }
+}
+
+fn status_title(status: &str) -> String {
+    let mut chars = status.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "Finished".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(name: &str, span_type: SpanType, start_ms: u64) -> TraceSpan {
+        TraceSpan {
+            span_id: name.into(),
+            parent_span_id: None,
+            span_type,
+            name: name.into(),
+            start_ms,
+            end_ms: Some(start_ms + 10),
+            duration_ms: Some(10),
+            cost_usd: None,
+            input_tokens: None,
+            output_tokens: None,
+            error: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn merges_sources_in_order_across_retries() {
+        let first = AttemptRecord {
+            execution_id: "e1".into(),
+            status: "failed".into(),
+            error_message: Some("boom".into()),
+            created_at: "2026-01-01T00:00:00Z".into(),
+            started_at: Some("2026-01-01T00:00:01Z".into()),
+            completed_at: Some("2026-01-01T00:00:05Z".into()),
+            tool_steps: vec![ToolCallStep {
+                step_index: 1,
+                tool_name: "Read".into(),
+                input_preview: "{}".into(),
+                output_preview: String::new(),
+                started_at_ms: 2000,
+                ended_at_ms: Some(2500),
+                duration_ms: Some(500),
+            }],
+            spans: vec![
+                span("Prompt", SpanType::PromptAssembly, 100),
+                span("ToolCall: Read", SpanType::ToolCall, 2000),
+            ],
+            stream_lines: parse_log_line(
+                "[2026-01-01T00:00:03.500+00:00] [STDOUT] {\"type\":\"result\"}",
+            )
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let retry = AttemptRecord {
+            execution_id: "e2".into(),
+            retry_count: 1,
+            status: "completed".into(),
+            created_at: "2026-01-01T00:01:00Z".into(),
+            started_at: Some("2026-01-01T00:01:00Z".into()),
+            completed_at: Some("2026-01-01T00:01:03Z".into()),
+            ..Default::default()
+        };
+
+        let timeline = build("e1", "p1", vec![first, retry]);
+        let titles: Vec<&str> = timeline.entries.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Queued",
+                "Running",
+                "Prompt",
+                "Read",
+                "Output",
+                "Failed",
+                "Healing retry created",
+                "Queued",
+                "Running",
+                "Completed"
+            ]
+        );
+        assert_eq!(timeline.attempts, vec!["e1", "e2"]);
+        assert_eq!(timeline.total_duration_ms, 63_000);
+        let retry_entry = &timeline.entries[6];
+        assert_eq!((retry_entry.at_ms, retry_entry.gap_ms), (60_000, 55_000));
+        assert_eq!(timeline.entries[5].error.as_deref(), Some("boom"));
+        assert_eq!(timeline.entries[5].duration_ms, Some(4000));
+    }
+
+    #[test]
+    fn parses_only_stdout_log_lines() {
+        assert!(
+            parse_log_line("[2026-01-01T00:00:00+00:00] Process exited with code: 0").is_none()
+        );
+        assert!(parse_log_line("[STDOUT] no timestamp").is_none());
+        let (_, text) = parse_log_line("[2026-01-01T00:00:00+00:00] [STDOUT] hi").unwrap();
+        assert_eq!(text, "hi");
+    }
+}
@@ -0,0 +1,70 @@
+//! Request/response semantics on top of the asynchronous execution queue.
+//!
+//! Executions always run through the normal queue; callers that need the
+//! result in the same call (`execute_persona_and_wait`, `/api/execute?wait=`,
+//! `/hooks/execute`) start the run as usual and then [`wait_for_terminal`] on
+//! its row. A timeout never cancels the run — the caller gets the last-seen
+//! row back with `completed: false` and can keep polling by id.
+
+use std::time::Duration;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::models::PersonaExecution;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Wait used when the caller does not pass one.
+pub const DEFAULT_WAIT_SECS: u64 = 120;
+/// Upper bound on a single wait.
+pub const MAX_WAIT_SECS: u64 = 600;
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Outcome of a synchronous execution.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ExecutionWaitResult {
+    /// True when the run reached a terminal state within the wait.
+    pub completed: bool,
+    /// Final row when `completed`, otherwise the last state seen — output,
+    /// cost, tokens and duration are populated once the run has finished.
+    pub execution: PersonaExecution,
+}
+
+/// Clamp a caller-supplied wait to `0..=max_secs`, defaulting to `default_secs`.
+pub fn effective_wait(requested: Option<i64>, default_secs: u64, max_secs: u64) -> Duration {
+    let secs = requested.map_or(default_secs, |s| s.max(0) as u64);
+    Duration::from_secs(secs.min(max_secs))
+}
+
+/// Poll `execution_id` until it is terminal or `wait` elapses.
+pub async fn wait_for_terminal(
+    pool: &DbPool,
+    execution_id: &str,
+    wait: Duration,
+) -> Result<ExecutionWaitResult, AppError> {
+    let deadline = tokio::time::Instant::now() + wait;
+    loop {
+        let execution = exec_repo::get_by_id(pool, execution_id)?;
+        let completed = execution.state().is_terminal();
+        if completed || tokio::time::Instant::now() >= deadline {
+            return Ok(ExecutionWaitResult { completed, execution });
+        }
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        tokio::time::sleep(POLL_INTERVAL.min(remaining)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effective_wait_defaults_and_clamps() {
+        assert_eq!(effective_wait(None, 30, 300), Duration::from_secs(30));
+        assert_eq!(effective_wait(Some(-5), 30, 300), Duration::ZERO);
+        assert_eq!(effective_wait(Some(10_000), 30, 300), Duration::from_secs(300));
+    }
+}
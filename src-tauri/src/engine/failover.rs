@@ -22,7 +22,7 @@ use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 use chrono::Utc;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use super::byom::PolicyDecision;
@@ -116,7 +116,7 @@ struct GlobalState {
 // =============================================================================
 
 /// Per-provider circuit state snapshot for diagnostics.
-#[derive(Debug, Clone, Serialize, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[ts(export)]
 #[serde(rename_all = "camelCase")]
 pub struct ProviderCircuitState {
@@ -736,6 +736,81 @@ pub fn build_failover_chain(
     chain
 }
 
+// =============================================================================
+// Configured provider chains
+// =============================================================================
+
+/// One entry of a configured provider failover chain
+/// (`provider_failover_chain` setting, globally or per persona).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailoverTarget {
+    /// A CLI engine, tried through the runner's spawn loop.
+    Cli(EngineKind),
+    /// An HTTP backend (`openai_api` / `ollama`), tried in order once every
+    /// CLI candidate is missing or breaker-blocked. HTTP backends are not
+    /// tracked by the circuit breaker.
+    Http(&'static str),
+}
+
+impl FailoverTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailoverTarget::Cli(kind) => kind.as_setting(),
+            FailoverTarget::Http(p) => p,
+        }
+    }
+}
+
+/// Parse a comma-separated provider chain such as
+/// `"claude_code, openai_api, ollama"`, rejecting unknown ids. Duplicates
+/// keep their first position.
+pub fn parse_failover_chain(value: &str) -> Result<Vec<FailoverTarget>, String> {
+    use super::types::providers;
+
+    let mut chain = Vec::new();
+    for id in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let target = match id {
+            providers::OPENAI_API => FailoverTarget::Http(providers::OPENAI_API),
+            providers::OLLAMA => FailoverTarget::Http(providers::OLLAMA),
+            _ => FailoverTarget::Cli(
+                EngineKind::from_str_exact(id)
+                    .ok_or_else(|| format!("unknown failover provider '{id}'"))?,
+            ),
+        };
+        if !chain.contains(&target) {
+            chain.push(target);
+        }
+    }
+    Ok(chain)
+}
+
+/// The chain for `persona_id`: its own `provider_failover_chain:<id>` setting,
+/// else the global `provider_failover_chain`, else empty (built-in failover
+/// only). An unparseable stored value is logged and ignored.
+pub fn load_failover_chain(pool: &DbPool, persona_id: &str) -> Vec<FailoverTarget> {
+    use crate::db::repos::core::settings;
+    use crate::db::settings_keys;
+
+    let persona_key = format!("{}{persona_id}", settings_keys::PROVIDER_FAILOVER_CHAIN_PREFIX);
+    let raw = settings::get(pool, &persona_key)
+        .ok()
+        .flatten()
+        .filter(|v| !v.trim().is_empty())
+        .or_else(|| {
+            settings::get(pool, settings_keys::PROVIDER_FAILOVER_CHAIN)
+                .ok()
+                .flatten()
+        });
+    match raw.as_deref().map(parse_failover_chain) {
+        Some(Ok(chain)) => chain,
+        Some(Err(e)) => {
+            tracing::warn!(persona_id, error = %e, "Ignoring invalid provider failover chain");
+            Vec::new()
+        }
+        None => Vec::new(),
+    }
+}
+
 // =============================================================================
 // BYOM-aware failover chain
 // =============================================================================
@@ -743,9 +818,12 @@ pub fn build_failover_chain(
 /// Build the failover chain with BYOM policy applied.
 ///
 /// If a policy decision has a preferred provider, it becomes the primary.
+/// `fallbacks` (the configured chain's CLI engines, then the global
+/// `cli_engine` default) are appended in order when not already present.
 /// Blocked providers are filtered out of the chain entirely.
 pub fn build_failover_chain_with_policy(
     primary: EngineKind,
+    fallbacks: &[EngineKind],
     model_profile: Option<&ModelProfile>,
     policy: &PolicyDecision,
 ) -> Vec<FailoverCandidate> {
@@ -762,7 +840,18 @@ pub fn build_failover_chain_with_policy(
     };
 
     // Build the base chain
-    let base_chain = build_failover_chain(effective_primary, effective_profile.as_ref());
+    let mut base_chain = build_failover_chain(effective_primary, effective_profile.as_ref());
+
+    // Configured fallbacks, ending with the global default engine
+    for &alt in fallbacks {
+        if !base_chain.iter().any(|c| c.engine_kind == alt) {
+            base_chain.push(FailoverCandidate {
+                engine_kind: alt,
+                model: None,
+                label: format!("{alt:?} (fallback)"),
+            });
+        }
+    }
 
     // Filter out blocked providers
     base_chain
@@ -979,6 +1068,22 @@ mod tests {
             .all(|c| c.engine_kind == EngineKind::ClaudeCode));
     }
 
+    #[test]
+    fn test_parse_failover_chain() {
+        assert_eq!(
+            parse_failover_chain(" claude_code, openai_api,ollama,openai_api ").unwrap(),
+            vec![
+                FailoverTarget::Cli(EngineKind::ClaudeCode),
+                FailoverTarget::Http("openai_api"),
+                FailoverTarget::Http("ollama"),
+            ]
+        );
+        assert_eq!(parse_failover_chain("").unwrap(), vec![]);
+        assert!(parse_failover_chain("claude_code,gemini_cli")
+            .unwrap_err()
+            .contains("gemini_cli"));
+    }
+
     #[test]
     fn test_failover_chain_skips_configured_model() {
         let profile = ModelProfile {
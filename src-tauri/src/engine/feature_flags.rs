@@ -0,0 +1,189 @@
+//! Feature flags for experimental engine behavior.
+//!
+//! Risky engine paths ship dark behind an [`EngineFlag`] and are switched on
+//! selectively:
+//!
+//! - **per persona** — `engine_flags:<persona_id>` setting, a comma-separated
+//!   list of flag names (managed by `set_persona_engine_flags`);
+//! - **per execution** — `input_data._flags`, either a list of names to turn
+//!   on or an object of `name: bool` that can also turn a persona flag off
+//!   for one run.
+//!
+//! The runner resolves the set once per run and stores it on the execution
+//! row (`persona_executions.feature_flags`), so outcomes can be compared per
+//! flag afterwards (`get_engine_flag_stats`). Unknown names are ignored.
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+
+/// An experimental engine path that can be toggled per persona or per run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EngineFlag {
+    /// Alternative stream-output parser.
+    NewParser,
+    /// Run several candidates and keep the answer they agree on.
+    ConsensusMode,
+    /// Start a cheaper model speculatively and escalate on low confidence.
+    SpeculativeRouting,
+}
+
+impl EngineFlag {
+    pub const ALL: [EngineFlag; 3] = [
+        EngineFlag::NewParser,
+        EngineFlag::ConsensusMode,
+        EngineFlag::SpeculativeRouting,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EngineFlag::NewParser => "new_parser",
+            EngineFlag::ConsensusMode => "consensus_mode",
+            EngineFlag::SpeculativeRouting => "speculative_routing",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == name.trim())
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            EngineFlag::NewParser => "Alternative stream-output parser",
+            EngineFlag::ConsensusMode => "Run several candidates and keep the agreed answer",
+            EngineFlag::SpeculativeRouting => {
+                "Start on a cheaper model and escalate on low confidence"
+            }
+        }
+    }
+}
+
+/// A flag as listed to the UI.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct EngineFlagInfo {
+    pub name: String,
+    pub description: String,
+}
+
+pub fn all_flag_info() -> Vec<EngineFlagInfo> {
+    EngineFlag::ALL
+        .into_iter()
+        .map(|f| EngineFlagInfo {
+            name: f.as_str().to_string(),
+            description: f.description().to_string(),
+        })
+        .collect()
+}
+
+/// Flags in effect for one execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActiveFlags(BTreeSet<EngineFlag>);
+
+impl ActiveFlags {
+    // Consulted by experimental paths as they land behind a flag.
+    #[allow(dead_code)]
+    pub fn is_enabled(&self, flag: EngineFlag) -> bool {
+        self.0.contains(&flag)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.0.iter().map(|f| f.as_str()).collect()
+    }
+}
+
+/// Parse a comma-separated flag list, rejecting unknown names.
+pub fn parse_list(value: &str) -> Result<Vec<EngineFlag>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| EngineFlag::parse(s).ok_or_else(|| format!("unknown engine flag '{s}'")))
+        .collect()
+}
+
+/// Flags enabled for `persona_id` through its setting.
+pub fn persona_flags(pool: &DbPool, persona_id: &str) -> Vec<EngineFlag> {
+    let key = format!("{}{persona_id}", settings_keys::ENGINE_FLAGS_PREFIX);
+    settings::get(pool, &key)
+        .ok()
+        .flatten()
+        .map(|v| v.split(',').filter_map(EngineFlag::parse).collect())
+        .unwrap_or_default()
+}
+
+/// Combine the persona's flags with the run's `_flags` override.
+pub fn combine(persona: &[EngineFlag], input_data: Option<&serde_json::Value>) -> ActiveFlags {
+    let mut active: BTreeSet<EngineFlag> = persona.iter().copied().collect();
+    match input_data.and_then(|d| d.get("_flags")) {
+        Some(serde_json::Value::Array(names)) => {
+            active.extend(
+                names
+                    .iter()
+                    .filter_map(|n| n.as_str())
+                    .filter_map(EngineFlag::parse),
+            );
+        }
+        Some(serde_json::Value::Object(map)) => {
+            for (name, on) in map {
+                let Some(flag) = EngineFlag::parse(name) else { continue };
+                match on.as_bool() {
+                    Some(true) => {
+                        active.insert(flag);
+                    }
+                    Some(false) => {
+                        active.remove(&flag);
+                    }
+                    None => {}
+                }
+            }
+        }
+        _ => {}
+    }
+    ActiveFlags(active)
+}
+
+/// Resolve the flags for one run of `persona_id`.
+pub fn resolve(
+    pool: &DbPool,
+    persona_id: &str,
+    input_data: Option<&serde_json::Value>,
+) -> ActiveFlags {
+    combine(&persona_flags(pool, persona_id), input_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_overrides_persona_flags() {
+        let persona = [EngineFlag::NewParser];
+        assert_eq!(combine(&persona, None).names(), vec!["new_parser"]);
+
+        let add = serde_json::json!({ "_flags": ["consensus_mode", "bogus"] });
+        assert_eq!(
+            combine(&persona, Some(&add)).names(),
+            vec!["new_parser", "consensus_mode"]
+        );
+
+        let toggle = serde_json::json!({
+            "_flags": { "new_parser": false, "speculative_routing": true }
+        });
+        let flags = combine(&persona, Some(&toggle));
+        assert!(!flags.is_enabled(EngineFlag::NewParser));
+        assert!(flags.is_enabled(EngineFlag::SpeculativeRouting));
+
+        assert!(parse_list("new_parser, consensus_mode").is_ok());
+        assert!(parse_list("new_parser,nope").is_err());
+    }
+}
@@ -34,9 +34,14 @@ pub struct RawFsEvent {
 /// auto-save, git operations) hundreds of FS events can arrive for the same
 /// path in milliseconds. Events for a given path are suppressed for this
 /// duration after the first trigger-match, reducing work from
-/// O(burst_size × triggers) to O(unique_paths × triggers).
+/// O(burst_size × triggers) to O(unique_paths × triggers). A trigger can
+/// lengthen (not shorten) its own window with `debounce_ms`.
 const DEBOUNCE_MS: u64 = 500;
 
+/// How much of a created/modified file is attached to the event payload as
+/// `snippet`. Enough for a persona to triage the change without a file read.
+const SNIPPET_BYTES: usize = 2048;
+
 /// Shared state for the file watcher.
 pub struct FileWatcherState {
     watcher: Option<RecommendedWatcher>,
@@ -44,6 +49,9 @@ pub struct FileWatcherState {
     /// Tracks when each normalized path last caused a trigger publish.
     /// Used to suppress duplicate events within the debounce window.
     last_fired: HashMap<String, Instant>,
+    /// Per-(trigger, normalized path) quiet periods for triggers whose
+    /// `debounce_ms` is longer than the global window.
+    quiet_until: HashMap<(String, String), Instant>,
 }
 
 impl FileWatcherState {
@@ -52,6 +60,7 @@ impl FileWatcherState {
             watcher: None,
             registered: HashMap::new(),
             last_fired: HashMap::new(),
+            quiet_until: HashMap::new(),
         }
     }
 }
@@ -206,54 +215,146 @@ pub async fn file_watcher_tick(
         })
         .collect();
 
-    // Phase 4: Match and publish (over coalesced, debounced events)
-    let mut fired_paths: Vec<String> = Vec::new();
+    // Phase 4: Match triggers against the coalesced, debounced events
+    let mut candidates = Vec::new();
     for (norm_path, kind, paths) in &coalesced {
-        let mut matched = false;
-        for (trigger_id, persona_id, use_case_id, config) in &fw_triggers {
-            if !matches_trigger(config, kind, paths) {
-                continue;
+        for trigger in &fw_triggers {
+            if matches_trigger(&trigger.3, kind, paths) {
+                candidates.push((norm_path, kind, paths, trigger));
             }
+        }
+    }
 
-            let event_type_str = if let TriggerConfig::FileWatcher { event_type, .. } = config {
-                event_type.as_deref().unwrap_or("file_changed")
-            } else {
-                "file_changed"
+    // Re-acquire lock briefly to apply per-trigger quiet periods and record
+    // last_fired before publishing.
+    let mut fw_state = state.lock().await;
+    let to_fire: Vec<_> = candidates
+        .into_iter()
+        .filter(|(norm_path, _, _, (trigger_id, _, _, config))| {
+            let Some(quiet) = trigger_quiet_period(config) else {
+                return true;
             };
+            let key = (trigger_id.clone(), (*norm_path).clone());
+            if fw_state
+                .quiet_until
+                .get(&key)
+                .is_some_and(|until| now < *until)
+            {
+                return false;
+            }
+            fw_state.quiet_until.insert(key, now + quiet);
+            true
+        })
+        .collect();
+    for (norm_path, ..) in &to_fire {
+        fw_state.last_fired.insert((*norm_path).clone(), now);
+    }
+    // Prune stale entries to prevent unbounded growth
+    prune_last_fired(&mut fw_state.last_fired, debounce_window, now);
+    fw_state.quiet_until.retain(|_, until| now < *until);
+    drop(fw_state);
 
-            let payload = serde_json::json!({
-                "event_kind": kind,
-                "paths": paths,
-            });
-
-            let input = CreatePersonaEventInput {
-                event_type: event_type_str.into(),
-                source_type: "file_watcher".into(),
-                project_id: None,
-                source_id: Some(trigger_id.clone()),
-                target_persona_id: Some(persona_id.clone()),
-                payload: Some(serde_json::to_string(&payload).unwrap_or_default()),
-                use_case_id: use_case_id.clone(),
+    // Phase 5: Publish, attaching a content snippet read once per path
+    let mut snippets: HashMap<&str, Option<(String, bool)>> = HashMap::new();
+    for (norm_path, kind, paths, (trigger_id, persona_id, use_case_id, config)) in to_fire {
+        let (event_type_str, include_content) = match config {
+            TriggerConfig::FileWatcher {
+                event_type,
+                include_content,
+                ..
+            } => (
+                event_type.as_deref().unwrap_or("file_changed"),
+                include_content.unwrap_or(true),
+            ),
+            _ => ("file_changed", true),
+        };
+
+        let mut payload = serde_json::json!({
+            "event_kind": kind,
+            "paths": paths,
+            "path": paths.first(),
+        });
+        if include_content && matches!(kind.as_str(), "create" | "modify") {
+            let snippet = match snippets.get(norm_path.as_str()) {
+                Some(cached) => cached.clone(),
+                None => {
+                    let read = match paths.first() {
+                        Some(path) => read_snippet(path).await,
+                        None => None,
+                    };
+                    snippets.insert(norm_path.as_str(), read.clone());
+                    read
+                }
             };
-
-            if let Err(e) = event_repo::publish(pool, input) {
-                tracing::warn!(trigger_id = %trigger_id, "file_watcher publish error: {e}");
+            if let Some((text, truncated)) = snippet {
+                payload["snippet"] = serde_json::json!(text);
+                payload["snippet_truncated"] = serde_json::json!(truncated);
             }
-            matched = true;
         }
-        if matched {
-            fired_paths.push(norm_path.clone());
+
+        let input = CreatePersonaEventInput {
+            event_type: event_type_str.into(),
+            source_type: "file_watcher".into(),
+            project_id: None,
+            source_id: Some(trigger_id.clone()),
+            target_persona_id: Some(persona_id.clone()),
+            payload: Some(serde_json::to_string(&payload).unwrap_or_default()),
+            use_case_id: use_case_id.clone(),
+        };
+
+        if let Err(e) = event_repo::publish(pool, input) {
+            tracing::warn!(trigger_id = %trigger_id, "file_watcher publish error: {e}");
         }
     }
+}
 
-    // Re-acquire lock briefly for last_fired bookkeeping
-    let mut fw_state = state.lock().await;
-    for path in fired_paths {
-        fw_state.last_fired.insert(path, now);
+/// The trigger's own `debounce_ms` when it is longer than the global window,
+/// capped at the validation maximum.
+fn trigger_quiet_period(config: &TriggerConfig) -> Option<Duration> {
+    match config {
+        TriggerConfig::FileWatcher {
+            debounce_ms: Some(ms),
+            ..
+        } if *ms > DEBOUNCE_MS => {
+            let max = crate::validation::trigger::MAX_FILE_WATCH_DEBOUNCE_MS as u64;
+            Some(Duration::from_millis((*ms).min(max)))
+        }
+        _ => None,
+    }
+}
+
+/// Read the leading text of a changed file. `None` for directories,
+/// unreadable files and binary content; the flag reports truncation.
+async fn read_snippet(path: &str) -> Option<(String, bool)> {
+    use tokio::io::AsyncReadExt;
+
+    let file = tokio::fs::File::open(path).await.ok()?;
+    if !file.metadata().await.ok()?.is_file() {
+        return None;
+    }
+    let mut buf = Vec::with_capacity(SNIPPET_BYTES + 1);
+    file.take(SNIPPET_BYTES as u64 + 1)
+        .read_to_end(&mut buf)
+        .await
+        .ok()?;
+    let truncated = buf.len() > SNIPPET_BYTES;
+    buf.truncate(SNIPPET_BYTES);
+    snippet_text(&buf).map(|text| (text, truncated))
+}
+
+/// Decode snippet bytes as UTF-8. NUL bytes or invalid sequences mark the
+/// file as binary; a character cut at the byte limit is dropped.
+fn snippet_text(bytes: &[u8]) -> Option<String> {
+    if bytes.contains(&0) {
+        return None;
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Some(text.to_string()),
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&bytes[..e.valid_up_to()])
+            .ok()
+            .map(String::from),
+        Err(_) => None,
     }
-    // Prune stale last_fired entries to prevent unbounded growth
-    prune_last_fired(&mut fw_state.last_fired, debounce_window, now);
-    drop(fw_state);
 }
 
 /// Normalize a path for dedup: lowercase + forward slashes.
@@ -293,6 +394,7 @@ fn matches_trigger(config: &TriggerConfig, kind: &str, paths: &[String]) -> bool
         watch_paths: Some(ref wpaths),
         events,
         glob_filter,
+        ignore_patterns,
         ..
     } = config
     {
@@ -303,13 +405,26 @@ fn matches_trigger(config: &TriggerConfig, kind: &str, paths: &[String]) -> bool
             }
         }
 
-        // Check path prefix match
-        let path_matches = paths.iter().any(|rp| {
-            let rp_lower = rp.to_lowercase().replace('\\', "/");
-            wpaths
+        // Check path prefix match. Ignore patterns apply to the part of the
+        // path below the watch root, so watching `/src/build/app` still works
+        // with `build` ignored.
+        let mut path_matches = false;
+        for rp in paths {
+            let rp_norm = normalize_path(rp);
+            let Some(root) = wpaths
                 .iter()
-                .any(|wp| rp_lower.starts_with(&wp.to_lowercase().replace('\\', "/")))
-        });
+                .map(|wp| normalize_path(wp))
+                .find(|wp| rp_norm.starts_with(wp.as_str()))
+            else {
+                continue;
+            };
+            if let Some(ref ignores) = ignore_patterns {
+                if is_ignored(ignores, &rp_norm[root.len()..]) {
+                    return false;
+                }
+            }
+            path_matches = true;
+        }
         if !path_matches {
             return false;
         }
@@ -497,6 +612,15 @@ fn compute_stale_paths(
     stale
 }
 
+/// Whether any component of `relative` (the normalized path below the watch
+/// root) matches one of the ignore patterns.
+fn is_ignored(patterns: &[String], relative: &str) -> bool {
+    relative
+        .split('/')
+        .filter(|c| !c.is_empty())
+        .any(|component| patterns.iter().any(|p| simple_glob_match(p, component)))
+}
+
 /// Simple glob matching for file name filters.
 fn simple_glob_match(pattern: &str, text: &str) -> bool {
     let pattern = pattern.to_lowercase();
@@ -516,9 +640,30 @@ fn simple_glob_match(pattern: &str, text: &str) -> bool {
         return text.ends_with(ext);
     }
 
+    if pattern.contains('*') {
+        return wildcard_match(&pattern, &text);
+    }
+
     pattern == text
 }
 
+/// `*` matches any run of characters; everything else is literal.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if text.len() < first.len() + last.len() || !text.starts_with(first) || !text.ends_with(last) {
+        return false;
+    }
+    let mut rest = &text[first.len()..text.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -693,6 +838,81 @@ mod tests {
         assert_eq!(coalesced.len(), 1);
     }
 
+    #[test]
+    fn test_simple_glob_wildcards() {
+        assert!(simple_glob_match("~$*", "~$report.docx"));
+        assert!(simple_glob_match("*~", "notes.txt~"));
+        assert!(simple_glob_match("build*.log", "build-2024.log"));
+        assert!(!simple_glob_match("build*.log", "build.txt"));
+        assert!(!simple_glob_match("ab*ba", "aba"));
+    }
+
+    fn watcher_config(ignore: &[&str]) -> TriggerConfig {
+        TriggerConfig::FileWatcher {
+            watch_paths: Some(vec!["/home/me/build/app".into()]),
+            events: None,
+            recursive: Some(true),
+            glob_filter: None,
+            ignore_patterns: Some(ignore.iter().map(|s| s.to_string()).collect()),
+            debounce_ms: None,
+            include_content: None,
+            event_type: None,
+            payload: None,
+        }
+    }
+
+    #[test]
+    fn test_ignore_patterns_match_components_below_root() {
+        let config = watcher_config(&["build", "node_modules", "*.tmp"]);
+        let hit = |p: &str| matches_trigger(&config, "modify", &[p.to_string()]);
+        assert!(hit("/home/me/build/app/src/main.rs"));
+        assert!(!hit("/home/me/build/app/node_modules/x/index.js"));
+        assert!(!hit("/home/me/build/app/src/draft.tmp"));
+        assert!(!hit("/home/me/build/app/build/out.txt"));
+        assert!(!hit("/home/me/other/main.rs"));
+    }
+
+    #[test]
+    fn test_trigger_quiet_period_only_lengthens_global_window() {
+        let mut config = watcher_config(&[]);
+        assert_eq!(trigger_quiet_period(&config), None);
+        if let TriggerConfig::FileWatcher { debounce_ms, .. } = &mut config {
+            *debounce_ms = Some(100);
+        }
+        assert_eq!(trigger_quiet_period(&config), None);
+        if let TriggerConfig::FileWatcher { debounce_ms, .. } = &mut config {
+            *debounce_ms = Some(5_000);
+        }
+        assert_eq!(trigger_quiet_period(&config), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_snippet_text_rejects_binary_and_trims_split_chars() {
+        assert_eq!(snippet_text(b"hello").as_deref(), Some("hello"));
+        assert_eq!(snippet_text(b"PK\x03\x04\x00\x00"), None);
+        // "é" is two bytes; cutting after the first one drops it.
+        assert_eq!(snippet_text(b"caf\xc3").as_deref(), Some("caf"));
+        assert_eq!(snippet_text(b"\xff\xfe text"), None);
+    }
+
+    #[tokio::test]
+    async fn test_read_snippet_truncates_large_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let small = dir.path().join("small.txt");
+        std::fs::write(&small, "id,name\n1,alice\n").unwrap();
+        let (text, truncated) = read_snippet(small.to_str().unwrap()).await.unwrap();
+        assert_eq!(text, "id,name\n1,alice\n");
+        assert!(!truncated);
+
+        let large = dir.path().join("large.log");
+        std::fs::write(&large, "x".repeat(SNIPPET_BYTES * 2)).unwrap();
+        let (text, truncated) = read_snippet(large.to_str().unwrap()).await.unwrap();
+        assert_eq!(text.len(), SNIPPET_BYTES);
+        assert!(truncated);
+
+        assert!(read_snippet(dir.path().to_str().unwrap()).await.is_none());
+    }
+
     #[test]
     fn test_prune_last_fired_removes_old() {
         let mut map = HashMap::new();
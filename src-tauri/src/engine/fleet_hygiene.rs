@@ -0,0 +1,470 @@
+//! Scheduled fleet hygiene review.
+//!
+//! Once a week (and on demand through `run_fleet_hygiene_now`) the fleet is
+//! scanned for agents and credentials that have quietly stopped earning their
+//! keep:
+//!
+//! - personas with no executions in the last `fleet_hygiene_idle_weeks`;
+//! - personas with a budget set that has never been spent against in that window;
+//! - personas disabled by a circuit breaker more than a week ago and left off;
+//! - credentials no execution has used in that window.
+//!
+//! Nothing is changed automatically. Each flagged persona gets one
+//! `fleet_hygiene` message listing its findings and a suggested action, and
+//! the run ends with a single OS notification summarising the fleet. A persona
+//! that still has an unread hygiene message is not messaged again.
+
+use std::collections::BTreeMap;
+
+use rusqlite::params;
+use serde::Serialize;
+use tauri::AppHandle;
+use ts_rs::TS;
+
+use crate::db::models::CreateMessageInput;
+use crate::db::repos::communication::messages as message_repo;
+use crate::db::repos::core::settings;
+use crate::db::settings_keys::{
+    FLEET_HYGIENE_ENABLED, FLEET_HYGIENE_ENABLED_DEFAULT, FLEET_HYGIENE_IDLE_WEEKS,
+    FLEET_HYGIENE_IDLE_WEEKS_DEFAULT, FLEET_HYGIENE_LAST,
+};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Hours between scheduled reviews.
+const INTERVAL_HOURS: i64 = 7 * 24;
+/// How long a breaker-disabled persona may sit switched off before it's flagged.
+const BREAKER_GRACE_DAYS: i64 = 7;
+/// `content_type` of the per-persona hygiene messages.
+const MESSAGE_CONTENT_TYPE: &str = "fleet_hygiene";
+
+/// One thing the review thinks should be cleaned up.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct HygieneFinding {
+    /// `idle_persona` | `untouched_budget` | `breaker_disabled` | `unused_credential`.
+    pub kind: String,
+    /// Persona id, or credential id for `unused_credential`.
+    pub subject_id: String,
+    pub subject_name: String,
+    pub detail: String,
+    /// `archive` | `review_and_archive` | `clear_budget` | `delete_credential`.
+    pub suggested_action: String,
+}
+
+/// Outcome of one review pass.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetHygieneReport {
+    pub generated_at: String,
+    pub idle_weeks: u32,
+    pub findings: Vec<HygieneFinding>,
+    /// Persona messages written by this pass (already-flagged personas are skipped).
+    pub messages_created: u32,
+}
+
+/// Idle window from [`FLEET_HYGIENE_IDLE_WEEKS`].
+pub fn idle_weeks(pool: &DbPool) -> u32 {
+    settings::get(pool, FLEET_HYGIENE_IDLE_WEEKS)
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<u32>().ok())
+        .unwrap_or(FLEET_HYGIENE_IDLE_WEEKS_DEFAULT)
+}
+
+/// Collect findings as of `now`. Read-only.
+pub fn scan(
+    pool: &DbPool,
+    now: chrono::DateTime<chrono::Utc>,
+    idle_weeks: u32,
+) -> Result<Vec<HygieneFinding>, AppError> {
+    let cutoff = (now - chrono::Duration::weeks(i64::from(idle_weeks))).to_rfc3339();
+    let breaker_cutoff = (now - chrono::Duration::days(BREAKER_GRACE_DAYS)).to_rfc3339();
+    let conn = pool.get()?;
+    let mut findings = Vec::new();
+
+    // Disabled by a breaker and left off. Checked first so an agent that is
+    // also idle (it can't run while disabled) gets the more specific finding.
+    let mut stmt = conn.prepare(
+        "SELECT p.id, p.name, MAX(s.at) AS tripped_at
+         FROM personas p
+         JOIN (
+             SELECT persona_id, created_at AS at FROM persona_healing_issues
+             WHERE is_circuit_breaker = 1
+             UNION ALL
+             SELECT persona_id, created_at AS at FROM persona_messages
+             WHERE content_type = 'alert' AND content LIKE 'Persona auto-disabled%'
+         ) s ON s.persona_id = p.id
+         WHERE p.enabled = 0
+           AND p.lifecycle != 'archived'
+           AND COALESCE(p.trust_origin, 'builtin') != 'system'
+         GROUP BY p.id
+         HAVING datetime(MAX(s.at)) < datetime(?1)
+         ORDER BY p.name",
+    )?;
+    let rows = stmt.query_map(params![breaker_cutoff], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+        ))
+    })?;
+    for row in rows {
+        let (id, name, tripped_at) = row?;
+        findings.push(HygieneFinding {
+            kind: "breaker_disabled".into(),
+            subject_id: id,
+            subject_name: name,
+            detail: format!(
+                "Disabled by the circuit breaker on {} and not re-enabled since.",
+                date_part(&tripped_at)
+            ),
+            suggested_action: "review_and_archive".into(),
+        });
+    }
+
+    // Active agents that haven't run in the window.
+    let mut stmt = conn.prepare(
+        "SELECT p.id, p.name,
+                (SELECT MAX(e.created_at) FROM persona_executions e WHERE e.persona_id = p.id)
+         FROM personas p
+         WHERE p.lifecycle = 'active'
+           AND COALESCE(p.trust_origin, 'builtin') != 'system'
+           AND datetime(p.created_at) < datetime(?1)
+           AND NOT EXISTS (
+               SELECT 1 FROM persona_executions e
+               WHERE e.persona_id = p.id AND datetime(e.created_at) >= datetime(?1)
+           )
+         ORDER BY p.name",
+    )?;
+    let rows = stmt.query_map(params![cutoff], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, Option<String>>(2)?,
+        ))
+    })?;
+    for row in rows {
+        let (id, name, last_run) = row?;
+        if findings.iter().any(|f| f.subject_id == id) {
+            continue;
+        }
+        let detail = match last_run {
+            Some(at) => format!(
+                "No executions in {idle_weeks} weeks (last run {}).",
+                date_part(&at)
+            ),
+            None => format!("Never executed since it was created over {idle_weeks} weeks ago."),
+        };
+        findings.push(HygieneFinding {
+            kind: "idle_persona".into(),
+            subject_id: id,
+            subject_name: name,
+            detail,
+            suggested_action: "archive".into(),
+        });
+    }
+
+    // A budget that nothing has been spent against. Idle agents are skipped:
+    // archiving them covers the budget too.
+    let mut stmt = conn.prepare(
+        "SELECT p.id, p.name, p.max_budget_usd
+         FROM personas p
+         WHERE p.max_budget_usd > 0
+           AND p.lifecycle = 'active'
+           AND COALESCE(p.trust_origin, 'builtin') != 'system'
+           AND datetime(p.created_at) < datetime(?1)
+           AND COALESCE((
+               SELECT SUM(e.cost_usd) FROM persona_executions e
+               WHERE e.persona_id = p.id AND datetime(e.created_at) >= datetime(?1)
+           ), 0) = 0
+         ORDER BY p.name",
+    )?;
+    let rows = stmt.query_map(params![cutoff], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, f64>(2)?,
+        ))
+    })?;
+    for row in rows {
+        let (id, name, budget) = row?;
+        if findings.iter().any(|f| f.subject_id == id) {
+            continue;
+        }
+        findings.push(HygieneFinding {
+            kind: "untouched_budget".into(),
+            subject_id: id,
+            subject_name: name,
+            detail: format!(
+                "Budget of ${budget:.2} with no spend against it in {idle_weeks} weeks."
+            ),
+            suggested_action: "clear_budget".into(),
+        });
+    }
+
+    // Credentials nothing has used in the window.
+    let mut stmt = conn.prepare(
+        "SELECT id, name, service_type, last_used_at
+         FROM persona_credentials
+         WHERE datetime(created_at) < datetime(?1)
+           AND (last_used_at IS NULL OR datetime(last_used_at) < datetime(?1))
+         ORDER BY name",
+    )?;
+    let rows = stmt.query_map(params![cutoff], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, Option<String>>(3)?,
+        ))
+    })?;
+    for row in rows {
+        let (id, name, service_type, last_used) = row?;
+        let detail = match last_used {
+            Some(at) => format!("{service_type} credential last used {}.", date_part(&at)),
+            None => format!("{service_type} credential has never been used."),
+        };
+        findings.push(HygieneFinding {
+            kind: "unused_credential".into(),
+            subject_id: id,
+            subject_name: name,
+            detail,
+            suggested_action: "delete_credential".into(),
+        });
+    }
+
+    Ok(findings)
+}
+
+/// Scan the fleet, message every newly flagged persona and send the summary
+/// notification. `app` is `None` in tests.
+pub fn run(pool: &DbPool, app: Option<&AppHandle>) -> Result<FleetHygieneReport, AppError> {
+    let now = chrono::Utc::now();
+    let weeks = idle_weeks(pool);
+    let findings = scan(pool, now, weeks)?;
+
+    let mut by_persona: BTreeMap<&str, Vec<&HygieneFinding>> = BTreeMap::new();
+    for finding in findings.iter().filter(|f| f.kind != "unused_credential") {
+        by_persona
+            .entry(finding.subject_id.as_str())
+            .or_default()
+            .push(finding);
+    }
+
+    let mut messages_created = 0;
+    for (persona_id, persona_findings) in by_persona {
+        if has_unread_message(pool, persona_id)? {
+            continue;
+        }
+        let content = persona_findings
+            .iter()
+            .map(|f| {
+                format!(
+                    "- {} Suggested: {}.",
+                    f.detail,
+                    action_label(&f.suggested_action)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        message_repo::create(
+            pool,
+            CreateMessageInput {
+                persona_id: persona_id.to_string(),
+                execution_id: None,
+                title: Some("Fleet hygiene: cleanup suggested".into()),
+                content,
+                content_type: Some(MESSAGE_CONTENT_TYPE.into()),
+                priority: Some("low".into()),
+                metadata: serde_json::to_string(&persona_findings).ok(),
+                thread_id: None,
+                use_case_id: None,
+            },
+        )?;
+        messages_created += 1;
+    }
+
+    if let Some(app) = app.filter(|_| !findings.is_empty()) {
+        let (title, body) = summary(&findings);
+        crate::notifications::send(app, &title, &body);
+    }
+
+    Ok(FleetHygieneReport {
+        generated_at: now.to_rfc3339(),
+        idle_weeks: weeks,
+        findings,
+        messages_created,
+    })
+}
+
+/// Background entry point: runs [`run`] when enabled and a week has passed
+/// since the last review.
+pub fn hygiene_tick(pool: &DbPool, app: &AppHandle) {
+    let enabled = settings::get(pool, FLEET_HYGIENE_ENABLED)
+        .ok()
+        .flatten()
+        .map(|v| v == "true")
+        .unwrap_or(FLEET_HYGIENE_ENABLED_DEFAULT);
+    if !enabled {
+        return;
+    }
+    let now = chrono::Utc::now();
+    let due = settings::get(pool, FLEET_HYGIENE_LAST)
+        .ok()
+        .flatten()
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+        .map(|prev| {
+            now.signed_duration_since(prev.with_timezone(&chrono::Utc))
+                .num_hours()
+                >= INTERVAL_HOURS
+        })
+        .unwrap_or(true);
+    if !due {
+        return;
+    }
+    // Stamp before running so a failing pass backs off for a full interval
+    // instead of retrying every tick.
+    if let Err(e) = settings::set(pool, FLEET_HYGIENE_LAST, &now.to_rfc3339()) {
+        tracing::warn!(error = %e, "Failed to record fleet hygiene timestamp; skipping run");
+        return;
+    }
+    match run(pool, Some(app)) {
+        Ok(report) => tracing::info!(
+            findings = report.findings.len(),
+            messages = report.messages_created,
+            "Fleet hygiene review complete"
+        ),
+        Err(e) => tracing::warn!(error = %e, "Fleet hygiene review failed"),
+    }
+}
+
+fn has_unread_message(pool: &DbPool, persona_id: &str) -> Result<bool, AppError> {
+    let conn = pool.get()?;
+    let exists = conn.query_row(
+        "SELECT EXISTS(SELECT 1 FROM persona_messages
+                       WHERE persona_id = ?1 AND content_type = ?2 AND is_read = 0)",
+        params![persona_id, MESSAGE_CONTENT_TYPE],
+        |row| row.get::<_, bool>(0),
+    )?;
+    Ok(exists)
+}
+
+fn summary(findings: &[HygieneFinding]) -> (String, String) {
+    let count = |kind: &str| findings.iter().filter(|f| f.kind == kind).count();
+    let parts: Vec<String> = [
+        ("idle_persona", "idle agent(s)"),
+        ("untouched_budget", "unused budget(s)"),
+        (
+            "breaker_disabled",
+            "agent(s) left disabled by the circuit breaker",
+        ),
+        ("unused_credential", "unused credential(s)"),
+    ]
+    .iter()
+    .filter_map(|(kind, label)| match count(kind) {
+        0 => None,
+        n => Some(format!("{n} {label}")),
+    })
+    .collect();
+    (
+        format!("Fleet hygiene: {} item(s) to review", findings.len()),
+        format!(
+            "{}. See agent messages for suggested cleanup.",
+            parts.join(", ")
+        ),
+    )
+}
+
+fn action_label(action: &str) -> &str {
+    match action {
+        "archive" => "archive the agent",
+        "review_and_archive" => "fix and re-enable it, or archive it",
+        "clear_budget" => "remove or lower the budget",
+        "delete_credential" => "delete the credential",
+        other => other,
+    }
+}
+
+/// `YYYY-MM-DD` of a stored timestamp.
+fn date_part(ts: &str) -> &str {
+    ts.get(..10).unwrap_or(ts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan_flags_idle_budget_breaker_and_credentials() {
+        let pool = crate::db::init_test_db().unwrap();
+        let now = chrono::Utc::now();
+        let old = (now - chrono::Duration::weeks(10)).to_rfc3339();
+        let recent = (now - chrono::Duration::days(1)).to_rfc3339();
+        let tripped = (now - chrono::Duration::days(20)).to_rfc3339();
+        {
+            let conn = pool.get().unwrap();
+            for (id, enabled, budget) in [
+                ("idle", 1, None),
+                ("busy", 1, None),
+                ("budgeted", 1, Some(5.0)),
+                ("tripped", 0, None),
+            ] {
+                conn.execute(
+                    "INSERT INTO personas (id, project_id, name, system_prompt, enabled,
+                                           max_budget_usd, created_at, updated_at)
+                     VALUES (?1, 'default', ?1, 'p', ?2, ?3, ?4, ?4)",
+                    params![id, enabled, budget, old],
+                )
+                .unwrap();
+            }
+            for (id, persona, cost) in [("e1", "busy", 0.2), ("e2", "budgeted", 0.0)] {
+                conn.execute(
+                    "INSERT INTO persona_executions (id, persona_id, status, cost_usd, created_at)
+                     VALUES (?1, ?2, 'completed', ?3, ?4)",
+                    params![id, persona, cost, recent],
+                )
+                .unwrap();
+            }
+            conn.execute(
+                "INSERT INTO persona_healing_issues
+                     (id, persona_id, title, description, is_circuit_breaker, created_at)
+                 VALUES ('h1', 'tripped', 'Circuit breaker tripped', 'x', 1, ?1)",
+                params![tripped],
+            )
+            .unwrap();
+            for (id, last_used) in [("stale", None), ("fresh", Some(recent.clone()))] {
+                conn.execute(
+                    "INSERT INTO persona_credentials
+                         (id, name, service_type, encrypted_data, iv, last_used_at,
+                          created_at, updated_at)
+                     VALUES (?1, ?1, 'slack', 'x', 'x', ?2, ?3, ?3)",
+                    params![id, last_used, old],
+                )
+                .unwrap();
+            }
+        }
+
+        let findings = scan(&pool, now, 4).unwrap();
+        let kinds: Vec<(&str, &str)> = findings
+            .iter()
+            .map(|f| (f.kind.as_str(), f.subject_id.as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("breaker_disabled", "tripped"),
+                ("idle_persona", "idle"),
+                ("untouched_budget", "budgeted"),
+                ("unused_credential", "stale"),
+            ]
+        );
+
+        let first = run(&pool, None).unwrap();
+        assert_eq!(first.messages_created, 3);
+        // Unread hygiene messages suppress a repeat on the next pass.
+        assert_eq!(run(&pool, None).unwrap().messages_created, 0);
+    }
+}
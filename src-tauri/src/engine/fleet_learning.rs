@@ -0,0 +1,295 @@
+//! Opt-in anonymous fleet learning for healing backoff patterns.
+//!
+//! When [`FLEET_PATTERN_SHARING_ENABLED`] is on, this install periodically
+//! uploads the backoff patterns it has learned in `healing_knowledge` to a
+//! shared endpoint and downloads the fleet-wide aggregate into
+//! `fleet_healing_recommendations`. The healing engine consults that cache only
+//! when the local knowledge base has no delay of its own (see
+//! `healing::get_knowledge_hint`), so a fresh install starts with sensible
+//! backoffs for built-in services instead of rediscovering them one rate limit
+//! at a time.
+//!
+//! What leaves the machine is deliberately tiny: built-in connector name,
+//! pattern key, learned delay, and a capped occurrence count. No install id,
+//! persona, prompt, payload, or error text is ever sent — the `description`
+//! column (which embeds raw error output) is not even selected, and custom
+//! connector names are filtered out because they are user-chosen.
+//!
+//! The endpoint is resolved from `PERSONAS_FLEET_PATTERNS_URL` (compile-time,
+//! then runtime env). When unset, sharing stays inert even if the toggle is on.
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::models::{FleetHealingRecommendation, FleetPatternSyncResult};
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::settings_keys::{
+    FLEET_PATTERN_SHARING_ENABLED, FLEET_PATTERN_SHARING_ENABLED_DEFAULT,
+    FLEET_PATTERN_SHARING_LAST,
+};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Wire schema version sent with every upload so the endpoint can evolve.
+const SCHEMA_VERSION: u32 = 1;
+/// Hours between automatic exchanges.
+const SYNC_INTERVAL_HOURS: i64 = 24;
+/// Occurrence counts are capped before upload so a single very noisy install
+/// can't dominate the aggregate (and the exact count can't fingerprint it).
+const MAX_SHARED_OCCURRENCES: i64 = 100;
+/// Upper bound on any delay accepted from (or sent to) the fleet. Matches the
+/// healing engine's own backoff ceiling, which clamps again at diagnosis time.
+const MAX_FLEET_DELAY_SECS: i64 = 300;
+/// Hard cap on patterns per upload / recommendations per download.
+const MAX_PATTERNS: usize = 500;
+
+/// One anonymized pattern as uploaded to the fleet endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedHealingPattern {
+    pub service_type: String,
+    pub pattern_key: String,
+    pub recommended_delay_secs: i64,
+    pub occurrence_count: i64,
+}
+
+#[derive(Serialize)]
+struct UploadBody<'a> {
+    schema: u32,
+    patterns: &'a [SharedHealingPattern],
+}
+
+/// One aggregated recommendation as returned by the fleet endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RemoteRecommendation {
+    pub service_type: String,
+    pub pattern_key: String,
+    pub recommended_delay_secs: i64,
+    #[serde(default)]
+    pub install_count: i64,
+}
+
+/// Resolve the fleet endpoint base URL, trailing slash trimmed.
+fn fleet_endpoint() -> Option<String> {
+    if let Some(url) = option_env!("PERSONAS_FLEET_PATTERNS_URL") {
+        if !url.is_empty() {
+            return Some(url.trim_end_matches('/').to_string());
+        }
+    }
+    std::env::var("PERSONAS_FLEET_PATTERNS_URL")
+        .ok()
+        .filter(|u| !u.is_empty())
+        .map(|u| u.trim_end_matches('/').to_string())
+}
+
+/// Whether the user has opted in to fleet pattern sharing.
+pub fn is_enabled(pool: &DbPool) -> bool {
+    settings::get(pool, FLEET_PATTERN_SHARING_ENABLED)
+        .ok()
+        .flatten()
+        .map(|v| v == "true")
+        .unwrap_or(FLEET_PATTERN_SHARING_ENABLED_DEFAULT)
+}
+
+/// Service names and pattern keys must be short lowercase slugs. Anything else
+/// (spaces, paths, URLs, mixed case) is dropped in both directions.
+fn is_safe_slug(s: &str) -> bool {
+    !s.is_empty()
+        && s.len() <= 64
+        && s.chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Filter and normalize local patterns before they leave the machine.
+pub(crate) fn prepare_upload(patterns: Vec<SharedHealingPattern>) -> Vec<SharedHealingPattern> {
+    patterns
+        .into_iter()
+        .filter(|p| is_safe_slug(&p.service_type) && is_safe_slug(&p.pattern_key))
+        .filter(|p| p.recommended_delay_secs > 0)
+        .map(|p| SharedHealingPattern {
+            recommended_delay_secs: p.recommended_delay_secs.min(MAX_FLEET_DELAY_SECS),
+            occurrence_count: p.occurrence_count.clamp(1, MAX_SHARED_OCCURRENCES),
+            ..p
+        })
+        .take(MAX_PATTERNS)
+        .collect()
+}
+
+/// Validate downloaded recommendations. The endpoint is untrusted input: bad
+/// slugs and non-positive delays are dropped, delays are clamped.
+pub(crate) fn sanitize_recommendations(
+    raw: Vec<RemoteRecommendation>,
+    fetched_at: &str,
+) -> Vec<FleetHealingRecommendation> {
+    raw.into_iter()
+        .filter(|r| is_safe_slug(&r.service_type) && is_safe_slug(&r.pattern_key))
+        .filter(|r| r.recommended_delay_secs > 0)
+        .take(MAX_PATTERNS)
+        .map(|r| FleetHealingRecommendation {
+            service_type: r.service_type,
+            pattern_key: r.pattern_key,
+            recommended_delay_secs: r.recommended_delay_secs.min(MAX_FLEET_DELAY_SECS),
+            install_count: r.install_count.max(0),
+            fetched_at: fetched_at.to_string(),
+        })
+        .collect()
+}
+
+/// Run one exchange: upload local patterns, then replace the cached fleet
+/// recommendations with the endpoint's current aggregate.
+///
+/// Errors when sharing is disabled or no endpoint is configured, so the manual
+/// "sync now" path reports why nothing happened.
+pub async fn sync_patterns(pool: &DbPool) -> Result<FleetPatternSyncResult, AppError> {
+    if !is_enabled(pool) {
+        return Err(AppError::Validation(
+            "Fleet pattern sharing is disabled".into(),
+        ));
+    }
+    let base = fleet_endpoint().ok_or_else(|| {
+        AppError::Validation("Fleet pattern endpoint is not configured".into())
+    })?;
+    let http = &*crate::SHARED_HTTP;
+
+    let patterns = prepare_upload(healing_repo::list_shareable_knowledge(pool)?);
+    if !patterns.is_empty() {
+        let resp = http
+            .post(format!("{base}/v1/patterns"))
+            .json(&UploadBody {
+                schema: SCHEMA_VERSION,
+                patterns: &patterns,
+            })
+            .send()
+            .await
+            .map_err(|e| AppError::External(format!("fleet pattern upload: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(AppError::External(format!(
+                "fleet pattern upload failed: {}",
+                resp.status()
+            )));
+        }
+    }
+
+    let resp = http
+        .get(format!("{base}/v1/recommendations"))
+        .send()
+        .await
+        .map_err(|e| AppError::External(format!("fleet recommendations download: {e}")))?;
+    if !resp.status().is_success() {
+        return Err(AppError::External(format!(
+            "fleet recommendations download failed: {}",
+            resp.status()
+        )));
+    }
+    let raw: Vec<RemoteRecommendation> = resp
+        .json()
+        .await
+        .map_err(|e| AppError::External(format!("fleet recommendations decode: {e}")))?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let recommendations = sanitize_recommendations(raw, &now);
+    let downloaded = healing_repo::replace_fleet_recommendations(pool, &recommendations)?;
+    settings::set(pool, FLEET_PATTERN_SHARING_LAST, &now)?;
+
+    Ok(FleetPatternSyncResult {
+        uploaded: patterns.len() as i64,
+        downloaded: downloaded as i64,
+        synced_at: now,
+    })
+}
+
+/// Daily-gated entry point called by `FleetLearningSubscription::tick`. Silent
+/// no-op when disabled or unconfigured; failures are logged and retried on the
+/// next due tick.
+pub async fn fleet_learning_tick(pool: &DbPool) {
+    if !is_enabled(pool) || fleet_endpoint().is_none() {
+        return;
+    }
+    let due = settings::get(pool, FLEET_PATTERN_SHARING_LAST)
+        .ok()
+        .flatten()
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+        .map(|prev| {
+            chrono::Utc::now()
+                .signed_duration_since(prev.with_timezone(&chrono::Utc))
+                .num_hours()
+                >= SYNC_INTERVAL_HOURS
+        })
+        .unwrap_or(true);
+    if !due {
+        return;
+    }
+    match sync_patterns(pool).await {
+        Ok(r) => tracing::info!(
+            uploaded = r.uploaded,
+            downloaded = r.downloaded,
+            "fleet learning: pattern exchange complete"
+        ),
+        Err(e) => tracing::warn!(error = %e, "fleet learning: pattern exchange failed"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(service: &str, key: &str, delay: i64, count: i64) -> SharedHealingPattern {
+        SharedHealingPattern {
+            service_type: service.into(),
+            pattern_key: key.into(),
+            recommended_delay_secs: delay,
+            occurrence_count: count,
+        }
+    }
+
+    #[test]
+    fn prepare_upload_drops_unsafe_and_clamps() {
+        let out = prepare_upload(vec![
+            pattern("slack", "rate_limit", 60, 3),
+            pattern("My Custom API", "rate_limit", 60, 3),
+            pattern("gmail", "timeout", 0, 3),
+            pattern("github", "rate_limit", 9_000, 5_000),
+        ]);
+        assert_eq!(
+            out,
+            vec![
+                pattern("slack", "rate_limit", 60, 3),
+                pattern("github", "rate_limit", MAX_FLEET_DELAY_SECS, MAX_SHARED_OCCURRENCES),
+            ]
+        );
+    }
+
+    #[test]
+    fn sanitize_recommendations_rejects_untrusted_values() {
+        let raw = vec![
+            RemoteRecommendation {
+                service_type: "slack".into(),
+                pattern_key: "rate_limit".into(),
+                recommended_delay_secs: 45,
+                install_count: 40,
+            },
+            RemoteRecommendation {
+                service_type: "../etc".into(),
+                pattern_key: "rate_limit".into(),
+                recommended_delay_secs: 45,
+                install_count: 1,
+            },
+            RemoteRecommendation {
+                service_type: "gmail".into(),
+                pattern_key: "timeout".into(),
+                recommended_delay_secs: -5,
+                install_count: 1,
+            },
+            RemoteRecommendation {
+                service_type: "notion".into(),
+                pattern_key: "rate_limit".into(),
+                recommended_delay_secs: 86_400,
+                install_count: -3,
+            },
+        ];
+        let out = sanitize_recommendations(raw, "2026-01-01T00:00:00Z");
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].service_type, "slack");
+        assert_eq!(out[1].recommended_delay_secs, MAX_FLEET_DELAY_SECS);
+        assert_eq!(out[1].install_count, 0);
+    }
+}
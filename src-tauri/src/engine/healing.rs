@@ -33,6 +33,7 @@
 //! | `Transient`     | → `RetryWithBackoff`                  | → `CreateIssue`                              | Short fixed backoff, ignores `consecutive`  |
 //! | `Config`        | → `AiHealing` (unconditional)         | → `AiHealing`                                | Retries don't help a bad config             |
 //! | `Credential`    | → `CreateIssue` (unconditional)       | → `CreateIssue`                              | Human must rotate creds                     |
+//! | `ContractViolation` | → `CreateIssue` (unconditional)   | → `CreateIssue`                              | Output failed its schema after the runner's one repair turn; a retry reproduces it |
 //! | `Unknown`       | → `CreateIssue` (unconditional)       | → `CreateIssue`                              | Safe default                                |
 //!
 //! ## Priority when multiple counters apply
@@ -102,7 +103,7 @@ pub struct HealingDiagnosis {
 // ---------------------------------------------------------------------------
 
 /// Maximum backoff delay in seconds (5 minutes).
-const MAX_BACKOFF_SECS: u64 = 300;
+pub const MAX_BACKOFF_SECS: u64 = 300;
 /// Buffer added on top of a usage-limit reset time before retrying, so the
 /// retry doesn't race the provider's own clock.
 const USAGE_LIMIT_RESET_BUFFER_SECS: i64 = 120;
@@ -522,6 +523,20 @@ pub fn diagnose(
             db_category: "prompt".into(),
             suggested_fix: Some("Check input data format and prompt structure.".into()),
         },
+        FailureCategory::ContractViolation => HealingDiagnosis {
+            category: *category,
+            action: HealingAction::CreateIssue,
+            title: "Output contract violated".into(),
+            description: format!(
+                "The run completed but its output does not conform to the persona's output contract. Error: {}",
+                truncate(error, 200),
+            ),
+            severity: "medium".into(),
+            db_category: "prompt".into(),
+            suggested_fix: Some(
+                "Tighten the prompt's output instructions so the final result matches the contract schema, or relax the schema.".into(),
+            ),
+        },
         FailureCategory::TransientProcessFailure => {
             // Transient CLI process failure — empty/short stderr suggests the
             // process died without diagnostic output (OOM, signal, brief
@@ -821,6 +836,16 @@ mod tests {
         assert_eq!(d.action, HealingAction::CreateIssue);
     }
 
+    #[test]
+    fn test_diagnose_contract_violation_files_prompt_issue() {
+        let error = "contract_violation: output does not conform to the persona's output contract: /score: \"x\" is not of type \"integer\"";
+        let category = classify_error(error, false, false);
+        assert_eq!(category, FailureCategory::ContractViolation);
+        let d = diagnose(&category, error, 600_000, 0, 0, None);
+        assert_eq!(d.action, HealingAction::CreateIssue);
+        assert_eq!(d.db_category, "prompt");
+    }
+
     #[test]
     fn test_is_auto_fixable() {
         assert!(is_auto_fixable(&FailureCategory::RateLimit));
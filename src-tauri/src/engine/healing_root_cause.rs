@@ -0,0 +1,246 @@
+//! Root-cause summaries for healing issues.
+//!
+//! The diagnosis attached to a healing issue comes from the error-category
+//! ladder in [`super::healing`], so its `suggested_fix` is the same advice for
+//! every failure in a category. Opt-in per persona via the
+//! `healing_root_cause:<persona_id>` setting, each new execution-failure issue
+//! gets one single-turn CLI pass over the run's error and the tail of its log.
+//! The structured result is stored on the issue as `root_cause`, and its
+//! suggested fix replaces the generic one.
+//!
+//! The same analysis can be requested for any issue tied to an execution
+//! (`generate_healing_root_cause`), whether or not the persona opted in.
+
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::db::models::{HealingRootCause, PersonaExecution, PersonaHealingIssue};
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::log_archive;
+use super::memory_reflection::{clamp_chars, extract_json_object, run_claude_oneshot};
+
+/// Log lines kept from the end of the run's log.
+const LOG_TAIL_LINES: usize = 120;
+/// The log tail sent to the model is clipped to this many chars (from the end).
+const MAX_LOG_TAIL_CHARS: usize = 10_000;
+/// The error message sent to the model is clipped to this many chars.
+const MAX_ERROR_CHARS: usize = 3_000;
+/// Most evidence lines kept from the model's answer.
+const MAX_EVIDENCE: usize = 5;
+/// Each stored text field is clipped to this many chars.
+const MAX_FIELD_CHARS: usize = 1_000;
+
+#[derive(Debug, Deserialize)]
+struct AnalysisOutput {
+    summary: String,
+    cause: String,
+    #[serde(default)]
+    evidence: Vec<String>,
+    suggested_fix: String,
+    #[serde(default)]
+    confidence: Option<String>,
+}
+
+/// Whether `persona_id` has opted into automatic root-cause summaries.
+pub fn is_enabled(pool: &DbPool, persona_id: &str) -> bool {
+    let key = format!("{}{persona_id}", settings_keys::HEALING_ROOT_CAUSE_PREFIX);
+    matches!(settings::get(pool, &key), Ok(Some(v)) if v == "true")
+}
+
+/// Issue-creation hook. No-op unless the persona opted in; failures are
+/// logged and leave the issue with its original diagnosis.
+pub async fn on_issue_created(
+    pool: DbPool,
+    log_dir: PathBuf,
+    persona_id: String,
+    issue_id: String,
+) {
+    if !is_enabled(&pool, &persona_id) {
+        return;
+    }
+    match analyze_issue(&pool, &log_dir, &issue_id).await {
+        Ok(_) => tracing::info!(%persona_id, %issue_id, "Generated healing root-cause summary"),
+        Err(e) => {
+            tracing::warn!(%persona_id, %issue_id, error = %e, "Healing root-cause analysis failed")
+        }
+    }
+}
+
+/// Run one analysis pass over the execution behind `issue_id` and store the
+/// result on the issue. Returns the updated issue.
+pub async fn analyze_issue(
+    pool: &DbPool,
+    log_dir: &Path,
+    issue_id: &str,
+) -> Result<PersonaHealingIssue, AppError> {
+    let issue = healing_repo::get_by_id(pool, issue_id)?;
+    let Some(execution_id) = issue.execution_id.as_deref() else {
+        return Err(AppError::Validation(
+            "Root-cause analysis needs an issue raised by an execution".into(),
+        ));
+    };
+    let execution = exec_repo::get_by_id(pool, execution_id)?;
+    let log_tail = read_log_tail(&execution, log_dir);
+
+    let prompt = build_prompt(&issue, &execution, log_tail.as_deref());
+    let raw = run_claude_oneshot(&prompt).await?;
+    let root_cause = parse_output(&raw)?;
+    healing_repo::set_root_cause(pool, issue_id, &root_cause)
+}
+
+/// The last [`LOG_TAIL_LINES`] lines of the run's log, redacted. `None` when
+/// the run has no readable log.
+fn read_log_tail(execution: &PersonaExecution, log_dir: &Path) -> Option<String> {
+    let path = execution.log_file_path.as_deref()?;
+    let reader = match log_archive::open(path, log_dir) {
+        Ok(reader) => reader,
+        Err(e) => {
+            tracing::debug!(execution_id = %execution.id, error = %e, "No log for root-cause analysis");
+            return None;
+        }
+    };
+    let tail = tail_lines(reader, LOG_TAIL_LINES);
+    (!tail.trim().is_empty()).then(|| super::redact::redact_string(&tail))
+}
+
+/// The last `n` lines of `reader`, clipped to [`MAX_LOG_TAIL_CHARS`] from the
+/// end. Stops quietly at the first unreadable line.
+fn tail_lines(reader: impl BufRead, n: usize) -> String {
+    let mut lines = VecDeque::with_capacity(n);
+    for line in reader.lines().map_while(Result::ok) {
+        if lines.len() == n {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+    let tail = Vec::from(lines).join("\n");
+    let skip = tail.chars().count().saturating_sub(MAX_LOG_TAIL_CHARS);
+    if skip == 0 {
+        tail
+    } else {
+        format!(
+            "[truncated]…{}",
+            tail.chars().skip(skip).collect::<String>()
+        )
+    }
+}
+
+fn build_prompt(
+    issue: &PersonaHealingIssue,
+    execution: &PersonaExecution,
+    log_tail: Option<&str>,
+) -> String {
+    let error = execution
+        .error_message
+        .as_deref()
+        .filter(|e| !e.trim().is_empty())
+        .map(|e| clamp_chars(&super::redact::redact_string(e), MAX_ERROR_CHARS))
+        .unwrap_or_else(|| "(no error message recorded)".to_string());
+    format!(
+        r#"You diagnose failed runs of an AI agent. Using the error and the end of the run's log below, find the most likely root cause of THIS failure and the most useful concrete fix. Be specific: name the tool, credential, input, file or setting involved when the evidence shows it. Do not repeat the generic diagnosis unless the evidence supports nothing better, and say so through a low confidence.
+
+## Generic diagnosis
+Title: {}
+Category: {}
+Description: {}
+
+## Run
+Status: {}
+Duration: {}
+
+## Error
+{error}
+
+## Log tail
+{}
+
+Respond with ONLY a JSON object:
+{{"summary": "one sentence on what went wrong", "cause": "the underlying cause", "evidence": ["up to {MAX_EVIDENCE} short quotes from the error or log"], "suggested_fix": "concrete next step for the user", "confidence": "low|medium|high"}}"#,
+        issue.title,
+        issue.category,
+        issue.description,
+        execution.status,
+        execution
+            .duration_ms
+            .map(|ms| format!("{:.1}s", ms as f64 / 1000.0))
+            .unwrap_or_else(|| "unknown".to_string()),
+        log_tail.unwrap_or("(no log available)"),
+    )
+}
+
+fn parse_output(raw: &str) -> Result<HealingRootCause, AppError> {
+    let json = extract_json_object(raw)
+        .ok_or_else(|| AppError::Internal("Failed to parse root-cause output as JSON".into()))?;
+    let parsed: AnalysisOutput = serde_json::from_str(&json)
+        .map_err(|e| AppError::Internal(format!("Invalid JSON in root-cause output: {e}")))?;
+    if parsed.summary.trim().is_empty() || parsed.suggested_fix.trim().is_empty() {
+        return Err(AppError::Internal(
+            "Root-cause output is missing a summary or fix".into(),
+        ));
+    }
+    let confidence = match parsed.confidence.as_deref().map(str::trim) {
+        Some(c @ ("low" | "medium" | "high")) => c.to_string(),
+        _ => "low".to_string(),
+    };
+    Ok(HealingRootCause {
+        summary: clamp_chars(parsed.summary.trim(), MAX_FIELD_CHARS),
+        cause: clamp_chars(parsed.cause.trim(), MAX_FIELD_CHARS),
+        evidence: parsed
+            .evidence
+            .iter()
+            .map(|e| e.trim())
+            .filter(|e| !e.is_empty())
+            .take(MAX_EVIDENCE)
+            .map(|e| clamp_chars(e, MAX_FIELD_CHARS))
+            .collect(),
+        suggested_fix: clamp_chars(parsed.suggested_fix.trim(), MAX_FIELD_CHARS),
+        confidence,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_normalizes_analysis() {
+        let raw = r#"Here is the analysis:
+```json
+{"summary": " The Gmail fetch was rejected. ", "cause": "The OAuth token expired.",
+ "evidence": ["401 invalid_grant", " ", "a", "b", "c", "d", "e"],
+ "suggested_fix": "Reconnect the Gmail credential.", "confidence": "certain"}
+```"#;
+        let rc = parse_output(raw).unwrap();
+        assert_eq!(rc.summary, "The Gmail fetch was rejected.");
+        assert_eq!(rc.evidence.len(), MAX_EVIDENCE);
+        assert_eq!(rc.evidence[0], "401 invalid_grant");
+        assert_eq!(rc.confidence, "low");
+
+        assert!(parse_output("no json here").is_err());
+        assert!(parse_output(r#"{"summary": "x", "cause": "y", "suggested_fix": "  "}"#).is_err());
+    }
+
+    #[test]
+    fn tail_keeps_the_last_lines() {
+        let log: String = (1..=10).map(|i| format!("line {i}\n")).collect();
+        assert_eq!(tail_lines(log.as_bytes(), 3), "line 8\nline 9\nline 10");
+
+        let long = "x".repeat(MAX_LOG_TAIL_CHARS + 10);
+        let tail = tail_lines(long.as_bytes(), 3);
+        assert!(tail.starts_with("[truncated]…"));
+        assert_eq!(
+            tail.chars().count(),
+            MAX_LOG_TAIL_CHARS + "[truncated]…".chars().count()
+        );
+    }
+}
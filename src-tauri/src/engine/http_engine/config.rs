@@ -53,15 +53,17 @@ pub(super) const REMOTE_SAFE_MCP_TOOLS: &[&str] = &[
     "post_message",
 ];
 
-/// Connector MCP tools (Gmail/Drive/Calendar) — opt-in via the
+/// Connector MCP tools (Gmail/Drive/Calendar/IMAP) — opt-in via the
 /// `qwen_connector_tools` setting (default OFF). They route through the desktop
 /// credential proxy on :9420 (credentials stay local; only args + results cross
 /// to the model). Off by default because enabling sends connector RESULTS (e.g.
 /// email content) to the remote provider — a per-team data-residency decision.
+/// Read-only only: `email_smtp_send` is withheld like the other write tools.
 pub(super) const CONNECTOR_TOOLS: &[&str] = &[
     "gmail_list_messages", "gmail_get_message",
     "gdrive_list_files", "gdrive_get_file",
     "gcalendar_list_events",
+    "email_imap_read", "email_imap_search",
 ];
 
 /// Whether a tool name may be exposed to the remote engine.
@@ -9,7 +9,7 @@ use crate::engine::types::{
     ExecutionOutputEvent, ExecutionResult, ExecutionState, ExecutionStatusEvent,
 };
 
-pub(super) fn emit_output(emitter: &dyn ExecutionEventEmitter, execution_id: &str, line: &str) {
+pub(crate) fn emit_output(emitter: &dyn ExecutionEventEmitter, execution_id: &str, line: &str) {
     emit_to(
         emitter,
         event_name::EXECUTION_OUTPUT,
@@ -20,7 +20,7 @@ pub(super) fn emit_output(emitter: &dyn ExecutionEventEmitter, execution_id: &st
     );
 }
 
-pub(super) fn emit_status(
+pub(crate) fn emit_status(
     emitter: &dyn ExecutionEventEmitter,
     execution_id: &str,
     status: ExecutionState,
@@ -42,7 +42,7 @@ pub(super) fn emit_status(
 }
 
 /// Emit a failure status + return a failed `ExecutionResult`.
-pub(super) fn fail(
+pub(crate) fn fail(
     emitter: &dyn ExecutionEventEmitter,
     execution_id: &str,
     error_msg: &str,
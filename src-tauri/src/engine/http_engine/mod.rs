@@ -30,6 +30,9 @@ pub use secrets::{clear_qwen_api_key, qwen_key_configured, store_qwen_api_key};
 /// SSRF egress guard, reused by other engine modules (e.g. the Zapier deploy
 /// path) that POST to an externally-supplied URL.
 pub(crate) use tools::is_blocked_ip;
+/// Shared with the generic OpenAI-compatible backend (`provider::openai_api`).
+pub(crate) use events::{emit_output, emit_status, fail};
+pub(crate) use tools::RemoteToolbox;
 
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -40,27 +40,8 @@ pub(super) async fn run_tool_loop(
         Err(e) => return fail(emitter, execution_id, &format!("HTTP client init failed: {e}"), start_time),
     };
 
-    // Tool catalog = safe built-ins + the remote-safe MCP tools (run in-process
-    // via mcp_server::tools::call_tool against a read connection to the same DB).
-    let mcp_pool = mcp_server::db::open_pool(&default_data_dir().join("personas.db")).ok();
-    let connectors_on = mcp_pool.as_ref().map(connector_tools_enabled).unwrap_or(false);
-    let mut schemas = builtin_tool_schemas();
-    if let Some(mcp_pool) = mcp_pool.as_ref() {
-        for t in mcp_server::tools::list_tools(mcp_pool) {
-            let name = t.get("name").and_then(Value::as_str).unwrap_or("");
-            if tool_allowed(name, connectors_on) {
-                schemas.push(json!({
-                    "type": "function",
-                    "function": {
-                        "name": name,
-                        "description": t.get("description").cloned().unwrap_or_else(|| json!("")),
-                        "parameters": t.get("inputSchema").cloned().unwrap_or_else(|| json!({ "type": "object", "properties": {} })),
-                    }
-                }));
-            }
-        }
-    }
-    let tools_value = Value::Array(schemas);
+    let toolbox = RemoteToolbox::load();
+    let tools_value = toolbox.schemas();
     let mut messages: Vec<Value> = vec![json!({ "role": "user", "content": prompt_text })];
     let mut in_tok: u64 = 0;
     let mut out_tok: u64 = 0;
@@ -132,16 +113,7 @@ pub(super) async fn run_tool_loop(
             let args_str = call["function"]["arguments"].as_str().unwrap_or("{}");
             let args: Value = serde_json::from_str(args_str).unwrap_or_else(|_| json!({}));
             emit_output(emitter, execution_id, &format!("🔧 {name}({})", args.to_string().chars().take(120).collect::<String>()));
-            let result = if name == "get_current_time" || name == "http_get" {
-                execute_builtin_tool(&client, &name, &args).await
-            } else if tool_allowed(&name, connectors_on) {
-                match &mcp_pool {
-                    Some(pool) => mcp_call_text(&name, &args, pool),
-                    None => format!("error: tool '{name}' backend unavailable"),
-                }
-            } else {
-                format!("error: tool '{name}' is not available to the remote engine")
-            };
+            let result = toolbox.call(&client, &name, &args).await;
             emit_output(emitter, execution_id, &format!("   ↳ {}", result.chars().take(200).collect::<String>()));
             messages.push(json!({ "role": "tool", "tool_call_id": id, "content": result }));
         }
@@ -150,6 +122,59 @@ pub(super) async fn run_tool_loop(
     fail(emitter, execution_id, &format!("Tool loop exceeded {MAX_TOOL_ITERS} iterations without a final answer"), start_time)
 }
 
+/// Tools a remote model may call: the safe built-ins plus the remote-safe MCP
+/// tools (run in-process via `mcp_server::tools::call_tool` against a read
+/// connection to the same DB). Shared with `provider::openai_api`.
+pub(crate) struct RemoteToolbox {
+    mcp_pool: Option<mcp_server::db::McpDbPool>,
+    connectors_on: bool,
+    schemas: Vec<Value>,
+}
+
+impl RemoteToolbox {
+    pub(crate) fn load() -> Self {
+        let mcp_pool = mcp_server::db::open_pool(&default_data_dir().join("personas.db")).ok();
+        let connectors_on = mcp_pool.as_ref().map(connector_tools_enabled).unwrap_or(false);
+        let mut schemas = builtin_tool_schemas();
+        if let Some(mcp_pool) = mcp_pool.as_ref() {
+            for t in mcp_server::tools::list_tools(mcp_pool) {
+                let name = t.get("name").and_then(Value::as_str).unwrap_or("");
+                if tool_allowed(name, connectors_on) {
+                    schemas.push(json!({
+                        "type": "function",
+                        "function": {
+                            "name": name,
+                            "description": t.get("description").cloned().unwrap_or_else(|| json!("")),
+                            "parameters": t.get("inputSchema").cloned().unwrap_or_else(|| json!({ "type": "object", "properties": {} })),
+                        }
+                    }));
+                }
+            }
+        }
+        Self { mcp_pool, connectors_on, schemas }
+    }
+
+    /// OpenAI `tools` array for the request body.
+    pub(crate) fn schemas(&self) -> Value {
+        Value::Array(self.schemas.clone())
+    }
+
+    /// Execute one tool call locally and return its text result (errors are
+    /// returned as `error: ...` text for the model, never raised).
+    pub(crate) async fn call(&self, client: &Client, name: &str, args: &Value) -> String {
+        if name == "get_current_time" || name == "http_get" {
+            execute_builtin_tool(client, name, args).await
+        } else if tool_allowed(name, self.connectors_on) {
+            match &self.mcp_pool {
+                Some(pool) => mcp_call_text(name, args, pool),
+                None => format!("error: tool '{name}' backend unavailable"),
+            }
+        } else {
+            format!("error: tool '{name}' is not available to the remote engine")
+        }
+    }
+}
+
 /// Read the connector opt-in (default false) from app_settings via the MCP pool.
 fn connector_tools_enabled(pool: &mcp_server::db::McpDbPool) -> bool {
     pool.get()
@@ -0,0 +1,358 @@
+//! The builtin `http_request` tool, executed by the engine.
+//!
+//! The personas-mcp sidecar forwards `http_request` calls to the management
+//! API (`POST /api/http-request/{persona_id}`), so the request is made here
+//! rather than improvised by the CLI agent (curl, WebFetch). That keeps the
+//! limits out of the model's hands:
+//! - the persona's `http_request` policy and the global [`NetworkPolicy`] are
+//!   checked before sending and again on every redirect hop;
+//! - a per-persona rate limit and a hard timeout (policy `timeout_secs`,
+//!   capped at [`MAX_HTTP_TIMEOUT_SECS`]) apply to every call;
+//! - calls with a `credential_id` go through [`api_proxy`], which injects the
+//!   connector's auth and applies its rate limit — the agent never sees the
+//!   secret;
+//! - the response body is truncated to [`MAX_RESPONSE_BODY_BYTES`], which is
+//!   what the agent receives and what the run's tool steps record.
+//!
+//! [`NetworkPolicy`]: super::tool_policy::NetworkPolicy
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::db::DbPool;
+use crate::error::AppError;
+use crate::utils::text::truncate_on_char_boundary;
+
+use super::api_proxy;
+use super::rate_limiter::RateLimiter;
+use super::tool_policy::{ToolPolicySet, HTTP_REQUEST, MAX_HTTP_TIMEOUT_SECS};
+use super::url_safety::SsrfSafeDnsResolver;
+
+/// Timeout when neither the call nor the policy sets one.
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Requests one persona may make per window.
+const MAX_REQUESTS_PER_WINDOW: usize = 60;
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Largest response body handed back to the agent.
+pub const MAX_RESPONSE_BODY_BYTES: usize = 16 * 1024;
+
+const MAX_REDIRECTS: usize = 5;
+
+/// Request headers the caller may not set.
+const BLOCKED_HEADERS: &[&str] = &["host", "content-length", "transfer-encoding"];
+
+/// Clients for direct requests. Redirects are not followed by reqwest but by
+/// [`send_direct`], so each hop is checked against the policy.
+static SSRF_SAFE_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| build_client(true));
+static PRIVATE_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| build_client(false));
+
+fn build_client(ssrf_safe: bool) -> reqwest::Client {
+    let mut builder = reqwest::Client::builder()
+        .user_agent("Personas-HttpRequest/1.0")
+        .redirect(reqwest::redirect::Policy::none());
+    if ssrf_safe {
+        builder = builder.dns_resolver(Arc::new(SsrfSafeDnsResolver));
+    }
+    builder
+        .build()
+        .expect("Failed to build http_request client")
+}
+
+/// Arguments of an `http_request` call. Either `url`, or `credential_id`
+/// with a `path` relative to the connector's base URL.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HttpToolRequest {
+    #[serde(default = "default_method")]
+    pub method: String,
+    pub url: Option<String>,
+    pub credential_id: Option<String>,
+    pub path: Option<String>,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// A string is sent as-is; any other JSON value is sent as JSON.
+    pub body: Option<Value>,
+    pub timeout_secs: Option<u64>,
+}
+
+fn default_method() -> String {
+    "GET".into()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HttpToolResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub content_type: Option<String>,
+    pub body: String,
+    /// True when `body` was cut at [`MAX_RESPONSE_BODY_BYTES`].
+    pub truncated: bool,
+    pub duration_ms: u64,
+}
+
+/// Run one `http_request` call for `persona_id`.
+pub async fn execute(
+    pool: &DbPool,
+    rate_limiter: &RateLimiter,
+    persona_id: &str,
+    request: HttpToolRequest,
+) -> Result<HttpToolResponse, AppError> {
+    let method = request.method.trim().to_ascii_uppercase();
+    reqwest::Method::from_bytes(method.as_bytes())
+        .map_err(|_| AppError::Validation(format!("'{}' is not an HTTP method", request.method)))?;
+    rate_limiter
+        .check(
+            &format!("{HTTP_REQUEST}:{persona_id}"),
+            MAX_REQUESTS_PER_WINDOW,
+            RATE_WINDOW,
+        )
+        .map_err(|retry_after| {
+            AppError::RateLimited(format!(
+                "{HTTP_REQUEST} rate limit reached; retry in {retry_after}s"
+            ))
+        })?;
+
+    let policies = ToolPolicySet::load(pool, persona_id, Path::new("."));
+    let timeout_secs = effective_timeout(request.timeout_secs, policies.http_timeout_secs());
+
+    let mut headers = request.headers;
+    headers.retain(|name, _| !BLOCKED_HEADERS.contains(&name.to_ascii_lowercase().as_str()));
+    let body = match request.body {
+        None | Some(Value::Null) => None,
+        Some(Value::String(text)) => Some(text),
+        Some(json) => {
+            if !headers
+                .keys()
+                .any(|k| k.eq_ignore_ascii_case("content-type"))
+            {
+                headers.insert("Content-Type".into(), "application/json".into());
+            }
+            Some(json.to_string())
+        }
+    };
+
+    let call = async {
+        match (request.url, request.credential_id) {
+            (Some(url), None) => {
+                send_direct(&policies, persona_id, &method, &url, &headers, body).await
+            }
+            (None, Some(credential_id)) => {
+                let path = request.path.unwrap_or_default();
+                // The resolved URL can carry a secret (webhook URLs), so a
+                // violation names only its host.
+                let guard = |url: &str| {
+                    enforce(&policies, persona_id, url, &method).map_err(|_| {
+                        AppError::Forbidden(format!(
+                            "{HTTP_REQUEST} policy blocks {method} requests to {}",
+                            host_of(url)
+                        ))
+                    })
+                };
+                let response = api_proxy::execute_api_request_guarded(
+                    pool,
+                    &credential_id,
+                    &method,
+                    &path,
+                    headers,
+                    body,
+                    &guard,
+                )
+                .await?;
+                let (body, cut) = cap_body(response.body);
+                Ok(HttpToolResponse {
+                    status: response.status,
+                    status_text: response.status_text,
+                    content_type: response.content_type,
+                    body,
+                    truncated: cut || response.truncated,
+                    duration_ms: response.duration_ms,
+                })
+            }
+            _ => Err(AppError::Validation(
+                "Pass either `url`, or `credential_id` with a `path`".into(),
+            )),
+        }
+    };
+    tokio::time::timeout(Duration::from_secs(timeout_secs), call)
+        .await
+        .map_err(|_| {
+            AppError::External(format!("{HTTP_REQUEST} timed out after {timeout_secs}s"))
+        })?
+}
+
+/// The caller's timeout, defaulting to the policy's, never above the
+/// policy's or the hard cap.
+fn effective_timeout(requested: Option<u64>, policy: Option<u64>) -> u64 {
+    let cap = policy
+        .unwrap_or(MAX_HTTP_TIMEOUT_SECS)
+        .min(MAX_HTTP_TIMEOUT_SECS);
+    requested
+        .or(policy)
+        .unwrap_or(DEFAULT_TIMEOUT_SECS)
+        .clamp(1, cap.max(1))
+}
+
+fn enforce(
+    policies: &ToolPolicySet,
+    persona_id: &str,
+    url: &str,
+    method: &str,
+) -> Result<(), AppError> {
+    match policies.check_request(url, method) {
+        None => Ok(()),
+        Some(violation) => {
+            tracing::warn!(
+                persona_id = %persona_id,
+                rule = violation.rule,
+                "http_request blocked by tool policy"
+            );
+            Err(AppError::Forbidden(violation.error_message()))
+        }
+    }
+}
+
+async fn send_direct(
+    policies: &ToolPolicySet,
+    persona_id: &str,
+    method: &str,
+    url: &str,
+    headers: &HashMap<String, String>,
+    mut body: Option<String>,
+) -> Result<HttpToolResponse, AppError> {
+    let client = if policies.allows_private_network() {
+        &*PRIVATE_CLIENT
+    } else {
+        &*SSRF_SAFE_CLIENT
+    };
+    let mut method = reqwest::Method::from_bytes(method.as_bytes())
+        .map_err(|_| AppError::Validation(format!("'{method}' is not an HTTP method")))?;
+    let mut target = parse_url(url)?;
+    let start = Instant::now();
+
+    let mut hops = 0;
+    let mut response = loop {
+        enforce(policies, persona_id, target.as_str(), method.as_str())?;
+        let mut builder = client.request(method.clone(), target.clone());
+        for (name, value) in headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = &body {
+            builder = builder.body(body.clone());
+        }
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| AppError::External(format!("Request to {target} failed: {e}")))?;
+
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok());
+        let Some(location) = location.filter(|_| response.status().is_redirection()) else {
+            break response;
+        };
+        hops += 1;
+        if hops > MAX_REDIRECTS {
+            return Err(AppError::External(format!(
+                "Request to {url} redirected more than {MAX_REDIRECTS} times"
+            )));
+        }
+        target = target
+            .join(location)
+            .map_err(|e| AppError::External(format!("Bad redirect to '{location}': {e}")))?;
+        if matches!(response.status().as_u16(), 301..=303) && method != reqwest::Method::HEAD {
+            method = reqwest::Method::GET;
+            body = None;
+        }
+    };
+
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let mut buf = Vec::new();
+    let mut truncated = false;
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| AppError::External(format!("Failed to read response body: {e}")))?
+    {
+        buf.extend_from_slice(&chunk);
+        if buf.len() > MAX_RESPONSE_BODY_BYTES {
+            truncated = true;
+            break;
+        }
+    }
+    let (body, cut) = cap_body(String::from_utf8_lossy(&buf).into_owned());
+
+    Ok(HttpToolResponse {
+        status: status.as_u16(),
+        status_text: status.canonical_reason().unwrap_or("").to_string(),
+        content_type,
+        body,
+        truncated: truncated || cut,
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+fn parse_url(url: &str) -> Result<url::Url, AppError> {
+    let parsed = url::Url::parse(url.trim())
+        .map_err(|e| AppError::Validation(format!("Invalid url '{url}': {e}")))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(AppError::Validation(format!(
+            "Only http and https URLs are supported, got '{}'",
+            parsed.scheme()
+        )));
+    }
+    Ok(parsed)
+}
+
+fn host_of(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_else(|| "the connector".into())
+}
+
+fn cap_body(body: String) -> (String, bool) {
+    if body.len() <= MAX_RESPONSE_BODY_BYTES {
+        return (body, false);
+    }
+    (
+        truncate_on_char_boundary(&body, MAX_RESPONSE_BODY_BYTES).to_string(),
+        true,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_defaults_and_caps() {
+        assert_eq!(effective_timeout(None, None), DEFAULT_TIMEOUT_SECS);
+        assert_eq!(effective_timeout(None, Some(10)), 10);
+        assert_eq!(effective_timeout(Some(60), Some(10)), 10);
+        assert_eq!(effective_timeout(Some(5), Some(10)), 5);
+        assert_eq!(effective_timeout(Some(0), None), 1);
+        assert_eq!(effective_timeout(Some(900), None), MAX_HTTP_TIMEOUT_SECS);
+    }
+
+    #[test]
+    fn body_is_capped_on_a_char_boundary() {
+        let (short, cut) = cap_body("ok".into());
+        assert_eq!((short.as_str(), cut), ("ok", false));
+        let (long, cut) = cap_body("é".repeat(MAX_RESPONSE_BODY_BYTES));
+        assert!(cut);
+        assert!(long.len() <= MAX_RESPONSE_BODY_BYTES);
+        assert!(parse_url("file:///etc/passwd").is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! Input payload size management.
+//!
+//! Before a run is spawned, the runner estimates the token size of its
+//! `input_data` (`cost::estimate_tokens`). Above [`INPUT_TOKEN_LIMIT`] the
+//! persona's `input_overflow:<persona_id>` mode decides what happens:
+//!
+//! - `reject` (default) — the run fails up front with an error that says how
+//!   big the input was and how to get it through;
+//! - `chunk` — oversized fields are condensed by a map-reduce summarization
+//!   pre-pass: the text is split into chunks, each chunk is summarized by a
+//!   one-shot CLI call, and the partial summaries are reduced again until
+//!   they fit in a single chunk. Fields are condensed largest first, and only
+//!   until the whole input is back under the limit.
+//!
+//! `_`-prefixed metadata fields (`_use_case`, `_flags`, `_deadline`, …) are
+//! never touched.
+
+use futures_util::stream::{self, StreamExt};
+use serde_json::Value;
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys::{
+    INPUT_OVERFLOW_PREFIX, INPUT_TOKEN_LIMIT, INPUT_TOKEN_LIMIT_DEFAULT,
+};
+use crate::db::DbPool;
+use crate::engine::cost::estimate_tokens;
+
+/// Characters per map chunk (~6k tokens) — small enough that one summary
+/// call stays fast, large enough to keep the call count down.
+const CHUNK_CHARS: usize = 24_000;
+/// Most chunks a single field may be split into; beyond this the input is
+/// refused rather than fanned out into hundreds of CLI calls.
+const MAX_CHUNKS: usize = 64;
+/// Map calls in flight at once.
+const MAP_CONCURRENCY: usize = 3;
+/// Reduce rounds before the partial summaries are used as they are.
+const MAX_REDUCE_ROUNDS: usize = 3;
+
+/// What to do with an input over the token limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowMode {
+    Reject,
+    Chunk,
+}
+
+/// The input to run with, plus a log line when it was condensed.
+pub struct PreparedInput {
+    pub input: Option<Value>,
+    pub note: Option<String>,
+}
+
+/// Token limit from [`INPUT_TOKEN_LIMIT`]; `0` means no limit.
+pub fn token_limit(pool: &DbPool) -> u64 {
+    settings::get(pool, INPUT_TOKEN_LIMIT)
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(INPUT_TOKEN_LIMIT_DEFAULT)
+}
+
+pub fn overflow_mode(pool: &DbPool, persona_id: &str) -> OverflowMode {
+    let key = format!("{INPUT_OVERFLOW_PREFIX}{persona_id}");
+    match settings::get(pool, &key) {
+        Ok(Some(v)) if v == "chunk" => OverflowMode::Chunk,
+        _ => OverflowMode::Reject,
+    }
+}
+
+/// Check `input` against the token limit and reject or condense it per the
+/// persona's overflow mode. `Err` carries the message the run fails with.
+pub async fn prepare(
+    pool: &DbPool,
+    persona_id: &str,
+    input: Option<Value>,
+) -> Result<PreparedInput, String> {
+    let limit = token_limit(pool);
+    let tokens = input
+        .as_ref()
+        .map(|v| estimate_tokens(&v.to_string()))
+        .unwrap_or(0);
+    let Some(input) = input.filter(|_| limit > 0 && tokens > limit) else {
+        return Ok(PreparedInput { input, note: None });
+    };
+
+    match overflow_mode(pool, persona_id) {
+        OverflowMode::Reject => Err(format!(
+            "Input is ~{tokens} tokens, over the {limit}-token input limit. Shorten the input, \
+             raise `{INPUT_TOKEN_LIMIT}`, or set this persona's input overflow mode to `chunk` \
+             to summarize oversized input before the run."
+        )),
+        OverflowMode::Chunk => {
+            let (input, fields) = condense(input, limit).await?;
+            let after = estimate_tokens(&input.to_string());
+            Ok(PreparedInput {
+                input: Some(input),
+                note: Some(format!(
+                    "Input condensed from ~{tokens} to ~{after} tokens by map-reduce summarization ({})",
+                    fields.join(", ")
+                )),
+            })
+        }
+    }
+}
+
+/// Summarize the largest fields of `input` until it fits under `limit`.
+/// Returns the condensed input and the names of the fields it rewrote.
+async fn condense(mut input: Value, limit: u64) -> Result<(Value, Vec<String>), String> {
+    let mut condensed = Vec::new();
+    match &mut input {
+        Value::Object(map) => {
+            let mut fields: Vec<(String, usize)> = map
+                .iter()
+                .filter(|(key, _)| !key.starts_with('_'))
+                .map(|(key, value)| (key.clone(), field_text(value).len()))
+                .collect();
+            fields.sort_by(|a, b| b.1.cmp(&a.1));
+            for (key, _) in fields {
+                let size = serde_json::to_string(&*map)
+                    .map(|s| estimate_tokens(&s))
+                    .unwrap_or(0);
+                if size <= limit {
+                    break;
+                }
+                let Some(value) = map.get_mut(&key) else {
+                    continue;
+                };
+                *value = Value::String(summarize(&field_text(value)).await?);
+                condensed.push(key);
+            }
+        }
+        other => {
+            *other = Value::String(summarize(&field_text(other)).await?);
+            condensed.push("input".to_string());
+        }
+    }
+
+    let after = estimate_tokens(&input.to_string());
+    if after > limit {
+        return Err(format!(
+            "Input is still ~{after} tokens after summarization, over the {limit}-token input limit. \
+             Move the bulk of it out of the metadata (`_`-prefixed) fields or raise `{INPUT_TOKEN_LIMIT}`."
+        ));
+    }
+    Ok((input, condensed))
+}
+
+fn field_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Map-reduce `text` down to a single condensed summary.
+async fn summarize(text: &str) -> Result<String, String> {
+    let original_tokens = estimate_tokens(text);
+    let mut current = text.to_string();
+    for _ in 0..MAX_REDUCE_ROUNDS {
+        let chunks = split_chunks(&current, CHUNK_CHARS);
+        if chunks.len() > MAX_CHUNKS {
+            return Err(format!(
+                "Input is too large to summarize (~{original_tokens} tokens, more than {MAX_CHUNKS} chunks). \
+                 Split it across several runs."
+            ));
+        }
+        if chunks.len() == 1 {
+            current = oneshot(&reduce_prompt(&current)).await?;
+            break;
+        }
+        let total = chunks.len();
+        let prompts: Vec<String> = chunks
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| map_prompt(chunk, i + 1, total))
+            .collect();
+        let parts: Vec<Result<String, String>> = stream::iter(prompts)
+            .map(|prompt| async move { oneshot(&prompt).await })
+            .buffered(MAP_CONCURRENCY)
+            .collect()
+            .await;
+        current = parts
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n\n");
+    }
+    Ok(format!(
+        "[Summarized from ~{original_tokens} tokens of input]\n{}",
+        current.trim()
+    ))
+}
+
+async fn oneshot(prompt: &str) -> Result<String, String> {
+    super::memory_reflection::run_claude_oneshot(prompt)
+        .await
+        .map_err(|e| format!("Input summarization failed: {e}"))
+}
+
+fn map_prompt(chunk: &str, index: usize, total: usize) -> String {
+    format!(
+        "You are condensing part {index} of {total} of a large input so an agent can work from a \
+         shorter version. Summarize this part faithfully: keep every fact, figure, name, date, \
+         identifier and instruction that could matter, and drop repetition and filler. Reply \
+         with the condensed text only.\n\n--- PART {index}/{total} ---\n{chunk}"
+    )
+}
+
+fn reduce_prompt(text: &str) -> String {
+    format!(
+        "Condense the following input so an agent can work from a shorter version. Keep every \
+         fact, figure, name, date, identifier and instruction that could matter, merge \
+         duplicates, and drop filler. Reply with the condensed text only.\n\n--- INPUT ---\n{text}"
+    )
+}
+
+/// Split `text` into chunks of at most `max_chars` bytes, preferring a
+/// paragraph, line or word boundary in the back half of each chunk.
+fn split_chunks(text: &str, max_chars: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while rest.len() > max_chars {
+        let mut cut = max_chars;
+        while !rest.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let window = &rest[..cut];
+        let min = cut / 2;
+        let boundary = window
+            .rfind("\n\n")
+            .filter(|&i| i >= min)
+            .map(|i| i + 2)
+            .or_else(|| window.rfind('\n').filter(|&i| i >= min).map(|i| i + 1))
+            .or_else(|| window.rfind(' ').filter(|&i| i >= min).map(|i| i + 1))
+            .unwrap_or(cut);
+        chunks.push(&rest[..boundary]);
+        rest = &rest[boundary..];
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    #[test]
+    fn chunks_cover_the_text_and_respect_the_limit() {
+        let text = "alpha beta gamma.\n\n".repeat(500) + &"é".repeat(300);
+        let chunks = split_chunks(&text, 1_000);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.len() <= 1_000 && !c.is_empty()));
+        assert_eq!(chunks.concat(), text);
+        // Paragraph boundaries are preferred over mid-sentence cuts.
+        assert!(chunks[0].ends_with("\n\n"));
+        assert_eq!(split_chunks("short", 1_000), vec!["short"]);
+    }
+
+    #[tokio::test]
+    async fn oversized_input_is_rejected_unless_chunking_is_on() {
+        let pool = init_test_db().unwrap();
+        settings::set(&pool, INPUT_TOKEN_LIMIT, "10").unwrap();
+
+        let small = serde_json::json!({ "user_input": "hi" });
+        let prepared = prepare(&pool, "p1", Some(small.clone())).await.unwrap();
+        assert_eq!(prepared.input, Some(small));
+        assert!(prepared.note.is_none());
+
+        let big = serde_json::json!({ "user_input": "word ".repeat(200) });
+        let err = prepare(&pool, "p1", Some(big)).await.err().unwrap();
+        assert!(err.contains("input limit"), "{err}");
+
+        assert_eq!(overflow_mode(&pool, "p1"), OverflowMode::Reject);
+        settings::set(&pool, &format!("{INPUT_OVERFLOW_PREFIX}p1"), "chunk").unwrap();
+        assert_eq!(overflow_mode(&pool, "p1"), OverflowMode::Chunk);
+
+        settings::set(&pool, INPUT_TOKEN_LIMIT, "0").unwrap();
+        let huge = serde_json::json!({ "user_input": "word ".repeat(100_000) });
+        assert!(prepare(&pool, "p1", Some(huge))
+            .await
+            .unwrap()
+            .note
+            .is_none());
+    }
+}
@@ -0,0 +1,227 @@
+//! Registry of the background loops, for the jobs dashboard.
+//!
+//! Every [`ReactiveSubscription`](super::subscription::ReactiveSubscription)
+//! reports into it from `run_single`: when a tick starts, how long it took,
+//! the next scheduled run and the last error (a tick panic -- ticks log
+//! their own recoverable errors). It also holds the per-job controls behind
+//! the dashboard: `run_now` wakes the loop for an immediate extra tick and
+//! `pause` makes it skip its scheduled ticks until resumed.
+//!
+//! Pauses live in memory and reset when the app restarts, so a job paused
+//! while debugging can't stay off unnoticed.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::error::AppError;
+
+/// Dashboard row for one background loop.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct BackgroundJobStatus {
+    pub name: String,
+    /// Current poll interval (the idle interval while the app is idle).
+    #[ts(type = "number")]
+    pub interval_ms: u64,
+    pub paused: bool,
+    /// True while a tick is in flight.
+    pub running: bool,
+    /// ISO 8601 timestamp of the last tick start.
+    pub last_run_at: Option<String>,
+    #[ts(type = "number | null")]
+    pub last_duration_ms: Option<u64>,
+    /// ISO 8601 estimate of the next scheduled tick.
+    pub next_run_at: Option<String>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<String>,
+    #[ts(type = "number")]
+    pub run_count: u64,
+    #[ts(type = "number")]
+    pub error_count: u64,
+}
+
+/// Controls a loop checks between ticks.
+#[derive(Default)]
+pub struct JobControl {
+    paused: AtomicBool,
+    run_now: tokio::sync::Notify,
+}
+
+impl JobControl {
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Resolves when the dashboard asks for an immediate run. A request made
+    /// mid-tick is kept and runs right after the current tick.
+    pub async fn run_now_requested(&self) {
+        self.run_now.notified().await
+    }
+}
+
+struct JobEntry {
+    job: BackgroundJobStatus,
+    control: Arc<JobControl>,
+}
+
+#[derive(Default)]
+pub struct JobRegistry {
+    jobs: Mutex<HashMap<String, JobEntry>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_job<T>(&self, name: &str, f: impl FnOnce(&mut JobEntry) -> T) -> Option<T> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        jobs.get_mut(name).map(f)
+    }
+
+    /// Register a loop as it starts and return its controls. A loop that is
+    /// restarted (scheduler stop/start) keeps its history and pause state.
+    pub fn register(
+        &self,
+        name: &str,
+        interval: Duration,
+        first_run_in: Duration,
+    ) -> Arc<JobControl> {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = jobs.entry(name.to_string()).or_insert_with(|| JobEntry {
+            job: BackgroundJobStatus {
+                name: name.to_string(),
+                interval_ms: 0,
+                paused: false,
+                running: false,
+                last_run_at: None,
+                last_duration_ms: None,
+                next_run_at: None,
+                last_error: None,
+                last_error_at: None,
+                run_count: 0,
+                error_count: 0,
+            },
+            control: Arc::new(JobControl::default()),
+        });
+        entry.job.interval_ms = interval.as_millis() as u64;
+        entry.job.running = false;
+        entry.job.next_run_at = Some(after(first_run_in));
+        entry.control.clone()
+    }
+
+    pub fn record_started(&self, name: &str) {
+        self.with_job(name, |entry| {
+            entry.job.running = true;
+            entry.job.last_run_at = Some(chrono::Utc::now().to_rfc3339());
+        });
+    }
+
+    /// Record a finished tick. `error` is set when the tick panicked;
+    /// `next_run_in` is the wait before the next scheduled tick.
+    pub fn record_finished(
+        &self,
+        name: &str,
+        elapsed: Duration,
+        error: Option<String>,
+        interval: Duration,
+        next_run_in: Duration,
+    ) {
+        self.with_job(name, |entry| {
+            let job = &mut entry.job;
+            job.running = false;
+            job.run_count += 1;
+            job.last_duration_ms = Some(elapsed.as_millis() as u64);
+            job.interval_ms = interval.as_millis() as u64;
+            job.next_run_at = Some(after(next_run_in));
+            if let Some(error) = error {
+                job.error_count += 1;
+                job.last_error = Some(error);
+                job.last_error_at = job.last_run_at.clone();
+            }
+        });
+    }
+
+    /// Clear the next-run estimate once a loop exits (scheduler stopped).
+    pub fn record_stopped(&self, name: &str) {
+        self.with_job(name, |entry| {
+            entry.job.running = false;
+            entry.job.next_run_at = None;
+        });
+    }
+
+    /// All registered jobs, sorted by name.
+    pub fn list(&self) -> Vec<BackgroundJobStatus> {
+        let jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        let mut list: Vec<BackgroundJobStatus> = jobs.values().map(|e| e.job.clone()).collect();
+        list.sort_by(|a, b| a.name.cmp(&b.name));
+        list
+    }
+
+    /// Ask the loop to tick now. Also runs a paused job, once.
+    pub fn run_now(&self, name: &str) -> Result<(), AppError> {
+        self.with_job(name, |entry| entry.control.run_now.notify_one())
+            .ok_or_else(|| AppError::NotFound(format!("Background job {name}")))
+    }
+
+    pub fn set_paused(&self, name: &str, paused: bool) -> Result<BackgroundJobStatus, AppError> {
+        let job = self
+            .with_job(name, |entry| {
+                entry.control.paused.store(paused, Ordering::Relaxed);
+                entry.job.paused = paused;
+                entry.job.clone()
+            })
+            .ok_or_else(|| AppError::NotFound(format!("Background job {name}")))?;
+        tracing::info!(job = name, paused, "Background job pause state changed");
+        Ok(job)
+    }
+}
+
+fn after(wait: Duration) -> String {
+    let wait = chrono::Duration::from_std(wait).unwrap_or(chrono::Duration::zero());
+    (chrono::Utc::now() + wait).to_rfc3339()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn registry_tracks_runs_and_controls() {
+        let registry = JobRegistry::new();
+        let control = registry.register("cleanup", Duration::from_secs(60), Duration::ZERO);
+        assert!(registry.run_now("missing").is_err());
+
+        registry.record_started("cleanup");
+        assert!(registry.list()[0].running);
+        registry.record_finished(
+            "cleanup",
+            Duration::from_millis(120),
+            Some("boom".into()),
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+        );
+        let job = &registry.list()[0];
+        assert!(!job.running);
+        assert_eq!((job.run_count, job.error_count), (1, 1));
+        assert_eq!(job.last_duration_ms, Some(120));
+        assert_eq!(job.last_error.as_deref(), Some("boom"));
+
+        assert!(registry.set_paused("cleanup", true).unwrap().paused);
+        assert!(control.is_paused());
+        // Re-registering after a scheduler restart keeps the pause.
+        let control = registry.register("cleanup", Duration::from_secs(60), Duration::ZERO);
+        assert!(control.is_paused());
+
+        registry.run_now("cleanup").unwrap();
+        tokio::time::timeout(Duration::from_secs(1), control.run_now_requested())
+            .await
+            .expect("run_now should wake the loop");
+    }
+}
@@ -0,0 +1,276 @@
+//! Execution log files after the run: compression and bounded reads.
+//!
+//! A log lives as `logs/<execution_id>.log` while its execution runs, rotated
+//! by [`ExecutionLogger`] into `<execution_id>.log.<n>` segments once it grows
+//! past [`logger::ROTATE_BYTES`]. When the run is over and the files have sat
+//! idle for [`COMPRESS_IDLE`], the retention pass folds every part into one
+//! gzip file, `<execution_id>.log.gz`.
+//!
+//! `log_file_path` on the execution row keeps pointing at the `.log` path;
+//! [`open`] resolves it to whichever parts exist and reads them as one
+//! stream, so callers never care whether a log was rotated or compressed.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::logger::{self, ExecutionLogger};
+use crate::error::AppError;
+use crate::validation::open_log_file_safely;
+
+/// Suffix appended to the `.log` path of a compressed log.
+const GZ_SUFFIX: &str = ".gz";
+/// Logs untouched for this long (and whose execution is no longer active)
+/// are compressed.
+const COMPRESS_IDLE: Duration = Duration::from_secs(10 * 60);
+/// Default and largest page returned by [`read_range`].
+pub const DEFAULT_RANGE_BYTES: u64 = 64 * 1024;
+pub const MAX_RANGE_BYTES: u64 = 1024 * 1024;
+
+/// One page of an execution log.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ExecutionLogChunk {
+    pub content: String,
+    /// Byte offset of `content` in the (uncompressed) log.
+    #[ts(type = "number")]
+    pub offset: u64,
+    /// Offset to request for the next page.
+    #[ts(type = "number")]
+    pub next_offset: u64,
+    /// `true` when `content` runs to the end of the log.
+    pub eof: bool,
+}
+
+fn gz_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(GZ_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// The on-disk parts of the log at `path`, oldest first: an earlier
+/// compressed archive, rotated segments, then the live file.
+fn parts(path: &Path) -> Vec<PathBuf> {
+    let mut parts = vec![gz_path(path)];
+    parts.extend(
+        (1..=logger::MAX_SEGMENTS)
+            .rev()
+            .map(|n| ExecutionLogger::segment_path(path, n)),
+    );
+    parts.push(path.to_path_buf());
+    parts.retain(|p| p.is_file());
+    parts
+}
+
+fn decoded(part: &Path, file: File) -> Box<dyn Read + Send> {
+    if part.as_os_str().to_string_lossy().ends_with(GZ_SUFFIX) {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    }
+}
+
+/// Open the execution log whose row stores `raw_path`, validating every part
+/// against `log_dir`, as one decompressed stream.
+pub fn open(raw_path: &str, log_dir: &Path) -> Result<Box<dyn BufRead + Send>, AppError> {
+    let parts = parts(Path::new(raw_path));
+    if parts.is_empty() {
+        return Err(AppError::NotFound("Log file not found".into()));
+    }
+    let mut reader: Box<dyn Read + Send> = Box::new(io::empty());
+    for part in parts {
+        let file = open_log_file_safely(&part.to_string_lossy(), log_dir)?;
+        reader = Box::new(reader.chain(decoded(&part, file)));
+    }
+    Ok(Box::new(BufReader::new(reader)))
+}
+
+/// Read up to `limit` bytes starting at `offset`. A page ends on a line
+/// boundary unless a single line is longer than `limit`. Compressed logs are
+/// decompressed up to `offset`, so later pages cost more than earlier ones.
+pub fn read_range(
+    reader: &mut dyn BufRead,
+    offset: u64,
+    limit: u64,
+) -> io::Result<ExecutionLogChunk> {
+    let limit = limit.clamp(1, MAX_RANGE_BYTES);
+    let skipped = io::copy(&mut (&mut *reader).take(offset), &mut io::sink())?;
+    let mut buf = Vec::new();
+    if skipped == offset {
+        (&mut *reader).take(limit).read_to_end(&mut buf)?;
+    }
+    let eof = reader.fill_buf()?.is_empty();
+    if !eof {
+        if let Some(pos) = buf.iter().rposition(|b| *b == b'\n') {
+            buf.truncate(pos + 1);
+        } else if let Err(e) = std::str::from_utf8(&buf) {
+            // Don't split a character across pages.
+            if e.error_len().is_none() {
+                buf.truncate(e.valid_up_to());
+            }
+        }
+    }
+    Ok(ExecutionLogChunk {
+        content: String::from_utf8_lossy(&buf).into_owned(),
+        offset: skipped,
+        next_offset: skipped + buf.len() as u64,
+        eof,
+    })
+}
+
+/// Whether `ext` (a file name after its execution id) names a log part:
+/// `.log`, `.log.gz` or a rotated `.log.<n>`.
+pub fn is_log_part(ext: &str) -> bool {
+    match ext.strip_prefix(".log") {
+        Some("") => true,
+        Some(rest) => {
+            rest == GZ_SUFFIX
+                || rest
+                    .strip_prefix('.')
+                    .is_some_and(|n| n.parse::<u32>().is_ok())
+        }
+        None => false,
+    }
+}
+
+/// Compress the uncompressed logs in `log_dir` of executions that fail
+/// `is_active` and have been idle for [`COMPRESS_IDLE`]. Returns
+/// `(logs, bytes saved)`.
+pub fn compress_completed_logs(log_dir: &Path, is_active: impl Fn(&str) -> bool) -> (u64, u64) {
+    let Ok(entries) = fs::read_dir(log_dir) else {
+        return (0, 0);
+    };
+    let now = SystemTime::now();
+    // Newest modification time across each execution's uncompressed parts.
+    let mut candidates: std::collections::HashMap<String, SystemTime> = Default::default();
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let Some((id, ext)) = name
+            .to_str()
+            .and_then(|n| n.find('.').map(|i| n.split_at(i)))
+        else {
+            continue;
+        };
+        if ext.ends_with(GZ_SUFFIX) || !is_log_part(ext) || uuid::Uuid::parse_str(id).is_err() {
+            continue;
+        }
+        let Some(modified) = entry.metadata().ok().and_then(|m| m.modified().ok()) else {
+            continue;
+        };
+        let newest = candidates.entry(id.to_string()).or_insert(modified);
+        *newest = (*newest).max(modified);
+    }
+
+    let (mut logs, mut saved) = (0u64, 0u64);
+    for (id, modified) in candidates {
+        let idle = now.duration_since(modified).unwrap_or_default();
+        if idle < COMPRESS_IDLE || is_active(&id) {
+            continue;
+        }
+        match compress(&ExecutionLogger::log_path(log_dir, &id)) {
+            Ok(bytes) => {
+                logs += 1;
+                saved += bytes;
+            }
+            Err(e) => tracing::warn!(execution_id = %id, error = %e, "Log compression failed"),
+        }
+    }
+    (logs, saved)
+}
+
+/// Fold every part of the log at `path` into `<path>.gz` and remove the
+/// rest. Returns the bytes saved.
+fn compress(path: &Path) -> io::Result<u64> {
+    let parts = parts(path);
+    let before: u64 = parts
+        .iter()
+        .filter_map(|p| fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum();
+    let gz = gz_path(path);
+    // Write-then-rename so a reader never sees a half-written archive.
+    let mut tmp = gz.clone().into_os_string();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    let mut encoder = GzEncoder::new(File::create(&tmp)?, Compression::default());
+    for part in &parts {
+        io::copy(&mut decoded(part, File::open(part)?), &mut encoder)?;
+    }
+    encoder.finish()?.sync_all()?;
+    fs::rename(&tmp, &gz)?;
+    for part in parts.iter().filter(|p| **p != gz) {
+        let _ = fs::remove_file(part);
+    }
+    Ok(before.saturating_sub(fs::metadata(&gz)?.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotated_logs_compress_and_read_back_as_one_stream() {
+        let dir = tempfile::tempdir().unwrap();
+        let id = uuid::Uuid::new_v4().to_string();
+        let path = ExecutionLogger::log_path(dir.path(), &id);
+        fs::write(ExecutionLogger::segment_path(&path, 2), "one\n").unwrap();
+        fs::write(ExecutionLogger::segment_path(&path, 1), "two\n").unwrap();
+        fs::write(&path, "three\nfour\n".repeat(200)).unwrap();
+        let full = format!("one\ntwo\n{}", "three\nfour\n".repeat(200));
+
+        let raw = path.to_string_lossy().to_string();
+        let mut text = String::new();
+        open(&raw, dir.path())
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, full);
+
+        // Still active, then too fresh: left alone.
+        assert_eq!(compress_completed_logs(dir.path(), |_| true), (0, 0));
+        assert_eq!(compress_completed_logs(dir.path(), |_| false), (0, 0));
+
+        assert!(compress(&path).unwrap() > 0);
+        assert!(!path.exists());
+        assert!(!ExecutionLogger::segment_path(&path, 1).exists());
+        let mut text = String::new();
+        open(&raw, dir.path())
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, full);
+    }
+
+    #[test]
+    fn ranges_page_on_line_boundaries() {
+        let log = "alpha\nbeta\ngamma\n";
+        let mut reader = io::Cursor::new(log);
+        let first = read_range(&mut reader, 0, 8).unwrap();
+        assert_eq!(first.content, "alpha\n");
+        assert!(!first.eof);
+
+        let mut reader = io::Cursor::new(log);
+        let second = read_range(&mut reader, first.next_offset, 100).unwrap();
+        assert_eq!(second.content, "beta\ngamma\n");
+        assert!(second.eof);
+
+        let mut reader = io::Cursor::new(log);
+        let past = read_range(&mut reader, 1_000, 10).unwrap();
+        assert_eq!((past.content.as_str(), past.eof), ("", true));
+
+        // A line longer than the page splits on a character boundary.
+        let mut reader = io::Cursor::new("ééé\n");
+        let page = read_range(&mut reader, 0, 3).unwrap();
+        assert_eq!((page.content.as_str(), page.next_offset), ("é", 2));
+
+        assert!(is_log_part(".log") && is_log_part(".log.gz") && is_log_part(".log.3"));
+        assert!(!is_log_part(".output.gz") && !is_log_part(".log.x") && !is_log_part(".txt"));
+    }
+}
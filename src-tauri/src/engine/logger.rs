@@ -1,12 +1,21 @@
 use std::fs::{self, OpenOptions};
 use std::io::{BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// A live log is rotated into a numbered segment once it grows past this.
+pub const ROTATE_BYTES: u64 = 8 * 1024 * 1024;
+/// Rotated segments kept per execution (`<id>.log.1` newest); the oldest is
+/// dropped beyond this, bounding a runaway log at roughly
+/// `ROTATE_BYTES * (MAX_SEGMENTS + 1)`.
+pub const MAX_SEGMENTS: u32 = 4;
 
 pub struct ExecutionLogger {
     writer: Option<BufWriter<std::fs::File>>,
     path: PathBuf,
     /// Set to `true` after the first I/O error so callers know the log may be incomplete.
     write_failed: bool,
+    /// Bytes in the live file, for size-based rotation.
+    written: u64,
 }
 
 impl ExecutionLogger {
@@ -19,29 +28,79 @@ impl ExecutionLogger {
         log_dir.join(format!("{execution_id}.log"))
     }
 
+    /// Path of rotated segment `n` (1 = newest) of the log at `path`.
+    pub fn segment_path(path: &Path, n: u32) -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".{n}"));
+        PathBuf::from(name)
+    }
+
     pub fn new(log_dir: &std::path::Path, execution_id: &str) -> std::io::Result<Self> {
         fs::create_dir_all(log_dir)?;
         let path = Self::log_path(log_dir, execution_id);
         let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
         Ok(Self {
             writer: Some(BufWriter::new(file)),
             path,
             write_failed: false,
+            written,
         })
     }
 
     pub fn log(&mut self, msg: &str) {
         if let Some(ref mut w) = self.writer {
             let timestamp = chrono::Utc::now().to_rfc3339();
-            if let Err(e) = writeln!(w, "[{timestamp}] {msg}") {
+            let line = format!("[{timestamp}] {msg}\n");
+            if let Err(e) = w.write_all(line.as_bytes()) {
                 if !self.write_failed {
                     self.write_failed = true;
                     eprintln!("[ExecutionLogger] write error (log may be truncated): {e}");
                 }
+                return;
+            }
+            self.written += line.len() as u64;
+        }
+        if self.written >= ROTATE_BYTES {
+            if let Err(e) = self.rotate() {
+                if !self.write_failed {
+                    self.write_failed = true;
+                    eprintln!("[ExecutionLogger] rotation error (log may be truncated): {e}");
+                }
             }
         }
     }
 
+    /// Move the live file to segment 1 (shifting older segments up and
+    /// dropping the oldest) and continue in a fresh file.
+    fn rotate(&mut self) -> std::io::Result<()> {
+        if let Some(w) = self.writer.take() {
+            w.into_inner().map_err(|e| e.into_error())?.flush()?;
+        }
+        let oldest = Self::segment_path(&self.path, MAX_SEGMENTS);
+        let dropped = oldest.exists();
+        if dropped {
+            fs::remove_file(&oldest)?;
+        }
+        for n in (1..MAX_SEGMENTS).rev() {
+            let from = Self::segment_path(&self.path, n);
+            if from.exists() {
+                fs::rename(&from, Self::segment_path(&self.path, n + 1))?;
+            }
+        }
+        fs::rename(&self.path, Self::segment_path(&self.path, 1))?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.writer = Some(BufWriter::new(file));
+        self.written = 0;
+        if dropped {
+            self.log("[LOG] Log rotated; the oldest segment was dropped");
+        }
+        Ok(())
+    }
+
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
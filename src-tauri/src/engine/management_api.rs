@@ -1,5 +1,6 @@
 //! Management API -- extends the webhook HTTP server with /api/* routes
-//! for persona execution, lab operations, and version management.
+//! for persona execution, event publishing, lab operations, and version
+//! management.
 //!
 //! These endpoints allow external tools (MCP servers, CLI scripts, A2A clients)
 //! to control Personas without going through the Tauri IPC layer.
@@ -28,6 +29,8 @@ use tauri::AppHandle;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 
 use crate::db::models::*;
+use crate::db::repos::communication::event_schemas as event_schema_repo;
+use crate::db::repos::communication::events as event_repo;
 use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::execution::executions as exec_repo;
 use crate::db::repos::execution::metrics as metrics_repo;
@@ -44,6 +47,7 @@ use crate::engine::a2a::types::{
     A2AStatusMessage, A2ATask, A2ATaskResponse, A2ATaskStatus, AgentCapabilities, AgentCard,
     AgentSkill, MessageSendParams, TaskIdParams,
 };
+use crate::engine::execution_wait;
 use crate::engine::test_runner::{self, TestModelConfig};
 use crate::engine::types::EphemeralPersona;
 use crate::error::AppError;
@@ -77,6 +81,8 @@ pub fn management_router(state: ManagementState) -> Router {
         .route("/api/execute/{persona_id}", post(execute_persona))
         .route("/api/executions", get(list_executions))
         .route("/api/executions/{id}", get(get_execution))
+        // Events
+        .route("/api/events", post(publish_event))
         // Lab
         .route("/api/lab/arena/{persona_id}", post(start_arena))
         .route("/api/lab/matrix/{persona_id}", post(start_matrix))
@@ -106,6 +112,15 @@ pub fn management_router(state: ManagementState) -> Router {
         )
         // Credential proxy -- route HTTP calls through stored credentials
         .route("/api/proxy/{credential_id}", post(proxy_request))
+        // IMAP/SMTP mail -- the personas-mcp email_* tools forward here so the
+        // mailbox password is decrypted and used only in the main app.
+        .route("/api/email/{credential_id}", post(email_request))
+        // Builtin http_request -- executed here, under the persona's network
+        // policy, instead of by the CLI agent.
+        .route("/api/http-request/{persona_id}", post(http_request))
+        // Builtin sql_query -- run here with native drivers, so the
+        // connection secret stays in the main app.
+        .route("/api/sql-query/{persona_id}", post(sql_query))
         // Local scraper (embedded Pumper) -- the personas-mcp `fetch_readable`
         // tool forwards here so the SSRF-safe fetch runs in the main app where
         // the engine lives (the mcp binary has no engine module).
@@ -256,6 +271,9 @@ mod paired_origin_tests {
 /// implicit — read routes require only a valid key, so no constant is needed.
 const SCOPE_EXECUTE: &str = "personas:execute";
 const SCOPE_BUILD: &str = "personas:build";
+/// Publish onto the event bus without being able to run personas directly.
+/// `personas:execute` also covers it: a published event can trigger runs.
+const SCOPE_EVENTS_PUBLISH: &str = "events:publish";
 /// Broad credential-proxy scope. The proxy injects stored secrets server-side,
 /// so it is gated on its OWN scope (not `personas:execute`) — a paired cloud key
 /// never receives it unless the user grants a specific credential. The internal
@@ -285,8 +303,13 @@ const API_KEY_RATE_WINDOW: Duration = Duration::from_secs(60);
 /// - `/api/proxy/{credential_id}` — requires `proxy` OR
 ///   `proxy:credential:{credential_id}`. NOT `personas:execute`: the proxy
 ///   injects stored secrets, so it is gated on a dedicated scope.
+/// - `/api/email/{credential_id}` — same as the proxy: it logs in with the
+///   stored mailbox credential.
+/// - `/api/http-request/{persona_id}`, `/api/sql-query/{persona_id}` —
+///   require broad `proxy`: a call may name any of the persona's credentials.
 /// - `/api/execute/{persona_id}` — requires `personas:execute` OR
 ///   `personas:execute:persona:{persona_id}`.
+/// - `POST /api/events` — requires `events:publish` OR `personas:execute`.
 /// - all other `/api/*` — read verbs (GET/HEAD) need only authentication;
 ///   mutating verbs (POST/…) require broad `personas:execute`.
 ///
@@ -341,7 +364,10 @@ fn authorize(method: &Method, path: &str, scopes: &[String]) -> Result<(), &'sta
             Err("api key lacks the personas:build scope")
         };
     }
-    if let Some(credential_id) = path.strip_prefix("/api/proxy/") {
+    if let Some(credential_id) = path
+        .strip_prefix("/api/proxy/")
+        .or_else(|| path.strip_prefix("/api/email/"))
+    {
         let specific = format!("{SCOPE_PROXY_CREDENTIAL_PREFIX}{credential_id}");
         return if has(SCOPE_PROXY) || has(&specific) {
             Ok(())
@@ -349,6 +375,13 @@ fn authorize(method: &Method, path: &str, scopes: &[String]) -> Result<(), &'sta
             Err("api key lacks proxy scope for this credential")
         };
     }
+    if path.starts_with("/api/http-request/") || path.starts_with("/api/sql-query/") {
+        return if has(SCOPE_PROXY) {
+            Ok(())
+        } else {
+            Err("api key lacks the proxy scope")
+        };
+    }
     if let Some(persona_id) = path.strip_prefix("/api/execute/") {
         let specific = format!("{SCOPE_EXECUTE_PERSONA_PREFIX}{persona_id}");
         return if has(SCOPE_EXECUTE) || has(&specific) {
@@ -357,6 +390,13 @@ fn authorize(method: &Method, path: &str, scopes: &[String]) -> Result<(), &'sta
             Err("api key lacks execute scope for this persona")
         };
     }
+    if path == "/api/events" && *method == Method::POST {
+        return if has(SCOPE_EVENTS_PUBLISH) || has(SCOPE_EXECUTE) {
+            Ok(())
+        } else {
+            Err("api key lacks the events:publish scope")
+        };
+    }
     if path.starts_with("/api/") {
         return match *method {
             Method::GET | Method::HEAD | Method::OPTIONS => Ok(()),
@@ -575,6 +615,13 @@ struct ExecuteInput {
     input_data: Option<serde_json::Value>,
 }
 
+/// `?wait=<secs>` on `/api/execute/{persona_id}`: hold the request until the
+/// run finishes (capped at `execution_wait::MAX_WAIT_SECS`).
+#[derive(Deserialize)]
+struct ExecuteQuery {
+    wait: Option<i64>,
+}
+
 #[derive(Deserialize)]
 struct LabStartInput {
     models: Vec<TestModelConfig>,
@@ -678,6 +725,77 @@ async fn proxy_request(
     }
 }
 
+/// `POST /api/email/{credential_id}` — run one IMAP/SMTP operation
+/// ([`crate::engine::email::EmailOp`]) with a stored `imap_smtp` credential.
+async fn email_request(
+    AxumState(state): AxumState<Arc<ManagementState>>,
+    Path(credential_id): Path<String>,
+    Json(op): Json<crate::engine::email::EmailOp>,
+) -> impl IntoResponse {
+    match crate::engine::email::execute(&state.pool, &credential_id, op).await {
+        Ok(result) => ok_json(result).into_response(),
+        Err(e) => {
+            let status = match e {
+                AppError::Validation(_) => StatusCode::BAD_REQUEST,
+                AppError::NotFound(_) => StatusCode::NOT_FOUND,
+                _ => StatusCode::BAD_GATEWAY,
+            };
+            err_json(status, &format!("{e}")).into_response()
+        }
+    }
+}
+
+/// `POST /api/http-request/{persona_id}` — run one builtin `http_request`
+/// call ([`crate::engine::http_tool`]) for the persona.
+async fn http_request(
+    AxumState(state): AxumState<Arc<ManagementState>>,
+    Path(persona_id): Path<String>,
+    Json(request): Json<crate::engine::http_tool::HttpToolRequest>,
+) -> impl IntoResponse {
+    match crate::engine::http_tool::execute(&state.pool, &state.rate_limiter, &persona_id, request)
+        .await
+    {
+        Ok(response) => ok_json(response).into_response(),
+        Err(e) => {
+            let status = match e {
+                AppError::Validation(_) => StatusCode::BAD_REQUEST,
+                AppError::NotFound(_) => StatusCode::NOT_FOUND,
+                AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+                AppError::RateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+                _ => StatusCode::BAD_GATEWAY,
+            };
+            err_json(status, &format!("{e}")).into_response()
+        }
+    }
+}
+
+/// `POST /api/sql-query/{persona_id}` — run one builtin `sql_query` call
+/// ([`crate::engine::sql_tool`]) for the persona.
+async fn sql_query(
+    AxumState(state): AxumState<Arc<ManagementState>>,
+    Path(persona_id): Path<String>,
+    Json(request): Json<crate::engine::sql_tool::SqlToolRequest>,
+) -> impl IntoResponse {
+    let user_db = state
+        .app
+        .try_state::<Arc<crate::AppState>>()
+        .map(|s| s.user_db.clone());
+    match crate::engine::sql_tool::execute(&state.pool, user_db.as_ref(), &persona_id, request)
+        .await
+    {
+        Ok(result) => ok_json(result).into_response(),
+        Err(e) => {
+            let status = match e {
+                AppError::Validation(_) => StatusCode::BAD_REQUEST,
+                AppError::NotFound(_) => StatusCode::NOT_FOUND,
+                AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+                _ => StatusCode::BAD_GATEWAY,
+            };
+            err_json(status, &format!("{e}")).into_response()
+        }
+    }
+}
+
 // =============================================================================
 // Persona endpoints
 // =============================================================================
@@ -728,43 +846,63 @@ async fn get_persona(
 async fn execute_persona(
     AxumState(state): AxumState<Arc<ManagementState>>,
     Path(persona_id): Path<String>,
+    Query(query): Query<ExecuteQuery>,
     Json(input): Json<ExecuteInput>,
 ) -> impl IntoResponse {
-    let persona = match persona_repo::get_by_id(&state.pool, &persona_id) {
-        Ok(p) => p,
-        Err(_) => return err_json(StatusCode::NOT_FOUND, "Persona not found").into_response(),
+    let execution_id = match start_persona_execution(&state, &persona_id, input.input_data).await {
+        Ok(id) => id,
+        Err((status, msg)) => return err_json(status, &msg).into_response(),
     };
+    if query.wait.is_none() {
+        return ok_json(serde_json::json!({
+            "execution_id": execution_id,
+            "status": "queued",
+        }))
+        .into_response();
+    }
+
+    let wait = execution_wait::effective_wait(query.wait, 0, execution_wait::MAX_WAIT_SECS);
+    match execution_wait::wait_for_terminal(&state.pool, &execution_id, wait).await {
+        Ok(result) => ok_json(result).into_response(),
+        Err(e) => err_json(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()).into_response(),
+    }
+}
+
+/// Create an execution row for `persona_id` and hand it to the engine. Shared
+/// by `/api/execute` and the token-authenticated `/hooks/execute` route.
+/// Returns the execution id, or the HTTP status and message to answer with.
+pub(crate) async fn start_persona_execution(
+    state: &ManagementState,
+    persona_id: &str,
+    input_data: Option<serde_json::Value>,
+) -> Result<String, (StatusCode, String)> {
+    let persona = persona_repo::get_by_id(&state.pool, persona_id)
+        .map_err(|_| (StatusCode::NOT_FOUND, "Persona not found".to_string()))?;
 
     if !persona.enabled {
-        return err_json(StatusCode::BAD_REQUEST, "Persona is disabled").into_response();
+        return Err((StatusCode::BAD_REQUEST, "Persona is disabled".into()));
     }
 
     // Create execution record
-    let input_str = input.input_data.as_ref().map(|v| v.to_string());
-    let execution = match exec_repo::create(&state.pool, &persona_id, None, input_str, None, None) {
-        Ok(e) => e,
-        Err(e) => {
-            return err_json(
+    let input_str = input_data.as_ref().map(|v| v.to_string());
+    let execution = exec_repo::create(&state.pool, persona_id, None, input_str, None, None)
+        .map_err(|e| {
+            (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                &format!("Failed to create execution: {e}"),
+                format!("Failed to create execution: {e}"),
             )
-            .into_response()
-        }
-    };
+        })?;
 
     // Get tools
-    let tools = tool_repo::get_tools_for_persona(&state.pool, &persona_id).unwrap_or_default();
+    let tools = tool_repo::get_tools_for_persona(&state.pool, persona_id).unwrap_or_default();
 
     // Start via engine
-    let app_state: tauri::State<'_, Arc<crate::AppState>> = match state.app.try_state() {
-        Some(s) => s,
-        None => {
-            return err_json(StatusCode::INTERNAL_SERVER_ERROR, "App state not available")
-                .into_response()
-        }
-    };
+    let app_state: tauri::State<'_, Arc<crate::AppState>> = state.app.try_state().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "App state not available".to_string(),
+    ))?;
 
-    match app_state
+    app_state
         .engine
         .start_execution(
             state.app.clone(),
@@ -772,18 +910,12 @@ async fn execute_persona(
             execution.id.clone(),
             persona,
             tools,
-            input.input_data,
+            input_data,
             None,
         )
         .await
-    {
-        Ok(()) => ok_json(serde_json::json!({
-            "execution_id": execution.id,
-            "status": "queued",
-        }))
-        .into_response(),
-        Err(e) => err_json(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()).into_response(),
-    }
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    Ok(execution.id)
 }
 
 async fn list_executions(
@@ -811,6 +943,56 @@ async fn get_execution(
     }
 }
 
+// =============================================================================
+// Event endpoints
+// =============================================================================
+
+#[derive(Deserialize)]
+struct PublishEventBody {
+    event_type: String,
+    #[serde(default)]
+    payload: Option<serde_json::Value>,
+    #[serde(default)]
+    target_persona_id: Option<String>,
+    #[serde(default)]
+    project_id: Option<String>,
+    #[serde(default)]
+    use_case_id: Option<String>,
+    #[serde(default)]
+    source_id: Option<String>,
+}
+
+/// `POST /api/events` — publish an event as the `publish_event` command does,
+/// with `source_type = "local_api"`. A registered payload schema is enforced.
+async fn publish_event(
+    AxumState(state): AxumState<Arc<ManagementState>>,
+    Json(body): Json<PublishEventBody>,
+) -> impl IntoResponse {
+    crate::engine::event_vocabulary::validate_and_warn(&body.event_type);
+    let payload = body.payload.map(|v| v.to_string());
+    if let Err(e) =
+        event_schema_repo::validate_payload(&state.pool, &body.event_type, payload.as_deref())
+    {
+        return err_json(StatusCode::BAD_REQUEST, &e.to_string()).into_response();
+    }
+    let input = CreatePersonaEventInput {
+        event_type: body.event_type,
+        source_type: "local_api".into(),
+        project_id: body.project_id,
+        source_id: body.source_id,
+        target_persona_id: body.target_persona_id,
+        payload,
+        use_case_id: body.use_case_id,
+    };
+    match event_repo::publish(&state.pool, input) {
+        Ok(event) => ok_json(event).into_response(),
+        Err(e @ AppError::Validation(_)) => {
+            err_json(StatusCode::BAD_REQUEST, &e.to_string()).into_response()
+        }
+        Err(e) => err_json(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()).into_response(),
+    }
+}
+
 // =============================================================================
 // Lab endpoints
 // =============================================================================
@@ -1873,8 +2055,8 @@ fn build_a2a_task(row: &PersonaExecution, persona_id: &str) -> A2ATask {
 
 /// Execute a persona synchronously and return its final text output.
 ///
-/// The existing `/api/execute` handler is fire-and-forget — it returns an
-/// execution ID immediately. For A2A we need to block until completion, so
+/// Without `?wait`, the `/api/execute` handler is fire-and-forget — it returns
+/// an execution ID immediately. For A2A we need to block until completion, so
 /// we kick off the same engine call and then poll the executions table for
 /// terminal status.
 async fn run_persona_synchronous(
@@ -2311,6 +2493,34 @@ mod tests {
         // Per-credential grant works only for the matching credential.
         assert!(authorize(&Method::POST, "/api/proxy/cred-1", &scopes(&["proxy:credential:cred-1"])).is_ok());
         assert!(authorize(&Method::POST, "/api/proxy/cred-2", &scopes(&["proxy:credential:cred-1"])).is_err());
+        // The email bridge uses the stored credential the same way.
+        assert!(authorize(&Method::POST, "/api/email/cred-1", &scopes(&["personas:execute"])).is_err());
+        assert!(authorize(&Method::POST, "/api/email/cred-1", &scopes(&["proxy:credential:cred-1"])).is_ok());
+        // http_request can name any credential, so only broad proxy reaches it.
+        assert!(authorize(
+            &Method::POST,
+            "/api/http-request/p1",
+            &scopes(&["proxy:credential:cred-1"])
+        )
+        .is_err());
+        assert!(authorize(&Method::POST, "/api/http-request/p1", &scopes(&["proxy"])).is_ok());
+        assert!(authorize(
+            &Method::POST,
+            "/api/sql-query/p1",
+            &scopes(&["personas:execute"])
+        )
+        .is_err());
+        assert!(authorize(&Method::POST, "/api/sql-query/p1", &scopes(&["proxy"])).is_ok());
+    }
+
+    #[test]
+    fn authorize_event_publish_needs_publish_or_execute() {
+        assert!(authorize(&Method::POST, "/api/events", &[]).is_err());
+        assert!(authorize(&Method::POST, "/api/events", &scopes(&["personas:read"])).is_err());
+        assert!(authorize(&Method::POST, "/api/events", &scopes(&["events:publish"])).is_ok());
+        assert!(authorize(&Method::POST, "/api/events", &scopes(&["personas:execute"])).is_ok());
+        // The publish scope does not reach other mutating routes.
+        assert!(authorize(&Method::POST, "/api/versions/v1/tag", &scopes(&["events:publish"])).is_err());
     }
 
     #[test]
@@ -2388,6 +2598,8 @@ mod tests {
             setup_status: "ready".to_string(),
             setup_detail: None,
             disabled_dims_json: None,
+            engine: None,
+            execution_placement: None,
             created_at: now.clone(),
             updated_at: now,
         };
@@ -2439,6 +2651,8 @@ mod tests {
             setup_status: "ready".to_string(),
             setup_detail: None,
             disabled_dims_json: None,
+            engine: None,
+            execution_placement: None,
             created_at: now.clone(),
             updated_at: now,
         };
@@ -2507,6 +2721,9 @@ mod tests {
             business_outcome: "unknown".to_string(),
             director_score: None,
             director_review_md: None,
+            feature_flags: None,
+            output_ref: None,
+            firing_id: None,
         }
     }
 
@@ -0,0 +1,521 @@
+//! MCP server lifecycle — install, start, health-check and stop the stdio MCP
+//! servers that connector metadata describes.
+//!
+//! A connector advertises its server either as an `auth_methods[]` entry of
+//! `type: "mcp"` (`package`, `transport`, `suggested_env`) or as a top-level
+//! `mcp_package`. Only stdio servers are managed here; hosted (SSE /
+//! streamable-http) servers need no local process.
+//!
+//! - **install** runs `npm install --prefix <data>/mcp_servers/<connector>`,
+//!   so later starts resolve the package locally instead of fetching it.
+//! - **start** spawns `npx -y <package>` in that directory through
+//!   [`mcp_tools::spawn_stdio_session`] (command allowlist, stderr drain,
+//!   `initialize` handshake). Env comes from the connector's newest
+//!   credential: each `suggested_env` key is filled from the matching
+//!   decrypted field, and the credential's `env_vars` JSON wins.
+//! - **health check** lists tools over the live session; a server that exited
+//!   or stops answering is marked unhealthy.
+//!
+//! Running servers live in a process-wide registry keyed by connector name and
+//! are killed on drop, like the tool-call session pool in [`mcp_tools`].
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use ts_rs::TS;
+
+use super::mcp_tools::{self, PooledStdioSession};
+use crate::db::repos::resources::{connectors as connector_repo, credentials as cred_repo};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Upper bound for one `tools/list` health probe.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Upper bound for `npm install` of one server package.
+const INSTALL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Field names that hold a connector's primary secret, tried when a
+/// connector suggests exactly one empty env var and no field matches it.
+const PRIMARY_SECRET_FIELDS: &[&str] = &[
+    "api_key",
+    "token",
+    "access_token",
+    "api_token",
+    "pat",
+    "personal_access_token",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum McpServerState {
+    Stopped,
+    Running,
+    Unhealthy,
+    Failed,
+}
+
+/// Status of one connector's MCP server, as returned by `list_mcp_servers`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct McpServerStatus {
+    pub connector: String,
+    pub label: String,
+    pub package: String,
+    pub state: McpServerState,
+    /// Package present in the managed install directory.
+    pub installed: bool,
+    /// Credential whose fields supplied the env, if any.
+    pub credential_id: Option<String>,
+    pub pid: Option<u32>,
+    pub started_at: Option<String>,
+    pub last_health_check_at: Option<String>,
+    pub tool_count: Option<u32>,
+    pub last_error: Option<String>,
+}
+
+/// Stdio server declared by a connector's metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct McpServerSpec {
+    pub connector: String,
+    pub label: String,
+    pub package: String,
+    /// `suggested_env` from the metadata: env var name → default value.
+    pub suggested_env: HashMap<String, String>,
+}
+
+struct ManagedServer {
+    session: Option<PooledStdioSession>,
+    status: McpServerStatus,
+}
+
+fn registry() -> &'static tokio::sync::Mutex<HashMap<String, ManagedServer>> {
+    static SERVERS: std::sync::OnceLock<tokio::sync::Mutex<HashMap<String, ManagedServer>>> =
+        std::sync::OnceLock::new();
+    SERVERS.get_or_init(|| tokio::sync::Mutex::new(HashMap::new()))
+}
+
+// ============================================================================
+// Spec + env resolution
+// ============================================================================
+
+/// The stdio server a connector's metadata declares, if any.
+fn spec_from_metadata(connector: &str, label: &str, metadata: &Value) -> Option<McpServerSpec> {
+    let method = metadata
+        .get("auth_methods")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .find(|m| {
+            m.get("type").and_then(Value::as_str) == Some("mcp")
+                && m.get("package").and_then(Value::as_str).is_some()
+                && m.get("transport").and_then(Value::as_str).unwrap_or("stdio") == "stdio"
+        });
+    let package = method
+        .and_then(|m| m.get("package"))
+        .or_else(|| metadata.get("mcp_package"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|p| !p.is_empty())?;
+    let suggested_env = method
+        .and_then(|m| m.get("suggested_env"))
+        .and_then(Value::as_object)
+        .map(|env| {
+            env.iter()
+                .map(|(k, v)| (k.clone(), v.as_str().unwrap_or_default().to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(McpServerSpec {
+        connector: connector.to_string(),
+        label: label.to_string(),
+        package: package.to_string(),
+        suggested_env,
+    })
+}
+
+/// Every connector that declares a stdio MCP server.
+pub fn list_specs(pool: &DbPool) -> Result<Vec<McpServerSpec>, AppError> {
+    Ok(connector_repo::get_all(pool)?
+        .into_iter()
+        .filter_map(|c| {
+            let metadata: Value = serde_json::from_str(c.metadata.as_deref()?).ok()?;
+            spec_from_metadata(&c.name, &c.label, &metadata)
+        })
+        .collect())
+}
+
+fn resolve_spec(pool: &DbPool, connector: &str) -> Result<McpServerSpec, AppError> {
+    let def = connector_repo::get_by_name(pool, connector)?
+        .ok_or_else(|| AppError::NotFound(format!("Connector {connector}")))?;
+    def.metadata
+        .as_deref()
+        .and_then(|m| serde_json::from_str::<Value>(m).ok())
+        .and_then(|m| spec_from_metadata(&def.name, &def.label, &m))
+        .ok_or_else(|| {
+            AppError::Validation(format!("Connector '{connector}' declares no stdio MCP server"))
+        })
+}
+
+/// npm package names only: an optional `@scope/`, then `[a-z0-9._-]`, with an
+/// optional `@version`. Rejects URLs, paths and anything shell-significant.
+fn validate_package(package: &str) -> Result<(), AppError> {
+    let valid = !package.is_empty()
+        && !mcp_tools::is_remote_code_spec(package)
+        && !package.starts_with(['.', '/', '-'])
+        && !package.contains("..")
+        && package
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '@' | '/' | '.' | '_' | '-'));
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::Validation(format!("Invalid MCP package name '{package}'")))
+    }
+}
+
+/// Env for the server process: each suggested var filled from the
+/// credential field with the same name (case-insensitive) or whose name ends
+/// it (`api_key` → `CAL_API_KEY`), else its metadata default. A lone empty
+/// suggested var falls back to the credential's primary secret field. The
+/// credential's explicit `env_vars` JSON is applied last and wins.
+pub fn build_env(
+    suggested: &HashMap<String, String>,
+    fields: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let lone_empty = suggested.len() == 1 && suggested.values().all(String::is_empty);
+    let mut env = HashMap::new();
+    for (key, default) in suggested {
+        let lower = key.to_ascii_lowercase();
+        let from_field = fields
+            .iter()
+            .filter(|(_, v)| !v.is_empty())
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .or_else(|| {
+                fields.iter().filter(|(_, v)| !v.is_empty()).find(|(k, _)| {
+                    lower.ends_with(&format!("_{}", k.to_ascii_lowercase()))
+                })
+            })
+            .map(|(_, v)| v.clone())
+            .or_else(|| {
+                lone_empty
+                    .then(|| {
+                        PRIMARY_SECRET_FIELDS
+                            .iter()
+                            .find_map(|f| fields.get(*f).filter(|v| !v.is_empty()))
+                            .cloned()
+                    })
+                    .flatten()
+            });
+        let value = from_field.unwrap_or_else(|| default.clone());
+        if value.is_empty() {
+            continue;
+        }
+        if let Some(name) = super::runner::sanitize_env_name(key) {
+            env.insert(name, value);
+        }
+    }
+    env.extend(mcp_tools::parse_env_vars(fields));
+    env
+}
+
+fn install_dir(connector: &str) -> Result<PathBuf, AppError> {
+    let safe: String = connector
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    dirs::data_dir()
+        .map(|d| d.join("com.personas.desktop").join("mcp_servers").join(safe))
+        .ok_or_else(|| AppError::Internal("Could not resolve the app data directory".into()))
+}
+
+fn is_installed(connector: &str, package: &str) -> bool {
+    let bare = match package.rfind('@') {
+        Some(i) if i > 0 => &package[..i],
+        _ => package,
+    };
+    install_dir(connector)
+        .map(|d| d.join("node_modules").join(bare).join("package.json").is_file())
+        .unwrap_or(false)
+}
+
+fn stopped_status(spec: &McpServerSpec) -> McpServerStatus {
+    McpServerStatus {
+        connector: spec.connector.clone(),
+        label: spec.label.clone(),
+        package: spec.package.clone(),
+        state: McpServerState::Stopped,
+        installed: is_installed(&spec.connector, &spec.package),
+        credential_id: None,
+        pid: None,
+        started_at: None,
+        last_health_check_at: None,
+        tool_count: None,
+        last_error: None,
+    }
+}
+
+// ============================================================================
+// Lifecycle
+// ============================================================================
+
+/// Install (or update) the connector's server package into its managed
+/// directory.
+pub async fn install(pool: &DbPool, connector: &str) -> Result<McpServerStatus, AppError> {
+    let spec = resolve_spec(pool, connector)?;
+    validate_package(&spec.package)?;
+    let dir = install_dir(connector)?;
+    std::fs::create_dir_all(&dir)?;
+
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut c = tokio::process::Command::new("cmd");
+        c.args(["/C", "npm"]);
+        c
+    };
+    #[cfg(not(windows))]
+    let mut cmd = tokio::process::Command::new("npm");
+    cmd.args(["install", "--no-audit", "--no-fund", "--prefix"])
+        .arg(&dir)
+        .arg(&spec.package)
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true);
+    #[cfg(windows)]
+    {
+        #[allow(unused_imports)]
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x08000000); // CREATE_NO_WINDOW
+    }
+
+    let output = tokio::time::timeout(INSTALL_TIMEOUT, cmd.output())
+        .await
+        .map_err(|_| AppError::Internal(format!("npm install of {} timed out", spec.package)))?
+        .map_err(|e| AppError::Internal(format!("Failed to run npm: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let tail: String = stderr.lines().rev().take(5).collect::<Vec<_>>().join(" | ");
+        return Err(AppError::Internal(format!(
+            "npm install of {} failed: {tail}",
+            spec.package
+        )));
+    }
+    tracing::info!(connector, package = %spec.package, "MCP server package installed");
+    status(pool, connector).await
+}
+
+/// Start the connector's server (no-op if it is already running) and run a
+/// first health check.
+pub async fn start(pool: &DbPool, connector: &str) -> Result<McpServerStatus, AppError> {
+    let spec = resolve_spec(pool, connector)?;
+    validate_package(&spec.package)?;
+    {
+        let mut servers = registry().lock().await;
+        if let Some(server) = servers.get_mut(connector) {
+            if let Some(session) = server.session.as_mut() {
+                if !session.has_exited() {
+                    return Ok(server.status.clone());
+                }
+            }
+        }
+    }
+
+    let credential = cred_repo::get_by_service_type(pool, connector)?.into_iter().next();
+    let fields = match &credential {
+        Some(c) => cred_repo::get_decrypted_fields(pool, c)?,
+        None => HashMap::new(),
+    };
+    let env = build_env(&spec.suggested_env, &fields);
+
+    let mut spawn_fields = HashMap::new();
+    spawn_fields.insert("command".to_string(), format!("npx -y {}", spec.package));
+    spawn_fields.insert("env_vars".to_string(), serde_json::to_string(&env)?);
+    if is_installed(connector, &spec.package) {
+        let dir = install_dir(connector)?;
+        spawn_fields.insert("working_directory".to_string(), dir.display().to_string());
+    }
+
+    let mut status = stopped_status(&spec);
+    status.credential_id = credential.map(|c| c.id);
+    let session = match mcp_tools::spawn_stdio_session(&spawn_fields).await {
+        Ok(session) => session,
+        Err(e) => {
+            status.state = McpServerState::Failed;
+            status.last_error = Some(e.to_string());
+            registry()
+                .lock()
+                .await
+                .insert(connector.to_string(), ManagedServer { session: None, status });
+            return Err(e);
+        }
+    };
+    status.state = McpServerState::Running;
+    status.pid = session.pid();
+    status.started_at = Some(chrono::Utc::now().to_rfc3339());
+    tracing::info!(connector, pid = ?status.pid, "MCP server started");
+
+    // A racing start for the same connector is replaced; its process is
+    // killed when the old session drops.
+    registry().lock().await.insert(
+        connector.to_string(),
+        ManagedServer { session: Some(session), status },
+    );
+    health_check(connector).await
+}
+
+/// Stop the connector's server. Returns whether one was running.
+pub async fn stop(connector: &str) -> bool {
+    let mut servers = registry().lock().await;
+    let Some(server) = servers.get_mut(connector) else {
+        return false;
+    };
+    let was_running = match server.session.take() {
+        Some(mut session) => {
+            let alive = !session.has_exited();
+            session.kill();
+            alive
+        }
+        None => false,
+    };
+    server.status.state = McpServerState::Stopped;
+    server.status.pid = None;
+    tracing::info!(connector, "MCP server stopped");
+    was_running
+}
+
+pub async fn restart(pool: &DbPool, connector: &str) -> Result<McpServerStatus, AppError> {
+    stop(connector).await;
+    start(pool, connector).await
+}
+
+/// Probe a running server with `tools/list`. Exited or unresponsive servers
+/// are marked unhealthy (their session is dropped); the updated status is
+/// returned either way.
+pub async fn health_check(connector: &str) -> Result<McpServerStatus, AppError> {
+    let mut servers = registry().lock().await;
+    let server = servers
+        .get_mut(connector)
+        .ok_or_else(|| AppError::NotFound(format!("MCP server for {connector} is not started")))?;
+    server.status.last_health_check_at = Some(chrono::Utc::now().to_rfc3339());
+
+    let Some(session) = server.session.as_mut() else {
+        return Ok(server.status.clone());
+    };
+    let probe = if session.has_exited() {
+        Err("server process exited".to_string())
+    } else {
+        match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, mcp_tools::list_tools_on_session(session))
+            .await
+        {
+            Ok(Ok(tools)) => Ok(tools.len() as u32),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(_) => Err("health check timed out".to_string()),
+        }
+    };
+    match probe {
+        Ok(count) => {
+            server.status.state = McpServerState::Running;
+            server.status.tool_count = Some(count);
+            server.status.last_error = None;
+        }
+        Err(e) => {
+            tracing::warn!(connector, error = %e, "MCP server health check failed");
+            if let Some(mut session) = server.session.take() {
+                session.kill();
+            }
+            server.status.state = McpServerState::Unhealthy;
+            server.status.pid = None;
+            server.status.last_error = Some(e);
+        }
+    }
+    Ok(server.status.clone())
+}
+
+/// Current status of one connector's server without probing it.
+pub async fn status(pool: &DbPool, connector: &str) -> Result<McpServerStatus, AppError> {
+    let spec = resolve_spec(pool, connector)?;
+    let servers = registry().lock().await;
+    Ok(match servers.get(connector) {
+        Some(server) => McpServerStatus {
+            installed: is_installed(connector, &spec.package),
+            ..server.status.clone()
+        },
+        None => stopped_status(&spec),
+    })
+}
+
+/// Status of every connector that declares a stdio MCP server.
+pub async fn list_servers(pool: &DbPool) -> Result<Vec<McpServerStatus>, AppError> {
+    let specs = list_specs(pool)?;
+    let servers = registry().lock().await;
+    Ok(specs
+        .iter()
+        .map(|spec| match servers.get(&spec.connector) {
+            Some(server) => McpServerStatus {
+                installed: is_installed(&spec.connector, &spec.package),
+                ..server.status.clone()
+            },
+            None => stopped_status(spec),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_stdio_server_from_connector_metadata() {
+        let spec = spec_from_metadata(
+            "cal_com",
+            "Cal.com",
+            &json!({ "auth_methods": [
+                { "id": "api_key", "type": "credential" },
+                { "id": "mcp", "type": "mcp", "package": "@calcom/cal-mcp",
+                  "transport": "stdio", "suggested_env": { "CAL_API_KEY": "" } }
+            ]}),
+        )
+        .unwrap();
+        assert_eq!(spec.package, "@calcom/cal-mcp");
+        assert_eq!(spec.suggested_env.get("CAL_API_KEY").map(String::as_str), Some(""));
+
+        let legacy = spec_from_metadata("kalshi", "Kalshi", &json!({ "mcp_package": "kalshi-mcp" }));
+        assert_eq!(legacy.unwrap().package, "kalshi-mcp");
+        let hosted = json!({ "auth_methods": [
+            { "type": "mcp", "transport": "streamable-http", "url": "https://mcp.example.com" }
+        ]});
+        assert!(spec_from_metadata("x", "X", &hosted).is_none());
+
+        assert!(validate_package("@scope/pkg@1.2.0").is_ok());
+        assert!(validate_package("https://evil.example/x.tgz").is_err());
+        assert!(validate_package("--registry=x").is_err());
+        assert!(validate_package("pkg;rm").is_err());
+    }
+
+    #[test]
+    fn builds_env_from_credential_fields() {
+        let suggested: HashMap<String, String> = [
+            ("OBSIDIAN_API_KEY".to_string(), String::new()),
+            ("OBSIDIAN_API_URL".to_string(), "https://127.0.0.1:27124".to_string()),
+        ]
+        .into();
+        let fields: HashMap<String, String> = [("api_key".to_string(), "k1".to_string())].into();
+        let env = build_env(&suggested, &fields);
+        assert_eq!(env.get("OBSIDIAN_API_KEY").map(String::as_str), Some("k1"));
+        assert_eq!(env.get("OBSIDIAN_API_URL").map(String::as_str), Some("https://127.0.0.1:27124"));
+
+        let lone: HashMap<String, String> = [("ASANA_PAT".to_string(), String::new())].into();
+        let fields: HashMap<String, String> = [
+            ("token".to_string(), "t1".to_string()),
+            ("env_vars".to_string(), r#"{"EXTRA_FLAG":"1"}"#.to_string()),
+        ]
+        .into();
+        let env = build_env(&lone, &fields);
+        assert_eq!(env.get("ASANA_PAT").map(String::as_str), Some("t1"));
+        assert_eq!(env.get("EXTRA_FLAG").map(String::as_str), Some("1"));
+    }
+}
@@ -212,7 +212,7 @@ pub fn invalidate_tools_cache(credential_id: &str) {
 /// A live MCP stdio session that has completed the initialization handshake.
 /// The child process is kept alive so subsequent tool calls skip the spawn +
 /// handshake overhead (~200-500ms per call).
-struct PooledStdioSession {
+pub(crate) struct PooledStdioSession {
     child: tokio::process::Child,
     stdin: tokio::process::ChildStdin,
     reader: tokio::io::BufReader<tokio::process::ChildStdout>,
@@ -221,6 +221,22 @@ struct PooledStdioSession {
     next_id: u64,
 }
 
+impl PooledStdioSession {
+    /// OS process id, if the child has not been reaped yet.
+    pub(crate) fn pid(&self) -> Option<u32> {
+        self.child.id()
+    }
+
+    /// True once the server process has exited.
+    pub(crate) fn has_exited(&mut self) -> bool {
+        !matches!(self.child.try_wait(), Ok(None))
+    }
+
+    pub(crate) fn kill(&mut self) {
+        let _ = self.child.start_kill();
+    }
+}
+
 /// Global pool of warm MCP stdio sessions, keyed by credential_id.
 fn stdio_session_pool() -> &'static tokio::sync::Mutex<HashMap<String, PooledStdioSession>> {
     static POOL: std::sync::OnceLock<tokio::sync::Mutex<HashMap<String, PooledStdioSession>>> =
@@ -293,7 +309,7 @@ async fn return_pooled_session(credential_id: &str, mut session: PooledStdioSess
 }
 
 /// Spawn a new MCP stdio process and perform the initialization handshake.
-async fn spawn_stdio_session(
+pub(crate) async fn spawn_stdio_session(
     fields: &HashMap<String, String>,
 ) -> Result<PooledStdioSession, AppError> {
     let spawn_start = Instant::now();
@@ -1127,7 +1143,7 @@ fn parse_tool_defs(raw: &[serde_json::Value], transport: &str) -> Vec<McpTool> {
     tools
 }
 
-async fn list_tools_on_session(session: &mut PooledStdioSession) -> Result<Vec<McpTool>, AppError> {
+pub(crate) async fn list_tools_on_session(session: &mut PooledStdioSession) -> Result<Vec<McpTool>, AppError> {
     let tools_val = fetch_tools_paginated_stdio(session).await?;
     Ok(parse_tool_defs(&tools_val, "stdio"))
 }
@@ -1478,7 +1494,7 @@ fn jsonrpc_request(id: u64, method: &str, params: serde_json::Value) -> serde_js
     })
 }
 
-fn parse_env_vars(fields: &HashMap<String, String>) -> HashMap<String, String> {
+pub(crate) fn parse_env_vars(fields: &HashMap<String, String>) -> HashMap<String, String> {
     let raw: HashMap<String, String> = fields
         .get("env_vars")
         .and_then(|v| serde_json::from_str(v).ok())
@@ -1583,7 +1599,7 @@ fn validate_mcp_command(command: &str) -> Result<Vec<String>, AppError> {
 /// would fetch and execute — rather than a registry package name or local path.
 /// Flags that merely *contain* a URL value (e.g. `--registry=https://…`) do not
 /// match, since only a bare remote spec is the fetch-and-run vector.
-fn is_remote_code_spec(arg: &str) -> bool {
+pub(crate) fn is_remote_code_spec(arg: &str) -> bool {
     const REMOTE_PREFIXES: &[&str] = &[
         "http://", "https://", "git://", "git+", "ssh://", "ftp://", "file://",
     ];
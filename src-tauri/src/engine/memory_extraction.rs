@@ -0,0 +1,204 @@
+//! Automatic memory extraction from completed executions.
+//!
+//! Opt-in per persona via the `memory_extraction:<persona_id>` setting. After
+//! a completed, non-simulated run, one single-turn CLI pass reads the run's
+//! output and proposes durable facts worth remembering. Each survivor is
+//! written as a normal `persona_memories` row with `source_execution_id`
+//! pointing at the run and an `auto-extracted` tag, so it shows up in the
+//! memory UI with its provenance and can be reviewed like any other memory.
+//!
+//! Dedup happens in two layers: the prompt lists the persona's existing
+//! memory titles so the model skips known facts, and titles that still
+//! collide are dropped here. Content-level duplicates are then caught by the
+//! write-path dedup in `memories::create`.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::db::models::{normalize_category, CreatePersonaMemoryInput, Json};
+use crate::db::repos::core::memories as repo;
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Outputs shorter than this rarely hold anything durable; skip the CLI pass.
+const MIN_OUTPUT_CHARS: usize = 200;
+/// Output sent to the model is clipped to this many chars.
+const MAX_OUTPUT_CHARS: usize = 12_000;
+/// Most memories one run may add.
+const MAX_EXTRACTED_PER_RUN: usize = 5;
+/// Existing titles listed in the prompt for dedup.
+const EXISTING_TITLES_LIMIT: i64 = 100;
+/// Tag stamped on every extracted memory.
+pub const EXTRACTED_TAG: &str = "auto-extracted";
+
+#[derive(Debug, Deserialize)]
+struct ExtractedMemory {
+    title: String,
+    content: String,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    importance: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExtractionOutput {
+    #[serde(default)]
+    memories: Vec<ExtractedMemory>,
+}
+
+/// Whether `persona_id` has opted into automatic extraction.
+pub fn is_enabled(pool: &DbPool, persona_id: &str) -> bool {
+    let key = format!("{}{persona_id}", settings_keys::MEMORY_EXTRACTION_PREFIX);
+    matches!(settings::get(pool, &key), Ok(Some(v)) if v == "true")
+}
+
+/// Post-execution hook. No-op unless the persona opted in; failures are
+/// logged and never affect the execution itself.
+pub async fn on_execution_complete(pool: DbPool, persona_id: String, execution_id: String) {
+    if !is_enabled(&pool, &persona_id) {
+        return;
+    }
+    match extract_from_execution(&pool, &persona_id, &execution_id).await {
+        Ok(0) => {}
+        Ok(n) => {
+            tracing::info!(%persona_id, %execution_id, created = n, "Extracted memories from execution")
+        }
+        Err(e) => {
+            tracing::warn!(%persona_id, %execution_id, error = %e, "Memory extraction failed")
+        }
+    }
+}
+
+/// Run one extraction pass over `execution_id` and store the new memories.
+/// Returns how many rows were created.
+pub async fn extract_from_execution(
+    pool: &DbPool,
+    persona_id: &str,
+    execution_id: &str,
+) -> Result<usize, AppError> {
+    let execution = exec_repo::get_by_id(pool, execution_id)?;
+    if execution.status != "completed" || execution.is_simulation {
+        return Ok(0);
+    }
+    let Some(output) = execution
+        .output_data
+        .as_deref()
+        .filter(|o| o.trim().chars().count() >= MIN_OUTPUT_CHARS)
+    else {
+        return Ok(0);
+    };
+
+    let existing_titles: Vec<String> =
+        repo::get_by_persona(pool, persona_id, Some(EXISTING_TITLES_LIMIT))?
+            .into_iter()
+            .map(|m| m.title)
+            .collect();
+    let prompt = build_prompt(output, &existing_titles);
+    let raw = super::memory_reflection::run_claude_oneshot(&prompt).await?;
+    let parsed = parse_output(&raw)?;
+
+    let mut created = 0;
+    for m in select_new(parsed, &existing_titles) {
+        let input = CreatePersonaMemoryInput {
+            persona_id: persona_id.to_string(),
+            title: m.title,
+            content: m.content,
+            category: Some(
+                normalize_category(m.category.as_deref().unwrap_or_default()).to_string(),
+            ),
+            source_execution_id: Some(execution_id.to_string()),
+            importance: Some(m.importance.unwrap_or(3).clamp(1, 5)),
+            tags: Some(Json(vec![EXTRACTED_TAG.to_string()])),
+            use_case_id: execution.use_case_id.clone(),
+        };
+        match repo::create(pool, input) {
+            // A content duplicate returns the existing row untouched.
+            Ok(mem) if mem.source_execution_id.as_deref() == Some(execution_id) => created += 1,
+            Ok(_) => {}
+            Err(e) => tracing::debug!(error = %e, "Skipped extracted memory"),
+        }
+    }
+    Ok(created)
+}
+
+fn build_prompt(output: &str, existing_titles: &[String]) -> String {
+    let known = if existing_titles.is_empty() {
+        "(none)".to_string()
+    } else {
+        existing_titles
+            .iter()
+            .map(|t| format!("- {t}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    format!(
+        r#"You maintain the long-term memory of an AI agent. Read the output of its latest run and extract at most {MAX_EXTRACTED_PER_RUN} DURABLE facts worth remembering for future runs: stable facts about the user, their systems or data, preferences, instructions, and lessons learned. Skip anything specific to this one run (counts, timestamps, transient status), anything speculative, and anything already covered by the known memories below.
+
+## Known memories
+{known}
+
+## Run output
+{}
+
+Respond with ONLY a JSON object:
+{{"memories": [{{"title": "short title", "content": "one or two sentences", "category": "fact|preference|instruction|context|learned", "importance": 1-5}}]}}
+Return {{"memories": []}} when nothing qualifies."#,
+        super::memory_reflection::clamp_chars(output, MAX_OUTPUT_CHARS)
+    )
+}
+
+fn parse_output(raw: &str) -> Result<Vec<ExtractedMemory>, AppError> {
+    let json = super::memory_reflection::extract_json_object(raw)
+        .ok_or_else(|| AppError::Internal("Failed to parse extraction output as JSON".into()))?;
+    let parsed: ExtractionOutput = serde_json::from_str(&json)
+        .map_err(|e| AppError::Internal(format!("Invalid JSON in extraction output: {e}")))?;
+    Ok(parsed.memories)
+}
+
+fn normalize_title(title: &str) -> String {
+    title.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Drop empty entries and titles already held (or repeated within the batch),
+/// capped at [`MAX_EXTRACTED_PER_RUN`].
+fn select_new(
+    candidates: Vec<ExtractedMemory>,
+    existing_titles: &[String],
+) -> Vec<ExtractedMemory> {
+    let mut seen: HashSet<String> = existing_titles.iter().map(|t| normalize_title(t)).collect();
+    candidates
+        .into_iter()
+        .filter(|m| !m.title.trim().is_empty() && !m.content.trim().is_empty())
+        .filter(|m| seen.insert(normalize_title(&m.title)))
+        .take(MAX_EXTRACTED_PER_RUN)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_drops_known_or_repeated_titles() {
+        let raw = r#"Sure:
+```json
+{"memories": [
+  {"title": "Invoices live in Drive", "content": "Monthly invoices are in /Finance/Invoices.", "category": "fact", "importance": 4},
+  {"title": "  user PREFERS  dark mode ", "content": "Dark mode.", "category": "preference"},
+  {"title": "Invoices live in drive", "content": "Repeat within the batch."},
+  {"title": "", "content": "No title."}
+]}
+```"#;
+        let parsed = parse_output(raw).unwrap();
+        assert_eq!(parsed.len(), 4);
+        let kept = select_new(parsed, &["User prefers dark mode".to_string()]);
+        let titles: Vec<&str> = kept.iter().map(|m| m.title.as_str()).collect();
+        assert_eq!(titles, vec!["Invoices live in Drive"]);
+        assert!(parse_output("no json here").is_err());
+    }
+}
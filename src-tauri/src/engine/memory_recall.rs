@@ -218,6 +218,47 @@ pub fn pack_by_budget(
     }
 }
 
+/// Characters of active-tier memories packed into a run's prompt.
+pub const ACTIVE_MEMORY_BUDGET_CHARS: usize = 6000;
+
+/// The memory section appended to a run's prompt: every core memory, then
+/// the packed active tier. Empty when there is nothing to inject.
+pub fn render_prompt_section(core: &[PersonaMemory], packed: &PackedRecall) -> String {
+    let mut section = String::new();
+
+    // Core beliefs — always present, define agent identity
+    if !core.is_empty() {
+        section.push_str("\n\n## Agent Memory — Core Beliefs\n\n");
+        section.push_str("These are your established principles and preferences learned over many interactions. Treat them as strong defaults.\n\n");
+        for m in core {
+            section.push_str(&format!(
+                "- **{}** [{}]: {}\n",
+                m.title, m.category, m.content
+            ));
+        }
+    }
+
+    if !packed.selected.is_empty() {
+        section.push_str("\n\n## Agent Memory — Recent Learnings\n\n");
+        section.push_str("Context from recent work. Use to inform your analysis and avoid repeating past mistakes.\n\n");
+        for m in &packed.selected {
+            section.push_str(&format!(
+                "- **{}** [{}] (importance: {}): {}\n",
+                m.title, m.category, m.importance, m.content
+            ));
+        }
+        if packed.omitted > 0 {
+            section.push_str(&format!(
+                "- …(+{} more lower-value memories omitted to bound prompt size)\n",
+                packed.omitted
+            ));
+        }
+    }
+
+    section.push('\n');
+    section
+}
+
 // ---------------------------------------------------------------------------
 // Task-relevant recall (MEMORY CONTRACT (7)) — semantic blend
 //
@@ -321,6 +362,83 @@ pub fn pack_by_budget_relevance(
     }
 }
 
+/// Operator knobs for embedding recall, read from `app_settings` per run.
+#[cfg_attr(not(feature = "ml"), allow(dead_code))] // runtime consumers are ml-gated; non-ml exercises via tests
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecallTuning {
+    /// Similarity below which a hit counts as unrelated
+    /// ([`crate::db::settings_keys::MEMORY_SIMILARITY_THRESHOLD`]).
+    pub min_similarity: f64,
+    /// `> 0` switches to strict top-k selection
+    /// ([`crate::db::settings_keys::MEMORY_RECALL_TOP_K`]).
+    pub top_k: usize,
+}
+
+impl Default for RecallTuning {
+    fn default() -> Self {
+        Self {
+            min_similarity: crate::db::settings_keys::MEMORY_SIMILARITY_THRESHOLD_DEFAULT,
+            top_k: crate::db::settings_keys::MEMORY_RECALL_TOP_K_DEFAULT as usize,
+        }
+    }
+}
+
+impl RecallTuning {
+    /// Load from settings; missing or malformed values fall back to defaults.
+    #[cfg_attr(not(feature = "ml"), allow(dead_code))]
+    pub fn from_settings(pool: &DbPool) -> Self {
+        use crate::db::repos::core::settings;
+        use crate::db::settings_keys;
+        let read = |key: &str| settings::get(pool, key).ok().flatten();
+        let defaults = Self::default();
+        Self {
+            min_similarity: read(settings_keys::MEMORY_SIMILARITY_THRESHOLD)
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| (0.0..=1.0).contains(v))
+                .unwrap_or(defaults.min_similarity),
+            top_k: read(settings_keys::MEMORY_RECALL_TOP_K)
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(defaults.top_k),
+        }
+    }
+}
+
+/// Apply [`RecallTuning`] to a task similarity map before packing.
+///
+/// Hits under `min_similarity` are removed, so they get no lift. With
+/// `top_k > 0` the candidate set is also narrowed to the `top_k` most similar
+/// memories that cleared the threshold (ties broken by decayed value, then
+/// id); everything else is dropped rather than packed by value alone.
+#[cfg_attr(not(feature = "ml"), allow(dead_code))] // runtime caller (task-aware pack) is ml-gated; non-ml exercises via tests
+pub fn apply_recall_tuning(
+    candidates: Vec<PersonaMemory>,
+    relevance: &mut HashMap<String, f64>,
+    tuning: RecallTuning,
+    now: DateTime<Utc>,
+) -> Vec<PersonaMemory> {
+    if tuning.min_similarity > 0.0 {
+        relevance.retain(|_, sim| *sim >= tuning.min_similarity);
+    }
+    if tuning.top_k == 0 {
+        return candidates;
+    }
+    let mut relevant: Vec<(f64, f64, PersonaMemory)> = candidates
+        .into_iter()
+        .filter_map(|m| {
+            let sim = *relevance.get(&m.id)?;
+            Some((sim, decay_score(&m, now), m))
+        })
+        .collect();
+    relevant.sort_by(|a, b| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal))
+            .then_with(|| a.2.id.cmp(&b.2.id))
+    });
+    relevant.truncate(tuning.top_k);
+    relevant.into_iter().map(|(_, _, m)| m).collect()
+}
+
 /// Task-aware recall orchestrator (ml builds): the one-call composition the
 /// runner swaps in for [`pack_by_budget`] when it has an embedder + the
 /// vec-registered user-DB pool at hand.
@@ -334,7 +452,8 @@ pub fn pack_by_budget_relevance(
 ///    [`crate::retrieval::MAX_VECTOR_DISTANCE`]),
 /// 3. convert surviving distances to similarities
 ///    ([`similarity_from_distance`]) keyed by memory id,
-/// 4. blend-rank + greedy-pack via [`pack_by_budget_relevance`].
+/// 4. apply the operator's threshold / top-k ([`apply_recall_tuning`]),
+/// 5. blend-rank + greedy-pack via [`pack_by_budget_relevance`].
 ///
 /// Degrades to the value-only [`pack_by_budget`] — byte-identical behavior —
 /// whenever the task context is empty or the embedding search fails (embedder
@@ -352,6 +471,7 @@ pub async fn pack_by_budget_task_aware(
     task_context: &str,
     vec_pool: &crate::db::UserDbPool,
     embedder: &std::sync::Arc<crate::engine::embedder::EmbeddingManager>,
+    tuning: RecallTuning,
 ) -> PackedRecall {
     if candidates.is_empty() || task_context.trim().is_empty() {
         return pack_by_budget(candidates, char_budget, now);
@@ -373,7 +493,7 @@ pub async fn pack_by_budget_task_aware(
     };
     let (kept, dropped_far) =
         crate::retrieval::filter_by_distance_floor(&hits, crate::retrieval::MAX_VECTOR_DISTANCE);
-    let relevance: HashMap<String, f64> = kept
+    let mut relevance: HashMap<String, f64> = kept
         .iter()
         .map(|(id, d)| {
             (
@@ -388,13 +508,18 @@ pub async fn pack_by_budget_task_aware(
         dropped_far,
         "task-aware recall: similarity map built"
     );
-    pack_by_budget_relevance(
+    let total = candidates.len();
+    let candidates = apply_recall_tuning(candidates, &mut relevance, tuning, now);
+    let excluded = total - candidates.len();
+    let mut packed = pack_by_budget_relevance(
         candidates,
         char_budget,
         now,
         &relevance,
         DEFAULT_RELEVANCE_WEIGHT,
-    )
+    );
+    packed.omitted += excluded;
+    packed
 }
 
 /// A memory is forgotten (archived) when its decayed value falls below
@@ -483,6 +608,7 @@ mod tests {
             home_team_id: None,
             derived_from: None,
             open_claim_count: 0,
+            expires_at: None,
         }
     }
 
@@ -687,6 +813,43 @@ mod tests {
         assert_eq!(value_only.omitted, blended.omitted);
     }
 
+    #[test]
+    fn recall_tuning_thresholds_and_keeps_top_k_by_similarity() {
+        let now = Utc::now();
+        let candidates = vec![
+            mem("a", "learned", 3, 0, 0),
+            mem("b", "learned", 5, 0, 0),
+            mem("c", "learned", 3, 0, 0),
+            mem("unembedded", "constraint", 5, 0, 0),
+        ];
+        let mut rel: HashMap<String, f64> = [("a", 0.9), ("b", 0.2), ("c", 0.6)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+
+        // Default tuning is a no-op.
+        let mut untouched = rel.clone();
+        let all = apply_recall_tuning(
+            candidates.clone(),
+            &mut untouched,
+            RecallTuning::default(),
+            now,
+        );
+        assert_eq!(all.len(), 4);
+        assert_eq!(untouched.len(), 3);
+
+        // Threshold drops `b`; top-2 keeps the two most similar, never the
+        // unembedded memory regardless of its value.
+        let tuning = RecallTuning {
+            min_similarity: 0.3,
+            top_k: 2,
+        };
+        let kept = apply_recall_tuning(candidates, &mut rel, tuning, now);
+        let ids: Vec<&str> = kept.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+        assert!(!rel.contains_key("b"));
+    }
+
     #[test]
     fn forgetting_spares_young_important_and_core_paths() {
         let now = Utc::now();
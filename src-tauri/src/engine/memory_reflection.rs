@@ -81,7 +81,7 @@ const MAX_CONSUMPTION_RATIO: f64 = 0.6;
 const MAX_PROMPT_CONTENT_CHARS: usize = 700;
 
 /// Clamp a string to `max` characters on a char boundary, with a marker.
-fn clamp_chars(s: &str, max: usize) -> String {
+pub(crate) fn clamp_chars(s: &str, max: usize) -> String {
     if s.chars().count() <= max {
         return s.to_string();
     }
@@ -324,7 +324,7 @@ Memories:
 // CLI one-shot (mirrors the curation pipeline's spawn contract)
 // ---------------------------------------------------------------------------
 
-async fn run_claude_oneshot(prompt: &str) -> Result<String, AppError> {
+pub(crate) async fn run_claude_oneshot(prompt: &str) -> Result<String, AppError> {
     let (program, mut args) = crate::engine::cli_process::claude_cli_invocation();
     args.extend(
         ["-p", "-", "--max-turns", "1", "--dangerously-skip-permissions"]
@@ -886,6 +886,7 @@ mod tests {
             home_team_id: None,
             derived_from: None,
             open_claim_count: 0,
+            expires_at: None,
         }
     }
 
@@ -22,6 +22,7 @@ pub mod autonomy;
 pub mod autopilot;
 pub mod automation_runner;
 pub mod background;
+pub mod budget;
 pub mod build_session;
 #[cfg(feature = "p2p")]
 pub mod bundle;
@@ -44,11 +45,15 @@ pub mod compilation_pipeline;
 pub mod compiler;
 pub mod composite;
 pub mod config_merge;
+pub mod connector_rate_limit;
 pub mod connector_strategy;
+pub mod container_sandbox;
 pub mod context_fidelity;
 #[cfg(feature = "desktop")]
 pub mod context_rules;
 pub mod cost;
+pub mod cost_reconciliation;
+pub mod deadline;
 pub mod connector_explorer;
 pub mod credential_design;
 pub mod credential_negotiator;
@@ -81,6 +86,7 @@ pub mod dispatch;
 pub mod dream_replay;
 pub mod dry_run;
 #[cfg(feature = "ml")]
+pub mod email;
 pub mod embedder;
 #[cfg(feature = "p2p")]
 pub mod enclave;
@@ -90,28 +96,40 @@ pub mod event_registry;
 pub mod event_vocabulary;
 pub mod events;
 pub mod evolution;
+pub mod execute_hook;
+pub mod execution_timeline;
+pub mod execution_wait;
+pub mod execution_placement;
 mod execution_engine;
 pub mod failover;
+pub mod feature_flags;
 pub mod failure_signature;
 #[cfg(feature = "desktop")]
 pub mod file_watcher;
 pub mod fix_loop;
+pub mod fleet_hygiene;
+pub mod fleet_learning;
 pub mod git_checkpoint;
 pub mod genome;
 pub mod genome_critique;
 pub mod google_oauth;
 pub mod healing;
 pub mod healing_orchestrator;
+pub mod healing_root_cause;
 pub mod healing_timeline;
 pub mod healthcheck;
 pub mod hooks_sidecar;
+pub mod http_tool;
 pub mod leadership;
 pub mod skill_scratchpad;
 pub mod skills_sidecar;
 #[cfg(feature = "p2p")]
 pub mod identity;
 pub mod inflight_guard;
+pub mod injection_scan;
+pub mod input_budget;
 pub mod intent_compiler;
+pub mod job_registry;
 pub mod kb_index;
 #[cfg(feature = "ml")]
 pub mod kb_extract;
@@ -123,25 +141,35 @@ pub mod knowledge;
 pub mod lifecycle;
 pub mod limits;
 pub mod llm_topology;
+pub mod log_archive;
 pub mod logger;
 pub mod management_api;
+pub mod mcp;
 pub mod mcp_tools;
+pub mod memory_extraction;
 pub mod memory_recall;
 pub mod memory_reflection;
 pub mod pairing;
+pub mod model_registry;
 pub mod model_routing;
 pub mod oauth_refresh;
 pub mod oauth_refresh_lock;
+pub mod observer_mode;
 // Ollama-as-CLI-engine is deferred (decision recorded 2026-05-05). The native
 // HTTP path here is not dispatched from `runner` and is gated behind the
 // `ollama` Cargo feature so it does not get compiled into normal builds.
 // See ollama.rs module docs for the full revival checklist.
+// Offline Ollama execution ships via `provider::ollama` (HTTP, not an
+// `EngineKind`), which does not depend on this module.
 #[cfg(feature = "ollama")]
 pub mod ollama;
 /// Remote HTTP inference (Qwen/DashScope) — Phase 1 split engine. See module docs.
 pub mod http_engine;
 pub mod optimizer;
 pub mod output_assertions;
+pub mod output_contract;
+pub mod output_regression;
+pub mod output_store;
 #[cfg(feature = "p2p")]
 pub mod p2p;
 pub mod parser;
@@ -149,6 +177,7 @@ pub mod path_safety;
 pub mod persona_icon;
 pub mod persona_jobs;
 pub mod pipeline;
+pub mod pipeline_condition;
 pub mod pipeline_executor;
 pub mod platform_rules;
 pub mod goal_advance;
@@ -159,17 +188,23 @@ pub mod kpi_binding;
 pub mod team_assignment_matching;
 pub mod team_assignment_orchestrator;
 pub mod team_handoff;
+pub mod template_variables;
 pub mod platforms;
 pub mod polling;
 pub mod prepared_run_cache;
 pub mod process_activity;
+pub mod process_reaper;
+pub mod process_tree;
 pub mod process_session;
+pub mod project_keys;
 pub mod project_tracking;
 pub mod prompt;
+pub mod prompt_blocks;
 pub mod protocol;
 pub mod provider;
 pub mod quality_gate;
 pub mod queue;
+pub mod queue_monitor;
 pub mod run_budget;
 pub mod resource_governor;
 pub mod rate_limiter;
@@ -179,7 +214,9 @@ pub mod recipe_parameters;
 pub mod recipe_seed;
 pub mod redact;
 pub mod render_plan;
+pub mod report_sink;
 pub mod resource_listing;
+pub mod retention;
 pub mod rotation;
 pub mod runner;
 pub mod safe_json;
@@ -190,11 +227,16 @@ pub mod scraper;
 pub mod session_pool;
 #[cfg(feature = "p2p")]
 pub mod share_link;
+pub mod shared_context;
 pub mod shared_event_local_relay;
 pub mod shared_event_relay;
 pub mod sla_breach;
 pub mod slack_poller;
+pub mod slo_monitor;
 pub mod smee_relay;
+pub mod sql_tool;
+pub mod state_snapshot;
+pub mod status_narrative;
 pub mod str_utils;
 pub mod subscription;
 pub mod team_preset_adopter;
@@ -203,7 +245,9 @@ pub mod template_checksums;
 pub mod template_v3;
 pub mod test_runner;
 pub mod tier;
+pub mod tool_approval;
 pub mod tool_outcome;
+pub mod tool_policy;
 pub mod tool_runner;
 pub mod topology_graph;
 pub mod topology_heuristic;
@@ -211,11 +255,14 @@ pub mod topology_types;
 pub mod trace;
 pub mod types;
 pub mod url_safety;
+pub mod vault_backend;
+pub mod vault_migration;
 #[cfg(feature = "ml")]
 pub mod vector_store;
 // F8 deterministic-verification primitive; consumed by the F7 fix-loop.
 #[allow(dead_code)]
 pub mod verification_command;
+pub mod warm_pool;
 pub mod webhook;
 pub mod webhook_notifier;
 pub mod workflow_compiler;
@@ -536,6 +583,12 @@ impl ExecutionEngine {
             // Same for the per-connector SKILL.md sidecar (skills_sidecar_enabled,
             // default ON). See skills_sidecar::is_enabled.
             crate::engine::skills_sidecar::seed_enabled_from_settings(p);
+            // Prompt `{{var.*}}` registry snapshot; commands reload it on write.
+            crate::engine::template_variables::reload(p);
+            // Prompt block library snapshot; commands reload it on write.
+            crate::engine::prompt_blocks::reload(p);
+            // Prompt `{{setting.*}}` snapshot; set_app_setting reloads it.
+            crate::engine::prompt::reload_setting_variables(p);
         }
 
         let circuit_breaker = match pool {
@@ -633,6 +686,33 @@ impl ExecutionEngine {
         }
     }
 
+    /// Hot-apply a persona's new `max_concurrent` to work already waiting in its
+    /// queue. Queued entries carry the limit captured at enqueue time, so
+    /// without this a raised limit would only help executions admitted later.
+    /// Drains with the same progress bound as `set_global_max_concurrent`.
+    pub async fn apply_persona_max_concurrent(
+        &self,
+        app: AppHandle,
+        pool: DbPool,
+        persona_id: &str,
+        max: i32,
+    ) {
+        self.tracker
+            .lock()
+            .await
+            .set_queued_max_concurrent(persona_id, max);
+        for _ in 0..max.max(1) {
+            let before = self.tracker.lock().await.queue_depth(persona_id);
+            if before == 0 {
+                break;
+            }
+            self.drain_after_slot_freed(app.clone(), pool.clone()).await;
+            if self.tracker.lock().await.queue_depth(persona_id) >= before {
+                break;
+            }
+        }
+    }
+
     /// Returns a reference to the concurrency tracker (for tier usage reporting).
     pub fn tracker(&self) -> &Arc<Mutex<queue::ConcurrencyTracker>> {
         &self.tracker
@@ -745,7 +825,7 @@ impl ExecutionEngine {
                         &exec.id,
                         UpdateExecutionStatus {
                             status: ExecutionState::Failed,
-                            error_message: Some("App restarted while execution was running".into()),
+                            error_message: Some(exec_repo::RESTART_INTERRUPTED_ERROR.into()),
                             ..Default::default()
                         },
                     );
@@ -1284,7 +1364,10 @@ impl ExecutionEngine {
         )
         .await;
 
-        // 3. Kill the child OS process to stop API credit consumption.
+        // 3. Kill the child OS process to stop API credit consumption. A
+        //    sandboxed run's container outlives its killed runtime client, so
+        //    it is removed first, while the run still has it registered.
+        container_sandbox::kill(execution_id);
         if let Some(pid) = self.child_pids.lock().await.remove(execution_id) {
             tracing::info!(execution_id = %execution_id, pid = pid, "Killing child process");
             kill_process(pid);
@@ -1726,6 +1809,10 @@ impl ExecutionEngine {
 
 /// Kill an OS process by PID. Cross-platform.
 pub(crate) fn kill_process(pid: u32) {
+    // Isolated CLI children (job object / process group) die as a whole tree.
+    if process_tree::kill_tree(pid) {
+        return;
+    }
     #[cfg(windows)]
     {
         #[allow(unused_imports)]
@@ -2470,13 +2557,28 @@ async fn handle_execution_result(
         .unwrap_or(false);
     if !is_simulation {
         notify_execution_rich(app, pool, persona_id, status.as_str(), result);
-    }
 
-    // Budget enforcement (only on success)
-    if result.success {
-        check_budget_enforcement(pool, persona_id, exec_id);
+        // File-report sink (no-op unless the persona has one configured).
+        let (pool, persona_id, exec_id) = (pool.clone(), persona_id.to_string(), exec_id.to_string());
+        tokio::task::spawn_blocking(move || {
+            report_sink::on_execution_complete(&pool, &persona_id, &exec_id);
+            // Run-over-run diff for schedule triggers that opted in.
+            output_regression::on_execution_complete(&pool, &persona_id, &exec_id);
+        });
+    }
+    if !is_simulation && status == ExecutionState::Completed {
+        // Opt-in memory extraction (no-op unless enabled for the persona).
+        tokio::spawn(memory_extraction::on_execution_complete(
+            pool.clone(),
+            persona_id.to_string(),
+            exec_id.to_string(),
+        ));
     }
 
+    // Budget enforcement. Failed runs count toward monthly spend too, so this
+    // runs regardless of outcome (alert once per month; pause in `pause` mode).
+    budget::enforce_after_execution(pool, app, persona_id, exec_id);
+
     // Chain triggers -- extract chain depth/visited/trace_id from execution's input_data
     // (propagated via chain event payloads to prevent infinite cycles)
     let source_input = exec_repo::get_by_id(pool, exec_id)
@@ -2497,6 +2599,10 @@ async fn handle_execution_result(
     // Use existing chain_trace_id if this execution is part of a chain,
     // otherwise use this execution's trace_id as the root of a new chain trace
     let chain_trace_id = existing_chain_trace_id.or_else(|| result.trace_id.clone());
+    // A root's blackboard writes must be visible to the hops it is about to fire.
+    if let Some(ctid) = chain_trace_id.as_deref().filter(|_| !is_downstream_hop) {
+        shared_context::adopt_root(pool, exec_id, ctid);
+    }
     // Direction 3: fold this hop's cost into the running chain total before
     // evaluating the next links (so the ceiling sees spend-through-this-hop).
     let chain_cost_total = chain_cost_in + result.cost_usd;
@@ -2605,7 +2711,14 @@ fn notify_execution(
         .as_ref()
         .and_then(|p| p.notification_channels.as_deref());
     let name = persona.as_ref().map(|p| p.name.as_str()).unwrap_or("Agent");
-    crate::notifications::notify_execution_completed(app, name, status, duration_ms, channels);
+    crate::notifications::notify_execution_completed(
+        app,
+        persona_id,
+        name,
+        status,
+        duration_ms,
+        channels,
+    );
 }
 
 fn notify_execution_rich(
@@ -2622,6 +2735,7 @@ fn notify_execution_rich(
     let name = persona.as_ref().map(|p| p.name.as_str()).unwrap_or("Agent");
     crate::notifications::notify_execution_completed_rich(
         app,
+        persona_id,
         name,
         status,
         result.duration_ms,
@@ -2799,42 +2913,6 @@ fn check_and_apply_circuit_breaker(pool: &DbPool, app: &AppHandle, persona_id: &
     );
 }
 
-fn check_budget_enforcement(pool: &DbPool, persona_id: &str, exec_id: &str) {
-    let monthly_spend = exec_repo::get_monthly_spend(pool, persona_id).unwrap_or(0.0);
-    let persona = persona_repo::get_by_id(pool, persona_id).ok();
-
-    if let Some(ref p) = persona {
-        if let Some(budget) = p.max_budget_usd {
-            if budget > 0.0 && monthly_spend >= budget {
-                let alert_content = format!(
-                    "Budget alert: {} has spent ${:.4} this month (budget: ${:.2}). Agent may be automatically paused.",
-                    p.name, monthly_spend, budget
-                );
-                let _ = crate::db::repos::communication::messages::create(
-                    pool,
-                    crate::db::models::CreateMessageInput {
-                        persona_id: persona_id.into(),
-                        execution_id: Some(exec_id.into()),
-                        title: Some("Budget Exceeded".into()),
-                        content: alert_content,
-                        content_type: Some("budget_alert".into()),
-                        priority: Some("critical".into()),
-                        metadata: None,
-                        thread_id: None,
-                        use_case_id: None,
-                    },
-                );
-                tracing::warn!(
-                    persona_id = %persona_id,
-                    monthly_spend = monthly_spend,
-                    budget = budget,
-                    "Budget exceeded for persona"
-                );
-            }
-        }
-    }
-}
-
 /// Per-capability "Errors" sigil routing, resolved from the persona's
 /// `design_context.use_cases[i].error_policy` (set during adoption). Returns
 /// `(incident, lab, escalate_after)`. Absent policy falls back to the same
@@ -2925,6 +3003,24 @@ fn evaluate_healing_and_retry(
     };
 
     let error_str = result.error.as_deref().unwrap_or("");
+    // Deadline refusals/truncations are by design: the work is worthless past
+    // its deadline, so neither retry it nor raise the persona's timeout.
+    if deadline::is_deadline_error(error_str) {
+        tracing::info!(execution_id = %exec_id, "Healing skipped: execution hit its deadline");
+        return;
+    }
+    // Policy violations are recorded as their own healing issue by the runner;
+    // a retry would only break the same rule again.
+    if tool_policy::is_policy_error(error_str) {
+        tracing::info!(execution_id = %exec_id, "Healing skipped: tool policy violation");
+        return;
+    }
+    // Paused for a human decision on a gated tool call; approving the review
+    // continues the run.
+    if tool_approval::is_approval_pause(error_str) {
+        tracing::info!(execution_id = %exec_id, "Healing skipped: awaiting tool approval");
+        return;
+    }
     let timed_out = error_str.contains("timed out");
 
     let is_dev_mode =
@@ -3009,6 +3105,14 @@ fn evaluate_healing_and_retry(
         Err(_) => return,
     };
 
+    // Opt-in root-cause summary (no-op unless enabled for the persona).
+    tokio::spawn(healing_root_cause::on_issue_created(
+        pool.clone(),
+        log_dir.clone(),
+        persona_id.to_string(),
+        issue.id.clone(),
+    ));
+
     // auto_fixed is true only when the strategy wants auto-fix AND the DB
     // status transition succeeded — prevents orphaned retries on DB failure.
     let mut auto_fixed = strategy.is_auto_action();
@@ -3138,6 +3242,7 @@ fn evaluate_healing_and_retry(
     // Notify healing issue
     crate::notifications::notify_healing_issue(
         app,
+        persona_id,
         &heal_name,
         &diagnosis.title,
         &diagnosis.severity,
@@ -4250,6 +4355,7 @@ fn spawn_delayed_retry(
                     .unwrap_or("Agent");
                 crate::notifications::notify_execution_completed_rich(
                     &app,
+                    &persona_id,
                     p_name,
                     status.as_str(),
                     result.duration_ms,
@@ -4292,6 +4398,9 @@ fn spawn_delayed_retry(
             let is_downstream_hop = existing_chain_trace_id.is_some();
             let chain_trace_id =
                 existing_chain_trace_id.or_else(|| result.trace_id.clone());
+            if let Some(ctid) = chain_trace_id.as_deref().filter(|_| !is_downstream_hop) {
+                shared_context::adopt_root(&pool, &exec_id, ctid);
+            }
             let chain_cost_total = chain_cost_in + result.cost_usd;
 
             let cascade_metrics = chain::evaluate_chain_triggers(
@@ -0,0 +1,276 @@
+//! Model deprecation registry.
+//!
+//! Providers retire model IDs on a schedule; a persona pinned to a retired ID
+//! otherwise fails with an opaque "model not found" from the CLI. The registry
+//! maps deprecated IDs to replacements, layered (highest wins per model):
+//!
+//!   1. [`settings_keys::MODEL_DEPRECATIONS`] — operator entries.
+//!   2. [`settings_keys::MODEL_DEPRECATIONS_REMOTE`] — last successful fetch of
+//!      [`REGISTRY_URL`] (see [`fetch_remote`]).
+//!   3. [`BUILTIN`] — shipped with this build.
+//!
+//! At execution time [`apply`] swaps a retired model for its replacement and
+//! returns a warning; a model that is deprecated but not yet retired only
+//! warns. `migrate_model_profiles` rewrites stored persona profiles in bulk.
+
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::engine::types::ModelProfile;
+
+/// Published deprecation list, served by `personas-web` next to the roadmap.
+pub const REGISTRY_URL: &str = "https://personas.so/models/deprecations/v1.json";
+
+/// Schema version of the published list this build understands.
+const SCHEMA_VERSION: u32 = 1;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A healthy list is a few KB; guards against a compromised origin.
+const MAX_PAYLOAD_BYTES: usize = 256 * 1024;
+
+/// `(model, replacement, retires_on)` shipped with this build. Replacements are
+/// the CLI's family aliases so they keep resolving to the current release.
+const BUILTIN: &[(&str, &str, &str)] = &[
+    ("claude-instant-1.2", "haiku", "2024-11-06"),
+    ("claude-2.0", "sonnet", "2025-07-21"),
+    ("claude-2.1", "sonnet", "2025-07-21"),
+    ("claude-3-sonnet-20240229", "sonnet", "2025-07-21"),
+    ("claude-3-5-sonnet-20240620", "sonnet", "2025-10-22"),
+    ("claude-3-5-sonnet-20241022", "sonnet", "2025-10-22"),
+    ("claude-3-opus-20240229", "opus", "2026-01-05"),
+    ("claude-3-5-haiku-20241022", "haiku", "2026-02-19"),
+    ("claude-3-7-sonnet-20250219", "sonnet", "2026-02-19"),
+];
+
+/// One deprecated model and what replaces it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelDeprecation {
+    pub model: String,
+    pub replacement: String,
+    /// `YYYY-MM-DD`; from this day on the provider rejects the model.
+    pub retires_on: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RemoteRegistry {
+    schema_version: u32,
+    deprecations: Vec<ModelDeprecation>,
+}
+
+/// A persona whose stored model profile was (or would be) rewritten.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ModelProfileMigration {
+    pub persona_id: String,
+    pub persona_name: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Validate entries, returning human-readable diagnostics (empty = valid).
+#[must_use]
+pub fn validate(entries: &[ModelDeprecation]) -> Vec<String> {
+    let mut diags = Vec::new();
+    for (i, e) in entries.iter().enumerate() {
+        if e.model.trim().is_empty() || e.replacement.trim().is_empty() {
+            diags.push(format!(
+                "entry {i}: model and replacement must not be empty"
+            ));
+        } else if e.model == e.replacement {
+            diags.push(format!("entry {i}: '{}' cannot replace itself", e.model));
+        }
+        if NaiveDate::parse_from_str(&e.retires_on, "%Y-%m-%d").is_err() {
+            diags.push(format!(
+                "entry {i}: retiresOn '{}' is not a YYYY-MM-DD date",
+                e.retires_on
+            ));
+        }
+    }
+    diags
+}
+
+fn load_key(db: &DbPool, key: &str) -> Vec<ModelDeprecation> {
+    settings::get(db, key)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str::<Vec<ModelDeprecation>>(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Effective registry: operator entries, then the fetched list, then the
+/// builtin table; the first layer naming a model wins.
+#[must_use]
+pub fn load(db: &DbPool) -> Vec<ModelDeprecation> {
+    let builtin = BUILTIN
+        .iter()
+        .map(|(model, replacement, retires_on)| ModelDeprecation {
+            model: (*model).to_string(),
+            replacement: (*replacement).to_string(),
+            retires_on: (*retires_on).to_string(),
+            note: None,
+        });
+    let mut merged: Vec<ModelDeprecation> = Vec::new();
+    for entry in load_key(db, settings_keys::MODEL_DEPRECATIONS)
+        .into_iter()
+        .chain(load_key(db, settings_keys::MODEL_DEPRECATIONS_REMOTE))
+        .chain(builtin)
+    {
+        if !merged.iter().any(|m| m.model == entry.model) {
+            merged.push(entry);
+        }
+    }
+    merged
+}
+
+/// Final replacement for `model`, following chains (A → B → C) with a hop
+/// limit so a cyclic registry cannot loop. Returns the first entry hit, which
+/// carries the retirement date that applies to `model` itself.
+#[must_use]
+pub fn resolve<'a>(
+    entries: &'a [ModelDeprecation],
+    model: &str,
+) -> Option<(&'a ModelDeprecation, String)> {
+    let first = entries.iter().find(|e| e.model == model)?;
+    let mut target = first.replacement.clone();
+    for _ in 0..entries.len() {
+        match entries.iter().find(|e| e.model == target) {
+            Some(next) if next.replacement != model => target = next.replacement.clone(),
+            _ => break,
+        }
+    }
+    Some((first, target))
+}
+
+fn is_retired(entry: &ModelDeprecation, today: NaiveDate) -> bool {
+    NaiveDate::parse_from_str(&entry.retires_on, "%Y-%m-%d").is_ok_and(|d| d <= today)
+}
+
+/// Check the profile's model against the registry. A retired model is
+/// replaced in place; either way a warning for the run log is returned.
+pub fn apply(db: &DbPool, profile: &mut ModelProfile) -> Option<String> {
+    let model = profile.model.clone()?;
+    let entries = load(db);
+    let (entry, replacement) = resolve(&entries, &model)?;
+    if is_retired(entry, chrono::Utc::now().date_naive()) {
+        profile.model = Some(replacement.clone());
+        Some(format!(
+            "Model '{model}' was retired on {}; running with '{replacement}' instead. \
+             Run the model profile migration to update saved personas.",
+            entry.retires_on
+        ))
+    } else {
+        Some(format!(
+            "Model '{model}' is deprecated and retires on {}; switch to '{replacement}'.",
+            entry.retires_on
+        ))
+    }
+}
+
+/// Fetch and validate the published deprecation list.
+pub async fn fetch_remote() -> Result<Vec<ModelDeprecation>, String> {
+    let client = reqwest::Client::builder()
+        .connect_timeout(REQUEST_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(format!("PersonasDesktop/{}", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("client build failed: {e}"))?;
+    let resp = client
+        .get(REGISTRY_URL)
+        .send()
+        .await
+        .map_err(|e| format!("fetch failed: {e}"))?;
+    if !resp.status().is_success() {
+        return Err(format!("unexpected status {}", resp.status()));
+    }
+    let bytes = resp
+        .bytes()
+        .await
+        .map_err(|e| format!("read body failed: {e}"))?;
+    if bytes.len() > MAX_PAYLOAD_BYTES {
+        return Err(format!(
+            "payload too large: {} bytes (max {MAX_PAYLOAD_BYTES})",
+            bytes.len()
+        ));
+    }
+    let registry: RemoteRegistry =
+        serde_json::from_slice(&bytes).map_err(|e| format!("parse failed: {e}"))?;
+    if registry.schema_version != SCHEMA_VERSION {
+        return Err(format!(
+            "unsupported schema_version {}, expected {SCHEMA_VERSION}",
+            registry.schema_version
+        ));
+    }
+    let diags = validate(&registry.deprecations);
+    if !diags.is_empty() {
+        return Err(diags.join("; "));
+    }
+    Ok(registry.deprecations)
+}
+
+/// Rewrite a stored `model_profile` JSON to the replacement model, keeping
+/// every other field. `None` when the profile names no deprecated model.
+#[must_use]
+pub fn migrate_profile_json(
+    entries: &[ModelDeprecation],
+    json: &str,
+) -> Option<(String, String, String)> {
+    let mut value: serde_json::Value = serde_json::from_str(json).ok()?;
+    let model = value.get("model")?.as_str()?.to_string();
+    let (_, replacement) = resolve(entries, &model)?;
+    value["model"] = serde_json::Value::String(replacement.clone());
+    Some((value.to_string(), model, replacement))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dep(model: &str, replacement: &str, retires_on: &str) -> ModelDeprecation {
+        ModelDeprecation {
+            model: model.into(),
+            replacement: replacement.into(),
+            retires_on: retires_on.into(),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn resolve_follows_chains_and_stops_on_cycles() {
+        let entries = vec![
+            dep("a", "b", "2025-01-01"),
+            dep("b", "c", "2026-01-01"),
+            dep("x", "y", "2025-01-01"),
+            dep("y", "x", "2025-01-01"),
+        ];
+        let (entry, target) = resolve(&entries, "a").unwrap();
+        assert_eq!(
+            (entry.retires_on.as_str(), target.as_str()),
+            ("2025-01-01", "c")
+        );
+        assert_eq!(resolve(&entries, "x").unwrap().1, "y");
+        assert!(resolve(&entries, "c").is_none());
+
+        let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        assert!(is_retired(&entries[0], today));
+        assert!(!is_retired(&entries[1], today));
+
+        let (json, from, to) =
+            migrate_profile_json(&entries, r#"{"model":"a","effort":"high"}"#).unwrap();
+        assert_eq!((from.as_str(), to.as_str()), ("a", "c"));
+        assert!(json.contains(r#""effort":"high""#) && json.contains(r#""model":"c""#));
+        assert!(migrate_profile_json(&entries, r#"{"model":"c"}"#).is_none());
+    }
+}
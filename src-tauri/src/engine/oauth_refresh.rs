@@ -602,6 +602,15 @@ async fn refresh_single_credential_inner(
         "oauth_token_expires_at".to_string(),
         serde_json::json!(new_expiry),
     );
+    // The credential as a whole expires with its refresh token; track it so
+    // the expiry scan can warn before re-authorization is required.
+    if let Some(secs) = resolved.refresh_token_expires_in_secs {
+        let refresh_expiry = chrono::Utc::now() + chrono::Duration::seconds(secs as i64);
+        patch.insert(
+            "expires_at".to_string(),
+            serde_json::json!(refresh_expiry.to_rfc3339()),
+        );
+    }
     // Clear any previous revocation flag on successful refresh
     patch.insert("needs_reauth".to_string(), serde_json::Value::Null);
     patch.insert("needs_reauth_at".to_string(), serde_json::Value::Null);
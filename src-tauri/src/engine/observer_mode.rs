@@ -0,0 +1,172 @@
+//! Read-only observer mode for screen sharing and demos.
+//!
+//! While observer mode is on:
+//!   1. Every IPC command that isn't recognisably a read is rejected in
+//!      `ipc_auth::wrap_invoke_handler` before dispatch. Reads are recognised
+//!      by name (`list_*`, `get_*`, `*_status`, …), so a command that doesn't
+//!      follow the naming convention fails closed rather than slipping through.
+//!   2. Read results have credential names/values masked and strings matching
+//!      the configured [`ObserverModeConfig::redact_patterns`] replaced. Tauri
+//!      has no hook to rewrite a command's response after dispatch, so this
+//!      runs in the frontend invoke wrapper (`src/lib/observerMode.ts`); the
+//!      patterns are validated here so a broken one is rejected at write time
+//!      instead of silently redacting nothing.
+//!
+//! The config is persisted under [`OBSERVER_MODE_KEY`], so a demo doesn't drop
+//! out of observer mode because the app was relaunched.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::DbPool;
+
+/// Settings key storing the JSON-encoded [`ObserverModeConfig`].
+pub const OBSERVER_MODE_KEY: &str = "observer_mode";
+
+/// Upper bound on configured patterns, so a pasted customer list can't turn
+/// every IPC response into thousands of regex passes.
+const MAX_PATTERNS: usize = 100;
+
+/// Command-name shapes treated as reads.
+const READ_PREFIXES: &[&str] = &["list_", "get_", "search_", "count_", "fetch_"];
+const READ_INFIXES: &[&str] = &["_get_", "_list_", "_search_"];
+const READ_SUFFIXES: &[&str] = &["_status", "_stats"];
+
+/// Mutating commands that stay callable so observer mode can be switched off.
+const ALWAYS_ALLOWED: &[&str] = &["set_observer_mode"];
+
+/// Process-level switch, mirrored from the persisted config at startup and on
+/// every write so the IPC gate never touches the database.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ObserverModeConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Regular expressions for customer-identifying strings (names, emails,
+    /// domains). Matched case-insensitively.
+    #[serde(default)]
+    pub redact_patterns: Vec<String>,
+}
+
+/// Whether observer mode is currently on.
+#[must_use]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Make `config` the live observer-mode state.
+pub fn apply(config: &ObserverModeConfig) {
+    ENABLED.store(config.enabled, Ordering::Relaxed);
+}
+
+/// Load the persisted config (observer mode off when unset or malformed).
+#[must_use]
+pub fn load(db: &DbPool) -> ObserverModeConfig {
+    crate::db::repos::core::settings::get(db, OBSERVER_MODE_KEY)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Validate a config, returning human-readable diagnostics (empty = valid).
+#[must_use]
+pub fn validate(config: &ObserverModeConfig) -> Vec<String> {
+    let mut diags = Vec::new();
+    if config.redact_patterns.len() > MAX_PATTERNS {
+        diags.push(format!(
+            "at most {MAX_PATTERNS} redaction patterns are allowed (got {})",
+            config.redact_patterns.len()
+        ));
+    }
+    for (i, pattern) in config.redact_patterns.iter().enumerate() {
+        if pattern.trim().is_empty() {
+            diags.push(format!("pattern {i}: must not be empty"));
+            continue;
+        }
+        match Regex::new(pattern) {
+            Err(e) => diags.push(format!("pattern {i}: invalid regular expression: {e}")),
+            // A pattern that matches the empty string would splice the marker
+            // between every character of every response.
+            Ok(re) if re.is_match("") => {
+                diags.push(format!("pattern {i}: must not match an empty string"))
+            }
+            Ok(_) => {}
+        }
+    }
+    diags
+}
+
+/// Settings-store validator for [`OBSERVER_MODE_KEY`].
+pub fn validate_setting(value: &str) -> Result<(), String> {
+    let config: ObserverModeConfig = serde_json::from_str(value)
+        .map_err(|e| format!("value for '{OBSERVER_MODE_KEY}' is not a valid config: {e}"))?;
+    let diags = validate(&config);
+    if diags.is_empty() {
+        Ok(())
+    } else {
+        Err(diags.join("; "))
+    }
+}
+
+/// Whether `command` only reads state, judged by its name.
+#[must_use]
+pub fn is_read_command(command: &str) -> bool {
+    READ_PREFIXES.iter().any(|p| command.starts_with(p))
+        || READ_INFIXES.iter().any(|p| command.contains(p))
+        || READ_SUFFIXES.iter().any(|s| command.ends_with(s))
+}
+
+/// Whether observer mode must reject `command` before dispatch.
+#[must_use]
+pub fn blocks(command: &str) -> bool {
+    enabled() && !ALWAYS_ALLOWED.contains(&command) && !is_read_command(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_pass_and_everything_else_is_frozen() {
+        for read in [
+            "list_credentials",
+            "get_pipeline_run",
+            "dev_tools_get_git_status",
+            "cloud_status",
+            "credential_usage_stats",
+        ] {
+            assert!(is_read_command(read), "{read}");
+        }
+        for write in [
+            "create_credential",
+            "execute_persona",
+            "delete_team",
+            "set_app_setting",
+            "retry_pipeline_node",
+            "status_reset",
+        ] {
+            assert!(!is_read_command(write), "{write}");
+        }
+        assert!(ALWAYS_ALLOWED.iter().all(|c| !is_read_command(c)));
+
+        let valid = ObserverModeConfig {
+            enabled: true,
+            redact_patterns: vec!["acme(corp)?".into(), r"[\w.]+@example\.com".into()],
+        };
+        assert!(validate(&valid).is_empty());
+        let invalid = ObserverModeConfig {
+            enabled: true,
+            redact_patterns: vec!["(unclosed".into(), " ".into(), "a*".into()],
+        };
+        assert_eq!(validate(&invalid).len(), 3);
+        assert!(validate_setting(&serde_json::to_string(&valid).unwrap()).is_ok());
+        assert!(validate_setting("{\"enabled\": true, \"redactPatterns\": [\"(\"]}").is_err());
+    }
+}
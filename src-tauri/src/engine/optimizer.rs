@@ -3,7 +3,10 @@ use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::db::models::{PersonaTeamConnection, PersonaTeamMember, PipelineRun};
+use crate::db::models::{Persona, PersonaTeamConnection, PersonaTeamMember, PipelineRun};
+use crate::db::repos::execution::{executions as exec_repo, knowledge as knowledge_repo};
+use crate::db::DbPool;
+use crate::error::AppError;
 
 // ============================================================================
 // Analytics types
@@ -453,3 +456,190 @@ fn topological_order(
     order.extend(result.cycle_nodes);
     order
 }
+
+// ============================================================================
+// Pre-admission cost estimation
+// ============================================================================
+
+/// Minimum number of historical runs behind a `cost_quality` baseline before
+/// the estimator trusts it. Below this the EMA is dominated by its seed value
+/// and a single expensive first run would block the persona for the month.
+const MIN_COST_SAMPLES: i64 = 3;
+
+/// Predicted cost of one execution and where it would leave the persona's
+/// monthly budget. Produced before the execution record exists so the budget
+/// gate can refuse a run that would overshoot, instead of only noticing after
+/// the spend happened.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct CostEstimate {
+    pub persona_id: String,
+    pub use_case_id: Option<String>,
+    /// Predicted cost of a single run (USD). `0.0` when there is no trusted
+    /// history yet.
+    pub estimated_cost_usd: f64,
+    /// Which baseline produced the estimate: `"use_case"` (this capability's
+    /// own history), `"persona"` (sample-weighted mean across the persona's
+    /// baselines) or `"none"` (not enough history).
+    pub basis: String,
+    /// Historical runs behind the chosen baseline.
+    #[ts(type = "number")]
+    pub sample_count: i64,
+    /// Spend so far this calendar month (USD), same figure as the budget UI.
+    pub monthly_spend_usd: f64,
+    /// The persona's monthly cap. `None` or `0.0` means unlimited.
+    pub budget_usd: Option<f64>,
+    /// `monthly_spend_usd + estimated_cost_usd`.
+    pub projected_spend_usd: f64,
+    /// True when admitting this run would meet or cross the budget.
+    pub would_exceed_budget: bool,
+}
+
+/// Pick the cost baseline for a run from the persona's `cost_quality` rows
+/// (`(pattern_key, avg_cost_usd, sample_count)`). Prefers the capability's own
+/// history; otherwise falls back to a sample-weighted mean across every
+/// baseline the persona has. Returns `(estimate, basis, samples)`.
+pub(crate) fn pick_cost_baseline(
+    baselines: &[(String, f64, i64)],
+    use_case_id: Option<&str>,
+) -> (f64, &'static str, i64) {
+    if let Some(uc) = use_case_id {
+        let key = format!("use_case:{uc}");
+        if let Some((_, avg, n)) = baselines.iter().find(|(k, _, _)| *k == key) {
+            if *n >= MIN_COST_SAMPLES {
+                return (avg.max(0.0), "use_case", *n);
+            }
+        }
+    }
+
+    let total: i64 = baselines.iter().map(|(_, _, n)| (*n).max(0)).sum();
+    if total < MIN_COST_SAMPLES {
+        return (0.0, "none", total);
+    }
+    let weighted: f64 = baselines
+        .iter()
+        .map(|(_, avg, n)| avg.max(0.0) * (*n).max(0) as f64)
+        .sum();
+    (weighted / total as f64, "persona", total)
+}
+
+/// The budget decision shared by every admission path. Mirrors the
+/// `budget > 0.0` convention of the post-spend gate (`0.0` / `None` mean
+/// unlimited), and additionally refuses a run whose *predicted* cost would
+/// carry spend past the cap.
+pub(crate) fn exceeds_budget(budget: Option<f64>, monthly_spend: f64, estimate: f64) -> bool {
+    match budget {
+        Some(b) if b > 0.0 => monthly_spend >= b || monthly_spend + estimate > b,
+        _ => false,
+    }
+}
+
+/// Estimate the cost of running `persona` (optionally scoped to a capability)
+/// and project it onto the persona's monthly budget.
+pub fn estimate_execution_cost(
+    pool: &DbPool,
+    persona: &Persona,
+    use_case_id: Option<&str>,
+) -> Result<CostEstimate, AppError> {
+    let baselines = knowledge_repo::get_cost_baselines(pool, &persona.id)?;
+    let (estimated_cost_usd, basis, sample_count) = pick_cost_baseline(&baselines, use_case_id);
+    let monthly_spend_usd = exec_repo::get_monthly_spend(pool, &persona.id)?;
+
+    Ok(CostEstimate {
+        persona_id: persona.id.clone(),
+        use_case_id: use_case_id.map(str::to_string),
+        estimated_cost_usd,
+        basis: basis.to_string(),
+        sample_count,
+        monthly_spend_usd,
+        budget_usd: persona.max_budget_usd,
+        projected_spend_usd: monthly_spend_usd + estimated_cost_usd,
+        would_exceed_budget: exceeds_budget(
+            persona.max_budget_usd,
+            monthly_spend_usd,
+            estimated_cost_usd,
+        ),
+    })
+}
+
+/// Pre-admission budget gate. Returns the estimate when the run fits, or a
+/// `Validation` error naming the spend, the prediction, and the cap when it
+/// would not. The persona's workspace cap is checked against the same
+/// estimate. Personas with neither a positive budget nor a workspace skip the
+/// DB work entirely, and the `warn` enforcement mode (see `engine::budget`)
+/// never refuses.
+pub fn check_cost_admission(
+    pool: &DbPool,
+    persona: &Persona,
+    use_case_id: Option<&str>,
+) -> Result<Option<CostEstimate>, AppError> {
+    let has_persona_cap = matches!(persona.max_budget_usd, Some(b) if b > 0.0);
+    if !has_persona_cap && persona.home_team_id.is_none() {
+        return Ok(None);
+    }
+    let estimate = estimate_execution_cost(pool, persona, use_case_id)?;
+    // Workspace cap first: it bounds the whole team, so it wins over a persona
+    // that still has headroom of its own.
+    super::budget::check_workspace_admission(pool, persona, estimate.estimated_cost_usd)?;
+    if !estimate.would_exceed_budget
+        || !super::budget::enforcement_mode(pool).blocks_admission()
+    {
+        return Ok(Some(estimate));
+    }
+
+    let budget = estimate.budget_usd.unwrap_or(0.0);
+    if estimate.monthly_spend_usd >= budget {
+        Err(AppError::Validation(format!(
+            "Budget limit exceeded for '{}': ${:.2} spent this month, limit is ${:.2}",
+            persona.name, estimate.monthly_spend_usd, budget
+        )))
+    } else {
+        Err(AppError::Validation(format!(
+            "Budget limit would be exceeded for '{}': ${:.2} spent this month plus an estimated ${:.2} for this run exceeds the ${:.2} limit",
+            persona.name, estimate.monthly_spend_usd, estimate.estimated_cost_usd, budget
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(key: &str, avg: f64, n: i64) -> (String, f64, i64) {
+        (key.to_string(), avg, n)
+    }
+
+    #[test]
+    fn use_case_baseline_preferred_when_trusted() {
+        let rows = vec![row("use_case:uc1", 0.40, 5), row("overall", 0.10, 20)];
+        let (est, basis, n) = pick_cost_baseline(&rows, Some("uc1"));
+        assert_eq!(basis, "use_case");
+        assert_eq!(n, 5);
+        assert!((est - 0.40).abs() < 1e-9);
+    }
+
+    #[test]
+    fn thin_use_case_history_falls_back_to_weighted_persona_mean() {
+        let rows = vec![row("use_case:uc1", 5.0, 1), row("overall", 1.0, 3)];
+        let (est, basis, n) = pick_cost_baseline(&rows, Some("uc1"));
+        assert_eq!(basis, "persona");
+        assert_eq!(n, 4);
+        assert!((est - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn no_history_estimates_zero() {
+        let (est, basis, _) = pick_cost_baseline(&[row("overall", 3.0, 1)], None);
+        assert_eq!(basis, "none");
+        assert_eq!(est, 0.0);
+    }
+
+    #[test]
+    fn budget_gate_counts_the_predicted_run() {
+        assert!(!exceeds_budget(None, 100.0, 5.0));
+        assert!(!exceeds_budget(Some(0.0), 100.0, 5.0));
+        assert!(!exceeds_budget(Some(10.0), 4.0, 5.0));
+        assert!(exceeds_budget(Some(10.0), 6.0, 5.0));
+        assert!(exceeds_budget(Some(10.0), 10.0, 0.0));
+    }
+}
@@ -0,0 +1,196 @@
+//! Structured output contracts.
+//!
+//! A persona can carry a JSON Schema its final output must satisfy, stored
+//! under `output_contract:<persona_id>` as
+//! `{"schema": {...}, "on_violation": "repair" | "fail"}`. The runner states
+//! the contract in the prompt and validates the output of a successful run:
+//!
+//! - `repair` (the default): the run's status is held back and the CLI
+//!   session is resumed once with the validation errors
+//!   (see [`repair_input`]); a second violation fails the run.
+//! - `fail`: the run fails straight away.
+//!
+//! A failed run's error starts with [`VIOLATION_PREFIX`], which the error
+//! taxonomy classifies as `ContractViolation` so healing files a prompt issue
+//! instead of retrying a run that would produce the same output.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+
+/// Leading token of every contract-violation error message.
+pub const VIOLATION_PREFIX: &str = "contract_violation";
+
+/// Input key carrying the validation errors into the repair turn.
+pub const REPAIR_KEY: &str = "_contract_repair";
+
+/// Schema errors listed in an error message or repair prompt.
+const MAX_REPORTED_ERRORS: usize = 5;
+
+/// What happens when a run's output violates the contract.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViolationMode {
+    /// Resume the session once with the validation errors.
+    #[default]
+    Repair,
+    /// Fail the run.
+    Fail,
+}
+
+/// A persona's output contract as stored in settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputContract {
+    pub schema: Value,
+    #[serde(default)]
+    pub on_violation: ViolationMode,
+}
+
+/// Parse a stored contract, rejecting values whose schema does not compile.
+pub fn parse(raw: &str) -> Result<OutputContract, String> {
+    let contract: OutputContract =
+        serde_json::from_str(raw).map_err(|e| format!("invalid output contract: {e}"))?;
+    jsonschema::validator_for(&contract.schema)
+        .map_err(|e| format!("output contract schema is not a valid JSON Schema: {e}"))?;
+    Ok(contract)
+}
+
+/// The persona's contract, if one is set. A stored value that no longer
+/// parses is logged and ignored rather than failing every run.
+pub fn load(pool: &DbPool, persona_id: &str) -> Option<OutputContract> {
+    let key = format!("{}{persona_id}", settings_keys::OUTPUT_CONTRACT_PREFIX);
+    let raw = settings::get(pool, &key).ok().flatten()?;
+    match parse(&raw) {
+        Ok(contract) => Some(contract),
+        Err(e) => {
+            tracing::warn!(persona_id = %persona_id, "Ignoring output contract: {e}");
+            None
+        }
+    }
+}
+
+/// The JSON document in a run's output: the whole text when it is JSON,
+/// otherwise the last fenced ```json block, otherwise the first embedded
+/// object.
+pub fn extract_json(output: &str) -> Option<Value> {
+    let trimmed = output.trim();
+    if let Ok(value) = serde_json::from_str(trimmed) {
+        return Some(value);
+    }
+    let fenced = trimmed
+        .split("```json")
+        .skip(1)
+        .filter_map(|block| block.split("```").next())
+        .filter_map(|body| serde_json::from_str(body.trim()).ok())
+        .last();
+    fenced.or_else(|| {
+        let obj = super::safe_json::extract_balanced_object(trimmed)?;
+        serde_json::from_str(obj).ok()
+    })
+}
+
+impl OutputContract {
+    pub fn repairs(&self) -> bool {
+        self.on_violation == ViolationMode::Repair
+    }
+
+    /// Validation errors of `output` against the schema; empty when it
+    /// conforms.
+    pub fn violations(&self, output: &str) -> Vec<String> {
+        let validator = match jsonschema::validator_for(&self.schema) {
+            Ok(v) => v,
+            Err(e) => return vec![format!("contract schema does not compile: {e}")],
+        };
+        let Some(value) = extract_json(output) else {
+            return vec!["output contains no JSON document".into()];
+        };
+        validator
+            .iter_errors(&value)
+            .map(|err| {
+                let path = err.instance_path.to_string();
+                if path.is_empty() {
+                    err.to_string()
+                } else {
+                    format!("{path}: {err}")
+                }
+            })
+            .collect()
+    }
+
+    /// Prompt section stating the contract.
+    pub fn prompt_block(&self) -> String {
+        let schema =
+            serde_json::to_string_pretty(&self.schema).unwrap_or_else(|_| self.schema.to_string());
+        format!(
+            "\n\n## Output Contract\n\nEnd your response with your final result as a single \
+             ```json fenced block. It is validated against this JSON Schema and the run fails \
+             if it does not conform:\n```json\n{schema}\n```\n"
+        )
+    }
+}
+
+fn summarize(violations: &[String]) -> String {
+    if violations.len() <= MAX_REPORTED_ERRORS {
+        violations.join("; ")
+    } else {
+        format!(
+            "{}; ... and {} more errors",
+            violations[..MAX_REPORTED_ERRORS].join("; "),
+            violations.len() - MAX_REPORTED_ERRORS
+        )
+    }
+}
+
+/// Error message of a run failed by its contract.
+pub fn violation_message(violations: &[String]) -> String {
+    format!(
+        "{VIOLATION_PREFIX}: output does not conform to the persona's output contract: {}",
+        summarize(violations)
+    )
+}
+
+/// Input of the repair turn, delivered with the session resume.
+pub fn repair_input(violations: &[String]) -> Value {
+    serde_json::json!({
+        REPAIR_KEY: {
+            "instruction": "Your final output did not conform to the output contract. \
+                Reply with the corrected result as a single ```json fenced block and nothing else.",
+            "errors": violations.iter().take(MAX_REPORTED_ERRORS).collect::<Vec<_>>(),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contract_extracts_and_validates_output() {
+        assert!(parse(r#"{"schema": {"type": 12}}"#).is_err());
+        let contract = parse(
+            r#"{"schema": {"type": "object", "required": ["score"],
+                "properties": {"score": {"type": "integer"}}}}"#,
+        )
+        .unwrap();
+        assert!(contract.repairs());
+
+        let fenced = "Done.\n```json\n{\"draft\": 1}\n```\nFinal:\n```json\n{\"score\": 7}\n```";
+        assert!(contract.violations(fenced).is_empty());
+        assert!(contract.violations(r#"{"score": 7}"#).is_empty());
+
+        let bad = contract.violations("Result: {\"score\": \"high\"}");
+        assert_eq!(bad.len(), 1);
+        assert!(bad[0].starts_with("/score"));
+        assert_eq!(
+            contract.violations("no json here"),
+            vec!["output contains no JSON document".to_string()]
+        );
+
+        let message = violation_message(&bad);
+        assert!(message.starts_with(VIOLATION_PREFIX));
+        assert_eq!(repair_input(&bad)[REPAIR_KEY]["errors"][0], bad[0].as_str());
+    }
+}
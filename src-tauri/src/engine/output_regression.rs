@@ -0,0 +1,467 @@
+//! Run-over-run output regression check for scheduled personas.
+//!
+//! Data-pipeline personas on a schedule should produce much the same output
+//! every run; when one breaks silently (an upstream API changes shape, a query
+//! starts returning nothing) the run still completes. A schedule trigger whose
+//! config carries
+//!
+//! ```json
+//! { "output_regression": { "threshold": 0.3, "ignore_paths": ["generated_at"] } }
+//! ```
+//!
+//! has each completed run's structured (JSON) output compared with the
+//! trigger's previous completed run. Both are flattened to leaf paths (arrays
+//! contribute their length plus the shape of their first element, whose
+//! values are not compared). A path counts as changed when it appears or
+//! disappears, changes type, becomes null or empty, or — for numbers and
+//! array lengths — moves by more than `threshold` relative to its previous
+//! value. Text is expected to vary and is not compared. When the changed share
+//! of paths reaches `threshold`, a manual review is created with the diff
+//! attached.
+//!
+//! Best-effort like the report sink: failures are logged, never surfaced on
+//! the execution.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::safe_json;
+use crate::db::models::CreateManualReviewInput;
+use crate::db::repos::communication::manual_reviews;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::resources::triggers as trigger_repo;
+use crate::db::DbPool;
+
+/// Trigger config key enabling the check.
+pub const TRIGGER_CONFIG_KEY: &str = "output_regression";
+
+const DEFAULT_THRESHOLD: f64 = 0.3;
+/// Changes attached to the review; the score still counts all of them.
+const MAX_REPORTED_CHANGES: usize = 50;
+/// Longest text value quoted in a change.
+const MAX_VALUE_CHARS: usize = 120;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct RegressionConfig {
+    /// Share of changed paths (and relative numeric move) that counts as a
+    /// regression, in `(0, 1]`.
+    pub threshold: f64,
+    /// Path prefixes never compared (timestamps, run ids, ...).
+    pub ignore_paths: Vec<String>,
+}
+
+impl Default for RegressionConfig {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+            ignore_paths: Vec::new(),
+        }
+    }
+}
+
+/// The trigger's regression config: `None` when the key is absent or null.
+pub fn config_from_trigger(config: Option<&str>) -> Option<Result<RegressionConfig, String>> {
+    let raw = config
+        .and_then(|c| serde_json::from_str::<Value>(c).ok())
+        .and_then(|v| v.get(TRIGGER_CONFIG_KEY).cloned())
+        .filter(|v| !v.is_null())?;
+    Some(
+        serde_json::from_value::<RegressionConfig>(raw)
+            .map_err(|e| format!("{TRIGGER_CONFIG_KEY} is malformed: {e}"))
+            .and_then(|c| {
+                if c.threshold > 0.0 && c.threshold <= 1.0 {
+                    Ok(c)
+                } else {
+                    Err(format!("{TRIGGER_CONFIG_KEY}.threshold must be in (0, 1]"))
+                }
+            }),
+    )
+}
+
+/// One path that moved between runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PathChange {
+    pub path: String,
+    /// `added` | `removed` | `type_changed` | `emptied` | `numeric_shift` | `length_shift`
+    pub kind: &'static str,
+    pub previous: Option<Value>,
+    pub current: Option<Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OutputDiff {
+    /// Paths present in either run.
+    pub compared_paths: usize,
+    pub changes: Vec<PathChange>,
+}
+
+impl OutputDiff {
+    /// Share of compared paths that changed, `0.0..=1.0`.
+    pub fn score(&self) -> f64 {
+        if self.compared_paths == 0 {
+            0.0
+        } else {
+            self.changes.len() as f64 / self.compared_paths as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Leaf {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    Array(usize),
+    EmptyObject,
+}
+
+impl Leaf {
+    fn kind(&self) -> &'static str {
+        match self {
+            Leaf::Null => "null",
+            Leaf::Bool(_) => "bool",
+            Leaf::Number(_) => "number",
+            Leaf::Text(_) => "text",
+            Leaf::Array(_) => "array",
+            Leaf::EmptyObject => "object",
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Leaf::Null => Value::Null,
+            Leaf::Bool(b) => json!(b),
+            Leaf::Number(n) => json!(n),
+            Leaf::Text(s) => json!(s.chars().take(MAX_VALUE_CHARS).collect::<String>()),
+            Leaf::Array(n) => json!(format!("[{n} items]")),
+            Leaf::EmptyObject => json!({}),
+        }
+    }
+}
+
+fn flatten(value: &Value, path: &str, out: &mut BTreeMap<String, Leaf>) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+    match value {
+        Value::Object(map) if map.is_empty() => {
+            out.insert(path.to_string(), Leaf::EmptyObject);
+        }
+        Value::Object(map) => {
+            for (k, v) in map {
+                flatten(v, &child(k), out);
+            }
+        }
+        Value::Array(items) => {
+            out.insert(path.to_string(), Leaf::Array(items.len()));
+            if let Some(first @ (Value::Object(_) | Value::Array(_))) = items.first() {
+                flatten(first, &format!("{path}[]"), out);
+            }
+        }
+        Value::Null => {
+            out.insert(path.to_string(), Leaf::Null);
+        }
+        Value::Bool(b) => {
+            out.insert(path.to_string(), Leaf::Bool(*b));
+        }
+        Value::Number(n) => {
+            out.insert(path.to_string(), Leaf::Number(n.as_f64().unwrap_or(0.0)));
+        }
+        Value::String(s) => {
+            out.insert(path.to_string(), Leaf::Text(s.clone()));
+        }
+    }
+}
+
+fn relative_change(previous: f64, current: f64) -> f64 {
+    if previous == 0.0 {
+        if current == 0.0 {
+            0.0
+        } else {
+            1.0
+        }
+    } else {
+        ((current - previous) / previous).abs()
+    }
+}
+
+/// `shape_only` paths sit inside an array's sampled first element, whose
+/// values are arbitrary from run to run — only a type change counts there.
+fn compare(
+    previous: &Leaf,
+    current: &Leaf,
+    threshold: f64,
+    shape_only: bool,
+) -> Option<&'static str> {
+    if shape_only {
+        let nullish = matches!(previous, Leaf::Null) || matches!(current, Leaf::Null);
+        return (!nullish && previous.kind() != current.kind()).then_some("type_changed");
+    }
+    match (previous, current) {
+        (Leaf::Number(a), Leaf::Number(b)) => {
+            (relative_change(*a, *b) > threshold).then_some("numeric_shift")
+        }
+        (Leaf::Array(a), Leaf::Array(0)) if *a > 0 => Some("emptied"),
+        (Leaf::Array(a), Leaf::Array(b)) => {
+            (relative_change(*a as f64, *b as f64) > threshold).then_some("length_shift")
+        }
+        (Leaf::Text(a), Leaf::Text(b)) if !a.is_empty() && b.is_empty() => Some("emptied"),
+        (Leaf::Null, Leaf::Null)
+        | (Leaf::Bool(_), Leaf::Bool(_))
+        | (Leaf::Text(_), Leaf::Text(_)) => None,
+        (Leaf::EmptyObject, Leaf::EmptyObject) => None,
+        (_, Leaf::Null) => Some("emptied"),
+        _ => Some("type_changed"),
+    }
+}
+
+/// Diff two structured outputs. `current` is `None` when the run produced no
+/// parseable JSON, which counts every previous path as removed.
+pub fn diff(previous: &Value, current: Option<&Value>, config: &RegressionConfig) -> OutputDiff {
+    let ignored = |path: &str| {
+        config.ignore_paths.iter().any(|p| {
+            path == p || path.starts_with(&format!("{p}.")) || path.starts_with(&format!("{p}["))
+        })
+    };
+    let mut before = BTreeMap::new();
+    flatten(previous, "", &mut before);
+    let mut after = BTreeMap::new();
+    if let Some(current) = current {
+        flatten(current, "", &mut after);
+    }
+    before.retain(|p, _| !ignored(p));
+    after.retain(|p, _| !ignored(p));
+
+    let mut result = OutputDiff::default();
+    for (path, prev) in &before {
+        result.compared_paths += 1;
+        let change = match after.get(path) {
+            None => Some("removed"),
+            Some(cur) => compare(prev, cur, config.threshold, path.contains("[]")),
+        };
+        if let Some(kind) = change {
+            result.changes.push(PathChange {
+                path: path.clone(),
+                kind,
+                previous: Some(prev.to_value()),
+                current: after.get(path).map(Leaf::to_value),
+            });
+        }
+    }
+    for (path, cur) in after.iter().filter(|(p, _)| !before.contains_key(*p)) {
+        result.compared_paths += 1;
+        result.changes.push(PathChange {
+            path: path.clone(),
+            kind: "added",
+            previous: None,
+            current: Some(cur.to_value()),
+        });
+    }
+    result
+}
+
+fn structured(output: Option<&str>) -> Option<Value> {
+    let value = safe_json::parse_lenient_json::<Value>(output?).ok()?;
+    matches!(value, Value::Object(_) | Value::Array(_)).then_some(value)
+}
+
+fn describe(change: &PathChange) -> String {
+    let show = |v: &Option<Value>| v.as_ref().map_or("—".to_string(), Value::to_string);
+    let path = if change.path.is_empty() {
+        "(root)"
+    } else {
+        &change.path
+    };
+    format!(
+        "- `{path}` {}: {} → {}",
+        change.kind.replace('_', " "),
+        show(&change.previous),
+        show(&change.current)
+    )
+}
+
+/// Post-completion hook: compare the run with the trigger's previous completed
+/// run and open a review when the delta crosses the trigger's threshold.
+pub(crate) fn on_execution_complete(pool: &DbPool, persona_id: &str, exec_id: &str) {
+    let Ok(exec) = exec_repo::get_by_id(pool, exec_id) else {
+        return;
+    };
+    if exec.status != "completed" || exec.is_simulation {
+        return;
+    }
+    let Some(trigger_id) = exec.trigger_id.as_deref() else {
+        return;
+    };
+    let Ok(trigger) = trigger_repo::get_by_id(pool, trigger_id) else {
+        return;
+    };
+    if trigger.trigger_type != "schedule" {
+        return;
+    }
+    let config = match config_from_trigger(trigger.config.as_deref()) {
+        Some(Ok(config)) => config,
+        Some(Err(e)) => {
+            tracing::warn!(trigger_id, "Output regression check skipped: {e}");
+            return;
+        }
+        None => return,
+    };
+
+    let previous = exec_repo::get_by_trigger_id(pool, trigger_id, Some(10))
+        .unwrap_or_default()
+        .into_iter()
+        .find(|e| {
+            e.id != exec.id
+                && e.status == "completed"
+                && !e.is_simulation
+                && e.created_at < exec.created_at
+        })
+        .and_then(|e| exec_repo::get_by_id(pool, &e.id).ok());
+    let Some(previous) = previous else {
+        return;
+    };
+    let Some(previous_output) = structured(previous.output_data.as_deref()) else {
+        return;
+    };
+    let current_output = structured(exec.output_data.as_deref());
+
+    let diff = diff(&previous_output, current_output.as_ref(), &config);
+    let score = diff.score();
+    if score < config.threshold {
+        tracing::debug!(execution_id = %exec_id, score, "Output regression check passed");
+        return;
+    }
+
+    let summary = if current_output.is_none() {
+        "This run produced no structured output; the previous run did.".to_string()
+    } else {
+        format!(
+            "{} of {} output fields changed unexpectedly compared with the previous scheduled run.",
+            diff.changes.len(),
+            diff.compared_paths
+        )
+    };
+    let listed: Vec<String> = diff.changes.iter().take(10).map(describe).collect();
+    let more = diff.changes.len().saturating_sub(listed.len());
+    let description = format!(
+        "{summary}\n\n{}{}",
+        listed.join("\n"),
+        if more > 0 {
+            format!("\n- … and {more} more")
+        } else {
+            String::new()
+        }
+    );
+
+    let input = CreateManualReviewInput {
+        execution_id: exec.id.clone(),
+        persona_id: persona_id.to_string(),
+        title: format!("Output regression: {:.0}% of fields changed", score * 100.0),
+        description: Some(description),
+        severity: Some("warning".into()),
+        context_data: Some(
+            json!({
+                "source": "output_regression",
+                "trigger_id": trigger_id,
+                "previous_execution_id": previous.id,
+                "score": score,
+                "threshold": config.threshold,
+                "compared_paths": diff.compared_paths,
+                "changes": diff.changes.iter().take(MAX_REPORTED_CHANGES).collect::<Vec<_>>(),
+            })
+            .to_string(),
+        ),
+        suggested_actions: Some(
+            json!([
+                "Compare this run's output with the previous run",
+                "Check the persona's upstream data sources",
+                "Raise the trigger's output_regression threshold or ignore volatile paths"
+            ])
+            .to_string(),
+        ),
+        use_case_id: exec.use_case_id.clone(),
+        assignment_id: None,
+        step_id: None,
+    };
+    match manual_reviews::create(pool, input) {
+        Ok(_) => tracing::info!(
+            execution_id = %exec_id,
+            previous_execution_id = %previous.id,
+            score,
+            "Output regression flagged for review"
+        ),
+        Err(e) => tracing::warn!(
+            execution_id = %exec_id,
+            "Failed to create output regression review: {e}"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_flags_shape_and_volume_changes_but_not_text() {
+        let config = RegressionConfig {
+            ignore_paths: vec!["generated_at".into()],
+            ..Default::default()
+        };
+        let previous = json!({
+            "generated_at": "2026-10-15",
+            "summary": "12 new leads",
+            "total": 100,
+            "leads": [{"name": "a", "score": 1}, {"name": "b", "score": 2}],
+        });
+
+        let similar = json!({
+            "generated_at": "2026-10-16",
+            "summary": "14 new leads",
+            "total": 110,
+            "leads": [{"name": "c", "score": 3}, {"name": "d", "score": 2}],
+        });
+        assert!(diff(&previous, Some(&similar), &config).changes.is_empty());
+
+        let broken = json!({ "summary": "", "total": null, "leads": [] });
+        let d = diff(&previous, Some(&broken), &config);
+        let kinds: Vec<_> = d
+            .changes
+            .iter()
+            .map(|c| (c.path.as_str(), c.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("leads", "emptied"),
+                ("leads[].name", "removed"),
+                ("leads[].score", "removed"),
+                ("summary", "emptied"),
+                ("total", "emptied"),
+            ]
+        );
+        assert!(d.score() >= config.threshold);
+
+        assert_eq!(diff(&previous, None, &config).score(), 1.0);
+    }
+
+    #[test]
+    fn config_requires_threshold_in_range() {
+        assert_eq!(config_from_trigger(Some(r#"{"interval":60}"#)), None);
+        assert_eq!(
+            config_from_trigger(Some(r#"{"output_regression":{}}"#)),
+            Some(Ok(RegressionConfig::default()))
+        );
+        assert!(matches!(
+            config_from_trigger(Some(r#"{"output_regression":{"threshold":2}}"#)),
+            Some(Err(_))
+        ));
+    }
+}
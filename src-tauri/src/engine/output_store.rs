@@ -0,0 +1,179 @@
+//! Offloaded storage for large execution outputs.
+//!
+//! An output over [`EXECUTION_OUTPUT_OFFLOAD_BYTES`] is written gzip-compressed
+//! to `logs/<execution_id>.output.gz` next to the execution log. The row keeps
+//! a preview in `output_data` (so list queries and full-text search stay
+//! small) and a pointer in `output_ref`; `executions::get_by_id` reads the
+//! file back, so anything that loads a single execution sees the full text.
+//!
+//! Pointers carry a format version (`v1:<file name>`) so the on-disk format
+//! can change without stranding rows written by older builds. Offloaded files
+//! are swept with their rows by the retention pass (`engine::retention`).
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::db::models::PersonaExecution;
+use crate::db::repos::core::settings;
+use crate::db::settings_keys::{
+    EXECUTION_OUTPUT_OFFLOAD_BYTES, EXECUTION_OUTPUT_OFFLOAD_BYTES_DEFAULT,
+};
+use crate::db::DbPool;
+
+const FORMAT_V1: &str = "v1";
+/// File name suffix of offloaded outputs, after the execution id.
+pub const FILE_SUFFIX: &str = ".output.gz";
+/// Characters of the output kept inline as a preview.
+const PREVIEW_CHARS: usize = 4_000;
+
+/// Directory offloaded outputs live in — the execution log directory.
+pub fn output_dir() -> Option<PathBuf> {
+    crate::db::primary_db_path().and_then(|p| p.parent().map(|d| d.join("logs")))
+}
+
+/// Offload threshold from [`EXECUTION_OUTPUT_OFFLOAD_BYTES`]; `0` means never.
+pub fn threshold(pool: &DbPool) -> u64 {
+    settings::get(pool, EXECUTION_OUTPUT_OFFLOAD_BYTES)
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(EXECUTION_OUTPUT_OFFLOAD_BYTES_DEFAULT)
+}
+
+/// Move `output` to disk if it is over the threshold, leaving a preview in its
+/// place. Returns the pointer to store in `output_ref`. On any I/O failure the
+/// output simply stays inline.
+pub fn offload(pool: &DbPool, execution_id: &str, output: &mut Option<String>) -> Option<String> {
+    let text = output.as_deref()?;
+    let limit = threshold(pool);
+    if limit == 0 || text.len() as u64 <= limit {
+        return None;
+    }
+    let dir = output_dir()?;
+    match write(&dir, execution_id, text) {
+        Ok(pointer) => {
+            *output = Some(preview(text));
+            Some(pointer)
+        }
+        Err(e) => {
+            tracing::warn!(
+                execution_id = %execution_id,
+                error = %e,
+                "Failed to offload large execution output; keeping it inline",
+            );
+            None
+        }
+    }
+}
+
+/// Replace the preview with the full output when the row points at a file.
+/// A missing or unreadable file leaves the preview in place.
+pub fn hydrate(execution: &mut PersonaExecution) {
+    let (Some(pointer), Some(dir)) = (execution.output_ref.as_deref(), output_dir()) else {
+        return;
+    };
+    match read(&dir, pointer) {
+        Ok(full) => execution.output_data = Some(full),
+        Err(e) => tracing::warn!(
+            execution_id = %execution.id,
+            pointer = %pointer,
+            error = %e,
+            "Offloaded execution output unavailable; returning the preview",
+        ),
+    }
+}
+
+/// Remove an offloaded file that ended up unreferenced.
+pub fn discard(pointer: &str) {
+    if let (Some(dir), Ok(name)) = (output_dir(), file_name(pointer)) {
+        let _ = std::fs::remove_file(dir.join(name));
+    }
+}
+
+/// Compress `text` into `dir` for `execution_id` and return its pointer.
+fn write(dir: &Path, execution_id: &str, text: &str) -> io::Result<String> {
+    std::fs::create_dir_all(dir)?;
+    let name = format!("{execution_id}{FILE_SUFFIX}");
+    // Write-then-rename so a reader never sees a half-written file.
+    let tmp = dir.join(format!("{name}.tmp"));
+    let mut encoder = GzEncoder::new(File::create(&tmp)?, Compression::default());
+    encoder.write_all(text.as_bytes())?;
+    encoder.finish()?.sync_all()?;
+    std::fs::rename(&tmp, dir.join(&name))?;
+    Ok(format!("{FORMAT_V1}:{name}"))
+}
+
+fn read(dir: &Path, pointer: &str) -> io::Result<String> {
+    let mut text = String::new();
+    GzDecoder::new(File::open(dir.join(file_name(pointer)?))?).read_to_string(&mut text)?;
+    Ok(text)
+}
+
+/// File name a pointer refers to. Rejects unknown format versions and
+/// anything that could escape the output directory.
+fn file_name(pointer: &str) -> io::Result<&str> {
+    match pointer.split_once(':') {
+        Some((FORMAT_V1, name))
+            if name.ends_with(FILE_SUFFIX)
+                && !name.contains(['/', '\\'])
+                && !name.starts_with('.') =>
+        {
+            Ok(name)
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported output pointer {pointer:?}"),
+        )),
+    }
+}
+
+fn preview(text: &str) -> String {
+    let cut = text
+        .char_indices()
+        .nth(PREVIEW_CHARS)
+        .map(|(i, _)| i)
+        .unwrap_or(text.len());
+    format!(
+        "{}\n\n[Output truncated here: the full {} bytes are stored compressed on disk.]",
+        &text[..cut],
+        text.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offloaded_output_round_trips_and_pointers_are_checked() {
+        let dir = tempfile::tempdir().unwrap();
+        let text = "line of agent output ✓\n".repeat(50_000);
+        let pointer = write(dir.path(), "exec-1", &text).unwrap();
+        assert_eq!(pointer, "v1:exec-1.output.gz");
+        let on_disk = std::fs::metadata(dir.path().join("exec-1.output.gz")).unwrap();
+        assert!(on_disk.len() < text.len() as u64 / 10);
+        assert_eq!(read(dir.path(), &pointer).unwrap(), text);
+
+        for bad in [
+            "v2:exec-1.output.gz",
+            "v1:../exec-1.output.gz",
+            "v1:exec-1.log",
+            "exec-1",
+        ] {
+            assert!(read(dir.path(), bad).is_err(), "{bad}");
+        }
+
+        let short = preview(&text);
+        assert!(short.len() < 8 * PREVIEW_CHARS);
+        assert!(short.starts_with("line of agent output ✓"));
+        assert!(short.ends_with(&format!(
+            "the full {} bytes are stored compressed on disk.]",
+            text.len()
+        )));
+    }
+}
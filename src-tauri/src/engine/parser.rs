@@ -512,6 +512,7 @@ const PROTOCOL_KEYS: &[(&str, fn(&serde_json::Value) -> Option<ProtocolMessage>)
     ("raise_incident", parse_raise_incident),
     ("resolve_incident", parse_resolve_incident),
     ("kpi_measurement", parse_kpi_measurement),
+    ("shared_context", parse_shared_context),
     ("execution_flow", parse_execution_flow),
     ("knowledge_annotation", parse_knowledge_annotation),
     ("propose_improvement", parse_propose_improvement),
@@ -590,6 +591,13 @@ fn parse_kpi_measurement(msg: &serde_json::Value) -> Option<ProtocolMessage> {
     })
 }
 
+fn parse_shared_context(msg: &serde_json::Value) -> Option<ProtocolMessage> {
+    Some(ProtocolMessage::SharedContext {
+        key: str_field_or(msg, "key", ""),
+        value: msg.get("value").cloned()?,
+    })
+}
+
 fn parse_execution_flow(msg: &serde_json::Value) -> Option<ProtocolMessage> {
     let flows = msg.get("flows").cloned().unwrap_or(serde_json::Value::Null);
     Some(ProtocolMessage::ExecutionFlow { flows })
@@ -0,0 +1,584 @@
+//! Branch condition expressions for conditional pipeline edges.
+//!
+//! A `conditional` connection's `condition` is a small JMESPath-style
+//! expression evaluated against the upstream member's output:
+//!
+//! ```text
+//! severity == "high" && (length(items) > 0 || $.retry == true)
+//! contains(summary, "crash")            // function form
+//! summary contains "crash"              // infix form
+//! exists(result.errors[0]) && !dry_run  // bare path = truthiness
+//! ```
+//!
+//! - **Paths** start at the output root: `$`, `output`, or a bare field name,
+//!   followed by `.field`, `["quoted key"]` or `[index]` (negative indexes
+//!   count from the end). A missing path resolves to `null`.
+//! - **Literals**: `"string"` / `'string'`, numbers, `true`, `false`, `null`.
+//! - **Operators**: `== != < <= > >=`, `contains`, `&&`/`and`, `||`/`or`,
+//!   `!`/`not`, parentheses.
+//! - **Functions**: `exists(path)`, `length(value)`, `lower(value)`,
+//!   `contains(haystack, needle)`.
+//!
+//! Output that isn't valid JSON is exposed as a string at `$`, so
+//! `$ contains "LGTM"` works on plain-text output too.
+
+use serde_json::Value;
+
+/// Parsed condition expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Literal(Value),
+    Path(Vec<Segment>),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(Box<Expr>, CmpOp, Box<Expr>),
+    Call(Func, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Key(String),
+    Index(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Func {
+    Exists,
+    Length,
+    Lower,
+    Contains,
+}
+
+impl Func {
+    fn from_name(name: &str) -> Option<(Func, usize)> {
+        match name {
+            "exists" => Some((Func::Exists, 1)),
+            "length" => Some((Func::Length, 1)),
+            "lower" => Some((Func::Lower, 1)),
+            "contains" => Some((Func::Contains, 2)),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a condition expression. Errors carry the byte offset of the problem.
+pub fn parse(src: &str) -> Result<Expr, String> {
+    let tokens = tokenize(src)?;
+    if tokens.is_empty() {
+        return Err("Condition is empty".into());
+    }
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.or_expr()?;
+    if let Some((tok, at)) = parser.tokens.get(parser.pos) {
+        return Err(format!("Unexpected {} at {at}", tok.describe()));
+    }
+    Ok(expr)
+}
+
+/// Evaluate `expr` against an upstream output and reduce it to a branch
+/// decision. A missing output evaluates against `null`.
+pub fn evaluate(expr: &Expr, output: Option<&str>) -> bool {
+    let root = match output {
+        Some(raw) => serde_json::from_str::<Value>(raw)
+            .unwrap_or_else(|_| Value::String(raw.trim().to_string())),
+        None => Value::Null,
+    };
+    truthy(&eval(expr, &root))
+}
+
+// ============================================================================
+// Tokenizer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Dot,
+    Comma,
+    Dollar,
+    Op(&'static str),
+    Str(String),
+    Num(f64),
+    Ident(String),
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::LParen => "'('".into(),
+            Token::RParen => "')'".into(),
+            Token::LBracket => "'['".into(),
+            Token::RBracket => "']'".into(),
+            Token::Dot => "'.'".into(),
+            Token::Comma => "','".into(),
+            Token::Dollar => "'$'".into(),
+            Token::Op(op) => format!("'{op}'"),
+            Token::Str(s) => format!("string {s:?}"),
+            Token::Num(n) => format!("number {n}"),
+            Token::Ident(id) => format!("'{id}'"),
+        }
+    }
+}
+
+fn tokenize(src: &str) -> Result<Vec<(Token, usize)>, String> {
+    let chars: Vec<(usize, char)> = src.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let (at, c) = chars[i];
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+        let single = match c {
+            '(' => Some(Token::LParen),
+            ')' => Some(Token::RParen),
+            '[' => Some(Token::LBracket),
+            ']' => Some(Token::RBracket),
+            '.' => Some(Token::Dot),
+            ',' => Some(Token::Comma),
+            '$' => Some(Token::Dollar),
+            _ => None,
+        };
+        if c.is_whitespace() {
+            i += 1;
+        } else if let Some(tok) = single {
+            tokens.push((tok, at));
+            i += 1;
+        } else if let Some(op) = two_char_op(c, next) {
+            tokens.push((Token::Op(op), at));
+            i += 2;
+        } else if matches!(c, '<' | '>' | '!') {
+            let op = match c {
+                '<' => "<",
+                '>' => ">",
+                _ => "!",
+            };
+            tokens.push((Token::Op(op), at));
+            i += 1;
+        } else if c == '"' || c == '\'' {
+            let mut value = String::new();
+            i += 1;
+            loop {
+                let Some(&(_, ch)) = chars.get(i) else {
+                    return Err(format!("Unterminated string starting at {at}"));
+                };
+                i += 1;
+                match ch {
+                    '\\' => {
+                        let Some(&(_, escaped)) = chars.get(i) else {
+                            return Err(format!("Unterminated string starting at {at}"));
+                        };
+                        value.push(match escaped {
+                            'n' => '\n',
+                            't' => '\t',
+                            other => other,
+                        });
+                        i += 1;
+                    }
+                    ch if ch == c => break,
+                    ch => value.push(ch),
+                }
+            }
+            tokens.push((Token::Str(value), at));
+        } else if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) {
+            let start = i;
+            i += 1;
+            while chars
+                .get(i)
+                .is_some_and(|(_, ch)| ch.is_ascii_digit() || *ch == '.')
+            {
+                i += 1;
+            }
+            let end = chars.get(i).map(|(at, _)| *at).unwrap_or(src.len());
+            let text = &src[chars[start].0..end];
+            let num = text
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number '{text}' at {at}"))?;
+            tokens.push((Token::Num(num), at));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while chars
+                .get(i)
+                .is_some_and(|(_, ch)| ch.is_alphanumeric() || *ch == '_' || *ch == '-')
+            {
+                i += 1;
+            }
+            let end = chars.get(i).map(|(at, _)| *at).unwrap_or(src.len());
+            let word = &src[chars[start].0..end];
+            let tok = match word {
+                "and" => Token::Op("&&"),
+                "or" => Token::Op("||"),
+                "not" => Token::Op("!"),
+                _ => Token::Ident(word.to_string()),
+            };
+            tokens.push((tok, at));
+        } else {
+            return Err(format!("Unexpected character '{c}' at {at}"));
+        }
+    }
+    Ok(tokens)
+}
+
+fn two_char_op(c: char, next: Option<char>) -> Option<&'static str> {
+    match (c, next?) {
+        ('=', '=') => Some("=="),
+        ('!', '=') => Some("!="),
+        ('<', '=') => Some("<="),
+        ('>', '=') => Some(">="),
+        ('&', '&') => Some("&&"),
+        ('|', '|') => Some("||"),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Parser — recursive descent, lowest precedence first
+// ============================================================================
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        tok
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .or_else(|| self.tokens.last())
+            .map(|(_, at)| *at)
+            .unwrap_or(0)
+    }
+
+    fn expect(&mut self, want: Token) -> Result<(), String> {
+        let at = self.offset();
+        match self.advance() {
+            Some(tok) if tok == want => Ok(()),
+            Some(tok) => Err(format!(
+                "Expected {} but found {} at {at}",
+                want.describe(),
+                tok.describe()
+            )),
+            None => Err(format!("Expected {} at end of condition", want.describe())),
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.and_expr()?;
+        while self.peek() == Some(&Token::Op("||")) {
+            self.pos += 1;
+            lhs = Expr::Or(Box::new(lhs), Box::new(self.and_expr()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and_expr(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.not_expr()?;
+        while self.peek() == Some(&Token::Op("&&")) {
+            self.pos += 1;
+            lhs = Expr::And(Box::new(lhs), Box::new(self.not_expr()?));
+        }
+        Ok(lhs)
+    }
+
+    fn not_expr(&mut self) -> Result<Expr, String> {
+        if self.peek() == Some(&Token::Op("!")) {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.not_expr()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let lhs = self.operand()?;
+        let op = match self.peek() {
+            Some(Token::Op("==")) => CmpOp::Eq,
+            Some(Token::Op("!=")) => CmpOp::Ne,
+            Some(Token::Op("<")) => CmpOp::Lt,
+            Some(Token::Op("<=")) => CmpOp::Le,
+            Some(Token::Op(">")) => CmpOp::Gt,
+            Some(Token::Op(">=")) => CmpOp::Ge,
+            Some(Token::Ident(word)) if word == "contains" => CmpOp::Contains,
+            _ => return Ok(lhs),
+        };
+        self.pos += 1;
+        let rhs = self.operand()?;
+        Ok(Expr::Compare(Box::new(lhs), op, Box::new(rhs)))
+    }
+
+    fn operand(&mut self) -> Result<Expr, String> {
+        let at = self.offset();
+        match self.advance() {
+            Some(Token::LParen) => {
+                let inner = self.or_expr()?;
+                self.expect(Token::RParen)?;
+                Ok(inner)
+            }
+            Some(Token::Str(s)) => Ok(Expr::Literal(Value::String(s))),
+            Some(Token::Num(n)) => Ok(Expr::Literal(
+                serde_json::Number::from_f64(n)
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null),
+            )),
+            Some(Token::Dollar) => self.path(Vec::new()),
+            Some(Token::Ident(word)) => match word.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                "output" => self.path(Vec::new()),
+                _ if self.peek() == Some(&Token::LParen) => self.call(&word, at),
+                _ => self.path(vec![Segment::Key(word)]),
+            },
+            Some(tok) => Err(format!("Unexpected {} at {at}", tok.describe())),
+            None => Err("Condition ends where a value was expected".into()),
+        }
+    }
+
+    fn call(&mut self, name: &str, at: usize) -> Result<Expr, String> {
+        let (func, arity) =
+            Func::from_name(name).ok_or_else(|| format!("Unknown function '{name}' at {at}"))?;
+        self.expect(Token::LParen)?;
+        let mut args = Vec::new();
+        if self.peek() != Some(&Token::RParen) {
+            args.push(self.or_expr()?);
+            while self.peek() == Some(&Token::Comma) {
+                self.pos += 1;
+                args.push(self.or_expr()?);
+            }
+        }
+        self.expect(Token::RParen)?;
+        if args.len() != arity {
+            return Err(format!(
+                "{name}() takes {arity} argument(s), got {} at {at}",
+                args.len()
+            ));
+        }
+        if func == Func::Exists && !matches!(args[0], Expr::Path(_)) {
+            return Err(format!("exists() takes a path at {at}"));
+        }
+        Ok(Expr::Call(func, args))
+    }
+
+    fn path(&mut self, mut segments: Vec<Segment>) -> Result<Expr, String> {
+        loop {
+            match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    let at = self.offset();
+                    match self.advance() {
+                        Some(Token::Ident(key)) => segments.push(Segment::Key(key)),
+                        Some(tok) => {
+                            return Err(format!(
+                                "Expected a field name after '.' but found {} at {at}",
+                                tok.describe()
+                            ))
+                        }
+                        None => return Err("Condition ends after '.'".into()),
+                    }
+                }
+                Some(Token::LBracket) => {
+                    self.pos += 1;
+                    let at = self.offset();
+                    match self.advance() {
+                        Some(Token::Str(key)) => segments.push(Segment::Key(key)),
+                        Some(Token::Num(n)) if n.fract() == 0.0 => {
+                            segments.push(Segment::Index(n as i64))
+                        }
+                        Some(tok) => {
+                            return Err(format!(
+                                "Expected an index or quoted key but found {} at {at}",
+                                tok.describe()
+                            ))
+                        }
+                        None => return Err("Condition ends inside '['".into()),
+                    }
+                    self.expect(Token::RBracket)?;
+                }
+                _ => return Ok(Expr::Path(segments)),
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Evaluator
+// ============================================================================
+
+fn eval(expr: &Expr, root: &Value) -> Value {
+    match expr {
+        Expr::Literal(v) => v.clone(),
+        Expr::Path(segments) => resolve(root, segments).cloned().unwrap_or(Value::Null),
+        Expr::Not(inner) => Value::Bool(!truthy(&eval(inner, root))),
+        Expr::And(a, b) => Value::Bool(truthy(&eval(a, root)) && truthy(&eval(b, root))),
+        Expr::Or(a, b) => Value::Bool(truthy(&eval(a, root)) || truthy(&eval(b, root))),
+        Expr::Compare(a, op, b) => Value::Bool(compare(&eval(a, root), *op, &eval(b, root))),
+        Expr::Call(func, args) => match func {
+            Func::Exists => Value::Bool(match &args[0] {
+                Expr::Path(segments) => resolve(root, segments).is_some_and(|v| !v.is_null()),
+                _ => false,
+            }),
+            Func::Length => match eval(&args[0], root) {
+                Value::String(s) => Value::from(s.chars().count()),
+                Value::Array(a) => Value::from(a.len()),
+                Value::Object(o) => Value::from(o.len()),
+                _ => Value::from(0),
+            },
+            Func::Lower => match eval(&args[0], root) {
+                Value::String(s) => Value::String(s.to_lowercase()),
+                other => other,
+            },
+            Func::Contains => Value::Bool(compare(
+                &eval(&args[0], root),
+                CmpOp::Contains,
+                &eval(&args[1], root),
+            )),
+        },
+    }
+}
+
+fn resolve<'a>(root: &'a Value, segments: &[Segment]) -> Option<&'a Value> {
+    segments
+        .iter()
+        .try_fold(root, |value, segment| match segment {
+            Segment::Key(key) => value.get(key),
+            Segment::Index(index) => {
+                let items = value.as_array()?;
+                let i = if *index < 0 {
+                    items.len().checked_sub(index.unsigned_abs() as usize)?
+                } else {
+                    *index as usize
+                };
+                items.get(i)
+            }
+        })
+}
+
+fn compare(a: &Value, op: CmpOp, b: &Value) -> bool {
+    match op {
+        CmpOp::Eq => values_equal(a, b),
+        CmpOp::Ne => !values_equal(a, b),
+        CmpOp::Contains => match (a, b) {
+            (Value::String(hay), Value::String(needle)) => hay.contains(needle.as_str()),
+            (Value::Array(items), needle) => items.iter().any(|item| values_equal(item, needle)),
+            (Value::Object(map), Value::String(key)) => map.contains_key(key),
+            _ => false,
+        },
+        CmpOp::Lt | CmpOp::Le | CmpOp::Gt | CmpOp::Ge => {
+            let ordering = match (a, b) {
+                (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+                _ => match (as_number(a), as_number(b)) {
+                    (Some(x), Some(y)) => x.partial_cmp(&y),
+                    _ => None,
+                },
+            };
+            let Some(ordering) = ordering else {
+                return false;
+            };
+            match op {
+                CmpOp::Lt => ordering.is_lt(),
+                CmpOp::Le => ordering.is_le(),
+                CmpOp::Gt => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            }
+        }
+    }
+}
+
+/// JSON equality, except that numbers compare by value (`1 == 1.0`) and a
+/// numeric string equals the number it spells, since agent output often
+/// quotes numbers.
+fn values_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(_), _) | (_, Value::Number(_)) => match (as_number(a), as_number(b)) {
+            (Some(x), Some(y)) => x == y,
+            _ => false,
+        },
+        _ => a == b,
+    }
+}
+
+fn as_number(v: &Value) -> Option<f64> {
+    match v {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn truthy(v: &Value) -> bool {
+    match v {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(a) => !a.is_empty(),
+        Value::Object(o) => !o.is_empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(cond: &str, output: &str) -> bool {
+        evaluate(&parse(cond).unwrap(), Some(output))
+    }
+
+    #[test]
+    fn evaluates_paths_operators_and_functions() {
+        let out = r#"{"severity":"high","count":"3","items":[{"name":"a"},{"name":"b"}],
+                      "meta":{"dry run":false},"summary":"App crash on login"}"#;
+        assert!(check(r#"severity == "high""#, out));
+        assert!(check(r#"$.severity != 'low' and count > 2"#, out));
+        assert!(check("length(items) == 2 && items[-1].name == 'b'", out));
+        assert!(check(r#"output.meta["dry run"] == false"#, out));
+        assert!(check(r#"lower(summary) contains "crash""#, out));
+        assert!(check(r#"contains(items[0], "name")"#, out));
+        assert!(check("!exists(error) && (missing || items)", out));
+        assert!(!check("exists(items[5].name)", out));
+        assert!(!check("not (severity == 'high')", out));
+        // Plain-text output is the root string.
+        assert!(check(r#"$ contains "LGTM""#, "Looks good. LGTM!"));
+        assert!(!evaluate(&parse("exists($)").unwrap(), None));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions_with_a_position() {
+        for (cond, needle) in [
+            ("", "empty"),
+            ("severity ==", "value was expected"),
+            ("(a == 1", "')'"),
+            ("a = 1", "'='"),
+            ("frobnicate(a)", "Unknown function"),
+            ("exists('x')", "takes a path"),
+            ("length(a, b)", "1 argument"),
+            ("a == 'open", "Unterminated"),
+            ("a b", "Unexpected 'b' at 2"),
+        ] {
+            let err = parse(cond).unwrap_err();
+            assert!(err.contains(needle), "{cond}: {err}");
+        }
+    }
+}
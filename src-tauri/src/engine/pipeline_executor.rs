@@ -8,16 +8,20 @@
 //! - **Condition evaluator**: conditional branching on connection edges
 //! - **Command runner**: deterministic (non-LLM) pipeline nodes
 //! - **Approval gates**: pause pipeline for human review
+//! - **Pause / resume / node retry**: continue a run from its persisted node
+//!   statuses instead of re-running the whole upstream chain
+//! - **Simulation**: dry runs from `simulate_team_run` with sandboxed tools
 
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use tauri::Emitter;
+use ts_rs::TS;
 
 use crate::db::models::{
-    CreateTeamMemoryInput, NodeConfig, PersonaTeamConnection, PersonaTeamMember,
+    CreateTeamMemoryInput, NodeConfig, PersonaTeamConnection, PersonaTeamMember, PipelineSimulation,
 };
 use crate::db::repos::core::personas as persona_repo;
 use crate::db::repos::execution::executions as exec_repo;
@@ -25,6 +29,7 @@ use crate::db::repos::resources::team_memories as team_memories_repo;
 use crate::db::repos::resources::teams as team_repo;
 use crate::db::DbPool;
 use crate::engine::event_registry::event_name;
+use crate::engine::pipeline_condition;
 use crate::engine::ExecutionEngine;
 use crate::ActiveProcessRegistry;
 
@@ -130,7 +135,8 @@ pub fn build_predecessor_map(
 // Condition evaluator — conditional branching on connection edges
 // ============================================================================
 
-/// A simple condition specification for conditional pipeline edges.
+/// A simple condition specification for conditional pipeline edges. Kept
+/// for connections created before conditions became expressions.
 #[derive(Debug, Clone, Deserialize)]
 struct ConditionSpec {
     field: String,
@@ -138,10 +144,38 @@ struct ConditionSpec {
     value: Option<String>,
 }
 
-/// Evaluate a condition JSON against a predecessor node's output.
+const LEGACY_OPS: &[&str] = &["equals", "not_equals", "contains", "exists"];
+
+/// Evaluate an edge condition against a predecessor node's output.
+///
+/// Returns whether the branch is taken — `true` if the condition is met, or
+/// if the condition is malformed (fail-open is the safe default so pipelines
+/// don't break on bad config), in which case the reason comes back too.
+///
+/// A condition that is a JSON object is a legacy [`ConditionSpec`]; anything
+/// else is a [`pipeline_condition`] expression.
+fn evaluate_branch(condition: &str, predecessor_output: Option<&str>) -> (bool, Option<String>) {
+    if condition.trim_start().starts_with('{') {
+        return evaluate_spec(condition, predecessor_output);
+    }
+    match pipeline_condition::parse(condition) {
+        Ok(expr) => (
+            pipeline_condition::evaluate(&expr, predecessor_output),
+            None,
+        ),
+        Err(e) => {
+            tracing::warn!(
+                condition = %condition,
+                error = %e,
+                "Malformed condition expression — bypassing condition (fail-open)",
+            );
+            (true, Some(e))
+        }
+    }
+}
+
+/// Evaluate a legacy `{field, op, value}` condition.
 ///
-/// Returns `true` if the condition is met (or if the condition is malformed —
-/// fail-open is the safe default so pipelines don't break on bad config).
 ///
 /// Field lookup strategy:
 /// 1. If the output is valid JSON, look up `spec.field` on it.
@@ -150,7 +184,7 @@ struct ConditionSpec {
 ///    output string as the value. This is useful for `contains` checks on
 ///    raw text output.
 /// 3. If `spec.field == "*"` or `"_output"`, always use the whole output string.
-fn evaluate_condition(condition_json: &str, predecessor_output: Option<&str>) -> bool {
+fn evaluate_spec(condition_json: &str, predecessor_output: Option<&str>) -> (bool, Option<String>) {
     let spec: ConditionSpec = match serde_json::from_str(condition_json) {
         Ok(s) => s,
         Err(e) => {
@@ -159,7 +193,7 @@ fn evaluate_condition(condition_json: &str, predecessor_output: Option<&str>) ->
                 error = %e,
                 "Malformed condition JSON — bypassing condition (fail-open)",
             );
-            return true;
+            return (true, Some(format!("Malformed condition JSON: {e}")));
         }
     };
 
@@ -187,7 +221,7 @@ fn evaluate_condition(condition_json: &str, predecessor_output: Option<&str>) ->
         predecessor_output.map(|s| s.trim().to_string())
     };
 
-    match spec.op.as_str() {
+    let met = match spec.op.as_str() {
         "equals" => output_value.as_deref() == spec.value.as_deref(),
         "not_equals" => output_value.as_deref() != spec.value.as_deref(),
         "contains" => output_value
@@ -201,29 +235,89 @@ fn evaluate_condition(condition_json: &str, predecessor_output: Option<&str>) ->
                 field = %spec.field,
                 "Unknown condition operator — bypassing condition (fail-open)",
             );
-            true
+            return (
+                true,
+                Some(format!("Unknown condition operator '{unknown}'")),
+            );
         }
+    };
+    (met, None)
+}
+
+/// Result of [`validate_condition`].
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionValidation {
+    pub valid: bool,
+    pub error: Option<String>,
+    /// Whether the branch would be taken for the sample output, when one was
+    /// given and the condition is valid.
+    pub taken: Option<bool>,
+}
+
+/// Check that an edge condition (expression or legacy JSON spec) is well
+/// formed, and optionally which way it would branch for `sample_output`.
+pub fn validate_condition(condition: &str, sample_output: Option<&str>) -> ConditionValidation {
+    let error = if condition.trim_start().starts_with('{') {
+        match serde_json::from_str::<ConditionSpec>(condition) {
+            Ok(spec) if LEGACY_OPS.contains(&spec.op.as_str()) => None,
+            Ok(spec) => Some(format!("Unknown condition operator '{}'", spec.op)),
+            Err(e) => Some(format!("Malformed condition JSON: {e}")),
+        }
+    } else {
+        pipeline_condition::parse(condition).err()
+    };
+    let taken = match (&error, sample_output) {
+        (None, Some(output)) => Some(evaluate_branch(condition, Some(output)).0),
+        _ => None,
+    };
+    ConditionValidation {
+        valid: error.is_none(),
+        error,
+        taken,
     }
 }
 
-/// Check if a node should be skipped because an incoming conditional edge's
-/// condition is not met by its source node's output.
-fn should_skip_node(
+/// Which way one incoming conditional edge went, recorded on the target
+/// node's status entry as `branches`.
+#[derive(Debug, Clone, Serialize)]
+struct BranchRecord {
+    connection_id: String,
+    source_member_id: String,
+    condition: String,
+    taken: bool,
+    /// Set when the condition could not be evaluated and the edge was taken
+    /// by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Evaluate every incoming conditional edge of `member_id` against its
+/// source node's output. The node is skipped if any edge is not taken.
+fn evaluate_incoming_branches(
     member_id: &str,
     connections: &[PersonaTeamConnection],
     node_outputs: &HashMap<String, Option<String>>,
-) -> bool {
-    connections.iter().any(|c| {
-        c.target_member_id == *member_id
-            && c.connection_type == "conditional"
-            && c.condition.is_some()
-            && !evaluate_condition(
-                c.condition.as_deref().unwrap(),
-                node_outputs
-                    .get(&c.source_member_id)
-                    .and_then(|o| o.as_deref()),
-            )
-    })
+) -> Vec<BranchRecord> {
+    connections
+        .iter()
+        .filter(|c| c.target_member_id == *member_id && c.connection_type == "conditional")
+        .filter_map(|c| {
+            let condition = c.condition.as_deref()?;
+            let output = node_outputs
+                .get(&c.source_member_id)
+                .and_then(|o| o.as_deref());
+            let (taken, error) = evaluate_branch(condition, output);
+            Some(BranchRecord {
+                connection_id: c.id.clone(),
+                source_member_id: c.source_member_id.clone(),
+                condition: condition.to_string(),
+                taken,
+                error,
+            })
+        })
+        .collect()
 }
 
 /// Check if a node should be skipped because EVERY one of its predecessors was
@@ -340,11 +434,15 @@ async fn run_node(
     resolved_input: Option<serde_json::Value>,
     cancelled: &Arc<AtomicBool>,
     statuses: &mut Vec<serde_json::Value>,
+    simulation: Option<&PipelineSimulation>,
 ) -> NodeOutcome {
     match node_config.node_type.as_deref().unwrap_or("persona") {
-        "command" => {
-            run_command_node(node_config, member, resolved_input, cancelled, statuses).await
-        }
+        "command" => match simulation {
+            Some(sim) => simulate_command_node(node_config, member, sim, statuses),
+            None => {
+                run_command_node(node_config, member, resolved_input, cancelled, statuses).await
+            }
+        },
         _ => {
             run_persona_node(
                 db,
@@ -356,13 +454,16 @@ async fn run_node(
                 resolved_input,
                 cancelled,
                 statuses,
+                simulation.is_some(),
             )
             .await
         }
     }
 }
 
-/// Run a persona (LLM) node — the original execution path.
+/// Run a persona (LLM) node — the original execution path. A `simulated`
+/// node's execution is flagged as a simulation (no outbound delivery) and its
+/// completed status records the tool calls it reported.
 async fn run_persona_node(
     db: &DbPool,
     engine: &ExecutionEngine,
@@ -373,6 +474,7 @@ async fn run_persona_node(
     resolved_input: Option<serde_json::Value>,
     cancelled: &Arc<AtomicBool>,
     statuses: &mut Vec<serde_json::Value>,
+    simulated: bool,
 ) -> NodeOutcome {
     // Load persona + tools
     let persona = match persona_repo::get_by_id(db, &member.persona_id) {
@@ -400,13 +502,15 @@ async fn run_persona_node(
         .unwrap_or_default();
 
     // Create execution record
-    let exec = match exec_repo::create(
+    let exec = match exec_repo::create_with_idempotency(
         db,
         &member.persona_id,
         None,
         resolved_input.as_ref().map(|v| v.to_string()),
         None,
         None,
+        None,
+        simulated,
     ) {
         Ok(e) => e,
         Err(_) => {
@@ -508,6 +612,9 @@ async fn run_persona_node(
                             ("output", serde_json::json!(execution.output_data)),
                         ],
                     );
+                    if simulated {
+                        record_simulated_tool_calls(statuses, &member.id, &execution);
+                    }
                     return NodeOutcome::Completed(execution.output_data.clone());
                 }
                 "failed" | "cancelled" | "incomplete" => {
@@ -748,6 +855,97 @@ async fn poll_for_approval(
     }
 }
 
+// ============================================================================
+// Simulation — dry runs from `simulate_team_run`
+// ============================================================================
+
+/// Mark a node's input as a simulation: `_simulation` suppresses outbound
+/// delivery in the runner and `_tool_sandbox` makes the prompt ask for
+/// reported instead of real tool calls (see `engine::prompt`).
+fn stamp_simulation(input: &mut serde_json::Value, sim: &PipelineSimulation) {
+    if let Some(obj) = input.as_object_mut() {
+        obj.insert("_simulation".into(), serde_json::json!(true));
+        obj.insert(
+            "_tool_sandbox".into(),
+            serde_json::json!(sim.tool_responses),
+        );
+    }
+}
+
+/// Tool calls a simulated persona reported as
+/// `{"simulated_tool_call": {"tool": ..., "input": ...}}` lines.
+fn parse_simulated_tool_calls(output: &str) -> Vec<serde_json::Value> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if !line.starts_with('{') || !line.contains("\"simulated_tool_call\"") {
+                return None;
+            }
+            serde_json::from_str::<serde_json::Value>(line)
+                .ok()?
+                .get_mut("simulated_tool_call")
+                .map(serde_json::Value::take)
+        })
+        .collect()
+}
+
+/// Record the tool calls of a simulated persona node on its status: the ones
+/// it reported (`tool_calls`) and any it made for real despite the sandbox
+/// instructions (`unsandboxed_tool_calls`), so the trace shows both.
+fn record_simulated_tool_calls(
+    statuses: &mut [serde_json::Value],
+    member_id: &str,
+    execution: &crate::db::models::PersonaExecution,
+) {
+    let reported = execution
+        .output_data
+        .as_deref()
+        .map(parse_simulated_tool_calls)
+        .unwrap_or_default();
+    let unsandboxed: Vec<&str> = execution
+        .tool_steps
+        .iter()
+        .flat_map(|steps| steps.iter())
+        .map(|step| step.tool_name.as_str())
+        .collect();
+    let mut fields = vec![
+        ("simulated", serde_json::json!(true)),
+        ("tool_calls", serde_json::json!(reported)),
+    ];
+    if !unsandboxed.is_empty() {
+        fields.push(("unsandboxed_tool_calls", serde_json::json!(unsandboxed)));
+    }
+    update_node_status(statuses, member_id, &fields);
+}
+
+/// Stand-in for a command node in a simulation: the command is not run; the
+/// node outputs the configured text or a placeholder naming the command.
+fn simulate_command_node(
+    config: &NodeConfig,
+    member: &PersonaTeamMember,
+    sim: &PipelineSimulation,
+    statuses: &mut [serde_json::Value],
+) -> NodeOutcome {
+    let output = sim
+        .command_outputs
+        .get(&member.id)
+        .cloned()
+        .unwrap_or_else(|| {
+            serde_json::json!({ "simulated": true, "command": config.command }).to_string()
+        });
+    update_node_status(
+        statuses,
+        &member.id,
+        &[
+            ("status", serde_json::json!("completed")),
+            ("simulated", serde_json::json!(true)),
+            ("output", serde_json::json!(output)),
+        ],
+    );
+    NodeOutcome::Completed(Some(output))
+}
+
 // ============================================================================
 // Pipeline runner — orchestrate all nodes
 // ============================================================================
@@ -763,13 +961,24 @@ pub struct PipelineContext {
     pub members: Vec<PersonaTeamMember>,
     pub connections: Vec<PersonaTeamConnection>,
     pub execution_order: Vec<String>,
+    /// Node statuses to start from. A fresh run has every node `idle`; a
+    /// resumed or retried run carries the `completed` / `skipped` entries
+    /// (with their outputs) of the earlier attempt, and those nodes are not
+    /// run again.
     pub initial_node_statuses: Vec<serde_json::Value>,
     pub cancelled: Arc<AtomicBool>,
+    /// Set by `pause_pipeline_run`; the run stops at the next node boundary
+    /// with status `paused`, letting the node in flight finish.
+    pub pause_requested: Arc<AtomicBool>,
     pub process_registry: Arc<ActiveProcessRegistry>,
+    /// Set for runs started by `simulate_team_run`: tools are sandboxed,
+    /// command nodes are not executed, approval gates pass automatically and
+    /// no team memories are written.
+    pub simulation: Option<PipelineSimulation>,
 }
 
-/// Run the full pipeline. This is the async task body that `execute_team`
-/// spawns via `tokio::spawn`.
+/// Run the full pipeline. This is the async task body that `execute_team`,
+/// `resume_pipeline_run` and `retry_pipeline_node` spawn via `tokio::spawn`.
 pub async fn run_pipeline(ctx: PipelineContext) {
     let predecessor_map = build_predecessor_map(&ctx.connections);
     let emitter = StatusEmitter {
@@ -787,7 +996,10 @@ pub async fn run_pipeline(ctx: PipelineContext) {
     // global pipeline input.
     let mut skipped: HashSet<String> = HashSet::new();
     let mut statuses = ctx.initial_node_statuses.clone();
+    // Nodes finished by an earlier attempt of this run keep their outputs.
+    let finished = restore_finished_nodes(&statuses, &mut node_outputs, &mut skipped);
     let mut has_failure = false;
+    let mut paused = false;
     let mut memories_created: u32 = 0;
 
     // F3: graded upstream-context injection. Resolve the pipeline-wide fidelity
@@ -812,7 +1024,33 @@ pub async fn run_pipeline(ctx: PipelineContext) {
         crate::engine::run_budget::pipeline_ceiling_usd(),
     );
 
+    // Absolute deadline from the pipeline input (see `engine::deadline`):
+    // handed to every node so its run is trimmed to fit, and checked before
+    // each node so nothing starts once it has passed.
+    let deadline = crate::engine::deadline::from_input_str(ctx.input_data.as_deref());
+    let mut deadline_hit = false;
+
     for member_id in &ctx.execution_order {
+        if finished.contains(member_id) {
+            continue;
+        }
+        if ctx.pause_requested.load(Ordering::Relaxed) {
+            tracing::info!(run_id = %ctx.run_id, "Pipeline paused before member {member_id}");
+            paused = true;
+            break;
+        }
+        if let Some(d) = deadline {
+            if crate::engine::deadline::remaining_ms(d, chrono::Utc::now()).is_none() {
+                tracing::warn!(
+                    run_id = %ctx.run_id,
+                    deadline = %d.to_rfc3339(),
+                    "Pipeline halted — deadline passed; remaining nodes skipped",
+                );
+                deadline_hit = true;
+                has_failure = true;
+                break;
+            }
+        }
         // P2 enforce-mode: stop launching further nodes once the pipeline's
         // budget is exhausted (warn-only never halts). Remaining nodes are left
         // unstarted; the pipeline finalizes below with the work done so far.
@@ -848,8 +1086,17 @@ pub async fn run_pipeline(ctx: PipelineContext) {
 
         // ── Conditional branching ────────────────────────────────────
         // Skip this node if an incoming conditional edge's condition is not
-        // met by the source node's output.
-        if should_skip_node(member_id, &ctx.connections, &node_outputs) {
+        // met by the source node's output. Every evaluated edge is recorded
+        // on the node so the run shows which branch was taken and why.
+        let branches = evaluate_incoming_branches(member_id, &ctx.connections, &node_outputs);
+        if !branches.is_empty() {
+            update_node_status(
+                &mut statuses,
+                member_id,
+                &[("branches", serde_json::json!(branches))],
+            );
+        }
+        if branches.iter().any(|b| !b.taken) {
             skipped.insert(member_id.clone());
             update_node_status(
                 &mut statuses,
@@ -886,8 +1133,15 @@ pub async fn run_pipeline(ctx: PipelineContext) {
         }
 
         // ── Approval gate ────────────────────────────────────────────
-        // If this node requires human approval, pause and wait.
-        if node_config.approval_gate.unwrap_or(false) {
+        // If this node requires human approval, pause and wait. A simulation
+        // records the gate and passes it.
+        if node_config.approval_gate.unwrap_or(false) && ctx.simulation.is_some() {
+            update_node_status(
+                &mut statuses,
+                member_id,
+                &[("approval", serde_json::json!("auto_approved_simulation"))],
+            );
+        } else if node_config.approval_gate.unwrap_or(false) {
             let persona_name = persona_repo::get_by_id(&ctx.db, &member.persona_id)
                 .map(|p| p.name.clone())
                 .unwrap_or_else(|_| "Unknown".into());
@@ -985,7 +1239,7 @@ pub async fn run_pipeline(ctx: PipelineContext) {
             crate::engine::context_fidelity::build_upstream_preamble(&upstream, fidelity);
 
         // Build the JSON input payload
-        let node_input = build_node_input(
+        let mut node_input = build_node_input(
             resolved_input.as_deref(),
             &ctx.run_id,
             member_id,
@@ -993,6 +1247,20 @@ pub async fn run_pipeline(ctx: PipelineContext) {
             memory_context.as_deref(),
             upstream_context.as_deref(),
         );
+        if let Some(d) = deadline {
+            crate::engine::deadline::stamp(
+                node_input.get_or_insert_with(|| serde_json::json!({})),
+                d,
+            );
+        }
+        // Scope the node's shared context blackboard to this run.
+        crate::engine::shared_context::stamp_pipeline_run(
+            node_input.get_or_insert_with(|| serde_json::json!({})),
+            &ctx.run_id,
+        );
+        if let Some(ref sim) = ctx.simulation {
+            stamp_simulation(node_input.get_or_insert_with(|| serde_json::json!({})), sim);
+        }
 
         // Execute the node
         let persona_name = persona_repo::get_by_id(&ctx.db, &member.persona_id)
@@ -1009,12 +1277,13 @@ pub async fn run_pipeline(ctx: PipelineContext) {
             node_input,
             &ctx.cancelled,
             &mut statuses,
+            ctx.simulation.as_ref(),
         )
         .await
         {
             NodeOutcome::Completed(output) => {
-                // Auto-create team memory
-                if let Some(ref text) = output {
+                // Auto-create team memory (not from simulated output)
+                if let (Some(ref text), None) = (&output, &ctx.simulation) {
                     if create_node_memory(
                         &ctx.db,
                         &ctx.team_id,
@@ -1045,10 +1314,13 @@ pub async fn run_pipeline(ctx: PipelineContext) {
         }
     }
 
-    // Mark remaining idle nodes as skipped/cancelled
+    // Mark remaining idle nodes as skipped/cancelled. A paused run leaves
+    // them idle so resuming picks them up.
     let was_cancelled = ctx.cancelled.load(Ordering::Relaxed);
     let skip_label = if was_cancelled {
         "cancelled"
+    } else if paused {
+        ""
     } else if has_failure {
         "skipped"
     } else {
@@ -1059,6 +1331,9 @@ pub async fn run_pipeline(ctx: PipelineContext) {
             if ns.get("status").and_then(|v| v.as_str()) == Some("idle") {
                 if let Some(obj) = ns.as_object_mut() {
                     obj.insert("status".into(), serde_json::json!(skip_label));
+                    if deadline_hit {
+                        obj.insert("skip_reason".into(), serde_json::json!("deadline_exceeded"));
+                    }
                 }
             }
         }
@@ -1069,6 +1344,8 @@ pub async fn run_pipeline(ctx: PipelineContext) {
         "cancelled"
     } else if has_failure {
         "failed"
+    } else if paused {
+        "paused"
     } else {
         "completed"
     };
@@ -1094,6 +1371,124 @@ pub async fn run_pipeline(ctx: PipelineContext) {
     }
 }
 
+// ============================================================================
+// Resume / retry — continue a run from its persisted node statuses
+// ============================================================================
+
+/// Skip reasons that record a real branching decision. Other skipped nodes
+/// were only cut short by a failure, cancel or deadline and run again when
+/// the run continues.
+const DECIDED_SKIPS: &[&str] = &["condition_not_met", "upstream_skipped"];
+
+/// Collect the nodes an earlier attempt already finished: `completed` nodes
+/// (their outputs go back into `node_outputs`) and nodes skipped by a
+/// branching decision.
+fn restore_finished_nodes(
+    statuses: &[serde_json::Value],
+    node_outputs: &mut HashMap<String, Option<String>>,
+    skipped: &mut HashSet<String>,
+) -> HashSet<String> {
+    let mut finished = HashSet::new();
+    for ns in statuses {
+        let Some(member_id) = ns.get("member_id").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        match ns.get("status").and_then(|v| v.as_str()) {
+            Some("completed") => {
+                let output = ns.get("output").and_then(|v| v.as_str()).map(String::from);
+                node_outputs.insert(member_id.to_string(), output);
+            }
+            Some("skipped") if is_decided_skip(ns) => {
+                skipped.insert(member_id.to_string());
+            }
+            _ => continue,
+        }
+        finished.insert(member_id.to_string());
+    }
+    finished
+}
+
+fn is_decided_skip(ns: &serde_json::Value) -> bool {
+    ns.get("skip_reason")
+        .and_then(|v| v.as_str())
+        .is_some_and(|r| DECIDED_SKIPS.contains(&r))
+}
+
+/// Prepare a stopped run's node statuses for another attempt.
+///
+/// Every node that didn't finish (failed, cancelled, rejected, or skipped
+/// only because the run stopped) goes back to `idle`. With `retry_member`,
+/// that node and everything downstream of it are reset too, so they re-run
+/// on fresh input while the upstream chain's outputs are reused. Members
+/// added to the team since the run started are appended as `idle`.
+pub fn reset_for_continuation(
+    statuses: &[serde_json::Value],
+    members: &[PersonaTeamMember],
+    connections: &[PersonaTeamConnection],
+    retry_member: Option<&str>,
+) -> Vec<serde_json::Value> {
+    let rerun: HashSet<String> = retry_member
+        .map(|m| downstream_of(m, connections))
+        .unwrap_or_default();
+    let mut out: Vec<serde_json::Value> = statuses
+        .iter()
+        .map(|ns| {
+            let member_id = ns.get("member_id").and_then(|v| v.as_str()).unwrap_or("");
+            let status = ns.get("status").and_then(|v| v.as_str()).unwrap_or("idle");
+            let keep = match status {
+                "completed" => true,
+                "skipped" => is_decided_skip(ns),
+                _ => false,
+            };
+            if keep && !rerun.contains(member_id) {
+                return ns.clone();
+            }
+            let mut idle = serde_json::json!({
+                "member_id": member_id,
+                "persona_id": ns.get("persona_id").cloned().unwrap_or_default(),
+                "status": "idle",
+            });
+            let retries = ns.get("retries").and_then(|v| v.as_u64()).unwrap_or(0);
+            if retry_member == Some(member_id) {
+                idle["retries"] = serde_json::json!(retries + 1);
+            } else if retries > 0 {
+                idle["retries"] = serde_json::json!(retries);
+            }
+            idle
+        })
+        .collect();
+    for m in members {
+        let known = out
+            .iter()
+            .any(|ns| ns.get("member_id").and_then(|v| v.as_str()) == Some(m.id.as_str()));
+        if !known {
+            out.push(serde_json::json!({
+                "member_id": m.id,
+                "persona_id": m.persona_id,
+                "status": "idle",
+            }));
+        }
+    }
+    out
+}
+
+/// `member_id` plus every node reachable from it over non-feedback edges.
+fn downstream_of(member_id: &str, connections: &[PersonaTeamConnection]) -> HashSet<String> {
+    let mut seen = HashSet::from([member_id.to_string()]);
+    let mut stack = vec![member_id.to_string()];
+    while let Some(current) = stack.pop() {
+        for c in connections {
+            if c.connection_type != "feedback"
+                && c.source_member_id == current
+                && seen.insert(c.target_member_id.clone())
+            {
+                stack.push(c.target_member_id.clone());
+            }
+        }
+    }
+    seen
+}
+
 // ============================================================================
 // Input resolution helpers
 // ============================================================================
@@ -1216,6 +1611,20 @@ fn build_node_input(
 mod tests {
     use super::*;
 
+    fn evaluate_condition(condition: &str, output: Option<&str>) -> bool {
+        evaluate_branch(condition, output).0
+    }
+
+    fn should_skip_node(
+        member_id: &str,
+        connections: &[PersonaTeamConnection],
+        outputs: &HashMap<String, Option<String>>,
+    ) -> bool {
+        evaluate_incoming_branches(member_id, connections, outputs)
+            .iter()
+            .any(|b| !b.taken)
+    }
+
     #[test]
     fn test_evaluate_condition_equals_match() {
         let cond = r#"{"field":"type","op":"equals","value":"bug"}"#;
@@ -1400,4 +1809,127 @@ mod tests {
         let outputs = HashMap::new();
         assert!(!should_skip_node("b", &connections, &outputs));
     }
+
+    #[test]
+    fn test_incoming_branches_record_expressions_and_errors() {
+        let edge = |id: &str, source: &str, condition: &str| PersonaTeamConnection {
+            id: id.into(),
+            team_id: "t1".into(),
+            source_member_id: source.into(),
+            target_member_id: "c".into(),
+            connection_type: "conditional".into(),
+            condition: Some(condition.into()),
+            label: None,
+            created_at: "".into(),
+        };
+        let connections = vec![
+            edge("c1", "a", "severity == 'high' && length(items) > 0"),
+            edge("c2", "b", "score >="),
+        ];
+        let mut outputs = HashMap::new();
+        outputs.insert(
+            "a".into(),
+            Some(r#"{"severity":"high","items":[1]}"#.into()),
+        );
+        outputs.insert("b".into(), Some("plain text".into()));
+
+        let branches = evaluate_incoming_branches("c", &connections, &outputs);
+        assert_eq!(branches.len(), 2);
+        assert!(branches[0].taken && branches[0].error.is_none());
+        assert_eq!(branches[0].source_member_id, "a");
+        // A malformed expression is taken by default, with the reason recorded.
+        assert!(branches[1].taken && branches[1].error.is_some());
+
+        outputs.insert("a".into(), Some(r#"{"severity":"low","items":[1]}"#.into()));
+        assert!(should_skip_node("c", &connections, &outputs));
+    }
+
+    #[test]
+    fn test_reset_for_continuation_reuses_upstream_and_reruns_downstream() {
+        // a -> b -> c, with d gated off a conditional edge from a.
+        let edge = |id: &str, from: &str, to: &str| PersonaTeamConnection {
+            id: id.into(),
+            team_id: "t1".into(),
+            source_member_id: from.into(),
+            target_member_id: to.into(),
+            connection_type: "sequential".into(),
+            condition: None,
+            label: None,
+            created_at: "".into(),
+        };
+        let connections = vec![
+            edge("c1", "a", "b"),
+            edge("c2", "b", "c"),
+            edge("c3", "a", "d"),
+        ];
+        let members: Vec<PersonaTeamMember> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|id| PersonaTeamMember {
+                id: (*id).into(),
+                team_id: "t1".into(),
+                persona_id: format!("p-{id}"),
+                role: "worker".into(),
+                position_x: 0.0,
+                position_y: 0.0,
+                config: None,
+                created_at: "".into(),
+            })
+            .collect();
+        let statuses = vec![
+            serde_json::json!({"member_id": "a", "status": "completed", "output": "A"}),
+            serde_json::json!({"member_id": "b", "status": "failed", "error": "boom"}),
+            serde_json::json!({"member_id": "c", "status": "skipped"}),
+            serde_json::json!({
+                "member_id": "d",
+                "status": "skipped",
+                "skip_reason": "condition_not_met",
+            }),
+        ];
+
+        let resumed = reset_for_continuation(&statuses, &members, &connections, None);
+        let status_of = |all: &[serde_json::Value], id: &str| {
+            all.iter()
+                .find(|ns| ns["member_id"] == id)
+                .map(|ns| ns["status"].as_str().unwrap().to_string())
+                .unwrap()
+        };
+        assert_eq!(status_of(&resumed, "a"), "completed");
+        assert_eq!(status_of(&resumed, "b"), "idle");
+        assert_eq!(status_of(&resumed, "c"), "idle");
+        assert_eq!(status_of(&resumed, "d"), "skipped");
+        // Members added since the run started join as idle.
+        assert_eq!(status_of(&resumed, "e"), "idle");
+        assert!(resumed[1].get("error").is_none());
+
+        // Retrying `a` re-runs it and its whole downstream chain, including
+        // the gated branch whose condition depends on a's new output.
+        let retried = reset_for_continuation(&statuses, &members, &connections, Some("a"));
+        for id in ["a", "b", "c", "d"] {
+            assert_eq!(status_of(&retried, id), "idle", "{id}");
+        }
+        assert_eq!(retried[0]["retries"], 1);
+
+        let mut outputs = HashMap::new();
+        let mut skipped = HashSet::new();
+        let finished = restore_finished_nodes(&resumed, &mut outputs, &mut skipped);
+        assert_eq!(finished, HashSet::from(["a".to_string(), "d".to_string()]));
+        assert_eq!(outputs.get("a"), Some(&Some("A".to_string())));
+        assert!(skipped.contains("d"));
+    }
+
+    #[test]
+    fn simulated_tool_calls_are_read_from_protocol_lines() {
+        let output = concat!(
+            "Checking the ticket first.\n",
+            r#"{"simulated_tool_call": {"tool": "jira_get_issue", "input": {"key": "OPS-1"}}}"#,
+            "\n```json\n{\"summary\": \"not a call\"}\n```\n",
+            r#"  {"simulated_tool_call": {"tool": "slack_post", "input": {"text": "done"}}}"#,
+            "\n{\"simulated_tool_call\": broken\n",
+        );
+        let calls = parse_simulated_tool_calls(output);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0]["tool"], "jira_get_issue");
+        assert_eq!(calls[0]["input"]["key"], "OPS-1");
+        assert_eq!(calls[1]["tool"], "slack_post");
+    }
 }
@@ -164,7 +164,7 @@ fn purge_stale_backoff(pool: &DbPool) {
 
 /// Run one polling cycle: fetch all enabled polling triggers that are due,
 /// GET their configured endpoints, compare content hashes, and fire events
-/// when content changes.
+/// when content changes. Due `email_inbound` triggers ride the same cycle.
 pub async fn poll_due_triggers(pool: &DbPool, scheduler: &SchedulerState, http: &reqwest::Client) {
     // Sweep backoff entries for triggers that were deleted since the last cycle
     purge_stale_backoff(pool);
@@ -190,12 +190,145 @@ pub async fn poll_due_triggers(pool: &DbPool, scheduler: &SchedulerState, http:
     futures_util::stream::iter(
         triggers
             .into_iter()
-            .filter(|t| t.trigger_type == "polling"),
+            .filter(|t| matches!(t.trigger_type.as_str(), "polling" | "email_inbound")),
     )
-    .for_each_concurrent(4, |trigger| poll_one_trigger(pool, scheduler, http, now, trigger))
+    .for_each_concurrent(4, |trigger| async move {
+        if trigger.trigger_type == "email_inbound" {
+            poll_email_trigger(pool, scheduler, http, now, trigger).await;
+        } else {
+            poll_one_trigger(pool, scheduler, http, now, trigger).await;
+        }
+    })
     .await;
 }
 
+/// Poll a due `email_inbound` trigger: fetch messages newer than the stored
+/// cursor, CAS-advance the cursor, then publish one event per message. The
+/// cursor is committed before publishing so a crash between the two loses
+/// at most one batch instead of replaying it on every restart.
+async fn poll_email_trigger(
+    pool: &DbPool,
+    scheduler: &SchedulerState,
+    http: &reqwest::Client,
+    now: chrono::DateTime<chrono::Utc>,
+    trigger: crate::db::models::PersonaTrigger,
+) {
+    use crate::engine::email::inbound;
+
+    let next = sched_logic::compute_next_trigger_at(&trigger, now);
+    if !trigger.is_within_active_window(now) {
+        try_mark_triggered(pool, &trigger.id, next, trigger.trigger_version);
+        return;
+    }
+    if is_in_backoff(&trigger.id) {
+        return;
+    }
+
+    let (filter, cursor, mark_read) = match trigger.parse_config() {
+        crate::db::models::TriggerConfig::EmailInbound {
+            credential_id: Some(credential_id),
+            mailbox,
+            from,
+            subject,
+            unread_only,
+            mark_read,
+            cursor,
+            ..
+        } if !credential_id.is_empty() => (
+            inbound::InboundFilter {
+                credential_id,
+                mailbox: mailbox.filter(|m| !m.trim().is_empty()),
+                from: from.filter(|f| !f.trim().is_empty()),
+                subject: subject.filter(|s| !s.trim().is_empty()),
+                unread_only: unread_only.unwrap_or(false),
+            },
+            cursor.and_then(|c| serde_json::from_value::<inbound::PollCursor>(c).ok()),
+            mark_read.unwrap_or(false),
+        ),
+        _ => {
+            tracing::warn!(trigger_id = %trigger.id, "Email trigger missing 'credential_id' in config");
+            try_mark_triggered(pool, &trigger.id, next, trigger.trigger_version);
+            return;
+        }
+    };
+
+    let result = match inbound::poll(pool, http, &filter, cursor).await {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!(trigger_id = %trigger.id, "Email poll failed: {}", e);
+            try_mark_triggered(pool, &trigger.id, next, trigger.trigger_version);
+            return;
+        }
+    };
+
+    let cursor_json = serde_json::to_string(&result.cursor).unwrap_or_else(|_| "{}".into());
+    match trigger_repo::mark_email_polled(
+        pool,
+        &trigger.id,
+        &cursor_json,
+        next,
+        trigger.trigger_version,
+    ) {
+        Ok(true) => clear_backoff(&trigger.id),
+        Ok(false) => {
+            tracing::debug!(
+                trigger_id = %trigger.id,
+                "Email poll: CAS failed (cursor already advanced), skipping duplicate events"
+            );
+            return;
+        }
+        Err(e) => {
+            tracing::error!(trigger_id = %trigger.id, "mark_email_polled failed: {}", e);
+            record_mark_failure(&trigger.id);
+            return;
+        }
+    }
+
+    let event_type = sched_logic::trigger_event_type(&trigger);
+    let mut published = Vec::with_capacity(result.messages.len());
+    for email in &result.messages {
+        match event_repo::publish(
+            pool,
+            CreatePersonaEventInput {
+                event_type: event_type.clone(),
+                source_type: "email_inbound".into(),
+                source_id: Some(trigger.id.clone()),
+                target_persona_id: Some(trigger.persona_id.clone()),
+                project_id: None,
+                payload: Some(serde_json::to_string(email).unwrap_or_default()),
+                use_case_id: trigger.use_case_id.clone(),
+            },
+        ) {
+            Ok(_) => {
+                published.push(email.id.clone());
+                scheduler
+                    .triggers_fired
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
+            Err(e) => {
+                tracing::error!(
+                    trigger_id = %trigger.id,
+                    message_id = %email.id,
+                    "Email poll: failed to publish event: {}", e
+                );
+            }
+        }
+    }
+    if !published.is_empty() {
+        tracing::info!(
+            trigger_id = %trigger.id,
+            count = published.len(),
+            "Email poll: new messages published"
+        );
+    }
+
+    if mark_read {
+        if let Err(e) = inbound::mark_read(pool, http, &filter, &published).await {
+            tracing::warn!(trigger_id = %trigger.id, "Email poll: failed to mark messages read: {}", e);
+        }
+    }
+}
+
 /// Poll a single due trigger: active-window/backoff gates, SSRF check, HTTP
 /// GET, hash-compare, CAS-advance + event publish. Extracted from the former
 /// inline loop body of `poll_due_triggers` so independent triggers can run
@@ -0,0 +1,201 @@
+//! Orphaned CLI process reaper.
+//!
+//! Every CLI the engine spawns carries [`SPAWN_TAG_ENV`] = `personas:<app pid>`
+//! in its environment, and execution runs additionally carry
+//! [`EXECUTION_ID_ENV`]. `kill_on_drop` covers every orderly exit, but a crash,
+//! force-quit, or a panic on a path that leaks the `Child` leaves the CLI
+//! running — and billing — with nothing tracking it. The reaper scans the OS
+//! process table for the tag and kills:
+//!
+//! - processes tagged by a **dead** app instance (the previous session
+//!   crashed); processes owned by another live instance are left alone;
+//! - processes tagged by **this** instance whose PID is not in the engine's
+//!   `child_pids` map, once they are older than [`TRACKING_GRACE_SECS`] (the
+//!   window between `spawn` and `register_pid`).
+//!
+//! Runs shortly after startup and then periodically
+//! (`OrphanProcessReaperSubscription`). When a reaped process names its
+//! execution, a healing note is filed on it.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, System};
+use tokio::sync::Mutex;
+
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::DbPool;
+
+/// Env var tagging every engine-spawned CLI with its owning app instance.
+pub const SPAWN_TAG_ENV: &str = "PERSONAS_SPAWN_TAG";
+/// Env var naming the execution an engine-spawned CLI belongs to.
+pub const EXECUTION_ID_ENV: &str = "PERSONAS_EXECUTION_ID";
+
+const SPAWN_TAG_PREFIX: &str = "personas:";
+
+/// Seconds a freshly spawned, untracked process of this instance is given to
+/// show up in `child_pids` before it counts as leaked.
+const TRACKING_GRACE_SECS: u64 = 120;
+
+/// Tag value for processes spawned by this app instance.
+pub fn spawn_tag() -> String {
+    format!("{SPAWN_TAG_PREFIX}{}", std::process::id())
+}
+
+/// Attach the spawn tag to a command about to be spawned.
+pub fn tag_command(cmd: &mut tokio::process::Command) {
+    cmd.env(SPAWN_TAG_ENV, spawn_tag());
+}
+
+/// Owning app PID encoded in a spawn tag.
+fn parse_spawn_tag(value: &str) -> Option<u32> {
+    value.strip_prefix(SPAWN_TAG_PREFIX)?.parse().ok()
+}
+
+/// Reap decision for one tagged process.
+pub(crate) fn should_reap(
+    owner_pid: u32,
+    self_pid: u32,
+    owner_alive: bool,
+    tracked: bool,
+    age_secs: u64,
+) -> bool {
+    if owner_pid == self_pid {
+        !tracked && age_secs >= TRACKING_GRACE_SECS
+    } else {
+        !owner_alive
+    }
+}
+
+/// One process the reaper killed.
+#[derive(Debug, Clone)]
+pub struct ReapedProcess {
+    pub pid: u32,
+    pub owner_pid: u32,
+    pub execution_id: Option<String>,
+}
+
+/// Scan the process table and kill orphaned engine CLIs. Blocking — call from
+/// `spawn_blocking`.
+fn scan_and_kill(tracked: &HashSet<u32>) -> Vec<ReapedProcess> {
+    let sys = System::new_with_specifics(
+        RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
+    );
+    let self_pid = std::process::id();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut reaped = Vec::new();
+    for (pid, process) in sys.processes() {
+        let mut owner_pid = None;
+        let mut execution_id = None;
+        for var in process.environ() {
+            let var = var.to_string_lossy();
+            if let Some(v) = var.strip_prefix(SPAWN_TAG_ENV).and_then(|r| r.strip_prefix('=')) {
+                owner_pid = parse_spawn_tag(v);
+            } else if let Some(v) = var.strip_prefix(EXECUTION_ID_ENV).and_then(|r| r.strip_prefix('=')) {
+                execution_id = Some(v.to_string());
+            }
+        }
+        let Some(owner_pid) = owner_pid else {
+            continue;
+        };
+        let pid = pid.as_u32();
+        if pid == self_pid {
+            continue;
+        }
+        let owner_alive = sys.process(Pid::from_u32(owner_pid)).is_some();
+        let age_secs = now.saturating_sub(process.start_time());
+        if !should_reap(owner_pid, self_pid, owner_alive, tracked.contains(&pid), age_secs) {
+            continue;
+        }
+        tracing::warn!(
+            pid,
+            owner_pid,
+            execution_id = ?execution_id,
+            "process reaper: killing orphaned CLI process"
+        );
+        super::kill_process(pid);
+        reaped.push(ReapedProcess {
+            pid,
+            owner_pid,
+            execution_id,
+        });
+    }
+    reaped
+}
+
+/// File a healing note on the execution a reaped process belonged to.
+fn record_reaped(pool: &DbPool, reaped: &ReapedProcess) {
+    let Some(exec_id) = reaped.execution_id.as_deref() else {
+        return;
+    };
+    let Ok(execution) = exec_repo::get_by_id(pool, exec_id) else {
+        return;
+    };
+    let description = format!(
+        "CLI process {} for this execution was still running after its app instance (pid {}) lost track of it, and was terminated by the orphan reaper.",
+        reaped.pid, reaped.owner_pid
+    );
+    if let Err(e) = healing_repo::create(
+        pool,
+        &execution.persona_id,
+        "Orphaned CLI process terminated",
+        &description,
+        false,
+        Some("low"),
+        Some("process"),
+        Some(exec_id),
+        Some("No action needed. If this recurs, check for app crashes or forced shutdowns during runs."),
+    ) {
+        tracing::warn!(execution_id = %exec_id, error = %e, "process reaper: failed to record healing note");
+    }
+}
+
+/// One reaper pass: snapshot tracked PIDs, kill orphans, record notes.
+pub async fn reap_orphans(
+    pool: &DbPool,
+    child_pids: &Arc<Mutex<HashMap<String, u32>>>,
+) -> Vec<ReapedProcess> {
+    let tracked: HashSet<u32> = child_pids.lock().await.values().copied().collect();
+    let reaped = match tokio::task::spawn_blocking(move || scan_and_kill(&tracked)).await {
+        Ok(r) => r,
+        Err(e) => {
+            tracing::warn!(error = %e, "process reaper: scan task failed");
+            return Vec::new();
+        }
+    };
+    for r in &reaped {
+        record_reaped(pool, r);
+    }
+    if !reaped.is_empty() {
+        tracing::info!(count = reaped.len(), "process reaper: orphaned CLI processes terminated");
+    }
+    reaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_tag_round_trips() {
+        assert_eq!(parse_spawn_tag(&spawn_tag()), Some(std::process::id()));
+        assert_eq!(parse_spawn_tag("personas:abc"), None);
+        assert_eq!(parse_spawn_tag("other:12"), None);
+    }
+
+    #[test]
+    fn reap_rules() {
+        // Dead previous instance → reap; another live instance → leave alone.
+        assert!(should_reap(10, 20, false, false, 0));
+        assert!(!should_reap(10, 20, true, false, 9_999));
+        // Own instance: only untracked processes past the grace window.
+        assert!(!should_reap(20, 20, true, true, 9_999));
+        assert!(!should_reap(20, 20, true, false, 5));
+        assert!(should_reap(20, 20, true, false, TRACKING_GRACE_SECS));
+    }
+}
@@ -0,0 +1,202 @@
+//! Whole-tree termination for engine-spawned CLI processes.
+//!
+//! `taskkill /T` walks parent links that Windows does not keep reliably, and
+//! `kill -9 <pid>` only reaches the CLI itself — node subprocesses and MCP
+//! servers it launched survive a cancel and keep running. Children spawned
+//! through [`CliProcessDriver`](super::cli_process::CliProcessDriver) are
+//! therefore isolated at spawn time:
+//!
+//! - **Unix**: the child becomes the leader of its own process group
+//!   (`process_group(0)`), so `killpg` reaches every descendant that did not
+//!   deliberately leave the group.
+//! - **Windows**: the child is assigned to a Job Object created with
+//!   `JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE`. Descendants inherit the job, and
+//!   `TerminateJobObject` ends all of them atomically. Closing the job handle
+//!   (driver drop, or the app dying) has the same effect.
+//!
+//! [`kill_tree`] is consulted first by `engine::kill_process`; PIDs that were
+//! not isolated fall back to the old `taskkill` / `kill -9` path.
+
+#[cfg(windows)]
+use std::collections::HashMap;
+#[cfg(windows)]
+use std::sync::{LazyLock, Mutex};
+
+/// Job handle stored as an integer so the registry is `Send`.
+#[cfg(windows)]
+struct JobHandle(isize);
+
+#[cfg(windows)]
+impl JobHandle {
+    fn raw(&self) -> windows::Win32::Foundation::HANDLE {
+        windows::Win32::Foundation::HANDLE(self.0 as *mut core::ffi::c_void)
+    }
+}
+
+#[cfg(windows)]
+impl Drop for JobHandle {
+    fn drop(&mut self) {
+        // KILL_ON_JOB_CLOSE: anything still in the job dies with the handle.
+        let _ = unsafe { windows::Win32::Foundation::CloseHandle(self.raw()) };
+    }
+}
+
+/// Jobs of live isolated children, keyed by the root PID.
+#[cfg(windows)]
+static JOBS: LazyLock<Mutex<HashMap<u32, JobHandle>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Configure `cmd` so its whole process tree can be killed later. Call before
+/// `spawn`, then [`attach`] the spawned child.
+pub(crate) fn isolate(cmd: &mut tokio::process::Command) {
+    #[cfg(unix)]
+    {
+        cmd.process_group(0);
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = cmd;
+    }
+}
+
+/// Finish isolating a freshly spawned child. On Windows this creates the Job
+/// Object and assigns the child to it; grandchildren spawned before the
+/// assignment lands (microseconds after `spawn`) are not covered. Best-effort:
+/// a failure leaves the child on the fallback kill path.
+pub(crate) fn attach(child: &tokio::process::Child) {
+    #[cfg(windows)]
+    {
+        let (Some(pid), Some(handle)) = (child.id(), child.raw_handle()) else {
+            return;
+        };
+        match create_job_for(handle) {
+            Ok(job) => {
+                if let Ok(mut jobs) = JOBS.lock() {
+                    jobs.insert(pid, job);
+                }
+            }
+            Err(e) => tracing::warn!(pid, error = %e, "Could not assign CLI to a job object"),
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = child;
+    }
+}
+
+#[cfg(windows)]
+fn create_job_for(process: std::os::windows::io::RawHandle) -> windows::core::Result<JobHandle> {
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+
+    unsafe {
+        let job = JobHandle(CreateJobObjectW(None, windows::core::PCWSTR::null())?.0 as isize);
+        let mut info = JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+        info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+        SetInformationJobObject(
+            job.raw(),
+            JobObjectExtendedLimitInformation,
+            &info as *const _ as *const core::ffi::c_void,
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        )?;
+        AssignProcessToJobObject(job.raw(), HANDLE(process as *mut core::ffi::c_void))?;
+        Ok(job)
+    }
+}
+
+/// Kill `pid` and every descendant in its job / process group. Returns `false`
+/// when `pid` was not isolated, so the caller can fall back to a plain kill.
+pub(crate) fn kill_tree(pid: u32) -> bool {
+    #[cfg(windows)]
+    {
+        let job = JOBS.lock().ok().and_then(|mut jobs| jobs.remove(&pid));
+        match job {
+            Some(job) => {
+                let _ = unsafe { windows::Win32::System::JobObjects::TerminateJobObject(job.raw(), 1) };
+                true
+            }
+            None => false,
+        }
+    }
+    #[cfg(unix)]
+    {
+        let Ok(pid) = i32::try_from(pid) else {
+            return false;
+        };
+        // Only signal groups this child leads — never our own group.
+        let pgid = unsafe { libc::getpgid(pid) };
+        if pgid != pid || pgid == unsafe { libc::getpgrp() } {
+            return false;
+        }
+        unsafe { libc::killpg(pgid, libc::SIGKILL) == 0 }
+    }
+    #[cfg(not(any(windows, unix)))]
+    {
+        let _ = pid;
+        false
+    }
+}
+
+/// Forget the job of a child whose driver is going away. Closing the handle
+/// kills any descendants still running on Windows; on Unix this is a no-op
+/// and stragglers are left to the orphan reaper (they carry the spawn tag).
+pub(crate) fn release(pid: u32) {
+    #[cfg(windows)]
+    {
+        if let Ok(mut jobs) = JOBS.lock() {
+            jobs.remove(&pid);
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        let _ = pid;
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncBufReadExt;
+
+    fn is_gone(pid: u32) -> bool {
+        // Killed-but-unreaped processes linger as zombies (`Z`) under PID 1.
+        match std::fs::read_to_string(format!("/proc/{pid}/stat")) {
+            Ok(stat) => stat
+                .rsplit(')')
+                .next()
+                .is_some_and(|rest| rest.trim_start().starts_with('Z')),
+            Err(_) => true,
+        }
+    }
+
+    #[tokio::test]
+    async fn kill_tree_reaches_grandchildren() {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.args(["-c", "sleep 30 & echo $!; wait"])
+            .stdout(std::process::Stdio::piped())
+            .kill_on_drop(true);
+        isolate(&mut cmd);
+        let mut child = cmd.spawn().unwrap();
+        attach(&child);
+        let pid = child.id().unwrap();
+
+        let mut lines = tokio::io::BufReader::new(child.stdout.take().unwrap()).lines();
+        let grandchild: u32 = lines.next_line().await.unwrap().unwrap().trim().parse().unwrap();
+        assert!(!is_gone(grandchild));
+
+        assert!(kill_tree(pid));
+        let _ = child.wait().await;
+        for _ in 0..50 {
+            if is_gone(grandchild) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        assert!(is_gone(grandchild), "grandchild survived the tree kill");
+        release(pid);
+    }
+}
@@ -0,0 +1,243 @@
+//! Per-project data keys.
+//!
+//! Event payloads used to be encrypted with the single app master key. Each
+//! project (the `project_id` of `persona_events`, `default` included) now gets
+//! its own AES-256 data key, created on first use and stored in
+//! `project_data_keys` wrapped by the master key. Revoking a project's key
+//! erases the key material, which makes every payload written under it
+//! unreadable without touching any other project's data.
+//!
+//! Ciphertext written under a project key carries the key id in its IV column
+//! (`pk:<key_id>:<nonce>`); [`decrypt`] routes on that prefix and falls back
+//! to the master key for everything else, so rows written before this module
+//! keep decrypting unchanged.
+//!
+//! Decryption runs in row mappers without a pool, so unwrapped keys live in a
+//! process-wide cache: [`load_all`] warms it at startup and key creation adds
+//! to it. [`encrypt_for_project`] / [`decrypt`] are storage-agnostic — memory
+//! content can move onto the same pair once memories carry a project.
+
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key};
+use base64::engine::general_purpose::STANDARD as B64;
+use base64::Engine as _;
+use zeroize::Zeroize;
+
+use super::crypto::{self, CryptoError};
+use crate::db::repos::core::project_keys::{self as repo, WrappedProjectKey};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// IV prefix marking ciphertext written under a project key.
+pub const IV_PREFIX: &str = "pk:";
+
+/// Project every event belongs to unless it names one; never revocable.
+pub const DEFAULT_PROJECT: &str = "default";
+
+/// Unwrapped ciphers by key id.
+static CIPHERS: LazyLock<RwLock<HashMap<String, Arc<Aes256Gcm>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn cached(key_id: &str) -> Option<Arc<Aes256Gcm>> {
+    CIPHERS.read().ok()?.get(key_id).cloned()
+}
+
+fn cache(key_id: &str, cipher: Aes256Gcm) -> Arc<Aes256Gcm> {
+    let cipher = Arc::new(cipher);
+    if let Ok(mut map) = CIPHERS.write() {
+        map.insert(key_id.to_string(), cipher.clone());
+    }
+    cipher
+}
+
+fn cipher_from_raw(raw: &[u8]) -> Aes256Gcm {
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(raw))
+}
+
+fn unwrap_key(key: &WrappedProjectKey) -> Result<Aes256Gcm, CryptoError> {
+    let mut encoded = crypto::decrypt_from_db(&key.wrapped_key, &key.wrap_iv)?;
+    let decoded = B64.decode(&encoded);
+    encoded.zeroize();
+    let mut raw = decoded?;
+    if raw.len() != 32 {
+        raw.zeroize();
+        return Err(CryptoError::KeyManagement(format!(
+            "Project data key {} has invalid length",
+            key.id
+        )));
+    }
+    let cipher = cipher_from_raw(&raw);
+    raw.zeroize();
+    Ok(cipher)
+}
+
+/// Unwrap every active project key into the cache. Called once at startup,
+/// after the master key is available. Returns the number of keys loaded.
+pub fn load_all(pool: &DbPool) -> Result<usize, AppError> {
+    let mut loaded = 0;
+    for key in repo::list_active(pool)? {
+        match unwrap_key(&key) {
+            Ok(cipher) => {
+                cache(&key.id, cipher);
+                loaded += 1;
+            }
+            Err(e) => tracing::warn!(
+                project_id = %key.project_id,
+                key_id = %key.id,
+                "Failed to unwrap project data key: {}", e
+            ),
+        }
+    }
+    Ok(loaded)
+}
+
+/// The project's active key, created (and wrapped) on first use.
+fn active_cipher(pool: &DbPool, project_id: &str) -> Result<(String, Arc<Aes256Gcm>), AppError> {
+    if let Some(key) = repo::get_active(pool, project_id)? {
+        if let Some(cipher) = cached(&key.id) {
+            return Ok((key.id, cipher));
+        }
+        let cipher = unwrap_key(&key)?;
+        return Ok((key.id.clone(), cache(&key.id, cipher)));
+    }
+
+    let mut raw = [0u8; 32];
+    OsRng.fill_bytes(&mut raw);
+    let mut encoded = B64.encode(raw);
+    let wrapped = crypto::encrypt_for_db(&encoded);
+    encoded.zeroize();
+    let cipher = cipher_from_raw(&raw);
+    raw.zeroize();
+    let (wrapped_key, wrap_iv) = wrapped?;
+
+    let key = WrappedProjectKey {
+        id: uuid::Uuid::new_v4().to_string(),
+        project_id: project_id.to_string(),
+        wrapped_key,
+        wrap_iv,
+    };
+    if !repo::insert_active(pool, &key)? {
+        // A concurrent writer created the project's key first; use theirs.
+        return active_cipher(pool, project_id);
+    }
+    tracing::info!(project_id = %project_id, key_id = %key.id, "Created project data key");
+    Ok((key.id.clone(), cache(&key.id, cipher)))
+}
+
+/// Encrypt under the project's data key, returning `(ciphertext, iv)` for the
+/// same two columns [`crypto::encrypt_for_db`] fills.
+pub fn encrypt_for_project(
+    pool: &DbPool,
+    project_id: &str,
+    plaintext: &str,
+) -> Result<(String, String), AppError> {
+    let (key_id, cipher) = active_cipher(pool, project_id)?;
+    let (ciphertext, nonce) = crypto::encrypt_with(&cipher, plaintext)?;
+    Ok((ciphertext, format!("{IV_PREFIX}{key_id}:{nonce}")))
+}
+
+/// Decrypt a `(ciphertext, iv)` pair written by [`encrypt_for_project`] or by
+/// [`crypto::encrypt_for_db`]. Fails for payloads whose project key was
+/// revoked.
+pub fn decrypt(ciphertext_b64: &str, iv: &str) -> Result<String, CryptoError> {
+    let Some(tagged) = iv.strip_prefix(IV_PREFIX) else {
+        return crypto::decrypt_from_db(ciphertext_b64, iv);
+    };
+    let (key_id, nonce) = tagged
+        .split_once(':')
+        .ok_or_else(|| CryptoError::Decrypt("Malformed project key IV".into()))?;
+    let cipher = cached(key_id).ok_or_else(|| {
+        CryptoError::KeyManagement(format!(
+            "Project data key {key_id} is revoked or not loaded"
+        ))
+    })?;
+    crypto::decrypt_with(&cipher, ciphertext_b64, nonce)
+}
+
+/// Encrypt an optional event payload for at-rest storage under its project's
+/// key, falling back to the master key and then to plaintext (logged) so an
+/// event is never dropped over a key problem. Returns
+/// `(stored_payload, payload_iv)`.
+pub fn encrypt_event_payload(
+    pool: &DbPool,
+    project_id: &str,
+    payload: &Option<String>,
+) -> (Option<String>, Option<String>) {
+    let plaintext = match payload {
+        Some(p) if !p.is_empty() => p,
+        other => return (other.clone(), None),
+    };
+    match encrypt_for_project(pool, project_id, plaintext) {
+        Ok((ct, iv)) => return (Some(ct), Some(iv)),
+        Err(e) => tracing::warn!(
+            project_id = %project_id,
+            "Project data key unavailable, using master key for event payload: {}", e
+        ),
+    }
+    match crypto::encrypt_for_db(plaintext) {
+        Ok((ct, iv)) => (Some(ct), Some(iv)),
+        Err(e) => {
+            tracing::warn!("Failed to encrypt event payload, storing plaintext: {}", e);
+            (Some(plaintext.clone()), None)
+        }
+    }
+}
+
+/// Revoke a project's data key (e.g. once the project is archived). The key
+/// material is erased, so payloads written under it can no longer be read;
+/// the next write for the project creates a fresh key. Returns the number of
+/// event payloads sealed.
+pub fn revoke(pool: &DbPool, project_id: &str) -> Result<i64, AppError> {
+    if project_id == DEFAULT_PROJECT {
+        return Err(AppError::Validation(
+            "The default project's data key cannot be revoked".into(),
+        ));
+    }
+    let mut sealed = 0;
+    for key_id in repo::revoke(pool, project_id)? {
+        if let Ok(mut map) = CIPHERS.write() {
+            map.remove(&key_id);
+        }
+        sealed += repo::count_event_payloads(pool, &format!("{IV_PREFIX}{key_id}:"))?;
+        tracing::info!(project_id = %project_id, key_id = %key_id, "Revoked project data key");
+    }
+    Ok(sealed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    #[test]
+    fn project_keys_isolate_payloads_and_revoke_selectively() {
+        let pool = init_test_db().unwrap();
+        let (ct_a, iv_a) = encrypt_for_project(&pool, "proj-a", "alpha").unwrap();
+        let (ct_b, iv_b) = encrypt_for_project(&pool, "proj-b", "beta").unwrap();
+        assert!(iv_a.starts_with(IV_PREFIX));
+        assert_ne!(
+            iv_a.split(':').nth(1),
+            iv_b.split(':').nth(1),
+            "each project gets its own key"
+        );
+        assert_eq!(decrypt(&ct_a, &iv_a).unwrap(), "alpha");
+
+        // Master-key ciphertext still decrypts through the same entry point.
+        let (ct_m, iv_m) = crypto::encrypt_for_db("legacy").unwrap();
+        assert_eq!(decrypt(&ct_m, &iv_m).unwrap(), "legacy");
+
+        assert!(revoke(&pool, DEFAULT_PROJECT).is_err());
+        revoke(&pool, "proj-a").unwrap();
+        assert!(decrypt(&ct_a, &iv_a).is_err());
+        assert_eq!(decrypt(&ct_b, &iv_b).unwrap(), "beta");
+
+        // A revoked project starts over with a fresh key.
+        let (ct_a2, iv_a2) = encrypt_for_project(&pool, "proj-a", "again").unwrap();
+        assert_ne!(iv_a2.split(':').nth(1), iv_a.split(':').nth(1));
+        assert_eq!(decrypt(&ct_a2, &iv_a2).unwrap(), "again");
+    }
+}
@@ -0,0 +1,392 @@
+//! Prompt token budget.
+//!
+//! [`estimate_tokens`] approximates a BPE tokenizer by pre-tokenizing the way
+//! GPT/Claude tokenizers do (letter runs, digit runs, punctuation, whitespace)
+//! and charging each piece what such tokenizers typically spend on it, which
+//! tracks code, JSON and non-English text far better than a flat
+//! chars-per-token ratio.
+//!
+//! [`prompt_budget`] splits a compiled prompt on its `## ` section headers and
+//! groups the sections into system prompt, memories, tools and input,
+//! measured against the model's context window (less [`OUTPUT_RESERVE_TOKENS`]
+//! for the reply). [`fit_to_budget`] drops optional sections — memories first — until
+//! an over-budget prompt fits, so a run starts with a trimmed prompt instead
+//! of failing mid-run. Input size is bounded separately by
+//! `engine::input_budget`, and the system prompt and input are never trimmed.
+
+use std::sync::LazyLock;
+
+use serde::Serialize;
+use ts_rs::TS;
+
+/// Tokens kept free for the model's reply.
+pub const OUTPUT_RESERVE_TOKENS: u64 = 16_000;
+
+/// Share of the available window above which the budget warns.
+const WARN_RATIO: f64 = 0.8;
+
+/// Context window assumed for models not listed in [`context_window`].
+const DEFAULT_CONTEXT_WINDOW: u64 = 128_000;
+
+static PIECES: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"[A-Za-z]+|[0-9]+|\s+|[^\sA-Za-z0-9]").unwrap());
+
+/// Approximate token count of `text`.
+///
+/// - a letter run is one token per 6 letters (common words are one token,
+///   long identifiers split);
+/// - a digit run is one token per 3 digits;
+/// - a single space is merged into the next word; other whitespace runs
+///   (newlines, indentation) are one token;
+/// - every other character — punctuation, symbols, CJK, emoji — is one.
+pub fn estimate_tokens(text: &str) -> u64 {
+    PIECES
+        .find_iter(text)
+        .map(|piece| {
+            let s = piece.as_str();
+            let first = s.chars().next().unwrap_or(' ');
+            let n = s.len() as u64;
+            if first.is_ascii_alphabetic() {
+                n.div_ceil(6)
+            } else if first.is_ascii_digit() {
+                n.div_ceil(3)
+            } else if first.is_whitespace() {
+                u64::from(s != " ")
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// Context window in tokens of `model`.
+pub fn context_window(model: &str) -> u64 {
+    let m = model.to_lowercase();
+    if m.contains("[1m]") || m.ends_with("-1m") {
+        1_000_000
+    } else if m.contains("claude")
+        || m.contains("opus")
+        || m.contains("sonnet")
+        || m.contains("haiku")
+    {
+        200_000
+    } else if m.contains("gemini") || m.contains("gpt-4.1") {
+        1_000_000
+    } else if m.contains("gpt-5") {
+        400_000
+    } else if m.contains("qwen") {
+        131_072
+    } else if m.contains("gpt-3.5") {
+        16_385
+    } else {
+        DEFAULT_CONTEXT_WINDOW
+    }
+}
+
+/// What a prompt section is spent on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PromptSectionKind {
+    SystemPrompt,
+    Memories,
+    Tools,
+    Input,
+}
+
+/// One `## ` section of a compiled prompt.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PromptSectionTokens {
+    /// Header text without the `## `; empty for the preamble.
+    pub heading: String,
+    pub kind: PromptSectionKind,
+    #[ts(type = "number")]
+    pub tokens: u64,
+}
+
+/// Token counts of a compiled prompt against a model's context window.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct PromptBudget {
+    pub model: String,
+    #[ts(type = "number")]
+    pub context_window: u64,
+    /// Tokens the prompt may use: the window less the output reserve.
+    #[ts(type = "number")]
+    pub available_tokens: u64,
+    #[ts(type = "number")]
+    pub total_tokens: u64,
+    #[ts(type = "number")]
+    pub system_prompt_tokens: u64,
+    #[ts(type = "number")]
+    pub memory_tokens: u64,
+    #[ts(type = "number")]
+    pub tool_tokens: u64,
+    #[ts(type = "number")]
+    pub input_tokens: u64,
+    pub sections: Vec<PromptSectionTokens>,
+    /// `ok` | `warning` (over 80% of the available tokens) | `over`.
+    pub status: String,
+    pub warnings: Vec<String>,
+    /// Headings of the sections [`fit_to_budget`] would drop to bring the prompt
+    /// under budget, in drop order.
+    pub trimmable: Vec<String>,
+}
+
+/// Header prefixes and the kind of the sections they open. Any other `## `
+/// header counts as system prompt.
+const SECTION_KINDS: &[(&str, PromptSectionKind)] = &[
+    ("Agent Memory —", PromptSectionKind::Memories),
+    ("Team Shared Knowledge", PromptSectionKind::Memories),
+    ("Prior Human Feedback", PromptSectionKind::Memories),
+    ("Learned Skills", PromptSectionKind::Memories),
+    ("Available Tools", PromptSectionKind::Tools),
+    ("Protocol Tools", PromptSectionKind::Tools),
+    ("Personas Tool Semantics", PromptSectionKind::Tools),
+    ("Available Credentials", PromptSectionKind::Tools),
+    ("Connector Usage Reference", PromptSectionKind::Tools),
+    ("Input Data", PromptSectionKind::Input),
+    ("Triggering Event", PromptSectionKind::Input),
+    ("Current Focus", PromptSectionKind::Input),
+    ("Time Filter", PromptSectionKind::Input),
+    ("Correction Required", PromptSectionKind::Input),
+    ("Shared Context", PromptSectionKind::Input),
+];
+
+/// Sections [`fit_to_budget`] may drop, first to go first.
+const TRIM_ORDER: &[&str] = &[
+    "Agent Memory — Recent Learnings",
+    "Prior Human Feedback",
+    "Team Shared Knowledge",
+    "Learned Skills",
+    "Agent Memory — Core Beliefs",
+    "Connector Usage Reference",
+];
+
+struct Section<'a> {
+    heading: &'a str,
+    kind: PromptSectionKind,
+    text: &'a str,
+}
+
+fn kind_of(heading: &str) -> PromptSectionKind {
+    SECTION_KINDS
+        .iter()
+        .find(|(prefix, _)| heading.starts_with(prefix))
+        .map(|(_, kind)| *kind)
+        .unwrap_or(PromptSectionKind::SystemPrompt)
+}
+
+/// Split `prompt` at its `## ` headers. Headers inside an `<untrusted_*>`
+/// block belong to the input data, not the prompt's structure.
+fn split(prompt: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut heading = "";
+    let mut in_untrusted = false;
+    let mut offset = 0;
+    for line in prompt.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed.starts_with("</untrusted_") {
+            in_untrusted = false;
+        } else if trimmed.starts_with("<untrusted_") && !trimmed.contains("</untrusted_") {
+            in_untrusted = true;
+        } else if !in_untrusted {
+            if let Some(next) = trimmed.strip_prefix("## ") {
+                sections.push(Section {
+                    heading,
+                    kind: kind_of(heading),
+                    text: &prompt[start..offset],
+                });
+                start = offset;
+                heading = next;
+            }
+        }
+        offset += line.len();
+    }
+    sections.push(Section {
+        heading,
+        kind: kind_of(heading),
+        text: &prompt[start..],
+    });
+    sections
+}
+
+fn is_trimmable(heading: &str) -> Option<usize> {
+    TRIM_ORDER.iter().position(|p| heading.starts_with(p))
+}
+
+/// Token budget of a compiled `prompt` for `model`.
+pub fn prompt_budget(prompt: &str, model: &str) -> PromptBudget {
+    let window = context_window(model);
+    let available = window.saturating_sub(OUTPUT_RESERVE_TOKENS);
+    let sections: Vec<PromptSectionTokens> = split(prompt)
+        .into_iter()
+        .map(|s| PromptSectionTokens {
+            heading: s.heading.to_string(),
+            kind: s.kind,
+            tokens: estimate_tokens(s.text),
+        })
+        .filter(|s| s.tokens > 0)
+        .collect();
+    let sum = |kind: PromptSectionKind| -> u64 {
+        sections
+            .iter()
+            .filter(|s| s.kind == kind)
+            .map(|s| s.tokens)
+            .sum()
+    };
+    let total: u64 = sections.iter().map(|s| s.tokens).sum();
+
+    let mut trimmable: Vec<&PromptSectionTokens> = sections
+        .iter()
+        .filter(|s| is_trimmable(&s.heading).is_some())
+        .collect();
+    trimmable.sort_by_key(|s| is_trimmable(&s.heading));
+
+    let mut warnings = Vec::new();
+    let status = if total > available {
+        let mut remaining = total;
+        let mut drops = Vec::new();
+        for s in &trimmable {
+            if remaining <= available {
+                break;
+            }
+            remaining -= s.tokens;
+            drops.push(s.heading.clone());
+        }
+        warnings.push(format!(
+            "Prompt is ~{total} tokens, over the {available} available in {model}'s \
+             {window}-token context window."
+        ));
+        if remaining <= available {
+            warnings.push(format!(
+                "Runs drop {} to fit (~{remaining} tokens).",
+                drops.join(", ")
+            ));
+        } else {
+            warnings.push(
+                "Dropping every optional section is not enough; shorten the system prompt \
+                 or tool set, or pick a model with a larger context window."
+                    .to_string(),
+            );
+        }
+        "over"
+    } else if total as f64 > available as f64 * WARN_RATIO {
+        warnings.push(format!(
+            "Prompt uses ~{total} of the {available} tokens available in {model}'s context \
+             window; large inputs may not fit."
+        ));
+        "warning"
+    } else {
+        "ok"
+    };
+
+    PromptBudget {
+        model: model.to_string(),
+        context_window: window,
+        available_tokens: available,
+        total_tokens: total,
+        system_prompt_tokens: sum(PromptSectionKind::SystemPrompt),
+        memory_tokens: sum(PromptSectionKind::Memories),
+        tool_tokens: sum(PromptSectionKind::Tools),
+        input_tokens: sum(PromptSectionKind::Input),
+        trimmable: trimmable.iter().map(|s| s.heading.clone()).collect(),
+        sections,
+        status: status.to_string(),
+        warnings,
+    }
+}
+
+/// A prompt brought under its model's budget.
+pub struct FittedPrompt {
+    pub prompt: String,
+    pub budget: PromptBudget,
+    /// Headings of the sections dropped, in drop order.
+    pub dropped: Vec<String>,
+}
+
+/// Drop optional sections from an over-budget `prompt` until it fits
+/// `model`'s context window. A prompt that fits is returned unchanged; one
+/// that still does not fit after every optional section is gone is returned
+/// with its budget's warnings for the caller to log.
+pub fn fit_to_budget(prompt: String, model: &str) -> FittedPrompt {
+    let budget = prompt_budget(&prompt, model);
+    if budget.status != "over" {
+        return FittedPrompt {
+            prompt,
+            budget,
+            dropped: Vec::new(),
+        };
+    }
+    let mut sections = split(&prompt);
+    let mut total = budget.total_tokens;
+    let mut dropped = Vec::new();
+    for rank in 0..TRIM_ORDER.len() {
+        if total <= budget.available_tokens {
+            break;
+        }
+        sections.retain(|s| {
+            if is_trimmable(s.heading) != Some(rank) {
+                return true;
+            }
+            total = total.saturating_sub(estimate_tokens(s.text));
+            dropped.push(s.heading.to_string());
+            false
+        });
+    }
+    let trimmed: String = sections.iter().map(|s| s.text).collect();
+    FittedPrompt {
+        budget: prompt_budget(&trimmed, model),
+        prompt: trimmed,
+        dropped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_groups_sections_and_trims_memories_first() {
+        assert_eq!(estimate_tokens("Hello world"), 2);
+        assert_eq!(estimate_tokens("{\"a\": 1234}"), 8);
+        assert_eq!(estimate_tokens("internationalization"), 4);
+
+        let input =
+            "## Input Data\n<untrusted_input_data_1>\n## not a header\n</untrusted_input_data_1>\n";
+        let memories = format!(
+            "\n\n## Agent Memory — Recent Learnings\n\n{}\n",
+            "- **fact** [learned]: remember this detail. ".repeat(20_000)
+        );
+        let prompt = format!(
+            "# Persona: Test\n\n## Identity\nYou help.\n## Available Tools\n- search\n{input}## EXECUTE NOW\nGo.\n{memories}"
+        );
+
+        let budget = prompt_budget(&prompt, "claude-sonnet-4-6");
+        assert_eq!(budget.context_window, 200_000);
+        assert_eq!(budget.status, "over");
+        assert!(budget.tool_tokens > 0 && budget.input_tokens > 0);
+        assert!(budget.memory_tokens > budget.system_prompt_tokens);
+        assert_eq!(
+            budget.total_tokens,
+            budget.system_prompt_tokens
+                + budget.memory_tokens
+                + budget.tool_tokens
+                + budget.input_tokens
+        );
+        assert!(!budget.sections.iter().any(|s| s.heading == "not a header"));
+        assert_eq!(budget.trimmable, vec!["Agent Memory — Recent Learnings"]);
+
+        let fitted = fit_to_budget(prompt.clone(), "claude-sonnet-4-6");
+        assert_eq!(fitted.dropped, vec!["Agent Memory — Recent Learnings"]);
+        assert_eq!(fitted.budget.status, "ok");
+        assert!(fitted.prompt.contains("## not a header"));
+        assert!(fitted.prompt.contains("## EXECUTE NOW"));
+
+        let small = fit_to_budget("## Identity\nYou help.\n".to_string(), "gpt-4o");
+        assert!(small.dropped.is_empty());
+        assert_eq!(small.budget.context_window, DEFAULT_CONTEXT_WINDOW);
+    }
+}
@@ -1,5 +1,6 @@
 //! Shared helpers that derive structured info from a persona or its tools.
 
+use super::super::tool_policy::{HTTP_REQUEST, SQL_QUERY};
 use super::super::types::ModelProfile;
 use crate::db::models::PersonaToolDefinition;
 
@@ -326,6 +327,15 @@ pub fn render_capability_policy_lines(use_case: &serde_json::Value) -> Vec<Strin
     lines
 }
 
+/// `builtin://` tools the personas-mcp sidecar serves as `mcp__personas__<name>`.
+const SIDECAR_BUILTINS: &[&str] = &[
+    HTTP_REQUEST,
+    "email_imap_read",
+    "email_imap_search",
+    "email_smtp_send",
+    SQL_QUERY,
+];
+
 /// Build documentation string for a single tool definition.
 pub fn build_tool_documentation(tool: &PersonaToolDefinition) -> String {
     let mut doc = format!("### {}\n{}\n", tool.name, tool.description);
@@ -340,6 +350,19 @@ pub fn build_tool_documentation(tool: &PersonaToolDefinition) -> String {
         } else {
             doc.push_str("**Implementation**: Use the Bash tool with `curl` to call the API. Credentials are available as environment variables (e.g. `$GOOGLE_ACCESS_TOKEN`).\n");
         }
+    } else if let Some(name) = tool
+        .script_path
+        .strip_prefix("builtin://")
+        .filter(|name| SIDECAR_BUILTINS.contains(name))
+    {
+        doc.push_str(&format!(
+            "**Usage**: call the `mcp__personas__{name}` tool. It runs in the app, not in your shell.\n"
+        ));
+        if name == HTTP_REQUEST {
+            doc.push_str("Use it instead of curl or WebFetch: network policy, timeout and credential auth are applied for you.\n");
+        } else if name == SQL_QUERY {
+            doc.push_str("Use it instead of database CLIs: bind values through `params`; writes need human approval (`sql_write`).\n");
+        }
     } else {
         doc.push_str(&format!(
             "**Usage**: npx tsx \"{}\" --input '<JSON>'\n",
@@ -1,32 +1,118 @@
 //! Interpolate `{{var}}` placeholders in persona-authored strings.
+//!
+//! A placeholder resolves from, in order:
+//! - built-ins (`now`, `today`, `iso8601`, `weekday`, `project_id`,
+//!   `persona_id`, `persona_name`);
+//! - persona parameters (`param.<key>`);
+//! - the template variable registry (`var.<name>`, see
+//!   `engine::template_variables`);
+//! - app settings (`setting.language`, `setting.timezone`). Only this fixed
+//!   list is exposed, so API keys, tokens and other secrets kept in settings
+//!   can never be interpolated into a prompt;
+//! - the execution's input data, sanitized (keys starting with `_` skipped).
+//!
+//! Unknown placeholders are left as written.
 
-use crate::db::models::Persona;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{LazyLock, RwLock};
+
+use crate::db::models::{Persona, PromptVariable};
+use crate::db::repos::core::settings;
+use crate::db::settings_keys;
+use crate::db::DbPool;
 
 use super::runtime_safety::sanitize_runtime_variable;
 
-/// Replace {{variable}} placeholders in a string with values from input_data or magic variables.
-///
-/// Magic variables (now, today, persona_id, etc.) are trusted internal values.
-/// Input data values from user execution input are sanitized to prevent prompt injection
-/// and structural escaping issues before substitution.
-pub fn replace_variables(
-    text: &str,
+static PLACEHOLDER: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\{\{([^}]+)\}\}").unwrap());
+
+/// `setting.*` values, loaded when the execution engine starts and reloaded
+/// after a write to one of [`SETTING_VARIABLE_SOURCES`].
+static SETTING_VARS: RwLock<BTreeMap<String, String>> = RwLock::new(BTreeMap::new());
+
+/// Settings keys the `setting.*` variables are read from.
+pub const SETTING_VARIABLE_SOURCES: &[&str] = &[
+    settings_keys::APP_LANGUAGE,
+    settings_keys::APPEARANCE_PREFERENCES,
+];
+
+/// Refresh the `setting.*` snapshot from the database.
+pub fn reload_setting_variables(pool: &DbPool) {
+    let mut vars = BTreeMap::new();
+    let language = settings::get(pool, settings_keys::APP_LANGUAGE)
+        .ok()
+        .flatten()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty());
+    if let Some(language) = language {
+        vars.insert("language".to_string(), language);
+    }
+    let timezone = settings::get(pool, settings_keys::APPEARANCE_PREFERENCES)
+        .ok()
+        .flatten()
+        .and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok())
+        .and_then(|prefs| prefs.get("timezone")?.as_str().map(str::to_string))
+        .filter(|tz| !tz.is_empty());
+    if let Some(timezone) = timezone {
+        vars.insert("timezone".to_string(), timezone);
+    }
+    if let Ok(mut guard) = SETTING_VARS.write() {
+        *guard = vars;
+    }
+}
+
+fn push(vars: &mut Vec<PromptVariable>, name: String, source: &str, value: String) {
+    vars.push(PromptVariable {
+        name,
+        source: source.to_string(),
+        value,
+    });
+}
+
+/// Every variable a prompt for `persona` and `input_data` can reference, with
+/// the value it substitutes. Input keys shadowed by a trusted variable are
+/// left out, since the trusted value wins.
+pub fn resolve_variables(
     persona: &Persona,
     input_data: Option<&serde_json::Value>,
-) -> String {
+) -> Vec<PromptVariable> {
     use chrono::Datelike;
     let now = chrono::Utc::now();
 
-    // Define magic variables (trusted -- skip sanitization)
-    let mut trusted_vars: std::collections::HashMap<String, String> =
-        std::collections::HashMap::new();
-    trusted_vars.insert("now".into(), now.to_rfc3339());
-    trusted_vars.insert("today".into(), now.format("%Y-%m-%d").to_string());
-    trusted_vars.insert("iso8601".into(), now.to_rfc3339());
-    trusted_vars.insert("weekday".into(), now.weekday().to_string());
-    trusted_vars.insert("project_id".into(), persona.project_id.clone());
-    trusted_vars.insert("persona_id".into(), persona.id.clone());
-    trusted_vars.insert("persona_name".into(), persona.name.clone());
+    // Magic variables (trusted -- skip sanitization)
+    let mut vars = Vec::new();
+    push(&mut vars, "now".into(), "builtin", now.to_rfc3339());
+    push(
+        &mut vars,
+        "today".into(),
+        "builtin",
+        now.format("%Y-%m-%d").to_string(),
+    );
+    push(&mut vars, "iso8601".into(), "builtin", now.to_rfc3339());
+    push(
+        &mut vars,
+        "weekday".into(),
+        "builtin",
+        now.weekday().to_string(),
+    );
+    push(
+        &mut vars,
+        "project_id".into(),
+        "builtin",
+        persona.project_id.clone(),
+    );
+    push(
+        &mut vars,
+        "persona_id".into(),
+        "builtin",
+        persona.id.clone(),
+    );
+    push(
+        &mut vars,
+        "persona_name".into(),
+        "builtin",
+        persona.name.clone(),
+    );
 
     // Inject free parameters as trusted variables (persona-owned, not user-input)
     if let Some(ref params_json) = persona.parameters {
@@ -41,49 +127,97 @@ pub fn replace_variables(
                         serde_json::Value::Bool(b) => b.to_string(),
                         _ => value.to_string(),
                     };
-                    trusted_vars.insert(format!("param.{}", key), val_str);
+                    push(&mut vars, format!("param.{}", key), "parameter", val_str);
                 }
             }
         }
     }
 
+    // Registry variables ({{var.company_name}}) -- operator-defined at global,
+    // team or persona scope. See engine::template_variables.
+    let mut registry: Vec<_> = crate::engine::template_variables::resolve_for(persona)
+        .into_iter()
+        .collect();
+    registry.sort();
+    for (name, value) in registry {
+        push(&mut vars, format!("var.{name}"), "registry", value);
+    }
+
+    if let Ok(settings) = SETTING_VARS.read() {
+        for (name, value) in settings.iter() {
+            push(
+                &mut vars,
+                format!("setting.{name}"),
+                "setting",
+                value.clone(),
+            );
+        }
+    }
+
     // Add input_data variables -- these are user-provided and MUST be sanitized.
     // Keys starting with _ are internal metadata (e.g. _use_case, _time_filter)
     // and are not substituted into prompts via {{}} -- they are handled separately.
-    let mut user_vars: std::collections::HashMap<String, String> = std::collections::HashMap::new();
-    if let Some(data) = input_data {
-        if let Some(obj) = data.as_object() {
-            for (k, v) in obj {
-                // Skip internal metadata keys
-                if k.starts_with('_') {
-                    continue;
-                }
-                let raw = if let Some(s) = v.as_str() {
-                    s.to_string()
-                } else if let Some(n) = v.as_f64() {
-                    n.to_string()
-                } else if let Some(b) = v.as_bool() {
-                    b.to_string()
-                } else {
-                    continue;
-                };
-                user_vars.insert(k.clone(), sanitize_runtime_variable(&raw));
+    let trusted: BTreeSet<String> = vars.iter().map(|v| v.name.clone()).collect();
+    if let Some(obj) = input_data.and_then(|data| data.as_object()) {
+        for (k, v) in obj {
+            // Skip internal metadata keys
+            if k.starts_with('_') || trusted.contains(k) {
+                continue;
             }
+            let raw = if let Some(s) = v.as_str() {
+                s.to_string()
+            } else if let Some(n) = v.as_f64() {
+                n.to_string()
+            } else if let Some(b) = v.as_bool() {
+                b.to_string()
+            } else {
+                continue;
+            };
+            push(
+                &mut vars,
+                k.clone(),
+                "input",
+                sanitize_runtime_variable(&raw),
+            );
         }
     }
+    vars
+}
 
-    // Regex to find {{variable}}
-    let re = regex::Regex::new(r"\{\{([^}]+)\}\}").unwrap();
-    re.replace_all(text, |caps: &regex::Captures| {
-        let key = caps.get(1).unwrap().as_str().trim();
-        // Check trusted vars first, then sanitized user vars
-        if let Some(val) = trusted_vars.get(key) {
-            val.clone()
-        } else if let Some(val) = user_vars.get(key) {
-            val.clone()
-        } else {
-            caps.get(0).unwrap().as_str().to_string()
-        }
-    })
-    .to_string()
+/// Replace {{variable}} placeholders in a string with values from input_data or magic variables.
+///
+/// Magic variables (now, today, persona_id, etc.) are trusted internal values.
+/// Input data values from user execution input are sanitized to prevent prompt injection
+/// and structural escaping issues before substitution.
+pub fn replace_variables(
+    text: &str,
+    persona: &Persona,
+    input_data: Option<&serde_json::Value>,
+) -> String {
+    if !text.contains("{{") {
+        return text.to_string();
+    }
+    let vars = resolve_variables(persona, input_data);
+    let lookup: HashMap<&str, &str> = vars
+        .iter()
+        .map(|v| (v.name.as_str(), v.value.as_str()))
+        .collect();
+    PLACEHOLDER
+        .replace_all(text, |caps: &regex::Captures| {
+            let key = caps.get(1).unwrap().as_str().trim();
+            match lookup.get(key) {
+                Some(val) => val.to_string(),
+                None => caps.get(0).unwrap().as_str().to_string(),
+            }
+        })
+        .to_string()
+}
+
+/// Placeholder names still present in `text`, i.e. ones no source resolved.
+pub fn unresolved_placeholders(text: &str) -> Vec<String> {
+    let names: BTreeSet<String> = PLACEHOLDER
+        .captures_iter(text)
+        .map(|c| c[1].trim().to_string())
+        .collect();
+    names.into_iter().collect()
 }
@@ -0,0 +1,110 @@
+//! Reusable prompt blocks referenced from structured prompts.
+//!
+//! A `customSections` entry of the form `{"blockId": "<id>", "title"?: ...}`
+//! is replaced at assembly time with the block's current content, so editing a
+//! block updates every persona that references it. Like
+//! `engine::template_variables`, the blocks are held in a process-wide
+//! snapshot because prompt assembly has no pool: loaded when the execution
+//! engine starts and reloaded after every CRUD write.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{LazyLock, RwLock};
+
+use crate::db::models::{PromptBlock, PromptBlockUsage, TemplateVariablePersonaRef};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::resources::prompt_blocks as repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+static SNAPSHOT: LazyLock<RwLock<HashMap<String, PromptBlock>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Refresh the snapshot from the database. Failures keep the previous one.
+pub fn reload(pool: &DbPool) {
+    match repo::list_all(pool) {
+        Ok(blocks) => {
+            if let Ok(mut guard) = SNAPSHOT.write() {
+                *guard = blocks.into_iter().map(|b| (b.id.clone(), b)).collect();
+            }
+        }
+        Err(e) => tracing::warn!(error = %e, "Failed to load prompt blocks"),
+    }
+}
+
+/// Current `(name, content)` of a block, if it exists.
+pub fn lookup(id: &str) -> Option<(String, String)> {
+    SNAPSHOT
+        .read()
+        .ok()?
+        .get(id)
+        .map(|b| (b.name.clone(), b.content.clone()))
+}
+
+/// Block ids referenced from a structured prompt's `customSections`.
+pub fn referenced_ids(structured_prompt: &str) -> BTreeSet<String> {
+    let Ok(sp) = serde_json::from_str::<serde_json::Value>(structured_prompt) else {
+        return BTreeSet::new();
+    };
+    sp.get("customSections")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|s| s.get("blockId").and_then(|v| v.as_str()))
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Which personas reference each block. Covers every block (even when
+/// unused) plus ids referenced by a persona but since deleted.
+pub fn usage(pool: &DbPool) -> Result<Vec<PromptBlockUsage>, AppError> {
+    let names: HashMap<String, String> = repo::list_all(pool)?
+        .into_iter()
+        .map(|b| (b.id, b.name))
+        .collect();
+    let mut by_id: BTreeMap<String, Vec<TemplateVariablePersonaRef>> =
+        names.keys().map(|id| (id.clone(), Vec::new())).collect();
+    for persona in persona_repo::get_all(pool)? {
+        let Some(sp) = persona.structured_prompt.as_deref() else {
+            continue;
+        };
+        for id in referenced_ids(sp) {
+            by_id
+                .entry(id)
+                .or_default()
+                .push(TemplateVariablePersonaRef {
+                    id: persona.id.clone(),
+                    name: persona.name.clone(),
+                });
+        }
+    }
+    Ok(by_id
+        .into_iter()
+        .map(|(block_id, personas)| PromptBlockUsage {
+            block_name: names.get(&block_id).cloned(),
+            block_id,
+            personas,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_block_references_in_custom_sections() {
+        let sp = r#"{
+            "identity": "x",
+            "customSections": [
+                {"blockId": "tone-1"},
+                {"title": "Notes", "content": "plain"},
+                {"blockId": "fmt-2", "title": "Output"},
+                {"blockId": ""}
+            ]
+        }"#;
+        let ids = referenced_ids(sp);
+        assert_eq!(ids.into_iter().collect::<Vec<_>>(), vec!["fmt-2", "tone-1"]);
+        assert!(referenced_ids("not json").is_empty());
+    }
+}
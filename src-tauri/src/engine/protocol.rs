@@ -189,6 +189,7 @@ impl MockProtocol {
                 ProtocolMessage::RaiseIncident { .. } => "RaiseIncident",
                 ProtocolMessage::ResolveIncident { .. } => "ResolveIncident",
                 ProtocolMessage::KpiMeasurement { .. } => "KpiMeasurement",
+                ProtocolMessage::SharedContext { .. } => "SharedContext",
                 ProtocolMessage::ProposeBacklog { .. } => "ProposeBacklog",
                 ProtocolMessage::ExecutionFlow { .. } => "ExecutionFlow",
                 ProtocolMessage::KnowledgeAnnotation { .. } => "KnowledgeAnnotation",
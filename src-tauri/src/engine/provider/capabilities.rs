@@ -0,0 +1,229 @@
+//! Per-backend capability matrix.
+//!
+//! A persona runs on one of four backends: the Claude Code CLI (selected by
+//! [`EngineKind`]) or one of the HTTP backends the runner dispatches to by
+//! `model_profile.provider` before spawning any CLI. The HTTP backends only
+//! receive the assembled prompt and the remote toolbox, so features that live
+//! in the CLI path (session resume, output contracts, budget/turn caps, the
+//! container sandbox) silently do nothing there. [`check`] compares what a
+//! persona relies on against the matrix; the runner and `update_persona`
+//! consult it so a mismatch is reported instead of being ignored.
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::EngineKind;
+use crate::db::models::Persona;
+use crate::db::DbPool;
+use crate::engine::types::{providers, ModelProfile};
+use crate::engine::{container_sandbox, output_contract, prompt};
+
+/// Backend that actually executes a persona run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum Backend {
+    ClaudeCode,
+    Qwen,
+    Ollama,
+    OpenaiApi,
+}
+
+impl Backend {
+    pub const ALL: [Backend; 4] = [
+        Backend::ClaudeCode,
+        Backend::Qwen,
+        Backend::Ollama,
+        Backend::OpenaiApi,
+    ];
+
+    /// Mirrors the runner's dispatch: an HTTP provider on the model profile
+    /// wins over the engine; `litellm`/`custom` only re-point the CLI.
+    pub fn resolve(engine: EngineKind, provider: Option<&str>) -> Self {
+        match provider.map(str::trim) {
+            Some(p) if crate::engine::http_engine::is_remote_http_provider(p) => Backend::Qwen,
+            Some(p) if p == providers::OLLAMA => Backend::Ollama,
+            Some(p) if p == providers::OPENAI_API => Backend::OpenaiApi,
+            _ => match engine {
+                EngineKind::ClaudeCode => Backend::ClaudeCode,
+            },
+        }
+    }
+
+    /// Backend a persona would run on right now, with its engine override
+    /// falling back to the global `cli_engine` setting.
+    pub fn for_persona(pool: &DbPool, persona: &Persona) -> Self {
+        let global = super::load_engine_kind(pool);
+        let engine = super::resolve_engine_kind(persona.engine.as_deref(), global)
+            .unwrap_or_else(|fallback| fallback);
+        let profile = prompt::parse_model_profile(persona.model_profile.as_deref());
+        Self::resolve(engine, profile.as_ref().and_then(|m| m.provider.as_deref()))
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Backend::ClaudeCode => "Claude Code CLI",
+            Backend::Qwen => "Qwen (DashScope)",
+            Backend::Ollama => "Ollama",
+            Backend::OpenaiApi => "OpenAI-compatible API",
+        }
+    }
+
+    pub fn capabilities(self) -> BackendCapabilities {
+        let cli = self == Backend::ClaudeCode;
+        BackendCapabilities {
+            backend: self,
+            label: self.label().to_string(),
+            session_resume: cli,
+            // HTTP backends get the remote toolbox, not the CLI's MCP tools,
+            // but persona tools are still callable.
+            persona_tools: true,
+            structured_output: cli,
+            budget_cap: cli,
+            max_turns: cli,
+            prompt_caching: cli,
+            subagents: cli,
+            container_sandbox: cli,
+        }
+    }
+}
+
+/// One row of the capability matrix.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct BackendCapabilities {
+    pub backend: Backend,
+    pub label: String,
+    pub session_resume: bool,
+    pub persona_tools: bool,
+    /// Output contract validation and the repair turn.
+    pub structured_output: bool,
+    /// `max_budget_usd` enforcement.
+    pub budget_cap: bool,
+    pub max_turns: bool,
+    pub prompt_caching: bool,
+    /// Deep fan-out via CLI subagents.
+    pub subagents: bool,
+    pub container_sandbox: bool,
+}
+
+/// A feature the persona relies on that its backend lacks.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CapabilityFinding {
+    pub feature: String,
+    /// `"warning"` (the feature is ignored) or `"blocking"` (running would
+    /// violate an explicit persona setting).
+    pub severity: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct CompatibilityReport {
+    pub backend: Backend,
+    pub findings: Vec<CapabilityFinding>,
+}
+
+impl CompatibilityReport {
+    pub fn blocking(&self) -> Option<&CapabilityFinding> {
+        self.findings.iter().find(|f| f.severity == "blocking")
+    }
+}
+
+/// Compare what `persona` relies on against `backend`'s capabilities.
+pub fn check(
+    pool: &DbPool,
+    persona: &Persona,
+    has_tools: bool,
+    backend: Backend,
+) -> CompatibilityReport {
+    let caps = backend.capabilities();
+    let profile: Option<ModelProfile> =
+        prompt::parse_model_profile(persona.model_profile.as_deref());
+    let mut findings = Vec::new();
+    let mut warn = |feature: &str, supported: bool, relied_on: bool, what: &str| {
+        if relied_on && !supported {
+            findings.push(CapabilityFinding {
+                feature: feature.to_string(),
+                severity: "warning".into(),
+                message: format!("{what} is ignored on {}", caps.label),
+            });
+        }
+    };
+
+    warn("persona_tools", caps.persona_tools, has_tools, "Tool use");
+    warn(
+        "structured_output",
+        caps.structured_output,
+        output_contract::load(pool, &persona.id).is_some(),
+        "The output contract",
+    );
+    warn(
+        "budget_cap",
+        caps.budget_cap,
+        persona.max_budget_usd.is_some_and(|b| b > 0.0),
+        "The per-run budget cap",
+    );
+    warn(
+        "max_turns",
+        caps.max_turns,
+        persona.max_turns.is_some_and(|t| t > 0),
+        "The max turns limit",
+    );
+    warn(
+        "prompt_caching",
+        caps.prompt_caching,
+        profile
+            .as_ref()
+            .and_then(|m| m.prompt_cache_policy.as_deref())
+            .is_some_and(|p| !p.is_empty() && p != "none"),
+        "The prompt cache policy",
+    );
+    warn(
+        "subagents",
+        caps.subagents,
+        prompt::deep_fanout_enabled(persona),
+        "Deep fan-out",
+    );
+
+    if !caps.container_sandbox {
+        if let Some(sandbox) = container_sandbox::load(pool, &persona.id) {
+            let fail_closed = sandbox.fallback == container_sandbox::SandboxFallback::Fail;
+            findings.push(CapabilityFinding {
+                feature: "container_sandbox".into(),
+                severity: if fail_closed { "blocking" } else { "warning" }.into(),
+                message: if fail_closed {
+                    format!(
+                        "The container sandbox is required (fallback = fail) but {} cannot run inside it",
+                        caps.label
+                    )
+                } else {
+                    format!("The container sandbox is ignored on {}", caps.label)
+                },
+            });
+        }
+    }
+
+    CompatibilityReport { backend, findings }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_follows_runner_dispatch_order() {
+        let cc = EngineKind::ClaudeCode;
+        assert_eq!(Backend::resolve(cc, None), Backend::ClaudeCode);
+        assert_eq!(Backend::resolve(cc, Some("litellm")), Backend::ClaudeCode);
+        assert_eq!(Backend::resolve(cc, Some("DashScope")), Backend::Qwen);
+        assert_eq!(Backend::resolve(cc, Some("ollama")), Backend::Ollama);
+        assert_eq!(Backend::resolve(cc, Some("openai_api")), Backend::OpenaiApi);
+
+        let cli = Backend::ClaudeCode.capabilities();
+        assert!(cli.session_resume && cli.structured_output && cli.container_sandbox);
+        let http = Backend::Ollama.capabilities();
+        assert!(http.persona_tools && !http.session_resume && !http.budget_cap);
+    }
+}
@@ -1,4 +1,7 @@
+pub mod capabilities;
 pub mod claude;
+pub mod ollama;
+pub mod openai_api;
 
 use super::types::{CliArgs, ModelProfile, StreamLineType};
 use crate::db::models::Persona;
@@ -235,6 +238,20 @@ pub fn load_engine_kind_notified(
     }
 }
 
+/// Engine for one execution: the persona's `engine` override when it is set,
+/// otherwise `global_default` (the `cli_engine` setting). An override naming
+/// no known engine yields `Err(global_default)` so the caller can surface the
+/// fallback instead of failing the run.
+pub fn resolve_engine_kind(
+    persona_engine: Option<&str>,
+    global_default: EngineKind,
+) -> Result<EngineKind, EngineKind> {
+    match persona_engine.map(str::trim).filter(|s| !s.is_empty()) {
+        None => Ok(global_default),
+        Some(s) => EngineKind::from_str_exact(s).ok_or(global_default),
+    }
+}
+
 // =============================================================================
 // CLI version check
 // =============================================================================
@@ -383,6 +400,15 @@ mod tests {
         assert_eq!(EngineKind::from_str_exact(""), None);
     }
 
+    #[test]
+    fn persona_engine_override_falls_back_to_global_default() {
+        let global = EngineKind::ClaudeCode;
+        assert_eq!(resolve_engine_kind(None, global), Ok(global));
+        assert_eq!(resolve_engine_kind(Some("  "), global), Ok(global));
+        assert_eq!(resolve_engine_kind(Some("claude_code"), global), Ok(EngineKind::ClaudeCode));
+        assert_eq!(resolve_engine_kind(Some("gemini_cli"), global), Err(global));
+    }
+
     /// `resolve_provider` must return a provider for every known variant.
     #[test]
     fn resolve_provider_covers_all_variants() {
@@ -0,0 +1,280 @@
+//! Local Ollama backend — offline execution without any CLI agent installed.
+//!
+//! A profile with `provider = "ollama"` is dispatched here from
+//! `runner::run_execution`. Ollama serves an OpenAI-compatible API under
+//! `/v1`, so execution (streaming, tool calls, tool steps) reuses the
+//! [`openai_api`](super::openai_api) tool loop — minus its OpenAI key
+//! fallbacks, so only the profile's own key is ever sent. This module adds
+//! what is Ollama specific: the server base URL, model discovery
+//! (`/api/tags`), model pulls (`/api/pull`) and picking an installed model
+//! when the profile names none.
+//!
+//! The dormant `engine::ollama` module (behind the `ollama` feature) predates
+//! this one and is not used by it.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures_util::StreamExt;
+use serde::Serialize;
+use serde_json::Value;
+use ts_rs::TS;
+
+use crate::engine::events::ExecutionEventEmitter;
+use crate::engine::http_engine::fail;
+use crate::engine::types::{ExecutionResult, ModelProfile};
+use crate::error::AppError;
+
+/// Used when neither the profile nor the `ollama_base_url` setting sets one.
+pub const DEFAULT_BASE_URL: &str = "http://localhost:11434";
+
+/// A model installed on the Ollama server.
+#[derive(Debug, Clone, Serialize, TS, PartialEq)]
+#[ts(export)]
+pub struct OllamaModel {
+    /// Tag to put in `model_profile.model`, e.g. `"qwen3:8b"`.
+    pub name: String,
+    #[ts(type = "number")]
+    pub size_bytes: u64,
+    pub modified_at: Option<String>,
+    pub family: Option<String>,
+    /// e.g. `"8.2B"`.
+    pub parameter_size: Option<String>,
+    /// e.g. `"Q4_K_M"`.
+    pub quantization: Option<String>,
+}
+
+/// One progress line of a model pull, emitted as `ollama-pull-progress`.
+#[derive(Debug, Clone, Serialize, TS, PartialEq)]
+#[ts(export)]
+pub struct OllamaPullProgress {
+    pub model: String,
+    /// Ollama's status text ("pulling manifest", "downloading …", "success").
+    pub status: String,
+    #[ts(type = "number | null")]
+    pub completed: Option<u64>,
+    #[ts(type = "number | null")]
+    pub total: Option<u64>,
+    pub done: bool,
+}
+
+/// Strip a trailing `/` or `/v1` so both native and OpenAI-style URLs work.
+pub fn normalize_base_url(base_url: Option<&str>) -> String {
+    let base = base_url
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or(DEFAULT_BASE_URL)
+        .trim_end_matches('/');
+    base.strip_suffix("/v1").unwrap_or(base).to_string()
+}
+
+fn get(client: &reqwest::Client, url: String, api_key: Option<&str>) -> reqwest::RequestBuilder {
+    let req = client.get(url);
+    match api_key {
+        Some(k) => req.bearer_auth(k),
+        None => req,
+    }
+}
+
+fn parse_tags(tags: &Value) -> Vec<OllamaModel> {
+    let mut models: Vec<OllamaModel> = tags
+        .get("models")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|m| {
+            let detail = |k: &str| {
+                m.pointer(&format!("/details/{k}"))
+                    .and_then(Value::as_str)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+            };
+            Some(OllamaModel {
+                name: m.get("name").or_else(|| m.get("model"))?.as_str()?.to_string(),
+                size_bytes: m.get("size").and_then(Value::as_u64).unwrap_or(0),
+                modified_at: m.get("modified_at").and_then(Value::as_str).map(str::to_string),
+                family: detail("family"),
+                parameter_size: detail("parameter_size"),
+                quantization: detail("quantization_level"),
+            })
+        })
+        .collect();
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+    models
+}
+
+/// Models installed on the server at `base_url`.
+pub async fn list_models(
+    base_url: &str,
+    api_key: Option<&str>,
+) -> Result<Vec<OllamaModel>, AppError> {
+    let client = crate::SHARED_HTTP.clone();
+    let response = get(&client, format!("{base_url}/api/tags"), api_key)
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(format!("Ollama unreachable at {base_url}: {e}")))?;
+    if !response.status().is_success() {
+        return Err(AppError::Internal(format!(
+            "Ollama /api/tags returned {}",
+            response.status()
+        )));
+    }
+    let tags: Value = response
+        .json()
+        .await
+        .map_err(|e| AppError::Internal(format!("Ollama /api/tags unreadable: {e}")))?;
+    Ok(parse_tags(&tags))
+}
+
+fn parse_pull_line(model: &str, line: &str) -> Option<Result<OllamaPullProgress, String>> {
+    let v: Value = serde_json::from_str(line.trim()).ok()?;
+    if let Some(err) = v.get("error").and_then(Value::as_str) {
+        return Some(Err(err.to_string()));
+    }
+    let status = v.get("status").and_then(Value::as_str).unwrap_or_default().to_string();
+    Some(Ok(OllamaPullProgress {
+        model: model.to_string(),
+        done: status == "success",
+        status,
+        completed: v.get("completed").and_then(Value::as_u64),
+        total: v.get("total").and_then(Value::as_u64),
+    }))
+}
+
+/// Pull (download) `model`, reporting each NDJSON progress line. Pulls run
+/// for minutes, so no request timeout is applied.
+pub async fn pull_model(
+    base_url: &str,
+    api_key: Option<&str>,
+    model: &str,
+    mut on_progress: impl FnMut(OllamaPullProgress),
+) -> Result<(), AppError> {
+    let client = reqwest::Client::new();
+    let mut req = client
+        .post(format!("{base_url}/api/pull"))
+        .json(&serde_json::json!({ "model": model, "stream": true }));
+    if let Some(k) = api_key {
+        req = req.bearer_auth(k);
+    }
+    let response = req
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(format!("Ollama unreachable at {base_url}: {e}")))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(AppError::Internal(format!("Ollama pull failed ({status}): {body}")));
+    }
+
+    let mut buf: Vec<u8> = Vec::new();
+    let mut stream = response.bytes_stream();
+    let mut finished = false;
+    while let Some(chunk) = stream.next().await {
+        let bytes = chunk.map_err(|e| AppError::Internal(format!("Ollama pull stream: {e}")))?;
+        buf.extend_from_slice(&bytes);
+        while let Some(nl) = buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buf.drain(..=nl).collect();
+            match parse_pull_line(model, &String::from_utf8_lossy(&line)) {
+                Some(Ok(progress)) => {
+                    finished |= progress.done;
+                    on_progress(progress);
+                }
+                Some(Err(e)) => {
+                    return Err(AppError::Validation(format!("Ollama pull of '{model}': {e}")))
+                }
+                None => {}
+            }
+        }
+    }
+    if finished {
+        Ok(())
+    } else {
+        Err(AppError::Internal(format!(
+            "Ollama pull of '{model}' ended without success"
+        )))
+    }
+}
+
+/// Execute a persona on the Ollama server named by the profile. The model
+/// defaults to the first installed one when the profile leaves it unset.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_ollama_execution(
+    emitter: &dyn ExecutionEventEmitter,
+    execution_id: &str,
+    persona_name: &str,
+    model_profile: &ModelProfile,
+    prompt_text: &str,
+    tools_enabled: bool,
+    cancelled: &Arc<AtomicBool>,
+    start_time: Instant,
+) -> ExecutionResult {
+    let base_url = normalize_base_url(model_profile.base_url.as_deref());
+    let api_key = model_profile.auth_token.as_deref().filter(|k| !k.is_empty());
+    let model = match model_profile.model.as_deref().filter(|m| !m.is_empty()) {
+        Some(m) => m.to_string(),
+        None => match list_models(&base_url, api_key).await {
+            Ok(models) => match models.into_iter().next() {
+                Some(m) => m.name,
+                None => {
+                    return fail(
+                        emitter,
+                        execution_id,
+                        "No Ollama models installed (pull one from Settings or run `ollama pull <model>`)",
+                        start_time,
+                    )
+                }
+            },
+            Err(e) => return fail(emitter, execution_id, &e.to_string(), start_time),
+        },
+    };
+
+    let v1 = format!("{base_url}/v1");
+    super::openai_api::run_chat_completions(
+        emitter,
+        execution_id,
+        persona_name,
+        super::openai_api::Endpoint { base_url: &v1, model: &model, api_key },
+        prompt_text,
+        tools_enabled,
+        cancelled,
+        start_time,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn normalizes_base_url_and_parses_tags() {
+        assert_eq!(normalize_base_url(None), DEFAULT_BASE_URL);
+        assert_eq!(normalize_base_url(Some("http://box:11434/v1/")), "http://box:11434");
+
+        let models = parse_tags(&json!({ "models": [
+            { "name": "qwen3:8b", "size": 5_200_000_000u64, "modified_at": "2026-09-01T00:00:00Z",
+              "details": { "family": "qwen3", "parameter_size": "8.2B", "quantization_level": "Q4_K_M" } },
+            { "model": "gemma3:4b" },
+            { "size": 1 }
+        ]}));
+        assert_eq!(models.len(), 2);
+        assert_eq!(models[0].name, "gemma3:4b");
+        assert_eq!(models[1].parameter_size.as_deref(), Some("8.2B"));
+        assert_eq!(models[1].quantization.as_deref(), Some("Q4_K_M"));
+    }
+
+    #[test]
+    fn parses_pull_progress_lines() {
+        let p = parse_pull_line("m", r#"{"status":"downloading","completed":5,"total":10}"#)
+            .unwrap()
+            .unwrap();
+        assert_eq!((p.completed, p.total, p.done), (Some(5), Some(10), false));
+        assert!(parse_pull_line("m", r#"{"status":"success"}"#).unwrap().unwrap().done);
+        assert!(parse_pull_line("m", r#"{"error":"pull model manifest: file does not exist"}"#)
+            .unwrap()
+            .is_err());
+        assert!(parse_pull_line("m", "").is_none());
+    }
+}
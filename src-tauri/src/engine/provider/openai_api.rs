@@ -0,0 +1,476 @@
+//! Direct OpenAI-compatible chat-completions backend — no CLI process.
+//!
+//! Selected with `ModelProfile.provider = "openai_api"` and usable against any
+//! endpoint that speaks the chat-completions protocol: OpenAI (the default
+//! base URL), OpenRouter (`https://openrouter.ai/api/v1`) or a local
+//! llama.cpp / Ollama / vLLM server (e.g. `http://localhost:11434/v1`).
+//! Unlike the [`CliProvider`](super::CliProvider) engines it is dispatched
+//! from `runner::run_execution` by provider string, like the Qwen split
+//! engine in `engine::http_engine`, whose event helpers and remote-safe
+//! toolbox it reuses.
+//!
+//! Every model turn is streamed: text deltas are emitted live, tool-call
+//! deltas are accumulated by index and, once the turn ends, executed locally
+//! and recorded as [`ToolCallStep`]s. Cost comes from the provider's
+//! `usage.cost` when it reports one (OpenRouter), else from a small per-model
+//! price table, else $0 (local servers).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use crate::db::models::Json;
+use crate::engine::events::ExecutionEventEmitter;
+use crate::engine::http_engine::{emit_output, emit_status, fail, RemoteToolbox};
+use crate::engine::types::{ExecutionResult, ExecutionState, ModelProfile, ToolCallStep};
+
+/// Used when the profile does not set `base_url` (and no global setting does).
+pub const DEFAULT_BASE_URL: &str = "https://api.openai.com/v1";
+/// Used when the profile does not pin a model.
+pub const DEFAULT_MODEL: &str = "gpt-4o-mini";
+/// Per-request timeout; the runner's execution ceiling still caps wall time.
+const HTTP_TIMEOUT_SECS: u64 = 600;
+/// Max model⇄tool round-trips before giving up.
+const MAX_TOOL_ROUNDS: usize = 8;
+/// Chars of tool input/output kept on a [`ToolCallStep`].
+const PREVIEW_CHARS: usize = 200;
+
+/// Per-1M-token USD pricing for models commonly run through this backend.
+/// OpenRouter-style vendor prefixes (`openai/gpt-4o`) are ignored.
+fn price_per_million(model: &str) -> Option<(f64, f64)> {
+    match model.rsplit('/').next().unwrap_or(model) {
+        "gpt-4o" => Some((2.50, 10.00)),
+        "gpt-4o-mini" => Some((0.15, 0.60)),
+        "gpt-4.1" => Some((2.00, 8.00)),
+        "gpt-4.1-mini" => Some((0.40, 1.60)),
+        "gpt-4.1-nano" => Some((0.10, 0.40)),
+        _ => None,
+    }
+}
+
+/// Token and cost totals reported in a `usage` object.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Usage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
+    /// Provider-reported cost (OpenRouter `usage.cost`), when present.
+    cost_usd: Option<f64>,
+}
+
+impl Usage {
+    fn cost_for(&self, model: &str) -> f64 {
+        self.cost_usd.unwrap_or_else(|| match price_per_million(model) {
+            Some((pin, pout)) => {
+                (self.prompt_tokens as f64 / 1e6) * pin
+                    + (self.completion_tokens as f64 / 1e6) * pout
+            }
+            None => 0.0,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct PendingToolCall {
+    id: String,
+    name: String,
+    arguments: String,
+}
+
+/// One streamed assistant turn, folded from SSE `data:` payloads.
+#[derive(Debug, Default)]
+struct TurnAccumulator {
+    content: String,
+    tool_calls: Vec<PendingToolCall>,
+    usage: Option<Usage>,
+}
+
+impl TurnAccumulator {
+    /// Fold one `data:` payload. Returns the text delta to emit, if any.
+    fn push(&mut self, data: &str) -> Option<String> {
+        let chunk: Value = serde_json::from_str(data).ok()?;
+        if let Some(u) = chunk.get("usage").filter(|u| u.is_object()) {
+            self.usage = Some(Usage {
+                prompt_tokens: u.get("prompt_tokens").and_then(Value::as_u64).unwrap_or(0),
+                completion_tokens: u.get("completion_tokens").and_then(Value::as_u64).unwrap_or(0),
+                cost_usd: u.get("cost").and_then(Value::as_f64),
+            });
+        }
+        let delta = chunk.pointer("/choices/0/delta")?;
+        for (pos, call) in delta
+            .get("tool_calls")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .enumerate()
+        {
+            let index = call.get("index").and_then(Value::as_u64).unwrap_or(pos as u64) as usize;
+            if self.tool_calls.len() <= index {
+                self.tool_calls.resize_with(index + 1, PendingToolCall::default);
+            }
+            let slot = &mut self.tool_calls[index];
+            if let Some(id) = call.get("id").and_then(Value::as_str) {
+                slot.id = id.to_string();
+            }
+            if let Some(name) = call.pointer("/function/name").and_then(Value::as_str) {
+                slot.name.push_str(name);
+            }
+            if let Some(args) = call.pointer("/function/arguments").and_then(Value::as_str) {
+                slot.arguments.push_str(args);
+            }
+        }
+        let text = delta.get("content").and_then(Value::as_str).filter(|t| !t.is_empty())?;
+        self.content.push_str(text);
+        Some(text.to_string())
+    }
+
+    /// Complete tool calls (deltas for an index may never name a function).
+    fn tool_calls(&self) -> Vec<&PendingToolCall> {
+        self.tool_calls.iter().filter(|c| !c.name.is_empty()).collect()
+    }
+
+    /// The assistant message to replay before the tool results.
+    fn assistant_message(&self) -> Value {
+        let calls: Vec<Value> = self
+            .tool_calls()
+            .into_iter()
+            .map(|c| {
+                json!({
+                    "id": c.id,
+                    "type": "function",
+                    "function": { "name": c.name, "arguments": c.arguments },
+                })
+            })
+            .collect();
+        let content = if self.content.is_empty() { Value::Null } else { json!(self.content) };
+        json!({ "role": "assistant", "content": content, "tool_calls": calls })
+    }
+}
+
+enum TurnError {
+    Cancelled,
+    Failed(String),
+}
+
+/// Resolve the API key: profile (already filled from the global setting by
+/// the runner) → `OPENAI_API_KEY` / `OPENROUTER_API_KEY` env.
+fn resolve_api_key(model_profile: &ModelProfile) -> Option<String> {
+    if let Some(t) = model_profile.auth_token.as_deref().filter(|t| !t.is_empty()) {
+        return Some(t.to_string());
+    }
+    ["OPENAI_API_KEY", "OPENROUTER_API_KEY"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+}
+
+/// Local servers (llama.cpp, Ollama) usually run without a key.
+fn is_local_endpoint(base_url: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(base_url) else {
+        return false;
+    };
+    match url.host() {
+        Some(url::Host::Domain(d)) => d.eq_ignore_ascii_case("localhost"),
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
+fn preview(s: &str) -> String {
+    s.chars().take(PREVIEW_CHARS).collect()
+}
+
+/// Execute a persona against an OpenAI-compatible endpoint. Emits live output
+/// and a terminal status and returns the `ExecutionResult`; the caller
+/// persists the terminal DB row.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_openai_api_execution(
+    emitter: &dyn ExecutionEventEmitter,
+    execution_id: &str,
+    persona_name: &str,
+    model_profile: &ModelProfile,
+    prompt_text: &str,
+    tools_enabled: bool,
+    cancelled: &Arc<AtomicBool>,
+    start_time: Instant,
+) -> ExecutionResult {
+    let model = model_profile
+        .model
+        .as_deref()
+        .filter(|m| !m.is_empty())
+        .unwrap_or(DEFAULT_MODEL);
+    let base_url = model_profile
+        .base_url
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(DEFAULT_BASE_URL)
+        .trim_end_matches('/');
+    let api_key = resolve_api_key(model_profile);
+    if api_key.is_none() && !is_local_endpoint(base_url) {
+        return fail(
+            emitter,
+            execution_id,
+            &format!(
+                "No API key for {base_url}. Set one on the model profile, in the \
+                 openai_api_key setting, or via OPENAI_API_KEY/OPENROUTER_API_KEY."
+            ),
+            start_time,
+        );
+    }
+    run_chat_completions(
+        emitter,
+        execution_id,
+        persona_name,
+        Endpoint { base_url, model, api_key: api_key.as_deref() },
+        prompt_text,
+        tools_enabled,
+        cancelled,
+        start_time,
+    )
+    .await
+}
+
+/// A resolved chat-completions endpoint (`base_url` without a trailing `/`).
+pub(super) struct Endpoint<'a> {
+    pub base_url: &'a str,
+    pub model: &'a str,
+    pub api_key: Option<&'a str>,
+}
+
+/// Streamed tool loop against an already-resolved endpoint. Shared with
+/// backends that resolve the endpoint themselves (`provider::ollama`).
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn run_chat_completions(
+    emitter: &dyn ExecutionEventEmitter,
+    execution_id: &str,
+    persona_name: &str,
+    endpoint: Endpoint<'_>,
+    prompt_text: &str,
+    tools_enabled: bool,
+    cancelled: &Arc<AtomicBool>,
+    start_time: Instant,
+) -> ExecutionResult {
+    let Endpoint { base_url, model, api_key } = endpoint;
+    let model = model.to_string();
+    let client = match Client::builder().timeout(Duration::from_secs(HTTP_TIMEOUT_SECS)).build() {
+        Ok(c) => c,
+        Err(e) => {
+            return fail(emitter, execution_id, &format!("HTTP client init failed: {e}"), start_time)
+        }
+    };
+    let toolbox = tools_enabled.then(RemoteToolbox::load);
+    let url = format!("{base_url}/chat/completions");
+    // OpenRouter only reports cost when asked; OpenAI rejects unknown fields.
+    let wants_usage_cost = base_url.contains("openrouter.ai");
+
+    tracing::info!(execution_id, %model, persona = persona_name, %base_url, tools_enabled, "[openai_api] starting");
+
+    let mut messages = vec![json!({ "role": "user", "content": prompt_text })];
+    let mut usage = Usage::default();
+    let mut cost_usd = 0.0;
+    let mut steps: Vec<ToolCallStep> = Vec::new();
+
+    for round in 0..MAX_TOOL_ROUNDS {
+        let mut body = json!({
+            "model": model,
+            "messages": messages,
+            "stream": true,
+            "stream_options": { "include_usage": true },
+        });
+        if let Some(ref tb) = toolbox {
+            body["tools"] = tb.schemas();
+            body["tool_choice"] = json!("auto");
+        }
+        if wants_usage_cost {
+            body["usage"] = json!({ "include": true });
+        }
+
+        let mut request = client.post(&url).json(&body);
+        if let Some(key) = api_key {
+            request = request.bearer_auth(key);
+        }
+        let response = match request.send().await {
+            Ok(r) if r.status().is_success() => r,
+            Ok(r) => {
+                let status = r.status();
+                let text = r.text().await.unwrap_or_default();
+                return fail(
+                    emitter,
+                    execution_id,
+                    &format!(
+                        "API error ({status}): {}",
+                        crate::utils::text::truncate_on_char_boundary(&text, 300)
+                    ),
+                    start_time,
+                );
+            }
+            Err(e) => {
+                return fail(emitter, execution_id, &format!("Cannot reach {url}: {e}"), start_time)
+            }
+        };
+
+        let turn = match stream_turn(emitter, execution_id, response, cancelled).await {
+            Ok(t) => t,
+            Err(TurnError::Cancelled) => {
+                let duration_ms = start_time.elapsed().as_millis() as u64;
+                emit_status(emitter, execution_id, ExecutionState::Cancelled, Some("Cancelled"), duration_ms, None);
+                return ExecutionResult {
+                    success: false,
+                    error: Some("Cancelled".into()),
+                    duration_ms,
+                    model_used: Some(model),
+                    tool_steps: (!steps.is_empty()).then(|| Json(steps)),
+                    ..Default::default()
+                };
+            }
+            Err(TurnError::Failed(e)) => return fail(emitter, execution_id, &e, start_time),
+        };
+
+        if let Some(u) = turn.usage {
+            usage.prompt_tokens += u.prompt_tokens;
+            usage.completion_tokens += u.completion_tokens;
+            cost_usd += u.cost_for(&model);
+        }
+
+        let calls = turn.tool_calls();
+        let Some(tb) = toolbox.as_ref().filter(|_| !calls.is_empty()) else {
+            let duration_ms = start_time.elapsed().as_millis() as u64;
+            emit_status(emitter, execution_id, ExecutionState::Completed, None, duration_ms, Some(cost_usd));
+            tracing::info!(
+                execution_id,
+                %model,
+                rounds = round + 1,
+                prompt_tokens = usage.prompt_tokens,
+                completion_tokens = usage.completion_tokens,
+                cost_usd,
+                "[openai_api] completed"
+            );
+            let output = turn.content;
+            return ExecutionResult {
+                success: true,
+                output: (!output.is_empty()).then_some(output),
+                duration_ms,
+                model_used: Some(model),
+                input_tokens: usage.prompt_tokens,
+                output_tokens: usage.completion_tokens,
+                cost_usd,
+                tool_steps: (!steps.is_empty()).then(|| Json(steps)),
+                ..Default::default()
+            };
+        };
+
+        messages.push(turn.assistant_message());
+        for call in calls {
+            let args: Value = serde_json::from_str(&call.arguments).unwrap_or_else(|_| json!({}));
+            emit_output(emitter, execution_id, &format!("🔧 {}({})", call.name, preview(&call.arguments)));
+            let started_at_ms = start_time.elapsed().as_millis() as u64;
+            let result = tb.call(&client, &call.name, &args).await;
+            let ended_at_ms = start_time.elapsed().as_millis() as u64;
+            emit_output(emitter, execution_id, &format!("   ↳ {}", preview(&result)));
+            steps.push(ToolCallStep {
+                step_index: steps.len() as u32,
+                tool_name: call.name.clone(),
+                input_preview: preview(&call.arguments),
+                output_preview: preview(&result),
+                started_at_ms,
+                ended_at_ms: Some(ended_at_ms),
+                duration_ms: Some(ended_at_ms - started_at_ms),
+            });
+            messages.push(json!({ "role": "tool", "tool_call_id": call.id, "content": result }));
+        }
+    }
+
+    fail(
+        emitter,
+        execution_id,
+        &format!("Tool loop exceeded {MAX_TOOL_ROUNDS} rounds without a final answer"),
+        start_time,
+    )
+}
+
+/// Read one streamed turn to completion, emitting text deltas as they arrive.
+async fn stream_turn(
+    emitter: &dyn ExecutionEventEmitter,
+    execution_id: &str,
+    response: reqwest::Response,
+    cancelled: &Arc<AtomicBool>,
+) -> Result<TurnAccumulator, TurnError> {
+    let mut turn = TurnAccumulator::default();
+    let mut byte_buf: Vec<u8> = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        if cancelled.load(Ordering::Relaxed) {
+            return Err(TurnError::Cancelled);
+        }
+        let bytes = chunk.map_err(|e| TurnError::Failed(format!("Stream error: {e}")))?;
+        byte_buf.extend_from_slice(&bytes);
+        while let Some(nl) = byte_buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = byte_buf.drain(..=nl).collect();
+            let line = String::from_utf8_lossy(&line);
+            let Some(data) = line.trim().strip_prefix("data:") else { continue };
+            let data = data.trim();
+            if data.is_empty() || data == "[DONE]" {
+                continue;
+            }
+            if let Some(text) = turn.push(data) {
+                emit_output(emitter, execution_id, &text);
+            }
+        }
+    }
+    Ok(turn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulates_text_tool_call_deltas_and_usage() {
+        let mut turn = TurnAccumulator::default();
+        let chunks = [
+            r#"{"choices":[{"delta":{"role":"assistant","content":"Let me check"}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":0,"id":"call_1","type":"function","function":{"name":"get_current_time","arguments":""}}]}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":1,"id":"call_2","function":{"name":"http_get","arguments":"{\"url\":"}}]}}]}"#,
+            r#"{"choices":[{"delta":{"tool_calls":[{"index":1,"function":{"arguments":"\"https://example.com\"}"}}]}}]}"#,
+            r#"{"choices":[{"delta":{},"finish_reason":"tool_calls"}]}"#,
+            r#"{"choices":[],"usage":{"prompt_tokens":120,"completion_tokens":30,"cost":0.0042}}"#,
+        ];
+        let emitted: Vec<String> = chunks.iter().filter_map(|c| turn.push(c)).collect();
+        assert_eq!(emitted, vec!["Let me check"]);
+
+        let calls = turn.tool_calls();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].name, "get_current_time");
+        assert_eq!(calls[1].id, "call_2");
+        assert_eq!(calls[1].arguments, r#"{"url":"https://example.com"}"#);
+        assert_eq!(turn.assistant_message()["tool_calls"][1]["function"]["name"], "http_get");
+
+        let usage = turn.usage.unwrap();
+        assert_eq!((usage.prompt_tokens, usage.completion_tokens), (120, 30));
+        assert_eq!(usage.cost_for("anything"), 0.0042);
+        assert!(turn.push("not json").is_none());
+    }
+
+    #[test]
+    fn prices_from_table_when_provider_reports_no_cost() {
+        let usage = Usage {
+            prompt_tokens: 1_000_000,
+            completion_tokens: 1_000_000,
+            cost_usd: None,
+        };
+        assert!((usage.cost_for("openai/gpt-4o-mini") - 0.75).abs() < 1e-9);
+        assert_eq!(usage.cost_for("llama-3.1-8b-instruct"), 0.0);
+    }
+
+    #[test]
+    fn only_loopback_endpoints_skip_the_key() {
+        assert!(is_local_endpoint("http://localhost:11434/v1"));
+        assert!(is_local_endpoint("http://127.0.0.1:8080/v1"));
+        assert!(is_local_endpoint("http://[::1]:8080/v1"));
+        assert!(!is_local_endpoint("https://api.openai.com/v1"));
+        assert!(!is_local_endpoint("not a url"));
+    }
+}
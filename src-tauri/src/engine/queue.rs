@@ -70,6 +70,7 @@ pub enum AdmitResult {
 ///
 /// Tracks which executions are running per persona, enforces
 /// max_concurrent limits, and queues overflow with priority ordering.
+#[derive(Clone)]
 pub struct ConcurrencyTracker {
     /// Maps persona_id -> set of currently running execution_ids
     running: HashMap<String, HashSet<String>>,
@@ -455,6 +456,17 @@ impl ConcurrencyTracker {
             .unwrap_or_default()
     }
 
+    /// All running executions as `(persona_id, execution_id)`, sorted.
+    pub fn running_executions(&self) -> Vec<(String, String)> {
+        let mut running: Vec<(String, String)> = self
+            .running
+            .iter()
+            .flat_map(|(pid, set)| set.iter().map(move |eid| (pid.clone(), eid.clone())))
+            .collect();
+        running.sort();
+        running
+    }
+
     /// Count queued executions for a specific persona.
     pub fn queue_depth(&self, persona_id: &str) -> usize {
         self.queues.get(persona_id).map_or(0, |q| q.len())
@@ -477,6 +489,31 @@ impl ConcurrencyTracker {
             .map(|q| q.iter().map(|e| e.execution_id.clone()).collect())
             .unwrap_or_default()
     }
+
+    /// Queued executions that have waited at least `min_wait`, longest-waiting
+    /// first. Read-only snapshot for the queue-age monitor.
+    pub fn queued_longer_than(&self, min_wait: std::time::Duration) -> Vec<QueuedExecution> {
+        let mut stale: Vec<QueuedExecution> = self
+            .queues
+            .values()
+            .flatten()
+            .filter(|e| e.enqueued_at.elapsed() >= min_wait)
+            .cloned()
+            .collect();
+        stale.sort_by_key(|e| e.enqueued_at);
+        stale
+    }
+
+    /// Refresh the `max_concurrent` snapshot of a persona's queued entries
+    /// after the persona's limit changed, so `drain_next_global` admits
+    /// against the new value instead of the one captured at enqueue time.
+    pub fn set_queued_max_concurrent(&mut self, persona_id: &str, max_concurrent: i32) {
+        if let Some(queue) = self.queues.get_mut(persona_id) {
+            for entry in queue.iter_mut() {
+                entry.persona_max_concurrent = max_concurrent;
+            }
+        }
+    }
 }
 
 impl Default for ConcurrencyTracker {
@@ -1039,4 +1076,25 @@ mod tests {
             AdmitResult::Running
         ));
     }
+
+    #[test]
+    fn test_queued_longer_than_and_max_concurrent_refresh() {
+        let mut tracker = ConcurrencyTracker::new();
+        tracker.admit("p1", "e1", 1, ExecutionPriority::Normal);
+        tracker.admit("p1", "e2", 1, ExecutionPriority::Normal);
+        tracker.admit("p1", "e3", 1, ExecutionPriority::Normal);
+
+        let stale = tracker.queued_longer_than(std::time::Duration::ZERO);
+        let ids: Vec<&str> = stale.iter().map(|e| e.execution_id.as_str()).collect();
+        assert_eq!(ids, vec!["e2", "e3"]);
+        assert!(tracker
+            .queued_longer_than(std::time::Duration::from_secs(3600))
+            .is_empty());
+
+        // The queued snapshot still says 1, so the global drain stays blocked
+        // until the new limit is pushed into the queue.
+        assert!(tracker.drain_next_global().is_none());
+        tracker.set_queued_max_concurrent("p1", 2);
+        assert_eq!(tracker.drain_next_global().unwrap().execution_id, "e2");
+    }
 }
@@ -0,0 +1,342 @@
+//! Queue-age monitor — warns when executions sit in the admission queue too
+//! long.
+//!
+//! A queued execution only moves when its persona has a free slot (its
+//! `max_concurrent`) AND the global cap, provider quota and resource governor
+//! all admit it. A misconfigured limit (e.g. `max_concurrent = 1` on a persona
+//! with a long-running watcher) can park work indefinitely with no signal.
+//! Every tick, entries older than [`QUEUE_STUCK_WAIT_SECS`] are grouped per
+//! persona and reported once as:
+//! - a persona message (`content_type = "queue_stuck"`) whose metadata is the
+//!   [`StuckQueueReport`] JSON,
+//! - a `queue_stuck` healing issue naming the blocking executions,
+//! - a [`event_name::QUEUE_STUCK_DETECTED`] event carrying the same report, so
+//!   the UI can offer the one-click bump (`bump_persona_concurrency`) or
+//!   cancel (`cancel_execution`) it suggests.
+//!
+//! Deduped on the open healing issue: while one is open for a persona, further
+//! ticks stay quiet, like the schedule rate-limit issue in `background`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use ts_rs::TS;
+
+use crate::db::models::CreateMessageInput;
+use crate::db::repos::communication::messages as message_repo;
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::settings_keys::{QUEUE_STUCK_WAIT_SECS, QUEUE_STUCK_WAIT_SECS_DEFAULT};
+use crate::db::DbPool;
+use crate::validation::persona::MAX_CONCURRENT_MAX;
+
+use super::event_registry::event_name;
+use super::types::HealingEventPayload;
+use super::ExecutionEngine;
+
+const ISSUE_CATEGORY: &str = "queue_stuck";
+const ISSUE_TITLE: &str = "Executions stuck in queue";
+
+/// A queued execution that exceeded the wait threshold.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct StuckQueuedExecution {
+    pub execution_id: String,
+    #[ts(type = "number")]
+    pub waited_secs: u64,
+    /// "low" | "normal" | "urgent"
+    pub priority: String,
+}
+
+/// A running execution holding one of the persona's slots.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct BlockingExecution {
+    pub execution_id: String,
+    pub status: Option<String>,
+    pub started_at: Option<String>,
+}
+
+/// Why a persona's queue is not draining, with the suggested one-click fix.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct StuckQueueReport {
+    pub persona_id: String,
+    pub persona_name: String,
+    pub max_concurrent: i32,
+    #[ts(type = "number")]
+    pub threshold_secs: u64,
+    pub queued: Vec<StuckQueuedExecution>,
+    pub blocking: Vec<BlockingExecution>,
+    /// "persona_limit" | "global_limit" | "quota_cooldown" | "resource_pressure"
+    pub cause: String,
+    /// Suggested `max_concurrent` for a bump, when the persona limit is the
+    /// cause and can still be raised.
+    pub suggested_max_concurrent: Option<i32>,
+    /// Oldest blocking execution — the suggested cancel target.
+    pub suggested_cancel_execution_id: Option<String>,
+}
+
+/// Configured wait threshold; `None` when the monitor is disabled (`0`).
+fn threshold(pool: &DbPool) -> Option<u64> {
+    let secs = settings::get(pool, QUEUE_STUCK_WAIT_SECS)
+        .ok()
+        .flatten()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(QUEUE_STUCK_WAIT_SECS_DEFAULT);
+    (secs > 0).then_some(secs)
+}
+
+fn has_open_issue(pool: &DbPool, persona_id: &str) -> bool {
+    pool.get()
+        .ok()
+        .and_then(|conn| {
+            conn.query_row(
+                "SELECT EXISTS(
+                    SELECT 1 FROM persona_healing_issues
+                    WHERE persona_id = ?1 AND status = 'open' AND category = ?2
+                )",
+                rusqlite::params![persona_id, ISSUE_CATEGORY],
+                |row| row.get::<_, bool>(0),
+            )
+            .ok()
+        })
+        .unwrap_or(false)
+}
+
+/// Pick the dominant cause in admission order: the gates that pause ALL
+/// admission first, then the global cap, then the persona's own limit.
+fn classify(
+    quota_paused: bool,
+    resource_paused: bool,
+    global_full: bool,
+    running: usize,
+    max_concurrent: i32,
+) -> &'static str {
+    if quota_paused {
+        "quota_cooldown"
+    } else if resource_paused {
+        "resource_pressure"
+    } else if max_concurrent > 0 && running >= max_concurrent as usize {
+        "persona_limit"
+    } else if global_full {
+        "global_limit"
+    } else {
+        // Capacity exists but nothing promoted the entry — the drain that
+        // should have picked it up was missed. Bumping re-runs the drain.
+        "persona_limit"
+    }
+}
+
+fn suggested_fix(report: &StuckQueueReport) -> String {
+    let cancel = report
+        .suggested_cancel_execution_id
+        .as_deref()
+        .map(|id| format!(" or cancel the blocking execution {id}"))
+        .unwrap_or_default();
+    match report.cause.as_str() {
+        "quota_cooldown" => {
+            "Admission is paused until the provider usage limit resets; queued runs start automatically afterwards.".into()
+        }
+        "resource_pressure" => {
+            "Admission is paused while system CPU/memory load is high; close heavy applications or wait for load to drop.".into()
+        }
+        "global_limit" => format!(
+            "All global execution slots are busy. Raise 'Max parallel executions' in Settings{cancel}."
+        ),
+        _ => match report.suggested_max_concurrent {
+            Some(n) => format!(
+                "Raise this agent's max concurrent runs from {} to {n}{cancel}.",
+                report.max_concurrent
+            ),
+            None => format!("This agent is at its maximum concurrency{cancel}."),
+        },
+    }
+}
+
+/// One monitor tick: report every persona whose oldest queued execution has
+/// waited past the threshold.
+pub async fn queue_age_tick(pool: &DbPool, app: &AppHandle, engine: &Arc<ExecutionEngine>) {
+    let Some(threshold_secs) = threshold(pool) else {
+        return;
+    };
+
+    // Snapshot everything under one lock, then do the DB work without it.
+    let (stale, gates, running_by_persona) = {
+        let t = engine.tracker().lock().await;
+        let stale = t.queued_longer_than(Duration::from_secs(threshold_secs));
+        let gates = (
+            !t.quota_available(),
+            !t.resource_available(),
+            !t.has_global_capacity(),
+        );
+        let mut running = std::collections::HashMap::new();
+        for entry in &stale {
+            running
+                .entry(entry.persona_id.clone())
+                .or_insert_with(|| t.running_ids(&entry.persona_id));
+        }
+        (stale, gates, running)
+    };
+    if stale.is_empty() {
+        return;
+    }
+    let (quota_paused, resource_paused, global_full) = gates;
+
+    for (persona_id, running_ids) in running_by_persona {
+        if has_open_issue(pool, &persona_id) {
+            continue;
+        }
+        let persona = match persona_repo::get_by_id(pool, &persona_id) {
+            Ok(p) => p,
+            Err(e) => {
+                tracing::debug!(persona_id = %persona_id, error = %e, "queue monitor: persona lookup failed");
+                continue;
+            }
+        };
+
+        let queued: Vec<StuckQueuedExecution> = stale
+            .iter()
+            .filter(|e| e.persona_id == persona_id)
+            .map(|e| StuckQueuedExecution {
+                execution_id: e.execution_id.clone(),
+                waited_secs: e.enqueued_at.elapsed().as_secs(),
+                priority: format!("{:?}", e.priority).to_lowercase(),
+            })
+            .collect();
+        let mut blocking: Vec<BlockingExecution> = running_ids
+            .iter()
+            .map(|id| {
+                let exec = exec_repo::get_by_id(pool, id).ok();
+                BlockingExecution {
+                    execution_id: id.clone(),
+                    status: exec.as_ref().map(|e| e.status.clone()),
+                    started_at: exec.and_then(|e| e.started_at),
+                }
+            })
+            .collect();
+        blocking.sort_by(|a, b| a.started_at.cmp(&b.started_at));
+
+        let cause = classify(
+            quota_paused,
+            resource_paused,
+            global_full,
+            running_ids.len(),
+            persona.max_concurrent,
+        );
+        let report = StuckQueueReport {
+            persona_id: persona_id.clone(),
+            persona_name: persona.name.clone(),
+            max_concurrent: persona.max_concurrent,
+            threshold_secs,
+            suggested_max_concurrent: (cause == "persona_limit"
+                && persona.max_concurrent < MAX_CONCURRENT_MAX)
+                .then_some(persona.max_concurrent.max(0) + 1),
+            suggested_cancel_execution_id: blocking.first().map(|b| b.execution_id.clone()),
+            queued,
+            blocking,
+            cause: cause.to_string(),
+        };
+        report_stuck(pool, app, &report);
+    }
+}
+
+fn report_stuck(pool: &DbPool, app: &AppHandle, report: &StuckQueueReport) {
+    let oldest = report.queued.first().map(|q| q.waited_secs / 60).unwrap_or(0);
+    let blockers = if report.blocking.is_empty() {
+        "no running executions of its own".to_string()
+    } else {
+        report
+            .blocking
+            .iter()
+            .map(|b| b.execution_id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let description = format!(
+        "{} execution(s) for {} have been queued for over {} min (oldest {} min). Cause: {}. Slots held by: {}.",
+        report.queued.len(),
+        report.persona_name,
+        report.threshold_secs / 60,
+        oldest,
+        report.cause.replace('_', " "),
+        blockers,
+    );
+    let fix = suggested_fix(report);
+    tracing::warn!(
+        persona_id = %report.persona_id,
+        queued = report.queued.len(),
+        cause = %report.cause,
+        "Queue-age monitor: executions stuck in queue"
+    );
+
+    let _ = message_repo::create(
+        pool,
+        CreateMessageInput {
+            persona_id: report.persona_id.clone(),
+            execution_id: report.queued.first().map(|q| q.execution_id.clone()),
+            title: Some(ISSUE_TITLE.into()),
+            content: format!("{description} {fix}"),
+            content_type: Some(ISSUE_CATEGORY.into()),
+            priority: Some("high".into()),
+            metadata: serde_json::to_string(report).ok(),
+            thread_id: None,
+            use_case_id: None,
+        },
+    );
+    let issue = healing_repo::create(
+        pool,
+        &report.persona_id,
+        ISSUE_TITLE,
+        &description,
+        false,
+        Some("medium"),
+        Some(ISSUE_CATEGORY),
+        report.queued.first().map(|q| q.execution_id.as_str()),
+        Some(&fix),
+    )
+    .ok()
+    .flatten();
+    let _ = app.emit(
+        event_name::HEALING_EVENT,
+        HealingEventPayload {
+            issue_id: issue.map(|i| i.id).unwrap_or_default(),
+            persona_id: report.persona_id.clone(),
+            execution_id: report
+                .queued
+                .first()
+                .map(|q| q.execution_id.clone())
+                .unwrap_or_default(),
+            title: ISSUE_TITLE.into(),
+            action: "issue_created".into(),
+            auto_fixed: false,
+            severity: "medium".into(),
+            suggested_fix: Some(fix),
+            persona_name: report.persona_name.clone(),
+            description: Some(description),
+            strategy: None,
+            backoff_seconds: None,
+            retry_number: None,
+            max_retries: None,
+        },
+    );
+    let _ = app.emit(event_name::QUEUE_STUCK_DETECTED, report);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_prefers_admission_gates_then_limits() {
+        assert_eq!(classify(true, true, true, 1, 1), "quota_cooldown");
+        assert_eq!(classify(false, true, true, 1, 1), "resource_pressure");
+        assert_eq!(classify(false, false, true, 1, 1), "persona_limit");
+        assert_eq!(classify(false, false, true, 0, 2), "global_limit");
+        assert_eq!(classify(false, false, false, 0, 2), "persona_limit");
+    }
+}
@@ -0,0 +1,508 @@
+//! File-report sink: turns a persona's run output into a dated report file.
+//!
+//! Recurring report personas ("weekly competitor digest", "Monday metrics")
+//! produce output nobody opens if it only lives in the execution list. A
+//! persona with a `persona_report_sinks` row gets, after each completed run
+//! (by default only runs fired by a schedule trigger):
+//!
+//! 1. The output rendered through the sink's Mustache-style template (or the
+//!    built-in layout) — same `{{path.to.value}}` syntax as webhook templates.
+//! 2. Written to `<directory>/<prefix>-<YYYY-MM-DD-HHMMSS>.md` or `.html`.
+//! 3. Optionally printed to a sibling `.pdf` through a local Chromium-family
+//!    browser (`--headless --print-to-pdf`). Desktop builds only.
+//!
+//! Failures never affect the execution; they land in the sink's `last_error`.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::json;
+
+use super::webhook_notifier::templating;
+use crate::db::models::{Persona, PersonaExecution, PersonaReportSink};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::resources::{report_sinks as sink_repo, triggers as trigger_repo};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Layout used when the sink has no template of its own.
+const DEFAULT_TEMPLATE: &str = "# {{persona.name}} — {{date}}\n\n\
+{{execution.output}}\n\n\
+---\n\n\
+*Run {{execution.id}} · {{execution.status}} · {{datetime}}*\n";
+
+const REPORT_CSS: &str = "body{font-family:-apple-system,Segoe UI,Helvetica,Arial,sans-serif;\
+max-width:860px;margin:2rem auto;padding:0 1rem;line-height:1.55;color:#1f2328}\
+pre{background:#f6f8fa;padding:.75rem;overflow:auto;border-radius:6px}\
+code{font-family:ui-monospace,Consolas,monospace;font-size:.92em}\
+table{border-collapse:collapse}th,td{border:1px solid #d0d7de;padding:.35rem .6rem}\
+blockquote{margin:0;padding-left:1rem;border-left:4px solid #d0d7de;color:#57606a}";
+
+/// Completion hook: write a report for `exec_id` if the persona has an
+/// enabled sink that wants this run. Blocking (file IO, optional browser
+/// print) — call from `spawn_blocking`.
+pub(crate) fn on_execution_complete(pool: &DbPool, persona_id: &str, exec_id: &str) {
+    let sink = match sink_repo::get(pool, persona_id) {
+        Ok(Some(sink)) if sink.enabled => sink,
+        Ok(_) => return,
+        Err(e) => {
+            tracing::warn!(persona_id, error = %e, "Report sink lookup failed");
+            return;
+        }
+    };
+    let (Ok(persona), Ok(exec)) = (
+        persona_repo::get_by_id(pool, persona_id),
+        exec_repo::get_by_id(pool, exec_id),
+    ) else {
+        return;
+    };
+    if exec.status != "completed" || exec.is_simulation {
+        return;
+    }
+    if sink.scheduled_only && !is_scheduled_run(pool, &exec) {
+        return;
+    }
+    let _ = write_and_record(pool, &sink, &persona, &exec);
+}
+
+/// Write the report for `exec` regardless of `scheduled_only`, recording the
+/// outcome on the sink. Returns the written path.
+pub fn write_and_record(
+    pool: &DbPool,
+    sink: &PersonaReportSink,
+    persona: &Persona,
+    exec: &PersonaExecution,
+) -> Result<PathBuf, AppError> {
+    let result = write_report(sink, persona, exec);
+    let record = match &result {
+        Ok((path, pdf_error)) => {
+            let written = path.to_string_lossy();
+            sink_repo::record_result(pool, &sink.persona_id, Ok(written.as_ref())).and_then(|()| {
+                match pdf_error {
+                    Some(e) => sink_repo::record_result(
+                        pool,
+                        &sink.persona_id,
+                        Err(format!("PDF conversion failed: {e}").as_str()),
+                    ),
+                    None => Ok(()),
+                }
+            })
+        }
+        Err(e) => sink_repo::record_result(pool, &sink.persona_id, Err(e.to_string().as_str())),
+    };
+    if let Err(e) = record {
+        tracing::warn!(persona_id = %sink.persona_id, error = %e, "Failed to record report sink outcome");
+    }
+    match result {
+        Ok((path, _)) => {
+            tracing::info!(persona_id = %sink.persona_id, execution_id = %exec.id, path = %path.display(), "Report written");
+            Ok(path)
+        }
+        Err(e) => {
+            tracing::warn!(persona_id = %sink.persona_id, execution_id = %exec.id, error = %e, "Report sink write failed");
+            Err(e)
+        }
+    }
+}
+
+fn is_scheduled_run(pool: &DbPool, exec: &PersonaExecution) -> bool {
+    exec.trigger_id
+        .as_deref()
+        .and_then(|id| trigger_repo::get_by_id(pool, id).ok())
+        .is_some_and(|t| t.trigger_type == "schedule")
+}
+
+/// Render and write the report. The second element carries a PDF conversion
+/// error; the main file is written either way.
+fn write_report(
+    sink: &PersonaReportSink,
+    persona: &Persona,
+    exec: &PersonaExecution,
+) -> Result<(PathBuf, Option<String>), AppError> {
+    let now = chrono::Local::now();
+    let context = json!({
+        "persona": { "id": persona.id, "name": persona.name },
+        "execution": {
+            "id": exec.id,
+            "status": exec.status,
+            "output": exec.output_data.as_deref().unwrap_or(""),
+            "started_at": exec.started_at,
+            "completed_at": exec.completed_at,
+            "duration_ms": exec.duration_ms,
+            "cost_usd": exec.cost_usd,
+            "use_case_id": exec.use_case_id,
+        },
+        "date": now.format("%Y-%m-%d").to_string(),
+        "datetime": now.format("%Y-%m-%d %H:%M").to_string(),
+    });
+    let markdown = templating::render(sink.template.as_deref().unwrap_or(DEFAULT_TEMPLATE), &context);
+
+    let dir = Path::new(&sink.directory);
+    std::fs::create_dir_all(dir)?;
+    let stem = format!(
+        "{}-{}",
+        filename_stem(sink.filename_prefix.as_deref().unwrap_or(&persona.name)),
+        now.format("%Y-%m-%d-%H%M%S")
+    );
+    let html = || html_document(&format!("{} — {}", persona.name, now.format("%Y-%m-%d")), &markdown);
+    let (path, body) = match sink.format.as_str() {
+        "html" => (unique_path(dir, &stem, "html"), html()),
+        _ => (unique_path(dir, &stem, "md"), markdown.clone()),
+    };
+    std::fs::write(&path, &body)?;
+
+    let pdf_error = if sink.pdf {
+        let source_html = if sink.format == "html" { body } else { html() };
+        print_pdf(&source_html, &path.with_extension("pdf")).err()
+    } else {
+        None
+    };
+    Ok((path, pdf_error))
+}
+
+/// Filesystem-safe filename stem: keeps letters, digits, `-` and `_`.
+fn filename_stem(raw: &str) -> String {
+    let mut stem = String::new();
+    for c in raw.trim().chars() {
+        if c.is_alphanumeric() || c == '-' || c == '_' {
+            stem.push(c);
+        } else if !stem.ends_with('-') {
+            stem.push('-');
+        }
+    }
+    let stem: String = stem.trim_matches('-').chars().take(60).collect();
+    if stem.is_empty() {
+        "report".into()
+    } else {
+        stem
+    }
+}
+
+/// `<dir>/<stem>.<ext>`, suffixed `-2`, `-3`, ... if a file already exists.
+fn unique_path(dir: &Path, stem: &str, ext: &str) -> PathBuf {
+    let mut path = dir.join(format!("{stem}.{ext}"));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{stem}-{n}.{ext}"));
+        n += 1;
+    }
+    path
+}
+
+fn html_document(title: &str, markdown: &str) -> String {
+    format!(
+        "<!doctype html>\n<html><head><meta charset=\"utf-8\"><title>{}</title><style>{REPORT_CSS}</style></head>\n<body><article>\n{}</article></body></html>\n",
+        escape_html(title),
+        markdown_to_html(markdown)
+    )
+}
+
+#[cfg(feature = "desktop")]
+fn print_pdf(html: &str, pdf_path: &Path) -> Result<(), String> {
+    use std::io::Write;
+    use std::time::{Duration, Instant};
+
+    const PRINT_TIMEOUT: Duration = Duration::from_secs(60);
+
+    let browser = match super::desktop_discovery::is_desktop_app_installed("desktop_browser") {
+        // Lightpanda speaks CDP but has no print pipeline.
+        (true, Some(path)) if !path.to_lowercase().contains("lightpanda") => path,
+        _ => return Err("no Chrome or Edge installation found".into()),
+    };
+    let mut source = tempfile::Builder::new()
+        .suffix(".html")
+        .tempfile()
+        .map_err(|e| e.to_string())?;
+    source.write_all(html.as_bytes()).map_err(|e| e.to_string())?;
+    let url = url::Url::from_file_path(source.path()).map_err(|_| "invalid temp path".to_string())?;
+
+    let mut child = std::process::Command::new(browser)
+        .arg("--headless")
+        .arg("--disable-gpu")
+        .arg("--no-pdf-header-footer")
+        .arg(format!("--print-to-pdf={}", pdf_path.display()))
+        .arg(url.as_str())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    let started = Instant::now();
+    loop {
+        match child.try_wait().map_err(|e| e.to_string())? {
+            Some(_) if pdf_path.exists() => return Ok(()),
+            Some(status) => return Err(format!("browser exited with {status} without writing a PDF")),
+            None if started.elapsed() > PRINT_TIMEOUT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err("browser timed out printing the report".into());
+            }
+            None => std::thread::sleep(Duration::from_millis(200)),
+        }
+    }
+}
+
+#[cfg(not(feature = "desktop"))]
+fn print_pdf(_html: &str, _pdf_path: &Path) -> Result<(), String> {
+    Err("PDF conversion requires the desktop build".into())
+}
+
+// =============================================================================
+// Minimal Markdown -> HTML (headings, paragraphs, lists, quotes, code, tables,
+// emphasis, links). Reports are LLM output, so the common subset is enough.
+// =============================================================================
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Inline formatting on already-escaped text.
+fn inline(escaped: &str) -> String {
+    let mut out = String::with_capacity(escaped.len());
+    let mut rest = escaped;
+    while let Some(c) = rest.chars().next() {
+        let delimited = |open: &str, close: &str| -> Option<(usize, usize)> {
+            let after = rest.strip_prefix(open)?;
+            let end = after.find(close).filter(|&e| e > 0)?;
+            Some((open.len() + end, open.len() + end + close.len()))
+        };
+        if c == '`' {
+            if let Some((end, next)) = delimited("`", "`") {
+                out.push_str(&format!("<code>{}</code>", &rest[1..end]));
+                rest = &rest[next..];
+                continue;
+            }
+        } else if rest.starts_with("**") {
+            if let Some((end, next)) = delimited("**", "**") {
+                out.push_str(&format!("<strong>{}</strong>", inline(&rest[2..end])));
+                rest = &rest[next..];
+                continue;
+            }
+        } else if c == '*' {
+            if let Some((end, next)) = delimited("*", "*") {
+                out.push_str(&format!("<em>{}</em>", inline(&rest[1..end])));
+                rest = &rest[next..];
+                continue;
+            }
+        } else if c == '[' {
+            if let Some(link) = parse_link(rest) {
+                out.push_str(&link.0);
+                rest = &rest[link.1..];
+                continue;
+            }
+        }
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// `[text](url)` → anchor markup and consumed length. Only web and mail
+/// links become anchors; anything else is left as text.
+fn parse_link(s: &str) -> Option<(String, usize)> {
+    let text_end = s.find("](")?;
+    let url_end = s[text_end + 2..].find(')')? + text_end + 2;
+    let url = &s[text_end + 2..url_end];
+    if !(url.starts_with("http://") || url.starts_with("https://") || url.starts_with("mailto:")) {
+        return None;
+    }
+    Some((
+        format!("<a href=\"{url}\">{}</a>", inline(&s[1..text_end])),
+        url_end + 1,
+    ))
+}
+
+fn table_cells(line: &str) -> Vec<String> {
+    line.trim()
+        .trim_matches('|')
+        .split('|')
+        .map(|c| inline(&escape_html(c.trim())))
+        .collect()
+}
+
+fn is_table_separator(line: &str) -> bool {
+    let t = line.trim();
+    t.starts_with('|') && t.contains('-') && t.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+fn markdown_to_html(markdown: &str) -> String {
+    let mut html = String::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut list: Option<&'static str> = None;
+    let lines: Vec<&str> = markdown.lines().collect();
+
+    fn flush(html: &mut String, paragraph: &mut Vec<String>, list: &mut Option<&'static str>) {
+        if !paragraph.is_empty() {
+            html.push_str(&format!("<p>{}</p>\n", paragraph.join("<br>\n")));
+            paragraph.clear();
+        }
+        if let Some(tag) = list.take() {
+            html.push_str(&format!("</{tag}>\n"));
+        }
+    }
+
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim_end();
+        let t = line.trim_start();
+        i += 1;
+
+        if t.starts_with("```") {
+            flush(&mut html, &mut paragraph, &mut list);
+            html.push_str("<pre><code>");
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                html.push_str(&escape_html(lines[i]));
+                html.push('\n');
+                i += 1;
+            }
+            i += 1; // closing fence
+            html.push_str("</code></pre>\n");
+            continue;
+        }
+        if t.is_empty() {
+            flush(&mut html, &mut paragraph, &mut list);
+            continue;
+        }
+        let hashes = t.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) && t[hashes..].starts_with(' ') {
+            flush(&mut html, &mut paragraph, &mut list);
+            let text = inline(&escape_html(t[hashes..].trim()));
+            html.push_str(&format!("<h{hashes}>{text}</h{hashes}>\n"));
+            continue;
+        }
+        if matches!(t, "---" | "***" | "___") {
+            flush(&mut html, &mut paragraph, &mut list);
+            html.push_str("<hr>\n");
+            continue;
+        }
+        if t.starts_with('|') && i < lines.len() && is_table_separator(lines[i]) {
+            flush(&mut html, &mut paragraph, &mut list);
+            html.push_str("<table>\n<thead><tr>");
+            for cell in table_cells(t) {
+                html.push_str(&format!("<th>{cell}</th>"));
+            }
+            html.push_str("</tr></thead>\n<tbody>\n");
+            i += 1;
+            while i < lines.len() && lines[i].trim_start().starts_with('|') {
+                html.push_str("<tr>");
+                for cell in table_cells(lines[i]) {
+                    html.push_str(&format!("<td>{cell}</td>"));
+                }
+                html.push_str("</tr>\n");
+                i += 1;
+            }
+            html.push_str("</tbody></table>\n");
+            continue;
+        }
+        if let Some(quote) = t.strip_prefix("> ").or_else(|| t.strip_prefix('>')) {
+            flush(&mut html, &mut paragraph, &mut list);
+            html.push_str(&format!("<blockquote>{}</blockquote>\n", inline(&escape_html(quote))));
+            continue;
+        }
+        let bullet = ["- ", "* ", "+ "].iter().find_map(|b| t.strip_prefix(b));
+        let numbered = t
+            .split_once(". ")
+            .filter(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+            .map(|(_, item)| item);
+        if let Some((tag, item)) = bullet.map(|b| ("ul", b)).or(numbered.map(|n| ("ol", n))) {
+            if !paragraph.is_empty() || list.is_some_and(|open| open != tag) {
+                flush(&mut html, &mut paragraph, &mut list);
+            }
+            if list.is_none() {
+                html.push_str(&format!("<{tag}>\n"));
+                list = Some(tag);
+            }
+            html.push_str(&format!("<li>{}</li>\n", inline(&escape_html(item))));
+            continue;
+        }
+        if list.is_some() {
+            flush(&mut html, &mut paragraph, &mut list);
+        }
+        paragraph.push(inline(&escape_html(t)));
+    }
+    flush(&mut html, &mut paragraph, &mut list);
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use crate::db::models::SaveReportSinkInput;
+    use crate::db::repos::test_fixtures::create_test_persona;
+
+    #[test]
+    fn markdown_subset_renders() {
+        let html = markdown_to_html(
+            "# Weekly <digest>\n\nSales **up** 4% — see [dash](https://x.io).\n\n- one\n- `two`\n\n| A | B |\n|---|---|\n| 1 | 2 |\n\n```\nlet x = 1 < 2;\n```\n",
+        );
+        assert!(html.contains("<h1>Weekly &lt;digest&gt;</h1>"));
+        assert!(html.contains("<strong>up</strong>"));
+        assert!(html.contains("<a href=\"https://x.io\">dash</a>"));
+        assert!(html.contains("<ul>\n<li>one</li>\n<li><code>two</code></li>\n</ul>"));
+        assert!(html.contains("<th>A</th><th>B</th>") && html.contains("<td>1</td><td>2</td>"));
+        assert!(html.contains("<pre><code>let x = 1 &lt; 2;\n</code></pre>"));
+        // Non-web link targets stay text.
+        assert!(!markdown_to_html("[x](javascript:alert(1))").contains("<a "));
+    }
+
+    #[test]
+    fn filename_stem_is_filesystem_safe() {
+        assert_eq!(filename_stem("Weekly / Sales: Digest"), "Weekly-Sales-Digest");
+        assert_eq!(filename_stem("../.."), "report");
+    }
+
+    #[test]
+    fn completed_run_is_written_with_template() {
+        let pool = init_test_db().unwrap();
+        let persona = create_test_persona(&pool, "Digest Bot", "report");
+        let dir = tempfile::tempdir().unwrap();
+        sink_repo::upsert(
+            &pool,
+            &persona.id,
+            SaveReportSinkInput {
+                enabled: true,
+                directory: dir.path().to_string_lossy().into_owned(),
+                format: "markdown".into(),
+                template: Some("## {{persona.name}}\n{{execution.output}}".into()),
+                filename_prefix: None,
+                scheduled_only: false,
+                pdf: false,
+            },
+        )
+        .unwrap();
+        let exec = exec_repo::create(&pool, &persona.id, None, None, None, None).unwrap();
+        pool.get()
+            .unwrap()
+            .execute(
+                "UPDATE persona_executions SET status = 'completed', output_data = 'All green.' WHERE id = ?1",
+                rusqlite::params![exec.id],
+            )
+            .unwrap();
+
+        on_execution_complete(&pool, &persona.id, &exec.id);
+
+        let sink = sink_repo::get(&pool, &persona.id).unwrap().unwrap();
+        let path = PathBuf::from(sink.last_written_path.expect("report written"));
+        assert!(path.file_name().unwrap().to_string_lossy().starts_with("Digest-Bot-"));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "## Digest Bot\nAll green.");
+
+        // scheduled_only skips manual runs.
+        let manual = exec_repo::create(&pool, &persona.id, None, None, None, None).unwrap();
+        pool.get()
+            .unwrap()
+            .execute(
+                "UPDATE persona_executions SET status = 'completed' WHERE id = ?1",
+                rusqlite::params![manual.id],
+            )
+            .unwrap();
+        pool.get()
+            .unwrap()
+            .execute("UPDATE persona_report_sinks SET scheduled_only = 1", [])
+            .unwrap();
+        on_execution_complete(&pool, &persona.id, &manual.id);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
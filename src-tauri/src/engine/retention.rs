@@ -0,0 +1,292 @@
+//! Retention for execution history, execution log files, and messages.
+//!
+//! Run hourly by the cleanup subscription and on demand through
+//! `run_retention_cleanup_now`. Every knob lives in `app_settings`:
+//!
+//! - `execution_retention_days` — terminal executions older than this go,
+//!   always keeping the newest [`MIN_EXECUTIONS_KEPT`] per persona. Shared
+//!   context blackboards (`engine::shared_context`) idle that long go too.
+//! - `execution_retention_max_per_persona` — hard cap on terminal executions
+//!   per persona regardless of age (`0` = uncapped).
+//! - `message_retention_days` / `message_retention_max_per_persona` — the same
+//!   pair for READ messages. Unread messages are never pruned.
+//!
+//! Execution log files (`logs/<execution_id>.log`, its rotated segments and
+//! `.log.gz` archive) and offloaded outputs (`logs/<execution_id>.output.gz`,
+//! see `engine::output_store`) are swept afterwards: any file whose execution
+//! row no longer exists is deleted. The logs of finished runs that survive are
+//! then compressed (`engine::log_archive`). Tracing logs and other
+//! non-execution files in the directory are left alone.
+
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::background::parse_retention_setting;
+use crate::db::repos::communication::messages as messages_repo;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::shared_context as shared_context_repo;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::engine::{log_archive, output_store};
+
+/// Executions per persona that survive age-based retention.
+const MIN_EXECUTIONS_KEPT: usize = 50;
+
+/// Log files younger than this are never swept, so a run whose row is still
+/// being written can't lose its log.
+const LOG_MIN_AGE: Duration = Duration::from_secs(24 * 3600);
+
+/// What one retention pass removed.
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+pub struct RetentionReport {
+    #[ts(type = "number")]
+    pub executions_deleted: u64,
+    #[ts(type = "number")]
+    pub messages_deleted: u64,
+    #[ts(type = "number")]
+    pub log_files_deleted: u64,
+    /// Bytes of execution log files removed from disk.
+    #[ts(type = "number")]
+    pub log_bytes_reclaimed: u64,
+    /// Offloaded execution outputs removed along with their rows.
+    #[ts(type = "number")]
+    pub output_files_deleted: u64,
+    #[ts(type = "number")]
+    pub output_bytes_reclaimed: u64,
+    /// Logs of finished executions compressed during the pass.
+    #[ts(type = "number")]
+    pub log_files_compressed: u64,
+    /// Disk space compression saved.
+    #[ts(type = "number")]
+    pub log_bytes_compressed: u64,
+    /// Growth of the SQLite free-page list during the pass. Pages are reused by
+    /// later writes; the file itself only shrinks on `VACUUM`.
+    #[ts(type = "number")]
+    pub db_bytes_freed: u64,
+    pub ran_at: String,
+}
+
+/// Bytes currently on the SQLite freelist.
+fn freelist_bytes(pool: &DbPool) -> u64 {
+    let Ok(conn) = pool.get() else { return 0 };
+    let pages: i64 = conn
+        .query_row("PRAGMA freelist_count", [], |r| r.get(0))
+        .unwrap_or(0);
+    let page_size: i64 = conn
+        .query_row("PRAGMA page_size", [], |r| r.get(0))
+        .unwrap_or(0);
+    (pages.max(0) * page_size.max(0)) as u64
+}
+
+/// Apply every retention setting once and report what was removed. Each step
+/// is independent: a failing step is logged and the rest still run.
+pub fn run_retention(pool: &DbPool) -> RetentionReport {
+    let freelist_before = freelist_bytes(pool);
+    let mut report = RetentionReport::default();
+
+    let exec_days = parse_retention_setting(
+        pool,
+        settings_keys::EXECUTION_RETENTION_DAYS,
+        settings_keys::EXECUTION_RETENTION_DAYS_DEFAULT,
+    );
+    match exec_repo::cleanup_old_executions(pool, exec_days, MIN_EXECUTIONS_KEPT) {
+        Ok(n) => report.executions_deleted += n as u64,
+        Err(e) => tracing::error!("Execution retention error: {}", e),
+    }
+    let exec_cap = parse_retention_setting(
+        pool,
+        settings_keys::EXECUTION_RETENTION_MAX_PER_PERSONA,
+        settings_keys::EXECUTION_RETENTION_MAX_PER_PERSONA_DEFAULT,
+    );
+    match exec_repo::cap_terminal_per_persona(pool, exec_cap.max(0) as usize) {
+        Ok(n) => report.executions_deleted += n as u64,
+        Err(e) => tracing::error!("Execution per-persona cap error: {}", e),
+    }
+    // Shared context blackboards outlive their runs by the same window.
+    if exec_days > 0 {
+        if let Err(e) = shared_context_repo::cleanup_older_than(pool, exec_days) {
+            tracing::error!("Shared context retention error: {}", e);
+        }
+    }
+
+    let msg_days = parse_retention_setting(
+        pool,
+        settings_keys::MESSAGE_RETENTION_DAYS,
+        settings_keys::MESSAGE_RETENTION_DAYS_DEFAULT,
+    );
+    if msg_days > 0 {
+        match messages_repo::cleanup_old_messages(pool, msg_days) {
+            Ok(n) => report.messages_deleted += n as u64,
+            Err(e) => tracing::error!("Message retention error: {}", e),
+        }
+    }
+    let msg_cap = parse_retention_setting(
+        pool,
+        settings_keys::MESSAGE_RETENTION_MAX_PER_PERSONA,
+        settings_keys::MESSAGE_RETENTION_MAX_PER_PERSONA_DEFAULT,
+    );
+    match messages_repo::cap_read_per_persona(pool, msg_cap.max(0) as usize) {
+        Ok(n) => report.messages_deleted += n as u64,
+        Err(e) => tracing::error!("Message per-persona cap error: {}", e),
+    }
+
+    if let Some(log_dir) = crate::db::primary_db_path()
+        .and_then(|p| p.parent().map(|d| d.join("logs")))
+    {
+        match exec_repo::list_all_ids(pool) {
+            Ok(live) => {
+                let (files, bytes) = prune_orphan_logs(&log_dir, |id| live.contains(id));
+                report.log_files_deleted = files;
+                report.log_bytes_reclaimed = bytes;
+                let (files, bytes) =
+                    prune_orphan_files(&log_dir, output_store::FILE_SUFFIX, |id| live.contains(id));
+                report.output_files_deleted = files;
+                report.output_bytes_reclaimed = bytes;
+            }
+            Err(e) => tracing::error!("Execution log sweep skipped: {}", e),
+        }
+        match exec_repo::list_active_ids(pool) {
+            Ok(active) => {
+                let (files, bytes) =
+                    log_archive::compress_completed_logs(&log_dir, |id| active.contains(id));
+                report.log_files_compressed = files;
+                report.log_bytes_compressed = bytes;
+            }
+            Err(e) => tracing::error!("Execution log compression skipped: {}", e),
+        }
+    }
+
+    report.db_bytes_freed = freelist_bytes(pool).saturating_sub(freelist_before);
+    report.ran_at = chrono::Utc::now().to_rfc3339();
+    report
+}
+
+/// Delete `<uuid>.log` files (and their rotated segments and archives) in
+/// `log_dir` older than [`LOG_MIN_AGE`] whose execution id fails `is_live`.
+/// Returns `(files, bytes)` removed.
+fn prune_orphan_logs(log_dir: &Path, is_live: impl Fn(&str) -> bool) -> (u64, u64) {
+    prune_orphans(log_dir, log_archive::is_log_part, is_live)
+}
+
+/// Delete `<uuid><suffix>` files in `log_dir` older than [`LOG_MIN_AGE`]
+/// whose execution id fails `is_live`. Returns `(files, bytes)` removed.
+fn prune_orphan_files(log_dir: &Path, suffix: &str, is_live: impl Fn(&str) -> bool) -> (u64, u64) {
+    prune_orphans(log_dir, |ext| ext == suffix, is_live)
+}
+
+/// Delete `<uuid><ext>` files in `log_dir` older than [`LOG_MIN_AGE`] whose
+/// `ext` (everything from the first `.`) passes `is_ours` and whose execution
+/// id fails `is_live`. Returns `(files, bytes)` removed.
+fn prune_orphans(
+    log_dir: &Path,
+    is_ours: impl Fn(&str) -> bool,
+    is_live: impl Fn(&str) -> bool,
+) -> (u64, u64) {
+    let Ok(entries) = std::fs::read_dir(log_dir) else {
+        return (0, 0);
+    };
+    let now = SystemTime::now();
+    let (mut files, mut bytes) = (0u64, 0u64);
+    for entry in entries.flatten() {
+        let path = entry.path();
+        // Execution ids are UUIDs; anything else (personas.log.*, last_boot.log)
+        // belongs to someone else.
+        let Some((id, ext)) = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .and_then(|name| name.find('.').map(|i| name.split_at(i)))
+        else {
+            continue;
+        };
+        if !is_ours(ext) || uuid::Uuid::parse_str(id).is_err() || is_live(id) {
+            continue;
+        }
+        let Ok(meta) = entry.metadata() else { continue };
+        let old_enough = meta
+            .modified()
+            .ok()
+            .and_then(|m| now.duration_since(m).ok())
+            .is_some_and(|age| age >= LOG_MIN_AGE);
+        if !meta.is_file() || !old_enough {
+            continue;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            files += 1;
+            bytes += meta.len();
+        }
+    }
+    (files, bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_old_orphaned_execution_logs_are_swept() {
+        let dir = tempfile::tempdir().unwrap();
+        let orphan = uuid::Uuid::new_v4().to_string();
+        let live = uuid::Uuid::new_v4().to_string();
+        let fresh = uuid::Uuid::new_v4().to_string();
+        let old = SystemTime::now() - Duration::from_secs(48 * 3600);
+        for name in [
+            format!("{orphan}.log"),
+            format!("{orphan}.log.1"),
+            format!("{orphan}.log.gz"),
+            format!("{live}.log"),
+            format!("{live}.log.gz"),
+            "personas.log".to_string(),
+            "last_boot.log".to_string(),
+        ] {
+            let f = std::fs::File::create(dir.path().join(name)).unwrap();
+            std::io::Write::write_all(&mut &f, b"line\n").unwrap();
+            f.set_modified(old).unwrap();
+        }
+        std::fs::write(dir.path().join(format!("{fresh}.log")), b"new").unwrap();
+
+        let (files, bytes) = prune_orphan_logs(dir.path(), |id| id == live);
+        assert_eq!((files, bytes), (3, 15));
+        assert!(!dir.path().join(format!("{orphan}.log")).exists());
+        assert!(!dir.path().join(format!("{orphan}.log.1")).exists());
+        assert!(!dir.path().join(format!("{orphan}.log.gz")).exists());
+        assert!(dir.path().join(format!("{live}.log")).exists());
+        assert!(dir.path().join(format!("{live}.log.gz")).exists());
+        assert!(dir.path().join(format!("{fresh}.log")).exists());
+        assert!(dir.path().join("personas.log").exists());
+        assert!(dir.path().join("last_boot.log").exists());
+    }
+
+    #[test]
+    fn orphaned_offloaded_outputs_are_swept_with_their_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let orphan = uuid::Uuid::new_v4().to_string();
+        let live = uuid::Uuid::new_v4().to_string();
+        let old = SystemTime::now() - Duration::from_secs(48 * 3600);
+        for name in [
+            format!("{orphan}{}", output_store::FILE_SUFFIX),
+            format!("{live}{}", output_store::FILE_SUFFIX),
+            format!("{orphan}.log"),
+        ] {
+            let f = std::fs::File::create(dir.path().join(name)).unwrap();
+            std::io::Write::write_all(&mut &f, b"gz").unwrap();
+            f.set_modified(old).unwrap();
+        }
+
+        let swept = prune_orphan_files(dir.path(), output_store::FILE_SUFFIX, |id| id == live);
+        assert_eq!(swept, (1, 2));
+        assert!(!dir
+            .path()
+            .join(format!("{orphan}{}", output_store::FILE_SUFFIX))
+            .exists());
+        assert!(dir
+            .path()
+            .join(format!("{live}{}", output_store::FILE_SUFFIX))
+            .exists());
+        // The log sweep is a separate pass.
+        assert!(dir.path().join(format!("{orphan}.log")).exists());
+    }
+}
@@ -1,7 +1,9 @@
 //! Credential rotation engine.
 //!
-//! Evaluates rotation policies, refreshes OAuth tokens, runs healthchecks,
-//! and records rotation history. Integrated into the scheduler background loop.
+//! Evaluates rotation policies, refreshes OAuth tokens, issues replacement
+//! keys for services with a rotation API (see `RotationDriver`), runs
+//! healthchecks, and records rotation history. Integrated into the scheduler
+//! background loop.
 //!
 //! Uses windowed anomaly scoring instead of a hard failure cutoff: a sliding
 //! window of recent healthcheck results is maintained per credential, and
@@ -9,8 +11,8 @@
 //! classified as transient (429, 503, timeout) vs permanent (401, 403),
 //! and remediation policies vary accordingly.
 
-use std::collections::HashSet;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::Mutex;
 
 use ts_rs::TS;
@@ -556,17 +558,8 @@ pub async fn evaluate_due_rotations(pool: &DbPool, app: &AppHandle) {
             }
         };
 
-        // Dispatch rotation through the connector strategy
-        let reg = match connector_strategy::registry() {
-            Ok(r) => r,
-            Err(e) => {
-                tracing::error!("Connector registry unavailable for rotation: {e}");
-                unlock_credential(&policy.credential_id);
-                continue;
-            }
-        };
-        let strategy = reg.get(&credential.service_type, None);
-        let result = strategy.rotate(pool, &credential).await;
+        // Dispatch rotation through the provider driver or connector strategy
+        let result = dispatch_rotation(pool, &credential).await;
 
         match result {
             Ok(raw_detail) => {
@@ -940,7 +933,448 @@ pub async fn detect_anomalies(pool: &DbPool, app: &AppHandle) {
 
 // OAuth/API-key rotation logic is now consolidated in connector strategies
 // (see `connector_strategy.rs`). The default strategy trait impl delegates
-// rotation to a healthcheck round-trip.
+// rotation to a healthcheck round-trip; connectors with a `RotationDriver`
+// bypass the strategy and get a real key rotation.
+
+// ---------------------------------------------------------------------------
+// Provider-side key rotation drivers
+// ---------------------------------------------------------------------------
+//
+// Connector strategies "rotate" API keys by re-verifying them, since most
+// services offer no way to mint a key programmatically. For services that do,
+// a `RotationDriver` issues a real replacement: the new key is verified before
+// it is committed, and the old key is revoked only after the commit.
+//
+// GitHub has no driver: it has no API for minting fine-grained PATs, so
+// GitHub credentials keep the verify-only rotation.
+
+/// Replacement secret returned by [`RotationDriver::issue`].
+pub struct IssuedKey {
+    /// Credential fields to overwrite with the new secret.
+    pub fields: HashMap<String, String>,
+    /// Provider-side id of the new key, for rolling it back.
+    pub key_id: Option<String>,
+}
+
+#[async_trait::async_trait]
+pub trait RotationDriver: Send + Sync {
+    fn label(&self) -> &'static str;
+
+    /// Whether issuing invalidates the old key on its own (a "roll"). The new
+    /// key must then be committed even if verification fails, since the old
+    /// one no longer works either.
+    fn issue_invalidates_old(&self) -> bool {
+        false
+    }
+
+    async fn issue(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+    ) -> Result<IssuedKey, AppError>;
+
+    /// Check that `fields` (with the new key merged in) authenticate.
+    async fn verify(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+    ) -> Result<(), AppError>;
+
+    /// Delete a new key that failed verification. `fields` are the originals.
+    async fn revoke_new(
+        &self,
+        _client: &reqwest::Client,
+        _fields: &HashMap<String, String>,
+        _issued: &IssuedKey,
+    ) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    /// Revoke the replaced key; returns a short note for the rotation detail.
+    async fn revoke_old(
+        &self,
+        client: &reqwest::Client,
+        original: &HashMap<String, String>,
+    ) -> Result<String, AppError>;
+}
+
+/// Driver for a connector, when its provider has a key rotation API.
+pub fn rotation_driver(service_type: &str) -> Option<&'static dyn RotationDriver> {
+    match service_type {
+        "cloudflare" => Some(&CloudflareTokenDriver),
+        "supabase" => Some(&SupabaseSecretKeyDriver),
+        _ => None,
+    }
+}
+
+fn driver_client() -> Result<reqwest::Client, AppError> {
+    reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .user_agent("personas-desktop")
+        .dns_resolver(std::sync::Arc::new(
+            crate::engine::url_safety::SsrfSafeDnsResolver,
+        ))
+        .build()
+        .map_err(|e| AppError::Internal(format!("HTTP client error: {e}")))
+}
+
+fn required_field<'a>(
+    fields: &'a HashMap<String, String>,
+    key: &str,
+    label: &str,
+) -> Result<&'a str, AppError> {
+    fields
+        .get(key)
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| AppError::Validation(format!("{label} rotation needs the `{key}` field")))
+}
+
+/// Send a request and return its JSON body, mapping non-2xx to an error
+/// carrying the provider's message.
+async fn driver_json(
+    request: reqwest::RequestBuilder,
+    what: &str,
+) -> Result<serde_json::Value, AppError> {
+    let resp = request
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(format!("{what} failed: {e}")))?;
+    let status = resp.status();
+    let body: serde_json::Value = resp.json().await.unwrap_or(serde_json::Value::Null);
+    if !status.is_success() {
+        let message = body
+            .pointer("/errors/0/message")
+            .or_else(|| body.get("message"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("no error message");
+        return Err(AppError::Internal(sanitize_secrets(&format!(
+            "{what} failed: HTTP {} ({message})",
+            status.as_u16()
+        ))));
+    }
+    Ok(body)
+}
+
+/// Rotate through `driver`: issue, verify, commit, then revoke the old key.
+/// A key that fails verification is rolled back and the old one kept.
+async fn rotate_with_driver(
+    pool: &DbPool,
+    credential: &crate::db::models::PersonaCredential,
+    driver: &dyn RotationDriver,
+) -> Result<String, AppError> {
+    let label = driver.label();
+    let original = cred_repo::get_decrypted_fields(pool, credential)?;
+    if let Err(e) = crate::db::repos::resources::audit_log::log_decrypt(
+        pool,
+        &credential.id,
+        &credential.name,
+        "rotation:driver",
+        None,
+        None,
+    ) {
+        tracing::warn!(credential_id = %credential.id, error = %e, "Failed to write audit log for credential decrypt");
+    }
+    let client = driver_client()?;
+
+    let issued = driver.issue(&client, &original).await?;
+    let mut updated = original.clone();
+    updated.extend(issued.fields.clone());
+
+    if driver.issue_invalidates_old() {
+        cred_repo::save_fields(pool, &credential.id, &updated)?;
+        driver.verify(&client, &updated).await.map_err(|e| {
+            AppError::Internal(format!(
+                "{label} rolled the key and it was saved, but it failed verification: {e}"
+            ))
+        })?;
+        return Ok(format!(
+            "{label}: key rolled and verified; the provider invalidated the previous key"
+        ));
+    }
+
+    if let Err(e) = driver.verify(&client, &updated).await {
+        if let Err(re) = driver.revoke_new(&client, &original, &issued).await {
+            tracing::warn!(
+                credential_id = %credential.id,
+                error = %re,
+                "Rotation: failed to delete unverified replacement key"
+            );
+        }
+        return Err(AppError::Internal(format!(
+            "{label}: replacement key failed verification, kept the current key: {e}"
+        )));
+    }
+
+    cred_repo::save_fields(pool, &credential.id, &updated)?;
+
+    let revoke_note = match driver.revoke_old(&client, &original).await {
+        Ok(note) => note,
+        Err(e) => {
+            let note = format!("previous key NOT revoked: {e}");
+            crate::db::repos::resources::audit_log::insert_warn(
+                pool,
+                &credential.id,
+                &credential.name,
+                "rotation_revoke_failed",
+                Some(&sanitize_secrets(&note)),
+            );
+            note
+        }
+    };
+    Ok(format!(
+        "{label}: new key issued, verified and saved; {revoke_note}"
+    ))
+}
+
+/// Rotate via the connector's driver when it has one, otherwise through its
+/// connector strategy.
+async fn dispatch_rotation(
+    pool: &DbPool,
+    credential: &crate::db::models::PersonaCredential,
+) -> Result<String, AppError> {
+    if let Some(driver) = rotation_driver(&credential.service_type) {
+        return rotate_with_driver(pool, credential, driver).await;
+    }
+    let strategy = connector_strategy::registry()?.get(&credential.service_type, None);
+    strategy.rotate(pool, credential).await
+}
+
+// -- Cloudflare: roll the API token in place ------------------------
+
+/// `PUT /user/tokens/{id}/value` replaces the token's secret, invalidating the
+/// old one. The token needs the "API Tokens: Edit" permission to roll itself.
+pub struct CloudflareTokenDriver;
+
+const CLOUDFLARE_API: &str = "https://api.cloudflare.com/client/v4";
+
+#[async_trait::async_trait]
+impl RotationDriver for CloudflareTokenDriver {
+    fn label(&self) -> &'static str {
+        "Cloudflare"
+    }
+
+    fn issue_invalidates_old(&self) -> bool {
+        true
+    }
+
+    async fn issue(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+    ) -> Result<IssuedKey, AppError> {
+        let token = required_field(fields, "api_token", self.label())?;
+        let verified = driver_json(
+            client
+                .get(format!("{CLOUDFLARE_API}/user/tokens/verify"))
+                .bearer_auth(token),
+            "Cloudflare token lookup",
+        )
+        .await?;
+        let token_id = verified
+            .pointer("/result/id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| AppError::Internal("Cloudflare did not return the token id".into()))?
+            .to_string();
+        let rolled = driver_json(
+            client
+                .put(format!("{CLOUDFLARE_API}/user/tokens/{token_id}/value"))
+                .bearer_auth(token)
+                .json(&serde_json::json!({})),
+            "Cloudflare token roll",
+        )
+        .await?;
+        let new_token = rolled
+            .get("result")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| AppError::Internal("Cloudflare did not return the new token".into()))?;
+        Ok(IssuedKey {
+            fields: HashMap::from([("api_token".to_string(), new_token.to_string())]),
+            key_id: Some(token_id),
+        })
+    }
+
+    async fn verify(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+    ) -> Result<(), AppError> {
+        let token = required_field(fields, "api_token", self.label())?;
+        let body = driver_json(
+            client
+                .get(format!("{CLOUDFLARE_API}/user/tokens/verify"))
+                .bearer_auth(token),
+            "Cloudflare token verify",
+        )
+        .await?;
+        match body.pointer("/result/status").and_then(|v| v.as_str()) {
+            Some("active") => Ok(()),
+            other => Err(AppError::Internal(format!(
+                "Cloudflare token status is {}",
+                other.unwrap_or("unknown")
+            ))),
+        }
+    }
+
+    async fn revoke_old(
+        &self,
+        _client: &reqwest::Client,
+        _original: &HashMap<String, String>,
+    ) -> Result<String, AppError> {
+        Ok("previous value invalidated by the roll".into())
+    }
+}
+
+// -- Supabase: new secret API key via the Management API -------------
+
+/// Issues a new `sb_secret_…` key through the Management API and deletes the
+/// old one. Needs a Management API access token (`sbp_…`) in the
+/// `management_token` field. Legacy JWT `service_role` keys cannot be revoked
+/// individually; those are replaced but left for the user to disable.
+pub struct SupabaseSecretKeyDriver;
+
+const SUPABASE_MANAGEMENT_API: &str = "https://api.supabase.com/v1";
+
+/// Project ref from `https://<ref>.supabase.co`.
+fn supabase_project_ref(fields: &HashMap<String, String>) -> Result<String, AppError> {
+    let url = required_field(fields, "project_url", "Supabase")?;
+    let parsed = url::Url::parse(url)
+        .map_err(|e| AppError::Validation(format!("Invalid Supabase project_url: {e}")))?;
+    parsed
+        .host_str()
+        .and_then(|h| h.strip_suffix(".supabase.co"))
+        .filter(|r| !r.is_empty() && !r.contains('.'))
+        .map(str::to_string)
+        .ok_or_else(|| {
+            AppError::Validation(
+                "Supabase rotation needs a hosted project_url (https://<ref>.supabase.co)".into(),
+            )
+        })
+}
+
+#[async_trait::async_trait]
+impl RotationDriver for SupabaseSecretKeyDriver {
+    fn label(&self) -> &'static str {
+        "Supabase"
+    }
+
+    async fn issue(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+    ) -> Result<IssuedKey, AppError> {
+        let project = supabase_project_ref(fields)?;
+        let mgmt = required_field(fields, "management_token", self.label())?;
+        let created = driver_json(
+            client
+                .post(format!(
+                    "{SUPABASE_MANAGEMENT_API}/projects/{project}/api-keys?reveal=true"
+                ))
+                .bearer_auth(mgmt)
+                .json(&serde_json::json!({
+                    "type": "secret",
+                    "name": format!("personas_{}", chrono::Utc::now().format("%Y%m%d%H%M%S")),
+                })),
+            "Supabase key creation",
+        )
+        .await?;
+        let new_key = created
+            .get("api_key")
+            .and_then(|v| v.as_str())
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| AppError::Internal("Supabase did not return the new key".into()))?;
+        Ok(IssuedKey {
+            fields: HashMap::from([("service_role_key".to_string(), new_key.to_string())]),
+            key_id: created
+                .get("id")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+        })
+    }
+
+    async fn verify(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+    ) -> Result<(), AppError> {
+        let project = supabase_project_ref(fields)?;
+        let key = required_field(fields, "service_role_key", self.label())?;
+        driver_json(
+            client
+                .get(format!("https://{project}.supabase.co/rest/v1/"))
+                .header("apikey", key),
+            "Supabase key verify",
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn revoke_new(
+        &self,
+        client: &reqwest::Client,
+        fields: &HashMap<String, String>,
+        issued: &IssuedKey,
+    ) -> Result<(), AppError> {
+        let Some(id) = issued.key_id.as_deref() else {
+            return Ok(());
+        };
+        let project = supabase_project_ref(fields)?;
+        let mgmt = required_field(fields, "management_token", self.label())?;
+        driver_json(
+            client
+                .delete(format!(
+                    "{SUPABASE_MANAGEMENT_API}/projects/{project}/api-keys/{id}"
+                ))
+                .bearer_auth(mgmt),
+            "Supabase key deletion",
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn revoke_old(
+        &self,
+        client: &reqwest::Client,
+        original: &HashMap<String, String>,
+    ) -> Result<String, AppError> {
+        let old_key = required_field(original, "service_role_key", self.label())?;
+        if !old_key.starts_with("sb_secret_") {
+            return Ok(
+                "the previous key is a legacy service_role JWT; disable legacy keys in the Supabase dashboard to revoke it"
+                    .into(),
+            );
+        }
+        let project = supabase_project_ref(original)?;
+        let mgmt = required_field(original, "management_token", self.label())?;
+        let keys = driver_json(
+            client
+                .get(format!(
+                    "{SUPABASE_MANAGEMENT_API}/projects/{project}/api-keys?reveal=true"
+                ))
+                .bearer_auth(mgmt),
+            "Supabase key listing",
+        )
+        .await?;
+        let id = keys
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|k| k.get("api_key").and_then(|v| v.as_str()) == Some(old_key))
+            .and_then(|k| k.get("id"))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| AppError::NotFound("previous key in the project's key list".into()))?;
+        driver_json(
+            client
+                .delete(format!(
+                    "{SUPABASE_MANAGEMENT_API}/projects/{project}/api-keys/{id}"
+                ))
+                .bearer_auth(mgmt),
+            "Supabase key deletion",
+        )
+        .await?;
+        Ok("previous key deleted".into())
+    }
+}
 
 // ---------------------------------------------------------------------------
 // Manual rotation trigger
@@ -969,15 +1403,8 @@ pub async fn rotate_now(
         }
     };
 
-    // Dispatch rotation through the connector strategy
-    let strategy = match connector_strategy::registry() {
-        Ok(r) => r.get(&credential.service_type, None),
-        Err(e) => {
-            unlock_credential(credential_id);
-            return Err(e);
-        }
-    };
-    let result = strategy.rotate(pool, &credential).await;
+    // Dispatch rotation through the provider driver or connector strategy
+    let result = dispatch_rotation(pool, &credential).await;
 
     match &result {
         Ok(detail) => {
@@ -1300,18 +1727,10 @@ fn evaluate_expiration_event(
 
     let ledger = crate::db::models::CredentialLedger::parse(credential.metadata.as_deref());
 
-    // Check typed oauth_token_expires_at first, then fall back to custom hints
+    // Check typed oauth_token_expires_at first, then the credential's own expiry
     let expires_at = ledger
         .oauth_expires_at()
-        .or_else(|| {
-            // Legacy: check custom hints for expires_at / expiresAt
-            ledger
-                .custom
-                .get("expires_at")
-                .or_else(|| ledger.custom.get("expiresAt"))
-                .and_then(|v| v.as_str())
-                .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-        })
+        .or_else(|| ledger.credential_expires_at())
         .map(|dt| dt.with_timezone(&chrono::Utc));
 
     match expires_at {
@@ -1372,6 +1791,121 @@ fn parse_event_config(config: Option<&str>) -> serde_json::Value {
         .unwrap_or(serde_json::Value::Object(serde_json::Map::new()))
 }
 
+// ---------------------------------------------------------------------------
+// Expiry tracking and renewal alerts
+// ---------------------------------------------------------------------------
+
+/// A credential whose `expires_at` falls inside a look-ahead window (or has
+/// already passed).
+#[derive(Debug, Clone, serde::Serialize, TS)]
+#[ts(export)]
+pub struct ExpiringCredential {
+    pub credential_id: String,
+    pub name: String,
+    pub service_type: String,
+    pub expires_at: String,
+    /// Whole days until expiry; negative once expired.
+    pub days_remaining: i64,
+    pub expired: bool,
+}
+
+/// Credentials expiring within `days` (including already-expired ones),
+/// soonest first.
+pub fn list_expiring(pool: &DbPool, days: u32) -> Result<Vec<ExpiringCredential>, AppError> {
+    let now = chrono::Utc::now();
+    let horizon = now + chrono::Duration::days(i64::from(days));
+    let mut out: Vec<ExpiringCredential> = cred_repo::get_all(pool)?
+        .into_iter()
+        .filter_map(|cred| {
+            let ledger = crate::db::models::CredentialLedger::parse(cred.metadata.as_deref());
+            let exp = ledger.credential_expires_at()?.with_timezone(&chrono::Utc);
+            (exp <= horizon).then(|| ExpiringCredential {
+                credential_id: cred.id,
+                name: cred.name,
+                service_type: cred.service_type,
+                expires_at: exp.to_rfc3339(),
+                days_remaining: (exp - now).num_days(),
+                expired: exp <= now,
+            })
+        })
+        .collect();
+    out.sort_by(|a, b| a.expires_at.cmp(&b.expires_at));
+    Ok(out)
+}
+
+/// Unix seconds of the last expiry scan. The rotation tick runs every minute;
+/// expiry only needs checking hourly.
+static LAST_EXPIRY_SCAN: AtomicI64 = AtomicI64::new(0);
+const EXPIRY_SCAN_INTERVAL_SECS: i64 = 3600;
+
+/// Notify once when a credential enters the `credential_expiry_warning_days`
+/// window and once more when it expires. The alerted stage is stored in the
+/// ledger's `expiry_alerted` so restarts don't repeat it; a renewed credential
+/// (new `expires_at`) re-arms both alerts.
+pub async fn warn_expiring_credentials(pool: &DbPool, app: &AppHandle) {
+    let now_secs = chrono::Utc::now().timestamp();
+    let last = LAST_EXPIRY_SCAN.load(Ordering::Relaxed);
+    if now_secs - last < EXPIRY_SCAN_INTERVAL_SECS
+        || LAST_EXPIRY_SCAN
+            .compare_exchange(last, now_secs, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+    {
+        return;
+    }
+
+    let days = crate::db::repos::core::settings::get(
+        pool,
+        crate::db::settings_keys::CREDENTIAL_EXPIRY_WARNING_DAYS,
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.parse::<u32>().ok())
+    .unwrap_or(crate::db::settings_keys::CREDENTIAL_EXPIRY_WARNING_DAYS_DEFAULT);
+
+    let expiring = match list_expiring(pool, days) {
+        Ok(list) => list,
+        Err(e) => {
+            tracing::warn!("Credential expiry scan failed: {}", e);
+            return;
+        }
+    };
+
+    for item in expiring {
+        let stage = if item.expired { "expired" } else { "warning" };
+        let marker = format!("{stage}:{}", item.expires_at);
+        let already_alerted = cred_repo::read_ledger(pool, &item.credential_id)
+            .ok()
+            .and_then(|ledger| ledger.expiry_alerted)
+            .is_some_and(|m| m == marker);
+        if already_alerted {
+            continue;
+        }
+
+        tracing::warn!(
+            credential_id = %item.credential_id,
+            expires_at = %item.expires_at,
+            expired = item.expired,
+            "Credential expiry alert"
+        );
+        crate::notifications::notify_credential_expiring(
+            app,
+            &item.name,
+            item.days_remaining,
+            item.expired,
+        );
+
+        let mut patch = serde_json::Map::new();
+        patch.insert("expiry_alerted".into(), serde_json::Value::String(marker));
+        if let Err(e) = cred_repo::patch_metadata_atomic(pool, &item.credential_id, patch) {
+            tracing::warn!(
+                "Failed to record expiry alert for credential {}: {}",
+                item.credential_id,
+                e
+            );
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
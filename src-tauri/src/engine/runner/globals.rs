@@ -28,6 +28,7 @@ pub(super) fn apply_global_setting(pool: &DbPool, field: &mut Option<String>, se
 pub(super) fn resolve_global_provider_settings(pool: &DbPool, profile: &mut ModelProfile) {
     match profile.provider.as_deref() {
         Some(providers::OLLAMA) => {
+            apply_global_setting(pool, &mut profile.base_url, settings_keys::OLLAMA_BASE_URL);
             apply_global_setting(pool, &mut profile.auth_token, settings_keys::OLLAMA_API_KEY);
         }
         Some(providers::LITELLM) => {
@@ -43,6 +44,10 @@ pub(super) fn resolve_global_provider_settings(pool: &DbPool, profile: &mut Mode
             // key is resolved at call time (keyring/env) in engine::http_engine.
             apply_global_setting(pool, &mut profile.base_url, settings_keys::QWEN_BASE_URL);
         }
+        Some(providers::OPENAI_API) => {
+            apply_global_setting(pool, &mut profile.base_url, settings_keys::OPENAI_API_BASE_URL);
+            apply_global_setting(pool, &mut profile.auth_token, settings_keys::OPENAI_API_KEY);
+        }
         _ => {}
     }
 }
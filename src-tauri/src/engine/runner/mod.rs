@@ -56,6 +56,13 @@ use self::stages::RunnerStage;
 /// personas' generic "timed out after 600s" fires. See
 /// `.planning/handoffs/2026-04-17-claude-cli-2-1-111-adapter-drift.md` T6.
 pub(crate) const DEFAULT_EXECUTION_TIMEOUT_MS: u64 = 660_000;
+
+/// Characters of tool output kept on each [`ToolCallStep`].
+const TOOL_OUTPUT_PREVIEW_CHARS: usize = 500;
+
+/// `http_request` steps keep the whole response the engine returned (its body
+/// is already capped by `http_tool`), so the run records what the agent saw.
+const HTTP_OUTPUT_PREVIEW_CHARS: usize = 2 * super::http_tool::MAX_RESPONSE_BODY_BYTES;
 use super::trace::{SpanType, TraceCollector, TraceSpanEvent};
 use super::types::*;
 
@@ -64,6 +71,11 @@ use super::types::*;
 /// Supports automatic provider failover: if the primary provider fails with a
 /// retryable error (binary not found, rate limited, session limit), the next
 /// available provider/model in the failover chain is tried automatically.
+///
+/// When the persona has an output contract in `repair` mode and the first
+/// turn's output violates it, the CLI session is resumed once with the
+/// validation errors; the repair turn's outcome is final (see
+/// `output_contract.rs`).
 #[allow(clippy::too_many_arguments)]
 pub async fn run_execution(
     emitter: Arc<dyn super::events::ExecutionEventEmitter>,
@@ -78,6 +90,99 @@ pub async fn run_execution(
     continuation: Option<Continuation>,
     chain_trace_id: Option<String>,
     circuit_breaker: Arc<super::failover::ProviderCircuitBreaker>,
+) -> ExecutionResult {
+    let contract = super::output_contract::load(&pool, &persona.id);
+    let Some(repairing) = contract.as_ref().filter(|c| c.repairs()).cloned() else {
+        return run_execution_turn(
+            emitter,
+            pool,
+            execution_id,
+            persona,
+            tools,
+            input_data,
+            log_dir,
+            child_pids,
+            cancelled,
+            continuation,
+            chain_trace_id,
+            circuit_breaker,
+            contract.as_ref(),
+            None,
+        )
+        .await;
+    };
+
+    let first = run_execution_turn(
+        emitter.clone(),
+        pool.clone(),
+        execution_id.clone(),
+        persona.clone(),
+        tools.clone(),
+        input_data,
+        log_dir.clone(),
+        child_pids.clone(),
+        cancelled.clone(),
+        continuation,
+        chain_trace_id.clone(),
+        circuit_breaker.clone(),
+        Some(&repairing),
+        None,
+    )
+    .await;
+
+    // The first turn holds its terminal status back only for a successful run
+    // whose output violates the contract and whose session can be resumed.
+    let Some(session_id) = first.claude_session_id.clone().filter(|_| first.success) else {
+        return first;
+    };
+    let violations = repairing.violations(first.output.as_deref().unwrap_or(""));
+    if violations.is_empty() {
+        return first;
+    }
+    tracing::info!(
+        execution_id = %execution_id,
+        persona_id = %persona.id,
+        violations = violations.len(),
+        "Output contract violated, resuming session for one repair turn"
+    );
+    run_execution_turn(
+        emitter,
+        pool,
+        execution_id,
+        persona,
+        tools,
+        Some(super::output_contract::repair_input(&violations)),
+        log_dir,
+        child_pids,
+        cancelled,
+        Some(Continuation::SessionResume(session_id)),
+        chain_trace_id,
+        circuit_breaker,
+        Some(&repairing),
+        Some(&first),
+    )
+    .await
+}
+
+/// One CLI turn of [`run_execution`]. `contract` is validated against a
+/// successful turn's output; `repair_of` is the turn being repaired, whose
+/// usage is folded into this turn's result.
+#[allow(clippy::too_many_arguments)]
+async fn run_execution_turn(
+    emitter: Arc<dyn super::events::ExecutionEventEmitter>,
+    pool: DbPool,
+    execution_id: String,
+    persona: Persona,
+    tools: Vec<PersonaToolDefinition>,
+    input_data: Option<serde_json::Value>,
+    log_dir: PathBuf,
+    child_pids: Arc<Mutex<HashMap<String, u32>>>,
+    cancelled: Arc<AtomicBool>,
+    continuation: Option<Continuation>,
+    chain_trace_id: Option<String>,
+    circuit_breaker: Arc<super::failover::ProviderCircuitBreaker>,
+    contract: Option<&super::output_contract::OutputContract>,
+    repair_of: Option<&ExecutionResult>,
 ) -> ExecutionResult {
     let start_time = std::time::Instant::now();
 
@@ -174,6 +279,96 @@ pub async fn run_execution(
     persona.max_budget_usd = effective.max_budget_usd.value;
     persona.max_turns = effective.max_turns.value;
 
+    // Absolute deadline inherited from the trigger firing / upstream chain hop /
+    // pipeline run (see `engine::deadline`). Past it, refuse to start; before
+    // it, cut the stream timeout so the run cannot outlive it.
+    let deadline = input_data.as_ref().and_then(super::deadline::from_input);
+    let mut deadline_trimmed = false;
+    if let Some(deadline) = deadline {
+        match super::deadline::remaining_ms(deadline, chrono::Utc::now()) {
+            None => {
+                let msg = format!(
+                    "{} before start (deadline {})",
+                    super::deadline::DEADLINE_EXCEEDED,
+                    deadline.to_rfc3339()
+                );
+                trace.end_span_error(&validate_stage, &msg);
+                logger.log(&format!("[DEADLINE] {msg}"));
+                logger.close();
+                let final_trace = trace.finalize(None, None, None, Some(msg.clone()));
+                let _ = crate::db::repos::execution::traces::save(&pool, &final_trace);
+                emit_to(
+                    &*emitter,
+                    event_name::EXECUTION_OUTPUT,
+                    &ExecutionOutputEvent {
+                        execution_id: execution_id.clone(),
+                        line: format!("[DEADLINE] {msg}"),
+                    },
+                );
+                return ExecutionResult {
+                    success: false,
+                    error: Some(msg),
+                    log_file_path: Some(log_file_path),
+                    duration_ms: start_time.elapsed().as_millis() as u64,
+                    trace_id: Some(final_trace.trace_id.clone()),
+                    ..default_result()
+                };
+            }
+            Some(remaining) => {
+                let configured_ms = if persona.timeout_ms > 0 {
+                    persona.timeout_ms
+                } else {
+                    DEFAULT_EXECUTION_TIMEOUT_MS as i32
+                };
+                if let Some(trimmed) = super::deadline::trim_timeout_ms(configured_ms, remaining) {
+                    logger.log(&format!(
+                        "[DEADLINE] Timeout trimmed from {}ms to {}ms to finish by {}",
+                        configured_ms,
+                        trimmed,
+                        deadline.to_rfc3339()
+                    ));
+                    persona.timeout_ms = trimmed;
+                    deadline_trimmed = true;
+                }
+            }
+        }
+    }
+
+    // Oversized input: refuse it, or condense it with a map-reduce
+    // summarization pre-pass when the persona opted into chunking (see
+    // `engine::input_budget`).
+    let input_data = match super::input_budget::prepare(&pool, &persona.id, input_data).await {
+        Ok(prepared) => {
+            if let Some(note) = prepared.note {
+                logger.log(&format!("[INPUT] {note}"));
+            }
+            prepared.input
+        }
+        Err(msg) => {
+            trace.end_span_error(&validate_stage, &msg);
+            logger.log(&format!("[INPUT] {msg}"));
+            logger.close();
+            let final_trace = trace.finalize(None, None, None, Some(msg.clone()));
+            let _ = crate::db::repos::execution::traces::save(&pool, &final_trace);
+            emit_to(
+                &*emitter,
+                event_name::EXECUTION_OUTPUT,
+                &ExecutionOutputEvent {
+                    execution_id: execution_id.clone(),
+                    line: format!("[INPUT] {msg}"),
+                },
+            );
+            return ExecutionResult {
+                success: false,
+                error: Some(msg),
+                log_file_path: Some(log_file_path),
+                duration_ms: start_time.elapsed().as_millis() as u64,
+                trace_id: Some(final_trace.trace_id.clone()),
+                ..default_result()
+            };
+        }
+    };
+
     // Parse model profile
     let mut model_profile = prompt::parse_model_profile(persona.model_profile.as_deref());
 
@@ -191,6 +386,24 @@ pub async fn run_execution(
         }
     }
 
+    // Deprecated model IDs: a retired one is swapped for its replacement so the
+    // run does not fail with an opaque "model not found"; either way the run
+    // log and output stream carry the warning.
+    if let Some(ref mut profile) = model_profile {
+        if let Some(msg) = crate::engine::model_registry::apply(&pool, profile) {
+            tracing::warn!(persona_id = %persona.id, "{}", msg);
+            logger.log(&format!("[MODEL] {msg}"));
+            emit_to(
+                &*emitter,
+                event_name::EXECUTION_OUTPUT,
+                &ExecutionOutputEvent {
+                    execution_id: execution_id.clone(),
+                    line: format!("[MODEL] {msg}"),
+                },
+            );
+        }
+    }
+
     // Resolve global provider settings (Ollama, LiteLLM) from app settings DB
     if let Some(ref mut profile) = model_profile {
         resolve_global_provider_settings(&pool, profile);
@@ -222,6 +435,18 @@ pub async fn run_execution(
         .and_then(|f| f.as_bool())
         .unwrap_or(false);
 
+    // Experimental engine paths for this run (persona setting + `_flags`
+    // override). Recorded on the execution row for per-flag comparison.
+    let engine_flags =
+        crate::engine::feature_flags::resolve(&pool, &persona.id, input_data.as_ref());
+    if !engine_flags.is_empty() {
+        let names = engine_flags.names();
+        logger.log(&format!("[FLAGS] Active: {}", names.join(", ")));
+        if let Err(e) = exec_repo::set_feature_flags(&pool, &execution_id, &names) {
+            logger.log(&format!("[FLAGS] Failed to record flags: {e}"));
+        }
+    }
+
     // Phase C5 — capability attribution. The execution's use_case_id is
     // expanded into `input_data._use_case` by `execute_persona` (see
     // commands/execution/executions.rs §1b). Recover the bare id here so
@@ -509,8 +734,10 @@ pub async fn run_execution(
 
     let cred_env_clone = cred_env.clone();
 
-    // Load engine kind once and reuse for both config snapshot and provider selection
-    let engine_kind = {
+    // Load engine kind once and reuse for both config snapshot and provider
+    // selection: the persona's own `engine` override wins, the global
+    // `cli_engine` setting is the default (and the failover fallback).
+    let global_engine = {
         let raw =
             crate::db::repos::core::settings::get(&pool, crate::db::settings_keys::CLI_ENGINE)
                 .ok()
@@ -532,6 +759,25 @@ pub async fn run_execution(
             None => provider::EngineKind::ClaudeCode,
         }
     };
+    let engine_kind = match provider::resolve_engine_kind(persona.engine.as_deref(), global_engine) {
+        Ok(kind) => kind,
+        Err(fallback) => {
+            tracing::warn!(
+                persona_id = %persona.id,
+                engine = persona.engine.as_deref().unwrap_or_default(),
+                "Unrecognized persona engine override, using the global default"
+            );
+            emit_to(
+                &*emitter,
+                event_name::ENGINE_FALLBACK,
+                &serde_json::json!({
+                    "requested": persona.engine,
+                    "actual": fallback.as_setting(),
+                }),
+            );
+            fallback
+        }
+    };
 
     // Assemble immutable ExecutionConfig snapshot from all resolved sources.
     // This is the single source of truth for what config this execution used.
@@ -856,20 +1102,6 @@ pub async fn run_execution(
                 120,
             ) {
                 Ok(mut tiered) if !tiered.core.is_empty() || !tiered.active.is_empty() => {
-                    let mut mem_section = String::new();
-
-                    // Core beliefs — always present, define agent identity
-                    if !tiered.core.is_empty() {
-                        mem_section.push_str("\n\n## Agent Memory — Core Beliefs\n\n");
-                        mem_section.push_str("These are your established principles and preferences learned over many interactions. Treat them as strong defaults.\n\n");
-                        for m in &tiered.core {
-                            mem_section.push_str(&format!(
-                                "- **{}** [{}]: {}\n",
-                                m.title, m.category, m.content
-                            ));
-                        }
-                    }
-
                     // Active knowledge — recent learnings, contextual facts.
                     // Memory Engine v2: decay-scored budget pack. The same char
                     // budget as the old truncation, but WHICH memories survive
@@ -885,8 +1117,9 @@ pub async fn run_execution(
                     // bands. Core pinning, the budget, and packing semantics
                     // are unchanged; with no registered embedder, no task
                     // text, or any embedding failure this is exactly the
-                    // value-only pack below.
-                    const ACTIVE_MEM_BUDGET_CHARS: usize = 6000;
+                    // value-only pack below. The operator's similarity
+                    // threshold and optional top-k (`RecallTuning`) narrow
+                    // the set before packing.
                     #[cfg(feature = "ml")]
                     let packed = {
                         let task_context = crate::engine::memory_recall::task_context_from_input(
@@ -896,17 +1129,20 @@ pub async fn run_execution(
                             Some((vec_pool, embedder)) if !task_context.is_empty() => {
                                 crate::engine::memory_recall::pack_by_budget_task_aware(
                                     std::mem::take(&mut tiered.active),
-                                    ACTIVE_MEM_BUDGET_CHARS,
+                                    crate::engine::memory_recall::ACTIVE_MEMORY_BUDGET_CHARS,
                                     chrono::Utc::now(),
                                     &task_context,
                                     &vec_pool,
                                     &embedder,
+                                    crate::engine::memory_recall::RecallTuning::from_settings(
+                                        &pool,
+                                    ),
                                 )
                                 .await
                             }
                             _ => crate::engine::memory_recall::pack_by_budget(
                                 std::mem::take(&mut tiered.active),
-                                ACTIVE_MEM_BUDGET_CHARS,
+                                crate::engine::memory_recall::ACTIVE_MEMORY_BUDGET_CHARS,
                                 chrono::Utc::now(),
                             ),
                         }
@@ -914,27 +1150,11 @@ pub async fn run_execution(
                     #[cfg(not(feature = "ml"))]
                     let packed = crate::engine::memory_recall::pack_by_budget(
                         std::mem::take(&mut tiered.active),
-                        ACTIVE_MEM_BUDGET_CHARS,
+                        crate::engine::memory_recall::ACTIVE_MEMORY_BUDGET_CHARS,
                         chrono::Utc::now(),
                     );
-                    if !packed.selected.is_empty() {
-                        mem_section.push_str("\n\n## Agent Memory — Recent Learnings\n\n");
-                        mem_section.push_str("Context from recent work. Use to inform your analysis and avoid repeating past mistakes.\n\n");
-                        for m in &packed.selected {
-                            mem_section.push_str(&format!(
-                                "- **{}** [{}] (importance: {}): {}\n",
-                                m.title, m.category, m.importance, m.content
-                            ));
-                        }
-                        if packed.omitted > 0 {
-                            mem_section.push_str(&format!(
-                                "- …(+{} more lower-value memories omitted to bound prompt size)\n",
-                                packed.omitted
-                            ));
-                        }
-                    }
-
-                    mem_section.push('\n');
+                    let mem_section =
+                        crate::engine::memory_recall::render_prompt_section(&tiered.core, &packed);
                     logger.log(&format!(
                         "[MEMORY] Injected {} memories ({} core, {} active packed by value, {} omitted)",
                         tiered.core.len() + packed.selected.len(),
@@ -1097,6 +1317,76 @@ pub async fn run_execution(
         prompt_text
     };
 
+    // Shared context blackboard: structured artifacts earlier personas in the
+    // same pipeline run or chain trace published (see `shared_context.rs`).
+    let shared_context_block = if is_session_resume {
+        None
+    } else {
+        super::shared_context::prompt_block(&pool, &persona.id, input_data.as_ref())
+    };
+    let prompt_text = match shared_context_block {
+        Some(block) => {
+            logger.log("[SHARED-CTX] Injected shared context blackboard");
+            format!("{prompt_text}{block}")
+        }
+        None => prompt_text,
+    };
+
+    // Output contract: the JSON Schema the final output is validated against
+    // at finalize (see `output_contract.rs`).
+    let prompt_text = match contract {
+        Some(c) if !is_session_resume => {
+            logger.log("[CONTRACT] Injected output contract");
+            format!("{prompt_text}{}", c.prompt_block())
+        }
+        _ => prompt_text,
+    };
+
+    // Tool sandbox policies (`persona_tools.tool_config` → `policy`): state the
+    // limits up front; the CLI args and stream loop below enforce them.
+    let tool_policies = super::tool_policy::ToolPolicySet::load(&pool, &persona.id, &exec_dir);
+    let prompt_text = match tool_policies.prompt_block() {
+        Some(block) if !is_session_resume => {
+            logger.log("[POLICY] Injected tool sandbox policies");
+            format!("{prompt_text}{block}")
+        }
+        _ => prompt_text,
+    };
+
+    // Approval gates (`tool_approval_tools` setting / `requires_approval`
+    // flag): the persona asks through `request_approval` and the run pauses.
+    let approval_gate =
+        super::tool_approval::ApprovalGate::load(&pool, &persona.id, input_data.as_ref());
+    let prompt_text = match approval_gate.prompt_block() {
+        Some(block) if !is_session_resume => {
+            logger.log("[APPROVAL] Injected tool approval gates");
+            format!("{prompt_text}{block}")
+        }
+        _ => prompt_text,
+    };
+
+    // Context budget: an over-window prompt loses its optional sections
+    // (memories first) here instead of failing mid-run in the CLI.
+    let prompt_text = {
+        let model = model_profile
+            .as_ref()
+            .and_then(|p| p.model.as_deref())
+            .unwrap_or(prompt::DEFAULT_CAPABILITY_MODEL);
+        let fitted = prompt::fit_to_budget(prompt_text, model);
+        if !fitted.dropped.is_empty() {
+            logger.log(&format!(
+                "[BUDGET] Prompt over {model}'s context window; dropped {}",
+                fitted.dropped.join(", ")
+            ));
+        }
+        if fitted.budget.status != "ok" {
+            for warning in &fitted.budget.warnings {
+                logger.log(&format!("[BUDGET] {warning}"));
+            }
+        }
+        fitted.prompt
+    };
+
     trace.end_span(&prompt_span, None, None, None, None);
 
     logger.log("=== Persona Execution Started ===");
@@ -1265,6 +1555,8 @@ pub async fn run_execution(
     };
     let mcp_installed = match super::cli_mcp_config::install_mcp_sidecar(
         &exec_dir,
+        &persona.id,
+        &execution_id,
         drive_root_for_sync.as_deref(),
         None,
         bridge_api_key.as_deref(),
@@ -1419,8 +1711,21 @@ pub async fn run_execution(
     let execution_config = execution_config;
     let execution_config_json = serde_json::to_string(&execution_config).ok();
 
+    // Ordered provider chain (persona setting → global setting). Its CLI
+    // engines join the spawn loop ahead of the global default; its HTTP
+    // backends are tried once every CLI candidate has failed.
+    let configured_chain = failover::load_failover_chain(&pool, &persona.id);
+    let cli_fallbacks: Vec<provider::EngineKind> = configured_chain
+        .iter()
+        .filter_map(|t| match t {
+            failover::FailoverTarget::Cli(kind) => Some(*kind),
+            failover::FailoverTarget::Http(_) => None,
+        })
+        .chain(std::iter::once(global_engine))
+        .collect();
     let failover_chain = failover::build_failover_chain_with_policy(
         primary_engine,
+        &cli_fallbacks,
         model_profile.as_ref(),
         &policy_decision,
     );
@@ -1439,6 +1744,38 @@ pub async fn run_execution(
         );
     }
 
+    // Capability check: features the persona relies on but its backend lacks
+    // are logged; a fail-closed container sandbox on an HTTP backend blocks.
+    let backend = provider::capabilities::Backend::resolve(
+        primary_engine,
+        model_profile.as_ref().and_then(|m| m.provider.as_deref()),
+    );
+    let compat = provider::capabilities::check(&pool, &persona, !tools.is_empty(), backend);
+    for finding in &compat.findings {
+        let (feature, message) = (&finding.feature, &finding.message);
+        logger.log(&format!("[COMPAT] {feature}: {message}"));
+    }
+    if let Some(blocking) = compat.blocking() {
+        let err_msg = blocking.message.clone();
+        let _ = exec_repo::update_status(
+            &pool,
+            &execution_id,
+            crate::db::models::UpdateExecutionStatus {
+                status: ExecutionState::Failed,
+                error_message: Some(err_msg.clone()),
+                duration_ms: Some(start_time.elapsed().as_millis() as i64),
+                ..Default::default()
+            },
+        );
+        return ExecutionResult {
+            success: false,
+            error: Some(err_msg),
+            log_file_path: Some(log_file_path),
+            duration_ms: start_time.elapsed().as_millis() as u64,
+            ..default_result()
+        };
+    }
+
     // ── Split engine (Phase 1): route per-capability to a remote HTTP provider
     // (Qwen/DashScope) instead of spawning the local Claude CLI. Text-only —
     // tool-using capabilities are rejected inside run_http_execution (they must
@@ -1470,6 +1807,40 @@ pub async fn run_execution(
             )
             .await;
         }
+        // Local Ollama: offline, no CLI agent needed.
+        if p == providers::OLLAMA {
+            return provider::ollama::run_ollama_execution(
+                &*emitter,
+                &execution_id,
+                &persona.name,
+                model_profile.as_ref().unwrap(),
+                &prompt_text,
+                !tools.is_empty(),
+                &cancelled,
+                start_time,
+            )
+            .await;
+        }
+        // Generic OpenAI-compatible backend (OpenAI, OpenRouter, local
+        // llama.cpp/Ollama): same event contract, streamed tool loop.
+        if p == providers::OPENAI_API {
+            let mut result = provider::openai_api::run_openai_api_execution(
+                &*emitter,
+                &execution_id,
+                &persona.name,
+                model_profile.as_ref().unwrap(),
+                &prompt_text,
+                !tools.is_empty(),
+                &cancelled,
+                start_time,
+            )
+            .await;
+            result.cost_usd *= crate::engine::cost_reconciliation::correction_factor(
+                &pool,
+                result.model_used.as_deref(),
+            );
+            return result;
+        }
     }
 
     // Resolve provider + build CLI args + spawn, trying each failover candidate
@@ -1480,9 +1851,48 @@ pub async fn run_execution(
     let mut cli_provider: Box<dyn provider::CliProvider> =
         provider::resolve_provider(primary_engine); // overwritten per-candidate
     let mut cli_args;
+    // Warm pool (see `warm_pool`): whether this run's spawn could be served
+    // by a pre-spawned session, whether it was, and when the spawn began.
+    let warm_pool_size = crate::engine::warm_pool::pool_size(&pool);
+    let mut warm_eligible;
+    let mut warm_attached = false;
+    let mut spawn_started;
+
+    // Container sandbox (see `container_sandbox.rs`), resolved once per run. A
+    // persona that must run in a container but can't fails through the
+    // spawn-failure path below without trying any provider.
+    let sandbox = match super::container_sandbox::resolve(&pool, &persona.id).await {
+        Ok(super::container_sandbox::SandboxPlan::Container(config, runtime)) => {
+            logger.log(&format!(
+                "[SANDBOX] Running CLI in {} {} container ({}, network {})",
+                runtime.kind.label(),
+                runtime.version,
+                config.image,
+                config.network
+            ));
+            Some((config, runtime))
+        }
+        Ok(super::container_sandbox::SandboxPlan::HostFallback(reason)) => {
+            logger.log(&format!(
+                "[SANDBOX] No container runtime usable, running on the host: {reason}"
+            ));
+            None
+        }
+        Ok(super::container_sandbox::SandboxPlan::Host) => None,
+        Err(e) => {
+            last_spawn_error = Some(e);
+            None
+        }
+    };
+    let sandbox_blocked = last_spawn_error.is_some();
+    // Keeps the container registered for cancellation while the run lasts.
+    let mut _container_guard = None;
 
     let mut driver = 'failover: {
         for (candidate_idx, candidate) in failover_chain.iter().enumerate() {
+            if sandbox_blocked {
+                break;
+            }
             // Atomically check circuit breaker and reserve a slot.
             // try_acquire prevents the TOCTOU race where another thread could
             // open the circuit between check and use.
@@ -1609,6 +2019,25 @@ pub async fn run_execution(
                 cli_args.args.push("--strict-mcp-config".to_string());
             }
 
+            // A read-only file_write policy removes the CLI's write tools
+            // outright rather than relying on the stream-time check alone;
+            // approval-gated tools are removed so only `request_approval`
+            // can reach them.
+            let mut denied_tools: Vec<String> = tool_policies
+                .disallowed_cli_tools()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            for tool in approval_gate.disallowed_cli_tools() {
+                if !denied_tools.contains(&tool) {
+                    denied_tools.push(tool);
+                }
+            }
+            if !denied_tools.is_empty() {
+                cli_args.args.push("--disallowedTools".to_string());
+                cli_args.args.push(denied_tools.join(","));
+            }
+
             if candidate_idx > 0 {
                 logger.log(&format!(
                     "[FAILOVER] Trying {} after previous provider failed",
@@ -1662,6 +2091,45 @@ pub async fn run_execution(
                 };
             }
 
+            // Lets the orphan reaper attribute a leaked CLI to this run.
+            cli_args.env_overrides.push((
+                crate::engine::process_reaper::EXECUTION_ID_ENV.to_string(),
+                execution_id.clone(),
+            ));
+
+            // Attach to an idle pre-spawned session when one matches these
+            // exact args; otherwise spawn cold.
+            warm_eligible = matches!(cli_provider.prompt_delivery(), PromptDelivery::Stdin)
+                && !matches!(continuation, Some(Continuation::SessionResume(_)))
+                && exec_worktree.is_none()
+                && sandbox.is_none();
+            spawn_started = std::time::Instant::now();
+            if warm_pool_size > 0 && warm_eligible {
+                if let Some(fingerprint) =
+                    crate::engine::warm_pool::fingerprint(&cli_args, &exec_dir)
+                {
+                    if let Some(driver) =
+                        crate::engine::warm_pool::take(&persona.id, fingerprint, &child_pids).await
+                    {
+                        logger.log("[WARM] Attached to a pre-spawned CLI session");
+                        warm_attached = true;
+                        break 'failover driver;
+                    }
+                }
+            }
+
+            if let Some((config, runtime)) = &sandbox {
+                let (wrapped, guard) = super::container_sandbox::wrap(
+                    &cli_args,
+                    &exec_dir,
+                    &execution_id,
+                    config,
+                    runtime,
+                );
+                cli_args = wrapped;
+                _container_guard = Some(guard);
+            }
+
             // Spawn CLI process via CliProcessDriver
             match CliProcessDriver::spawn(&cli_args, exec_dir.clone()) {
                 Ok(driver) => {
@@ -1693,6 +2161,11 @@ pub async fn run_execution(
                             event_name::CIRCUIT_BREAKER_GLOBAL_TRIPPED,
                             &circuit_breaker.get_status(),
                         );
+                        crate::cloud::push::relay(
+                            crate::cloud::push::PushCategory::CircuitBreaker,
+                            "Circuit breaker tripped",
+                            "Too many provider failures in a short window. Failover is paused until the breaker cools down.",
+                        );
                     }
                     logger.log(&format!(
                         "[FAILOVER] {} failed: {}",
@@ -1704,6 +2177,101 @@ pub async fn run_execution(
             }
         }
 
+        // All CLI candidates exhausted: try the configured HTTP fallbacks in
+        // order. Session resumes are CLI-only and skip this step.
+        if !matches!(continuation, Some(Continuation::SessionResume(_))) {
+            for target in &configured_chain {
+                let failover::FailoverTarget::Http(http_provider) = *target else {
+                    continue;
+                };
+                if CliProcessDriver::is_cancelled(&cancelled) {
+                    break;
+                }
+                let mut http_profile = ModelProfile {
+                    provider: Some(http_provider.to_string()),
+                    ..Default::default()
+                };
+                resolve_global_provider_settings(&pool, &mut http_profile);
+                logger.log(&format!(
+                    "[FAILOVER] Trying {http_provider} after CLI providers failed"
+                ));
+                emit_to(
+                    &*emitter,
+                    event_name::EXECUTION_OUTPUT,
+                    &ExecutionOutputEvent {
+                        execution_id: execution_id.clone(),
+                        line: format!("[FAILOVER] Trying {http_provider}..."),
+                    },
+                );
+                let mut result = if http_provider == providers::OLLAMA {
+                    provider::ollama::run_ollama_execution(
+                        &*emitter,
+                        &execution_id,
+                        &persona.name,
+                        &http_profile,
+                        &prompt_text,
+                        !tools.is_empty(),
+                        &cancelled,
+                        start_time,
+                    )
+                    .await
+                } else {
+                    provider::openai_api::run_openai_api_execution(
+                        &*emitter,
+                        &execution_id,
+                        &persona.name,
+                        &http_profile,
+                        &prompt_text,
+                        !tools.is_empty(),
+                        &cancelled,
+                        start_time,
+                    )
+                    .await
+                };
+                result.cost_usd *= crate::engine::cost_reconciliation::correction_factor(
+                    &pool,
+                    result.model_used.as_deref(),
+                );
+                let was_cancelled = CliProcessDriver::is_cancelled(&cancelled);
+                if !result.success && !was_cancelled {
+                    let err = result.error.clone().unwrap_or_default();
+                    logger.log(&format!("[FAILOVER] {http_provider} failed: {err}"));
+                    last_spawn_error = Some(format!("{http_provider}: {err}"));
+                    continue;
+                }
+
+                // Record which provider served the execution.
+                let status = if was_cancelled {
+                    ExecutionState::Cancelled
+                } else {
+                    ExecutionState::Completed
+                };
+                let audit_entry = super::byom::ProviderAuditEntry {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    execution_id: execution_id.clone(),
+                    persona_id: persona.id.clone(),
+                    persona_name: persona.name.clone(),
+                    engine_kind: http_provider.to_string(),
+                    model_used: result.model_used.clone(),
+                    was_failover: true,
+                    routing_rule_name: policy_decision.routing_rule_name.clone(),
+                    compliance_rule_name: policy_decision.compliance_rule_name.clone(),
+                    cost_usd: Some(result.cost_usd),
+                    duration_ms: Some(result.duration_ms as i64),
+                    status: status.as_str().to_string(),
+                    created_at: chrono::Utc::now().to_rfc3339(),
+                };
+                if let Err(e) =
+                    crate::db::repos::execution::provider_audit::insert(&pool, &audit_entry)
+                {
+                    tracing::warn!(execution_id = %execution_id, "Failed to record provider audit log: {}", e);
+                }
+                trace.end_span_ok(&spawn_engine_stage);
+                logger.close();
+                return result;
+            }
+        }
+
         // All candidates exhausted
         let error_msg = last_spawn_error
             .unwrap_or_else(|| "All providers failed or have open circuit breakers".to_string());
@@ -1781,6 +2349,19 @@ pub async fn run_execution(
     // cancel_execution cannot kill the process (no PID in the map yet).
     driver.register_pid(&child_pids, &execution_id).await;
 
+    // Keep a session warm for this persona's next run. No-op while the pool
+    // is off or the persona isn't run often enough.
+    if warm_eligible {
+        crate::engine::warm_pool::note_run(&persona.id);
+        crate::engine::warm_pool::replenish(
+            warm_pool_size,
+            persona.id.clone(),
+            cli_args.clone(),
+            exec_dir.clone(),
+            child_pids.clone(),
+        );
+    }
+
     // Cost observability: stamp the model/effort this run was ACTUALLY
     // spawned with (extracted from the final argv, so failover/resume paths
     // are covered). Column-scoped + status-guarded; the stream's system-init
@@ -2063,8 +2644,14 @@ pub async fn run_execution(
     // Start stream processing span
     let stream_span = trace.start_span(SpanType::StreamProcessing, "Stream Processing", None, None);
 
+    // First tool call that broke a sandbox policy; stops the stream loop.
+    let mut policy_violation: Option<super::tool_policy::PolicyViolation> = None;
+    // First approval-gated call; pauses the run for a manual review.
+    let mut approval_pending: Option<super::tool_approval::PendingApproval> = None;
+
     // Process stdout lines with timeout
     let mut last_activity = std::time::Instant::now();
+    let mut first_output_at: Option<std::time::Instant> = None;
     let stream_result = tokio::time::timeout(timeout_duration, async {
         const HEARTBEAT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
         let mut heartbeat_interval = tokio::time::interval(HEARTBEAT_INTERVAL);
@@ -2121,6 +2708,7 @@ pub async fn run_execution(
                     match line_result {
                         Ok(Some(raw_line)) => {
                             last_activity = std::time::Instant::now();
+                            first_output_at.get_or_insert(last_activity);
 
                             // Heartbeat off the biased read path. The tick branch
                             // below is starved while output streams continuously, so
@@ -2438,6 +3026,36 @@ pub async fn run_execution(
                                         },
                                     );
                                 }
+
+                                if let Some(violation) = tool_policies.check_line(&line, tool_name, input_preview) {
+                                    let message = violation.error_message();
+                                    logger.log(&format!("[POLICY] {message}"));
+                                    emit_to(
+                                        &*emitter,
+                                        event_name::EXECUTION_OUTPUT,
+                                        &ExecutionOutputEvent {
+                                            execution_id: exec_id_for_stream.clone(),
+                                            line: format!("[POLICY] {message}"),
+                                        },
+                                    );
+                                    policy_violation = Some(violation);
+                                    break;
+                                }
+
+                                if let Some(pending) = approval_gate.check_line(&line, tool_name, input_preview) {
+                                    let message = pending.error_message();
+                                    logger.log(&format!("[APPROVAL] {message}"));
+                                    emit_to(
+                                        &*emitter,
+                                        event_name::EXECUTION_OUTPUT,
+                                        &ExecutionOutputEvent {
+                                            execution_id: exec_id_for_stream.clone(),
+                                            line: format!("[APPROVAL] {message}"),
+                                        },
+                                    );
+                                    approval_pending = Some(pending);
+                                    break;
+                                }
                             }
 
                             // Fill last tool step with result output
@@ -2454,8 +3072,13 @@ pub async fn run_execution(
                                         // in real tool output). That panic failed a
                                         // persona execution and stalled an autonomous
                                         // team cascade (eval framework run-2 finding).
-                                        last.output_preview = if content_preview.len() > 500 {
-                                            format!("{}...", content_preview.chars().take(500).collect::<String>())
+                                        let limit = if last.tool_name.ends_with("__http_request") {
+                                            HTTP_OUTPUT_PREVIEW_CHARS
+                                        } else {
+                                            TOOL_OUTPUT_PREVIEW_CHARS
+                                        };
+                                        last.output_preview = if content_preview.len() > limit {
+                                            format!("{}...", content_preview.chars().take(limit).collect::<String>())
                                         } else {
                                             content_preview.clone()
                                         };
@@ -2579,6 +3202,17 @@ pub async fn run_execution(
         trace.end_span_ok(&stream_output_stage);
     }
 
+    // Start-to-first-output feeds the warm pool's cold baseline (cold runs)
+    // or its saved-latency counter (warm runs).
+    if let (true, Some(first_output_at)) = (warm_eligible, first_output_at) {
+        let startup_ms = first_output_at.duration_since(spawn_started).as_millis() as u64;
+        logger.log(&format!(
+            "[WARM] First output after {startup_ms}ms ({} start)",
+            if warm_attached { "warm" } else { "cold" }
+        ));
+        crate::engine::warm_pool::record_startup(&persona.id, warm_attached, startup_ms);
+    }
+
     // -- Pipeline Stage: FinalizeStatus -----------------------------------
     // Covers stderr collection, exit code handling, outcome assessment,
     // circuit breaker recording, audit logging, trace finalization, and status emit.
@@ -2621,6 +3255,42 @@ pub async fn run_execution(
         );
     }
 
+    // Policy violation: the stream loop stopped reading, so stop the CLI too
+    // and record the violation for the persona's health view.
+    if let Some(ref violation) = policy_violation {
+        logger.log("[POLICY] Stopping execution after tool policy violation");
+        driver.kill().await;
+        if let Some(payload) = super::tool_policy::record_violation(
+            &pool,
+            &persona.id,
+            &persona.name,
+            &execution_id,
+            violation,
+        ) {
+            emit_to(&*emitter, event_name::HEALING_EVENT, &payload);
+        }
+    }
+
+    // Approval gate: stop the CLI before it can go further, and file the
+    // pending call as a manual review; approving it continues the run.
+    if let Some(ref pending) = approval_pending {
+        logger.log("[APPROVAL] Pausing execution until the tool call is approved");
+        driver.kill().await;
+        match super::tool_approval::request_review(
+            &pool,
+            &persona.id,
+            &persona.name,
+            &execution_id,
+            pending,
+        ) {
+            Ok(request) => emit_to(&*emitter, event_name::TOOL_APPROVAL_REQUIRED, &request),
+            Err(e) => {
+                tracing::error!(execution_id = %execution_id, error = %e, "Failed to create tool approval review");
+                logger.log(&format!("[APPROVAL] Failed to create review: {e}"));
+            }
+        }
+    }
+
     // Wait for process to exit (after timeout kill, if applicable)
     let exit_status = driver.wait().await;
     let duration_ms = start_time.elapsed().as_millis() as u64;
@@ -2747,16 +3417,29 @@ pub async fn run_execution(
     // Only per-execution fallback dirs are cleaned up.
 
     // Build result
-    let success = !timed_out && exit_code == 0;
+    let stopped = policy_violation.is_some() || approval_pending.is_some();
+    let mut success = !timed_out && !stopped && exit_code == 0;
     // Usage-limit details can land on stderr (CLI errors) or in the streamed
     // assistant/result text (stream-json runs) — check both on failure.
-    let usage_limit = if !timed_out && exit_code != 0 {
+    let usage_limit = if !timed_out && !stopped && exit_code != 0 {
         parser::parse_usage_limit(&stderr_text)
             .or_else(|| parser::parse_usage_limit(&assistant_text))
     } else {
         None
     };
-    let error = if timed_out {
+    let mut error = if let Some(ref violation) = policy_violation {
+        Some(violation.error_message())
+    } else if let Some(ref pending) = approval_pending {
+        Some(pending.error_message())
+    } else if timed_out && deadline_trimmed {
+        // Cut short by the inherited deadline, not a slow persona — reported
+        // as a deadline truncation so healing does not raise the timeout.
+        Some(format!(
+            "{} after {}s (timeout trimmed to the deadline)",
+            super::deadline::DEADLINE_EXCEEDED,
+            timeout_ms / 1000
+        ))
+    } else if timed_out {
         Some(format!("Execution timed out after {}s", timeout_ms / 1000))
     } else if exit_code != 0 {
         if let Some(ul) = &usage_limit {
@@ -2785,6 +3468,30 @@ pub async fn run_execution(
         None
     };
 
+    // Output contract. A violating first turn that can be repaired holds its
+    // terminal status for the repair turn `run_execution` resumes; any other
+    // violation fails the run with a `contract_violation` error.
+    let mut contract_repair_pending = false;
+    if let Some(contract) = contract.filter(|_| success) {
+        let violations = contract.violations(&assistant_text);
+        if !violations.is_empty() {
+            logger.log(&format!(
+                "[CONTRACT] Output violates contract: {}",
+                violations.join("; ")
+            ));
+            if repair_of.is_none()
+                && contract.repairs()
+                && metrics.session_id.is_some()
+                && !cancelled.load(std::sync::atomic::Ordering::Acquire)
+            {
+                contract_repair_pending = true;
+            } else {
+                success = false;
+                error = Some(super::output_contract::violation_message(&violations));
+            }
+        }
+    }
+
     // Check outcome assessment: CLI exited 0 but task may not have been accomplished
     let mut final_status = if success {
         ExecutionState::Completed
@@ -2832,6 +3539,13 @@ pub async fn run_execution(
         }
     }
 
+    // Provider cost correction from the last usage reconciliation.
+    let cost_factor =
+        crate::engine::cost_reconciliation::correction_factor(&pool, metrics.model_used.as_deref());
+    if cost_factor != 1.0 {
+        metrics.cost_usd *= cost_factor;
+    }
+
     let session_limit_reached = usage_limit.is_some()
         || error
             .as_ref()
@@ -2855,6 +3569,11 @@ pub async fn run_execution(
                     event_name::CIRCUIT_BREAKER_GLOBAL_TRIPPED,
                     &circuit_breaker.get_status(),
                 );
+                crate::cloud::push::relay(
+                    crate::cloud::push::PushCategory::CircuitBreaker,
+                    "Circuit breaker tripped",
+                    "Too many provider failures in a short window. Failover is paused until the breaker cools down.",
+                );
             }
         }
     } else {
@@ -2929,18 +3648,20 @@ pub async fn run_execution(
         );
     }
 
-    // Emit final status
-    emit_to(
-        &*emitter,
-        event_name::EXECUTION_STATUS,
-        &ExecutionStatusEvent {
-            execution_id: execution_id.clone(),
-            status: final_status,
-            error: error.clone(),
-            duration_ms: Some(duration_ms),
-            cost_usd: Some(metrics.cost_usd),
-        },
-    );
+    // Emit final status (held back while a contract repair turn follows)
+    if !contract_repair_pending {
+        emit_to(
+            &*emitter,
+            event_name::EXECUTION_STATUS,
+            &ExecutionStatusEvent {
+                execution_id: execution_id.clone(),
+                status: final_status,
+                error: error.clone(),
+                duration_ms: Some(duration_ms),
+                cost_usd: Some(metrics.cost_usd),
+            },
+        );
+    }
 
     // Scrub the sidecar config BEFORE the worktree finalize below: finalize runs
     // `git add -A` + commit onto the review branch, which would otherwise capture
@@ -2974,6 +3695,7 @@ pub async fn run_execution(
     let protocol_messages_sent =
         stream_messages_dispatched.load(std::sync::atomic::Ordering::Relaxed);
     if success
+        && !contract_repair_pending
         && !assistant_text.is_empty()
         && protocol_messages_sent == 0
         && !cancelled.load(std::sync::atomic::Ordering::Acquire)
@@ -3038,7 +3760,19 @@ pub async fn run_execution(
     // confirmation. Best-effort single shot — mirrors the existing in-runner
     // error/cancel writes; the caller's retrying persist is the backstop
     // whenever this handler DOES run.
-    {
+    //
+    // A contract repair turn reports the usage of both turns, and a turn
+    // awaiting repair writes nothing so the repair turn's write lands.
+    let duration_ms = match repair_of {
+        Some(prior) => {
+            metrics.input_tokens += prior.input_tokens;
+            metrics.output_tokens += prior.output_tokens;
+            metrics.cost_usd += prior.cost_usd;
+            duration_ms + prior.duration_ms
+        }
+        None => duration_ms,
+    };
+    if !contract_repair_pending {
         let runner_terminal_status = if success {
             ExecutionState::Completed
         } else {
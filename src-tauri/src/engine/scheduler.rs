@@ -1,7 +1,7 @@
 use chrono::{DateTime, Duration, Utc};
 use chrono_tz::Tz;
 
-use crate::db::models::{PersonaTrigger, TriggerConfig};
+use crate::db::models::{PersonaTrigger, ScheduleExclusion, TriggerConfig};
 
 use super::cron;
 
@@ -48,6 +48,43 @@ pub(crate) fn resolve_schedule_tz(raw: Option<&str>) -> Result<Option<Tz>, Sched
     }
 }
 
+/// Cap on consecutive excluded fire times skipped while looking for the next
+/// allowed one, so an exclusion set that covers every slot cannot spin
+/// forever. Enough for a one-minute cron through a two-month blackout.
+const MAX_EXCLUDED_SKIPS: usize = 100_000;
+
+/// Next cron fire strictly after `from`, evaluated in `tz` (system local when
+/// `None`), skipping fire times that fall inside any of the schedule's
+/// exclusion windows. `None` when the cron never fires again or every slot
+/// within [`MAX_EXCLUDED_SKIPS`] is excluded.
+pub(crate) fn next_allowed_fire(
+    schedule: &cron::CronSchedule,
+    from: DateTime<Utc>,
+    tz: Option<Tz>,
+    exclusions: &[ScheduleExclusion],
+) -> Option<DateTime<Utc>> {
+    let mut from = from;
+    for _ in 0..=MAX_EXCLUDED_SKIPS {
+        let next = match tz {
+            Some(zone) => cron::next_fire_time_in_tz(schedule, from, zone),
+            None => cron::next_fire_time_local(schedule, from),
+        }?;
+        let local = match tz {
+            Some(zone) => next.with_timezone(&zone).naive_local(),
+            None => next.with_timezone(&chrono::Local).naive_local(),
+        };
+        if !exclusions.iter().any(|e| e.covers(local)) {
+            return Some(next);
+        }
+        from = next;
+    }
+    None
+}
+
+/// Mailbox poll cadence for `email_inbound` triggers without an
+/// `interval_seconds`.
+const DEFAULT_EMAIL_POLL_SECS: u64 = 300;
+
 /// Compute the next trigger time from an already-parsed `TriggerConfig`.
 /// Called by `compute_next_trigger_at` and also directly from `background.rs`
 /// when `parse_config()` has already been called for other purposes.
@@ -103,6 +140,7 @@ pub(crate) fn compute_next_from_config_anchored(
         TriggerConfig::Schedule {
             cron: Some(cron_expr),
             timezone,
+            exclusions,
             ..
         } => {
             let schedule = cron::parse_cron_seeded(cron_expr, seed).ok()?;
@@ -118,24 +156,19 @@ pub(crate) fn compute_next_from_config_anchored(
                     return None;
                 }
             };
-            let next = match resolved_tz {
-                Some(tz) => cron::next_fire_time_in_tz(&schedule, now, tz)?,
-                None => {
-                    if timezone.is_none() {
-                        // No timezone authored — falls back to system Local. This
-                        // is the common case for triggers created before the TS
-                        // ScheduleConfig type carried a `timezone` field, so we
-                        // emit at debug to avoid flooding logs. Enable via
-                        // RUST_LOG=personas_desktop=debug to audit which triggers
-                        // are still on the implicit-local path.
-                        tracing::debug!(
-                            cron = %cron_expr,
-                            "schedule has no timezone set; falling back to system-local"
-                        );
-                    }
-                    cron::next_fire_time_local(&schedule, now)?
-                }
-            };
+            if timezone.is_none() {
+                // No timezone authored — falls back to system Local. This
+                // is the common case for triggers created before the TS
+                // ScheduleConfig type carried a `timezone` field, so we
+                // emit at debug to avoid flooding logs. Enable via
+                // RUST_LOG=personas_desktop=debug to audit which triggers
+                // are still on the implicit-local path.
+                tracing::debug!(
+                    cron = %cron_expr,
+                    "schedule has no timezone set; falling back to system-local"
+                );
+            }
+            let next = next_allowed_fire(&schedule, now, resolved_tz, exclusions)?;
             Some(next.to_rfc3339())
         }
         TriggerConfig::Schedule {
@@ -146,6 +179,13 @@ pub(crate) fn compute_next_from_config_anchored(
             interval_seconds: Some(secs),
             ..
         } => Some(next_interval_at(now, anchor, *secs)),
+        TriggerConfig::EmailInbound {
+            interval_seconds, ..
+        } => Some(next_interval_at(
+            now,
+            anchor,
+            interval_seconds.unwrap_or(DEFAULT_EMAIL_POLL_SECS),
+        )),
         _ => None, // "manual", "webhook", "chain", and unknown have no scheduled next time
     }
 }
@@ -194,6 +234,7 @@ pub fn compute_slots_in_range(
         TriggerConfig::Schedule {
             cron: Some(expr),
             timezone,
+            exclusions,
             ..
         } => {
             let Ok(schedule) = cron::parse_cron_seeded(expr, seed) else {
@@ -209,11 +250,7 @@ pub fn compute_slots_in_range(
             };
             let mut from = start;
             while slots.len() < cap {
-                let next = match tz {
-                    Some(zone) => cron::next_fire_time_in_tz(&schedule, from, zone),
-                    None => cron::next_fire_time_local(&schedule, from),
-                };
-                match next {
+                match next_allowed_fire(&schedule, from, tz, exclusions) {
                     Some(t) if t <= end => {
                         slots.push(t);
                         from = t;
@@ -249,7 +286,7 @@ pub fn trigger_event_type(trigger: &PersonaTrigger) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
+    use chrono::{TimeZone, Timelike};
 
     fn make_trigger(trigger_type: &str, config: Option<&str>) -> PersonaTrigger {
         PersonaTrigger {
@@ -362,6 +399,36 @@ mod tests {
         assert_eq!(slots.len(), 4, "expected 4 hourly slots, got {slots:?}");
     }
 
+    #[test]
+    fn test_compute_next_schedule_skips_weekend_exclusion() {
+        // Daily 09:00 UTC with weekends excluded: from Friday evening the next
+        // fire is Monday, not Saturday.
+        let trigger = make_trigger(
+            "schedule",
+            Some(r#"{"cron": "0 9 * * *", "timezone": "UTC", "exclusions": [{"days": [0, 6]}]}"#),
+        );
+        let now = Utc.with_ymd_and_hms(2026, 5, 1, 18, 0, 0).unwrap();
+        let next = compute_next_trigger_at(&trigger, now).unwrap();
+        assert!(next.starts_with("2026-05-04T09:00:00"), "got {next}");
+    }
+
+    #[test]
+    fn test_compute_slots_in_range_honours_exclusions() {
+        // Hourly over a day with a 22:00–06:00 blackout leaves 16 slots.
+        let trigger = make_trigger(
+            "schedule",
+            Some(
+                r#"{"cron": "0 * * * *", "timezone": "UTC", "exclusions": [{"start": "22:00", "end": "06:00"}]}"#,
+            ),
+        );
+        let cfg = trigger.parse_config();
+        let start = Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 1, 16, 0, 0, 0).unwrap();
+        let slots = compute_slots_in_range(&cfg, start, end, 0, 100);
+        assert_eq!(slots.len(), 16, "got {slots:?}");
+        assert!(slots.iter().all(|t| (6..22).contains(&t.hour())));
+    }
+
     #[test]
     fn test_compute_slots_in_range_interval() {
         // Every 600s, 1-hour window — expect 6 slots
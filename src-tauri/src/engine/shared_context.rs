@@ -0,0 +1,326 @@
+//! Shared context blackboard for pipeline runs and chains.
+//!
+//! Personas in one team pipeline run or one chain trace can publish structured
+//! artifacts with the `shared_context` protocol message instead of leaving the
+//! next persona to parse them out of free-text output. Every later execution
+//! in the same scope sees the blackboard rendered into its prompt.
+//!
+//! The scope is read from the execution's input:
+//! - pipeline nodes carry `_pipeline_run_id` (stamped by the pipeline
+//!   executor) and share the run's blackboard;
+//! - chain hops carry `_chain_trace_id` and share the trace's blackboard.
+//!
+//! A chain ROOT has no trace id until it completes (its own trace id becomes
+//! the chain's), so its writes land in a provisional `execution` scope that
+//! [`adopt_root`] moves under the chain before the first link fires.
+//!
+//! Entries are swept by the retention pass with the execution history.
+
+use serde_json::Value;
+
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::execution::shared_context as repo;
+use crate::db::repos::execution::shared_context::SharedContextEntry;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+pub const SCOPE_PIPELINE: &str = "pipeline";
+pub const SCOPE_CHAIN: &str = "chain";
+/// Provisional scope of a run that may become a chain root.
+pub const SCOPE_EXECUTION: &str = "execution";
+
+/// Input key carrying the pipeline run id on every node input.
+pub const PIPELINE_RUN_KEY: &str = "_pipeline_run_id";
+
+const MAX_KEY_CHARS: usize = 128;
+const MAX_VALUE_BYTES: usize = 32 * 1024;
+/// Keys per scope; a persona looping on writes can't grow the table unbounded.
+const MAX_KEYS_PER_SCOPE: i64 = 200;
+/// Characters of blackboard rendered into a prompt.
+const PROMPT_BUDGET_CHARS: usize = 8_000;
+
+/// A blackboard scope: `(scope_type, scope_id)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scope {
+    pub scope_type: &'static str,
+    pub scope_id: String,
+}
+
+/// Write the pipeline run id into a JSON-object node input.
+pub fn stamp_pipeline_run(input: &mut Value, run_id: &str) {
+    if let Some(obj) = input.as_object_mut() {
+        obj.insert(PIPELINE_RUN_KEY.into(), Value::String(run_id.to_string()));
+    }
+}
+
+/// Blackboard scope of an execution input, top-level or under the event-bus
+/// `payload` wrapper. A pipeline run wins over a chain trace.
+pub fn scope_from_input(input: &Value) -> Option<Scope> {
+    let pipeline_run = input
+        .get(PIPELINE_RUN_KEY)
+        .or_else(|| input.get("payload").and_then(|p| p.get(PIPELINE_RUN_KEY)))
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty());
+    if let Some(run_id) = pipeline_run {
+        return Some(Scope {
+            scope_type: SCOPE_PIPELINE,
+            scope_id: run_id.to_string(),
+        });
+    }
+    super::chain::chain_trace_id_from_input(input)
+        .filter(|s| !s.is_empty())
+        .map(|trace_id| Scope {
+            scope_type: SCOPE_CHAIN,
+            scope_id: trace_id,
+        })
+}
+
+/// Scope named by a caller outside the engine.
+pub fn parse_scope(scope_type: &str, scope_id: &str) -> Result<Scope, AppError> {
+    let scope_type = match scope_type {
+        SCOPE_PIPELINE => SCOPE_PIPELINE,
+        SCOPE_CHAIN => SCOPE_CHAIN,
+        SCOPE_EXECUTION => SCOPE_EXECUTION,
+        other => {
+            return Err(AppError::Validation(format!(
+                "Unknown shared context scope '{other}' (expected pipeline, chain or execution)"
+            )))
+        }
+    };
+    if scope_id.trim().is_empty() {
+        return Err(AppError::Validation(
+            "Shared context scope id must not be empty".into(),
+        ));
+    }
+    Ok(Scope {
+        scope_type,
+        scope_id: scope_id.trim().to_string(),
+    })
+}
+
+/// Check a key/value pair against the blackboard limits.
+pub fn validate_entry(key: &str, value: &Value) -> Result<(), AppError> {
+    let key = key.trim();
+    if key.is_empty() || key.chars().count() > MAX_KEY_CHARS {
+        return Err(AppError::Validation(format!(
+            "Shared context key must be 1-{MAX_KEY_CHARS} characters"
+        )));
+    }
+    let size = value.to_string().len();
+    if size > MAX_VALUE_BYTES {
+        return Err(AppError::Validation(format!(
+            "Shared context value for '{key}' is {size} bytes (limit {MAX_VALUE_BYTES})"
+        )));
+    }
+    Ok(())
+}
+
+/// Write `key` to a scope after checking the limits. Replacing an existing key
+/// never counts against the per-scope cap.
+pub fn write(
+    pool: &DbPool,
+    scope: &Scope,
+    key: &str,
+    value: &Value,
+    persona_id: Option<&str>,
+    execution_id: Option<&str>,
+) -> Result<SharedContextEntry, AppError> {
+    validate_entry(key, value)?;
+    let key = key.trim();
+    let exists = repo::get(pool, scope.scope_type, &scope.scope_id, key)?.is_some();
+    if !exists && repo::count(pool, scope.scope_type, &scope.scope_id)? >= MAX_KEYS_PER_SCOPE {
+        return Err(AppError::Validation(format!(
+            "Shared context for {} {} is full ({MAX_KEYS_PER_SCOPE} keys)",
+            scope.scope_type, scope.scope_id
+        )));
+    }
+    repo::set(
+        pool,
+        scope.scope_type,
+        &scope.scope_id,
+        key,
+        value,
+        persona_id,
+        execution_id,
+    )
+}
+
+/// Record a persona's `shared_context` protocol message, scoped by the
+/// execution's stored input (provisional execution scope when it has none).
+pub fn record_from_execution(
+    pool: &DbPool,
+    execution_id: &str,
+    persona_id: &str,
+    key: &str,
+    value: &Value,
+) -> Result<(Scope, SharedContextEntry), AppError> {
+    let scope = exec_repo::get_by_id(pool, execution_id)
+        .ok()
+        .and_then(|exec| exec.input_data)
+        .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+        .and_then(|input| scope_from_input(&input))
+        .unwrap_or_else(|| Scope {
+            scope_type: SCOPE_EXECUTION,
+            scope_id: execution_id.to_string(),
+        });
+    let entry = write(
+        pool,
+        &scope,
+        key,
+        value,
+        Some(persona_id),
+        Some(execution_id),
+    )?;
+    Ok((scope, entry))
+}
+
+/// Move a chain root's provisional entries under the chain it started. Called
+/// on completion, before the chain's links are evaluated. Best-effort.
+pub fn adopt_root(pool: &DbPool, execution_id: &str, chain_trace_id: &str) {
+    match repo::rescope(
+        pool,
+        (SCOPE_EXECUTION, execution_id),
+        (SCOPE_CHAIN, chain_trace_id),
+    ) {
+        Ok(0) => {}
+        Ok(n) => tracing::debug!(
+            execution_id = %execution_id,
+            chain_trace_id = %chain_trace_id,
+            keys = n,
+            "Adopted chain root shared context"
+        ),
+        Err(e) => tracing::warn!(
+            execution_id = %execution_id,
+            chain_trace_id = %chain_trace_id,
+            error = %e,
+            "Failed to adopt chain root shared context"
+        ),
+    }
+}
+
+/// How a persona publishes to the blackboard. Shown only to runs that have (or
+/// may start) a scope, so standalone runs don't pay for it.
+const PROTOCOL_DOC: &str = r#"### Shared Context Protocol
+Publish a structured artifact for the personas that run after you in this pipeline or chain by outputting a JSON object on its own line:
+```json
+{"shared_context": {"key": "candidate_list", "value": {"items": ["a", "b"], "source": "crm"}}}
+```
+- `key` (required): short stable name; writing the same key again replaces it
+- `value` (required): any JSON — prefer objects with named fields over prose
+"#;
+
+/// Prompt section with the blackboard of the input's scope and the protocol
+/// for writing to it. A run without a scope still gets the protocol when its
+/// persona is the source of a chain trigger, since it may become a chain
+/// root. `None` for everything else.
+pub fn prompt_block(pool: &DbPool, persona_id: &str, input: Option<&Value>) -> Option<String> {
+    let Some(scope) = input.and_then(scope_from_input) else {
+        let is_chain_source =
+            crate::db::repos::resources::triggers::get_chain_triggers_for_source(pool, persona_id)
+                .is_ok_and(|triggers| !triggers.is_empty());
+        return is_chain_source.then(|| {
+            format!(
+                "\n\n## Shared Context\n\nAnything you publish is handed to the personas \
+                 your chain triggers start.\n\n{PROTOCOL_DOC}"
+            )
+        });
+    };
+    let entries = repo::list(pool, scope.scope_type, &scope.scope_id).unwrap_or_default();
+    Some(render(&scope, &entries))
+}
+
+fn render(scope: &Scope, entries: &[SharedContextEntry]) -> String {
+    let mut block = format!(
+        "\n\n## Shared Context ({} blackboard)\n\n",
+        scope.scope_type
+    );
+    if entries.is_empty() {
+        block.push_str("Nothing has been published yet.\n");
+    } else {
+        block.push_str(&format!(
+            "Structured artifacts published by earlier personas in this {}. \
+             Prefer these over re-parsing upstream output.\n\n",
+            scope.scope_type
+        ));
+    }
+    let mut omitted = 0;
+    for entry in entries {
+        let line = format!("- `{}`: {}\n", entry.key, entry.value);
+        if block.len() + line.len() > PROMPT_BUDGET_CHARS {
+            omitted += 1;
+            continue;
+        }
+        block.push_str(&line);
+    }
+    if omitted > 0 {
+        block.push_str(&format!(
+            "- …(+{omitted} more keys omitted to bound prompt size)\n"
+        ));
+    }
+    block.push('\n');
+    block.push_str(PROTOCOL_DOC);
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+    use serde_json::json;
+
+    #[test]
+    fn blackboard_is_scoped_limited_and_adopted_by_chains() {
+        let pipeline = json!({ "_event": {}, "payload": { "_pipeline_run_id": "run-1", "_chain_trace_id": "t" } });
+        assert_eq!(
+            scope_from_input(&pipeline).unwrap().scope_type,
+            SCOPE_PIPELINE
+        );
+        let chain = json!({ "_chain_trace_id": "trace-1" });
+        assert_eq!(
+            scope_from_input(&chain),
+            Some(Scope {
+                scope_type: SCOPE_CHAIN,
+                scope_id: "trace-1".into()
+            })
+        );
+        assert!(scope_from_input(&json!({ "topic": "x" })).is_none());
+
+        let pool = init_test_db().unwrap();
+        let root = Scope {
+            scope_type: SCOPE_EXECUTION,
+            scope_id: "exec-root".into(),
+        };
+        write(
+            &pool,
+            &root,
+            "plan",
+            &json!({ "steps": 3 }),
+            Some("p1"),
+            Some("exec-root"),
+        )
+        .unwrap();
+        write(
+            &pool,
+            &root,
+            "plan",
+            &json!({ "steps": 4 }),
+            Some("p1"),
+            Some("exec-root"),
+        )
+        .unwrap();
+        assert!(write(&pool, &root, " ", &json!(1), None, None).is_err());
+        let huge = json!("x".repeat(MAX_VALUE_BYTES + 1));
+        assert!(write(&pool, &root, "big", &huge, None, None).is_err());
+
+        adopt_root(&pool, "exec-root", "trace-1");
+        assert!(repo::list(&pool, SCOPE_EXECUTION, "exec-root")
+            .unwrap()
+            .is_empty());
+        let block = prompt_block(&pool, "p2", Some(&chain)).unwrap();
+        assert!(block.contains("## Shared Context (chain blackboard)"));
+        assert!(block.contains("- `plan`: {\"steps\":4}"));
+        let empty = prompt_block(&pool, "p2", Some(&pipeline)).unwrap();
+        assert!(empty.contains("Nothing has been published yet."));
+        assert!(prompt_block(&pool, "p2", None).is_none());
+    }
+}
@@ -0,0 +1,194 @@
+//! Per-persona SLO monitor — the background half of opt-in SLOs.
+//!
+//! Targets live in `persona_slos` and the measurement and threshold logic in
+//! [`crate::db::repos::communication::sla`]. Every tick re-measures each
+//! enabled SLO over its rolling window and acts only on a state crossing:
+//! - entering breach opens a `slo_breach` healing issue, notifies through the
+//!   persona's channels and emits [`event_name::HEALING_EVENT`];
+//! - recovering resolves that issue.
+//!
+//! Breach state is stored on the SLO row, so a restart mid-breach never
+//! re-announces it. A breach whose objectives change (e.g. latency recovers
+//! while the success rate stays low) updates the stored reason without a new
+//! issue.
+
+use tauri::{AppHandle, Emitter};
+
+use crate::db::models::PersonaSlo;
+use crate::db::repos::communication::sla::{self, SloMeasurement, SLO_SUCCESS_RATE};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::event_registry::event_name;
+use super::types::HealingEventPayload;
+
+const ISSUE_CATEGORY: &str = "slo_breach";
+const ISSUE_TITLE: &str = "SLO breached";
+const ISSUE_SEVERITY: &str = "high";
+
+/// One monitor tick over every enabled SLO.
+pub fn slo_tick(pool: &DbPool, app: &AppHandle) {
+    let slos = match sla::list_persona_slos(pool, true) {
+        Ok(slos) => slos,
+        Err(e) => {
+            tracing::warn!(error = %e, "SLO monitor: failed to list SLOs");
+            return;
+        }
+    };
+    for slo in slos {
+        if let Err(e) = evaluate(pool, app, &slo) {
+            tracing::warn!(persona_id = %slo.persona_id, error = %e, "SLO monitor: evaluation failed");
+        }
+    }
+}
+
+fn evaluate(pool: &DbPool, app: &AppHandle, slo: &PersonaSlo) -> Result<(), AppError> {
+    let m = sla::measure_slo_window(pool, &slo.persona_id, slo.window_hours)?;
+    let violations = sla::slo_violations(slo, &m);
+    let now = chrono::Utc::now().to_rfc3339();
+
+    if violations.is_empty() {
+        // Too little data to judge keeps an open breach open rather than
+        // flapping it shut on a quiet window.
+        if slo.breach_reason.is_some() && m.decided < slo.min_sample {
+            return Ok(());
+        }
+        if let Some(issue_id) = slo.breach_issue_id.as_deref() {
+            let _ = healing_repo::update_status(pool, issue_id, "resolved");
+            tracing::info!(persona_id = %slo.persona_id, "SLO monitor: SLO recovered");
+        }
+        return sla::record_slo_evaluation(pool, &slo.persona_id, None, None, &now);
+    }
+
+    let reason = violations.join(",");
+    if slo.breach_reason.is_some() {
+        return sla::record_slo_evaluation(pool, &slo.persona_id, Some(&reason), None, &now);
+    }
+
+    let persona = persona_repo::get_by_id(pool, &slo.persona_id)?;
+    let description = describe(slo, &m, &violations);
+    let fix = "Review this agent's recent failed or slow runs in Observability; if the \
+               target is too strict, adjust it in the agent's SLO settings."
+        .to_string();
+    tracing::warn!(
+        persona_id = %slo.persona_id,
+        reason = %reason,
+        "SLO monitor: SLO breached"
+    );
+
+    let issue = healing_repo::create(
+        pool,
+        &slo.persona_id,
+        ISSUE_TITLE,
+        &description,
+        false,
+        Some(ISSUE_SEVERITY),
+        Some(ISSUE_CATEGORY),
+        None,
+        Some(&fix),
+    )
+    .ok()
+    .flatten();
+    let issue_id = issue.map(|i| i.id);
+    sla::record_slo_evaluation(
+        pool,
+        &slo.persona_id,
+        Some(&reason),
+        issue_id.as_deref(),
+        &now,
+    )?;
+
+    crate::notifications::notify_healing_issue(
+        app,
+        &slo.persona_id,
+        &persona.name,
+        ISSUE_TITLE,
+        ISSUE_SEVERITY,
+        Some(&fix),
+        persona.notification_channels.as_deref(),
+    );
+    let _ = app.emit(
+        event_name::HEALING_EVENT,
+        HealingEventPayload {
+            issue_id: issue_id.unwrap_or_default(),
+            persona_id: slo.persona_id.clone(),
+            execution_id: String::new(),
+            title: ISSUE_TITLE.into(),
+            action: "issue_created".into(),
+            auto_fixed: false,
+            severity: ISSUE_SEVERITY.into(),
+            suggested_fix: Some(fix),
+            persona_name: persona.name,
+            description: Some(description),
+            strategy: None,
+            backoff_seconds: None,
+            retry_number: None,
+            max_retries: None,
+        },
+    );
+    Ok(())
+}
+
+/// Human-readable breach summary naming each violated objective.
+fn describe(slo: &PersonaSlo, m: &SloMeasurement, violations: &[&str]) -> String {
+    let parts: Vec<String> = violations
+        .iter()
+        .map(|v| {
+            if *v == SLO_SUCCESS_RATE {
+                format!(
+                    "success rate {:.1}% is below the {:.1}% target",
+                    m.success_rate.unwrap_or(0.0) * 100.0,
+                    slo.min_success_rate.unwrap_or(0.0) * 100.0,
+                )
+            } else {
+                format!(
+                    "p95 duration {:.1}s is above the {:.1}s target",
+                    m.p95_duration_ms.unwrap_or(0.0) / 1000.0,
+                    slo.max_p95_duration_ms.unwrap_or(0) as f64 / 1000.0,
+                )
+            }
+        })
+        .collect();
+    format!(
+        "Over the last {}h ({} runs): {}.",
+        slo.window_hours,
+        m.decided,
+        parts.join("; "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repos::communication::sla::SLO_P95_DURATION;
+
+    #[test]
+    fn describe_names_each_violation() {
+        let slo = PersonaSlo {
+            persona_id: "p".into(),
+            min_success_rate: Some(0.95),
+            max_p95_duration_ms: Some(30_000),
+            window_hours: 24,
+            min_sample: 5,
+            enabled: true,
+            breach_reason: None,
+            breached_at: None,
+            breach_issue_id: None,
+            last_evaluated_at: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        let m = SloMeasurement {
+            decided: 20,
+            success_rate: Some(0.8),
+            p95_duration_ms: Some(45_000.0),
+        };
+        assert_eq!(
+            describe(&slo, &m, &[SLO_SUCCESS_RATE, SLO_P95_DURATION]),
+            "Over the last 24h (20 runs): success rate 80.0% is below the 95.0% target; \
+             p95 duration 45.0s is above the 30.0s target."
+        );
+    }
+}
@@ -19,6 +19,7 @@ use super::event_registry::{emit_event_bus, event_name};
 use super::safe_json;
 use super::url_safety;
 use crate::db::models::CreatePersonaEventInput;
+use crate::db::repos::communication::event_schemas;
 use crate::db::repos::communication::events as event_repo;
 use crate::db::DbPool;
 
@@ -498,13 +499,24 @@ async fn relay_sse_core(
                 continue;
             }
 
+            let payload = body.to_string();
+            if let Err(e) = event_schemas::validate_payload(pool, &event_type, Some(&payload)) {
+                tracing::warn!(
+                    relay_key = %params.relay_key,
+                    event_type = %event_type,
+                    error = %e,
+                    "Smee relay: dropped event that failed its payload schema"
+                );
+                continue;
+            }
+
             let input = CreatePersonaEventInput {
                 event_type,
                 source_type: "smee_relay".to_string(),
                 project_id: None,
                 source_id: params.source_id.clone(),
                 target_persona_id: params.target_persona_id.clone(),
-                payload: Some(body.to_string()),
+                payload: Some(payload),
                 use_case_id: None,
             };
 
@@ -0,0 +1,978 @@
+//! The builtin `sql_query` tool, executed by the engine.
+//!
+//! The personas-mcp sidecar forwards `sql_query` calls to the management API
+//! (`POST /api/sql-query/{persona_id}`), which runs them here against a
+//! database credential with native drivers — PostgreSQL and Neon through
+//! `tokio-postgres`, MySQL through `mysql_async`, SQLite files and the
+//! built-in database through `rusqlite`. The agent never sees the connection
+//! secret.
+//!
+//! - One statement per call, with `$1`/`?` placeholders bound from `params`.
+//! - Reads run in a read-only transaction (or a read-only SQLite handle), so
+//!   a statement misclassified as a read still cannot write.
+//! - A statement that writes needs the persona's `sql_query` policy to set
+//!   `allow_write`, and the run must have been approved for
+//!   [`SQL_WRITE`](super::tool_approval::SQL_WRITE) — see
+//!   [`tool_approval`](super::tool_approval).
+//! - Rows are returned as JSON up to the policy's `max_rows` and
+//!   `max_result_bytes`; the result is marked `truncated` past either.
+//!   PostgreSQL `numeric` values come back as strings to keep their precision.
+
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use futures_util::{pin_mut, TryStreamExt};
+use serde::Deserialize;
+use serde_json::Value;
+use tokio_postgres::types::{FromSql, Kind, ToSql, Type};
+
+use crate::db::models::QueryResult;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::resources::audit_log;
+use crate::db::repos::resources::credentials as cred_repo;
+use crate::db::{DbPool, UserDbPool};
+use crate::error::AppError;
+
+use super::db_query::{
+    extract_first_keyword, has_multiple_statements, is_mutation, sanitize_error,
+};
+use super::tool_approval::{self, REQUEST_APPROVAL_TOOL, SQL_WRITE};
+use super::tool_policy::{
+    ToolPolicy, ToolPolicySet, MAX_SQL_RESULT_BYTES, MAX_SQL_ROWS, SQL_QUERY,
+};
+
+/// Limits when the persona's policy sets none.
+const DEFAULT_MAX_ROWS: u64 = 200;
+const DEFAULT_MAX_RESULT_BYTES: u64 = 64 * 1024;
+
+const QUERY_TIMEOUT: Duration = Duration::from_secs(60);
+const SQLITE_BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Credential service types `sql_query` can run against.
+pub const SUPPORTED_SERVICES: &[&str] =
+    &["postgres", "neon", "mysql", "sqlite", "personas_database"];
+
+/// Arguments of a `sql_query` call.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SqlToolRequest {
+    pub credential_id: String,
+    pub sql: String,
+    /// Values bound to the statement's placeholders, in order.
+    #[serde(default)]
+    pub params: Vec<Value>,
+    /// The calling run; writes are checked against its approvals.
+    pub execution_id: Option<String>,
+}
+
+/// Whether a statement writes. Conservative: anything not recognised as a
+/// read counts, as does a `PRAGMA` that assigns.
+pub fn is_write(sql: &str) -> bool {
+    is_mutation(sql)
+        || (extract_first_keyword(sql).as_deref() == Some("PRAGMA") && sql.contains('='))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Limits {
+    max_rows: usize,
+    max_bytes: usize,
+}
+
+impl Limits {
+    fn from_policy(policy: &ToolPolicy) -> Self {
+        Self {
+            max_rows: policy
+                .max_rows
+                .unwrap_or(DEFAULT_MAX_ROWS)
+                .min(MAX_SQL_ROWS) as usize,
+            max_bytes: policy
+                .max_result_bytes
+                .unwrap_or(DEFAULT_MAX_RESULT_BYTES)
+                .min(MAX_SQL_RESULT_BYTES) as usize,
+        }
+    }
+}
+
+struct Query {
+    sql: String,
+    params: Vec<Value>,
+    write: bool,
+    limits: Limits,
+}
+
+/// Run one `sql_query` call for `persona_id`. `user_db` serves
+/// `personas_database` credentials.
+pub async fn execute(
+    pool: &DbPool,
+    user_db: Option<&UserDbPool>,
+    persona_id: &str,
+    request: SqlToolRequest,
+) -> Result<QueryResult, AppError> {
+    let sql = request
+        .sql
+        .trim()
+        .trim_end_matches(';')
+        .trim_end()
+        .to_string();
+    if sql.is_empty() {
+        return Err(AppError::Validation("sql is empty".into()));
+    }
+    if has_multiple_statements(&sql) {
+        return Err(AppError::Validation(
+            "Run one statement per sql_query call".into(),
+        ));
+    }
+
+    let policies = ToolPolicySet::load(pool, persona_id, Path::new("."));
+    let policy = policies.policy(SQL_QUERY).cloned().unwrap_or_default();
+    let write = is_write(&sql);
+    if write {
+        authorize_write(pool, persona_id, &policy, request.execution_id.as_deref())?;
+    }
+
+    let credential = cred_repo::get_by_id(pool, &request.credential_id)?;
+    let service = credential.service_type.clone();
+    if !SUPPORTED_SERVICES.contains(&service.as_str()) {
+        return Err(AppError::Validation(format!(
+            "sql_query runs against PostgreSQL, Neon, MySQL and SQLite credentials, not '{service}'"
+        )));
+    }
+    let query = Query {
+        sql,
+        params: request.params,
+        write,
+        limits: Limits::from_policy(&policy),
+    };
+
+    let start = Instant::now();
+    let (result, fields) = if service == "personas_database" {
+        let udb =
+            user_db.ok_or_else(|| AppError::Internal("User database pool not available".into()))?;
+        let conn = udb
+            .get()
+            .map_err(|e| AppError::Internal(format!("Failed to connect to user database: {e}")))?;
+        (run_sqlite(conn, query, true).await, HashMap::new())
+    } else {
+        let fields = cred_repo::get_decrypted_fields(pool, &credential)?;
+        if let Err(e) = audit_log::log_decrypt(
+            pool,
+            &credential.id,
+            &credential.name,
+            SQL_QUERY,
+            None,
+            None,
+        ) {
+            tracing::warn!(credential_id = %credential.id, error = %e, "Failed to write audit log for credential decrypt");
+        }
+        let result = match service.as_str() {
+            "sqlite" => match open_sqlite(&fields, query.write) {
+                Ok(conn) => run_sqlite(Box::new(conn), query, false).await,
+                Err(e) => Err(e),
+            },
+            "mysql" => with_timeout(run_mysql(&fields, query)).await,
+            _ => with_timeout(run_postgres(&fields, query)).await,
+        };
+        (result, fields)
+    };
+
+    let duration_ms = start.elapsed().as_millis() as u64;
+    match result {
+        Ok(mut qr) => {
+            qr.duration_ms = duration_ms;
+            tracing::info!(
+                persona_id = %persona_id,
+                service_type = %service,
+                credential_id = %credential.id,
+                write,
+                duration_ms,
+                row_count = qr.row_count,
+                truncated = qr.truncated,
+                "sql_query completed"
+            );
+            Ok(qr)
+        }
+        Err(AppError::External(msg)) => Err(AppError::External(sanitize_error(&msg, &fields))),
+        Err(e) => Err(e),
+    }
+}
+
+/// Refuse a write unless the policy allows writes and the calling run was
+/// approved for [`SQL_WRITE`].
+fn authorize_write(
+    pool: &DbPool,
+    persona_id: &str,
+    policy: &ToolPolicy,
+    execution_id: Option<&str>,
+) -> Result<(), AppError> {
+    if !policy.allow_write {
+        return Err(AppError::Forbidden(format!(
+            "{SQL_QUERY} is read-only for this persona; the statement would modify data"
+        )));
+    }
+    let approved = execution_id
+        .and_then(|id| exec_repo::get_by_id(pool, id).ok())
+        .filter(|exec| exec.persona_id == persona_id)
+        .is_some_and(|exec| {
+            let input = exec
+                .input_data
+                .as_deref()
+                .and_then(|s| serde_json::from_str::<Value>(s).ok());
+            tool_approval::is_approved(input.as_ref(), SQL_WRITE)
+        });
+    if approved {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(format!(
+            "Writes need human approval: call `{REQUEST_APPROVAL_TOOL}` with tool \
+             `{SQL_WRITE}` and this {SQL_QUERY} call as its input"
+        )))
+    }
+}
+
+async fn with_timeout(
+    work: impl std::future::Future<Output = Result<QueryResult, AppError>>,
+) -> Result<QueryResult, AppError> {
+    tokio::time::timeout(QUERY_TIMEOUT, work)
+        .await
+        .unwrap_or_else(|_| Err(timed_out()))
+}
+
+fn timed_out() -> AppError {
+    AppError::Validation(format!(
+        "Query timed out after {}s. Narrow the query or add a LIMIT.",
+        QUERY_TIMEOUT.as_secs()
+    ))
+}
+
+fn field<'a>(fields: &'a HashMap<String, String>, key: &str) -> Option<&'a str> {
+    fields.get(key).map(|v| v.trim()).filter(|v| !v.is_empty())
+}
+
+/// Rows collected under the call's limits.
+struct RowCollector {
+    limits: Limits,
+    rows: Vec<Vec<Value>>,
+    bytes: usize,
+    truncated: bool,
+}
+
+impl RowCollector {
+    fn new(limits: Limits) -> Self {
+        Self {
+            limits,
+            rows: Vec::new(),
+            bytes: 0,
+            truncated: false,
+        }
+    }
+
+    /// Add a row. Returns false, and marks the result truncated, once the row
+    /// would exceed a limit; the caller stops reading.
+    fn push(&mut self, row: Vec<Value>) -> bool {
+        let size = serde_json::to_string(&row).map_or(0, |s| s.len());
+        if self.rows.len() >= self.limits.max_rows || self.bytes + size > self.limits.max_bytes {
+            self.truncated = true;
+            return false;
+        }
+        self.bytes += size;
+        self.rows.push(row);
+        true
+    }
+
+    fn finish(self, columns: Vec<String>) -> QueryResult {
+        QueryResult {
+            columns,
+            row_count: self.rows.len(),
+            rows: self.rows,
+            duration_ms: 0,
+            truncated: self.truncated,
+        }
+    }
+}
+
+/// Result of a statement that returns no rows.
+fn affected_result(affected: u64) -> QueryResult {
+    QueryResult {
+        columns: vec!["affected_rows".to_string()],
+        rows: vec![vec![Value::from(affected)]],
+        row_count: affected as usize,
+        duration_ms: 0,
+        truncated: false,
+    }
+}
+
+// ============================================================================
+// PostgreSQL / Neon
+// ============================================================================
+
+type PgParam = Box<dyn ToSql + Sync + Send>;
+
+async fn run_postgres(
+    fields: &HashMap<String, String>,
+    query: Query,
+) -> Result<QueryResult, AppError> {
+    let config = pg_config(fields)?;
+    let (mut client, connection) = config.connect(pg_tls()?).await.map_err(pg_error)?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::debug!(error = %e, "sql_query: postgres connection closed with an error");
+        }
+    });
+
+    let tx = client
+        .build_transaction()
+        .read_only(!query.write)
+        .start()
+        .await
+        .map_err(pg_error)?;
+    let stmt = tx.prepare(&query.sql).await.map_err(pg_error)?;
+    if stmt.params().len() != query.params.len() {
+        return Err(AppError::Validation(format!(
+            "The statement takes {} parameters, {} given",
+            stmt.params().len(),
+            query.params.len()
+        )));
+    }
+    let params = stmt
+        .params()
+        .iter()
+        .zip(&query.params)
+        .map(|(ty, value)| pg_param(ty, value))
+        .collect::<Result<Vec<_>, _>>()?;
+    let param_refs: Vec<&(dyn ToSql + Sync)> = params
+        .iter()
+        .map(|p| p.as_ref() as &(dyn ToSql + Sync))
+        .collect();
+
+    let result = if stmt.columns().is_empty() {
+        let affected = tx.execute(&stmt, &param_refs).await.map_err(pg_error)?;
+        affected_result(affected)
+    } else {
+        let columns = stmt
+            .columns()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        let stream = tx
+            .query_raw(&stmt, param_refs.iter().copied())
+            .await
+            .map_err(pg_error)?;
+        pin_mut!(stream);
+        let mut rows = RowCollector::new(query.limits);
+        while let Some(row) = stream.try_next().await.map_err(pg_error)? {
+            let values = (0..row.len())
+                .map(|i| pg_value(&row, i))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(pg_error)?;
+            if !rows.push(values) {
+                break;
+            }
+        }
+        rows.finish(columns)
+    };
+    tx.commit().await.map_err(pg_error)?;
+    Ok(result)
+}
+
+fn pg_config(fields: &HashMap<String, String>) -> Result<tokio_postgres::Config, AppError> {
+    use tokio_postgres::config::SslMode;
+
+    if let Some(conn_str) = field(fields, "connection_string") {
+        return conn_str.parse().map_err(|e| {
+            AppError::Validation(format!("Invalid PostgreSQL connection string: {e}"))
+        });
+    }
+    let host = field(fields, "host").ok_or_else(|| {
+        AppError::Validation("The credential has neither a connection string nor a host".into())
+    })?;
+    let port = match field(fields, "port") {
+        Some(p) => p
+            .parse()
+            .map_err(|_| AppError::Validation(format!("Invalid port '{p}'")))?,
+        None => 5432,
+    };
+    let mut config = tokio_postgres::Config::new();
+    config.host(host).port(port);
+    if let Some(db) = field(fields, "database") {
+        config.dbname(db);
+    }
+    if let Some(user) = field(fields, "username") {
+        config.user(user);
+    }
+    if let Some(password) = field(fields, "password") {
+        config.password(password);
+    }
+    config.ssl_mode(match field(fields, "ssl_mode") {
+        Some("disable") => SslMode::Disable,
+        Some("require" | "verify-ca" | "verify-full") => SslMode::Require,
+        _ => SslMode::Prefer,
+    });
+    Ok(config)
+}
+
+/// TLS for PostgreSQL, checked against the bundled web PKI roots.
+fn pg_tls() -> Result<tokio_postgres_rustls::MakeRustlsConnect, AppError> {
+    use tokio_rustls::rustls;
+
+    let roots: rustls::RootCertStore = webpki_roots::TLS_SERVER_ROOTS.iter().cloned().collect();
+    let config = rustls::ClientConfig::builder_with_provider(std::sync::Arc::new(
+        rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()
+    .map_err(|e| AppError::Internal(format!("TLS setup failed: {e}")))?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    Ok(tokio_postgres_rustls::MakeRustlsConnect::new(config))
+}
+
+fn pg_error(e: tokio_postgres::Error) -> AppError {
+    match e.as_db_error() {
+        Some(db) => AppError::External(format!("PostgreSQL {}: {}", db.severity(), db.message())),
+        None => AppError::External(format!("PostgreSQL: {e}")),
+    }
+}
+
+/// `Some(None)` for JSON null, `Some(Some(v))` when `get` converts the value,
+/// `None` when it does not fit.
+fn nullable<T>(value: &Value, get: impl FnOnce(&Value) -> Option<T>) -> Option<Option<T>> {
+    if value.is_null() {
+        Some(None)
+    } else {
+        get(value).map(Some)
+    }
+}
+
+fn boxed<T: ToSql + Sync + Send + 'static>(value: Option<T>) -> PgParam {
+    Box::new(value)
+}
+
+/// Convert a JSON parameter to the type PostgreSQL inferred for its placeholder.
+fn pg_param(ty: &Type, value: &Value) -> Result<PgParam, AppError> {
+    let param = match ty.name() {
+        "bool" => nullable(value, Value::as_bool).map(boxed),
+        "int2" => nullable(value, |v| i16::try_from(v.as_i64()?).ok()).map(boxed),
+        "int4" => nullable(value, |v| i32::try_from(v.as_i64()?).ok()).map(boxed),
+        "int8" => nullable(value, Value::as_i64).map(boxed),
+        "float4" => nullable(value, |v| v.as_f64().map(|f| f as f32)).map(boxed),
+        "float8" => nullable(value, Value::as_f64).map(boxed),
+        "json" | "jsonb" => Some(boxed((!value.is_null()).then(|| value.clone()))),
+        "text" | "varchar" | "bpchar" | "name" => nullable(value, |v| {
+            Some(v.as_str().map_or_else(|| v.to_string(), String::from))
+        })
+        .map(boxed),
+        "uuid" => nullable(value, |v| v.as_str()?.parse::<uuid::Uuid>().ok()).map(boxed),
+        "timestamptz" => nullable(value, |v| {
+            chrono::DateTime::parse_from_rfc3339(v.as_str()?)
+                .ok()
+                .map(|t| t.with_timezone(&chrono::Utc))
+        })
+        .map(boxed),
+        "timestamp" => nullable(value, |v| {
+            let s = v.as_str()?;
+            chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+                .or_else(|_| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+                .ok()
+        })
+        .map(boxed),
+        "date" => nullable(value, |v| {
+            chrono::NaiveDate::parse_from_str(v.as_str()?, "%Y-%m-%d").ok()
+        })
+        .map(boxed),
+        other => {
+            return Err(AppError::Validation(format!(
+                "Parameters of type {other} are not supported; cast the placeholder in the \
+                 query (e.g. `$1::text`)"
+            )))
+        }
+    };
+    param.ok_or_else(|| {
+        AppError::Validation(format!("Parameter {value} does not fit type {}", ty.name()))
+    })
+}
+
+fn pg_cell<'a, T: FromSql<'a>>(
+    row: &'a tokio_postgres::Row,
+    i: usize,
+) -> Result<Option<T>, tokio_postgres::Error> {
+    row.try_get(i)
+}
+
+fn pg_value(row: &tokio_postgres::Row, i: usize) -> Result<Value, tokio_postgres::Error> {
+    let ty = row.columns()[i].type_();
+    let value =
+        match ty.name() {
+            "bool" => pg_cell::<bool>(row, i)?.map(Value::Bool),
+            "int2" => pg_cell::<i16>(row, i)?.map(Value::from),
+            "int4" => pg_cell::<i32>(row, i)?.map(Value::from),
+            "int8" => pg_cell::<i64>(row, i)?.map(Value::from),
+            "oid" => pg_cell::<u32>(row, i)?.map(Value::from),
+            "float4" => pg_cell::<f32>(row, i)?.map(|f| Value::from(f64::from(f))),
+            "float8" => pg_cell::<f64>(row, i)?.map(Value::from),
+            "numeric" => pg_cell::<PgNumeric>(row, i)?.map(|n| Value::String(n.0)),
+            "json" | "jsonb" => pg_cell::<Value>(row, i)?,
+            "uuid" => pg_cell::<uuid::Uuid>(row, i)?.map(|u| Value::String(u.to_string())),
+            "timestamptz" => pg_cell::<chrono::DateTime<chrono::Utc>>(row, i)?
+                .map(|t| Value::String(t.to_rfc3339())),
+            "timestamp" => {
+                pg_cell::<chrono::NaiveDateTime>(row, i)?.map(|t| Value::String(t.to_string()))
+            }
+            "date" => pg_cell::<chrono::NaiveDate>(row, i)?.map(|d| Value::String(d.to_string())),
+            "time" => pg_cell::<chrono::NaiveTime>(row, i)?.map(|t| Value::String(t.to_string())),
+            "bytea" => pg_cell::<Vec<u8>>(row, i)?
+                .map(|b| Value::String(format!("<bytea {} bytes>", b.len()))),
+            _ => pg_cell::<PgText>(row, i)?
+                .map(|t| Value::String(t.0.unwrap_or_else(|| format!("<{}>", ty.name())))),
+        };
+    Ok(value.unwrap_or(Value::Null))
+}
+
+/// A `numeric` value as its decimal string.
+struct PgNumeric(String);
+
+impl<'a> FromSql<'a> for PgNumeric {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        decode_numeric(raw)
+            .map(PgNumeric)
+            .ok_or_else(|| "malformed numeric value".into())
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::NUMERIC
+    }
+}
+
+/// Decode PostgreSQL's binary `numeric`: digit count, weight, sign and display
+/// scale, then base-10000 digits.
+fn decode_numeric(raw: &[u8]) -> Option<String> {
+    let word = |i: usize| {
+        raw.get(i * 2..i * 2 + 2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+    };
+    let ndigits = word(0)? as usize;
+    let weight = word(1)? as i16 as i32;
+    let sign = word(2)?;
+    let dscale = word(3)? as usize;
+    match sign {
+        0xC000 => return Some("NaN".into()),
+        0xD000 => return Some("Infinity".into()),
+        0xF000 => return Some("-Infinity".into()),
+        _ => {}
+    }
+    let digits = (0..ndigits)
+        .map(|i| word(4 + i))
+        .collect::<Option<Vec<_>>>()?;
+    let digit = |pos: i32| {
+        usize::try_from(pos)
+            .ok()
+            .and_then(|p| digits.get(p).copied())
+            .unwrap_or(0)
+    };
+
+    let mut out = String::new();
+    if sign == 0x4000 {
+        out.push('-');
+    }
+    if weight < 0 {
+        out.push('0');
+    } else {
+        out.push_str(&digit(0).to_string());
+        for pos in 1..=weight {
+            out.push_str(&format!("{:04}", digit(pos)));
+        }
+    }
+    if dscale > 0 {
+        let mut frac = String::new();
+        let mut pos = weight + 1;
+        while frac.len() < dscale {
+            frac.push_str(&format!("{:04}", digit(pos)));
+            pos += 1;
+        }
+        frac.truncate(dscale);
+        out.push('.');
+        out.push_str(&frac);
+    }
+    Some(out)
+}
+
+/// Any other column: the text of text-like and enum types, `None` otherwise.
+struct PgText(Option<String>);
+
+impl<'a> FromSql<'a> for PgText {
+    fn from_sql(
+        ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let text_like = matches!(ty.kind(), Kind::Enum(_))
+            || matches!(
+                ty.name(),
+                "text" | "varchar" | "bpchar" | "name" | "citext" | "xml" | "unknown"
+            );
+        Ok(PgText(
+            text_like.then(|| String::from_utf8_lossy(raw).into_owned()),
+        ))
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// MySQL
+// ============================================================================
+
+async fn run_mysql(
+    fields: &HashMap<String, String>,
+    query: Query,
+) -> Result<QueryResult, AppError> {
+    let mut conn = mysql_async::Conn::new(mysql_opts(fields)?)
+        .await
+        .map_err(mysql_error)?;
+    let result = run_mysql_tx(&mut conn, query).await;
+    let _ = conn.disconnect().await;
+    result
+}
+
+async fn run_mysql_tx(conn: &mut mysql_async::Conn, query: Query) -> Result<QueryResult, AppError> {
+    use mysql_async::consts::ColumnType;
+    use mysql_async::prelude::Queryable;
+
+    let mut tx_opts = mysql_async::TxOpts::default();
+    tx_opts.with_readonly(Some(!query.write));
+    let mut tx = conn.start_transaction(tx_opts).await.map_err(mysql_error)?;
+    let params = if query.params.is_empty() {
+        mysql_async::Params::Empty
+    } else {
+        mysql_async::Params::Positional(query.params.iter().map(mysql_param).collect())
+    };
+
+    let qr = {
+        let mut result = tx
+            .exec_iter(query.sql.as_str(), params)
+            .await
+            .map_err(mysql_error)?;
+        let columns: Vec<String> = result
+            .columns_ref()
+            .iter()
+            .map(|c| c.name_str().into_owned())
+            .collect();
+        let json_columns: Vec<bool> = result
+            .columns_ref()
+            .iter()
+            .map(|c| c.column_type() == ColumnType::MYSQL_TYPE_JSON)
+            .collect();
+        let qr = if columns.is_empty() {
+            affected_result(result.affected_rows())
+        } else {
+            let mut rows = RowCollector::new(query.limits);
+            while let Some(row) = result.next().await.map_err(mysql_error)? {
+                let values = (0..row.len())
+                    .map(|i| mysql_value(row.as_ref(i), json_columns[i]))
+                    .collect();
+                if !rows.push(values) {
+                    break;
+                }
+            }
+            rows.finish(columns)
+        };
+        result.drop_result().await.map_err(mysql_error)?;
+        qr
+    };
+    tx.commit().await.map_err(mysql_error)?;
+    Ok(qr)
+}
+
+fn mysql_opts(fields: &HashMap<String, String>) -> Result<mysql_async::Opts, AppError> {
+    if let Some(url) = field(fields, "connection_string") {
+        return mysql_async::Opts::from_url(url)
+            .map_err(|e| AppError::Validation(format!("Invalid MySQL connection string: {e}")));
+    }
+    let host = field(fields, "host").ok_or_else(|| {
+        AppError::Validation("The credential has neither a connection string nor a host".into())
+    })?;
+    let port = match field(fields, "port") {
+        Some(p) => p
+            .parse()
+            .map_err(|_| AppError::Validation(format!("Invalid port '{p}'")))?,
+        None => 3306,
+    };
+    let mut builder = mysql_async::OptsBuilder::default()
+        .ip_or_hostname(host)
+        .tcp_port(port)
+        .db_name(field(fields, "database"))
+        .user(field(fields, "username"))
+        .pass(field(fields, "password"));
+    if !matches!(
+        field(fields, "ssl_mode"),
+        None | Some("disable" | "disabled")
+    ) {
+        builder = builder.ssl_opts(mysql_async::SslOpts::default());
+    }
+    Ok(builder.into())
+}
+
+fn mysql_error(e: mysql_async::Error) -> AppError {
+    AppError::External(format!("MySQL: {e}"))
+}
+
+fn mysql_param(value: &Value) -> mysql_async::Value {
+    use mysql_async::Value as My;
+    match value {
+        Value::Null => My::NULL,
+        Value::Bool(b) => My::Int(i64::from(*b)),
+        Value::Number(n) => n
+            .as_i64()
+            .map(My::Int)
+            .or_else(|| n.as_u64().map(My::UInt))
+            .unwrap_or_else(|| My::Double(n.as_f64().unwrap_or_default())),
+        Value::String(s) => My::Bytes(s.clone().into_bytes()),
+        other => My::Bytes(other.to_string().into_bytes()),
+    }
+}
+
+fn mysql_value(value: Option<&mysql_async::Value>, json: bool) -> Value {
+    use mysql_async::Value as My;
+    match value {
+        None | Some(My::NULL) => Value::Null,
+        Some(My::Int(n)) => Value::from(*n),
+        Some(My::UInt(n)) => Value::from(*n),
+        Some(My::Float(f)) => Value::from(f64::from(*f)),
+        Some(My::Double(f)) => Value::from(*f),
+        Some(My::Bytes(bytes)) => match std::str::from_utf8(bytes) {
+            Ok(s) if json => serde_json::from_str(s).unwrap_or_else(|_| Value::String(s.into())),
+            Ok(s) => Value::String(s.into()),
+            Err(_) => Value::String(format!("<blob {} bytes>", bytes.len())),
+        },
+        Some(My::Date(y, mo, d, h, mi, s, us)) => {
+            let mut out = format!("{y:04}-{mo:02}-{d:02} {h:02}:{mi:02}:{s:02}");
+            if *us > 0 {
+                out.push_str(&format!(".{us:06}"));
+            }
+            Value::String(out)
+        }
+        Some(My::Time(negative, days, h, mi, s, us)) => {
+            let sign = if *negative { "-" } else { "" };
+            let hours = u64::from(*days) * 24 + u64::from(*h);
+            let mut out = format!("{sign}{hours:02}:{mi:02}:{s:02}");
+            if *us > 0 {
+                out.push_str(&format!(".{us:06}"));
+            }
+            Value::String(out)
+        }
+    }
+}
+
+// ============================================================================
+// SQLite (database files and the built-in database)
+// ============================================================================
+
+fn open_sqlite(
+    fields: &HashMap<String, String>,
+    write: bool,
+) -> Result<rusqlite::Connection, AppError> {
+    use rusqlite::OpenFlags;
+
+    let path = field(fields, "database_path")
+        .ok_or_else(|| AppError::Validation("The credential has no database path".into()))?;
+    let mode = if write {
+        OpenFlags::SQLITE_OPEN_READ_WRITE
+    } else {
+        OpenFlags::SQLITE_OPEN_READ_ONLY
+    };
+    rusqlite::Connection::open_with_flags(path, mode | OpenFlags::SQLITE_OPEN_NO_MUTEX)
+        .map_err(|e| AppError::External(format!("Cannot open SQLite database: {e}")))
+}
+
+/// Run the statement on a blocking thread that owns `conn`, interrupting it
+/// at the query deadline. `query_only` guards a shared connection (the
+/// built-in database's pool) with `PRAGMA query_only` for reads instead of a
+/// read-only handle.
+async fn run_sqlite<C>(conn: C, query: Query, query_only: bool) -> Result<QueryResult, AppError>
+where
+    C: Deref<Target = rusqlite::Connection> + Send + 'static,
+{
+    // Statements that could reach outside the database file.
+    match extract_first_keyword(&query.sql).as_deref() {
+        Some("ATTACH" | "DETACH") => {
+            return Err(AppError::Validation(
+                "Statement type 'ATTACH/DETACH' is not allowed".into(),
+            ))
+        }
+        Some("VACUUM") if query.sql.to_uppercase().contains("INTO") => {
+            return Err(AppError::Validation(
+                "Statement type 'VACUUM INTO' is not allowed".into(),
+            ))
+        }
+        _ => {}
+    }
+
+    let _ = conn.busy_timeout(SQLITE_BUSY_TIMEOUT);
+    let interrupt = conn.get_interrupt_handle();
+    let guard = query_only && !query.write;
+    let mut task = tokio::task::spawn_blocking(move || {
+        if guard {
+            conn.execute_batch("PRAGMA query_only = ON")
+                .map_err(|e| AppError::Internal(format!("SQLite: {e}")))?;
+        }
+        let result = sqlite_statement(&conn, &query);
+        if guard {
+            let _ = conn.execute_batch("PRAGMA query_only = OFF");
+        }
+        result
+    });
+
+    tokio::select! {
+        biased;
+        joined = &mut task => {
+            joined.map_err(|e| AppError::Internal(format!("SQL query task failed: {e}")))?
+        }
+        _ = tokio::time::sleep(QUERY_TIMEOUT) => {
+            interrupt.interrupt();
+            let _ = (&mut task).await;
+            Err(timed_out())
+        }
+    }
+}
+
+fn sqlite_statement(conn: &rusqlite::Connection, query: &Query) -> Result<QueryResult, AppError> {
+    let err = |e: rusqlite::Error| AppError::External(format!("SQLite: {e}"));
+    let mut stmt = conn.prepare(&query.sql).map_err(err)?;
+    let params = rusqlite::params_from_iter(query.params.iter().map(sqlite_param));
+    if stmt.column_count() == 0 {
+        let affected = stmt.execute(params).map_err(err)?;
+        return Ok(affected_result(affected as u64));
+    }
+
+    let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+    let mut rows = RowCollector::new(query.limits);
+    let mut cursor = stmt.query(params).map_err(err)?;
+    while let Some(row) = cursor.next().map_err(err)? {
+        let values = (0..columns.len())
+            .map(|i| sqlite_value(row.get_ref(i)))
+            .collect();
+        if !rows.push(values) {
+            break;
+        }
+    }
+    Ok(rows.finish(columns))
+}
+
+fn sqlite_param(value: &Value) -> rusqlite::types::Value {
+    use rusqlite::types::Value as Sq;
+    match value {
+        Value::Null => Sq::Null,
+        Value::Bool(b) => Sq::Integer(i64::from(*b)),
+        Value::Number(n) => n
+            .as_i64()
+            .map(Sq::Integer)
+            .unwrap_or_else(|| Sq::Real(n.as_f64().unwrap_or_default())),
+        Value::String(s) => Sq::Text(s.clone()),
+        other => Sq::Text(other.to_string()),
+    }
+}
+
+fn sqlite_value(value: rusqlite::Result<rusqlite::types::ValueRef<'_>>) -> Value {
+    use rusqlite::types::ValueRef;
+    match value {
+        Ok(ValueRef::Integer(n)) => Value::from(n),
+        Ok(ValueRef::Real(f)) => Value::from(f),
+        Ok(ValueRef::Text(t)) => Value::String(String::from_utf8_lossy(t).into_owned()),
+        Ok(ValueRef::Blob(b)) => Value::String(format!("<blob {} bytes>", b.len())),
+        Ok(ValueRef::Null) | Err(_) => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn limits_default_and_cap_at_the_maximum() {
+        let limits = Limits::from_policy(&ToolPolicy::default());
+        assert_eq!(limits.max_rows, DEFAULT_MAX_ROWS as usize);
+        assert_eq!(limits.max_bytes, DEFAULT_MAX_RESULT_BYTES as usize);
+
+        let policy = ToolPolicy {
+            max_rows: Some(MAX_SQL_ROWS * 10),
+            max_result_bytes: Some(100),
+            ..Default::default()
+        };
+        let limits = Limits::from_policy(&policy);
+        assert_eq!(limits.max_rows, MAX_SQL_ROWS as usize);
+        assert_eq!(limits.max_bytes, 100);
+    }
+
+    #[test]
+    fn collector_truncates_at_either_limit() {
+        let mut rows = RowCollector::new(Limits {
+            max_rows: 2,
+            max_bytes: 1024,
+        });
+        assert!(rows.push(vec![json!(1)]));
+        assert!(rows.push(vec![json!(2)]));
+        assert!(!rows.push(vec![json!(3)]));
+        let qr = rows.finish(vec!["n".into()]);
+        assert_eq!((qr.row_count, qr.truncated), (2, true));
+
+        let mut rows = RowCollector::new(Limits {
+            max_rows: 10,
+            max_bytes: 12,
+        });
+        assert!(rows.push(vec![json!("abcdef")]));
+        assert!(!rows.push(vec![json!("abcdef")]));
+        assert!(rows.finish(vec!["s".into()]).truncated);
+    }
+
+    #[test]
+    fn classifies_writes() {
+        assert!(!is_write("SELECT * FROM t WHERE a = 1"));
+        assert!(!is_write("PRAGMA table_info(t)"));
+        assert!(is_write("PRAGMA journal_mode = DELETE"));
+        assert!(is_write("UPDATE t SET a = 1"));
+        assert!(is_write(
+            "WITH d AS (DELETE FROM t RETURNING *) SELECT * FROM d"
+        ));
+    }
+
+    #[test]
+    fn decodes_binary_numeric() {
+        // 12345.678: weight 1, digits [1, 2345, 6780], dscale 3.
+        let raw = [0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1A, 0x7C];
+        assert_eq!(decode_numeric(&raw).as_deref(), Some("12345.678"));
+        // -0.05: weight -1, digits [500], dscale 2.
+        let raw = [0, 1, 0xFF, 0xFF, 0x40, 0, 0, 2, 0x01, 0xF4];
+        assert_eq!(decode_numeric(&raw).as_deref(), Some("-0.05"));
+        assert_eq!(
+            decode_numeric(&[0, 0, 0, 0, 0xC0, 0, 0, 0]).as_deref(),
+            Some("NaN")
+        );
+    }
+
+    #[tokio::test]
+    async fn sqlite_reads_bind_params_and_cap_rows() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, name TEXT);
+             INSERT INTO t VALUES (1, 'a'), (2, 'b'), (3, 'c');",
+        )
+        .unwrap();
+        let query = Query {
+            sql: "SELECT id, name FROM t WHERE id >= ? ORDER BY id".into(),
+            params: vec![json!(2)],
+            write: false,
+            limits: Limits {
+                max_rows: 1,
+                max_bytes: 1024,
+            },
+        };
+        let qr = run_sqlite(Box::new(conn), query, false).await.unwrap();
+        assert_eq!(qr.columns, vec!["id", "name"]);
+        assert_eq!(qr.rows, vec![vec![json!(2), json!("b")]]);
+        assert!(qr.truncated);
+    }
+}
@@ -0,0 +1,373 @@
+//! Sanitized app-state snapshot for support escalations.
+//!
+//! [`capture`] gathers what support asks for first when someone reports a
+//! stuck or misbehaving app: row counts per table, the running and queued
+//! executions, the background jobs, the circuit-breaker states and the app
+//! settings. A setting is redacted when its key names a credential or its
+//! value looks like one; every other value still goes through
+//! [`sanitize_secrets`], so the snapshot can be attached to a ticket as is.
+//!
+//! [`replay`] is the other half: it loads a snapshot into a (test) database
+//! -- settings, stub personas and the execution rows -- and rebuilds the
+//! in-memory concurrency tracker and circuit breaker, so a reported state
+//! can be reproduced in a unit test.
+
+use std::collections::BTreeMap;
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::db::repos::core::settings;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::error::AppError;
+use crate::utils::sanitization::{key_is_secret, sanitize_secrets, value_looks_secret};
+
+use super::failover::{ProviderCircuitBreaker, ProviderCircuitState};
+use super::job_registry::BackgroundJobStatus;
+use super::provider::EngineKind;
+use super::queue::{ConcurrencyTracker, ExecutionPriority};
+use super::ExecutionEngine;
+
+/// Placeholder for a redacted setting value.
+pub const REDACTED: &str = "[redacted]";
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct StateSnapshot {
+    /// ISO 8601 capture time.
+    pub captured_at: String,
+    pub app_version: String,
+    /// Row count per table (SQLite internal tables excluded).
+    #[ts(type = "Record<string, number>")]
+    pub table_counts: BTreeMap<String, i64>,
+    /// App settings, sorted by key.
+    pub settings: Vec<SnapshotSetting>,
+    pub running_executions: Vec<SnapshotExecution>,
+    /// Queued executions, longest-waiting first.
+    pub queued_executions: Vec<SnapshotExecution>,
+    #[ts(type = "number")]
+    pub global_max_concurrent: usize,
+    /// ISO 8601 end of the provider-quota cooldown, when one is active.
+    pub quota_cooldown_until: Option<String>,
+    pub resource_throttled: bool,
+    pub background_jobs: Vec<BackgroundJobStatus>,
+    pub circuit_breakers: Vec<ProviderCircuitState>,
+    pub circuit_global_paused: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotSetting {
+    pub key: String,
+    pub value: String,
+    /// True when the value was replaced or masked; replay skips these.
+    pub redacted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotExecution {
+    pub execution_id: String,
+    pub persona_id: String,
+    /// The persona's `max_concurrent` at capture time.
+    pub persona_max_concurrent: i32,
+    /// "low" | "normal" | "urgent" (queued executions only).
+    pub priority: Option<String>,
+    /// Seconds spent in the queue so far (queued executions only).
+    #[ts(type = "number | null")]
+    pub waited_secs: Option<u64>,
+}
+
+/// In-memory engine state rebuilt by [`replay`].
+pub struct ReplayedState {
+    pub tracker: ConcurrencyTracker,
+    pub circuit_breaker: ProviderCircuitBreaker,
+}
+
+/// Capture a snapshot of the live app.
+pub async fn capture(
+    pool: &DbPool,
+    engine: &ExecutionEngine,
+    jobs: Vec<BackgroundJobStatus>,
+) -> Result<StateSnapshot, AppError> {
+    // Copy the tracker so the DB reads below don't hold up admission.
+    let tracker = engine.tracker().lock().await.clone();
+    capture_from(pool, &tracker, &engine.circuit_breaker, jobs)
+}
+
+/// Build a snapshot from explicit engine state; [`capture`] passes the live
+/// tracker and breaker.
+pub fn capture_from(
+    pool: &DbPool,
+    tracker: &ConcurrencyTracker,
+    circuit_breaker: &ProviderCircuitBreaker,
+    background_jobs: Vec<BackgroundJobStatus>,
+) -> Result<StateSnapshot, AppError> {
+    let table_counts = table_counts(pool)?;
+
+    let mut settings: Vec<SnapshotSetting> = settings::get_by_prefix(pool, "")?
+        .into_iter()
+        .map(|(key, value)| sanitize_setting(key, value))
+        .collect();
+    settings.sort_by(|a, b| a.key.cmp(&b.key));
+
+    let running_executions = tracker
+        .running_executions()
+        .into_iter()
+        .map(|(persona_id, execution_id)| SnapshotExecution {
+            persona_max_concurrent: persona_max_concurrent(pool, &persona_id),
+            execution_id,
+            persona_id,
+            priority: None,
+            waited_secs: None,
+        })
+        .collect();
+    let queued_executions = tracker
+        .queued_longer_than(std::time::Duration::ZERO)
+        .into_iter()
+        .map(|q| SnapshotExecution {
+            execution_id: q.execution_id,
+            persona_id: q.persona_id,
+            persona_max_concurrent: q.persona_max_concurrent,
+            priority: Some(priority_label(q.priority)),
+            waited_secs: Some(q.enqueued_at.elapsed().as_secs()),
+        })
+        .collect();
+
+    let breakers = circuit_breaker.get_status();
+
+    Ok(StateSnapshot {
+        captured_at: chrono::Utc::now().to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        table_counts,
+        settings,
+        running_executions,
+        queued_executions,
+        global_max_concurrent: tracker.global_max_concurrent(),
+        quota_cooldown_until: tracker.quota_cooldown_until().map(|t| t.to_rfc3339()),
+        resource_throttled: tracker.resource_throttled(),
+        background_jobs,
+        circuit_breakers: breakers.providers,
+        circuit_global_paused: breakers.global_paused,
+    })
+}
+
+/// Load a snapshot into `pool` (meant for a test DB) and rebuild the engine
+/// state around it.
+///
+/// Non-redacted settings are written as captured. Every persona referenced
+/// by an execution gets a stub row if it doesn't exist, and each execution
+/// gets a `running` / `queued` row. Circuit breakers are replayed as the
+/// captured number of consecutive failures, which re-opens the ones that
+/// were open; trip history and cooldown timers start fresh.
+pub fn replay(pool: &DbPool, snapshot: &StateSnapshot) -> Result<ReplayedState, AppError> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let mut conn = pool.get()?;
+    let tx = conn.transaction()?;
+    for setting in snapshot.settings.iter().filter(|s| !s.redacted) {
+        tx.execute(
+            "INSERT OR REPLACE INTO app_settings (key, value, updated_at) VALUES (?1, ?2, ?3)",
+            params![setting.key, setting.value, now],
+        )?;
+    }
+    let executions = snapshot
+        .running_executions
+        .iter()
+        .map(|e| (e, "running"))
+        .chain(snapshot.queued_executions.iter().map(|e| (e, "queued")));
+    for (exec, status) in executions {
+        tx.execute(
+            "INSERT OR IGNORE INTO personas
+             (id, name, system_prompt, max_concurrent, created_at, updated_at)
+             VALUES (?1, ?2, '', ?3, ?4, ?4)",
+            params![
+                exec.persona_id,
+                format!("Snapshot persona {}", exec.persona_id),
+                exec.persona_max_concurrent,
+                now
+            ],
+        )?;
+        let started_at = (status == "running").then(|| now.clone());
+        tx.execute(
+            "INSERT OR IGNORE INTO persona_executions
+             (id, persona_id, status, started_at, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![exec.execution_id, exec.persona_id, status, started_at, now],
+        )?;
+    }
+    tx.commit()?;
+
+    let mut tracker = ConcurrencyTracker::new();
+    tracker.set_global_max_concurrent(snapshot.global_max_concurrent);
+    for exec in &snapshot.running_executions {
+        tracker.add_running(&exec.persona_id, &exec.execution_id);
+    }
+    if let Some(until) = snapshot
+        .quota_cooldown_until
+        .as_deref()
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+    {
+        tracker.set_quota_cooldown(until.with_timezone(&chrono::Utc));
+    }
+    tracker.set_resource_throttled(snapshot.resource_throttled);
+    for exec in &snapshot.queued_executions {
+        let priority = exec
+            .priority
+            .as_deref()
+            .and_then(|p| serde_json::from_value(serde_json::Value::from(p)).ok())
+            .unwrap_or_default();
+        tracker.admit(
+            &exec.persona_id,
+            &exec.execution_id,
+            exec.persona_max_concurrent,
+            priority,
+        );
+    }
+
+    let circuit_breaker = ProviderCircuitBreaker::new();
+    for state in &snapshot.circuit_breakers {
+        let Some(kind) = EngineKind::from_str_exact(&state.provider) else {
+            continue;
+        };
+        for _ in 0..state.consecutive_failures {
+            circuit_breaker.record_failure(kind);
+        }
+    }
+
+    Ok(ReplayedState {
+        tracker,
+        circuit_breaker,
+    })
+}
+
+fn table_counts(pool: &DbPool) -> Result<BTreeMap<String, i64>, AppError> {
+    let conn = pool.get()?;
+    let mut stmt = conn.prepare(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+    )?;
+    let tables = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut counts = BTreeMap::new();
+    for table in tables {
+        let sql = format!("SELECT COUNT(*) FROM \"{}\"", table.replace('"', "\"\""));
+        // Virtual tables whose module isn't loaded can't be counted; skip them.
+        if let Ok(count) = conn.query_row(&sql, [], |row| row.get::<_, i64>(0)) {
+            counts.insert(table, count);
+        }
+    }
+    Ok(counts)
+}
+
+/// Redact credentials outright and mask anything secret-looking in the
+/// rest. Errs on the side of redacting: a key that merely mentions "token"
+/// loses its value too.
+fn sanitize_setting(key: String, value: String) -> SnapshotSetting {
+    let secret = settings_keys::audit_category(&key) == Some("api_keys")
+        || key_is_secret(&key)
+        || value_looks_secret(&value);
+    if secret {
+        return SnapshotSetting {
+            key,
+            value: REDACTED.into(),
+            redacted: true,
+        };
+    }
+    let sanitized = sanitize_secrets(&value);
+    SnapshotSetting {
+        redacted: sanitized != value,
+        key,
+        value: sanitized,
+    }
+}
+
+fn persona_max_concurrent(pool: &DbPool, persona_id: &str) -> i32 {
+    pool.get()
+        .ok()
+        .and_then(|conn| {
+            conn.query_row(
+                "SELECT max_concurrent FROM personas WHERE id = ?1",
+                params![persona_id],
+                |row| row.get(0),
+            )
+            .ok()
+        })
+        .unwrap_or(1)
+}
+
+fn priority_label(priority: ExecutionPriority) -> String {
+    serde_json::to_value(priority)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_else(|| "normal".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_test_db;
+
+    #[test]
+    fn snapshot_is_sanitized_and_replays_into_a_fresh_db() {
+        let pool = init_test_db().unwrap();
+        settings::set(&pool, settings_keys::OPENAI_API_KEY, "sk-live-abcdef").unwrap();
+        settings::set(&pool, settings_keys::MAX_PARALLEL_EXECUTIONS, "3").unwrap();
+
+        let mut tracker = ConcurrencyTracker::new();
+        tracker.set_global_max_concurrent(3);
+        tracker.add_running("p-1", "exec-running");
+        tracker.admit("p-1", "exec-queued", 1, ExecutionPriority::Urgent);
+        let breaker = ProviderCircuitBreaker::new();
+        breaker.record_failure(EngineKind::ClaudeCode);
+
+        let snapshot = capture_from(&pool, &tracker, &breaker, Vec::new()).unwrap();
+        assert!(snapshot.table_counts.contains_key("personas"));
+        let key = snapshot
+            .settings
+            .iter()
+            .find(|s| s.key == settings_keys::OPENAI_API_KEY)
+            .unwrap();
+        assert!(key.redacted);
+        assert_eq!(key.value, REDACTED);
+        assert_eq!(snapshot.running_executions.len(), 1);
+        assert_eq!(
+            snapshot.queued_executions[0].priority.as_deref(),
+            Some("urgent")
+        );
+        assert_eq!(snapshot.circuit_breakers[0].consecutive_failures, 1);
+
+        // Round-trip through JSON, as a snapshot attached to a ticket would.
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot: StateSnapshot = serde_json::from_str(&json).unwrap();
+
+        let test_db = init_test_db().unwrap();
+        let replayed = replay(&test_db, &snapshot).unwrap();
+        assert_eq!(
+            settings::get(&test_db, settings_keys::MAX_PARALLEL_EXECUTIONS).unwrap(),
+            Some("3".into())
+        );
+        assert_eq!(
+            settings::get(&test_db, settings_keys::OPENAI_API_KEY).unwrap(),
+            None
+        );
+        assert_eq!(replayed.tracker.running_ids("p-1"), vec!["exec-running"]);
+        assert_eq!(replayed.tracker.queue_depth("p-1"), 1);
+        let status = replayed.circuit_breaker.get_status();
+        assert_eq!(status.providers[0].consecutive_failures, 1);
+
+        let conn = test_db.get().unwrap();
+        let statuses: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM persona_executions WHERE persona_id = 'p-1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(statuses, 2);
+    }
+}
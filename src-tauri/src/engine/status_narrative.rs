@@ -0,0 +1,173 @@
+//! Plain-language summary of current app state.
+//!
+//! Backs `get_status_narrative` (screen readers, status announcements) and
+//! the system-tray tooltip. Counts are read from the database plus the
+//! scheduler flag; the wording is produced by the pure [`compose`] so it can
+//! be tested without a pool.
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use crate::db::repos::communication::{events as event_repo, manual_reviews as review_repo};
+use crate::db::repos::execution::{executions as exec_repo, healing as healing_repo};
+use crate::db::DbPool;
+
+/// Window for "recent failures".
+const FAILURE_WINDOW_HOURS: i64 = 24;
+
+/// Raw counts the narrative is built from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusCounts {
+    pub running: usize,
+    pub queued: usize,
+    pub pending_reviews: i64,
+    pub failed_recent: i64,
+    pub open_healing_issues: usize,
+    pub dead_letter_events: i64,
+    pub scheduler_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct StatusNarrative {
+    /// One short line, e.g. "2 running · 1 review waiting · 3 need attention".
+    pub headline: String,
+    /// Full sentences suitable for a screen reader.
+    pub summary: String,
+    /// One plain sentence per problem that needs the user.
+    pub attention: Vec<String>,
+    pub needs_attention: bool,
+}
+
+/// Gather counts. Individual read failures count as zero so the narrative is
+/// always available.
+pub fn collect(pool: &DbPool, scheduler_active: bool) -> StatusCounts {
+    let since = (chrono::Utc::now() - chrono::Duration::hours(FAILURE_WINDOW_HOURS)).to_rfc3339();
+    let (running, queued) = exec_repo::get_running(pool)
+        .map(|rows| {
+            let running = rows.iter().filter(|e| e.status == "running").count();
+            (running, rows.len() - running)
+        })
+        .unwrap_or_default();
+    StatusCounts {
+        running,
+        queued,
+        pending_reviews: review_repo::get_pending_count(pool, None).unwrap_or(0),
+        failed_recent: exec_repo::count_failed_since(pool, &since).unwrap_or(0),
+        open_healing_issues: healing_repo::get_all(pool, None, Some("open"))
+            .map(|v| v.len())
+            .unwrap_or(0),
+        dead_letter_events: event_repo::count_dead_letter(pool).unwrap_or(0),
+        scheduler_active,
+    }
+}
+
+pub fn build(pool: &DbPool, scheduler_active: bool) -> StatusNarrative {
+    compose(&collect(pool, scheduler_active))
+}
+
+fn counted(n: i64, singular: &str, plural: &str) -> String {
+    format!("{n} {}", if n == 1 { singular } else { plural })
+}
+
+pub fn compose(c: &StatusCounts) -> StatusNarrative {
+    let mut attention = Vec::new();
+    if c.failed_recent > 0 {
+        attention.push(format!(
+            "{} in the last {FAILURE_WINDOW_HOURS} hours.",
+            counted(c.failed_recent, "run failed", "runs failed")
+        ));
+    }
+    if c.open_healing_issues > 0 {
+        attention.push(format!(
+            "{} open.",
+            counted(c.open_healing_issues as i64, "healing issue is", "healing issues are")
+        ));
+    }
+    if c.dead_letter_events > 0 {
+        attention.push(format!(
+            "{} could not be delivered.",
+            counted(c.dead_letter_events, "event", "events")
+        ));
+    }
+
+    let mut sentences = Vec::new();
+    let mut headline = Vec::new();
+    match (c.running, c.queued) {
+        (0, 0) => {
+            sentences.push("No agents are running.".to_string());
+            headline.push("Idle".to_string());
+        }
+        (r, q) => {
+            let mut s = counted(r as i64, "agent is running", "agents are running");
+            if q > 0 {
+                s.push_str(&format!(" and {q} queued"));
+            }
+            sentences.push(format!("{s}."));
+            headline.push(format!("{r} running"));
+            if q > 0 {
+                headline.push(format!("{q} queued"));
+            }
+        }
+    }
+    if c.pending_reviews > 0 {
+        sentences.push(format!(
+            "{} for your decision.",
+            counted(c.pending_reviews, "review is waiting", "reviews are waiting")
+        ));
+        headline.push(counted(c.pending_reviews, "review waiting", "reviews waiting"));
+    }
+    if !c.scheduler_active {
+        sentences.push("The scheduler is paused.".to_string());
+        headline.push("scheduler paused".to_string());
+    }
+    if attention.is_empty() {
+        sentences.push("Nothing needs attention.".to_string());
+    } else {
+        sentences.push(format!("Needs attention: {}", attention.join(" ")));
+        headline.push(format!("{} need attention", attention.len()));
+    }
+
+    StatusNarrative {
+        headline: headline.join(" · "),
+        summary: sentences.join(" "),
+        needs_attention: !attention.is_empty(),
+        attention,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composes_idle_and_busy_states() {
+        let idle = compose(&StatusCounts {
+            scheduler_active: true,
+            ..Default::default()
+        });
+        assert_eq!(idle.headline, "Idle");
+        assert_eq!(idle.summary, "No agents are running. Nothing needs attention.");
+        assert!(!idle.needs_attention);
+
+        let busy = compose(&StatusCounts {
+            running: 1,
+            queued: 2,
+            pending_reviews: 3,
+            failed_recent: 1,
+            dead_letter_events: 2,
+            ..Default::default()
+        });
+        assert_eq!(
+            busy.headline,
+            "1 running · 2 queued · 3 reviews waiting · scheduler paused · 2 need attention"
+        );
+        assert_eq!(
+            busy.summary,
+            "1 agent is running and 2 queued. 3 reviews are waiting for your decision. \
+             The scheduler is paused. Needs attention: 1 run failed in the last 24 hours. \
+             2 events could not be delivered."
+        );
+        assert_eq!(busy.attention.len(), 2);
+    }
+}
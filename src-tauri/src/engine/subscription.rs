@@ -20,7 +20,7 @@ use futures_util::FutureExt;
 use tauri::{AppHandle, Emitter, Manager};
 
 use super::event_registry::event_name;
-use crate::db::DbPool;
+use crate::db::{pool_budget, DbPool};
 use crate::engine::background::{SchedulerState, SubscriptionCrashEvent};
 use crate::engine::inflight_guard::InflightGuard;
 use crate::engine::ExecutionEngine;
@@ -298,6 +298,28 @@ pub struct ZombieExecutionSubscription {
     pub app: AppHandle,
 }
 
+/// Queue-age monitor: reports executions waiting in the admission queue past
+/// `queue_stuck_wait_secs` (see `queue_monitor`).
+pub struct QueueAgeMonitorSubscription {
+    pub pool: DbPool,
+    pub app: AppHandle,
+    pub engine: Arc<ExecutionEngine>,
+}
+
+/// Per-persona SLO monitor: re-measures every enabled `persona_slos` target
+/// and opens/resolves `slo_breach` healing issues (see `slo_monitor`).
+pub struct SloMonitorSubscription {
+    pub pool: DbPool,
+    pub app: AppHandle,
+}
+
+/// Hourly refresh of the daily metrics snapshots and their weekly/monthly
+/// rollups, catching up on days missed while the app was closed (see
+/// `db::repos::execution::metrics_rollups`).
+pub struct MetricsSnapshotSubscription {
+    pub pool: DbPool,
+}
+
 /// Periodic sweep that reverts `auto_fix_pending` healing issues older than
 /// [`crate::db::repos::execution::healing::AUTO_FIX_PENDING_TTL_MINUTES`]
 /// back to `open`. Without this, an app crash or no-further-failures
@@ -308,6 +330,35 @@ pub struct HealingTtlSubscription {
     pub pool: DbPool,
 }
 
+/// Opt-in fleet learning: exchanges anonymized healing backoff patterns with
+/// the shared fleet endpoint once a day. Inert unless the user enabled
+/// `fleet_pattern_sharing_enabled` and an endpoint is configured; the 24h gate
+/// lives in `fleet_learning::fleet_learning_tick`.
+pub struct FleetLearningSubscription {
+    pub pool: DbPool,
+}
+
+/// Budget month-boundary reset: once per UTC billing period, re-enables
+/// personas that budget enforcement paused last month and clears last month's
+/// alert state. Cheap no-op (one settings read) on every other tick.
+pub struct BudgetPeriodSubscription {
+    pub pool: DbPool,
+}
+
+/// Orphaned CLI process reaper: kills engine-spawned CLIs whose app instance
+/// died or that fell out of `child_pids`. See [`super::process_reaper`].
+pub struct OrphanProcessReaperSubscription {
+    pub pool: DbPool,
+    pub engine: Arc<ExecutionEngine>,
+}
+
+/// Scheduled database backup: snapshots `personas.db` into `backups/` when
+/// `auto_backup_enabled` is on and `auto_backup_interval_hours` has elapsed
+/// (gate lives in `db::backup::run_auto_backup`).
+pub struct AutoBackupSubscription {
+    pub pool: DbPool,
+}
+
 /// Performance digest subscription: periodically generates and delivers
 /// a performance digest summarizing agent success rates, cost trends,
 /// top failures, credential health, and anomalies.
@@ -316,6 +367,12 @@ pub struct DigestSubscription {
     pub app: AppHandle,
 }
 
+/// Weekly fleet hygiene review. See [`super::fleet_hygiene`].
+pub struct FleetHygieneSubscription {
+    pub pool: DbPool,
+    pub app: AppHandle,
+}
+
 /// Cloud webhook relay: polls cloud trigger firings and injects them into
 /// the local event bus so 3rd-party webhooks reach the desktop app.
 pub struct CloudWebhookRelaySubscription {
@@ -380,6 +437,9 @@ impl ReactiveSubscription for EventBusSubscription {
     }
 }
 
+/// How long a scheduler tick waits for a background connection permit.
+const SCHEDULER_PERMIT_TIMEOUT: Duration = Duration::from_secs(5);
+
 #[async_trait::async_trait]
 impl ReactiveSubscription for TriggerSchedulerSubscription {
     fn name(&self) -> &'static str {
@@ -398,6 +458,15 @@ impl ReactiveSubscription for TriggerSchedulerSubscription {
         let scheduler = self.scheduler.clone();
         let pool = self.pool.clone();
         run_blocking_tick(move || {
+            // Stay out of the connections reserved for interactive commands;
+            // a skipped tick is picked up 5s later (or by missed-fire backfill).
+            let Some(_permit) = pool_budget::acquire_blocking(
+                pool_budget::Subsystem::Scheduler,
+                SCHEDULER_PERMIT_TIMEOUT,
+            ) else {
+                tracing::debug!("Trigger scheduler tick skipped: background DB budget exhausted");
+                return;
+            };
             super::background::trigger_scheduler_tick(&scheduler, &pool)
         })
         .await;
@@ -461,6 +530,9 @@ impl ReactiveSubscription for RotationSubscription {
         super::rotation::evaluate_due_rotations(&self.pool, &self.app).await;
         super::rotation::evaluate_credential_events(&self.pool).await;
         super::rotation::detect_anomalies(&self.pool, &self.app).await;
+        super::rotation::warn_expiring_credentials(&self.pool, &self.app).await;
+        crate::commands::credentials::intelligence::scan_usage_anomalies(&self.pool, &self.app)
+            .await;
     }
 }
 
@@ -1003,6 +1075,83 @@ impl ReactiveSubscription for ZombieExecutionSubscription {
     }
 }
 
+#[async_trait::async_trait]
+impl ReactiveSubscription for QueueAgeMonitorSubscription {
+    fn name(&self) -> &'static str {
+        "queue_age_monitor"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(60)
+    }
+
+    fn idle_interval(&self) -> Duration {
+        Duration::from_secs(300)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        Duration::from_secs(120)
+    }
+
+    async fn tick(&self) {
+        super::queue_monitor::queue_age_tick(&self.pool, &self.app, &self.engine).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactiveSubscription for SloMonitorSubscription {
+    fn name(&self) -> &'static str {
+        "slo_monitor"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(300)
+    }
+
+    fn idle_interval(&self) -> Duration {
+        Duration::from_secs(900)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        Duration::from_secs(180)
+    }
+
+    async fn tick(&self) {
+        let pool = self.pool.clone();
+        let app = self.app.clone();
+        run_blocking_tick(move || super::slo_monitor::slo_tick(&pool, &app)).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactiveSubscription for MetricsSnapshotSubscription {
+    fn name(&self) -> &'static str {
+        "metrics_snapshot_refresh"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(3600)
+    }
+
+    fn idle_interval(&self) -> Duration {
+        Duration::from_secs(3600)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        Duration::from_secs(300)
+    }
+
+    async fn tick(&self) {
+        let pool = self.pool.clone();
+        run_blocking_tick(move || {
+            if let Err(e) = crate::db::repos::execution::metrics_rollups::refresh_recent(&pool) {
+                tracing::warn!(error = %e, "Metrics snapshot refresh failed");
+            }
+        })
+        .await;
+    }
+}
+
 #[async_trait::async_trait]
 impl ReactiveSubscription for HealingTtlSubscription {
     fn name(&self) -> &'static str {
@@ -1040,6 +1189,113 @@ impl ReactiveSubscription for HealingTtlSubscription {
     }
 }
 
+#[async_trait::async_trait]
+impl ReactiveSubscription for FleetLearningSubscription {
+    fn name(&self) -> &'static str {
+        "fleet_learning"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(3600) // hourly — the 24h gate inside the tick is the real cadence
+    }
+
+    fn initial_delay(&self) -> Duration {
+        // Stay off the network during startup.
+        Duration::from_secs(300)
+    }
+
+    async fn tick(&self) {
+        super::fleet_learning::fleet_learning_tick(&self.pool).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactiveSubscription for BudgetPeriodSubscription {
+    fn name(&self) -> &'static str {
+        "budget_period_reset"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(600) // 10 min — bounds how late a paused persona resumes
+    }
+
+    fn idle_interval(&self) -> Duration {
+        Duration::from_secs(1800)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        // First tick doubles as the catch-up when the app was closed over a
+        // month boundary.
+        Duration::from_secs(30)
+    }
+
+    async fn tick(&self) {
+        let pool = self.pool.clone();
+        run_blocking_tick(move || {
+            if let Err(e) = super::budget::monthly_reset_tick(&pool) {
+                tracing::warn!(error = %e, "budget period reset failed");
+            }
+        })
+        .await;
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactiveSubscription for OrphanProcessReaperSubscription {
+    fn name(&self) -> &'static str {
+        "orphan_process_reaper"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(300)
+    }
+
+    fn idle_interval(&self) -> Duration {
+        Duration::from_secs(900)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        // Startup sweep for CLIs left behind by a crashed previous session.
+        Duration::from_secs(15)
+    }
+
+    async fn tick(&self) {
+        super::process_reaper::reap_orphans(&self.pool, &self.engine.child_pids).await;
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactiveSubscription for AutoBackupSubscription {
+    fn name(&self) -> &'static str {
+        "auto_backup"
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(900)
+    }
+
+    fn idle_interval(&self) -> Duration {
+        Duration::from_secs(3600)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        // Boot already took a pre-migration snapshot; don't stack another on it.
+        Duration::from_secs(600)
+    }
+
+    async fn tick(&self) {
+        let pool = self.pool.clone();
+        run_blocking_tick(move || match crate::db::backup::run_auto_backup(&pool) {
+            Ok(Some(info)) => {
+                tracing::info!(path = %info.path, size_bytes = info.size_bytes, "scheduled database backup written")
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!(error = %e, "scheduled database backup failed"),
+        })
+        .await;
+    }
+}
+
 #[async_trait::async_trait]
 impl ReactiveSubscription for DigestSubscription {
     fn name(&self) -> &'static str {
@@ -1063,6 +1319,29 @@ impl ReactiveSubscription for DigestSubscription {
     }
 }
 
+#[async_trait::async_trait]
+impl ReactiveSubscription for FleetHygieneSubscription {
+    fn name(&self) -> &'static str {
+        "fleet_hygiene"
+    }
+
+    fn interval(&self) -> Duration {
+        // Check hourly whether the weekly review is due
+        Duration::from_secs(3600)
+    }
+
+    fn initial_delay(&self) -> Duration {
+        // Stay out of the way of startup work
+        Duration::from_secs(600)
+    }
+
+    async fn tick(&self) {
+        let pool = self.pool.clone();
+        let app = self.app.clone();
+        run_blocking_tick(move || super::fleet_hygiene::hygiene_tick(&pool, &app)).await;
+    }
+}
+
 #[async_trait::async_trait]
 impl ReactiveSubscription for CloudWebhookRelaySubscription {
     fn name(&self) -> &'static str {
@@ -1203,6 +1482,9 @@ const SLOW_TICK_THRESHOLD_DEN: u64 = 5;
 /// Registers itself as alive/dead in `SchedulerState` and emits a
 /// `subscription-crashed` Tauri event on every panic so the frontend can
 /// surface dead subscriptions immediately.
+///
+/// Reports every tick into the jobs registry ([`super::job_registry`]) and
+/// honours its pause / run-now controls.
 async fn run_single(
     sub: Box<dyn ReactiveSubscription>,
     scheduler: Arc<SchedulerState>,
@@ -1217,6 +1499,8 @@ async fn run_single(
     scheduler.mark_subscription_alive(name, active_interval.as_millis() as u64);
 
     let delay = sub.initial_delay();
+    let jobs = scheduler.jobs();
+    let job = jobs.register(name, active_interval, delay);
     if !delay.is_zero() {
         tracing::debug!(subscription = name, delay_secs = ?delay.as_secs(), "Delaying initial poll");
         tokio::time::sleep(delay).await;
@@ -1234,6 +1518,10 @@ async fn run_single(
         // the very next loop iteration (no lost wakeups). Dropping the losing
         // `interval.tick()` future does not disturb the interval's schedule —
         // the poll heartbeat cadence is unchanged.
+        //
+        // A run-now request from the jobs dashboard wakes the loop the same
+        // way, and is the only thing that ticks a paused job.
+        let mut manual_run = false;
         match wake {
             Some(notify) => {
                 tokio::select! {
@@ -1241,10 +1529,14 @@ async fn run_single(
                     _ = notify.notified() => {
                         tracing::trace!(subscription = name, "Push-wake signal received");
                     }
+                    _ = job.run_now_requested() => manual_run = true,
                 }
             }
             None => {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = job.run_now_requested() => manual_run = true,
+                }
             }
         }
         if !scheduler.is_running() {
@@ -1289,6 +1581,22 @@ async fn run_single(
             }
         }
 
+        let effective_interval = if has_idle_mode && !was_active {
+            idle_interval
+        } else {
+            active_interval
+        };
+        if job.is_paused() && !manual_run {
+            continue;
+        }
+        if manual_run {
+            tracing::info!(
+                subscription = name,
+                "Manual run requested from the jobs dashboard"
+            );
+        }
+
+        jobs.record_started(name);
         let tick_start = Instant::now();
 
         // Execute the tick within a tracing span for structured observability.
@@ -1323,7 +1631,7 @@ async fn run_single(
                 event_name::SUBSCRIPTION_CRASHED,
                 SubscriptionCrashEvent {
                     name: name.to_string(),
-                    panic_message: msg,
+                    panic_message: msg.clone(),
                     consecutive_panics,
                     timestamp: chrono::Utc::now().to_rfc3339(),
                 },
@@ -1331,23 +1639,29 @@ async fn run_single(
 
             // Apply backoff when panics exceed the threshold, to avoid
             // tight-looping on a persistently broken subscription.
+            let mut backoff = None;
             if consecutive_panics >= PANIC_BACKOFF_THRESHOLD {
                 let multiplier = PANIC_BACKOFF_MULTIPLIER
                     .saturating_pow(consecutive_panics - PANIC_BACKOFF_THRESHOLD + 1)
                     .min(PANIC_BACKOFF_MAX);
-                let effective = if has_idle_mode && !was_active {
-                    idle_interval
-                } else {
-                    active_interval
-                };
-                let backoff = effective * multiplier;
+                let delay = effective_interval * multiplier;
                 tracing::warn!(
                     subscription = name,
                     consecutive_panics,
-                    backoff_secs = backoff.as_secs(),
+                    backoff_secs = delay.as_secs(),
                     "Applying backoff after repeated panics"
                 );
-                tokio::time::sleep(backoff).await;
+                backoff = Some(delay);
+            }
+            jobs.record_finished(
+                name,
+                elapsed,
+                Some(msg),
+                effective_interval,
+                backoff.unwrap_or(effective_interval),
+            );
+            if let Some(delay) = backoff {
+                tokio::time::sleep(delay).await;
             }
             continue;
         }
@@ -1363,12 +1677,14 @@ async fn run_single(
         }
 
         // Use the current effective interval for overrun / slow-tick detection
-        let effective_interval = if has_idle_mode && !was_active {
-            idle_interval
-        } else {
-            active_interval
-        };
         scheduler.record_tick_latency(name, effective_interval, elapsed);
+        jobs.record_finished(
+            name,
+            elapsed,
+            None,
+            effective_interval,
+            effective_interval.saturating_sub(elapsed),
+        );
 
         let elapsed_ms = elapsed.as_millis() as u64;
         let interval_ms = effective_interval.as_millis() as u64;
@@ -1403,6 +1719,7 @@ async fn run_single(
         }
     }
     scheduler.mark_subscription_dead(name);
+    jobs.record_stopped(name);
     tracing::info!(subscription = name, "Subscription loop exited");
 }
 
@@ -7,6 +7,14 @@
 //! project's context map (incremental by default). The Chain Studio commits
 //! these as `SystemOpAutomation` rows (trigger → system op); the Context Map
 //! "Plan update" button creates a weekly schedule.
+//!
+//! Besides `schedule` and `event`, an automation can be fired by a `webhook`
+//! (`POST /webhook/<automation id>`, HMAC-signed with the `webhookSecret`
+//! param) or a `chain` (an execution of the persona named in `source_filter`
+//! finishing). Both publish a [`event_name::SYSTEM_OP_FIRED`] event carrying
+//! the automation id, and the bus loop runs the op like any other event
+//! automation — the webhook server and the completion path have no
+//! `AppHandle` to run it themselves.
 
 use serde_json::{json, Value};
 use tauri::AppHandle;
@@ -25,6 +33,14 @@ use super::event_registry::event_name;
 pub const OP_CONTEXT_SCAN: &str = "context_scan";
 pub const OP_MEMORY_REFLECTION: &str = "memory_reflection";
 pub const OP_HEALTH_INGEST: &str = "health_ingest";
+pub const OP_TEAM_PIPELINE: &str = "team_pipeline";
+
+/// Trigger kinds. `schedule` and `event` are matched by the bus loop directly;
+/// `webhook` and `chain` arrive as a [`event_name::SYSTEM_OP_FIRED`] event.
+pub const TRIGGER_SCHEDULE: &str = "schedule";
+pub const TRIGGER_EVENT: &str = "event";
+pub const TRIGGER_WEBHOOK: &str = "webhook";
+pub const TRIGGER_CHAIN: &str = "chain";
 
 /// Tauri event that asks the frontend to run a findings sweep + verification pass.
 ///
@@ -103,6 +119,14 @@ pub fn list_kinds() -> Vec<SystemOpKindMeta> {
             requires_project: false,
             requires_persona_or_team: false,
         },
+        SystemOpKindMeta {
+            kind: OP_TEAM_PIPELINE.to_string(),
+            label: "Run Team Pipeline".to_string(),
+            description: "Start a team's pipeline. Input comes from the `inputData` param, else the triggering event's payload."
+                .to_string(),
+            requires_project: false,
+            requires_persona_or_team: true,
+        },
     ]
 }
 
@@ -139,6 +163,7 @@ pub fn run_op(
         OP_HEALTH_INGEST => run_health_ingest(app, params, source),
         OP_SIGNAL_DISPATCH_RUNNER => run_signal_dispatch(app, params, source, "runner"),
         OP_SIGNAL_DISPATCH_FLEET => run_signal_dispatch(app, params, source, "fleet"),
+        OP_TEAM_PIPELINE => run_team_pipeline(app, params),
         other => Err(AppError::Validation(format!("Unknown system op: {other}"))),
     }
 }
@@ -236,6 +261,40 @@ fn run_memory_reflection_op(pool: &DbPool, params: &Value) -> Result<String, App
     ))
 }
 
+/// Start a team pipeline run. Params: `{"teamId": "…", "inputData": …}`.
+/// Without `inputData`, an event-fired run takes the triggering event's payload
+/// (the webhook body, the finished execution's outcome, or the bus event's own
+/// payload) as its input.
+fn run_team_pipeline(app: &AppHandle, params: &Value) -> Result<String, AppError> {
+    use tauri::Manager;
+
+    let team_id = params
+        .get("teamId")
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| AppError::Validation("team_pipeline requires a teamId param".into()))?;
+    let input_data = match params.get("inputData") {
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(Value::Null) | None => params
+            .get("_event")
+            .and_then(|e| e.get("payload"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string),
+        Some(other) => Some(other.to_string()),
+    };
+    let state = app
+        .try_state::<std::sync::Arc<crate::AppState>>()
+        .ok_or_else(|| AppError::Internal("app state is not available".into()))?;
+    let run_id = crate::commands::teams::teams::launch_team_pipeline(
+        state.inner(),
+        app.clone(),
+        team_id.to_string(),
+        input_data,
+        None,
+    )?;
+    Ok(format!("run_id={run_id}"))
+}
+
 fn run_context_scan(
     app: &AppHandle,
     pool: &DbPool,
@@ -338,6 +397,12 @@ pub fn dispatch_event_automations(app: &AppHandle, pool: &DbPool, events: &[Pers
     if events.is_empty() {
         return;
     }
+    for ev in events
+        .iter()
+        .filter(|e| e.event_type == event_name::SYSTEM_OP_FIRED)
+    {
+        run_fired_automation(app, pool, ev);
+    }
     let autos = match repo::list_enabled_event_automations(pool) {
         Ok(a) => a,
         Err(e) => {
@@ -361,25 +426,7 @@ pub fn dispatch_event_automations(app: &AppHandle, pool: &DbPool, events: &[Pers
                     continue;
                 }
             }
-            let mut params: Value = serde_json::from_str(&a.params_json).unwrap_or_else(|_| json!({}));
-            // Thread the TRIGGERING EVENT into the op's params under `_event`.
-            // Without this an event-fired op knows only its static config — a
-            // dispatch op bound to `signal.raised` would have no idea WHICH finding
-            // fired it. Reserved key (underscore) so it can't collide with an op's
-            // own params; ops that don't care simply ignore it.
-            if let Some(obj) = params.as_object_mut() {
-                obj.insert(
-                    "_event".to_string(),
-                    json!({
-                        "event_type": ev.event_type,
-                        "source_id": ev.source_id,
-                        "source_type": ev.source_type,
-                        "project_id": ev.project_id,
-                        "use_case_id": ev.use_case_id,
-                        "payload": ev.payload,
-                    }),
-                );
-            }
+            let params = params_with_event(&a.params_json, ev);
             let (status, detail) = match run_op(app, pool, &a.op_kind, &params, "event") {
                 Ok(d) => ("ok", d),
                 Err(e) => ("failed", e.to_string()),
@@ -390,6 +437,132 @@ pub fn dispatch_event_automations(app: &AppHandle, pool: &DbPool, events: &[Pers
     }
 }
 
+/// An automation's params with the TRIGGERING EVENT threaded in under
+/// `_event`. Without this an event-fired op knows only its static config — a
+/// dispatch op bound to `signal.raised` would have no idea WHICH finding fired
+/// it. Reserved key (underscore) so it can't collide with an op's own params;
+/// ops that don't care simply ignore it.
+fn params_with_event(params_json: &str, ev: &PersonaEvent) -> Value {
+    let mut params: Value = serde_json::from_str(params_json).unwrap_or_else(|_| json!({}));
+    if let Some(obj) = params.as_object_mut() {
+        obj.insert(
+            "_event".to_string(),
+            json!({
+                "event_type": ev.event_type,
+                "source_id": ev.source_id,
+                "source_type": ev.source_type,
+                "project_id": ev.project_id,
+                "use_case_id": ev.use_case_id,
+                "payload": ev.payload,
+            }),
+        );
+    }
+    params
+}
+
+/// Run the webhook/chain automation a [`event_name::SYSTEM_OP_FIRED`] event
+/// names in its `source_id`. Skipped when it was disabled or deleted after the
+/// event was published.
+fn run_fired_automation(app: &AppHandle, pool: &DbPool, ev: &PersonaEvent) {
+    let Some(id) = ev.source_id.as_deref() else {
+        return;
+    };
+    let a = match repo::get(pool, id) {
+        Ok(a)
+            if a.enabled && matches!(a.trigger_kind.as_str(), TRIGGER_WEBHOOK | TRIGGER_CHAIN) =>
+        {
+            a
+        }
+        Ok(_) => return,
+        Err(e) => {
+            tracing::warn!(automation = %id, error = %e, "system_ops: fired automation not found");
+            return;
+        }
+    };
+    let params = params_with_event(&a.params_json, ev);
+    let (status, detail) = match run_op(app, pool, &a.op_kind, &params, &a.trigger_kind) {
+        Ok(d) => ("ok", d),
+        Err(e) => ("failed", e.to_string()),
+    };
+    let _ = repo::mark_run(pool, &a.id, status, Some(&detail), None);
+    tracing::info!(automation = %a.id, op = %a.op_kind, trigger = %a.trigger_kind, status, "system-op fired");
+}
+
+/// Publish the [`event_name::SYSTEM_OP_FIRED`] event that runs automation `id`
+/// on the next bus tick. `source_type` is the trigger kind that fired it.
+pub fn publish_fired(
+    pool: &DbPool,
+    id: &str,
+    source_type: &str,
+    payload: Option<String>,
+) -> Result<PersonaEvent, AppError> {
+    event_repo::publish(
+        pool,
+        crate::db::models::CreatePersonaEventInput {
+            event_type: event_name::SYSTEM_OP_FIRED.to_string(),
+            source_type: source_type.to_string(),
+            source_id: Some(id.to_string()),
+            target_persona_id: None,
+            project_id: None,
+            payload,
+            use_case_id: None,
+        },
+    )
+}
+
+/// Whether a chain automation's `condition` param (`any` | `success` |
+/// `failure`, default `any`) accepts an execution that ended in `status`.
+pub fn chain_condition_matches(params: &Value, status: &str) -> bool {
+    match params
+        .get("condition")
+        .and_then(|v| v.as_str())
+        .unwrap_or("any")
+    {
+        "success" => status == "completed",
+        "failure" => status == "failed",
+        _ => true,
+    }
+}
+
+/// Fire every enabled chain automation whose `source_filter` matches the
+/// persona whose execution just finished. Called from the chain-trigger
+/// evaluator, so a team pipeline can follow an agent the way a chained persona
+/// does. The payload is the finished execution's outcome.
+pub fn fire_chain_automations(
+    pool: &DbPool,
+    source_persona_id: &str,
+    execution_status: &str,
+    execution_output: Option<&str>,
+    execution_id: &str,
+) {
+    let autos = match repo::list_enabled_chain_automations(pool) {
+        Ok(a) => a,
+        Err(e) => {
+            tracing::error!(error = %e, "system_ops: chain-automation fetch failed");
+            return;
+        }
+    };
+    for a in autos {
+        let params: Value = serde_json::from_str(&a.params_json).unwrap_or_else(|_| json!({}));
+        let source_ok = a
+            .source_filter
+            .as_deref()
+            .is_some_and(|f| source_matches(f, Some(source_persona_id)));
+        if !source_ok || !chain_condition_matches(&params, execution_status) {
+            continue;
+        }
+        let payload = json!({
+            "source_persona_id": source_persona_id,
+            "execution_id": execution_id,
+            "status": execution_status,
+            "output": execution_output,
+        });
+        if let Err(e) = publish_fired(pool, &a.id, TRIGGER_CHAIN, Some(payload.to_string())) {
+            tracing::warn!(automation = %a.id, error = %e, "system_ops: failed to fire chain automation");
+        }
+    }
+}
+
 /// Simple `prefix*` / exact wildcard match used by `source_filter`.
 fn source_matches(filter: &str, source_id: Option<&str>) -> bool {
     let Some(src) = source_id else { return false };
@@ -399,3 +572,46 @@ fn source_matches(filter: &str, source_id: Option<&str>) -> bool {
         src == filter
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_automation_fires_on_matching_source_and_outcome() {
+        let pool = crate::db::init_test_db().unwrap();
+        repo::create(
+            &pool,
+            repo::NewAutomation {
+                id: "auto-1",
+                op_kind: OP_TEAM_PIPELINE,
+                params_json: r#"{"teamId":"team-1","condition":"success"}"#,
+                trigger_kind: TRIGGER_CHAIN,
+                cron: None,
+                timezone: None,
+                listen_event_type: None,
+                source_filter: Some("persona-a"),
+                next_run_at: None,
+                label: None,
+            },
+        )
+        .unwrap();
+        let fired = || -> i64 {
+            pool.get()
+                .unwrap()
+                .query_row(
+                    "SELECT COUNT(*) FROM persona_events
+                     WHERE event_type = ?1 AND source_type = 'chain' AND source_id = 'auto-1'",
+                    rusqlite::params![event_name::SYSTEM_OP_FIRED],
+                    |row| row.get(0),
+                )
+                .unwrap()
+        };
+
+        fire_chain_automations(&pool, "persona-b", "completed", None, "exec-1");
+        fire_chain_automations(&pool, "persona-a", "failed", None, "exec-2");
+        assert_eq!(fired(), 0);
+        fire_chain_automations(&pool, "persona-a", "completed", Some("{}"), "exec-3");
+        assert_eq!(fired(), 1);
+    }
+}
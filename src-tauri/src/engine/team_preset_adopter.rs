@@ -294,6 +294,7 @@ pub fn adopt_preset(
                     default_model_profile: None,
                     default_max_budget_usd: None,
                     default_max_turns: None,
+                    monthly_budget_usd: None,
                 },
             );
         }
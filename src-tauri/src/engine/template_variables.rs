@@ -0,0 +1,156 @@
+//! `{{var.<name>}}` prompt variables defined at global, team and persona scope.
+//!
+//! Prompt assembly (`engine::prompt::replace_variables`) is pure and is called
+//! from many places without a pool, so the variables are held in a
+//! process-wide snapshot: loaded when the execution engine starts and
+//! reloaded after every CRUD write. For a persona, a persona-scoped variable
+//! beats one on its home team, which beats a global one.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{LazyLock, RwLock};
+
+use crate::db::models::{
+    Persona, TemplateVariable, TemplateVariablePersonaRef, TemplateVariableUsage,
+};
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::resources::template_variables as repo;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+static SNAPSHOT: RwLock<Vec<TemplateVariable>> = RwLock::new(Vec::new());
+
+static VAR_REF: LazyLock<regex::Regex> =
+    LazyLock::new(|| regex::Regex::new(r"\{\{\s*var\.([A-Za-z0-9_]+)\s*\}\}").unwrap());
+
+/// Refresh the snapshot from the database. Failures keep the previous one.
+pub fn reload(pool: &DbPool) {
+    match repo::list_all(pool) {
+        Ok(vars) => {
+            if let Ok(mut guard) = SNAPSHOT.write() {
+                *guard = vars;
+            }
+        }
+        Err(e) => tracing::warn!(error = %e, "Failed to load template variables"),
+    }
+}
+
+/// Variables visible to `persona`, keyed by name (without the `var.` prefix).
+pub fn resolve_for(persona: &Persona) -> HashMap<String, String> {
+    match SNAPSHOT.read() {
+        Ok(vars) => resolve(&vars, &persona.id, persona.home_team_id.as_deref()),
+        Err(_) => HashMap::new(),
+    }
+}
+
+fn resolve(
+    vars: &[TemplateVariable],
+    persona_id: &str,
+    team_id: Option<&str>,
+) -> HashMap<String, String> {
+    let rank = |v: &TemplateVariable| match v.scope.as_str() {
+        "persona" if v.scope_id == persona_id => Some(2),
+        "team" if Some(v.scope_id.as_str()) == team_id => Some(1),
+        "global" => Some(0),
+        _ => None,
+    };
+    let mut best: HashMap<&str, (u8, &str)> = HashMap::new();
+    for v in vars {
+        let Some(r) = rank(v) else { continue };
+        match best.get(v.name.as_str()) {
+            Some((existing, _)) if *existing >= r => {}
+            _ => {
+                best.insert(&v.name, (r, &v.value));
+            }
+        }
+    }
+    best.into_iter()
+        .map(|(name, (_, value))| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Variable names referenced as `{{var.<name>}}` in `text`.
+pub fn referenced_names(text: &str) -> BTreeSet<String> {
+    VAR_REF
+        .captures_iter(text)
+        .map(|c| c[1].to_string())
+        .collect()
+}
+
+fn persona_references(p: &Persona) -> BTreeSet<String> {
+    [
+        Some(p.name.as_str()),
+        p.description.as_deref(),
+        Some(p.system_prompt.as_str()),
+        p.structured_prompt.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(referenced_names)
+    .collect()
+}
+
+/// Which personas reference each variable. Covers every defined name (even
+/// when unused) plus names referenced by a persona but defined nowhere.
+pub fn usage(pool: &DbPool) -> Result<Vec<TemplateVariableUsage>, AppError> {
+    let defined: HashSet<String> = repo::list_all(pool)?.into_iter().map(|v| v.name).collect();
+    let mut by_name: BTreeMap<String, Vec<TemplateVariablePersonaRef>> =
+        defined.iter().map(|n| (n.clone(), Vec::new())).collect();
+    for persona in persona_repo::get_all(pool)? {
+        for name in persona_references(&persona) {
+            by_name.entry(name).or_default().push(TemplateVariablePersonaRef {
+                id: persona.id.clone(),
+                name: persona.name.clone(),
+            });
+        }
+    }
+    Ok(by_name
+        .into_iter()
+        .map(|(name, personas)| TemplateVariableUsage {
+            defined: defined.contains(&name),
+            name,
+            personas,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(scope: &str, scope_id: &str, name: &str, value: &str) -> TemplateVariable {
+        TemplateVariable {
+            id: format!("{scope}-{scope_id}-{name}"),
+            scope: scope.into(),
+            scope_id: scope_id.into(),
+            name: name.into(),
+            value: value.into(),
+            description: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn persona_beats_team_beats_global() {
+        let vars = vec![
+            var("global", "", "company", "Acme"),
+            var("global", "", "site", "acme.com"),
+            var("team", "t1", "company", "Acme Sales"),
+            var("persona", "p1", "company", "Acme EU"),
+            var("persona", "p2", "site", "other.com"),
+        ];
+        let p1 = resolve(&vars, "p1", Some("t1"));
+        assert_eq!(p1["company"], "Acme EU");
+        assert_eq!(p1["site"], "acme.com");
+        let p3 = resolve(&vars, "p3", Some("t1"));
+        assert_eq!(p3["company"], "Acme Sales");
+        assert_eq!(resolve(&vars, "p3", None)["company"], "Acme");
+    }
+
+    #[test]
+    fn finds_var_references_only() {
+        let names =
+            referenced_names("Hi {{ var.company }} at {{var.site}}, not {{site}} or {{var.}}");
+        assert_eq!(names.into_iter().collect::<Vec<_>>(), vec!["company", "site"]);
+    }
+}
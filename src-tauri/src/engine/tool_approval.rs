@@ -0,0 +1,541 @@
+//! Human-in-the-loop approval gates for tool calls.
+//!
+//! A tool is gated when it is listed in the `tool_approval_tools` setting
+//! (every persona) or flagged `"requires_approval": true` in a persona's
+//! `persona_tools.tool_config`. Names are builtin/tool names (`gmail_send`,
+//! `file_write`, ...), mapped onto CLI tools the same way as sandbox policies
+//! ([`tool_policy::builtin_for_cli_tool`]), plus `mcp__<server>__<name>`.
+//!
+//! The CLI runs with permissions skipped, so a call cannot be held open while
+//! a human decides. Instead the gated action is never executed by the paused
+//! run:
+//! - before spawn the gated CLI tools are removed (`--disallowedTools`) and the
+//!   prompt tells the persona to call the virtual `request_approval` tool with
+//!   the exact call it wants to make — curl-backed tools have no CLI tool to
+//!   remove, so for them this instruction is the gate;
+//! - the stream loop stops the run on a `request_approval` call, or on a
+//!   direct call to a gated tool that slipped through ([`ApprovalGate::check_line`]);
+//! - the run ends with an error prefixed [`AWAITING_APPROVAL`] (no healing
+//!   retry), a `persona_manual_reviews` row holds the pending call in its
+//!   `context_data`, and `event_name::TOOL_APPROVAL_REQUIRED` is emitted.
+//!
+//! Approving the review (`update_manual_review_status`) calls
+//! [`resume_approved`]: a `create_retry` continuation of the paused run, with
+//! the tool listed under `_approved_tools` in its input so the gate lets it
+//! through, and a prompt hint naming the approved call. The review's
+//! `context_data.resumed_at` is claimed atomically first, so a review resumes
+//! its run at most once. Rejecting leaves the paused run as it is.
+//!
+//! A `sql_query` policy with `allow_write` gates the pseudo-tool
+//! [`SQL_WRITE`]: reads run freely, a call whose statement writes pauses the
+//! run like any gated call. The engine's SQL tool checks the same
+//! `_approved_tools` entry before it executes a write.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::AppHandle;
+use ts_rs::TS;
+
+use crate::db::models::{CreateManualReviewInput, PersonaManualReview};
+use crate::db::repos::communication::manual_reviews as review_repo;
+use crate::db::repos::core::personas as persona_repo;
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::executions as exec_repo;
+use crate::db::repos::resources::tools as tool_repo;
+use crate::db::settings_keys::TOOL_APPROVAL_TOOLS;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::tool_policy;
+use super::types::Continuation;
+use super::ExecutionEngine;
+
+/// Virtual protocol tool the persona calls to ask for approval.
+pub const REQUEST_APPROVAL_TOOL: &str = "request_approval";
+
+/// Prefix of the error recorded on runs paused for approval.
+pub const AWAITING_APPROVAL: &str = "Awaiting approval";
+
+/// Input key listing tools a continuation run was approved to call.
+pub const APPROVED_TOOLS_KEY: &str = "_approved_tools";
+
+/// Gated name of `sql_query` calls whose statement writes.
+pub const SQL_WRITE: &str = "sql_write";
+
+/// `context_data.kind` of the reviews this module creates.
+const REVIEW_KIND: &str = "tool_approval";
+
+/// `tool_config` flag that gates one persona's tool.
+const REQUIRES_APPROVAL_KEY: &str = "requires_approval";
+
+/// A gated call the run stopped on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingApproval {
+    /// Gated tool name (`gmail_send`, `file_write`, ...).
+    pub tool: String,
+    /// CLI tool that was called (`request_approval`, `Write`, ...).
+    pub cli_tool: String,
+    /// Input of the call to approve.
+    pub input: Value,
+    /// The persona's reason, when it asked through `request_approval`.
+    pub reason: Option<String>,
+}
+
+impl PendingApproval {
+    pub fn error_message(&self) -> String {
+        format!(
+            "{AWAITING_APPROVAL}: {} call paused for human review",
+            self.tool
+        )
+    }
+}
+
+pub fn is_approval_pause(error: &str) -> bool {
+    error.starts_with(AWAITING_APPROVAL)
+}
+
+/// Payload of `event_name::TOOL_APPROVAL_REQUIRED`.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+pub struct ToolApprovalRequest {
+    pub review_id: String,
+    pub execution_id: String,
+    pub persona_id: String,
+    pub persona_name: String,
+    pub tool: String,
+    /// JSON of the call's input, as shown in the review.
+    pub input: String,
+    pub reason: Option<String>,
+}
+
+/// The gated tools for one persona's run.
+#[derive(Debug, Default)]
+pub struct ApprovalGate {
+    gated: BTreeSet<String>,
+}
+
+impl ApprovalGate {
+    /// Load the gate for a run. Tools approved for this run (its input's
+    /// `_approved_tools`) are left ungated. A failed read is logged and gates
+    /// nothing from that source.
+    pub fn load(pool: &DbPool, persona_id: &str, input: Option<&Value>) -> Self {
+        let global = settings::get(pool, TOOL_APPROVAL_TOOLS)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        let configs = tool_repo::get_tool_configs_for_persona(pool, persona_id)
+            .unwrap_or_else(|e| {
+                tracing::warn!(persona_id = %persona_id, error = %e, "Failed to load tool approval flags");
+                Vec::new()
+            });
+        Self::from_sources(&global, configs, &approved_tools(input))
+    }
+
+    pub fn from_sources(
+        global: &str,
+        configs: impl IntoIterator<Item = (String, String)>,
+        approved: &BTreeSet<String>,
+    ) -> Self {
+        let configs: Vec<(String, String)> = configs.into_iter().collect();
+        let flagged = configs.iter().filter_map(|(name, config)| {
+            serde_json::from_str::<Value>(config)
+                .ok()?
+                .get(REQUIRES_APPROVAL_KEY)?
+                .as_bool()?
+                .then(|| name.clone())
+        });
+        let sql_write = configs
+            .iter()
+            .filter(|(name, _)| name == tool_policy::SQL_QUERY)
+            .filter_map(|(_, config)| tool_policy::policy_from_config(config))
+            .any(|p| p.allow_write)
+            .then(|| SQL_WRITE.to_string());
+        let gated = global
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .chain(flagged)
+            .chain(sql_write)
+            .filter(|t| !approved.contains(t))
+            .collect();
+        Self { gated }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.gated.is_empty()
+    }
+
+    /// CLI tools to remove outright (`--disallowedTools`).
+    pub fn disallowed_cli_tools(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        for tool in &self.gated {
+            match tool.as_str() {
+                tool_policy::FILE_READ => {
+                    out.extend(["Read", "Glob", "Grep", "LS", "NotebookRead"].map(String::from))
+                }
+                tool_policy::FILE_WRITE => {
+                    out.extend(["Write", "Edit", "MultiEdit", "NotebookEdit"].map(String::from))
+                }
+                tool_policy::HTTP_REQUEST => out.push("WebFetch".into()),
+                // Reads stay available; writes are caught per call.
+                SQL_WRITE => {}
+                name => out.push(format!("mcp__personas__{name}")),
+            }
+        }
+        out
+    }
+
+    /// Prompt section naming the gated tools and how to ask for approval.
+    pub fn prompt_block(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let tools = self
+            .gated
+            .iter()
+            .map(|t| format!("`{t}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = if self.gated.contains(SQL_WRITE) {
+            format!(
+                "`{SQL_WRITE}` is a `sql_query` call whose statement writes (INSERT, UPDATE, \
+                 DELETE, DDL); read queries need no approval.\n"
+            )
+        } else {
+            String::new()
+        };
+        Some(format!(
+            "\n\n## Tools Requiring Approval\n\n\
+             These tools need human approval before each use: {tools}. Do not perform them \
+             yourself — not directly, and not through shell commands or HTTP calls. When you \
+             need one, call the `{REQUEST_APPROVAL_TOOL}` tool with the exact call you want \
+             to make; this run then pauses and resumes once a human approves it.\n\
+             **Input**: `{{\"tool\": \"string\", \"input\": {{}}, \"reason\": \"string\"}}`\n{sql}"
+        ))
+    }
+
+    /// The gated call in one stream line, if any. Calls are read from the raw
+    /// stream-json line; if it has none, the parsed `(tool_name,
+    /// input_preview)` is checked instead.
+    pub fn check_line(
+        &self,
+        raw_line: &str,
+        tool_name: &str,
+        input_preview: &str,
+    ) -> Option<PendingApproval> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut calls = tool_policy::tool_calls(raw_line);
+        if calls.is_empty() {
+            let input = serde_json::from_str::<Value>(input_preview).unwrap_or(Value::Null);
+            calls.push((tool_name.to_string(), input));
+        }
+        calls
+            .into_iter()
+            .find_map(|(name, input)| self.check_call(name, input))
+    }
+
+    fn check_call(&self, cli_tool: String, input: Value) -> Option<PendingApproval> {
+        if cli_tool == REQUEST_APPROVAL_TOOL {
+            let tool = input
+                .get("tool")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown")
+                .to_string();
+            return Some(PendingApproval {
+                tool,
+                cli_tool,
+                reason: input
+                    .get("reason")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                input: input.get("input").cloned().unwrap_or(Value::Null),
+            });
+        }
+        let tool = gated_name(&cli_tool);
+        let writes_sql = tool == tool_policy::SQL_QUERY
+            && self.gated.contains(SQL_WRITE)
+            && input
+                .get("sql")
+                .and_then(Value::as_str)
+                .is_some_and(super::sql_tool::is_write);
+        if writes_sql {
+            return Some(PendingApproval {
+                tool: SQL_WRITE.to_string(),
+                cli_tool,
+                input,
+                reason: None,
+            });
+        }
+        self.gated.contains(tool).then(|| PendingApproval {
+            tool: tool.to_string(),
+            cli_tool,
+            input,
+            reason: None,
+        })
+    }
+}
+
+/// The tool name a CLI call is gated under.
+fn gated_name(cli_tool: &str) -> &str {
+    if let Some(builtin) = tool_policy::builtin_for_cli_tool(cli_tool) {
+        return builtin;
+    }
+    cli_tool
+        .strip_prefix("mcp__")
+        .and_then(|rest| rest.split_once("__"))
+        .map(|(_, name)| name)
+        .unwrap_or(cli_tool)
+}
+
+/// Whether an execution input lists `tool` under `_approved_tools`.
+pub fn is_approved(input: Option<&Value>, tool: &str) -> bool {
+    approved_tools(input).contains(tool)
+}
+
+/// Tools listed under `_approved_tools` in an execution input.
+fn approved_tools(input: Option<&Value>) -> BTreeSet<String> {
+    input
+        .and_then(|v| v.get(APPROVED_TOOLS_KEY))
+        .and_then(Value::as_array)
+        .map(|tools| {
+            tools
+                .iter()
+                .filter_map(Value::as_str)
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// File the manual review for a paused call and return the event to emit.
+pub fn request_review(
+    pool: &DbPool,
+    persona_id: &str,
+    persona_name: &str,
+    execution_id: &str,
+    pending: &PendingApproval,
+) -> Result<ToolApprovalRequest, AppError> {
+    let input = serde_json::to_string_pretty(&pending.input).unwrap_or_default();
+    let reason = pending
+        .reason
+        .as_deref()
+        .map(|r| format!("\n\n**Reason:** {r}"))
+        .unwrap_or_default();
+    let context = serde_json::json!({
+        "kind": REVIEW_KIND,
+        "tool": pending.tool,
+        "cli_tool": pending.cli_tool,
+        "input": pending.input,
+        "reason": pending.reason,
+    });
+    let review = review_repo::create(
+        pool,
+        CreateManualReviewInput {
+            execution_id: execution_id.to_string(),
+            persona_id: persona_id.to_string(),
+            title: format!("Approve {} call", pending.tool),
+            description: Some(format!(
+                "{persona_name} wants to call **{}**. The run is paused until you decide; \
+                 approving resumes it with this call allowed.{reason}\n\n```json\n{input}\n```",
+                pending.tool
+            )),
+            severity: Some("warning".into()),
+            context_data: Some(context.to_string()),
+            suggested_actions: serde_json::to_string(&["Approve call", "Reject call"]).ok(),
+            use_case_id: None,
+            assignment_id: None,
+            step_id: None,
+        },
+    )?;
+    Ok(ToolApprovalRequest {
+        review_id: review.id,
+        execution_id: execution_id.to_string(),
+        persona_id: persona_id.to_string(),
+        persona_name: persona_name.to_string(),
+        tool: pending.tool.clone(),
+        input,
+        reason: pending.reason.clone(),
+    })
+}
+
+/// Continue the run an approved tool-approval review paused. A no-op for any
+/// other review, and for one that already resumed. Returns the continuation
+/// execution id when one was started.
+pub async fn resume_approved(
+    engine: &Arc<ExecutionEngine>,
+    app: AppHandle,
+    pool: DbPool,
+    review: &PersonaManualReview,
+) -> Result<Option<String>, AppError> {
+    let Some(tool) = review
+        .context_data
+        .as_deref()
+        .and_then(|c| serde_json::from_str::<Value>(c).ok())
+        .filter(|c| c.get("kind").and_then(Value::as_str) == Some(REVIEW_KIND))
+        .and_then(|c| c.get("tool").and_then(Value::as_str).map(String::from))
+    else {
+        return Ok(None);
+    };
+    if !review_repo::claim_approval_resume(&pool, &review.id)? {
+        return Ok(None);
+    }
+
+    let paused = exec_repo::get_by_id(&pool, &review.execution_id)?;
+    if paused.is_simulation {
+        tracing::warn!(review_id = %review.id, "tool approval: paused run was a simulation; not resuming");
+        return Ok(None);
+    }
+    let persona = persona_repo::get_by_id(&pool, &paused.persona_id)?;
+    let tools = tool_repo::get_tools_for_persona(&pool, &paused.persona_id).unwrap_or_default();
+
+    let mut input = paused
+        .input_data
+        .as_deref()
+        .and_then(|s| serde_json::from_str::<Value>(s).ok())
+        .filter(Value::is_object)
+        .unwrap_or_else(|| Value::Object(Default::default()));
+    let mut approved = approved_tools(Some(&input));
+    approved.insert(tool.clone());
+    if let Some(obj) = input.as_object_mut() {
+        obj.insert(APPROVED_TOOLS_KEY.into(), serde_json::json!(approved));
+    }
+
+    let retry = exec_repo::create_retry(
+        &pool,
+        &paused.persona_id,
+        &paused.id,
+        paused.retry_count + 1,
+    )?;
+    let call = review
+        .context_data
+        .as_deref()
+        .and_then(|c| serde_json::from_str::<Value>(c).ok())
+        .and_then(|c| c.get("input").cloned())
+        .map(|i| i.to_string())
+        .unwrap_or_default();
+    let notes = review
+        .reviewer_notes
+        .as_deref()
+        .filter(|n| !n.trim().is_empty())
+        .map(|n| format!(" Reviewer notes: {n}."))
+        .unwrap_or_default();
+    let hint = format!(
+        "A previous run paused to ask for approval to call `{tool}`. A human approved this \
+         call: {call}.{notes} Make exactly that call now, then finish the task. Do not \
+         repeat work the paused run already completed."
+    );
+
+    engine
+        .start_execution(
+            app,
+            pool,
+            retry.id.clone(),
+            persona,
+            tools,
+            Some(input),
+            Some(Continuation::PromptHint(hint)),
+        )
+        .await?;
+    tracing::info!(
+        review_id = %review.id,
+        paused_execution_id = %paused.id,
+        continuation_execution_id = %retry.id,
+        tool = %tool,
+        "tool approval: resumed paused run after approval"
+    );
+    Ok(Some(retry.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn gate(global: &str, approved: &[&str]) -> ApprovalGate {
+        let configs = vec![
+            (
+                "slack_post".to_string(),
+                r#"{"requires_approval":true}"#.to_string(),
+            ),
+            ("drive_list".to_string(), r#"{"policy":{}}"#.to_string()),
+        ];
+        let approved = approved.iter().map(|s| s.to_string()).collect();
+        ApprovalGate::from_sources(global, configs, &approved)
+    }
+
+    #[test]
+    fn gates_settings_list_and_flagged_tools() {
+        let g = gate("gmail_send, file_write", &[]);
+        assert_eq!(
+            g.disallowed_cli_tools(),
+            vec![
+                "Write",
+                "Edit",
+                "MultiEdit",
+                "NotebookEdit",
+                "mcp__personas__gmail_send",
+                "mcp__personas__slack_post"
+            ]
+        );
+        assert!(g.prompt_block().unwrap().contains("`gmail_send`"));
+        assert_eq!(gate("", &[]).disallowed_cli_tools().len(), 1);
+
+        let approved = gate("gmail_send", &["gmail_send", "slack_post"]);
+        assert!(approved.is_empty());
+        assert!(approved.check_line("", "Write", "{}").is_none());
+    }
+
+    #[test]
+    fn stops_on_request_or_gated_call() {
+        let g = gate("gmail_send,file_write", &[]);
+        let line = json!({
+            "type": "assistant",
+            "message": { "content": [{
+                "type": "tool_use",
+                "name": "request_approval",
+                "input": { "tool": "gmail_send", "input": { "to": "a@b.c" }, "reason": "weekly digest" }
+            }]}
+        })
+        .to_string();
+        let pending = g.check_line(&line, "request_approval", "{}").unwrap();
+        assert_eq!(pending.tool, "gmail_send");
+        assert_eq!(pending.input, json!({ "to": "a@b.c" }));
+        assert!(is_approval_pause(&pending.error_message()));
+
+        let direct = g
+            .check_line("", "mcp__personas__gmail_send", r#"{"to":"x"}"#)
+            .unwrap();
+        assert_eq!(direct.tool, "gmail_send");
+        assert_eq!(g.check_line("", "Edit", "{}").unwrap().tool, "file_write");
+        assert!(g.check_line("", "Read", "{}").is_none());
+    }
+
+    #[test]
+    fn sql_writes_are_gated_when_the_policy_allows_them() {
+        let configs = vec![(
+            "sql_query".to_string(),
+            r#"{"policy":{"allow_write":true}}"#.to_string(),
+        )];
+        let g = ApprovalGate::from_sources("", configs.clone(), &BTreeSet::new());
+        assert!(g.disallowed_cli_tools().is_empty());
+        assert!(g.prompt_block().unwrap().contains("`sql_write`"));
+        let read = g.check_line("", "mcp__personas__sql_query", r#"{"sql":"SELECT 1"}"#);
+        assert!(read.is_none());
+        let write = g
+            .check_line("", "mcp__personas__sql_query", r#"{"sql":"DELETE FROM t"}"#)
+            .unwrap();
+        assert_eq!(write.tool, SQL_WRITE);
+
+        let approved = BTreeSet::from([SQL_WRITE.to_string()]);
+        assert!(ApprovalGate::from_sources("", configs, &approved).is_empty());
+        assert!(is_approved(
+            Some(&json!({ "_approved_tools": ["sql_write"] })),
+            SQL_WRITE
+        ));
+    }
+}
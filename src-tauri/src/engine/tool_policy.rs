@@ -0,0 +1,995 @@
+//! Per-tool sandbox policies for the builtin filesystem, HTTP and SQL tools.
+//!
+//! `file_read`, `file_write` and `http_request` are otherwise unrestricted;
+//! `sql_query` is read-only unless its policy sets `allow_write`. A policy
+//! lives under `policy` in the persona's `persona_tools.tool_config`:
+//!
+//! ```json
+//! { "policy": { "allowed_dirs": ["~/reports"], "allowed_domains": ["api.github.com"],
+//!               "max_file_bytes": 1048576, "read_only": true } }
+//! ```
+//!
+//! Network calls (`http_request`, `WebFetch`, URLs in shell commands) are also
+//! held to the global [`NetworkPolicy`] in [`settings_keys::NETWORK_POLICY`],
+//! which applies to every persona whether or not it has a policy of its own.
+//! Private and internal addresses (RFC 1918, loopback, link-local, cloud
+//! metadata) are denied unless the global policy or the persona's
+//! `allow_private_network` permits them; deny lists from both layers apply,
+//! and a call must pass both layers' allow lists.
+//!
+//! Persona runs use the CLI's own tools, so each builtin is mapped to the CLI
+//! tools that implement it ([`builtin_for_cli_tool`]). The runner enforces the
+//! policies at three points:
+//! - before spawn: the limits are appended to the prompt, and a read-only
+//!   `file_write` policy removes the CLI's write tools (`--disallowedTools`);
+//! - while streaming: every tool call is checked ([`ToolPolicySet::check_line`]).
+//!   The CLI runs with permissions skipped, so a single call cannot be vetoed —
+//!   the run is killed and fails with an error prefixed [`POLICY_VIOLATION`];
+//! - afterwards: the violation is recorded as a healing issue (`security` for
+//!   network rules, `tool_policy` otherwise) and no healing retry is attempted
+//!   (retrying would break the same rule).
+//!
+//! The personas-mcp `http_request` tool is executed by the engine itself
+//! ([`super::http_tool`]), which checks each request and redirect hop with
+//! [`ToolPolicySet::check_request`] before sending it — this also covers what
+//! the stream check cannot see (a credential's resolved URL, redirects).
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use ts_rs::TS;
+
+use crate::db::repos::core::settings;
+use crate::db::repos::execution::healing as healing_repo;
+use crate::db::repos::resources::tools as tool_repo;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+use super::types::HealingEventPayload;
+
+pub const FILE_READ: &str = "file_read";
+pub const FILE_WRITE: &str = "file_write";
+pub const HTTP_REQUEST: &str = "http_request";
+pub const SQL_QUERY: &str = "sql_query";
+
+/// Builtin tools that accept a policy.
+pub const POLICY_TOOLS: &[&str] = &[FILE_READ, FILE_WRITE, HTTP_REQUEST, SQL_QUERY];
+
+/// Prefix of the error recorded on runs stopped by a policy violation.
+pub const POLICY_VIOLATION: &str = "Tool policy violation";
+
+/// Healing-issue category for violations.
+pub const ISSUE_CATEGORY: &str = "tool_policy";
+
+/// Healing-issue category for network violations.
+pub const SECURITY_CATEGORY: &str = "security";
+
+const HTTP_METHODS: &[&str] = &["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"];
+
+/// `tool_config` key holding the policy.
+const POLICY_KEY: &str = "policy";
+
+const MAX_POLICY_ENTRIES: usize = 50;
+
+/// Longest timeout an `http_request` policy may set, in seconds.
+pub const MAX_HTTP_TIMEOUT_SECS: u64 = 120;
+
+/// Most rows a `sql_query` policy may allow per call.
+pub const MAX_SQL_ROWS: u64 = 5_000;
+
+/// Largest `sql_query` result a policy may allow, in bytes of JSON.
+pub const MAX_SQL_RESULT_BYTES: u64 = 1024 * 1024;
+
+/// Sandbox limits for one builtin tool. Empty lists mean unrestricted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct ToolPolicy {
+    /// Directories the tool may touch. Relative entries resolve against the
+    /// execution directory, `~/` against the home directory.
+    pub allowed_dirs: Vec<String>,
+    /// Hosts the tool may call; subdomains of a listed host also match.
+    pub allowed_domains: Vec<String>,
+    /// Largest file the tool may read or write, in bytes.
+    #[ts(type = "number | null")]
+    pub max_file_bytes: Option<u64>,
+    /// `file_write`: no writes at all. `http_request`: GET/HEAD only.
+    pub read_only: bool,
+    /// `http_request`: hosts the tool may never call, subdomains included.
+    pub denied_domains: Vec<String>,
+    /// `http_request`: HTTP methods the tool may use.
+    pub allowed_methods: Vec<String>,
+    /// `http_request`: overrides the global policy's `allow_private_network`.
+    pub allow_private_network: Option<bool>,
+    /// `http_request`: longest a request may take, in seconds.
+    #[ts(type = "number | null")]
+    pub timeout_secs: Option<u64>,
+    /// `sql_query`: most rows returned per call.
+    #[ts(type = "number | null")]
+    pub max_rows: Option<u64>,
+    /// `sql_query`: largest result returned per call, in bytes of JSON.
+    #[ts(type = "number | null")]
+    pub max_result_bytes: Option<u64>,
+    /// `sql_query`: permit writes. Each run that writes still needs a human
+    /// approval (`sql_write`).
+    pub allow_write: bool,
+}
+
+/// Global network rules for every persona's HTTP calls, stored as JSON in
+/// [`settings_keys::NETWORK_POLICY`]. Empty lists mean unrestricted.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct NetworkPolicy {
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    /// Reach private, loopback, link-local and metadata addresses. Off by
+    /// default; a persona's `http_request` policy can override it.
+    pub allow_private_network: bool,
+}
+
+impl NetworkPolicy {
+    /// The stored policy; unset or unreadable falls back to the default,
+    /// which denies private addresses.
+    pub fn load(pool: &DbPool) -> Self {
+        settings::get(pool, settings_keys::NETWORK_POLICY)
+            .ok()
+            .flatten()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    fn is_open(&self) -> bool {
+        self.allow_private_network
+            && self.allowed_domains.is_empty()
+            && self.denied_domains.is_empty()
+            && self.allowed_methods.is_empty()
+    }
+}
+
+/// A tool call that broke a policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyViolation {
+    /// Builtin whose policy was broken.
+    pub tool: &'static str,
+    /// CLI tool that made the call (`Write`, `WebFetch`, ...).
+    pub cli_tool: String,
+    /// `allowed_dirs` | `allowed_domains` | `denied_domains` | `allowed_methods` |
+    /// `private_network` | `max_file_bytes` | `read_only`
+    pub rule: &'static str,
+    pub detail: String,
+}
+
+impl PolicyViolation {
+    pub fn error_message(&self) -> String {
+        format!(
+            "{POLICY_VIOLATION}: {} ({}) broke `{}` — {}",
+            self.tool, self.cli_tool, self.rule, self.detail
+        )
+    }
+
+    /// Healing-issue category the violation is filed under.
+    pub fn issue_category(&self) -> &'static str {
+        if self.tool == HTTP_REQUEST {
+            SECURITY_CATEGORY
+        } else {
+            ISSUE_CATEGORY
+        }
+    }
+}
+
+pub fn is_policy_error(error: &str) -> bool {
+    error.starts_with(POLICY_VIOLATION)
+}
+
+/// The builtin a CLI tool call counts as. `Bash` is handled separately: its
+/// command is only scanned for URLs against the `http_request` policy.
+pub fn builtin_for_cli_tool(name: &str) -> Option<&'static str> {
+    match name {
+        "Read" | "Glob" | "Grep" | "LS" | "NotebookRead" => Some(FILE_READ),
+        "Write" | "Edit" | "MultiEdit" | "NotebookEdit" => Some(FILE_WRITE),
+        "WebFetch" => Some(HTTP_REQUEST),
+        n if n == HTTP_REQUEST || n.ends_with("__http_request") => Some(HTTP_REQUEST),
+        _ => None,
+    }
+}
+
+/// The policy in a `tool_config` JSON string, if it has one.
+pub fn policy_from_config(config: &str) -> Option<ToolPolicy> {
+    let value = serde_json::from_str::<Value>(config).ok()?;
+    serde_json::from_value(value.get(POLICY_KEY)?.clone()).ok()
+}
+
+/// Write `policy` into an existing `tool_config`, keeping its other keys.
+/// `None` removes the policy.
+pub fn merge_into_config(config: Option<&str>, policy: Option<&ToolPolicy>) -> Option<String> {
+    let mut value = config
+        .and_then(|c| serde_json::from_str::<Value>(c).ok())
+        .filter(Value::is_object)
+        .unwrap_or_else(|| Value::Object(Default::default()));
+    let obj = value.as_object_mut()?;
+    match policy {
+        Some(p) => {
+            obj.insert(POLICY_KEY.into(), serde_json::to_value(p).ok()?);
+        }
+        None => {
+            obj.remove(POLICY_KEY);
+        }
+    }
+    (!obj.is_empty()).then(|| value.to_string())
+}
+
+pub fn validate(tool_name: &str, policy: &ToolPolicy) -> Result<(), AppError> {
+    if !POLICY_TOOLS.contains(&tool_name) {
+        return Err(AppError::Validation(format!(
+            "Tool '{tool_name}' does not support sandbox policies (supported: {})",
+            POLICY_TOOLS.join(", ")
+        )));
+    }
+    if policy.allowed_dirs.len() > MAX_POLICY_ENTRIES
+        || policy.allowed_domains.len() > MAX_POLICY_ENTRIES
+    {
+        return Err(AppError::Validation(format!(
+            "A policy may list at most {MAX_POLICY_ENTRIES} directories and {MAX_POLICY_ENTRIES} domains"
+        )));
+    }
+    if policy.allowed_dirs.iter().any(|d| d.trim().is_empty()) {
+        return Err(AppError::Validation(
+            "Allowed directories cannot be empty".into(),
+        ));
+    }
+    validate_network_lists(
+        &policy.allowed_domains,
+        &policy.denied_domains,
+        &policy.allowed_methods,
+    )?;
+    if policy.max_file_bytes == Some(0) {
+        return Err(AppError::Validation(
+            "Max file size must be positive".into(),
+        ));
+    }
+    if policy
+        .timeout_secs
+        .is_some_and(|t| t == 0 || t > MAX_HTTP_TIMEOUT_SECS)
+    {
+        return Err(AppError::Validation(format!(
+            "Request timeout must be between 1 and {MAX_HTTP_TIMEOUT_SECS} seconds"
+        )));
+    }
+    if policy.max_rows.is_some_and(|n| n == 0 || n > MAX_SQL_ROWS) {
+        return Err(AppError::Validation(format!(
+            "Max rows must be between 1 and {MAX_SQL_ROWS}"
+        )));
+    }
+    if policy
+        .max_result_bytes
+        .is_some_and(|n| n == 0 || n > MAX_SQL_RESULT_BYTES)
+    {
+        return Err(AppError::Validation(format!(
+            "Max result size must be between 1 and {MAX_SQL_RESULT_BYTES} bytes"
+        )));
+    }
+    Ok(())
+}
+
+pub fn validate_network(policy: &NetworkPolicy) -> Result<(), AppError> {
+    validate_network_lists(
+        &policy.allowed_domains,
+        &policy.denied_domains,
+        &policy.allowed_methods,
+    )
+}
+
+fn validate_network_lists(
+    allowed_domains: &[String],
+    denied_domains: &[String],
+    allowed_methods: &[String],
+) -> Result<(), AppError> {
+    if allowed_domains.len() > MAX_POLICY_ENTRIES || denied_domains.len() > MAX_POLICY_ENTRIES {
+        return Err(AppError::Validation(format!(
+            "A policy may allow and deny at most {MAX_POLICY_ENTRIES} domains each"
+        )));
+    }
+    if let Some(bad) = allowed_domains
+        .iter()
+        .chain(denied_domains)
+        .find(|d| d.trim().is_empty() || d.contains(['/', ':', ' ']))
+    {
+        return Err(AppError::Validation(format!(
+            "'{bad}' is not a domain — list bare hosts such as api.github.com"
+        )));
+    }
+    if let Some(bad) = allowed_methods
+        .iter()
+        .find(|m| !HTTP_METHODS.contains(&m.to_ascii_uppercase().as_str()))
+    {
+        return Err(AppError::Validation(format!(
+            "'{bad}' is not an HTTP method (supported: {})",
+            HTTP_METHODS.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Record a violation as a healing issue (see [`PolicyViolation::issue_category`])
+/// and return the event to emit. `None` while an issue of that category is
+/// still open for the persona — a scheduled persona would otherwise file one
+/// per run.
+pub fn record_violation(
+    pool: &DbPool,
+    persona_id: &str,
+    persona_name: &str,
+    execution_id: &str,
+    violation: &PolicyViolation,
+) -> Option<HealingEventPayload> {
+    let open = pool.get().ok().and_then(|conn| {
+        conn.query_row(
+            "SELECT EXISTS(
+                SELECT 1 FROM persona_healing_issues
+                WHERE persona_id = ?1 AND status = 'open' AND category = ?2
+            )",
+            rusqlite::params![persona_id, violation.issue_category()],
+            |row| row.get::<_, bool>(0),
+        )
+        .ok()
+    });
+    if open.unwrap_or(false) {
+        return None;
+    }
+    let title = format!("Tool policy violated: {}", violation.tool);
+    let description = violation.error_message();
+    let fix = if violation.tool == HTTP_REQUEST {
+        format!(
+            "Review the network policy (`{}`) — the global one in settings and this agent's {HTTP_REQUEST} policy: widen it if the call was legitimate, or tighten the prompt so the agent stays inside it.",
+            violation.rule
+        )
+    } else {
+        format!(
+            "Review the {} policy (`{}`) in this agent's tool settings: widen it if the call was legitimate, or tighten the prompt so the agent stays inside it.",
+            violation.tool, violation.rule
+        )
+    };
+    let issue = healing_repo::create(
+        pool,
+        persona_id,
+        &title,
+        &description,
+        false,
+        Some("high"),
+        Some(violation.issue_category()),
+        Some(execution_id),
+        Some(&fix),
+    )
+    .ok()
+    .flatten();
+    Some(HealingEventPayload {
+        issue_id: issue.map(|i| i.id).unwrap_or_default(),
+        persona_id: persona_id.to_string(),
+        execution_id: execution_id.to_string(),
+        title,
+        action: "issue_created".into(),
+        auto_fixed: false,
+        severity: "high".into(),
+        suggested_fix: Some(fix),
+        persona_name: persona_name.to_string(),
+        description: Some(description),
+        strategy: None,
+        backoff_seconds: None,
+        retry_number: None,
+        max_retries: None,
+    })
+}
+
+/// Every policy configured for one persona's run.
+#[derive(Debug, Default)]
+pub struct ToolPolicySet {
+    policies: BTreeMap<&'static str, ToolPolicy>,
+    /// Global network rules, checked alongside the persona's `http_request` policy.
+    network: NetworkPolicy,
+    /// Execution directory — the CLI's cwd, used for relative paths.
+    root: PathBuf,
+}
+
+impl ToolPolicySet {
+    /// Load the persona's policies. A failed read is logged and yields an
+    /// empty set — the run then proceeds unrestricted, as before policies.
+    pub fn load(pool: &DbPool, persona_id: &str, exec_dir: &Path) -> Self {
+        let set = match tool_repo::get_tool_configs_for_persona(pool, persona_id) {
+            Ok(configs) => Self::from_configs(configs, exec_dir),
+            Err(e) => {
+                tracing::warn!(persona_id = %persona_id, error = %e, "Failed to load tool policies");
+                Self::default()
+            }
+        };
+        set.with_network(NetworkPolicy::load(pool))
+    }
+
+    pub fn with_network(mut self, network: NetworkPolicy) -> Self {
+        self.network = network;
+        self
+    }
+
+    pub fn from_configs(configs: impl IntoIterator<Item = (String, String)>, root: &Path) -> Self {
+        let policies = configs
+            .into_iter()
+            .filter_map(|(name, config)| {
+                let tool = POLICY_TOOLS.iter().copied().find(|t| *t == name)?;
+                Some((tool, policy_from_config(&config)?))
+            })
+            .filter(|(_, p)| *p != ToolPolicy::default())
+            .collect();
+        Self {
+            policies,
+            network: NetworkPolicy::default(),
+            root: root.to_path_buf(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// CLI tools to remove outright (`--disallowedTools`).
+    pub fn disallowed_cli_tools(&self) -> Vec<&'static str> {
+        match self.policies.get(FILE_WRITE) {
+            Some(p) if p.read_only => vec!["Write", "Edit", "MultiEdit", "NotebookEdit"],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Prompt section stating the limits, so the persona stays inside them
+    /// instead of discovering them by being stopped.
+    pub fn prompt_block(&self) -> Option<String> {
+        let network = &self.network;
+        let global_network = network.allowed_domains.len()
+            + network.denied_domains.len()
+            + network.allowed_methods.len()
+            > 0;
+        if self.is_empty() && !global_network {
+            return None;
+        }
+        let mut block = String::from(
+            "\n\n## Tool Policies\n\nThese limits are enforced. A tool call that breaks one stops this run.\n",
+        );
+        for (tool, p) in &self.policies {
+            let mut rules = Vec::new();
+            if !p.allowed_dirs.is_empty() {
+                rules.push(format!("only under {}", quoted(&p.allowed_dirs)));
+            }
+            if !p.allowed_domains.is_empty() {
+                rules.push(format!(
+                    "only these domains: {}",
+                    quoted(&p.allowed_domains)
+                ));
+            }
+            if !p.denied_domains.is_empty() {
+                rules.push(format!(
+                    "never these domains: {}",
+                    quoted(&p.denied_domains)
+                ));
+            }
+            if !p.allowed_methods.is_empty() {
+                rules.push(format!("only {} requests", p.allowed_methods.join("/")));
+            }
+            if let Some(max) = p.max_file_bytes {
+                rules.push(format!("files up to {max} bytes"));
+            }
+            if let Some(secs) = p.timeout_secs {
+                rules.push(format!("requests time out after {secs}s"));
+            }
+            if let Some(rows) = p.max_rows {
+                rules.push(format!("up to {rows} rows per query"));
+            }
+            if let Some(bytes) = p.max_result_bytes {
+                rules.push(format!("results up to {bytes} bytes"));
+            }
+            if *tool == SQL_QUERY {
+                rules.push(
+                    if p.allow_write {
+                        "writes need human approval (`sql_write`)"
+                    } else {
+                        "read-only: no INSERT/UPDATE/DELETE or DDL"
+                    }
+                    .into(),
+                );
+            }
+            if p.read_only {
+                rules.push(
+                    if *tool == HTTP_REQUEST {
+                        "GET/HEAD requests only"
+                    } else {
+                        "read-only: do not create or modify files"
+                    }
+                    .into(),
+                );
+            }
+            block.push_str(&format!("- {tool}: {}\n", rules.join("; ")));
+        }
+        if global_network {
+            let mut rules = Vec::new();
+            if !network.allowed_domains.is_empty() {
+                rules.push(format!(
+                    "only these domains: {}",
+                    quoted(&network.allowed_domains)
+                ));
+            }
+            if !network.denied_domains.is_empty() {
+                rules.push(format!(
+                    "never these domains: {}",
+                    quoted(&network.denied_domains)
+                ));
+            }
+            if !network.allowed_methods.is_empty() {
+                rules.push(format!(
+                    "only {} requests",
+                    network.allowed_methods.join("/")
+                ));
+            }
+            block.push_str(&format!("- all network calls: {}\n", rules.join("; ")));
+        }
+        Some(block)
+    }
+
+    /// Check every tool call in one stream line. Calls are read from the raw
+    /// stream-json line (untruncated input); if it has none, the parsed
+    /// `(tool_name, input_preview)` is checked instead.
+    pub fn check_line(
+        &self,
+        raw_line: &str,
+        tool_name: &str,
+        input_preview: &str,
+    ) -> Option<PolicyViolation> {
+        if self.is_empty() && self.network.is_open() {
+            return None;
+        }
+        let mut calls = tool_calls(raw_line);
+        if calls.is_empty() {
+            let input = serde_json::from_str::<Value>(input_preview).unwrap_or(Value::Null);
+            calls.push((tool_name.to_string(), input));
+        }
+        calls
+            .iter()
+            .find_map(|(name, input)| self.check_call(name, input))
+    }
+
+    /// Check a request made by the engine's own `http_request` tool.
+    pub fn check_request(&self, url: &str, method: &str) -> Option<PolicyViolation> {
+        let input = serde_json::json!({ "url": url, "method": method });
+        self.check_call(HTTP_REQUEST, &input)
+    }
+
+    /// The persona's policy for one builtin tool, if it has one.
+    pub fn policy(&self, tool: &str) -> Option<&ToolPolicy> {
+        self.policies.get(tool)
+    }
+
+    /// Timeout from the persona's `http_request` policy, if it sets one.
+    pub fn http_timeout_secs(&self) -> Option<u64> {
+        self.policies.get(HTTP_REQUEST)?.timeout_secs
+    }
+
+    /// Whether `http_request` may reach private and internal addresses.
+    pub fn allows_private_network(&self) -> bool {
+        self.policies
+            .get(HTTP_REQUEST)
+            .and_then(|p| p.allow_private_network)
+            .unwrap_or(self.network.allow_private_network)
+    }
+
+    fn check_call(&self, cli_tool: &str, input: &Value) -> Option<PolicyViolation> {
+        let violation = |tool: &'static str, rule: &'static str, detail: String| PolicyViolation {
+            tool,
+            cli_tool: cli_tool.to_string(),
+            rule,
+            detail,
+        };
+
+        if cli_tool == "Bash" {
+            let command = input.get("command").and_then(Value::as_str)?;
+            return urls_in(command).find_map(|u| {
+                self.check_url(u)
+                    .map(|rule| violation(HTTP_REQUEST, rule, format!("shell command calls {u}")))
+            });
+        }
+
+        let tool = builtin_for_cli_tool(cli_tool)?;
+
+        if tool == HTTP_REQUEST {
+            if let Some(url) = input.get("url").and_then(Value::as_str) {
+                if let Some(rule) = self.check_url(url) {
+                    return Some(violation(tool, rule, format!("request to {url}")));
+                }
+            }
+            let method = input
+                .get("method")
+                .and_then(Value::as_str)
+                .unwrap_or("GET")
+                .to_ascii_uppercase();
+            return self
+                .check_method(&method)
+                .map(|rule| violation(tool, rule, format!("{method} request")));
+        }
+
+        let policy = self.policies.get(tool)?;
+
+        let path = ["file_path", "notebook_path", "path"]
+            .iter()
+            .find_map(|k| input.get(*k).and_then(Value::as_str));
+        if tool == FILE_WRITE && policy.read_only {
+            return Some(violation(
+                tool,
+                "read_only",
+                format!("write to {}", path.unwrap_or("a file")),
+            ));
+        }
+        let path = path?;
+        let resolved = self.resolve(path);
+        if !policy.allowed_dirs.is_empty()
+            && !policy
+                .allowed_dirs
+                .iter()
+                .any(|d| resolved.starts_with(self.resolve(d)))
+        {
+            return Some(violation(
+                tool,
+                "allowed_dirs",
+                format!("{path} is outside the allowed directories"),
+            ));
+        }
+        let max = policy.max_file_bytes?;
+        let size = match cli_tool {
+            "Write" => input
+                .get("content")
+                .and_then(Value::as_str)
+                .map(|c| c.len() as u64),
+            "Read" => std::fs::metadata(&resolved).ok().map(|m| m.len()),
+            _ => None,
+        }?;
+        (size > max).then(|| {
+            violation(
+                tool,
+                "max_file_bytes",
+                format!("{path} is {size} bytes (limit {max})"),
+            )
+        })
+    }
+
+    /// The network rule `url` breaks, if any: private address, then deny
+    /// lists, then allow lists — global and persona layers alike.
+    fn check_url(&self, url: &str) -> Option<&'static str> {
+        let persona = self.policies.get(HTTP_REQUEST);
+        if !self.allows_private_network() && is_private_target(url) {
+            return Some("private_network");
+        }
+        if let Some(host) = url_host(url) {
+            let denied = host_listed(&self.network.denied_domains, &host)
+                || persona.is_some_and(|p| host_listed(&p.denied_domains, &host));
+            if denied {
+                return Some("denied_domains");
+            }
+        }
+        let allowed = domain_allowed(&self.network.allowed_domains, url)
+            && persona.is_none_or(|p| domain_allowed(&p.allowed_domains, url));
+        (!allowed).then_some("allowed_domains")
+    }
+
+    fn check_method(&self, method: &str) -> Option<&'static str> {
+        let persona = self.policies.get(HTTP_REQUEST);
+        if persona.is_some_and(|p| p.read_only) && !matches!(method, "GET" | "HEAD") {
+            return Some("read_only");
+        }
+        let permits = |list: &[String]| {
+            list.is_empty() || list.iter().any(|m| m.eq_ignore_ascii_case(method))
+        };
+        let allowed = permits(&self.network.allowed_methods)
+            && persona.is_none_or(|p| permits(&p.allowed_methods));
+        (!allowed).then_some("allowed_methods")
+    }
+
+    /// Absolute, lexically normalised form of a path from a tool call or policy.
+    fn resolve(&self, path: &str) -> PathBuf {
+        let expanded = path
+            .strip_prefix("~/")
+            .or_else(|| path.strip_prefix("~\\"))
+            .and_then(|rest| dirs::home_dir().map(|h| h.join(rest)))
+            .unwrap_or_else(|| PathBuf::from(path));
+        let absolute = if expanded.is_absolute() {
+            expanded
+        } else {
+            self.root.join(expanded)
+        };
+        let mut out = PathBuf::new();
+        for c in absolute.components() {
+            match c {
+                Component::ParentDir => {
+                    out.pop();
+                }
+                Component::CurDir => {}
+                other => out.push(other),
+            }
+        }
+        out
+    }
+}
+
+fn quoted(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|i| format!("`{i}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `(name, input)` of every `tool_use` block in a stream-json assistant line.
+pub(crate) fn tool_calls(raw_line: &str) -> Vec<(String, Value)> {
+    let Ok(value) = serde_json::from_str::<Value>(raw_line) else {
+        return Vec::new();
+    };
+    value
+        .pointer("/message/content")
+        .and_then(Value::as_array)
+        .map(|blocks| {
+            blocks
+                .iter()
+                .filter(|b| b.get("type").and_then(Value::as_str) == Some("tool_use"))
+                .filter_map(|b| {
+                    let name = b.get("name")?.as_str()?.to_string();
+                    Some((name, b.get("input").cloned().unwrap_or(Value::Null)))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn urls_in(command: &str) -> impl Iterator<Item = &str> {
+    static URL_RE: OnceLock<regex::Regex> = OnceLock::new();
+    URL_RE
+        .get_or_init(|| regex::Regex::new(r#"https?://[^\s'"`<>|;)]+"#).expect("valid url regex"))
+        .find_iter(command)
+        .map(|m| m.as_str())
+}
+
+fn url_host(url: &str) -> Option<String> {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_ascii_lowercase))
+}
+
+/// Whether `host` is one of `list` or a subdomain of one.
+fn host_listed(list: &[String], host: &str) -> bool {
+    list.iter().any(|d| {
+        let d = d.trim().trim_start_matches("*.").to_ascii_lowercase();
+        host == d || host.ends_with(&format!(".{d}"))
+    })
+}
+
+fn domain_allowed(allowed: &[String], url: &str) -> bool {
+    if allowed.is_empty() {
+        return true;
+    }
+    url_host(url).is_some_and(|host| host_listed(allowed, &host))
+}
+
+/// Literal private/loopback/link-local IPs and internal hostnames. No DNS
+/// lookup — this runs per stream line; hostnames that merely resolve to a
+/// private address are not caught here.
+fn is_private_target(url: &str) -> bool {
+    url::Url::parse(url).is_ok_and(|u| {
+        u.host_str()
+            .is_some_and(|h| h.eq_ignore_ascii_case("localhost"))
+            || super::url_safety::is_url_target_private(&u)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(tool: &str, policy: &str) -> ToolPolicySet {
+        ToolPolicySet::from_configs(
+            [(tool.to_string(), format!(r#"{{"policy":{policy}}}"#))],
+            Path::new("/work/exec"),
+        )
+    }
+
+    #[test]
+    fn file_policies_check_dirs_size_and_read_only() {
+        let reads = set(FILE_READ, r#"{"allowed_dirs":["data","/shared"]}"#);
+        assert!(reads
+            .check_line("", "Read", r#"{"file_path":"data/a.csv"}"#)
+            .is_none());
+        assert!(reads
+            .check_line("", "Read", r#"{"file_path":"/shared/x/b.md"}"#)
+            .is_none());
+        let escaped = reads
+            .check_line("", "Read", r#"{"file_path":"data/../../secrets.txt"}"#)
+            .unwrap();
+        assert_eq!(escaped.rule, "allowed_dirs");
+
+        let writes = set(FILE_WRITE, r#"{"max_file_bytes":4}"#);
+        let line = r#"{"type":"assistant","message":{"content":[{"type":"tool_use","name":"Write","input":{"file_path":"out.txt","content":"hello"}}]}}"#;
+        assert_eq!(
+            writes.check_line(line, "Write", "{...").unwrap().rule,
+            "max_file_bytes"
+        );
+
+        let read_only = set(FILE_WRITE, r#"{"read_only":true}"#);
+        assert_eq!(
+            read_only.disallowed_cli_tools(),
+            vec!["Write", "Edit", "MultiEdit", "NotebookEdit"]
+        );
+        assert!(is_policy_error(
+            &read_only
+                .check_line("", "Edit", r#"{"file_path":"a"}"#)
+                .unwrap()
+                .error_message()
+        ));
+        assert!(read_only
+            .check_line("", "Read", r#"{"file_path":"a"}"#)
+            .is_none());
+    }
+
+    #[test]
+    fn http_policy_checks_domains_and_methods() {
+        let http = set(
+            HTTP_REQUEST,
+            r#"{"allowed_domains":["github.com"],"read_only":true}"#,
+        );
+        assert!(http
+            .check_line("", "WebFetch", r#"{"url":"https://api.github.com/repos"}"#)
+            .is_none());
+        assert_eq!(
+            http.check_line("", "WebFetch", r#"{"url":"https://evil.example/x"}"#)
+                .unwrap()
+                .rule,
+            "allowed_domains"
+        );
+        assert_eq!(
+            http.check_line(
+                "",
+                "mcp__personas__http_request",
+                r#"{"url":"https://github.com","method":"post"}"#
+            )
+            .unwrap()
+            .rule,
+            "read_only"
+        );
+        assert!(http
+            .check_line(
+                "",
+                "Bash",
+                r#"{"command":"curl -s https://notgithub.com/a | jq ."}"#
+            )
+            .is_some());
+
+        assert!(validate(
+            HTTP_REQUEST,
+            &ToolPolicy {
+                allowed_domains: vec!["https://x.com".into()],
+                ..Default::default()
+            }
+        )
+        .is_err());
+        assert!(validate("gmail_send", &ToolPolicy::default()).is_err());
+
+        let timed = set(HTTP_REQUEST, r#"{"timeout_secs":10,"read_only":true}"#);
+        assert_eq!(timed.http_timeout_secs(), Some(10));
+        assert_eq!(
+            timed
+                .check_request("https://example.com", "PUT")
+                .unwrap()
+                .rule,
+            "read_only"
+        );
+        assert!(timed.check_request("https://example.com", "GET").is_none());
+        assert!(validate(
+            HTTP_REQUEST,
+            &ToolPolicy {
+                timeout_secs: Some(MAX_HTTP_TIMEOUT_SECS + 1),
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+        let sql = set(SQL_QUERY, r#"{"max_rows":50,"allow_write":true}"#);
+        assert_eq!(sql.policy(SQL_QUERY).unwrap().max_rows, Some(50));
+        assert!(sql.prompt_block().unwrap().contains("`sql_write`"));
+        assert!(validate(
+            SQL_QUERY,
+            &ToolPolicy {
+                max_rows: Some(MAX_SQL_ROWS + 1),
+                ..Default::default()
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn network_policy_denies_private_targets_and_listed_domains() {
+        let open = ToolPolicySet::default();
+        for url in [
+            "http://169.254.169.254/latest",
+            "http://localhost:8080",
+            "http://10.0.0.5",
+        ] {
+            assert_eq!(
+                open.check_line("", "WebFetch", &format!(r#"{{"url":"{url}"}}"#))
+                    .unwrap()
+                    .rule,
+                "private_network"
+            );
+        }
+        assert!(open
+            .check_line("", "WebFetch", r#"{"url":"https://example.com"}"#)
+            .is_none());
+
+        let global = NetworkPolicy {
+            denied_domains: vec!["pastebin.com".into()],
+            allowed_methods: vec!["GET".into(), "POST".into()],
+            ..Default::default()
+        };
+        let persona = set(
+            HTTP_REQUEST,
+            r#"{"allow_private_network":true,"denied_domains":["example.org"]}"#,
+        )
+        .with_network(global);
+        assert!(persona
+            .check_line("", "WebFetch", r#"{"url":"http://192.168.1.2/api"}"#)
+            .is_none());
+        let denied = persona
+            .check_line(
+                "",
+                "Bash",
+                r#"{"command":"curl https://x.pastebin.com/raw"}"#,
+            )
+            .unwrap();
+        assert_eq!(
+            (denied.rule, denied.issue_category()),
+            ("denied_domains", SECURITY_CATEGORY)
+        );
+        assert_eq!(
+            persona
+                .check_line("", "WebFetch", r#"{"url":"https://example.org"}"#)
+                .unwrap()
+                .rule,
+            "denied_domains"
+        );
+        assert_eq!(
+            persona
+                .check_line(
+                    "",
+                    "mcp__personas__http_request",
+                    r#"{"url":"https://example.com","method":"DELETE"}"#
+                )
+                .unwrap()
+                .rule,
+            "allowed_methods"
+        );
+
+        assert!(validate_network(&NetworkPolicy {
+            allowed_methods: vec!["FETCH".into()],
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn merge_keeps_other_config_keys() {
+        let policy = ToolPolicy {
+            read_only: true,
+            ..Default::default()
+        };
+        let merged = merge_into_config(Some(r#"{"timeout":5}"#), Some(&policy)).unwrap();
+        assert_eq!(policy_from_config(&merged), Some(policy));
+        assert_eq!(
+            merge_into_config(Some(&merged), None).as_deref(),
+            Some(r#"{"timeout":5}"#)
+        );
+        assert_eq!(merge_into_config(None, None), None);
+    }
+}
@@ -204,7 +204,7 @@ pub async fn invoke_tool_direct(
     }
 
     // Resolve credential env vars using the existing runner infrastructure
-    let (env_vars, _hints, cred_failures, _injected_connectors) =
+    let (env_vars, _hints, cred_failures, injected_connectors) =
         super::runner::resolve_credential_env_vars(
             pool,
             std::slice::from_ref(tool),
@@ -224,6 +224,18 @@ pub async fn invoke_tool_direct(
         ));
     }
 
+    // Connector-wide budgets shared with every other persona calling the
+    // same APIs; waits for a slot, fails typed only when the queue is full.
+    for connector in &injected_connectors {
+        if let Err(e) = super::connector_rate_limit::acquire(pool, connector).await {
+            return Ok(early_failure(
+                ToolErrorKind::RateLimited,
+                e.to_string(),
+                true,
+            ));
+        }
+    }
+
     let env_map: HashMap<&str, &str> = env_vars
         .iter()
         .map(|(k, v)| (k.as_str(), v.as_str()))
@@ -1232,7 +1244,7 @@ fn classify_api_error(error_msg: &str) -> (&'static str, Option<u16>) {
 
 /// Extract the curl command from an implementation_guide string.
 /// Looks for a line starting with "Curl:" and returns everything after it.
-fn extract_curl_line(guide: &str) -> Option<&str> {
+pub(crate) fn extract_curl_line(guide: &str) -> Option<&str> {
     for segment in guide.split("\\n") {
         let trimmed = segment.trim();
         if let Some(rest) = trimmed.strip_prefix("Curl:") {
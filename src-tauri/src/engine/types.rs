@@ -232,6 +232,14 @@ pub enum ProtocolMessage {
     /// prefix >= 8 chars is accepted); `note` says what fixed it. Personas see
     /// open incidents (with ids) in their team-alignment block.
     ResolveIncident { id: String, note: Option<String> },
+    /// Persona publishes a structured artifact to the shared context blackboard
+    /// of its pipeline run or chain trace: `{"shared_context": {"key": "...",
+    /// "value": <any JSON>}}`. Later personas in the same scope see it in their
+    /// prompt (see `engine::shared_context`).
+    SharedContext {
+        key: String,
+        value: serde_json::Value,
+    },
     /// Persona surfaces a concrete FUTURE-WORK item into the project's backlog
     /// (`dev_ideas`) — a follow-up, refactor, test gap, or hardening that is worth
     /// doing but is NOT part of the current increment. Scoped to the persona's
@@ -445,6 +453,9 @@ pub mod providers {
     pub const CUSTOM: &str = "custom";
     /// Remote HTTP provider (Phase 1 split engine): Qwen via DashScope.
     pub const QWEN: &str = "qwen";
+    /// Direct OpenAI-compatible chat-completions backend (OpenAI, OpenRouter,
+    /// local llama.cpp/Ollama) — see `engine::provider::openai_api`.
+    pub const OPENAI_API: &str = "openai_api";
 }
 
 // =============================================================================
@@ -551,6 +562,8 @@ impl EphemeralPersona {
             setup_status: "ready".to_string(),
             setup_detail: None,
             disabled_dims_json: None,
+            engine: None,
+            execution_placement: None,
             lifecycle: "draft".to_string(),
             created_at: now.clone(),
             updated_at: now,
@@ -722,7 +735,8 @@ pub struct HealingEventPayload {
     pub persona_id: String,
     pub execution_id: String,
     pub title: String,
-    /// "auto_retry" | "issue_created" | "circuit_breaker"
+    /// "auto_retry" | "issue_created" | "circuit_breaker" | "budget_paused"
+    /// | "workspace_budget_exceeded"
     pub action: String,
     pub auto_fixed: bool,
     /// "low" | "medium" | "high" | "critical"
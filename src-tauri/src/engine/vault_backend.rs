@@ -0,0 +1,529 @@
+//! Pluggable storage for the credential master key.
+//!
+//! `engine::crypto` encrypts every credential with one 32-byte master key. By
+//! default that key lives in the OS keychain, which headless and CI installs
+//! often do not have. This module lets the key live elsewhere:
+//!
+//! - `keychain` — the OS keychain (default; handled entirely by `crypto`).
+//! - `encrypted_file` — `vault.key` in the app data dir, sealed with a
+//!   passphrase (PBKDF2-SHA256 + AES-256-GCM). Unlocked at startup from
+//!   `PERSONAS_VAULT_PASSPHRASE`, or later through the `unlock_vault` command.
+//! - `hashicorp_vault` — a HashiCorp Vault KV v2 secret. Unlocked at startup
+//!   from `VAULT_TOKEN`, or later through `unlock_vault` with a token.
+//!
+//! The backend is chosen by the [`VAULT_BACKEND`] setting, overridable with
+//! `PERSONAS_VAULT_BACKEND` (and `VAULT_ADDR` for the Vault address) so a
+//! headless install can be configured without the UI. It is resolved once at
+//! startup; [`configure`] copies the live key into a new backend and takes
+//! effect on the next start. Passphrases and tokens are never persisted.
+//!
+//! [`VAULT_BACKEND`]: crate::db::settings_keys::VAULT_BACKEND
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD as B64;
+use base64::Engine as _;
+use pbkdf2::pbkdf2_hmac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use ts_rs::TS;
+use zeroize::Zeroize;
+
+use super::crypto::{self, CryptoError, KeySource};
+use crate::db::repos::core::settings;
+use crate::db::repos::resources::credentials;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Header of a sealed `vault.key` file, followed by salt, nonce, ciphertext.
+const FILE_MAGIC: &[u8] = b"PVK1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const PBKDF2_ITERATIONS: u32 = 600_000;
+pub(crate) const MIN_PASSPHRASE_LEN: usize = 12;
+
+const DEFAULT_MOUNT: &str = "secret";
+const DEFAULT_SECRET_PATH: &str = "personas/master-key";
+const DEFAULT_FIELD: &str = "key";
+const VAULT_HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+// ---------------------------------------------------------------------------
+// Configuration
+// ---------------------------------------------------------------------------
+
+/// Where the master key is stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultBackendKind {
+    #[default]
+    Keychain,
+    EncryptedFile,
+    HashicorpVault,
+}
+
+impl VaultBackendKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Keychain => "keychain",
+            Self::EncryptedFile => "encrypted_file",
+            Self::HashicorpVault => "hashicorp_vault",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "keychain" => Some(Self::Keychain),
+            "encrypted_file" => Some(Self::EncryptedFile),
+            "hashicorp_vault" => Some(Self::HashicorpVault),
+            _ => None,
+        }
+    }
+}
+
+/// Persisted backend selection (JSON in the `vault_backend` setting).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase", default)]
+pub struct VaultBackendConfig {
+    pub backend: VaultBackendKind,
+    /// HashiCorp Vault address, e.g. `https://vault.example.com:8200`.
+    pub vault_addr: Option<String>,
+    /// KV v2 mount (default `secret`).
+    pub vault_mount: Option<String>,
+    /// Secret path under the mount (default `personas/master-key`).
+    pub vault_path: Option<String>,
+    /// Field of the secret holding the base64 key (default `key`).
+    pub vault_field: Option<String>,
+}
+
+impl VaultBackendConfig {
+    fn mount(&self) -> &str {
+        self.vault_mount.as_deref().unwrap_or(DEFAULT_MOUNT)
+    }
+
+    fn path(&self) -> &str {
+        self.vault_path.as_deref().unwrap_or(DEFAULT_SECRET_PATH)
+    }
+
+    fn field(&self) -> &str {
+        self.vault_field.as_deref().unwrap_or(DEFAULT_FIELD)
+    }
+}
+
+/// Reject configurations that could never unlock.
+pub fn validate_config(config: &VaultBackendConfig) -> Result<(), String> {
+    if config.backend != VaultBackendKind::HashicorpVault {
+        return Ok(());
+    }
+    let addr = config.vault_addr.as_deref().unwrap_or("").trim();
+    if addr.is_empty() {
+        return Err("vaultAddr is required for the hashicorp_vault backend".into());
+    }
+    let url = url::Url::parse(addr).map_err(|e| format!("vaultAddr is not a valid URL: {e}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("vaultAddr must be an http(s) URL".into());
+    }
+    for (name, value) in [
+        ("vaultMount", config.mount()),
+        ("vaultPath", config.path()),
+        ("vaultField", config.field()),
+    ] {
+        if value.trim().is_empty() || value.contains("..") || value.starts_with('/') {
+            return Err(format!("{name} must be a non-empty relative path"));
+        }
+    }
+    Ok(())
+}
+
+/// The stored selection with environment overrides applied.
+fn resolve_config(pool: &DbPool) -> VaultBackendConfig {
+    let mut config = settings::get(pool, settings_keys::VAULT_BACKEND)
+        .ok()
+        .flatten()
+        .and_then(|raw| serde_json::from_str::<VaultBackendConfig>(&raw).ok())
+        .unwrap_or_default();
+    if let Ok(value) = std::env::var("PERSONAS_VAULT_BACKEND") {
+        match VaultBackendKind::parse(&value) {
+            Some(kind) => config.backend = kind,
+            None => tracing::warn!("Ignoring unknown PERSONAS_VAULT_BACKEND value '{}'", value),
+        }
+    }
+    if let Ok(addr) = std::env::var("VAULT_ADDR") {
+        if !addr.trim().is_empty() {
+            config.vault_addr = Some(addr);
+        }
+    }
+    config
+}
+
+static ACTIVE: OnceLock<VaultBackendConfig> = OnceLock::new();
+
+/// Backend in force for this process. `Keychain` until [`init`] runs.
+pub fn active() -> VaultBackendKind {
+    ACTIVE.get().map(|c| c.backend).unwrap_or_default()
+}
+
+/// Resolve the backend and try a non-interactive unlock from the environment.
+/// Must run before anything touches the master key. Never fails: a backend
+/// that cannot unlock yet leaves the vault locked until `unlock_vault`.
+pub fn init(pool: &DbPool) {
+    let config = resolve_config(pool);
+    if let Err(e) = validate_config(&config) {
+        tracing::error!("Vault backend configuration is invalid: {}", e);
+    }
+    let config = ACTIVE.get_or_init(|| config);
+
+    let secret = match config.backend {
+        VaultBackendKind::Keychain => return,
+        VaultBackendKind::EncryptedFile => std::env::var("PERSONAS_VAULT_PASSPHRASE").ok(),
+        VaultBackendKind::HashicorpVault => std::env::var("VAULT_TOKEN").ok(),
+    };
+    let Some(secret) = secret.filter(|s| !s.is_empty()) else {
+        tracing::warn!(
+            "Vault backend '{}' is locked; credentials are unavailable until it is unlocked",
+            config.backend.label()
+        );
+        return;
+    };
+    match tauri::async_runtime::block_on(unlock(pool, &secret)) {
+        Ok(()) => tracing::info!("Vault backend '{}' unlocked", config.backend.label()),
+        Err(e) => tracing::error!(
+            "Vault backend '{}' unlock failed: {}",
+            config.backend.label(),
+            e
+        ),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Unlock / configure
+// ---------------------------------------------------------------------------
+
+/// Unlock the active backend with a passphrase (`encrypted_file`) or token
+/// (`hashicorp_vault`). When the backend holds no key yet and the vault has
+/// no credentials, a fresh key is generated and stored there.
+pub async fn unlock(pool: &DbPool, secret: &str) -> Result<(), AppError> {
+    let config = ACTIVE.get().cloned().unwrap_or_default();
+    let stored = match config.backend {
+        VaultBackendKind::Keychain => {
+            return Err(AppError::Validation(
+                "The keychain backend does not need unlocking".into(),
+            ))
+        }
+        VaultBackendKind::EncryptedFile => read_file_key(secret)?,
+        VaultBackendKind::HashicorpVault => read_vault_key(&config, secret).await?,
+    };
+
+    let key = match stored {
+        Some(key) => key,
+        None => {
+            let (total, _) = credentials::count_vault_status(pool)?;
+            if total > 0 {
+                return Err(AppError::Validation(format!(
+                    "The {} backend holds no master key, but {} credential(s) are already \
+                     encrypted. Configure the backend from a running install to copy its key.",
+                    config.backend.label(),
+                    total
+                )));
+            }
+            let mut key = [0u8; 32];
+            OsRng.fill_bytes(&mut key);
+            store_key(&config, &key, secret).await?;
+            tracing::info!(
+                "Generated a new master key in the {} backend",
+                config.backend.label()
+            );
+            key
+        }
+    };
+
+    let source = match config.backend {
+        VaultBackendKind::HashicorpVault => KeySource::ExternalVault,
+        _ => KeySource::EncryptedFile,
+    };
+    crypto::install_master_key(key, source)?;
+    Ok(())
+}
+
+/// Copy the live master key into `config`'s backend and persist the selection.
+/// `secret` is the passphrase (`encrypted_file`) or token (`hashicorp_vault`);
+/// unused for `keychain`. Takes effect on the next start.
+pub async fn configure(
+    pool: &DbPool,
+    config: VaultBackendConfig,
+    secret: Option<&str>,
+) -> Result<(), AppError> {
+    validate_config(&config).map_err(AppError::Validation)?;
+    let key = *crypto::get_master_key()?;
+    let secret = secret.unwrap_or("");
+    if config.backend == VaultBackendKind::EncryptedFile && secret.len() < MIN_PASSPHRASE_LEN {
+        return Err(AppError::Validation(format!(
+            "Passphrase must be at least {MIN_PASSPHRASE_LEN} characters"
+        )));
+    }
+    if config.backend == VaultBackendKind::HashicorpVault && secret.is_empty() {
+        return Err(AppError::Validation("A Vault token is required".into()));
+    }
+
+    store_key(&config, &key, secret).await?;
+    let raw = serde_json::to_string(&config).map_err(|e| AppError::Internal(e.to_string()))?;
+    settings::set(pool, settings_keys::VAULT_BACKEND, &raw)?;
+    tracing::info!(
+        "Master key copied to the {} backend; it takes effect on next start",
+        config.backend.label()
+    );
+    Ok(())
+}
+
+async fn store_key(
+    config: &VaultBackendConfig,
+    key: &[u8; 32],
+    secret: &str,
+) -> Result<(), AppError> {
+    match config.backend {
+        VaultBackendKind::Keychain => Ok(crypto::store_key_in_keychain(key)?),
+        VaultBackendKind::EncryptedFile => write_file_key(key, secret),
+        VaultBackendKind::HashicorpVault => write_vault_key(config, key, secret).await,
+    }
+}
+
+/// Current selection and lock state, for the settings UI.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultBackendStatus {
+    /// Backend in force for this process.
+    pub active: VaultBackendKind,
+    /// Stored selection; differs from `active` until the next start.
+    pub configured: VaultBackendConfig,
+    /// Whether the master key is not available yet.
+    pub locked: bool,
+    pub key_source: String,
+}
+
+pub fn status(pool: &DbPool) -> VaultBackendStatus {
+    VaultBackendStatus {
+        active: active(),
+        configured: resolve_config(pool),
+        locked: crypto::key_source().is_none() && active() != VaultBackendKind::Keychain,
+        key_source: crypto::key_source_label().to_string(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Encrypted file backend
+// ---------------------------------------------------------------------------
+
+fn key_file_path() -> Result<PathBuf, AppError> {
+    if let Ok(path) = std::env::var("PERSONAS_VAULT_FILE") {
+        if !path.trim().is_empty() {
+            return Ok(PathBuf::from(path));
+        }
+    }
+    crypto::local_key_dir()
+        .map(|dir| dir.join("vault.key"))
+        .ok_or_else(|| AppError::Internal("Cannot determine the app data directory".into()))
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, PBKDF2_ITERATIONS, &mut key);
+    key
+}
+
+pub(crate) fn seal(master: &[u8; 32], passphrase: &str) -> Result<Vec<u8>, CryptoError> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+    let mut wrapping = derive_key(passphrase, &salt);
+    let cipher = Aes256Gcm::new_from_slice(&wrapping)
+        .map_err(|e| CryptoError::Encrypt(format!("Cipher init failed: {e}")));
+    wrapping.zeroize();
+    let ciphertext = cipher?
+        .encrypt(Nonce::from_slice(&nonce), master.as_slice())
+        .map_err(|e| CryptoError::Encrypt(e.to_string()))?;
+
+    let mut out = Vec::with_capacity(FILE_MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(FILE_MAGIC);
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+pub(crate) fn unseal(sealed: &[u8], passphrase: &str) -> Result<[u8; 32], AppError> {
+    let header = FILE_MAGIC.len() + SALT_LEN + NONCE_LEN;
+    if sealed.len() <= header || &sealed[..FILE_MAGIC.len()] != FILE_MAGIC {
+        return Err(AppError::Validation(
+            "Not a sealed Personas master key".into(),
+        ));
+    }
+    let salt = &sealed[FILE_MAGIC.len()..FILE_MAGIC.len() + SALT_LEN];
+    let nonce = &sealed[FILE_MAGIC.len() + SALT_LEN..header];
+    let mut wrapping = derive_key(passphrase, salt);
+    let cipher = Aes256Gcm::new_from_slice(&wrapping)
+        .map_err(|e| AppError::Internal(format!("Cipher init failed: {e}")));
+    wrapping.zeroize();
+    let mut plain = cipher?
+        .decrypt(Nonce::from_slice(nonce), &sealed[header..])
+        .map_err(|_| AppError::Validation("Wrong passphrase or corrupted key file".into()))?;
+    let key = to_key(&plain);
+    plain.zeroize();
+    key
+}
+
+fn read_file_key(passphrase: &str) -> Result<Option<[u8; 32]>, AppError> {
+    let path = key_file_path()?;
+    match fs::read(&path) {
+        Ok(sealed) => unseal(&sealed, passphrase).map(Some),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(AppError::Internal(format!(
+            "Cannot read {}: {e}",
+            path.display()
+        ))),
+    }
+}
+
+fn write_file_key(master: &[u8; 32], passphrase: &str) -> Result<(), AppError> {
+    let path = key_file_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let sealed = seal(master, passphrase)?;
+    // Write beside the target and rename so a crash never leaves a torn file.
+    let tmp = path.with_extension("key.tmp");
+    fs::write(&tmp, &sealed)?;
+    crypto::restrict_file_permissions(&tmp)?;
+    fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+fn to_key(bytes: &[u8]) -> Result<[u8; 32], AppError> {
+    if bytes.len() != 32 {
+        return Err(AppError::Internal(format!(
+            "Stored master key has wrong length: {} (expected 32)",
+            bytes.len()
+        )));
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(bytes);
+    Ok(key)
+}
+
+// ---------------------------------------------------------------------------
+// HashiCorp Vault KV v2 backend
+// ---------------------------------------------------------------------------
+
+fn vault_url(config: &VaultBackendConfig) -> Result<String, AppError> {
+    let addr = config
+        .vault_addr
+        .as_deref()
+        .ok_or_else(|| AppError::Validation("vaultAddr is not configured".into()))?;
+    Ok(format!(
+        "{}/v1/{}/data/{}",
+        addr.trim_end_matches('/'),
+        config.mount().trim_matches('/'),
+        config.path().trim_matches('/')
+    ))
+}
+
+fn vault_client() -> Result<reqwest::Client, AppError> {
+    reqwest::Client::builder()
+        .timeout(VAULT_HTTP_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| AppError::Internal(format!("HTTP client init failed: {e}")))
+}
+
+async fn read_vault_key(
+    config: &VaultBackendConfig,
+    token: &str,
+) -> Result<Option<[u8; 32]>, AppError> {
+    let resp = vault_client()?
+        .get(vault_url(config)?)
+        .header("X-Vault-Token", token)
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(format!("Vault request failed: {e}")))?;
+    match resp.status() {
+        reqwest::StatusCode::NOT_FOUND => return Ok(None),
+        s if s.is_success() => {}
+        s => {
+            return Err(AppError::Internal(format!(
+                "Vault returned HTTP {} reading the master key",
+                s.as_u16()
+            )))
+        }
+    }
+    let body: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| AppError::Internal(format!("Vault response is not JSON: {e}")))?;
+    let Some(encoded) = body["data"]["data"][config.field()].as_str() else {
+        return Ok(None);
+    };
+    let mut bytes = B64.decode(encoded.trim()).map_err(CryptoError::from)?;
+    let key = to_key(&bytes);
+    bytes.zeroize();
+    key.map(Some)
+}
+
+async fn write_vault_key(
+    config: &VaultBackendConfig,
+    key: &[u8; 32],
+    token: &str,
+) -> Result<(), AppError> {
+    let mut data = serde_json::Map::new();
+    data.insert(config.field().to_string(), B64.encode(key).into());
+    let resp = vault_client()?
+        .post(vault_url(config)?)
+        .header("X-Vault-Token", token)
+        .json(&serde_json::json!({ "data": data }))
+        .send()
+        .await
+        .map_err(|e| AppError::Internal(format!("Vault request failed: {e}")))?;
+    if !resp.status().is_success() {
+        return Err(AppError::Internal(format!(
+            "Vault returned HTTP {} storing the master key",
+            resp.status().as_u16()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sealed_key_round_trips_and_rejects_wrong_passphrase() {
+        let key = [7u8; 32];
+        let sealed = seal(&key, "correct horse battery").unwrap();
+        assert_eq!(unseal(&sealed, "correct horse battery").unwrap(), key);
+        assert!(unseal(&sealed, "wrong passphrase!").is_err());
+    }
+
+    #[test]
+    fn hashicorp_config_requires_an_address() {
+        let mut config = VaultBackendConfig {
+            backend: VaultBackendKind::HashicorpVault,
+            ..Default::default()
+        };
+        assert!(validate_config(&config).is_err());
+        config.vault_addr = Some("https://vault.example.com:8200".into());
+        assert!(validate_config(&config).is_ok());
+        assert_eq!(
+            vault_url(&config).unwrap(),
+            "https://vault.example.com:8200/v1/secret/data/personas/master-key"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Moving the credential master key between machines.
+//!
+//! Copying the app data directory to a new machine carries the database but
+//! not the OS keychain entry, so the new install generates a fresh master key
+//! and every encrypted credential becomes unreadable. [`export_key`] seals the
+//! current master key with a passphrase; on the new machine [`import_key`]
+//! unseals it and re-encrypts everything written under it with the new
+//! machine's key, so no keychain entry has to be carried over.
+//!
+//! Re-encryption covers every value sealed directly by the master key:
+//! credential fields and legacy credential blobs, project data key wraps,
+//! master-key event payloads, and the `<name>_enc` / `<name>_iv` pairs
+//! embedded in persona model profiles, notification channels and trigger
+//! configs. Values the current key already opens are left alone, so an import
+//! can be repeated safely.
+
+use aes_gcm::aead::KeyInit;
+use aes_gcm::{Aes256Gcm, Key};
+use base64::engine::general_purpose::STANDARD as B64;
+use base64::Engine as _;
+use serde::Serialize;
+use ts_rs::TS;
+use zeroize::Zeroize;
+
+use super::crypto;
+use super::project_keys::IV_PREFIX;
+use super::vault_backend::{self, MIN_PASSPHRASE_LEN};
+use crate::db::DbPool;
+use crate::error::AppError;
+
+/// Ciphertext/IV column pairs sealed directly by the master key:
+/// `(table, ciphertext column, iv column)`.
+const COLUMN_PAIRS: &[(&str, &str, &str)] = &[
+    ("credential_fields", "encrypted_value", "iv"),
+    ("persona_credentials", "encrypted_data", "iv"),
+    ("project_data_keys", "wrapped_key", "wrap_iv"),
+    ("persona_events", "payload", "payload_iv"),
+];
+
+/// JSON columns holding `<name>_enc` / `<name>_iv` pairs: `(table, column)`.
+const JSON_COLUMNS: &[(&str, &str)] = &[
+    ("personas", "model_profile"),
+    ("personas", "notification_channels"),
+    ("persona_triggers", "config"),
+];
+
+/// Outcome of [`import_key`].
+#[derive(Debug, Clone, Default, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultKeyImportReport {
+    /// The imported key is the one already in use; nothing was rewritten.
+    pub same_key: bool,
+    /// Values moved from the imported key to the current one.
+    pub reencrypted: usize,
+    /// Values the current key already opens.
+    pub already_current: usize,
+    /// Values neither key opens; left untouched.
+    pub failed: usize,
+}
+
+/// Seal the current master key with `passphrase` for transfer to another
+/// machine. Returns the sealed key as base64.
+pub fn export_key(passphrase: &str) -> Result<String, AppError> {
+    if passphrase.len() < MIN_PASSPHRASE_LEN {
+        return Err(AppError::Validation(format!(
+            "Passphrase must be at least {MIN_PASSPHRASE_LEN} characters"
+        )));
+    }
+    let key = crypto::get_master_key()?;
+    let sealed = vault_backend::seal(key, passphrase)?;
+    tracing::warn!("Master key exported (passphrase-sealed)");
+    Ok(B64.encode(sealed))
+}
+
+/// Unseal a key exported by [`export_key`] and re-encrypt everything written
+/// under it with this machine's master key, in one transaction.
+pub fn import_key(
+    pool: &DbPool,
+    exported: &str,
+    passphrase: &str,
+) -> Result<VaultKeyImportReport, AppError> {
+    let sealed = B64
+        .decode(exported.trim())
+        .map_err(|_| AppError::Validation("Exported key is not valid base64".into()))?;
+    let mut old_key = vault_backend::unseal(&sealed, passphrase)?;
+    let same_key = &old_key == crypto::get_master_key()?;
+    let old = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&old_key));
+    old_key.zeroize();
+    if same_key {
+        return Ok(VaultKeyImportReport {
+            same_key: true,
+            ..Default::default()
+        });
+    }
+
+    let mut report = VaultKeyImportReport::default();
+    let mut conn = pool.get()?;
+    let tx = conn.transaction()?;
+
+    for &(table, ct_col, iv_col) in COLUMN_PAIRS {
+        let rows: Vec<(i64, String, String)> = {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT rowid, {ct_col}, {iv_col} FROM {table}
+                 WHERE {iv_col} IS NOT NULL AND {iv_col} <> '' AND {iv_col} NOT LIKE '{IV_PREFIX}%'
+                   AND {ct_col} IS NOT NULL"
+            ))?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (rowid, ciphertext, iv) in rows {
+            if let Some((ct, nonce)) = reseal(&old, &ciphertext, &iv, &mut report) {
+                tx.execute(
+                    &format!("UPDATE {table} SET {ct_col} = ?1, {iv_col} = ?2 WHERE rowid = ?3"),
+                    rusqlite::params![ct, nonce, rowid],
+                )?;
+            }
+        }
+    }
+
+    for &(table, column) in JSON_COLUMNS {
+        let rows: Vec<(i64, String)> = {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT rowid, {column} FROM {table} WHERE {column} LIKE '%\\_enc\"%' ESCAPE '\\'"
+            ))?;
+            let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+            rows.collect::<Result<_, _>>()?
+        };
+        for (rowid, raw) in rows {
+            let Ok(mut value) = serde_json::from_str::<serde_json::Value>(&raw) else {
+                continue;
+            };
+            if reseal_json(&old, &mut value, &mut report) {
+                tx.execute(
+                    &format!("UPDATE {table} SET {column} = ?1 WHERE rowid = ?2"),
+                    rusqlite::params![value.to_string(), rowid],
+                )?;
+            }
+        }
+    }
+
+    tx.commit()?;
+    tracing::info!(
+        reencrypted = report.reencrypted,
+        already_current = report.already_current,
+        failed = report.failed,
+        "Imported master key; re-encrypted vault under the current key"
+    );
+    Ok(report)
+}
+
+/// Move one value from `old` to the current key. `None` when it needs no
+/// rewrite (already current, or unreadable with either key).
+fn reseal(
+    old: &Aes256Gcm,
+    ciphertext: &str,
+    iv: &str,
+    report: &mut VaultKeyImportReport,
+) -> Option<(String, String)> {
+    if crypto::decrypt_from_db(ciphertext, iv).is_ok() {
+        report.already_current += 1;
+        return None;
+    }
+    let resealed =
+        crypto::decrypt_with(old, ciphertext, iv).and_then(|plain| crypto::encrypt_for_db(&plain));
+    match resealed {
+        Ok(pair) => {
+            report.reencrypted += 1;
+            Some(pair)
+        }
+        Err(_) => {
+            report.failed += 1;
+            None
+        }
+    }
+}
+
+/// Reseal every `<name>_enc` / `<name>_iv` pair in a JSON tree. Returns
+/// whether anything changed.
+fn reseal_json(
+    old: &Aes256Gcm,
+    value: &mut serde_json::Value,
+    report: &mut VaultKeyImportReport,
+) -> bool {
+    let mut changed = false;
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                changed |= reseal_json(old, item, report);
+            }
+        }
+        serde_json::Value::Object(obj) => {
+            let names: Vec<String> = obj
+                .keys()
+                .filter_map(|k| k.strip_suffix("_enc").map(str::to_string))
+                .collect();
+            for name in names {
+                let iv_key = format!("{name}_iv");
+                let enc_key = format!("{name}_enc");
+                let (Some(ct), Some(iv)) = (
+                    obj.get(&enc_key).and_then(|v| v.as_str()),
+                    obj.get(&iv_key).and_then(|v| v.as_str()),
+                ) else {
+                    continue;
+                };
+                if let Some((ct, nonce)) = reseal(old, ct, iv, report) {
+                    obj.insert(enc_key, ct.into());
+                    obj.insert(iv_key, nonce.into());
+                    changed = true;
+                }
+            }
+            for child in obj.values_mut() {
+                if child.is_object() || child.is_array() {
+                    changed |= reseal_json(old, child, report);
+                }
+            }
+        }
+        _ => {}
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reseal_json_moves_nested_pairs_to_the_current_key() {
+        let old = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&[9u8; 32]));
+        let (ct, iv) = crypto::encrypt_with(&old, "hunter2").unwrap();
+        let mut value = serde_json::json!([
+            { "type": "slack", "config": { "token_enc": ct, "token_iv": iv } }
+        ]);
+
+        let mut report = VaultKeyImportReport::default();
+        assert!(reseal_json(&old, &mut value, &mut report));
+        assert_eq!(report.reencrypted, 1);
+
+        let config = &value[0]["config"];
+        let plain = crypto::decrypt_from_db(
+            config["token_enc"].as_str().unwrap(),
+            config["token_iv"].as_str().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(plain, "hunter2");
+
+        // A second pass finds everything already under the current key.
+        let mut again = VaultKeyImportReport::default();
+        assert!(!reseal_json(&old, &mut value, &mut again));
+        assert_eq!(again.already_current, 1);
+    }
+}
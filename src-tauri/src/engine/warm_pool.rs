@@ -0,0 +1,461 @@
+//! Warm CLI session pool.
+//!
+//! Most of a short execution's start latency is the provider CLI booting
+//! (runtime start, config load, auth check) before it reads the prompt. When
+//! `engine_warm_pool_size` is non-zero, the runner keeps one idle, pre-spawned
+//! session per frequently-run persona: a CLI started with that persona's exact
+//! arguments, blocked on stdin. The next run of the persona takes it over and
+//! writes the prompt straight in instead of spawning cold.
+//!
+//! A session is only reused when it was spawned with the same command, args,
+//! env and working directory the run would use ([`fingerprint`]), ignoring the
+//! per-run trace/execution env vars. Runs that resume a session, deliver the
+//! prompt in argv, run in an isolated worktree or read the per-run MCP sidecar
+//! config (`--mcp-config`, rewritten and scrubbed around every run) always
+//! start cold. Idle sessions are killed after [`WARM_SESSION_TTL`], and the
+//! pool never holds more than the configured count.
+//!
+//! Idle PIDs are registered in `child_pids` under `warm:<persona_id>:<pid>`
+//! so the orphan reaper leaves them alone. Saved latency is estimated per
+//! persona against a moving average of cold start-to-first-output times and
+//! exposed by [`snapshot`] (`get_warm_pool_stats`).
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use ts_rs::TS;
+
+use super::cli_process::CliProcessDriver;
+use super::types::CliArgs;
+use crate::db::settings_keys;
+use crate::db::DbPool;
+
+/// Idle sessions older than this are killed rather than handed to a run.
+pub const WARM_SESSION_TTL: Duration = Duration::from_secs(5 * 60);
+/// A persona counts as frequently run after this many runs ...
+const FREQUENT_RUNS: usize = 3;
+/// ... within this window.
+const FREQUENT_WINDOW: Duration = Duration::from_secs(60 * 60);
+/// Weight of the newest sample in the cold-start moving average.
+const COLD_EWMA_ALPHA: f64 = 0.3;
+
+/// Env vars that carry the identity of one run and must not be baked into a
+/// pre-spawned session, nor break a fingerprint match.
+const RUN_SCOPED_ENV: &[&str] = &[
+    "TRACEPARENT",
+    "TRACESTATE",
+    super::process_reaper::EXECUTION_ID_ENV,
+];
+
+/// Args that tie a spawn to state that only exists for one run.
+const RUN_SCOPED_ARGS: &[&str] = &["--mcp-config", "--resume"];
+
+struct WarmSession {
+    driver: CliProcessDriver,
+    fingerprint: u64,
+    spawned_at: Instant,
+}
+
+#[derive(Default)]
+struct Counters {
+    hits: u64,
+    misses: u64,
+    spawned: u64,
+    expired: u64,
+    discarded: u64,
+    saved_ms: u64,
+}
+
+struct State {
+    since: String,
+    idle: HashMap<String, WarmSession>,
+    recent_runs: HashMap<String, VecDeque<Instant>>,
+    cold_startup_ms: HashMap<String, f64>,
+    counters: Counters,
+}
+
+static STATE: Mutex<Option<State>> = Mutex::new(None);
+
+fn with_state<R>(f: impl FnOnce(&mut State) -> R) -> R {
+    let mut guard = STATE.lock().unwrap_or_else(|e| e.into_inner());
+    let state = guard.get_or_insert_with(|| State {
+        since: chrono::Utc::now().to_rfc3339(),
+        idle: HashMap::new(),
+        recent_runs: HashMap::new(),
+        cold_startup_ms: HashMap::new(),
+        counters: Counters::default(),
+    });
+    f(state)
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmPoolSnapshot {
+    /// Start of the measurement window (process start).
+    pub since: String,
+    /// Configured pool size; `0` when the pool is off.
+    pub pool_size: u32,
+    /// Personas with an idle session right now.
+    pub idle_persona_ids: Vec<String>,
+    /// Runs that attached to a warm session.
+    #[ts(type = "number")]
+    pub hits: u64,
+    /// Eligible runs that found no usable session and spawned cold.
+    #[ts(type = "number")]
+    pub misses: u64,
+    /// Sessions pre-spawned.
+    #[ts(type = "number")]
+    pub spawned: u64,
+    /// Sessions killed unused after the idle TTL or to make room.
+    #[ts(type = "number")]
+    pub expired: u64,
+    /// Sessions dropped because they had exited or no longer matched the
+    /// persona's configuration.
+    #[ts(type = "number")]
+    pub discarded: u64,
+    /// Estimated start latency saved across all hits.
+    #[ts(type = "number")]
+    pub saved_ms: u64,
+}
+
+/// Configured pool size; `0` when unset, invalid or off.
+pub fn pool_size(pool: &DbPool) -> usize {
+    crate::db::repos::core::settings::get(pool, settings_keys::ENGINE_WARM_POOL_SIZE)
+        .ok()
+        .flatten()
+        .and_then(|v| v.parse::<u32>().ok())
+        .unwrap_or(settings_keys::ENGINE_WARM_POOL_SIZE_DEFAULT)
+        .min(settings_keys::ENGINE_WARM_POOL_SIZE_MAX) as usize
+}
+
+/// Identity of a spawn, or `None` when these args can never be pre-spawned.
+pub fn fingerprint(cli_args: &CliArgs, exec_dir: &Path) -> Option<u64> {
+    if cli_args
+        .args
+        .iter()
+        .any(|a| RUN_SCOPED_ARGS.contains(&a.as_str()))
+    {
+        return None;
+    }
+    let mut hasher = DefaultHasher::new();
+    cli_args.command.hash(&mut hasher);
+    cli_args.args.hash(&mut hasher);
+    for (key, val) in &cli_args.env_overrides {
+        if !RUN_SCOPED_ENV.contains(&key.as_str()) {
+            (key, val).hash(&mut hasher);
+        }
+    }
+    cli_args.env_removals.hash(&mut hasher);
+    cli_args.cwd.hash(&mut hasher);
+    exec_dir.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// `child_pids` key of an idle session. Includes the PID so a replacement
+/// session of the same persona never overwrites or drops another's entry.
+fn pid_key(persona_id: &str, driver: &CliProcessDriver) -> String {
+    format!("warm:{persona_id}:{}", driver.pid().unwrap_or_default())
+}
+
+/// Record a run of `persona_id` towards its "frequently run" status.
+pub fn note_run(persona_id: &str) {
+    let now = Instant::now();
+    with_state(|s| {
+        let runs = s.recent_runs.entry(persona_id.to_string()).or_default();
+        runs.push_back(now);
+        while runs
+            .front()
+            .is_some_and(|t| now.duration_since(*t) > FREQUENT_WINDOW)
+        {
+            runs.pop_front();
+        }
+    });
+}
+
+fn is_frequent(state: &State, persona_id: &str) -> bool {
+    let now = Instant::now();
+    state.recent_runs.get(persona_id).is_some_and(|runs| {
+        runs.iter()
+            .filter(|t| now.duration_since(**t) <= FREQUENT_WINDOW)
+            .count()
+            >= FREQUENT_RUNS
+    })
+}
+
+/// Remove idle sessions past the TTL, returning them for the caller to kill
+/// outside the lock.
+fn drain_expired(state: &mut State) -> Vec<(String, CliProcessDriver)> {
+    let expired: Vec<String> = state
+        .idle
+        .iter()
+        .filter(|(_, s)| s.spawned_at.elapsed() >= WARM_SESSION_TTL)
+        .map(|(id, _)| id.clone())
+        .collect();
+    state.counters.expired += expired.len() as u64;
+    expired
+        .into_iter()
+        .filter_map(|id| state.idle.remove(&id).map(|s| (id, s.driver)))
+        .collect()
+}
+
+async fn kill_all(
+    sessions: Vec<(String, CliProcessDriver)>,
+    child_pids: &tokio::sync::Mutex<HashMap<String, u32>>,
+) {
+    for (persona_id, mut driver) in sessions {
+        driver.kill().await;
+        driver
+            .unregister_pid(child_pids, &pid_key(&persona_id, &driver))
+            .await;
+    }
+}
+
+/// Hand the idle session for `persona_id` to a run, if one exists, is still
+/// alive and was spawned with the same `fingerprint`.
+pub(crate) async fn take(
+    persona_id: &str,
+    fingerprint: u64,
+    child_pids: &tokio::sync::Mutex<HashMap<String, u32>>,
+) -> Option<CliProcessDriver> {
+    let (session, expired) = with_state(|s| {
+        let expired = drain_expired(s);
+        (s.idle.remove(persona_id), expired)
+    });
+    kill_all(expired, child_pids).await;
+
+    let Some(mut session) = session else {
+        with_state(|s| s.counters.misses += 1);
+        return None;
+    };
+    session
+        .driver
+        .unregister_pid(child_pids, &pid_key(persona_id, &session.driver))
+        .await;
+    let alive = matches!(session.driver.child.try_wait(), Ok(None));
+    if alive && session.fingerprint == fingerprint {
+        with_state(|s| s.counters.hits += 1);
+        return Some(session.driver);
+    }
+    tracing::debug!(
+        persona_id,
+        alive,
+        "Warm pool: idle session unusable, spawning cold"
+    );
+    session.driver.kill().await;
+    with_state(|s| {
+        s.counters.discarded += 1;
+        s.counters.misses += 1;
+    });
+    None
+}
+
+/// Pre-spawn the next session for `persona_id` in the background, when the
+/// pool is on, the persona runs often and it has no idle session yet. The
+/// oldest idle session is evicted if the pool is full.
+pub fn replenish(
+    pool_size: usize,
+    persona_id: String,
+    mut cli_args: CliArgs,
+    exec_dir: PathBuf,
+    child_pids: Arc<tokio::sync::Mutex<HashMap<String, u32>>>,
+) {
+    if pool_size == 0 {
+        return;
+    }
+    let Some(fingerprint) = fingerprint(&cli_args, &exec_dir) else {
+        return;
+    };
+    let wanted = with_state(|s| is_frequent(s, &persona_id) && !s.idle.contains_key(&persona_id));
+    if !wanted {
+        return;
+    }
+    cli_args
+        .env_overrides
+        .retain(|(key, _)| !RUN_SCOPED_ENV.contains(&key.as_str()));
+
+    tokio::spawn(async move {
+        let driver = match CliProcessDriver::spawn(&cli_args, exec_dir) {
+            Ok(driver) => driver,
+            Err(e) => {
+                tracing::debug!(persona_id = %persona_id, "Warm pool: pre-spawn failed: {e}");
+                return;
+            }
+        };
+        driver
+            .register_pid(&child_pids, &pid_key(&persona_id, &driver))
+            .await;
+
+        let (rejected, evicted) = with_state(|s| {
+            let mut evicted = drain_expired(s);
+            if s.idle.contains_key(&persona_id) {
+                return (Some(driver), evicted);
+            }
+            while s.idle.len() >= pool_size {
+                let Some(oldest) = s
+                    .idle
+                    .iter()
+                    .min_by_key(|(_, session)| session.spawned_at)
+                    .map(|(id, _)| id.clone())
+                else {
+                    break;
+                };
+                if let Some(session) = s.idle.remove(&oldest) {
+                    s.counters.expired += 1;
+                    evicted.push((oldest, session.driver));
+                }
+            }
+            s.idle.insert(
+                persona_id.clone(),
+                WarmSession {
+                    driver,
+                    fingerprint,
+                    spawned_at: Instant::now(),
+                },
+            );
+            s.counters.spawned += 1;
+            (None, evicted)
+        });
+        kill_all(evicted, &child_pids).await;
+        if let Some(mut driver) = rejected {
+            // A concurrent replenish won the slot; this one is surplus.
+            driver.kill().await;
+            driver
+                .unregister_pid(&child_pids, &pid_key(&persona_id, &driver))
+                .await;
+            return;
+        }
+
+        // Auto-expiry: without this an unused session would idle until the
+        // next run of any persona happened to sweep it.
+        tokio::time::sleep(WARM_SESSION_TTL).await;
+        let expired = with_state(drain_expired);
+        kill_all(expired, &child_pids).await;
+    });
+}
+
+/// Account a run's start-to-first-output time. Cold runs feed the persona's
+/// baseline; warm runs are credited with what they saved against it.
+pub fn record_startup(persona_id: &str, warm: bool, startup_ms: u64) {
+    with_state(|s| record_startup_in(s, persona_id, warm, startup_ms));
+}
+
+fn record_startup_in(state: &mut State, persona_id: &str, warm: bool, startup_ms: u64) {
+    if warm {
+        if let Some(cold) = state.cold_startup_ms.get(persona_id) {
+            state.counters.saved_ms += (*cold - startup_ms as f64).max(0.0) as u64;
+        }
+        return;
+    }
+    state
+        .cold_startup_ms
+        .entry(persona_id.to_string())
+        .and_modify(|avg| *avg += COLD_EWMA_ALPHA * (startup_ms as f64 - *avg))
+        .or_insert(startup_ms as f64);
+}
+
+pub fn snapshot(pool_size: usize) -> WarmPoolSnapshot {
+    with_state(|s| {
+        let mut idle_persona_ids: Vec<String> = s.idle.keys().cloned().collect();
+        idle_persona_ids.sort();
+        WarmPoolSnapshot {
+            since: s.since.clone(),
+            pool_size: pool_size as u32,
+            idle_persona_ids,
+            hits: s.counters.hits,
+            misses: s.counters.misses,
+            spawned: s.counters.spawned,
+            expired: s.counters.expired,
+            discarded: s.counters.discarded,
+            saved_ms: s.counters.saved_ms,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(env: &[(&str, &str)], extra: &[&str]) -> CliArgs {
+        let mut args = vec!["-p".to_string(), "-".to_string()];
+        args.extend(extra.iter().map(|a| a.to_string()));
+        CliArgs {
+            command: "claude".into(),
+            args,
+            env_overrides: env
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            env_removals: Vec::new(),
+            cwd: None,
+        }
+    }
+
+    #[test]
+    fn fingerprint_ignores_run_scoped_env_and_rejects_sidecar_runs() {
+        let dir = Path::new("/tmp/personas-workspace/p1");
+        let a = fingerprint(
+            &args(
+                &[
+                    ("TOKEN", "x"),
+                    ("TRACEPARENT", "00-aa"),
+                    ("PERSONAS_EXECUTION_ID", "e1"),
+                ],
+                &[],
+            ),
+            dir,
+        );
+        let b = fingerprint(
+            &args(
+                &[
+                    ("TOKEN", "x"),
+                    ("TRACEPARENT", "00-bb"),
+                    ("PERSONAS_EXECUTION_ID", "e2"),
+                ],
+                &[],
+            ),
+            dir,
+        );
+        assert!(a.is_some());
+        assert_eq!(a, b);
+        assert_ne!(a, fingerprint(&args(&[("TOKEN", "y")], &[]), dir));
+        assert_ne!(
+            a,
+            fingerprint(
+                &args(&[("TOKEN", "x")], &[]),
+                Path::new("/tmp/personas-workspace/p2")
+            )
+        );
+        assert!(fingerprint(&args(&[], &["--mcp-config", "cfg.json"]), dir).is_none());
+    }
+
+    #[test]
+    fn warm_runs_are_credited_against_the_cold_baseline() {
+        let mut state = State {
+            since: String::new(),
+            idle: HashMap::new(),
+            recent_runs: HashMap::new(),
+            cold_startup_ms: HashMap::new(),
+            counters: Counters::default(),
+        };
+        // No baseline yet: nothing to credit.
+        record_startup_in(&mut state, "p1", true, 500);
+        assert_eq!(state.counters.saved_ms, 0);
+
+        record_startup_in(&mut state, "p1", false, 3000);
+        record_startup_in(&mut state, "p1", false, 2000);
+        assert_eq!(state.cold_startup_ms["p1"], 2700.0);
+
+        record_startup_in(&mut state, "p1", true, 700);
+        assert_eq!(state.counters.saved_ms, 2000);
+        // A warm run slower than the baseline saves nothing rather than
+        // subtracting.
+        record_startup_in(&mut state, "p1", true, 4000);
+        assert_eq!(state.counters.saved_ms, 2000);
+    }
+}
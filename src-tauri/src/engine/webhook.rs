@@ -19,14 +19,20 @@ use rusqlite::params;
 use crate::db::models::webhook_log::CreateWebhookRequestLogInput;
 use crate::db::models::CreatePersonaEventInput;
 use crate::db::models::PersonaEvent;
+use crate::db::pool_budget::{self, Subsystem};
+use crate::db::repos::communication::event_schemas;
 use crate::db::repos::communication::events as event_repo;
 use crate::db::repos::resources::triggers as trigger_repo;
 use crate::db::repos::resources::webhook_log as webhook_log_repo;
+use crate::db::repos::system_ops as system_op_repo;
 use crate::db::DbPool;
-use crate::engine::crypto;
+use crate::engine::background::SchedulerState;
+use crate::engine::project_keys;
 use crate::engine::rate_limiter::{RateLimiter, WEBHOOK_TRIGGER_WINDOW};
+use crate::engine::system_ops;
 use crate::engine::tier::TierConfig;
 use crate::error::AppError;
+use crate::gitlab::events as gitlab_events;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -36,6 +42,8 @@ pub struct WebhookState {
     pub pool: DbPool,
     pub rate_limiter: Arc<RateLimiter>,
     pub tier_config: Arc<std::sync::Mutex<TierConfig>>,
+    /// Read by `/healthz` and `/readyz` for scheduler liveness.
+    pub scheduler: Arc<SchedulerState>,
 }
 
 /// Port for the webhook + management HTTP server. Defaults to 9420; override
@@ -57,12 +65,14 @@ pub async fn start_webhook_server(
     pool: DbPool,
     rate_limiter: Arc<RateLimiter>,
     tier_config: Arc<std::sync::Mutex<TierConfig>>,
+    scheduler: Arc<SchedulerState>,
     mut shutdown_rx: watch::Receiver<bool>,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let state = WebhookState {
         pool,
         rate_limiter,
         tier_config,
+        scheduler,
     };
 
     // 1 MB body limit to prevent OOM DoS via oversized payloads
@@ -72,6 +82,8 @@ pub async fn start_webhook_server(
         .route("/webhook/{trigger_id}", post(handle_webhook))
         .route("/webhook/{trigger_id}", get(webhook_info))
         .route("/health", get(health))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
         .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
         .with_state(Arc::new(state));
     #[cfg(feature = "p2p")]
@@ -99,6 +111,7 @@ pub async fn start_webhook_server_with_management(
     pool: DbPool,
     rate_limiter: Arc<RateLimiter>,
     tier_config: Arc<std::sync::Mutex<TierConfig>>,
+    scheduler: Arc<SchedulerState>,
     app_handle: tauri::AppHandle,
     process_registry: Arc<crate::ActiveProcessRegistry>,
     mut shutdown_rx: watch::Receiver<bool>,
@@ -111,6 +124,7 @@ pub async fn start_webhook_server_with_management(
         pool: pool.clone(),
         rate_limiter: rate_limiter.clone(),
         tier_config,
+        scheduler,
     };
 
     let mgmt_state = super::management_api::ManagementState {
@@ -128,8 +142,13 @@ pub async fn start_webhook_server_with_management(
         .route("/webhook/{trigger_id}", post(handle_webhook))
         .route("/webhook/{trigger_id}", get(webhook_info))
         .route("/health", get(health))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
         .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
         .with_state(Arc::new(webhook_state))
+        // Per-persona execute hooks: the path token is the credential, so this
+        // sits outside the management API's api-key middleware.
+        .merge(super::execute_hook::execute_hook_router(mgmt_state.clone()))
         .merge(super::management_api::management_router(mgmt_state))
         // Pairing entry points (/pair/request, /pair/claim) — permissive CORS,
         // no api-key middleware; the nonce + user approval are the gate (Dir 1).
@@ -157,6 +176,146 @@ async fn health() -> impl IntoResponse {
     Json(serde_json::json!({ "status": "ok", "service": "personas-webhook" }))
 }
 
+/// Status snapshot served by `/healthz` and `/readyz` for external uptime
+/// monitoring (e.g. a headless install behind a reverse proxy). Aggregate
+/// counts only -- nothing persona- or trigger-specific.
+#[derive(Debug, Serialize)]
+struct HealthProbe {
+    /// `ok` when ready, `degraded` otherwise.
+    status: &'static str,
+    version: &'static str,
+    scheduler: SchedulerProbe,
+    database: DatabaseProbe,
+    queue: Option<QueueProbe>,
+}
+
+#[derive(Debug, Serialize)]
+struct SchedulerProbe {
+    running: bool,
+    subscriptions: usize,
+    /// Subscription loops that died after repeated panics.
+    dead_subscriptions: Vec<String>,
+    /// Last completed trigger-scheduler tick.
+    last_trigger_tick_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct DatabaseProbe {
+    reachable: bool,
+    latency_ms: u64,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct QueueProbe {
+    pending_events: i64,
+    queued_executions: i64,
+    running_executions: i64,
+}
+
+impl HealthProbe {
+    fn is_ready(&self) -> bool {
+        self.database.reachable
+            && self.scheduler.running
+            && self.scheduler.dead_subscriptions.is_empty()
+    }
+}
+
+/// Probe the database once (a single connection for reachability and queue
+/// depth) and read scheduler liveness from memory.
+fn probe_health(state: &WebhookState) -> HealthProbe {
+    let started = std::time::Instant::now();
+    let queue = state.pool.get().map_err(AppError::from).and_then(|conn| {
+        conn.query_row(
+            "SELECT
+                     (SELECT COUNT(*) FROM persona_events WHERE status = 'pending'),
+                     (SELECT COUNT(*) FROM persona_executions WHERE status = 'queued'),
+                     (SELECT COUNT(*) FROM persona_executions WHERE status = 'running')",
+            [],
+            |row| {
+                Ok(QueueProbe {
+                    pending_events: row.get(0)?,
+                    queued_executions: row.get(1)?,
+                    running_executions: row.get(2)?,
+                })
+            },
+        )
+        .map_err(AppError::from)
+    });
+    let database = DatabaseProbe {
+        reachable: queue.is_ok(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: queue.as_ref().err().map(|e| e.to_string()),
+    };
+
+    let subscriptions = state.scheduler.subscription_health();
+    let scheduler = SchedulerProbe {
+        running: state.scheduler.is_running(),
+        subscriptions: subscriptions.len(),
+        dead_subscriptions: subscriptions
+            .iter()
+            .filter(|s| !s.alive)
+            .map(|s| s.name.clone())
+            .collect(),
+        last_trigger_tick_at: subscriptions
+            .iter()
+            .find(|s| s.name == "trigger_scheduler")
+            .and_then(|s| s.last_tick_at.clone()),
+    };
+
+    let mut probe = HealthProbe {
+        status: "ok",
+        version: env!("CARGO_PKG_VERSION"),
+        scheduler,
+        database,
+        queue: queue.ok(),
+    };
+    if !probe.is_ready() {
+        probe.status = "degraded";
+    }
+    probe
+}
+
+async fn run_probe(state: Arc<WebhookState>) -> HealthProbe {
+    // Pool checkout can block up to POOL_ACQUIRE_TIMEOUT under contention.
+    tokio::task::spawn_blocking(move || probe_health(&state))
+        .await
+        .unwrap_or_else(|e| HealthProbe {
+            status: "degraded",
+            version: env!("CARGO_PKG_VERSION"),
+            scheduler: SchedulerProbe {
+                running: false,
+                subscriptions: 0,
+                dead_subscriptions: Vec::new(),
+                last_trigger_tick_at: None,
+            },
+            database: DatabaseProbe {
+                reachable: false,
+                latency_ms: 0,
+                error: Some(format!("health probe task failed: {e}")),
+            },
+            queue: None,
+        })
+}
+
+/// GET /healthz -- liveness. Always 200 while the server answers; the body
+/// carries the full status so a monitor can alert on `degraded`.
+async fn healthz(AxumState(state): AxumState<Arc<WebhookState>>) -> impl IntoResponse {
+    Json(run_probe(state).await)
+}
+
+/// GET /readyz -- readiness. 503 unless the database answers and the
+/// scheduler and all of its subscription loops are running.
+async fn readyz(AxumState(state): AxumState<Arc<WebhookState>>) -> impl IntoResponse {
+    let probe = run_probe(state).await;
+    let status = if probe.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(probe))
+}
+
 /// GET /webhook/{trigger_id} -- confirms the webhook endpoint exists and
 /// documents active window behavior without leaking internal metadata.
 async fn webhook_info(
@@ -233,6 +392,10 @@ fn serialize_headers(headers: &HeaderMap) -> String {
     serde_json::Value::Object(map).to_string()
 }
 
+/// How long a webhook waits for a background connection permit before
+/// answering 503.
+const POOL_PERMIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
 /// POST /webhook/{trigger_id} -- receive webhook payload, validate HMAC, publish event.
 async fn handle_webhook(
     AxumState(state): AxumState<Arc<WebhookState>>,
@@ -243,7 +406,7 @@ async fn handle_webhook(
     let headers_json = serialize_headers(&headers);
     let body_str = String::from_utf8_lossy(&body).to_string();
     // The event payload itself is encrypted at rest (see mark_triggered_and_publish
-    // below via crypto::encrypt_for_db). Don't undermine that by also writing the
+    // below via project_keys::encrypt_event_payload). Don't undermine that by also writing the
     // identical raw body into webhook_request_logs in plaintext — inbound webhooks
     // routinely carry secrets/PII (GitHub payloads, third-party tokens). Redact the
     // logged body to headers + status only; the durable, encrypted copy lives on the
@@ -254,6 +417,25 @@ async fn handle_webhook(
         Some("[redacted: see encrypted persona_event payload]".to_string())
     };
 
+    // Hold a background connection permit for processing *and* logging so a
+    // webhook burst can never take the connections reserved for IPC commands.
+    // When the budget stays exhausted, shed load with a 503 instead of
+    // queueing behind the pool; the log write is skipped for the same reason.
+    let permit = pool_budget::acquire(Subsystem::Webhook, POOL_PERMIT_TIMEOUT).await;
+    let Some(_permit) = permit else {
+        let mut resp_headers = HeaderMap::new();
+        resp_headers.insert("retry-after", HeaderValue::from_static("1"));
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            resp_headers,
+            Json(WebhookResponse {
+                accepted: false,
+                event_id: None,
+                error: Some("Server busy, retry shortly".into()),
+            }),
+        );
+    };
+
     let (status, extra_headers, response) =
         process_webhook(&state, &trigger_id, &headers, &body).await;
 
@@ -287,10 +469,16 @@ async fn process_webhook(
     headers: &HeaderMap,
     body: &Bytes,
 ) -> (StatusCode, HeaderMap, WebhookResponse) {
-    // 1. Look up the trigger
+    // 1. Look up the trigger. Ids that aren't persona triggers may belong to a
+    //    webhook-fired system-op automation (e.g. a scheduled team pipeline).
     let trigger = match trigger_repo::get_by_id(&state.pool, trigger_id) {
         Ok(t) => t,
         Err(_) => {
+            if let Ok(automation) = system_op_repo::get(&state.pool, trigger_id) {
+                if automation.trigger_kind == system_ops::TRIGGER_WEBHOOK {
+                    return process_automation_webhook(state, &automation, headers, body);
+                }
+            }
             return (
                 StatusCode::NOT_FOUND,
                 no_headers(),
@@ -329,34 +517,8 @@ async fn process_webhook(
     }
 
     // 2b. Rate limit: max webhook calls per trigger per minute (tier-aware)
-    let webhook_trigger_max = state
-        .tier_config
-        .lock()
-        .unwrap_or_else(|e| e.into_inner())
-        .webhook_trigger_max;
-    let rate_key = format!("webhook:{}", trigger_id);
-    if let Err(retry_after) =
-        state
-            .rate_limiter
-            .check(&rate_key, webhook_trigger_max, WEBHOOK_TRIGGER_WINDOW)
-    {
-        tracing::warn!(
-            trigger_id = %trigger_id,
-            retry_after = retry_after,
-            "Webhook rate limited",
-        );
-        return (
-            StatusCode::TOO_MANY_REQUESTS,
-            no_headers(),
-            WebhookResponse {
-                accepted: false,
-                event_id: None,
-                error: Some(format!(
-                    "Rate limited: max {} webhook calls/minute per trigger. Retry after {}s",
-                    webhook_trigger_max, retry_after
-                )),
-            },
-        );
+    if let Some(rejection) = rate_limited(state, trigger_id) {
+        return rejection;
     }
 
     // 3. Parse config once -- typed access replaces manual JSON extraction
@@ -372,59 +534,10 @@ async fn process_webhook(
 
     // HMAC validation is mandatory. Webhook triggers must have a non-empty
     // secret (enforced at creation time). Reject unsigned or secretless requests.
-    match webhook_secret {
-        Some(ref secret) if !secret.is_empty() => {
-            let signature = headers
-                .get("x-hub-signature-256") // GitHub
-                .or_else(|| headers.get("x-signature-256")) // Generic
-                .or_else(|| headers.get("x-webhook-signature")) // Custom
-                .and_then(|v| v.to_str().ok());
-
-            match signature {
-                Some(sig) => {
-                    if !verify_hmac_sha256(secret, body, sig) {
-                        return (
-                            StatusCode::UNAUTHORIZED,
-                            no_headers(),
-                            WebhookResponse {
-                                accepted: false,
-                                event_id: None,
-                                error: Some("Invalid HMAC signature".into()),
-                            },
-                        );
-                    }
-                }
-                None => {
-                    return (
-                        StatusCode::UNAUTHORIZED,
-                        no_headers(),
-                        WebhookResponse {
-                            accepted: false,
-                            event_id: None,
-                            error: Some(
-                                "Missing signature header (x-hub-signature-256, x-signature-256, or x-webhook-signature)".into(),
-                            ),
-                        },
-                    );
-                }
-            }
-        }
-        _ => {
-            // No secret configured or empty -- reject as misconfigured.
-            tracing::warn!(
-                trigger_id = %trigger_id,
-                "Webhook trigger has no HMAC secret configured -- rejecting request",
-            );
-            return (
-                StatusCode::FORBIDDEN,
-                no_headers(),
-                WebhookResponse {
-                    accepted: false,
-                    event_id: None,
-                    error: Some("Webhook trigger has no HMAC secret configured".into()),
-                },
-            );
-        }
+    if let Some(rejection) =
+        signature_rejection(webhook_secret.as_deref(), trigger_id, headers, body)
+    {
+        return rejection;
     }
 
     // 3b. Active window gate — return 422 so webhook senders know to retry
@@ -479,8 +592,67 @@ async fn process_webhook(
         }
     };
 
-    // 5. Extract event_type from typed config or default
-    let event_type = cfg_event_type.unwrap_or_else(|| "webhook_received".to_string());
+    // 5. Extract event_type from typed config or default. GitLab deliveries
+    //    are reduced to persona input first; events other than failed
+    //    pipelines and merge requests are acknowledged with 200 so GitLab
+    //    doesn't count them as failures and disable the hook.
+    let (event_type, payload) = match headers
+        .get(gitlab_events::EVENT_HEADER)
+        .and_then(|v| v.to_str().ok())
+    {
+        Some(gitlab_event) => {
+            let body_json = serde_json::from_slice(body).unwrap_or_default();
+            let Some(mut delivery) = gitlab_events::parse_delivery(gitlab_event, &body_json) else {
+                tracing::debug!(
+                    trigger_id = %trigger_id,
+                    gitlab_event = %gitlab_event,
+                    "GitLab delivery ignored",
+                );
+                return (
+                    StatusCode::OK,
+                    no_headers(),
+                    WebhookResponse {
+                        accepted: false,
+                        event_id: None,
+                        error: Some(format!("Ignored GitLab event '{gitlab_event}'")),
+                    },
+                );
+            };
+            gitlab_events::attach_failure_logs(&mut delivery).await;
+            (
+                cfg_event_type.unwrap_or_else(|| delivery.event_type.to_string()),
+                Some(delivery.input.to_string()),
+            )
+        }
+        None => (
+            cfg_event_type.unwrap_or_else(|| "webhook_received".to_string()),
+            payload,
+        ),
+    };
+
+    // 5b. Payload schema gate — a registered schema for this event type is
+    //     enforced here so malformed deliveries never reach subscribers.
+    if let Err(e) = event_schemas::validate_payload(&state.pool, &event_type, payload.as_deref()) {
+        let (status, msg) = match e {
+            AppError::Validation(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
+            other => {
+                tracing::error!(trigger_id = %trigger_id, "Failed to load event schema: {}", other);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to process webhook".into(),
+                )
+            }
+        };
+        return (
+            status,
+            no_headers(),
+            WebhookResponse {
+                accepted: false,
+                event_id: None,
+                error: Some(msg),
+            },
+        );
+    }
 
     // 6+7. Publish the event (durable record) and best-effort mark the trigger
     //       as fired in a single SQLite transaction. The event insert always
@@ -528,6 +700,183 @@ async fn process_webhook(
     }
 }
 
+/// Handle a delivery to a webhook-fired system-op automation: the same rate
+/// limit and mandatory HMAC check as persona triggers (secret in the
+/// `webhookSecret` param), then a `system_op.fired` event that the bus loop
+/// turns into a run of the automation's op.
+fn process_automation_webhook(
+    state: &WebhookState,
+    automation: &crate::db::models::SystemOpAutomation,
+    headers: &HeaderMap,
+    body: &Bytes,
+) -> (StatusCode, HeaderMap, WebhookResponse) {
+    if !automation.enabled {
+        return (
+            StatusCode::FORBIDDEN,
+            no_headers(),
+            WebhookResponse {
+                accepted: false,
+                event_id: None,
+                error: Some("Trigger is disabled".into()),
+            },
+        );
+    }
+    if let Some(rejection) = rate_limited(state, &automation.id) {
+        return rejection;
+    }
+    let params: serde_json::Value =
+        serde_json::from_str(&automation.params_json).unwrap_or(serde_json::Value::Null);
+    let secret = params.get("webhookSecret").and_then(|v| v.as_str());
+    if let Some(rejection) = signature_rejection(secret, &automation.id, headers, body) {
+        return rejection;
+    }
+
+    let payload = match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(v) => Some(v.to_string()),
+        Err(_) => {
+            let text = String::from_utf8_lossy(body);
+            (!text.is_empty()).then(|| serde_json::json!({ "raw": text.to_string() }).to_string())
+        }
+    };
+    match system_ops::publish_fired(
+        &state.pool,
+        &automation.id,
+        system_ops::TRIGGER_WEBHOOK,
+        payload,
+    ) {
+        Ok(event) => {
+            tracing::info!(
+                automation_id = %automation.id,
+                op_kind = %automation.op_kind,
+                event_id = %event.id,
+                "Webhook received for system-op automation",
+            );
+            (
+                StatusCode::OK,
+                no_headers(),
+                WebhookResponse {
+                    accepted: true,
+                    event_id: Some(event.id),
+                    error: None,
+                },
+            )
+        }
+        Err(e) => {
+            tracing::error!(automation_id = %automation.id, "Failed to process webhook: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                no_headers(),
+                WebhookResponse {
+                    accepted: false,
+                    event_id: None,
+                    error: Some("Failed to process webhook".into()),
+                },
+            )
+        }
+    }
+}
+
+/// Per-trigger rate limit (tier-aware max calls per minute). Returns the 429
+/// response when the caller is over it.
+fn rate_limited(
+    state: &WebhookState,
+    trigger_id: &str,
+) -> Option<(StatusCode, HeaderMap, WebhookResponse)> {
+    let webhook_trigger_max = state
+        .tier_config
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .webhook_trigger_max;
+    let rate_key = format!("webhook:{}", trigger_id);
+    let retry_after = state
+        .rate_limiter
+        .check(&rate_key, webhook_trigger_max, WEBHOOK_TRIGGER_WINDOW)
+        .err()?;
+    tracing::warn!(
+        trigger_id = %trigger_id,
+        retry_after = retry_after,
+        "Webhook rate limited",
+    );
+    Some((
+        StatusCode::TOO_MANY_REQUESTS,
+        no_headers(),
+        WebhookResponse {
+            accepted: false,
+            event_id: None,
+            error: Some(format!(
+                "Rate limited: max {} webhook calls/minute per trigger. Retry after {}s",
+                webhook_trigger_max, retry_after
+            )),
+        },
+    ))
+}
+
+/// Mandatory HMAC check. Returns the rejection when the secret is missing or
+/// the request is unsigned or signed wrongly.
+fn signature_rejection(
+    secret: Option<&str>,
+    trigger_id: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Option<(StatusCode, HeaderMap, WebhookResponse)> {
+    let reject = |status: StatusCode, error: &str| {
+        Some((
+            status,
+            no_headers(),
+            WebhookResponse {
+                accepted: false,
+                event_id: None,
+                error: Some(error.into()),
+            },
+        ))
+    };
+    let Some(secret) = secret.filter(|s| !s.is_empty()) else {
+        // No secret configured or empty -- reject as misconfigured.
+        tracing::warn!(
+            trigger_id = %trigger_id,
+            "Webhook trigger has no HMAC secret configured -- rejecting request",
+        );
+        return reject(
+            StatusCode::FORBIDDEN,
+            "Webhook trigger has no HMAC secret configured",
+        );
+    };
+    let signature = headers
+        .get("x-hub-signature-256") // GitHub
+        .or_else(|| headers.get("x-signature-256")) // Generic
+        .or_else(|| headers.get("x-webhook-signature")) // Custom
+        .and_then(|v| v.to_str().ok());
+    // GitLab doesn't sign deliveries; it sends the secret token verbatim.
+    let gitlab_token = headers
+        .get(gitlab_events::TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok());
+    match (signature, gitlab_token) {
+        (Some(sig), _) if verify_hmac_sha256(secret, body, sig) => None,
+        (Some(_), _) => reject(StatusCode::UNAUTHORIZED, "Invalid HMAC signature"),
+        (None, Some(token)) if verify_secret_token(secret, token) => None,
+        (None, Some(_)) => reject(StatusCode::UNAUTHORIZED, "Invalid GitLab secret token"),
+        (None, None) => reject(
+            StatusCode::UNAUTHORIZED,
+            "Missing signature header (x-hub-signature-256, x-signature-256, x-webhook-signature, or x-gitlab-token)",
+        ),
+    }
+}
+
+/// Constant-time comparison of a verbatim secret token: both sides are MACed
+/// under the secret so the comparison never short-circuits on content.
+fn verify_secret_token(secret: &str, token: &str) -> bool {
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(secret.as_bytes());
+    let expected = mac.finalize().into_bytes();
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(token.as_bytes());
+    mac.verify_slice(&expected).is_ok()
+}
+
 /// Verify HMAC-SHA256 signature.
 ///
 /// Supports both `sha256=<hex>` format (GitHub-style) and plain hex.
@@ -580,16 +929,9 @@ fn mark_triggered_and_publish(
     let now = chrono::Utc::now().to_rfc3339();
     let project_id = input.project_id.unwrap_or_else(|| "default".into());
 
-    let (stored_payload, payload_iv) = match &input.payload {
-        Some(plaintext) if !plaintext.is_empty() => match crypto::encrypt_for_db(plaintext) {
-            Ok((ct, iv)) => (Some(ct), Some(iv)),
-            Err(e) => {
-                tracing::warn!("Failed to encrypt event payload, storing plaintext: {}", e);
-                (Some(plaintext.clone()), None)
-            }
-        },
-        other => (other.clone(), None),
-    };
+    // Encrypt payload at rest under the project's data key
+    let (stored_payload, payload_iv) =
+        project_keys::encrypt_event_payload(pool, &project_id, &input.payload);
 
     let mut conn = pool.get()?;
     let tx = conn.transaction().map_err(AppError::Database)?;
@@ -673,10 +1015,43 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn readiness_requires_database_and_live_scheduler() {
+        let mut probe = HealthProbe {
+            status: "ok",
+            version: "0.0.0",
+            scheduler: SchedulerProbe {
+                running: true,
+                subscriptions: 2,
+                dead_subscriptions: Vec::new(),
+                last_trigger_tick_at: None,
+            },
+            database: DatabaseProbe {
+                reachable: true,
+                latency_ms: 1,
+                error: None,
+            },
+            queue: None,
+        };
+        assert!(probe.is_ready());
+        probe.scheduler.dead_subscriptions.push("event_bus".into());
+        assert!(!probe.is_ready());
+        probe.scheduler.dead_subscriptions.clear();
+        probe.database.reachable = false;
+        assert!(!probe.is_ready());
+    }
+
     #[test]
     fn test_hmac_verification_invalid() {
         assert!(!verify_hmac_sha256("secret", b"body", "deadbeef"));
         assert!(!verify_hmac_sha256("secret", b"body", "sha256=deadbeef"));
         assert!(!verify_hmac_sha256("secret", b"body", "not-hex"));
     }
+
+    #[test]
+    fn gitlab_token_must_match_secret() {
+        assert!(verify_secret_token("s3cret-token", "s3cret-token"));
+        assert!(!verify_secret_token("s3cret-token", "s3cret-tokem"));
+        assert!(!verify_secret_token("s3cret-token", ""));
+    }
 }
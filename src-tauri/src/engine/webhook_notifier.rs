@@ -227,8 +227,10 @@ pub mod templating {
                     }
                 }
             }
-            out.push(bytes[i] as char);
-            i += 1;
+            // Copy the whole (possibly multi-byte) character, not one byte.
+            let ch = template[i..].chars().next().unwrap_or_default();
+            out.push(ch);
+            i += ch.len_utf8();
         }
         out
     }
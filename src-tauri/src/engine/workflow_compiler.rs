@@ -237,6 +237,7 @@ pub fn persist_blueprint(
         default_model_profile: None,
         default_max_budget_usd: None,
         default_max_turns: None,
+        monthly_budget_usd: None,
         created_at: team_now.clone(),
         updated_at: team_now,
     };
@@ -428,4 +428,19 @@ impl GitLabClient {
 
         self.send_ok(create_req).await
     }
+
+    // --------------------------------------------------------------------
+    // Jobs
+    // --------------------------------------------------------------------
+
+    /// `GET /api/v4/projects/:id/jobs/:job_id/trace` -- a job's full log.
+    pub async fn get_job_trace(&self, project_id: i64, job_id: i64) -> Result<String, AppError> {
+        let req = self
+            .authed(
+                reqwest::Method::GET,
+                &format!("/projects/{project_id}/jobs/{job_id}/trace"),
+            )
+            .timeout(std::time::Duration::from_secs(10));
+        self.send_text(req).await
+    }
 }
@@ -0,0 +1,220 @@
+//! GitLab webhook deliveries as persona trigger input.
+//!
+//! A webhook trigger's URL can be registered directly as a GitLab project
+//! webhook (secret token = the trigger's `webhook_secret`). Deliveries carrying
+//! an `X-Gitlab-Event` header are reduced to the fields a persona needs --
+//! project, ref, pipeline id, failed jobs -- instead of GitLab's full payload
+//! (which includes user emails and every build). Failed pipelines and merge
+//! request events fire; other GitLab events are acknowledged and dropped.
+
+use serde_json::{json, Value};
+
+use super::client::GitLabClient;
+
+/// Header GitLab names the event kind in, e.g. `Pipeline Hook`.
+pub const EVENT_HEADER: &str = "x-gitlab-event";
+/// Header GitLab sends the webhook secret token in, verbatim.
+pub const TOKEN_HEADER: &str = "x-gitlab-token";
+
+pub const EVENT_PIPELINE_FAILED: &str = "gitlab_pipeline_failed";
+pub const EVENT_MERGE_REQUEST: &str = "gitlab_merge_request";
+
+/// Failed jobs whose logs are fetched per pipeline, and how much of each
+/// log's tail is kept.
+const MAX_LOGGED_JOBS: usize = 3;
+const LOG_TAIL_BYTES: usize = 4_000;
+
+/// A GitLab delivery reduced to persona input.
+#[derive(Debug, Clone)]
+pub struct GitLabDelivery {
+    pub event_type: &'static str,
+    pub input: Value,
+}
+
+/// Reduce a delivery to persona input. `None` for events that should not
+/// fire a persona (non-failed pipelines, pushes, notes, ...).
+pub fn parse_delivery(event_header: &str, body: &Value) -> Option<GitLabDelivery> {
+    match event_header {
+        "Pipeline Hook" => parse_pipeline(body),
+        "Merge Request Hook" => parse_merge_request(body),
+        _ => None,
+    }
+}
+
+fn project(body: &Value) -> Value {
+    let p = &body["project"];
+    json!({
+        "id": p["id"],
+        "path": p["path_with_namespace"],
+        "web_url": p["web_url"],
+    })
+}
+
+fn parse_pipeline(body: &Value) -> Option<GitLabDelivery> {
+    let attrs = &body["object_attributes"];
+    if attrs["status"].as_str() != Some("failed") {
+        return None;
+    }
+    let failed_jobs: Vec<Value> = body["builds"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|b| b["status"].as_str() == Some("failed"))
+        .map(|b| {
+            json!({
+                "id": b["id"],
+                "name": b["name"],
+                "stage": b["stage"],
+                "failure_reason": b["failure_reason"],
+                "allow_failure": b["allow_failure"],
+            })
+        })
+        .collect();
+    Some(GitLabDelivery {
+        event_type: EVENT_PIPELINE_FAILED,
+        input: json!({
+            "source": "gitlab",
+            "kind": "pipeline",
+            "project": project(body),
+            "ref": attrs["ref"],
+            "sha": attrs["sha"],
+            "pipeline_id": attrs["id"],
+            "pipeline_url": attrs["url"],
+            "pipeline_source": attrs["source"],
+            "status": attrs["status"],
+            "commit_title": body["commit"]["title"],
+            "merge_request_iid": body["merge_request"]["iid"],
+            "failed_jobs": failed_jobs,
+        }),
+    })
+}
+
+fn parse_merge_request(body: &Value) -> Option<GitLabDelivery> {
+    let attrs = &body["object_attributes"];
+    attrs["iid"].as_i64()?;
+    Some(GitLabDelivery {
+        event_type: EVENT_MERGE_REQUEST,
+        input: json!({
+            "source": "gitlab",
+            "kind": "merge_request",
+            "project": project(body),
+            "action": attrs["action"],
+            "merge_request_iid": attrs["iid"],
+            "title": attrs["title"],
+            "state": attrs["state"],
+            "source_branch": attrs["source_branch"],
+            "target_branch": attrs["target_branch"],
+            "url": attrs["url"],
+            "sha": attrs["last_commit"]["id"],
+            "author": body["user"]["username"],
+        }),
+    })
+}
+
+/// Attach the tail of each failed job's log (first [`MAX_LOGGED_JOBS`]) as
+/// `failed_jobs[].log_tail`. Best-effort: needs a connected GitLab account
+/// with access to the project; fetch failures leave the job without a log.
+pub async fn attach_failure_logs(delivery: &mut GitLabDelivery) {
+    if delivery.event_type != EVENT_PIPELINE_FAILED {
+        return;
+    }
+    let Some(token) = super::config::load_gitlab_config() else {
+        return;
+    };
+    let base_url = super::config::load_gitlab_instance_url()
+        .unwrap_or_else(|| "https://gitlab.com".to_string());
+    let Ok(client) = GitLabClient::new(base_url, token) else {
+        return;
+    };
+    let Some(project_id) = delivery.input["project"]["id"].as_i64() else {
+        return;
+    };
+    let Some(jobs) = delivery.input["failed_jobs"].as_array_mut() else {
+        return;
+    };
+    for job in jobs.iter_mut().take(MAX_LOGGED_JOBS) {
+        let Some(job_id) = job["id"].as_i64() else {
+            continue;
+        };
+        match client.get_job_trace(project_id, job_id).await {
+            Ok(trace) => job["log_tail"] = Value::String(log_tail(&trace)),
+            Err(e) => tracing::debug!(job_id, error = %e, "Failed to fetch GitLab job log"),
+        }
+    }
+}
+
+/// Last [`LOG_TAIL_BYTES`] of a job log, cut at a line boundary, with ANSI
+/// color codes stripped.
+fn log_tail(trace: &str) -> String {
+    let plain = strip_ansi(trace);
+    if plain.len() <= LOG_TAIL_BYTES {
+        return plain;
+    }
+    let mut start = plain.len() - LOG_TAIL_BYTES;
+    while !plain.is_char_boundary(start) {
+        start += 1;
+    }
+    let tail = &plain[start..];
+    tail.split_once('\n')
+        .map_or(tail, |(_, rest)| rest)
+        .to_string()
+}
+
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' && chars.peek() == Some(&'[') {
+            chars.next();
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_pipeline_keeps_failed_jobs_only() {
+        let body = json!({
+            "object_kind": "pipeline",
+            "object_attributes": {
+                "id": 31, "ref": "main", "sha": "abc", "status": "failed",
+                "url": "https://gitlab.example/p/-/pipelines/31"
+            },
+            "user": { "email": "dev@example.com" },
+            "project": { "id": 7, "path_with_namespace": "acme/api", "web_url": "https://gitlab.example/acme/api" },
+            "commit": { "title": "Bump deps" },
+            "builds": [
+                { "id": 1, "name": "lint", "stage": "test", "status": "success" },
+                { "id": 2, "name": "unit", "stage": "test", "status": "failed", "failure_reason": "script_failure" }
+            ]
+        });
+        let d = parse_delivery("Pipeline Hook", &body).unwrap();
+        assert_eq!(d.event_type, EVENT_PIPELINE_FAILED);
+        assert_eq!(d.input["pipeline_id"], 31);
+        assert_eq!(d.input["project"]["path"], "acme/api");
+        assert_eq!(d.input["failed_jobs"].as_array().unwrap().len(), 1);
+        assert!(!d.input.to_string().contains("dev@example.com"));
+
+        let mut passed = body.clone();
+        passed["object_attributes"]["status"] = json!("success");
+        assert!(parse_delivery("Pipeline Hook", &passed).is_none());
+        assert!(parse_delivery("Push Hook", &body).is_none());
+    }
+
+    #[test]
+    fn log_tail_strips_color_and_cuts_at_a_line() {
+        assert_eq!(strip_ansi("\u{1b}[31;1mERROR\u{1b}[0m done"), "ERROR done");
+        let long = format!("{}\nlast line", "x".repeat(LOG_TAIL_BYTES));
+        assert_eq!(log_tail(&long), "last line");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod client;
 pub mod config;
 pub mod converter;
+pub mod events;
 pub mod types;
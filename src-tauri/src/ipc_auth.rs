@@ -25,6 +25,9 @@
 //! 4. **Frontend init script** (`generate_ipc_auth_script`): Injects the
 //!    session token and monkey-patches `__TAURI_INTERNALS__.invoke` to attach
 //!    the token as an `x-ipc-token` header on every IPC call.
+//!
+//! Independently of tier, the invoke wrapper rejects every mutating command
+//! while observer mode is on (`engine::observer_mode`).
 
 use std::cell::Cell;
 use std::collections::HashSet;
@@ -128,6 +131,14 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     "delete_credential_event",
     "migrate_plaintext_credentials",
     "update_credential_field",
+    // Credentials -- Vault backend (unlock / switch master-key storage)
+    "unlock_vault",
+    "configure_vault_backend",
+    // Credentials -- Vault key portability (export / import + re-encrypt)
+    "export_vault_key",
+    "import_vault_key",
+    // Encryption -- per-project data key revocation (irreversibly seals payloads)
+    "dev_tools_revoke_project_data_key",
     // Credentials -- Resource scoping (post-save sub-resource picker)
     "save_scoped_resources",
     "list_connector_resources",
@@ -150,11 +161,19 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     "start_credential_negotiation",
     "cancel_credential_negotiation",
     "get_negotiation_step_help",
+    "open_negotiation_transcript",
+    "record_negotiation_event",
+    "link_negotiation_credential",
+    "get_negotiation_transcript",
+    "search_negotiation_transcripts",
     // Credentials -- Intelligence
     "credential_audit_log",
     "credential_audit_log_global",
     "credential_usage_stats",
     "credential_dependents",
+    "list_credential_anomalies",
+    "scan_credential_anomalies",
+    "acknowledge_credential_anomaly",
     // Credentials -- OAuth
     "start_google_credential_oauth",
     "get_google_credential_oauth_status",
@@ -178,6 +197,7 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     "get_rotation_history_bulk",
     "get_rotation_status",
     "get_all_rotation_statuses",
+    "list_expiring_credentials",
     "rotate_credential_now",
     "refresh_credential_oauth_now",
     "get_oauth_token_metrics",
@@ -259,6 +279,12 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     "get_frontend_crashes",
     "clear_frontend_crashes",
     "get_frontend_crash_count",
+    // System -- Database restore (reads a caller-supplied path and stages it
+    // to replace the entire database on next launch)
+    "restore_database",
+    // System -- State snapshot (settings, queue and table counts of the whole
+    // install, for support escalations)
+    "capture_state_snapshot",
     // Credentials -- Desktop Bridges
     "execute_desktop_bridge",
     "execute_desktop_plan",
@@ -281,6 +307,11 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     "delete_external_api_key",
     "get_system_api_key",
     "list_api_key_audit",
+    "create_persona_execute_hook",
+    "get_persona_execute_hook",
+    "set_persona_execute_hook_wait",
+    "revoke_persona_execute_hook",
+    "set_persona_report_sink",
     "list_pending_pairings",
     "approve_pairing",
     "reject_pairing",
@@ -328,6 +359,9 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     // user's image-gen API key, so it must be privileged like other secret-using
     // commands (its sibling `list_image_gen_credentials` is read-only metadata).
     "generate_persona_icon",
+    // Credential bundle import -- takes a caller-supplied path (no native
+    // dialog, so the wrapper header check is reliable) and writes secrets.
+    "import_credentials_bundle",
     // Data Portability — NOT in PRIVILEGED_COMMANDS because the wrapper-level
     // header check fails intermittently on Windows WebView2 (the monkey-patch
     // may not reliably forward headers for commands that open native file dialogs).
@@ -335,6 +369,7 @@ pub const PRIVILEGED_COMMANDS: &[&str] = &[
     // function bodies as defense-in-depth.
     // "export_credentials",
     // "import_credentials",
+    // "export_credentials_bundle",
     // "export_full",
     // "import_portability_bundle",
 ];
@@ -519,7 +554,8 @@ pub async fn require_cloud_auth(state: &Arc<AppState>, command: &str) -> Result<
 /// 4. Dispatches to the real handler
 /// 5. Clears the validation flag
 ///
-/// Non-privileged commands are dispatched without validation.
+/// Non-privileged commands are dispatched without validation. While observer
+/// mode is on, mutating commands of any tier are rejected up front.
 pub fn wrap_invoke_handler<R: tauri::Runtime>(
     inner: impl Fn(tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static,
 ) -> impl Fn(tauri::ipc::Invoke<R>) -> bool + Send + Sync + 'static {
@@ -527,6 +563,15 @@ pub fn wrap_invoke_handler<R: tauri::Runtime>(
         let _in_flight = IpcInFlightGuard::new();
         let cmd = invoke.message.command().to_string();
 
+        if crate::engine::observer_mode::blocks(&cmd) {
+            tracing::info!(command = %cmd, "Rejected IPC call: observer mode is on");
+            invoke.resolver.reject(serde_json::json!({
+                "error": "Observer mode is on: changes are disabled until it is switched off",
+                "kind": "Forbidden"
+            }));
+            return true; // handled (rejected)
+        }
+
         if is_privileged_command(&cmd) {
             // Extract and validate the IPC session token from headers
             let token_valid = match IPC_SESSION_TOKEN.get() {
@@ -672,6 +717,7 @@ pub const CLOUD_COMMANDS: &[&str] = &[
     "cloud_disconnect",
     "cloud_execute_persona",
     "cloud_cancel_execution",
+    "cloud_reconcile_executions",
     "cloud_oauth_authorize",
     "cloud_oauth_callback",
     "cloud_oauth_status",
@@ -539,6 +539,18 @@ fn get_startup_timing() -> Option<startup_timing::StartupTimingReport> {
     startup_timing::get_full_report()
 }
 
+/// Startup profile: setup phase timings plus the deferred startup tasks.
+#[tauri::command]
+fn get_startup_profile() -> startup_timing::StartupProfile {
+    startup_timing::get_profile()
+}
+
+/// Outcome of the last builtin connector seeding pass (`None` while running).
+#[tauri::command]
+fn get_seed_report() -> Option<db::connector_seed::ConnectorSeedReport> {
+    db::connector_seed::last_report()
+}
+
 /// Called by the frontend to report its time-to-interactive.
 #[tauri::command]
 fn report_frontend_ready(tti_ms: f64) {
@@ -665,7 +677,10 @@ pub fn run() {
             // Create CDC channel for reactive SQLite change notifications
             let (cdc_sender, cdc_receiver) = db::cdc::create_cdc_channel(512);
 
-            let pool = db::init_db(&app_data_dir, Some(cdc_sender))?;
+            let pool = db::init_db(&app_data_dir, Some(cdc_sender));
+            // Report the startup integrity pass even when boot fails after it.
+            notifications::notify_database_integrity(app.handle());
+            let pool = pool?;
             tracing::info!("Database pool ready (max_size=4, CDC enabled)");
             st.checkpoint("db_init");
 
@@ -693,17 +708,22 @@ pub fn run() {
             // Stage B Phase 2.4 — seed the recipe catalog from the embedded
             // bundle so adoption of recipe_ref-shaped templates works on a
             // fresh install. Idempotent: existing rows are left untouched.
-            match engine::recipe_seed::seed_recipes_from_bundle(&pool) {
-                Ok(report) => tracing::info!(
-                    total = report.total,
-                    created = report.created,
-                    skipped = report.skipped_existing,
-                    failed = report.failed,
-                    "Recipe catalog seeded from bundle"
-                ),
-                Err(e) => tracing::warn!("Recipe catalog seed failed: {}", e),
+            // Deferred past window creation: only adoption reads the catalog.
+            {
+                let seed_pool = pool.clone();
+                startup_timing::defer("recipe_seed", move || {
+                    let report = engine::recipe_seed::seed_recipes_from_bundle(&seed_pool)
+                        .map_err(|e| e.to_string())?;
+                    tracing::info!(
+                        total = report.total,
+                        created = report.created,
+                        skipped = report.skipped_existing,
+                        failed = report.failed,
+                        "Recipe catalog seeded from bundle"
+                    );
+                    Ok(())
+                });
             }
-            st.checkpoint("recipe_seed");
 
             // Initialize P2P identity (Invisible Apps Phase 1)
             #[cfg(feature = "p2p")]
@@ -717,6 +737,10 @@ pub fn run() {
             }
             st.checkpoint("p2p_identity");
 
+            // Select the master-key vault backend (keychain, encrypted file,
+            // HashiCorp Vault) before anything below touches the key.
+            engine::vault_backend::init(&pool);
+
             // Encrypt any legacy plaintext credentials
             match engine::crypto::migrate_plaintext_credentials(&pool) {
                 Ok((migrated, failed)) => {
@@ -784,6 +808,14 @@ pub fn run() {
                     tracing::warn!("Trigger config secret migration skipped: {}", e);
                 }
             }
+
+            // Unwrap per-project data keys so event payloads written under them
+            // decrypt in row mappers (which have no pool to load keys lazily).
+            match engine::project_keys::load_all(&pool) {
+                Ok(n) if n > 0 => tracing::info!("Loaded {} project data keys", n),
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Project data keys not loaded: {}", e),
+            }
             st.checkpoint("credential_migrations");
 
             // Initialise the connector strategy registry (healthcheck + rotation dispatch)
@@ -842,6 +874,16 @@ pub fn run() {
             }
             st.checkpoint("pipeline_run_recovery");
 
+            // Batch drivers are plain tokio tasks; a batch still `running` at
+            // startup lost its driver, so stop it instead of showing it as live.
+            match db::repos::execution::batches::recover_interrupted(&pool) {
+                Ok(n) if n > 0 => {
+                    tracing::info!("Startup: marked {} batch execution(s) interrupted", n)
+                }
+                Err(e) => tracing::warn!("Failed to recover interrupted batch executions: {}", e),
+                _ => {}
+            }
+
             // Fail lab_*_runs (arena/ab/matrix/eval) left non-terminal by a crash
             // so they stop re-hydrating as phantom active runs that pin the UI's
             // launch/cancel/orbit state.
@@ -864,13 +906,19 @@ pub fn run() {
             }
             st.checkpoint("approval_recovery");
 
-            // Purge old completed/failed events to prevent unbounded table growth
-            match db::repos::communication::events::cleanup(&pool, Some(7)) {
-                Ok(n) if n > 0 => tracing::info!("Startup: cleaned up {} old events", n),
-                Err(e) => tracing::warn!("Startup event cleanup failed: {}", e),
-                _ => {}
+            // Purge old completed/failed events to prevent unbounded table growth.
+            // Deferred: a large purge on a big DB used to hold up the window.
+            {
+                let cleanup_pool = pool.clone();
+                startup_timing::defer("event_cleanup", move || {
+                    let n = db::repos::communication::events::cleanup(&cleanup_pool, Some(7))
+                        .map_err(|e| e.to_string())?;
+                    if n > 0 {
+                        tracing::info!("Startup: cleaned up {} old events", n);
+                    }
+                    Ok(())
+                });
             }
-            st.checkpoint("event_cleanup");
 
             let scheduler = Arc::new(engine::background::SchedulerState::new());
             let engine = Arc::new(engine::ExecutionEngine::new(log_dir, scheduler.clone(), Some(Arc::new(pool.clone()))));
@@ -1081,15 +1129,21 @@ pub fn run() {
                 st.checkpoint("memory_recall_runtime");
             }
 
-            // Reconcile orphaned KB records left by crashes during creation
+            // Reconcile orphaned KB records left by crashes during creation.
+            // Deferred: nothing reads an orphan before the user opens a KB.
             #[cfg(feature = "ml")]
             {
-                commands::credentials::vector_kb::reconcile_orphaned_kb_records(
-                    &pool,
-                    &user_db_pool,
-                    &vector_store,
-                );
-                st.checkpoint("kb_reconciliation");
+                let kb_pool = pool.clone();
+                let kb_user_pool = user_db_pool.clone();
+                let kb_store = vector_store.clone();
+                startup_timing::defer("kb_reconciliation", move || {
+                    commands::credentials::vector_kb::reconcile_orphaned_kb_records(
+                        &kb_pool,
+                        &kb_user_pool,
+                        &kb_store,
+                    );
+                    Ok(())
+                });
             }
 
             let smee_notifier = engine::smee_relay::SmeeRelayNotifier::new();
@@ -1173,6 +1227,9 @@ pub fn run() {
                 state_arc.user_db.clone(),
                 app.handle().clone(),
             );
+            // Mobile push relay for opted-in critical notifications; a
+            // no-op until the cloud orchestrator is connected.
+            cloud::push::init(state_arc.db.clone(), state_arc.cloud_client.clone());
             app.manage(state_arc.clone());
 
             // Engine leadership (multi-driver orchestration, ADR 2026-05-26):
@@ -1226,6 +1283,12 @@ pub fn run() {
                 });
             }
 
+            // Observer mode survives a relaunch. Applied synchronously so the
+            // IPC gate is armed before the webview issues its first command.
+            crate::engine::observer_mode::apply(&crate::engine::observer_mode::load(
+                &state_arc.db,
+            ));
+
             // Trace redaction: secrets are scrubbed from persisted execution
             // output by default (engine::redact). Honor an explicit user opt-out
             // persisted in a prior session; absent/any-other value keeps it ON.
@@ -1545,6 +1608,15 @@ pub fn run() {
                                 event_name::REFERRAL_RECEIVED,
                                 serde_json::json!({ "code": code }),
                             );
+                        } else if let Some(tab) = url_str.strip_prefix("personas://open/") {
+                            // Push-notification deep link (cloud::push): the
+                            // frontend opens the named overview tab.
+                            let tab = tab.trim_end_matches('/').to_string();
+                            tracing::info!("Open-tab deep link received: tab={}", tab);
+                            let _ = dl_handle.emit(
+                                event_name::OPEN_TAB_REQUESTED,
+                                serde_json::json!({ "tab": tab }),
+                            );
                         } else if url_str.starts_with("personas://pair") {
                             // Pairing deep link (Direction 1): register a pending
                             // pairing and surface the approval modal to the user.
@@ -1588,6 +1660,7 @@ pub fn run() {
                         f.write_all(timing_text.as_bytes())
                     });
             }
+            startup_timing::run_deferred();
 
             // Lightweight freeze/OOM monitor — uses Windows API, no external process spawn
             freeze_monitor::start(app.handle().clone(), app_data_dir.join("logs"));
@@ -1699,6 +1772,8 @@ pub fn run() {
             greet,
             log_frontend_error,
             get_startup_timing,
+            get_startup_profile,
+            get_seed_report,
             report_frontend_ready,
             // Test automation (always registered; server only starts when enabled)
             test_automation::__test_respond,
@@ -1750,6 +1825,7 @@ pub fn run() {
             commands::core::memories::merge_memories,
             commands::core::memories::update_memory_importance,
             commands::core::memories::update_memory_content,
+            commands::core::memories::update_memory,
             commands::core::memories::batch_delete_memories,
             commands::core::memories::review_memories_with_cli,
             commands::core::memories::reflect_memories_with_cli,
@@ -1760,6 +1836,7 @@ pub fn run() {
             commands::core::memories::get_persona_memory_review_proposal,
             commands::core::memories::update_memory_tier,
             commands::core::memories::run_memory_lifecycle,
+            commands::core::memories::reindex_memories,
             commands::core::memory_compile::compile_persona_memories,
             // Core -- Memory curation runs (persona_background_job framework)
             commands::core::persona_jobs::enqueue_persona_memory_curation,
@@ -1800,11 +1877,21 @@ pub fn run() {
             commands::core::data_portability::preview_competitive_import,
             commands::core::data_portability::export_credentials,
             commands::core::data_portability::import_credentials,
+            commands::core::data_portability::export_credentials_bundle,
+            commands::core::data_portability::import_credentials_bundle,
             #[cfg(debug_assertions)]
             commands::core::data_portability::export_selective_to_path,
             #[cfg(debug_assertions)]
             commands::core::data_portability::import_portability_bundle_from_path,
             // Core -- Saved Views
+            commands::core::template_variables::list_template_variables,
+            commands::core::template_variables::save_template_variable,
+            commands::core::template_variables::delete_template_variable,
+            commands::core::template_variables::get_template_variable_usage,
+            commands::core::prompt_blocks::list_prompt_blocks,
+            commands::core::prompt_blocks::save_prompt_block,
+            commands::core::prompt_blocks::delete_prompt_block,
+            commands::core::prompt_blocks::get_prompt_block_usage,
             commands::core::saved_views::create_saved_view,
             commands::core::saved_views::list_saved_views,
             commands::core::saved_views::list_saved_views_by_type,
@@ -1826,21 +1913,41 @@ pub fn run() {
             commands::execution::executions::get_execution,
             commands::execution::executions::create_execution,
             commands::execution::executions::execute_persona,
+            commands::execution::executions::execute_persona_and_wait,
             commands::execution::executions::prepare_persona_execution,
             commands::execution::executions::cancel_execution,
+            commands::execution::executions::bump_persona_concurrency,
             commands::execution::executions::list_executions_by_trigger,
+            commands::execution::executions::list_executions_by_firing,
             commands::execution::executions::list_executions_for_use_case,
             commands::execution::executions::get_execution_log,
+            commands::execution::executions::get_execution_log_range,
             commands::execution::executions::get_execution_log_lines,
             commands::execution::executions::get_execution_trace,
             commands::execution::executions::get_chain_trace,
             commands::execution::executions::get_chain_stop_reasons,
+            commands::execution::executions::get_shared_context,
+            commands::execution::executions::set_shared_context,
             commands::execution::executions::list_active_chains,
+            commands::execution::executions::get_execution_timeline,
             commands::execution::executions::get_dream_replay,
             commands::execution::executions::get_circuit_breaker_status,
+            commands::execution::executions::get_warm_pool_stats,
+            commands::execution::batches::start_batch_execution,
+            commands::execution::batches::list_batch_executions,
+            commands::execution::batches::get_batch_execution_progress,
+            commands::execution::batches::list_batch_execution_items,
+            commands::execution::batches::cancel_batch_execution,
+            commands::execution::batches::export_batch_results,
             commands::execution::executions::preview_execution,
+            commands::execution::executions::get_prompt_budget,
+            commands::execution::executions::estimate_execution_cost,
             commands::execution::executions::dry_run_persona,
             // Execution -- Annotations (tags / note / star)
+            commands::execution::feature_flags::list_engine_flags,
+            commands::execution::feature_flags::get_persona_engine_flags,
+            commands::execution::feature_flags::set_persona_engine_flags,
+            commands::execution::feature_flags::get_engine_flag_stats,
             commands::execution::annotations::add_annotation,
             commands::execution::annotations::list_execution_annotations,
             commands::execution::annotations::list_persona_annotations,
@@ -1850,6 +1957,9 @@ pub fn run() {
             commands::execution::scheduler::start_scheduler,
             commands::execution::scheduler::stop_scheduler,
             commands::execution::scheduler::get_subscription_health,
+            commands::execution::scheduler::list_background_jobs,
+            commands::execution::scheduler::run_background_job_now,
+            commands::execution::scheduler::set_background_job_paused,
             commands::execution::scheduler::backfill_schedule,
             commands::execution::scheduler::list_schedule_missed_runs,
             commands::execution::scheduler::clear_schedule_missed_runs,
@@ -1945,10 +2055,19 @@ pub fn run() {
             // Execution -- Healing
             commands::execution::healing::list_healing_issues,
             commands::execution::healing::get_healing_issue,
+            commands::execution::healing::generate_healing_root_cause,
             commands::execution::healing::update_healing_status,
             commands::execution::healing::run_healing_analysis,
             commands::execution::healing::get_retry_chain,
             commands::execution::healing::list_healing_knowledge,
+            commands::execution::healing::search_healing_knowledge,
+            commands::execution::healing::update_healing_knowledge,
+            commands::execution::healing::delete_healing_knowledge,
+            commands::execution::healing::export_healing_knowledge,
+            commands::execution::healing::import_healing_knowledge,
+            commands::execution::healing::list_fleet_healing_recommendations,
+            commands::execution::healing::set_fleet_pattern_sharing,
+            commands::execution::healing::sync_fleet_patterns_now,
             commands::execution::healing::trigger_ai_healing,
             commands::execution::healing::get_healing_timeline,
             commands::execution::healing::list_healing_audit_log,
@@ -1970,6 +2089,7 @@ pub fn run() {
             commands::design::analysis::cancel_design_analysis,
             commands::design::analysis::compile_from_intent,
             commands::design::analysis::preview_prompt,
+            commands::design::analysis::preview_compiled_prompt,
             // Design -- Build Sessions
             commands::design::build_sessions::start_build_session,
             commands::design::build_sessions::start_build_session_headless,
@@ -2007,6 +2127,12 @@ pub fn run() {
             commands::design::n8n_transform::job_state::cancel_n8n_transform,
             commands::design::n8n_transform::confirmation::confirm_n8n_persona_draft,
             commands::design::n8n_transform::cli_runner::continue_n8n_transform,
+            commands::design::n8n_transform::export::export_persona_to_n8n,
+            // Design -- GPT / Assistant import (reuses the n8n draft confirmation)
+            commands::design::gpt_import::parse_gpt_export,
+            // Design -- CrewAI / LangChain agent config import
+            commands::design::agent_framework_import::parse_agent_framework_config,
+            commands::design::agent_framework_import::confirm_agent_framework_import,
             // Design -- N8n Limits (canonical payload caps)
             commands::design::n8n_limits::get_n8n_payload_limits,
             // Design -- N8n Sessions
@@ -2103,6 +2229,11 @@ pub fn run() {
             commands::credentials::crud::healthcheck_all_credentials,
             commands::credentials::crud::vault_status,
             commands::credentials::crud::migrate_plaintext_credentials,
+            commands::credentials::crud::vault_backend_status,
+            commands::credentials::crud::unlock_vault,
+            commands::credentials::crud::configure_vault_backend,
+            commands::credentials::crud::export_vault_key,
+            commands::credentials::crud::import_vault_key,
             commands::credentials::crud::list_credential_fields,
             commands::credentials::crud::update_credential_field,
             commands::credentials::resources::get_scoped_resources,
@@ -2116,6 +2247,10 @@ pub fn run() {
             commands::credentials::external_api_keys::delete_external_api_key,
             commands::credentials::external_api_keys::get_system_api_key,
             commands::credentials::external_api_keys::list_api_key_audit,
+            commands::credentials::external_api_keys::create_persona_execute_hook,
+            commands::credentials::external_api_keys::get_persona_execute_hook,
+            commands::credentials::external_api_keys::set_persona_execute_hook_wait,
+            commands::credentials::external_api_keys::revoke_persona_execute_hook,
             commands::credentials::external_api_keys::list_pending_pairings,
             commands::credentials::external_api_keys::approve_pairing,
             commands::credentials::external_api_keys::reject_pairing,
@@ -2140,11 +2275,19 @@ pub fn run() {
             commands::credentials::negotiator::start_credential_negotiation,
             commands::credentials::negotiator::cancel_credential_negotiation,
             commands::credentials::negotiator::get_negotiation_step_help,
+            commands::credentials::negotiator::open_negotiation_transcript,
+            commands::credentials::negotiator::record_negotiation_event,
+            commands::credentials::negotiator::link_negotiation_credential,
+            commands::credentials::negotiator::get_negotiation_transcript,
+            commands::credentials::negotiator::search_negotiation_transcripts,
             // Credentials -- Intelligence
             commands::credentials::intelligence::credential_audit_log,
             commands::credentials::intelligence::credential_audit_log_global,
             commands::credentials::intelligence::credential_usage_stats,
             commands::credentials::intelligence::credential_dependents,
+            commands::credentials::intelligence::list_credential_anomalies,
+            commands::credentials::intelligence::scan_credential_anomalies,
+            commands::credentials::intelligence::acknowledge_credential_anomaly,
             // Credentials -- OAuth
             commands::credentials::oauth::start_google_credential_oauth,
             commands::credentials::oauth::get_google_credential_oauth_status,
@@ -2182,6 +2325,7 @@ pub fn run() {
             commands::credentials::rotation::get_rotation_history_bulk,
             commands::credentials::rotation::get_rotation_status,
             commands::credentials::rotation::get_all_rotation_statuses,
+            commands::credentials::rotation::list_expiring_credentials,
             commands::credentials::rotation::rotate_credential_now,
             commands::credentials::rotation::refresh_credential_oauth_now,
             commands::credentials::rotation::refresh_credential_cli_now,
@@ -2228,6 +2372,13 @@ pub fn run() {
             commands::credentials::mcp_tools::healthcheck_mcp_preview,
             commands::credentials::mcp_tools::get_mcp_pool_metrics,
             commands::credentials::mcp_tools::probe_mcp_server,
+            // Credentials -- MCP server lifecycle (per-connector stdio servers)
+            commands::credentials::mcp_servers::list_mcp_servers,
+            commands::credentials::mcp_servers::install_mcp_server,
+            commands::credentials::mcp_servers::start_mcp_server,
+            commands::credentials::mcp_servers::stop_mcp_server,
+            commands::credentials::mcp_servers::restart_mcp_server,
+            commands::credentials::mcp_servers::check_mcp_server_health,
             // Credentials -- MCP Gateway membership (bundles multiple MCP servers under one credential)
             commands::credentials::mcp_gateways::add_mcp_gateway_member,
             commands::credentials::mcp_gateways::remove_mcp_gateway_member,
@@ -2352,6 +2503,18 @@ pub fn run() {
             commands::communication::notifications::update_notification_subscription,
             commands::communication::notifications::delete_notification_subscription,
             commands::communication::notifications::test_notification_subscription,
+            commands::communication::notifications::list_notification_rules,
+            commands::communication::notifications::create_notification_rule,
+            commands::communication::notifications::update_notification_rule,
+            commands::communication::notifications::delete_notification_rule,
+            commands::communication::notifications::list_notification_templates,
+            commands::communication::notifications::upsert_notification_template,
+            commands::communication::notifications::delete_notification_template,
+            commands::communication::notifications::preview_notification_template,
+            commands::communication::report_sinks::get_persona_report_sink,
+            commands::communication::report_sinks::set_persona_report_sink,
+            commands::communication::report_sinks::delete_persona_report_sink,
+            commands::communication::report_sinks::test_persona_report_sink,
             commands::communication::events::list_dead_letter_events,
             commands::communication::events::count_dead_letter_events,
             commands::communication::events::retry_dead_letter_event,
@@ -2359,6 +2522,12 @@ pub fn run() {
             commands::communication::events::bulk_retry_dead_letter_events,
             commands::communication::events::bulk_discard_dead_letter_events,
             commands::communication::events::get_dead_letter_config,
+            commands::communication::events::replay_event,
+            commands::communication::events::replay_dead_letters,
+            commands::communication::events::list_event_schemas,
+            commands::communication::events::get_event_schema,
+            commands::communication::events::upsert_event_schema,
+            commands::communication::events::delete_event_schema,
             // Communication -- Shared Events
             commands::communication::shared_events::shared_events_browse_catalog,
             commands::communication::shared_events::shared_events_refresh_catalog,
@@ -2385,13 +2554,23 @@ pub fn run() {
             commands::communication::observability::metrics::get_metrics_summary,
             commands::communication::observability::metrics::get_metrics_chart_data,
             commands::communication::observability::metrics::get_value_rollup,
+            commands::communication::observability::metrics::get_trigger_roi,
+            commands::communication::observability::metrics::get_cost_breakdown,
+            commands::communication::observability::metrics::get_cost_anomalies,
+            commands::communication::observability::metrics::rebuild_metrics_snapshots,
+            commands::communication::observability::metrics::get_metrics_rollups,
             commands::communication::observability::metrics::get_error_category_breakdown,
             commands::communication::observability::metrics::get_all_monthly_spend,
+            commands::communication::observability::metrics::get_budget_status,
+            commands::communication::observability::metrics::get_group_spend,
+            commands::communication::observability::metrics::reconcile_provider_usage,
+            commands::communication::observability::metrics::get_cost_correction_factors,
             commands::communication::observability::metrics::get_overview_bundle,
             commands::communication::observability::metrics::get_health_bundle,
             commands::communication::observability::metrics::get_prompt_performance,
             commands::communication::observability::metrics::get_execution_dashboard,
             commands::communication::observability::metrics::get_execution_heatmap,
+            commands::communication::observability::metrics::get_failure_heatmap,
             commands::communication::observability::metrics::get_anomaly_drilldown,
             // Communication -- Observability: Prompt Lab
             commands::communication::observability::prompt_lab::get_prompt_versions,
@@ -2415,8 +2594,13 @@ pub fn run() {
             commands::communication::observability::digest::set_digest_config,
             commands::communication::observability::digest::preview_digest,
             commands::communication::observability::digest::send_digest_now,
+            commands::communication::observability::digest::generate_digest_now,
+            commands::communication::observability::digest::run_fleet_hygiene_now,
             // Communication -- SLA Dashboard
             commands::communication::sla::get_sla_dashboard,
+            commands::communication::sla::get_slo_status,
+            commands::communication::sla::upsert_persona_slo,
+            commands::communication::sla::delete_persona_slo,
             // Teams
             commands::teams::teams::list_teams,
             commands::teams::teams::get_team_counts,
@@ -2434,16 +2618,24 @@ pub fn run() {
             commands::teams::teams::create_team_connection,
             commands::teams::teams::update_team_connection,
             commands::teams::teams::delete_team_connection,
+            commands::teams::teams::validate_connection_condition,
             commands::teams::teams::list_pipeline_runs,
             commands::teams::teams::get_pipeline_run,
             commands::teams::teams::execute_team,
+            commands::teams::teams::simulate_team_run,
             commands::teams::teams::cancel_pipeline,
             commands::teams::teams::approve_pipeline_node,
             commands::teams::teams::reject_pipeline_node,
+            commands::teams::teams::pause_pipeline_run,
+            commands::teams::teams::resume_pipeline_run,
+            commands::teams::teams::retry_pipeline_node,
             commands::teams::teams::get_pipeline_analytics,
             commands::teams::teams::suggest_topology,
             commands::teams::teams::suggest_topology_llm,
             commands::teams::teams::compile_workflow,
+            // Teams -- Spec export/import
+            commands::teams::team_spec::export_team_spec,
+            commands::teams::team_spec::import_team_spec,
             // Team Memories
             commands::teams::team_channel::list_team_channel,
             commands::teams::team_channel::count_team_channel_kinds,
@@ -2504,6 +2696,7 @@ pub fn run() {
             commands::tools::tools::update_tool_definition,
             commands::tools::tools::delete_tool_definition,
             commands::tools::tools::assign_tool,
+            commands::tools::tools::set_tool_policy,
             commands::tools::tools::unassign_tool,
             commands::tools::tools::bulk_assign_tools,
             commands::tools::tools::bulk_unassign_tools,
@@ -2545,6 +2738,12 @@ pub fn run() {
             commands::tools::triggers::set_trigger_unattended_mode,
             commands::tools::triggers::list_pending_trigger_fires,
             commands::tools::triggers::resolve_pending_trigger_fire,
+            commands::tools::triggers::list_injection_detections,
+            commands::tools::triggers::review_injection_detection,
+            commands::tools::maintenance::list_maintenance_windows,
+            commands::tools::maintenance::create_maintenance_window,
+            commands::tools::maintenance::end_maintenance_window,
+            commands::tools::maintenance::delete_maintenance_window,
             commands::tools::triggers::delete_trigger,
             commands::tools::triggers::validate_trigger,
             commands::tools::triggers::get_trigger_health_map,
@@ -2557,6 +2756,8 @@ pub fn run() {
             commands::tools::triggers::rename_event_type,
             commands::tools::triggers::get_webhook_status,
             commands::tools::triggers::preview_cron_schedule,
+            commands::tools::triggers::validate_cron,
+            commands::tools::triggers::describe_cron,
             commands::tools::triggers::cron_fire_times_in_range,
             commands::tools::triggers::dry_run_trigger,
             commands::tools::triggers::list_cron_agents,
@@ -2848,6 +3049,7 @@ pub fn run() {
             commands::infrastructure::auth::get_google_drive_status,
             // Infrastructure -- System
             commands::infrastructure::system::system_health_check,
+            commands::infrastructure::system::get_status_narrative,
             commands::infrastructure::system::health_check_local,
             commands::infrastructure::system::health_check_agents,
             commands::infrastructure::system::health_check_cloud,
@@ -2857,6 +3059,11 @@ pub fn run() {
             commands::infrastructure::system::health_check_environment,
             commands::infrastructure::system::storage_usage,
             commands::infrastructure::system::prune_storage,
+            commands::infrastructure::system::run_retention_cleanup_now,
+            commands::infrastructure::system::backup_database,
+            commands::infrastructure::system::list_database_backups,
+            commands::infrastructure::system::restore_database,
+            commands::infrastructure::system::check_database_health,
             commands::infrastructure::system::open_external_url,
             commands::infrastructure::system::open_local_path,
             commands::infrastructure::system::register_claude_desktop_mcp,
@@ -2870,6 +3077,9 @@ pub fn run() {
             commands::infrastructure::system::clear_frontend_crashes,
             commands::infrastructure::system::get_frontend_crash_count,
             commands::infrastructure::system::get_db_performance,
+            commands::infrastructure::system::get_db_contention_stats,
+            commands::infrastructure::system::reset_db_contention_stats,
+            commands::infrastructure::system::capture_state_snapshot,
             // Infrastructure -- Setup / Auto-install
             commands::infrastructure::setup::start_setup_install,
             commands::infrastructure::setup::cancel_setup_install,
@@ -2889,6 +3099,11 @@ pub fn run() {
             commands::infrastructure::settings::set_app_setting,
             commands::infrastructure::settings::get_model_routing_rules,
             commands::infrastructure::settings::set_model_routing_rules,
+            commands::infrastructure::settings::get_model_deprecations,
+            commands::infrastructure::settings::refresh_model_deprecations,
+            commands::infrastructure::settings::migrate_model_profiles,
+            commands::infrastructure::settings::get_observer_mode,
+            commands::infrastructure::settings::set_observer_mode,
             commands::infrastructure::git_checkpoint::dev_checkpoint_stage,
             commands::infrastructure::git_checkpoint::dev_fork_from_checkpoint,
             commands::infrastructure::git_checkpoint::dev_rollback_to_checkpoint,
@@ -2902,6 +3117,10 @@ pub fn run() {
             commands::infrastructure::qwen_engine::set_qwen_credentials,
             commands::infrastructure::qwen_engine::get_qwen_status,
             commands::infrastructure::qwen_engine::clear_qwen_credentials,
+            // Infrastructure -- Ollama local models (offline personas)
+            commands::infrastructure::ollama_models::list_ollama_models,
+            commands::infrastructure::ollama_models::pull_ollama_model,
+            commands::infrastructure::ollama_models::set_persona_ollama_model,
             // Infrastructure -- BYOM (Bring Your Own Model)
             commands::infrastructure::byom::get_byom_policy,
             commands::infrastructure::byom::set_byom_policy,
@@ -2912,6 +3131,7 @@ pub fn run() {
             commands::infrastructure::byom::get_provider_usage_stats,
             commands::infrastructure::byom::get_provider_usage_timeseries,
             commands::infrastructure::byom::test_provider_connection,
+            commands::infrastructure::byom::get_provider_capabilities,
             // Infrastructure -- Cloud
             commands::infrastructure::cloud::cloud_connect,
             commands::infrastructure::cloud::cloud_diagnose,
@@ -2921,6 +3141,7 @@ pub fn run() {
             commands::infrastructure::cloud::cloud_status,
             commands::infrastructure::cloud::cloud_execute_persona,
             commands::infrastructure::cloud::cloud_cancel_execution,
+            commands::infrastructure::cloud::cloud_reconcile_executions,
             commands::infrastructure::cloud::cloud_oauth_authorize,
             commands::infrastructure::cloud::cloud_oauth_callback,
             commands::infrastructure::cloud::cloud_oauth_status,
@@ -3029,6 +3250,7 @@ pub fn run() {
             commands::infrastructure::dev_tools::dev_tools_set_standards_config,
             commands::infrastructure::dev_tools::dev_tools_backfill_qa_pr_review,
             commands::infrastructure::dev_tools::dev_tools_delete_project,
+            commands::infrastructure::dev_tools::dev_tools_revoke_project_data_key,
             commands::infrastructure::dev_tools::dev_tools_get_active_project,
             commands::infrastructure::dev_tools::dev_tools_set_active_project,
             // Dev Tools -- Goals
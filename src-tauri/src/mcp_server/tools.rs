@@ -19,7 +19,10 @@
 //! `personas_list`, `personas_get`, `personas_status`, `personas_result`,
 //! `personas_set_model`, `post_message`, `knowledge_search`, `annotate`,
 //! `health`, `list_templates`, `search_executions`, `arena_*`, `context_*`,
-//! `obsidian_vault_*`, `gmail_*`/`gdrive_*`/`gcalendar_*` (bridge proxies).
+//! `obsidian_vault_*`, `gmail_*`/`gdrive_*`/`gcalendar_*` (bridge proxies),
+//! `email_*` (desktop IMAP/SMTP bridge), `http_request` (desktop
+//! `engine::http_tool` bridge), `sql_query` (desktop `engine::sql_tool`
+//! bridge).
 
 use std::path::{Component, Path, PathBuf};
 
@@ -1022,6 +1025,87 @@ pub fn list_tools(pool: &McpDbPool) -> Vec<Value> {
                 }
             }
         }),
+        json!({
+            "name": "email_imap_read",
+            "description": "Read messages from any IMAP mailbox (Outlook, Fastmail, iCloud, self-hosted) connected as an Email (IMAP/SMTP) credential in the vault. Returns the newest messages (or one message by uid), newest first: [{uid,from,to,cc,subject,date,message_id,seen,size_bytes,body,body_truncated,attachments}]. Messages are not marked as read unless mark_seen is true.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "mailbox": { "type": "string", "description": "Mailbox/folder name (default 'INBOX')." },
+                    "uid": { "type": "integer", "description": "Read this message (uid from email_imap_search or an earlier read)." },
+                    "limit": { "type": "integer", "description": "Number of newest messages when uid is omitted (1-20, default 5)." },
+                    "unseen_only": { "type": "boolean", "description": "Only unread messages (default false)." },
+                    "mark_seen": { "type": "boolean", "description": "Flag the returned messages as read (default false)." },
+                    "credential_id": { "type": "string", "description": "Optional specific Email (IMAP/SMTP) credential UUID. If omitted, the first one in the vault is used." }
+                }
+            }
+        }),
+        json!({
+            "name": "email_imap_search",
+            "description": "Search an IMAP mailbox connected as an Email (IMAP/SMTP) credential. All given filters must match. Returns {total, messages:[{uid,from,to,cc,subject,date,message_id,seen,size_bytes}]} (newest first, headers only). Use email_imap_read with a uid to read a message body.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "mailbox": { "type": "string", "description": "Mailbox/folder name (default 'INBOX')." },
+                    "from": { "type": "string", "description": "Sender contains this text." },
+                    "to": { "type": "string", "description": "Recipient contains this text." },
+                    "subject": { "type": "string", "description": "Subject contains this text." },
+                    "text": { "type": "string", "description": "Headers or body contain this text." },
+                    "since": { "type": "string", "description": "Received on or after this date (YYYY-MM-DD)." },
+                    "before": { "type": "string", "description": "Received before this date (YYYY-MM-DD)." },
+                    "unseen_only": { "type": "boolean", "description": "Only unread messages (default false)." },
+                    "limit": { "type": "integer", "description": "Max messages to return (1-100, default 20)." },
+                    "credential_id": { "type": "string", "description": "Optional specific Email (IMAP/SMTP) credential UUID." }
+                }
+            }
+        }),
+        json!({
+            "name": "email_smtp_send",
+            "description": "Send an email over SMTP from the mailbox connected as an Email (IMAP/SMTP) credential. Returns {message_id, accepted}. To reply, pass the original message_id as in_reply_to.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "to": { "type": "array", "items": { "type": "string" }, "description": "Recipient addresses ('a@example.com' or 'Name <a@example.com>')." },
+                    "cc": { "type": "array", "items": { "type": "string" }, "description": "Optional Cc addresses." },
+                    "bcc": { "type": "array", "items": { "type": "string" }, "description": "Optional Bcc addresses (not shown to other recipients)." },
+                    "subject": { "type": "string", "description": "Subject line." },
+                    "body": { "type": "string", "description": "Plain-text body." },
+                    "html": { "type": "string", "description": "Optional HTML version of the body." },
+                    "in_reply_to": { "type": "string", "description": "Optional Message-ID of the message being replied to." },
+                    "credential_id": { "type": "string", "description": "Optional specific Email (IMAP/SMTP) credential UUID." }
+                },
+                "required": ["to", "subject", "body"]
+            }
+        }),
+        json!({
+            "name": "http_request",
+            "description": "Make an HTTP request. Runs in the desktop app under this agent's network policy (allowed/denied domains, methods, timeout); a blocked request returns an error instead of a response. Pass `url` for a public endpoint, or `credential_id` + `path` to call a connected API with the credential's auth applied. Returns {status, status_text, content_type, body, truncated, duration_ms}; bodies over 16 KB are truncated.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "method": { "type": "string", "description": "HTTP method (default GET)." },
+                    "url": { "type": "string", "description": "Absolute http(s) URL. Omit when using credential_id." },
+                    "credential_id": { "type": "string", "description": "Vault credential UUID whose connector base URL and auth to use." },
+                    "path": { "type": "string", "description": "Path (and query) relative to the credential's base URL." },
+                    "headers": { "type": "object", "additionalProperties": { "type": "string" }, "description": "Extra request headers." },
+                    "body": { "description": "Request body: a string is sent as-is, an object or array as JSON." },
+                    "timeout_secs": { "type": "integer", "description": "Timeout in seconds (default 30; capped by the policy)." }
+                }
+            }
+        }),
+        json!({
+            "name": "sql_query",
+            "description": "Run one SQL statement against a connected database (PostgreSQL, Neon, MySQL, SQLite, or the built-in database). Runs in the desktop app with the credential's connection; the agent never sees it. Read-only unless this agent's policy allows writes, and every write needs human approval first. Bind values with `params` ($1, $2 for PostgreSQL; ? for MySQL and SQLite) instead of inlining them. Returns {columns, rows, row_count, duration_ms, truncated}; rows past the policy's row or size limit are dropped and `truncated` is set.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "sql": { "type": "string", "description": "A single SQL statement." },
+                    "params": { "type": "array", "description": "Values for the statement's placeholders, in order." },
+                    "credential_id": { "type": "string", "description": "Optional database credential UUID (default: the first database credential)." }
+                },
+                "required": ["sql"]
+            }
+        }),
         json!({
             "name": "obsidian_vault_search",
             "description": "Search the user's Obsidian vault for notes relevant to a query (TF-IDF over titles + bodies). Use to pull specific cases, prior analysis, or background from the user's own notes. Requires the Athena Brain vault-access toggle (Obsidian Brain → Setup) with a configured vault.",
@@ -1162,6 +1246,11 @@ pub fn call_tool(name: &str, args: &Value, pool: &McpDbPool) -> Value {
         "gdrive_list_files" => handle_gdrive_list_files(args, pool),
         "gdrive_get_file" => handle_gdrive_get_file(args, pool),
         "gcalendar_list_events" => handle_gcalendar_list_events(args, pool),
+        "email_imap_read" => handle_email_imap_read(args, pool),
+        "email_imap_search" => handle_email_imap_search(args, pool),
+        "email_smtp_send" => handle_email_smtp_send(args, pool),
+        "http_request" => handle_http_request(args),
+        "sql_query" => handle_sql_query(args, pool),
         "obsidian_vault_search" => handle_obsidian_vault_search(args, pool),
         "obsidian_vault_write_note" => handle_obsidian_vault_write_note(args, pool),
         #[cfg(feature = "scraper")]
@@ -1462,13 +1551,19 @@ fn vault_credential_id(
 /// Forward an HTTP request through the desktop app's credential proxy, which
 /// resolves the credential's auth (OAuth refresh included) and calls the API.
 fn bridge_proxy(credential_id: &str, method: &str, path: &str) -> Result<String, String> {
+    let payload = json!({ "method": method, "path": path, "headers": {}, "body": null });
+    bridge_post(&format!("proxy/{credential_id}"), &payload)
+}
+
+/// POST `payload` to the desktop app's `/api/{route}` with the bridge system key.
+fn bridge_post(route: &str, payload: &Value) -> Result<String, String> {
     let bridge =
         std::env::var("PERSONAS_BRIDGE_URL").unwrap_or_else(|_| "http://127.0.0.1:9420".to_string());
     let api_key = std::env::var("PERSONAS_API_KEY").map_err(|_| {
         "Connector bridge unavailable for this run (PERSONAS_API_KEY not set).".to_string()
     })?;
-    let url = format!("{}/api/proxy/{}", bridge.trim_end_matches('/'), credential_id);
-    let payload = json!({ "method": method, "path": path, "headers": {}, "body": null });
+    let url = format!("{}/api/{}", bridge.trim_end_matches('/'), route);
+    let payload = payload.clone();
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -1614,6 +1709,89 @@ fn handle_gcalendar_list_events(args: &Value, pool: &McpDbPool) -> Result<String
     bridge_proxy(&cred, "GET", &path)
 }
 
+// ── Generic IMAP/SMTP (desktop route /api/email/{id}) ──
+// Unlike the Google tools these don't go through the HTTP proxy: the desktop
+// app speaks IMAP/SMTP itself (`engine::email`) with the decrypted mailbox
+// password, and gets the tool arguments as-is plus the operation name.
+fn email_bridge(args: &Value, pool: &McpDbPool, op: &str) -> Result<String, String> {
+    let cred = vault_credential_id(args, pool, "imap_smtp", "Email (IMAP/SMTP)")?;
+    let mut payload = match args {
+        Value::Object(map) => map.clone(),
+        _ => serde_json::Map::new(),
+    };
+    payload.remove("credential_id");
+    payload.insert("op".into(), json!(op));
+    bridge_post(&format!("email/{cred}"), &Value::Object(payload))
+}
+
+fn handle_email_imap_read(args: &Value, pool: &McpDbPool) -> Result<String, String> {
+    email_bridge(args, pool, "imap_read")
+}
+
+fn handle_email_imap_search(args: &Value, pool: &McpDbPool) -> Result<String, String> {
+    email_bridge(args, pool, "imap_search")
+}
+
+fn handle_email_smtp_send(args: &Value, pool: &McpDbPool) -> Result<String, String> {
+    let has_recipient = args
+        .get("to")
+        .and_then(|v| v.as_array())
+        .is_some_and(|to| !to.is_empty());
+    if !has_recipient {
+        return Err("'to' must be a non-empty array of email addresses".to_string());
+    }
+    email_bridge(args, pool, "smtp_send")
+}
+
+// ── http_request (desktop route /api/http-request/{persona_id}) ──
+// The request is made by the desktop app (`engine::http_tool`), which checks
+// the persona's network policy, applies the timeout and rate limit, and
+// injects credential auth. The persona id comes from the runner's env, not
+// from the agent, so an agent cannot borrow another persona's policy.
+fn handle_http_request(args: &Value) -> Result<String, String> {
+    let persona_id = std::env::var("PERSONAS_PERSONA_ID").map_err(|_| {
+        "http_request unavailable for this run (PERSONAS_PERSONA_ID not set).".to_string()
+    })?;
+    bridge_post(&format!("http-request/{persona_id}"), args)
+}
+
+// ── sql_query (desktop route /api/sql-query/{persona_id}) ──
+// The statement runs in the desktop app (`engine::sql_tool`) with the
+// credential's connection, the persona's row/size limits and write policy.
+// The execution id lets the engine check the run's write approval.
+fn handle_sql_query(args: &Value, pool: &McpDbPool) -> Result<String, String> {
+    let persona_id = std::env::var("PERSONAS_PERSONA_ID").map_err(|_| {
+        "sql_query unavailable for this run (PERSONAS_PERSONA_ID not set).".to_string()
+    })?;
+    if args.get("sql").and_then(|v| v.as_str()).is_none() {
+        return Err("Missing required parameter: sql".to_string());
+    }
+    let credential_id = match args.get("credential_id").and_then(|v| v.as_str()) {
+        Some(id) => id.to_string(),
+        None => {
+            let conn = pool.get()?;
+            conn.query_row(
+                "SELECT id FROM persona_credentials \
+                 WHERE service_type IN ('postgres', 'neon', 'mysql', 'sqlite', 'personas_database') \
+                 ORDER BY created_at LIMIT 1",
+                [],
+                |r| r.get::<_, String>(0),
+            )
+            .map_err(|_| {
+                "No database credential found in the vault. Connect PostgreSQL, MySQL or SQLite \
+                 in Settings → Connectors."
+                    .to_string()
+            })?
+        }
+    };
+    let mut payload = args.clone();
+    payload["credential_id"] = json!(credential_id);
+    if let Ok(execution_id) = std::env::var("PERSONAS_EXECUTION_ID") {
+        payload["execution_id"] = json!(execution_id);
+    }
+    bridge_post(&format!("sql-query/{persona_id}"), &payload)
+}
+
 fn handle_personas_list(args: &Value, pool: &McpDbPool) -> Result<String, String> {
     let conn = pool.get()?;
     let enabled_only = args
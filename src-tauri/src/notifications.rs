@@ -10,6 +10,7 @@ use ts_rs::TS;
 use crate::db::models::{ChannelScopeV2, ChannelSpecV2, ChannelSpecV2Type};
 use crate::engine::crypto::SecureString;
 use crate::engine::event_registry::{emit_event, event_name};
+use crate::error::AppError;
 
 /// Per-persona notification preferences parsed from `notification_channels` JSON.
 #[derive(Debug, Deserialize)]
@@ -422,16 +423,23 @@ async fn resolve_credential_fields(
 /// credential fields layered under `spec.config` (config wins on collision so
 /// the per-channel destination — Slack channel, Discord channel_id, Telegram
 /// chat_id — set in the picker can override anything in the credential).
-async fn merged_channel_config(
+async fn merged_channel_config(app: &AppHandle, spec: &ChannelSpecV2) -> HashMap<String, String> {
+    merge_channel_config(app, spec.credential_id.as_deref(), spec.config.as_ref()).await
+}
+
+/// [`merged_channel_config`] for any credential + inline config pair (shape-v2
+/// channel specs and notification rules).
+async fn merge_channel_config(
     app: &AppHandle,
-    spec: &ChannelSpecV2,
+    credential_id: Option<&str>,
+    config: Option<&serde_json::Value>,
 ) -> HashMap<String, String> {
-    let mut merged = if let Some(cred_id) = spec.credential_id.as_deref() {
+    let mut merged = if let Some(cred_id) = credential_id {
         resolve_credential_fields(app, cred_id).await
     } else {
         HashMap::new()
     };
-    if let Some(obj) = spec.config.as_ref().and_then(|c| c.as_object()) {
+    if let Some(obj) = config.and_then(|c| c.as_object()) {
         for (k, v) in obj {
             let s = match v {
                 serde_json::Value::String(s) => s.clone(),
@@ -1012,6 +1020,422 @@ async fn deliver_teams(ch: &ExternalChannel, title: &str, body: &str) -> Result<
     )
 }
 
+// ---------------------------------------------------------------------------
+// Rule-based routing (notification_rules)
+// ---------------------------------------------------------------------------
+
+/// A notification as seen by the routing rules in `notification_rules`.
+pub(crate) struct RuleNotification<'a> {
+    /// One of `notification_rules::CATEGORIES` (never `*`).
+    pub category: &'a str,
+    /// One of `notification_rules::SEVERITIES`.
+    pub severity: &'a str,
+    pub persona_id: Option<&'a str>,
+    pub title: &'a str,
+    pub body: &'a str,
+}
+
+/// Recent deliveries per rule id, for `max_per_hour`. In-memory only; resets
+/// on app restart.
+static RULE_DELIVERIES: LazyLock<
+    std::sync::Mutex<HashMap<String, std::collections::VecDeque<std::time::Instant>>>,
+> = LazyLock::new(|| std::sync::Mutex::new(HashMap::new()));
+
+const RULE_RATE_WINDOW: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+fn rule_matches(
+    rule: &crate::db::models::NotificationRule,
+    notification: &RuleNotification<'_>,
+    team_id: Option<&str>,
+) -> bool {
+    use crate::db::repos::communication::notification_rules::severity_rank;
+
+    (rule.category == "*" || rule.category == notification.category)
+        && rule
+            .persona_id
+            .as_deref()
+            .is_none_or(|p| Some(p) == notification.persona_id)
+        && rule.team_id.as_deref().is_none_or(|t| Some(t) == team_id)
+        && severity_rank(notification.severity) >= severity_rank(&rule.min_severity)
+}
+
+/// Whether `now` falls in the `[start, end)` window, which may wrap midnight.
+fn in_quiet_hours(
+    start: chrono::NaiveTime,
+    end: chrono::NaiveTime,
+    now: chrono::NaiveTime,
+) -> bool {
+    if start <= end {
+        now >= start && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+fn rule_is_quiet(rule: &crate::db::models::NotificationRule, now: chrono::NaiveTime) -> bool {
+    use crate::db::repos::communication::notification_rules::parse_quiet_time;
+
+    match (
+        rule.quiet_hours_start.as_deref().and_then(parse_quiet_time),
+        rule.quiet_hours_end.as_deref().and_then(parse_quiet_time),
+    ) {
+        (Some(start), Some(end)) => in_quiet_hours(start, end, now),
+        _ => false,
+    }
+}
+
+/// Whether the rule may deliver again under its `max_per_hour`; records the
+/// delivery when it may.
+fn take_rule_slot(rule_id: &str, max_per_hour: Option<i32>) -> bool {
+    let Some(max) = max_per_hour else {
+        return true;
+    };
+    let mut deliveries = RULE_DELIVERIES.lock().unwrap_or_else(|e| e.into_inner());
+    let now = std::time::Instant::now();
+    let recent = deliveries.entry(rule_id.to_string()).or_default();
+    while recent
+        .front()
+        .is_some_and(|at| now.duration_since(*at) >= RULE_RATE_WINDOW)
+    {
+        recent.pop_front();
+    }
+    if recent.len() >= max.max(0) as usize {
+        return false;
+    }
+    recent.push_back(now);
+    true
+}
+
+/// Send a notification to a `deliver` rule's channel (fire-and-forget).
+fn deliver_rule(
+    app: &AppHandle,
+    rule: &crate::db::models::NotificationRule,
+    title: &str,
+    body: &str,
+) {
+    let Some(channel_type) = rule.channel_type.clone() else {
+        return;
+    };
+    let app = app.clone();
+    let rule_id = rule.id.clone();
+    let credential_id = rule.credential_id.clone();
+    let config: Option<serde_json::Value> = rule
+        .channel_config
+        .as_deref()
+        .and_then(|c| serde_json::from_str(c).ok());
+    let title = title.to_string();
+    let body = body.to_string();
+    tokio::spawn(async move {
+        let ch = ExternalChannel {
+            channel_type,
+            enabled: true,
+            config: merge_channel_config(&app, credential_id.as_deref(), config.as_ref()).await,
+            credential_id,
+        };
+        let metrics = DELIVERY_METRICS.for_channel(&ch.channel_type);
+        let start = std::time::Instant::now();
+        let result = match ch.channel_type.as_str() {
+            "slack" => deliver_slack(&ch, &title, &body).await,
+            "telegram" => deliver_telegram(&ch, &title, &body).await,
+            "email" => deliver_email(&ch, &title, &body).await,
+            "discord" => deliver_discord(&ch, &title, &body).await,
+            "teams" => deliver_teams(&ch, &title, &body).await,
+            other => {
+                tracing::debug!("Unknown channel type: {}", other);
+                Ok(())
+            }
+        };
+        let latency_ms = start.elapsed().as_millis() as u64;
+
+        let (success, error) = match &result {
+            Ok(()) => {
+                metrics.record_success(latency_ms);
+                (true, None)
+            }
+            Err(e) => {
+                metrics.record_failure();
+                tracing::warn!(
+                    rule_id = %rule_id,
+                    channel_type = %ch.channel_type,
+                    "Failed to deliver rule notification to {} channel: {}", ch.channel_type, e
+                );
+                (false, Some(e.clone()))
+            }
+        };
+
+        let event = NotificationDeliveryEvent {
+            channel_type: ch.channel_type.clone(),
+            success,
+            latency_ms,
+            error,
+            consecutive_failures: metrics.consecutive_failures.load(Ordering::Relaxed),
+        };
+        emit_event(&app, event_name::NOTIFICATION_DELIVERY, &event);
+    });
+}
+
+/// Apply the enabled notification rules to a notification: every matching
+/// `deliver` rule outside its quiet hours and under its hourly limit sends it
+/// to the rule's channel. Returns `true` when a matching `silent` rule asks
+/// for the default delivery (desktop toast + the persona's own channels) to
+/// be skipped.
+pub(crate) fn route_by_rules(app: &AppHandle, notification: &RuleNotification<'_>) -> bool {
+    use crate::db::repos::communication::notification_rules;
+    use tauri::Manager;
+
+    let Some(state) = app.try_state::<std::sync::Arc<crate::AppState>>() else {
+        return false;
+    };
+    let rules = match notification_rules::list_enabled(&state.db) {
+        Ok(rules) => rules,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to load notification rules");
+            return false;
+        }
+    };
+    if rules.is_empty() {
+        return false;
+    }
+    // Team filters match on the persona's workspace; only look it up if a
+    // rule needs it.
+    let team_id = if rules.iter().any(|r| r.team_id.is_some()) {
+        notification
+            .persona_id
+            .and_then(|id| crate::db::repos::core::personas::get_by_id(&state.db, id).ok())
+            .and_then(|p| p.home_team_id)
+    } else {
+        None
+    };
+
+    let now = chrono::Local::now().time();
+    let mut silenced = false;
+    for rule in rules
+        .iter()
+        .filter(|r| rule_matches(r, notification, team_id.as_deref()))
+    {
+        if rule.action == "silent" {
+            silenced = true;
+            continue;
+        }
+        if rule_is_quiet(rule, now) {
+            tracing::debug!(rule_id = %rule.id, "Notification rule in quiet hours, skipping");
+            continue;
+        }
+        if !take_rule_slot(&rule.id, rule.max_per_hour) {
+            tracing::debug!(rule_id = %rule.id, "Notification rule hit its hourly limit, skipping");
+            continue;
+        }
+        deliver_rule(app, rule, notification.title, notification.body);
+    }
+    silenced
+}
+
+// ---------------------------------------------------------------------------
+// User templates (notification_templates)
+// ---------------------------------------------------------------------------
+
+/// A notification type that can be worded by a user template, with the
+/// variables it fills in and the sample values previews use.
+pub struct TemplateType {
+    pub name: &'static str,
+    pub variables: &'static [(&'static str, &'static str)],
+}
+
+pub const TEMPLATE_TYPES: &[TemplateType] = &[
+    TemplateType {
+        name: "execution_completed",
+        variables: &[
+            ("persona_name", "Inbox Triage"),
+            ("status", "completed"),
+            ("duration", "12.4s"),
+            ("cost", "$0.0132"),
+            ("model", "claude-sonnet-4"),
+        ],
+    },
+    TemplateType {
+        name: "execution_failed",
+        variables: &[
+            ("persona_name", "Inbox Triage"),
+            ("status", "failed"),
+            ("duration", "3.1s"),
+            ("cost", "$0.0021"),
+            ("model", "claude-sonnet-4"),
+            ("error", "Gmail API returned 401 Unauthorized"),
+        ],
+    },
+    TemplateType {
+        name: "healing_issue",
+        variables: &[
+            ("persona_name", "Inbox Triage"),
+            ("title", "Repeated authentication failures"),
+            ("severity", "high"),
+            ("suggested_fix", "Reconnect the Gmail credential"),
+        ],
+    },
+    TemplateType {
+        name: "budget_exceeded",
+        variables: &[
+            ("persona_name", "Inbox Triage"),
+            ("title", "Budget Exceeded"),
+            (
+                "message",
+                "Inbox Triage has spent $25.0310 this month (budget: $25.00).",
+            ),
+            ("spent", "$25.03"),
+            ("budget", "$25.00"),
+        ],
+    },
+    TemplateType {
+        name: "manual_review",
+        variables: &[
+            ("persona_name", "Inbox Triage"),
+            ("title", "Approve refund for order #1042"),
+        ],
+    },
+];
+
+pub fn template_type(name: &str) -> Option<&'static TemplateType> {
+    TEMPLATE_TYPES.iter().find(|t| t.name == name)
+}
+
+/// The `{{name}}` placeholders in `text`, in order of first use. Errors on an
+/// unclosed or empty placeholder.
+pub fn template_placeholders(text: &str) -> Result<Vec<String>, String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            return Err("Unclosed '{{' placeholder".into());
+        };
+        let name = after[..end].trim();
+        if name.is_empty() {
+            return Err("Empty '{{}}' placeholder".into());
+        }
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    Ok(names)
+}
+
+/// Check a template against the variables its notification type offers.
+pub fn validate_template(notification_type: &str, text: &str) -> Result<Vec<String>, AppError> {
+    let Some(ty) = template_type(notification_type) else {
+        return Err(AppError::Validation(format!(
+            "Unknown notification type '{notification_type}': expected one of {}",
+            TEMPLATE_TYPES
+                .iter()
+                .map(|t| t.name)
+                .collect::<Vec<_>>()
+                .join(" | ")
+        )));
+    };
+    let names = template_placeholders(text).map_err(AppError::Validation)?;
+    if let Some(unknown) = names
+        .iter()
+        .find(|n| !ty.variables.iter().any(|(v, _)| v == n))
+    {
+        return Err(AppError::Validation(format!(
+            "Unknown variable '{{{{{unknown}}}}}' for {notification_type}: available are {}",
+            ty.variables
+                .iter()
+                .map(|(v, _)| *v)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+    Ok(names)
+}
+
+/// Substitute `{{name}}` placeholders. Variables without a value render
+/// empty.
+pub fn render_template(text: &str, vars: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let name = after[..end].trim();
+        if let Some((_, value)) = vars.iter().find(|(n, _)| *n == name) {
+            out.push_str(value);
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Render a template with sample values (or the caller's `variables`),
+/// validating it as a save would.
+pub fn preview_template(
+    input: &crate::db::models::PreviewNotificationTemplateInput,
+) -> Result<crate::db::models::NotificationTemplatePreview, AppError> {
+    let mut used_variables = validate_template(&input.notification_type, &input.title_template)?;
+    for name in validate_template(&input.notification_type, &input.body_template)? {
+        if !used_variables.contains(&name) {
+            used_variables.push(name);
+        }
+    }
+    let ty = template_type(&input.notification_type)
+        .ok_or_else(|| AppError::Validation("Unknown notification type".into()))?;
+    let vars: Vec<(&str, String)> = ty
+        .variables
+        .iter()
+        .map(|(name, sample)| {
+            let value = input
+                .variables
+                .as_ref()
+                .and_then(|v| v.get(*name))
+                .map_or(*sample, String::as_str);
+            (*name, value.to_string())
+        })
+        .collect();
+    Ok(crate::db::models::NotificationTemplatePreview {
+        title: render_template(&input.title_template, &vars),
+        body: render_template(&input.body_template, &vars),
+        available_variables: ty.variables.iter().map(|(v, _)| v.to_string()).collect(),
+        used_variables,
+    })
+}
+
+/// Reword a notification with the user's template for its type and the UI
+/// language, if there is one; otherwise the built-in `title`/`body` stand.
+fn apply_template(
+    app: &AppHandle,
+    notification_type: &str,
+    vars: &[(&str, String)],
+    title: String,
+    body: String,
+) -> (String, String) {
+    use crate::db::repos::communication::notification_templates;
+    use tauri::Manager;
+
+    let Some(state) = app.try_state::<std::sync::Arc<crate::AppState>>() else {
+        return (title, body);
+    };
+    let locale =
+        crate::db::repos::core::settings::get(&state.db, crate::db::settings_keys::APP_LANGUAGE)
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+    match notification_templates::resolve(&state.db, notification_type, &locale) {
+        Ok(Some(template)) => (
+            render_template(&template.title_template, vars),
+            render_template(&template.body_template, vars),
+        ),
+        Ok(None) => (title, body),
+        Err(e) => {
+            tracing::warn!(notification_type, error = %e, "Failed to load notification template");
+            (title, body)
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Public notification helpers
 // ---------------------------------------------------------------------------
@@ -1019,6 +1443,7 @@ async fn deliver_teams(ch: &ExternalChannel, title: &str, body: &str) -> Result<
 #[allow(dead_code)]
 pub fn notify_execution_completed(
     app: &AppHandle,
+    persona_id: &str,
     persona_name: &str,
     status: &str,
     duration_ms: u64,
@@ -1026,6 +1451,7 @@ pub fn notify_execution_completed(
 ) {
     notify_execution_completed_rich(
         app,
+        persona_id,
         persona_name,
         status,
         duration_ms,
@@ -1040,6 +1466,7 @@ pub fn notify_execution_completed(
 #[allow(clippy::too_many_arguments)]
 pub fn notify_execution_completed_rich(
     app: &AppHandle,
+    persona_id: &str,
     persona_name: &str,
     status: &str,
     duration_ms: u64,
@@ -1048,9 +1475,6 @@ pub fn notify_execution_completed_rich(
     model_used: Option<&str>,
     error: Option<&str>,
 ) {
-    if !parse_prefs(channels).execution_completed {
-        return;
-    }
     let duration_str = format!("{:.1}s", duration_ms as f64 / 1000.0);
     let emoji = match status {
         "completed" => "OK",
@@ -1070,19 +1494,66 @@ pub fn notify_execution_completed_rich(
             body.push_str(&format!(" | {}", model));
         }
     }
-    if let Some(err) = error {
-        if !err.is_empty() {
-            // Truncate error for notification readability
-            let short_err = if err.len() > 200 { crate::utils::text::truncate_on_char_boundary(&err, 200) } else { err };
-            body.push_str(&format!("\nError: {}", short_err));
+    // Truncate error for notification readability
+    let short_err = error.filter(|e| !e.is_empty()).map(|err| {
+        if err.len() > 200 {
+            crate::utils::text::truncate_on_char_boundary(err, 200)
+        } else {
+            err
         }
+    });
+    if let Some(short_err) = short_err {
+        body.push_str(&format!("\nError: {}", short_err));
+    }
+    let (title, body) = apply_template(
+        app,
+        if status == "completed" {
+            "execution_completed"
+        } else {
+            "execution_failed"
+        },
+        &[
+            ("persona_name", persona_name.to_string()),
+            ("status", status.to_string()),
+            ("duration", duration_str),
+            (
+                "cost",
+                cost_usd
+                    .filter(|c| *c > 0.0)
+                    .map(|c| format!("${:.4}", c))
+                    .unwrap_or_default(),
+            ),
+            ("model", model_used.unwrap_or_default().to_string()),
+            ("error", short_err.unwrap_or_default().to_string()),
+        ],
+        title,
+        body,
+    );
+    // Rules see every completion; failures rank above successes so a rule
+    // can route only the runs that need attention.
+    let silenced = route_by_rules(
+        app,
+        &RuleNotification {
+            category: "execution",
+            severity: match status {
+                "completed" => "info",
+                "cancelled" => "low",
+                _ => "medium",
+            },
+            persona_id: Some(persona_id),
+            title: &title,
+            body: &body,
+        },
+    );
+    if silenced || !parse_prefs(channels).execution_completed {
+        return;
     }
     send(app, &title, &body);
     // notify_execution_completed_rich is called from runner.rs which may not have
-    // a DeliveryContext; use an empty-sentinel context (emit_event_type: None so
-    // event_filter is bypassed — execution completion is UserMessage-class per D-02).
+    // a DeliveryContext; build one here (emit_event_type: None so event_filter
+    // is bypassed — execution completion is UserMessage-class per D-02).
     let delivery_ctx = DeliveryContext {
-        persona_id: String::new(), // sentinel: runner call site lacks persona_id in scope
+        persona_id: persona_id.to_string(),
         persona_name: persona_name.to_string(),
         use_case_id: None,
         emit_event_type: None, // always bypasses event_filter
@@ -1098,13 +1569,36 @@ pub fn notify_manual_review(
     channels: Option<&str>,
     delivery_ctx: &DeliveryContext,
 ) {
-    if !parse_prefs(channels).manual_review {
+    let (heading, body) = apply_template(
+        app,
+        "manual_review",
+        &[
+            ("persona_name", persona_name.to_string()),
+            ("title", title.to_string()),
+        ],
+        "Manual Review Needed".to_string(),
+        format!("{}: {}", persona_name, title),
+    );
+    let silenced = route_by_rules(
+        app,
+        &RuleNotification {
+            category: "manual_review",
+            severity: "medium",
+            persona_id: Some(&delivery_ctx.persona_id),
+            title: &heading,
+            body: &body,
+        },
+    );
+    if silenced || !parse_prefs(channels).manual_review {
         return;
     }
-    let heading = "Manual Review Needed";
-    let body = format!("{}: {}", persona_name, title);
-    send(app, heading, &body);
-    deliver_to_channels(app, channels, heading, &body, delivery_ctx);
+    send(app, &heading, &body);
+    deliver_to_channels(app, channels, &heading, &body, delivery_ctx);
+    crate::cloud::push::relay(
+        crate::cloud::push::PushCategory::PendingApproval,
+        &heading,
+        &body,
+    );
 }
 
 pub fn notify_new_message(
@@ -1114,23 +1608,64 @@ pub fn notify_new_message(
     channels: Option<&str>,
     delivery_ctx: &DeliveryContext,
 ) {
-    if !parse_prefs(channels).new_message {
+    let heading = format!("Message from {}", persona_name);
+    let silenced = route_by_rules(
+        app,
+        &RuleNotification {
+            category: "message",
+            severity: "info",
+            persona_id: Some(&delivery_ctx.persona_id),
+            title: &heading,
+            body: title,
+        },
+    );
+    if silenced || !parse_prefs(channels).new_message {
         return;
     }
-    let heading = format!("Message from {}", persona_name);
     send(app, &heading, title);
     deliver_to_channels(app, channels, &heading, title, delivery_ctx);
 }
 
 pub fn notify_healing_issue(
     app: &AppHandle,
+    persona_id: &str,
     persona_name: &str,
     title: &str,
     severity: &str,
     suggested_fix: Option<&str>,
     channels: Option<&str>,
 ) {
-    if !parse_prefs(channels).healing_issue {
+    let body = match suggested_fix {
+        Some(fix) => format!("{persona_name}: {title}\nFix: {fix}"),
+        None => format!("{persona_name}: {title}"),
+    };
+    let (heading, body) = apply_template(
+        app,
+        "healing_issue",
+        &[
+            ("persona_name", persona_name.to_string()),
+            ("title", title.to_string()),
+            ("severity", severity.to_string()),
+            (
+                "suggested_fix",
+                suggested_fix.unwrap_or_default().to_string(),
+            ),
+        ],
+        format!("Healing Alert ({})", severity),
+        body,
+    );
+    // Rules see every severity; their own `min_severity` decides.
+    let silenced = route_by_rules(
+        app,
+        &RuleNotification {
+            category: "healing",
+            severity,
+            persona_id: Some(persona_id),
+            title: &heading,
+            body: &body,
+        },
+    );
+    if silenced || !parse_prefs(channels).healing_issue {
         return;
     }
     // Per-severity gating: critical and high always notify, medium/low are silent
@@ -1138,14 +1673,9 @@ pub fn notify_healing_issue(
         "critical" | "high" => {}
         _ => return,
     }
-    let body = match suggested_fix {
-        Some(fix) => format!("{persona_name}: {title}\nFix: {fix}"),
-        None => format!("{persona_name}: {title}"),
-    };
-    let heading = format!("Healing Alert ({})", severity);
     send(app, &heading, &body);
     let delivery_ctx = DeliveryContext {
-        persona_id: String::new(),
+        persona_id: persona_id.to_string(),
         persona_name: persona_name.to_string(),
         use_case_id: None,
         emit_event_type: None, // healing alerts are UserMessage-class — bypass filter
@@ -1154,6 +1684,123 @@ pub fn notify_healing_issue(
     deliver_to_channels(app, channels, &heading, &body, &delivery_ctx);
 }
 
+/// Route a budget alert through the notification rules. Budget alerts have
+/// no desktop toast of their own — they land in the inbox — so rules only
+/// add deliveries here.
+pub fn notify_budget_exceeded(
+    app: &AppHandle,
+    persona_id: &str,
+    persona_name: &str,
+    spent_usd: f64,
+    budget_usd: f64,
+    title: &str,
+    body: &str,
+) {
+    let (title, body) = apply_template(
+        app,
+        "budget_exceeded",
+        &[
+            ("persona_name", persona_name.to_string()),
+            ("title", title.to_string()),
+            ("message", body.to_string()),
+            ("spent", format!("${spent_usd:.2}")),
+            ("budget", format!("${budget_usd:.2}")),
+        ],
+        title.to_string(),
+        body.to_string(),
+    );
+    route_by_rules(
+        app,
+        &RuleNotification {
+            category: "budget",
+            severity: "critical",
+            persona_id: Some(persona_id),
+            title: &title,
+            body: &body,
+        },
+    );
+}
+
+/// Alert that a credential is about to expire (or has). Not tied to a
+/// persona, so only rules with no persona filter match.
+pub fn notify_credential_expiring(
+    app: &AppHandle,
+    credential_name: &str,
+    days_remaining: i64,
+    expired: bool,
+) {
+    let (heading, severity) = if expired {
+        ("Credential expired", "critical")
+    } else {
+        ("Credential expiring soon", "high")
+    };
+    let body = match days_remaining {
+        _ if expired => format!("{credential_name} has expired."),
+        0 => format!("{credential_name} expires within a day."),
+        1 => format!("{credential_name} expires in 1 day."),
+        n => format!("{credential_name} expires in {n} days."),
+    };
+    let silenced = route_by_rules(
+        app,
+        &RuleNotification {
+            category: "credential",
+            severity,
+            persona_id: None,
+            title: heading,
+            body: &body,
+        },
+    );
+    if !silenced {
+        send(app, heading, &body);
+    }
+}
+
+/// Alert on unusual credential usage flagged by the anomaly detector.
+pub fn notify_credential_anomaly(app: &AppHandle, anomaly: &crate::db::models::CredentialAnomaly) {
+    let silenced = route_by_rules(
+        app,
+        &RuleNotification {
+            category: "credential",
+            severity: &anomaly.severity,
+            persona_id: anomaly.persona_id.as_deref(),
+            title: &anomaly.title,
+            body: &anomaly.description,
+        },
+    );
+    if !silenced {
+        send(app, &anomaly.title, &anomaly.description);
+    }
+}
+
+/// Surface the startup integrity pass (`db::integrity`) when it had to repair
+/// the database or could not. Silent after a clean check.
+pub fn notify_database_integrity(app: &AppHandle) {
+    let Some(report) = crate::db::integrity::startup_report() else {
+        return;
+    };
+    match report.status.as_str() {
+        "reindexed" => send(
+            app,
+            "Database repaired",
+            "Damaged database indices were rebuilt at startup.",
+        ),
+        "restored" => send(
+            app,
+            "Database restored from backup",
+            &format!(
+                "The database was corrupt and has been replaced with {}. Changes made after that backup are not included.",
+                report.restored_from.as_deref().unwrap_or("the latest backup")
+            ),
+        ),
+        "corrupt" => send(
+            app,
+            "Database is corrupt",
+            "The database failed its integrity check and no valid backup was found. Restore a backup from Settings.",
+        ),
+        _ => {}
+    }
+}
+
 pub fn notify_n8n_transform_completed(app: &AppHandle, workflow_name: &str, success: bool) {
     if success {
         send(
@@ -2023,4 +2670,102 @@ mod tests {
         let _ = stats.discord.attempted;
         let _ = stats.teams.attempted;
     }
+
+    fn rule(category: &str, min_severity: &str) -> crate::db::models::NotificationRule {
+        crate::db::models::NotificationRule {
+            id: format!("rule-{category}-{min_severity}"),
+            name: "test".into(),
+            category: category.into(),
+            persona_id: None,
+            team_id: None,
+            min_severity: min_severity.into(),
+            action: "deliver".into(),
+            channel_type: Some("slack".into()),
+            credential_id: None,
+            channel_config: None,
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+            max_per_hour: None,
+            enabled: true,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn rules_match_on_category_severity_persona_and_team() {
+        let critical = RuleNotification {
+            category: "healing",
+            severity: "critical",
+            persona_id: Some("p1"),
+            title: "t",
+            body: "b",
+        };
+        assert!(rule_matches(&rule("healing", "high"), &critical, None));
+        assert!(rule_matches(&rule("*", "info"), &critical, None));
+        assert!(!rule_matches(&rule("budget", "info"), &critical, None));
+
+        let low = RuleNotification {
+            severity: "low",
+            ..critical
+        };
+        assert!(!rule_matches(&rule("healing", "high"), &low, None));
+
+        let mut for_p2 = rule("*", "info");
+        for_p2.persona_id = Some("p2".into());
+        assert!(!rule_matches(&for_p2, &low, None));
+
+        let mut for_team = rule("*", "info");
+        for_team.team_id = Some("team-a".into());
+        assert!(rule_matches(&for_team, &low, Some("team-a")));
+        assert!(!rule_matches(&for_team, &low, None));
+    }
+
+    #[test]
+    fn quiet_hours_wrap_midnight() {
+        let t = |s| chrono::NaiveTime::parse_from_str(s, "%H:%M").unwrap();
+        assert!(in_quiet_hours(t("22:00"), t("07:00"), t("23:30")));
+        assert!(in_quiet_hours(t("22:00"), t("07:00"), t("06:59")));
+        assert!(!in_quiet_hours(t("22:00"), t("07:00"), t("07:00")));
+        assert!(in_quiet_hours(t("12:00"), t("13:00"), t("12:30")));
+        assert!(!in_quiet_hours(t("12:00"), t("13:00"), t("13:30")));
+    }
+
+    #[test]
+    fn rule_rate_limit_caps_deliveries_per_hour() {
+        assert!(take_rule_slot("rate-test", Some(2)));
+        assert!(take_rule_slot("rate-test", Some(2)));
+        assert!(!take_rule_slot("rate-test", Some(2)));
+        assert!(take_rule_slot("rate-test-unlimited", None));
+    }
+
+    #[test]
+    fn templates_render_and_preview_with_sample_values() {
+        assert_eq!(
+            render_template(
+                "{{ persona_name }} {{status}}{{error}} ({{unset}})",
+                &[
+                    ("persona_name", "Triage".into()),
+                    ("status", "failed".into())
+                ],
+            ),
+            "Triage failed ()"
+        );
+        assert!(template_placeholders("{{open").is_err());
+        assert!(template_placeholders("{{ }}").is_err());
+
+        let preview = preview_template(&crate::db::models::PreviewNotificationTemplateInput {
+            notification_type: "manual_review".into(),
+            title_template: "Review: {{title}}".into(),
+            body_template: "{{persona_name}} is waiting".into(),
+            variables: Some([("persona_name".to_string(), "Refunds".to_string())].into()),
+        })
+        .unwrap();
+        assert_eq!(preview.title, "Review: Approve refund for order #1042");
+        assert_eq!(preview.body, "Refunds is waiting");
+        assert_eq!(preview.used_variables, ["title", "persona_name"]);
+
+        assert!(validate_template("manual_review", "{{error}}").is_err());
+        assert!(validate_template("nope", "plain").is_err());
+    }
 }
@@ -1,6 +1,6 @@
 use serde::Serialize;
-use std::sync::OnceLock;
-use std::time::Instant;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use ts_rs::TS;
 
 /// Global process start time — set once at the top of `run()`.
@@ -146,3 +146,125 @@ pub fn format_boot_log(report: &StartupTimingReport) -> String {
     }
     out
 }
+
+// -- Deferred (lazy) startup work ---------------------------------------------
+//
+// Non-critical boot work (catalog seeding refresh, event cleanup, KB
+// reconciliation) does not need to finish before the window appears. `setup()`
+// registers it with [`defer`]; [`run_deferred`] then runs it in order on a
+// blocking thread once setup has finished, recording each job's timing for
+// `get_startup_profile`.
+
+/// How long after setup the deferred jobs start, so they don't compete with
+/// the window's first paint for DB connections.
+const DEFERRED_START_DELAY: Duration = Duration::from_secs(3);
+
+type DeferredJob = Box<dyn FnOnce() -> Result<(), String> + Send>;
+
+static DEFERRED_JOBS: Mutex<Vec<(&'static str, DeferredJob)>> = Mutex::new(Vec::new());
+static DEFERRED_TASKS: Mutex<Vec<DeferredStartupTask>> = Mutex::new(Vec::new());
+
+/// Timing of one deferred startup job.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct DeferredStartupTask {
+    pub name: String,
+    /// "pending" | "running" | "completed" | "failed"
+    pub status: String,
+    /// Milliseconds since process start when the job began.
+    #[ts(type = "number | null")]
+    pub started_at_ms: Option<u64>,
+    #[ts(type = "number | null")]
+    pub duration_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Backend startup profile: the blocking setup phases, the slowest of them,
+/// and the work deferred past window creation.
+#[derive(Debug, Clone, Serialize, TS)]
+#[ts(export)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupProfile {
+    /// None until setup() has finished.
+    pub report: Option<StartupTimingReport>,
+    /// Up to five setup phases, longest first.
+    pub slowest_phases: Vec<StartupPhase>,
+    pub deferred: Vec<DeferredStartupTask>,
+}
+
+/// Register non-critical startup work to run after setup completes.
+pub fn defer(name: &'static str, job: impl FnOnce() -> Result<(), String> + Send + 'static) {
+    lock(&DEFERRED_TASKS).push(DeferredStartupTask {
+        name: name.to_string(),
+        status: "pending".into(),
+        started_at_ms: None,
+        duration_ms: None,
+        error: None,
+    });
+    lock(&DEFERRED_JOBS).push((name, Box::new(job)));
+}
+
+/// Run every deferred job, in registration order. Call once after `finalize`.
+pub fn run_deferred() {
+    let jobs = std::mem::take(&mut *lock(&DEFERRED_JOBS));
+    if jobs.is_empty() {
+        return;
+    }
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(DEFERRED_START_DELAY).await;
+        for (name, job) in jobs {
+            update_task(name, |t| {
+                t.status = "running".into();
+                t.started_at_ms = Some(elapsed_ms());
+            });
+            let started = Instant::now();
+            let outcome = tauri::async_runtime::spawn_blocking(job)
+                .await
+                .unwrap_or_else(|e| Err(format!("task panicked: {e}")));
+            let duration_ms = started.elapsed().as_millis() as u64;
+            tracing::info!(
+                task = name,
+                duration_ms,
+                ok = outcome.is_ok(),
+                "Deferred startup task finished"
+            );
+            update_task(name, |t| {
+                t.duration_ms = Some(duration_ms);
+                match outcome {
+                    Ok(()) => t.status = "completed".into(),
+                    Err(e) => {
+                        t.status = "failed".into();
+                        t.error = Some(e);
+                    }
+                }
+            });
+        }
+    });
+}
+
+/// Current startup profile.
+pub fn get_profile() -> StartupProfile {
+    let report = get_full_report();
+    let mut slowest_phases = report
+        .as_ref()
+        .map(|r| r.phases.clone())
+        .unwrap_or_default();
+    slowest_phases.sort_by(|a, b| b.duration_ms.cmp(&a.duration_ms));
+    slowest_phases.truncate(5);
+    StartupProfile {
+        report,
+        slowest_phases,
+        deferred: lock(&DEFERRED_TASKS).clone(),
+    }
+}
+
+fn update_task(name: &str, f: impl FnOnce(&mut DeferredStartupTask)) {
+    if let Some(task) = lock(&DEFERRED_TASKS).iter_mut().find(|t| t.name == name) {
+        f(task);
+    }
+}
+
+fn lock<T>(m: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -4,7 +4,7 @@ use tauri::tray::TrayIconBuilder;
 use tauri::{AppHandle, Manager};
 
 use crate::db::repos::execution::executions as exec_repo;
-use crate::engine::background;
+use crate::engine::{background, status_narrative};
 use crate::AppState;
 
 /// Set up the system tray. Called once from `lib.rs` setup.
@@ -36,13 +36,18 @@ pub fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-/// Rebuild the tray menu to reflect current state. Safe to call from any thread.
+/// Rebuild the tray menu and status tooltip to reflect current state. Safe
+/// to call from any thread.
 pub fn refresh_tray(app: &AppHandle) {
+    let Some(tray) = app.tray_by_id("main") else {
+        return;
+    };
     if let Ok(menu) = build_tray_menu(app) {
-        if let Some(tray) = app.tray_by_id("main") {
-            let _ = tray.set_menu(Some(menu));
-        }
+        let _ = tray.set_menu(Some(menu));
     }
+    let state: &Arc<AppState> = &app.state::<Arc<AppState>>();
+    let narrative = status_narrative::build(&state.db, state.scheduler.is_running());
+    let _ = tray.set_tooltip(Some(format!("Personas Desktop — {}", narrative.headline)));
 }
 
 // ---------------------------------------------------------------------------
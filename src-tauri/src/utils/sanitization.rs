@@ -75,6 +75,40 @@ pub fn sanitize_secrets(text: &str) -> String {
     sanitized
 }
 
+/// Key substrings (case-insensitive) whose values are always redacted.
+const SECRET_KEY_NEEDLES: &[&str] = &[
+    "token", "secret", "password", "passwd", "api_key", "apikey", "authorization",
+    "credential", "cookie", "private_key", "access_key", "client_secret", "bearer",
+];
+
+/// Whether a key (JSON field, setting name) names a credential.
+pub fn key_is_secret(key: &str) -> bool {
+    let k = key.to_ascii_lowercase();
+    SECRET_KEY_NEEDLES.iter().any(|n| k.contains(n))
+}
+
+/// A string value that looks like a credential even under an innocuous key:
+/// known token prefixes, or a long whitespace-free high-base64/hex-density run.
+pub fn value_looks_secret(s: &str) -> bool {
+    const PREFIXES: &[&str] = &[
+        "sk-", "sk_", "ghp_", "gho_", "ghs_", "github_pat_", "glpat-", "xox", "AKIA",
+        "ASIA", "eyJ", "Bearer ", "-----BEGIN",
+    ];
+    if PREFIXES.iter().any(|p| s.starts_with(p)) {
+        return true;
+    }
+    if s.len() >= 60 && !s.chars().any(|c| c.is_whitespace()) {
+        let dense = s
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_' | '='))
+            .count();
+        if dense * 100 / s.len() >= 90 {
+            return true;
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +114,7 @@ const KNOWN_STRUCTURED_KEYS: &[&str] = &[
 ///    prompt is effectively empty and the persona would silently lose its behavior)
 /// 3. String fields are actually strings (not nested objects/arrays)
 /// 4. `customSections`, if present, is an array of objects with `content` strings
+///    or a prompt library `blockId`
 /// 5. No unknown top-level keys (catches LLM hallucinated fields)
 pub fn validate_structured_prompt_schema(val: &serde_json::Value) -> Vec<ValidationError> {
     let mut errors = Vec::new();
@@ -174,6 +175,18 @@ pub fn validate_structured_prompt_schema(val: &serde_json::Value) -> Vec<Validat
         } else if let Some(sections) = sections_val.as_array() {
             for (i, section) in sections.iter().enumerate() {
                 if let Some(sec_obj) = section.as_object() {
+                    // A prompt library reference carries its content and
+                    // heading in the block itself.
+                    if let Some(block_id) = sec_obj.get("blockId") {
+                        if !block_id.as_str().is_some_and(|s| !s.is_empty()) {
+                            errors.push(ValidationError::new(
+                                "structured_prompt",
+                                "schema",
+                                format!("customSections[{i}].blockId must be a non-empty string"),
+                            ));
+                        }
+                        continue;
+                    }
                     // Must have a content string
                     match sec_obj.get("content") {
                         Some(c) if c.is_string() => {}
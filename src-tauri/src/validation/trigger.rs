@@ -8,6 +8,7 @@ pub const VALID_TRIGGER_TYPES: &[&str] = &[
     "chain",
     "event_listener",
     "file_watcher",
+    "email_inbound",
     "clipboard",
     "app_focus",
     "composite",
@@ -23,6 +24,11 @@ pub const MIN_INTERVAL_SECONDS: i64 = 60;
 pub const MIN_COMPOSITE_WINDOW_SECONDS: i64 = 1;
 pub const MAX_COMPOSITE_WINDOW_SECONDS: i64 = 86_400; // 24h
 
+/// Upper bound for a file_watcher trigger's per-path `debounce_ms`. Each
+/// quiet period is an in-memory entry per (trigger, path), so an hour keeps
+/// the map bounded while still covering slow batch writers.
+pub const MAX_FILE_WATCH_DEBOUNCE_MS: i64 = 3_600_000; // 1h
+
 /// Normalize common LLM/template trigger type aliases to valid enum values.
 /// Templates and LLMs sometimes produce shortened or alternative names
 /// (e.g., "event" instead of "event_listener", "cron" instead of "schedule").
@@ -34,6 +40,7 @@ pub fn normalize_trigger_type(raw: &str) -> &str {
         "hook" | "http" | "web_hook" => "webhook",
         "watcher" | "fs_watcher" | "watch" => "file_watcher",
         "focus" | "window_focus" => "app_focus",
+        "email" | "inbound_email" | "mailbox" => "email_inbound",
         other => other,
     }
 }
@@ -140,6 +147,56 @@ pub fn validate_config(trigger_type: &str, config: Option<&str>) -> Vec<Validati
                 }
             }
 
+            if trigger_type == "file_watcher" {
+                if let Some(debounce) = parsed.get("debounce_ms") {
+                    match debounce.as_i64() {
+                        Some(n) if !(0..=MAX_FILE_WATCH_DEBOUNCE_MS).contains(&n) => {
+                            errors.push(ValidationError::new(
+                                "config.debounce_ms",
+                                "range",
+                                format!(
+                                    "debounce_ms must be between 0 and {MAX_FILE_WATCH_DEBOUNCE_MS} (1h)"
+                                ),
+                            ));
+                        }
+                        Some(_) => {}
+                        None => {
+                            errors.push(ValidationError::new(
+                                "config.debounce_ms",
+                                "type",
+                                "debounce_ms must be a valid integer",
+                            ));
+                        }
+                    }
+                }
+                if let Some(patterns) = parsed.get("ignore_patterns") {
+                    let valid = patterns
+                        .as_array()
+                        .is_some_and(|arr| arr.iter().all(|p| p.is_string()));
+                    if !valid {
+                        errors.push(ValidationError::new(
+                            "config.ignore_patterns",
+                            "type",
+                            "ignore_patterns must be an array of strings",
+                        ));
+                    }
+                }
+            }
+
+            if trigger_type == "email_inbound" {
+                let credential = parsed
+                    .get("credential_id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("");
+                if credential.trim().is_empty() {
+                    errors.push(ValidationError::new(
+                        "config.credential_id",
+                        "required",
+                        "Email triggers require the credential_id of an IMAP or Gmail connection",
+                    ));
+                }
+            }
+
             if trigger_type == "schedule" {
                 for key in ["cron", "cron_expression"] {
                     if let Some(expr) = parsed.get(key).and_then(|v| v.as_str()) {
@@ -251,6 +308,113 @@ pub fn validate_schedule_has_cron_or_interval(
     vec![]
 }
 
+/// A schedule's `exclusions` must be an array of well-formed windows — a
+/// malformed entry would otherwise be dropped silently by `parse_config` and
+/// the trigger would fire through the blackout the user configured.
+pub fn validate_schedule_exclusions(
+    trigger_type: &str,
+    config: Option<&str>,
+) -> Vec<ValidationError> {
+    if trigger_type != "schedule" {
+        return vec![];
+    }
+    let Some(raw) = config
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(c).ok())
+        .and_then(|v| v.get("exclusions").cloned())
+    else {
+        return vec![];
+    };
+    let windows: Vec<crate::db::models::ScheduleExclusion> = match serde_json::from_value(raw) {
+        Ok(w) => w,
+        Err(e) => {
+            return vec![ValidationError::new(
+                "config.exclusions",
+                "format",
+                format!("exclusions must be an array of exclusion windows: {e}"),
+            )];
+        }
+    };
+    windows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, w)| {
+            w.validate()
+                .err()
+                .map(|msg| ValidationError::new(format!("config.exclusions[{i}]"), "format", msg))
+        })
+        .collect()
+}
+
+/// A schedule's `catch_up` must name a known policy — `parse_config` would
+/// otherwise fall back to the legacy backfill behavior without a word.
+pub fn validate_schedule_catch_up(
+    trigger_type: &str,
+    config: Option<&str>,
+) -> Vec<ValidationError> {
+    if trigger_type != "schedule" {
+        return vec![];
+    }
+    let Some(raw) = config
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(c).ok())
+        .and_then(|v| v.get("catch_up").cloned())
+        .filter(|v| !v.is_null())
+    else {
+        return vec![];
+    };
+    if serde_json::from_value::<crate::db::models::CatchUpPolicy>(raw).is_ok() {
+        return vec![];
+    }
+    vec![ValidationError::new(
+        "config.catch_up",
+        "allowed_values",
+        "catch_up must be one of: skip, run_once, run_all_missed",
+    )]
+}
+
+/// `injection_policy` is optional on every trigger type but must name a known
+/// policy when present (see `engine::injection_scan`).
+pub fn validate_injection_policy(config: Option<&str>) -> Vec<ValidationError> {
+    use crate::engine::injection_scan::{POLICIES, TRIGGER_POLICY_KEY};
+    let Some(raw) = config
+        .and_then(|c| serde_json::from_str::<serde_json::Value>(c).ok())
+        .and_then(|v| v.get(TRIGGER_POLICY_KEY).cloned())
+        .filter(|v| !v.is_null())
+    else {
+        return vec![];
+    };
+    if raw.as_str().is_some_and(|p| POLICIES.contains(&p)) {
+        return vec![];
+    }
+    vec![ValidationError::new(
+        "config.injection_policy",
+        "allowed_values",
+        "injection_policy must be one of: off, flag, sanitize, block, require_approval",
+    )]
+}
+
+/// `output_regression` compares consecutive scheduled runs, so it is only
+/// accepted on schedule triggers (see `engine::output_regression`).
+pub fn validate_output_regression(
+    trigger_type: &str,
+    config: Option<&str>,
+) -> Vec<ValidationError> {
+    use crate::engine::output_regression::config_from_trigger;
+    match config_from_trigger(config) {
+        None => vec![],
+        Some(Err(e)) => vec![ValidationError::new(
+            "config.output_regression",
+            "invalid",
+            e,
+        )],
+        Some(Ok(_)) if trigger_type != "schedule" => vec![ValidationError::new(
+            "config.output_regression",
+            "trigger_type",
+            "output_regression is only supported on schedule triggers",
+        )],
+        Some(Ok(_)) => vec![],
+    }
+}
+
 pub fn validate_polling_url(trigger_type: &str, config: Option<&str>) -> Vec<ValidationError> {
     if trigger_type != "polling" {
         return vec![];
@@ -412,6 +576,36 @@ mod tests {
         assert_eq!(errs[0].rule, "type");
     }
 
+    #[test]
+    fn file_watcher_debounce_and_ignore_patterns_validated() {
+        assert!(validate_config(
+            "file_watcher",
+            Some(r#"{"debounce_ms": 2000, "ignore_patterns": ["node_modules", "*.tmp"]}"#)
+        )
+        .is_empty());
+
+        let too_big = validate_config("file_watcher", Some(r#"{"debounce_ms": 3600001}"#));
+        assert_eq!(too_big.len(), 1);
+        assert_eq!(too_big[0].field, "config.debounce_ms");
+        assert_eq!(too_big[0].rule, "range");
+
+        let bad_patterns = validate_config("file_watcher", Some(r#"{"ignore_patterns": "*.tmp"}"#));
+        assert_eq!(bad_patterns.len(), 1);
+        assert_eq!(bad_patterns[0].field, "config.ignore_patterns");
+    }
+
+    #[test]
+    fn email_inbound_requires_a_credential() {
+        assert!(validate_config(
+            "email_inbound",
+            Some(r#"{"credential_id": "cred-1", "interval_seconds": 300}"#)
+        )
+        .is_empty());
+        let errs = validate_config("email_inbound", Some(r#"{"from": "a@example.com"}"#));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].field, "config.credential_id");
+    }
+
     #[test]
     fn schedule_validator_accepts_both() {
         assert!(validate_schedule_has_cron_or_interval(
@@ -420,6 +614,42 @@ mod tests {
         )
         .is_empty());
     }
+
+    #[test]
+    fn exclusions_validator_flags_malformed_windows() {
+        assert!(validate_schedule_exclusions(
+            "schedule",
+            Some(r#"{"cron": "0 9 * * *", "exclusions": [{"days": [0, 6]}]}"#)
+        )
+        .is_empty());
+        let errs = validate_schedule_exclusions(
+            "schedule",
+            Some(
+                r#"{"cron": "0 9 * * *", "exclusions": [{"days": [1]}, {"start": "25:00", "end": "06:00"}]}"#,
+            ),
+        );
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].field, "config.exclusions[1]");
+        assert_eq!(
+            validate_schedule_exclusions("schedule", Some(r#"{"exclusions": "weekends"}"#)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn catch_up_validator_accepts_known_policies_only() {
+        for policy in ["skip", "run_once", "run_all_missed"] {
+            let config = format!(r#"{{"cron": "0 9 * * *", "catch_up": "{policy}"}}"#);
+            assert!(validate_schedule_catch_up("schedule", Some(&config)).is_empty());
+        }
+        let errs = validate_schedule_catch_up(
+            "schedule",
+            Some(r#"{"cron": "0 9 * * *", "catch_up": "all"}"#),
+        );
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].field, "config.catch_up");
+        assert!(validate_schedule_catch_up("webhook", Some(r#"{"catch_up": "all"}"#)).is_empty());
+    }
 }
 
 // -- Rule catalog -------------------------------------------------------------
@@ -452,6 +682,32 @@ pub fn rules() -> Vec<ValidationRule> {
             "required",
             "Required for webhook triggers",
         ),
+        ValidationRule::new(
+            "trigger",
+            "config.exclusions",
+            "format",
+            "Schedule exclusion windows need valid days (0-6), YYYY-MM-DD dates or an HH:MM start/end pair",
+        ),
+        ValidationRule::new(
+            "trigger",
+            "config.catch_up",
+            "allowed_values",
+            "Missed-schedule catch-up policy",
+        )
+        .with_allowed(
+            ["skip", "run_once", "run_all_missed"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        ),
+        ValidationRule::new(
+            "trigger",
+            "config.debounce_ms",
+            "range",
+            format!("File watcher quiet period, 0 to {MAX_FILE_WATCH_DEBOUNCE_MS} ms"),
+        )
+        .with_min(0.0)
+        .with_max(MAX_FILE_WATCH_DEBOUNCE_MS as f64),
         ValidationRule::new(
             "trigger",
             "config.url",
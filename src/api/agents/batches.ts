@@ -0,0 +1,41 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { ExecutionBatch } from "@/lib/bindings/ExecutionBatch";
+import type { ExecutionBatchItem } from "@/lib/bindings/ExecutionBatchItem";
+import type { ExecutionBatchProgress } from "@/lib/bindings/ExecutionBatchProgress";
+
+/**
+ * Run `personaId` once per dataset item, at most `concurrency` at a time.
+ * Strings are passed through as the run's input; other values are sent as JSON.
+ * Progress arrives via the `batch-execution-progress` event.
+ */
+export const startBatchExecution = (
+  personaId: string,
+  items: unknown[],
+  concurrency?: number,
+  useCaseId?: string,
+) =>
+  invoke<ExecutionBatch>("start_batch_execution", {
+    personaId,
+    items,
+    concurrency: concurrency ?? null,
+    useCaseId: useCaseId ?? null,
+  });
+
+export const listBatchExecutions = (personaId?: string, limit?: number) =>
+  invoke<ExecutionBatch[]>("list_batch_executions", {
+    personaId: personaId ?? null,
+    limit: limit ?? null,
+  });
+
+export const getBatchExecutionProgress = (batchId: string) =>
+  invoke<ExecutionBatchProgress>("get_batch_execution_progress", { batchId });
+
+export const listBatchExecutionItems = (batchId: string) =>
+  invoke<ExecutionBatchItem[]>("list_batch_execution_items", { batchId });
+
+export const cancelBatchExecution = (batchId: string) =>
+  invoke<ExecutionBatchProgress>("cancel_batch_execution", { batchId });
+
+/** Save the batch's per-item results to a user-picked file. `false` if dismissed. */
+export const exportBatchResults = (batchId: string, format: "json" | "csv" = "json") =>
+  invoke<boolean>("export_batch_results", { batchId, format });
@@ -0,0 +1,18 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { EngineFlagInfo } from "@/lib/bindings/EngineFlagInfo";
+import type { EngineFlagStats } from "@/lib/bindings/EngineFlagStats";
+
+// Experimental engine flags, enabled per persona or per run via
+// `input_data._flags`.
+
+export const listEngineFlags = () =>
+  invoke<EngineFlagInfo[]>("list_engine_flags");
+
+export const getPersonaEngineFlags = (personaId: string) =>
+  invoke<string[]>("get_persona_engine_flags", { personaId });
+
+export const setPersonaEngineFlags = (personaId: string, flags: string[]) =>
+  invoke<string[]>("set_persona_engine_flags", { personaId, flags });
+
+export const getEngineFlagStats = (personaId?: string) =>
+  invoke<EngineFlagStats[]>("get_engine_flag_stats", { personaId: personaId ?? null });
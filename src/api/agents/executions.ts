@@ -1,18 +1,27 @@
 import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 
 import type { PersonaExecution } from "@/lib/bindings/PersonaExecution";
+import type { Persona } from "@/lib/bindings/Persona";
 import type { ExecutionListItem } from "@/lib/bindings/ExecutionListItem";
 import type { GlobalExecutionRow } from "@/lib/bindings/GlobalExecutionRow";
+import type { FiringExecutions } from "@/lib/bindings/FiringExecutions";
 import type { ExecutionCounts } from "@/lib/bindings/ExecutionCounts";
 import type { ExecutionSearchResult } from "@/lib/bindings/ExecutionSearchResult";
 import type { Continuation } from "@/lib/bindings/Continuation";
 import type { ExecutionTrace } from "@/lib/bindings/ExecutionTrace";
 import type { ChainStopReason } from "@/lib/bindings/ChainStopReason";
+import type { SharedContextEntry } from "@/lib/bindings/SharedContextEntry";
 import type { ActiveChain } from "@/lib/bindings/ActiveChain";
 import type { DreamReplaySession } from "@/lib/bindings/DreamReplaySession";
+import type { ExecutionTimeline } from "@/lib/bindings/ExecutionTimeline";
 import type { CircuitBreakerStatus } from "@/lib/bindings/CircuitBreakerStatus";
+import type { WarmPoolSnapshot } from "@/lib/bindings/WarmPoolSnapshot";
 import type { DryRunReport } from "@/lib/bindings/DryRunReport";
 import type { ExecutionPreview } from "@/lib/bindings/ExecutionPreview";
+import type { ExecutionLogChunk } from "@/lib/bindings/ExecutionLogChunk";
+import type { PromptBudget } from "@/lib/bindings/PromptBudget";
+import type { CostEstimate } from "@/lib/bindings/CostEstimate";
+import type { ExecutionWaitResult } from "@/lib/bindings/ExecutionWaitResult";
 
 // ============================================================================
 // Executions
@@ -50,6 +59,10 @@ export const getExecution = (id: string, callerPersonaId: string) =>
 export const cancelExecution = (id: string, callerPersonaId: string) =>
   invoke<void>("cancel_execution", { id, callerPersonaId });
 
+/** Queue-age monitor fix: raise `max_concurrent` by one and drain the queue. */
+export const bumpPersonaConcurrency = (personaId: string) =>
+  invoke<Persona>("bump_persona_concurrency", { personaId });
+
 export const executePersona = (
   personaId: string,
   triggerId?: string,
@@ -76,6 +89,22 @@ export const executePersona = (
   }, { idempotencyKey: resolvedKey });
 };
 
+/**
+ * Run a persona through the normal queue and resolve once it finishes (or
+ * `timeoutSecs` elapses — default 120, max 600 — with `completed: false`).
+ */
+export const executePersonaAndWait = (
+  personaId: string,
+  inputData?: string,
+  useCaseId?: string,
+  timeoutSecs?: number,
+) =>
+  invoke<ExecutionWaitResult>(
+    "execute_persona_and_wait",
+    { personaId, inputData, useCaseId, timeoutSecs },
+    { timeoutMs: (Math.min(timeoutSecs ?? 120, 600) + 30) * 1000 },
+  );
+
 export const preparePersonaExecution = (personaId: string) =>
   invoke<string>("prepare_persona_execution", { personaId });
 
@@ -85,6 +114,10 @@ export const listExecutionsByTrigger = (triggerId: string, limit?: number) =>
     limit: limit,
   });
 
+/** Every execution one trigger firing fanned out to, with roll-up status and cost. */
+export const listExecutionsByFiring = (firingId: string) =>
+  invoke<FiringExecutions>("list_executions_by_firing", { firingId });
+
 export const listExecutionsForUseCase = (
   personaId: string,
   useCaseId: string,
@@ -107,6 +140,20 @@ export const getExecutionLogLines = (
 ) =>
   invoke<string[]>("get_execution_log_lines", { id, callerPersonaId, offset, limit });
 
+/** One page of an execution log, line-aligned; page with `next_offset` until `eof`. */
+export const getExecutionLogRange = (
+  executionId: string,
+  callerPersonaId: string,
+  offset?: number,
+  limit?: number,
+) =>
+  invoke<ExecutionLogChunk | null>("get_execution_log_range", {
+    executionId,
+    callerPersonaId,
+    offset,
+    limit,
+  });
+
 // ============================================================================
 // Traces
 // ============================================================================
@@ -120,6 +167,20 @@ export const getChainTrace = (chainTraceId: string, callerPersonaId: string) =>
 export const getChainStopReasons = (chainTraceId: string, callerPersonaId: string) =>
   invoke<ChainStopReason[]>("get_chain_stop_reasons", { chainTraceId, callerPersonaId });
 
+export type SharedContextScope = "pipeline" | "chain";
+
+/** Shared context blackboard of a pipeline run or chain trace. With `key`,
+ *  at most that one entry. */
+export const getSharedContext = (scopeType: SharedContextScope, scopeId: string, key?: string) =>
+  invoke<SharedContextEntry[]>("get_shared_context", { scopeType, scopeId, key: key ?? null });
+
+export const setSharedContext = (
+  scopeType: SharedContextScope,
+  scopeId: string,
+  key: string,
+  value: unknown,
+) => invoke<SharedContextEntry>("set_shared_context", { scopeType, scopeId, key, value });
+
 /** Chains with in-flight (running/queued) executions right now, grouped by
  *  chain_trace_id. Empty array = nothing chain-shaped is running. */
 export const listActiveChains = () =>
@@ -129,6 +190,11 @@ export const listActiveChains = () =>
 // Dream Replay
 // ============================================================================
 
+/** Status changes, tool steps, trace spans and stream lines of an execution
+ *  and its healing retries, merged into one ordered replay timeline. */
+export const getExecutionTimeline = (executionId: string, callerPersonaId: string) =>
+  invoke<ExecutionTimeline>("get_execution_timeline", { executionId, callerPersonaId });
+
 export const getDreamReplay = (executionId: string, callerPersonaId: string) =>
   invoke<DreamReplaySession | null>("get_dream_replay", { executionId, callerPersonaId });
 
@@ -139,6 +205,10 @@ export const getDreamReplay = (executionId: string, callerPersonaId: string) =>
 export const getCircuitBreakerStatus = () =>
   invoke<CircuitBreakerStatus>("get_circuit_breaker_status");
 
+/** Warm-pool hits, misses and estimated saved start latency since app start. */
+export const getWarmPoolStats = () =>
+  invoke<WarmPoolSnapshot>("get_warm_pool_stats");
+
 // ============================================================================
 // Execution Preview
 // ============================================================================
@@ -152,5 +222,15 @@ export type { ExecutionPreview };
 export const previewExecution = (personaId: string, inputData?: string, useCaseId?: string) =>
   invoke<ExecutionPreview>("preview_execution", { personaId, inputData, useCaseId });
 
+export type { PromptBudget };
+
+/** Per-section token counts of the prompt a run with `inputData` would get,
+ *  checked against the persona's model context window. */
+export const getPromptBudget = (personaId: string, inputData?: string) =>
+  invoke<PromptBudget>("get_prompt_budget", { personaId, inputData });
+
+export const estimateExecutionCost = (personaId: string, useCaseId?: string) =>
+  invoke<CostEstimate>("estimate_execution_cost", { personaId, useCaseId });
+
 export const dryRunPersona = (personaId: string, inputData?: string, useCaseId?: string) =>
   invoke<DryRunReport>("dry_run_persona", { personaId, inputData, useCaseId });
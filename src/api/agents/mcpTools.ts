@@ -4,6 +4,7 @@ import type { McpToolResult } from "@/lib/bindings/McpToolResult";
 import type { McpPingResult } from "@/lib/bindings/McpPingResult";
 import type { StdioPoolMetrics } from "@/lib/bindings/StdioPoolMetrics";
 import type { McpServerLiveness } from "@/lib/bindings/McpServerLiveness";
+import type { McpServerStatus } from "@/lib/bindings/McpServerStatus";
 
 export type { McpServerLiveness } from "@/lib/bindings/McpServerLiveness";
 export type { McpTool } from "@/lib/bindings/McpTool";
@@ -41,3 +42,27 @@ export const getMcpPoolMetrics = () =>
 /** Live `/health` probe of the local management (":9420") server. */
 export const probeMcpServer = () =>
   invoke<McpServerLiveness>('probe_mcp_server');
+
+// -- MCP Server Lifecycle (per-connector stdio servers) -------------------
+
+export type { McpServerState } from "@/lib/bindings/McpServerState";
+export type { McpServerStatus } from "@/lib/bindings/McpServerStatus";
+
+export const listMcpServers = () =>
+  invoke<McpServerStatus[]>('list_mcp_servers');
+
+/** `npm install` can take minutes on a cold cache. */
+export const installMcpServer = (connector: string) =>
+  invoke<McpServerStatus>('install_mcp_server', { connector }, undefined, 320_000);
+
+export const startMcpServer = (connector: string) =>
+  invoke<McpServerStatus>('start_mcp_server', { connector }, undefined, 90_000);
+
+export const stopMcpServer = (connector: string) =>
+  invoke<McpServerStatus>('stop_mcp_server', { connector });
+
+export const restartMcpServer = (connector: string) =>
+  invoke<McpServerStatus>('restart_mcp_server', { connector }, undefined, 90_000);
+
+export const checkMcpServerHealth = (connector: string) =>
+  invoke<McpServerStatus>('check_mcp_server_health', { connector });
@@ -10,6 +10,7 @@ import type { CreatePersonaInput } from "@/lib/bindings/CreatePersonaInput";
 import type { DeletePersonaResult } from "@/lib/bindings/DeletePersonaResult";
 import type { EffectiveModelConfig } from "@/lib/bindings/EffectiveModelConfig";
 import type { UpdatePersonaInput } from "@/lib/bindings/UpdatePersonaInput";
+import type { ExecutionPlacement } from "@/lib/bindings/ExecutionPlacement";
 import type { PersonaChangeEntry } from "@/lib/bindings/PersonaChangeEntry";
 import type { BulkDeleteOutcome } from "@/lib/bindings/BulkDeleteOutcome";
 import type { DuplicatePersonaResult } from "@/lib/bindings/DuplicatePersonaResult";
@@ -223,6 +224,16 @@ export interface PartialPersonaUpdate {
    * this and skips actions for a capability whose dim is in its set.
    */
   disabled_dims_json?: string | null;
+  /**
+   * Per-persona CLI engine override (e.g. `claude_code`). Pass `null` to fall
+   * back to the global `cli_engine` setting; omit to leave unchanged.
+   */
+  engine?: string | null;
+  /**
+   * Cloud/local routing (`prefer_local` | `prefer_cloud` | `auto`). Pass
+   * `null` to reset to `prefer_local`; omit to leave unchanged.
+   */
+  execution_placement?: ExecutionPlacement | null;
   /** Lifecycle stage (`draft` | `active` | `archived`); omit to leave unchanged. */
   lifecycle?: string | null;
   /** Change-log attribution — where this edit originated. Defaults to `editor`
@@ -388,6 +399,8 @@ export function buildUpdateInput(partial: PartialPersonaUpdate): UpdatePersonaIn
     gateway_exposure: partial.gateway_exposure !== undefined ? partial.gateway_exposure : null,
     cli_awareness_enabled: partial.cli_awareness_enabled !== undefined ? partial.cli_awareness_enabled : null,
     disabled_dims_json: partial.disabled_dims_json !== undefined ? partial.disabled_dims_json : null,
+    engine: partial.engine !== undefined ? partial.engine : null,
+    execution_placement: partial.execution_placement !== undefined ? partial.execution_placement : null,
     // lifecycle is normally driven by the archive/restore/promote commands, not
     // this generic builder; passing null = "leave unchanged".
     lifecycle: partial.lifecycle ?? null,
@@ -0,0 +1,20 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { PromptBlock } from "@/lib/bindings/PromptBlock";
+import type { SavePromptBlockInput } from "@/lib/bindings/SavePromptBlockInput";
+import type { PromptBlockUsage } from "@/lib/bindings/PromptBlockUsage";
+
+// Reusable prompt blocks, referenced from a structured prompt as a
+// customSections entry { blockId } and inlined with their current content at
+// run time.
+
+export const listPromptBlocks = () =>
+  invoke<PromptBlock[]>("list_prompt_blocks");
+
+export const savePromptBlock = (input: SavePromptBlockInput) =>
+  invoke<PromptBlock>("save_prompt_block", { input });
+
+export const deletePromptBlock = (id: string) =>
+  invoke<boolean>("delete_prompt_block", { id });
+
+export const getPromptBlockUsage = () =>
+  invoke<PromptBlockUsage[]>("get_prompt_block_usage");
@@ -0,0 +1,19 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { TemplateVariable } from "@/lib/bindings/TemplateVariable";
+import type { SaveTemplateVariableInput } from "@/lib/bindings/SaveTemplateVariableInput";
+import type { TemplateVariableUsage } from "@/lib/bindings/TemplateVariableUsage";
+
+// Prompt variables referenced as {{var.<name>}}; persona scope overrides team,
+// team overrides global.
+
+export const listTemplateVariables = () =>
+  invoke<TemplateVariable[]>("list_template_variables");
+
+export const saveTemplateVariable = (input: SaveTemplateVariableInput) =>
+  invoke<TemplateVariable>("save_template_variable", { input });
+
+export const deleteTemplateVariable = (id: string) =>
+  invoke<boolean>("delete_template_variable", { id });
+
+export const getTemplateVariableUsage = () =>
+  invoke<TemplateVariableUsage[]>("get_template_variable_usage");
@@ -4,6 +4,8 @@ import type { PersonaToolDefinition } from "@/lib/bindings/PersonaToolDefinition
 import type { CreateToolDefinitionInput } from "@/lib/bindings/CreateToolDefinitionInput";
 import type { UpdateToolDefinitionInput } from "@/lib/bindings/UpdateToolDefinitionInput";
 import type { PersonaTool } from "@/lib/bindings/PersonaTool";
+import type { ToolPolicy } from "@/lib/bindings/ToolPolicy";
+import type { NetworkPolicy } from "@/lib/bindings/NetworkPolicy";
 import type { ToolUsageSummary } from "@/lib/bindings/ToolUsageSummary";
 import type { ToolUsageOverTime } from "@/lib/bindings/ToolUsageOverTime";
 import type { PersonaUsageSummary } from "@/lib/bindings/PersonaUsageSummary";
@@ -42,6 +44,26 @@ export const assignTool = (
     toolConfig: toolConfig,
   });
 
+/** Set the sandbox policy of an assigned builtin tool; `null` clears it. */
+export const setToolPolicy = (
+  personaId: string,
+  toolId: string,
+  policy: ToolPolicy | null,
+) =>
+  invoke<PersonaTool>("set_tool_policy", { personaId, toolId, policy });
+
+const NETWORK_POLICY_KEY = "network_policy";
+
+/** Global network rules for every persona's HTTP calls; `null` when unset
+ *  (private addresses denied, everything else allowed). */
+export const getNetworkPolicy = async (): Promise<NetworkPolicy | null> => {
+  const raw = await invoke<string | null>("get_app_setting", { key: NETWORK_POLICY_KEY });
+  return raw ? (JSON.parse(raw) as NetworkPolicy) : null;
+};
+
+export const setNetworkPolicy = (policy: NetworkPolicy) =>
+  invoke<void>("set_app_setting", { key: NETWORK_POLICY_KEY, value: JSON.stringify(policy) });
+
 export const unassignTool = (personaId: string, toolId: string) =>
   invoke<boolean>("unassign_tool", { personaId, toolId });
 
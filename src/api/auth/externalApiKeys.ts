@@ -3,7 +3,15 @@ import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 import type { ExternalApiKey } from "@/lib/bindings/ExternalApiKey";
 import type { CreateApiKeyResponse } from "@/lib/bindings/CreateApiKeyResponse";
 import type { ApiKeyAuditEntry } from "@/lib/bindings/ApiKeyAuditEntry";
-export type { ExternalApiKey, CreateApiKeyResponse, ApiKeyAuditEntry };
+import type { PersonaExecuteHook } from "@/lib/bindings/PersonaExecuteHook";
+import type { CreateExecuteHookResponse } from "@/lib/bindings/CreateExecuteHookResponse";
+export type {
+  ExternalApiKey,
+  CreateApiKeyResponse,
+  ApiKeyAuditEntry,
+  PersonaExecuteHook,
+  CreateExecuteHookResponse,
+};
 
 /**
  * Create a key. `expiresInDays` (7/30/90 or undefined for never) is turned into
@@ -38,3 +46,22 @@ export const deleteExternalApiKey = (id: string) =>
 /// at first call.
 export const getSystemApiKey = () =>
   invoke<string>("get_system_api_key");
+
+/**
+ * Issue or rotate a persona's `/hooks/execute/<token>` URL. The previous token
+ * stops working; the returned URL is the only time the token is shown.
+ */
+export const createPersonaExecuteHook = (personaId: string, waitTimeoutSecs?: number) =>
+  invoke<CreateExecuteHookResponse>("create_persona_execute_hook", {
+    personaId,
+    waitTimeoutSecs: waitTimeoutSecs ?? null,
+  });
+
+export const getPersonaExecuteHook = (personaId: string) =>
+  invoke<PersonaExecuteHook | null>("get_persona_execute_hook", { personaId });
+
+export const setPersonaExecuteHookWait = (personaId: string, waitTimeoutSecs: number) =>
+  invoke<PersonaExecuteHook>("set_persona_execute_hook_wait", { personaId, waitTimeoutSecs });
+
+export const revokePersonaExecuteHook = (personaId: string) =>
+  invoke<boolean>("revoke_persona_execute_hook", { personaId });
@@ -1,9 +1,11 @@
 import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 import type { DesignStartResult } from "@/lib/bindings/DesignStartResult";
 import type { FeasibilityResult } from "@/lib/bindings/FeasibilityResult";
+import type { CompiledPromptPreview } from "@/lib/bindings/CompiledPromptPreview";
 
 export type { DesignStartResult } from "@/lib/bindings/DesignStartResult";
 export type { FeasibilityResult } from "@/lib/bindings/FeasibilityResult";
+export type { CompiledPromptPreview } from "@/lib/bindings/CompiledPromptPreview";
 
 export const startDesignAnalysis = (instruction: string, personaId: string, designId?: string) =>
   invoke<DesignStartResult>("start_design_analysis", { instruction, personaId, designId: designId });
@@ -25,6 +27,11 @@ export const compileFromIntent = (personaId: string, intent: string, designId?:
 export const previewPrompt = (personaId: string, structuredPromptJson?: string | null) =>
   invoke<string>("preview_prompt", { personaId, structuredPromptJson: structuredPromptJson });
 
+/** Compile the runtime prompt for a sample input (JSON string) and list every
+ *  `{{variable}}` it can use, plus placeholders nothing resolves. */
+export const previewCompiledPrompt = (personaId: string, inputData?: string | null, structuredPromptJson?: string | null) =>
+  invoke<CompiledPromptPreview>("preview_compiled_prompt", { personaId, inputData: inputData, structuredPromptJson: structuredPromptJson });
+
 // ============================================================================
 // Design Conversations
 // ============================================================================
@@ -125,6 +125,11 @@ export const backfillQaPrReview = () =>
 export const deleteProject = (id: string) =>
   invoke<boolean>("dev_tools_delete_project", { id });
 
+/** Revoke an archived project's data key. Its event payloads become
+ *  permanently unreadable. Returns the number of payloads sealed. */
+export const revokeProjectDataKey = (id: string) =>
+  invoke<number>("dev_tools_revoke_project_data_key", { id });
+
 export const scanDirectory = (path: string) =>
   safeInvoke<DirectoryScanResult>({} as DirectoryScanResult, "dev_tools_scan_directory", { path });
 
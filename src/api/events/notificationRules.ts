@@ -0,0 +1,16 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { NotificationRule } from "@/lib/bindings/NotificationRule";
+import type { CreateNotificationRuleInput } from "@/lib/bindings/CreateNotificationRuleInput";
+import type { UpdateNotificationRuleInput } from "@/lib/bindings/UpdateNotificationRuleInput";
+
+export const listNotificationRules = () =>
+  invoke<NotificationRule[]>("list_notification_rules");
+
+export const createNotificationRule = (input: CreateNotificationRuleInput) =>
+  invoke<NotificationRule>("create_notification_rule", { input });
+
+export const updateNotificationRule = (id: string, input: UpdateNotificationRuleInput) =>
+  invoke<NotificationRule>("update_notification_rule", { id, input });
+
+export const deleteNotificationRule = (id: string) =>
+  invoke<boolean>("delete_notification_rule", { id });
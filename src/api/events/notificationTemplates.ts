@@ -0,0 +1,17 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { NotificationTemplate } from "@/lib/bindings/NotificationTemplate";
+import type { NotificationTemplatePreview } from "@/lib/bindings/NotificationTemplatePreview";
+import type { PreviewNotificationTemplateInput } from "@/lib/bindings/PreviewNotificationTemplateInput";
+import type { UpsertNotificationTemplateInput } from "@/lib/bindings/UpsertNotificationTemplateInput";
+
+export const listNotificationTemplates = () =>
+  invoke<NotificationTemplate[]>("list_notification_templates");
+
+export const upsertNotificationTemplate = (input: UpsertNotificationTemplateInput) =>
+  invoke<NotificationTemplate>("upsert_notification_template", { input });
+
+export const deleteNotificationTemplate = (id: string) =>
+  invoke<boolean>("delete_notification_template", { id });
+
+export const previewNotificationTemplate = (input: PreviewNotificationTemplateInput) =>
+  invoke<NotificationTemplatePreview>("preview_notification_template", { input });
@@ -0,0 +1,16 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import type { PersonaReportSink } from "@/lib/bindings/PersonaReportSink";
+import type { SaveReportSinkInput } from "@/lib/bindings/SaveReportSinkInput";
+
+export const getPersonaReportSink = (personaId: string) =>
+  invoke<PersonaReportSink | null>("get_persona_report_sink", { personaId });
+
+export const setPersonaReportSink = (personaId: string, input: SaveReportSinkInput) =>
+  invoke<PersonaReportSink>("set_persona_report_sink", { personaId, input });
+
+export const deletePersonaReportSink = (personaId: string) =>
+  invoke<boolean>("delete_persona_report_sink", { personaId });
+
+/** Writes a report from the latest completed run; resolves to the file path. */
+export const testPersonaReportSink = (personaId: string) =>
+  invoke<string>("test_persona_report_sink", { personaId }, { timeoutMs: 120_000 });
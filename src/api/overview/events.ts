@@ -10,6 +10,8 @@ import type { DeadLetterConfig } from "@/lib/bindings/DeadLetterConfig";
 import type { BulkDeadLetterOutcome } from "@/lib/bindings/BulkDeadLetterOutcome";
 import type { EventVocabularyEntry } from "@/lib/bindings/EventVocabularyEntry";
 import type { EventSkippedStats } from "@/lib/bindings/EventSkippedStats";
+import type { EventSchema } from "@/lib/bindings/EventSchema";
+import type { UpsertEventSchemaInput } from "@/lib/bindings/UpsertEventSchemaInput";
 
 // ============================================================================
 // Events
@@ -110,3 +112,39 @@ export const bulkRetryDeadLetterEvents = (ids: string[]) =>
  */
 export const bulkDiscardDeadLetterEvents = (ids: string[]) =>
   invoke<BulkDeadLetterOutcome>("bulk_discard_dead_letter_events", { ids });
+
+/**
+ * Re-dispatch one event. Dead-lettered events are retried in place; any
+ * other finished event is published again as a fresh copy (capped at
+ * `maxEventReplays` copies per original).
+ */
+export const replayEvent = (eventId: string) =>
+  invoke<PersonaEvent>("replay_event", { eventId });
+
+/**
+ * Retry every dead-lettered event matching `filter` (its `status` is
+ * ignored). Same per-id outcome shape as `bulkRetryDeadLetterEvents`.
+ */
+export const replayDeadLetters = (filter: EventFilterInput) =>
+  invoke<BulkDeadLetterOutcome>("replay_dead_letters", { filter });
+
+// ============================================================================
+// Event payload schemas
+// ============================================================================
+
+export const listEventSchemas = () =>
+  invoke<EventSchema[]>("list_event_schemas");
+
+export const getEventSchema = (eventType: string) =>
+  invoke<EventSchema | null>("get_event_schema", { eventType });
+
+/**
+ * Register or replace the JSON Schema for an event type. Published and
+ * webhook-ingested events of that type are rejected when their payload
+ * does not match.
+ */
+export const upsertEventSchema = (input: UpsertEventSchemaInput) =>
+  invoke<EventSchema>("upsert_event_schema", { input });
+
+export const deleteEventSchema = (eventType: string) =>
+  invoke<boolean>("delete_event_schema", { eventType });
@@ -2,6 +2,7 @@ import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 
 import type { PersonaExecution } from "@/lib/bindings/PersonaExecution";
 import type { PersonaHealingIssue } from "@/lib/bindings/PersonaHealingIssue";
+export type { HealingRootCause } from "@/lib/bindings/HealingRootCause";
 import type { HealingTimelineEvent } from "@/lib/bindings/HealingTimelineEvent";
 
 // ============================================================================
@@ -17,6 +18,19 @@ export const listHealingIssues = (personaId?: string, status?: string) =>
 export const getHealingIssue = (id: string, callerPersonaId: string) =>
   invoke<PersonaHealingIssue>("get_healing_issue", { id, callerPersonaId });
 
+/**
+ * Generate (or regenerate) the root-cause summary of an execution-failure
+ * issue and return the updated issue. Works whether or not the persona opted
+ * into automatic summaries.
+ */
+export const generateHealingRootCause = (id: string, callerPersonaId: string) =>
+  invoke<PersonaHealingIssue>(
+    "generate_healing_root_cause",
+    { id, callerPersonaId },
+    // One-shot CLI pass over the run's error and log tail.
+    { timeoutMs: 300_000 },
+  );
+
 export const updateHealingStatus = (id: string, status: string, callerPersonaId: string) =>
   invoke<void>("update_healing_status", { id, status, callerPersonaId });
 
@@ -60,3 +74,55 @@ export type { HealingStrategyStat } from "@/lib/bindings/HealingStrategyStat";
 
 export const getHealingEffectiveness = (windowDays?: number) =>
   invoke<HealingEffectivenessReport>("get_healing_effectiveness", { windowDays });
+
+// ============================================================================
+// Healing Knowledge Base
+// ============================================================================
+
+import type { HealingKnowledge } from "@/lib/bindings/HealingKnowledge";
+import type { HealingKnowledgeImportResult } from "@/lib/bindings/HealingKnowledgeImportResult";
+import type { UpdateHealingKnowledgeInput } from "@/lib/bindings/UpdateHealingKnowledgeInput";
+export type { HealingKnowledge } from "@/lib/bindings/HealingKnowledge";
+export type { HealingKnowledgeExport } from "@/lib/bindings/HealingKnowledgeExport";
+export type { HealingKnowledgeRecord } from "@/lib/bindings/HealingKnowledgeRecord";
+export type { HealingKnowledgeImportResult } from "@/lib/bindings/HealingKnowledgeImportResult";
+export type { UpdateHealingKnowledgeInput } from "@/lib/bindings/UpdateHealingKnowledgeInput";
+
+export const listHealingKnowledge = (serviceType?: string) =>
+  invoke<HealingKnowledge[]>("list_healing_knowledge", { serviceType });
+
+export const searchHealingKnowledge = (query: string, serviceType?: string, limit?: number) =>
+  invoke<HealingKnowledge[]>("search_healing_knowledge", { query, serviceType, limit });
+
+/** Edited entries become curated: later learning keeps the correction. */
+export const updateHealingKnowledge = (id: string, input: Partial<UpdateHealingKnowledgeInput>) =>
+  invoke<HealingKnowledge>("update_healing_knowledge", { id, input });
+
+export const deleteHealingKnowledge = (id: string) =>
+  invoke<boolean>("delete_healing_knowledge", { id });
+
+/** JSON document for `importHealingKnowledge` on another install. */
+export const exportHealingKnowledge = (serviceType?: string) =>
+  invoke<string>("export_healing_knowledge", { serviceType });
+
+/** Accepts an exported document or a bare array of entries. */
+export const importHealingKnowledge = (json: string) =>
+  invoke<HealingKnowledgeImportResult>("import_healing_knowledge", { json });
+
+// ============================================================================
+// Fleet Learning (opt-in anonymous pattern sharing)
+// ============================================================================
+
+import type { FleetHealingRecommendation } from "@/lib/bindings/FleetHealingRecommendation";
+import type { FleetPatternSyncResult } from "@/lib/bindings/FleetPatternSyncResult";
+export type { FleetHealingRecommendation } from "@/lib/bindings/FleetHealingRecommendation";
+export type { FleetPatternSyncResult } from "@/lib/bindings/FleetPatternSyncResult";
+
+export const listFleetHealingRecommendations = () =>
+  invoke<FleetHealingRecommendation[]>("list_fleet_healing_recommendations");
+
+export const setFleetPatternSharing = (enabled: boolean) =>
+  invoke<void>("set_fleet_pattern_sharing", { enabled });
+
+export const syncFleetPatternsNow = () =>
+  invoke<FleetPatternSyncResult>("sync_fleet_patterns_now");
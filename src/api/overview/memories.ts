@@ -3,6 +3,8 @@ import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 import type { PersonaMemory } from "@/lib/bindings/PersonaMemory";
 import type { CreatePersonaMemoryInput } from "@/lib/bindings/CreatePersonaMemoryInput";
 import type { MemoryCategoryInfo } from "@/lib/bindings/MemoryCategoryInfo";
+import type { MemoryReindexResult } from "@/lib/bindings/MemoryReindexResult";
+import type { UpdatePersonaMemoryInput } from "@/lib/bindings/UpdatePersonaMemoryInput";
 
 // ============================================================================
 // Memories
@@ -172,6 +174,15 @@ export const updateMemoryContent = (
   return invoke<boolean>("update_memory_content", { id, title, content, importance, tags });
 };
 
+/**
+ * Patch any subset of a memory's fields. Omitted keys are left unchanged;
+ * `expires_at: null` clears the expiry.
+ */
+export const updateMemory = (id: string, input: Partial<UpdatePersonaMemoryInput>) => {
+  if (input.importance != null) assertImportance(input.importance);
+  return invoke<PersonaMemory>("update_memory", { id, input });
+};
+
 export const batchDeleteMemories = (ids: string[]) =>
   invoke<number>("batch_delete_memories", { ids });
 
@@ -240,6 +251,19 @@ export const reviewMemoriesWithCli = (
     autoApply: autoApply,
   });
 
+/**
+ * Rebuild the embeddings used for task-relevant memory recall, for one
+ * persona or (when omitted) every persona. Only needed after an embedding
+ * model change; new and edited memories are embedded on write.
+ */
+export const reindexMemories = (personaId?: string) =>
+  invoke<MemoryReindexResult>(
+    "reindex_memories",
+    { personaId },
+    // Embeds every recall-eligible memory in sequence.
+    { timeoutMs: 600_000 },
+  );
+
 /**
  * Run a memory REFLECTION pass (Memory Engine v2): the LLM consolidates
  * related/contradicting memories into durable insights with `derived_from`
@@ -8,11 +8,19 @@ import type { FiredAlert } from "@/lib/bindings/FiredAlert";
 import type { MetricsChartData } from "@/lib/bindings/MetricsChartData";
 import type { MetricsSummary } from "@/lib/bindings/MetricsSummary";
 import type { ValueRollup } from "@/lib/bindings/ValueRollup";
+import type { TriggerRoi } from "@/lib/bindings/TriggerRoi";
+import type { CostAnomaly } from "@/lib/bindings/CostAnomaly";
+import type { CostBreakdown } from "@/lib/bindings/CostBreakdown";
+import type { CostGroupBy } from "@/lib/bindings/CostGroupBy";
+import type { MetricsRollupGranularity } from "@/lib/bindings/MetricsRollupGranularity";
+import type { MetricsRollupPoint } from "@/lib/bindings/MetricsRollupPoint";
+import type { MetricsSnapshotRebuild } from "@/lib/bindings/MetricsSnapshotRebuild";
 import type { PersonaPromptVersion } from "@/lib/bindings/PersonaPromptVersion";
 import type { PromptAbTestResult } from "@/lib/bindings/PromptAbTestResult";
 import type { PromptPerformanceData } from "@/lib/bindings/PromptPerformanceData";
 import type { ExecutionDashboardData } from "@/lib/bindings/ExecutionDashboardData";
 import type { ExecutionHeatmapData } from "@/lib/bindings/ExecutionHeatmapData";
+import type { FailureHeatmapData } from "@/lib/bindings/FailureHeatmapData";
 import type { AnomalyDrilldownData } from "@/lib/bindings/AnomalyDrilldownData";
 import type { OverviewBundle } from "@/lib/bindings/OverviewBundle";
 
@@ -45,6 +53,52 @@ export const getValueRollup = (days?: number, personaId?: string) =>
     personaId: personaId,
   });
 
+/**
+ * Cost and outcome per trigger of one persona (default window 30 days), most
+ * expensive first. Runs no trigger started come back as an `untriggered` row.
+ */
+export const getTriggerRoi = (personaId: string, days?: number) =>
+  invoke<TriggerRoi[]>("get_trigger_roi", {
+    personaId: personaId,
+    days: days,
+  });
+
+/**
+ * Spend over the window grouped by persona, team, model, connector or use
+ * case, with a zero-filled daily series overall and per group.
+ */
+export const getCostBreakdown = (days?: number, groupBy?: CostGroupBy) =>
+  invoke<CostBreakdown>("get_cost_breakdown", {
+    days: days,
+    groupBy: groupBy,
+  });
+
+/** Days whose spend deviates more than 2σ from the trailing average. */
+export const getCostAnomalies = (days?: number, personaId?: string) =>
+  invoke<CostAnomaly[]>("get_cost_anomalies", {
+    days: days,
+    personaId: personaId,
+  });
+
+/** Backfill daily metrics snapshots (YYYY-MM-DD, UTC) and refresh rollups. */
+export const rebuildMetricsSnapshots = (startDate: string, endDate?: string) =>
+  invoke<MetricsSnapshotRebuild>("rebuild_metrics_snapshots", {
+    startDate,
+    endDate,
+  });
+
+/** Weekly or monthly metrics from the materialized rollups. */
+export const getMetricsRollups = (
+  granularity: MetricsRollupGranularity,
+  periods?: number,
+  personaId?: string,
+) =>
+  invoke<MetricsRollupPoint[]>("get_metrics_rollups", {
+    granularity,
+    periods,
+    personaId,
+  });
+
 /**
  * Category-aware error analytics. Returns per-category failure counts for the
  * window and the prior window of equal length (category-grounded deltas) plus
@@ -79,6 +133,31 @@ export const getAllMonthlySpend = () =>
     utcOffsetMinutes: -new Date().getTimezoneOffset(),
   });
 
+/** Budget position per persona for the current UTC month (all personas when
+ *  `personaId` is omitted): spend, remaining, projected end-of-month spend. */
+export const getBudgetStatus = (personaId?: string) =>
+  invoke<import('@/lib/bindings/BudgetStatus').BudgetStatus[]>("get_budget_status", {
+    personaId,
+  });
+
+/** Month-to-date spend rolled up per workspace (team) against its
+ *  `monthly_budget_usd` cap (all teams when `teamId` is omitted). */
+export const getGroupSpend = (teamId?: string) =>
+  invoke<import('@/lib/bindings/GroupSpend').GroupSpend[]>("get_group_spend", {
+    teamId,
+  });
+
+/** Reconcile a provider usage CSV against recorded costs; `applyCorrection` folds the ratio into future estimates. */
+export const reconcileProviderUsage = (provider: "anthropic" | "openai", csv: string, applyCorrection?: boolean) =>
+  invoke<import('@/lib/bindings/CostReconciliationReport').CostReconciliationReport>("reconcile_provider_usage", {
+    provider,
+    csv,
+    applyCorrection,
+  });
+
+export const getCostCorrectionFactors = () =>
+  invoke<Record<string, number>>("get_cost_correction_factors", {});
+
 const overviewBundleCache = new Map<string, { expiresAt: number; promise: Promise<OverviewBundle> }>();
 const OVERVIEW_BUNDLE_CACHE_MS = 1000;
 
@@ -136,6 +215,18 @@ export const getExecutionHeatmap = (days?: number, personaId?: string) =>
     tzOffsetMinutes: -new Date().getTimezoneOffset(),
   });
 
+/**
+ * Failures bucketed by local hour of day × the connector they mention, from
+ * failed executions and healing issues. Surfaces recurring failure windows
+ * (e.g. a service's nightly rate limit) so schedules can be moved around them.
+ */
+export const getFailureHeatmap = (days?: number, personaId?: string) =>
+  invoke<FailureHeatmapData>("get_failure_heatmap", {
+    days: days,
+    personaId: personaId,
+    tzOffsetMinutes: -new Date().getTimezoneOffset(),
+  });
+
 // ============================================================================
 // Anomaly Drill-Down
 // ============================================================================
@@ -245,8 +336,15 @@ export const getDigestConfig = () =>
 export const setDigestConfig = (config: import("@/lib/bindings/DigestConfig").DigestConfig) =>
   invoke<import("@/lib/bindings/DigestConfig").DigestConfig>("set_digest_config", { config });
 
-export const previewDigest = (days?: number) =>
-  invoke<import("@/lib/bindings/PerformanceDigest").PerformanceDigest>("preview_digest", { days });
+export const previewDigest = (days?: number, groupId?: string) =>
+  invoke<import("@/lib/bindings/PerformanceDigest").PerformanceDigest>("preview_digest", { days, groupId });
 
 export const sendDigestNow = () =>
   invoke<void>("send_digest_now");
+
+/** Generate and deliver a digest now; defaults to the configured cadence and scope. */
+export const generateDigestNow = (days?: number, groupId?: string) =>
+  invoke<import("@/lib/bindings/PerformanceDigest").PerformanceDigest>("generate_digest_now", { days, groupId });
+
+export const runFleetHygieneNow = () =>
+  invoke<import("@/lib/bindings/FleetHygieneReport").FleetHygieneReport>("run_fleet_hygiene_now");
@@ -1,11 +1,17 @@
 import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 import type { SlaDashboardData } from "@/lib/bindings/SlaDashboardData";
+import type { PersonaSlo } from "@/lib/bindings/PersonaSlo";
+import type { SloStatus } from "@/lib/bindings/SloStatus";
+import type { UpsertPersonaSloInput } from "@/lib/bindings/UpsertPersonaSloInput";
 
 export type { SlaDashboardData } from "@/lib/bindings/SlaDashboardData";
 export type { PersonaSlaStats } from "@/lib/bindings/PersonaSlaStats";
 export type { GlobalSlaStats } from "@/lib/bindings/GlobalSlaStats";
 export type { HealingSummary } from "@/lib/bindings/HealingSummary";
 export type { SlaDailyPoint } from "@/lib/bindings/SlaDailyPoint";
+export type { PersonaSlo } from "@/lib/bindings/PersonaSlo";
+export type { SloStatus } from "@/lib/bindings/SloStatus";
+export type { UpsertPersonaSloInput } from "@/lib/bindings/UpsertPersonaSloInput";
 
 // ============================================================================
 // Commands
@@ -20,3 +26,13 @@ export const getSlaDashboard = (days?: number, utcOffsetMinutes?: number) =>
     days,
     utcOffsetMinutes: utcOffsetMinutes ?? localUtcOffsetMinutes(),
   });
+
+/** Per-persona SLOs with their live measurement; all personas when omitted. */
+export const getSloStatus = (personaId?: string) =>
+  invoke<SloStatus[]>("get_slo_status", { personaId });
+
+export const upsertPersonaSlo = (input: UpsertPersonaSloInput) =>
+  invoke<PersonaSlo>("upsert_persona_slo", { input });
+
+export const deletePersonaSlo = (personaId: string) =>
+  invoke<boolean>("delete_persona_slo", { personaId });
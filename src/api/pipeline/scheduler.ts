@@ -1,11 +1,13 @@
 import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 import type { SchedulerStats } from "@/lib/bindings/SchedulerStats";
 import type { SubscriptionHealth } from "@/lib/bindings/SubscriptionHealth";
+import type { BackgroundJobStatus } from "@/lib/bindings/BackgroundJobStatus";
 import type { BackfillResult } from "@/lib/bindings/BackfillResult";
 import type { ScheduleMissedRuns } from "@/lib/bindings/ScheduleMissedRuns";
 
 export type { SchedulerStats } from "@/lib/bindings/SchedulerStats";
 export type { SubscriptionHealth } from "@/lib/bindings/SubscriptionHealth";
+export type { BackgroundJobStatus } from "@/lib/bindings/BackgroundJobStatus";
 export type { BackfillResult } from "@/lib/bindings/BackfillResult";
 export type { ScheduleMissedRuns } from "@/lib/bindings/ScheduleMissedRuns";
 
@@ -21,6 +23,16 @@ export const stopScheduler = () =>
 export const getSubscriptionHealth = () =>
   invoke<SubscriptionHealth[]>("get_subscription_health");
 
+/** Jobs dashboard: every background loop with its last/next run and controls. */
+export const listBackgroundJobs = () =>
+  invoke<BackgroundJobStatus[]>("list_background_jobs");
+
+export const runBackgroundJobNow = (name: string) =>
+  invoke<void>("run_background_job_now", { name });
+
+export const setBackgroundJobPaused = (name: string, paused: boolean) =>
+  invoke<BackgroundJobStatus>("set_background_job_paused", { name, paused });
+
 export const backfillSchedule = (
   triggerId: string,
   start: string,
@@ -8,6 +8,8 @@ import type { PersonaTeamConnection } from "@/lib/bindings/PersonaTeamConnection
 import type { PipelineAnalytics } from "@/lib/bindings/PipelineAnalytics";
 import type { TopologyBlueprint } from "@/lib/bindings/TopologyBlueprint";
 import type { TeamCounts } from "@/lib/bindings/TeamCounts";
+import type { TeamSpecExport } from "@/lib/bindings/TeamSpecExport";
+import type { ConditionValidation } from "@/lib/bindings/ConditionValidation";
 
 // ============================================================================
 // Teams
@@ -57,6 +59,14 @@ export interface HandoffWireResult {
 export const repairTeamHandoff = (teamId: string) =>
   invoke<HandoffWireResult>("repair_team_handoff", { teamId });
 
+/** Team pipeline as a YAML spec plus Mermaid and DOT diagrams (no credentials). */
+export const exportTeamSpec = (teamId: string) =>
+  invoke<TeamSpecExport>("export_team_spec", { teamId });
+
+/** Recreate a team and fresh member personas from an exported YAML spec. */
+export const importTeamSpec = (specYaml: string, projectId?: string | null) =>
+  invoke<PersonaTeam>("import_team_spec", { specYaml, projectId: projectId ?? null });
+
 export const listTeamMembers = (teamId: string) =>
   invoke<PersonaTeamMember[]>("list_team_members", { teamId });
 
@@ -121,6 +131,16 @@ export const updateTeamConnection = (id: string, connectionType: string) =>
 export const deleteTeamConnection = (id: string) =>
   invoke<boolean>("delete_team_connection", { id });
 
+/**
+ * Check a conditional connection's expression, e.g. `severity == "high"`.
+ * With `sampleOutput`, also reports whether the branch would be taken.
+ */
+export const validateConnectionCondition = (condition: string, sampleOutput?: string) =>
+  invoke<ConditionValidation>("validate_connection_condition", {
+    condition,
+    sampleOutput: sampleOutput ?? null,
+  });
+
 // -- Pipeline -------------------------------------------------------------
 
 import type { PipelineRun } from "@/lib/bindings/PipelineRun";
@@ -129,6 +149,20 @@ export type { PipelineRun } from "@/lib/bindings/PipelineRun";
 export const executeTeam = (teamId: string, inputData?: string) =>
   invoke<string>("execute_team", { teamId, inputData });
 
+import type { PipelineSimulation } from "@/lib/bindings/PipelineSimulation";
+
+/** Dry-run a team's pipeline with sandboxed tools; returns the run id. */
+export const simulateTeamRun = (
+  teamId: string,
+  sampleInput?: string,
+  simulation?: PipelineSimulation,
+) =>
+  invoke<string>("simulate_team_run", {
+    teamId,
+    sampleInput: sampleInput ?? null,
+    simulation: simulation ?? null,
+  });
+
 export const listPipelineRuns = (teamId: string) =>
   invoke<PipelineRun[]>("list_pipeline_runs", { teamId });
 
@@ -144,6 +178,15 @@ export const approvePipelineNode = (runId: string, memberId: string) =>
 export const rejectPipelineNode = (runId: string) =>
   invoke<boolean>("reject_pipeline_node", { runId });
 
+export const pausePipelineRun = (runId: string) =>
+  invoke<boolean>("pause_pipeline_run", { runId });
+
+export const resumePipelineRun = (runId: string) =>
+  invoke<void>("resume_pipeline_run", { runId });
+
+export const retryPipelineNode = (runId: string, memberId: string) =>
+  invoke<void>("retry_pipeline_node", { runId, memberId });
+
 // -- Pipeline Analytics & Optimizer --------------------------------------
 
 export const getPipelineAnalytics = (teamId: string) =>
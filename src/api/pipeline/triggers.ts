@@ -2,6 +2,7 @@ import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 
 import type { PersonaTrigger } from "@/lib/bindings/PersonaTrigger";
 import type { PendingTriggerFire } from "@/lib/bindings/PendingTriggerFire";
+import type { InjectionDetection } from "@/lib/bindings/InjectionDetection";
 import type { CreateTriggerInput } from "@/lib/bindings/CreateTriggerInput";
 import type { UpdateTriggerInput } from "@/lib/bindings/UpdateTriggerInput";
 import type { TriggerValidationResult } from "@/lib/bindings/TriggerValidationResult";
@@ -9,6 +10,10 @@ import type { WebhookStatus } from "@/lib/bindings/WebhookStatus";
 import type { CronAgent } from "@/lib/bindings/CronAgent";
 import type { RecentScheduleRun } from "@/lib/bindings/RecentScheduleRun";
 import type { WebhookRequestLog } from "@/lib/bindings/WebhookRequestLog";
+import type { CronValidation } from "@/lib/bindings/CronValidation";
+import type { ScheduleExclusion } from "@/lib/bindings/ScheduleExclusion";
+import type { MaintenanceWindow } from "@/lib/bindings/MaintenanceWindow";
+import type { CreateMaintenanceWindowInput } from "@/lib/bindings/CreateMaintenanceWindowInput";
 
 // ============================================================================
 // Triggers
@@ -50,6 +55,54 @@ export const listPendingTriggerFires = () =>
 export const resolvePendingTriggerFire = (id: string, approved: boolean) =>
   invoke<PendingTriggerFire>("resolve_pending_trigger_fire", { id, approved });
 
+// ============================================================================
+// Prompt-injection scan: inbound payloads flagged before execution. The policy
+// is the trigger config's `injection_policy` or the global default setting.
+// ============================================================================
+
+export type InjectionPolicy = "off" | "flag" | "sanitize" | "block" | "require_approval";
+
+export type InjectionReviewStatus = "unreviewed" | "false_positive" | "confirmed";
+
+export const listInjectionDetections = (reviewStatus?: InjectionReviewStatus, limit?: number) =>
+  invoke<InjectionDetection[]>("list_injection_detections", { reviewStatus, limit });
+
+/** Record a verdict; `false_positive` lets the same payload through from then on. */
+export const reviewInjectionDetection = (id: string, reviewStatus: InjectionReviewStatus) =>
+  invoke<InjectionDetection>("review_injection_detection", { id, reviewStatus });
+
+// ============================================================================
+// Output regression: a schedule trigger's `output_regression` config compares
+// each completed run's JSON output with the previous run and opens a manual
+// review when too much of it changed.
+// ============================================================================
+
+export interface OutputRegressionConfig {
+  /** Share of changed fields (and relative numeric move) that counts as a
+   *  regression, in (0, 1]. Default 0.3. */
+  threshold?: number;
+  /** Path prefixes never compared, e.g. `generated_at` or `items[].id`. */
+  ignore_paths?: string[];
+}
+
+// ============================================================================
+// Maintenance windows: trigger fires are recorded but execution waits for the
+// window to end. A window without personaId covers every persona.
+// ============================================================================
+
+export const listMaintenanceWindows = (includeEnded?: boolean) =>
+  invoke<MaintenanceWindow[]>("list_maintenance_windows", { includeEnded });
+
+export const createMaintenanceWindow = (input: CreateMaintenanceWindowInput) =>
+  invoke<MaintenanceWindow>("create_maintenance_window", { input });
+
+/** End a window now; its deferred fires dispatch on the next bus tick. */
+export const endMaintenanceWindow = (id: string) =>
+  invoke<MaintenanceWindow>("end_maintenance_window", { id });
+
+export const deleteMaintenanceWindow = (id: string) =>
+  invoke<boolean>("delete_maintenance_window", { id });
+
 // ============================================================================
 // Builder: atomic persona <-> event linking
 // See docs/design/event-routing-proposal.md
@@ -207,6 +260,26 @@ export const cronFireTimesInRange = (
   seed,
 });
 
+/**
+ * Validate a schedule as it will be saved: cron expression, IANA timezone and
+ * exclusion windows (e.g. `[{ days: [0, 6] }]` for "not on weekends").
+ * Unknown timezones are reported as errors rather than falling back to
+ * system-local. Returns the next five fire times with excluded slots skipped.
+ */
+export const validateCron = (
+  expression: string,
+  timezone?: string,
+  exclusions?: ScheduleExclusion[],
+  seed?: string,
+) => invoke<CronValidation>("validate_cron", { expression, timezone, exclusions, seed });
+
+/**
+ * Describe a cron expression and list its next ten fire times, evaluated the
+ * way the scheduler will run it (exclusion windows not applied).
+ */
+export const describeCron = (expression: string, timezone?: string, seed?: string) =>
+  invoke<CronValidation>("describe_cron", { expression, timezone, seed });
+
 // ============================================================================
 // Webhook Server
 // ============================================================================
@@ -8,6 +8,7 @@ import type { ProviderAuditEntry } from "@/lib/bindings/ProviderAuditEntry";
 import type { ProviderUsageStats } from "@/lib/bindings/ProviderUsageStats";
 import type { ProviderUsageTimeseries } from "@/lib/bindings/ProviderUsageTimeseries";
 import type { ProviderConnectionResult } from "@/lib/bindings/ProviderConnectionResult";
+import type { ProviderCapabilities } from "@/lib/bindings/ProviderCapabilities";
 // Aliased to `RustPolicyWarning` to disambiguate from `PolicyWarning` in
 // `features/settings/sub_byom/libs/byomHelpers.ts`, which is a richer
 // frontend-only shape (adds `ruleType` + `ruleIndex` for per-rule UI
@@ -16,7 +17,7 @@ import type { ProviderConnectionResult } from "@/lib/bindings/ProviderConnection
 // and consumers can import `PolicyWarning` directly.
 import type { PolicyWarning as RustPolicyWarning } from "@/lib/bindings/PolicyWarning";
 import type { PolicyWarningSeverity } from "@/lib/bindings/PolicyWarningSeverity";
-export type { TaskComplexity, RoutingRule, ComplianceRule, ByomPolicy, ProviderAuditEntry, ProviderUsageStats, ProviderUsageTimeseries, ProviderConnectionResult, RustPolicyWarning, PolicyWarningSeverity };
+export type { TaskComplexity, RoutingRule, ComplianceRule, ByomPolicy, ProviderAuditEntry, ProviderUsageStats, ProviderUsageTimeseries, ProviderConnectionResult, ProviderCapabilities, RustPolicyWarning, PolicyWarningSeverity };
 
 // ============================================================================
 // BYOM Policy API
@@ -59,3 +60,10 @@ export const getProviderUsageTimeseries = (days?: number) =>
 
 export const testProviderConnection = (providerId: string) =>
   invoke<ProviderConnectionResult>("test_provider_connection", { providerId });
+
+// ============================================================================
+// Provider Capabilities
+// ============================================================================
+
+export const getProviderCapabilities = (personaId?: string) =>
+  invoke<ProviderCapabilities>("get_provider_capabilities", { personaId: personaId ?? null });
@@ -12,9 +12,11 @@ import type { CloudTrigger } from "@/lib/bindings/CloudTrigger";
 import type { CloudTriggerFiring } from "@/lib/bindings/CloudTriggerFiring";
 import type { CloudDiagnostics } from "@/lib/bindings/CloudDiagnostics";
 import type { SmeeRelay } from "@/lib/bindings/SmeeRelay";
+import type { CloudReconcileReport } from "@/lib/bindings/CloudReconcileReport";
 
 export type { CloudConfig } from "@/lib/bindings/CloudConfig";
 export type { SmeeRelay } from "@/lib/bindings/SmeeRelay";
+export type { CloudReconcileReport } from "@/lib/bindings/CloudReconcileReport";
 export type { CloudWorkerCounts } from "@/lib/bindings/CloudWorkerCounts";
 export type { CloudStatusResponse } from "@/lib/bindings/CloudStatusResponse";
 export type { CloudOAuthAuthorizeResponse } from "@/lib/bindings/CloudOAuthAuthorizeResponse";
@@ -52,6 +54,9 @@ export const cloudExecutePersona = (personaId: string, inputData?: string) =>
 export const cloudCancelExecution = (executionId: string) =>
   invoke<boolean>("cloud_cancel_execution", { executionId });
 
+export const cloudReconcileExecutions = () =>
+  invoke<CloudReconcileReport>("cloud_reconcile_executions");
+
 // OAuth
 export const cloudOAuthAuthorize = () =>
   invoke<CloudOAuthAuthorizeResponse>("cloud_oauth_authorize");
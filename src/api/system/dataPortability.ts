@@ -38,3 +38,13 @@ export const importCredentials = (passphrase: string, resolutionsJson?: string,
     resolutionsJson: resolutionsJson ?? null,
     filePathOverride: filePathOverride ?? null,
   });
+
+export const exportCredentialsBundle = (ids: string[], passphrase: string) =>
+  invoke<boolean>("export_credentials_bundle", { ids, passphrase });
+
+export const importCredentialsBundle = (file: string, passphrase: string, resolutionsJson?: string) =>
+  invoke<CredentialImportResult>("import_credentials_bundle", {
+    file,
+    passphrase,
+    resolutionsJson: resolutionsJson ?? null,
+  });
@@ -0,0 +1,28 @@
+import { invokeWithTimeout as invoke } from '@/lib/tauriInvoke';
+
+import type { OllamaModel } from '@/lib/bindings/OllamaModel';
+import type { Persona } from '@/lib/bindings/Persona';
+
+/**
+ * Local Ollama models for offline personas (`provider: "ollama"`).
+ *
+ * The server comes from the `ollama_base_url` setting unless `baseUrl` is
+ * passed. Pull progress streams on the `ollama-pull-progress` event.
+ */
+export type { OllamaModel } from '@/lib/bindings/OllamaModel';
+export type { OllamaPullProgress } from '@/lib/bindings/OllamaPullProgress';
+
+export const listOllamaModels = (baseUrl?: string) =>
+  invoke<OllamaModel[]>('list_ollama_models', { baseUrl });
+
+export const pullOllamaModel = (model: string, baseUrl?: string) =>
+  invoke<void>(
+    'pull_ollama_model',
+    { model, baseUrl },
+    // Multi-GB downloads; progress arrives via events.
+    { timeoutMs: 3_600_000 },
+  );
+
+/** Point a persona at an installed model, or pass `null` to clear it. */
+export const setPersonaOllamaModel = (personaId: string, model: string | null) =>
+  invoke<Persona>('set_persona_ollama_model', { personaId, model });
@@ -1,4 +1,5 @@
 import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+import { applyObserverMode } from "@/lib/observerMode";
 
 import type { HealthCheckItem } from "@/lib/bindings/HealthCheckItem";
 import type { HealthCheckStatus } from "@/lib/bindings/HealthCheckStatus";
@@ -9,11 +10,20 @@ import type { FrontendCrashRow } from "@/lib/bindings/FrontendCrashRow";
 import type { SetupStartResult } from "@/lib/bindings/SetupStartResult";
 import type { DbPerfSnapshot } from "@/lib/bindings/DbPerfSnapshot";
 import type { LogDirectoryStats } from "@/lib/bindings/LogDirectoryStats";
-export type { HealthCheckItem, HealthCheckStatus, HealthCheckSection, SystemHealthReport, CrashLogEntry, FrontendCrashRow, SetupStartResult, DbPerfSnapshot, LogDirectoryStats };
+import type { StatusNarrative } from "@/lib/bindings/StatusNarrative";
+import type { StartupProfile } from "@/lib/bindings/StartupProfile";
+import type { ConnectorSeedReport } from "@/lib/bindings/ConnectorSeedReport";
+import type { DbContentionSnapshot } from "@/lib/bindings/DbContentionSnapshot";
+import type { StateSnapshot } from "@/lib/bindings/StateSnapshot";
+export type { HealthCheckItem, HealthCheckStatus, HealthCheckSection, SystemHealthReport, CrashLogEntry, FrontendCrashRow, SetupStartResult, DbPerfSnapshot, LogDirectoryStats, StatusNarrative, StartupProfile, ConnectorSeedReport, DbContentionSnapshot, StateSnapshot };
 
 export const systemHealthCheck = () =>
   invoke<SystemHealthReport>("system_health_check");
 
+/** Plain-language app status for screen readers and status announcements. */
+export const getStatusNarrative = () =>
+  invoke<StatusNarrative>("get_status_narrative");
+
 // Per-section health checks for cascade loading
 export const healthCheckLocal = () =>
   invoke<HealthCheckSection>("health_check_local");
@@ -96,6 +106,26 @@ export const getFrontendCrashCount = (hours?: number) =>
 export const getDbPerformance = () =>
   invoke<DbPerfSnapshot>("get_db_performance");
 
+/** SQLite busy/locked retry counters per table plus main pool occupancy. */
+export const getDbContentionStats = () =>
+  invoke<DbContentionSnapshot>("get_db_contention_stats");
+
+/** Zero the contention counters to start a fresh measurement window. */
+export const resetDbContentionStats = () =>
+  invoke<void>("reset_db_contention_stats");
+
+/** Sanitized app-state snapshot (counts, queue, jobs, breakers, settings minus secrets) for support tickets. */
+export const captureStateSnapshot = () =>
+  invoke<StateSnapshot>("capture_state_snapshot");
+
+/** Startup phase timings plus the non-critical work deferred past window creation. */
+export const getStartupProfile = () =>
+  invoke<StartupProfile>("get_startup_profile");
+
+/** Which builtin connectors the last boot inserted or upgraded; null while seeding runs. */
+export const getSeedReport = () =>
+  invoke<ConnectorSeedReport | null>("get_seed_report");
+
 // ============================================================================
 // Setup / Auto-install
 // ============================================================================
@@ -122,8 +152,53 @@ export const storageUsage = () =>
 export const pruneStorage = (olderThanHours?: number, dryRun?: boolean) =>
   invoke<import('@/lib/bindings/PruneResult').PruneResult>("prune_storage", { olderThanHours, dryRun });
 
+// Database backup + staged restore (restore applies on next launch)
+export const backupDatabase = (passphrase?: string) =>
+  invoke<import('@/lib/bindings/DatabaseBackupInfo').DatabaseBackupInfo>(
+    "backup_database",
+    { passphrase: passphrase ?? null },
+    { timeoutMs: 300_000 },
+  );
+export const listDatabaseBackups = () =>
+  invoke<import('@/lib/bindings/DatabaseBackupInfo').DatabaseBackupInfo[]>("list_database_backups");
+export const restoreDatabase = (path: string, passphrase?: string) =>
+  invoke<import('@/lib/bindings/DatabaseRestoreResult').DatabaseRestoreResult>(
+    "restore_database",
+    { path, passphrase: passphrase ?? null },
+    { timeoutMs: 300_000 },
+  );
+export const checkDatabaseHealth = () =>
+  invoke<import('@/lib/bindings/DatabaseHealth').DatabaseHealth>(
+    "check_database_health",
+    undefined,
+    { timeoutMs: 300_000 },
+  );
+
 // F10: model-routing rules
 export const getModelRoutingRules = () =>
   invoke<import('@/lib/bindings/ModelRoutingRule').ModelRoutingRule[]>("get_model_routing_rules");
 export const setModelRoutingRules = (rules: import('@/lib/bindings/ModelRoutingRule').ModelRoutingRule[]) =>
   invoke<void>("set_model_routing_rules", { rules });
+
+// Model deprecation registry
+export const getModelDeprecations = () =>
+  invoke<import('@/lib/bindings/ModelDeprecation').ModelDeprecation[]>("get_model_deprecations");
+export const refreshModelDeprecations = () =>
+  invoke<number>("refresh_model_deprecations");
+export const migrateModelProfiles = (dryRun?: boolean) =>
+  invoke<import('@/lib/bindings/ModelProfileMigration').ModelProfileMigration[]>(
+    "migrate_model_profiles",
+    { dryRun: dryRun ?? null },
+  );
+
+// Observer mode (read-only screen sharing)
+export const getObserverMode = () =>
+  invoke<import('@/lib/bindings/ObserverModeConfig').ObserverModeConfig>("get_observer_mode");
+export const setObserverMode = (config: import('@/lib/bindings/ObserverModeConfig').ObserverModeConfig) =>
+  invoke<void>("set_observer_mode", { config }).then(() => applyObserverMode(config));
+export const runRetentionCleanupNow = () =>
+  invoke<import('@/lib/bindings/RetentionReport').RetentionReport>(
+    "run_retention_cleanup_now",
+    undefined,
+    { timeoutMs: 300_000 },
+  );
@@ -1,9 +1,9 @@
 /**
  * System-operation automations — IPC wrappers.
  *
- * A system-op automation binds a trigger (schedule cron OR event listener) to a
- * built-in backend operation that is NOT a persona execution (first op:
- * `context_scan`). Powers the Chain Studio "System events" rail (a committed
+ * A system-op automation binds a trigger (schedule cron, event listener,
+ * webhook or chain) to a built-in backend operation that is NOT a persona
+ * execution (first op: `context_scan`; `team_pipeline` runs a whole team). Powers the Chain Studio "System events" rail (a committed
  * route = one automation) and the Context Map "Plan update" button.
  */
 import { invokeWithTimeout as invoke } from '@/lib/tauriInvoke';
@@ -35,6 +35,13 @@ export const OP_MEMORY_REFLECTION = 'memory_reflection';
  */
 export const OP_HEALTH_INGEST = 'health_ingest';
 
+/**
+ * Op key for running a team pipeline unattended. Params carry `teamId` and an
+ * optional `inputData`; without it the triggering event's payload (webhook
+ * body, finished execution) becomes the pipeline input.
+ */
+export const OP_TEAM_PIPELINE = 'team_pipeline';
+
 /** Params builder for the memory-reflection op (exactly one id set). */
 export const memoryReflectionParamsJson = (scope: { personaId?: string; teamId?: string }): string =>
   JSON.stringify(scope.teamId ? { teamId: scope.teamId } : { personaId: scope.personaId });
@@ -42,6 +49,17 @@ export const memoryReflectionParamsJson = (scope: { personaId?: string; teamId?:
 /** Params builder for the health-ingest op. */
 export const healthIngestParamsJson = (projectId: string): string => JSON.stringify({ projectId });
 
+/**
+ * Params builder for the team-pipeline op. `webhookSecret` is required for
+ * webhook triggers; `condition` applies to chain triggers (default `any`).
+ */
+export const teamPipelineParamsJson = (params: {
+  teamId: string;
+  inputData?: string;
+  webhookSecret?: string;
+  condition?: 'any' | 'success' | 'failure';
+}): string => JSON.stringify(params);
+
 /**
  * The two DISPATCH ops (docs/plans/dev-findings-loop.md §4 C/D). Bind either to a
  * `signal.raised` event listener in Chain Studio; the finding comes from the event.
@@ -67,8 +85,11 @@ export interface CreateAutomationInput {
   opKind: string;
   /** JSON-encoded op params, e.g. `{"projectId":"…","deltaMode":true}`. */
   paramsJson: string;
-  /** 'schedule' | 'event'. */
-  triggerKind: 'schedule' | 'event';
+  /**
+   * 'schedule' (cron) | 'event' (listenEventType) | 'webhook' (POST to
+   * `/webhook/<automation id>`) | 'chain' (sourceFilter = source persona id).
+   */
+  triggerKind: 'schedule' | 'event' | 'webhook' | 'chain';
   cron?: string;
   timezone?: string;
   listenEventType?: string;
@@ -0,0 +1,46 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+
+import type { N8nPersonaDraft } from "./n8nTransform";
+
+// ============================================================================
+// CrewAI / LangChain agent config import -- mirrors
+// `commands/design/agent_framework_import.rs` (serde_json-shaped, no ts-rs).
+// ============================================================================
+
+export interface FrameworkAgentDraft {
+  key: string;
+  team_role: "orchestrator" | "worker" | "reviewer" | "router";
+  draft: N8nPersonaDraft;
+}
+
+export interface FrameworkConnection {
+  source_index: number;
+  target_index: number;
+}
+
+export interface AgentFrameworkImport {
+  source_format: "crewai" | "langchain";
+  team_name: string | null;
+  agents: FrameworkAgentDraft[];
+  connections: FrameworkConnection[];
+  warnings: string[];
+}
+
+export interface AgentFrameworkImportResult {
+  persona_ids: string[];
+  team_id: string | null;
+}
+
+/** Parse an agents config (CrewAI `agents.yaml` + optional `tasks.yaml`, or LangChain JSON). */
+export const parseAgentFrameworkConfig = (
+  content: string,
+  tasksContent?: string,
+  format?: "crewai" | "langchain",
+) =>
+  invoke<AgentFrameworkImport>("parse_agent_framework_config", { content, tasksContent, format });
+
+/** Create the reviewed personas, plus a team when there are several agents. */
+export const confirmAgentFrameworkImport = (importData: AgentFrameworkImport) =>
+  invoke<AgentFrameworkImportResult>("confirm_agent_framework_import", {
+    importJson: JSON.stringify(importData),
+  });
@@ -0,0 +1,21 @@
+import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
+
+import type { N8nPersonaDraft } from "./n8nTransform";
+
+// ============================================================================
+// GPT / Assistant import -- parses an OpenAI export into a persona draft that
+// is reviewed and committed with `confirmN8nPersonaDraft`.
+// ============================================================================
+
+/** Mirrors `GptImportPreview` in `commands/design/gpt_import.rs`. */
+export interface GptImportPreview {
+  draft: N8nPersonaDraft;
+  source_kind: "assistant" | "gpt";
+  /** File names/ids referenced by the export; ingest the originals into a knowledge base after confirming. */
+  knowledge_files: string[];
+  conversation_starters: string[];
+  warnings: string[];
+}
+
+export const parseGptExport = (exportJson: string) =>
+  invoke<GptImportPreview>("parse_gpt_export", { exportJson });
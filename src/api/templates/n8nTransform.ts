@@ -282,6 +282,11 @@ export interface ConfirmDraftResponse {
 export const confirmN8nPersonaDraft = (draftJson: string, sessionId?: string | null) =>
   invoke<ConfirmDraftResponse>("confirm_n8n_persona_draft", { draftJson, sessionId: sessionId });
 
+/** n8n workflow JSON skeleton for a persona (`export_persona_to_n8n`), ready
+ *  for n8n's "Import from file". Untyped: it is n8n's format, not ours. */
+export const exportPersonaToN8n = (personaId: string) =>
+  invoke<Record<string, unknown>>("export_persona_to_n8n", { personaId });
+
 export const continueN8nTransform = (
   transformId: string,
   userAnswersJson: string,
@@ -88,6 +88,39 @@ export const vaultStatus = () =>
 export const migratePlaintextCredentials = () =>
   invoke<MigrationResult>("migrate_plaintext_credentials");
 
+// -- Vault Backend ------------------------------------------------------
+
+import type { VaultBackendConfig } from "@/lib/bindings/VaultBackendConfig";
+import type { VaultBackendKind } from "@/lib/bindings/VaultBackendKind";
+import type { VaultBackendStatus } from "@/lib/bindings/VaultBackendStatus";
+
+export type { VaultBackendConfig, VaultBackendKind, VaultBackendStatus };
+
+export const getVaultBackendStatus = () =>
+  invoke<VaultBackendStatus>("vault_backend_status");
+
+/** Unlock with the file passphrase or Vault token; the secret is not stored. */
+export const unlockVault = (secret: string) =>
+  invoke<VaultBackendStatus>("unlock_vault", { secret }, { timeoutMs: 30_000 });
+
+/** Copy the master key into another backend; takes effect on next start. */
+export const configureVaultBackend = (config: VaultBackendConfig, secret?: string) =>
+  invoke<VaultBackendStatus>("configure_vault_backend", { config, secret }, { timeoutMs: 30_000 });
+
+// -- Vault Key Portability ---------------------------------------------
+
+import type { VaultKeyImportReport } from "@/lib/bindings/VaultKeyImportReport";
+
+export type { VaultKeyImportReport };
+
+/** Passphrase-sealed master key (base64) for moving the vault to another machine. */
+export const exportVaultKey = (passphrase: string) =>
+  invoke<string>("export_vault_key", { passphrase }, { timeoutMs: 30_000 });
+
+/** Re-encrypt every secret sealed by an exported key under this machine's key. */
+export const importVaultKey = (exportedKey: string, passphrase: string) =>
+  invoke<VaultKeyImportReport>("import_vault_key", { exportedKey, passphrase }, { timeoutMs: 120_000 });
+
 // -- Field-level Credential Storage ------------------------------------
 
 
@@ -110,8 +143,9 @@ export const updateCredentialField = (
 import type { CredentialAuditEntry } from "@/lib/bindings/CredentialAuditEntry";
 import type { CredentialUsageStats } from "@/lib/bindings/CredentialUsageStats";
 import type { CredentialDependent } from "@/lib/bindings/CredentialDependent";
+import type { CredentialAnomaly } from "@/lib/bindings/CredentialAnomaly";
 
-export type { CredentialAuditEntry, CredentialUsageStats, CredentialDependent };
+export type { CredentialAuditEntry, CredentialUsageStats, CredentialDependent, CredentialAnomaly };
 
 export const getCredentialAuditLog = (credentialId: string, limit?: number) =>
   invoke<CredentialAuditEntry[]>("credential_audit_log", { credentialId, limit });
@@ -124,3 +158,15 @@ export const getCredentialUsageStats = (credentialId: string) =>
 
 export const getCredentialDependents = (credentialId: string) =>
   invoke<CredentialDependent[]>("credential_dependents", { credentialId });
+
+export type CredentialAnomalyStatus = "open" | "acknowledged";
+
+export const listCredentialAnomalies = (status?: CredentialAnomalyStatus, limit?: number) =>
+  invoke<CredentialAnomaly[]>("list_credential_anomalies", { status, limit });
+
+/** Run the usage anomaly detector now; resolves to newly flagged anomalies. */
+export const scanCredentialAnomalies = () =>
+  invoke<CredentialAnomaly[]>("scan_credential_anomalies");
+
+export const acknowledgeCredentialAnomaly = (id: string) =>
+  invoke<CredentialAnomaly>("acknowledge_credential_anomaly", { id });
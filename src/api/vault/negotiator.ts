@@ -2,7 +2,16 @@ import { invokeWithTimeout as invoke } from "@/lib/tauriInvoke";
 
 import type { NegotiationStartResult } from "@/lib/bindings/NegotiationStartResult";
 import type { StepHelpResult } from "@/lib/bindings/StepHelpResult";
-export type { NegotiationStartResult, StepHelpResult };
+import type { CredentialNegotiation } from "@/lib/bindings/CredentialNegotiation";
+import type { NegotiationTranscript } from "@/lib/bindings/NegotiationTranscript";
+import type { RecordNegotiationEventInput } from "@/lib/bindings/RecordNegotiationEventInput";
+export type {
+  NegotiationStartResult,
+  StepHelpResult,
+  CredentialNegotiation,
+  NegotiationTranscript,
+  RecordNegotiationEventInput,
+};
 
 // ============================================================================
 // Credential Negotiator
@@ -21,18 +30,60 @@ export const startCredentialNegotiation = (
     authenticatedServices: authenticatedServices ?? null,
   });
 
-export const cancelCredentialNegotiation = () =>
-  invoke<void>("cancel_credential_negotiation");
+export const cancelCredentialNegotiation = (negotiationId?: string | null) =>
+  invoke<void>("cancel_credential_negotiation", {
+    negotiationId: negotiationId ?? null,
+  });
 
 export const getNegotiationStepHelp = (
   serviceName: string,
   stepIndex: number,
   stepTitle: string,
   userQuestion: string,
+  negotiationId?: string | null,
 ) =>
   invoke<StepHelpResult>("get_negotiation_step_help", {
     serviceName,
     stepIndex,
     stepTitle,
     userQuestion,
+    negotiationId: negotiationId ?? null,
+  });
+
+// ============================================================================
+// Negotiation Transcripts
+// ============================================================================
+
+export const openNegotiationTranscript = (
+  serviceName: string,
+  plan: unknown,
+) =>
+  invoke<string>("open_negotiation_transcript", { serviceName, plan });
+
+export const recordNegotiationEvent = (input: RecordNegotiationEventInput) =>
+  invoke<void>("record_negotiation_event", { input });
+
+export const linkNegotiationCredential = (
+  negotiationId: string,
+  credentialId: string,
+) =>
+  invoke<void>("link_negotiation_credential", { negotiationId, credentialId });
+
+export const getNegotiationTranscript = (
+  ref: { negotiationId: string } | { credentialId: string },
+) =>
+  invoke<NegotiationTranscript>("get_negotiation_transcript", {
+    negotiationId: "negotiationId" in ref ? ref.negotiationId : null,
+    credentialId: "credentialId" in ref ? ref.credentialId : null,
+  });
+
+export const searchNegotiationTranscripts = (
+  query?: string,
+  credentialId?: string,
+  limit?: number,
+) =>
+  invoke<CredentialNegotiation[]>("search_negotiation_transcripts", {
+    query: query ?? null,
+    credentialId: credentialId ?? null,
+    limit: limit ?? null,
   });
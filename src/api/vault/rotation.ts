@@ -10,7 +10,8 @@ import type { HealthcheckEntry } from "@/lib/bindings/HealthcheckEntry";
 import type { RotationStatus } from "@/lib/bindings/RotationStatus";
 import type { OAuthTokenMetric } from "@/lib/bindings/OAuthTokenMetric";
 import type { OAuthTokenLifetimeSummary } from "@/lib/bindings/OAuthTokenLifetimeSummary";
-export type { RotationPolicy, CreateRotationPolicyInput, UpdateRotationPolicyInput, RotationHistoryEntry, Remediation, AnomalyScore, HealthcheckEntry, RotationStatus, OAuthTokenMetric, OAuthTokenLifetimeSummary };
+import type { ExpiringCredential } from "@/lib/bindings/ExpiringCredential";
+export type { RotationPolicy, CreateRotationPolicyInput, UpdateRotationPolicyInput, RotationHistoryEntry, Remediation, AnomalyScore, HealthcheckEntry, RotationStatus, OAuthTokenMetric, OAuthTokenLifetimeSummary, ExpiringCredential };
 
 // ============================================================================
 // API Functions
@@ -46,6 +47,10 @@ export const getRotationStatus = (credentialId: string) =>
 export const getAllRotationStatuses = () =>
   invoke<Record<string, RotationStatus>>("get_all_rotation_statuses", {});
 
+/** Credentials expiring within `days` (default: the warning-days setting), expired ones included. */
+export const listExpiringCredentials = (days?: number) =>
+  invoke<ExpiringCredential[]>("list_expiring_credentials", { days });
+
 export const rotateCredentialNow = (credentialId: string) =>
   invoke<string>("rotate_credential_now", { credentialId });
 
@@ -28,6 +28,9 @@ export const MODEL_OPTIONS: ModelOption[] = [
   // omitted so the engine uses the configured `qwen_base_url` setting / default.
   { id: 'qwen-coder', label: 'Qwen Coder', provider: 'qwen', model: 'qwen3-coder-plus' },
   { id: 'qwen-max', label: 'Qwen Max', provider: 'qwen', model: 'qwen3-max' },
+  // Generic OpenAI-compatible backend — endpoint/key come from the
+  // `openai_api_base_url` / `openai_api_key` settings (OpenAI by default).
+  { id: 'openai-gpt-4o-mini', label: 'GPT-4o mini (API)', provider: 'openai_api', model: 'gpt-4o-mini' },
   ...OLLAMA_CLOUD_PRESETS.map((p) => ({
     id: p.value,
     label: p.label.split(' (')[0] ?? p.label,
@@ -4,8 +4,8 @@
  * Tauri command and surfaces the plaintext via `CreatedKeyDialog`).
  *
  * Capability-token model (see docs/architecture/cloud-integration-bridge.md):
- * `personas:build` / `personas:read` are simple toggles; execution is
- * least-privilege — the user must name each agent, minting one
+ * `personas:build` / `personas:read` / `events:publish` are simple toggles;
+ * execution is least-privilege — the user must name each agent, minting one
  * `personas:execute:persona:<id>` scope per pick (no blanket execute). Keys are
  * time-boxed by default.
  */
@@ -17,13 +17,14 @@ import { ExecutePersonaPicker } from './ExecutePersonaPicker';
 
 interface ScopeOption {
   id: string;
-  labelKey: 'scope_build' | 'scope_read';
-  descriptionKey: 'scope_build_desc' | 'scope_read_desc';
+  labelKey: 'scope_build' | 'scope_read' | 'scope_events';
+  descriptionKey: 'scope_build_desc' | 'scope_read_desc' | 'scope_events_desc';
 }
 
 const AVAILABLE_SCOPES: ScopeOption[] = [
   { id: 'personas:build', labelKey: 'scope_build', descriptionKey: 'scope_build_desc' },
   { id: 'personas:read', labelKey: 'scope_read', descriptionKey: 'scope_read_desc' },
+  { id: 'events:publish', labelKey: 'scope_events', descriptionKey: 'scope_events_desc' },
 ];
 
 const EXPIRY_OPTIONS: { days: number | null; labelKey: 'expiry_never' | 'expiry_7d' | 'expiry_30d' | 'expiry_90d' }[] = [
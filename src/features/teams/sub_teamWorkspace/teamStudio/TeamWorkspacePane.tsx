@@ -135,6 +135,8 @@ export function TeamWorkspacePane({ teamId, onDirtyChange }: {
       default_model_profile: profileModel ? JSON.stringify({ model: profileModel, provider: 'anthropic' }) : null,
       default_max_budget_usd: budget.trim() === '' ? null : Number(budget),
       default_max_turns: turns.trim() === '' ? null : Number(turns),
+      // Not edited in this pane — resend the stored cap so null doesn't clear it.
+      monthly_budget_usd: team.monthly_budget_usd,
     };
     try {
       await updateTeam(teamId, input);
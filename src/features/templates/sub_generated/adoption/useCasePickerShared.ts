@@ -29,6 +29,7 @@ export const TRIGGER_TYPE_ALIASES: Record<string, string> = {
   poll: 'polling', hook: 'webhook', http: 'webhook', web_hook: 'webhook',
   watcher: 'file_watcher', fs_watcher: 'file_watcher', watch: 'file_watcher',
   focus: 'app_focus', window_focus: 'app_focus',
+  email: 'email_inbound', inbound_email: 'email_inbound', mailbox: 'email_inbound',
 };
 
 export function normalizeTriggerType(raw: string): string {
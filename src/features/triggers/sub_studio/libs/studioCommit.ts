@@ -36,6 +36,7 @@ export const FORM_COMMITTABLE_SOURCE_TYPES: ReadonlySet<string> = new Set([
   'webhook',
   'event_listener',
   'file_watcher',
+  'email_inbound',
   'clipboard',
   'app_focus',
   'composite',
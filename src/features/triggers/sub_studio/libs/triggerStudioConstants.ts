@@ -1,6 +1,6 @@
 import {
   Clock, Globe, Webhook, Link, Radio, Clipboard, AppWindow,
-  Layers, FileEdit,
+  Layers, FileEdit, Mail,
   type LucideIcon,
 } from 'lucide-react';
 
@@ -24,6 +24,7 @@ export const TRIGGER_BLOCK_TEMPLATES: TriggerBlockTemplate[] = [
   { id: 'blk-chain',      triggerType: 'chain',          label: 'Chain',             icon: Link,      color: 'text-purple-400',  description: 'Fires when another persona completes' },
   { id: 'blk-event',      triggerType: 'event_listener', label: 'Event Listener',    icon: Radio,     color: 'text-cyan-400',    description: 'Listen for published events' },
   { id: 'blk-file',       triggerType: 'file_watcher',   label: 'File Watcher',      icon: FileEdit,  color: 'text-cyan-400',    description: 'Monitor filesystem changes' },
+  { id: 'blk-email',      triggerType: 'email_inbound',  label: 'Inbound Email',     icon: Mail,      color: 'text-sky-400',     description: 'New mail matching a filter' },
   { id: 'blk-clipboard',  triggerType: 'clipboard',      label: 'Clipboard',         icon: Clipboard, color: 'text-pink-400',    description: 'Monitor clipboard content' },
   { id: 'blk-app-focus',  triggerType: 'app_focus',      label: 'App Focus',         icon: AppWindow, color: 'text-indigo-400',  description: 'Monitor foreground app changes' },
   { id: 'blk-composite',  triggerType: 'composite',      label: 'Composite',         icon: Layers,    color: 'text-rose-400',    description: 'Multi-condition trigger with time window' },
@@ -16,6 +16,7 @@ import { NlTriggerInput } from './NlTriggerInput';
 import type { NlParseResult } from './nlTriggerParser';
 import { WebhookConfig } from './configs/WebhookConfig';
 import { FileWatcherConfig } from './configs/FileWatcherConfig';
+import { EmailInboundConfig } from './configs/EmailInboundConfig';
 import { ClipboardConfig } from './configs/ClipboardConfig';
 import { AppFocusConfig } from './configs/AppFocusConfig';
 import { CompositeConfig } from './configs/CompositeConfig';
@@ -66,6 +67,16 @@ export function TriggerAddForm({
   const [watchEvents, setWatchEvents] = useState<string[]>(['modify']);
   const [watchRecursive, setWatchRecursive] = useState(true);
   const [globFilter, setGlobFilter] = useState('');
+  const [ignorePatterns, setIgnorePatterns] = useState('');
+  const [debounceMs, setDebounceMs] = useState('');
+  const [includeContent, setIncludeContent] = useState(true);
+  const [emailCredentialId, setEmailCredentialId] = useState('');
+  const [emailMailbox, setEmailMailbox] = useState('');
+  const [emailFrom, setEmailFrom] = useState('');
+  const [emailSubject, setEmailSubject] = useState('');
+  const [emailUnreadOnly, setEmailUnreadOnly] = useState(true);
+  const [emailMarkRead, setEmailMarkRead] = useState(false);
+  const [emailInterval, setEmailInterval] = useState('300');
   const [clipboardContentType, setClipboardContentType] = useState('text');
   const [clipboardPattern, setClipboardPattern] = useState('');
   const [clipboardInterval, setClipboardInterval] = useState('5');
@@ -115,6 +126,9 @@ export function TriggerAddForm({
       setWatchEvents((cfg.events as string[] | undefined) ?? ['modify']);
       setWatchRecursive((cfg.recursive as boolean | undefined) ?? true);
       setGlobFilter((cfg.glob_filter as string | undefined) ?? '');
+      setIgnorePatterns(((cfg.ignore_patterns as string[] | undefined) ?? []).join(', '));
+      setDebounceMs(cfg.debounce_ms !== undefined ? String(cfg.debounce_ms) : '');
+      setIncludeContent((cfg.include_content as boolean | undefined) ?? true);
     } else if (tpl.triggerType === 'clipboard') {
       setClipboardContentType((cfg.content_type as string | undefined) ?? 'text');
       setClipboardPattern((cfg.pattern as string | undefined) ?? '');
@@ -161,6 +175,9 @@ export function TriggerAddForm({
       scheduleTimezone, scheduleMaxBackfill,
       endpoint, selectedEventId, hmacSecret, listenEventType, sourceFilter,
       watchPaths, watchEvents, watchRecursive, globFilter,
+      ignorePatterns, debounceMs, includeContent,
+      emailCredentialId, emailMailbox, emailFrom, emailSubject,
+      emailUnreadOnly, emailMarkRead, emailInterval,
       clipboardContentType, clipboardPattern, clipboardInterval,
       appNames, titlePattern, appFocusInterval,
       compositeConditions, compositeOperator, windowSeconds,
@@ -230,7 +247,8 @@ export function TriggerAddForm({
       {triggerType === 'polling' && <PollingConfig credentialEventsList={credentialEventsList} selectedEventId={selectedEventId} setSelectedEventId={setSelectedEventId} endpoint={endpoint} setEndpoint={setEndpoint} />}
       {triggerType === 'webhook' && <WebhookConfig hmacSecret={hmacSecret} setHmacSecret={setHmacSecret} />}
       {triggerType === 'event_listener' && <EventListenerConfig listenEventType={listenEventType} setListenEventType={setListenEventType} sourceFilter={sourceFilter} setSourceFilter={setSourceFilter} validationError={validationError} setValidationError={setValidationError} />}
-      {triggerType === 'file_watcher' && <FileWatcherConfig watchPaths={watchPaths} setWatchPaths={setWatchPaths} watchEvents={watchEvents} setWatchEvents={setWatchEvents} watchRecursive={watchRecursive} setWatchRecursive={setWatchRecursive} globFilter={globFilter} setGlobFilter={setGlobFilter} validationError={validationError} setValidationError={setValidationError} />}
+      {triggerType === 'file_watcher' && <FileWatcherConfig watchPaths={watchPaths} setWatchPaths={setWatchPaths} watchEvents={watchEvents} setWatchEvents={setWatchEvents} watchRecursive={watchRecursive} setWatchRecursive={setWatchRecursive} globFilter={globFilter} setGlobFilter={setGlobFilter} ignorePatterns={ignorePatterns} setIgnorePatterns={setIgnorePatterns} debounceMs={debounceMs} setDebounceMs={setDebounceMs} includeContent={includeContent} setIncludeContent={setIncludeContent} validationError={validationError} setValidationError={setValidationError} />}
+      {triggerType === 'email_inbound' && <EmailInboundConfig emailCredentialId={emailCredentialId} setEmailCredentialId={setEmailCredentialId} emailMailbox={emailMailbox} setEmailMailbox={setEmailMailbox} emailFrom={emailFrom} setEmailFrom={setEmailFrom} emailSubject={emailSubject} setEmailSubject={setEmailSubject} emailUnreadOnly={emailUnreadOnly} setEmailUnreadOnly={setEmailUnreadOnly} emailMarkRead={emailMarkRead} setEmailMarkRead={setEmailMarkRead} emailInterval={emailInterval} setEmailInterval={setEmailInterval} validationError={validationError} setValidationError={setValidationError} />}
       {triggerType === 'clipboard' && <ClipboardConfig clipboardContentType={clipboardContentType} setClipboardContentType={setClipboardContentType} clipboardPattern={clipboardPattern} setClipboardPattern={setClipboardPattern} clipboardInterval={clipboardInterval} setClipboardInterval={setClipboardInterval} />}
       {triggerType === 'app_focus' && <AppFocusConfig appNames={appNames} setAppNames={setAppNames} titlePattern={titlePattern} setTitlePattern={setTitlePattern} appFocusInterval={appFocusInterval} setAppFocusInterval={setAppFocusInterval} />}
       {triggerType === 'composite' && <CompositeConfig compositeConditions={compositeConditions} setCompositeConditions={setCompositeConditions} compositeOperator={compositeOperator} setCompositeOperator={setCompositeOperator} windowSeconds={windowSeconds} setWindowSeconds={setWindowSeconds} validationError={validationError} setValidationError={setValidationError} />}
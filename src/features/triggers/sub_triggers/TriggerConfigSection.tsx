@@ -78,6 +78,15 @@ export function ConfigSection({ trigger, credentialEventsList, detail }: ConfigS
           {config.glob_filter && <div>{t.triggers.filter_colon} <code className="px-1.5 py-0.5 bg-background/50 border border-border/20 rounded typo-code font-mono">{config.glob_filter}</code></div>}
         </>
       )}
+      {config.type === 'email_inbound' && (
+        <>
+          <div>{t.triggers.email_inbound.folder_colon} <code className="px-1.5 py-0.5 bg-background/50 border border-border/20 rounded typo-code font-mono">{config.mailbox || 'INBOX'}</code></div>
+          {config.from && <div>{t.triggers.email_inbound.from_colon} {config.from}</div>}
+          {config.subject && <div>{t.triggers.email_inbound.subject_colon} {config.subject}</div>}
+          {config.unread_only && <div>{t.triggers.email_inbound.unread_only_yes}</div>}
+          {config.interval_seconds && <div>{t.triggers.interval_colon} {formatInterval(config.interval_seconds)}</div>}
+        </>
+      )}
       {config.type === 'clipboard' && (
         <>
           <div>{t.triggers.watches_colon} {config.content_type || 'text'} content</div>
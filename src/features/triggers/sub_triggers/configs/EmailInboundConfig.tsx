@@ -0,0 +1,116 @@
+import { useMemo } from 'react';
+import { ThemedSelect } from '@/features/shared/components/forms/ThemedSelect';
+import { NumberStepper } from '@/features/shared/components/forms/NumberStepper';
+import { useVaultStore } from '@/stores/vaultStore';
+import { TriggerFieldGroup } from './TriggerFieldGroup';
+import { useTranslation } from '@/i18n/useTranslation';
+
+/** Credential types the inbound email poller can read from. */
+const MAILBOX_SERVICE_TYPES = ['imap_smtp', 'gmail'];
+
+export interface EmailInboundConfigProps {
+  emailCredentialId: string;
+  setEmailCredentialId: (v: string) => void;
+  emailMailbox: string;
+  setEmailMailbox: (v: string) => void;
+  emailFrom: string;
+  setEmailFrom: (v: string) => void;
+  emailSubject: string;
+  setEmailSubject: (v: string) => void;
+  emailUnreadOnly: boolean;
+  setEmailUnreadOnly: (v: boolean) => void;
+  emailMarkRead: boolean;
+  setEmailMarkRead: (v: boolean) => void;
+  emailInterval: string;
+  setEmailInterval: (v: string) => void;
+  validationError: string | null;
+  setValidationError: (v: string | null) => void;
+}
+
+export function EmailInboundConfig({
+  emailCredentialId, setEmailCredentialId, emailMailbox, setEmailMailbox,
+  emailFrom, setEmailFrom, emailSubject, setEmailSubject,
+  emailUnreadOnly, setEmailUnreadOnly, emailMarkRead, setEmailMarkRead,
+  emailInterval, setEmailInterval, validationError, setValidationError,
+}: EmailInboundConfigProps) {
+  const { t } = useTranslation();
+  const credentials = useVaultStore((s) => s.credentials);
+  const mailboxCredentials = useMemo(
+    () => credentials.filter((c) => MAILBOX_SERVICE_TYPES.includes(c.service_type)),
+    [credentials],
+  );
+  const isGmail = mailboxCredentials.find((c) => c.id === emailCredentialId)?.service_type === 'gmail';
+
+  return (
+    <div className="space-y-3">
+      <TriggerFieldGroup
+        label={t.triggers.email_inbound.credential_label}
+        helpText={mailboxCredentials.length === 0 ? t.triggers.email_inbound.no_credentials : undefined}
+        error={validationError}
+        errorId="email-credential-error"
+      >
+        <ThemedSelect
+          value={emailCredentialId}
+          onChange={(e) => {
+            setEmailCredentialId(e.target.value);
+            if (validationError) setValidationError(null);
+          }}
+          className="rounded-modal"
+        >
+          <option value="">{t.triggers.email_inbound.credential_placeholder}</option>
+          {mailboxCredentials.map((c) => (
+            <option key={c.id} value={c.id}>{c.name}</option>
+          ))}
+        </ThemedSelect>
+      </TriggerFieldGroup>
+      <TriggerFieldGroup label={isGmail ? t.triggers.email_inbound.label_label : t.triggers.email_inbound.mailbox_label} optional>
+        <input
+          type="text"
+          value={emailMailbox}
+          onChange={(e) => setEmailMailbox(e.target.value)}
+          placeholder={isGmail ? t.triggers.email_inbound.label_placeholder : 'INBOX'}
+          className="w-full px-3 py-2 bg-background/50 border border-primary/15 rounded-modal text-foreground font-mono typo-code placeholder-muted-foreground/30 focus-ring transition-all"
+        />
+      </TriggerFieldGroup>
+      <TriggerFieldGroup label={t.triggers.email_inbound.from_label} optional>
+        <input
+          type="text"
+          value={emailFrom}
+          onChange={(e) => setEmailFrom(e.target.value)}
+          placeholder={t.triggers.email_inbound.from_placeholder}
+          className="w-full px-3 py-2 bg-background/50 border border-primary/15 rounded-modal text-foreground placeholder-muted-foreground/30 focus-ring transition-all"
+        />
+      </TriggerFieldGroup>
+      <TriggerFieldGroup label={t.triggers.email_inbound.subject_label} optional>
+        <input
+          type="text"
+          value={emailSubject}
+          onChange={(e) => setEmailSubject(e.target.value)}
+          placeholder={t.triggers.email_inbound.subject_placeholder}
+          className="w-full px-3 py-2 bg-background/50 border border-primary/15 rounded-modal text-foreground placeholder-muted-foreground/30 focus-ring transition-all"
+        />
+      </TriggerFieldGroup>
+      <div className="flex flex-wrap items-center gap-4">
+        <label className="flex items-center gap-2 cursor-pointer">
+          <input type="checkbox" checked={emailUnreadOnly} onChange={(e) => setEmailUnreadOnly(e.target.checked)} className="rounded border-primary/30" />
+          <span className="typo-body text-foreground">{t.triggers.email_inbound.unread_only}</span>
+        </label>
+        <label className="flex items-center gap-2 cursor-pointer">
+          <input type="checkbox" checked={emailMarkRead} onChange={(e) => setEmailMarkRead(e.target.checked)} className="rounded border-primary/30" />
+          <span className="typo-body text-foreground">{t.triggers.email_inbound.mark_read}</span>
+        </label>
+      </div>
+      <TriggerFieldGroup label={t.triggers.poll_interval_label}>
+        <NumberStepper
+          value={emailInterval.trim() === '' ? null : Number(emailInterval)}
+          onChange={(v) => setEmailInterval(v == null ? '' : String(v))}
+          min={60}
+          step={60}
+          allowEmpty
+          ariaLabel={t.triggers.poll_interval_label}
+          className="w-24"
+        />
+      </TriggerFieldGroup>
+    </div>
+  );
+}
@@ -11,6 +11,12 @@ export interface FileWatcherConfigProps {
   setWatchRecursive: (v: boolean) => void;
   globFilter: string;
   setGlobFilter: (v: string) => void;
+  ignorePatterns: string;
+  setIgnorePatterns: (v: string) => void;
+  debounceMs: string;
+  setDebounceMs: (v: string) => void;
+  includeContent: boolean;
+  setIncludeContent: (v: boolean) => void;
   validationError: string | null;
   setValidationError: (v: string | null) => void;
 }
@@ -18,6 +24,7 @@ export interface FileWatcherConfigProps {
 export function FileWatcherConfig({
   watchPaths, setWatchPaths, watchEvents, setWatchEvents,
   watchRecursive, setWatchRecursive, globFilter, setGlobFilter,
+  ignorePatterns, setIgnorePatterns, debounceMs, setDebounceMs, includeContent, setIncludeContent,
   validationError, setValidationError,
 }: FileWatcherConfigProps) {
   const { t } = useTranslation();
@@ -86,6 +93,30 @@ export function FileWatcherConfig({
           className="w-full px-3 py-2 bg-background/50 border border-primary/15 rounded-modal text-foreground font-mono typo-code placeholder-muted-foreground/30 focus-ring transition-all"
         />
       </TriggerFieldGroup>
+      <TriggerFieldGroup label={t.triggers.file_watcher.ignore_patterns} optional>
+        <input
+          type="text"
+          value={ignorePatterns}
+          onChange={(e) => setIgnorePatterns(e.target.value)}
+          placeholder={t.triggers.file_watcher.ignore_placeholder}
+          className="w-full px-3 py-2 bg-background/50 border border-primary/15 rounded-modal text-foreground font-mono typo-code placeholder-muted-foreground/30 focus-ring transition-all"
+        />
+      </TriggerFieldGroup>
+      <TriggerFieldGroup label={t.triggers.file_watcher.debounce_label} optional>
+        <input
+          type="number"
+          min={0}
+          step={100}
+          value={debounceMs}
+          onChange={(e) => setDebounceMs(e.target.value)}
+          placeholder="500"
+          className="w-full px-3 py-2 bg-background/50 border border-primary/15 rounded-modal text-foreground font-mono typo-code placeholder-muted-foreground/30 focus-ring transition-all"
+        />
+      </TriggerFieldGroup>
+      <label className="flex items-center gap-2 cursor-pointer">
+        <input type="checkbox" checked={includeContent} onChange={(e) => setIncludeContent(e.target.checked)} className="rounded border-primary/30" />
+        <span className="typo-body text-foreground">{t.triggers.file_watcher.include_content}</span>
+      </label>
     </div>
   );
 }
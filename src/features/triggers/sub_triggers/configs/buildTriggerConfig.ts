@@ -24,6 +24,18 @@ export interface TriggerFormState {
   watchEvents: string[];
   watchRecursive: boolean;
   globFilter: string;
+  /** Comma-separated names or globs to skip (e.g. "node_modules, *.tmp"). */
+  ignorePatterns: string;
+  debounceMs: string;
+  includeContent: boolean;
+  emailCredentialId: string;
+  /** IMAP folder or Gmail label; blank = INBOX / all mail. */
+  emailMailbox: string;
+  emailFrom: string;
+  emailSubject: string;
+  emailUnreadOnly: boolean;
+  emailMarkRead: boolean;
+  emailInterval: string;
   clipboardContentType: string;
   clipboardPattern: string;
   clipboardInterval: string;
@@ -87,6 +99,21 @@ export function buildTriggerConfig(s: TriggerFormState, t: Translations): BuildR
     config.events = s.watchEvents;
     config.recursive = s.watchRecursive;
     if (s.globFilter.trim()) config.glob_filter = s.globFilter.trim();
+    const ignores = s.ignorePatterns.split(',').map(p => p.trim()).filter(Boolean);
+    if (ignores.length > 0) config.ignore_patterns = ignores;
+    const debounce = parseInt(s.debounceMs);
+    if (!isNaN(debounce) && debounce > 0) config.debounce_ms = Math.min(debounce, 3_600_000);
+    if (!s.includeContent) config.include_content = false;
+  } else if (s.triggerType === 'email_inbound') {
+    if (!s.emailCredentialId) return { ok: false, error: v.email_credential_required };
+    config.credential_id = s.emailCredentialId;
+    if (s.emailMailbox.trim()) config.mailbox = s.emailMailbox.trim();
+    if (s.emailFrom.trim()) config.from = s.emailFrom.trim();
+    if (s.emailSubject.trim()) config.subject = s.emailSubject.trim();
+    config.unread_only = s.emailUnreadOnly;
+    config.mark_read = s.emailMarkRead;
+    const pi = parseInt(s.emailInterval);
+    config.interval_seconds = isNaN(pi) || pi < 60 ? 300 : pi;
   } else if (s.triggerType === 'clipboard') {
     config.content_type = s.clipboardContentType;
     if (s.clipboardPattern.trim()) config.pattern = s.clipboardPattern.trim();
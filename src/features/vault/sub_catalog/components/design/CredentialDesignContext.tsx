@@ -43,7 +43,7 @@ export interface CredentialDesignContextValue {
   onValuesChanged: (key: string, value: string) => void;
   onReset: () => void;
   onRefine?: () => void;
-  onNegotiatorValues?: (capturedValues: Record<string, string>) => void;
+  onNegotiatorValues?: (capturedValues: Record<string, string>, negotiationId: string | null) => void;
 
   /** Pre-fetched auth detections (warm-cached during analyzing phase). */
   prefetchedAuthDetections?: AuthDetectionInfo[];
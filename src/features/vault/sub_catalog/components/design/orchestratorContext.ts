@@ -30,7 +30,7 @@ interface ContextBuildInput {
   onValuesChanged: (key: string, value: string) => void;
   onReset: () => void;
   onRefine: () => void;
-  onNegotiatorValues: (values: Record<string, string>) => void;
+  onNegotiatorValues: (values: Record<string, string>, negotiationId: string | null) => void;
   prefetchedAuthDetections?: AuthDetectionInfo[];
 }
 
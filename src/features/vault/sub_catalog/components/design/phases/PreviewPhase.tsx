@@ -107,10 +107,10 @@ export function PreviewPhase() {
               key="neg-panel"
               designResult={result}
               prefetchedAuthDetections={prefetchedAuthDetections}
-              onComplete={(values) => {
+              onComplete={(values, negotiationId) => {
                 setShowNegotiator(false);
                 if (onNegotiatorValues) {
-                  onNegotiatorValues(values);
+                  onNegotiatorValues(values, negotiationId);
                 }
               }}
               onClose={() => setShowNegotiator(false)}
@@ -5,6 +5,7 @@ import { useUniversalOAuth } from '@/hooks/design/oauth/useUniversalOAuth';
 import { useCredentialHealth } from '@/features/vault/shared/hooks/health/useCredentialHealth';
 import { extractFirstUrl, OAUTH_FIELD } from '@/features/vault/sub_catalog/components/design/CredentialDesignHelpers';
 import { detectAuthenticatedServices, toAuthDetectionInfo } from '@/api/auth/authDetect';
+import { linkNegotiationCredential } from '@/api/vault/negotiator';
 import type { AuthDetectionInfo } from '@/hooks/design/credential/useCredentialNegotiator';
 import type { CredentialDesignContextValue } from '@/features/vault/sub_catalog/components/design/CredentialDesignContext';
 import type { CredentialDesignOrchestrator } from './orchestratorTypes';
@@ -32,11 +33,22 @@ export function useCredentialDesignOrchestrator(): CredentialDesignOrchestrator
   const [negotiatorValues, setNegotiatorValues] = useState<Record<string, string>>({});
   const [refinementCount, setRefinementCount] = useState(0);
   const lastResultRef = useRef<CredentialDesignResult | null>(null);
+  /** Negotiation whose captured values feed the credential being designed. */
+  const negotiationIdRef = useRef<string | null>(null);
 
   useEffect(() => {
     if (design.result) lastResultRef.current = design.result;
   }, [design.result]);
 
+  // -- Link the negotiation transcript to the credential it produced --
+  useEffect(() => {
+    const negotiationId = negotiationIdRef.current;
+    if (!design.savedCredentialId || !negotiationId) return;
+    negotiationIdRef.current = null;
+    linkNegotiationCredential(negotiationId, design.savedCredentialId)
+      .catch(silentCatch('useCredentialDesignOrchestrator:linkNegotiationCredential'));
+  }, [design.savedCredentialId]);
+
   // -- Prefetch auth detections during analyzing phase so results are
   //    warm-cached by the time the user opens the NegotiatorPanel. The
   //    backend has a 5-minute cache (AUTH_DETECT_CACHE_TTL), so even if
@@ -201,6 +213,7 @@ export function useCredentialDesignOrchestrator(): CredentialDesignOrchestrator
       setCredentialName('');
       health.invalidate();
       setNegotiatorValues({});
+      negotiationIdRef.current = null;
       oauth.reset();
       universalOAuth.reset();
       setRefinementCount((c) => c + 1);
@@ -211,8 +224,9 @@ export function useCredentialDesignOrchestrator(): CredentialDesignOrchestrator
   );
 
   const handleNegotiatorValues = useCallback(
-    (values: Record<string, string>) => {
+    (values: Record<string, string>, negotiationId: string | null) => {
       setNegotiatorValues(values);
+      negotiationIdRef.current = negotiationId;
       health.invalidate();
     },
     [health],
@@ -226,6 +240,7 @@ export function useCredentialDesignOrchestrator(): CredentialDesignOrchestrator
     setInstruction('');
     setCredentialName('');
     setNegotiatorValues({});
+    negotiationIdRef.current = null;
     setRefinementCount(0);
     lastResultRef.current = null;
     authPrefetchedRef.current = false;
@@ -13,7 +13,8 @@ import { PHASE_VARIANTS, PHASE_TRANSITION } from './negotiatorMotion';
 
 interface NegotiatorPanelProps {
   designResult: CredentialDesignResult;
-  onComplete: (capturedValues: Record<string, string>) => void;
+  /** `negotiationId` identifies the transcript to link to the saved credential. */
+  onComplete: (capturedValues: Record<string, string>, negotiationId: string | null) => void;
   onClose: () => void;
   prefilledValues?: Record<string, string>;
   prefetchedAuthDetections?: AuthDetectionInfo[];
@@ -92,11 +93,11 @@ export function NegotiatorPanel({ designResult, onComplete, onClose, prefilledVa
   };
 
   const handleFinish = () => {
-    onComplete(negotiator.capturedValues);
+    onComplete(negotiator.capturedValues, negotiator.negotiationId);
   };
 
   const handleClose = () => {
-    if (negotiator.phase === 'planning') {
+    if (negotiator.phase === 'planning' || negotiator.phase === 'guiding') {
       negotiator.cancel();
     }
     onClose();
@@ -6,7 +6,10 @@ import {
   startCredentialNegotiation,
   cancelCredentialNegotiation,
   getNegotiationStepHelp,
+  openNegotiationTranscript,
+  recordNegotiationEvent,
 } from '@/api/vault/negotiator';
+import { silentCatch } from '@/lib/silentCatch';
 import { useAiArtifactTask } from '../core/useAiArtifactTask';
 import { EventName } from '@/lib/eventRegistry';
 import { useStepProgress } from '@/hooks/useStepProgress';
@@ -86,14 +89,52 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
   const helpedStepsRef = useRef<Set<number>>(new Set());
   /** Tracks completed steps via ref to avoid stale closure in completeStep callback. */
   const completedStepsRef = useRef<Set<number>>(new Set());
+  /** Backend transcript id; the ref serves callbacks, the state consumers. */
+  const negotiationIdRef = useRef<string | null>(null);
+  const [negotiationId, setNegotiationIdState] = useState<string | null>(null);
+  const setNegotiationId = useCallback((id: string | null) => {
+    negotiationIdRef.current = id;
+    setNegotiationIdState(id);
+  }, []);
+
+  const startNegotiation = useCallback(
+    async (...args: Parameters<typeof startCredentialNegotiation>) => {
+      const res = await startCredentialNegotiation(...args);
+      setNegotiationId(res.negotiation_id);
+      return res;
+    },
+    [setNegotiationId],
+  );
+  const cancelNegotiation = useCallback(
+    () => cancelCredentialNegotiation(negotiationIdRef.current),
+    [],
+  );
+
+  /** Best-effort transcript entry; captured values are never sent, only keys. */
+  const recordEvent = useCallback((
+    kind: 'step_completed' | 'fields_captured',
+    stepIndex: number | null,
+    stepTitle: string | null,
+    fieldKeys: string[],
+  ) => {
+    const id = negotiationIdRef.current;
+    if (!id) return;
+    void recordNegotiationEvent({
+      negotiation_id: id,
+      kind,
+      step_index: stepIndex,
+      step_title: stepTitle,
+      field_keys: fieldKeys,
+    }).catch(silentCatch('useCredentialNegotiator:recordNegotiationEvent'));
+  }, []);
 
   const flow = useAiArtifactTask<[string, Record<string, unknown>, string[], Array<Record<string, unknown>> | undefined], NegotiationPlan>({
     progressEvent: 'credential-negotiation-progress',
     statusEvent: EventName.CREDENTIAL_NEGOTIATION_STATUS,
     runningPhase: 'planning',
     completedPhase: 'guiding',
-    startFn: startCredentialNegotiation,
-    cancelFn: cancelCredentialNegotiation,
+    startFn: startNegotiation,
+    cancelFn: cancelNegotiation,
     errorMessage: 'Failed to generate provisioning plan',
     traceOperation: 'credential_negotiation',
   });
@@ -146,6 +187,7 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
     sp.reset();
     setStepHelp(null);
     setFromPlaybook(false);
+    setNegotiationId(null);
 
     // Lookup-before-generate: check if a successful playbook exists for this service
     const cached = lookupPlaybook(serviceName);
@@ -155,6 +197,9 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
       // Inject the cached plan directly, skipping AI generation
       flow.setResult(cached.plan);
       flow.setPhase('guiding');
+      void openNegotiationTranscript(serviceName, cached.plan)
+        .then(setNegotiationId)
+        .catch(silentCatch('useCredentialNegotiator:openNegotiationTranscript'));
       return;
     }
 
@@ -178,7 +223,7 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
       : undefined;
 
     await flow.start(serviceName, connector as unknown as Record<string, unknown>, fieldKeys, authForBackend);
-  }, [sp, authServices, flow]);
+  }, [sp, authServices, flow, setNegotiationId]);
 
   // completeStep operates on visible indices -- translates to original for refs/playbook
   const completeStep = useCallback((visibleIndex: number) => {
@@ -188,9 +233,17 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
     completedStepsRef.current.add(visibleIndex);
     const completedCount = completedStepsRef.current.size;
 
+    const node = resolved.visible[visibleIndex];
+    if (node) {
+      const filledKeys = Object.keys(node.step.field_fills ?? {})
+        .filter((k) => sp.capturedValues[k]?.trim());
+      recordEvent('step_completed', node.originalIndex, node.step.title, filledKeys);
+    }
+
     // Transition to done when every visible step is marked complete
     if (flow.result && completedCount >= resolved.visible.length) {
       flow.setPhase('done');
+      recordEvent('fields_captured', null, null, Object.keys(sp.capturedValues));
 
       // Record successful playbook for future reuse
       savePlaybook({
@@ -204,7 +257,7 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
         usageCount: 0,
       });
     }
-  }, [sp, flow, resolved.visible.length]);
+  }, [sp, flow, resolved.visible, recordEvent]);
 
   const goToStep = useCallback((visibleIndex: number) => {
     sp.goToStep(visibleIndex);
@@ -230,6 +283,7 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
         originalIndex,
         step.title,
         question,
+        negotiationIdRef.current,
       );
       setStepHelp({ answer: result.answer, stepIndex: visibleIndex });
     } catch (err) {
@@ -248,7 +302,8 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
     sp.reset();
     completedStepsRef.current = new Set();
     setStepHelp(null);
-  }, [flow, sp]);
+    setNegotiationId(null);
+  }, [flow, sp, setNegotiationId]);
 
   return {
     phase: flow.phase as NegotiatorPhase,
@@ -261,6 +316,8 @@ export function useCredentialNegotiator(context?: NegotiatorContext) {
     stepHelp,
     isLoadingHelp,
     fromPlaybook,
+    /** Backend transcript id for this negotiation, once known */
+    negotiationId,
     /** Resolved visible steps (skipped steps removed) */
     visibleSteps: resolved.visible,
     /** Steps that were skipped with reasons */
@@ -16,9 +16,9 @@ export const EN_SECTION_STRINGS = {
   "overview": "{\"filters\":{\"start_date\":\"Start Date\",\"end_date\":\"End Date\",\"all_personas\":\"All Personas\"},\"messages\":{\"priority_high\":\"High\",\"priority_normal\":\"Normal\",\"priority_low\":\"Low\",\"loading\":\"Loading messages...\",\"no_messages\":\"No messages yet\",\"switch_to_all\":\"Try switching to \\\"All\\\" to see all messages\",\"columns\":{\"persona\":\"Persona\",\"title\":\"Title\",\"priority\":\"Priority\",\"status\":\"Status\",\"created\":\"Created\"},\"content_header\":\"Content\",\"filter_all\":\"All\",\"filter_unread\":\"Unread\",\"filter_high_priority\":\"High Priority\",\"delivery_delivered\":\"Delivered\",\"delivery_failed\":\"Failed\",\"delivery_pending\":\"Pending\",\"delivery_queued\":\"Queued\",\"channel_email\":\"Email\",\"channel_slack\":\"Slack\",\"channel_telegram\":\"Telegram\",\"channel_desktop\":\"Desktop\",\"id_label\":\"ID:\",\"confirm_delete_title\":\"Confirm delete\",\"close_message\":\"Close message detail\"},\"incidents\":{\"stale_label\":\"Stale\",\"sort_newest\":\"Newest\",\"sort_oldest\":\"Oldest\",\"a11y_position\":\"{current} of {total}\",\"a11y_acknowledged\":\"Acknowledged\",\"a11y_resolved\":\"Resolved\",\"range_all_time\":\"All time\",\"range_24h\":\"Last 24 hours\",\"range_7d\":\"Last 7 days\",\"preset_fixed\":\"Fixed\",\"preset_transient\":\"Transient — ignored\",\"preset_known_issue\":\"Known issue\",\"preset_not_an_issue\":\"Not an issue\",\"guidance_label\":\"What to do\",\"guidance_tool\":\"Check the tool's connection or credentials, then re-run the agent.\",\"guidance_credential\":\"Re-authenticate or rotate this credential in Connections, then re-run.\",\"guidance_healing\":\"Auto-healing couldn't fix this. Review the agent's recent run and its instructions.\",\"guidance_provider\":\"A backup model provider was used. No action needed unless this happens often.\",\"guidance_policy\":\"A policy blocked this. Adjust the policy in Settings if it should be allowed.\",\"guidance_alert\":\"An alert rule fired. Review the metric that crossed its threshold.\",\"guidance_execution\":\"The agent run failed. Open the execution to see the error, then re-run.\",\"guidance_mcp_sidecar\":\"The agent ran without its built-in MCP tools because the personas-mcp binary was missing. Reinstall or repair the app so it ships alongside the desktop app.\",\"guidance_default\":\"Review the details below, then resolve or dismiss it.\",\"groups_collapse_all\":\"Collapse all\",\"groups_expand_all\":\"Expand all\",\"group_no_persona\":\"No agent\",\"group_by_label\":\"Group by\",\"group_by_agent\":\"Agent\",\"group_by_severity\":\"Severity\",\"group_by_source\":\"Source\",\"group_by_none\":\"None\",\"urgency_critical\":\"Needs attention now\",\"urgency_high\":\"Important\",\"urgency_medium\":\"Worth a look\",\"urgency_low\":\"Minor\",\"detail_raw_toggle\":\"Raw data\",\"title\":\"Incidents\",\"subtitle\":\"Cross-source triage inbox for alerts, tool errors, credential failures, healing misses, and policy drops\",\"kpi_open\":\"Open\",\"kpi_acknowledged\":\"Acked\",\"kpi_resolved\":\"Resolved\",\"kpi_critical\":\"Critical\",\"distribution_aria\":\"Open incidents by severity\",\"filter_status_label\":\"Status\",\"filter_status_all\":\"All\",\"filter_status_open\":\"Open\",\"filter_status_acknowledged\":\"Acked\",\"filter_status_resolved\":\"Resolved\",\"filter_status_dismissed\":\"Dismissed\",\"filter_severity_label\":\"Severity\",\"filter_severity_all\":\"All severities\",\"filter_source_label\":\"Source\",\"filter_source_all\":\"All sources\",\"filter_persona_label\":\"Agent\",\"filter_persona_any\":\"any\",\"col_incident\":\"Incident\",\"col_days\":\"Days\",\"filters_active_aria\":\"{count} active filters\",\"filters_clear_all\":\"Clear filters\",\"action_acknowledge\":\"Ack\",\"action_resolve\":\"Resolve\",\"action_dismiss\":\"Dismiss\",\"action_reopen\":\"Reopen\",\"action_open_detail\":\"Open\",\"action_start_work\":\"Start work\",\"action_back_to_open\":\"Back to open\",\"filter_status_in_progress\":\"In progress\",\"detail_title\":\"Incident detail\",\"detail_label_status\":\"Status\",\"detail_label_kind\":\"Kind\",\"detail_label_source\":\"Source\",\"detail_label_persona\":\"Agent\",\"detail_label_detail\":\"Details\",\"detail_label_resolution_note\":\"Resolution note\",\"detail_label_created\":\"Created\",\"detail_label_acknowledged\":\"Acknowledged\",\"detail_label_resolved\":\"Resolved\",\"detail_no_detail\":\"No additional detail.\",\"detail_note_label\":\"Resolution note (optional)\",\"detail_no_persona\":\"No agent\",\"siblings_label\":\"Other open incidents from this agent\",\"siblings_none\":\"No other open incidents from this agent.\",\"view_all_from_agent\":\"View all from this agent\",\"action_failed_prefix\":\"Couldn't\",\"action_refused\":\"Action not applied — the incident may have already changed status.\",\"bulk_acknowledge_count\":\"Ack {count}\",\"bulk_resolve_count\":\"Resolve {count}\",\"bulk_clear_selection\":\"Clear\",\"empty_open_title\":\"All clear\",\"empty_filtered_title\":\"No matching incidents\",\"empty_state_open\":\"No open incidents — fleet is healthy.\",\"empty_state_filtered\":\"No incidents match the current filters.\",\"loading\":\"Loading incidents…\",\"load_failed\":\"Failed to load incidents\",\"refresh\":\"Refresh\",\"new_since_last_visit\":\"{count} new since your last visit\",\"list_truncated\":\"Showing the first {limit} incidents — narrow the filters to see the rest.\",\"mark_seen\":\"Mark seen\",\"just_now\":\"just now\",\"resolved_at_label\":\"Resolved\",\"acknowledged_at_label\":\"Acked\",\"resolution_note_placeholder\":\"What did you do? (optional)\",\"source_alert\":\"Alert\",\"source_tool\":\"Tool\",\"source_credential\":\"Credential\",\"source_healing_log\":\"Healing log\",\"source_provider\":\"Provider\",\"source_policy\":\"Policy\",\"source_healing_issue\":\"Healing issue\",\"source_execution_error\":\"Execution error\",\"source_mcp_sidecar\":\"MCP toolbelt\"},\"observability\":{\"title\":\"Observability\",\"subtitle\":\"Monitor execution health, costs, and anomalies across your agents\",\"alert_rules\":\"Alert Rules\",\"refresh_metrics\":\"Refresh metrics\",\"metrics_unavailable\":\"Metrics unavailable -- data shown may be stale\",\"error_recovery\":{\"panel_loaded\":\"Loaded\",\"panel_failed\":\"Failed to load\",\"panel_stale\":\"Data may be outdated\"},\"persona_disabled\":\"Agent auto-paused\",\"auto_resolved\":\"This issue was automatically resolved\",\"mark_resolved\":\"Mark as Resolved\",\"manual_fix\":\"(manual fix applied)\",\"no_open_issues\":\"No open issues\",\"run_analysis\":\"Run analysis to check for problems.\"},\"memories\":{\"title\":\"Memories\",\"delete_all\":\"Delete all\",\"delete_all_confirm_title\":\"Delete all memories?\",\"delete_all_confirm_body\":\"This permanently deletes all {count} memories. This cannot be undone.\",\"delete_all_confirm_cta\":\"Delete all\",\"created_success\":\"Memory created successfully\",\"no_memories\":\"No memories yet\",\"no_memories_hint\":\"When agents run, they can store valuable notes and learnings here.\",\"no_memories_match\":\"No memories match current filters\",\"list_aria_label\":\"Memory list\",\"add_memory_btn\":\"Add Memory\",\"search_placeholder\":\"Search memories...\",\"no_filter_match\":\"No memories match your filters. Try adjusting your search.\",\"form\":{\"agent\":\"Agent\",\"category\":\"Category\",\"title\":\"Title\",\"content\":\"Content\",\"importance\":\"Importance\",\"tags\":\"Tags\",\"tags_hint\":\"(comma-separated)\"},\"curation_schedule_button\":\"Schedule\",\"curation_schedule_button_hint\":\"Schedule automatic memory curation\",\"curation_schedule_button_no_persona\":\"Pick an agent to schedule curation\",\"curation_schedule_title\":\"Schedule memory curation\",\"curation_schedule_description\":\"Run a memory review automatically on a recurring schedule. The result lands as a proposal you can apply or discard — your live memories are never touched without your confirmation.\",\"curation_schedule_cron_label\":\"Schedule (cron)\",\"curation_schedule_cron_hint\":\"5-field cron expression: minutes hours day-of-month month day-of-week.\",\"curation_schedule_presets_label\":\"Quick picks\",\"preset_weekly_sunday\":\"Weekly · Sun 3am\",\"preset_daily_3am\":\"Daily · 3am\",\"preset_weekly_monday\":\"Weekly · Mon 9am\",\"preset_monthly\":\"Monthly · 1st\",\"curation_schedule_last_run_label\":\"Last run\",\"curation_schedule_disabled_state\":\"Curation is disabled for this agent\",\"curation_schedule_disable\":\"Disable schedule\",\"curation_schedule_save\":\"Save schedule\",\"reflect\":\"Reflect\",\"reflecting\":\"Reflecting...\",\"reflect_hint\":\"Consolidate related memories into durable insights — the result is a proposal you review before anything changes\",\"reflect_pick_persona\":\"Reflect memories for an agent\"},\"memory_review\":{\"keep_prefix\":\"Keep “\",\"keep_suffix\":\"”\",\"all_conflicts_resolved\":\"All conflicts resolved\"},\"sla\":{\"title\":\"Agent Reliability\",\"subtitle\":\"How well your agents are performing -- success rates, response times, and auto-recovery across all agents\",\"no_data\":\"No execution data available.\",\"success_rate\":\"Success Rate\",\"success_rate_tooltip\":\"Success rate = successful / (successful + failed). Cancelled runs are excluded because they are user-initiated and don't reflect system reliability.\",\"executions_summary\":\"{successful}/{total} executions\",\"avg_latency\":\"Avg Response Time\",\"active_agents\":\"{count} active agents\",\"open_issues\":\"Open Issues\",\"circuit_breakers\":\"{count} auto-paused\",\"auto_healed\":\"Auto-Fixed\",\"known_patterns\":\"{count} known issues\",\"all_time_badge\":\"All-time\",\"open_issues_tooltip\":\"Currently open healing issues across all agents. This is an all-time snapshot and does not change with the time window above -- an issue that opened months ago and is still open is still broken now.\",\"auto_healed_tooltip\":\"Total issues the healing engine has auto-resolved across the lifetime of this install. This number is all-time and does not change with the time window above.\",\"windowed_tooltip\":\"Computed over the selected time window above ({days} days).\",\"daily_success_rate\":\"Daily Success Rate -- {days} Days\",\"per_agent\":\"Per-Agent Reliability\",\"no_agent_data\":\"No agents have executed in this period.\",\"loading\":\"Loading reliability data...\",\"metric_successful\":\"Successful\",\"metric_failed\":\"Failed\",\"metric_avg_latency\":\"Avg Response Time\",\"metric_p95_latency\":\"Slowest 5%\",\"metric_cost\":\"Cost\",\"metric_mtbf\":\"Time Between Failures\",\"metric_auto_healed\":\"Auto-Fixed\",\"metric_cancelled\":\"Cancelled\"},\"knowledge\":{\"title\":\"Knowledge\",\"patterns_tab\":\"Patterns\",\"graph_tab\":\"Graph\",\"search_placeholder\":\"Search patterns...\",\"sort_aria\":\"Sort patterns\",\"sort_default\":\"Top patterns\",\"sort_confidence\":\"Highest confidence\",\"sort_runs\":\"Most runs\",\"sort_recent\":\"Most recent\",\"needs_review\":\"Needs review\",\"pending_scope_hint\":\"Select a persona to review all pending annotations.\",\"subtitle\":\"{count} things learned from past runs\",\"total_patterns\":\"Total Learnings\",\"tool_sequences\":\"Action Sequences\",\"tool_sequences_hint\":\"Learned step-by-step workflows\",\"failure_patterns\":\"Known Issues\",\"failure_patterns_hint\":\"Recognized error types\",\"model_insights\":\"AI Model Insights\",\"model_insights_hint\":\"How each AI model performs\",\"all_personas\":\"All Personas (Global)\",\"all_types\":\"All Types\",\"failure_drilldown\":\"Failure drill-down: {date}\",\"failure_drilldown_hint\":\"Showing failure patterns active on or after this date.\",\"failure_drilldown_empty\":\"No matching patterns found -- try selecting a specific persona above.\",\"unavailable\":\"Knowledge data unavailable\",\"empty\":\"No knowledge patterns yet\",\"empty_hint\":\"Run your agents to start building knowledge. Every run teaches the system about workflows, common issues, and what works best.\",\"recent_learnings\":\"Recent Learnings\",\"successes\":\"Successes\",\"failures\":\"Failures\",\"avg_cost\":\"Avg Cost\",\"avg_duration\":\"Avg Duration\",\"pattern_data\":\"Details\"},\"dashboard\":{\"mission_control_eyebrow\":\"Mission Control\",\"greeting_morning\":\"Good Morning\",\"greeting_afternoon\":\"Good Afternoon\",\"greeting_evening\":\"Good Evening\",\"default_user\":\"Operator\",\"empty_title\":\"Welcome to Personas\",\"empty_subtitle\":\"Create your first agent to start seeing execution metrics, traffic charts, and activity here.\",\"create_persona\":\"Create Persona\",\"from_templates\":\"From Templates\",\"todos_label\":\"Todos\",\"todos_subtitle_open\":\"{count} open\",\"todos_empty\":\"All clear.\",\"scope_fleet\":\"Fleet\",\"scope_fleet_hint\":\"Shows all personas — the persona filter doesn't change this.\",\"customize_label\":\"Customize\",\"customize_title\":\"Show sections\",\"customize_reset\":\"Show all\",\"section_heatmap\":\"Activity heatmap\",\"section_instruments\":\"Instruments\",\"section_memory\":\"Memory suggestions\",\"section_fleet\":\"Fleet optimization\",\"section_routines\":\"Routines & vault changes\",\"triage_jump\":\"Most urgent\",\"triage_detail_pipelines\":\"data sources failing to sync\",\"triage_up_next\":\"Up next\",\"title\":\"Dashboard\",\"subtitle\":\"Operational overview and system status\",\"pending_reviews_prompt\":\"pending reviews\",\"requiring_attention\":\"requiring attention.\",\"empty_cta\":\"Create your first agent to get started.\",\"pipeline_failed\":\"{source} failed to load\",\"pane_vitals\":\"Vitals\",\"pane_instruments\":\"Instruments\",\"pane_stream\":\"Stream\",\"vitals_subtitle_fleet\":\"fleet health\",\"instruments_subtitle\":\"fleet telemetry\",\"stream_events_count\":\"{count} events\",\"success_label\":\"success\",\"status_label\":\"status\",\"status_sources\":\"sources\",\"status_errors\":\"errors\",\"status_runs\":\"runs\",\"status_synced\":\"synced\",\"traffic_label\":\"Traffic\",\"spark_traffic_errors\":\"traffic · errors\",\"triage_tag_alert\":\"ALT\",\"triage_tag_pipeline\":\"SYS\",\"triage_tag_review\":\"REV\",\"triage_tag_message\":\"MSG\",\"triage_detail_alerts\":\"active health alerts\",\"triage_detail_reviews\":\"manual review queue\",\"triage_detail_messages\":\"unread in inbox\",\"tile_runs\":\"Runs\",\"tile_agents\":\"Agents\",\"tile_alerts\":\"Alerts\",\"tile_reviews\":\"Reviews\",\"pane_memory\":\"Memory\",\"memory_suggestions_count\":\"{count} suggestions\",\"stream_agent_fallback\":\"agent\"},\"provenance\":{\"sourced_tip\":\"Cites {count} source(s) you can trace and audit.\",\"unsourced\":\"Unsourced\",\"unsourced_tip\":\"This report includes figures but no Sources section — they can't be traced back to their origin.\"},\"review\":{\"title\":\"Manual Reviews\",\"subtitle\":\"{count} reviews\",\"pending_count\":\"{count} pending\",\"cloud_count\":\"{count} cloud\",\"mock_review\":\"Mock Review\",\"seed_tooltip\":\"Seed a mock review (dev only)\",\"split\":\"Split\",\"split_tooltip\":\"Split view — list with a detail pane\",\"table\":\"Table\",\"table_tooltip\":\"Table view — all reviews in a grid\",\"empty_title\":\"No review items yet\",\"empty_subtitle\":\"Items requiring approval will appear here when agents request human review.\",\"filter_all\":\"All\",\"filter_pending\":\"Pending\",\"filter_approved\":\"Approved\",\"filter_rejected\":\"Rejected\",\"source_all\":\"All\",\"source_local\":\"Local\",\"source_cloud\":\"Cloud\",\"approve\":\"Approve\",\"reject\":\"Reject\",\"approve_all\":\"Approve All\",\"reject_all\":\"Reject All\",\"accept\":\"Accept\",\"accept_all\":\"Accept all\",\"reject_all_items\":\"Reject all\",\"processing\":\"Processing...\",\"deselect\":\"Deselect\",\"select_all\":\"Select all\",\"confirm_bulk\":\"{count} reviews?\",\"confirm_bulk_one\":\"{count} review?\",\"pending_selected\":\"pending reviews selected\",\"pending_selected_one\":\"pending review selected\",\"unknown_persona\":\"Unknown Persona\",\"severity_label\":\"severity\",\"cloud_badge\":\"Cloud\",\"auto_resolved\":{\"badge\":\"Auto-resolved\",\"tip_trust_llm\":\"Approved automatically by this capability's trust_llm review policy — it skipped your review queue without a human decision.\",\"tip_auto_triage\":\"Decided by an automatic second-pass evaluator (auto_triage review policy) — it skipped your review queue without a human decision.\",\"banner_trust_llm\":\"This review was auto-approved by the capability's trust_llm policy and never waited for you. Change the capability's review policy if you want to decide these yourself.\",\"banner_auto_triage\":\"This review was resolved automatically by a second-pass auto_triage evaluator, not by you. Change the capability's review policy to require your approval.\",\"reasoning_label\":\"Auto-triage reasoning\"},\"backlog_group_title\":\"Dev Tools backlog\",\"backlog_accept\":\"Accept\",\"backlog_reject\":\"Reject\",\"execution_link\":\"Execution\",\"context_label\":\"Context\",\"you\":\"You\",\"agent\":\"Agent\",\"review_status\":\"Review {status}\",\"decisions_label\":\"Decisions\",\"decisions_count\":\"({count} items)\",\"accepted_label\":\"accepted\",\"rejected_label\":\"rejected\",\"undecided_label\":\"undecided\",\"reply_placeholder\":\"Reply to this review...\",\"cloud_reply_placeholder\":\"Response message (optional)...\",\"cloud_action_hint\":\"Approve or reject this cloud review\",\"reply_hint\":\"Enter to send -- Shift+Enter for new line\",\"send_message\":\"Send message\",\"approve_with_count\":\"Approve ({accepted}/{total})\",\"all_caught_up\":\"All caught up! No pending reviews.\",\"queue_label\":\"Queue\",\"select_action\":\"Select an action\",\"required\":\"(required)\",\"add_notes\":\"Add notes\",\"notes_placeholder\":\"Add review notes...\",\"select_action_first\":\"Select a suggested action first\",\"review_detail\":\"Review Detail\",\"select_review\":\"Select a review to view\",\"technical_context\":\"Technical Context\",\"delete_all\":\"Delete all\",\"delete_all_confirm_title\":\"Delete all reviews?\",\"delete_all_confirm_body\":\"This permanently deletes all {count} reviews. This cannot be undone.\",\"delete_all_confirm_cta\":\"Delete all\"},\"messages_view\":{\"title\":\"Messages\",\"messages_subtitle\":\"{count} messages recorded\",\"messages_subtitle_one\":\"{count} message recorded\",\"mock_message\":\"Mock Message\",\"seed_tooltip\":\"Seed a mock message (dev only)\",\"mark_all_read\":\"Mark All Read\",\"no_messages\":\"No messages yet\",\"no_messages_hint\":\"Messages are created when agents run and communicate with each other.\",\"no_filter_match\":\"No messages match current filters\",\"load_more\":\"Load More ({count} remaining)\",\"col_title\":\"Title\",\"col_created\":\"Created\",\"all_priorities\":\"All priorities\",\"all_statuses\":\"All statuses\",\"read\":\"Read\",\"unread\":\"Unread\",\"show_read_messages\":\"Show read\",\"show_only_unread\":\"Hide read\",\"message_label\":\"Message\",\"from_label\":\"From {name}\",\"content_label\":\"Content\",\"view_execution\":\"View Execution\",\"delivery_status\":\"Delivery Status\",\"no_channels\":\"No delivery channels configured\",\"improve_agent\":\"Improve Agent\",\"improvement_started\":\"Improvement started -- you'll be notified when done\",\"what_could_be_better\":\"What could be better?\",\"improve_placeholder\":\"Describe how this output could be improved...\",\"submit_improvement\":\"Submit Improvement\",\"starting\":\"Starting...\",\"unknown_persona\":\"Unknown\",\"rating_label\":\"Rate this message\",\"rating_star_aria\":\"Rate {value} of 5\",\"rating_saved\":\"Saved · {stars}/5\",\"rating_memory_title\":\"Message rating: {stars}/5\",\"rating_memory_content_good\":\"User rated the output {stars}/5 stars. This message met expectations — keep the approach.\",\"rating_memory_content_neutral\":\"User rated the output {stars}/5 stars. Neutral feedback — review the message for marginal improvements.\",\"rating_memory_content_poor\":\"User rated the output {stars}/5 stars. Low score — review the prompt or approach before repeating this pattern.\",\"section_pending_decisions\":\"Pending decisions\",\"pending_decisions_empty\":\"No human-review tasks linked to this execution.\",\"pending_decisions_approve\":\"Approve\",\"pending_decisions_reject\":\"Reject\",\"pending_decisions_view_all\":\"Open in Approvals\",\"action_export_pdf\":\"Export to PDF\",\"action_play_in_chat\":\"Play in chat\",\"persona_link_title\":\"View persona\",\"pending_decisions_approve_all\":\"Approve all\",\"pending_decisions_reject_all\":\"Reject all\",\"delete_all\":\"Delete all\",\"delete_all_confirm_title\":\"Delete all messages?\",\"delete_all_confirm_body\":\"This permanently deletes all {count} messages. This cannot be undone.\",\"delete_all_confirm_cta\":\"Delete all\",\"threads_subtitle\":\"{count} threads\",\"threads_subtitle_one\":\"{count} thread\",\"flat_view\":\"Flat view\",\"threaded_view\":\"Threaded view\",\"threads_of\":\"{count} of {total} threads\",\"no_threads\":\"No message threads yet\",\"no_threads_hint\":\"Threads are created automatically when agents produce messages during executions.\",\"loading_replies\":\"Loading replies...\",\"col_priority\":\"Priority\",\"col_delivery\":\"Delivery\",\"col_status\":\"Status\",\"new_badge\":\"New\",\"failed_count\":\"{count} failed\",\"pending_count\":\"{count} pending\",\"sent_count\":\"{count} sent\",\"type_label\":\"Type:\",\"confirm_delete\":\"Confirm delete\",\"rating_clear\":\"Clear rating\"},\"cockpit\":{\"title_default\":\"Cockpit\",\"subtitle_contextual\":\"Showing context for a single message — chat with Athena alongside.\",\"subtitle_explaining\":\"Athena composed this explanation for you — exit it to return to your own cockpit.\",\"context_for\":\"Showing context for {title}\",\"context_exit\":\"Exit context\",\"context_explaining\":\"Athena is explaining: {title}\",\"widget_empty\":\"Nothing to show yet.\",\"verdict_title\":\"Athena's verdict\",\"verdict_confidence\":\"Confidence\",\"verdict_confidence_high\":\"High\",\"verdict_confidence_medium\":\"Medium\",\"verdict_confidence_low\":\"Low\",\"verdict_resolve\":\"Resolve now\",\"comparison_recommended\":\"Recommended\",\"flow_title\":\"How it unfolds\",\"timeline_title\":\"Timeline\",\"log_title\":\"Evidence\",\"message_summary_title\":\"Message\",\"message_unavailable\":\"Message unavailable\",\"message_empty\":\"(empty body)\",\"open_in_messages\":\"Open in Messages\",\"execution_facts_title\":\"Execution facts\",\"fact_model\":\"Model\",\"fact_cost\":\"Cost\",\"fact_duration\":\"Duration\",\"fact_tokens\":\"Tokens (in/out)\",\"fact_status\":\"Status\",\"fact_outcome\":\"Outcome\",\"linked_decisions_title\":\"Pending decisions\",\"linked_decisions_empty\":\"No human-review tasks for this execution.\",\"linked_decisions_approve\":\"Approve\",\"linked_decisions_reject\":\"Reject\",\"linked_memories_title\":\"Linked memories\",\"linked_memories_empty\":\"No memories recorded for this execution.\",\"compose_personas_prompt\":\"Compose a cockpit with an overview of all my personas — show their status, recent activity, and anything that needs my attention.\",\"subtitle_default\":\"Your companion-driven workspace\",\"subtitle_composed_prefix\":\"Composed by Athena — updated\",\"talk_to_athena\":\"Talk to Athena\",\"error_title\":\"Couldn't load your cockpit\",\"error_retry\":\"Retry\",\"empty_title\":\"Your cockpit is empty\",\"empty_hint\":\"Ask Athena to compose a cockpit view — try {personas} or {attention}.\",\"empty_example_personas\":\"\\\"show me my personas\\\"\",\"empty_example_attention\":\"\\\"what needs my attention\\\"\",\"unknown_widget\":\"Unknown widget: {kind}\",\"default_title\":\"Starter cockpit\",\"default_subtitle\":\"A starter view Athena can personalize — chat to compose your own.\",\"default_callout_title\":\"Starter cockpit\",\"default_callout_body\":\"This is a starter view built from your fleet state. Ask Athena to compose a cockpit tailored to what you care about — she'll replace this automatically.\",\"default_vitals_title\":\"Fleet vitals\",\"default_roster_title\":\"Your personas\",\"default_attention_title\":\"Needs attention\",\"default_attention_empty\":\"All personas healthy — nothing needs attention.\",\"default_stat_active_personas\":\"Active personas\",\"default_stat_success_rate\":\"Success rate\",\"default_stat_executions\":\"Executions (7d)\",\"default_stat_needs_attention\":\"Needs attention\",\"default_attention_setup\":\"Setup required\",\"default_attention_paused\":\"Paused\",\"default_attention_low_trust\":\"Low trust\"},\"events\":{\"title\":\"Events\",\"subtitle\":\"{filtered} of {total} events\",\"subtitle_one\":\"{filtered} of {total} event\",\"mock_event\":\"Mock Event\",\"seed_tooltip\":\"Seed a mock event (dev only)\",\"search_placeholder\":\"Search events by type, source, or payload...\",\"loading_older\":\"Loading older events...\",\"no_events\":\"No events yet\",\"no_events_hint\":\"Events from webhooks, executions, and persona actions will appear here as your agents run.\",\"no_filter_match\":\"No events match current filters\",\"save_view\":\"Save view\",\"view_name_placeholder\":\"View name (e.g. 'Failed webhooks this week')\",\"views_label\":\"Views:\",\"delete_view\":\"Delete view\",\"all_statuses\":\"All statuses\",\"all_types\":\"All types\",\"all_triggers\":\"All triggers\",\"source_event\":\"Event\",\"source_manual\":\"Manual\",\"source_system\":\"System\",\"source_scheduled\":\"Scheduled\",\"event_detail_title\":\"Event:\",\"event_detail_status\":\"Status:\",\"type_labels\":{\"sla_breach_opened\":\"SLA breach opened\",\"sla_breach_recovered\":\"SLA breach recovered\",\"schedule_missed_offline\":\"Missed schedule runs (offline)\",\"schedule_skipped_overlap\":\"Schedule skipped (previous run active)\"},\"load_older\":\"Load older events\",\"clear_filters\":\"Clear all filters\",\"col_trigger\":\"Trigger\",\"col_persona\":\"Persona\",\"col_event_name\":\"Event Name\",\"col_status\":\"Status\",\"col_created\":\"Created\",\"event_id\":\"Event ID\",\"project\":\"Project\",\"source\":\"Source\",\"processed\":\"Processed\",\"event_data\":\"Event Data\",\"error\":\"Error\",\"skipped_stat_label\":\"{skipped} skipped\",\"skipped_stat_tooltip\":\"{skipped} of {total} events in the last 7 days had no subscriber and were skipped ({rate}). A high rate signals a trigger nothing is listening to.\"},\"health\":{\"title\":\"Agent Health\",\"subtitle\":\"Real-time health monitoring across all agents\",\"status_page\":\"Status Page\",\"all_healthy\":\"All agents healthy\",\"all_healthy_hint\":\"Every monitored agent is operating normally\",\"no_agents\":\"No agents yet\",\"no_agents_hint\":\"Create agents to start monitoring their health\",\"success_rate\":\"Success Rate\",\"avg_latency\":\"Avg Latency\",\"executions\":\"Executions\",\"last_execution\":\"Last execution\",\"never_executed\":\"Never\",\"cost\":\"Cost\",\"healthy\":\"Healthy\",\"warning\":\"Warning\",\"critical\":\"Critical\",\"unknown\":\"Unknown\",\"burn_rate\":\"Burn Rate Projection\",\"monthly_budget\":\"Monthly Budget\",\"current_burn\":\"Current Burn\",\"projected\":\"Projected\",\"days_remaining\":\"Days Remaining\",\"cascade_title\":\"Cascade Analysis\",\"predictive_alerts\":\"Predictive Alerts\",\"no_alerts\":\"No predictive alerts at this time\",\"operational\":\"Operational\",\"degraded\":\"Degraded\",\"outage\":\"Outage\"},\"certification\":{\"title\":\"Certification Command Center\",\"subtitle\":\"Team-autonomy evaluation runs and certification status (dev-only)\",\"updated\":\"Updated\",\"refresh\":\"Refresh\",\"error_title\":\"Could not load evaluation runs\",\"loading\":\"Loading evaluation runs…\",\"loading_detail\":\"Loading run detail…\",\"tab_overview\":\"Overview\",\"tab_history\":\"Run History\",\"back\":\"Back\",\"empty_title\":\"No evaluation runs\",\"empty_desc\":\"Run the eval harness (scripts/test/) to populate docs/test/runs/.\",\"teams_certified\":\"teams certified\",\"certified\":\"Certified\",\"streak_label\":\"streak\",\"held_out_runs\":\"held-out runs\",\"provisional\":\"Provisional verdict (judge not yet scored)\",\"final\":\"Final verdict\",\"team_score\":\"Team score\",\"cost\":\"Cost\",\"dims_title\":\"Deterministic dimensions\",\"dim_cascade\":\"Cascade completion\",\"dim_density\":\"Work density\",\"dim_handoff\":\"Handoff health\",\"dim_learning\":\"Learning loop\",\"dim_grounding\":\"Grounding\",\"dim_correctness\":\"Correctness\",\"dim_actionability\":\"Actionability\",\"dim_specificity\":\"Specificity\",\"dim_role_fidelity\":\"Role fidelity\",\"gates_title\":\"Build / lint / test gates\",\"gate_build\":\"Build\",\"gate_lint\":\"Lint\",\"gate_test\":\"Test\",\"gate_pass\":\"pass\",\"gate_fail\":\"fail\",\"gate_log\":\"log\",\"gate_copy_log\":\"Copy log\",\"gate_code_track\":\"Code-track run\",\"delivered\":\"Delivered to master\",\"cascade_stalled\":\"Cascade stalled\",\"self_vetoed\":\"Self-vetoed\",\"self_veto_capped\":\"Team self-capped verdict to\",\"grounding_title\":\"Citation grounding\",\"grounding_file\":\"File\",\"grounding_valid\":\"Valid\",\"grounding_pct\":\"%\",\"grounding_invalid\":\"Invalid\",\"grounding_na\":\"No citations to check\",\"standards_title\":\"Standards & branching compliance\",\"standards_subtitle\":\"How well the run honored the project's declared pre-commit + branching policy\",\"standards_compliance\":\"Compliance\",\"standards_rule\":\"Rule\",\"standards_basis\":\"Basis\",\"standards_na\":\"n/a\",\"trajectory_title\":\"Score trajectory\",\"judge_title\":\"Judge panel\",\"judge_mean\":\"Mean\",\"judge_min\":\"Min output\",\"judge_balance\":\"Balance\",\"fact_executions\":\"Completed\",\"fact_failed\":\"Failed\",\"fact_members\":\"Members\",\"fact_reviews\":\"Reviews\",\"fact_memories\":\"Memories\",\"fact_cost\":\"Cost\",\"fact_window\":\"Window\",\"col_team\":\"Team\",\"col_seed\":\"Seed\",\"col_verdict\":\"Verdict\",\"col_score\":\"Score\",\"col_gates\":\"Gates\",\"col_started\":\"Started\"},\"leaderboard\":{\"title\":\"Agent Leaderboard\",\"subtitle\":\"Performance rankings across all agents\",\"no_data\":\"No leaderboard data available\",\"no_data_hint\":\"Run your agents to start building performance rankings\",\"fleet_avg\":\"Fleet avg:\",\"refresh_label\":\"Refresh leaderboard\",\"computing_scores\":\"Computing agent scores...\",\"single_agent_has_data\":\"has data.\",\"add_more_agents\":\"Add more agents to see rankings. Currently only {name}\",\"open_agent\":\"Open Agent\",\"no_agent_data_title\":\"No agent data yet\",\"no_agent_data_hint\":\"Run some agents to see performance rankings. The leaderboard needs execution history and health data to compute scores.\",\"reliability\":\"Reliability\",\"speed\":\"Speed\",\"efficiency\":\"Efficiency\",\"cost_effectiveness\":\"Cost Effectiveness\",\"overall\":\"Overall\",\"rank_by\":\"Rank by\",\"dim_overall\":\"Overall\",\"dim_success\":\"Success\",\"dim_health\":\"Health\",\"dim_speed\":\"Speed\",\"dim_cost\":\"Cost\",\"dim_activity\":\"Activity\",\"stat_total_runs\":\"Total runs\",\"stat_recent_7d\":\"Recent (7d)\",\"stat_avg_latency\":\"Avg latency\",\"stat_daily_burn\":\"Daily burn\",\"fleet_average\":\"Fleet average\",\"this_agent\":\"This agent\",\"biggest_opportunity\":\"Biggest opportunity\",\"pts_to_gain\":\"pts to gain\",\"hint_success\":\"Cut failures — review recent errors and add retries or guardrails.\",\"hint_health\":\"Clear healing issues and rollbacks to lift the health score.\",\"hint_speed\":\"Trim latency with leaner prompts or a faster model.\",\"hint_cost\":\"Lower cost per run with a cheaper model or shorter context.\",\"hint_activity\":\"Trigger or schedule this agent more often to raise activity.\",\"improve_agent\":\"Improve this agent\"},\"observability_extra\":{\"auto_refresh_on\":\"Auto-refresh on\",\"auto_refresh_off\":\"Auto-refresh off\",\"total_cost\":\"Total Cost\",\"executions_label\":\"Executions\",\"success_rate\":\"Success Rate\",\"active_personas\":\"Active Personas\",\"system_trace\":\"System Trace Timeline\",\"ipc_performance\":\"IPC Performance\",\"alert_rules_label\":\"Alert rules\",\"alert_history_label\":\"Alert history\",\"healing_issues\":\"Health Issues\",\"run_analysis\":\"Run analysis\",\"resolve\":\"Resolve\",\"healing_view_list\":\"List\",\"healing_view_timeline\":\"Timeline\",\"issue_details\":\"Issue Details\",\"anomaly_drilldown\":\"Anomaly Drill-Down\",\"spend_overview\":\"Spend Overview\",\"cost_vs_executions\":\"Cost vs Executions\",\"daily_executions\":\"Daily Executions\",\"ipc_channel\":\"IPC Channel\",\"avg_duration\":\"Avg Duration\",\"call_count\":\"Call Count\",\"error_rate\":\"Error Rate\"},\"widgets\":{\"view_all\":\"View all\",\"traffic_errors_chart\":\"Traffic & Errors\",\"tool_performance\":\"Tool Performance\",\"tool_performance_subtitle\":\"Latency & error rate per tool\",\"tool_performance_col_tool\":\"Tool\",\"tool_performance_col_runs\":\"Runs\",\"tool_performance_col_avg\":\"Avg\",\"tool_performance_col_max\":\"Max\",\"tool_performance_col_errors\":\"Errors\",\"tool_performance_unit_ms\":\"ms\",\"top_performers\":\"Top Performers\",\"messages_badge\":\"Messages\",\"reviews_badge\":\"Reviews\",\"alerts_badge\":\"Alerts\",\"pipelines_badge\":\"Pipelines\",\"recent_activity\":\"Recent Activity\",\"total_traffic\":\"Total Traffic\",\"total_errors\":\"Total Errors\",\"tool_performance_empty\":\"No tool runs in this window\",\"metric_help\":\"Metric Help\",\"how_calculated\":\"How is this calculated?\",\"details\":\"Details\",\"executions_badge\":\"Executions\",\"success_badge\":\"Success\",\"agents_badge\":\"Agents\",\"traffic_errors_chart_aria\":\"Traffic and errors chart\",\"range_today\":\"Today\",\"range_yesterday\":\"Yesterday\",\"range_days_ago\":\"{days} Days Ago\"},\"llm_spend\":{\"section_title\":\"Headless LLM Spend\",\"section_hint\":\"Scanners, evaluators & generators\",\"no_activity\":\"No headless LLM calls recorded in this window\",\"total_cost\":\"Total cost\",\"calls\":\"Calls\",\"tokens\":\"Tokens\",\"errors\":\"Errors\",\"by_source\":\"Cost by tier\",\"by_trigger\":\"Cost by task\",\"cost_per_day\":\"Cost per day\",\"source_scanner\":\"Scanners\",\"source_evaluator\":\"Evaluators\",\"source_design\":\"Design\",\"source_kpi\":\"KPI\",\"source_recipe\":\"Recipes\"},\"athena\":{\"section_title\":\"Athena\",\"section_hint\":\"Your assistant's own usage\",\"turns\":\"Turns\",\"total_cost\":\"Athena cost\",\"avg_cost\":\"Avg / turn\",\"tokens\":\"Tokens\",\"tokens_io\":\"{input} in · {output} out\",\"vs_fleet\":\"Athena vs fleet\",\"vs_fleet_hint\":\"{athena} of {total} total spend this window\",\"cost_by_action\":\"Cost by action type\",\"cost_per_day\":\"Athena cost per day\",\"no_activity\":\"No Athena activity in this window\",\"origin_chat\":\"Chat\",\"origin_autonomous\":\"Autonomous\",\"origin_proactive\":\"Proactive\",\"origin_external\":\"External\",\"origin_headless\":\"Background\",\"leg_exec_triage\":\"Execution triage\",\"leg_msg_triage\":\"Message triage\",\"leg_reaction\":\"Channel reaction\",\"leg_reaction_batch\":\"Channel reactions\",\"leg_review_resolution\":\"Review resolution\",\"leg_fleet_analysis\":\"Fleet analysis\",\"leg_daily_brief\":\"Daily brief\",\"leg_browser_test\":\"Browser test\",\"leg_execution_review\":\"Execution review\",\"leg_other\":\"Other\",\"health_title\":\"Athena health\",\"health_hint\":\"How the assistant is doing\",\"health_no_activity\":\"No Athena activity to report in this window\",\"triage_title\":\"Triage funnel\",\"triage_passes\":\"Passes\",\"triage_drop\":\"Dropped\",\"triage_digest\":\"Digested\",\"triage_attention\":\"Attention\",\"triage_deep_dive\":\"Deep dive\",\"triage_parse_failures\":\"Parse failures\",\"proactive_title\":\"Proactive economy\",\"proactive_delivered\":\"Delivered\",\"proactive_engaged\":\"Engaged\",\"proactive_dismissed\":\"Dismissed\",\"proactive_expired\":\"Expired\",\"proactive_engaged_rate\":\"Engaged rate\",\"proactive_budget\":\"Budget today\",\"jobs_title\":\"Background jobs\",\"jobs_completed\":\"Completed\",\"jobs_failed\":\"Failed\",\"errors\":\"Turn errors\"},\"activity\":{\"title\":\"Activity\",\"recorded\":\"{count} executions recorded\",\"recorded_one\":\"{count} execution recorded\",\"list\":\"List\",\"metrics\":\"Metrics\",\"show_list\":\"Show execution list\",\"show_metrics\":\"Show metrics dashboard\",\"execution_metrics\":\"Execution Metrics\",\"total_executions\":\"Total Executions\",\"total_cost\":\"Total Cost\",\"success_rate\":\"Success Rate\",\"avg_latency\":\"Avg Latency\",\"cost_anomalies\":\"Cost Anomalies Detected\",\"value_section_title\":\"Business value\",\"value_delivered_rate\":\"Value-delivered rate\",\"cost_per_value\":\"Cost / value run\",\"value_delivered_count\":\"Value delivered\",\"error_category_section_title\":\"Failures by category\",\"error_category_total_failures\":\"Total failures\",\"error_category_prior_period\":\"vs. prior period\",\"error_category_by_persona\":\"Top failure by agent\",\"no_data\":\"No execution data for the selected period\",\"cost_per_day\":\"Cost per Day\",\"executions_by_status\":\"Executions by Status\",\"success_rate_trend\":\"Success Rate Trend\",\"latency_distribution\":\"Latency Distribution (p50 / p95 / p99)\",\"top_personas_by_cost\":\"Top Personas by Cost\",\"executions_label\":\"{count} executions\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"prev_completed\":\"Prev Completed\",\"prev_failed\":\"Prev Failed\",\"success_pct\":\"Success %\",\"prev_success_pct\":\"Prev Success %\",\"all_models\":\"All models\",\"showing\":\"Showing {count} of {total}\",\"load_more\":\"Load More\",\"col_model\":\"Model\",\"col_cost\":\"Cost\",\"col_duration\":\"Duration\",\"col_started\":\"Started\",\"no_agents\":\"No agents created yet\",\"no_agents_hint\":\"Create your first persona to see execution activity here.\",\"no_executions\":\"No executions yet\",\"no_executions_hint\":\"Run an agent to see execution activity here.\",\"create_persona\":\"Create Persona\",\"from_templates\":\"From Templates\",\"unknown\":\"Unknown\",\"successful\":\"Successful\",\"all\":\"All\",\"running\":\"Running\",\"all_statuses\":\"All statuses\",\"col_status\":\"Status\",\"col_id\":\"ID\",\"execution_label\":\"Execution\"},\"analytics_dashboard\":{\"issues_found\":\"{count} issues found\",\"issues_found_one\":\"{count} issue found\",\"auto_fixed\":\"auto-fixed\",\"executions_scanned\":\"{count} executions scanned\",\"executions_scanned_one\":\"{count} execution scanned\",\"credential_rotation\":\"Credential Rotation\",\"no_rotation_policies\":\"No rotation policies\",\"no_rotation_hint\":\"Configure rotation on credentials in the Vault.\",\"active_count\":\"{count} active\",\"soon_count\":\"{count} soon\",\"issues_count\":\"{count} issues\",\"issues_count_one\":\"{count} issue\",\"fail_count\":\"{count}x fail\",\"rotation_manage_all\":\"Manage\",\"rotation_open_in_connections\":\"Open in Connections\",\"rotation_started_for\":\"Rotation started for {name}\",\"rotation_failed_for\":\"Failed to rotate {name}\",\"rotation_due_now\":\"due now\",\"rotation_countdown_days_hours\":\"{days}d {hours}h\",\"rotation_countdown_hours\":\"{hours}h\",\"title\":\"Analytics\",\"subtitle\":\"Unified cost, execution, and tool usage analytics\",\"metrics_unavailable\":\"Metrics unavailable\",\"auto_refresh_on\":\"Auto-refresh ON (30s)\",\"auto_refresh_off\":\"Auto-refresh OFF\",\"cost_anomalies_detected\":\"{count} cost anomalies detected\",\"cost_anomaly_detected\":\"{count} cost anomaly detected\",\"cost_over_time\":\"Cost Over Time\",\"execution_health\":\"Execution Health\",\"tool_usage_over_time\":\"Tool Usage Over Time\",\"executions_by_persona\":\"Executions by Persona\",\"latency_chart\":\"Latency (p50 / p95 / p99)\",\"tool_invocations\":\"Tool Invocations\",\"no_execution_data\":\"No execution data\",\"prev_cost\":\"Prev Cost\",\"successful\":\"Successful\",\"failed\":\"Failed\",\"prev_successful\":\"Prev Successful\",\"prev_failed\":\"Prev Failed\",\"invocations\":\"Invocations\",\"anomaly_click_hint\":\"Click a diamond marker on the chart to investigate\",\"saved_views\":\"Saved Views\",\"save_current_view\":\"Save Current View\",\"smart_presets\":\"Smart Presets\",\"your_saved_views\":\"Your Saved Views\",\"view_name_placeholder\":\"View name...\",\"save_view\":\"Save view\",\"delete_view\":\"Delete view\",\"this_week_vs_last\":\"This Week vs Last Week\",\"this_month_vs_last\":\"This Month vs Last Month\",\"health_issues\":\"Health Issues\",\"run_analysis\":\"Run Analysis\",\"analyzing\":\"Analyzing...\",\"analysis_complete\":\"Analysis complete\",\"no_open_issues\":\"No open issues\",\"run_analysis_hint\":\"Run analysis to check for problems.\",\"filter_all\":\"All\",\"filter_open\":\"Open\",\"filter_auto_fixed\":\"Auto-fixed\",\"resolve\":\"Resolve\",\"breaker\":\"breaker\",\"fixed\":\"fixed\",\"retry\":\"retry\",\"never\":\"never\"},\"cron\":{\"title\":\"Cron Agents\",\"subtitle\":\"Background agents running on scheduled intervals\",\"mock_schedule\":\"Mock Schedule\",\"seed_tooltip\":\"Seed a mock schedule (dev only)\",\"scheduled_count\":\"{count} scheduled\",\"headless_count\":\"{count} headless\",\"loading\":\"Loading cron agents...\",\"no_agents\":\"No scheduled agents found.\",\"no_agents_hint\":\"Create a schedule trigger on any agent to see it here.\",\"headless_section\":\"Headless Background Agents\",\"interactive_section\":\"Interactive Scheduled Agents\",\"scheduled_section\":\"Scheduled Agents\",\"headless_badge\":\"headless\",\"no_schedule\":\"no schedule\",\"every_interval\":\"every {interval}\",\"next\":\"next\",\"last\":\"last\"},\"sla_card\":{\"successful\":\"Successful\",\"failed\":\"Failed\",\"avg_latency\":\"Avg Latency\",\"p95_latency\":\"P95 Latency\",\"cost\":\"Cost\",\"mtbf\":\"MTBF\",\"auto_healed\":\"Auto-Healed\",\"cancelled\":\"Cancelled\",\"failing\":\"{count} failing\",\"healed\":\"{count} healed\"},\"realtime_page\":{\"title\":\"Event Bus Monitor\",\"live_subtitle\":\"Live visualization of event flows and persona interactions\",\"replay_subtitle_1d\":\"Replaying last 24 hours at {speed}x speed\",\"replay_subtitle_7d\":\"Replaying last 7 days at {speed}x speed\",\"paused\":\"Paused\",\"live\":\"Live\",\"offline\":\"Offline\",\"connection_paused\":\"Connection status: Paused\",\"connection_live\":\"Connection status: Live\",\"connection_offline\":\"Connection status: Disconnected\",\"events_per_min\":\"events/min\",\"pending\":\"pending\",\"success\":\"success\",\"in_window\":\"in window\",\"search_events\":\"Search events...\",\"filter_type\":\"Type\",\"filter_status\":\"Status\",\"filter_source\":\"Source\",\"filter_agent\":\"Agent\",\"clear\":\"Clear\",\"views\":\"Views\",\"no_saved_views\":\"No saved views yet\",\"save_current_filter\":\"Save current filter\",\"view_name_placeholder\":\"View name...\",\"close_event_details\":\"Close event details\",\"reset_to_start\":\"Reset to start\",\"cycle_speed\":\"Cycle playback speed\",\"exit_replay\":\"Exit replay\",\"test_flow\":\"Test Flow\",\"testing_flow\":\"Testing flow...\",\"test_event_flow\":\"Test event flow\",\"resume\":\"Resume\",\"pause\":\"Pause\",\"resume_stream\":\"Resume realtime stream\",\"pause_stream\":\"Pause realtime stream\",\"delete_saved_view\":\"Delete saved view\",\"event_log\":\"Event Log\",\"entries\":\"{count} entries\",\"filter_events\":\"Filter events...\",\"no_events\":\"No events yet\",\"open_in_drawer\":\"Open in detail drawer\",\"event_label\":\"Event\",\"status_label\":\"Status\",\"source_label\":\"Source\",\"target_label\":\"Target\",\"id_label\":\"ID\",\"error_label\":\"Error\",\"payload_label\":\"Payload\",\"galaxy\":\"Galaxy\",\"galaxy_desc\":\"Orbital constellation with comet trails\",\"lanes\":\"Lanes\",\"lanes_desc\":\"Horizontal swim-lane flow diagram\"},\"memory_form\":{\"agent\":\"Agent\",\"category\":\"Category\",\"title\":\"Title\",\"title_placeholder\":\"e.g. Always use metric units\",\"content\":\"Content\",\"content_placeholder\":\"Describe what the agent should remember...\",\"importance\":\"Importance\",\"tags\":\"Tags\",\"tags_hint\":\"(comma-separated)\",\"tags_placeholder\":\"e.g. units, formatting, output\",\"save_memory\":\"Save Memory\",\"saving\":\"Saving...\",\"created_success\":\"Memory created successfully\",\"fill_required\":\"Fill in all required fields to save\",\"saving_memory\":\"Saving memory...\",\"share_with_group\":\"Share with\",\"share_with_group_hint\":\"Every persona in this group sees this memory in their prompt — useful for org-wide context like style or policies.\",\"share_with_group_title\":\"Authored by the selected persona; surfaces for every member of its group.\"},\"memory_filter\":{\"search_placeholder\":\"Search memories...\",\"all_agents\":\"All agents\",\"all_categories\":\"All categories\",\"tier_all\":\"Active set\",\"tier_core\":\"Core\",\"tier_active\":\"Active\",\"tier_working\":\"Working\",\"tier_archived\":\"Archived\"},\"memory_actions\":{\"dismiss_suggestion\":\"Dismiss suggestion\",\"memory_insights\":\"Memory Insights\",\"suggestions\":\"{count} suggestions\",\"suggestions_one\":\"{count} suggestion\"},\"memory_conflict\":{\"memory_a\":\"Memory A\",\"memory_b\":\"Memory B\",\"merge\":\"Merge\",\"keep\":\"Keep\",\"vs\":\"vs\"},\"observability_charts\":{\"cost_over_time\":\"Cost Over Time\",\"executions_by_persona\":\"Executions by Persona\",\"execution_health\":\"Execution Health\",\"successful\":\"Successful\",\"failed\":\"Failed\",\"anomalies_detected\":\"{count} cost anomalies detected\",\"anomaly_detected\":\"{count} cost anomaly detected\",\"anomaly_click_hint\":\"Click a diamond marker on the chart to investigate\",\"clear_traces\":\"Clear completed traces\",\"all_operations\":\"All operations\"},\"health_extra\":{\"success\":\"Success\",\"burn\":\"Burn\",\"healing\":\"Healing\",\"rollbacks\":\"Rollbacks\",\"improving\":\"Improving\",\"degrading\":\"Degrading\",\"stable\":\"Stable\",\"success_pct\":\"{pct}% success\",\"budget_exhaustion\":\"Budget exhaustion in\",\"exhausted\":\"exhausted\",\"predicted_failure\":\"Predicted failure spike in\",\"loading_status\":\"Loading status page data...\",\"no_personas\":\"No personas to display.\",\"score_prefix\":\"Score:\",\"uptime_30d_prefix\":\"30d uptime:\",\"updated_prefix\":\"Updated\",\"consecutive_failures_one\":\"{count} consecutive failure\",\"consecutive_failures_other\":\"{count} consecutive failures\",\"score_label\":\"Score\",\"uptime_30d\":\"30d uptime\",\"updated\":\"Updated {time}\",\"legend\":\"Legend:\",\"operational\":\"Operational\",\"degraded\":\"Degraded\",\"outage\":\"Outage\",\"no_data\":\"No data\",\"success_rate_label\":\"Success Rate\",\"latency_p95\":\"Latency (p95)\",\"cost_anomalies\":\"Cost Anomalies\",\"detected\":\"{count} detected\",\"healing_issues\":\"Healing Issues\",\"open\":\"{count} open\",\"sla_compliance\":\"SLA Compliance\",\"consecutive_failures\":\"{count} consecutive failures\",\"consecutive_failure\":\"{count} consecutive failure\"},\"system_health\":{\"title\":\"System Checks\",\"subtitle\":\"Verifying your environment is ready\",\"re_run_checks\":\"Re-run checks\",\"ollama_title\":\"Ollama Cloud API Key\",\"ollama_subtitle\":\"Optional -- unlocks free cloud models (Qwen3 Coder, GLM-5, Kimi K2.5) for all agents.\",\"litellm_title\":\"LiteLLM Proxy Configuration\",\"litellm_subtitle\":\"Optional -- route agents through your LiteLLM proxy for model management and cost tracking.\",\"save_key\":\"Save Key\",\"save_configuration\":\"Save Configuration\",\"litellm_footer\":\"These settings are stored locally and shared across all agents configured to use the LiteLLM provider.\",\"ipc_error\":\"The application bridge is not responding. Try restarting the app. You can still continue to explore the interface.\",\"issues_warning\":\"Some checks reported issues. You can still continue, but some features may not work correctly.\"},\"review_extra\":{\"add_note\":\"Add a note (optional)...\",\"confirm\":\"Confirm\",\"processing\":\"Processing...\",\"clear_verdicts\":\"Clear all verdicts\",\"retry_with_changes\":\"Retry with changes\",\"reject_all\":\"Reject all\",\"quick_actions\":\"Quick Actions\",\"accepted\":\"{count} accepted\",\"rejected\":\"{count} rejected\",\"undecided\":\"{count} undecided\"},\"heatmap\":{\"title_persona\":\"Activity over the last year\",\"title_global\":\"Fleet activity over the last year\",\"subtitle_executions\":\"{count} executions\",\"subtitle_executions_with_cost\":\"{count} executions · ${cost} total\",\"no_activity\":\"No activity in the last year\",\"less\":\"Less\",\"more\":\"More\",\"tooltip_no_runs\":\"No runs\",\"tooltip_one_run\":\"1 run on {date}\",\"tooltip_runs\":\"{count} runs on {date}\",\"tooltip_with_cost\":\" · ${cost}\",\"insights_streak\":\"Longest streak\",\"insights_streak_days\":\"{days}d\",\"insights_dormant\":\"Dormant\",\"insights_dormant_days\":\"{days}d\",\"insights_dormant_active\":\"Active today\",\"insights_dormant_never\":\"Never run\",\"insights_peak\":\"Peak day\",\"insights_peak_value\":\"{count} on {date}\",\"insights_wow\":\"Week over week\",\"insights_wow_up\":\"+{pct}% vs last week\",\"insights_wow_down\":\"{pct}% vs last week\",\"insights_wow_flat\":\"No change vs last week\",\"insights_wow_new\":\"New activity\",\"month_jan\":\"Jan\",\"month_feb\":\"Feb\",\"month_mar\":\"Mar\",\"month_apr\":\"Apr\",\"month_may\":\"May\",\"month_jun\":\"Jun\",\"month_jul\":\"Jul\",\"month_aug\":\"Aug\",\"month_sep\":\"Sep\",\"month_oct\":\"Oct\",\"month_nov\":\"Nov\",\"month_dec\":\"Dec\",\"aria_year_activity\":\"Execution activity over the last year\"},\"detail_modal\":{\"close\":\"Close\"},\"metric_help_popover\":{\"dismiss_help\":\"Dismiss help\",\"healthy\":\"Healthy:\",\"click\":\"Click:\",\"got_it\":\"Got it, don't show again\",\"help_for\":\"Help for {label}\"},\"install_button\":{\"install_node\":\"Install Node.js\",\"install_cli\":\"Install Claude CLI\",\"downloading\":\"Downloading...\",\"installing\":\"Installing...\",\"installed_success\":\"Installed successfully\",\"installation_failed\":\"Installation failed\",\"try_manually\":\"Try running manually:\",\"retry\":\"Retry\",\"official_page\":\"Official page\"},\"section_card\":{\"checking\":\"Checking {section}...\",\"edit_key\":\"Edit Key\",\"configure\":\"Configure\",\"edit_config\":\"Edit Config\",\"signing_in\":\"Signing in...\",\"sign_in_google\":\"Sign in with Google\",\"working\":\"Working...\",\"connect_claude\":\"Connect to Claude Desktop\",\"disconnect\":\"Disconnect\"},\"metrics_cards\":{\"top_executions\":\"Top executions:\",\"cost_spike\":\"Cost spike\",\"above_avg\":\"above avg\"},\"event_log_item\":{\"event_id\":\"Event ID\",\"project\":\"Project\",\"source\":\"Source\",\"processed\":\"Processed\",\"event_data\":\"Event Data\",\"error\":\"Error\",\"copy_event_data\":\"Copy event data\",\"copied\":\"Copied\",\"copy\":\"Copy\",\"system\":\"System\"},\"burn_rate_extra\":{\"title\":\"Burn Rate Projections\",\"daily_burn\":\"Daily Burn\",\"projected_monthly\":\"Projected Monthly\",\"at_risk\":\"At Risk\",\"top_cost_drivers\":\"Top Cost Drivers\",\"budget_exhaustion_warnings\":\"Budget Exhaustion Warnings\",\"exhausted\":\"Exhausted\",\"days_left\":\"{days}d left\",\"active_personas_subtitle\":\"{count} active personas · local month boundary\"},\"cascade\":{\"title\":\"Chain Cascade Map\",\"no_chains\":\"No chains detected -- all personas operating independently\",\"cofail_pct\":\"{pct}% co-fail\",\"cofail_insufficient\":\"no co-failures\"},\"predictive_alerts_extra\":{\"title\":\"Predictive Alerts\",\"all_nominal\":\"All systems nominal\",\"no_alerts\":\"No predictive alerts -- all personas within healthy parameters.\",\"budget_exhausted\":\"Budget Exhausted\",\"budget_exhaustion_in\":\"Budget exhaustion in {days}d\",\"failure_spike_predicted\":\"Failure rate spike predicted in {days}d\",\"excessive_healing\":\"Excessive self-healing activity\",\"critical_health\":\"Critical health status\",\"byom_recommendations\":\"BYOM Routing Recommendations\",\"per_month\":\"/mo\",\"confidence_pct\":\"% conf\"},\"annotate_modal\":{\"title\":\"Add Knowledge Annotation\",\"persona_label\":\"Attribution Persona\",\"scope_label\":\"Scope\",\"tool_name\":\"Tool Name\",\"connector_type\":\"Connector / Service Type\",\"annotation_label\":\"Annotation\",\"cancel\":\"Cancel\",\"saving\":\"Saving...\",\"save_annotation\":\"Save Annotation\",\"annotation_placeholder\":\"e.g. Stripe webhook verification requires the raw request body, not the parsed JSON\"},\"knowledge_row\":{\"annotation\":\"Annotation\",\"successes\":\"Successes\",\"failures\":\"Failures\",\"avg_cost\":\"Avg Cost\",\"avg_duration\":\"Avg Duration\",\"pattern_data\":\"Pattern Data\",\"collapse_details\":\"Collapse details\",\"expand_details\":\"Expand details\",\"verify_annotation\":\"Verify annotation\",\"dismiss_annotation\":\"Dismiss annotation\",\"execution_trend_label\":\"Execution trend\"},\"knowledge_graph\":{\"mock_pattern\":\"Mock Pattern\",\"seed_tooltip\":\"Seed a mock pattern (dev only)\",\"all_types\":\"All Types\",\"all_scopes\":\"All Scopes\",\"failure_drilldown_prefix\":\"Failure drill-down:\",\"failure_date_filter\":\"Showing failure patterns active on or after {date}\",\"data_unavailable\":\"Knowledge data unavailable\",\"loading_patterns\":\"Loading knowledge patterns...\",\"drilldown_toggle_title\":\"Show/hide failure drill-down\",\"no_patterns_yet\":\"No knowledge patterns yet\",\"no_patterns_yet_hint\":\"Run agent executions to build up knowledge patterns. Agents get smarter over time.\",\"no_patterns_match\":\"No patterns match current filters\",\"recent_learnings\":\"Recent Learnings\",\"curating_manually\":\"Curating documents manually?\",\"obsidian_tip\":\"For fewer than ~1000 notes, an Obsidian connector can sync your vault directly.\"},\"focused_decision\":{\"accept\":\"Accept\",\"reject\":\"Reject\",\"media_unavailable\":\"Media unavailable\",\"video_not_supported\":\"Your browser does not support video playback.\",\"reject_this\":\"Reject this\",\"accept_this\":\"Accept this\"},\"review_focus\":{\"all_caught_up\":\"All caught up\",\"no_pending\":\"No pending reviews to process.\",\"queue\":\"Queue\",\"clear_all_verdicts\":\"Clear all verdicts\",\"quick_actions\":\"Quick Actions\",\"reject_all\":\"Reject all\",\"accept_all\":\"Accept all\",\"retry_with_changes\":\"Retry with changes\",\"carry_out_hint\":\"Approves the review and runs the persona to carry this out\",\"clear\":\"Clear\",\"video_not_supported\":\"Your browser does not support video playback.\"},\"memory_card\":{\"confirm\":\"Confirm\",\"cancel\":\"Cancel\",\"restore\":\"Restore to active set\"},\"memory_detail\":{\"title_label\":\"Title\",\"content_label\":\"Content\",\"category_label\":\"Category\",\"importance_label\":\"Importance\",\"tags_label\":\"Tags\",\"view_source_execution\":\"View Source Execution\",\"delete_memory\":\"Delete Memory\",\"close\":\"Close\",\"derived_from_label\":\"Synthesized from\",\"claims_label\":\"Disputes\",\"claims_open_badge\":\"{count} open\",\"claims_empty\":\"No disputes filed against this memory.\",\"claim_wrong\":\"Mark wrong\",\"claim_outdated\":\"Mark outdated\",\"claim_note_placeholder\":\"Why is this memory wrong or outdated? (optional)\",\"claim_filed_toast\":\"Dispute filed — the memory is demoted in recall until resolved\",\"claim_resolved_toast\":\"Disputes resolved\",\"claim_error_toast\":\"Couldn’t update disputes\",\"claim_resolve_reverify\":\"Reverify\",\"claim_resolve_deprecate\":\"Deprecate & archive\",\"claim_resolve_dismiss\":\"Dismiss disputes\",\"claim_verdict_wrong\":\"wrong\",\"claim_verdict_outdated\":\"outdated\",\"claim_verdict_helpful\":\"helpful\",\"claim_resolution_reverified\":\"reverified\",\"claim_resolution_deprecated\":\"deprecated\",\"claim_resolution_dismissed\":\"dismissed\"},\"memory_table\":{\"agent\":\"Agent\",\"title\":\"Title\",\"category\":\"Category\",\"tags\":\"Tags\",\"created\":\"Created\",\"priority\":\"Priority\"},\"review_results\":{\"title\":\"AI Memory Review\",\"review_failed\":\"Review failed\"},\"anomaly_drilldown_extra\":{\"title\":\"Anomaly Drill-Down\",\"value_label\":\"Value:\",\"baseline_label\":\"Baseline:\",\"correlating\":\"Correlating events...\",\"likely_root_causes\":\"Likely Root Causes\",\"no_correlated\":\"No correlated events found in the ±24h window.\",\"correlated_events\":\"Correlated Events\"},\"healing_issue_modal\":{\"issue_resolved\":\"Issue Resolved\",\"analysis\":\"Analysis\",\"suggested_fix\":\"Suggested Fix\",\"copied\":\"Copied\",\"copy_fix\":\"Copy Fix\",\"persona_auto_disabled\":\"Persona auto-disabled\",\"marking_resolved_note\":\"Marking resolved means you have addressed this issue outside the healing system.\",\"auto_resolved\":\"This issue was automatically resolved\",\"resolving\":\"Resolving…\",\"mark_resolved\":\"Mark as Resolved\",\"persona_auto_disabled_desc\":\"This persona was automatically disabled after 5 consecutive failures. Review the error pattern below and re-enable manually once the root cause is resolved.\",\"retry_in_progress\":\"Retry in progress -- status will update when complete\",\"close\":\"Close\",\"retrying_badge\":\"retrying\",\"auto_fixed_badge\":\"auto-fixed\",\"retry_in_progress_badge\":\"retry in progress\",\"healed_via_retry_badge\":\"healed via retry\",\"severity_suffix\":\"severity\"},\"healing_issues_panel\":{\"title\":\"Health Issues\",\"analyzing\":\"Analyzing...\",\"run_analysis\":\"Run Analysis\",\"no_open_issues\":\"No open issues\",\"run_analysis_hint\":\"Run analysis to check for problems.\",\"healing_audit_log\":\"Healing Audit Log\",\"no_silent_failures\":\"No silent failures recorded.\",\"analysis_complete_prefix\":\"Analysis complete:\",\"ai_healing_title\":\"AI Healing\",\"diagnosis_label\":\"Diagnosis:\",\"fixes_applied\":\"Fixes Applied\",\"alert_history_title\":\"Alert History\",\"all_agents_global\":\"All agents (global)\",\"add_rule\":\"Add Rule\",\"no_rules_configured\":\"No alert rules configured. Add a rule to start monitoring.\",\"confidence_pct_suffix\":\"% confidence\",\"spike_on\":\"spike on\",\"correlated_events_prefix\":\"Correlated Events (\",\"circuit_breaker_label\":\"circuit breaker\",\"auto_disabled_message\":\"This persona was automatically disabled as a circuit-breaker response to repeated failures.\",\"execution_label\":\"Execution:\",\"issue_marked_as\":\"This issue is marked as\",\"retry_in_progress\":\"Retry in progress — status will update when complete.\",\"resolve_issue_title\":\"Mark issue resolved\",\"oauth_source_badge\":\"Credential\",\"oauth_source_hint\":\"Raised because this credential's access was revoked — reconnect it in the Vault to clear the issue.\"},\"healing_timeline\":{\"loading\":\"Loading timeline...\",\"no_events\":\"No healing events\",\"no_events_hint\":\"Run analysis to build the resilience timeline.\",\"knowledge_base\":\"Knowledge Base\",\"patterns_hint\":\"Patterns influencing healing decisions\",\"retry_badge\":\"retry #{count}\"},\"ipc_panel\":{\"title\":\"IPC Performance\",\"by_command\":\"By Command\",\"slowest_calls\":\"Slowest Calls\",\"command\":\"Command\",\"calls_header\":\"Calls\",\"duration_header\":\"Duration\",\"when_header\":\"When\",\"commands_table_label\":\"IPC command performance\",\"slowest_table_label\":\"Slowest IPC calls\",\"error_rate\":\"Error rate:\",\"timeout_rate\":\"Timeout rate:\"},\"system_trace_extra\":{\"no_traces\":\"No system traces recorded\",\"no_traces_hint\":\"Traces appear when design, credential, or template operations run\",\"all_operations\":\"All operations\",\"clear_completed\":\"Clear completed traces\",\"span\":\"Span\",\"zero_ms\":\"0ms\"},\"event_log_sidebar\":{\"title\":\"Event Log\",\"no_events\":\"No events yet\",\"open_detail_drawer\":\"Open in detail drawer\",\"filter_placeholder\":\"Filter events…\"},\"chart_error\":{\"chart_unavailable\":\"Chart unavailable\"},\"realtime_idle\":{\"idle\":\"Idle\"},\"day_range\":{\"apply\":\"Apply\"},\"event_detail_drawer\":{\"event_id_label\":\"Event ID\",\"broadcast\":\"(broadcast)\"},\"realtime_stats\":{\"events_per_min\":\"events/min\",\"pending\":\"pending\",\"success\":\"success\",\"in_window\":\"in window\",\"test_flow\":\"Test Flow\",\"to_simulate_traffic\":\"to simulate traffic\"},\"bus_lane\":{\"event_queue\":\"EVENT QUEUE\"},\"health_dashboard\":{\"heartbeats_view\":\"Heartbeats view\",\"status_page_view\":\"Status page view\",\"reliability_view\":\"SLA reliability view\",\"refresh_tooltip\":\"Refresh health data\",\"persona_heartbeats\":\"Persona Heartbeats\",\"system_health\":\"System Health:\",\"computing\":\"Computing health signals...\",\"no_match\":\"No personas match the selected filter.\",\"loading_status_page\":\"Loading status page...\",\"loading_reliability\":\"Loading reliability data...\",\"heartbeats_btn\":\"Heartbeats\",\"reliability_btn\":\"Reliability\",\"sources_unavailable\":\"{sources} could not be loaded — scores may be inaccurate.\",\"source_monthly_spend\":\"Monthly spend\",\"source_healing_issues\":\"Healing issues\",\"source_byom_policy\":\"BYOM policy\",\"source_provider_stats\":\"Provider stats\"},\"heartbeats\":{\"monitored\":\"monitored\",\"show_all\":\"Show all\",\"focus_issues\":\"Focus issues\",\"hide_healthy\":\"Hide healthy\",\"healthy_hidden\":\"healthy hidden\",\"all_clear_title\":\"All systems nominal\",\"all_clear_detail\":\"Every monitored persona is operating within healthy thresholds.\",\"heartbeat\":\"Heartbeat\",\"col_persona\":\"Persona\",\"col_vitals\":\"Trend · Success · Burn\",\"col_score\":\"HP\",\"runs_suffix\":\"runs\",\"idle\":\"Idle\",\"chains\":\"chains\",\"seg_success\":\"Success\",\"seg_healing\":\"Healing\",\"seg_stability\":\"Stability\",\"seg_budget\":\"Budget\",\"fleet_avg_badge\":\"fleet avg\",\"fleet_avg_tooltip\":\"No runs recorded for this persona in the window — showing the fleet-wide average success rate, not a measured per-persona value.\",\"no_data_badge\":\"no data\",\"no_data_tooltip\":\"No completed or failed runs for this persona in the window — the success rate is a placeholder, not measured.\"},\"popup_fields\":{\"sign_up_free_at\":\"Sign up free at\",\"ollama_domain\":\"ollama.com\",\"ollama_key_instructions\":\", then copy your API key from Settings. This key is stored locally and shared across all agents.\"},\"usage_filters\":{\"time_range_label\":\"Time range\"},\"healing_summary\":{\"auto_fixed_this_week\":\"auto-fixed this week\",\"issues_in_7d\":\"issues in 7d\"},\"healing_effectiveness\":{\"title\":\"Self-healing\",\"window_label\":\"Last\",\"loading\":\"Loading healing stats…\",\"no_data\":\"No auto-fix activity yet\",\"success_rate\":\"success rate\",\"confirmed\":\"confirmed\",\"reverted\":\"reverted\",\"attempted\":\"attempted\",\"recent_activity\":\"Recent activity\"},\"fleet_optimization\":{\"title\":\"Fleet optimization\",\"open_lab\":\"Open Lab\",\"open_lab_hint\":\"Test alternative models for {persona} in the Lab\",\"ask_athena\":\"Ask Athena\",\"ask_athena_hint\":\"Forward this suggestion to Athena to investigate\",\"ask_athena_prompt\":\"I'm reviewing a fleet optimization suggestion for my agents: \\\"{title}\\\". {description} Suggested action: {action}. {focus}\",\"ask_athena_focus_persona\":\"Please look into the agent \\\"{persona}\\\" and, if it would help, open its Lab to test alternative models, then report back what you find.\",\"ask_athena_focus_general\":\"Please look into this and recommend what to change.\"},\"upcoming_routines\":{\"title\":\"Upcoming Routines\",\"subtitle\":\"next scheduled runs\",\"empty\":\"No scheduled runs.\",\"never_fired\":\"pending first run\",\"view_all\":\"View schedules\"},\"vault_recent_changes\":{\"title\":\"Vault Changes\",\"subtitle\":\"recent Obsidian sync\",\"empty\":\"No recent sync activity.\",\"view_all\":\"View sync log\"},\"trend\":{\"up\":\"Up\",\"down\":\"Down\",\"stable\":\"Stable — no significant change\",\"vs_previous\":\"vs previous period\"},\"errorRecovery\":{\"audit_fetch_failed\":\"Could not load the healing audit log.\",\"audit_fetch_cause\":\"The log may be temporarily unavailable. Your other data is unaffected.\",\"action_retry\":\"Retry\"},\"emptyState\":{\"alerts_title\":\"All clear — no alerts fired\",\"alerts_subtitle\":\"Your monitoring rules are watching. If anything needs attention, alerts will surface here automatically.\",\"realtime_title\":\"Watch your events flow in real time\",\"realtime_subtitle\":\"Animated particles trace each event as it travels from sources to your agents. Every dot is a live event moving through your system.\",\"realtime_action\":\"Send a test event\"},\"title\":\"Overview\",\"no_output\":\"No output yet\",\"no_background_jobs\":\"No background jobs running or recent\",\"background_jobs_hint\":\"Tasks appear here when you import workflows, set up templates, generate agents, or run diagnostics\",\"realtime\":{\"idle\":\"Idle -- click {action} to simulate traffic\",\"test_flow\":\"Test Flow\",\"events_per_min\":\"events/min\",\"pending\":\"pending\",\"success\":\"success\",\"in_window\":\"in window\"},\"executions\":{\"title\":\"Executions\",\"loading\":\"Loading executions...\",\"no_agents\":\"No agents created yet\",\"create_first\":\"Create your first agent to see execution activity here\",\"no_executions\":\"No executions yet\",\"run_agent\":\"Run an agent to see execution activity here\"},\"workflows\":{\"loading\":\"Loading workflows...\",\"title\":\"Workflows\",\"subtitle\":\"Active and recent background operations across your workspace\",\"no_jobs\":\"No background jobs running or recent\",\"jobs_hint\":\"Jobs appear here when you run N8n transforms, template adoptions, template generation, or query debugging\",\"all_types\":\"All types\",\"no_filter_match\":\"No jobs match the current filters\",\"auto_refreshing\":\"Auto-refreshing while jobs are running\",\"cancel_failed\":\"Failed to cancel workflow job\"},\"schedules\":{\"title\":\"Schedule Timeline\",\"subtitle\":\"Aggregated view of all scheduled agent executions\",\"engine_on\":\"Running\",\"engine_off\":\"Stopped\",\"engine_on_tooltip\":\"Schedules are active -- click to pause all\",\"engine_off_tooltip\":\"Schedules are paused -- click to resume all\",\"active_count\":\"{count} active\",\"paused_count\":\"{count} paused\",\"view_grouped\":\"Grouped\",\"view_timeline\":\"Timeline\",\"triggers_fired\":\"Times triggered: \",\"events_processed\":\"Events handled: \",\"delivered\":\"Delivered: \",\"failed_label\":\"Failed: \",\"loading\":\"Loading schedules...\",\"empty\":\"No scheduled agents found.\",\"empty_hint\":\"Create a schedule or polling trigger on any agent to see it here.\",\"group_overdue\":\"Overdue\",\"group_15min\":\"Next 15 minutes\",\"group_1hour\":\"Next hour\",\"group_6hours\":\"Next 6 hours\",\"group_24hours\":\"Next 24 hours\",\"group_later\":\"Later\",\"group_paused\":\"Paused / Unscheduled\",\"run_now\":\"Run now\",\"change_frequency\":\"Change frequency\",\"pause_schedule\":\"Pause schedule\",\"resume_schedule\":\"Resume schedule\",\"headless_badge\":\"background\"},\"frequency_editor\":{\"title\":\"Change Frequency\",\"current\":\"Current: \",\"quick_presets\":\"Quick presets\",\"cron_expression\":\"Schedule expression\",\"interval_seconds\":\"Repeat every (seconds)\",\"previewing\":\"Previewing...\",\"next_runs\":\"Next runs\",\"invalid_cron\":\"Invalid schedule expression\"},\"recovery\":{\"agents_missed_one\":\"{count} agent missed executions\",\"agents_missed_other\":\"{count} agents missed executions\",\"total_skipped\":\"~{count} total runs skipped while app was offline\",\"missed_since\":\"{count} missed since {time}\",\"every_interval\":\"every {interval}\",\"mark_for_recovery\":\"Mark for recovery\",\"recover\":\"Recover\",\"run_once\":\"Run 1x\",\"run_once_tooltip\":\"Run once now\",\"skip_tooltip\":\"Skip -- don't recover\",\"dismiss_all\":\"Dismiss all\",\"recover_selected\":\"Recover {count} selected\"},\"conflicts\":{\"dismissed\":\"Conflict dismissed\",\"resolved\":\"Conflict resolved\",\"resolve_failed\":\"Failed to resolve conflict\",\"conflict_count_one\":\"{count} conflict\",\"conflict_count_other\":\"{count} conflicts\"},\"execution_metrics\":{\"title\":\"Execution Metrics\",\"total_executions\":\"Total Executions\",\"total_cost\":\"Total Cost\",\"success_rate\":\"Success Rate\",\"avg_latency\":\"Avg Latency\",\"cost_anomalies\":\"Cost Anomalies Detected\",\"no_data\":\"No execution data for the selected period\",\"cost_per_day\":\"Cost per Day\",\"executions_by_status\":\"Executions by Status\",\"success_rate_trend\":\"Success Rate Trend\",\"latency_distribution\":\"Latency Distribution (p50 / p95 / p99)\",\"top_personas_by_cost\":\"Top Personas by Cost\",\"cost_spike\":\"Cost spike\",\"above_avg\":\"above avg\",\"top_executions\":\"Top executions:\",\"executions_label\":\"{count} executions\"},\"execution_list\":{\"recorded\":\"{count} executions recorded\",\"recorded_one\":\"{count} execution recorded\",\"list\":\"List\",\"metrics\":\"Metrics\",\"show_list\":\"Show execution list\",\"show_metrics\":\"Show metrics dashboard\",\"showing\":\"Showing {count} of {total}\",\"load_more\":\"Load More\",\"filter_all\":\"All\",\"filter_running\":\"Running\",\"filter_completed\":\"Completed\",\"filter_failed\":\"Failed\",\"col_persona\":\"Persona\",\"col_status\":\"Status\",\"col_duration\":\"Duration\",\"col_started\":\"Started\",\"col_id\":\"ID\",\"all_statuses\":\"All statuses\",\"all_personas\":\"All personas\",\"no_agents\":\"No agents created yet\",\"no_agents_hint\":\"Create your first agent to see execution activity here.\",\"unknown_persona\":\"Unknown\",\"healing_retry\":\"Healing retry #{count}\"},\"analytics\":{\"title\":\"Analytics\",\"subtitle\":\"Execution patterns and trends\",\"loading\":\"Loading analytics...\",\"no_data\":\"No analytics data available\",\"executions_over_time\":\"Executions Over Time\",\"success_failure\":\"Success vs Failure\",\"cost_breakdown\":\"Cost Breakdown\",\"total_executions\":\"Total Executions\",\"total_cost\":\"Total Cost\",\"avg_success_rate\":\"Avg Success Rate\",\"active_agents\":\"Active Agents\",\"saved_views\":\"Saved Views\",\"create_view\":\"Create View\",\"health_issues\":\"Health Issues\",\"rotation_overview\":\"Rotation Overview\"},\"usage\":{\"title\":\"Usage\",\"subtitle\":\"Cost and resource consumption\",\"chart_error\":\"Chart failed to load\",\"chart_error_hint\":\"An error occurred rendering this chart\",\"try_again\":\"Try Again\"},\"cron_agents\":{\"title\":\"Scheduled Agents\",\"subtitle\":\"Agents running on automatic schedules\",\"no_agents\":\"No scheduled agents\",\"no_agents_hint\":\"Add a cron trigger to any agent to see it here\"},\"timeline\":{\"title\":\"Activity Timeline\",\"subtitle\":\"Unified view of all agent activity\",\"no_activity\":\"No recent activity\"},\"realtime_viz\":{\"title\":\"Event Bus\",\"filter_events\":\"Filter events\",\"pause\":\"Pause\",\"resume\":\"Resume\",\"clear\":\"Clear\",\"total_events\":\"Total Events\",\"events_per_sec\":\"Events/sec\",\"active_lanes\":\"Active Lanes\",\"event_type\":\"Event Type\",\"source\":\"Source\",\"target\":\"Target\",\"timestamp\":\"Timestamp\",\"payload\":\"Payload\",\"saved_views\":\"Saved Views\",\"save_current\":\"Save Current\"},\"cards\":{\"fleet_optimization\":\"Fleet Optimization\",\"fleet_subtitle\":\"Automated recommendations to improve agent performance\",\"remote_control\":\"Remote Control\",\"remote_subtitle\":\"Quick actions for agent management\",\"resume_setup\":\"Continue Setup\",\"resume_subtitle\":\"Pick up where you left off\",\"knowledge_hub\":\"Knowledge Hub\"},\"saved_views\":{\"errors\":{\"load_failed_message\":\"Couldn't load your saved views.\",\"save_failed_message\":\"Couldn't save this view.\",\"delete_failed_message\":\"Couldn't delete this view.\"}},\"activity_timeline\":{\"title\":\"Activity Timeline\",\"subtitle\":\"{events} events, {messages} messages\",\"all\":\"All\",\"events\":\"Events\",\"messages\":\"Messages\",\"no_activity\":\"No activity yet\",\"high\":\"High\"},\"widgets_extra\":{\"execution_health_chart\":\"Execution Health\",\"cost_over_time_chart\":\"Cost Over Time\",\"successful\":\"Successful\",\"failed\":\"Failed\",\"close\":\"Close\",\"dismiss_help\":\"Dismiss help\",\"skip_tour\":\"Skip tour entirely\"},\"remote_control_card\":{\"connect_to_desktop\":\"Connect to Desktop\",\"connect_description\":\"Run agents using your desktop CLI via Remote Control. Start {command} on your computer, then connect here.\",\"requires_subscription\":\"Requires Claude Pro or Max subscription\"},\"resume_setup_card\":{\"resume_tour\":\"Resume Tour\",\"left_off_at\":\"You left off at\",\"steps_completed\":\"{completed}/{total} steps completed\",\"skip_tour\":\"Skip tour entirely\",\"continue_label\":\"Continue\"},\"bulk_action_bar\":{\"pending_reviews_selected_one\":\"{count} pending review selected\",\"pending_reviews_selected_other\":\"{count} pending reviews selected\"},\"review_inbox\":{\"drag_to_resize\":\"Drag to resize\"},\"event_bus_overlay\":{\"earlier_events_not_shown_one\":\"{count} earlier event not shown\",\"earlier_events_not_shown_other\":\"{count} earlier events not shown\"},\"event_bus_filter\":{\"search_placeholder\":\"Search events...\"},\"crash_logs\":{\"title\":\"Crash Logs\",\"no_logs\":\"No crash logs recorded.\",\"component_stack_separator\":\"--- Component Stack ---\"},\"events_list\":{\"save_view_tooltip\":\"Save current filters as a view\",\"clear_filters_tooltip\":\"Clear all filters\"},\"dashboard_home\":{\"you_have\":\"You have\"},\"fleet_optimization_card\":{\"title\":\"Fleet Optimization\"},\"day_range_picker\":{\"start_date\":\"Start Date\",\"end_date\":\"End Date\"}}",
   "matrix_v3": "{\"behavior_core_section_title\":\"Behavior Core\",\"behavior_core_section_subtitle\":\"The mission and identity that every capability shares.\",\"mission_label\":\"Mission\",\"mission_placeholder\":\"Be the user's... so that...\",\"mission_coach_task_warning\":\"This reads like a task. A mission is the unchanging purpose every capability serves. Try starting with 'Be...', 'Make...', 'Ensure...', or 'Serve...'.\",\"mission_coach_good\":\"Strong mission — captures the shared purpose.\",\"mission_helper_text\":\"One sentence. What every capability ultimately serves.\",\"identity_role_label\":\"Role\",\"identity_role_placeholder\":\"You are a senior email triage concierge.\",\"identity_description_label\":\"Description\",\"identity_description_placeholder\":\"What this persona is responsible for in one line.\",\"voice_style_label\":\"Style\",\"voice_style_placeholder\":\"Direct, lightly wry, never alarmist.\",\"voice_output_format_label\":\"Output format\",\"voice_output_format_placeholder\":\"Markdown digest with a ranked list.\",\"principles_label\":\"Principles\",\"principles_helper_text\":\"Cross-cutting rules this persona always honors. 2-5 short entries.\",\"principles_add_placeholder\":\"Add a principle\",\"constraints_label\":\"Constraints\",\"constraints_helper_text\":\"Hard limits. Breaking them is a bug. 2-5 entries.\",\"constraints_add_placeholder\":\"Add a constraint\",\"decision_principles_label\":\"Decision principles\",\"decision_principles_helper_text\":\"Tiebreakers. 0-5 entries.\",\"decision_principles_add_placeholder\":\"Add a tiebreaker\",\"capability_row_expand\":\"Expand\",\"capability_row_collapse\":\"Collapse\",\"capability_row_remove\":\"Remove capability\",\"capability_row_field_trigger\":\"Trigger\",\"capability_row_field_connectors\":\"Connectors\",\"capability_row_field_channels\":\"Channels\",\"capability_row_field_review\":\"Review\",\"capability_row_field_memory\":\"Memory\",\"capability_row_field_events\":\"Events\",\"capability_row_field_pending\":\"pending\",\"capability_title_label\":\"Title\",\"capability_summary_label\":\"Summary\",\"capability_goal_label\":\"User-facing goal\",\"add_capability_modal_title\":\"Add a capability\",\"add_capability_modal_subtitle\":\"Describe what this capability does. The persona keeps its mission; this is just a new thing it knows how to do.\",\"add_capability_modal_title_placeholder\":\"Weekly Review\",\"add_capability_modal_summary_placeholder\":\"Sunday-evening pattern roll-up over the past 7 days.\",\"add_capability_modal_goal_placeholder\":\"See whether my attention allocation matched what mattered.\",\"add_capability_modal_cta\":\"Add capability\",\"shared_resources_section_title\":\"Shared Resources\",\"shared_resources_section_subtitle\":\"Persona-wide tools, connectors, and defaults every capability can draw from.\",\"shared_resources_tools_label\":\"Tools\",\"shared_resources_connectors_label\":\"Connectors\",\"shared_resources_defaults_label\":\"Defaults\",\"shared_resources_operating_instructions_label\":\"Operating instructions\",\"shared_resources_tool_guidance_label\":\"Tool guidance\",\"shared_resources_error_handling_label\":\"Error handling\",\"shared_resources_core_memories_label\":\"Core memories\",\"shared_resources_empty\":\"Nothing resolved yet.\",\"review_mode_never\":\"Never\",\"review_mode_on_low_confidence\":\"On low confidence\",\"review_mode_always\":\"Always\",\"memory_enabled_true\":\"Enabled\",\"memory_enabled_false\":\"Disabled\",\"layout_toggle_legacy\":\"8-dimension view\",\"layout_toggle_v3\":\"Capability view\",\"layout_toggle_tooltip\":\"Switch between the legacy 8-dimension matrix and the capability-first view.\",\"identity_label\":\"Identity\",\"voice_label\":\"Voice\",\"verbosity_label\":\"Default verbosity\",\"verbosity_terse\":\"Terse\",\"verbosity_normal\":\"Normal\",\"verbosity_verbose\":\"Verbose\",\"capabilities_section_title\":\"Capabilities\",\"capabilities_section_subtitle\":\"The distinct things this persona does. Each can be turned on and off independently.\",\"capabilities_add_button\":\"Add capability\",\"capabilities_empty\":\"No capabilities yet. The persona is still being designed.\",\"capability_row_enabled\":\"Enabled by default\",\"capability_row_field_flow\":\"Flow\",\"capability_row_field_input_schema\":\"Input\",\"capability_row_field_tool_hints\":\"Tools\",\"capability_row_field_error_handling\":\"Error handling\",\"shared_resources_channels_default_label\":\"Default channels\",\"clarifying_scope_mission\":\"Mission\",\"clarifying_scope_capability\":\"Capability\",\"clarifying_scope_field\":\"Field\",\"clarifying_question_heading\":\"One question\",\"clarifying_question_answer_placeholder\":\"Pick an option or type your answer\",\"clarifying_question_submit\":\"Send answer\"}",
   "templates": "{\"presets\":{\"loading\":\"Loading presets…\",\"empty_title\":\"No team presets yet\",\"empty_hint\":\"Team presets bundle multiple persona templates with their wiring. Drop a manifest into scripts/templates/_team_presets/ to see it here.\",\"page_title\":\"Team Presets\",\"page_subtitle\":\"Pre-wired bundles that turn N personas into a working team in one click.\",\"card_member_count_one\":\"{count} member\",\"card_member_count_other\":\"{count} members\",\"card_group_binding\":\"→ Group: {name}\",\"preview_graph_heading\":\"Team graph\",\"preview_graph_aria\":\"Read-only preview of the team's members and connections\",\"graph_legend_sequential\":\"Hands off\",\"graph_legend_feedback\":\"Sends back\",\"preview_group_binding\":\"Members will be bound to a new PersonaGroup: {name}\",\"preview_members_heading\":\"Members\",\"preview_members_select_hint\":\"Click to include / exclude\",\"adopt_all_button_one\":\"Adopt {count} member\",\"adopt_all_button_other\":\"Adopt {count} members\",\"open_team_button\":\"Open the new team\",\"footer_preview_hint\":\"Each member is adopted via the integrity-checked single-template path. Existing personas with the same name will get a suffix.\",\"footer_adopting_hint\":\"Adopting members one by one — watch the rows turn green.\",\"footer_done_hint\":\"Adopted {count} members. The team and group are ready.\",\"footer_done_partial\":\"{ok} adopted, {failed} failed. The team is ready; failed rows are kept here for retry.\",\"footer_handoff_warning\":\"Team built, but handoff wiring failed — downstream members won't run until you repair it.\",\"repair_handoff_button\":\"Repair handoff\",\"toast_handoff_warning\":\"Team built, but handoff wiring failed — click Repair handoff so downstream members run.\",\"toast_handoff_repaired\":\"Handoff wiring repaired — the team can now cascade.\",\"toast_handoff_repair_failed\":\"Handoff repair failed\",\"status_queued\":\"Queued\",\"status_adopting\":\"Adopting…\",\"status_done\":\"Done\",\"status_failed\":\"Failed\",\"toast_success\":\"Adopted {count} members from “{name}”\",\"toast_partial\":\"Adopted {ok} members, {failed} failed\",\"toast_failure\":\"Preset adoption failed\",\"retry_failed_button\":\"Retry {count} failed\",\"toast_retry_success\":\"All retried members adopted\",\"toast_retry_partial\":\"Retried {ok} members, {failed} still failed\",\"toast_retry_failure\":\"Retry failed\",\"customize_show\":\"Customize\",\"customize_hide\":\"Hide customization\",\"customize_hide_with_changes\":\"Hide ({count} customized)\",\"questionnaire_heading\":\"Combined questionnaire\",\"questionnaire_summary\":\"{configurable} of {total} members configurable · {questions} questions\",\"questionnaire_expand_all\":\"Expand all\",\"questionnaire_collapse_all\":\"Collapse all\",\"questionnaire_expand_section\":\"Expand section\",\"questionnaire_collapse_section\":\"Collapse section\",\"questionnaire_member_summary_default\":\"{count} questions, all defaults\",\"questionnaire_member_summary_customized\":\"{count} questions, {customized} customized\",\"questionnaire_member_no_config\":\"No configuration needed\"},\"gallery\":{\"ready_to_deploy\":\"Ready to Use\",\"ready_to_deploy_hint\":\"Templates with all services connected\",\"adopted\":\"{count} adopted\",\"popular\":\"Popular\",\"filter_all\":\"All\",\"filter_ready\":\"Ready\",\"filter_partial\":\"Partial\",\"use_cases\":\"Use Cases\",\"connectors\":\"Connectors\",\"triggers\":\"Triggers\",\"no_flows\":\"No flows\",\"readiness_tooltip\":\"{percent}% of services connected\",\"template_details_tabs_aria\":\"Template details\"},\"actions\":{\"view_details\":\"View Details\",\"adopt\":\"Adopt\",\"try_it\":\"Try It\",\"adopt_as_persona\":\"Adopt as Persona\",\"delete\":\"Delete\"},\"detail\":{\"tab_overview\":\"Overview\",\"tab_prompt\":\"Prompt\",\"tab_features\":\"Features\",\"tab_raw_json\":\"Raw JSON\",\"review_passed\":\"Passed\",\"review_failed\":\"Failed\",\"review_error\":\"Error\",\"used_references\":\"Used reference patterns\",\"no_design_data\":\"No design data available\",\"no_design_data_hint\":\"Design data unavailable for this template.\",\"tab_use_cases\":\"Use cases\",\"open_diagram\":\"Open full diagram\"},\"detail_overview\":{\"use_case_flows\":\"Use Case Flows\",\"suggested_adjustment\":\"Suggested Adjustment\",\"adjustment_attempt\":\"(attempt {attempt}/3)\",\"dimension_completion\":\"Dimension Completion\",\"dimensions_score\":\"({score}/9 dimensions)\"},\"adoption\":{\"title\":\"Adopt Template\",\"close_processing\":\"Close (processing continues in background)\",\"step_capabilities\":\"Pick capabilities\",\"step_credentials\":\"Link credentials\",\"step_preferences\":\"Set preferences\",\"step_generate\":\"Generate persona\",\"step_review\":\"Review & save\",\"matrix_fallbacks\":{\"not_required\":\"Not required — fully automated\",\"stateless_no_memory\":\"Stateless — no memory between runs\",\"default_error_handling\":\"Default error handling\",\"no_event_subscriptions\":\"No event subscriptions\",\"review_required\":\"Review required\",\"memory_enabled\":\"Memory enabled\",\"event\":\"event\"},\"time_presets\":{\"hourly\":\"Hourly\",\"daily\":\"Daily\",\"weekly\":\"Weekly\"},\"weekdays\":{\"sun\":\"Sun\",\"mon\":\"Mon\",\"tue\":\"Tue\",\"wed\":\"Wed\",\"thu\":\"Thu\",\"fri\":\"Fri\",\"sat\":\"Sat\"},\"cron_descriptions\":{\"daily_at_local\":\"Daily at {hour}:00 local\",\"weekly_on_day_at_local\":\"Weekly on {day} at {hour}:00 local\",\"hourly_at_local\":\"Hourly\"},\"trigger_descriptions\":{\"event_driven\":\"Event-driven.\",\"listens_for\":\"Listens for {event_type}.\",\"manual_on_demand\":\"Manual — user invokes on demand.\",\"custom_cron\":\"Custom cron: {cron}.\"}},\"choose\":{\"select_use_cases\":\"Select Use Cases\",\"clear_all\":\"Clear All\",\"select_all\":\"Select All\",\"choose_hint\":\"Choose which capabilities to include. Deselected use cases and their connectors will be excluded.\",\"components\":\"Components\",\"examples\":\"Examples\",\"tools\":\"Tools\",\"none_selected\":\"No use cases selected -- select at least one to continue\"},\"connect\":{\"all_set\":\"No connectors needed -- you're all set!\",\"title\":\"Connect Services\",\"subtitle\":\"Connect your accounts to the services this template needs.\",\"service_flow\":\"How services connect\",\"configured_count\":\"{configured} of {total} configured\",\"missing\":\"Missing: {names}\",\"built_in\":\"Built-in\",\"select_credential\":\"Select credential...\",\"create_new\":\"+ Create new credential\",\"design_custom\":\"+ Design custom connector\",\"add_credential\":\"Add credential\"},\"build\":{\"title\":\"Build Persona\",\"subtitle\":\"Generating persona prompt, tools, triggers, and connectors based on your selections.\",\"phase_initializing\":\"Initializing...\",\"phase_tools\":\"Configuring tools...\",\"phase_triggers\":\"Setting up triggers...\",\"phase_prompt\":\"Building persona prompt...\",\"phase_connectors\":\"Connecting services...\",\"phase_validating\":\"Checking everything...\",\"phase_finalizing\":\"Finalizing...\",\"phase_generating\":\"Generating persona...\",\"connector_progress\":\"{count} connectors\",\"close_hint\":\"You can close this dialog -- processing continues in the background.\",\"adjustments_label\":\"Request adjustments (optional)\",\"adjustments_placeholder\":\"Example: Change the schedule to run at 9 AM, remove ClickUp integration, add Slack notifications\",\"discard_draft\":\"Discard draft and start over\"},\"quick_adopt\":{\"all_matched\":\"All connectors matched\",\"match_summary\":\"{matched}/{total} connectors linked\",\"missing_prefix\":\"Missing: \",\"add_in_catalog\":\"add in Keys Catalog\",\"build_persona\":\"Build Persona\",\"full_wizard\":\"Full wizard\"},\"data\":{\"title\":\"Data Setup\",\"subtitle\":\"This template requires database tables. Choose to create new tables or use existing ones.\",\"tables_exist\":\"Tables already exist\",\"tables_found\":\"{tables} found in the built-in database.\",\"create_new\":\"Create New Tables\",\"use_existing\":\"Use Existing Tables\",\"complete\":\"Data setup complete\"},\"tune\":{\"title\":\"Configure Persona\",\"subtitle\":\"Set template variables, triggers, review policy, and memory.\",\"required_hint\":\"Required fields marked below.\",\"analyzing\":\"Analyzing template for configuration questions...\",\"variables_title\":\"Template Configuration\",\"select_placeholder\":\"Select...\",\"trigger_title\":\"Trigger Setup\",\"no_triggers\":\"No triggers selected\",\"when_label\":\"When should this run?\",\"cron_placeholder\":\"Every weekday at 9am\",\"cron_hint\":\"Plain English (e.g. \\\"Every weekday at 9am\\\") or a schedule expression (e.g. \\\"0 9 * * 1-5\\\")\",\"webhook_url\":\"Webhook URL\",\"webhook_placeholder\":\"https://...\",\"check_interval\":\"Check interval\",\"check_interval_placeholder\":\"Every 5 minutes\",\"manual_hint\":\"Triggered manually -- no configuration needed\",\"system_hint\":\"Triggered by system events -- no configuration needed\"},\"review_policy\":{\"title\":\"Human Review\",\"require_approval\":\"Require approval\",\"sandbox\":\"Sandbox\",\"pause_hint\":\"Pause before executing actions\",\"auto_approve\":\"Auto-approve\",\"auto_approve_hint\":\"Skip review for lower severity\",\"severity_info\":\"Info only\",\"severity_info_warning\":\"Info + Warning\",\"severity_all\":\"All (no review)\",\"review_timeout\":\"Review timeout\",\"auto_reject\":\"Auto-reject after timeout\",\"timeout_1h\":\"1 hour\",\"timeout_4h\":\"4 hours\",\"timeout_24h\":\"24 hours\",\"timeout_none\":\"No timeout\"},\"memory\":{\"title\":\"Memory\",\"description\":\"Persona retains learned patterns and preferences across runs\",\"enabled\":\"Memory enabled\",\"scope_label\":\"Memory scope\",\"scope_hint\":\"What should the persona remember?\",\"scope_everything\":\"Everything (default)\",\"scope_preferences\":\"User preferences only\",\"scope_patterns\":\"Execution patterns\",\"scope_errors\":\"Error resolutions\",\"scope_custom\":\"Custom scope...\",\"scope_custom_placeholder\":\"Describe what to remember...\"},\"create\":{\"use_cases\":\"Use Cases\",\"tools_and_connectors\":\"Tools & Connectors\",\"waiting\":\"Waiting for persona draft...\",\"title\":\"Review & Create\",\"subtitle\":\"Review the generated persona, then create it.\",\"unnamed\":\"Unnamed Persona\",\"no_description\":\"No description provided\",\"all_ready\":\"All connectors ready\",\"ready_count\":\"{ready}/{total} ready\",\"will_create\":\"Will create: 1 persona\",\"with_tools_one\":\", {count} tool\",\"with_tools_other\":\", {count} tools\",\"with_triggers_one\":\", {count} trigger\",\"with_triggers_other\":\", {count} triggers\",\"with_connectors_one\":\", {count} connector subscription\",\"with_connectors_other\":\", {count} connector subscriptions\",\"check_name\":\"Name\",\"check_prompt\":\"Prompt\",\"check_connectors\":\"Connectors\",\"safety_issues\":\"Safety issues\",\"safety_acknowledge_one\":\"I acknowledge {count} critical safety finding and accept the risk\",\"safety_acknowledge_other\":\"I acknowledge {count} critical safety findings and accept the risk\",\"edit_details\":\"Edit Details\"},\"review\":{\"use_cases_header\":\"Use Cases ({count})\",\"tools_header\":\"Tools ({count})\",\"triggers_header\":\"Triggers ({count})\"},\"sandbox\":{\"restriction_events\":\"Cannot send events\",\"restriction_chains\":\"Cannot trigger other agents\",\"restriction_webhooks\":\"Cannot receive web notifications\",\"restriction_polling\":\"Cannot auto-check for changes\",\"restriction_review\":\"Needs your approval before acting\",\"restriction_budget\":\"Spending limit enforced\",\"restriction_concurrent_one\":\"Max {max} concurrent run\",\"restriction_concurrent_other\":\"Max {max} concurrent runs\",\"title_unverified\":\"Unverified Template\",\"title_community\":\"Community Template -- Restricted Mode\",\"desc_unverified\":\"This template comes from an unknown source and has not been verified. It will run with restricted capabilities to protect your system.\",\"desc_community\":\"This community template has not been officially verified. It will run in restricted mode with limited capabilities.\",\"badge_unverified\":\"Unverified\",\"badge_sandbox\":\"Restricted Mode\"},\"page\":{\"title\":\"Agentic Templates\",\"subtitle_one\":\"{count} template available\",\"subtitle_other\":\"{count} templates available\"},\"explore\":{\"ready_to_deploy\":\"Ready to Deploy\",\"ready_to_deploy_hint\":\"Templates with all connectors configured\",\"adoption_count_one\":\"{count} adoption\",\"adoption_count_other\":\"{count} adoptions\",\"popular_in\":\"Popular in {role}\",\"configure_to_unlock\":\"Configure connectors to unlock ready-to-deploy templates\",\"hero_title\":\"What do you want to automate?\",\"hero_subtitle\":\"Browse by use case or search for templates that match your workflow needs.\",\"hero_search_placeholder\":\"Search templates by keyword or describe your need...\",\"templates_count_one\":\"{count} template\",\"templates_count_other\":\"{count} templates\",\"view_all\":\"View all\",\"most_adopted\":\"Most Adopted\",\"whats_your_role\":\"What's your role?\",\"categories_for_role\":\"{count} categories with specialized agent templates for {role} workflows.\",\"role_templates\":\"{role} Templates\",\"by_role\":\"By Role\",\"by_need\":\"By Need\",\"classic\":\"Classic\"},\"opportunities\":{\"title\":\"Automation Opportunities\",\"subtitle\":\"Workflows you can unlock\",\"ready_now\":\"Ready now\",\"add_connector\":\"Add\",\"unlock_more\":\"to unlock {count} more\",\"explore_templates\":\"Explore {label} templates\"},\"recommended\":{\"title\":\"Recommended for You\",\"subtitle\":\"Based on your connectors\",\"no_recommendations\":\"No recommendations available yet.\"},\"trending\":{\"title\":\"Most Adopted This Week\"},\"empty\":{\"no_templates\":\"No generated templates yet\",\"no_templates_hint\":\"Use the Synthesize Team button in the header or the Claude Code skill to generate templates.\",\"no_search_results\":\"No matching templates\",\"no_search_results_hint\":\"Try adjusting your search terms or filters to find what you're looking for.\",\"clear_search\":\"Clear search\",\"waiting_for_draft\":\"Waiting for persona draft\",\"waiting_for_draft_hint\":\"The AI is generating a draft based on your selections. This usually takes a few seconds.\"},\"banners\":{\"draft_prefix\":\"Draft: \",\"step_click_resume\":\"Step: {step} -- click to resume\",\"discard_draft\":\"Discard draft\",\"adoption_in_progress\":\"Template adoption in progress\",\"click_to_view_progress\":\"Click to view progress\",\"rebuilding\":\"Rebuilding: {name}\",\"status_testing\":\"Testing\",\"status_completed\":\"Completed\",\"status_failed\":\"Failed\",\"click_to_view_result\":\"Click to view result\",\"click_to_view_output\":\"Click to view output\"},\"search\":{\"switch_to_keyword\":\"Switch to keyword search\",\"switch_to_ai\":\"Switch to AI search\",\"few_results\":\"Few results found\",\"try_ai_search\":\"Try AI search\",\"ai_searching\":\"Searching with AI -- results will appear when ready...\",\"ai_results_one\":\"{count} result\",\"ai_results_other\":\"{count} results\",\"show_log\":\"Show Log\",\"hide_log\":\"Hide Log\",\"placeholder_default\":\"Search templates... (try category: difficulty: setup:)\",\"placeholder_ai\":\"Describe what you need, then press Enter...\",\"placeholder_add_more\":\"Add more filters or search...\",\"list_view\":\"List view\",\"explore_view\":\"Explore view\",\"comfortable_view\":\"Comfortable view\",\"compact_view\":\"Compact view\",\"recommended_for_you\":\"Recommended for you\",\"connectors_label\":\"Connectors\",\"components_label\":\"Components\",\"search_connectors\":\"Search connectors...\",\"search_components\":\"Search components...\",\"no_matching_connectors\":\"No matching connectors\",\"no_connectors_available\":\"No connectors available\",\"no_matching_components\":\"No matching components\",\"no_components_available\":\"No components available\",\"clear_all\":\"Clear all\",\"admin_tools\":\"Admin tools\",\"deduplicate\":\"Deduplicate\",\"backfill_pipelines\":\"Backfill Pipelines\",\"backfill_tools\":\"Backfill Tools\",\"coverage_all\":\"All\",\"coverage_ready\":\"Ready\",\"coverage_partial\":\"Partial\",\"autocomplete_categories\":\"Categories\",\"autocomplete_difficulty\":\"Difficulty\",\"autocomplete_setup_time\":\"Setup Time\",\"autocomplete_suggestions\":\"Suggestions\",\"coverage_filter_aria\":\"Coverage filter\",\"_comment_coverage_chips\":\"Template gallery coverage filter chips — keep to 1 word each. 'Drafts' = unpublished dev-only templates.\",\"coverage_drafts\":\"Drafts\",\"search_suggestions_aria\":\"Search suggestions\",\"clear_search_aria\":\"Clear search\",\"search_with_ai_aria\":\"Search with AI\",\"density_filter_aria\":\"Layout density\"},\"list\":{\"template_name\":\"Template Name\",\"components\":\"Components\",\"adoptions\":\"Adoptions\"},\"row_actions\":{\"row_actions_label\":\"Row actions\",\"view_details\":\"View Details\",\"rebuild\":\"Rebuild\",\"delete_template\":\"Delete template\"},\"connector_readiness\":{\"click_to_add\":\"click to add credential\",\"ready\":\"Ready\",\"partial\":\"Partial\",\"setup_needed\":\"Setup needed\",\"needs_setup\":\"Needs setup\",\"not_ready\":\"Not ready\",\"needs_credential\":\"needs credential\",\"not_installed\":\"not installed\"},\"detail_modal\":{\"adopted\":\"{count} adopted\",\"reference_patterns\":\"Reference patterns\",\"adopt_as_persona\":\"Adopt as Persona\",\"try_it\":\"Try It\",\"design_unavailable\":\"Design data unavailable for this template.\"},\"overview_tab\":{\"loading_metrics\":\"Loading performance metrics...\",\"metrics_unavailable\":\"Performance metrics unavailable\",\"metrics_load_error\":\"Could not load metrics for this template.\",\"incomplete_data\":\"Incomplete performance data\",\"incomplete_data_hint\":\"Some metric queries failed. The values below may not reflect actual usage.\",\"performance\":\"Performance\",\"adoptions_label\":\"Adoptions\",\"executions_label\":\"Executions\",\"success_label\":\"Success\",\"avg_cost_label\":\"Avg Cost\",\"quality_score\":\"Quality score\",\"use_case_flows\":\"Use Case Flows\",\"nodes\":\"{count} nodes\",\"edges\":\"{count} edges\",\"suggested_adjustment\":\"Suggested Adjustment\",\"adjustment_attempt\":\"(attempt {attempt}/3)\",\"dimension_completion\":\"Dimension Completion\",\"dimensions_score\":\"({score}/9 dimensions)\"},\"review_detail\":{\"design_unavailable\":\"Design data unavailable for this template.\",\"use_case_flows\":\"Use Case Flows\",\"view_diagram\":\"View diagram\",\"apply_rerun\":\"Apply & Re-run\",\"adopt_as_new_persona\":\"Adopt as New Persona\",\"view_raw_json\":\"View Raw JSON\",\"hide_raw_json\":\"Hide Raw JSON\",\"used_references\":\"This template used reference patterns from prior passing reviews\"},\"rebuild_modal\":{\"title\":\"Rebuild Template\",\"template_instruction\":\"Template Instruction\",\"custom_direction\":\"Custom Direction (optional)\",\"custom_direction_placeholder\":\"Add specific requirements, focus areas, or constraints for this rebuild...\",\"custom_direction_hint\":\"The rebuild will regenerate all 9 data dimensions using the Protocol System.\",\"rebuilding_with_cli\":\"Rebuilding template with Claude CLI...\",\"waiting_for_output\":\"Waiting for output...\",\"close_continues_bg\":\"You can close this dialog -- the rebuild will continue in the background.\",\"rebuild_complete\":\"Rebuild Complete\",\"rebuild_complete_hint\":\"The template has been regenerated with all data dimensions. The gallery will refresh to show updated scores.\",\"rebuild_failed\":\"Rebuild Failed\",\"unknown_error\":\"An unknown error occurred during rebuild.\",\"start_rebuild\":\"Start Rebuild\",\"cancel_rebuild\":\"Cancel Rebuild\",\"run_in_background\":\"Run in Background\"},\"preview_modal\":{\"preview_title\":\"Preview: {name}\",\"sandboxed_hint\":\"Sandboxed single-turn execution -- no persona created\",\"try_this_template\":\"Try this template\",\"try_description\":\"Run a sandboxed single-turn execution to see how this persona behaves. Uses the template's system prompt with mock inputs -- nothing is saved.\",\"run_preview\":\"Run Preview\",\"no_design_data\":\"No design data available for this template.\",\"ready\":\"Ready\",\"running\":\"Running...\",\"completed\":\"Completed\",\"execution_failed\":\"Execution failed\",\"run_again\":\"Run Again\",\"close_test_continues\":\"You can close -- test will continue in background\"},\"expanded\":{\"adopt\":\"Adopt\",\"try_it\":\"Try It\",\"flows\":\"Flows\",\"use_cases\":\"Use Cases\",\"architecture\":\"Architecture\",\"events\":\"Events\",\"reviews_label\":\"Reviews\",\"notifications\":\"Notifications\"},\"questionnaire\":{\"header\":\"{label} -- Question {current} of {total}\",\"answered\":\"{count} answered\",\"cancel_setup\":\"Cancel setup\",\"type_your_answer\":\"Type your answer...\",\"type_answer\":\"Type your answer...\",\"default_label\":\"Default: {value}\",\"select_project\":\"Select a codebase project...\",\"navigate_hint\":\"navigate\",\"navigate\":\"navigate\",\"skip_all\":\"Skip all\",\"submit_answers\":\"Submit Answers\",\"answer_remaining\":\"Answer remaining ({count})\",\"next\":\"Next\",\"setup\":\"Setup\",\"previous_question\":\"Previous question\",\"next_question\":\"Next question\",\"go_to_question\":\"Go to question {number}\",\"question_answered_suffix\":\" (answered)\",\"question_unanswered_suffix\":\" (unanswered)\",\"category_labels\":{\"credentials\":\"Credentials\",\"configuration\":\"Configuration\",\"human_in_the_loop\":\"Human in the Loop\",\"memory_and_learning\":\"Memory & Learning\",\"notifications\":\"Notifications\",\"domain\":\"Domain\",\"quality\":\"Quality\"}},\"sandbox_banner\":{\"community_sandbox\":\"Community Template -- Sandbox Mode\",\"event_emission_disabled\":\"Event emission disabled\",\"chain_triggers_disabled\":\"Chain triggers disabled\",\"webhook_triggers_disabled\":\"Webhook triggers disabled\",\"polling_triggers_disabled\":\"Polling triggers disabled\",\"human_review_required\":\"Human review required\",\"budget_cap_enforced\":\"Budget cap enforced\",\"max_concurrent_one\":\"Max {max} concurrent run\",\"max_concurrent_other\":\"Max {max} concurrent runs\"},\"n8n\":{\"credential_label\":\"Credential: {name}\",\"n8n_type_label\":\"n8n type: {type}\",\"test\":\"Test\",\"link_existing\":\"Link Existing\",\"add_new\":\"Add New\",\"best_match\":\"Best match\",\"other_credentials\":\"Other credentials\",\"no_stored_credentials\":\"No stored credentials found\",\"no_entities_selected\":\"No entities selected.\",\"go_back_to_analyze\":\"Go back to the Analyze step to select tools and triggers.\",\"entities_generated\":\"Entities generated by the transformation.\",\"entities_from_workflow\":\"Items from your n8n workflow associated with this persona.\",\"ready_count\":\"{count} ready\",\"missing_count\":\"{count} missing\",\"edit_selection\":\"Edit Selection\",\"test_all\":\"Test All\",\"connectors_count\":\"Connectors ({count})\",\"general_tools_count\":\"General Tools ({count})\",\"triggers_count\":\"Triggers ({count})\",\"no_use_cases_design\":\"No structured use cases found in design context.\",\"no_use_cases_yet\":\"No use cases generated yet.\",\"use_adjustment_hint\":\"Use the adjustment input below to request use case generation.\",\"use_cases_identified\":\"{count} use case(s) identified\",\"informational_only\":\"This use case is informational only\",\"view_example_output\":\"View example output\",\"test_use_case\":\"Test this use case\",\"save_to_test\":\"Save to test\",\"example_output\":\"Example output:\",\"no_sample_data\":\"// No sample data provided\",\"capabilities_label\":\"Capabilities\",\"request_ai_adjustments\":\"Request AI Adjustments\",\"adjustment_placeholder\":\"Example: Add more use cases, make error handling stricter...\",\"apply\":\"Apply\",\"use_cases_tab\":\"Use Cases\",\"tools_and_connectors_tab\":\"Tools & Connectors\",\"test_output\":\"Test Output\",\"lines_count\":\"{count} lines\",\"import_error\":\"Import Error\",\"dismiss\":\"Dismiss\",\"partial_session_restore\":\"Partial Session Restore\",\"analyzing_workflow\":\"Analyzing workflow and preparing transformation...\",\"usually_one_minute\":\"Usually takes about 1 minute\",\"import_another\":\"Import Another\",\"platform_confirm\":\"This looks like a {platform} workflow, but we're not 100% sure. Is that correct?\",\"yes_thats_right\":\"Yes, that's right\",\"no_reupload\":\"No, re-upload\",\"tools_count\":\"{count} tools\",\"triggers_count_summary\":\"{count} triggers\",\"connectors_count_summary\":\"{count} connectors\",\"selected_for_import\":\"selected for import\",\"tools_header\":\"Tools ({count})\",\"triggers_header\":\"Triggers ({count})\",\"connectors_header\":\"Connectors ({count})\",\"previous_imports\":\"Previous Imports\",\"sessions_count\":\"{count} session(s)\",\"retry_label\":\"Retry\",\"delete_session\":\"Delete session\",\"failed_to_load_imports\":\"Failed to load previous imports. Please retry.\",\"failed_to_delete_session\":\"Failed to delete session. Please retry.\",\"failed_to_load_session\":\"Failed to load session. Please retry.\",\"connectors_ready\":\"{ready} of {total} connector(s) ready\",\"no_credential\":\"No credential\",\"persona_preview\":\"Persona Preview\",\"unnamed_persona\":\"Unnamed Persona\",\"no_description\":\"No description provided\",\"tools_label\":\"Tools\",\"triggers_label\":\"Triggers\",\"connectors_label\":\"Connectors\",\"reviews_label\":\"Reviews\",\"memory_label\":\"Memory\",\"events_label\":\"Events\",\"tools_require_credentials\":\"{count} tool(s) require credentials not yet configured:\",\"system_prompt_preview\":\"System Prompt Preview\",\"confirm_hint\":\"Review the details above, then click \\\"Confirm & Save Persona\\\" to create.\",\"persona_created\":\"Persona Created Successfully\",\"persona_ready\":\"{name} is ready to use. Find it in the sidebar.\",\"entities_failed\":\"{count} entity/entities failed\",\"configure_connectors\":\"Configure connector(s): {names}\",\"upload_file\":\"Upload File\",\"paste_json\":\"Paste JSON\",\"from_url\":\"From URL\",\"drop_file_here\":\"Drop your workflow file here\",\"import_from_any_platform\":\"Import a workflow from any platform\",\"click_to_browse\":\"Click to browse or drag and drop your exported workflow\",\"dropzone_aria\":\"Drop workflow file or click to browse\",\"continue_btn\":\"Continue\",\"press_enter_or_click\":\"Press Enter or click to continue\",\"paste_workflow_json\":\"Paste workflow JSON\",\"paste_placeholder\":\"Paste your exported workflow JSON here...\",\"paste_aria\":\"Workflow JSON content\",\"import_btn\":\"Import\",\"import_from_url\":\"Import from URL\",\"url_description\":\"Paste a URL to a raw workflow JSON file. Supports GitHub raw URLs, Gist links, and direct JSON endpoints.\",\"fetching\":\"Fetching\",\"fetch\":\"Fetch\",\"accepts_label\":\"Accepts:\",\"url_aria\":\"Workflow URL\",\"url_placeholder\":\"https://raw.githubusercontent.com/.../workflow.json\",\"url_format_github\":\"github.com/*/blob/*\",\"url_format_gist\":\"gist.github.com/*\",\"url_format_raw\":\"raw JSON endpoint\",\"customize_persona\":\"A few questions to customize your persona\",\"answer_then_generate\":\"Answer below, then click Generate\",\"question_view_mode_aria\":\"Question view mode\",\"list_view\":\"List\",\"focus_view\":\"Focus\",\"no_config_needed\":\"No configuration needed\",\"click_generate_defaults\":\"Click Generate to create your persona draft with defaults.\",\"your_answers\":\"Your answers\",\"back\":\"Back\",\"review_and_confirm\":\"Review & Confirm\",\"persona_saved\":\"Persona Saved\",\"saving\":\"Saving...\",\"confirm_and_save\":\"Confirm & Save Persona\",\"connectors_need_credentials\":\"{count} connector(s) need credentials\",\"testing_btn\":\"Testing...\",\"test_passed\":\"Test Passed\",\"retest\":\"Retest\",\"test_persona\":\"Test Persona\",\"fix_and_regenerate\":\"Fix & Regenerate\",\"build_persona\":\"Build Persona\",\"analyzing_btn\":\"Analyzing...\",\"upload_step\":\"Upload\",\"analyze_step\":\"Analyze\",\"wizard_progress_aria\":\"Import wizard progress\",\"wizard_steps_aria\":\"Wizard steps\",\"streaming_sections\":\"Streaming Sections\",\"awaiting_next_section\":\"Awaiting next section...\",\"transform_progress_aria\":\"Transform progress\",\"analyze_phase\":\"Analyze\",\"questions_phase\":\"Questions\",\"generate_phase\":\"Generate\",\"type_your_answer\":\"Type your answer...\",\"navigate_hint\":\"Use arrow keys to navigate\"},\"diagram\":{\"no_flow_data\":\"No flow data available\",\"nodes_count\":\"{count} nodes\",\"edges_count\":\"{count} edges\",\"connectors_count\":\"{count} connector(s)\",\"decisions_count\":\"{count} decision(s)\",\"error_label\":\"Error\",\"request_label\":\"Request\",\"response_label\":\"Response\"},\"design\":{\"connectors_and_tools\":\"Connectors & Tools\",\"general_tools\":\"General Tools\",\"credential_ready\":\"Credential ready\",\"configure_credential\":\"Configure credential\",\"events_and_triggers\":\"Events & Triggers\",\"what_activates\":\"What activates this persona\",\"triggers_section\":\"Triggers\",\"event_subscriptions\":\"Event Subscriptions\",\"messages_and_notifications\":\"Messages & Notifications\",\"how_communicates\":\"How this persona communicates\",\"requires_connector\":\"Requires {name}\",\"feasibility_assessment\":\"Feasibility Assessment\",\"confirmed_capabilities\":\"Confirmed Capabilities\",\"issues_label\":\"Issues\",\"suggested_next_steps\":\"Suggested Next Steps\",\"summary\":{\"connectors\":\"Connectors\",\"tools\":\"Tools\",\"events\":\"Events\",\"channels\":\"Channels\",\"ready\":\"Ready\",\"partial\":\"Partial\",\"blocked\":\"Blocked\"}},\"card\":{\"use_cases_label\":\"Use Cases\",\"connectors_label\":\"Connectors\",\"triggers_label\":\"Triggers\",\"no_flows\":\"No flows\",\"none_label\":\"None\",\"more_count\":\"+{count} more\",\"system_prompt\":\"System Prompt\"},\"compare\":{\"compare\":\"Compare\",\"selected\":\"{count} selected\",\"clear\":\"Clear\",\"title\":\"Compare templates\",\"subtitle\":\"Side-by-side across {count} templates\",\"hint_min\":\"Select at least 2 templates to compare\",\"max_reached\":\"Compare up to {max} at once\",\"add_to_compare\":\"Add to compare\",\"remove_from_compare\":\"Remove from compare\",\"col_category\":\"Category\",\"col_goal\":\"Goal\",\"col_complexity\":\"Complexity\",\"col_setup\":\"Setup time\",\"none\":\"—\",\"differs\":\"Differs\"},\"matrix_grid\":{\"prerequisites\":\"Prerequisites\",\"all_set_start\":\"All set -- Start Adoption\",\"continue_to_adoption\":\"Continue to Adoption\",\"setup_in_wizard\":\"(setup in wizard)\",\"credential_configured\":\"Credential configured\",\"connector_not_installed\":\"Connector not installed\",\"needs_credential\":\"Needs credential\",\"setup_btn\":\"Setup\"},\"adopt_modal\":{\"adopt_template\":\"Adopt Template\",\"loading_template\":\"Loading template into matrix...\",\"discard_title\":\"Discard adoption progress?\",\"discard_body\":\"You have unsaved work in this adoption wizard. Closing now will discard your progress.\",\"discard_confirm\":\"Discard & Close\",\"dynamic_misconfigured_scope\":\"Misconfigured: source=scope requires from_scope + from_credential_question\",\"dynamic_pick_resource\":\"Open the {service} credential and pick at least one {resource}\",\"dynamic_connect_with_resource\":\"Connect a {category} credential and pick at least one {resource}\",\"dynamic_no_credential\":\"No healthy {category} credential connected\",\"dynamic_load_failed\":\"Failed to load options\",\"dynamic_unknown_error\":\"Unknown error\",\"scope_persona_heading\":\"Persona setup\",\"scope_capability_heading\":\"Capability: {subject}\",\"scope_connector_heading\":\"Connector: {subject}\",\"scope_unknown_subject\":\"unknown\",\"configure_your_persona\":\"Configure Your Persona\",\"cancel\":\"Cancel\",\"submit_all\":\"Submit All\",\"submit_remaining\":\"Submit All ({remaining} remaining)\",\"custom_btn\":\"Custom...\",\"type_custom_value\":\"Type your custom value...\",\"auto_detected\":\"Auto-detected from credentials\",\"credential_required\":\"No {category} credential found in your vault. Add one to continue.\",\"add_credential\":\"Add credential\",\"blocked_blocking_submit\":\"{count} question(s) need credentials before you can continue\",\"_comment_section_PersonaLayoutAdoption\":\"── ChronologyAdoptionView.tsx + persona-layout/PersonaLayoutAdoption.tsx ──\",\"_comment_layout_tab_label\":\"Label preceding the adoption layout switcher\",\"layout_tab_label\":\"Layout\",\"_comment_layout_tab_classic\":\"Classic three-step adoption flow (default)\",\"layout_tab_classic\":\"Classic\",\"_comment_layout_tab_persona_layout\":\"Persona Layout single-screen adoption prototype (Persona Sigil + Capability Sigil rows)\",\"layout_tab_persona_layout\":\"Persona Layout\",\"_comment_layout_tab_prototype_badge\":\"Small badge marking the Persona Layout tab as a prototype\",\"layout_tab_prototype_badge\":\"Prototype\",\"_comment_persona_layout_continue_to_build\":\"Action button to leave pre-seed and start the build\",\"persona_layout_continue_to_build\":\"Continue to Build\",\"_comment_persona_layout_edit_questions\":\"Action returning to the Classic tab to edit answers\",\"persona_layout_edit_questions\":\"Edit questions\",\"_comment_persona_layout_continue_blocked\":\"Tooltip explaining why Continue is disabled when credentials are missing\",\"persona_layout_continue_blocked\":\"{count} blocked by missing credentials\",\"_comment_persona_layout_continue_remaining\":\"Tooltip explaining how many questions still need answers\",\"persona_layout_continue_remaining\":\"{count} unanswered\",\"_comment_persona_layout_continue_no_capabilities\":\"Tooltip when zero capabilities are selected\",\"persona_layout_continue_no_capabilities\":\"Enable at least one capability\",\"_comment_persona_layout_no_capabilities\":\"Empty state when no capabilities are enabled in adoption\",\"persona_layout_no_capabilities\":\"All capabilities are skipped — enable at least one to continue.\",\"_comment_left_connections_heading\":\"Heading above the connector card in the adoption left panel — keep 1 word\",\"left_connections_heading\":\"Connections\",\"_comment_left_messages_heading\":\"Heading above the messaging-channels card in the adoption left panel — keep 1 word\",\"left_messages_heading\":\"Messages\",\"_comment_left_empty_placeholder\":\"Muted placeholder in the adoption left panel before anything is configured\",\"left_empty_placeholder\":\"Your selections appear here as you configure each dimension.\",\"_comment_capabilities_aria\":\"Accessibility label for the adoption capability tab strip\",\"capabilities_aria\":\"Capabilities\",\"_comment_capability_include\":\"Tooltip on the per-capability power toggle when the capability is skipped — click to include it\",\"capability_include\":\"Include capability\",\"_comment_capability_skip\":\"Tooltip on the per-capability power toggle when the capability is on — click to skip it\",\"capability_skip\":\"Skip capability\",\"_comment_policy_on\":\"On/off state shown for the Memory and Review dimensions — keep 1 word\",\"policy_on\":\"On\",\"policy_off\":\"Off\",\"_comment_schedule_hourly\":\"Schedule summary label for the hourly cadence — keep short\",\"schedule_hourly\":\"Hourly\",\"_comment_events_count\":\"Count of cross-persona event subscriptions configured for a capability\",\"events_count_one\":\"{count} event\",\"events_count_other\":\"{count} events\",\"story_no_questions\":\"No questions for this capability.\",\"error_policy\":{\"title\":\"Error handling\",\"intro\":\"Retry and self-healing are automatic. Choose what happens when a failure can't recover on its own.\",\"incident_label\":\"Raise an incident\",\"incident_desc\":\"Surface unrecovered failures in the Incidents inbox for triage.\",\"lab_label\":\"Send to the Lab\",\"lab_desc\":\"Turn on Lab auto-improvement (prompt evolution) for this persona when failures recur.\",\"escalate_after_label\":\"Escalate after\",\"escalate_after_desc\":\"Consecutive failures before raising an incident or sending to the Lab.\"},\"_comment_persona_layout_dim_all_answered\":\"Footer state when every question in the active dim is answered\",\"persona_layout_dim_all_answered\":\"All questions for this dimension answered\",\"_comment_persona_layout_dim_done\":\"Action button to close the answer card after all dim questions are answered\",\"persona_layout_dim_done\":\"Done\",\"_comment_persona_layout_dim_disable\":\"Footer toggle in the petal answer modal: disable this dimension for the active capability — keep 1 word\",\"persona_layout_dim_disable\":\"Disable\",\"persona_layout_dim_disabled\":\"Disabled\",\"_comment_persona_layout_dim_disable_title\":\"Tooltip on the Disable toggle when the dimension is currently enabled\",\"persona_layout_dim_disable_title\":\"Disable this sigil for the active capability — its questions will be skipped during build.\",\"_comment_persona_layout_dim_reenable\":\"Tooltip on the Disable toggle when the dimension is currently disabled\",\"persona_layout_dim_reenable\":\"Re-enable this sigil for the active capability — its questions will reappear.\",\"_comment_persona_layout_rail_activated\":\"Left quick-action rail info box: shown next to the Memory/Review petal icon when that dimension is enabled — keep 1 word\",\"persona_layout_rail_activated\":\"Activated\",\"_comment_persona_layout_dim_open_hint\":\"Hint shown when there are pending questions but no petal is active\",\"persona_layout_dim_open_hint\":\"Click a glowing petal to answer its questions.\",\"_comment_persona_layout_center_questions_to_answer_one\":\"Center-button label (singular) below the count when there is 1 unanswered adoption question\",\"persona_layout_center_questions_to_answer_one\":\"question to answer\",\"_comment_persona_layout_center_questions_to_answer_other\":\"Center-button label (plural) below the count when there are N unanswered adoption questions\",\"persona_layout_center_questions_to_answer_other\":\"questions to answer\",\"_comment_persona_layout_center_click_to_start\":\"Italic hint below the center count button\",\"persona_layout_center_click_to_start\":\"Click to start\",\"_comment_persona_layout_center_open_questions_title\":\"Native browser tooltip on the center count button\",\"persona_layout_center_open_questions_title\":\"Open the first unanswered question\",\"_comment_persona_layout_build_draft_ready_hint\":\"Hint shown in sigil center when build is ready for testing — translator: short, italic\",\"persona_layout_build_draft_ready_hint\":\"Draft ready — start the test suite or request a change below.\",\"_comment_persona_layout_build_refine_default_prompt\":\"Placeholder refine prompt when the user hits Refine without typing — generic 'rework this' nudge to the build engine\",\"persona_layout_build_refine_default_prompt\":\"Please review and propose improvements to this persona's design.\",\"_comment_persona_layout_build_view_logs\":\"Button label to open the rich test-results modal\",\"persona_layout_build_view_logs\":\"View Logs\",\"_comment_persona_layout_build_refine_placeholder\":\"Textarea placeholder for the inline refine composer\",\"persona_layout_build_refine_placeholder\":\"Describe what to change (e.g. switch to weekly cadence, add Slack notifications)…\",\"_comment_persona_layout_build_refine_send\":\"Send button label for the inline refine composer\",\"persona_layout_build_refine_send\":\"Send & rebuild\",\"credentials_required_title\":\"Credentials required\",\"credentials_required_body\":\"This template needs at least one credential from each category below before adoption can continue. Click \\\"Add credential\\\" — you'll return here automatically once setup is complete.\",\"answered_of_total\":\"{answered} / {total} answered\",\"blocked_count\":\"{count} blocked\",\"question_number_of\":\"Question {current} of {total}\",\"question_number_aria\":\"Question {number}\",\"navigate_hint\":\"to navigate\",\"enter_to_advance\":\"to advance\",\"previous\":\"Previous\",\"next\":\"Next\",\"live_preview\":\"Live preview\",\"persona_label\":\"Persona\",\"untitled_agent\":\"Untitled agent\",\"not_yet_set\":\"Not yet set\",\"auto_badge\":\"auto\",\"jump_to_question_hint\":\"Click any row above to jump to that question. Auto-detected values are inferred from your connected credentials.\",\"hide_explanation\":\"Hide explanation\",\"show_explanation\":\"Show explanation\",\"all_option\":\"All\",\"add_custom\":\"Add\",\"custom_prefix\":\"+ Other…\",\"custom_plain\":\"Other…\",\"type_your_answer\":\"Type your answer...\",\"describe_in_detail\":\"Describe in detail...\",\"select_directory\":\"Select a directory...\",\"type_a_value\":\"Type a value…\",\"preparing\":\"Preparing…\",\"waiting_for_parent\":\"Waiting for earlier answer…\",\"loading_from_service\":\"Loading options from {service}…\",\"loaded_live_from\":\"Loaded live from {service}\",\"no_items_found\":\"No {item} found. Create one in {service} first.\",\"retry\":\"Retry\",\"source_local\":\"Local file or folder\",\"source_codebase\":\"Codebase\",\"source_database\":\"Database\",\"source_local_hint\":\"Paste a full local path to a file or folder.\",\"source_codebase_hint\":\"Pick a project registered in Dev Tools.\",\"source_database_hint\":\"Pick a database credential from your vault.\",\"source_local_placeholder\":\"/Users/me/project/design.md\",\"source_no_codebases\":\"No Dev Tools projects found. Register one in Dev Tools first.\",\"source_no_databases\":\"No database credentials found. Add one in the Vault first.\",\"source_pick_codebase\":\"Select a codebase…\",\"source_pick_database\":\"Select a database…\",\"use_cases_title\":\"Which capabilities should this persona handle?\",\"use_cases_subtitle\":\"Turn off anything you don't need. Disabled capabilities won't appear in the matrix and their setup questions are skipped.\",\"use_cases_continue\":\"Continue\",\"use_cases_enabled_count\":\"{count} of {total} enabled\",\"use_cases_none_selected\":\"Enable at least one capability to continue\",\"use_case_enabled_badge\":\"On\",\"use_case_disabled_badge\":\"Off\"},\"scan\":{\"scanning_draft\":\"Scanning persona draft...\",\"checking_unsafe\":\"Checking for malicious instructions and unsafe patterns\",\"scan_passed\":\"Safety scan passed\",\"no_concerns\":\"No security concerns detected in this persona draft\",\"info_notes\":\"{count} informational note(s) for review\",\"critical_issues\":\"Critical security issues detected\",\"security_warnings\":\"Security warnings detected\",\"review_findings\":\"review findings before creating this persona\",\"critical_label\":\"Critical\",\"warnings_label\":\"Warnings\",\"informational_label\":\"Informational\",\"source_label\":\"Source:\"},\"team_synthesis\":{\"title\":\"Synthesize Team\",\"subtitle\":\"AI selects templates and assembles a connected team\",\"team_name_label\":\"Team Name\",\"team_name_placeholder\":\"e.g., Content Pipeline Team\",\"describe_team\":\"Describe what this team should do\",\"describe_placeholder\":\"e.g., Monitor social media mentions, analyze sentiment, generate reports, and send alerts to Slack when negative trends are detected\",\"synthesizing\":\"Synthesizing...\",\"synthesize_team\":\"Synthesize Team\",\"personas_created\":\"{count} personas created and connected\",\"done\":\"Done\"},\"matrix_variants\":{\"processing\":\"Processing...\",\"run_test\":\"RUN TEST\",\"testing_dots\":\"TESTING...\",\"approve_label\":\"APPROVE\",\"view_agent_label\":\"VIEW AGENT\",\"no_data_yet\":\"No data yet\",\"completeness\":\"Completeness\",\"start_test\":\"Start Test\",\"testing_agent\":\"Testing agent...\",\"approve_and_promote\":\"Approve & Promote\",\"view_agent_btn\":\"View Agent\",\"command_center_header\":\"COMMAND CENTER // BUILD v1.0\",\"phase_label\":\"[PHASE: {phase}]\",\"your_answer_placeholder\":\"Your answer...\",\"testing_background_hint\":\"This may take a few minutes. You can leave this page and come back later — testing continues in the background.\",\"cancel_test\":\"Cancel Test\",\"missing_keys\":\"Missing keys:\",\"approve_anyway\":\"Approve Anyway\",\"delete_draft_title\":\"Discard this draft persona and close\",\"delete_draft\":\"Delete Draft\",\"agent_promoted\":\"Agent Promoted\",\"view_agent\":\"View Agent\",\"refine_placeholder\":\"Describe what changed (e.g. 'Added API key')...\",\"retry_with_changes\":\"Retry\"},\"chronology\":{\"prototype_label\":\"Prototype:\",\"back_to_legacy\":\"← Back to legacy\",\"empty_seeding\":\"No capabilities yet — the template is still seeding.\",\"dim_trigger\":\"Trigger\",\"dim_task\":\"Task\",\"dim_connector\":\"Connector\",\"dim_apps\":\"Apps & Services\",\"dim_message\":\"Message\",\"dim_messages\":\"Messages\",\"dim_review\":\"Review\",\"dim_human_review\":\"Human Review\",\"dim_memory\":\"Memory\",\"dim_event\":\"Event\",\"dim_events\":\"Events\",\"dim_error\":\"Error\",\"dim_error_handling\":\"Error Handling\",\"dim_description\":\"Description\",\"dim_flow\":\"Flow\",\"short_trig\":\"Trig\",\"short_task\":\"Task\",\"short_apps\":\"Apps\",\"short_msg\":\"Msg\",\"short_rev\":\"Rev\",\"short_mem\":\"Mem\",\"short_evt\":\"Evt\",\"short_err\":\"Err\",\"trigger_schedule\":\"Schedule\",\"trigger_webhook\":\"Webhook\",\"trigger_manual\":\"Manual\",\"trigger_polling\":\"Polling\",\"trigger_event\":\"Event\",\"trigger_file_watch\":\"File watch\",\"trigger_app_focus\":\"App focus\",\"manual_only\":\"Manual only\",\"no_external_services\":\"No external services\",\"not_configured\":\"{label}: not configured\",\"presence_tooltip\":\"{label}: {state}\",\"presence_linked\":\"linked\",\"presence_shared\":\"shared\",\"presence_none\":\"not set\",\"sigil_group_aria\":\"Persona dimensions\",\"off_badge\":\"Off\",\"shared_badge\":\"Shared\",\"flow_label\":\"Flow · {count} steps\",\"dims_ratio\":\"{count}/8 dims\",\"dims_label\":\"DIMS\",\"legend_label\":\"Legend\",\"legend_semantics\":\"filled = linked · dashed = shared · hollow = none\",\"hub_phase_idle\":\"Idle\",\"hub_phase_initializing\":\"Initializing\",\"hub_phase_analyzing\":\"Analyzing\",\"hub_phase_awaiting_input\":\"Awaiting Input\",\"hub_phase_resolving\":\"Resolving\",\"hub_phase_draft_ready\":\"Draft Ready\",\"hub_phase_testing\":\"Testing\",\"hub_phase_test_complete\":\"Test Complete\",\"hub_phase_completed\":\"Completed\",\"hub_phase_promoted\":\"Promoted\",\"hub_phase_failed\":\"Failed\",\"hub_phase_cancelled\":\"Cancelled\",\"hub_completeness_label\":\"Completeness\",\"hub_pending_questions\":\"{count} question(s) need an answer\",\"hub_answer_placeholder\":\"Your answer…\",\"hub_submit_answer\":\"Submit\",\"hub_test_running_hint\":\"Testing continues in the background — you can close and return.\",\"hub_no_activity\":\"Waiting for activity…\",\"hub_details_show\":\"Show details\",\"hub_details_hide\":\"Hide details\",\"hub_draft_ready_title\":\"Draft ready\",\"hub_draft_ready_subtitle\":\"Review, request changes, or run the test suite.\",\"hub_request_change\":\"Request change\",\"hub_refine_placeholder\":\"Describe what to change (e.g. 'Switch to weekly cadence, add Slack notifications')…\",\"hub_refine_send\":\"Send & rebuild\",\"hub_delete_draft\":\"Delete draft\",\"open_flow_diagram\":\"Open flow diagram\",\"panel_back\":\"Back to the sigil\",\"refine_dim_label\":\"Refine this dimension\",\"refine_dim_placeholder\":\"Describe the change — e.g. 'switch review to on_uncertainty only'\",\"refine_dim_apply\":\"Apply on rebuild\",\"build_locked_note\":\"Build in progress — dimensions are locked until the rebuild completes.\",\"no_dim_editor_note\":\"No editor wired for this dim yet — toggle below adjusts its active state on this capability.\",\"answer_own_words_placeholder\":\"Answer in your own words…\",\"empty_trigger\":\"No trigger configured\",\"empty_steps\":\"No steps defined\",\"empty_connectors\":\"No connectors configured\",\"empty_channels\":\"No channels configured\",\"empty_review\":\"No review policy\",\"empty_memory\":\"Memory not configured\",\"empty_events\":\"No event subscriptions\",\"empty_error\":\"No error handler\",\"empty_generic\":\"No content\",\"empty_dim_refine_hint\":\"Want it? Describe it below — it's added on the next rebuild.\",\"show_n_more\":\"+{count} more\",\"show_less\":\"Show less\"},\"diagrams\":{\"no_flow_data\":\"No flow data available\",\"nodes_count\":\"{count} nodes\",\"edges_count\":\"{count} edges\",\"connectors_count\":\"{count} connector(s)\",\"decisions_count\":\"{count} decision(s)\",\"close_dialog\":\"Close dialog\"},\"generation\":{\"create_template\":\"Create Template\",\"create_template_subtitle\":\"Design a reusable persona template with AI\",\"template_name_label\":\"Template Name\",\"run_design_review\":\"Run Design Review\",\"cancel\":\"Cancel\",\"close\":\"Close\",\"generating\":\"Generating: {name}\",\"running\":\"Running...\",\"runner_title\":\"Run Design Review\",\"runner_running\":\"Running tests...\",\"runner_complete\":\"Review complete\",\"runner_configure\":\"Configure and start a review run\",\"no_persona_warning\":\"No persona selected. Select a persona in the sidebar before generating templates.\",\"template_progress\":\"Template {current} of {total}\",\"generating_template\":\"Generating: {name}\",\"start_review_predefined\":\"Start Review (5 cases)\",\"start_batch\":\"Start Batch ({count} template{plural})\",\"start_review_custom\":\"Start Review ({count} case{plural})\",\"copy_log\":\"Copy Log\",\"copied_log\":\"Copied!\",\"back\":\"Back\",\"generate_template\":\"Generate Template\",\"view_draft\":\"View Draft\",\"saving\":\"Saving...\",\"save_template\":\"Save Template\",\"template_saved\":\"Template Saved\",\"template_name_label_step\":\"Template Name\",\"template_name_placeholder\":\"Template name...\",\"description_label\":\"Description\",\"description_placeholder\":\"Describe what this persona should do, what services it connects to, and how it should behave. Be specific about tools, triggers, and integrations needed.\\n\\nExample: A persona that monitors a Gmail inbox for important emails, classifies them by priority, sends Slack notifications for urgent ones, and creates a daily digest summary.\",\"description_hint\":\"The AI will generate a full persona template including system prompt, tools, triggers, connectors, and template variables.\",\"terminal_aria_label\":\"Design review output\",\"terminal_placeholder\":\"Output will appear here when the review starts\",\"terminal_running\":\"Running...\",\"result_passed\":\"{count} passed\",\"result_failed\":\"{count} failed\",\"result_errored\":\"{count} errors\",\"result_total\":\"{count} total tests\",\"mode_predefined\":\"Predefined (5)\",\"mode_custom\":\"Custom\",\"mode_batch\":\"Batch\",\"mode_batch_count\":\"Batch ({count})\",\"batch_upload_hint\":\"Upload a list.md file with numbered template entries to batch-generate templates via Claude CLI.\",\"batch_upload_btn\":\"Upload list.md\",\"batch_format_hint\":\"Expected format:\",\"batch_all\":\"All ({count})\",\"batch_count\":\"{count} template{plural} will be generated via Claude CLI (~45s each)\",\"batch_clear\":\"Clear\",\"batch_format_example\":\"**1. Template Name**\",\"custom_count\":\"Define template use cases ({count} ready)\",\"custom_load_file_title\":\"Load from .txt or .md file\",\"custom_load_file\":\"Load file\",\"custom_add\":\"Add\",\"custom_case_name_placeholder\":\"Template name (e.g. Gmail Smart Filter)\",\"custom_instruction_placeholder\":\"Describe what this persona should do, which services to integrate, and what triggers should activate it...\",\"custom_short_instruction\":\"{current}/{min} characters minimum\",\"custom_category_default\":\"Category...\",\"custom_trigger_default\":\"Trigger...\",\"custom_connectors_placeholder\":\"Connectors (e.g. gmail, slack)\",\"custom_detail_hint\":\"More detail = better results. Include services, triggers, and expected behavior.\",\"custom_show_example\":\"Show example\",\"custom_hide_example\":\"Hide example\",\"custom_example_title\":\"Example: Gmail Smart Filter\",\"custom_example_body\":\"\\\"Create an agent that monitors Gmail for important emails, categorizes them by sender and urgency, applies labels, and forwards urgent ones to Slack. Use polling trigger with gmail and slack connectors.\\\"\",\"predefined_intro\":\"Runs {count} predefined use cases through the design engine:\"},\"connector_edit\":{\"credential\":\"Credential\",\"select_credential\":\"Select credential...\",\"connector_type\":\"Connector Type\",\"switch_connector\":\"Switch connector...\",\"no_credentials\":\"No credentials available for this connector\",\"add_in_catalog\":\"Add in Keys Catalog\",\"database\":\"Database\",\"new_table\":\"New\",\"existing_table\":\"Existing\",\"configure_table\":\"Configure table...\",\"existing_table_label\":\"Existing Table\",\"schema\":\"Schema\",\"table_name\":\"Table name\",\"table_name_placeholder\":\"e.g. persona_data\",\"in_app_messages\":\"In-App Messages\",\"not_connected\":\"not connected\",\"no_connectors\":\"No connectors required\",\"linked\":\"Linked\",\"set_up\":\"Set up\",\"more_connectors\":\"+{count} more\"},\"trigger_edit\":{\"schedule\":\"Schedule\",\"schedule_placeholder\":\"Every weekday at 9am\",\"schedule_hint\":\"Natural language or cron (e.g. \\\"0 9 * * 1-5\\\")\",\"webhook_url\":\"Webhook URL\",\"webhook_url_placeholder\":\"https://...\",\"check_interval\":\"Check Interval\",\"check_interval_placeholder\":\"Every 5 minutes\",\"no_config_needed\":\"No configuration needed\",\"manual_only\":\"Manual execution only\"},\"preset_edit\":{\"select_review\":\"Select review policy...\",\"select_memory\":\"Select memory strategy...\",\"notification_strategy\":\"Notification strategy...\",\"error_handling\":\"Error handling...\",\"add_use_case\":\"Add use case...\",\"halt_on_error\":\"Halt on error\",\"retry_once\":\"Retry once\",\"retry_3x\":\"Retry 3x\",\"notify_continue\":\"Notify & continue\",\"skip_failed\":\"Skip failed step\"},\"test_report\":{\"title\":\"Test Report\",\"passed\":\"{count} passed\",\"failed\":\"{count} failed\",\"skipped\":\"{count} skipped\",\"test_scope\":\"Test Scope\",\"overview\":\"Overview\",\"analysis\":\"Analysis\",\"results\":\"Results\",\"next_steps\":\"Next Steps\",\"connector_credentials\":\"Connector Credentials\",\"matched\":\"matched\",\"not_found\":\"not found\",\"missing_keys_hint\":\"Add missing API keys in the Keys section before approving this agent.\",\"connected_successfully\":\"Connected Successfully\",\"needs_credentials\":\"Needs Credentials\",\"add_keys_hint\":\"Add the required API keys in the Keys section to enable these tools.\",\"connection_failed\":\"Connection Failed\",\"builtin_no_test\":\"Built-in (No Test Needed)\",\"what_happened\":\"What happened\",\"service\":\"Service\",\"response_preview\":\"Response Preview\",\"error_detail\":\"Error Detail\",\"copy\":\"Copy\",\"copied\":\"Copied\",\"add_key\":\"Add Key\",\"key_added\":\"Added\",\"add_key_for\":\"Add credential for {connector}\",\"latency_fast\":\"Fast\",\"latency_ok\":\"OK\",\"latency_slow\":\"Slow\",\"status_passed\":\"Passed\",\"status_skipped_builtin\":\"Skipped (Built-in)\",\"status_needs_credential\":\"Needs Credential\",\"status_failed\":\"Failed\",\"verified_default\":\"Connection verified successfully.\",\"skipped_builtin_default\":\"This tool uses built-in capabilities and does not require an external API connection to test.\",\"credential_missing_default\":\"**{subject}** needs credentials. Open the **Keys** section to add or refresh them.\",\"fail_default\":\"Could not connect to the service.\",\"http_hint_auth\":\"Authentication issue\",\"http_hint_not_found\":\"Endpoint not found\",\"http_hint_rate_limited\":\"Rate limited\",\"http_hint_service_error\":\"Service error\",\"friendly_auth_failed\":\"Authentication failed. Your credentials may have expired. Go to **Keys** to refresh them.\",\"friendly_endpoint_not_found\":\"The API endpoint could not be found. The service configuration may need updating.\",\"friendly_rate_limited\":\"The service rate-limited the request. This is temporary — try again in a few minutes.\",\"friendly_service_error\":\"The service is currently experiencing issues. This is not a problem with your agent — try again later.\",\"friendly_timeout\":\"The connection timed out. The service may be slow or unavailable right now.\",\"friendly_missing_credentials\":\"Missing credentials. Go to **Keys** to add the required service credentials.\"},\"complexity\":{\"beginner\":\"Beginner\",\"intermediate\":\"Intermediate\",\"advanced\":\"Advanced\",\"minuteShort\":\"~{minutes}m\",\"minuteSetup\":\"~{minutes} min setup\"}}",
-  "triggers": "{\"title\":\"Triggers & Automations\",\"subtitle\":\"Set up what causes your agents to run automatically\",\"builder\":{\"add_persona_action\":\"Add persona\",\"auto_layout\":\"Auto layout\",\"cancel\":\"Cancel\",\"canvas_templates\":\"Canvas Templates\",\"connected_listeners\":\"Connected listeners\",\"connected_personas\":\"Connected personas\",\"current_name\":\"Current name\",\"custom_event_placeholder\":\"custom.event.type\",\"done\":\"Done\",\"double_click_edit\":\"Double-click to edit\",\"events\":\"Events\",\"filter_events_placeholder\":\"Filter events...\",\"filter_personas_placeholder\":\"Filter personas...\",\"hide_sidebar\":\"Hide sidebar\",\"impact_preview\":\"Impact preview\",\"init_handlers_title\":\"Initial handlers\",\"layout\":\"Layout\",\"marketplace\":\"Marketplace\",\"new_name\":\"New name\",\"no_events_filter\":\"No events match filter\",\"no_matches\":\"No matches\",\"no_matching_events\":\"No matching events\",\"no_personas_connected\":\"No personas connected\",\"no_personas_created\":\"No personas created\",\"note_placeholder\":\"Add a note...\",\"personas\":\"Personas\",\"refresh\":\"Refresh\",\"rename\":\"Rename\",\"rename_event_action\":\"Rename event\",\"rename_event_desc\":\"Update the event type across all references.\",\"rename_event_type\":\"Rename event type\",\"rename_placeholder\":\"new.event.type\",\"renaming\":\"Renaming...\",\"show_sidebar\":\"Show sidebar\",\"source\":\"Type\",\"source_personas\":\"Source personas\",\"use_template\":\"Use template\"},\"studio\":{\"sources_title\":\"Sources\",\"sources_subtitle\":\"What starts the chain\",\"targets_title\":\"Targets\",\"targets_subtitle\":\"Which persona runs\",\"group_signals\":\"Signals\",\"group_personas\":\"Personas\",\"group_after_persona\":\"After a persona completes\",\"source_persona_hint\":\"fires when this persona finishes\",\"persona_completes\":\"completes\",\"group_system_events\":\"Events\",\"system_events_subtitle\":\"Run a built-in op on a trigger\",\"system_events_empty\":\"No system operations available\",\"system_event_needs_trigger\":\"System events run on a time or event trigger — arm a Schedule or Event Listener source.\",\"system_event_label\":\"System event\",\"system_event_commit_title\":\"Schedule system event\",\"commit_project_label\":\"Project\",\"commit_no_projects\":\"Create a dev-tools project first.\",\"commit_cadence_label\":\"Cadence\",\"cadence_weekly\":\"Weekly (Mon 03:00)\",\"cadence_daily\":\"Daily (03:00)\",\"cadence_hourly\":\"Hourly\",\"cadence_custom\":\"Custom cron…\",\"commit_event_label\":\"On event\",\"commit_event_placeholder\":\"e.g. deploy_completed\",\"commit_delta_label\":\"Incremental (changed files only)\",\"commit_create\":\"Create automation\",\"commit_created_toast\":\"System event scheduled\",\"commit_failed_toast\":\"Couldn't create the automation\",\"automations_title\":\"Active system events\",\"automation_ran\":\"ran\",\"automation_failed\":\"failed\",\"automation_next\":\"next\",\"automation_idle\":\"waiting for its event\",\"automation_run_now\":\"Run now\",\"automation_enabled\":\"Enabled\",\"automation_delete\":\"Delete automation\",\"routes_title\":\"Routes\",\"existing_routes\":\"Existing routes\",\"proto_tab_patchbay\":\"Patchbay\",\"proto_tab_eventhub\":\"Event hub\",\"proto_tab_baseline\":\"Compose only\",\"proto_live\":\"live\",\"proto_pending\":\"pending\",\"proto_add_listener\":\"Add listener\",\"proto_disconnect\":\"Disconnect listener\",\"proto_rename_event\":\"Rename event\",\"proto_no_listeners\":\"No listeners\",\"proto_show_unconnected\":\"Show {count} unconnected\",\"proto_hide_unconnected\":\"Hide unconnected\",\"proto_chain_route\":\"Chain route — fires when the source persona completes; the event isn't renameable\",\"tab_compose\":\"Compose\",\"tab_routes\":\"Routes\",\"clear_all\":\"Clear all\",\"commit_route\":\"Save this route\",\"commit_all\":\"Save all\",\"route_committed\":\"Route saved\",\"routes_committed\":\"Saved {count} routes\",\"route_commit_failed\":\"Couldn't save route\",\"commit_blocked_signal\":\"Signal-source routes need a trigger config — coming soon\",\"commit_blocked_output_match\":\"Output-match routes need a match expression — coming soon\",\"pick_a_source\":\"pick a source\",\"pick_a_target\":\"pick a target\",\"cancel_pending_route\":\"Cancel pending route\",\"no_routes_title\":\"No routes yet\",\"no_routes_desc\":\"Arm a source on the left, then click a target persona on the right. The route patches in here.\",\"cycle_condition_hint\":\"Click to cycle the run condition\",\"condition_always\":\"always\",\"condition_on_success\":\"on success\",\"condition_on_failure\":\"on failure\",\"condition_output_match\":\"if output matches\",\"remove_route\":\"Remove route\",\"filter_placeholder\":\"Filter…\",\"no_sources_match\":\"No sources match \\\"{query}\\\"\",\"no_targets_match\":\"No personas match \\\"{query}\\\"\",\"agent_fallback\":\"Agent\",\"configure_commit_route\":\"Configure & save this route\",\"form_commit_title\":\"Save route\",\"form_commit_subtitle\":\"Configure the trigger that will run {persona}\",\"om_path_placeholder\":\"$.result.status\",\"om_path_label\":\"JSONPath into the source output\",\"om_expected_label\":\"Expected value\",\"om_expected_placeholder\":\"expected value\",\"commit_blocked_source_type\":\"This source can't be saved from the Studio — for completion chains, arm the source persona instead\",\"commit_needs_output_match\":\"Enter a JSONPath and expected value to save this route\",\"group_marketplace\":\"Marketplace\",\"marketplace_source_hint\":\"Runs the persona when this feed reports a change\",\"marketplace_empty\":\"No subscribed feeds yet — subscribe in the Marketplace to wire them into personas.\",\"reflection_label\":\"Memory reflection\",\"reflect_scope_label\":\"Reflect over\",\"reflect_scope_agent\":\"Agent\",\"reflect_scope_team\":\"Team\",\"reflect_pick_agent\":\"Pick an agent…\",\"reflect_pick_team\":\"Pick a team…\",\"reflect_scope_hint\":\"Consolidates the chosen memory pool into durable insights. The result is a proposal you review in Overview → Memories — nothing changes without your approval.\"},\"subscription_list\":{\"active\":\"Active\",\"browse_marketplace\":\"Browse marketplace\",\"col_actions\":\"Actions\",\"col_event_type\":\"Event Type\",\"col_events\":\"Events\",\"col_feed\":\"Feed\",\"col_last_event\":\"Last Event\",\"col_status\":\"Status\",\"error\":\"Error\",\"never\":\"Never\",\"no_active_subs\":\"No active subscriptions\",\"unsubscribe\":\"Unsubscribe\"},\"type_selector\":{\"trigger_type\":\"Trigger Type\"},\"tab_triggers\":\"Triggers\",\"tab_chains\":\"Automations\",\"tab_subscriptions\":\"Subscriptions\",\"health_failing\":\"One or more triggers failing\",\"health_degraded\":\"One or more triggers degraded\",\"category_label\":\"Trigger Category\",\"category_manual\":\"Manual\",\"category_manual_hint\":\"Run on demand\",\"type_label\":\"Trigger Type\",\"quick_templates\":\"Quick Templates\",\"type_manual\":\"Manual\",\"type_schedule\":\"Schedule\",\"type_webhook\":\"Webhook\",\"type_polling\":\"Auto-Check\",\"type_event_listener\":\"Event Listener\",\"type_file_watcher\":\"File Watcher\",\"type_clipboard\":\"Clipboard\",\"type_app_focus\":\"App Focus\",\"type_chain\":\"Chain\",\"type_composite\":\"Combined\",\"desc_manual\":\"Run on demand\",\"desc_schedule\":\"Run on a timer or cron\",\"desc_polling\":\"Check an endpoint\",\"desc_webhook\":\"HTTP webhook listener\",\"desc_event_listener\":\"React to internal events\",\"desc_file_watcher\":\"React to file system changes\",\"desc_clipboard\":\"React to clipboard changes\",\"desc_app_focus\":\"React to app focus changes\",\"desc_chain\":\"Trigger after another agent completes\",\"desc_composite\":\"Multiple conditions + time window\",\"category_pull\":\"Watch\",\"category_push\":\"Listen\",\"category_compose\":\"Combine\",\"category_pull_desc\":\"Poll for changes on an interval\",\"category_push_desc\":\"Receive external signals\",\"category_compose_desc\":\"Chain or compose triggers\",\"rate_per_minute\":\"Per minute\",\"rate_per_5_minutes\":\"Per 5 minutes\",\"rate_per_hour\":\"Per hour\",\"tpl_fw_error_logs\":\"Auto-analyze error logs\",\"tpl_fw_error_logs_desc\":\"Triggers when new .log files appear or change in a folder\",\"tpl_fw_csv_data\":\"Process new CSV files\",\"tpl_fw_csv_data_desc\":\"Triggers when CSV files are added or modified\",\"tpl_fw_config_changes\":\"Watch config file changes\",\"tpl_fw_config_changes_desc\":\"Triggers on changes to JSON, YAML, or TOML config files\",\"tpl_cb_url_summarize\":\"Auto-summarize copied URLs\",\"tpl_cb_url_summarize_desc\":\"Triggers when you copy a URL to your clipboard\",\"tpl_cb_error_message\":\"Auto-diagnose error messages\",\"tpl_cb_error_message_desc\":\"Triggers when you copy text containing errors or exceptions\",\"tpl_cb_code_snippet\":\"Auto-format code snippets\",\"tpl_cb_code_snippet_desc\":\"Triggers when you copy code-like text (function definitions, imports)\",\"webhook_listener\":\"webhook listener\",\"custom_endpoint\":\"custom endpoint\",\"every_interval\":\"every {interval}\",\"from_source\":\"from {source}\",\"schedule\":{\"interval_label\":\"Interval\",\"preset_1m\":\"1 min\",\"preset_5m\":\"5 min\",\"preset_15m\":\"15 min\",\"preset_1h\":\"1 hour\",\"preset_6h\":\"6 hours\",\"preset_24h\":\"24 hours\",\"custom\":\"Custom\",\"mode_interval\":\"Interval\",\"mode_cron\":\"Cron\",\"cron_label\":\"Schedule Expression\",\"cron_placeholder\":\"0 9 * * 1-5\",\"cron_loading\":\"Previewing...\",\"cron_weekday_9am\":\"Weekdays 9am\",\"cron_every_hour\":\"Every hour\",\"cron_daily_midnight\":\"Daily midnight\",\"cron_weekly_monday\":\"Weekly Monday\",\"next_runs\":\"Next runs\",\"invalid_cron\":\"Invalid schedule expression\"},\"add\":{\"create_trigger\":\"Create Trigger\",\"creating\":\"Creating...\"},\"detail\":{\"test_fire\":\"Test Fire\",\"test_firing\":\"Firing...\",\"validate_and_fire\":\"Validate trigger config, then fire\",\"validating\":\"Validating...\",\"dry_run\":\"Test Run\",\"dry_running\":\"Running...\",\"simulate_hint\":\"Preview what would happen without actually running\",\"simulating\":\"Simulating...\",\"delete\":\"Delete\",\"delete_confirm\":\"Confirm delete\",\"delete_trigger\":\"Delete trigger\",\"activity_log\":\"Activity Log\",\"no_activity\":\"No activity recorded yet\",\"webhook_url\":\"Webhook URL\",\"webhook_secret\":\"Security Key\",\"copied\":\"Copied!\",\"copy_curl\":\"Copy sample curl\",\"last_fired\":\"Last fired\",\"never_fired\":\"Never fired\",\"fire_count_one\":\"Fired {count} time\",\"fire_count_other\":\"Fired {count} times\"},\"list\":{\"empty_title\":\"No triggers configured yet\",\"empty_hint\":\"Triggers let your agents run automatically -- on a schedule, when a file changes, when data arrives, and more.\",\"create_first\":\"Create Your First Trigger\",\"event_triggers\":\"Event Triggers\",\"budget_unavailable\":\"Budget data unavailable\",\"unknown_budget\":\"Unknown Budget\",\"budget_exceeded\":\"Monthly budget exceeded -- trigger paused\",\"budget\":\"Budget\"},\"config\":{\"no_persona\":\"No persona selected\",\"title\":\"Triggers\",\"add_trigger\":\"Add Trigger\",\"empty\":\"No triggers configured. Add one to automate this persona.\"},\"countdown\":{\"due_now\":\"Due now\",\"fires_in\":\"Fires in {time}\"},\"polling\":{\"endpoint_label\":\"URL to Check\",\"endpoint_placeholder\":\"https://api.example.com/status\",\"check_interval\":\"Check every {interval}\",\"content_hash\":\"Detect changes only\"},\"webhook\":{\"url_label\":\"Webhook URL\",\"secret_label\":\"Security Key (optional)\",\"secret_placeholder\":\"Secret key to verify incoming data\"},\"file_watcher\":{\"paths_label\":\"Watch Paths\",\"path_placeholder\":\"/path/to/watch\",\"add_path\":\"Add path\",\"events_label\":\"File Events\",\"event_modify\":\"Modify\",\"event_create\":\"Create\",\"event_delete\":\"Delete\",\"recursive\":\"Recursive\",\"glob_filter\":\"File Pattern\",\"glob_placeholder\":\"*.json\"},\"clipboard\":{\"content_type\":\"Content Type\",\"type_text\":\"Text\",\"type_image\":\"Image\",\"pattern_label\":\"Match Pattern\",\"pattern_placeholder\":\"e.g. https?://.*\",\"interval_label\":\"Check Interval (seconds)\"},\"app_focus\":{\"app_names_label\":\"Application Names\",\"app_placeholder\":\"e.g. Chrome, Firefox\",\"add_app\":\"Add app\",\"title_pattern\":\"Window Title Pattern\",\"title_placeholder\":\"e.g. .*GitHub.*\",\"interval_label\":\"Check Interval (seconds)\"},\"event_listener\":{\"event_type_label\":\"Event Type\",\"event_type_placeholder\":\"e.g. persona.execution.completed\",\"source_filter_label\":\"Source Filter\",\"source_filter_placeholder\":\"e.g. persona:abc123\"},\"composite\":{\"conditions_label\":\"Conditions\",\"add_condition\":\"Add condition\",\"operator_all\":\"All conditions must match\",\"operator_any\":\"Any condition can match\",\"window_label\":\"Time Window (seconds)\"},\"rate_limit\":{\"title\":\"Speed Limits\",\"max_fires\":\"Max runs\",\"per_window\":\"Time period\",\"window_seconds\":\"{seconds}s\",\"window_minutes\":\"{minutes}m\",\"window_hours\":\"{hours}h\",\"currently_limited\":\"Currently paused (limit reached)\"},\"dry_run\":{\"title\":\"Test Run Result\",\"would_fire\":\"Would run\",\"would_not_fire\":\"Would not run\",\"matched_conditions\":\"Conditions met\",\"payload_preview\":\"Data preview\"},\"tab_live_stream\":\"Live Stream\",\"tab_live_stream_subtitle\":\"Real-time event hub -- agents publish and subscribe to events through this shared bus\",\"tab_builder\":\"Builder\",\"tab_rate_limits\":\"Speed Limits\",\"tab_rate_limits_subtitle\":\"Throttling, queue depth, and concurrency limits for triggers\",\"tab_test\":\"Test\",\"tab_test_subtitle\":\"Fire test events into the bus to validate listeners and routing\",\"tab_smee_relay\":\"Local Relay\",\"tab_smee_relay_subtitle\":\"Forward webhooks from public endpoints into your local event bus\",\"tab_cloud_webhooks\":\"Cloud Events\",\"tab_cloud_webhooks_subtitle\":\"Webhook endpoints exposed by deployed cloud workers\",\"tab_dead_letter\":\"Dead Letter Queue\",\"tab_dead_letter_subtitle\":\"Events that failed delivery -- inspect, retry, or discard\",\"tab_studio\":\"Chain Studio\",\"tab_studio_subtitle\":\"Route trigger signals and persona completions to target personas\",\"tab_shared\":\"Marketplace\",\"tab_shared_subtitle\":\"Subscribe to curated global events — including connector API updates — and wire them into your workflows\",\"full_event_log\":\"Full Event Log\",\"on_label\":\"On\",\"off_label\":\"Off\",\"throttled_label\":\"Throttled\",\"queued_label\":\"{count} queued\",\"unknown_budget_label\":\"Unknown Budget\",\"budget_label\":\"Budget\",\"or_use_templates\":\"or use templates\",\"schedule_mode_label\":\"Schedule Mode\",\"test_fire_label\":\"Test fire\",\"dry_run_label\":\"Dry run\",\"unattended\":{\"title\":\"When this runs unattended\",\"subtitle\":\"Controls what happens when this trigger fires on its own (on schedule), without you watching.\",\"auto\":\"Run automatically\",\"auto_desc\":\"Fires and runs without asking — including any actions it sends, posts, or writes.\",\"dry_run\":\"Dry run (safe)\",\"dry_run_desc\":\"Fires on schedule but suppresses every outbound action — you review the result, then switch to automatic once you trust it.\",\"approval\":\"Require my approval\",\"approval_desc\":\"Holds each scheduled run until you approve it, so nothing happens unattended without your OK.\",\"badge_dry_run\":\"Dry run\",\"badge_approval\":\"Approval\"},\"arm_state\":{\"sleeping\":\"Sleeping\",\"sleeping_title\":\"Enabled, but outside its active hours — it won't fire until the active window reopens.\"},\"pending_approval\":{\"title\":\"Awaiting your approval\",\"subtitle_one\":\"{count} scheduled run is held until you approve it.\",\"subtitle_other\":\"{count} scheduled runs are held until you approve them.\",\"run_now\":\"Approve & run\",\"discard\":\"Discard\",\"approved\":\"Approved — running\",\"discarded\":\"Discarded\"},\"copy_sample_curl\":\"Copy sample curl\",\"event_listener_label\":\"Event Listener\",\"execution_history\":\"Execution history\",\"could_not_load_history\":\"Could not load history\",\"no_executions_recorded\":\"No executions recorded for this trigger yet\",\"replaying_label\":\"Replaying...\",\"replay_label\":\"Replay\",\"local_time\":\"local time\",\"describe_trigger\":\"Describe your trigger\",\"could_not_parse\":\"Could not parse a trigger from that description. Try something like\",\"rate_limiting\":\"Rate Limiting\",\"max_executions\":\"Max executions\",\"cooldown_label\":\"Cooldown between firings (seconds)\",\"max_concurrent_label\":\"Max concurrent executions\",\"unlimited_hint\":\"0 = unlimited\",\"window_usage\":\"Window usage\",\"concurrent_label\":\"Concurrent\",\"cooldown_stat\":\"Cooldown\",\"queued_stat\":\"Queued\",\"clear_all_limits\":\"Clear all limits\",\"dry_run_result_title\":\"Dry Run Result\",\"all_checks_passed\":\"All checks passed\",\"validation_failed\":\"Validation failed\",\"validation_failed_with_details\":\"Validation failed — {failures}\",\"build_validation\":{\"cron_required\":\"Cron expression is required.\",\"cron_invalid\":\"Invalid cron expression.\",\"interval_minimum\":\"Interval must be at least 60 seconds.\",\"event_type_required\":\"Event type to listen for is required.\",\"watch_path_required\":\"At least one watch path is required.\",\"composite_min_conditions\":\"Composite triggers need at least 2 conditions.\",\"composite_window_minimum\":\"Time window must be at least 5 seconds.\",\"endpoint_required\":\"Endpoint URL or event is required.\"},\"simulated_event\":\"Simulated Event\",\"matched_subscriptions_title\":\"Matched Subscriptions\",\"no_subscriptions_activated\":\"No subscriptions would be activated\",\"active_hours\":\"Active Hours\",\"only_fire_during_active\":\"Only fire during active hours\",\"weekdays_preset\":\"Weekdays\",\"every_day_preset\":\"Every day\",\"hmac_secret_label\":\"HMAC Secret\",\"hmac_help\":\"Incoming webhooks must include a valid HMAC signature header. A secret will be auto-generated if left empty.\",\"auto_generated_hint\":\"Auto-generated if left empty\",\"webhook_url_note\":\"A unique webhook URL will be shown after creation with a copy button\",\"hide_secret\":\"Hide secret\",\"show_secret\":\"Show secret\",\"generate_secret\":\"Generate random secret\",\"watch_subdirs\":\"Watch subdirectories recursively\",\"text_pattern_help\":\"Only fires when clipboard text matches this pattern\",\"app_names_help\":\"Leave empty to trigger on any app focus change\",\"source_filter_optional\":\"Source filter (optional)\",\"op_all_label\":\"ALL (AND)\",\"op_all_desc\":\"All conditions must match\",\"op_any_label\":\"ANY (OR)\",\"op_any_desc\":\"At least one condition\",\"op_sequence_label\":\"Sequence\",\"op_sequence_desc\":\"Conditions in order\",\"time_window_help\":\"All conditions must be met within this time window\",\"credential_event_help\":\"Link to a credential event instead of a custom endpoint\",\"none_use_endpoint\":\"None - use endpoint URL instead\",\"disabled_label\":\"Disabled\",\"manual_label\":\"Manual\",\"pending_label\":\"Pending\",\"fire_label\":\"Fire\",\"overdue_label\":\"Overdue\",\"webhook_label\":\"Webhook\",\"chain_label\":\"Chain\",\"poll_interval_label\":\"Poll Interval (seconds)\",\"endpoint_url\":\"Endpoint URL\",\"dev_mode_warning\":\"Dev mode -- this URL is only reachable locally\",\"conditions_met\":\"{met}/{total} conditions met\",\"suppressed_label\":\"suppressed\",\"request_inspector\":\"Request inspector\",\"errors_count\":\"{count} errors\",\"could_not_load_log\":\"Could not load request log\",\"no_webhook_requests\":\"No webhook requests received yet\",\"clear_all\":\"Clear all\",\"all_statuses\":\"All statuses\",\"all_types\":\"All types\",\"target_agent_label\":\"Target Agent\",\"broadcast_label\":\"broadcast\",\"live_label\":\"Live\",\"paused_label\":\"Paused\",\"connecting_label\":\"Connecting\",\"events_per_min\":\"events/min\",\"received_label\":\"received\",\"in_buffer\":\"in buffer\",\"resume_label\":\"Resume\",\"pause_label\":\"Pause\",\"no_events_title\":\"No events on the bus\",\"no_events_desc\":\"Events will appear here in real-time as agents publish and subscribe through the shared event bus.\",\"connecting_to_bus\":\"Connecting to event bus...\",\"event_data\":\"Event Data\",\"copy_json\":\"Copy JSON\",\"no_event_data\":\"No event data\",\"publish_test_event\":\"Publish Test Event\",\"publish_test_desc\":\"Fire a test event into the bus to verify subscriptions and agent routing.\",\"event_type_form_label\":\"Event Type\",\"payload_json_label\":\"Payload (JSON)\",\"publishing_label\":\"Publishing...\",\"publish_event\":\"Publish Event\",\"event_published\":\"Event published\",\"dead_letter_help\":\"Events that failed processing after exhausting all retry attempts. You can retry them manually or discard.\",\"no_dead_letters\":\"No dead-lettered events\",\"all_events_processed\":\"All events processed successfully\",\"exhausted_label\":\"Exhausted\",\"no_active_relays\":\"No active relays\",\"smee_relays\":\"Smee Relays\",\"add_relay\":\"Add Relay\",\"create_relay\":\"Create Relay\",\"label_field\":\"Label\",\"channel_url_label\":\"Channel URL\",\"route_to_agent\":\"Route to Agent\",\"broadcast_to_all\":\"Broadcast to all\",\"event_filter_label\":\"Event Filter\",\"no_smee_relays\":\"No Smee relays configured\",\"smee_relay_desc\":\"Add a Smee relay to receive GitHub webhooks and 3rd-party events in real-time through the event bus.\",\"add_first_relay\":\"Add First Relay\",\"how_it_works\":\"How it works\",\"live_stream\":\"Live Stream\",\"cloud_not_connected\":\"Cloud not connected\",\"cloud_not_connected_desc\":\"Connect to a cloud orchestrator to receive 3rd-party webhooks\",\"cloud_relay_active\":\"Cloud relay active\",\"cloud_webhook_triggers\":\"Cloud Webhook Triggers\",\"add_webhook\":\"Add Webhook\",\"deployed_persona\":\"Deployed Persona\",\"select_persona\":\"Select a persona...\",\"create_webhook\":\"Create Webhook\",\"no_webhook_triggers\":\"No webhook triggers yet\",\"no_webhook_triggers_desc\":\"Create a webhook trigger on a deployed persona to receive 3rd-party POSTs\",\"recent_firings\":\"Recent Firings\",\"no_firings\":\"No firings recorded yet\",\"no_rate_limits\":\"No rate limits configured\",\"no_rate_limits_desc\":\"Add rate limits to your triggers to control execution frequency and prevent API overuse.\",\"rate_limits_heading\":\"Rate Limits\",\"triggers_configured\":\"triggers configured\",\"trigger_configured\":\"trigger configured\",\"running_stat\":\"running\",\"throttled_stat\":\"throttled\",\"secret_label\":\"Secret\",\"copy_channel_url_title\":\"Copy channel URL\",\"pause_relay\":\"Pause relay\",\"resume_relay\":\"Resume relay\",\"browse_label\":\"Browse\",\"my_subscriptions\":\"My Subscriptions\",\"search_feeds\":\"Search feeds...\",\"loading_catalog\":\"Loading catalog...\",\"no_feeds\":\"No shared event feeds available yet\",\"no_feeds_hint\":\"Click Refresh to fetch the latest feeds from the cloud\",\"cron_colon\":\"Cron:\",\"interval_colon\":\"Interval:\",\"event_colon\":\"Event:\",\"endpoint_colon\":\"Endpoint:\",\"listens_for_colon\":\"Listens for:\",\"source_filter_colon\":\"Source filter:\",\"hmac_colon\":\"HMAC:\",\"paths_colon\":\"Paths:\",\"events_colon\":\"Events:\",\"recursive_yes\":\"Recursive: yes\",\"filter_colon\":\"Filter:\",\"watches_colon\":\"Watches:\",\"pattern_colon\":\"Pattern:\",\"poll_every\":\"Poll: every\",\"apps_colon\":\"Apps:\",\"title_colon\":\"Title:\",\"operator_colon\":\"Operator:\",\"window_colon\":\"Window:\",\"id_colon\":\"ID:\",\"type_colon\":\"Type:\",\"status_colon\":\"Status:\",\"target_colon\":\"Target:\",\"retry_hash\":\"retry #\",\"model_colon\":\"model:\",\"next_run_colon\":\"next run:\",\"local_label\":\"(local)\",\"then_every\":\", then every\",\"source_colon\":\"source:\",\"dry_run_target_colon\":\"target:\",\"matched_subscriptions_count\":\"Matched Subscriptions ({count})\",\"last_label\":\"Last:\",\"loading_history\":\"Loading...\",\"zero_unlimited\":\"0 = unlimited\",\"category_section_label\":\"Trigger Category\",\"no_persona_selected\":\"No persona selected\",\"quick_templates_label\":\"Quick Templates\",\"quick_presets_label\":\"Quick Presets\",\"cron_expression_label\":\"Cron Expression\",\"this_persona_will\":\"This persona will\",\"starting_from\":\", starting from when you enable it.\",\"per_day\":\"per day.\",\"last_poll_label\":\"Last poll:\",\"deployed_persona_label\":\"Deployed Persona\",\"fired_at_label\":\"Fired At\",\"webhook_last_label\":\"Last:\",\"relay_last_label\":\"Last:\",\"optional_label\":\"(optional)\",\"optional_comma_separated\":\"(optional, comma-separated)\",\"get_channel_url_from\":\"Get a channel URL from\",\"setup_step1\":\"Visit smee.io/new to create a free relay channel\",\"setup_step2\":\"Add the relay here with a label and the channel URL\",\"setup_step3\":\"Paste the channel URL as a webhook in GitHub / Stripe / any service\",\"setup_step4\":\"Events appear in Live Stream and route to your agents automatically\",\"toolbar_title_auto_layout\":\"Auto Layout\",\"toolbar_title_add_note\":\"Add Sticky Note\",\"toolbar_title_start_dry_run\":\"Start Dry Run\",\"toolbar_title_stop_dry_run\":\"Stop Dry Run\",\"toolbar_title_assistant\":\"Canvas Assistant\",\"toolbar_dry_run\":\"Dry Run\",\"canvas_assistant_title\":\"Canvas Assistant\",\"try_asking_hint\":\"Try asking\",\"assistant_placeholder\":\"Describe your event topology...\",\"disconnect_persona_title\":\"Disconnect persona?\",\"disconnect_will_no_longer\":\"will no longer react to\",\"disconnect_events_reconnect\":\"events. You can reconnect later.\",\"rename_also_updates\":\"Also updates historical events, persona event handlers, and trigger audit metadata.\",\"delete_connection_label\":\"Delete connection\",\"search_personas_placeholder\":\"Search personas...\",\"no_matching_personas_found\":\"No matching personas found\",\"clear_search_label\":\"Clear search\",\"dead_letter_source\":\"Source:\",\"dead_letter_id\":\"ID:\",\"event_data_label\":\"Event Data\",\"shared_prefix\":\"shared:\",\"nl_type_colon\":\"Type:\",\"nl_cron_colon\":\"· Cron:\",\"nl_interval_colon\":\"· Interval:\",\"nl_filter_colon\":\"· Filter:\",\"nl_could_not_parse\":\"Could not parse a trigger from that description. Try something like\",\"active_hours_every_day\":\"Every day\",\"event_type_to_listen\":\"Event Type to Listen For\",\"event_type_input_placeholder\":\"e.g. file_changed, execution_completed\",\"event_type_helper\":\"Type to search registered event types, or enter a custom type.\",\"source_filter_optional_label\":\"(optional)\",\"wildcard_hint\":\"— trailing * prefix wildcard supported\",\"window_title_pattern_label\":\"Window Title Pattern\",\"optional_regex_label\":\"(optional regex)\",\"text_pattern_label\":\"Text Pattern\",\"credential_event_label\":\"Credential Event\",\"refresh_label\":\"Refresh\",\"copy_webhook_url_title\":\"Copy webhook URL\",\"copy_webhook_secret_title\":\"Copy webhook secret\",\"delete_webhook_title\":\"Delete webhook trigger\",\"status_col_label\":\"Status\",\"duration_col_label\":\"Duration\",\"cost_col_label\":\"Cost\",\"dead_letter_refresh\":\"Refresh\",\"dead_letter_loading\":\"Loading...\",\"dead_letter_retry\":\"Retry\",\"dead_letter_discard\":\"Discard\",\"dead_letter_payload\":\"Payload\",\"event_data_section_label\":\"Event Data\",\"copy_event_data_title\":\"Copy event data\",\"copied_label\":\"Copied\",\"error_section_label\":\"Error\",\"meta_event_id\":\"Event ID\",\"meta_project\":\"Project\",\"meta_processed\":\"Processed\",\"clear_stream\":\"Clear\",\"clear_stream_title\":\"Clear stream buffer\",\"col_type\":\"Type\",\"col_source\":\"Source\",\"col_event\":\"Event\",\"col_listeners\":\"Listeners\",\"col_target_agent\":\"Target Agent\",\"col_status\":\"Status\",\"col_time\":\"Time\",\"source_personas_filter\":\"Source personas\",\"listeners_filter\":\"Listeners\",\"filter_events_placeholder\":\"Filter events…\",\"queued_bare\":\"queued\",\"pause_tooltip\":\"Pause incoming events\",\"resume_tooltip\":\"Resume live updates\",\"relay_label_field\":\"Label\",\"relay_channel_url_field\":\"Channel URL\",\"relay_route_to_agent\":\"Route to Agent\",\"optional_suffix\":\"(optional)\",\"relay_event_filter_field\":\"Event Filter\",\"relay_event_filter_note\":\"(optional, comma-separated)\",\"relay_confirm_delete\":\"Confirm\",\"setup_guide_step2\":\"Add the relay here with a label and the channel URL\",\"setup_guide_step3\":\"Paste the channel URL as a webhook in GitHub / Stripe / any service\",\"setup_guide_step4\":\"Events appear in Live Stream and route to your agents automatically\",\"get_channel_url_prompt\":\"Get a channel URL from\",\"test_event_type_placeholder\":\"e.g. build_complete, deploy, file_changed\",\"test_source_persona_label\":\"Source persona\",\"test_source_persona_help\":\"Choose the persona that will publish this event.\",\"test_output_event_label\":\"Output event\",\"test_output_event_help\":\"Events this persona has emitted or is configured to emit.\",\"test_select_persona_placeholder\":\"Select a persona…\",\"test_select_event_placeholder\":\"Select an event…\",\"test_no_emitted_events\":\"No tracked events for this persona yet — type a custom event type below.\",\"test_custom_event_label\":\"Custom event type\",\"test_custom_event_option\":\"Custom event type…\",\"test_payload_from_history\":\"Prefilled from the last event published {ago}.\",\"test_payload_no_history\":\"No prior event recorded — using empty payload as fallback.\",\"test_payload_invalid_json\":\"Payload is not valid JSON — it will be sent as a raw string.\",\"test_payload_reset\":\"Reset to last event\",\"result_id_prefix\":\"ID:\",\"result_type_prefix\":\"Type:\",\"result_status_prefix\":\"Status:\",\"result_target_prefix\":\"Target:\",\"tab_loading\":\"Loading...\",\"smee_open_new_title\":\"Open smee.io/new to create a channel\",\"relay_label_placeholder\":\"e.g. GitHub — my-repo\",\"relay_channel_url_placeholder\":\"https://smee.io/your-channel-id\",\"relay_filter_placeholder\":\"github_push, github_pull_request\",\"dead_letter_retry_exhausted_title\":\"Retry limit exhausted — discard or investigate the root cause\",\"dead_letter_discard_title\":\"Discard this event permanently\",\"dead_letter_retry_title\":\"Retry this event\",\"replay_button_title\":\"Re-fire with the same input payload\",\"interval_seconds_placeholder\":\"Seconds (min 60)\",\"cron_expression_placeholder\":\"* * * * *  (min hour dom mon dow)\",\"app_focus_window_placeholder\":\"e.g. .*.rs$ or Project - Visual Studio\",\"app_focus_process_placeholder\":\"e.g. Code.exe or firefox\",\"field_optional\":\"(optional)\",\"source_filter_input_placeholder\":\"e.g. watcher-* or exact-source-id\",\"meta_source\":\"Source\",\"relay_last_event\":\"Last:\",\"relay_delete_title\":\"Delete relay\",\"relay_delete_failed\":\"Failed to delete relay — please try again\",\"setup_guide_step1\":\"Create a channel at smee.io/new\",\"clipboard_pattern_placeholder\":\"e.g. https?://.* or error|exception\",\"composite_event_type_placeholder\":\"Event type (e.g. file_changed)\",\"composite_debounce_placeholder\":\"300\",\"file_watcher_path_placeholder\":\"C:/Users/me/projects or /home/me/src\",\"file_watcher_pattern_placeholder\":\"e.g. *.py, *.{ts,tsx}, Dockerfile\",\"polling_endpoint_placeholder\":\"https://api.example.com/poll\",\"more_tools_title\":\"More tools\",\"validate_and_fire_title\":\"Validate trigger config, then fire\",\"simulate_trigger_title\":\"Simulate trigger without executing\",\"dead_letter_select_all\":\"Select all\",\"dead_letter_select_visible\":\"Select visible\",\"dead_letter_clear_selection\":\"Clear\",\"dead_letter_selected_count\":\"{count} selected\",\"dead_letter_bulk_retry\":\"Retry {count}\",\"dead_letter_bulk_discard\":\"Discard {count}\",\"dead_letter_bulk_retry_title\":\"Retry every selected event in a single transaction\",\"dead_letter_bulk_discard_title\":\"Discard every selected event permanently\",\"dead_letter_bulk_discard_confirm_title\":\"Discard {count} events\",\"dead_letter_bulk_discard_confirm_message\":\"These dead-lettered events will be permanently discarded. This cannot be undone.\",\"dead_letter_bulk_retry_summary\":\"Retried {success} of {total} events\",\"dead_letter_bulk_discard_summary\":\"Discarded {success} of {total} events\",\"dead_letter_bulk_partial_failures\":\"{count} skipped\",\"dead_letter_bulk_failure_retry_exhausted\":\"retry limit reached\",\"dead_letter_bulk_failure_not_found\":\"no longer in queue\",\"dead_letter_bulk_failure_wrong_status\":\"no longer eligible\",\"dead_letter_filters_title\":\"Filters\",\"dead_letter_filter_event_type_placeholder\":\"Event type contains...\",\"dead_letter_filter_source_type_placeholder\":\"Source type contains...\",\"dead_letter_filter_error_placeholder\":\"Error message contains...\",\"dead_letter_filter_age\":\"Age\",\"dead_letter_filter_age_any\":\"Any age\",\"dead_letter_filter_age_15m\":\"<15 min\",\"dead_letter_filter_age_1h\":\"<1 hour\",\"dead_letter_filter_age_24h\":\"<24 hours\",\"dead_letter_filter_age_old\":\">24 hours\",\"dead_letter_filters_clear\":\"Clear filters\",\"dead_letter_filtered_summary\":\"Showing {visible} of {total}\",\"dead_letter_view_list\":\"List\",\"dead_letter_view_grouped\":\"Group by error\",\"dead_letter_group_singular\":\"{count} event\",\"dead_letter_group_plural\":\"{count} events\",\"dead_letter_group_retry\":\"Retry group\",\"dead_letter_group_discard\":\"Discard group\",\"dead_letter_group_expand\":\"Expand group\",\"dead_letter_group_collapse\":\"Collapse group\",\"dead_letter_group_other\":\"Other errors\",\"dead_letter_no_matches\":\"No events match the current filters\",\"dead_letter_clear_filters_to_see\":\"Clear filters to see all dead-lettered events\",\"marketplace\":{\"view_cards\":\"Cards\",\"view_cards_sub\":\"Original card grid\",\"view_registry\":\"Registry\",\"view_registry_sub\":\"Dense, sortable table of every feed\",\"view_watchtower\":\"Watchtower\",\"view_watchtower_sub\":\"Feeds ranked by recent change activity\",\"col_feed\":\"Feed\",\"col_category\":\"Category\",\"col_publisher\":\"Publisher\",\"col_subscribers\":\"Subscribers\",\"col_last_change\":\"Latest change\",\"col_status\":\"Status\",\"col_actions\":\"Actions\",\"subscribe\":\"Subscribe\",\"subscribed\":\"Subscribed\",\"unsubscribe\":\"Unsubscribe\",\"watch\":\"Watch\",\"watching\":\"Watching\",\"no_last_change\":\"No changes yet\",\"view_history\":\"View change history\",\"history_subtitle\":\"Recorded changes for this feed\",\"history_empty_title\":\"No changes recorded yet\",\"history_empty_hint\":\"When this API changes, the update appears here and fires to your subscribed workflows.\",\"history_detected\":\"Detected\",\"history_docs\":\"View API docs\",\"history_release\":\"Shipped in {version}\",\"history_loading\":\"Loading history…\",\"sev_patch\":\"Patch\",\"sev_minor\":\"Minor\",\"sev_major\":\"Major\",\"sev_breaking\":\"Breaking\",\"watching_only\":\"Watching only\"}}",
+  "triggers": "{\"title\":\"Triggers & Automations\",\"subtitle\":\"Set up what causes your agents to run automatically\",\"builder\":{\"add_persona_action\":\"Add persona\",\"auto_layout\":\"Auto layout\",\"cancel\":\"Cancel\",\"canvas_templates\":\"Canvas Templates\",\"connected_listeners\":\"Connected listeners\",\"connected_personas\":\"Connected personas\",\"current_name\":\"Current name\",\"custom_event_placeholder\":\"custom.event.type\",\"done\":\"Done\",\"double_click_edit\":\"Double-click to edit\",\"events\":\"Events\",\"filter_events_placeholder\":\"Filter events...\",\"filter_personas_placeholder\":\"Filter personas...\",\"hide_sidebar\":\"Hide sidebar\",\"impact_preview\":\"Impact preview\",\"init_handlers_title\":\"Initial handlers\",\"layout\":\"Layout\",\"marketplace\":\"Marketplace\",\"new_name\":\"New name\",\"no_events_filter\":\"No events match filter\",\"no_matches\":\"No matches\",\"no_matching_events\":\"No matching events\",\"no_personas_connected\":\"No personas connected\",\"no_personas_created\":\"No personas created\",\"note_placeholder\":\"Add a note...\",\"personas\":\"Personas\",\"refresh\":\"Refresh\",\"rename\":\"Rename\",\"rename_event_action\":\"Rename event\",\"rename_event_desc\":\"Update the event type across all references.\",\"rename_event_type\":\"Rename event type\",\"rename_placeholder\":\"new.event.type\",\"renaming\":\"Renaming...\",\"show_sidebar\":\"Show sidebar\",\"source\":\"Type\",\"source_personas\":\"Source personas\",\"use_template\":\"Use template\"},\"studio\":{\"sources_title\":\"Sources\",\"sources_subtitle\":\"What starts the chain\",\"targets_title\":\"Targets\",\"targets_subtitle\":\"Which persona runs\",\"group_signals\":\"Signals\",\"group_personas\":\"Personas\",\"group_after_persona\":\"After a persona completes\",\"source_persona_hint\":\"fires when this persona finishes\",\"persona_completes\":\"completes\",\"group_system_events\":\"Events\",\"system_events_subtitle\":\"Run a built-in op on a trigger\",\"system_events_empty\":\"No system operations available\",\"system_event_needs_trigger\":\"System events run on a time or event trigger — arm a Schedule or Event Listener source.\",\"system_event_label\":\"System event\",\"system_event_commit_title\":\"Schedule system event\",\"commit_project_label\":\"Project\",\"commit_no_projects\":\"Create a dev-tools project first.\",\"commit_cadence_label\":\"Cadence\",\"cadence_weekly\":\"Weekly (Mon 03:00)\",\"cadence_daily\":\"Daily (03:00)\",\"cadence_hourly\":\"Hourly\",\"cadence_custom\":\"Custom cron…\",\"commit_event_label\":\"On event\",\"commit_event_placeholder\":\"e.g. deploy_completed\",\"commit_delta_label\":\"Incremental (changed files only)\",\"commit_create\":\"Create automation\",\"commit_created_toast\":\"System event scheduled\",\"commit_failed_toast\":\"Couldn't create the automation\",\"automations_title\":\"Active system events\",\"automation_ran\":\"ran\",\"automation_failed\":\"failed\",\"automation_next\":\"next\",\"automation_idle\":\"waiting for its event\",\"automation_run_now\":\"Run now\",\"automation_enabled\":\"Enabled\",\"automation_delete\":\"Delete automation\",\"routes_title\":\"Routes\",\"existing_routes\":\"Existing routes\",\"proto_tab_patchbay\":\"Patchbay\",\"proto_tab_eventhub\":\"Event hub\",\"proto_tab_baseline\":\"Compose only\",\"proto_live\":\"live\",\"proto_pending\":\"pending\",\"proto_add_listener\":\"Add listener\",\"proto_disconnect\":\"Disconnect listener\",\"proto_rename_event\":\"Rename event\",\"proto_no_listeners\":\"No listeners\",\"proto_show_unconnected\":\"Show {count} unconnected\",\"proto_hide_unconnected\":\"Hide unconnected\",\"proto_chain_route\":\"Chain route — fires when the source persona completes; the event isn't renameable\",\"tab_compose\":\"Compose\",\"tab_routes\":\"Routes\",\"clear_all\":\"Clear all\",\"commit_route\":\"Save this route\",\"commit_all\":\"Save all\",\"route_committed\":\"Route saved\",\"routes_committed\":\"Saved {count} routes\",\"route_commit_failed\":\"Couldn't save route\",\"commit_blocked_signal\":\"Signal-source routes need a trigger config — coming soon\",\"commit_blocked_output_match\":\"Output-match routes need a match expression — coming soon\",\"pick_a_source\":\"pick a source\",\"pick_a_target\":\"pick a target\",\"cancel_pending_route\":\"Cancel pending route\",\"no_routes_title\":\"No routes yet\",\"no_routes_desc\":\"Arm a source on the left, then click a target persona on the right. The route patches in here.\",\"cycle_condition_hint\":\"Click to cycle the run condition\",\"condition_always\":\"always\",\"condition_on_success\":\"on success\",\"condition_on_failure\":\"on failure\",\"condition_output_match\":\"if output matches\",\"remove_route\":\"Remove route\",\"filter_placeholder\":\"Filter…\",\"no_sources_match\":\"No sources match \\\"{query}\\\"\",\"no_targets_match\":\"No personas match \\\"{query}\\\"\",\"agent_fallback\":\"Agent\",\"configure_commit_route\":\"Configure & save this route\",\"form_commit_title\":\"Save route\",\"form_commit_subtitle\":\"Configure the trigger that will run {persona}\",\"om_path_placeholder\":\"$.result.status\",\"om_path_label\":\"JSONPath into the source output\",\"om_expected_label\":\"Expected value\",\"om_expected_placeholder\":\"expected value\",\"commit_blocked_source_type\":\"This source can't be saved from the Studio — for completion chains, arm the source persona instead\",\"commit_needs_output_match\":\"Enter a JSONPath and expected value to save this route\",\"group_marketplace\":\"Marketplace\",\"marketplace_source_hint\":\"Runs the persona when this feed reports a change\",\"marketplace_empty\":\"No subscribed feeds yet — subscribe in the Marketplace to wire them into personas.\",\"reflection_label\":\"Memory reflection\",\"reflect_scope_label\":\"Reflect over\",\"reflect_scope_agent\":\"Agent\",\"reflect_scope_team\":\"Team\",\"reflect_pick_agent\":\"Pick an agent…\",\"reflect_pick_team\":\"Pick a team…\",\"reflect_scope_hint\":\"Consolidates the chosen memory pool into durable insights. The result is a proposal you review in Overview → Memories — nothing changes without your approval.\"},\"subscription_list\":{\"active\":\"Active\",\"browse_marketplace\":\"Browse marketplace\",\"col_actions\":\"Actions\",\"col_event_type\":\"Event Type\",\"col_events\":\"Events\",\"col_feed\":\"Feed\",\"col_last_event\":\"Last Event\",\"col_status\":\"Status\",\"error\":\"Error\",\"never\":\"Never\",\"no_active_subs\":\"No active subscriptions\",\"unsubscribe\":\"Unsubscribe\"},\"type_selector\":{\"trigger_type\":\"Trigger Type\"},\"tab_triggers\":\"Triggers\",\"tab_chains\":\"Automations\",\"tab_subscriptions\":\"Subscriptions\",\"health_failing\":\"One or more triggers failing\",\"health_degraded\":\"One or more triggers degraded\",\"category_label\":\"Trigger Category\",\"category_manual\":\"Manual\",\"category_manual_hint\":\"Run on demand\",\"type_label\":\"Trigger Type\",\"quick_templates\":\"Quick Templates\",\"type_manual\":\"Manual\",\"type_schedule\":\"Schedule\",\"type_webhook\":\"Webhook\",\"type_polling\":\"Auto-Check\",\"type_event_listener\":\"Event Listener\",\"type_file_watcher\":\"File Watcher\",\"type_email_inbound\":\"Inbound Email\",\"type_clipboard\":\"Clipboard\",\"type_app_focus\":\"App Focus\",\"type_chain\":\"Chain\",\"type_composite\":\"Combined\",\"desc_manual\":\"Run on demand\",\"desc_schedule\":\"Run on a timer or cron\",\"desc_polling\":\"Check an endpoint\",\"desc_webhook\":\"HTTP webhook listener\",\"desc_event_listener\":\"React to internal events\",\"desc_file_watcher\":\"React to file system changes\",\"desc_email_inbound\":\"React to new mail in an inbox\",\"desc_clipboard\":\"React to clipboard changes\",\"desc_app_focus\":\"React to app focus changes\",\"desc_chain\":\"Trigger after another agent completes\",\"desc_composite\":\"Multiple conditions + time window\",\"category_pull\":\"Watch\",\"category_push\":\"Listen\",\"category_compose\":\"Combine\",\"category_pull_desc\":\"Poll for changes on an interval\",\"category_push_desc\":\"Receive external signals\",\"category_compose_desc\":\"Chain or compose triggers\",\"rate_per_minute\":\"Per minute\",\"rate_per_5_minutes\":\"Per 5 minutes\",\"rate_per_hour\":\"Per hour\",\"tpl_fw_error_logs\":\"Auto-analyze error logs\",\"tpl_fw_error_logs_desc\":\"Triggers when new .log files appear or change in a folder\",\"tpl_fw_csv_data\":\"Process new CSV files\",\"tpl_fw_csv_data_desc\":\"Triggers when CSV files are added or modified\",\"tpl_fw_config_changes\":\"Watch config file changes\",\"tpl_fw_config_changes_desc\":\"Triggers on changes to JSON, YAML, or TOML config files\",\"tpl_cb_url_summarize\":\"Auto-summarize copied URLs\",\"tpl_cb_url_summarize_desc\":\"Triggers when you copy a URL to your clipboard\",\"tpl_cb_error_message\":\"Auto-diagnose error messages\",\"tpl_cb_error_message_desc\":\"Triggers when you copy text containing errors or exceptions\",\"tpl_cb_code_snippet\":\"Auto-format code snippets\",\"tpl_cb_code_snippet_desc\":\"Triggers when you copy code-like text (function definitions, imports)\",\"webhook_listener\":\"webhook listener\",\"custom_endpoint\":\"custom endpoint\",\"every_interval\":\"every {interval}\",\"from_source\":\"from {source}\",\"schedule\":{\"interval_label\":\"Interval\",\"preset_1m\":\"1 min\",\"preset_5m\":\"5 min\",\"preset_15m\":\"15 min\",\"preset_1h\":\"1 hour\",\"preset_6h\":\"6 hours\",\"preset_24h\":\"24 hours\",\"custom\":\"Custom\",\"mode_interval\":\"Interval\",\"mode_cron\":\"Cron\",\"cron_label\":\"Schedule Expression\",\"cron_placeholder\":\"0 9 * * 1-5\",\"cron_loading\":\"Previewing...\",\"cron_weekday_9am\":\"Weekdays 9am\",\"cron_every_hour\":\"Every hour\",\"cron_daily_midnight\":\"Daily midnight\",\"cron_weekly_monday\":\"Weekly Monday\",\"next_runs\":\"Next runs\",\"invalid_cron\":\"Invalid schedule expression\"},\"add\":{\"create_trigger\":\"Create Trigger\",\"creating\":\"Creating...\"},\"detail\":{\"test_fire\":\"Test Fire\",\"test_firing\":\"Firing...\",\"validate_and_fire\":\"Validate trigger config, then fire\",\"validating\":\"Validating...\",\"dry_run\":\"Test Run\",\"dry_running\":\"Running...\",\"simulate_hint\":\"Preview what would happen without actually running\",\"simulating\":\"Simulating...\",\"delete\":\"Delete\",\"delete_confirm\":\"Confirm delete\",\"delete_trigger\":\"Delete trigger\",\"activity_log\":\"Activity Log\",\"no_activity\":\"No activity recorded yet\",\"webhook_url\":\"Webhook URL\",\"webhook_secret\":\"Security Key\",\"copied\":\"Copied!\",\"copy_curl\":\"Copy sample curl\",\"last_fired\":\"Last fired\",\"never_fired\":\"Never fired\",\"fire_count_one\":\"Fired {count} time\",\"fire_count_other\":\"Fired {count} times\"},\"list\":{\"empty_title\":\"No triggers configured yet\",\"empty_hint\":\"Triggers let your agents run automatically -- on a schedule, when a file changes, when data arrives, and more.\",\"create_first\":\"Create Your First Trigger\",\"event_triggers\":\"Event Triggers\",\"budget_unavailable\":\"Budget data unavailable\",\"unknown_budget\":\"Unknown Budget\",\"budget_exceeded\":\"Monthly budget exceeded -- trigger paused\",\"budget\":\"Budget\"},\"config\":{\"no_persona\":\"No persona selected\",\"title\":\"Triggers\",\"add_trigger\":\"Add Trigger\",\"empty\":\"No triggers configured. Add one to automate this persona.\"},\"countdown\":{\"due_now\":\"Due now\",\"fires_in\":\"Fires in {time}\"},\"polling\":{\"endpoint_label\":\"URL to Check\",\"endpoint_placeholder\":\"https://api.example.com/status\",\"check_interval\":\"Check every {interval}\",\"content_hash\":\"Detect changes only\"},\"webhook\":{\"url_label\":\"Webhook URL\",\"secret_label\":\"Security Key (optional)\",\"secret_placeholder\":\"Secret key to verify incoming data\"},\"file_watcher\":{\"paths_label\":\"Watch Paths\",\"path_placeholder\":\"/path/to/watch\",\"add_path\":\"Add path\",\"events_label\":\"File Events\",\"event_modify\":\"Modify\",\"event_create\":\"Create\",\"event_delete\":\"Delete\",\"recursive\":\"Recursive\",\"glob_filter\":\"File Pattern\",\"glob_placeholder\":\"*.json\",\"ignore_patterns\":\"Ignore\",\"ignore_placeholder\":\"node_modules, .git, *.tmp\",\"debounce_label\":\"Quiet period (ms)\",\"include_content\":\"Include a snippet of the changed file\"},\"email_inbound\":{\"credential_label\":\"Mailbox\",\"credential_placeholder\":\"Select an email credential\",\"no_credentials\":\"Connect an Email (IMAP/SMTP) or Gmail credential first.\",\"mailbox_label\":\"Folder\",\"label_label\":\"Label\",\"label_placeholder\":\"Support\",\"from_label\":\"From contains\",\"from_placeholder\":\"billing@example.com\",\"subject_label\":\"Subject contains\",\"subject_placeholder\":\"Invoice\",\"unread_only\":\"Unread only\",\"mark_read\":\"Mark processed messages as read\",\"folder_colon\":\"Folder:\",\"from_colon\":\"From:\",\"subject_colon\":\"Subject:\",\"unread_only_yes\":\"Unread messages only\"},\"clipboard\":{\"content_type\":\"Content Type\",\"type_text\":\"Text\",\"type_image\":\"Image\",\"pattern_label\":\"Match Pattern\",\"pattern_placeholder\":\"e.g. https?://.*\",\"interval_label\":\"Check Interval (seconds)\"},\"app_focus\":{\"app_names_label\":\"Application Names\",\"app_placeholder\":\"e.g. Chrome, Firefox\",\"add_app\":\"Add app\",\"title_pattern\":\"Window Title Pattern\",\"title_placeholder\":\"e.g. .*GitHub.*\",\"interval_label\":\"Check Interval (seconds)\"},\"event_listener\":{\"event_type_label\":\"Event Type\",\"event_type_placeholder\":\"e.g. persona.execution.completed\",\"source_filter_label\":\"Source Filter\",\"source_filter_placeholder\":\"e.g. persona:abc123\"},\"composite\":{\"conditions_label\":\"Conditions\",\"add_condition\":\"Add condition\",\"operator_all\":\"All conditions must match\",\"operator_any\":\"Any condition can match\",\"window_label\":\"Time Window (seconds)\"},\"rate_limit\":{\"title\":\"Speed Limits\",\"max_fires\":\"Max runs\",\"per_window\":\"Time period\",\"window_seconds\":\"{seconds}s\",\"window_minutes\":\"{minutes}m\",\"window_hours\":\"{hours}h\",\"currently_limited\":\"Currently paused (limit reached)\"},\"dry_run\":{\"title\":\"Test Run Result\",\"would_fire\":\"Would run\",\"would_not_fire\":\"Would not run\",\"matched_conditions\":\"Conditions met\",\"payload_preview\":\"Data preview\"},\"tab_live_stream\":\"Live Stream\",\"tab_live_stream_subtitle\":\"Real-time event hub -- agents publish and subscribe to events through this shared bus\",\"tab_builder\":\"Builder\",\"tab_rate_limits\":\"Speed Limits\",\"tab_rate_limits_subtitle\":\"Throttling, queue depth, and concurrency limits for triggers\",\"tab_test\":\"Test\",\"tab_test_subtitle\":\"Fire test events into the bus to validate listeners and routing\",\"tab_smee_relay\":\"Local Relay\",\"tab_smee_relay_subtitle\":\"Forward webhooks from public endpoints into your local event bus\",\"tab_cloud_webhooks\":\"Cloud Events\",\"tab_cloud_webhooks_subtitle\":\"Webhook endpoints exposed by deployed cloud workers\",\"tab_dead_letter\":\"Dead Letter Queue\",\"tab_dead_letter_subtitle\":\"Events that failed delivery -- inspect, retry, or discard\",\"tab_studio\":\"Chain Studio\",\"tab_studio_subtitle\":\"Route trigger signals and persona completions to target personas\",\"tab_shared\":\"Marketplace\",\"tab_shared_subtitle\":\"Subscribe to curated global events — including connector API updates — and wire them into your workflows\",\"full_event_log\":\"Full Event Log\",\"on_label\":\"On\",\"off_label\":\"Off\",\"throttled_label\":\"Throttled\",\"queued_label\":\"{count} queued\",\"unknown_budget_label\":\"Unknown Budget\",\"budget_label\":\"Budget\",\"or_use_templates\":\"or use templates\",\"schedule_mode_label\":\"Schedule Mode\",\"test_fire_label\":\"Test fire\",\"dry_run_label\":\"Dry run\",\"unattended\":{\"title\":\"When this runs unattended\",\"subtitle\":\"Controls what happens when this trigger fires on its own (on schedule), without you watching.\",\"auto\":\"Run automatically\",\"auto_desc\":\"Fires and runs without asking — including any actions it sends, posts, or writes.\",\"dry_run\":\"Dry run (safe)\",\"dry_run_desc\":\"Fires on schedule but suppresses every outbound action — you review the result, then switch to automatic once you trust it.\",\"approval\":\"Require my approval\",\"approval_desc\":\"Holds each scheduled run until you approve it, so nothing happens unattended without your OK.\",\"badge_dry_run\":\"Dry run\",\"badge_approval\":\"Approval\"},\"arm_state\":{\"sleeping\":\"Sleeping\",\"sleeping_title\":\"Enabled, but outside its active hours — it won't fire until the active window reopens.\"},\"pending_approval\":{\"title\":\"Awaiting your approval\",\"subtitle_one\":\"{count} scheduled run is held until you approve it.\",\"subtitle_other\":\"{count} scheduled runs are held until you approve them.\",\"run_now\":\"Approve & run\",\"discard\":\"Discard\",\"approved\":\"Approved — running\",\"discarded\":\"Discarded\"},\"copy_sample_curl\":\"Copy sample curl\",\"event_listener_label\":\"Event Listener\",\"execution_history\":\"Execution history\",\"could_not_load_history\":\"Could not load history\",\"no_executions_recorded\":\"No executions recorded for this trigger yet\",\"replaying_label\":\"Replaying...\",\"replay_label\":\"Replay\",\"local_time\":\"local time\",\"describe_trigger\":\"Describe your trigger\",\"could_not_parse\":\"Could not parse a trigger from that description. Try something like\",\"rate_limiting\":\"Rate Limiting\",\"max_executions\":\"Max executions\",\"cooldown_label\":\"Cooldown between firings (seconds)\",\"max_concurrent_label\":\"Max concurrent executions\",\"unlimited_hint\":\"0 = unlimited\",\"window_usage\":\"Window usage\",\"concurrent_label\":\"Concurrent\",\"cooldown_stat\":\"Cooldown\",\"queued_stat\":\"Queued\",\"clear_all_limits\":\"Clear all limits\",\"dry_run_result_title\":\"Dry Run Result\",\"all_checks_passed\":\"All checks passed\",\"validation_failed\":\"Validation failed\",\"validation_failed_with_details\":\"Validation failed — {failures}\",\"build_validation\":{\"cron_required\":\"Cron expression is required.\",\"cron_invalid\":\"Invalid cron expression.\",\"interval_minimum\":\"Interval must be at least 60 seconds.\",\"event_type_required\":\"Event type to listen for is required.\",\"watch_path_required\":\"At least one watch path is required.\",\"email_credential_required\":\"Select the mailbox credential to watch.\",\"composite_min_conditions\":\"Composite triggers need at least 2 conditions.\",\"composite_window_minimum\":\"Time window must be at least 5 seconds.\",\"endpoint_required\":\"Endpoint URL or event is required.\"},\"simulated_event\":\"Simulated Event\",\"matched_subscriptions_title\":\"Matched Subscriptions\",\"no_subscriptions_activated\":\"No subscriptions would be activated\",\"active_hours\":\"Active Hours\",\"only_fire_during_active\":\"Only fire during active hours\",\"weekdays_preset\":\"Weekdays\",\"every_day_preset\":\"Every day\",\"hmac_secret_label\":\"HMAC Secret\",\"hmac_help\":\"Incoming webhooks must include a valid HMAC signature header. A secret will be auto-generated if left empty.\",\"auto_generated_hint\":\"Auto-generated if left empty\",\"webhook_url_note\":\"A unique webhook URL will be shown after creation with a copy button\",\"hide_secret\":\"Hide secret\",\"show_secret\":\"Show secret\",\"generate_secret\":\"Generate random secret\",\"watch_subdirs\":\"Watch subdirectories recursively\",\"text_pattern_help\":\"Only fires when clipboard text matches this pattern\",\"app_names_help\":\"Leave empty to trigger on any app focus change\",\"source_filter_optional\":\"Source filter (optional)\",\"op_all_label\":\"ALL (AND)\",\"op_all_desc\":\"All conditions must match\",\"op_any_label\":\"ANY (OR)\",\"op_any_desc\":\"At least one condition\",\"op_sequence_label\":\"Sequence\",\"op_sequence_desc\":\"Conditions in order\",\"time_window_help\":\"All conditions must be met within this time window\",\"credential_event_help\":\"Link to a credential event instead of a custom endpoint\",\"none_use_endpoint\":\"None - use endpoint URL instead\",\"disabled_label\":\"Disabled\",\"manual_label\":\"Manual\",\"pending_label\":\"Pending\",\"fire_label\":\"Fire\",\"overdue_label\":\"Overdue\",\"webhook_label\":\"Webhook\",\"chain_label\":\"Chain\",\"poll_interval_label\":\"Poll Interval (seconds)\",\"endpoint_url\":\"Endpoint URL\",\"dev_mode_warning\":\"Dev mode -- this URL is only reachable locally\",\"conditions_met\":\"{met}/{total} conditions met\",\"suppressed_label\":\"suppressed\",\"request_inspector\":\"Request inspector\",\"errors_count\":\"{count} errors\",\"could_not_load_log\":\"Could not load request log\",\"no_webhook_requests\":\"No webhook requests received yet\",\"clear_all\":\"Clear all\",\"all_statuses\":\"All statuses\",\"all_types\":\"All types\",\"target_agent_label\":\"Target Agent\",\"broadcast_label\":\"broadcast\",\"live_label\":\"Live\",\"paused_label\":\"Paused\",\"connecting_label\":\"Connecting\",\"events_per_min\":\"events/min\",\"received_label\":\"received\",\"in_buffer\":\"in buffer\",\"resume_label\":\"Resume\",\"pause_label\":\"Pause\",\"no_events_title\":\"No events on the bus\",\"no_events_desc\":\"Events will appear here in real-time as agents publish and subscribe through the shared event bus.\",\"connecting_to_bus\":\"Connecting to event bus...\",\"event_data\":\"Event Data\",\"copy_json\":\"Copy JSON\",\"no_event_data\":\"No event data\",\"publish_test_event\":\"Publish Test Event\",\"publish_test_desc\":\"Fire a test event into the bus to verify subscriptions and agent routing.\",\"event_type_form_label\":\"Event Type\",\"payload_json_label\":\"Payload (JSON)\",\"publishing_label\":\"Publishing...\",\"publish_event\":\"Publish Event\",\"event_published\":\"Event published\",\"dead_letter_help\":\"Events that failed processing after exhausting all retry attempts. You can retry them manually or discard.\",\"no_dead_letters\":\"No dead-lettered events\",\"all_events_processed\":\"All events processed successfully\",\"exhausted_label\":\"Exhausted\",\"no_active_relays\":\"No active relays\",\"smee_relays\":\"Smee Relays\",\"add_relay\":\"Add Relay\",\"create_relay\":\"Create Relay\",\"label_field\":\"Label\",\"channel_url_label\":\"Channel URL\",\"route_to_agent\":\"Route to Agent\",\"broadcast_to_all\":\"Broadcast to all\",\"event_filter_label\":\"Event Filter\",\"no_smee_relays\":\"No Smee relays configured\",\"smee_relay_desc\":\"Add a Smee relay to receive GitHub webhooks and 3rd-party events in real-time through the event bus.\",\"add_first_relay\":\"Add First Relay\",\"how_it_works\":\"How it works\",\"live_stream\":\"Live Stream\",\"cloud_not_connected\":\"Cloud not connected\",\"cloud_not_connected_desc\":\"Connect to a cloud orchestrator to receive 3rd-party webhooks\",\"cloud_relay_active\":\"Cloud relay active\",\"cloud_webhook_triggers\":\"Cloud Webhook Triggers\",\"add_webhook\":\"Add Webhook\",\"deployed_persona\":\"Deployed Persona\",\"select_persona\":\"Select a persona...\",\"create_webhook\":\"Create Webhook\",\"no_webhook_triggers\":\"No webhook triggers yet\",\"no_webhook_triggers_desc\":\"Create a webhook trigger on a deployed persona to receive 3rd-party POSTs\",\"recent_firings\":\"Recent Firings\",\"no_firings\":\"No firings recorded yet\",\"no_rate_limits\":\"No rate limits configured\",\"no_rate_limits_desc\":\"Add rate limits to your triggers to control execution frequency and prevent API overuse.\",\"rate_limits_heading\":\"Rate Limits\",\"triggers_configured\":\"triggers configured\",\"trigger_configured\":\"trigger configured\",\"running_stat\":\"running\",\"throttled_stat\":\"throttled\",\"secret_label\":\"Secret\",\"copy_channel_url_title\":\"Copy channel URL\",\"pause_relay\":\"Pause relay\",\"resume_relay\":\"Resume relay\",\"browse_label\":\"Browse\",\"my_subscriptions\":\"My Subscriptions\",\"search_feeds\":\"Search feeds...\",\"loading_catalog\":\"Loading catalog...\",\"no_feeds\":\"No shared event feeds available yet\",\"no_feeds_hint\":\"Click Refresh to fetch the latest feeds from the cloud\",\"cron_colon\":\"Cron:\",\"interval_colon\":\"Interval:\",\"event_colon\":\"Event:\",\"endpoint_colon\":\"Endpoint:\",\"listens_for_colon\":\"Listens for:\",\"source_filter_colon\":\"Source filter:\",\"hmac_colon\":\"HMAC:\",\"paths_colon\":\"Paths:\",\"events_colon\":\"Events:\",\"recursive_yes\":\"Recursive: yes\",\"filter_colon\":\"Filter:\",\"watches_colon\":\"Watches:\",\"pattern_colon\":\"Pattern:\",\"poll_every\":\"Poll: every\",\"apps_colon\":\"Apps:\",\"title_colon\":\"Title:\",\"operator_colon\":\"Operator:\",\"window_colon\":\"Window:\",\"id_colon\":\"ID:\",\"type_colon\":\"Type:\",\"status_colon\":\"Status:\",\"target_colon\":\"Target:\",\"retry_hash\":\"retry #\",\"model_colon\":\"model:\",\"next_run_colon\":\"next run:\",\"local_label\":\"(local)\",\"then_every\":\", then every\",\"source_colon\":\"source:\",\"dry_run_target_colon\":\"target:\",\"matched_subscriptions_count\":\"Matched Subscriptions ({count})\",\"last_label\":\"Last:\",\"loading_history\":\"Loading...\",\"zero_unlimited\":\"0 = unlimited\",\"category_section_label\":\"Trigger Category\",\"no_persona_selected\":\"No persona selected\",\"quick_templates_label\":\"Quick Templates\",\"quick_presets_label\":\"Quick Presets\",\"cron_expression_label\":\"Cron Expression\",\"this_persona_will\":\"This persona will\",\"starting_from\":\", starting from when you enable it.\",\"per_day\":\"per day.\",\"last_poll_label\":\"Last poll:\",\"deployed_persona_label\":\"Deployed Persona\",\"fired_at_label\":\"Fired At\",\"webhook_last_label\":\"Last:\",\"relay_last_label\":\"Last:\",\"optional_label\":\"(optional)\",\"optional_comma_separated\":\"(optional, comma-separated)\",\"get_channel_url_from\":\"Get a channel URL from\",\"setup_step1\":\"Visit smee.io/new to create a free relay channel\",\"setup_step2\":\"Add the relay here with a label and the channel URL\",\"setup_step3\":\"Paste the channel URL as a webhook in GitHub / Stripe / any service\",\"setup_step4\":\"Events appear in Live Stream and route to your agents automatically\",\"toolbar_title_auto_layout\":\"Auto Layout\",\"toolbar_title_add_note\":\"Add Sticky Note\",\"toolbar_title_start_dry_run\":\"Start Dry Run\",\"toolbar_title_stop_dry_run\":\"Stop Dry Run\",\"toolbar_title_assistant\":\"Canvas Assistant\",\"toolbar_dry_run\":\"Dry Run\",\"canvas_assistant_title\":\"Canvas Assistant\",\"try_asking_hint\":\"Try asking\",\"assistant_placeholder\":\"Describe your event topology...\",\"disconnect_persona_title\":\"Disconnect persona?\",\"disconnect_will_no_longer\":\"will no longer react to\",\"disconnect_events_reconnect\":\"events. You can reconnect later.\",\"rename_also_updates\":\"Also updates historical events, persona event handlers, and trigger audit metadata.\",\"delete_connection_label\":\"Delete connection\",\"search_personas_placeholder\":\"Search personas...\",\"no_matching_personas_found\":\"No matching personas found\",\"clear_search_label\":\"Clear search\",\"dead_letter_source\":\"Source:\",\"dead_letter_id\":\"ID:\",\"event_data_label\":\"Event Data\",\"shared_prefix\":\"shared:\",\"nl_type_colon\":\"Type:\",\"nl_cron_colon\":\"· Cron:\",\"nl_interval_colon\":\"· Interval:\",\"nl_filter_colon\":\"· Filter:\",\"nl_could_not_parse\":\"Could not parse a trigger from that description. Try something like\",\"active_hours_every_day\":\"Every day\",\"event_type_to_listen\":\"Event Type to Listen For\",\"event_type_input_placeholder\":\"e.g. file_changed, execution_completed\",\"event_type_helper\":\"Type to search registered event types, or enter a custom type.\",\"source_filter_optional_label\":\"(optional)\",\"wildcard_hint\":\"— trailing * prefix wildcard supported\",\"window_title_pattern_label\":\"Window Title Pattern\",\"optional_regex_label\":\"(optional regex)\",\"text_pattern_label\":\"Text Pattern\",\"credential_event_label\":\"Credential Event\",\"refresh_label\":\"Refresh\",\"copy_webhook_url_title\":\"Copy webhook URL\",\"copy_webhook_secret_title\":\"Copy webhook secret\",\"delete_webhook_title\":\"Delete webhook trigger\",\"status_col_label\":\"Status\",\"duration_col_label\":\"Duration\",\"cost_col_label\":\"Cost\",\"dead_letter_refresh\":\"Refresh\",\"dead_letter_loading\":\"Loading...\",\"dead_letter_retry\":\"Retry\",\"dead_letter_discard\":\"Discard\",\"dead_letter_payload\":\"Payload\",\"event_data_section_label\":\"Event Data\",\"copy_event_data_title\":\"Copy event data\",\"copied_label\":\"Copied\",\"error_section_label\":\"Error\",\"meta_event_id\":\"Event ID\",\"meta_project\":\"Project\",\"meta_processed\":\"Processed\",\"clear_stream\":\"Clear\",\"clear_stream_title\":\"Clear stream buffer\",\"col_type\":\"Type\",\"col_source\":\"Source\",\"col_event\":\"Event\",\"col_listeners\":\"Listeners\",\"col_target_agent\":\"Target Agent\",\"col_status\":\"Status\",\"col_time\":\"Time\",\"source_personas_filter\":\"Source personas\",\"listeners_filter\":\"Listeners\",\"filter_events_placeholder\":\"Filter events…\",\"queued_bare\":\"queued\",\"pause_tooltip\":\"Pause incoming events\",\"resume_tooltip\":\"Resume live updates\",\"relay_label_field\":\"Label\",\"relay_channel_url_field\":\"Channel URL\",\"relay_route_to_agent\":\"Route to Agent\",\"optional_suffix\":\"(optional)\",\"relay_event_filter_field\":\"Event Filter\",\"relay_event_filter_note\":\"(optional, comma-separated)\",\"relay_confirm_delete\":\"Confirm\",\"setup_guide_step2\":\"Add the relay here with a label and the channel URL\",\"setup_guide_step3\":\"Paste the channel URL as a webhook in GitHub / Stripe / any service\",\"setup_guide_step4\":\"Events appear in Live Stream and route to your agents automatically\",\"get_channel_url_prompt\":\"Get a channel URL from\",\"test_event_type_placeholder\":\"e.g. build_complete, deploy, file_changed\",\"test_source_persona_label\":\"Source persona\",\"test_source_persona_help\":\"Choose the persona that will publish this event.\",\"test_output_event_label\":\"Output event\",\"test_output_event_help\":\"Events this persona has emitted or is configured to emit.\",\"test_select_persona_placeholder\":\"Select a persona…\",\"test_select_event_placeholder\":\"Select an event…\",\"test_no_emitted_events\":\"No tracked events for this persona yet — type a custom event type below.\",\"test_custom_event_label\":\"Custom event type\",\"test_custom_event_option\":\"Custom event type…\",\"test_payload_from_history\":\"Prefilled from the last event published {ago}.\",\"test_payload_no_history\":\"No prior event recorded — using empty payload as fallback.\",\"test_payload_invalid_json\":\"Payload is not valid JSON — it will be sent as a raw string.\",\"test_payload_reset\":\"Reset to last event\",\"result_id_prefix\":\"ID:\",\"result_type_prefix\":\"Type:\",\"result_status_prefix\":\"Status:\",\"result_target_prefix\":\"Target:\",\"tab_loading\":\"Loading...\",\"smee_open_new_title\":\"Open smee.io/new to create a channel\",\"relay_label_placeholder\":\"e.g. GitHub — my-repo\",\"relay_channel_url_placeholder\":\"https://smee.io/your-channel-id\",\"relay_filter_placeholder\":\"github_push, github_pull_request\",\"dead_letter_retry_exhausted_title\":\"Retry limit exhausted — discard or investigate the root cause\",\"dead_letter_discard_title\":\"Discard this event permanently\",\"dead_letter_retry_title\":\"Retry this event\",\"replay_button_title\":\"Re-fire with the same input payload\",\"interval_seconds_placeholder\":\"Seconds (min 60)\",\"cron_expression_placeholder\":\"* * * * *  (min hour dom mon dow)\",\"app_focus_window_placeholder\":\"e.g. .*.rs$ or Project - Visual Studio\",\"app_focus_process_placeholder\":\"e.g. Code.exe or firefox\",\"field_optional\":\"(optional)\",\"source_filter_input_placeholder\":\"e.g. watcher-* or exact-source-id\",\"meta_source\":\"Source\",\"relay_last_event\":\"Last:\",\"relay_delete_title\":\"Delete relay\",\"relay_delete_failed\":\"Failed to delete relay — please try again\",\"setup_guide_step1\":\"Create a channel at smee.io/new\",\"clipboard_pattern_placeholder\":\"e.g. https?://.* or error|exception\",\"composite_event_type_placeholder\":\"Event type (e.g. file_changed)\",\"composite_debounce_placeholder\":\"300\",\"file_watcher_path_placeholder\":\"C:/Users/me/projects or /home/me/src\",\"file_watcher_pattern_placeholder\":\"e.g. *.py, *.{ts,tsx}, Dockerfile\",\"polling_endpoint_placeholder\":\"https://api.example.com/poll\",\"more_tools_title\":\"More tools\",\"validate_and_fire_title\":\"Validate trigger config, then fire\",\"simulate_trigger_title\":\"Simulate trigger without executing\",\"dead_letter_select_all\":\"Select all\",\"dead_letter_select_visible\":\"Select visible\",\"dead_letter_clear_selection\":\"Clear\",\"dead_letter_selected_count\":\"{count} selected\",\"dead_letter_bulk_retry\":\"Retry {count}\",\"dead_letter_bulk_discard\":\"Discard {count}\",\"dead_letter_bulk_retry_title\":\"Retry every selected event in a single transaction\",\"dead_letter_bulk_discard_title\":\"Discard every selected event permanently\",\"dead_letter_bulk_discard_confirm_title\":\"Discard {count} events\",\"dead_letter_bulk_discard_confirm_message\":\"These dead-lettered events will be permanently discarded. This cannot be undone.\",\"dead_letter_bulk_retry_summary\":\"Retried {success} of {total} events\",\"dead_letter_bulk_discard_summary\":\"Discarded {success} of {total} events\",\"dead_letter_bulk_partial_failures\":\"{count} skipped\",\"dead_letter_bulk_failure_retry_exhausted\":\"retry limit reached\",\"dead_letter_bulk_failure_not_found\":\"no longer in queue\",\"dead_letter_bulk_failure_wrong_status\":\"no longer eligible\",\"dead_letter_filters_title\":\"Filters\",\"dead_letter_filter_event_type_placeholder\":\"Event type contains...\",\"dead_letter_filter_source_type_placeholder\":\"Source type contains...\",\"dead_letter_filter_error_placeholder\":\"Error message contains...\",\"dead_letter_filter_age\":\"Age\",\"dead_letter_filter_age_any\":\"Any age\",\"dead_letter_filter_age_15m\":\"<15 min\",\"dead_letter_filter_age_1h\":\"<1 hour\",\"dead_letter_filter_age_24h\":\"<24 hours\",\"dead_letter_filter_age_old\":\">24 hours\",\"dead_letter_filters_clear\":\"Clear filters\",\"dead_letter_filtered_summary\":\"Showing {visible} of {total}\",\"dead_letter_view_list\":\"List\",\"dead_letter_view_grouped\":\"Group by error\",\"dead_letter_group_singular\":\"{count} event\",\"dead_letter_group_plural\":\"{count} events\",\"dead_letter_group_retry\":\"Retry group\",\"dead_letter_group_discard\":\"Discard group\",\"dead_letter_group_expand\":\"Expand group\",\"dead_letter_group_collapse\":\"Collapse group\",\"dead_letter_group_other\":\"Other errors\",\"dead_letter_no_matches\":\"No events match the current filters\",\"dead_letter_clear_filters_to_see\":\"Clear filters to see all dead-lettered events\",\"marketplace\":{\"view_cards\":\"Cards\",\"view_cards_sub\":\"Original card grid\",\"view_registry\":\"Registry\",\"view_registry_sub\":\"Dense, sortable table of every feed\",\"view_watchtower\":\"Watchtower\",\"view_watchtower_sub\":\"Feeds ranked by recent change activity\",\"col_feed\":\"Feed\",\"col_category\":\"Category\",\"col_publisher\":\"Publisher\",\"col_subscribers\":\"Subscribers\",\"col_last_change\":\"Latest change\",\"col_status\":\"Status\",\"col_actions\":\"Actions\",\"subscribe\":\"Subscribe\",\"subscribed\":\"Subscribed\",\"unsubscribe\":\"Unsubscribe\",\"watch\":\"Watch\",\"watching\":\"Watching\",\"no_last_change\":\"No changes yet\",\"view_history\":\"View change history\",\"history_subtitle\":\"Recorded changes for this feed\",\"history_empty_title\":\"No changes recorded yet\",\"history_empty_hint\":\"When this API changes, the update appears here and fires to your subscribed workflows.\",\"history_detected\":\"Detected\",\"history_docs\":\"View API docs\",\"history_release\":\"Shipped in {version}\",\"history_loading\":\"Loading history…\",\"sev_patch\":\"Patch\",\"sev_minor\":\"Minor\",\"sev_major\":\"Major\",\"sev_breaking\":\"Breaking\",\"watching_only\":\"Watching only\"}}",
   "remote_approval": "{\"title\":\"Remote run request\",\"body\":\"Your dashboard requested a run of {persona} on this device.\",\"safety_note\":\"This runs locally on this device with its own credentials. Nothing runs until you approve.\",\"approve\":\"Approve & run\",\"approving\":\"Starting…\",\"reject\":\"Reject\",\"queue_count\":\"{index} of {total} pending\",\"prompt_label\":\"Prompt\",\"no_prompt\":\"No prompt provided.\",\"from_dashboard\":\"Requested from the web dashboard\",\"later\":\"Decide later\"}",
-  "settings": "{\"search\":{\"placeholder\":\"Search settings…\",\"recommended\":\"Recommended\",\"section\":\"Settings\",\"trigger_hint\":\"Search settings  ·  Ctrl / ⌘ K\",\"trigger_aria\":\"Open settings search\",\"reduce_motion\":\"Reduce motion\",\"dim\":\"Reduce color intensity\",\"cvd_safe\":\"Color-blind safe palette\",\"high_contrast\":\"High contrast\",\"ambient\":\"Time-of-day header art\",\"theme\":\"Theme & colors\",\"text_size\":\"Text size\",\"brightness\":\"Brightness\",\"density\":\"Layout density\",\"timezone\":\"Time zone\",\"tabs\":{\"limits\":\"Limits\",\"api_keys\":\"API Keys\",\"history\":\"History\"}},\"account\":{\"title\":\"Account\",\"subtitle\":\"Manage your sign-in and profile\",\"avatar_alt\":\"Profile picture\",\"offline\":\"Offline\",\"sign_out\":\"Sign out\",\"sign_in_prompt\":\"Sign in to sync your data across devices\",\"sign_in_google\":\"Sign in with Google\",\"dismiss\":\"Dismiss\",\"waiting_sign_in\":\"Waiting for sign-in...\",\"complete_sign_in\":\"Complete sign-in in the popup window\",\"cancel\":\"Cancel\",\"telemetry_title\":\"Troubleshooting Telemetry\",\"telemetry_description\":\"When enabled, anonymous crash reports and feature usage analytics are sent to help identify and fix bugs. No personal data, credentials, or execution content is ever included.\",\"telemetry_toggle\":\"Send anonymous telemetry\",\"telemetry_on\":\"Crash reports and usage analytics are active.\",\"telemetry_off\":\"Telemetry is disabled. No data is sent to Sentry.\",\"telemetry_restart\":\"Restart the app for this change to take effect\",\"telemetry_toggle_aria\":\"Toggle telemetry\",\"updates_title\":\"Updates\",\"updates_description\":\"Personas auto-checks for updates every six hours. Click below to check now.\",\"updates_check_button\":\"Check for Updates\",\"updates_checking\":\"Checking...\",\"updates_up_to_date\":\"You're up to date\",\"updates_available_toast\":\"Update available — see the banner at the top to install\",\"updates_check_failed\":\"Couldn't check for updates. Try again in a moment.\",\"updates_current_version\":\"You're on v{version}\",\"updates_last_checked\":\"Last checked {time}\",\"updates_history_title\":\"Recent updates\",\"cloud_sync_title\":\"Cloud Dashboard Sync\",\"cloud_sync_description\":\"Sync a read-only copy of your personas, runs, and activity to your private cloud so you can view them in the web dashboard. Your connector credentials never leave this device.\",\"cloud_sync_toggle\":\"Sync to cloud dashboard\",\"cloud_sync_on\":\"Your data is syncing to the cloud dashboard.\",\"cloud_sync_off\":\"Your data stays on this device only.\",\"cloud_sync_toggle_aria\":\"Toggle cloud dashboard sync\",\"cloud_sync_now\":\"Sync now\",\"cloud_sync_syncing\":\"Syncing...\",\"cloud_sync_last\":\"Last synced\",\"cloud_sync_never\":\"Not synced yet\",\"cloud_sync_error\":\"Last sync failed: {error}\",\"cloud_sync_enable_failed\":\"Couldn't update the sync setting\",\"cloud_sync_now_failed\":\"Sync failed. Check your connection and try again.\",\"cloud_sync_now_done\":\"Synced {count} records\",\"cloud_sync_state_off\":\"Off\",\"cloud_sync_state_active\":\"Active\",\"cloud_sync_state_syncing\":\"Syncing…\",\"cloud_sync_total\":\"synced all-time\",\"cloud_sync_device\":\"Device {id}\",\"cloud_sync_tables_title\":\"Synced data\",\"cloud_sync_show_details\":\"Show breakdown\",\"cloud_sync_hide_details\":\"Hide breakdown\",\"cloud_sync_retry\":\"Retry\",\"cloud_sync_rows\":\"rows\",\"cloud_sync_table_pending\":\"Awaiting first sync\",\"cloud_sync_tables\":{\"synced_personas\":\"Personas\",\"synced_executions\":\"Executions\",\"synced_events\":\"Events\",\"synced_manual_reviews\":\"Reviews\",\"synced_messages\":\"Messages\",\"synced_metrics_snapshots\":\"Metrics\",\"synced_tool_usage\":\"Tool usage\",\"synced_memories\":\"Memories\",\"synced_knowledge_patterns\":\"Knowledge\"}},\"appearance\":{\"title\":\"Appearance\",\"subtitle\":\"Customize how the app looks\",\"dark\":\"Dark\",\"light\":\"Light\",\"language\":\"Language\",\"language_hint\":\"Choose the language for the app interface. The same picker is also on the Home screen.\",\"text_size\":\"Text Size\",\"interface_mode\":\"Interface Mode\",\"interface_mode_hint\":\"Simple mode shows only core features. Power mode unlocks the full interface.\",\"theming\":\"Theming\",\"default_tab\":\"Default\",\"custom_tab\":\"Custom\",\"theme_preview_aria\":\"Live preview of {name} theme\",\"contrast_badge_aaa\":\"AAA\",\"contrast_badge_aa\":\"AA\",\"contrast_badge_low\":\"Low\",\"contrast_badge_aria\":\"{level} contrast ({ratio}:1)\",\"contrast_readout_title\":\"Contrast\",\"contrast_pair_body\":\"Body text\",\"contrast_pair_primary_btn\":\"Primary button\",\"contrast_pair_accent_text\":\"Accent text\",\"timezone\":\"Timezone\",\"timezone_hint\":\"Controls how schedule times and cron expressions are displayed throughout the app.\",\"brightness\":\"Brightness\",\"brightness_hint\":\"Adjust screen brightness if the app feels too dark on your display.\",\"dim_mode_label\":\"Dim mode\",\"dim_mode_hint\":\"Reduces overall color saturation. Helpful for late-night use; pairs with any theme.\",\"dim_mode_on\":\"On\",\"dim_mode_off\":\"Off\",\"cvd_safe_label\":\"Color-blind safe status\",\"cvd_safe_hint\":\"Shifts warning chips to yellow and error to a more saturated red, so the red/orange pair stays distinguishable for protan/deuteran vision.\",\"cvd_safe_on\":\"On\",\"cvd_safe_off\":\"Off\",\"high_contrast_label\":\"High contrast\",\"high_contrast_hint\":\"Pushes all status colors to maximum luminance distance from the canvas, strengthens borders, and lifts secondary text. AAA across the board.\",\"high_contrast_on\":\"On\",\"high_contrast_off\":\"Off\",\"reduce_motion_label\":\"Reduce motion\",\"reduce_motion_hint\":\"Disables transitions and animations across the app. Toggles and theme switches still apply, but without movement.\",\"reduce_motion_on\":\"On\",\"reduce_motion_off\":\"Off\",\"density\":\"Density\",\"density_hint\":\"Controls how much spacing the app uses. Compact fits more on screen; Cozy gives everything more room to breathe.\",\"density_compact_desc\":\"More rows per screen\",\"density_comfortable_desc\":\"Balanced — the default\",\"density_cozy_desc\":\"Extra breathing room\",\"custom_theme\":\"Custom Theme\",\"custom_theme_active\":\"Active\",\"custom_theme_hint\":\"Choose a primary color to auto-derive all others, or override individual colors for full control.\",\"base_mode\":\"Base Mode\",\"theme_name\":\"Theme Name\",\"colors\":\"Colors\",\"background_gradient\":\"Background gradient\",\"end_color\":\"End Color\",\"angle\":\"Angle\",\"preview\":\"Preview\",\"save_apply\":\"Save & Apply\",\"applied\":\"Applied\",\"reset\":\"Reset\",\"enter_theme_name\":\"Enter a theme name\",\"reset_to_auto\":\"Reset to auto\",\"preview_dashboard\":\"Dashboard\",\"preview_action\":\"Action\",\"preview_card_title\":\"Card Title\",\"preview_muted_text\":\"Muted description text with secondary content\",\"preview_ok\":\"OK\",\"preview_warn\":\"Warn\",\"preview_err\":\"Err\",\"language_translations\":\"Language & Translations\",\"translation_keys\":\"{count} translation keys\",\"translation_coverage\":\"{covered} of {total} keys ({pct}%)\",\"coverage_full\":\"Full\",\"coverage_hint\":\"Translation coverage -- click to export\",\"contribute_title\":\"Contribute translations\",\"contribute_hint\":\"Help translate Personas into your language. Export a language file above, translate the values, and submit via GitHub.\",\"contribute_github\":\"Contribute on GitHub\",\"ambient_title\":\"Ambient time-of-day\",\"ambient_hint\":\"Phase chip in the title bar that updates with your local clock — dawn, day, dusk, night.\",\"ambient_enabled\":\"Enabled\",\"ambient_disabled\":\"Off\",\"ambient_phase_dawn\":\"Dawn\",\"ambient_phase_day\":\"Day\",\"ambient_phase_dusk\":\"Dusk\",\"ambient_phase_night\":\"Night\",\"tz_label_local\":\"Local\",\"tz_description_local\":\"Browser timezone\",\"tz_label_utc\":\"UTC\",\"tz_description_utc\":\"Coordinated Universal Time\",\"tz_label_us_eastern\":\"US Eastern\",\"tz_description_us_eastern\":\"ET (UTC-5/-4)\",\"tz_label_us_central\":\"US Central\",\"tz_description_us_central\":\"CT (UTC-6/-5)\",\"tz_label_us_pacific\":\"US Pacific\",\"tz_description_us_pacific\":\"PT (UTC-8/-7)\",\"tz_label_london\":\"London\",\"tz_description_london\":\"GMT/BST (UTC+0/+1)\",\"tz_label_prague\":\"Prague\",\"tz_description_prague\":\"CET (UTC+1/+2)\",\"tz_label_tokyo\":\"Tokyo\",\"tz_description_tokyo\":\"JST (UTC+9)\"},\"notifications\":{\"title\":\"Notifications\",\"subtitle\":\"Control which healing alerts trigger notifications\",\"severity_critical_label\":\"Critical\",\"severity_high_label\":\"High\",\"severity_medium_label\":\"Medium\",\"severity_low_label\":\"Low\",\"severity_critical\":\"Circuit breaker tripped, CLI not found\",\"severity_high\":\"Credential errors, session limits, repeated timeouts\",\"severity_medium\":\"Rate limits, first timeouts (auto-fixable)\",\"severity_low\":\"Informational issues\",\"weekly_digest\":\"Weekly Health Digest\",\"digest_title\":\"Agent Health Digest\",\"digest_description\":\"Weekly notification summarizing health issues across all agents with a total health score\",\"healing_alerts_hint\":\"Control which auto-fix alerts trigger notifications\",\"healing_severity\":\"Healing Alert Severity\",\"notification_hint\":\"Desktop notifications use the native OS notification system. In-app toasts appear for critical and high severity issues regardless of these settings.\",\"severity_toggle_aria\":\"{level} notifications\",\"test_button\":\"Test\",\"test_button_tooltip\":\"Show a sample {level} notification — does not change settings.\",\"test_persona_name\":\"Test persona\",\"test_issue_title\":\"Sample {level} notification\",\"test_suggested_fix\":\"This is only a test — your notification settings are wired up correctly.\",\"weekly_digest_aria\":\"Weekly health digest\",\"webhook_subscriptions_title\":\"Outbound webhooks\",\"webhook_subscriptions_add\":\"Add webhook\",\"webhook_subscriptions_empty\":\"No webhook subscriptions yet. Add one to route persona events to Slack, Discord, Teams, or a custom JSON endpoint.\",\"webhook_subscriptions_label\":\"Label\",\"webhook_subscriptions_label_placeholder\":\"Production alerts\",\"webhook_subscriptions_provider\":\"Destination\",\"webhook_subscriptions_provider_labels\":{\"slack\":\"Slack (incoming webhook)\",\"discord\":\"Discord (channel webhook)\",\"teams\":\"Microsoft Teams (incoming webhook)\",\"generic\":\"Generic JSON (POST)\"},\"webhook_subscriptions_url\":\"Webhook URL\",\"webhook_subscriptions_url_placeholder\":\"https://hooks.slack.com/services/...\",\"webhook_subscriptions_events\":\"Event patterns\",\"webhook_subscriptions_events_placeholder\":\"execution.finished, healing.escalated\",\"webhook_subscriptions_events_hint\":\"Comma-separated. Use `prefix.*` to match all sub-types (e.g. `execution.*`) or `*` for everything.\",\"webhook_subscriptions_template\":\"Message template (optional)\",\"webhook_subscriptions_template_placeholder\":\"Persona {{payload.persona_id}} {{event_type}}: {{payload.status}}\",\"webhook_subscriptions_template_hint\":\"Mustache-style placeholders walk the event JSON. Leave blank to use a default summary.\",\"webhook_subscriptions_cancel\":\"Cancel\",\"webhook_subscriptions_save\":\"Save\",\"webhook_subscriptions_test_aria\":\"Send a test notification\",\"webhook_subscriptions_edit_aria\":\"Edit webhook subscription\",\"webhook_subscriptions_delete_aria\":\"Delete webhook subscription\",\"webhook_subscriptions_enabled_aria\":\"Enable webhook subscription\"},\"engine\":{\"title\":\"Engine\",\"loading_capabilities\":\"Loading engine capabilities...\",\"routing_title\":\"Model Routing\",\"routing_subtitle\":\"Tier models by persona category. A rule applies only when a persona has no explicit model; an empty category is the default for all.\",\"routing_empty\":\"No routing rules yet. Add one to tier models by category.\",\"routing_category_ph\":\"Category (empty = all)\",\"routing_model_ph\":\"Model (e.g. claude-opus-4-8)\",\"routing_effort_inherit\":\"Effort: inherit\",\"routing_add\":\"Add rule\",\"routing_save\":\"Save rules\",\"routing_remove\":\"Remove rule\",\"detecting_providers\":\"Detecting installed providers...\",\"subtitle\":\"Configure which CLI providers handle each operation\",\"capability_map\":\"Operation Capability Map\",\"operation\":\"Operation\",\"reset_defaults\":\"Reset to defaults\",\"provider_installed\":\"installed\",\"provider_missing\":\"missing\",\"legend\":\"Legend\",\"legend_enabled\":\"Enabled\",\"legend_unsupported\":\"Unsupported (locked)\",\"legend_not_installed\":\"Not installed\",\"defaults_heading\":\"Defaults from Integration Tests\",\"defaults_description\":\"The default map is derived from Round 9 business-level integration tests that validate each provider against the exact JSON schemas the backend parses. Enabling a provider for an operation it failed may cause unparseable responses. Claude Code is the only provider that passed all operations at 100%.\",\"engine_not_capable\":\"{provider} has not passed integration tests for this operation. Results may be unparseable.\",\"op_persona_execution_label\":\"Persona Execution\",\"op_persona_execution_description\":\"Protocol-compliant output with outcome_assessment, user_message, agent_memory\",\"op_design_analysis_label\":\"Persona Design\",\"op_design_analysis_description\":\"Structured prompts from persona briefs -- DESIGN_OUTPUT_SCHEMA JSON\",\"op_credential_design_label\":\"Credential Design\",\"op_credential_design_description\":\"Connector definitions with fields, healthchecks -- CREDENTIAL_DESIGN_OUTPUT_SCHEMA\",\"op_credential_healthcheck_label\":\"Credential Healthcheck\",\"op_credential_healthcheck_description\":\"API healthcheck endpoint design with {{field_key}} placeholders\",\"op_n8n_transform_label\":\"N8N Transform\",\"op_n8n_transform_description\":\"TRANSFORM_QUESTIONS or section-delimited persona from n8n workflows\",\"op_template_adopt_label\":\"Template Adoption\",\"op_template_adopt_description\":\"TRANSFORM_QUESTIONS or persona JSON from template definitions\",\"op_test_generation_label\":\"Test Scenario Generation\",\"op_test_generation_description\":\"TestScenario[] with mock tools, expected_tool_sequence, edge cases\",\"op_healing_analysis_label\":\"Healing Diagnosis\",\"op_healing_analysis_description\":\"Root-cause analysis from error logs and connector failures\",\"op_recipe_execution_label\":\"Recipe Execution\",\"op_recipe_execution_description\":\"Run automation recipes with prompt templates and input schemas\",\"op_query_debug_label\":\"Query Debug\",\"op_query_debug_description\":\"Fix broken SQL in ```sql code blocks -- rejects JS/TS/Python output\",\"op_unsupported_title\":\"{label} is not supported by {provider} -- failed integration tests\",\"op_disable_title\":\"Disable {label} for {provider}\",\"op_enable_title\":\"Enable {label} for {provider}\",\"engine_verified_title\":\"{provider} is verified for this operation\",\"engine_unparseable_title\":\"{provider} may produce unparseable output for this operation\"},\"ambient\":{\"title\":\"Ambient Context Fusion\",\"description\":\"Ambient context captures clipboard, file changes, and app focus signals to give personas awareness of your desktop workflow.\",\"toggle_aria\":\"Ambient context fusion\",\"events_broadcast\":\"{count} events broadcast\",\"subscribers\":\"{count} subscriber\",\"subscribers_plural\":\"{count} subscribers\",\"live_context\":\"Live Context Window\",\"total_signals\":\"{count} total signals\",\"no_signals\":\"No recent signals captured\",\"sensory_policy\":\"Sensory Policy\",\"reset_defaults\":\"Reset to defaults\",\"clipboard\":\"Clipboard\",\"file_changes\":\"File Changes\",\"app_focus\":\"App Focus\",\"focus_filter\":\"Focus App Filter\",\"focus_filter_hint\":\"Only capture signals when these apps are in focus. Empty = capture from any app.\",\"focus_filter_placeholder\":\"e.g. Code.exe\",\"add\":\"Add\",\"context_rules\":\"Context Rules\",\"add_rule\":\"Add Rule\",\"context_rules_hint\":\"Define patterns that trigger proactive persona actions when desktop context matches.\",\"rule_name_placeholder\":\"Rule name (e.g. \\\"Crash debug helper\\\")\",\"match_sources\":\"Match sources (empty = all)\",\"summary_contains_placeholder\":\"Summary contains (e.g. \\\"error\\\", \\\"Code.exe\\\")\",\"file_glob_placeholder\":\"File glob (e.g. *.rs)\",\"app_filter_placeholder\":\"App filter (e.g. Code.exe)\",\"action\":\"Action\",\"action_trigger\":\"Trigger Execution\",\"action_emit\":\"Emit Event\",\"action_log\":\"Log Only\",\"cooldown\":\"Cooldown (sec)\",\"cancel\":\"Cancel\",\"create_rule\":\"Create Rule\",\"no_rules\":\"No context rules defined\",\"all_sources\":\"all sources\"},\"byom\":{\"title\":\"Bring Your Own Model\",\"subtitle\":\"Configure approved providers, compliance restrictions, and cost-optimized routing\",\"loading\":\"Loading...\",\"unsaved_changes\":\"Unsaved changes\",\"reset\":\"Reset\",\"save_policy\":\"Save Policy\",\"fix_errors\":\"Fix all errors before saving\",\"policy_corrupted\":\"BYOM Policy Corrupted\",\"policy_corrupted_desc\":\"The stored policy JSON could not be parsed. All provider restrictions are currently inactive and executions are blocked. Reset the policy to restore normal operation.\",\"reset_policy\":\"Reset Policy\",\"policy_enforcement\":\"BYOM Policy Enforcement\",\"policy_enforcement_desc\":\"When enabled, provider selection follows your configured rules\",\"tab_providers\":\"Providers\",\"tab_keys\":\"API Keys\",\"tab_routing\":\"Cost Routing\",\"tab_compliance\":\"Compliance\",\"tab_audit\":\"Audit Log\",\"allowed_providers\":\"Allowed Providers\",\"allowed_providers_hint\":\"Select which providers your organization approves. Leave empty to allow all.\",\"allowed\":\"Allowed\",\"blocked_providers\":\"Blocked Providers\",\"blocked_providers_hint\":\"Explicitly block specific providers. Takes precedence over allowed list.\",\"blocked\":\"Blocked\",\"provider_usage\":\"Provider Usage\",\"usage_trends\":\"30-day trends\",\"usage_empty_title\":\"No provider activity yet\",\"usage_empty_description\":\"Once you allow a provider and run an agent, executions, cost, and latency for each provider will appear here.\",\"usage_empty_action\":\"Add a provider key\",\"executions\":\"Executions\",\"cost\":\"Cost\",\"avg_duration\":\"Avg Duration\",\"failover_one\":\"{count} failover\",\"failover_other\":\"{count} failovers\",\"no_trend_data\":\"No trend data\",\"test_connection\":\"Test Connection\",\"testing\":\"Testing...\",\"test_timed_out\":\"Connection test timed out (5s)\",\"test_ipc_failed\":\"IPC call failed\",\"reachable\":\"Reachable\",\"unreachable\":\"Unreachable\",\"test_cli_scope_note\":\"Verifies the provider CLI is installed and responding. The model endpoint and API key are validated on first real use, not by this check.\",\"health_pending\":\"Not yet tested\",\"health_testing\":\"Testing connection…\",\"health_pass\":\"Provider is reachable\",\"health_fail\":\"Provider is unreachable\",\"api_key_title\":\"API Key Management\",\"api_key_hint\":\"Configure API keys and endpoints for custom model providers. Keys are stored encrypted in the local database.\",\"verify\":\"Verify\",\"remove_key\":\"Remove key\",\"save\":\"Save\",\"stored\":\"Stored\",\"error\":\"Error\",\"hide_key\":\"Hide\",\"reveal_key\":\"Reveal\",\"save_key_title\":\"Save\",\"cancel_key_title\":\"Cancel\",\"provider_ollama_label\":\"Ollama\",\"provider_ollama_description\":\"Cloud API key for Ollama-hosted models (Qwen3, GLM-5, Kimi K2.5)\",\"provider_litellm_base_url_label\":\"LiteLLM Base URL\",\"provider_litellm_base_url_description\":\"Proxy base URL for LiteLLM-compatible endpoints\",\"provider_litellm_master_key_label\":\"LiteLLM Master Key\",\"provider_litellm_master_key_description\":\"Authentication key for LiteLLM proxy\",\"provider_qwen_label\":\"Qwen Cloud\",\"provider_qwen_description\":\"DashScope API key for Qwen cloud models. Required to enable the Qwen provider.\",\"qwen_needs_key\":\"Add an API key to enable\",\"routing_title\":\"Cost-Optimized Routing Rules\",\"routing_hint\":\"Route tasks to specific providers/models based on complexity level\",\"routing_empty\":\"No routing rules configured. Add rules to optimize cost by task complexity.\",\"add_rule\":\"Add Rule\",\"rule_name_placeholder\":\"Rule name\",\"complexity\":\"Complexity\",\"provider\":\"Provider\",\"model_optional\":\"Model (optional)\",\"model_placeholder\":\"e.g. claude-haiku-4-5-20251001\",\"compliance_title\":\"Compliance-Driven Restrictions\",\"compliance_hint\":\"Restrict providers for specific workflow types (e.g., HIPAA, SOC2)\",\"compliance_empty\":\"No compliance rules configured. Add rules to restrict providers for sensitive workflows.\",\"compliance_name_placeholder\":\"Rule name (e.g., HIPAA)\",\"workflow_tags\":\"Workflow Tags (comma-separated)\",\"workflow_tags_placeholder\":\"hipaa, healthcare, pii\",\"allowed_providers_label\":\"Allowed Providers\",\"audit_title\":\"Provider Audit Log\",\"audit_hint\":\"Compliance trail showing which provider handled each execution\",\"audit_empty\":\"No audit entries yet. Entries are recorded automatically for every execution.\",\"audit_provider\":\"Provider\",\"audit_model\":\"Model\",\"audit_persona\":\"Persona\",\"audit_status\":\"Status\",\"audit_cost\":\"Cost\",\"audit_time\":\"Time\",\"failover\":\"failover\"},\"admin\":{\"title\":\"Admin\",\"subtitle\":\"Development tools and testing utilities\",\"guided_tour\":\"Guided Tour\",\"tour_hint\":\"Force-start or reset the onboarding tour for e2e testing\",\"tour_active\":\"Active\",\"tour_completed\":\"Completed\",\"tour_dismissed\":\"Dismissed\",\"tour_not_started\":\"Not started\",\"progress\":\"Progress\",\"steps\":\"steps\",\"current_step\":\"Current Step\",\"step_status\":\"Step Status\",\"force_start\":\"Force Start Tour\",\"confirm_reset\":\"Confirm Reset\",\"reset_state\":\"Reset State\",\"force_complete\":\"Force Complete\",\"force_dismiss\":\"Force Dismiss\",\"user_consent\":\"User Consent\",\"consent_hint\":\"Reset the first-use consent modal to test onboarding\",\"consent_accepted\":\"Accepted\",\"consent_not_accepted\":\"Not accepted\",\"storage_key\":\"Storage Key\",\"reset_consent\":\"Reset Consent\",\"reload_modal\":\"Reload to Show Modal\",\"tour_step_unavailable\":\"N/A\"},\"portability\":{\"title\":\"Data Portability\",\"subtitle\":\"Export, import, and migrate your workspace data\",\"storage_title\":\"Storage\",\"storage_subtitle\":\"Local database usage and cleanup of finished runs.\",\"storage_db_size\":\"Database size\",\"storage_total\":\"Total runs\",\"storage_removable\":\"Removable\",\"storage_prune\":\"Clean up old runs\",\"storage_confirm\":\"Remove {count} finished runs older than 24 hours?\",\"storage_confirm_yes\":\"Remove\",\"storage_pruned\":\"Removed {count} runs.\",\"workspace_overview\":\"Workspace Overview\",\"loading_stats\":\"Loading workspace stats...\",\"stats_error\":\"Failed to load workspace statistics.\",\"error_label\":\"Error\",\"personas\":\"Personas\",\"teams\":\"Teams\",\"tools\":\"Tools\",\"groups\":\"Groups\",\"credentials\":\"Credentials\",\"memories\":\"Memories\",\"team_memories\":\"Team Memories\",\"test_suites\":\"Test Suites\",\"kpis\":\"KPIs\",\"export_import_title\":\"Workspace Export & Import\",\"export_import_hint\":\"Export your workspace to a portable ZIP archive containing personas, teams, credentials, and related data. Choose exactly what to include. Import restores from a previously exported archive -- imported items are created as new entities (disabled by default).\",\"exporting\":\"Exporting...\",\"exported\":\"Exported!\",\"export_workspace\":\"Export Workspace\",\"import_workspace\":\"Import Workspace\",\"import_label\":\"Import\",\"imported\":\"Imported!\",\"cancel\":\"Cancel\",\"passphrase_optional\":\"Passphrase (optional)\",\"import_complete\":\"Import Complete\",\"warnings\":\"Warnings:\",\"import_personas\":\"{count} persona(s)\",\"import_teams\":\"{count} team(s)\",\"import_tools\":\"{count} tool(s)\",\"import_groups\":\"{count} group(s)\",\"import_credentials_count\":\"{count} credential(s)\",\"import_team_memories\":\"{count} team memory(ies)\",\"import_kpis\":\"{count} KPI(s)\",\"include_memories_label\":\"Include memories\",\"include_memories_note\":\"Persona and team memories travel with the export. Turn this off to export agents and teams without their accumulated memories.\",\"export_title\":\"Export Workspace\",\"export_subtitle\":\"Choose what to include in your export\",\"close\":\"Close\",\"loading_data\":\"Loading workspace data...\",\"deselect_all\":\"Deselect All\",\"select_all\":\"Select All\",\"items_selected\":\"{selected} of {total} items selected\",\"of_selected\":\"{count} of {total} selected\",\"encrypt_passphrase\":\"Encrypt credentials with passphrase\",\"optional\":\"(optional)\",\"passphrase_placeholder\":\"Passphrase (min 8 characters)\",\"passphrase_too_short\":\"Passphrase must be at least 8 characters\",\"passphrase_note\":\"If set, credential secrets will be included in the export and protected with AES-256 encryption.\",\"auto_included_note\":\"Groups, tools, memories, and test suites linked to selected personas are automatically included.\",\"no_passphrase_note\":\" Credential secrets are not included unless a passphrase is set above.\",\"export_all\":\"Export All\",\"export_items\":\"Export {count} Item\",\"export_items_plural\":\"Export {count} Items\",\"credential_vault\":\"Credential Vault\",\"credential_vault_hint\":\"Workspace exports do not include credential secrets. Use this section to export and import your vault with password-protected AES-256 encryption.\",\"export_credentials\":\"Export Credentials\",\"import_credentials\":\"Import Credentials\",\"passphrase_min\":\"Passphrase (min 8 chars)\",\"passphrase_label\":\"Passphrase\",\"export\":\"Export\",\"credentials_exist\":\"{count} credential already exist\",\"credentials_exist_plural\":\"{count} credentials already exist\",\"conflict_hint\":\"Choose how to handle each conflict:\",\"skip\":\"Skip\",\"keep_both\":\"Keep Both\",\"replace\":\"Replace\",\"import_with_resolutions\":\"Import with Resolutions\",\"cred_import_complete\":\"Credential Import Complete\",\"cred_imported\":\"{count} imported\",\"cred_skipped\":\", {count} skipped\",\"cred_replaced\":\", {count} replaced\",\"please_enter_passphrase\":\"Please enter the passphrase used during export\",\"no_import_file\":\"No import file available -- please start the import again\",\"export_failed\":\"Export failed\",\"selective_export_failed\":\"Selective export failed\",\"import_failed\":\"Import failed\",\"cred_export_failed\":\"Credential export failed\",\"cred_import_failed\":\"Credential import failed\",\"proto\":{\"search_placeholder\":\"Search by name, team, model…\",\"filter_all\":\"All\",\"filter_teamed\":\"In a team\",\"filter_unteamed\":\"No team\",\"filter_enabled\":\"Enabled only\",\"filter_starred\":\"Starred\",\"scope_personas\":\"Personas\",\"scope_teams\":\"Teams\",\"scope_credentials\":\"Credentials\",\"select_filtered\":\"Select {count} shown\",\"deselect_filtered\":\"Deselect {count} shown\",\"clear_all\":\"Clear all\",\"select_everything\":\"Select everything\",\"no_results_title\":\"No matches\",\"no_results_hint\":\"Adjust your search or filters.\",\"results_count\":\"{count} shown\",\"selected_of\":\"{selected} / {total}\",\"manifest_title\":\"Export manifest\",\"manifest_subtitle\":\"What ships in this archive\",\"manifest_full\":\"Full workspace\",\"manifest_empty\":\"Nothing selected yet — pick items on the left.\",\"row_personas\":\"Personas\",\"row_teams\":\"Teams\",\"row_credentials\":\"Credentials\",\"dependency_note\":\"Tools, groups, memories & test suites linked to selected personas ship automatically.\",\"secrets_encrypted\":\"Secrets encrypted (AES-256)\",\"secrets_excluded\":\"Credential secrets excluded\",\"disabled\":\"Off\",\"model_label\":\"Model profile\",\"model_default\":\"Default model\",\"trust_label_full\":\"Trust score\",\"team_label\":\"Team membership\",\"in_team\":\"in {team}\",\"no_team\":\"No team\",\"multi_team\":\"{count} teams\",\"members_label\":\"Members\",\"kpi_count_label\":\"{count} KPIs\",\"kpi_off_track\":\"Off-track KPIs\",\"kpi_setup_title\":\"Include KPI setup\",\"kpi_setup_hint\":\"Each selected team's KPIs travel with it — all or nothing.\",\"kpi_setup_ship\":\"{count} KPIs ship with the selected teams.\",\"kpi_setup_off\":\"KPI setup excluded from this export.\",\"kpi_setup_none\":\"No team KPIs available to include.\",\"memories_title\":\"Include memories\",\"memories_hint\":\"Persona & team memories travel with the export.\",\"encryption_title\":\"Encrypt credential secrets\",\"encryption_hint\":\"Set a passphrase to include encrypted secrets in the archive.\"}},\"config\":{\"title\":\"Config Resolution\",\"subtitle\":\"Shows which tier (agent / workspace / global) supplies each setting per persona\",\"refresh\":\"Refresh\",\"agent_level\":\"Agent-level\",\"workspace_level\":\"Workspace\",\"global_level\":\"Global\",\"not_set\":\"Not set\",\"overrides_inherited\":\"Overrides inherited\",\"agent\":\"Agent\",\"loading_agents\":\"Loading agents...\",\"no_agents\":\"No agents found\",\"source_agent_label\":\"Agent\",\"source_workspace_label\":\"Workspace\",\"source_global_label\":\"Global\",\"source_default_label\":\"--\",\"field_model\":\"Model\",\"field_provider\":\"Provider\",\"field_budget\":\"Budget\",\"field_turns\":\"Turns\",\"field_cache\":\"Cache\",\"_comment_per_capability\":\"Config matrix — model is chosen per capability (use-case model_override); shown as a legend item + on Model cells that resolve per-capability\",\"per_capability\":\"Per capability\",\"failed_to_resolve\":\"Failed to resolve\",\"config_could_not_be_resolved\":\"Config could not be resolved\",\"search_placeholder\":\"Filter agents by name…\",\"clear_filter_aria\":\"Clear filter\",\"overrides_only_label\":\"Overrides only\",\"overrides_only_tooltip\":\"Show only agents that have at least one agent-level override on top of workspace or global defaults.\",\"filtered_empty\":\"No agents match the current filter.\",\"showing_count\":\"Showing {shown} of {total}\"},\"limits\":{\"title\":\"Limits\",\"subtitle\":\"Parallel-execution cap and monthly cost ceiling\",\"description\":\"Set a monthly USD ceiling on your Personas spend. Stage 1 (this build) is informational — it shows progress and warnings but does not block runs. Stage 2 will enforce the ceiling at execution-dispatch time.\",\"refresh\":\"Refresh\",\"concurrency_section\":\"Parallel executions\",\"concurrency_hint\":\"How many personas can run at the same time. Extra runs wait in a queue and start automatically as slots free up. Changes apply immediately — no restart needed.\",\"concurrency_aria\":\"Maximum parallel executions\",\"concurrency_range\":\"Between {min} and {max} concurrent runs.\",\"concurrency_queued_note\":\"Runs beyond this limit are queued and promoted by priority, then arrival order, as running ones finish.\",\"ceiling_section\":\"Monthly ceiling\",\"ceiling_hint\":\"Combined cost across all personas, refreshed when you reopen this tab. Set to 0 to disable the ceiling.\",\"ceiling_placeholder\":\"0.00\",\"ceiling_aria\":\"Monthly cost ceiling in USD\",\"ceiling_unit\":\"USD / month\",\"set\":\"Set\",\"saved\":\"Saved\",\"unlimited\":\"(no ceiling)\",\"usage_section\":\"Current month\",\"of_ceiling\":\"of {ceiling}\",\"progress_aria\":\"{pct}% of monthly ceiling used\",\"over_budget\":\"You have exceeded your monthly ceiling. Stage 2 will block new executions when this happens; for now, treat this as a warning.\",\"approaching_budget\":\"You are approaching your monthly ceiling. Consider reviewing recent runs.\",\"no_ceiling_hint\":\"No ceiling is set. Enter a USD amount above to start tracking against a budget.\",\"stage1_note\":\"Stage 1: informational only. Stage 2 will gate execution dispatch when the ceiling is crossed.\"},\"recent_change\":{\"label\":\"Last {action}: {when}\",\"click_to_open\":\"Click to open History\"},\"history\":{\"title\":\"Settings History\",\"subtitle\":\"Audit log of changes made to settings\",\"description\":\"Append-only record of who changed which setting and when. Use this to trace back surprising behavior or coordinate audit reviews. Every user-facing settings change is recorded here — API keys, engine, limits, retention, autonomy, notifications and more; secret values are redacted, and internal engine bookkeeping is excluded.\",\"refresh\":\"Refresh\",\"filter_category\":\"Category\",\"filter_all\":\"All\",\"showing_max\":\"Showing the most recent 100 entries — older history is still in the database.\",\"loading\":\"Loading audit entries…\",\"empty\":\"No settings changes recorded yet. Mutations made from here on will appear in this list.\",\"empty_filtered\":\"No entries match the current category filter.\",\"before\":\"Before\",\"after\":\"After\",\"categories\":{\"api_keys\":\"API Keys\",\"engine\":\"Engine\",\"limits\":\"Limits\",\"retention\":\"Retention\",\"byom\":\"BYOM\",\"notifications\":\"Notifications\",\"autonomy\":\"Autonomy\",\"quality_gates\":\"Quality Gates\",\"integrations\":\"Integrations\",\"sync\":\"Cloud Sync\",\"config\":\"General\"}},\"api_keys\":{\"title\":\"API Keys\",\"description\":\"Generate keys for third-party tools and MCP clients to drive Personas remotely. Each key authenticates to the local management HTTP server (127.0.0.1:9420) — only valid keys can start builds, list personas, or trigger executions.\",\"loading\":\"Loading...\",\"loading_keys\":\"Loading keys...\",\"active_keys\":\"active\",\"create_key\":\"New key\",\"your_keys\":\"Your keys\",\"empty\":\"No keys yet. Create one to let an MCP client connect.\",\"retry\":\"Retry\",\"never_used\":\"never used\",\"last_used\":\"Last used\",\"created_label\":\"Created\",\"stale_chip\":\"Stale\",\"stale_tooltip\":\"No activity in 30+ days — consider revoking if no longer in use.\",\"no_scopes\":\"no scopes\",\"revoke\":\"Revoke\",\"revoked\":\"Revoked\",\"revoke_tooltip\":\"Disable this key — clients using it will be rejected immediately. Audit row stays for history.\",\"delete\":\"Delete\",\"delete_tooltip\":\"Remove this key from the database permanently.\",\"confirm_delete\":\"Confirm\",\"create_dialog_title\":\"Create API Key\",\"field_name_label\":\"Name\",\"field_name_placeholder\":\"e.g. Claude Desktop · build-mcp\",\"field_name_hint\":\"Short label so you remember which client uses it. Visible only to you.\",\"field_scopes_label\":\"Scopes\",\"scope_build\":\"Build (one-shot + interactive)\",\"scope_build_desc\":\"Drive a persona build session: start, answer questions, test, promote.\",\"scope_read\":\"Read\",\"scope_read_desc\":\"List personas, fetch persona detail, read execution history.\",\"scope_execute\":\"Execute\",\"scope_execute_desc\":\"Run only the specific agents you pick below. Each pick becomes a per-agent grant — no blanket execute.\",\"scope_execute_label\":\"Execute specific agents\",\"execute_pick_hint\":\"Choose which agents this key may run\",\"execute_filter_placeholder\":\"Filter agents…\",\"execute_no_personas\":\"No agents yet — create one first.\",\"field_expiry_label\":\"Expires\",\"expiry_never\":\"Never\",\"expiry_7d\":\"7 days\",\"expiry_30d\":\"30 days\",\"expiry_90d\":\"90 days\",\"expired_chip\":\"Expired\",\"expires_in\":\"Expires in {days}d\",\"audit\":\"Activity\",\"audit_tooltip\":\"View this key's recent requests\",\"audit_title\":\"Key activity\",\"audit_empty\":\"No requests recorded for this key yet.\",\"pair_title\":\"Connect a cloud app\",\"pair_body\":\"This app is asking to connect to your local Personas. Review what it can do, then approve or reject.\",\"pair_insecure_origin\":\"This origin isn't HTTPS — approve only if you trust it.\",\"pair_scopes_label\":\"Requested access\",\"pair_no_scopes\":\"Read-only (list agents and executions).\",\"pair_safety_note\":\"The key is bound to this origin, expires, and is fully auditable. Disconnect it anytime under Connected apps.\",\"pair_later\":\"Later\",\"pair_reject\":\"Reject\",\"pair_approve\":\"Approve\",\"connected_apps_title\":\"Connected apps\",\"connected_apps_desc\":\"Cloud apps you've paired with this device. Each has an origin-bound, expiring key.\",\"connected_apps_revoke\":\"Disconnect\",\"connected_apps_revoke_tooltip\":\"Revoke this app's access\",\"cancel\":\"Cancel\",\"generate_key\":\"Generate key\",\"error_name_required\":\"A name is required.\",\"error_scope_required\":\"Pick at least one scope.\",\"error_execute_persona_required\":\"Pick at least one agent to allow execution, or turn off execution.\",\"created_dialog_title\":\"Key created\",\"created_warning\":\"This is the only time the full key will be shown. Store it now — if you lose it, you'll need to generate a new one.\",\"created_key_label\":\"Your new API key\",\"created_key_hint\":\"Treat this like a password. Anyone with it can drive your local Personas instance.\",\"created_mcp_config_label\":\"MCP client config snippet\",\"copy_mcp_config\":\"Copy MCP config\",\"copy\":\"Copy\",\"copied\":\"Copied\",\"close\":\"Close\",\"created_acknowledge\":\"I have stored this key somewhere safe.\",\"created_done\":\"Done\",\"server_panel_title\":\"MCP server\",\"server_status_running\":\"Running\",\"server_status_checking\":\"Checking…\",\"server_status_down\":\"Down\",\"server_panel_description\":\"Always-on local HTTP server on 127.0.0.1. Auth-gated by the keys below — revoking a key is the kill switch.\",\"endpoints_disclosure\":\"Available endpoints\",\"endpoint_health\":\"Liveness probe (no auth required)\",\"endpoint_build_start\":\"Start a new build session\",\"endpoint_build_status\":\"Read session phase + pending question\",\"endpoint_build_answer\":\"Answer a clarifying question\",\"endpoint_build_test\":\"Run pre-promote tool tests\",\"endpoint_build_promote\":\"Promote a tested draft\",\"server_auth_label\":\"Auth\",\"server_auth_description\":\"Send your key as Authorization: Bearer <token> on every request. Health is the only unauthenticated endpoint.\"},\"title\":\"Settings\",\"settings_saved\":\"Saved\",\"appearance_extra\":{\"simple\":\"Simple\",\"simple_hint\":\"Core features only\",\"full\":\"Full\",\"full_hint\":\"Show the complete interface\",\"dev\":\"Dev\",\"dev_hint\":\"Unlock developer tooling\"},\"quality_gates\":{\"title\":\"Quality Gates\",\"action_reject\":\"Reject\",\"action_tag\":\"Tag\",\"action_warn\":\"Warn\",\"loading\":\"Loading...\",\"error_loading\":\"Error loading config\",\"active_rules\":\"{count} active filter rules\",\"loading_config\":\"Loading quality gate configuration...\",\"description\":\"Quality gates filter AI-generated memories and reviews during execution dispatch. Patterns are matched as substrings against the combined title and content of each submission. When a pattern matches, the configured action is applied. These rules prevent operational noise (credential errors, stack traces, empty workspace reports) from polluting your knowledge base.\",\"memory_filters\":\"Memory Filters\",\"memory_filters_desc\":\"Applied to AgentMemory submissions. Blocks operational failures and credential leaks from being stored as persona memories.\",\"review_filters\":\"Review Filters\",\"review_filters_desc\":\"Applied to ManualReview submissions. Filters infrastructure errors so only genuine business decisions reach the review queue.\",\"rejected_categories\":\"Rejected categories\",\"rules_count\":\"{count} rule\",\"rules_count_plural\":\"{count} rules\",\"reset_defaults\":\"Reset to defaults\",\"confirm_reset\":\"Confirm reset?\",\"rules_hint\":\"Rules are loaded from the database on each dispatch. Changes take effect immediately.\"}}",
+  "settings": "{\"search\":{\"placeholder\":\"Search settings…\",\"recommended\":\"Recommended\",\"section\":\"Settings\",\"trigger_hint\":\"Search settings  ·  Ctrl / ⌘ K\",\"trigger_aria\":\"Open settings search\",\"reduce_motion\":\"Reduce motion\",\"dim\":\"Reduce color intensity\",\"cvd_safe\":\"Color-blind safe palette\",\"high_contrast\":\"High contrast\",\"ambient\":\"Time-of-day header art\",\"theme\":\"Theme & colors\",\"text_size\":\"Text size\",\"brightness\":\"Brightness\",\"density\":\"Layout density\",\"timezone\":\"Time zone\",\"tabs\":{\"limits\":\"Limits\",\"api_keys\":\"API Keys\",\"history\":\"History\"}},\"account\":{\"title\":\"Account\",\"subtitle\":\"Manage your sign-in and profile\",\"avatar_alt\":\"Profile picture\",\"offline\":\"Offline\",\"sign_out\":\"Sign out\",\"sign_in_prompt\":\"Sign in to sync your data across devices\",\"sign_in_google\":\"Sign in with Google\",\"dismiss\":\"Dismiss\",\"waiting_sign_in\":\"Waiting for sign-in...\",\"complete_sign_in\":\"Complete sign-in in the popup window\",\"cancel\":\"Cancel\",\"telemetry_title\":\"Troubleshooting Telemetry\",\"telemetry_description\":\"When enabled, anonymous crash reports and feature usage analytics are sent to help identify and fix bugs. No personal data, credentials, or execution content is ever included.\",\"telemetry_toggle\":\"Send anonymous telemetry\",\"telemetry_on\":\"Crash reports and usage analytics are active.\",\"telemetry_off\":\"Telemetry is disabled. No data is sent to Sentry.\",\"telemetry_restart\":\"Restart the app for this change to take effect\",\"telemetry_toggle_aria\":\"Toggle telemetry\",\"updates_title\":\"Updates\",\"updates_description\":\"Personas auto-checks for updates every six hours. Click below to check now.\",\"updates_check_button\":\"Check for Updates\",\"updates_checking\":\"Checking...\",\"updates_up_to_date\":\"You're up to date\",\"updates_available_toast\":\"Update available — see the banner at the top to install\",\"updates_check_failed\":\"Couldn't check for updates. Try again in a moment.\",\"updates_current_version\":\"You're on v{version}\",\"updates_last_checked\":\"Last checked {time}\",\"updates_history_title\":\"Recent updates\",\"cloud_sync_title\":\"Cloud Dashboard Sync\",\"cloud_sync_description\":\"Sync a read-only copy of your personas, runs, and activity to your private cloud so you can view them in the web dashboard. Your connector credentials never leave this device.\",\"cloud_sync_toggle\":\"Sync to cloud dashboard\",\"cloud_sync_on\":\"Your data is syncing to the cloud dashboard.\",\"cloud_sync_off\":\"Your data stays on this device only.\",\"cloud_sync_toggle_aria\":\"Toggle cloud dashboard sync\",\"cloud_sync_now\":\"Sync now\",\"cloud_sync_syncing\":\"Syncing...\",\"cloud_sync_last\":\"Last synced\",\"cloud_sync_never\":\"Not synced yet\",\"cloud_sync_error\":\"Last sync failed: {error}\",\"cloud_sync_enable_failed\":\"Couldn't update the sync setting\",\"cloud_sync_now_failed\":\"Sync failed. Check your connection and try again.\",\"cloud_sync_now_done\":\"Synced {count} records\",\"cloud_sync_state_off\":\"Off\",\"cloud_sync_state_active\":\"Active\",\"cloud_sync_state_syncing\":\"Syncing…\",\"cloud_sync_total\":\"synced all-time\",\"cloud_sync_device\":\"Device {id}\",\"cloud_sync_tables_title\":\"Synced data\",\"cloud_sync_show_details\":\"Show breakdown\",\"cloud_sync_hide_details\":\"Hide breakdown\",\"cloud_sync_retry\":\"Retry\",\"cloud_sync_rows\":\"rows\",\"cloud_sync_table_pending\":\"Awaiting first sync\",\"cloud_sync_tables\":{\"synced_personas\":\"Personas\",\"synced_executions\":\"Executions\",\"synced_events\":\"Events\",\"synced_manual_reviews\":\"Reviews\",\"synced_messages\":\"Messages\",\"synced_metrics_snapshots\":\"Metrics\",\"synced_tool_usage\":\"Tool usage\",\"synced_memories\":\"Memories\",\"synced_knowledge_patterns\":\"Knowledge\"}},\"appearance\":{\"title\":\"Appearance\",\"subtitle\":\"Customize how the app looks\",\"dark\":\"Dark\",\"light\":\"Light\",\"language\":\"Language\",\"language_hint\":\"Choose the language for the app interface. The same picker is also on the Home screen.\",\"text_size\":\"Text Size\",\"interface_mode\":\"Interface Mode\",\"interface_mode_hint\":\"Simple mode shows only core features. Power mode unlocks the full interface.\",\"theming\":\"Theming\",\"default_tab\":\"Default\",\"custom_tab\":\"Custom\",\"theme_preview_aria\":\"Live preview of {name} theme\",\"contrast_badge_aaa\":\"AAA\",\"contrast_badge_aa\":\"AA\",\"contrast_badge_low\":\"Low\",\"contrast_badge_aria\":\"{level} contrast ({ratio}:1)\",\"contrast_readout_title\":\"Contrast\",\"contrast_pair_body\":\"Body text\",\"contrast_pair_primary_btn\":\"Primary button\",\"contrast_pair_accent_text\":\"Accent text\",\"timezone\":\"Timezone\",\"timezone_hint\":\"Controls how schedule times and cron expressions are displayed throughout the app.\",\"brightness\":\"Brightness\",\"brightness_hint\":\"Adjust screen brightness if the app feels too dark on your display.\",\"dim_mode_label\":\"Dim mode\",\"dim_mode_hint\":\"Reduces overall color saturation. Helpful for late-night use; pairs with any theme.\",\"dim_mode_on\":\"On\",\"dim_mode_off\":\"Off\",\"cvd_safe_label\":\"Color-blind safe status\",\"cvd_safe_hint\":\"Shifts warning chips to yellow and error to a more saturated red, so the red/orange pair stays distinguishable for protan/deuteran vision.\",\"cvd_safe_on\":\"On\",\"cvd_safe_off\":\"Off\",\"high_contrast_label\":\"High contrast\",\"high_contrast_hint\":\"Pushes all status colors to maximum luminance distance from the canvas, strengthens borders, and lifts secondary text. AAA across the board.\",\"high_contrast_on\":\"On\",\"high_contrast_off\":\"Off\",\"reduce_motion_label\":\"Reduce motion\",\"reduce_motion_hint\":\"Disables transitions and animations across the app. Toggles and theme switches still apply, but without movement.\",\"reduce_motion_on\":\"On\",\"reduce_motion_off\":\"Off\",\"density\":\"Density\",\"density_hint\":\"Controls how much spacing the app uses. Compact fits more on screen; Cozy gives everything more room to breathe.\",\"density_compact_desc\":\"More rows per screen\",\"density_comfortable_desc\":\"Balanced — the default\",\"density_cozy_desc\":\"Extra breathing room\",\"custom_theme\":\"Custom Theme\",\"custom_theme_active\":\"Active\",\"custom_theme_hint\":\"Choose a primary color to auto-derive all others, or override individual colors for full control.\",\"base_mode\":\"Base Mode\",\"theme_name\":\"Theme Name\",\"colors\":\"Colors\",\"background_gradient\":\"Background gradient\",\"end_color\":\"End Color\",\"angle\":\"Angle\",\"preview\":\"Preview\",\"save_apply\":\"Save & Apply\",\"applied\":\"Applied\",\"reset\":\"Reset\",\"enter_theme_name\":\"Enter a theme name\",\"reset_to_auto\":\"Reset to auto\",\"preview_dashboard\":\"Dashboard\",\"preview_action\":\"Action\",\"preview_card_title\":\"Card Title\",\"preview_muted_text\":\"Muted description text with secondary content\",\"preview_ok\":\"OK\",\"preview_warn\":\"Warn\",\"preview_err\":\"Err\",\"language_translations\":\"Language & Translations\",\"translation_keys\":\"{count} translation keys\",\"translation_coverage\":\"{covered} of {total} keys ({pct}%)\",\"coverage_full\":\"Full\",\"coverage_hint\":\"Translation coverage -- click to export\",\"contribute_title\":\"Contribute translations\",\"contribute_hint\":\"Help translate Personas into your language. Export a language file above, translate the values, and submit via GitHub.\",\"contribute_github\":\"Contribute on GitHub\",\"ambient_title\":\"Ambient time-of-day\",\"ambient_hint\":\"Phase chip in the title bar that updates with your local clock — dawn, day, dusk, night.\",\"ambient_enabled\":\"Enabled\",\"ambient_disabled\":\"Off\",\"ambient_phase_dawn\":\"Dawn\",\"ambient_phase_day\":\"Day\",\"ambient_phase_dusk\":\"Dusk\",\"ambient_phase_night\":\"Night\",\"tz_label_local\":\"Local\",\"tz_description_local\":\"Browser timezone\",\"tz_label_utc\":\"UTC\",\"tz_description_utc\":\"Coordinated Universal Time\",\"tz_label_us_eastern\":\"US Eastern\",\"tz_description_us_eastern\":\"ET (UTC-5/-4)\",\"tz_label_us_central\":\"US Central\",\"tz_description_us_central\":\"CT (UTC-6/-5)\",\"tz_label_us_pacific\":\"US Pacific\",\"tz_description_us_pacific\":\"PT (UTC-8/-7)\",\"tz_label_london\":\"London\",\"tz_description_london\":\"GMT/BST (UTC+0/+1)\",\"tz_label_prague\":\"Prague\",\"tz_description_prague\":\"CET (UTC+1/+2)\",\"tz_label_tokyo\":\"Tokyo\",\"tz_description_tokyo\":\"JST (UTC+9)\"},\"notifications\":{\"title\":\"Notifications\",\"subtitle\":\"Control which healing alerts trigger notifications\",\"severity_critical_label\":\"Critical\",\"severity_high_label\":\"High\",\"severity_medium_label\":\"Medium\",\"severity_low_label\":\"Low\",\"severity_critical\":\"Circuit breaker tripped, CLI not found\",\"severity_high\":\"Credential errors, session limits, repeated timeouts\",\"severity_medium\":\"Rate limits, first timeouts (auto-fixable)\",\"severity_low\":\"Informational issues\",\"weekly_digest\":\"Weekly Health Digest\",\"digest_title\":\"Agent Health Digest\",\"digest_description\":\"Weekly notification summarizing health issues across all agents with a total health score\",\"healing_alerts_hint\":\"Control which auto-fix alerts trigger notifications\",\"healing_severity\":\"Healing Alert Severity\",\"notification_hint\":\"Desktop notifications use the native OS notification system. In-app toasts appear for critical and high severity issues regardless of these settings.\",\"severity_toggle_aria\":\"{level} notifications\",\"test_button\":\"Test\",\"test_button_tooltip\":\"Show a sample {level} notification — does not change settings.\",\"test_persona_name\":\"Test persona\",\"test_issue_title\":\"Sample {level} notification\",\"test_suggested_fix\":\"This is only a test — your notification settings are wired up correctly.\",\"weekly_digest_aria\":\"Weekly health digest\",\"webhook_subscriptions_title\":\"Outbound webhooks\",\"webhook_subscriptions_add\":\"Add webhook\",\"webhook_subscriptions_empty\":\"No webhook subscriptions yet. Add one to route persona events to Slack, Discord, Teams, or a custom JSON endpoint.\",\"webhook_subscriptions_label\":\"Label\",\"webhook_subscriptions_label_placeholder\":\"Production alerts\",\"webhook_subscriptions_provider\":\"Destination\",\"webhook_subscriptions_provider_labels\":{\"slack\":\"Slack (incoming webhook)\",\"discord\":\"Discord (channel webhook)\",\"teams\":\"Microsoft Teams (incoming webhook)\",\"generic\":\"Generic JSON (POST)\"},\"webhook_subscriptions_url\":\"Webhook URL\",\"webhook_subscriptions_url_placeholder\":\"https://hooks.slack.com/services/...\",\"webhook_subscriptions_events\":\"Event patterns\",\"webhook_subscriptions_events_placeholder\":\"execution.finished, healing.escalated\",\"webhook_subscriptions_events_hint\":\"Comma-separated. Use `prefix.*` to match all sub-types (e.g. `execution.*`) or `*` for everything.\",\"webhook_subscriptions_template\":\"Message template (optional)\",\"webhook_subscriptions_template_placeholder\":\"Persona {{payload.persona_id}} {{event_type}}: {{payload.status}}\",\"webhook_subscriptions_template_hint\":\"Mustache-style placeholders walk the event JSON. Leave blank to use a default summary.\",\"webhook_subscriptions_cancel\":\"Cancel\",\"webhook_subscriptions_save\":\"Save\",\"webhook_subscriptions_test_aria\":\"Send a test notification\",\"webhook_subscriptions_edit_aria\":\"Edit webhook subscription\",\"webhook_subscriptions_delete_aria\":\"Delete webhook subscription\",\"webhook_subscriptions_enabled_aria\":\"Enable webhook subscription\"},\"engine\":{\"title\":\"Engine\",\"loading_capabilities\":\"Loading engine capabilities...\",\"routing_title\":\"Model Routing\",\"routing_subtitle\":\"Tier models by persona category. A rule applies only when a persona has no explicit model; an empty category is the default for all.\",\"routing_empty\":\"No routing rules yet. Add one to tier models by category.\",\"routing_category_ph\":\"Category (empty = all)\",\"routing_model_ph\":\"Model (e.g. claude-opus-4-8)\",\"routing_effort_inherit\":\"Effort: inherit\",\"routing_add\":\"Add rule\",\"routing_save\":\"Save rules\",\"routing_remove\":\"Remove rule\",\"detecting_providers\":\"Detecting installed providers...\",\"subtitle\":\"Configure which CLI providers handle each operation\",\"capability_map\":\"Operation Capability Map\",\"operation\":\"Operation\",\"reset_defaults\":\"Reset to defaults\",\"provider_installed\":\"installed\",\"provider_missing\":\"missing\",\"legend\":\"Legend\",\"legend_enabled\":\"Enabled\",\"legend_unsupported\":\"Unsupported (locked)\",\"legend_not_installed\":\"Not installed\",\"defaults_heading\":\"Defaults from Integration Tests\",\"defaults_description\":\"The default map is derived from Round 9 business-level integration tests that validate each provider against the exact JSON schemas the backend parses. Enabling a provider for an operation it failed may cause unparseable responses. Claude Code is the only provider that passed all operations at 100%.\",\"engine_not_capable\":\"{provider} has not passed integration tests for this operation. Results may be unparseable.\",\"op_persona_execution_label\":\"Persona Execution\",\"op_persona_execution_description\":\"Protocol-compliant output with outcome_assessment, user_message, agent_memory\",\"op_design_analysis_label\":\"Persona Design\",\"op_design_analysis_description\":\"Structured prompts from persona briefs -- DESIGN_OUTPUT_SCHEMA JSON\",\"op_credential_design_label\":\"Credential Design\",\"op_credential_design_description\":\"Connector definitions with fields, healthchecks -- CREDENTIAL_DESIGN_OUTPUT_SCHEMA\",\"op_credential_healthcheck_label\":\"Credential Healthcheck\",\"op_credential_healthcheck_description\":\"API healthcheck endpoint design with {{field_key}} placeholders\",\"op_n8n_transform_label\":\"N8N Transform\",\"op_n8n_transform_description\":\"TRANSFORM_QUESTIONS or section-delimited persona from n8n workflows\",\"op_template_adopt_label\":\"Template Adoption\",\"op_template_adopt_description\":\"TRANSFORM_QUESTIONS or persona JSON from template definitions\",\"op_test_generation_label\":\"Test Scenario Generation\",\"op_test_generation_description\":\"TestScenario[] with mock tools, expected_tool_sequence, edge cases\",\"op_healing_analysis_label\":\"Healing Diagnosis\",\"op_healing_analysis_description\":\"Root-cause analysis from error logs and connector failures\",\"op_recipe_execution_label\":\"Recipe Execution\",\"op_recipe_execution_description\":\"Run automation recipes with prompt templates and input schemas\",\"op_query_debug_label\":\"Query Debug\",\"op_query_debug_description\":\"Fix broken SQL in ```sql code blocks -- rejects JS/TS/Python output\",\"op_unsupported_title\":\"{label} is not supported by {provider} -- failed integration tests\",\"op_disable_title\":\"Disable {label} for {provider}\",\"op_enable_title\":\"Enable {label} for {provider}\",\"engine_verified_title\":\"{provider} is verified for this operation\",\"engine_unparseable_title\":\"{provider} may produce unparseable output for this operation\"},\"ambient\":{\"title\":\"Ambient Context Fusion\",\"description\":\"Ambient context captures clipboard, file changes, and app focus signals to give personas awareness of your desktop workflow.\",\"toggle_aria\":\"Ambient context fusion\",\"events_broadcast\":\"{count} events broadcast\",\"subscribers\":\"{count} subscriber\",\"subscribers_plural\":\"{count} subscribers\",\"live_context\":\"Live Context Window\",\"total_signals\":\"{count} total signals\",\"no_signals\":\"No recent signals captured\",\"sensory_policy\":\"Sensory Policy\",\"reset_defaults\":\"Reset to defaults\",\"clipboard\":\"Clipboard\",\"file_changes\":\"File Changes\",\"app_focus\":\"App Focus\",\"focus_filter\":\"Focus App Filter\",\"focus_filter_hint\":\"Only capture signals when these apps are in focus. Empty = capture from any app.\",\"focus_filter_placeholder\":\"e.g. Code.exe\",\"add\":\"Add\",\"context_rules\":\"Context Rules\",\"add_rule\":\"Add Rule\",\"context_rules_hint\":\"Define patterns that trigger proactive persona actions when desktop context matches.\",\"rule_name_placeholder\":\"Rule name (e.g. \\\"Crash debug helper\\\")\",\"match_sources\":\"Match sources (empty = all)\",\"summary_contains_placeholder\":\"Summary contains (e.g. \\\"error\\\", \\\"Code.exe\\\")\",\"file_glob_placeholder\":\"File glob (e.g. *.rs)\",\"app_filter_placeholder\":\"App filter (e.g. Code.exe)\",\"action\":\"Action\",\"action_trigger\":\"Trigger Execution\",\"action_emit\":\"Emit Event\",\"action_log\":\"Log Only\",\"cooldown\":\"Cooldown (sec)\",\"cancel\":\"Cancel\",\"create_rule\":\"Create Rule\",\"no_rules\":\"No context rules defined\",\"all_sources\":\"all sources\"},\"byom\":{\"title\":\"Bring Your Own Model\",\"subtitle\":\"Configure approved providers, compliance restrictions, and cost-optimized routing\",\"loading\":\"Loading...\",\"unsaved_changes\":\"Unsaved changes\",\"reset\":\"Reset\",\"save_policy\":\"Save Policy\",\"fix_errors\":\"Fix all errors before saving\",\"policy_corrupted\":\"BYOM Policy Corrupted\",\"policy_corrupted_desc\":\"The stored policy JSON could not be parsed. All provider restrictions are currently inactive and executions are blocked. Reset the policy to restore normal operation.\",\"reset_policy\":\"Reset Policy\",\"policy_enforcement\":\"BYOM Policy Enforcement\",\"policy_enforcement_desc\":\"When enabled, provider selection follows your configured rules\",\"tab_providers\":\"Providers\",\"tab_keys\":\"API Keys\",\"tab_routing\":\"Cost Routing\",\"tab_compliance\":\"Compliance\",\"tab_audit\":\"Audit Log\",\"allowed_providers\":\"Allowed Providers\",\"allowed_providers_hint\":\"Select which providers your organization approves. Leave empty to allow all.\",\"allowed\":\"Allowed\",\"blocked_providers\":\"Blocked Providers\",\"blocked_providers_hint\":\"Explicitly block specific providers. Takes precedence over allowed list.\",\"blocked\":\"Blocked\",\"provider_usage\":\"Provider Usage\",\"usage_trends\":\"30-day trends\",\"usage_empty_title\":\"No provider activity yet\",\"usage_empty_description\":\"Once you allow a provider and run an agent, executions, cost, and latency for each provider will appear here.\",\"usage_empty_action\":\"Add a provider key\",\"executions\":\"Executions\",\"cost\":\"Cost\",\"avg_duration\":\"Avg Duration\",\"failover_one\":\"{count} failover\",\"failover_other\":\"{count} failovers\",\"no_trend_data\":\"No trend data\",\"test_connection\":\"Test Connection\",\"testing\":\"Testing...\",\"test_timed_out\":\"Connection test timed out (5s)\",\"test_ipc_failed\":\"IPC call failed\",\"reachable\":\"Reachable\",\"unreachable\":\"Unreachable\",\"test_cli_scope_note\":\"Verifies the provider CLI is installed and responding. The model endpoint and API key are validated on first real use, not by this check.\",\"health_pending\":\"Not yet tested\",\"health_testing\":\"Testing connection…\",\"health_pass\":\"Provider is reachable\",\"health_fail\":\"Provider is unreachable\",\"api_key_title\":\"API Key Management\",\"api_key_hint\":\"Configure API keys and endpoints for custom model providers. Keys are stored encrypted in the local database.\",\"verify\":\"Verify\",\"remove_key\":\"Remove key\",\"save\":\"Save\",\"stored\":\"Stored\",\"error\":\"Error\",\"hide_key\":\"Hide\",\"reveal_key\":\"Reveal\",\"save_key_title\":\"Save\",\"cancel_key_title\":\"Cancel\",\"provider_ollama_label\":\"Ollama\",\"provider_ollama_description\":\"Cloud API key for Ollama-hosted models (Qwen3, GLM-5, Kimi K2.5)\",\"provider_litellm_base_url_label\":\"LiteLLM Base URL\",\"provider_litellm_base_url_description\":\"Proxy base URL for LiteLLM-compatible endpoints\",\"provider_litellm_master_key_label\":\"LiteLLM Master Key\",\"provider_litellm_master_key_description\":\"Authentication key for LiteLLM proxy\",\"provider_qwen_label\":\"Qwen Cloud\",\"provider_qwen_description\":\"DashScope API key for Qwen cloud models. Required to enable the Qwen provider.\",\"qwen_needs_key\":\"Add an API key to enable\",\"routing_title\":\"Cost-Optimized Routing Rules\",\"routing_hint\":\"Route tasks to specific providers/models based on complexity level\",\"routing_empty\":\"No routing rules configured. Add rules to optimize cost by task complexity.\",\"add_rule\":\"Add Rule\",\"rule_name_placeholder\":\"Rule name\",\"complexity\":\"Complexity\",\"provider\":\"Provider\",\"model_optional\":\"Model (optional)\",\"model_placeholder\":\"e.g. claude-haiku-4-5-20251001\",\"compliance_title\":\"Compliance-Driven Restrictions\",\"compliance_hint\":\"Restrict providers for specific workflow types (e.g., HIPAA, SOC2)\",\"compliance_empty\":\"No compliance rules configured. Add rules to restrict providers for sensitive workflows.\",\"compliance_name_placeholder\":\"Rule name (e.g., HIPAA)\",\"workflow_tags\":\"Workflow Tags (comma-separated)\",\"workflow_tags_placeholder\":\"hipaa, healthcare, pii\",\"allowed_providers_label\":\"Allowed Providers\",\"audit_title\":\"Provider Audit Log\",\"audit_hint\":\"Compliance trail showing which provider handled each execution\",\"audit_empty\":\"No audit entries yet. Entries are recorded automatically for every execution.\",\"audit_provider\":\"Provider\",\"audit_model\":\"Model\",\"audit_persona\":\"Persona\",\"audit_status\":\"Status\",\"audit_cost\":\"Cost\",\"audit_time\":\"Time\",\"failover\":\"failover\"},\"admin\":{\"title\":\"Admin\",\"subtitle\":\"Development tools and testing utilities\",\"guided_tour\":\"Guided Tour\",\"tour_hint\":\"Force-start or reset the onboarding tour for e2e testing\",\"tour_active\":\"Active\",\"tour_completed\":\"Completed\",\"tour_dismissed\":\"Dismissed\",\"tour_not_started\":\"Not started\",\"progress\":\"Progress\",\"steps\":\"steps\",\"current_step\":\"Current Step\",\"step_status\":\"Step Status\",\"force_start\":\"Force Start Tour\",\"confirm_reset\":\"Confirm Reset\",\"reset_state\":\"Reset State\",\"force_complete\":\"Force Complete\",\"force_dismiss\":\"Force Dismiss\",\"user_consent\":\"User Consent\",\"consent_hint\":\"Reset the first-use consent modal to test onboarding\",\"consent_accepted\":\"Accepted\",\"consent_not_accepted\":\"Not accepted\",\"storage_key\":\"Storage Key\",\"reset_consent\":\"Reset Consent\",\"reload_modal\":\"Reload to Show Modal\",\"tour_step_unavailable\":\"N/A\"},\"portability\":{\"title\":\"Data Portability\",\"subtitle\":\"Export, import, and migrate your workspace data\",\"storage_title\":\"Storage\",\"storage_subtitle\":\"Local database usage and cleanup of finished runs.\",\"storage_db_size\":\"Database size\",\"storage_total\":\"Total runs\",\"storage_removable\":\"Removable\",\"storage_prune\":\"Clean up old runs\",\"storage_confirm\":\"Remove {count} finished runs older than 24 hours?\",\"storage_confirm_yes\":\"Remove\",\"storage_pruned\":\"Removed {count} runs.\",\"workspace_overview\":\"Workspace Overview\",\"loading_stats\":\"Loading workspace stats...\",\"stats_error\":\"Failed to load workspace statistics.\",\"error_label\":\"Error\",\"personas\":\"Personas\",\"teams\":\"Teams\",\"tools\":\"Tools\",\"groups\":\"Groups\",\"credentials\":\"Credentials\",\"memories\":\"Memories\",\"team_memories\":\"Team Memories\",\"test_suites\":\"Test Suites\",\"kpis\":\"KPIs\",\"export_import_title\":\"Workspace Export & Import\",\"export_import_hint\":\"Export your workspace to a portable ZIP archive containing personas, teams, credentials, and related data. Choose exactly what to include. Import restores from a previously exported archive -- imported items are created as new entities (disabled by default).\",\"exporting\":\"Exporting...\",\"exported\":\"Exported!\",\"export_workspace\":\"Export Workspace\",\"import_workspace\":\"Import Workspace\",\"import_label\":\"Import\",\"imported\":\"Imported!\",\"cancel\":\"Cancel\",\"passphrase_optional\":\"Passphrase (optional)\",\"import_complete\":\"Import Complete\",\"warnings\":\"Warnings:\",\"import_personas\":\"{count} persona(s)\",\"import_teams\":\"{count} team(s)\",\"import_tools\":\"{count} tool(s)\",\"import_groups\":\"{count} group(s)\",\"import_credentials_count\":\"{count} credential(s)\",\"import_team_memories\":\"{count} team memory(ies)\",\"import_kpis\":\"{count} KPI(s)\",\"include_memories_label\":\"Include memories\",\"include_memories_note\":\"Persona and team memories travel with the export. Turn this off to export agents and teams without their accumulated memories.\",\"export_title\":\"Export Workspace\",\"export_subtitle\":\"Choose what to include in your export\",\"close\":\"Close\",\"loading_data\":\"Loading workspace data...\",\"deselect_all\":\"Deselect All\",\"select_all\":\"Select All\",\"items_selected\":\"{selected} of {total} items selected\",\"of_selected\":\"{count} of {total} selected\",\"encrypt_passphrase\":\"Encrypt credentials with passphrase\",\"optional\":\"(optional)\",\"passphrase_placeholder\":\"Passphrase (min 8 characters)\",\"passphrase_too_short\":\"Passphrase must be at least 8 characters\",\"passphrase_note\":\"If set, credential secrets will be included in the export and protected with AES-256 encryption.\",\"auto_included_note\":\"Groups, tools, memories, and test suites linked to selected personas are automatically included.\",\"no_passphrase_note\":\" Credential secrets are not included unless a passphrase is set above.\",\"export_all\":\"Export All\",\"export_items\":\"Export {count} Item\",\"export_items_plural\":\"Export {count} Items\",\"credential_vault\":\"Credential Vault\",\"credential_vault_hint\":\"Workspace exports do not include credential secrets. Use this section to export and import your vault with password-protected AES-256 encryption.\",\"export_credentials\":\"Export Credentials\",\"import_credentials\":\"Import Credentials\",\"passphrase_min\":\"Passphrase (min 8 chars)\",\"passphrase_label\":\"Passphrase\",\"export\":\"Export\",\"credentials_exist\":\"{count} credential already exist\",\"credentials_exist_plural\":\"{count} credentials already exist\",\"conflict_hint\":\"Choose how to handle each conflict:\",\"skip\":\"Skip\",\"keep_both\":\"Keep Both\",\"replace\":\"Replace\",\"import_with_resolutions\":\"Import with Resolutions\",\"cred_import_complete\":\"Credential Import Complete\",\"cred_imported\":\"{count} imported\",\"cred_skipped\":\", {count} skipped\",\"cred_replaced\":\", {count} replaced\",\"please_enter_passphrase\":\"Please enter the passphrase used during export\",\"no_import_file\":\"No import file available -- please start the import again\",\"export_failed\":\"Export failed\",\"selective_export_failed\":\"Selective export failed\",\"import_failed\":\"Import failed\",\"cred_export_failed\":\"Credential export failed\",\"cred_import_failed\":\"Credential import failed\",\"proto\":{\"search_placeholder\":\"Search by name, team, model…\",\"filter_all\":\"All\",\"filter_teamed\":\"In a team\",\"filter_unteamed\":\"No team\",\"filter_enabled\":\"Enabled only\",\"filter_starred\":\"Starred\",\"scope_personas\":\"Personas\",\"scope_teams\":\"Teams\",\"scope_credentials\":\"Credentials\",\"select_filtered\":\"Select {count} shown\",\"deselect_filtered\":\"Deselect {count} shown\",\"clear_all\":\"Clear all\",\"select_everything\":\"Select everything\",\"no_results_title\":\"No matches\",\"no_results_hint\":\"Adjust your search or filters.\",\"results_count\":\"{count} shown\",\"selected_of\":\"{selected} / {total}\",\"manifest_title\":\"Export manifest\",\"manifest_subtitle\":\"What ships in this archive\",\"manifest_full\":\"Full workspace\",\"manifest_empty\":\"Nothing selected yet — pick items on the left.\",\"row_personas\":\"Personas\",\"row_teams\":\"Teams\",\"row_credentials\":\"Credentials\",\"dependency_note\":\"Tools, groups, memories & test suites linked to selected personas ship automatically.\",\"secrets_encrypted\":\"Secrets encrypted (AES-256)\",\"secrets_excluded\":\"Credential secrets excluded\",\"disabled\":\"Off\",\"model_label\":\"Model profile\",\"model_default\":\"Default model\",\"trust_label_full\":\"Trust score\",\"team_label\":\"Team membership\",\"in_team\":\"in {team}\",\"no_team\":\"No team\",\"multi_team\":\"{count} teams\",\"members_label\":\"Members\",\"kpi_count_label\":\"{count} KPIs\",\"kpi_off_track\":\"Off-track KPIs\",\"kpi_setup_title\":\"Include KPI setup\",\"kpi_setup_hint\":\"Each selected team's KPIs travel with it — all or nothing.\",\"kpi_setup_ship\":\"{count} KPIs ship with the selected teams.\",\"kpi_setup_off\":\"KPI setup excluded from this export.\",\"kpi_setup_none\":\"No team KPIs available to include.\",\"memories_title\":\"Include memories\",\"memories_hint\":\"Persona & team memories travel with the export.\",\"encryption_title\":\"Encrypt credential secrets\",\"encryption_hint\":\"Set a passphrase to include encrypted secrets in the archive.\"}},\"config\":{\"title\":\"Config Resolution\",\"subtitle\":\"Shows which tier (agent / workspace / global) supplies each setting per persona\",\"refresh\":\"Refresh\",\"agent_level\":\"Agent-level\",\"workspace_level\":\"Workspace\",\"global_level\":\"Global\",\"not_set\":\"Not set\",\"overrides_inherited\":\"Overrides inherited\",\"agent\":\"Agent\",\"loading_agents\":\"Loading agents...\",\"no_agents\":\"No agents found\",\"source_agent_label\":\"Agent\",\"source_workspace_label\":\"Workspace\",\"source_global_label\":\"Global\",\"source_default_label\":\"--\",\"field_model\":\"Model\",\"field_provider\":\"Provider\",\"field_budget\":\"Budget\",\"field_turns\":\"Turns\",\"field_cache\":\"Cache\",\"_comment_per_capability\":\"Config matrix — model is chosen per capability (use-case model_override); shown as a legend item + on Model cells that resolve per-capability\",\"per_capability\":\"Per capability\",\"failed_to_resolve\":\"Failed to resolve\",\"config_could_not_be_resolved\":\"Config could not be resolved\",\"search_placeholder\":\"Filter agents by name…\",\"clear_filter_aria\":\"Clear filter\",\"overrides_only_label\":\"Overrides only\",\"overrides_only_tooltip\":\"Show only agents that have at least one agent-level override on top of workspace or global defaults.\",\"filtered_empty\":\"No agents match the current filter.\",\"showing_count\":\"Showing {shown} of {total}\"},\"limits\":{\"title\":\"Limits\",\"subtitle\":\"Parallel-execution cap and monthly cost ceiling\",\"description\":\"Set a monthly USD ceiling on your Personas spend. Stage 1 (this build) is informational — it shows progress and warnings but does not block runs. Stage 2 will enforce the ceiling at execution-dispatch time.\",\"refresh\":\"Refresh\",\"concurrency_section\":\"Parallel executions\",\"concurrency_hint\":\"How many personas can run at the same time. Extra runs wait in a queue and start automatically as slots free up. Changes apply immediately — no restart needed.\",\"concurrency_aria\":\"Maximum parallel executions\",\"concurrency_range\":\"Between {min} and {max} concurrent runs.\",\"concurrency_queued_note\":\"Runs beyond this limit are queued and promoted by priority, then arrival order, as running ones finish.\",\"ceiling_section\":\"Monthly ceiling\",\"ceiling_hint\":\"Combined cost across all personas, refreshed when you reopen this tab. Set to 0 to disable the ceiling.\",\"ceiling_placeholder\":\"0.00\",\"ceiling_aria\":\"Monthly cost ceiling in USD\",\"ceiling_unit\":\"USD / month\",\"set\":\"Set\",\"saved\":\"Saved\",\"unlimited\":\"(no ceiling)\",\"usage_section\":\"Current month\",\"of_ceiling\":\"of {ceiling}\",\"progress_aria\":\"{pct}% of monthly ceiling used\",\"over_budget\":\"You have exceeded your monthly ceiling. Stage 2 will block new executions when this happens; for now, treat this as a warning.\",\"approaching_budget\":\"You are approaching your monthly ceiling. Consider reviewing recent runs.\",\"no_ceiling_hint\":\"No ceiling is set. Enter a USD amount above to start tracking against a budget.\",\"stage1_note\":\"Stage 1: informational only. Stage 2 will gate execution dispatch when the ceiling is crossed.\"},\"recent_change\":{\"label\":\"Last {action}: {when}\",\"click_to_open\":\"Click to open History\"},\"history\":{\"title\":\"Settings History\",\"subtitle\":\"Audit log of changes made to settings\",\"description\":\"Append-only record of who changed which setting and when. Use this to trace back surprising behavior or coordinate audit reviews. Every user-facing settings change is recorded here — API keys, engine, limits, retention, autonomy, notifications and more; secret values are redacted, and internal engine bookkeeping is excluded.\",\"refresh\":\"Refresh\",\"filter_category\":\"Category\",\"filter_all\":\"All\",\"showing_max\":\"Showing the most recent 100 entries — older history is still in the database.\",\"loading\":\"Loading audit entries…\",\"empty\":\"No settings changes recorded yet. Mutations made from here on will appear in this list.\",\"empty_filtered\":\"No entries match the current category filter.\",\"before\":\"Before\",\"after\":\"After\",\"categories\":{\"api_keys\":\"API Keys\",\"engine\":\"Engine\",\"limits\":\"Limits\",\"retention\":\"Retention\",\"byom\":\"BYOM\",\"notifications\":\"Notifications\",\"autonomy\":\"Autonomy\",\"quality_gates\":\"Quality Gates\",\"integrations\":\"Integrations\",\"sync\":\"Cloud Sync\",\"config\":\"General\"}},\"api_keys\":{\"title\":\"API Keys\",\"description\":\"Generate keys for third-party tools and MCP clients to drive Personas remotely. Each key authenticates to the local management HTTP server (127.0.0.1:9420) — only valid keys can start builds, list personas, or trigger executions.\",\"loading\":\"Loading...\",\"loading_keys\":\"Loading keys...\",\"active_keys\":\"active\",\"create_key\":\"New key\",\"your_keys\":\"Your keys\",\"empty\":\"No keys yet. Create one to let an MCP client connect.\",\"retry\":\"Retry\",\"never_used\":\"never used\",\"last_used\":\"Last used\",\"created_label\":\"Created\",\"stale_chip\":\"Stale\",\"stale_tooltip\":\"No activity in 30+ days — consider revoking if no longer in use.\",\"no_scopes\":\"no scopes\",\"revoke\":\"Revoke\",\"revoked\":\"Revoked\",\"revoke_tooltip\":\"Disable this key — clients using it will be rejected immediately. Audit row stays for history.\",\"delete\":\"Delete\",\"delete_tooltip\":\"Remove this key from the database permanently.\",\"confirm_delete\":\"Confirm\",\"create_dialog_title\":\"Create API Key\",\"field_name_label\":\"Name\",\"field_name_placeholder\":\"e.g. Claude Desktop · build-mcp\",\"field_name_hint\":\"Short label so you remember which client uses it. Visible only to you.\",\"field_scopes_label\":\"Scopes\",\"scope_build\":\"Build (one-shot + interactive)\",\"scope_build_desc\":\"Drive a persona build session: start, answer questions, test, promote.\",\"scope_read\":\"Read\",\"scope_read_desc\":\"List personas, fetch persona detail, read execution history.\",\"scope_events\":\"Publish events\",\"scope_events_desc\":\"Publish events onto the event bus, which can trigger subscribed personas.\",\"scope_execute\":\"Execute\",\"scope_execute_desc\":\"Run only the specific agents you pick below. Each pick becomes a per-agent grant — no blanket execute.\",\"scope_execute_label\":\"Execute specific agents\",\"execute_pick_hint\":\"Choose which agents this key may run\",\"execute_filter_placeholder\":\"Filter agents…\",\"execute_no_personas\":\"No agents yet — create one first.\",\"field_expiry_label\":\"Expires\",\"expiry_never\":\"Never\",\"expiry_7d\":\"7 days\",\"expiry_30d\":\"30 days\",\"expiry_90d\":\"90 days\",\"expired_chip\":\"Expired\",\"expires_in\":\"Expires in {days}d\",\"audit\":\"Activity\",\"audit_tooltip\":\"View this key's recent requests\",\"audit_title\":\"Key activity\",\"audit_empty\":\"No requests recorded for this key yet.\",\"pair_title\":\"Connect a cloud app\",\"pair_body\":\"This app is asking to connect to your local Personas. Review what it can do, then approve or reject.\",\"pair_insecure_origin\":\"This origin isn't HTTPS — approve only if you trust it.\",\"pair_scopes_label\":\"Requested access\",\"pair_no_scopes\":\"Read-only (list agents and executions).\",\"pair_safety_note\":\"The key is bound to this origin, expires, and is fully auditable. Disconnect it anytime under Connected apps.\",\"pair_later\":\"Later\",\"pair_reject\":\"Reject\",\"pair_approve\":\"Approve\",\"connected_apps_title\":\"Connected apps\",\"connected_apps_desc\":\"Cloud apps you've paired with this device. Each has an origin-bound, expiring key.\",\"connected_apps_revoke\":\"Disconnect\",\"connected_apps_revoke_tooltip\":\"Revoke this app's access\",\"cancel\":\"Cancel\",\"generate_key\":\"Generate key\",\"error_name_required\":\"A name is required.\",\"error_scope_required\":\"Pick at least one scope.\",\"error_execute_persona_required\":\"Pick at least one agent to allow execution, or turn off execution.\",\"created_dialog_title\":\"Key created\",\"created_warning\":\"This is the only time the full key will be shown. Store it now — if you lose it, you'll need to generate a new one.\",\"created_key_label\":\"Your new API key\",\"created_key_hint\":\"Treat this like a password. Anyone with it can drive your local Personas instance.\",\"created_mcp_config_label\":\"MCP client config snippet\",\"copy_mcp_config\":\"Copy MCP config\",\"copy\":\"Copy\",\"copied\":\"Copied\",\"close\":\"Close\",\"created_acknowledge\":\"I have stored this key somewhere safe.\",\"created_done\":\"Done\",\"server_panel_title\":\"MCP server\",\"server_status_running\":\"Running\",\"server_status_checking\":\"Checking…\",\"server_status_down\":\"Down\",\"server_panel_description\":\"Always-on local HTTP server on 127.0.0.1. Auth-gated by the keys below — revoking a key is the kill switch.\",\"endpoints_disclosure\":\"Available endpoints\",\"endpoint_health\":\"Liveness probe (no auth required)\",\"endpoint_build_start\":\"Start a new build session\",\"endpoint_build_status\":\"Read session phase + pending question\",\"endpoint_build_answer\":\"Answer a clarifying question\",\"endpoint_build_test\":\"Run pre-promote tool tests\",\"endpoint_build_promote\":\"Promote a tested draft\",\"server_auth_label\":\"Auth\",\"server_auth_description\":\"Send your key as Authorization: Bearer <token> on every request. Health is the only unauthenticated endpoint.\"},\"title\":\"Settings\",\"settings_saved\":\"Saved\",\"appearance_extra\":{\"simple\":\"Simple\",\"simple_hint\":\"Core features only\",\"full\":\"Full\",\"full_hint\":\"Show the complete interface\",\"dev\":\"Dev\",\"dev_hint\":\"Unlock developer tooling\"},\"quality_gates\":{\"title\":\"Quality Gates\",\"action_reject\":\"Reject\",\"action_tag\":\"Tag\",\"action_warn\":\"Warn\",\"loading\":\"Loading...\",\"error_loading\":\"Error loading config\",\"active_rules\":\"{count} active filter rules\",\"loading_config\":\"Loading quality gate configuration...\",\"description\":\"Quality gates filter AI-generated memories and reviews during execution dispatch. Patterns are matched as substrings against the combined title and content of each submission. When a pattern matches, the configured action is applied. These rules prevent operational noise (credential errors, stack traces, empty workspace reports) from polluting your knowledge base.\",\"memory_filters\":\"Memory Filters\",\"memory_filters_desc\":\"Applied to AgentMemory submissions. Blocks operational failures and credential leaks from being stored as persona memories.\",\"review_filters\":\"Review Filters\",\"review_filters_desc\":\"Applied to ManualReview submissions. Filters infrastructure errors so only genuine business decisions reach the review queue.\",\"rejected_categories\":\"Rejected categories\",\"rules_count\":\"{count} rule\",\"rules_count_plural\":\"{count} rules\",\"reset_defaults\":\"Reset to defaults\",\"confirm_reset\":\"Confirm reset?\",\"rules_hint\":\"Rules are loaded from the database on each dispatch. Changes take effect immediately.\"}}",
   "design": "{\"conversation_truncated\":\"This conversation reached the message limit. Older messages have been removed — consider starting a new conversation to preserve context.\",\"no_persona\":\"No persona selected\",\"stages\":{\"assembling_label\":\"Preparing instructions\",\"assembling_desc\":\"Putting together the agent instructions from your settings\",\"generating_label\":\"Generating with AI\",\"generating_desc\":\"Running Claude to create the agent design\",\"parsing_label\":\"Reading results\",\"parsing_desc\":\"Processing the AI response into a usable format\",\"checking_label\":\"Checking everything works\",\"checking_desc\":\"Making sure the suggested tools and services are available\",\"saving_label\":\"Saving result\",\"saving_desc\":\"Saving the finished design\"}}",
   "onboarding": "{\"title\":\"Get Started\",\"subtitle\":\"Create and run your first agent\",\"skip_tooltip\":\"Skip onboarding\",\"opening_wizard\":\"Setting up your agent...\",\"skip_button\":\"Skip\",\"replay_setup\":\"Replay setup\",\"resume_setup\":\"Resume setup\",\"adopt_button\":\"Set Up Agent\",\"continue_button\":\"Continue\",\"done_button\":\"Done\",\"your_agent_fallback\":\"Your Agent\",\"scanning_tooltip\":\"Scanning your desktop apps...\",\"select_template_tooltip\":\"Select a template first\",\"step_appearance\":\"Look & Feel\",\"step_discover\":\"Desktop\",\"step_pick_template\":\"Pick Template\",\"step_adopt\":\"Set Up Agent\",\"step_execute\":\"First Run\",\"desktop_title\":\"Your desktop environment\",\"desktop_description\":\"We found these apps on your machine. Allow access so your agents can interact with them directly.\",\"desktop_empty\":\"No supported desktop apps detected.\",\"desktop_empty_hint\":\"You can connect desktop apps later from the Connections section.\",\"desktop_scan_error\":\"Could not scan your desktop.\",\"desktop_scan_error_hint\":\"We couldn't reach the discovery service. Check your network and try again, or skip this step.\",\"desktop_scan_retry\":\"Retry scan\",\"risk_review\":\"Review recommended\",\"risk_review_tooltip\":\"This app can run commands on your system — review before allowing\",\"risk_safe\":\"Safe to allow\",\"risk_safe_tooltip\":\"Read-only access, safe to allow\",\"appearance_heading\":\"Set up your preferences\",\"appearance_description\":\"Configure language, text size, and theme. You can change these anytime in Settings.\",\"interface_mode_heading\":\"Choose your interface\",\"interface_mode_description\":\"You can change this later in Settings.\",\"language_label\":\"Language\",\"text_size_label\":\"Text Size\",\"density_label\":\"Density\",\"dark_label\":\"Dark\",\"light_label\":\"Light\",\"brightness_label\":\"Brightness\",\"brightness_hint\":\"If the app feels too dark on your monitor, increase brightness.\",\"scanning_desktop\":\"Scanning your desktop...\",\"approved\":\"Approved\",\"approve\":\"Approve\",\"app_running_tooltip\":\"Running\",\"run_first_agent\":\"Run your first agent\",\"execute_description\":\"Execute {name} and see real-time output.\",\"agent_ready_hint\":\"Your agent is ready. Click below to start the first execution and see it in action.\",\"run_agent\":\"Run Agent\",\"execution_completed\":\"Execution completed successfully\",\"executing\":\"Executing...\",\"agent_output\":\"Agent Output\",\"waiting_for_output\":\"Waiting for output...\",\"execution_failed\":\"Failed to start execution\",\"loading_templates\":\"Loading templates...\",\"templates_load_error\":\"Could not load starter templates.\",\"retry\":\"Retry\",\"no_templates\":\"No starter templates found.\",\"no_templates_hint\":\"Generate templates first from the Templates section.\",\"pick_template_heading\":\"Pick a starter template\",\"pick_template_description\":\"Choose one of these popular templates to create your first agent.\",\"more_connectors\":\"+{count} more\",\"because_you_connected\":\"Because you connected {apps}, these templates fit best:\",\"recommended_match_badge\":\"Matches {apps}\",\"getting_started\":\"Getting Started\",\"progress_appearance\":\"Look & feel\",\"progress_discover\":\"Detect desktop apps\",\"progress_pick_template\":\"Pick template\",\"progress_adopt\":\"Adopt agent\",\"progress_execute\":\"First run\",\"tour_step_of\":\"Step {current} of {total}\",\"minimize\":\"Minimize\",\"end_tour\":\"End tour\",\"narration_replay\":\"Replay narration\",\"narration_mute\":\"Mute narration\",\"narration_unmute\":\"Unmute narration\",\"resume_tour\":\"Resume Tour ({completed}/{total})\",\"resume_continue_title\":\"Pick up where you left off\",\"resume_continue_cta\":\"Continue tour\",\"back\":\"Back\",\"complete_tour\":\"Complete Tour\",\"tour_show_me\":\"Show me\",\"tour_show_me_title\":\"Scroll to and re-highlight this on screen\",\"tour_locate_title\":\"Show me where this is\",\"tour_progress_done\":\"{completed} of {total} done\",\"tour_skip_step\":\"Skip step\",\"tour_intro_heading\":\"Before you start\",\"tour_intro_steps\":\"{count} steps · go at your own pace\",\"tour_complete_title\":\"Tour complete!\",\"tour_complete_recap\":\"You finished all {total} steps.\",\"tour_complete_up_next\":\"Up next\",\"tour_complete_next\":\"Start the next tour\",\"tour_minimized_hint\":\"{tour} · {completed}/{total} steps · click to resume\",\"tour_complete_more\":\"Or explore another tour\",\"tour_target_offscreen\":\"What this step points at isn't on screen yet — it'll highlight once it appears.\",\"tour_a11y_region\":\"Guided tour\",\"tour_substep_prev\":\"Previous detail\",\"tour_substep_next\":\"Next detail\",\"tour_loading\":\"Loading...\",\"tour_skip\":\"Skip\",\"what_to_explore\":\"What to explore\",\"auto_complete_hint\":\"This step completes once you've performed the suggested action above.\",\"tour_explore_to_continue\":\"Take your time. Click below when you've explored this section.\",\"tour_acknowledge\":\"I've explored this\",\"tour_handoff_title\":\"Want the guided tour?\",\"tour_handoff_body\":\"Your first agent is live. Take a quick guided tour of the rest — connections and running agents — and we'll skip the parts you just did.\",\"tour_handoff_accept\":\"Take the tour\",\"tour_handoff_dismiss\":\"Not now\",\"start_tour\":\"Start Tour\",\"tour_storage_unavailable_toast\":\"Your browser blocked our local storage, so the tour will restart each session. Try clearing site data or leaving private browsing.\",\"connector_count_stat\":\"200+ Built-in Connectors\",\"connector_count_hint\":\"Pre-configured with auth fields and health checks\",\"categories_label\":\"Categories\",\"browsed_progress\":\"Browsed {count}/2\",\"local_tooling_label\":\"Built-in tooling\",\"local_tooling_sentence\":\"Personas has local tooling connected from the start — Messaging, Database, Vector, and more — no credential required.\",\"connection_types_label\":\"Connection Types\",\"conn_api_key\":\"API Key / Token\",\"conn_api_key_desc\":\"Standard authentication — paste your key and go.\",\"conn_oauth\":\"OAuth 2.0\",\"conn_oauth_desc\":\"Secure authorization flow — click to authorize, no secrets to manage.\",\"conn_mcp\":\"MCP Protocol\",\"conn_mcp_desc\":\"Model Context Protocol — connect AI tools via stdio or SSE transport.\",\"conn_desktop\":\"Desktop Bridge\",\"conn_desktop_desc\":\"Integrate directly with local apps — VS Code, Terminal, Docker.\",\"connect_once\":\"Connect once, use across all agents\",\"connect_once_hint\":\"Credentials are shared across your entire agent fleet. Set up a Slack connection once and every agent can use it.\",\"describe_intent\":\"Describe what your agent should do. Be specific about the task, data sources, and desired output.\",\"example_intents_label\":\"Example intents\",\"example_intent_1\":\"Monitor GitHub PRs and summarize weekly activity\",\"example_intent_2\":\"Scrape job postings and send daily digest to Slack\",\"example_intent_3\":\"Analyze customer feedback and update Notion database\",\"coach_substep_describe\":\"Describe\",\"coach_substep_answer\":\"Answer\",\"coach_substep_review\":\"Review\",\"coach_substep_promote\":\"Promote\",\"intent_field_hint\":\"Type your intent in the field on the right, then click the launch button.\",\"analyzing_hint\":\"The AI is analyzing your intent and may ask clarifying questions to refine the agent design.\",\"questions_waiting_one\":\"{count} question waiting\",\"questions_waiting_other\":\"{count} questions waiting\",\"answer_questions_hint\":\"Answer them in the matrix to shape your agent's design.\",\"answers_help_hint\":\"Your answers help the AI choose the right connectors, triggers, and policies.\",\"matrix_heading\":\"Your agent's eight sigils:\",\"matrix_completeness\":\"{pct}% complete\",\"dim_use_cases\":\"Use Cases\",\"dim_use_cases_desc\":\"What workflows your agent handles\",\"dim_connectors\":\"Connectors\",\"dim_connectors_desc\":\"External services it integrates with\",\"dim_triggers\":\"Triggers\",\"dim_triggers_desc\":\"How and when it activates\",\"dim_human_review\":\"Human Review\",\"dim_human_review_desc\":\"When it needs your approval\",\"dim_messages\":\"Messages\",\"dim_messages_desc\":\"How it notifies you of results\",\"dim_memory\":\"Memory\",\"dim_memory_desc\":\"Conversation persistence across runs\",\"dim_error_handling\":\"Error Handling\",\"dim_error_handling_desc\":\"Fallback strategies on failures\",\"dim_events\":\"Events\",\"dim_events_desc\":\"Event subscriptions it listens to\",\"all_tests_passed\":\"All tests passed!\",\"promote_hint\":\"Your agent has been verified. Click \\\"Promote\\\" to make it production-ready.\",\"some_tests_failed\":\"Some tests failed\",\"refine_hint\":\"You can refine the agent and re-test, or skip this step for now.\",\"testing_description\":\"Testing validates that your agent's tools work correctly with real APIs.\",\"what_testing_checks\":\"What testing checks:\",\"test_check_api\":\"Each tool connects to its target API\",\"test_check_creds\":\"Credentials are valid and have correct permissions\",\"test_check_format\":\"Response formats match expectations\",\"run_test_hint\":\"Click \\\"Run Test\\\" in the matrix to verify, then promote to production.\",\"agent_promoted\":\"Agent promoted!\",\"agent_promoted_hint\":\"Your first agent is live. The tour is almost complete!\",\"skip_build\":\"Skip build for now\",\"dark_themes\":\"Dark Themes\",\"light_themes\":\"Light Themes\"}",
   "system_health": "{\"install_all\":\"Install All Dependencies\",\"log_disk_usage\":\"Log Disk Usage\",\"log_disk_tracing\":\"Tracing logs\",\"log_disk_crashes\":\"Crash reports\",\"log_disk_files_count\":\"{count} files\",\"log_disk_retention_hint\":\"Daily rotation, capped at {limit} files\",\"log_disk_crash_retention_hint\":\"Capped at {limit} most recent reports\",\"log_disk_unavailable\":\"Log directory stats unavailable.\",\"title\":\"System Checks\",\"subtitle\":\"Verifying your environment is ready\",\"rerun\":\"Re-run checks\",\"category_local\":\"Local Environment\",\"category_agents\":\"Agents\",\"category_cloud\":\"Cloud Deployment\",\"category_account\":\"Account\",\"bridge_error\":\"The application bridge is not responding. Try restarting the app. You can still continue to explore the interface.\",\"partial_error\":\"Some checks reported issues. You can still continue, but some features may not work correctly.\",\"ollama_title\":\"Ollama Cloud API Key\",\"ollama_hint\":\"Optional -- unlocks free cloud models (Qwen3 Coder, GLM-5, Kimi K2.5) for all agents.\",\"save_key\":\"Save Key\",\"litellm_title\":\"LiteLLM Proxy Configuration\",\"litellm_hint\":\"Optional -- route agents through your LiteLLM proxy for model management and cost tracking.\",\"save_config\":\"Save Configuration\",\"litellm_note\":\"These settings are stored locally and shared across all agents configured to use the LiteLLM provider.\",\"crash_logs\":\"Crash Logs\",\"no_crash_logs\":\"No crash logs recorded.\",\"crash_auto_cred\":\"Auto-cred session\",\"crash_rust_panic\":\"Rust panic\",\"ready_title\":\"Ready to create your first agent?\",\"ready_hint\":\"All checks passed. Let us guide you through creating and running your first agent.\",\"get_started\":\"Get Started\",\"install_nodejs\":\"Install Node.js\",\"install_cli\":\"Install Claude CLI\",\"downloading\":\"Downloading...\",\"installing\":\"Installing...\",\"installed_success\":\"Installed successfully\",\"installation_failed\":\"Installation failed\",\"try_manual\":\"Try running manually:\",\"official_page\":\"Official page\",\"edit_key\":\"Edit Key\",\"edit_config\":\"Edit Config\",\"signing_in\":\"Signing in...\"}",
@@ -34,7 +34,7 @@ export const EN_SECTION_STRINGS = {
   "recipe_shared": "{\"schema_parse_error\":\"Could not read this recipe's input fields — the stored schema is malformed.\",\"schema_parse_error_detail\":\"Error: {error}\",\"schema_parse_error_hint\":\"Re-generate the recipe or edit the input schema manually to fix this.\"}",
   "agent_lab": "{\"export_download_html\":\"Export HTML\",\"export_copy_markdown\":\"Copy Markdown\",\"export_copied\":\"Copied!\"}",
   "execution_status": "{\"completed\":\"Completed\",\"failed\":\"Failed\",\"queued\":\"Queued\",\"running\":\"Running\",\"cancelled\":\"Cancelled\",\"error\":\"Error\"}",
-  "status_tokens": "{\"verdict\":{\"production\":\"Production\",\"promising\":\"Promising\",\"not_ready\":\"Not Ready\",\"broken\":\"Broken\"},\"chain_stop\":{\"depth_limit\":\"Depth limit\",\"handoff_suppressed\":\"Handoff suppressed\",\"cycle_detected\":\"Cycle detected\",\"predicate_unmet\":\"Condition not met\",\"outside_window\":\"Outside active window\",\"malformed_config\":\"Invalid config\",\"cas_lost\":\"Claimed by another run\",\"quarantined\":\"Trigger quarantined\",\"publish_failed\":\"Publish failed\",\"budget_exceeded\":\"Cost ceiling reached\",\"breadth_exceeded\":\"Fan-out limit reached\",\"healing_abandoned\":\"Healing gave up\",\"healing_capped\":\"Healing retries exhausted\"},\"error_category\":{\"rate_limit\":\"Rate limit\",\"session_limit\":\"Session limit\",\"timeout\":\"Timeout\",\"provider_not_found\":\"Provider not found\",\"credential_error\":\"Credential error\",\"network\":\"Network\",\"validation\":\"Validation\",\"tool_error\":\"Tool error\",\"api_error\":\"API error\",\"transient_process_failure\":\"Transient process failure\",\"unknown\":\"Unknown\"},\"tool_error_kind\":{\"auth\":\"Authentication\",\"timeout\":\"Timed out\",\"http\":\"HTTP error\",\"transport\":\"Connection failed\",\"tool_error\":\"Tool failed\",\"rate_limited\":\"Rate limited\",\"misconfigured\":\"Misconfigured\",\"unknown\":\"Unknown error\",\"unsupported\":\"Not runnable here\"},\"execution\":{\"queued\":\"Queued\",\"running\":\"Running\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"cancelled\":\"Cancelled\",\"error\":\"Error\"},\"event\":{\"pending\":\"Pending\",\"processing\":\"Processing\",\"processed\":\"Processed\",\"failed\":\"Failed\",\"retrying\":\"Retrying\"},\"automation\":{\"pending\":\"Pending\",\"running\":\"Running\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"timeout\":\"Timed Out\"},\"severity\":{\"info\":\"Info\",\"low\":\"Low\",\"medium\":\"Medium\",\"high\":\"High\",\"critical\":\"Critical\"},\"priority\":{\"low\":\"Low\",\"normal\":\"Normal\",\"high\":\"High\",\"urgent\":\"Urgent\"},\"healing_status\":{\"open\":\"Open\",\"auto_fixed\":\"Auto-Fixed\",\"acknowledged\":\"Acknowledged\",\"resolved\":\"Resolved\"},\"healing_category\":{\"config\":\"Configuration\",\"auth\":\"Authentication\",\"network\":\"Network\",\"timeout\":\"Timeout\",\"quota\":\"Quota\"},\"connector_status\":{\"ready\":\"Ready\",\"untested\":\"Untested\",\"failed\":\"Failed\",\"no_credential\":\"No Credential\",\"testing\":\"Testing\"},\"task_phase\":{\"analyzing\":\"Analyzing\",\"planning\":\"Planning\",\"implementing\":\"Implementing\",\"validating\":\"Validating\",\"complete\":\"Complete\"},\"forage_confidence\":{\"high\":\"High\",\"medium\":\"Medium\",\"low\":\"Low\"},\"capability_risk\":{\"low\":\"low\",\"medium\":\"medium\",\"high\":\"high\"},\"goal_state\":{\"pending\":\"Pending\",\"review\":\"Review\",\"blocked\":\"Blocked\",\"in_progress\":\"In Progress\",\"running\":\"Running\",\"completed\":\"Done\",\"done\":\"Done\"},\"test\":{\"generating\":\"Generating\",\"running\":\"Running\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"pending\":\"Pending\"},\"dev\":{\"open\":\"Open\",\"in_progress\":\"In Progress\",\"completed\":\"Completed\",\"blocked\":\"Blocked\",\"queued\":\"Queued\",\"running\":\"Running\",\"failed\":\"Failed\"},\"circuit_breaker\":{\"provider_claude_code\":\"Claude\",\"provider_codex_cli\":\"Codex\",\"provider_global\":\"Global\"},\"deployment\":{\"active\":\"Active\",\"paused\":\"Paused\",\"failed\":\"Failed\",\"file-based\":\"File-based\",\"unknown\":\"Unknown\"},\"delivery\":{\"delivered\":\"Delivered\",\"failed\":\"Failed\",\"pending\":\"Pending\",\"queued\":\"Queued\"},\"rotation\":{\"anomaly\":\"Anomaly\",\"paused\":\"Paused\",\"disabled\":\"Disabled\",\"perm_errors\":\"Perm Errors\",\"degrading\":\"Degrading\",\"backoff\":\"Backoff\",\"fresh\":\"Fresh\",\"active\":\"Active\"},\"thinking\":{\"low\":\"Low\",\"medium\":\"Medium\",\"high\":\"High\"},\"build\":{\"initializing\":\"Initializing\",\"analyzing\":\"Analyzing\",\"awaiting_input\":\"Awaiting input\",\"resolving\":\"Resolving\",\"draft_ready\":\"Draft ready\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"cancelled\":\"Cancelled\",\"testing\":\"Testing\",\"test_complete\":\"Test complete\",\"promoted\":\"Promoted\"}}",
+  "status_tokens": "{\"verdict\":{\"production\":\"Production\",\"promising\":\"Promising\",\"not_ready\":\"Not Ready\",\"broken\":\"Broken\"},\"chain_stop\":{\"depth_limit\":\"Depth limit\",\"handoff_suppressed\":\"Handoff suppressed\",\"cycle_detected\":\"Cycle detected\",\"predicate_unmet\":\"Condition not met\",\"outside_window\":\"Outside active window\",\"malformed_config\":\"Invalid config\",\"cas_lost\":\"Claimed by another run\",\"quarantined\":\"Trigger quarantined\",\"publish_failed\":\"Publish failed\",\"budget_exceeded\":\"Cost ceiling reached\",\"breadth_exceeded\":\"Fan-out limit reached\",\"healing_abandoned\":\"Healing gave up\",\"healing_capped\":\"Healing retries exhausted\",\"deadline_exceeded\":\"Deadline passed\"},\"error_category\":{\"rate_limit\":\"Rate limit\",\"session_limit\":\"Session limit\",\"timeout\":\"Timeout\",\"provider_not_found\":\"Provider not found\",\"credential_error\":\"Credential error\",\"network\":\"Network\",\"validation\":\"Validation\",\"tool_error\":\"Tool error\",\"api_error\":\"API error\",\"transient_process_failure\":\"Transient process failure\",\"contract_violation\":\"Contract violation\",\"unknown\":\"Unknown\"},\"tool_error_kind\":{\"auth\":\"Authentication\",\"timeout\":\"Timed out\",\"http\":\"HTTP error\",\"transport\":\"Connection failed\",\"tool_error\":\"Tool failed\",\"rate_limited\":\"Rate limited\",\"misconfigured\":\"Misconfigured\",\"unknown\":\"Unknown error\",\"unsupported\":\"Not runnable here\"},\"execution\":{\"queued\":\"Queued\",\"running\":\"Running\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"cancelled\":\"Cancelled\",\"error\":\"Error\"},\"event\":{\"pending\":\"Pending\",\"processing\":\"Processing\",\"processed\":\"Processed\",\"failed\":\"Failed\",\"retrying\":\"Retrying\"},\"automation\":{\"pending\":\"Pending\",\"running\":\"Running\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"timeout\":\"Timed Out\"},\"severity\":{\"info\":\"Info\",\"low\":\"Low\",\"medium\":\"Medium\",\"high\":\"High\",\"critical\":\"Critical\"},\"priority\":{\"low\":\"Low\",\"normal\":\"Normal\",\"high\":\"High\",\"urgent\":\"Urgent\"},\"healing_status\":{\"open\":\"Open\",\"auto_fixed\":\"Auto-Fixed\",\"acknowledged\":\"Acknowledged\",\"resolved\":\"Resolved\"},\"healing_category\":{\"config\":\"Configuration\",\"auth\":\"Authentication\",\"network\":\"Network\",\"timeout\":\"Timeout\",\"quota\":\"Quota\"},\"connector_status\":{\"ready\":\"Ready\",\"untested\":\"Untested\",\"failed\":\"Failed\",\"no_credential\":\"No Credential\",\"testing\":\"Testing\"},\"task_phase\":{\"analyzing\":\"Analyzing\",\"planning\":\"Planning\",\"implementing\":\"Implementing\",\"validating\":\"Validating\",\"complete\":\"Complete\"},\"forage_confidence\":{\"high\":\"High\",\"medium\":\"Medium\",\"low\":\"Low\"},\"capability_risk\":{\"low\":\"low\",\"medium\":\"medium\",\"high\":\"high\"},\"goal_state\":{\"pending\":\"Pending\",\"review\":\"Review\",\"blocked\":\"Blocked\",\"in_progress\":\"In Progress\",\"running\":\"Running\",\"completed\":\"Done\",\"done\":\"Done\"},\"test\":{\"generating\":\"Generating\",\"running\":\"Running\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"pending\":\"Pending\"},\"dev\":{\"open\":\"Open\",\"in_progress\":\"In Progress\",\"completed\":\"Completed\",\"blocked\":\"Blocked\",\"queued\":\"Queued\",\"running\":\"Running\",\"failed\":\"Failed\"},\"circuit_breaker\":{\"provider_claude_code\":\"Claude\",\"provider_codex_cli\":\"Codex\",\"provider_global\":\"Global\"},\"deployment\":{\"active\":\"Active\",\"paused\":\"Paused\",\"failed\":\"Failed\",\"file-based\":\"File-based\",\"unknown\":\"Unknown\"},\"delivery\":{\"delivered\":\"Delivered\",\"failed\":\"Failed\",\"pending\":\"Pending\",\"queued\":\"Queued\"},\"rotation\":{\"anomaly\":\"Anomaly\",\"paused\":\"Paused\",\"disabled\":\"Disabled\",\"perm_errors\":\"Perm Errors\",\"degrading\":\"Degrading\",\"backoff\":\"Backoff\",\"fresh\":\"Fresh\",\"active\":\"Active\"},\"thinking\":{\"low\":\"Low\",\"medium\":\"Medium\",\"high\":\"High\"},\"build\":{\"initializing\":\"Initializing\",\"analyzing\":\"Analyzing\",\"awaiting_input\":\"Awaiting input\",\"resolving\":\"Resolving\",\"draft_ready\":\"Draft ready\",\"completed\":\"Completed\",\"failed\":\"Failed\",\"cancelled\":\"Cancelled\",\"testing\":\"Testing\",\"test_complete\":\"Test complete\",\"promoted\":\"Promoted\"}}",
   "project_overview": "{\"today_activity_heading\":\"TODAY\",\"codebase\":\"Codebase\",\"monitoring\":\"Monitoring\",\"connected\":\"Connected\",\"not_connected\":\"Not connected\",\"connect_to_see_stats\":\"Connect {{service}} to see {{category}} stats\",\"go_to_connections\":\"Go to Connections\",\"credential_found\":\"{{service}} credential found\",\"set_repo_url\":\"Set a repository URL on your project to see stats.\",\"go_to_projects\":\"Go to Projects\",\"link_monitoring\":\"Link it to this project to see error tracking stats.\",\"link_to_project\":\"Link to Project\",\"project_slug\":\"Project slug\",\"project_slug_placeholder\":\"my-project\",\"save\":\"Save\",\"open_issues\":\"Open issues\",\"open_prs\":\"Open PRs\",\"open_mrs\":\"Open MRs\",\"commits_this_week\":\"Commits this week\",\"default_branch\":\"Default branch\",\"last_push\":\"Last push\",\"unresolved_issues\":\"Unresolved issues\",\"events_24h\":\"Events (24h)\",\"events_7d\":\"Events (7d)\",\"vital_jump_repo\":\"Open on the connected repository\",\"vital_jump_monitor\":\"Show monitoring details\",\"vital_jump_setup\":\"Set up this connection\",\"vital_updated_label\":\"updated\",\"loading_stats\":\"Loading stats...\",\"failed_to_load\":\"Failed to load stats\",\"retry\":\"Retry\",\"no_project_selected\":\"No project selected\",\"select_project_hint\":\"Select a dev project to see its overview.\",\"pipeline_heading\":\"Pipeline\",\"monitoring_linked_toast\":\"Monitoring linked\",\"monitoring_link_failed_toast\":\"Failed to link monitoring\",\"credential_label\":\"Credential\",\"org_slug_label\":\"Organization slug\",\"org_label\":\"Organization\",\"project_label\":\"Project\",\"discovering_orgs\":\"Discovering organizations…\",\"no_orgs_found\":\"No orgs found\",\"select_organization\":\"Select an organization\",\"pick_org_first\":\"Pick an organization first\",\"loading_projects\":\"Loading projects…\",\"no_projects_in_org\":\"No projects in this org\",\"select_project_option\":\"Select a project\",\"sentry_discovery_failed\":\"Sentry discovery failed: {error}\",\"try_auto_discovery\":\"Try auto-discovery again\",\"enter_slugs_manually\":\"Enter slugs manually instead\"}",
   "plugins": "{\"fleet\":{\"search_placeholder\":\"Filter sessions…\",\"search_no_matches\":\"No sessions match\",\"history_label\":\"Recent state history\",\"all_clear\":\"All clear\",\"jump_next\":\"Jump to next waiting session\",\"pair_title\":\"Pair a device\",\"pair_preview_badge\":\"Preview\",\"pair_desc\":\"Link a phone to monitor and approve from anywhere. Scan this code with the Personas mobile companion.\",\"pair_qr_placeholder\":\"QR appears here once pairing is enabled\",\"pair_token_label\":\"Pairing code\",\"pair_endpoint_label\":\"Endpoint\",\"pair_regenerate\":\"New code\",\"pair_copy\":\"Copy\",\"pair_copied\":\"Copied\",\"pair_credentials_note\":\"Your credentials never leave this desktop.\",\"pair_preview_note\":\"Preview only — the secure handshake, live QR, and mobile client ship in a later update.\",\"approvals_pending_one\":\"{count} approval pending\",\"approvals_pending_other\":\"{count} approvals pending\",\"approve\":\"Approve\",\"reject\":\"Reject\",\"ago_just_now\":\"just now\",\"ago_seconds\":\"{n}s ago\",\"ago_minutes\":\"{n}m ago\",\"ago_hours\":\"{n}h ago\",\"reply_to\":\"Reply to {name}\",\"reply_placeholder\":\"Reply to {name}…\",\"reply_send\":\"Send\",\"reply_sending\":\"Sending…\",\"reply_cancel\":\"Cancel\",\"notify_title\":\"Session needs your input\",\"notify_body\":\"{name} is waiting for you\",\"notify_body_detail\":\"{name}: {detail}\",\"notify_enable\":\"Enable awaiting-input alerts\",\"notify_disable\":\"Mute awaiting-input alerts\",\"preview_title\":\"Mobile companion preview\",\"preview_desc\":\"A read-only look at how your fleet would appear on the paired phone companion.\",\"preview_all_clear\":\"All clear\",\"preview_no_sessions\":\"No active sessions\",\"preview_idle\":\"Waiting for output…\",\"dot_console_spawning\":\"Process spawning\",\"dot_console_alive\":\"Process alive\",\"dot_console_exited\":\"Process exited\",\"dot_biz_idle\":\"Idle — turn finished\",\"dot_biz_working\":\"Working — Claude is processing\",\"dot_biz_awaiting\":\"Awaiting your input\",\"dot_biz_stale\":\"Stale — no activity for 5+ min\",\"legend_show\":\"Status legend\",\"hotkeys_title\":\"Keyboard shortcuts\",\"hotkey_next_waiting\":\"Jump to the next waiting session\",\"hotkey_move_focus\":\"Move session focus up / down\",\"hotkey_focus_search\":\"Focus the session search\",\"hotkey_toggle_grid\":\"Open or close the terminal grid\",\"hotkey_show_help\":\"Show this reference\",\"hotkeys_typing_note\":\"Shortcuts pause while you're typing in a terminal or input field.\",\"spawn_with_task\":\"Spawn with task\",\"spawn_task_title\":\"Spawn with a first task\",\"spawn_task_desc\":\"Launches a new Claude session at {path} and hands it this task the moment it boots.\",\"spawn_task_placeholder\":\"What should this session work on?\",\"spawn_task_submit\":\"Spawn session\",\"spawn_task_spawning\":\"Spawning…\",\"provenance_hook_activity\":\"hook activity\",\"provenance_console\":\"console output\",\"provenance_transcript\":\"transcript growth\",\"provenance_never\":\"none yet\",\"state_cutoffs_title\":\"State detection cutoffs\",\"state_cutoffs_desc\":\"How long a quiet session may sit before being flagged. Lower values react faster; higher values suit slow models or long quiet operations.\",\"state_cutoffs_stale\":\"Stale after\",\"state_cutoffs_frozen\":\"Frozen after\",\"state_cutoffs_minutes_unit\":\"min\",\"legend_process\":\"Process\",\"legend_activity\":\"Activity (Claude)\",\"state_awaiting_input\":\"Awaiting input\",\"state_working\":\"Working\",\"state_spawning\":\"Spawning\",\"state_idle\":\"Idle\",\"state_stale\":\"Stale\",\"state_exited\":\"Exited\",\"sessions_one\":\"{count} session\",\"sessions_other\":\"{count} sessions\",\"no_project_hint\":\"No project selected — pick one in Dev Tools → Projects\",\"filter_by\":\"Show only {label}\",\"filter_clear\":\"Clear filter\",\"needs_input_one\":\"{count} session needs your input\",\"needs_input_other\":\"{count} sessions need your input\",\"needs_you_aria\":\"Sessions awaiting your input\",\"jump_to_session\":\"Jump to this session\",\"terminal_font_decrease\":\"Decrease terminal font size\",\"terminal_font_increase\":\"Increase terminal font size\",\"view_grid\":\"Grid\",\"new_session\":\"New session\",\"kill_session\":\"Kill session\",\"grid_open_aria\":\"Open the fullscreen terminal grid\",\"grid_back\":\"Back\",\"grid_overlay_aria\":\"Fleet terminal grid\",\"grid_active_hint\":\"Terminal grid is open\",\"settings_terminal_title\":\"Terminal\",\"settings_terminal_desc\":\"Appearance and behaviour of the embedded session terminals.\",\"settings_font_size\":\"Font size\",\"settings_copy_on_select\":\"Copy on select\",\"settings_copy_on_select_desc\":\"Selecting text in a terminal copies it to the clipboard automatically.\",\"settings_theme\":\"Color theme\",\"settings_theme_auto\":\"Auto\",\"settings_theme_dark\":\"Dark\",\"settings_theme_light\":\"Light\",\"settings_theme_desc\":\"Auto follows the app's light/dark appearance.\",\"skill_apply\":\"Apply to session\",\"skill_apply_title\":\"Apply skill to sessions\",\"skill_apply_no_sessions\":\"No active sessions — spawn one first\",\"skill_source_label\":\"Skill source\",\"skill_source_project\":\"This project\",\"skill_source_global\":\"Global library\",\"skill_install\":\"Install to repo\",\"skill_install_title\":\"Install skill into a project\",\"skill_install_desc\":\"Copy {skill} into a project's .claude/skills so it's available there.\",\"skill_install_target_label\":\"Target project\",\"skill_install_overwrite\":\"Overwrite if it already exists\",\"skill_install_confirm\":\"Install\",\"skill_install_busy\":\"Installing…\",\"skill_install_no_targets\":\"No projects registered — add one in Dev Tools → Projects.\",\"skill_install_success\":\"Installed {skill} into {project} ({count} files)\",\"skill_install_exists\":\"{skill} already exists in {project} — enable overwrite to replace it\",\"skill_install_failed\":\"Failed to install skill\",\"skill_install_diff_summary\":\"{changed} changed · {added} added · {removed} removed\",\"skill_sync_in_sync\":\"In sync\",\"skill_sync_in_sync_hint\":\"This installed copy still matches the source it was installed from.\",\"skill_sync_diverged\":\"Diverged\",\"skill_sync_diverged_hint\":\"This copy no longer matches its installed source — the copy or its source changed.\",\"athena_ask\":\"Ask Athena\",\"athena_never_attached\":\"Claude never attached — kill & retry\",\"athena_never_attached_hint\":\"This session spawned but no Claude agent ever came up (no session id). Asking Athena can't help — there's nothing live to type into. Kill it and retry; the folder may need Claude Code's trust approval.\",\"athena_thinking\":\"Athena is thinking…\",\"athena_label\":\"Athena\",\"athena_approve_send\":\"Approve — send to terminal\",\"athena_dismiss\":\"Dismiss suggestion\",\"view_terminal\":\"Terminal\",\"view_insights\":\"Insights\",\"insights_title\":\"Session insights\",\"insights_loading\":\"Reading transcript…\",\"insights_error\":\"Couldn't read this session's transcript\",\"insights_no_transcript\":\"No transcript yet — this session hasn't started one\",\"insights_tokens\":\"Tokens\",\"insights_turns\":\"Turns\",\"insights_prompts\":\"Prompts\",\"insights_input\":\"Input\",\"insights_output\":\"Output\",\"insights_cache_read\":\"Cache read\",\"insights_cache_write\":\"Cache write\",\"insights_tools\":\"Tools used\",\"insights_no_tools\":\"No tools used yet\",\"insights_files\":\"Files touched ({count})\",\"insights_no_files\":\"No files modified\",\"insights_span\":\"{minutes}m span\",\"insights_parse_errors\":\"{count} unparsed lines\",\"activity_title\":\"Cross-session activity\",\"activity_subtitle_one\":\"{count} recent session\",\"activity_subtitle_other\":\"{count} recent sessions\",\"activity_search_placeholder\":\"Search files, tools, projects…\",\"activity_loading\":\"Scanning transcripts…\",\"activity_error\":\"Couldn't read transcripts\",\"activity_empty\":\"No recent sessions found\",\"activity_no_matches\":\"No sessions match your search\",\"activity_files_more\":\"+{count} more\",\"skills_button\":\"Skills\",\"skills_drawer_title\":\"Skill library\",\"skills_drawer_apply_to\":\"Apply to {name}\",\"skills_drawer_no_target\":\"Select a session to apply skills\",\"skills_drawer_search\":\"Search skills…\",\"skills_drawer_empty\":\"No skills found\",\"skills_drawer_command_placeholder\":\"Click a skill, then add arguments…\",\"skills_drawer_apply_btn\":\"Apply\",\"skill_applied_toast\":\"Sent {command} to {name}\",\"context_size_label\":\"ctx\",\"context_size_hint\":\"Conversation size — the context this session re-sends each turn. Smaller is more efficient; growing toward the model's limit costs more per turn.\",\"fleet_total_tokens\":\"Fleet tokens\",\"fleet_total_tokens_hint\":\"Combined input + output tokens across every tracked session this run.\",\"fleet_cache_hit\":\"{percent}% cached\",\"fleet_cache_hit_hint\":\"Share of input served from cache across all sessions — higher means cheaper turns.\",\"fleet_heavy_sessions\":\"{count} heavy\",\"fleet_heavy_hint\":\"Sessions carrying a large context that re-sends every turn — compact them to cut cost.\",\"compact_button\":\"Compact\",\"compact_hint\":\"Summarize this conversation so each turn costs far less — replaces the long history with a short recap.\",\"compact_unavailable_hint\":\"Available once this session is idle between turns.\",\"compact_toast\":\"Compacting {name}…\",\"state_finished\":\"Finished\",\"state_hibernated\":\"Hibernated\",\"dot_console_hibernated\":\"Process hibernated — resumable\",\"sleep_session\":\"Hibernate\",\"sleep_unavailable\":\"Can't hibernate yet — session hasn't bound a Claude session id\",\"wake_session\":\"Wake\",\"hibernated_panel_title\":\"Session hibernated\",\"hibernated_panel_desc\":\"The process was freed to save resources. Wake to resume the conversation via claude --resume.\",\"auto_hibernate_title\":\"Auto-hibernate idle sessions\",\"auto_hibernate_desc\":\"Automatically hibernate sessions that sit idle past the threshold — freeing the process even when Fleet isn't focused. They stay resumable via Wake. Runs while Personas is open.\",\"auto_hibernate_toggle\":\"Enable auto-hibernate\",\"auto_hibernate_after\":\"After\",\"auto_hibernate_minutes_unit\":\"min idle\",\"live_slots_title\":\"Limit live sessions\",\"live_slots_desc\":\"Cap how many claude processes run at once. When the fleet is at the limit, the oldest idle session is hibernated to make room — it stays resumable via Wake. Working sessions are never interrupted.\",\"live_slots_toggle\":\"Enable session limit\",\"live_slots_max_label\":\"Max\",\"live_slots_unit\":\"live sessions\",\"spawn_headless_toggle\":\"Run in background (no terminal)\",\"spawn_headless_hint\":\"Headless mode — no live terminal, much lighter on CPU and RAM. Reply from the \\\"Needs you\\\" banner; inspect progress in Insights.\",\"headless_badge\":\"Background\",\"headless_no_terminal\":\"Background session — runs without a terminal. Reply from the \\\"Needs you\\\" banner; progress shows in Insights.\",\"live_slot_hibernated_toast\":\"{name} was hibernated to stay within the live-session limit — its conversation is saved; wake it to resume.\",\"footer_title\":\"Fleet\",\"footer_empty\":\"No sessions tracked\",\"footer_open_page\":\"Open Fleet page\",\"footer_hint_open_grid\":\"Click to open the terminal grid\",\"footer_hint_close_grid\":\"Click to close the terminal grid\",\"footer_hint_open_page\":\"Click to open Fleet\",\"footer_needs_you_one\":\"{count} session needs you\",\"footer_needs_you_other\":\"{count} sessions need you\",\"debug_log_start\":\"Record\",\"debug_log_stop\":\"Recording\",\"debug_log_start_title\":\"Record a high-level debug log of fleet state changes, hibernations and Athena decisions\",\"debug_log_started\":\"Fleet debug log started — state changes, hibernations and Athena decisions are being recorded.\",\"debug_log_saved\":\"Fleet debug log saved · {events} events → {path}\",\"debug_log_stop_title\":\"Stop the fleet debug recording and save the log\",\"doze_tooltip\":\"Sleeping to save resources — select to wake\",\"dismiss_session\":\"Dismiss session\",\"tombstone_wake_hint\":\"Click to wake\"},\"title\":\"Plugins\",\"subtitle\":\"Extend your workspace with plugins. Toggle to show or hide from the sidebar.\",\"dev_tools_label\":\"Dev Tools\",\"dev_tools_desc\":\"Project management, context mapping, idea scanning, triage, and task runner utilities.\",\"obsidian_brain_label\":\"Obsidian Brain\",\"obsidian_brain_desc\":\"Connect your Obsidian vault for knowledge retrieval, note browsing, and sync.\",\"drive_label\":\"Drive\",\"drive_desc\":\"A managed folder on this device where agents save exports — this is local storage, not a Google Drive cloud account, and files are not synced or backed up remotely. Browse them in a Finder-style UI; they survive app upgrades but live only on this machine.\",\"twin_label\":\"Twin\",\"twin_desc\":\"Build a digital twin — identity, voice, channels, and curated memory that personas can adopt.\",\"companion_label\":\"Companion\",\"companion_desc\":\"Athena — your always-available thinking partner. Inspect her memory, configure her chrome, and bind a voice.\",\"drive\":{\"title\":\"Drive\",\"subtitle\":\"Managed local filesystem for agent exports — on this device, not Google Drive cloud\",\"root_label\":\"Drive root\",\"dev_badge\":\"Dev\",\"back\":\"Back\",\"forward\":\"Forward\",\"up\":\"Up\",\"refresh\":\"Refresh\",\"search_placeholder\":\"Search this folder...\",\"path_input_placeholder\":\"Type a path, e.g. exports/2026\",\"path_input_aria\":\"Drive path\",\"path_input_hint\":\"Enter to navigate · Esc to cancel\",\"view_list\":\"List\",\"view_icons\":\"Icons\",\"view_columns\":\"Columns\",\"new_folder\":\"New Folder\",\"new_file\":\"New File\",\"reveal_in_os\":\"Show in file manager\",\"open_in_os\":\"Open\",\"sidebar_root\":\"Drive\",\"sidebar_recent\":\"Recent\",\"sidebar_storage\":\"Storage\",\"storage_used\":\"{used} used • {count} items\",\"empty_folder\":\"This folder is empty\",\"empty_hint\":\"Agents that export to Local Drive save files here — on this device only, with no cloud backup.\",\"empty_cta\":\"Create folder\",\"col_name\":\"Name\",\"col_size\":\"Size\",\"col_kind\":\"Kind\",\"col_modified\":\"Modified\",\"folder_kind\":\"Folder\",\"ctx_open\":\"Open\",\"ctx_rename\":\"Rename\",\"ctx_duplicate\":\"Duplicate\",\"ctx_delete\":\"Delete\",\"ctx_copy\":\"Copy\",\"ctx_cut\":\"Cut\",\"ctx_paste\":\"Paste\",\"ctx_new_folder\":\"New folder\",\"ctx_new_file\":\"New file\",\"ctx_reveal\":\"Show in file manager\",\"ctx_copy_path\":\"Copy path\",\"rename_title\":\"Rename\",\"rename_placeholder\":\"New name\",\"new_folder_title\":\"New folder\",\"new_folder_placeholder\":\"Folder name\",\"new_file_title\":\"New file\",\"new_file_placeholder\":\"filename.txt\",\"delete_confirm_title\":\"Delete {count} item(s)?\",\"delete_confirm_body\":\"Items move to .trash/ and are removed permanently after 7 days. Deleting again from .trash/ removes them immediately.\",\"confirm\":\"Confirm\",\"cancel\":\"Cancel\",\"details_title\":\"Details\",\"details_path\":\"Path\",\"details_size\":\"Size\",\"details_kind\":\"Kind\",\"details_modified\":\"Modified\",\"details_items\":\"Items\",\"details_modified_range\":\"{newest} → {oldest}\",\"details_preview\":\"Preview\",\"preview_unavailable\":\"Preview unavailable\",\"preview_binary\":\"Binary file — open in the OS file manager to inspect.\",\"preview_too_large\":\"File is too large to preview inline.\",\"loading\":\"Loading...\",\"error_prefix\":\"Error:\",\"items_selected\":\"{count} selected\",\"items_total\":\"{count} items\",\"items_selected_summary\":\"{count} items selected\",\"bulk_clear_selection\":\"Clear selection\",\"bulk_copy\":\"Copy\",\"bulk_cut\":\"Cut\",\"bulk_delete\":\"Delete\",\"bulk_move_to\":\"Move to…\",\"bulk_sign\":\"Sign\",\"move_to_title\":\"Move {count} item(s) to…\",\"move_to_root_option\":\"Drive root\",\"move_to_no_destinations\":\"No valid folders to move into\",\"lightbox_close\":\"Close\",\"lightbox_prev\":\"Previous image\",\"lightbox_next\":\"Next image\",\"lightbox_counter\":\"{current} of {total}\",\"lightbox_loading\":\"Loading image…\",\"lightbox_failed\":\"Could not load image.\",\"lightbox_zoom_in\":\"Zoom in\",\"lightbox_zoom_out\":\"Zoom out\",\"lightbox_zoom_reset\":\"Reset view\",\"lightbox_rotate\":\"Rotate 90°\",\"preview_open_video\":\"Play video\",\"preview_open_pdf\":\"Open PDF\",\"sidebar_recent_empty\":\"Files saved by agents land here\",\"sidebar_recent_reveal_aria\":\"Reveal in Drive\",\"details_empty_hint\":\"Select a file or folder to see its details and inline preview.\",\"details_location\":\"Location\",\"copy_path_tooltip\":\"Copy path\",\"kind_folder\":\"Folder\",\"kind_image\":\"Image\",\"kind_audio\":\"Audio\",\"kind_video\":\"Video\",\"kind_pdf\":\"PDF\",\"kind_code\":\"Code\",\"kind_data\":\"Data\",\"kind_sheet\":\"Sheet\",\"kind_archive\":\"Archive\",\"kind_text\":\"Document\",\"kind_signature\":\"Signature\",\"kind_generic\":\"File\",\"group_folders\":\"Folders\",\"group_images\":\"Images\",\"group_audio\":\"Audio\",\"group_videos\":\"Videos\",\"group_pdfs\":\"PDFs\",\"group_code\":\"Code\",\"group_data\":\"Data\",\"group_sheets\":\"Sheets\",\"group_archives\":\"Archives\",\"group_documents\":\"Documents\",\"group_signatures\":\"Signatures\",\"group_other\":\"Other\",\"time_just_now\":\"just now\",\"time_minutes_ago_one\":\"{count}m ago\",\"time_minutes_ago_other\":\"{count}m ago\",\"time_hours_ago_one\":\"{count}h ago\",\"time_hours_ago_other\":\"{count}h ago\",\"time_yesterday\":\"yesterday\",\"time_days_ago_one\":\"{count}d ago\",\"time_days_ago_other\":\"{count}d ago\",\"signatures_button\":\"Signatures\",\"signed\":\"Signed\",\"sidebar_trash\":\"Trash\",\"trash_banner\":\"Deleted items are kept for 7 days, then removed permanently.\",\"trash_restore\":\"Restore\",\"trash_empty\":\"Empty trash\",\"trash_empty_confirm_title\":\"Permanently delete {count} items?\",\"trash_empty_confirm_body\":\"This empties the trash. These items cannot be recovered.\",\"trash_purges_in_days\":\"Purges in {days}d\",\"trash_purges_soon\":\"Purges soon\",\"ctx_sign_file\":\"Sign file…\",\"ctx_verify_file\":\"Verify signature…\",\"ctx_extract_text\":\"Extract text (OCR)…\",\"ctx_extract_text_no_gemini\":\"Extract text — connect Gemini to enable\",\"ocr_title\":\"Extract text with Gemini\",\"ocr_title_claude\":\"Extract text with Claude\",\"ocr_subtitle\":\"Vision OCR via Google Gemini 3 Flash Preview\",\"ocr_subtitle_claude\":\"Local OCR via Claude Code CLI\",\"ocr_backend_label\":\"Backend\",\"ocr_backend_gemini\":\"Gemini Vision\",\"ocr_backend_claude\":\"Claude CLI\",\"ocr_claude_info\":\"Uses your local Claude subscription. Requires the claude binary on PATH.\",\"ocr_model_label\":\"Model\",\"ocr_connector_ready\":\"Gemini connector ready\",\"ocr_connector_missing\":\"No Gemini credential found in the vault. Connect Google Gemini to enable OCR.\",\"ocr_prompt_label\":\"Optional prompt\",\"ocr_prompt_placeholder\":\"e.g. Only extract the invoice total and line items\",\"ocr_extract\":\"Extract text\",\"ocr_running\":\"Extracting…\",\"ocr_done\":\"Extraction complete\",\"ocr_save\":\"Save as .ocr.txt\",\"ocr_saved\":\"Saved\",\"ocr_saved_to\":\"Saved to\",\"ocr_copied\":\"Copied to clipboard\",\"select_file_or_folder\":\"Select a file or folder to see its details.\",\"download_aria\":\"Download\",\"download_title\":\"Download\",\"sign_reason_placeholder\":\"Reason for signing…\",\"empty_column\":\"Empty\",\"loading_column\":\"Loading...\",\"sidebar_managed_local\":\"Managed local\",\"sidebar_folders_label\":\"Folders\",\"sidebar_root_fallback\":\"Drive\",\"search_clear_aria\":\"Clear search\",\"drop_overlay_title\":\"Drop to add to Drive\",\"drop_overlay_subtitle\":\"Files will be saved into {path}\",\"drop_added_n\":\"Added {count} file(s) to Drive\",\"drop_too_large_n\":\"{count} file(s) skipped (over 50 MB)\",\"drop_failed_n\":\"{count} file(s) failed to save\",\"search_no_local_hits\":\"No matches for \\\"{query}\\\" in this folder\",\"search_escalate_hint\":\"Search across the entire Drive sandbox to find files in subfolders.\",\"search_all_drive_cta\":\"Search all of Drive\",\"search_running\":\"Searching…\",\"search_results_for\":\"Results for \\\"{query}\\\"\",\"search_n_hits\":\"{count} match(es)\",\"search_back_to_folder\":\"Back to folder\",\"search_no_drive_hits\":\"No matches anywhere in the Drive.\",\"search_reveal_aria\":\"Reveal in Drive\"},\"artist\":{\"title\":\"Artist\",\"tab_creative_studio\":\"Creative Studio\",\"tab_gallery\":\"Gallery\",\"tab_media_studio\":\"Media Studio\",\"creative_studio_title\":\"Creative Studio\",\"creative_studio_desc\":\"Generate 3D models with Blender MCP, create images with Leonardo AI, and analyze with Gemini — all from one session.\",\"env_status\":\"Environment Status\",\"ready\":\"Ready\",\"refresh\":\"Refresh\",\"checking_env\":\"Checking environment...\",\"not_found\":\"Not found\",\"install_from_blender\":\"Install from blender.org\",\"installed\":\"Installed\",\"not_installed\":\"Not installed\",\"installing\":\"Installing...\",\"install\":\"Install\",\"image_gen_tools\":\"Image Generation Tools\",\"connected_healthy\":\"Connected & healthy\",\"connected_not_verified\":\"Connected (not verified)\",\"not_connected\":\"Not connected\",\"connect\":\"Connect\",\"creative_session\":\"Creative Session\",\"streaming\":\"streaming\",\"no_tools_connected\":\"No tools connected\",\"clear_session\":\"Clear session\",\"empty_session_hint\":\"Describe what you'd like to create. The session uses Claude CLI with access to your connected creative tools.\",\"example_forest\":\"Create a low-poly forest scene in Blender\",\"example_portrait\":\"Generate a cyberpunk character portrait\",\"example_mockup\":\"Design a product mockup with lighting\",\"describe_create\":\"Describe what to create...\",\"connect_tools_first\":\"Connect tools above to start creating...\",\"cancel\":\"Cancel\",\"delete\":\"Delete\",\"edit_tags\":\"Edit Tags\",\"mode_2d\":\"2D Images\",\"mode_3d\":\"3D Models\",\"search\":\"Search...\",\"sort_date\":\"Date\",\"sort_name\":\"Name\",\"sort_size\":\"Size\",\"scanning\":\"Scanning...\",\"scan_folder\":\"Scan Folder\",\"open_folder\":\"Open folder\",\"watching\":\"Watching:\",\"loading_assets\":\"Loading assets...\",\"no_images_yet\":\"No images yet\",\"no_models_yet\":\"No models yet\",\"scan_import_images_hint\":\"Click \\\"Scan Folder\\\" to import images from your Artist folder, or create them using the Blender Studio tab.\",\"scan_import_models_hint\":\"Click \\\"Scan Folder\\\" to import 3D models from your Artist folder, or create them using the Blender Studio tab.\",\"auto_rotate\":\"Auto-rotate\",\"wireframe\":\"Wireframe\",\"lighting_studio\":\"Studio\",\"lighting_outdoor\":\"Outdoor\",\"lighting_soft\":\"Soft\",\"preview_not_available\":\"Preview not available\",\"preview_glb_hint\":\"Only .glb and .gltf files can be previewed inline. Export from Blender as glTF for best compatibility.\",\"tags_label\":\"Tags:\",\"status_partial\":\"Partial\",\"status_not_checked\":\"Not checked\",\"blender_label\":\"Blender\",\"blender_mcp_label\":\"Blender MCP\",\"session_cancelled\":\"Session cancelled.\",\"imported_assets_one\":\"Imported 1 new asset to gallery.\",\"imported_assets_other\":\"Imported {count} new assets to gallery.\",\"scan_result_one\":\"Found {scanned} assets, imported 1 new.\",\"scan_result_other\":\"Found {scanned} assets, imported {imported} new.\",\"tool_blender\":\"Blender\",\"tool_leonardo\":\"Leonardo\",\"tool_gemini\":\"Gemini\",\"tag_editor_hint\":\"Enter or comma to add, Backspace to remove the last tag.\",\"tag_editor_placeholder\":\"tag, another-tag\",\"send_to_media_studio\":\"Send to Media Studio\",\"sent_to_media_studio\":\"Queued for Media Studio\",\"session_history\":\"History\",\"session_history_empty\":\"Sessions you run will appear here.\",\"session_tools_label\":\"Tools:\",\"session_status_running\":\"Running\",\"session_status_completed\":\"Completed\",\"session_status_failed\":\"Failed\",\"session_status_cancelled\":\"Cancelled\",\"replay_session\":\"Replay\",\"delete_session\":\"Delete session\",\"copy_session_output\":\"Copy session as markdown\",\"session_output_copied\":\"Session output copied as markdown\",\"viewer_prev_model\":\"Previous model\",\"viewer_next_model\":\"Next model\",\"group_by_day_on\":\"Group by day\",\"group_by_day_off\":\"Turn off grouping\",\"group_today\":\"Today\",\"group_yesterday\":\"Yesterday\",\"group_this_week\":\"Earlier this week\",\"group_this_month\":\"Earlier this month\",\"group_older\":\"Older\",\"lightbox_zoom_in\":\"Zoom in\",\"lightbox_zoom_out\":\"Zoom out\",\"lightbox_reset_zoom\":\"Reset zoom\",\"lightbox_fullscreen\":\"Fullscreen\",\"lightbox_exit_fullscreen\":\"Exit fullscreen\",\"lightbox_copy_path\":\"Copy file path\",\"lightbox_path_copied\":\"File path copied\",\"lightbox_prev_image\":\"Previous image\",\"lightbox_next_image\":\"Next image\",\"gallery_select\":\"Select\",\"gallery_deselect\":\"Deselect\",\"gallery_n_selected\":\"{count} selected\",\"gallery_delete_selected\":\"Delete\",\"gallery_confirm_delete_n\":\"Confirm delete {count}\",\"gallery_clear_selection\":\"Clear selection\",\"gallery_bulk_tag_button\":\"Tag\",\"gallery_bulk_tag_title\":\"Add tag to selection\",\"gallery_bulk_tag_subtitle\":\"Adds a tag to {count} selected assets, keeping their existing tags.\",\"gallery_bulk_tag_placeholder\":\"Tag to add\",\"gallery_bulk_tag_apply\":\"Apply\",\"rename_asset\":\"Rename asset\",\"rename_hint\":\"Double-click to rename\",\"subtitle\":\"Generate 3D models, create images, and manage creative assets\",\"tags_prompt\":\"Tags (comma-separated):\",\"viewer_3d\":\"3D Viewer\",\"viewer_3d_hint\":\"Install @react-three/fiber and @react-three/drei to enable interactive 3D previews with orbit controls.\",\"moodboard_title\":\"References\",\"moodboard_hint\":\"Drag images here from the gallery to direct the next prompt.\",\"moodboard_show\":\"Show references\",\"moodboard_hide\":\"Hide references\",\"moodboard_clear\":\"Clear all\",\"moodboard_clear_confirm_one\":\"Remove 1 reference?\",\"moodboard_clear_confirm_other\":\"Remove {count} references?\",\"moodboard_count_one\":\"1 reference\",\"moodboard_count_other\":\"{count} references\",\"moodboard_empty_title\":\"No references yet\",\"moodboard_empty_hint\":\"Drag any image from the Gallery onto this dock, or use the pin icon on a card.\",\"moodboard_drop_here\":\"Drop to pin reference\",\"moodboard_already_pinned\":\"Already pinned\",\"moodboard_pinned\":\"Pinned to mood board\",\"moodboard_pin_action\":\"Pin to mood board\",\"moodboard_unpin\":\"Remove reference\",\"moodboard_weight_label\":\"Weight\",\"moodboard_weight_help\":\"Higher weights bias the prompt more toward this reference.\",\"moodboard_block_header\":\"Visual references the user has pinned:\",\"moodboard_reference_label\":\"Reference {index}\",\"moodboard_no_tags_fallback\":\"no tags\"},\"dev_tools\":{\"no_project_configured\":\"No dev project configured\",\"create_project_hint\":\"Create a project first to use scanner tools.\",\"create_project\":\"Create Project\",\"select_project\":\"Select a project...\",\"context_map_title\":\"Context Map\",\"context_map_subtitle\":\"Scan codebases into business-feature contexts\",\"group\":\"Group\",\"scan_codebase\":\"Scan Codebase\",\"context_rescan\":\"Re-scan (incremental)\",\"context_full_rescan\":\"Full re-scan\",\"context_plan_update\":\"Plan update\",\"context_last_scanned\":\"Last scan\",\"context_plan_created\":\"Weekly context scan scheduled\",\"context_plan_failed\":\"Couldn't schedule context scan\",\"files\":\"files\",\"keywords\":\"Keywords\",\"entry_points\":\"Entry Points\",\"no_context_groups\":\"No context groups yet\",\"scan_or_create\":\"Scan your codebase or create groups manually\",\"context_goal_singular\":\"goal\",\"context_goal_plural\":\"goals\",\"context_goal_coverage_tooltip\":\"Open in Goals\",\"triage_build_now_title\":\"Build now — accept and queue a task\",\"triage_build_queued\":\"Accepted and queued as a task\",\"context_idea_singular\":\"idea\",\"context_idea_plural\":\"ideas\",\"context_idea_coverage_tooltip\":\"Ideas from this context — open triage\",\"context_scan_ideas_tooltip\":\"Scan this context for new ideas\",\"context_scan_ideas_started\":\"Scanning {name} for ideas…\",\"context_no_goal_label\":\"no goal\",\"context_no_goal_tooltip\":\"No goal references this context yet\",\"group_show_all\":\"Show all ({hidden} more)\",\"row_open_vscode\":\"Open in VS Code\",\"row_open_folder\":\"Open project folder\",\"triage_agent_rank_prefix\":\"rank \",\"triage_agent_accept_suffix\":\"% accept\",\"triage_agent_no_rank\":\"no signal yet\",\"triage_session_summary\":\"Done — triaged {total} idea(s) in {minutes}m ({accepted} accepted, {rejected} rejected)\",\"row_import_gh_issues\":\"Import GitHub issues as goals\",\"gh_import_title\":\"Import GitHub issues as goals\",\"gh_import_loading\":\"Loading open issues from GitHub…\",\"gh_import_no_credential\":\"No GitHub credential found\",\"gh_import_no_credential_hint\":\"Add a GitHub credential in Connections — the importer will use the first one that matches.\",\"gh_import_load_failed\":\"Couldn't load issues\",\"gh_import_no_issues\":\"No open issues on this repo. Nothing to import.\",\"gh_import_select_all\":\"Select all\",\"gh_import_clear\":\"Clear\",\"gh_import_selected_count\":\"{selected}/{total} selected\",\"gh_import_create_btn\":\"Create {count} goal(s)\",\"gh_import_toast_success\":\"Created {count} goal(s) in {project} from GitHub issues\",\"gh_import_toast_partial\":\"{failed} issue(s) failed to import — check Connections credential\",\"context_detail_goals_heading\":\"Goals\",\"context_detail_no_goals\":\"No goal references this context yet. Create one in Lifecycle → Goals and set its context.\",\"context_detail_task_summary\":\"{done} of {total} tasks done\",\"context_detail_goal_jump_tooltip\":\"Open in Lifecycle spotlight\",\"context_detail_pin\":\"Pin context\",\"context_detail_unpin\":\"Unpin context\",\"context_detail_pin_tooltip\":\"Pinned contexts are preserved across a full re-scan instead of being recreated\",\"context_detail_pin_failed\":\"Couldn't update the pin\",\"slot_compare_checkbox_tooltip\":\"Select for prompt diff (max 2)\",\"add_group\":\"Add Group\",\"group_name_placeholder\":\"Group name...\",\"create\":\"Create\",\"no_contexts_in_group\":\"No contexts in this group yet\",\"scanning_codebase\":\"Scanning Codebase\",\"analyzing_codebase\":\"Claude is analyzing your codebase...\",\"cancel_scan\":\"Cancel scan\",\"waiting_for_output\":\"Waiting for output...\",\"start_competition\":\"Start a Competition\",\"competition_desc\":\"Spawn 2–4 competitors with different strategies, each in a Claude Code worktree.\",\"task_title\":\"Task title\",\"task_description\":\"Task description (optional)\",\"strategy_slots\":\"Strategy slots (pick 2–4)\",\"cost_warning\":\"Cost warning:\",\"cost_warning_detail\":\"each competitor runs the full task independently (~$0.80 per Claude run). A 4-slot competition ≈ $3.20 in API costs.\",\"competitions\":\"Competitions\",\"new_competition\":\"New Competition\",\"no_competitions\":\"No competitions yet. Start one to have 2–4 Dev Clone variants race on the same task in parallel worktrees.\",\"active\":\"Active\",\"past\":\"Past\",\"select_project_for_competitions\":\"Select a project to see competitions.\",\"loading_competitors\":\"Loading competitors...\",\"failed_to_load_detail\":\"Failed to load detail.\",\"task\":\"Task\",\"pick_winner\":\"Pick winner\",\"winner\":\"Winner\",\"disqualified_label\":\"Disqualified\",\"elapsed_label\":\"elapsed\",\"cancel_competition\":\"Cancel competition\",\"no_goals_constellation\":\"No goals to map yet. Create a goal and it will appear here.\",\"your_turn\":\"Your Turn\",\"agents_turn\":\"Agent's Turn\",\"done\":\"Done\",\"no_goals_kanban\":\"No goals yet. Create goals to see them here.\",\"no_goals_here\":\"No goals here\",\"lifecycle_title\":\"Dev Lifecycle\",\"competition_title\":\"Competition\",\"lifecycle_subtitle\":\"Autonomous development flow: scan → goals → review → build → learn\",\"teardown\":\"Teardown\",\"auto_setup\":\"Auto-Setup\",\"adopt_first\":\"Adopt Dev Clone first\",\"loading_lifecycle\":\"Loading lifecycle status...\",\"adopt_dev_clone\":\"Adopt Dev Clone\",\"adopt_dev_clone_desc\":\"Dev Clone is an autonomous developer persona bundled with Personas. It scans your codebase every hour, proposes tasks for review, and builds on approval. Adopting the template creates the persona, registers its tools, and wires its triggers in a single step.\",\"dev_project\":\"Dev project\",\"github_repo\":\"GitHub repo\",\"dev_clone_template\":\"Dev Clone template\",\"linked\":\"linked\",\"not_linked\":\"not linked\",\"bundled\":\"bundled\",\"none_selected\":\"none selected\",\"needed_for_pr\":\"Needed for PR workflows\",\"select_project_first\":\"Select a project first\",\"adopt_now_note\":\"You can still adopt now — add the GitHub URL to the project later to enable PR workflows.\",\"how_it_works\":\"How the Autonomous Flow Works\",\"no_project_click_create\":\"No project — click to create\",\"repo\":\"repo\",\"no_repo\":\"no repo\",\"cross_project_title\":\"Cross-Project Metadata Map\",\"cross_project_desc\":\"Aggregated from existing context maps. Consumed by Codebases connector.\",\"regenerate\":\"Regenerate\",\"generate\":\"Generate\",\"no_metadata_yet\":\"No metadata map generated yet\",\"no_metadata_hint\":\"Click Generate to analyze all projects' context maps and build a rich cross-project metadata layer for the Codebases connector.\",\"tech_distribution\":\"Tech Distribution\",\"shared_keywords_desc\":\"Concepts present in multiple projects — signals where business tasks overlap.\",\"project_similarity\":\"Project Similarity\",\"capabilities\":\"Capabilities\",\"top_keywords\":\"Top Keywords\",\"database_tables\":\"Database Tables\",\"api_surface\":\"API Surface\",\"hot_directories\":\"Hot Directories\",\"purpose\":\"Purpose\",\"run_context_scan\":\"Run Context Map scan for this project to enable rich metadata extraction.\",\"collapse\":\"Collapse\",\"details\":\"Details\",\"loading_repositories\":\"Loading repositories...\",\"github_url\":\"GitHub URL\",\"github_repository\":\"GitHub Repository\",\"select_repository\":\"Select a repository...\",\"filter_repositories\":\"Filter repositories...\",\"no_repositories\":\"No repositories found\",\"implementation_log\":\"Implementation Log\",\"add_note_placeholder\":\"Add a note...\",\"no_activity\":\"No activity yet\",\"projects_title\":\"Projects\",\"projects_subtitle\":\"Manage local development projects and goals\",\"cross_project_map\":\"Cross-Project Map\",\"new_project\":\"New Project\",\"edit_project\":\"Edit Project\",\"project_folder\":\"Project Folder\",\"select_folder\":\"Select a folder...\",\"browse\":\"Browse\",\"project_name\":\"Project Name\",\"auto_filled\":\"(auto-filled from folder)\",\"project_type\":\"Project Type\",\"project_type_hint\":\"(optional, visual only)\",\"purpose_desc\":\"Purpose & Description\",\"save_changes\":\"Save Changes\",\"project_created\":\"Project Created\",\"generate_context_map\":\"Generate Context Map\",\"skip_for_now\":\"Skip for now\",\"add_goal\":\"Add a goal...\",\"add\":\"Add\",\"no_goals_yet\":\"No goals yet. Add one below.\",\"select_project_below\":\"Select a project below or create a new one\",\"no_projects_yet\":\"No projects yet\",\"create_first_project\":\"Create First Project\",\"col_name\":\"Name\",\"col_path\":\"Path\",\"col_tech_stack\":\"Tech Stack\",\"col_goals\":\"Goals\",\"col_status\":\"Status\",\"col_created\":\"Created\",\"delete_project\":\"Delete Project\",\"self_healing\":\"Self-Healing\",\"auto_heal\":\"Auto-heal\",\"heal\":\"Heal\",\"task_runner_title\":\"Task Runner\",\"task_runner_subtitle\":\"Batch execution queue for accepted tasks\",\"new_task\":\"New Task\",\"batch_from_accepted\":\"Batch from Accepted\",\"start_batch\":\"Start Batch\",\"cancel_all\":\"Cancel All\",\"batch_progress\":\"Batch Progress\",\"task_queue\":\"Task Queue\",\"no_tasks\":\"No tasks in queue\",\"no_tasks_hint\":\"Create tasks manually or batch from accepted ideas\",\"title_label\":\"Title\",\"description_label\":\"Description\",\"task_depth\":\"Task Depth\",\"goal_link\":\"Goal Link\",\"create_task\":\"Create Task\",\"partial_context\":\"Partial context\",\"context_warnings_title\":\"Context warnings — task ran with missing information\",\"context_scan_ready_title\":\"Context Map Ready\",\"context_scan_ready_body\":\"Codebase scan for \\\"{projectName}\\\" completed. Context map is ready to explore.\",\"context_scan_failed_title\":\"Context Scan Failed\",\"context_scan_failed_body\":\"Codebase scan for \\\"{projectName}\\\" failed. Check the Context Map for details.\",\"context_scan_unknown_project\":\"Unknown project\",\"context_scan_ungrouped\":\"Ungrouped\",\"context_scan_notif_ready_title\":\"Context Map Ready — {projectName}\",\"context_scan_notif_ready_summary\":\"Generated {counts}.\",\"context_scan_notif_ready_summary_default\":\"Codebase scan completed successfully.\",\"context_scan_notif_partial_title\":\"Context Map (partial) — {projectName}\",\"context_scan_notif_partial_summary\":\"Scan exceeded the timeout but {counts} were saved. Click Open to review what was generated.\",\"context_scan_notif_partial_summary_default\":\"partial results\",\"context_scan_notif_failed_title\":\"Context Map Failed — {projectName}\",\"context_scan_notif_failed_default\":\"The codebase scan failed before any contexts were created. Try again or check the logs.\",\"context_scan_notif_cancelled_title\":\"Context Map Cancelled — {projectName}\",\"context_scan_notif_cancelled_summary\":\"Codebase scan was cancelled by the user.\",\"context_scan_count_groups\":\"{count} groups\",\"context_scan_count_contexts\":\"{count} contexts\",\"context_scan_count_files\":\"{count} files\",\"slot_dev_server_started\":\"Dev server started on port {port}\",\"slot_dev_server_failed\":\"Failed to start server\",\"slot_dev_server_stopped\":\"Dev server stopped\",\"slot_empty_diff\":\"(empty diff)\",\"slot_load_diff_failed\":\"failed to load diff\",\"slot_load_diff_error\":\"Error: {error}\",\"slot_failed_status\":\"Failed\",\"slot_failed_with_error\":\"Failed: {error}\",\"slot_modified_files_one\":\"Modified {count} file\",\"slot_modified_files_other\":\"Modified {count} files\",\"slot_lines_added\":\"+{count} lines\",\"slot_lines_removed\":\"{count} lines\",\"slot_files_count\":\"{count} files\",\"slot_qscore_label\":\"Q: {total}/100\",\"slot_qscore_tooltip\":\"Build: {build}/25 · Tests: {tests}/30 · Lint: {lint}/20 · Review: {review}/15 · Completion: {completion}/10\",\"slot_btn_preview\":\"Preview\",\"slot_btn_stop\":\"Stop\",\"slot_btn_browse\":\"Browse\",\"slot_btn_hide\":\"Hide\",\"slot_btn_diff\":\"Diff\",\"slot_btn_open_url\":\"Open {url}\",\"dev_clone_adopted_toast\":\"Dev Clone persona adopted successfully.\",\"dev_clone_adopt_failed_toast\":\"Dev Clone adoption failed: {error}\",\"campaign\":\"Campaign\",\"deep_build\":\"Deep Build\",\"idea_evolution\":\"Idea Evolution\",\"fitness_ranking\":\"Fitness Ranking\",\"synthesis_suggestions\":\"Synthesis Suggestions\",\"potential_duplicates\":\"Potential Duplicates\",\"skills_title\":\"Skills\",\"search_skills\":\"Search skills...\",\"no_matching_skills\":\"No matching skills\",\"no_skills_found\":\"No skills found\",\"select_skill\":\"Select a skill to view its contents\",\"edit\":\"Edit\",\"save\":\"Save\",\"file_empty\":\"File is empty.\",\"file_load_failed\":\"File could not be loaded. Editing is disabled to prevent data loss.\",\"file_load_failed_hint\":\"Try refreshing or re-selecting the skill.\",\"skills_load_failed_toast\":\"Failed to load skills. Make sure a project with .claude/skills/ exists.\",\"skills_read_failed_toast\":\"Failed to read {skill}/{file}\",\"skills_save_success_toast\":\"Saved {skill}/{file}\",\"skills_save_failed_toast\":\"Save failed\",\"skills_subtitle_one\":\"{count} skill installed\",\"skills_subtitle_other\":\"{count} skills installed\",\"skills_ref_count_one\":\"{count} ref\",\"skills_ref_count_other\":\"{count} refs\",\"skills_favorite\":\"Pin to favorites\",\"skills_unfavorite\":\"Unpin from favorites\",\"skills_recent_heading\":\"Recent\",\"skills_preview_heading\":\"Live preview\",\"skills_preview_unsaved\":\"unsaved\",\"skills_preview_empty\":\"Start typing to see the rendered preview.\",\"effort\":\"Effort\",\"risk\":\"Risk\",\"quick_wins\":\"Quick Wins\",\"moderate\":\"Moderate\",\"heavy\":\"Heavy\",\"safe\":\"Safe\",\"risky\":\"Risky\",\"clear\":\"Clear\",\"auto_triage_rules\":\"Auto-Triage Rules\",\"new_rule\":\"New Rule\",\"run_rules\":\"Run Rules\",\"add_condition\":\"+ Add condition\",\"accept\":\"Accept\",\"reject\":\"Reject\",\"triage_title\":\"Idea Triage\",\"triage_subtitle\":\"Evaluate and prioritize generated ideas\",\"sidebar_sort\":\"Order\",\"sort_default\":\"Default\",\"sort_value\":\"Best value\",\"sort_quick\":\"Quick wins\",\"sort_value_tip\":\"Highest impact for the effort and risk\",\"sort_quick_tip\":\"Low-effort ideas first\",\"accepted_badge\":\"{count} accepted\",\"rejected_badge\":\"{count} rejected\",\"pending_badge\":\"{count} pending\",\"sidebar_category\":\"Category\",\"sidebar_scan_type\":\"Scan Type\",\"sidebar_all\":\"All ({count})\",\"remaining_count\":\"{count} remaining\",\"reviewed_count\":\"{done} / {total} reviewed\",\"empty_no_ideas\":\"No ideas to triage\",\"empty_no_ideas_hint\":\"Run the Idea Scanner first to generate ideas.\",\"empty_all_reviewed\":\"All ideas reviewed!\",\"empty_all_reviewed_summary\":\"{accepted} accepted, {rejected} rejected\",\"swipe_reject\":\"Reject\",\"swipe_accept\":\"Accept\",\"reasoning_label\":\"Reasoning\",\"hint_reject\":\"Reject\",\"hint_accept\":\"Accept\",\"shortcuts_title\":\"Keyboard Shortcuts\",\"shortcuts_reject\":\"Reject idea\",\"shortcuts_accept\":\"Accept idea\",\"shortcuts_toggle\":\"Toggle this overlay\",\"shortcuts_close\":\"Close overlay\",\"shortcuts_dismiss_hint\":\"Press ? or Esc to close\",\"shortcuts_open_title\":\"Keyboard shortcuts (?)\",\"shortcuts_btn_reject_title\":\"Reject (Left Arrow / A)\",\"shortcuts_btn_accept_title\":\"Accept (Right Arrow / Z)\",\"shortcuts_btn_delete_title\":\"Delete\",\"pr_bridge_title\":\"Ship as Draft PR\",\"pr_bridge_subtitle\":\"Branch, commit, and pre-filled PR — agent reasoning carried into the description.\",\"pr_bridge_branch\":\"Branch\",\"pr_bridge_commit_msg\":\"Commit message\",\"pr_bridge_pr_title\":\"PR title\",\"pr_bridge_pr_body\":\"PR body\",\"pr_bridge_agent_citation\":\"Proposed by {label} {emoji}\",\"pr_bridge_no_github\":\"Link a GitHub repo on this project to enable PR workflows.\",\"pr_bridge_link_project\":\"Open project settings\",\"pr_bridge_unsupported_host\":\"Auto-fill only works with GitHub right now. Copy the PR body and open manually.\",\"pr_bridge_copy_body\":\"Copy PR body\",\"pr_bridge_copy_all\":\"Copy all\",\"pr_bridge_copy_reasoning\":\"Copy reasoning\",\"pr_bridge_copy_reasoning_tooltip\":\"Copy just the agent's reasoning text — useful for pasting into review threads.\",\"pr_bridge_reasoning_copied\":\"Agent reasoning copied to clipboard\",\"pr_bridge_copy_git_block\":\"Copy git commands\",\"pr_bridge_copy_git_block_tooltip\":\"Copy a ready-to-paste bash block (checkout, commit via heredoc, push, optional gh pr create).\",\"pr_bridge_git_block_copied\":\"Git command block copied — paste into a terminal at the repo root.\",\"pr_bridge_reset_steps\":\"Reset checkmarks\",\"pr_bridge_reset_steps_tooltip\":\"Clear the per-task completion checkmarks (does not undo any work — just the visual tracker).\",\"pr_bridge_prepare\":\"Prepare branch & commit\",\"pr_bridge_open_github\":\"Open draft PR on GitHub\",\"pr_bridge_expand\":\"Show PR preview\",\"pr_bridge_collapse\":\"Hide PR preview\",\"pr_bridge_copied\":\"PR body copied to clipboard\",\"pr_bridge_copy_failed\":\"Couldn't copy to clipboard\",\"pr_bridge_branch_prepared\":\"Branch prepared: {branch}\",\"pr_bridge_branch_failed\":\"Branch preparation failed: {message}\",\"pr_bridge_opening\":\"Opening GitHub...\",\"pr_bridge_open_failed\":\"Couldn't open external URL\",\"pr_bridge_draft_hint\":\"Tip: on the GitHub page, use the dropdown next to \\\"Create pull request\\\" to mark it as a draft.\",\"scoreboard_title\":\"Agent Performance\",\"scoreboard_subtitle\":\"Acceptance & implementation rates per scan agent for this project.\",\"scoreboard_toggle_show\":\"Show scoreboard\",\"scoreboard_toggle_hide\":\"Hide scoreboard\",\"scoreboard_empty\":\"No data yet — run a scan and triage some ideas to populate the board.\",\"scoreboard_col_agent\":\"Agent\",\"scoreboard_col_ideas\":\"Ideas\",\"scoreboard_col_accept_rate\":\"Accept %\",\"scoreboard_col_impl_rate\":\"Impl %\",\"scoreboard_col_avg_impact\":\"Avg impact\",\"scoreboard_col_avg_effort\":\"Avg effort\",\"scoreboard_tip_ideas\":\"Total ideas this agent has generated across all scans.\",\"scoreboard_tip_accept_rate\":\"Accepted / (Accepted + Rejected). Pending ideas are excluded from the denominator.\",\"scoreboard_tip_impl_rate\":\"Completed tasks / tasks created from this agent's ideas.\",\"scoreboard_tip_avg_impact\":\"Mean impact score (1-10) of all ideas this agent generated.\",\"scoreboard_tip_avg_effort\":\"Mean effort score (1-10) of all ideas this agent generated.\",\"scoreboard_no_signal\":\"—\",\"scoreboard_n_pending\":\"{n} pending\",\"scoreboard_top_performer\":\"Top performer\",\"zoom_in\":\"Zoom in\",\"zoom_out\":\"Zoom out\",\"reset_view\":\"Reset view\",\"goals_label\":\"goals,\",\"connections_label\":\"connections\",\"legend_parent\":\"━━ parent\",\"legend_dependency\":\"┄┄ dependency\",\"competitors_dot\":\"competitors ·\",\"of_4_selected\":\"/4 selected\",\"status_label\":\"Status:\",\"wt_label\":\"wt:\",\"winning_insight\":\"Winning insight\",\"completed_in\":\"Completed in\",\"achievements_label\":\"Achievements:\",\"output_lines\":\"output lines\",\"loading_diff\":\"Loading diff...\",\"first_slot_bias\":\"First slot biased toward previous winner's approach.\",\"start_competition_prefix\":\"Start Competition (\",\"slots_suffix\":\"slots)\",\"strategy_leaderboard\":\"Strategy Leaderboard\",\"dq_label\":\"DQ x\",\"leaderboard_subtitle\":\"Aggregated across resolved competitions. Higher wins.\",\"capture_winning_insight\":\"Capture the winning insight\",\"capture_insight_desc\":\"What made this approach win? This note is stored with the winner and used to seed future competitions.\",\"insight_placeholder\":\"Describe what made this approach succeed…\",\"confirm_winner\":\"Confirm winner\",\"github_required_title\":\"GitHub repository required\",\"folder_required_title\":\"Project folder required\",\"requirements_met_title\":\"All requirements met\",\"can_still_adopt\":\"You can still adopt now — add GitHub URL and project folder later in project settings.\",\"active_triggers_prefix\":\"Active Triggers (\",\"lifecycle_readiness\":\"Lifecycle Readiness —\",\"goals_tab_no_goals\":\"No goals yet\",\"goal_constellation_prefix\":\"Goal Constellation (\",\"sync_to_obsidian\":\"Sync to Obsidian\",\"select_or_create\":\"Select a project below or create a new one.\",\"all_projects_prefix\":\"All Projects (\",\"percent_overall\":\"% overall\",\"task_queue_prefix\":\"Task Queue (\",\"project_created_title\":\"Project Created\",\"generate_context_map_desc\":\"Scans your codebase to identify business logic, entry points, and data structures.\",\"source_label\":\"Source:\",\"auto_filled_hint\":\"(auto-filled from folder)\",\"project_type_optional\":\"(optional, visual only)\",\"optional_label\":\"(optional)\",\"implementation_log_label\":\"Implementation Log\",\"cross_project_desc_btn\":\"Aggregated from existing context maps. Click any project to expand.\",\"generate_metadata_prompt\":\"to analyze all projects' context maps and extract shared patterns.\",\"projects_count_prefix\":\"Projects (\",\"entry_points_header\":\"Entry Points\",\"db_tables_header\":\"Database Tables\",\"entry_points_count\":\"entry points\",\"db_tables_count\":\"db tables\",\"run_context_map_scan\":\"Run Context Map scan for this project to populate metadata.\",\"active_goals_label\":\"active goals\",\"no_goals_add_below\":\"No goals yet. Add one below.\",\"goal_title_placeholder\":\"Goal title…\",\"loading_repos\":\"Loading repositories...\",\"github_url_label\":\"GitHub URL\",\"github_repo_label\":\"GitHub Repository\",\"idea_evolution_title\":\"Idea Evolution\",\"fitness_ranking_label\":\"Fitness Ranking\",\"similar_to_rejected\":\"Similar to rejected:\",\"percent_similar\":\"% similar\",\"scanning_with\":\"Scanning with\",\"scanning_subtitle\":\"Analyzing codebase patterns and generating context groups…\",\"no_previous_scans\":\"No previous scans.\",\"run_scan_prefix\":\"Run Scan (\",\"automated_context_scan\":\"Automated Context Scan\",\"results_prefix\":\"Results (\",\"scan_history_prefix\":\"Scan History (\",\"action_label\":\"Action:\",\"self_healing_title\":\"Self-Healing\",\"heal_all_prefix\":\"Heal All (\",\"task_title_placeholder\":\"Task title…\",\"task_details_placeholder\":\"Task details or paste a spec…\",\"goal_link_placeholder\":\"Select a goal…\",\"no_tasks_queued_sub\":\"Create tasks manually or batch from accepted ideas.\",\"llm_title\":\"LLM Overview\",\"llm_subtitle\":\"Where this project calls an LLM — feature, provider, model, usage and estimated cost.\",\"llm_refresh\":\"Refresh\",\"llm_no_cred\":\"No Langfuse / LangSmith / Helicone / LightTrack credential in your vault yet. Add one under Vault → Connectors, then assign it here.\",\"llm_projects_instrumented\":\"projects instrumented\",\"llm_gap\":\"gap\",\"llm_not_wired\":\"Not wired\",\"llm_wire_placeholder\":\"Wire a tracer…\",\"llm_no_project_title\":\"No project selected\",\"llm_no_project_sub\":\"Pick a Dev Tools project to see its LLM usage.\",\"llm_empty_title\":\"No LLM-observability connector\",\"llm_empty_sub\":\"Connect Langfuse, LangSmith, Helicone, or LightTrack in Vault → Connectors, then assign it to this project above.\",\"llm_unmapped_title\":\"Not assigned to this project\",\"llm_unmapped_sub\":\"Assign one of your LLM-observability connectors to this project using the matrix above.\",\"llm_unsupported_title\":\"Live data for {tool} is coming soon\",\"llm_unsupported_sub\":\"This connector is assigned, but its live adapter lands in a later phase. LightTrack works today.\",\"llm_error_title\":\"Couldn't load LLM usage\",\"llm_empty_calls_title\":\"No LLM calls in this window\",\"llm_empty_calls_sub\":\"{name} reported no calls in the last {window}. Widen the window or check that the tool is receiving traces.\",\"llm_col_usecase\":\"Feature\",\"llm_col_provider\":\"Provider\",\"llm_col_model\":\"Model\",\"llm_col_calls\":\"Calls\",\"llm_col_tokens\":\"Tokens\",\"llm_col_cost\":\"Est. $\",\"llm_unnamed\":\"unnamed\",\"llm_cost_note\":\"Costs are token×price estimates from {tool}, not billed amounts.\",\"llm_this_tool\":\"this tool\",\"llm_the_connector\":\"The connector\",\"llm_aria_window\":\"Time window\",\"llm_aria_table\":\"LLM pinpoints\",\"llm_unknown_error\":\"Unknown error\",\"obs_title\":\"Observability\",\"obs_tab_llm\":\"LLM\",\"obs_tab_monitoring\":\"Monitoring\",\"obs_aria_view\":\"Observability view\",\"mon_no_cred\":\"No app-monitoring connector in your vault yet — add Sentry in Connections to map error tracking per project.\",\"mon_coverage\":\"projects monitored\",\"mon_gap\":\"gap\",\"mon_not_wired\":\"Not monitored\",\"mon_wire_placeholder\":\"Wire a monitor…\",\"mon_no_project_title\":\"No project selected\",\"mon_no_project_sub\":\"Pick a project to see its monitoring.\",\"mon_empty_title\":\"No monitoring connector\",\"mon_empty_sub\":\"Add a Sentry credential in Connections to map monitoring.\",\"mon_unmapped_title\":\"Not linked\",\"mon_unmapped_sub\":\"Assign a monitoring connector to this project in the matrix above.\",\"mon_unsupported_title\":\"Live stats for {tool} are coming soon\",\"mon_unsupported_sub\":\"This connector is assigned, but its live adapter lands later.\",\"mon_error_title\":\"Couldn't load monitoring\",\"mon_unknown_error\":\"Unknown error\",\"mon_refresh\":\"Refresh\",\"uc_title\":\"Features\",\"uc_scan\":\"Scan\",\"uc_scan_tooltip\":\"Let an agent propose the key features from your context map\",\"uc_cancel_scan\":\"Cancel scan\",\"uc_backfill\":\"From labels\",\"uc_backfill_tooltip\":\"Create features from context labels that span two or more contexts\",\"uc_accept\":\"Accept\",\"uc_reject\":\"Reject\",\"uc_proposals_heading\":\"{count} awaiting review\",\"uc_span_count\":\"spans {count}\",\"uc_count\":\"{count} feature(s)\",\"uc_chip_tooltip\":\"Highlight the contexts this feature spans\",\"uc_highlight_hint\":\"Highlighted contexts are the ones this feature spans.\",\"uc_empty\":\"No features yet. Scan to propose them, or build them from your context labels.\",\"uc_empty_no_map\":\"Scan the codebase into a context map first — features are slices through it.\",\"uc_context_none\":\"No feature covers this context yet.\",\"uc_context_coverage_tooltip\":\"Features that slice through this context\",\"uc_status_proposed\":\"proposed\",\"uc_kind_user_flow\":\"user flow\",\"uc_kind_capability\":\"capability\",\"uc_kind_integration\":\"integration\",\"uc_kind_ops\":\"ops\",\"llm_usecase_linked\":\"Mapped to the feature \\\"{name}\\\"\",\"llm_usecase_coverage\":\"{mapped}/{total} mapped to a feature\",\"uc_backfill_none\":\"No context label spans more than one context, so nothing was created. Use Scan to propose features.\",\"uc_backfill_created\":\"Created {count} feature(s) for review.\",\"context_search_placeholder\":\"Filter contexts…\",\"context_search_no_results\":\"No contexts match\",\"context_column_label\":\"Context\",\"context_pinned\":\"Pinned\",\"uc_view_details\":\"View details\",\"uc_detail_description\":\"Description\",\"uc_detail_rationale\":\"Why it matters\",\"uc_detail_contexts\":\"Contexts covered\",\"uc_detail_primary\":\"primary\",\"uc_detail_source\":\"Proposed by\",\"uc_detail_no_description\":\"No description provided.\",\"uc_source_scan\":\"Agent scan\",\"uc_source_backfill\":\"Context labels\",\"uc_source_user\":\"You\",\"ctx_kpi_met\":\"Met\",\"ctx_kpi_on_track\":\"On track\",\"ctx_kpi_off_track\":\"Off track\",\"ctx_kpi_unmeasured\":\"Not measured\",\"ctx_kpi_none\":\"No KPIs\",\"llm_propose_tooltip\":\"Propose as a feature\",\"llm_propose_aria\":\"Propose “{name}” as a feature\",\"llm_propose_done\":\"“{name}” proposed — accept it on the Context Map\",\"ctx_indicator_kpis\":\"KPIs\",\"sidebar_source\":\"Source\",\"sidebar_source_any\":\"Any\",\"ctx_cost_tooltip\":\"30-day LLM spend of the features slicing this context. A feature's full cost counts against every context it touches, so this is a flow-through figure, not a share of the bill.\",\"ctx_errors_label\":\"errors\",\"ctx_errors_tooltip\":\"Unresolved Sentry events landing on this context's files — open Overview\"},\"doc_signing\":{\"sign_heading\":\"Sign a Document\",\"signing_as\":\"Signing as\",\"notes_label\":\"Notes (optional)\",\"sign_document\":\"Sign Document\",\"signing\":\"Signing...\",\"signed_success\":\"Signed successfully\",\"save_sig_json\":\"Save .sig.json\",\"copy\":\"Copy\",\"verify_heading\":\"Verify a Signature\",\"signature_label\":\"Signature (.sig.json)\",\"verify_signature\":\"Verify Signature\",\"verifying\":\"Verifying...\",\"valid_signature\":\"Valid signature\",\"verification_failed\":\"Verification failed\",\"signer\":\"Signer\",\"signed_at\":\"Signed at\",\"file_integrity\":\"File integrity\",\"unchanged\":\"Unchanged\",\"modified\":\"Modified\",\"crypto_signature\":\"Crypto signature\",\"valid\":\"Valid\",\"invalid\":\"Invalid\",\"history_heading\":\"Signature History\",\"no_signatures\":\"No signatures yet\",\"no_signatures_hint\":\"Sign a document to see it here.\",\"export_sig\":\"Export .sig.json\",\"delete_signature\":\"Delete signature\",\"title\":\"Document Signing\",\"subtitle\":\"Ed25519 digital signatures with portable sidecar verification\",\"tab_sign\":\"Sign\",\"tab_verify\":\"Verify\",\"tab_history\":\"History\",\"sign_desc\":\"Create an Ed25519 digital signature using your local identity key. The signature is stored locally and a portable .sig.json sidecar is generated.\",\"choose_file_sign\":\"Choose file to sign\",\"verify_desc\":\"Check that a document has not been tampered with and that the signature is cryptographically valid.\",\"choose_file_verify\":\"Choose file to verify\",\"load_from_file\":\"Load from file\"},\"obsidian_brain\":{\"title\":\"Obsidian Brain\",\"subtitle\":\"Bidirectional sync between your Obsidian vault and Personas\",\"tab_setup\":\"Setup\",\"tab_sync\":\"Sync\",\"tab_browse\":\"Browse Vault\",\"tab_graph\":\"Graph\",\"tab_cloud\":\"Cloud\",\"tab_revitalize\":\"Revitalize\",\"revitalize_title\":\"Memory revitalization\",\"revitalize_subtitle\":\"Let Claude consolidate your vault like sleep consolidates memory — prune, merge, and refresh notes in the background.\",\"revitalize_goal_prune\":\"Prune stale notes\",\"revitalize_goal_prune_desc\":\"Delete notes that are outdated, superseded, or empty\",\"revitalize_goal_merge\":\"Aggregate duplicates\",\"revitalize_goal_merge_desc\":\"Merge overlapping notes into one canonical note with links\",\"revitalize_goal_refresh\":\"Refresh structure\",\"revitalize_goal_refresh_desc\":\"Fix wiki-links, frontmatter, and titles on surviving notes\",\"revitalize_instructions_label\":\"Extra guidance (optional)\",\"revitalize_instructions_placeholder\":\"e.g. Focus on the Research folder; never delete meeting notes\",\"revitalize_safety_note\":\"Runs locally via the Claude Code CLI inside your vault. Only Markdown notes are touched — .obsidian and attachments are never modified. Each pass is bounded; run again to continue.\",\"revitalize_start\":\"Start revitalization\",\"revitalize_cancel\":\"Cancel pass\",\"revitalize_running_title\":\"Consolidation in progress\",\"revitalize_running_hint\":\"Keeps running in the background — you can leave this page.\",\"revitalize_no_goals\":\"Select at least one optimization goal\",\"revitalize_started_toast\":\"Revitalization started for “{name}”\",\"revitalize_start_failed\":\"Could not start revitalization: {error}\",\"revitalize_failed\":\"Revitalization failed: {error}\",\"revitalize_summary_title\":\"Sleep cycle complete\",\"revitalize_summary_vault\":\"Vault “{name}” optimized in {duration}\",\"revitalize_stat_deleted\":\"Notes removed\",\"revitalize_stat_merged\":\"Notes merged\",\"revitalize_stat_updated\":\"Notes updated\",\"revitalize_stat_reviewed\":\"Notes reviewed\",\"revitalize_stat_tokens_saved\":\"Est. tokens saved\",\"revitalize_stat_notes_delta\":\"Notes in vault\",\"revitalize_model_summary\":\"What happened\",\"revitalize_highlights\":\"Highlights\",\"revitalize_run_again\":\"Run another pass\",\"revitalize_done\":\"Done\",\"revitalize_badge_running\":\"Vault revitalization in progress\",\"revitalize_badge_complete\":\"Vault revitalization complete\",\"revitalize_history_title\":\"Recent passes\",\"revitalize_history_subtitle\":\"What each sleep cycle cleaned, newest first\",\"revitalize_history_empty\":\"No passes yet — run your first revitalization above.\",\"revitalize_history_col_when\":\"When\",\"revitalize_history_col_vault\":\"Vault\",\"revitalize_history_col_result\":\"Result\",\"revitalize_history_col_cleaned\":\"Cleaned\",\"revitalize_history_col_tokens\":\"Tokens saved\",\"revitalize_history_status_completed\":\"Completed\",\"revitalize_history_status_failed\":\"Failed\",\"revitalize_history_removed_short\":\"{count} removed\",\"revitalize_history_merged_short\":\"{count} merged\",\"revitalize_history_updated_short\":\"{count} updated\",\"revitalize_history_duration\":\"took {duration}\",\"revitalize_history_load_failed\":\"Could not load run history: {error}\",\"filter_notes\":\"Filter notes...\",\"vault_empty\":\"Vault is empty\",\"failed_to_load\":\"Failed to load\",\"open_in_obsidian\":\"Open in Obsidian\",\"word_count\":\"{count} words\",\"note_properties\":\"Properties\",\"no_vault_connected\":\"No Vault Connected\",\"no_vault_hint\":\"Set up an Obsidian vault in the Setup tab first.\",\"no_vault_cloud_hint\":\"Set up a local Obsidian vault in the Setup tab first, then connect Google Drive for cloud backup.\",\"sign_in_required\":\"Sign in to enable cloud sync\",\"sign_in_hint\":\"Sign in with your Google account to back up your vault to your own Google Drive (15 GB free). Files are stored under Personas/ObsidianSync/.\",\"google_drive_connection\":\"Google Drive Connection\",\"drive_connected\":\"Drive Connected\",\"connect_google_drive\":\"Connect Google Drive\",\"connecting\":\"Connecting...\",\"storage\":\"Storage\",\"drive_info\":\"Connect Google Drive to back up your vault across devices. Files are stored in your own Google Drive under Personas/ObsidianSync/. Free alternative to Obsidian Sync ($4/month).\",\"cloud_sync\":\"Cloud Sync\",\"push_to_drive\":\"Push to Drive\",\"pushing\":\"Pushing...\",\"pull_from_drive\":\"Pull from Drive\",\"pulling\":\"Pulling...\",\"push_pull_hint\":\"Push uploads local vault changes to Google Drive. Pull downloads remote changes to your local vault. Only files that have changed since the last sync are transferred.\",\"last_sync_result\":\"Last Sync Result\",\"how_it_works\":\"How it works\",\"step1_title\":\"Connect Google Drive\",\"step1_desc\":\"Grant Personas access to create files in your Drive. Only the app's own folder is accessible.\",\"step2_title\":\"Push your vault\",\"step2_desc\":\"Vault notes are uploaded as markdown files to Drive. Only changed files are synced (content-hash comparison).\",\"step3_title\":\"Sync across devices\",\"step3_desc\":\"Pull on another device to download. Your 15 GB free Google Drive storage is more than enough for thousands of notes.\",\"vault_connection\":\"Vault Connection\",\"vault_connection_subtitle\":\"Connect to an Obsidian vault for bidirectional sync\",\"detecting\":\"Detecting...\",\"auto_detect\":\"Auto-Detect Vaults\",\"detected_vaults\":\"Detected vaults:\",\"vault_path_placeholder\":\"Vault path...\",\"test\":\"Test\",\"sync_options\":\"Sync Options\",\"sync_options_subtitle\":\"Choose what data to synchronize\",\"knowledge_mirror\":\"Knowledge mirror\",\"mirror_research_lab\":\"Research Lab\",\"mirror_research_lab_desc\":\"Save Research Lab experiments to your vault as notes.\",\"mirror_execution_knowledge\":\"Execution Knowledge\",\"mirror_execution_knowledge_desc\":\"Write what agents learn from executions into your vault.\",\"mirror_athena\":\"Athena Brain\",\"mirror_athena_desc\":\"Let Athena read your vault for specific cases and analysis.\",\"mirror_save_failed\":\"Couldn't update knowledge mirror: {error}\",\"mirror_backfill_done\":\"Mirrored {count} notes to your vault.\",\"memories\":\"Memories\",\"memories_desc\":\"Persona memories with category, importance, and tags\",\"persona_profiles\":\"Persona Profiles\",\"persona_profiles_desc\":\"System prompts, config, and design context\",\"connectors\":\"Connectors\",\"connectors_desc\":\"Connector definitions and service documentation\",\"auto_sync\":\"Auto-Sync\",\"auto_sync_desc\":\"Automatically push changes when memories are created\",\"folder_structure\":\"Folder Structure\",\"folder_structure_subtitle\":\"Customize how data is organized in your vault\",\"personas_folder\":\"Personas Folder\",\"memories_subfolder\":\"Memories Subfolder\",\"connectors_folder\":\"Connectors Folder\",\"save_configuration\":\"Save Configuration\",\"saving\":\"Saving...\",\"sync_actions\":\"Sync Actions\",\"push_to_vault\":\"Push to Vault\",\"pull_from_vault\":\"Pull from Vault\",\"select_personas_push\":\"Select personas to push\",\"filter_personas\":\"Filter personas…\",\"personas_selected_count\":\"{selected} of {total} selected\",\"select_all\":\"Select all\",\"no_personas_found\":\"No personas found\",\"last_push_result\":\"Last Push Result\",\"result_pushed_headline\":\"Pushed {count}\",\"result_pulled_headline\":\"Pulled {count}\",\"result_created\":\"created\",\"result_updated\":\"updated\",\"result_skipped\":\"skipped\",\"result_converged\":\"converged\",\"result_conflicts\":\"conflicts\",\"result_errors\":\"errors\",\"result_error_detail\":\"Error details\",\"app_version\":\"App Version\",\"vault_version\":\"Vault Version\",\"conflicts_title\":\"Conflicts ({count})\",\"diff_truncated\":\"Diff truncated — open the note to see the full content.\",\"keep_app\":\"Keep App\",\"keep_vault\":\"Keep Vault\",\"skip\":\"Skip\",\"sync_log\":\"Sync Log\",\"no_sync_activity\":\"No sync activity yet. Push or pull to start.\",\"select_note\":\"Select a Note\",\"select_note_hint\":\"Choose a note from the tree on the left to preview its contents.\",\"vault_label\":\"Vault\",\"active_vault_label\":\"Active vault:\",\"deselect_all\":\"Deselect all\",\"browse_button\":\"Browse\",\"preview_label\":\"Preview:\",\"saved_vaults\":\"Saved Vaults\",\"saved_vaults_empty_hint\":\"Connect and save a vault to see it here.\",\"saved_vaults_empty_hint_setup\":\"Save a vault configuration to see it here. You can switch between vaults anytime.\",\"saved_vaults_empty_hint_other\":\"No saved vaults yet. Set one up in the Setup tab.\",\"connected_to_vault\":\"Connected to {name}\",\"notes_found_count\":\"{count} notes found\",\"vault_switched_to\":\"Switched to {name}\",\"vault_activate_failed\":\"Failed to activate vault: {error}\",\"vault_removed_from_saved\":\"Removed {name} from saved vaults\",\"remove_vault_aria\":\"Remove {name}\",\"vault_stats\":\"Vault Stats\",\"vault_stats_subtitle\":\"Graph metrics across all notes in the active vault\",\"reading_vault\":\"Reading vault...\",\"stat_notes\":\"Notes\",\"stat_links\":\"Links\",\"stat_orphans\":\"Orphans\",\"stat_mocs\":\"MOCs\",\"stat_daily_notes\":\"Daily Notes\",\"no_stats_yet\":\"No stats yet.\",\"search_vault\":\"Search Vault\",\"search_vault_subtitle\":\"Substring search over note titles and bodies\",\"search_vault_placeholder\":\"Search your vault...\",\"search\":\"Search\",\"score_label\":\"score\",\"no_matches\":\"No matches.\",\"orphan_notes_title\":\"Orphan Notes\",\"orphan_notes_subtitle\":\"No incoming links\",\"orphan_notes_empty\":\"No orphan notes — every note is linked from somewhere.\",\"mocs_title\":\"Maps of Content\",\"mocs_subtitle\":\"Notes that link out heavily\",\"mocs_empty\":\"No MOCs detected — try lowering the link threshold.\",\"quick_journal\":\"Quick Journal\",\"quick_journal_subtitle\":\"Append a section to today's daily note\",\"journal_section_placeholder\":\"Section heading\",\"journal_body_placeholder\":\"What happened? What did you learn? Drop it here and it ends up in today's daily note.\",\"append_to_today\":\"Append to Today\",\"capture_meeting\":\"Capture Meeting\",\"capture_meeting_subtitle\":\"Write a structured meeting note under Meetings/\",\"meeting_title_placeholder\":\"Meeting title\",\"meeting_attendees_placeholder\":\"Attendees, comma-separated (becomes [[wikilinks]])\",\"meeting_body_placeholder\":\"Agenda, decisions, action items...\",\"save_meeting_note\":\"Save Meeting Note\",\"files_synced_to_drive\":\"{count} files synced to Drive\",\"loading\":\"Loading...\",\"no_vaults_detected\":\"No Obsidian vaults detected. Try browsing manually.\",\"all_vaults_saved\":\"All detected vaults are already saved. Add a new one via Browse.\",\"detection_failed\":\"Detection failed: {error}\",\"select_vault_dialog_title\":\"Select Obsidian Vault\",\"connection_test_failed\":\"Connection test failed: {error}\",\"select_and_test_first\":\"Please select and test a valid vault first\",\"config_saved\":\"Obsidian Brain configuration saved\",\"save_failed\":\"Save failed: {error}\"},\"companion\":{\"name\":\"Athena\",\"conversations\":\"Conversations\",\"new_conversation\":\"New conversation\",\"archive_conversation\":\"Archive conversation\",\"rename_conversation\":\"Rename conversation\",\"switch_conversation\":\"Switch conversation\",\"replied_in_thread\":\"Athena replied in {thread}\",\"thread_status_awaiting\":\"awaiting you\",\"thread_status_working\":\"working\",\"thread_status_idle\":\"idle\",\"role\":\"thinking partner\",\"athena_assignments_title\":\"Team assignments in flight\",\"tracking_title\":\"Project tracking\",\"tracking_subtitle\":\"Athena absorbs CLI activity (git commits + active-runs ledger) into hourly per-project pulses she'll discuss with you when Dev Tools is on. Per-project flags live in Dev Tools → Lifecycle → Project Tracking.\",\"tracking_master_label\":\"Track development activity\",\"tracking_master_on\":\"ON — Sonnet consolidator runs every hour for enabled projects.\",\"tracking_master_off\":\"OFF — no ingestion, no LLM, no brain writes.\",\"open_label\":\"Open Athena\",\"footer_hold_to_talk\":\"Tap to open · hold to talk\",\"footer_listening\":\"Listening… release to send\",\"orb_talk_hint\":\"Tap to open · hold to talk · drag to move\",\"orb_dismiss\":\"Hide Athena\",\"orb_managing_fleet\":\"Managing the fleet…\",\"forward_ack_speech\":\"Understood, processing the message.\",\"guide_stop\":\"Stop\",\"guide_skip\":\"Skip\",\"guide_take_me_there\":\"Take me there\",\"guide_back\":\"Back\",\"guide_pause\":\"Pause\",\"guide_flash_composed\":\"Just composed\",\"guide_resume\":\"Resume\",\"guide_cta_build\":\"Start building\",\"guide_cta_connect\":\"Open the catalog\",\"guide_step_label\":\"Step {current} of {total}\",\"guide_goto_step\":\"Go to step {step}\",\"guide_click_hint\":\"Click the highlighted area to continue\",\"decision_title\":\"Athena needs a decision\",\"decision_explain\":\"Explain / recommend\",\"decision_explain_hint\":\"Hear my recommendation, then choose\",\"decision_composing\":\"Composing the full picture in your Cockpit…\",\"decision_compose_failed\":\"Couldn't compose the visual explanation — the recommendation above still stands.\",\"orb_composing_explanation\":\"Preparing an explanation…\",\"decision_recommend_prefix\":\"My recommendation\",\"decision_dismiss\":\"Hide decision\",\"decision_approve\":\"Approve\",\"decision_reject\":\"Reject\",\"decision_resolve\":\"Resolve\",\"decision_open\":\"Open\",\"decision_mark_read\":\"Mark read\",\"decision_recommend_read\":\"This one's worth your personal read — open it when you can.\",\"decision_recommend_approve\":\"This looks low-risk — I'd approve it.\",\"decision_recommend_review\":\"I'd take a closer look before approving this one.\",\"decision_recommend_resolve\":\"This is blocking work — I'd resolve it now.\",\"decision_recommend_review_open\":\"Open the review to see the full context before deciding.\",\"fleet_auto_decided\":\"Athena sent a follow-up\",\"fleet_auto_decided_to\":\"Athena → {project}\",\"fleet_auto_decided_dismiss\":\"Dismiss\",\"guide_pc_title\":\"Creating a persona\",\"guide_pc_intro\":\"Let me walk you through creating a persona — watch where I point.\",\"guide_pc_open\":\"This is the build studio, where every persona starts.\",\"guide_pc_compose\":\"This is where you begin — tap the center and describe, in plain language, what you want your persona to do.\",\"guide_pc_autonomous\":\"Prefer hands-off? Flip this on and I'll design, build, and test the whole persona myself.\",\"guide_pc_outro\":\"That's the whole flow. Want me to build one for you now?\",\"guide_conn_title\":\"Connecting a service\",\"guide_conn_intro\":\"Want to connect a service like GitHub or Slack? Let me show you where.\",\"guide_conn_vault\":\"This is your Vault — every connection lives here, with its secrets encrypted on your device.\",\"guide_conn_add\":\"Open \\\"Add new\\\" and my setup wizard walks you through any connector: paste a key or sign in, and it's ready to use.\",\"guide_conn_outro\":\"That's it. Once a credential is here, pin it to a persona and it can use the service.\",\"guide_trig_title\":\"Creating a trigger\",\"guide_trig_intro\":\"Triggers are how your agents react on their own. Let me show you where they live.\",\"guide_trig_hub\":\"This is your Events hub — a trigger links a signal (a message, a schedule, a webhook) to the agent that should respond.\",\"guide_trig_create\":\"The Builder lays your routes out visually. Connect a source to an agent here, and it fires whenever that event arrives.\",\"guide_trig_outro\":\"That's the idea — wire a source to an agent and it runs itself. Want to open the Builder?\",\"guide_tmpl_title\":\"Adopting a template\",\"guide_tmpl_intro\":\"Don't want to build from scratch? Templates are ready-made agents and teams. Let me show you.\",\"guide_tmpl_gallery\":\"This is the catalog — each card is a complete, pre-designed agent or team you can make your own.\",\"guide_tmpl_adopt\":\"Hit Adopt to copy a template into your workspace. A short questionnaire then tailors it to your tools and goals.\",\"guide_tmpl_outro\":\"That's it — adopt, answer a few questions, and you have a working agent in minutes.\",\"guide_inc_title\":\"Triaging an incident\",\"guide_inc_intro\":\"When something goes wrong, it doesn't get lost — it becomes an incident. Let me show you where.\",\"guide_inc_inbox\":\"When an agent or I flag a problem, it lands in this inbox, ranked by severity so the urgent ones rise to the top.\",\"guide_inc_row\":\"Open an incident to see what happened and resolve it — I'll often suggest the fix right there.\",\"guide_inc_outro\":\"That's triage: spot it here, open it, resolve it. Nothing slips through.\",\"guide_goal_title\":\"Setting up goals & KPIs\",\"guide_goal_intro\":\"Goals are the outcomes your teams pursue; KPIs are how you measure them. Let me show you both.\",\"guide_goal_board\":\"These are your goals — each one an outcome a team is working toward, tracked from idea to done.\",\"guide_goal_card\":\"Each goal is a card. Open it to track progress, check off milestones, and see which agent owns it.\",\"guide_goal_kpi\":\"KPIs are the metrics your goals move. Link a goal to a KPI so progress is measured, not guessed.\",\"guide_goal_outro\":\"That's the loop: set a goal, bind it to a KPI, and watch the number move. Want to set one up?\",\"guide_cta_create_trigger\":\"Open the Builder\",\"guide_cta_setup_goal\":\"Set up a goal\",\"offer_intro\":\"I can help you create that persona.\",\"offer_build\":\"Build it for me\",\"offer_build_hint\":\"I'll generate, test, and fix it automatically.\",\"offer_show\":\"Show me how to build it\",\"offer_show_hint\":\"I'll walk you through the studio step by step.\",\"walkthrough_offer_intro\":\"Want me to show you, or just explain?\",\"walkthrough_offer_show\":\"Show me\",\"walkthrough_offer_show_hint\":\"I'll walk your screen through it, step by step.\",\"walkthrough_offer_tell\":\"Just tell me\",\"walkthrough_offer_tell_hint\":\"I'll explain it here in chat.\",\"walkthrough_offer_tell_prompt\":\"Just explain {topic} in chat — I don't need the guided tour.\",\"stt_title\":\"Voice input\",\"stt_desc\":\"Choose how Athena hears you when you hold to talk.\",\"stt_engine_browser\":\"Browser\",\"stt_engine_browser_caption\":\"Built-in, zero setup. Audio is sent to the system speech service.\",\"stt_engine_whisper\":\"Local Whisper\",\"stt_engine_whisper_caption\":\"On-device transcription. Audio never leaves your machine.\",\"stt_browser_disclosure\":\"On this platform, browser dictation streams your microphone audio to the OS vendor's cloud speech service. Switch to Local Whisper to keep audio on-device.\",\"stt_engine_status_title\":\"Whisper engine\",\"stt_engine_status_desc\":\"Local transcription needs the whisper.cpp binary installed.\",\"stt_installed\":\"Engine installed\",\"stt_not_installed\":\"Engine not installed\",\"stt_install_hint\":\"Drop the whisper.cpp binary at the path below (or set PERSONAS_WHISPER_BIN). Get a build from github.com/ggerganov/whisper.cpp/releases.\",\"stt_models_title\":\"Models\",\"stt_models_desc\":\"Download a model, then select it. Smaller = faster; larger = more accurate.\",\"stt_model_select\":\"Use\",\"stt_model_selected\":\"In use\",\"stt_model_download\":\"Download\",\"stt_model_downloading\":\"Downloading…\",\"stt_model_delete\":\"Delete model\",\"stt_model_failed\":\"Retry\",\"stt_loading\":\"Loading…\",\"stt_refresh\":\"Re-check\",\"panel_label\":\"Athena — companion panel\",\"initializing\":\"Waking up…\",\"init_failed\":\"Athena couldn't initialize\",\"hello_phase_zero\":\"I'm here, but I haven't found my voice yet — chat lands in the next phase.\",\"brain_at\":\"Brain seeded at\",\"empty_transcript\":\"Say something. I'm listening.\",\"welcome_title\":\"How can I help today?\",\"welcome_subtitle\":\"I can see your agents, executions, and memory. Ask me anything, or start with one of these.\",\"jump_to_latest\":\"Jump to latest\",\"day_today\":\"Today\",\"day_yesterday\":\"Yesterday\",\"you_label\":\"You\",\"copy_conversation\":\"Copy conversation\",\"thinking\":\"Thinking…\",\"phase_reviewing\":\"Reviewing result…\",\"phase_thinking\":\"Thinking…\",\"phase_websearch\":\"Searching the web…\",\"phase_webfetch\":\"Fetching a page…\",\"phase_reading\":\"Reading files…\",\"phase_searching_code\":\"Searching the code…\",\"phase_editing\":\"Editing files…\",\"phase_running_command\":\"Running a command…\",\"phase_subagent\":\"Asking a subagent…\",\"phase_using_tool\":\"Using {tool}…\",\"phase_responding\":\"Composing reply…\",\"voice_progress_ack\":\"One moment…\",\"voice_progress_working\":\"Still working on it…\",\"ops_thread_label\":\"Plan\",\"ops_status_pending\":\"To do\",\"ops_status_in_progress\":\"In progress\",\"ops_status_completed\":\"Done\",\"narration_trail_label\":\"What I did\",\"narration_steps_one\":\"{count} step\",\"narration_steps_other\":\"{count} steps\",\"narration_earlier\":\"+{count} earlier\",\"narration_show\":\"Show the activity trail\",\"narration_hide\":\"Hide the activity trail\",\"narration_live_label\":\"Live activity\",\"slow_progress_soft\":\"Still working — taking a bit longer than usual.\",\"slow_progress_firm\":\"Athena is still working. Click the stop button if you'd like to interrupt.\",\"stop_turn\":\"Stop this reply\",\"recall_empty\":\"Athena spoke from a blank brain this turn.\",\"recall_episodes_only\":\"Athena replayed {count} recent turns.\",\"recall_memories_only\":\"Athena consulted {count} memories.\",\"recall_combined\":\"Athena replayed {episodes} recent turns and consulted {memories} memories.\",\"recall_synthesized_badge\":\"synthesized\",\"recall_synthesized_tooltip\":\"Raw recall was over budget — Athena summarized it into a focused briefing before responding.\",\"recall_group_doctrine\":\"Docs\",\"recall_group_facts\":\"Facts\",\"recall_group_procedurals\":\"Rules\",\"recall_group_goals\":\"Goals\",\"recall_group_backlog\":\"Backlog\",\"recall_open_in_brain\":\"Open in Brain Viewer: {title}\",\"turn_summary_label\":\"What Athena did this turn\",\"turn_summary_nav\":\"navigated\",\"turn_summary_approval\":\"approval\",\"turn_summary_lab\":\"opened lab\",\"turn_summary_dashboard\":\"composed dashboard\",\"turn_summary_cockpit\":\"composed cockpit\",\"turn_summary_card\":\"inline card\",\"turn_summary_continuation\":\"will continue autonomously\",\"turn_summary_jump_to\":\"Jump to: {label}\",\"refine_label\":\"Refine the previous turn\",\"refine_shorter\":\"Shorter\",\"refine_shorter_suffix\":\" — much shorter, please.\",\"refine_more_detail\":\"More detail\",\"refine_more_detail_suffix\":\" — go deeper, with examples.\",\"refine_code_only\":\"Code only\",\"refine_code_only_suffix\":\" — code only, minimal prose.\",\"connector_call_queued\":\"queued\",\"connector_call_running\":\"running…\",\"connector_call_completed\":\"done\",\"connector_call_failed\":\"failed\",\"task_status_queued\":\"queued\",\"task_status_running\":\"running\",\"task_status_done\":\"done\",\"task_status_failed\":\"failed\",\"tasks_running_one\":\"{count} task running\",\"tasks_running_other\":\"{count} tasks running\",\"queued_badge\":\"Queued\",\"queued_remove\":\"Remove queued message\",\"connector_call_in_flight_hint\":\"Athena will summarize the result when this finishes.\",\"connector_call_retry\":\"Retry\",\"connector_call_retrying\":\"Retrying…\",\"connector_call_retried\":\"Retried · new job {id}\",\"connector_call_retry_failed\":\"Retry failed: {message}\",\"connector_call_retry_waiting\":\"Waiting for status…\",\"read_aloud\":\"Read aloud\",\"read_aloud_synthesizing\":\"Synthesizing…\",\"read_aloud_stop\":\"Stop\",\"read_aloud_failed\":\"Read-aloud failed\",\"autonomous_toggle_on\":\"Enable autonomous mode (Athena keeps working between turns)\",\"autonomous_toggle_off\":\"Disable autonomous mode\",\"composer_placeholder\":\"Talk to Athena. Enter to send, Shift+Enter for a new line.\",\"slash_palette_heading\":\"Quick prompts\",\"slash_palette_empty\":\"No matches. Esc to clear.\",\"slash_label_intake\":\"Get to know me\",\"slash_message_intake\":\"Let's do the intake — ask me a few things so you understand how I work, then save what you learn to your identity notes (with my approval).\",\"slash_label_goals\":\"Show goals\",\"slash_message_goals\":\"Show me my current goals.\",\"slash_label_queued\":\"What's queued\",\"slash_message_queued\":\"What jobs are queued right now?\",\"slash_label_decisions\":\"Recent decisions\",\"slash_message_decisions\":\"Read back the recent design decisions.\",\"slash_label_live_ops\":\"Live ops\",\"slash_message_live_ops\":\"What's running in the fleet right now?\",\"slash_label_memory_recap\":\"Memory recap\",\"slash_message_memory_recap\":\"What do you remember about me?\",\"slash_label_capabilities\":\"What can you do?\",\"slash_message_capabilities\":\"What can Athena do?\",\"send\":\"Send\",\"reset\":\"Reset conversation\",\"compact_toggle_collapse\":\"Shrink panel to half width\",\"compact_toggle_expand\":\"Restore full panel width\",\"refresh_doctrine\":\"Refresh Athena's docs\",\"doctrine_up_to_date\":\"Athena's docs are up to date.\",\"doctrine_refreshed\":\"Athena's docs refreshed\",\"doctrine_refresh_failed\":\"Athena's docs refresh failed\",\"proposed_action\":\"Athena proposes\",\"action_params\":\"Parameters\",\"approve\":\"Approve\",\"approved_failed\":\"Approved, but execution failed: {message}\",\"reject\":\"Reject\",\"toolbar_label\":\"Quick actions\",\"help_capabilities\":\"What can Athena do?\",\"analyze_fleet\":\"Analyze the fleet\",\"analyze_fleet_started\":\"Fleet analysis started — Athena is reviewing the teams.\",\"daily_brief\":\"Daily brief\",\"daily_brief_started\":\"Daily brief started — Athena is reviewing your last 24h.\",\"voice_enable\":\"Enable spoken summaries\",\"voice_disable\":\"Mute spoken summaries\",\"voice_controls_label\":\"Voice controls\",\"voice_controls_title\":\"Voice\",\"voice_volume_label\":\"Volume\",\"voice_test\":\"Test voice\",\"voice_test_synthesizing\":\"Synthesizing…\",\"voice_test_stop\":\"Stop\",\"voice_test_unconfigured\":\"Configure a voice in the Voice tab to test.\",\"voice_test_sentence\":\"Hello, I am Athena, your personal AI assistant.\",\"play_latest\":\"Play Athena's latest spoken summary\",\"play_nothing\":\"Nothing new to play\",\"brain_open\":\"Open Athena's brain\",\"brain_title\":\"Brain\",\"brain_loading\":\"Loading…\",\"brain_empty\":\"Nothing here yet.\",\"brain_empty_hint\":\"This memory fills in as you and Athena work together.\",\"brain_empty_ask_cta\":\"Ask Athena\",\"brain_empty_ask_prompt\":\"Help me build out your {kind} memory — what belongs here, and can we add the first entry now?\",\"brain_empty_consolidation_started\":\"Consolidation started — review the proposals in the Memory tab.\",\"brain_empty_placeholder\":\"(empty)\",\"brain_one_item\":\"1 item\",\"brain_items\":\"items\",\"brain_desc_identity\":\"Who Athena understands you to be — role, preferences, working style.\",\"brain_desc_facts_user\":\"Durable facts about you she's learned and reuses across sessions.\",\"brain_desc_facts_project\":\"What she knows about your active projects and their context.\",\"brain_desc_facts_world\":\"General facts not tied to you or a specific project.\",\"brain_desc_goals\":\"What you're working toward, so she can track progress.\",\"brain_desc_backlog\":\"Ideas and tasks parked for later.\",\"brain_desc_procedurals\":\"Repeatable how-to steps she's agreed to follow.\",\"brain_desc_rituals\":\"Recurring routines she runs on a cadence.\",\"brain_desc_episodes\":\"A log of past conversations she can replay.\",\"brain_desc_reflections\":\"Periodic self-summaries of what she's learned.\",\"brain_desc_design_decisions\":\"Choices made while designing personas, with rationale.\",\"brain_desc_doctrine\":\"Reference guides that shape how she reasons.\",\"brain_desc_constitution\":\"Her core operating contract — the rules she won't break.\",\"brain_delete\":\"Delete\",\"brain_deleting\":\"Deleting…\",\"identity_edit\":\"Edit\",\"identity_save\":\"Save\",\"identity_saving\":\"Saving…\",\"identity_edit_hint\":\"You're the editor of record — Athena proposes changes for your approval.\",\"identity_adapts_title\":\"What Athena adapts\",\"identity_adapts_row\":\"{kind}: {base} → {eff} cards/day · {engaged} engaged, {dismissed} dismissed (30d)\",\"identity_correct_title\":\"Correct a claim\",\"identity_correct_hint\":\"Mark anything Athena got wrong — she'll propose removing it for your approval, and learn from the correction.\",\"identity_wrong\":\"That's wrong\",\"identity_wrong_proposed\":\"Proposed\",\"brain_linked_label\":\"Linked memories\",\"episodes\":\"Episodes\",\"doctrine\":\"Doctrine\",\"identity\":\"Identity\",\"constitution\":\"Constitution\",\"facts\":\"Facts\",\"facts_user\":\"About you\",\"facts_project\":\"Projects\",\"facts_world\":\"World\",\"facts_subtitle\":\"Distilled facts with source episodes — the anti-hallucination contract.\",\"facts_open_source\":\"Open source episode\",\"facts_imp_label\":\"Importance\",\"facts_conf_label\":\"Confidence\",\"facts_sources_label\":\"Sources\",\"facts_superseded\":\"Superseded — no longer wins retrieval.\",\"facts_empty_user\":\"No facts about you yet. Try asking Athena to remember something specific.\",\"facts_empty_project\":\"No project facts yet.\",\"facts_empty_world\":\"No world facts yet.\",\"procedurals\":\"Procedurals\",\"procedurals_chat\":\"Chat\",\"procedurals_action\":\"Action\",\"procedurals_memory\":\"Memory\",\"procedurals_build\":\"Build\",\"goals\":\"Goals\",\"goals_active\":\"Active\",\"rituals\":\"Rituals\",\"backlog\":\"Backlog\",\"backlog_self_promise\":\"Self-promises\",\"backlog_capability_gap\":\"Capability gaps\",\"dev_scan_codebase\":\"Scan codebase\",\"dev_generate_ideas\":\"Generate ideas\",\"dev_queue_tasks\":\"Queue tasks\",\"dev_projects_state\":\"Projects state\",\"dev_tools_enable\":\"Enable Dev Tools\",\"dev_tools_disable\":\"Disable Dev Tools\",\"connectors_add\":\"Attach a connector\",\"connectors_section_label\":\"Connectors\",\"connectors_remove_from_sidebar\":\"Remove from sidebar\",\"connector_state_enabled\":\"enabled\",\"connector_state_disabled\":\"disabled\",\"connector_action_enable\":\"click to enable\",\"connector_action_disable\":\"click to disable\",\"connector_right_click_menu_hint\":\"right-click for menu\",\"setup_memory_title\":\"Memory & retrieval\",\"setup_memory_desc\":\"Tune how Athena recalls and shapes context for each turn.\",\"setup_recall_synthesis_label\":\"Recall synthesis (beta)\",\"setup_recall_synthesis_desc\":\"On dense-recall turns, fold the brain's retrieved chunks through a Claude call into a focused briefing. Adds ~1 extra Claude call on qualifying turns; below the budget threshold no synthesis fires.\",\"setup_hands_free_decisions_label\":\"Hands-free decisions\",\"setup_hands_free_decisions_desc\":\"Surface pending approvals, reviews, and blocking incidents as numbered choices above the orb, one at a time.\",\"setup_desktop_title\":\"Desktop awareness (beta)\",\"setup_desktop_desc\":\"What Athena observes from your desktop. All sources start off. Disabling a source also clears what was already captured.\",\"setup_desktop_clipboard_label\":\"Clipboard activity\",\"setup_desktop_clipboard_desc\":\"Track when the clipboard changes (metadata only — content not stored).\",\"setup_desktop_file_changes_label\":\"File changes\",\"setup_desktop_file_changes_desc\":\"Watch declared work-roots for create / modify / delete events.\",\"setup_desktop_app_focus_label\":\"App focus\",\"setup_desktop_app_focus_desc\":\"Track which app is in the foreground. Window titles redacted at capture (paths reduced to filenames, emails masked).\",\"setup_desktop_cli_session_label\":\"Active Claude CLI session\",\"setup_desktop_cli_session_desc\":\"Let opted-in personas read your most recent Claude CLI conversation as prompt context. Per-persona opt-in required; only the last 8 turns are extracted, capped to 500 chars each.\",\"sensory_cli_reads_empty\":\"No CLI session reads yet.\",\"sensory_cli_reads_empty_hint\":\"When a persona with CLI awareness opted in fires while you're mid-conversation in `claude`, the read will be logged here.\",\"sensory_cli_reads_row_summary\":\"{persona} read {count} turns\",\"setup_desktop_signals_count_one\":\"{count} signal in the rolling window\",\"setup_desktop_signals_count_other\":\"{count} signals in the rolling window\",\"setup_desktop_load_failed\":\"Couldn't load desktop-awareness state — check the backend connection.\",\"setup_desktop_view_signals\":\"What did Athena see?\",\"sensory_signals_title\":\"What did Athena see?\",\"sensory_signals_subtitle\":\"Recent signals captured by the desktop-awareness watchers. Delete any row to remove it from Athena's rolling window.\",\"sensory_signals_empty\":\"Nothing captured yet.\",\"sensory_signals_empty_hint\":\"Either the watchers are off (Setup → Desktop awareness) or the rolling window is empty.\",\"sensory_signals_delete_aria\":\"Delete this signal\",\"sensory_signals_redacted_label\":\"Captured preview (credentials masked)\",\"sensory_filter_all\":\"All sources\",\"sensory_age_seconds\":\"{n}s ago\",\"sensory_age_minutes\":\"{n}m ago\",\"sensory_age_hours\":\"{n}h ago\",\"proactive_label\":\"Athena reached out\",\"proactive_engage\":\"Engage\",\"proactive_dismiss\":\"Not now\",\"footer_notice_analysis_completed\":\"Analysis completed\",\"footer_notice_proactive_default\":\"Athena has a note for you\",\"footer_notice_dismiss\":\"Dismiss\",\"proactive_kind_goal\":\"goal target approaching\",\"proactive_kind_backlog\":\"aging promise\",\"proactive_kind_cadence\":\"cadence check-in\",\"proactive_kind_on_this_day\":\"on this day\",\"proactive_kind_athena_scheduled\":\"scheduled by Athena\",\"proactive_kind_ambient_match\":\"context cue\",\"proactive_kind_fleet_failed\":\"fleet session failed\",\"proactive_kind_fleet_awaiting\":\"fleet session waiting\",\"proactive_kind_fleet_stale\":\"fleet session idle\",\"proactive_kind_fleet_stuck_dispatched\":\"dispatched session stuck\",\"proactive_kind_fleet_op_completed\":\"fleet operation done\",\"proactive_kind_incident_blocker\":\"incident needs attention\",\"proactive_kind_execution_review\":\"execution review digest\",\"proactive_kind_message_digest\":\"message inbox digest\",\"proactive_kind_daily_rollup\":\"daily rollup\",\"proactive_kind_unknown\":\"Athena reached out\",\"action_label_run_persona\":\"Run persona\",\"action_label_resolve_human_review\":\"Resolve review\",\"action_label_update_identity\":\"Update identity\",\"action_label_write_fact\":\"Remember a fact\",\"action_label_delete_fact\":\"Forget a fact\",\"action_label_write_procedural\":\"Add behavior rule\",\"action_label_delete_procedural\":\"Remove behavior rule\",\"action_label_write_goal\":\"Track a goal\",\"action_label_update_goal_status\":\"Update goal status\",\"action_label_delete_goal\":\"Remove goal\",\"action_label_write_ritual\":\"Add a ritual\",\"action_label_set_ritual_active\":\"Toggle ritual\",\"action_label_delete_ritual\":\"Remove ritual\",\"action_label_write_backlog_item\":\"Add to backlog\",\"action_label_resolve_backlog_item\":\"Resolve backlog item\",\"action_label_prefill_persona_create\":\"Start a persona\",\"action_label_build_oneshot\":\"Build a persona autonomously\",\"action_label_run_arena\":\"Run arena comparison\",\"action_label_compose_dashboard\":\"Compose dashboard\",\"action_label_compose_cockpit\":\"Compose cockpit\",\"action_label_register_project\":\"Register project\",\"action_label_enqueue_dev_job\":\"Queue a scan\",\"action_label_schedule_proactive\":\"Schedule a check-in\",\"action_label_fleet_send_input\":\"Send to a fleet session\",\"action_label_fleet_broadcast\":\"Broadcast to fleet\",\"action_label_fleet_kill\":\"Close fleet session\",\"action_label_fleet_spawn\":\"Spawn fleet session\",\"action_label_fleet_dispatch\":\"Dispatch fleet operation\",\"action_label_fleet_intervene\":\"Intervene in fleet session\",\"action_label_fleet_redirect_op\":\"Redirect fleet operation\",\"action_label_unknown_fallback\":\"Athena's proposal\",\"capability_label_list_issues\":\"Recent issues\",\"capability_label_get_issue\":\"Issue details\",\"capability_label_list_repos\":\"Your repositories\",\"capability_label_list_open_prs\":\"Open pull requests\",\"capability_label_list_channels\":\"Your channels\",\"capability_label_list_recent_threads\":\"Recent emails\",\"connector_label_sentry\":\"Sentry\",\"connector_label_github\":\"GitHub\",\"connector_label_gmail\":\"Gmail\",\"connector_label_google_workspace\":\"Google Workspace\",\"connector_label_slack\":\"Slack\",\"brain_kind_unknown_fallback\":\"Memory\",\"chat_card_unknown_kind\":\"Athena tried to show a card we don't recognize yet — looks like something updated. Reopen the chat to refresh.\",\"persona_overview_empty\":\"No personas yet\",\"persona_overview_open_in_editor\":\"Open in editor\",\"persona_overview_hover_hint\":\"Hover a node to inspect it. Click to open in the editor.\",\"persona_overview_eyebrow_default\":\"Featured persona\",\"persona_overview_open_short\":\"Open\",\"persona_overview_view_full\":\"View full persona\",\"persona_overview_kpi_trust\":\"Trust\",\"persona_overview_kpi_trust_clamped\":\"Trust score was out of range and has been capped at 100%.\",\"persona_overview_kpi_budget\":\"Budget\",\"persona_overview_kpi_max_turns\":\"Max turns\",\"persona_overview_active_relative\":\"active {when}\",\"persona_overview_rest_heading\":\"Also in your roster\",\"persona_overview_next_steps_label\":\"Next steps\",\"persona_overview_next_step_pick_label\":\"Pick\",\"persona_overview_next_step_pick_body\":\"Tap a persona to open it in the editor.\",\"persona_overview_next_step_tune_label\":\"Tune\",\"persona_overview_next_step_tune_body\":\"Adjust model tier, budget, or trust score.\",\"persona_overview_next_step_run_label\":\"Run\",\"persona_overview_next_step_run_body\":\"Send a real input from the play surface.\",\"walkthrough_title\":\"Persona-design walkthrough\",\"walkthrough_empty\":\"Athena's walkthrough card was empty — try asking again with more specifics.\",\"browser_report_title\":\"Browser test report\",\"browser_report_steps\":\"Steps\",\"browser_report_defects\":\"Defects\",\"browser_report_console\":\"Console errors\",\"browser_report_security\":\"Security notes\",\"browser_report_empty\":\"The browser-test report card was empty.\",\"browser_report_file_ideas\":\"File as ideas\",\"browser_report_filed\":\"Filed {count} into Idea Triage\",\"browser_report_uat_passed\":\"UAT passed — goal accepted\",\"browser_bridge_title\":\"Browser testing\",\"browser_bridge_desc\":\"Pair the Athena Browser Bridge extension so live tests run in your own Chrome. Without it, tests fall back to a bundled browser.\",\"browser_bridge_status_connected\":\"Extension connected\",\"browser_bridge_status_disconnected\":\"No extension connected\",\"browser_bridge_port\":\"Bridge port\",\"browser_bridge_token\":\"Pairing token\",\"browser_bridge_regenerate\":\"Regenerate\",\"browser_bridge_env_override\":\"The token is set by the PERSONAS_BROWSER_BRIDGE_TOKEN environment variable for this run — the stored token is inactive.\",\"browser_bridge_hint\":\"Load the extension from tools/athena-browser-extension (chrome://extensions → Load unpacked), then paste the port and token into its options page.\",\"walkthrough_commit_hint\":\"Ready to build it?\",\"walkthrough_commit_button\":\"Build from this\",\"pin_to_cockpit\":\"Pin to cockpit\",\"pin_to_cockpit_pinned\":\"Pinned\",\"pin_to_cockpit_success\":\"Widget pinned to your cockpit.\",\"template_suggestions_title\":\"Templates worth a look\",\"template_suggestions_loading\":\"Looking for matching templates…\",\"template_suggestions_empty\":\"No templates in your gallery match this intent yet. Worth a build from scratch — or browse the full catalog.\",\"template_suggestions_open_browse\":\"Browse the full template gallery\",\"use_case_set_title\":\"Proposed use cases\",\"use_case_set_empty\":\"Athena's use-case proposal was empty — try asking again.\",\"use_case_set_role_golden\":\"golden path\",\"use_case_set_role_variant\":\"variant\",\"use_case_set_role_out_of_scope\":\"out of scope\",\"trigger_set_title\":\"Proposed triggers\",\"trigger_set_empty\":\"Athena's trigger proposal was empty — try asking again.\",\"trigger_set_condition\":\"Fires when\",\"trigger_set_grain\":\"Grain\",\"trigger_set_idempotency\":\"Idempotency\",\"model_tier_title\":\"Model tier choice\",\"model_tier_empty\":\"Athena's tier comparison was empty — try asking again.\",\"model_tier_haiku\":\"Haiku\",\"model_tier_sonnet\":\"Sonnet\",\"model_tier_opus\":\"Opus\",\"model_tier_recommended_badge\":\"recommended\",\"observability_plan_title\":\"Observability plan\",\"observability_plan_empty\":\"Athena's observability plan was empty — try asking again.\",\"observability_plan_error_path\":\"Error path\",\"observability_plan_escalation\":\"Escalates to\",\"observability_plan_success_metric\":\"Success metric\",\"observability_plan_target\":\"Target\",\"observability_metric_count_by_status\":\"Count by status\",\"observability_metric_cost_per_run\":\"Cost per run\",\"observability_metric_latency\":\"Latency\",\"observability_metric_custom\":\"Custom metric\",\"decision_log_title\":\"Decision log\",\"decision_log_empty\":\"Athena hasn't logged any decisions for this conversation yet.\",\"decision_log_persisted_badge\":\"Saved\",\"decision_log_persisted_tooltip\":\"Athena writes every decision_log entry into companion_design_decision so the audit trail survives session reloads.\",\"persona_ready_title\":\"Persona ready to build\",\"persona_ready_empty\":\"Athena's recap needs at least a refined intent line. Try asking again.\",\"persona_ready_intent_label\":\"Refined intent\",\"persona_ready_prompt_outline\":\"Prompt outline\",\"persona_ready_use_cases\":\"Use cases\",\"persona_ready_triggers\":\"Triggers\",\"persona_ready_model_tier\":\"Model tier\",\"persona_ready_observability\":\"Observability\",\"persona_ready_hint_interactive\":\"Build with the standard ask-the-user gate flow.\",\"persona_ready_hint_oneshot\":\"Athena will decide everything and ping you when done.\",\"persona_ready_hint_template\":\"Open the template gallery to pick a starter.\",\"persona_ready_commit_interactive\":\"Build interactively\",\"persona_ready_commit_oneshot\":\"Build now (one-shot)\",\"persona_ready_commit_template\":\"Open template gallery\",\"design_cap_title\":\"What I can help you design\",\"design_cap_example_prefix\":\"Try:\",\"design_cap_walkthrough_label\":\"Design walkthrough\",\"design_cap_walkthrough_behavior\":\"I sketch the full plan: intent line, prompt outline, use cases, tools, triggers, tier, observability.\",\"design_cap_walkthrough_example\":\"\\\"Help me design a persona that triages support tickets.\\\"\",\"design_cap_templates_label\":\"Template suggestions\",\"design_cap_templates_behavior\":\"I match your intent against the template gallery and surface the top 3 starters.\",\"design_cap_templates_example\":\"\\\"What templates match this idea?\\\"\",\"design_cap_use_cases_label\":\"Use case decomposition\",\"design_cap_use_cases_behavior\":\"I propose 3-5 use cases tagged golden / variant / out-of-scope so coverage is glanceable.\",\"design_cap_use_cases_example\":\"\\\"What use cases would this persona need?\\\"\",\"design_cap_triggers_label\":\"Trigger set\",\"design_cap_triggers_behavior\":\"I propose 1-4 trigger configurations with source, condition, grain, idempotency notes.\",\"design_cap_triggers_example\":\"\\\"How should this persona be triggered?\\\"\",\"design_cap_tier_label\":\"Model tier choice\",\"design_cap_tier_behavior\":\"I compare Haiku / Sonnet / Opus for this intent and recommend one with rationale.\",\"design_cap_tier_example\":\"\\\"What model tier fits this persona?\\\"\",\"design_cap_observability_label\":\"Observability plan\",\"design_cap_observability_behavior\":\"I draft the error-handling and success-metric pair so this persona never black-holes.\",\"design_cap_observability_example\":\"\\\"What observability does this persona need?\\\"\",\"design_cap_decision_log_label\":\"Decision log\",\"design_cap_decision_log_behavior\":\"I track design choices during the conversation as a timeline so you can retrace later.\",\"design_cap_decision_log_example\":\"\\\"Show me what we've decided so far.\\\"\",\"design_cap_ready_label\":\"Ready-to-build recap\",\"design_cap_ready_behavior\":\"I roll everything into one build-ready card with a commit button when the design is locked in.\",\"design_cap_ready_example\":\"\\\"Are we ready to build this?\\\"\",\"recent_decisions_title\":\"You recently decided\",\"recent_decisions_loading\":\"Loading recent decisions…\",\"proactive_evaluate_now\":\"Check for nudges\",\"memory_run_consolidation\":\"Run consolidation\",\"memory_generate_reflection\":\"Generate reflection\",\"memory_decay_unused\":\"Decay unused facts\",\"memory_low_importance\":\"Show low-importance\",\"memory_show_all\":\"Show all\",\"memory_bulk_actions_title\":\"Bulk actions\",\"memory_bulk_actions_desc\":\"Manual maintenance passes that distill conversations into long-lived facts and surface patterns.\",\"consolidation_running\":\"Distilling recent conversations…\",\"consolidation_running_long\":\"Athena is reviewing your recent conversations and proposing fact updates. This usually takes 1-3 minutes.\",\"consolidation_failed\":\"Consolidation failed\",\"consolidation_review_title\":\"Review proposals\",\"consolidation_review_subtitle\":\"Each row is a proposed fact. Approve, reject, or edit before it lands in memory.\",\"consolidation_no_proposals\":\"Athena had nothing new to consolidate from this batch.\",\"consolidation_summary_label\":\"Pass summary\",\"consolidation_episodes_label\":\"Episodes reviewed\",\"consolidation_kind_add\":\"Add\",\"consolidation_kind_update\":\"Update\",\"consolidation_kind_contradict\":\"Conflict\",\"consolidation_apply\":\"Apply\",\"consolidation_apply_edits\":\"Apply with edits\",\"consolidation_reject\":\"Reject\",\"consolidation_applied\":\"Applied\",\"consolidation_rejected\":\"Rejected\",\"consolidation_supersedes\":\"Supersedes\",\"consolidation_rationale\":\"Athena's reasoning\",\"consolidation_edit\":\"Edit\",\"consolidation_done\":\"Done reviewing\",\"consolidation_back_to_runs\":\"Back to runs\",\"consolidation_runs_title\":\"Past consolidation runs\",\"consolidation_run_status_review\":\"Awaiting review\",\"consolidation_run_status_applied\":\"Reviewed\",\"consolidation_run_status_failed\":\"Failed\",\"consolidation_run_status_running\":\"Running\",\"reflections\":\"Reflections\",\"design_decisions\":\"Design decisions\",\"reflection_running\":\"Generating reflection…\",\"reflection_failed\":\"Reflection failed\",\"decay_done\":\"{{count}} facts decayed.\",\"decay_none\":\"No facts old enough to decay yet.\",\"page_title\":\"Companion (Athena)\",\"page_subtitle\":\"Setup, memory inspector, and voice configuration for your thinking partner.\",\"tab_setup\":\"Setup\",\"tab_memory\":\"Memory\",\"tab_voice\":\"Voice\",\"tab_dashboard\":\"Dashboard\",\"tab_decisions\":\"Decisions\",\"decisions_panel_title\":\"Design decisions\",\"decisions_panel_subtitle\":\"Every design choice Athena has logged across conversations. Filter by persona context to scope.\",\"decisions_panel_filter_placeholder\":\"Filter by persona context…\",\"decisions_panel_loading\":\"Loading decisions…\",\"decisions_panel_empty\":\"Athena hasn't logged any design decisions yet. Ask her to design a persona to see this fill up.\",\"decisions_panel_empty_title\":\"No decisions logged yet\",\"decisions_panel_empty_cta\":\"Ask Athena to log a decision\",\"decisions_panel_empty_prompt\":\"I'd like to log a design decision. Ask me what I chose, the alternatives I weighed, and my reasoning — then record it for me.\",\"decisions_panel_empty_filtered\":\"No decisions match that context. Try a shorter filter or clear it.\",\"decisions_panel_unscoped\":\"Unscoped\",\"decisions_panel_currently_designing\":\"Currently designing\",\"decisions_panel_show_all\":\"Show all\",\"decisions_atlas_contexts\":\"Contexts\",\"decisions_atlas_all_contexts\":\"All contexts\",\"dashboard_empty_title\":\"No dashboard yet\",\"dashboard_empty_body\":\"Ask Athena to compose one for you — for example, \\\"Show me my agent activity for the past week.\\\"\",\"dashboard_unknown_widget\":\"Unknown widget\",\"dashboard_updated_at\":\"Updated {{when}}\",\"loading\":\"Loading…\",\"setup_chrome_title\":\"Chrome\",\"setup_chrome_desc\":\"Where Athena shows up in the app and how she gets your attention.\",\"setup_footer_label\":\"Show footer icon\",\"setup_footer_desc\":\"Display the bot button in the bottom-left footer. When off, the chat panel is unreachable except via this plugin page.\",\"setup_orb_label\":\"Floating avatar\",\"setup_orb_desc\":\"Show Athena as a draggable orb that floats above the app. Tap to open chat, hold to talk, drag to dock anywhere. When off, only the footer button remains.\",\"setup_sound_label\":\"Reply chime\",\"setup_sound_desc\":\"Play a short two-note tone when Athena finishes a reply, even if you've tabbed away.\",\"setup_beta_title\":\"Beta features\",\"setup_beta_desc\":\"Experimental capabilities. Dev mode is toggled from the wrench in the chat header; other flags are read-only here.\",\"voice_title\":\"Voice playback\",\"voice_subtitle\":\"Have Athena speak her replies via ElevenLabs. Configuration only — playback in chat is downstream.\",\"voice_empty_title\":\"No ElevenLabs credential found\",\"voice_empty_desc\":\"Voice playback runs through your own ElevenLabs API key. Add a credential in the vault to continue.\",\"voice_empty_cta\":\"Open credential catalog\",\"voice_credential_title\":\"Credential\",\"voice_credential_desc\":\"Which ElevenLabs key Athena uses for synthesis.\",\"voice_credential_picker_label\":\"ElevenLabs credential\",\"voice_credential_pick\":\"— Pick a credential —\",\"voice_cred_healthy\":\"Verified\",\"voice_cred_unverified\":\"Unverified\",\"voice_id_title\":\"Voice id\",\"voice_id_desc\":\"The ElevenLabs voice id Athena should use. Find it on the ElevenLabs voice library page.\",\"voice_id_label\":\"Voice id\",\"voice_id_hint\":\"21–32 characters, alphanumeric. Each voice has a unique id you can copy from the ElevenLabs library.\",\"voice_enable_title\":\"Enable voice\",\"voice_enable_desc\":\"Master switch for voice playback. Requires a credential and a voice id.\",\"voice_enable_label\":\"Voice playback\",\"voice_enable_on\":\"Athena will speak her replies aloud. Playback wiring lands in a follow-up.\",\"voice_enable_off\":\"Athena replies in text only.\",\"voice_enable_blocked\":\"Pick a credential and enter a voice id first.\",\"voice_pick_title\":\"Voice\",\"voice_pick_desc\":\"Pick from voices scoped to this credential, or list every voice on the account.\",\"voice_pick_loading\":\"Loading voices…\",\"voice_pick_placeholder\":\"— Pick a voice —\",\"voice_pick_no_voices\":\"No voices found on this account.\",\"voice_pick_refresh\":\"Refresh\",\"voice_pick_scoped_hint\":\"Showing voices scoped to this credential. Edit scope from the credential detail to change.\",\"voice_pick_unscoped_hint\":\"No scope set — listing every voice on this account. Add a scope in the credential detail to narrow this down.\",\"voice_pick_custom_toggle\":\"Use a custom voice id\",\"voice_pick_error\":\"Couldn't load voices.\",\"voice_settings_title\":\"Voice settings\",\"voice_settings_desc\":\"Tune model, expressiveness, and pace. Leave on default unless Athena's voice feels off.\",\"voice_settings_model_label\":\"Model\",\"voice_settings_model_hint\":\"Turbo is the fastest and cheapest. Multilingual and v3 are the most expressive but slower.\",\"voice_settings_model_turbo\":\"Turbo v2.5 — fast, cheap (default)\",\"voice_settings_model_flash\":\"Flash v2.5 — fastest, lowest latency\",\"voice_settings_model_multilingual\":\"Multilingual v2 — broader languages\",\"voice_settings_model_v3\":\"v3 — most expressive (premium)\",\"voice_settings_stability_label\":\"Stability\",\"voice_settings_stability_hint\":\"Lower = more emotion and variation. Higher = more consistent and monotone.\",\"voice_settings_similarity_label\":\"Similarity\",\"voice_settings_similarity_hint\":\"How closely Athena should match the picked voice's reference recordings.\",\"voice_settings_speed_label\":\"Speed\",\"voice_settings_speed_hint\":\"Speech rate. Range 0.7–1.2; default speed sits in the middle. Leave at default to omit.\",\"voice_settings_style_label\":\"Style exaggeration\",\"voice_settings_style_hint\":\"Only meaningful on Multilingual v2 and v3. Higher values amplify expressive cues at the cost of consistency.\",\"voice_settings_default\":\"Default\",\"voice_settings_reset\":\"Reset to defaults\",\"voice_engine_title\":\"Engine\",\"voice_engine_desc\":\"Both engines run locally on your machine — no network, no API key — after a one-time install. Kokoro is the polished default; Pocket is experimental and can speak with a voice cloned from your own recording.\",\"voice_engine_elevenlabs\":\"ElevenLabs\",\"voice_engine_elevenlabs_caption\":\"Cloud, premium quality, ~70 languages\",\"voice_engine_kokoro\":\"Kokoro\",\"voice_engine_kokoro_caption\":\"Local, high quality, English\",\"voice_engine_piper\":\"Piper\",\"voice_engine_piper_caption\":\"Local, free, ~17 languages\",\"voice_kokoro_setup_title\":\"Kokoro setup\",\"voice_kokoro_setup_desc\":\"Kokoro synthesizes locally through the sherpa-onnx sidecar. Two one-time installs: the engine binary and the voice model package.\",\"voice_kokoro_engine_label\":\"Engine binary\",\"voice_kokoro_engine_hint\":\"Download the win-x64-shared-MT-Release build of sherpa-onnx and unzip sherpa-onnx-offline-tts.exe (with its onnxruntime DLLs) into the folder below.\",\"voice_kokoro_engine_path_label\":\"Drop the binary here:\",\"voice_kokoro_model_label\":\"Voice model\",\"voice_kokoro_model_hint\":\"Download kokoro-multi-lang-v1_0.tar.bz2, extract it, and put model.onnx + voices.bin + tokens.txt + the espeak-ng-data folder into the folder below.\",\"voice_kokoro_model_path_label\":\"Extract the model here:\",\"voice_kokoro_installed\":\"Installed\",\"voice_kokoro_not_installed\":\"Not installed\",\"voice_kokoro_download\":\"Download\",\"voice_kokoro_copy_url\":\"Copy download link\",\"voice_kokoro_copy_path\":\"Copy path\",\"voice_kokoro_recheck\":\"Re-check\",\"voice_kokoro_install_title\":\"One-click install\",\"voice_kokoro_install_desc\":\"Download and set up Kokoro automatically — about 400 MB (the af_heart English voice).\",\"voice_kokoro_install_button\":\"Download & install\",\"voice_kokoro_install_engine\":\"Downloading engine…\",\"voice_kokoro_install_model\":\"Downloading voice model…\",\"voice_kokoro_install_extract\":\"Extracting…\",\"voice_kokoro_install_failed\":\"Install failed\",\"voice_kokoro_install_manual\":\"Or set it up manually:\",\"voice_kokoro_voices_title\":\"Voices\",\"voice_kokoro_voices_desc\":\"All voices ship in one model — pick one and press Preview to hear it. af_heart is Kokoro's warm, expressive default.\",\"voice_kokoro_preview\":\"Preview\",\"voice_kokoro_preview_stop\":\"Stop\",\"voice_kokoro_preview_blocked\":\"Install the engine + model first\",\"voice_kokoro_enable_blocked\":\"Install the engine + model and pick a voice first\",\"voice_piper_engine_status_title\":\"Piper engine\",\"voice_piper_engine_status_desc\":\"Athena synthesizes with the official Piper binary. Install once; voices download separately below.\",\"voice_piper_engine_installed\":\"Installed\",\"voice_piper_engine_not_installed\":\"Not installed\",\"voice_piper_engine_install_hint_release\":\"Releases at github.com/rhasspy/piper/releases (the Windows zip contains piper.exe + onnxruntime.dll)\",\"voice_piper_engine_install_hint_path_label\":\"Drop the binary here:\",\"voice_piper_engine_recheck\":\"Re-check\",\"voice_piper_voices_title\":\"Voices\",\"voice_piper_voices_desc\":\"Pick a voice and click Download. Models live under ~/.personas/companion-tts/piper/ and are ~7–110 MB depending on quality.\",\"voice_piper_voice_download\":\"Download\",\"voice_piper_voice_select\":\"Select\",\"voice_piper_voice_selected\":\"Selected\",\"voice_piper_voice_redownload\":\"Re-download\",\"voice_piper_voice_delete\":\"Delete\",\"voice_piper_voice_downloading\":\"Downloading…\",\"voice_piper_voice_downloaded\":\"Downloaded\",\"voice_piper_voice_quality_x_low\":\"Compact\",\"voice_piper_voice_quality_low\":\"Low\",\"voice_piper_voice_quality_medium\":\"Medium\",\"voice_piper_voice_quality_high\":\"High\",\"voice_piper_voice_gender_female\":\"Female\",\"voice_piper_voice_gender_male\":\"Male\",\"voice_piper_voice_gender_neutral\":\"Neutral\",\"voice_piper_no_voice_selected\":\"Pick a voice from the list below to enable Piper synthesis.\",\"voice_piper_engine_missing_callout\":\"Install the Piper engine first to use the selected voice.\",\"voice_piper_no_voices_for_language\":\"No Piper voice covers your locale yet. Try a different language or fall back to ElevenLabs.\",\"voice_piper_your_language_badge\":\"Your language\",\"dictate_start\":\"Dictate\",\"dictate_stop\":\"Stop dictation\",\"dictate_start_hint\":\"Press to talk. Audio is sent to the browser's cloud speech-to-text service while listening.\",\"dictate_listening_hint\":\"Listening… click again to stop.\",\"dictate_error\":\"Dictation failed. Check microphone permission and try again.\",\"orchestration\":{\"guidance_from\":\"Guidance request from\",\"guidance_placeholder\":\"Type your answer — claude is paused on this RPC.\",\"guidance_send\":\"Send\",\"approval_from\":\"Approval request from\",\"approval_note_placeholder\":\"Optional note for the session…\",\"approval_approve\":\"Approve\",\"approval_deny\":\"Deny\",\"live_view_title\":\"Live operations\",\"live_view_op_count_one\":\"1 op\",\"live_view_op_count_other\":\"{count} ops\",\"live_view_session_count_one\":\"1 session\",\"live_view_session_count_other\":\"{count} sessions\",\"live_view_intent_label\":\"intent\",\"live_view_checkpoint_label\":\"checkpoint\",\"live_view_blockers_label\":\"blockers\",\"live_view_files_label\":\"files\",\"live_view_files_more\":\"+{count} more\",\"live_view_failure_label\":\"recent failure\",\"live_view_summary_label\":\"summary\",\"batch_pending_label\":\"{count} approvals pending from {session}\",\"batch_approve_all\":\"Approve all\",\"batch_approving\":\"Approving…\"},\"decision_hide\":\"Hide\",\"decision_show\":\"Show decision\",\"decision_minimize\":\"Minimize\",\"decision_expand\":\"Expand\",\"wake_cadence_label\":\"Autonomy cadence\",\"wake_cadence_hint\":\"How long signals accumulate before Athena wakes and handles them in one batch. Blocked teams and urgent messages bypass the timer.\",\"wake_reactive\":\"Reactive\",\"wake_30\":\"30 min\",\"wake_60\":\"1 h\",\"wake_120\":\"2 h\",\"wake_impact_line\":\"24 h: {wakes} wakes · {signals} signals · {calls} calls · {actions} actions\",\"boldness_label\":\"Boldness\",\"boldness_hint\":\"How far Athena acts on her own on a paused CLI before asking you. Cautious = only the obviously-right call; Balanced = she proceeds on reversible next steps and asks on real choices; Bold = she proceeds on both unless she's genuinely unsure. Low confidence always asks.\",\"boldness_cautious\":\"Cautious\",\"boldness_balanced\":\"Balanced\",\"boldness_bold\":\"Bold\",\"dev_toggle_on\":\"Enable dev mode (Athena can work on the Personas app’s own code)\",\"dev_toggle_off\":\"Disable dev mode\",\"dev_ledger_label\":\"Dev ledger\",\"dev_ledger_hint\":\"Every dev_improve run Athena dispatched, with your verdict. The scoreboard tells you whether dev mode is earning its keep — how many dispatches land a commit, how many merge, and your thumbs ratio.\",\"dev_ledger_summary\":\"{total} runs · {landed} committed · {merged} merged\",\"dev_ledger_none\":\"No dev runs yet\",\"dev_ledger_empty\":\"No dev runs yet — Athena logs each dispatch here.\",\"dev_ledger_verdict_up\":\"Good run\",\"dev_ledger_verdict_down\":\"Bad run\",\"dev_status_dispatched\":\"In flight\",\"dev_status_completed\":\"Awaiting merge\",\"dev_status_merged\":\"Merged\",\"dev_status_closed\":\"Applied\",\"dev_status_interrupted\":\"Interrupted\",\"setup_dev_mode_label\":\"Dev mode (self-development)\",\"setup_dev_mode_on\":\"Available in this build. The wrench in the chat header toggles it — when on, Athena resolves feature talk to code and proposes improvement dispatches you approve.\",\"setup_dev_mode_off\":\"Unavailable — requires a development build running from a source checkout.\",\"setup_dev_mode_active\":\"Active\",\"setup_dev_mode_inactive\":\"Off\",\"proactive_kind_dev_interrupted\":\"dev run interrupted\",\"voice_engine_pocket\":\"Pocket\",\"voice_engine_pocket_caption\":\"Local, clones your voice\",\"voice_pocket_setup_title\":\"Pocket TTS service\",\"voice_pocket_setup_desc\":\"Pocket synthesizes through a small local service that keeps the model warm. It can speak with a cloned voice built from a short recording of you.\",\"voice_pocket_running\":\"Running\",\"voice_pocket_not_running\":\"Not running\",\"voice_pocket_not_running_hint\":\"Start the Pocket TTS service on this computer, then re-check. Voices appear once the service is reachable at the address below.\",\"voice_pocket_url_label\":\"Service address\",\"voice_pocket_voices_title\":\"Pocket voices\",\"voice_pocket_voices_desc\":\"Cloned voices come from the service's voices folder — drop in a new voice file and re-check. The rest are built-in voices.\",\"voice_pocket_cloned_badge\":\"Cloned\",\"voice_pocket_enable_blocked\":\"Install the Pocket engine (or start the service) and select a voice to enable playback.\",\"voice_pocket_packaged_title\":\"Pocket setup\",\"voice_pocket_packaged_desc\":\"Pocket speaks with a voice cloned from a short recording. Everything runs on this computer — one engine, one voice model, no account.\",\"voice_pocket_engine_hint\":\"Get the sherpa-onnx release for your platform and drop the binary and its DLLs into the folder below.\",\"voice_pocket_model_hint\":\"Download the Pocket voice model package and extract its files into the folder below.\",\"voice_pocket_install_title\":\"Install Pocket voice engine\",\"voice_pocket_install_desc\":\"Downloads the speech engine (~23 MB) and the voice model (~98 MB), then sets everything up automatically.\",\"voice_pocket_install_button\":\"Download & install\",\"voice_pocket_voices_dir_label\":\"Cloned voices folder\",\"voice_pocket_voices_dir_hint\":\"Add a voice by dropping a 10–30 second .wav recording into this folder, then re-check. The file name becomes the voice name.\",\"voice_pocket_service_title\":\"Advanced: local service\",\"voice_pocket_service_desc\":\"Optional. A running Pocket TTS service adds the built-in voice catalog and faster repeated playback.\",\"voice_pocket_upload_title\":\"Add your voice\",\"voice_pocket_upload_hint\":\"Upload a 10–30 second recording of one person speaking clearly — most audio formats work. The recording's quality carries over, so use a clean take.\",\"voice_pocket_upload_button\":\"Choose recording\",\"voice_pocket_upload_name_label\":\"Voice name\",\"voice_pocket_upload_save\":\"Add voice\",\"voice_pocket_upload_decode_failed\":\"Couldn't read that audio file — try a different format or re-export it.\",\"voice_pocket_hf_gate_title\":\"Hugging Face account required\",\"voice_pocket_hf_gate_desc\":\"Managing your own cloned voices requires a Hugging Face access token in the Credential Vault — it anchors the voice-cloning model license to your account. Add a Hugging Face credential, then come back here.\",\"voice_pocket_delete_title\":\"Remove voice\",\"voice_pocket_delete_body\":\"The reference recording for this voice will be deleted from this computer.\"},\"artist_gallery\":{\"loading_viewer\":\"Loading viewer…\",\"loading_model\":\"Loading model...\",\"could_not_load_model\":\"Could not load model\"},\"artist_media_studio\":{\"drag_drop_hint\":\"— drag & drop files anywhere\",\"keyboard_shortcuts\":\"Space / Del / Arrows\",\"px_per_second\":\"px/s\",\"running_loudnorm\":\"Running ffmpeg loudnorm dry-run…\"},\"dev_lifecycle\":{\"uat_section_title\":\"Browser UAT gate\",\"uat_add\":\"Add browser UAT\",\"uat_remove\":\"Remove\",\"uat_scenario_label\":\"What should the test verify?\",\"uat_scenario_placeholder\":\"e.g. add a todo, mark it done, clear completed, check the console for errors\",\"uat_url_label\":\"Target URL (optional — defaults to the project's test environment)\",\"uat_save\":\"Save UAT gate\",\"uat_verify_now\":\"Verify now\",\"uat_verifying\":\"Verifying…\",\"uat_blocked_todos\":\"Finish the other to-dos first — the browser test is the final acceptance step.\",\"uat_pending\":\"Not yet verified\",\"uat_passed\":\"UAT passed\",\"uat_passed_at\":\"Verified {time}\",\"uat_row_title\":\"Browser UAT passes\",\"uat_gate_hint\":\"This goal can't be marked done until the browser UAT passes.\",\"uat_web_only\":\"Browser UAT is available for web projects only.\",\"uat_mark_passed\":\"Mark passed manually\",\"uat_mark_passed_confirm\":\"Mark the UAT as passed without running it?\",\"goal_new_title\":\"New goal\",\"goal_edit_title\":\"Edit goal\",\"goal_status_open\":\"Open\",\"goal_status_in_progress\":\"In progress\",\"goal_status_awaiting_acceptance\":\"Awaiting acceptance\",\"accept_overlay_title\":\"Accept goals\",\"accept_overlay_subtitle\":\"Review and accept the work your teams completed\",\"goal_status_blocked\":\"Blocked\",\"goal_status_done\":\"Done\",\"accept_view_standfirst_one\":\"{count} completed goal awaits your acceptance\",\"accept_view_standfirst_other\":\"{count} completed goals await your acceptance\",\"accept_view_teams_kpis\":\"{teams} teams · {kpis} KPIs\",\"accept_empty_title\":\"Nothing waiting on you\",\"accept_empty_sub\":\"Completed goals appear here for your acceptance.\",\"accept_standalone\":\"Standalone\",\"accept_off_track\":\"off track\",\"accept_accept\":\"Accept\",\"accept_accept_all\":\"Accept all\",\"accept_send_back\":\"Send back\",\"accept_send_back_placeholder\":\"Why is this not acceptable? (sent back to the team)\",\"accept_cancel\":\"Cancel\",\"accept_resolved_accepted\":\"{count} accepted\",\"accept_resolved_sent_back\":\"{count} sent back\",\"accept_reset\":\"reset\",\"timeline_title\":\"Timeline\",\"timeline_no_dated\":\"No goals with target dates\",\"timeline_no_dated_sub\":\"Set target dates on your goals to see them on the timeline.\",\"timeline_overdue_group\":\"Overdue\",\"timeline_group_this_week\":\"This week\",\"timeline_group_this_month\":\"This month\",\"timeline_group_later\":\"Later\",\"timeline_undated_group\":\"No target date\",\"timeline_today\":\"Today\",\"goal_empty_subtitle\":\"Set the high-level direction for this project. Create goals, link teams to advance them, and let Athena track progress.\",\"goal_ask_athena\":\"Ask Athena to set up goals\",\"goal_ask_athena_prompt\":\"Help me set up development goals for this project. Suggest a few high-level goals based on what you know about it, and we can refine them together.\",\"goal_scaffold_timeline_title\":\"Timeline\",\"goal_scaffold_timeline_sub\":\"A time-axis view of goals by target date, with dependency arrows and slip markers. Coming soon.\",\"goal_field_title\":\"Title\",\"goal_field_title_placeholder\":\"What outcome do you want to achieve?\",\"goal_field_description\":\"Description\",\"goal_field_description_placeholder\":\"Optional: context, scope, or definition of success…\",\"goal_field_status\":\"Status\",\"goal_field_target_date\":\"Target date\",\"goal_target_asap\":\"ASAP\",\"goal_target_1day\":\"1 day\",\"goal_target_1week\":\"1 week\",\"goal_delete_confirm\":\"Delete this goal?\",\"goal_view_board_label\":\"Board\",\"goal_view_board_sub\":\"Your turn → agent's turn → done\",\"goal_view_map_label\":\"Map\",\"goal_view_map_sub\":\"Constellation + dependencies\",\"goal_progress_suggested_label\":\"Suggested progress:\",\"goal_progress_accept\":\"Accept\",\"goal_detail_checklist\":\"Checklist\",\"goal_detail_subgoals\":\"Sub-goals\",\"goal_detail_dependencies\":\"Dependencies\",\"goal_explainer_board\":\"Each card is a goal. \\\"Your turn\\\" holds goals that need you; \\\"Agent's turn\\\" is what your AI team is working on.\",\"goal_explainer_map\":\"Goals arranged by how they connect — arrows show what needs to happen first. Drag to arrange, click a goal for details.\",\"goal_explainer_timeline\":\"Goals laid out by target date, grouped by week and month. Goals without a date sit in their own group.\",\"goal_explainer_progress\":\"Every project on one row, its goals plotted left to right by date and colored by status. Click a square to open the goal.\",\"progress_today\":\"Today\",\"progress_no_date\":\"No date\",\"progress_past\":\"Past\",\"progress_future\":\"Future\",\"progress_filter_done_aria\":\"How much completed work to show\",\"progress_filter_all\":\"All\",\"progress_filter_recent\":\"7D\",\"progress_filter_none\":\"None\",\"progress_hidden_note\":\"{count} completed goals hidden\",\"progress_summary\":\"{projects} projects · {goals} goals\",\"progress_row_counts\":\"{active} active · {done} done\",\"progress_empty_title\":\"No goals to plot yet\",\"progress_empty_sub\":\"Create goals in your projects and they'll line up here as a portfolio timeline.\",\"goal_starters_label\":\"Or start with a common goal:\",\"goal_starter_created\":\"\\\"{title}\\\" added to your goals\",\"goal_starter_release_title\":\"Ship the first release\",\"goal_starter_release_desc\":\"Get a first version into users' hands — decide what's in, finish it, and publish.\",\"goal_starter_bugs_title\":\"Fix the most painful bugs\",\"goal_starter_bugs_desc\":\"Collect the issues that hurt users most and work through them one by one.\",\"goal_starter_docs_title\":\"Write a getting-started guide\",\"goal_starter_docs_desc\":\"Explain what the project does and how a new person gets it running.\",\"goal_starter_quality_title\":\"Make the project more reliable\",\"goal_starter_quality_desc\":\"Add checks and tests around the riskiest parts so changes stop breaking things.\",\"goal_dep_depends_on\":\"Needs first\",\"goal_dep_follows\":\"Comes after\",\"goal_dep_add_depends_on\":\"+ Add a goal this needs first…\",\"goal_dep_add_follows\":\"+ Add a goal this comes after…\",\"goal_dep_none\":\"None\",\"goal_detail_team_steps\":\"Team steps\",\"goal_detail_linked_teams\":\"Linked teams\",\"goal_unlink_team\":\"Unlink\",\"goal_advance_label\":\"Advance this goal\",\"goal_advance_button\":\"Advance with team\",\"goal_advance_starting\":\"Starting…\",\"goal_advance_active\":\"A team assignment is already advancing this goal.\",\"goal_advance_hint\":\"Builds an assignment from the open to-dos (or decomposes the goal) and runs it.\",\"goal_handoff_label\":\"Hand off to your AI team\",\"goal_handoff_button\":\"Hand to AI team\",\"goal_handoff_explain\":\"Your AI team will work through the to-dos here — or break the goal into steps — then start working. You can review what they do as it happens.\",\"goal_handoff_active\":\"Your AI team is already working on this goal.\",\"goal_handoff_confirm_q\":\"Start your AI team on this goal?\",\"goal_handoff_start\":\"Yes, start\",\"goal_handoff_stop\":\"Stop the team\",\"goal_handoff_stop_confirm_q\":\"Stop the team working on this goal?\",\"goal_handoff_stop_explain\":\"The team finishes whatever step it's mid-way through, then stops — it won't pick up new steps. The goal stays, so you can hand it back to the team later.\",\"goal_handoff_stopping\":\"Stopping…\",\"goal_tasks_label\":\"Tasks\",\"goal_tasks_col_task\":\"Task\",\"goal_tasks_col_owner\":\"Owner\",\"goal_tasks_owner_you\":\"You\",\"goal_tasks_empty\":\"No tasks yet — add one below, or hand the goal to your AI team.\",\"goal_tasks_step_running\":\"Working\",\"goal_tasks_step_failed\":\"Failed\",\"goal_more_details\":\"More details\",\"goal_more_details_hint\":\"Dependencies, linked teams, and history\",\"goal_detail_activity\":\"Activity\",\"goal_item_add\":\"Add item\",\"goal_item_add_placeholder\":\"Add a checklist item…\",\"goal_item_mark_done\":\"Mark done\",\"goal_item_mark_undone\":\"Mark not done\",\"goal_card_items_more\":\"+{count} more\",\"goal_card_add_todos\":\"Add to-dos\",\"goal_card_todos_done\":\"{done}/{total} done\",\"goal_board_show_done\":\"Show done ({count})\",\"goal_board_hide_done\":\"Hide done\",\"goal_scope_all_projects\":\"All projects\",\"goal_scope_this_project\":\"This project\",\"goal_map_here\":\"Now\",\"goal_map_next\":\"Next\",\"goal_map_next_desc\":\"Ready to start\",\"goal_map_drag_hint\":\"Drag to arrange · zoom in for detail\",\"goal_map_tidy\":\"Tidy layout\",\"goal_map_tidy_hint\":\"Re-arrange goals into status clusters (clears dragged positions)\",\"goal_intervene_skip\":\"Skip step\",\"goal_intervene_abort\":\"Abort assignment\",\"goal_open_detail\":\"Open details\",\"start_a_competition\":\"Start a Competition\",\"start_a_competition_desc\":\"Spawn 2–4 competitors with different strategies to tackle the same task. The winner's approach gets promoted.\",\"task_title\":\"Task title\",\"task_title_placeholder\":\"E.g. Refactor auth module\",\"task_description\":\"Task description (optional)\",\"task_description_placeholder\":\"Describe what success looks like…\",\"strategy_slots\":\"Strategy slots (pick 2–4)\",\"of_4_selected\":\"/4 selected\",\"cost_warning\":\"Cost warning:\",\"cost_warning_desc\":\"each competitor runs the full task independently — this uses {count}× more tokens.\",\"start_competition\":\"Start Competition\",\"competitors_dot\":\"competitors ·\",\"loading_competitors\":\"Loading competitors...\",\"failed_to_load_detail\":\"Failed to load detail.\",\"status_label\":\"Status:\",\"weight_label\":\"wt:\",\"pick_winner\":\"Pick winner\",\"cancel_competition\":\"Cancel competition\",\"select_project_for_competitions\":\"Select a project to see competitions.\",\"new_competition\":\"New Competition\",\"no_competitions_yet\":\"No competitions yet. Start one to have 2–4 strategies race to solve a task.\",\"no_goals_yet_project\":\"No goals yet. Create goals in the Project Manager to track progress.\",\"zoom_in_title\":\"Zoom in\",\"zoom_out_title\":\"Zoom out\",\"reset_view_title\":\"Reset view\",\"goals_label\":\"goals,\",\"legend_parent\":\"━━ parent\",\"legend_dependency\":\"┄┄ dependency\",\"no_goals_kanban\":\"No goals yet. Create goals to see them here.\",\"no_goals_here\":\"No goals here\",\"auto_setup_title\":\"Run automated lifecycle setup\",\"auto_setup\":\"Auto-Setup\",\"loading_lifecycle\":\"Loading lifecycle status...\",\"no_project_click_to_create\":\"No project — click to create\",\"no_repo\":\"no repo\",\"baseline_label\":\"Baseline:\",\"ts_errors_label\":\"TS errors:\",\"cargo_errors_label\":\"Cargo errors:\",\"tests_label\":\"Tests:\",\"git_label\":\"Git:\",\"winning_insight\":\"Winning insight\",\"completed_in\":\"Completed in\",\"achievements_label\":\"Achievements:\",\"output_lines\":\"output lines\",\"expand_slot_title\":\"Expand slot\",\"collapse_slot_title\":\"Collapse slot\",\"copy_diff_title\":\"Copy diff\",\"loading_diff\":\"Loading diff...\",\"new_competition_modal_title\":\"Start a Competition\",\"first_slot_bias\":\"First slot biased toward previous winner's approach.\",\"start_competition_slots\":\"Start Competition (\",\"slots_suffix\":\"slots)\",\"strategy_leaderboard\":\"Strategy Leaderboard\",\"dq_title\":\"Disqualified\",\"dq_label\":\"DQ x\",\"leaderboard_subtitle\":\"Aggregated across resolved competitions. Higher wins.\",\"capture_winning_insight\":\"Capture the winning insight\",\"capture_insight_desc\":\"What made this approach win? This note is stored with the winner and used to seed future competitions.\",\"insight_placeholder\":\"Describe what made this approach succeed…\",\"confirm_winner\":\"Confirm winner\",\"competition_title_placeholder\":\"e.g. Add rate limiting to /api/auth/login\",\"competition_desc_placeholder\":\"Acceptance criteria, target files, constraints...\",\"regenerate_strategies_title\":\"Regenerate strategies\",\"winning_genes_title\":\"Winning gene profile\",\"winning_genes_analyze\":\"Analyze winners\",\"winning_genes_sample\":\"from {count} winning run(s)\",\"winning_genes_empty\":\"No resolved competitions to analyze yet\",\"winner_label\":\"Winner\",\"rematch_with_winner\":\"Rematch with winner\",\"rematch_hint\":\"Start a new competition with the winning strategy seeded in slot 1\",\"worktree_base_ref_label\":\"Branch from\",\"worktree_base_ref_head\":\"Working tree\",\"worktree_base_ref_fresh\":\"Clean origin\",\"worktree_base_ref_help\":\"Pick where each competitor's worktree branches from. Working tree continues your in-progress changes; Clean origin forks from the default branch on origin for a clean baseline.\",\"start_dev_server_title\":\"Start dev server in this worktree\",\"stop_dev_server_title\":\"Stop dev server\",\"open_worktree_title\":\"Open worktree directory\",\"adopt_dev_clone\":\"Adopt Dev Clone\",\"dev_clone_desc\":\"Dev Clone is an autonomous developer persona that manages your project lifecycle — competing strategies, healing errors, and shipping code.\",\"github_required_title\":\"GitHub repository required\",\"folder_required_title\":\"Project folder required\",\"requirements_met_title\":\"All requirements met\",\"can_still_adopt\":\"You can still adopt now — add GitHub URL and project folder later in project settings.\",\"active_triggers\":\"Active Triggers (\",\"lifecycle_readiness\":\"Lifecycle Readiness —\",\"goals_tab_no_goals\":\"No goals yet\",\"goal_constellation\":\"Your goals\",\"spotlight_pick_goal\":\"Pick a goal to inspect its dependencies and tasks.\",\"spotlight_standalone\":\"Standalone — no dependencies.\",\"spotlight_tasks_heading\":\"Tasks\",\"spotlight_tasks_done_suffix\":\"done\",\"spotlight_tasks_failed_suffix\":\"failed\",\"spotlight_tasks_empty\":\"No tasks linked to this goal yet. Batch from accepted ideas in Task Runner.\",\"spotlight_task_jump_tooltip\":\"Open in Task Runner\",\"constellation_click_hint\":\"Click → open in Pulse\",\"readiness_step_counter_label\":\"Steps\",\"readiness_step_counter_value\":\"{passed}/{total}\",\"prompt_diff_picker_label\":\"Compare prompts:\",\"prompt_diff_picker_count\":\"{selected}/2 selected\",\"prompt_diff_open_btn\":\"Open diff\",\"prompt_diff_title\":\"Prompt diff\",\"prompt_diff_stats\":\"{same} unchanged · {added} added · {removed} removed\",\"prompt_diff_empty\":\"Both prompts are empty.\",\"kanban_nudge_decrease\":\"Decrease progress 5%\",\"kanban_nudge_increase\":\"Increase progress 5%\",\"kanban_drop_here\":\"Drop here to move\",\"goal_view_baseline_label\":\"Baseline\",\"goal_view_baseline_sub\":\"Force-directed graph\",\"goal_view_pulse_label\":\"Project Pulse\",\"goal_view_pulse_sub\":\"Triage + spotlight\",\"goal_view_flow_label\":\"Dependency Flow\",\"goal_view_flow_sub\":\"Kanban with deps\",\"goal_view_kanban_label\":\"Kanban\",\"goal_view_kanban_sub\":\"Your turn · agent's turn · done\",\"sync_to_obsidian\":\"Sync to Obsidian\",\"tab_setup\":\"Lifecycle Setup\",\"tab_goals\":\"Goals\",\"tab_competitions\":\"Competitions\",\"tab_tracking\":\"Project Tracking\",\"tracking_title\":\"Project Tracking\",\"tracking_subtitle\":\"Companion absorbs CLI activity per project — git commits and the active-runs ledger — and forms a stable picture every hour. Per-project toggles below; the master switch lives in Companion plugin setup.\",\"tracking_master_disabled_hint\":\"Master tracking is currently OFF. Enable it in the Companion plugin setup to start hourly pulses.\",\"tracking_master_enabled_hint\":\"Master tracking is ON. Per-project enable toggles below decide which projects feed the consolidator.\",\"tracking_no_projects\":\"No projects registered. Use 'Register project' above to add the first one.\",\"tracking_column_project\":\"Project\",\"tracking_column_path\":\"Path\",\"tracking_column_enabled\":\"Enabled\",\"tracking_column_watch_git\":\"Git\",\"tracking_column_watch_active_runs\":\"Active runs\",\"tracking_column_watch_obsidian\":\"Obsidian\",\"tracking_column_last_pulse\":\"Last pulse\",\"tracking_obsidian_disabled_hint\":\"Obsidian opt-in lands in Phase 6 once a vault credential is detected.\",\"tracking_obsidian_no_credential\":\"Add an Obsidian credential in Connections to enable note watching.\",\"tracking_obsidian_vault_label\":\"Watching\",\"tracking_save\":\"Save\",\"tracking_save_failed\":\"Failed to save subscription\",\"tracking_saved\":\"Subscription saved\",\"tracking_never_pulsed\":\"Never\",\"tracking_stale_hint\":\"(stale)\",\"tracking_force_pulse\":\"Force pulse\",\"tracking_force_pulse_tooltip\":\"Run the consolidator now — pulses all enabled projects, not just this one.\",\"tracking_force_pulse_disabled\":\"Enable master + per-project tracking first.\",\"tracking_pulse_fired\":\"Pulse fired — refreshing in a few seconds.\",\"tracking_pulse_failed\":\"Failed to fire pulse — check Companion plugin setup.\",\"adopt_first_period\":\"Adopt Dev Clone first.\",\"auto_setup_complete\":\"Auto-setup complete: {n} trigger(s) created.\",\"setup_failed\":\"Setup failed\",\"triggers_removed\":\"All Dev Clone triggers removed.\",\"teardown_failed\":\"Teardown failed\",\"task_title_required\":\"Task title is required\",\"batch_start_failed\":\"Batch start failed: {error}\",\"unknown_error\":\"unknown\",\"competition_started_count\":\"Competition started with {count} competitors\",\"competition_failed\":\"Failed\",\"winner_insight_saved\":\"Winner selected. Insight saved to Dev Clone memory.\",\"winner_merge_when_ready\":\"Winner selected. Merge the winning branch when ready.\",\"failed_to_pick_winner\":\"Failed to pick winner\",\"competition_cancelled_cleaning\":\"Competition cancelled — cleaning up worktrees in background.\",\"background_cleanup_issue\":\"Background cleanup issue: {error}\",\"competition_deleted\":\"Competition deleted\",\"competition_delete_failed\":\"Delete failed\",\"readiness_ready\":\"Ready to run. Dev Clone will scan, propose, and build on approval.\",\"readiness_partial\":\"Partially configured. Click Auto-Setup to fill the gaps.\",\"readiness_not_configured\":\"Not configured yet. Adopt Dev Clone and run Auto-Setup.\",\"flow_persona_label\":\"Dev Clone Persona\",\"flow_persona_ready\":\"\\\"{name}\\\" ready\",\"flow_persona_idle\":\"Adopt from bundled template\",\"flow_schedule_label\":\"Hourly Scan\",\"flow_schedule_active\":\"Cron trigger active (0 * * * *)\",\"flow_schedule_idle\":\"Periodic codebase analysis\",\"flow_goals_label\":\"Goals\",\"flow_goals_count\":\"{count} goal(s) in project\",\"flow_review_label\":\"Human Review\",\"flow_review_desc\":\"Tasks proposed for approval\",\"flow_approved_label\":\"Approval → Build\",\"flow_approved_active\":\"Event listener active\",\"flow_approved_idle\":\"Triggers Dev Clone build cycle\",\"flow_rejected_label\":\"Rejection → Recompose\",\"flow_rejected_active\":\"Event listener active\",\"flow_rejected_idle\":\"Triggers recomposition with feedback\",\"flow_memory_label\":\"Memory Learning\",\"flow_memory_desc\":\"Decisions auto-saved as learned memories\",\"flow_trigger_enabled\":\"enabled\",\"flow_trigger_disabled\":\"disabled\",\"gate_dev_project\":\"Dev project selected\",\"gate_github_repo\":\"GitHub repo linked\",\"gate_dev_clone\":\"Dev Clone adopted\",\"gate_hourly_scan\":\"Hourly scan scheduled\",\"gate_approval_listener\":\"Approval listener wired\",\"gate_rejection_listener\":\"Rejection listener wired\",\"goal_view_board\":\"Board\",\"goal_view_map\":\"Map\",\"goal_view_timeline\":\"Timeline\",\"goal_view_progress\":\"Progress\",\"goal_view_accept\":\"Accept\",\"goal_view_missions\":\"Missions\",\"mission_unlinked\":\"No goal\",\"mission_link_goal\":\"Link to a goal…\",\"goal_field_kpi\":\"Linked KPI\",\"goal_kpi_none\":\"No linked KPI\"},\"dev_projects\":{\"bulk_selected_one\":\"project selected\",\"bulk_selected_many\":\"projects selected\",\"bulk_archive_btn\":\"Archive selected\",\"workspace_all_projects\":\"All projects\",\"workspace_unassigned\":\"Unassigned\",\"workspace_new\":\"New workspace\",\"workspace_hint\":\"Group projects into workspaces to scope every module to one.\",\"workspace_name_placeholder\":\"Workspace name\",\"workspace_delete\":\"Delete workspace\",\"workspace_delete_confirm\":\"Delete — projects stay\",\"workspace_done\":\"Done\",\"workspace_move_to\":\"Move to…\",\"workspace_move_heading\":\"Move to workspace\",\"bulk_archive_success\":\"Archived {count} project(s)\",\"bulk_archive_partial\":\"{failed} project(s) failed to archive\",\"bulk_select_all\":\"Select all active projects\",\"bulk_select_clear\":\"Clear selection\",\"bulk_select_row\":\"Select for bulk action\",\"bulk_already_archived\":\"Already archived\",\"active_goals\":\"active goals\",\"run_context_map_scan\":\"Run Context Map scan for this project to populate metadata.\",\"entry_points\":\"entry points\",\"db_tables\":\"db tables\",\"top_keywords\":\"Top Keywords\",\"entry_points_header\":\"Entry Points\",\"db_tables_header\":\"Database Tables\",\"api_surface\":\"API Surface\",\"hot_directories\":\"Hot Directories\",\"cross_project_map_title\":\"Cross-Project Metadata Map\",\"cross_project_map_subtitle\":\"Aggregated from existing context maps. Click any project to expand.\",\"tech_distribution\":\"Tech Distribution\",\"shared_keywords\":\"Shared Keywords (\",\"shared_keywords_subtitle\":\"Concepts present in multiple projects — may indicate shared architecture.\",\"project_similarity\":\"Project Similarity\",\"projects_header\":\"Projects (\",\"cross_project_map_btn\":\"Cross-Project Map\",\"new_project\":\"New Project\",\"select_or_create\":\"Select a project below or create a new one.\",\"all_projects\":\"All Projects (\",\"no_projects_yet\":\"No projects yet\",\"create_first_project\":\"Create First Project\",\"tech_stack\":\"Tech Stack\",\"edit_project\":\"Edit Project\",\"no_goals_add_below\":\"No goals yet. Add one below.\",\"goal_title_placeholder\":\"Goal title…\",\"project_folder\":\"Project Folder\",\"select_folder\":\"Select a folder...\",\"project_name\":\"Project Name\",\"auto_filled_from_folder\":\"(auto-filled from folder)\",\"project_name_placeholder\":\"My Project\",\"project_type\":\"Project Type\",\"project_type_optional\":\"(optional, visual only)\",\"pipeline_step_project\":\"Project\",\"pipeline_step_source\":\"Source control\",\"pipeline_next\":\"Next\",\"pipeline_back\":\"Back\",\"pipeline_not_set\":\"Not set\",\"pipeline_edit_field\":\"Click to edit\",\"pipeline_field_saved\":\"Saved\",\"pipeline_field_save_failed\":\"Couldn't save change\",\"source_mode_team\":\"Team\",\"source_mode_standalone\":\"Standalone\",\"source_mode_team_hint\":\"A team owns delivery\",\"source_mode_standalone_hint\":\"Bind a GitHub connector\",\"field_required\":\"required\",\"main_branch_label\":\"Main branch\",\"main_branch_placeholder\":\"main\",\"pipeline_step_standards\":\"Standards\",\"standards_precommit_heading\":\"Pre-commit rules\",\"standards_lint\":\"Lint\",\"standards_lint_hint\":\"Block commits that fail the linter\",\"standards_docs\":\"Docs covered\",\"standards_docs_hint\":\"Require docs updated for user-visible changes\",\"standards_quality\":\"Code quality\",\"standards_quality_hint\":\"Enforce the project's code-quality conventions\",\"standards_branching_heading\":\"Branching & merge\",\"standards_pr_base\":\"Open PRs against\",\"standards_branch_main\":\"Main branch\",\"standards_branch_test\":\"Test branch\",\"standards_automerge\":\"Automerge\",\"standards_automerge_hint\":\"Use GitHub native auto-merge when checks pass\",\"standards_automerge_target\":\"Automerge into\",\"standards_automerge_on\":\"On →\",\"standards_automerge_off\":\"Off\",\"standards_scan_title\":\"Standards compliance\",\"standards_scan_run\":\"Scan\",\"standards_scan_running\":\"Scanning…\",\"standards_scan_empty\":\"No scan yet — run a golden-standard scan to check this repo.\",\"standards_compliance\":\"compliant\",\"create_codebase_connector_label\":\"Create Codebase connector\",\"create_codebase_connector_desc\":\"Adds a \\\"Codebase — {name}\\\" connector so your agents can read this project without opening the credential catalog.\",\"project_created\":\"Project Created\",\"project_ready_desc\":\"is ready. Would you like to generate a context map now?\",\"generate_context_map\":\"Generate Context Map\",\"generate_context_map_desc\":\"Scans your codebase to identify business logic, entry points, and data structures.\",\"skip_for_now\":\"Skip for now\",\"scan_codebase\":\"Scan Codebase\",\"loading_repositories\":\"Loading repositories...\",\"github_url_label\":\"GitHub URL\",\"github_connector_label\":\"GitHub connector\",\"optional\":\"(optional)\",\"github_url_placeholder\":\"https://github.com/owner/repo\",\"github_repository\":\"GitHub Repository\",\"select_repository\":\"Select a repository...\",\"no_repositories_found\":\"No repositories found\",\"repo_private\":\"Private\",\"open_repo\":\"Open repository\",\"invalid_repo_url\":\"Enter a valid repository URL (e.g. https://github.com/owner/repo).\",\"browse\":\"Browse\",\"repos_from_connector\":\"Repositories are listed from this connector.\",\"test_env_url\":\"Test environment URL\",\"test_env_url_placeholder\":\"https://staging.example.com\",\"test_env_branch\":\"Test environment branch\",\"test_env_branch_placeholder\":\"main\",\"open_test_env\":\"Open test environment\",\"modal_section_project\":\"Project\",\"modal_section_source\":\"Source control\",\"modal_section_workspace\":\"Workspace\",\"new_project_subtitle\":\"Register a local folder as a tracked project.\",\"edit_project_subtitle\":\"Update this project's details and integrations.\",\"implementation_log\":\"Implementation Log\",\"loading_metadata\":\"Loading...\",\"no_metadata_yet\":\"No metadata map generated yet\",\"generate_metadata_prompt\":\"to analyze all projects' context maps and extract shared patterns.\",\"team_binding_label\":\"Bound Team\",\"team_binding_optional\":\"optional\",\"team_binding_none\":\"No team — keep project standalone\",\"team_binding_empty\":\"No teams yet — create one under Agents → Teams to bind it here.\",\"team_binding_bound\":\"Project is bound to this PersonaTeam pipeline\",\"team_binding_orphan\":\"Bound team has been deleted; open project to rebind\",\"team_binding_orphan_label\":\"team removed\",\"team_binding_preview_title\":\"Open team preview — members + recent runs\",\"team_preview_members\":\"Members\",\"team_preview_loading\":\"Loading…\",\"team_preview_no_members\":\"This team has no members yet.\",\"team_preview_unknown_persona\":\"(persona removed)\",\"team_preview_recent_runs\":\"Recent runs\",\"team_preview_recent_runs_total\":\"(last {count})\",\"team_preview_no_runs\":\"This team has not been executed yet.\",\"team_preview_live_label\":\"live\",\"team_preview_canvas\":\"Canvas\",\"team_preview_canvas_unplaced\":\"members not laid out yet\",\"team_preview_canvas_aria\":\"Mini canvas — team members and their connections\",\"team_preview_canvas_open_persona\":\"open in editor\",\"team_preview_canvas_reset\":\"Reset\",\"team_preview_canvas_reset_title\":\"Reset zoom and pan to defaults\",\"team_preview_member_enabled\":\"Enabled\",\"team_preview_member_disabled\":\"Disabled\",\"team_preview_member_trust\":\"Trust\",\"team_preview_member_last_run\":\"Last run\",\"team_preview_member_never_run\":\"never\",\"team_preview_member_budget\":\"Budget\",\"team_preview_member_open\":\"Open in editor\",\"group_binding_label\":\"Bound Group\",\"group_binding_none\":\"No group — keep project standalone\",\"group_binding_empty\":\"No groups yet — create one under Agents → Groups to bind it here.\",\"group_binding_bound\":\"Project is bound to this PersonaGroup workspace\",\"group_binding_orphan\":\"Bound group has been deleted; open project to rebind\",\"group_binding_orphan_label\":\"group removed\"},\"dev_runner\":{\"self_healing\":\"Self-Healing\",\"auto_heal\":\"Auto-heal\",\"heal_all\":\"Heal All (\",\"fp_test_failure_label\":\"Test Failure\",\"fp_test_failure_action\":\"Re-scan affected context and regenerate implementation with test constraints\",\"fp_build_error_label\":\"Build Error\",\"fp_build_error_action\":\"Analyze error output, fix syntax/type issues, retry build\",\"fp_timeout_label\":\"Timeout\",\"fp_timeout_action\":\"Increase timeout or break task into smaller units\",\"fp_dependency_label\":\"Dependency Issue\",\"fp_dependency_action\":\"Install missing dependencies and retry\",\"fp_permission_label\":\"Permission Issue\",\"fp_permission_action\":\"Check credentials and access rights\",\"fp_unknown_label\":\"Unknown Failure\",\"fp_unknown_action\":\"Manual investigation required\",\"heal_max_retries\":\"Max retries reached for \\\"{title}\\\"\",\"heal_chip_failed\":\"{count} failed\",\"heal_chip_auto_fixable\":\"{count} auto-fixable\",\"heal_status_retrying\":\"Retry {retry}/{max}\",\"heal_status_healed\":\"Healed\",\"heal_status_failed\":\"Failed ({retry}/{max})\",\"heal_btn\":\"Heal\",\"heal_signal_log\":\"Self-healing: retrying \\\"{title}\\\" ({pattern})\",\"task_title_placeholder\":\"Task title…\",\"task_details_placeholder\":\"Task details or paste a spec…\",\"task_depth\":\"Task Depth\",\"goal_link\":\"Goal Link\",\"optional\":\"(optional)\",\"goal_link_placeholder\":\"Select a goal…\",\"create_task\":\"Create Task\",\"source_label\":\"Source:\",\"goal_pill_tooltip\":\"Jump to this goal in Lifecycle\",\"new_task\":\"New Task\",\"batch_from_accepted\":\"Batch from Accepted\",\"start_batch\":\"Start Batch\",\"cancel_all\":\"Cancel All\",\"batch_progress\":\"Batch Progress\",\"percent_overall\":\"% overall\",\"task_queue\":\"Task Queue (\",\"no_tasks_queued\":\"No tasks in queue\",\"no_tasks_queued_sub\":\"Create tasks manually or batch from accepted ideas.\",\"auto_run_all\":\"Auto-Run All\",\"retry_all_failed\":\"Retry failed\",\"auto_run_progress\":\"Auto-Run in progress\",\"auto_run_complete\":\"Auto-Run finished\",\"auto_run_started\":\"Could not start Auto-Run\",\"auto_run_iterations\":\"tasks queued\",\"cancel_auto_run\":\"Cancel Auto-Run\"},\"dev_scanner\":{\"idea_evolution\":\"Idea Evolution\",\"fitness_ranking\":\"Fitness Ranking\",\"similar_to_rejected\":\"Similar to rejected:\",\"synthesis_suggestions\":\"Synthesis Suggestions\",\"percent_similar\":\"% similar\",\"potential_duplicates\":\"Potential Duplicates\",\"lifecycle_heading\":\"Accepted ideas — lifecycle\",\"lifecycle_stage_accepted\":\"Accepted\",\"lifecycle_stage_tasked\":\"Tasked\",\"lifecycle_stage_shipped\":\"Shipped\",\"lifecycle_jump_to_task\":\"Open this task in Task Runner\",\"scanning_with\":\"Scanning with\",\"analyzing_codebase\":\"Analyzing codebase...\",\"no_previous_scans\":\"No previous scans yet.\",\"auto_scan\":\"Auto-Scan\",\"automated_context_scan\":\"Automated Context Scan\",\"run_scan_prefix\":\"Run:\",\"results_prefix\":\"Results:\",\"scan_history_prefix\":\"Scan History\",\"idea_scanner_title\":\"Idea Scanner\",\"idea_scanner_subtitle\":\"Run specialized agents to generate improvement ideas\",\"run_scan_btn\":\"Run Scan (\",\"results_header\":\"Results (\",\"scan_history_header\":\"Scan History (\",\"static_scan_btn\":\"Static Scan\",\"static_scan_no_config\":\"No static-analysis CLI configured. Set one on this project first.\",\"static_scan_running\":\"Running static scan…\",\"static_scan_complete\":\"Static scan complete: {count} ideas added.\",\"static_scan_failed\":\"Static scan failed: {error}\",\"scan_config_btn\":\"Configure\",\"scan_config_title\":\"Scan configuration\",\"scan_config_granularity_label\":\"Target findings\",\"scan_config_granularity_hint\":\"Aim for roughly this many high-value ideas per scanned area. Leave on Auto for the model's default.\",\"scan_config_granularity_auto\":\"Auto\",\"scan_config_scope_label\":\"Context scope\",\"scan_config_scope_hint\":\"Limit the scan to selected areas. Leave everything unselected to scan the whole project.\",\"scan_config_scope_empty\":\"Run a context scan first to enable scoping.\",\"scan_config_ungrouped\":\"Ungrouped\",\"scan_config_selected_count\":\"{count} selected\",\"scan_config_clear\":\"Clear\",\"scan_config_apply\":\"Apply\",\"scan_config_whole_project\":\"Whole project\",\"static_config_title\":\"Static scan tool\",\"static_config_intro\":\"Pick a static-analysis CLI and the command to run. It runs in the project folder and must emit parseable JSON.\",\"static_config_tool_label\":\"Tool\",\"static_config_recommended\":\"recommended\",\"static_config_command_label\":\"Command\",\"static_config_command_hint\":\"Argv — the first element is the executable (e.g. npx). Personas adds no flags of its own.\",\"static_config_command_required\":\"Enter a command to run.\",\"static_config_save_run\":\"Save & run\",\"static_config_saved\":\"Static scan tool saved\",\"static_config_save_failed\":\"Couldn't save the static-scan config\",\"idea_scan_completed_title\":\"Idea Scan Completed\",\"idea_scan_ready_with_count\":\"Generated ideas are ready for triage. Total ideas in backlog: {count}.\",\"idea_scan_ready_no_count\":\"Scan completed. Open the page to see new ideas.\",\"idea_scan_partial_title\":\"Idea Scan (partial)\",\"idea_scan_partial_summary\":\"Scan exceeded the timeout but partial results were saved. Click Open to review what was generated.\",\"idea_scan_failed_title\":\"Idea Scan Failed\",\"idea_scan_failed_default\":\"The idea scan failed before any ideas were generated. Try again or check the logs.\",\"auto_scan_loading_contexts\":\"Loading contexts...\",\"auto_scan_no_contexts\":\"No contexts found. Run Context Map scan first.\",\"auto_scan_in_progress\":\"Scanning \\\"{name}\\\" ({matched} agents) — {done}/{total}\",\"auto_scan_failed_status\":\"Auto-scan failed\",\"auto_scan_completed_status\":\"Completed! Scanned {total} contexts.\",\"select_all_btn\":\"Select All\",\"build_task_btn\":\"Build\",\"build_task_tip\":\"Queue an implementation task from this idea\",\"build_task_done_tip\":\"Already queued as a task\",\"build_task_queued\":\"Task queued — see Task Runner\",\"agent_recommended_tip\":\"High acceptance rate in past triage\",\"select_recommended_btn\":\"Recommended\",\"select_recommended_tip\":\"Select the agents with the best acceptance rate\",\"clear_all_btn\":\"Clear All\",\"no_results_yet\":\"No scan results yet. Select agents above and run a scan.\",\"no_ideas_match_filter\":\"No ideas match this filter.\",\"ideas_count_one\":\"idea\",\"ideas_count_other\":\"ideas\",\"level_effort\":\"Effort\",\"level_impact\":\"Impact\",\"level_risk\":\"Risk\",\"value_high\":\"High value\",\"value_med\":\"Fair value\",\"value_low\":\"Low value\",\"value_tip\":\"Impact weighed against effort and risk\",\"history_col_agents\":\"Agents\",\"history_col_status\":\"Status\",\"history_col_ideas\":\"Ideas\",\"history_col_tokens\":\"Tokens\",\"history_col_duration\":\"Duration\",\"history_col_when\":\"When\",\"history_col_action\":\"Action\",\"history_rerun_label\":\"Rerun\",\"history_rerun_tooltip\":\"Re-run this scan against the current code\",\"history_filter_label\":\"Filter agents:\",\"history_filter_clear\":\"Clear filter\"},\"dev_triage\":{\"suggested_rules_label\":\"Suggested from your history\",\"suggestion_evidence\":\"{matched}/{total} past decisions match\",\"suggestion_add\":\"Add rule\",\"suggestion_name_reject_heavy\":\"Reject heavy ideas\",\"suggestion_name_accept_quick\":\"Accept quick wins\",\"suggestion_name_reject_risky\":\"Reject risky ideas\",\"suggestion_name_reject_category\":\"Reject {category} ideas\",\"auto_triage_rules\":\"Auto-Triage Rules\",\"add_condition\":\"+ Add condition\",\"action_label\":\"Action:\",\"new_rule\":\"New Rule\",\"run_rules\":\"Run Rules\",\"suggestion_name_reject_origin\":\"Auto-reject {origin} findings\"},\"artist_label\":\"Artist\",\"artist_desc\":\"Generate 3D models with Blender, create images with Leonardo AI, and manage creative assets.\",\"research_lab_label\":\"Research Lab\",\"research_lab_desc\":\"Academic paper search and hypothesis tracking\",\"ocr\":{\"title\":\"OCR\",\"subtitle\":\"Extract and compare text from images and documents\",\"tab_extract\":\"Extract\",\"tab_compare\":\"Compare\",\"tab_history\":\"History\",\"choose_image_pdf\":\"Choose image or PDF\",\"gemini_vision\":\"Gemini Vision\",\"claude_vision\":\"Claude Vision\",\"extract_text\":\"Extract Text\",\"extract_desc\":\"Upload an image or PDF and extract text using Gemini Vision API or Claude Code CLI.\",\"custom_prompt\":\"Custom prompt (optional)\",\"extracting\":\"Extracting...\",\"extraction_complete\":\"Extraction complete\",\"copy_text\":\"Copy text\",\"compare_heading\":\"Side-by-Side Comparison\",\"compare_desc\":\"Run both providers on the same file and compare outputs. Gemini uses API key, Claude uses your subscription.\",\"running_both\":\"Running both providers...\",\"compare\":\"Compare\",\"no_gemini_key\":\"No Gemini API key provided\",\"gemini_failed\":\"Gemini failed or still running\",\"claude_running\":\"Claude still running or failed\",\"comparison_stats\":\"Comparison Stats\",\"history_heading\":\"OCR History\",\"no_results\":\"No OCR results yet\",\"no_results_hint\":\"Extract text from a document to see it here.\"},\"dev_context\":{\"files_header\":\"Files (\",\"filter_groups_placeholder\":\"Filter groups…\",\"rescan_title\":\"Re-scan\",\"scanning_with\":\"Scanning with\",\"scanning_subtitle\":\"Analyzing codebase patterns and generating context groups…\",\"no_previous_scans\":\"No previous scans.\",\"run_scan\":\"Run Scan (\",\"auto_scan\":\"Auto-Scan\",\"automated_context_scan\":\"Automated Context Scan\",\"results\":\"Results (\",\"scan_history\":\"Scan History (\"},\"obsidian\":{\"saved_vaults\":\"Saved Vaults\",\"remove_vault_title\":\"Remove saved vault\",\"vault_is_empty\":\"Vault is empty\",\"failed_to_load\":\"Failed to load\",\"open_in_obsidian\":\"Open in Obsidian\",\"copy_path_title\":\"Copy path\",\"disconnect_title\":\"Disconnect Google Drive\",\"sign_in_to_enable\":\"Sign in to enable cloud sync\",\"sign_in_subtitle\":\"Sign in with your Google account to back up your vault to Google Drive.\",\"refresh_title\":\"Refresh sync status\",\"drive_connected\":\"Drive Connected\",\"files_synced\":\"files synced to Drive\",\"connect_drive_desc\":\"Connect Google Drive to back up your vault to the cloud.\",\"step_connect_drive\":\"Connect Google Drive\",\"step_connect_drive_desc\":\"Grant Personas access to create files in your Google Drive.\",\"step_push_vault\":\"Push your vault\",\"step_push_vault_desc\":\"Vault notes are uploaded as markdown files to Google Drive.\",\"step_sync_devices\":\"Sync across devices\",\"step_sync_devices_desc\":\"Pull on another device to download. Your vault is your data.\",\"push_title\":\"Push vault to Google Drive\",\"push_desc\":\"Push uploads local vault changes to Google Drive.\",\"last_sync_result\":\"Last Sync Result\",\"pull_title\":\"Pull vault from Google Drive\",\"connect_google_drive\":\"Connect Google Drive\",\"connect_drive_btn_desc\":\"Grant Personas access to create files in your Google Drive.\",\"no_stats_yet\":\"No stats yet.\",\"search_title\":\"Search notes\",\"search_placeholder\":\"Search notes…\",\"no_matches\":\"No matches.\",\"no_orphan_notes\":\"No orphan notes — every note is linked from at least one other note.\",\"no_mocs\":\"No MOCs detected — try lowering the link threshold.\",\"quick_capture_title\":\"AI quick-capture\",\"note_search_placeholder\":\"Search notes or ask a question…\",\"append_to_today\":\"Append to Today\",\"append_content_placeholder\":\"Content to append…\",\"save_meeting_title\":\"Save meeting note\",\"meeting_attendees_placeholder\":\"Attendees…\",\"meeting_summary_placeholder\":\"Meeting summary…\",\"meeting_action_items_placeholder\":\"Action items…\",\"meeting_decisions_placeholder\":\"Decisions…\",\"save_meeting_note\":\"Save Meeting Note\",\"remove_vault_setup_title\":\"Remove\",\"detected_vaults\":\"Detected vaults:\",\"connected_to\":\"Connected to \\\"\",\"notes_found\":\"notes found\",\"scan_for_vaults_title\":\"Scan for vaults\",\"manual_path_title\":\"Enter path manually\",\"preview_label\":\"Preview:\",\"vault_path_agent_placeholder\":\"/AgentName/\",\"vault_path_note_example\":\"/fact/memory-title.md\",\"disconnect_sync_title\":\"Disconnect\",\"active_vault\":\"Active vault:\",\"refresh_stats_title\":\"Refresh stats\",\"select_personas\":\"Select personas to push\",\"select_all\":\"Select all\",\"no_personas_found\":\"No personas found\",\"last_push_result\":\"Last Push Result\",\"app_version\":\"App Version\",\"vault_version\":\"Vault Version\",\"keep_app\":\"Keep App\",\"keep_vault\":\"Keep Vault\",\"clear_history_title\":\"Clear history\",\"no_sync_activity\":\"No sync activity yet. Push or pull to start.\"},\"research_lab\":{\"review_before_saving\":\"Review before saving. Uncheck any you don't want to add.\",\"re_run\":\"Re-run\",\"search_placeholder\":\"Search…\",\"filter_title\":\"Filter\",\"query_placeholder\":\"Type a query and press Enter to search across all panels…\",\"close_panel_title\":\"Close panel\",\"md_extension\":\".md\",\"edit_note_aria\":\"Edit note\"}}",
   "media_studio": "{\"title\":\"Media Studio\",\"subtitle\":\"Compose videos with text, images, and audio layers\",\"ffmpeg_not_found\":\"FFmpeg not found\",\"ffmpeg_not_found_hint\":\"FFmpeg is required to export videos. Install it and restart the app.\",\"ffmpeg_install_windows\":\"Windows: winget install ffmpeg (or download from ffmpeg.org)\",\"ffmpeg_install_mac\":\"macOS: brew install ffmpeg\",\"ffmpeg_install_linux\":\"Linux: sudo apt install ffmpeg\",\"ffmpeg_found\":\"FFmpeg ready\",\"check_again\":\"Check again\",\"layer_text\":\"Text\",\"layer_image\":\"Images\",\"layer_video\":\"Video\",\"layer_audio\":\"Audio\",\"add_text_beat\":\"Add beat\",\"add_image\":\"Add image\",\"layer_title\":\"Titles\",\"add_title\":\"Add title\",\"title_untitled\":\"(empty title)\",\"title_text\":\"Title text\",\"title_text_placeholder\":\"Type the title…\",\"entrance\":\"Entrance\",\"entrance_animate\":\"Animate in\",\"entrance_none\":\"None\",\"entrance_up\":\"Up\",\"entrance_down\":\"Down\",\"entrance_left\":\"Left\",\"entrance_right\":\"Right\",\"entrance_duration\":\"Speed\",\"voiceover\":\"Voiceover\",\"voiceover_text\":\"Narration\",\"voiceover_text_placeholder\":\"Type what the narrator should say…\",\"voiceover_voice\":\"Voice\",\"voiceover_generate\":\"Generate voiceover\",\"voiceover_generating\":\"Synthesizing…\",\"voiceover_added\":\"Voiceover added to the timeline\",\"voiceover_not_installed\":\"Local voice engine not installed — set it up in the companion Voice settings first.\",\"add_video\":\"Add video\",\"add_audio\":\"Add audio\",\"empty_lane\":\"Drop or click + to add\",\"zoom_in\":\"Zoom in\",\"zoom_out\":\"Zoom out\",\"fit_to_view\":\"Fit to view\",\"beat_word\":\"Beat word\",\"beat_description\":\"Description\",\"beat_word_placeholder\":\"Word\",\"beat_description_placeholder\":\"Describe this beat...\",\"inspector_title\":\"Properties\",\"no_selection\":\"Select an item on the timeline to edit its properties.\",\"start_time\":\"Start\",\"duration\":\"Duration\",\"trim_start\":\"Trim start\",\"trim_end\":\"Trim end\",\"volume\":\"Volume\",\"font_size\":\"Font size\",\"color\":\"Color\",\"position\":\"Position\",\"scale\":\"Scale\",\"transition\":\"Transition\",\"transition_cut\":\"Cut\",\"transition_crossfade\":\"Crossfade\",\"transition_fade_to_black\":\"Fade to black\",\"transition_duration\":\"Transition duration\",\"play\":\"Play\",\"pause\":\"Pause\",\"stop\":\"Stop\",\"loop\":\"Loop\",\"current_time\":\"Current time\",\"export_title\":\"Export\",\"export_button\":\"Export to MP4\",\"exporting\":\"Exporting...\",\"export_complete\":\"Export complete\",\"export_failed\":\"Export failed\",\"export_cancel\":\"Cancel export\",\"export_elapsed\":\"{time} elapsed\",\"export_eta\":\"~{time} left\",\"export_done\":\"Done\",\"export_open_file\":\"Open\",\"export_show_in_folder\":\"Show in folder\",\"export_dismiss\":\"Dismiss\",\"recent_compositions\":\"Recent\",\"recent_saved_ago\":\"saved {time} ago\",\"starter_templates_title\":\"Start from a template\",\"template_vertical_9_16_name\":\"Vertical 9:16 reel\",\"template_horizontal_16_9_name\":\"Horizontal 16:9 demo\",\"template_square_name\":\"Square social\",\"beat_sidebar_title\":\"Beats\",\"beat_sidebar_count\":\"{count}\",\"beat_sidebar_collapse\":\"Collapse beat list\",\"beat_sidebar_expand\":\"Expand beat list\",\"beat_sidebar_empty\":\"No beats yet. Add a Text beat to mark milestones on the timeline.\",\"beat_sidebar_untitled\":\"(untitled)\",\"output_settings\":\"Output settings\",\"resolution\":\"Resolution\",\"framerate\":\"Frame rate\",\"choose_output\":\"Choose output file\",\"empty_title\":\"Start composing\",\"empty_hint\":\"Add video or audio clips to the timeline to begin.\",\"split\":\"Split at playhead\",\"undo\":\"Undo\",\"redo\":\"Redo\",\"import_media\":\"Import media\",\"supported_video\":\"Video files\",\"supported_audio\":\"Audio files\",\"supported_images\":\"Image files\",\"clip_actions\":\"Clip actions\",\"action_split\":\"Split at playhead\",\"action_extract_audio\":\"Extract audio\",\"action_strip_audio\":\"Strip audio\",\"action_keep_audio\":\"Keep audio\",\"action_save_thumbnail\":\"Save frame as image\",\"action_trim_to_file\":\"Trim to new file\",\"extracting_audio\":\"Extracting audio track...\",\"extract_audio_done\":\"Audio track extracted\",\"saving_thumbnail\":\"Saving frame...\",\"thumbnail_saved\":\"Frame saved\",\"trimming_file\":\"Trimming file...\",\"trim_done\":\"Trim saved\",\"effects\":\"Effects\",\"speed\":\"Speed\",\"fade_in\":\"Fade in\",\"fade_out\":\"Fade out\",\"normalize\":\"Normalize loudness\",\"normalize_hint\":\"EBU R128 applied on export; preview is an approximation\",\"strip_audio_hint\":\"Mutes this clip's audio on export\",\"style_guide_title\":\"Style guide\",\"style_guide_edit\":\"Edit style guide\",\"style_guide_placeholder\":\"Describe the look and feel: motion language, palette, typography, pacing — anything you'd want the agent to follow every time it composes for this project.\",\"style_guide_hint\":\"Reused by plan-compose and auto-trim prompts so the agent matches your style every time.\",\"style_guide_clear\":\"Clear\",\"style_guide_save\":\"Save\",\"transcribe\":\"Transcribe\",\"transcribe_running\":\"Transcribing…\",\"transcribe_done\":\"Transcript ready\",\"transcribe_failed\":\"Transcription failed\",\"transcribe_no_whisper\":\"Local Whisper CLI not found. Install it with `pip install -U openai-whisper` (requires Python and ffmpeg).\",\"transcribe_provider\":\"Provider\",\"transcribe_provider_local\":\"Local Whisper (free)\",\"transcribe_provider_elevenlabs\":\"ElevenLabs (coming soon)\",\"transcribe_provider_openai\":\"OpenAI Whisper API (coming soon)\",\"action_transcribe_clip\":\"Transcribe clip\",\"transcript_ready_badge\":\"Transcript\",\"anchor_section\":\"Anchor\",\"anchor_manual\":\"Fixed time\",\"anchor_word_mode\":\"Anchor to a spoken word\",\"anchor_clip\":\"Clip\",\"anchor_word\":\"Word\",\"anchor_occurrence\":\"Occurrence\",\"anchor_occurrence_hint\":\"If the word is spoken more than once, pick which one\",\"anchor_no_transcripts\":\"No clips have transcripts yet. Transcribe a clip to anchor beats to spoken words.\",\"anchor_word_placeholder\":\"Type the word\",\"anchor_unresolved\":\"Word not found in transcript\",\"anchor_resolved_at\":\"Resolved to {time}s\",\"auto_trim_title\":\"Auto-trim filler words & silences\",\"auto_trim_button\":\"Auto-trim from transcript\",\"auto_trim_needs_transcript\":\"Transcribe the clip first to enable auto-trim.\",\"auto_trim_analyzing\":\"Analyzing transcript for filler, silences, and retakes…\",\"auto_trim_empty\":\"Nothing to cut — the transcript looks clean.\",\"auto_trim_apply\":\"Apply cuts\",\"auto_trim_discard\":\"Discard\",\"auto_trim_keep_label\":\"Keep\",\"auto_trim_cut_label\":\"Cut\",\"auto_trim_reason_filler\":\"Filler word\",\"auto_trim_reason_silence\":\"Silence\",\"auto_trim_reason_retake\":\"Retake / false start\",\"auto_trim_reason_repeat\":\"Repeated phrase\",\"auto_trim_failed\":\"Couldn't parse the agent's proposal. Try again or edit manually.\",\"plan_compose_title\":\"Plan composition\",\"plan_compose_brief_label\":\"What should this video be?\",\"plan_compose_brief_placeholder\":\"Describe the video: style, tone, key beats, calls to action. The agent will propose beats anchored to the transcript.\",\"plan_compose_generate\":\"Generate plan\",\"plan_compose_generating\":\"Planning…\",\"plan_compose_apply\":\"Apply plan\",\"plan_compose_discard\":\"Discard\",\"plan_compose_empty\":\"No proposed beats yet. Describe the video above and generate.\",\"plan_compose_needs_transcript\":\"Plan-compose works best when at least one video clip has a transcript.\",\"plan_compose_failed\":\"Couldn't parse the plan. Try again with a more specific brief.\"}",
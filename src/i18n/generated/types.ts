@@ -9372,6 +9372,7 @@ export type Translations = {
     type_polling: string;
     type_event_listener: string;
     type_file_watcher: string;
+    type_email_inbound: string;
     type_clipboard: string;
     type_app_focus: string;
     type_chain: string;
@@ -9382,6 +9383,7 @@ export type Translations = {
     desc_webhook: string;
     desc_event_listener: string;
     desc_file_watcher: string;
+    desc_email_inbound: string;
     desc_clipboard: string;
     desc_app_focus: string;
     desc_chain: string;
@@ -9501,6 +9503,28 @@ export type Translations = {
       recursive: string;
       glob_filter: string;
       glob_placeholder: string;
+      ignore_patterns: string;
+      ignore_placeholder: string;
+      debounce_label: string;
+      include_content: string;
+    };
+    email_inbound: {
+      credential_label: string;
+      credential_placeholder: string;
+      no_credentials: string;
+      mailbox_label: string;
+      label_label: string;
+      label_placeholder: string;
+      from_label: string;
+      from_placeholder: string;
+      subject_label: string;
+      subject_placeholder: string;
+      unread_only: string;
+      mark_read: string;
+      folder_colon: string;
+      from_colon: string;
+      subject_colon: string;
+      unread_only_yes: string;
     };
     clipboard: {
       content_type: string;
@@ -9630,6 +9654,7 @@ export type Translations = {
       interval_minimum: string;
       event_type_required: string;
       watch_path_required: string;
+      email_credential_required: string;
       composite_min_conditions: string;
       composite_window_minimum: string;
       endpoint_required: string;
@@ -10728,6 +10753,8 @@ export type Translations = {
       scope_build_desc: string;
       scope_read: string;
       scope_read_desc: string;
+      scope_events: string;
+      scope_events_desc: string;
       scope_execute: string;
       scope_execute_desc: string;
       scope_execute_label: string;
@@ -11879,6 +11906,7 @@ export type Translations = {
       breadth_exceeded: string;
       healing_abandoned: string;
       healing_capped: string;
+      deadline_exceeded: string;
     };
     error_category: {
       rate_limit: string;
@@ -11891,6 +11919,7 @@ export type Translations = {
       tool_error: string;
       api_error: string;
       transient_process_failure: string;
+      contract_violation: string;
       unknown: string;
     };
     tool_error_kind: {
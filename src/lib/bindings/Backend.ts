@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Backend that actually executes a persona run.
+ */
+export type Backend = "claude_code" | "qwen" | "ollama" | "openai_api";
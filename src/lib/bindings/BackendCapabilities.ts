@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Backend } from "./Backend";
+
+/**
+ * One row of the capability matrix.
+ */
+export type BackendCapabilities = { backend: Backend, label: string, session_resume: boolean, persona_tools: boolean, 
+/**
+ * Output contract validation and the repair turn.
+ */
+structured_output: boolean, 
+/**
+ * `max_budget_usd` enforcement.
+ */
+budget_cap: boolean, max_turns: boolean, prompt_caching: boolean, 
+/**
+ * Deep fan-out via CLI subagents.
+ */
+subagents: boolean, container_sandbox: boolean, };
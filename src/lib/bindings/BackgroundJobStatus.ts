@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Dashboard row for one background loop.
+ */
+export type BackgroundJobStatus = { name: string, 
+/**
+ * Current poll interval (the idle interval while the app is idle).
+ */
+intervalMs: number, paused: boolean, 
+/**
+ * True while a tick is in flight.
+ */
+running: boolean, 
+/**
+ * ISO 8601 timestamp of the last tick start.
+ */
+lastRunAt: string | null, lastDurationMs: number | null, 
+/**
+ * ISO 8601 estimate of the next scheduled tick.
+ */
+nextRunAt: string | null, lastError: string | null, lastErrorAt: string | null, runCount: number, errorCount: number, };
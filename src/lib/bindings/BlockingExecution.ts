@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A running execution holding one of the persona's slots.
+ */
+export type BlockingExecution = { execution_id: string, status: string | null, started_at: string | null, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Budget position of one persona for the current billing period.
+ */
+export type BudgetStatus = { persona_id: string, persona_name: string, 
+/**
+ * Billing period (`YYYY-MM`, UTC).
+ */
+period: string, 
+/**
+ * Monthly cap. `None` or `0.0` means unlimited.
+ */
+budget_usd: number | null, spend_usd: number, 
+/**
+ * `budget - spend`, floored at zero. `None` when unlimited.
+ */
+remaining_usd: number | null, 
+/**
+ * Linear projection of this month's spend to the end of the month.
+ */
+projected_month_end_usd: number, over_budget: boolean, 
+/**
+ * True when the projection crosses the cap before the month ends.
+ */
+projected_over_budget: boolean, 
+/**
+ * True when budget enforcement (mode `pause`) disabled this persona.
+ */
+paused_by_budget: boolean, 
+/**
+ * `warn` | `block` | `pause`.
+ */
+enforcement_mode: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A feature the persona relies on that its backend lacks.
+ */
+export type CapabilityFinding = { feature: string, 
+/**
+ * `"warning"` (the feature is ignored) or `"blocking"` (running would
+ * violate an explicit persona setting).
+ */
+severity: string, message: string, };
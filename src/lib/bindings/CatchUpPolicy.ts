@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a schedule does about fire times that passed while the app was closed.
+ * Stored inside the trigger's `config` JSON as `catch_up`. When unset the
+ * legacy behavior applies: fire once, plus up to `max_backfill - 1` extras.
+ */
+export type CatchUpPolicy = "skip" | "run_once" | "run_all_missed";
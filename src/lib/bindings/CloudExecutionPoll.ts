@@ -29,4 +29,12 @@ progress: JsonValue | null,
 /**
  * Pending human-in-the-loop review requests.
  */
-pendingReviews: Array<JsonValue> | null, };
+pendingReviews: Array<JsonValue> | null, 
+/**
+ * Token usage, reported once the execution has finished.
+ */
+inputTokens: bigint | null, outputTokens: bigint | null, 
+/**
+ * Worker-reported error for a failed execution.
+ */
+errorMessage: string | null, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ReconciledCloudExecution } from "./ReconciledCloudExecution";
+
+/**
+ * Outcome of one reconciliation pass.
+ */
+export type CloudReconcileReport = { 
+/**
+ * Cloud executions whose local row might be stale.
+ */
+checked: number, 
+/**
+ * Rows rewritten with the orchestrator's result.
+ */
+reconciled: Array<ReconciledCloudExecution>, 
+/**
+ * Still running on the orchestrator; left for the next pass.
+ */
+stillRunning: number, 
+/**
+ * Unknown to the orchestrator (expired or purged); closed as failed.
+ */
+missing: number, 
+/**
+ * Could not be fetched; left for the next pass.
+ */
+errors: number, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Backend } from "./Backend";
+import type { CapabilityFinding } from "./CapabilityFinding";
+
+export type CompatibilityReport = { backend: Backend, findings: Array<CapabilityFinding>, };
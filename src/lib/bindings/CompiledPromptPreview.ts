@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptVariable } from "./PromptVariable";
+
+/**
+ * Result of `preview_compiled_prompt`.
+ */
+export type CompiledPromptPreview = { 
+/**
+ * The assembled prompt with every resolvable placeholder substituted.
+ */
+prompt: string, variables: Array<PromptVariable>, 
+/**
+ * Placeholders left in the prompt because no source defines them.
+ */
+unresolved: Array<string>, };
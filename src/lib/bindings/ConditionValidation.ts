@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of [`validate_condition`].
+ */
+export type ConditionValidation = { valid: boolean, error: string | null, 
+/**
+ * Whether the branch would be taken for the sample output, when one was
+ * given and the condition is valid.
+ */
+taken: boolean | null, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of the last builtin connector seeding pass.
+ */
+export type ConnectorSeedReport = { started_at: string, duration_ms: number, 
+/**
+ * Builtin definitions shipped with this build.
+ */
+total: bigint, 
+/**
+ * Connectors seeded for the first time.
+ */
+inserted: Array<string>, 
+/**
+ * Connectors whose stored definition was rewritten.
+ */
+upgraded: Array<string>, 
+/**
+ * Connectors skipped because their definition is unchanged.
+ */
+unchanged: bigint, 
+/**
+ * Retired connectors removed.
+ */
+removed: Array<string>, 
+/**
+ * Set when the pass failed; rows written before the failure are rolled back.
+ */
+error: string | null, };
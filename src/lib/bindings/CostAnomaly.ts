@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A day whose spend sits more than two standard deviations from the mean of
+ * the days before it.
+ */
+export type CostAnomaly = { date: string, costUsd: number, 
+/**
+ * Mean daily spend over the trailing window.
+ */
+trailingMeanUsd: number, trailingStdDevUsd: number, 
+/**
+ * `(cost_usd - trailing_mean_usd) / trailing_std_dev_usd`; negative for drops.
+ */
+deviationSigma: number, 
+/**
+ * The persona that spent the most that day, if any spent anything.
+ */
+topPersonaId: string | null, topPersonaName: string | null, topPersonaCostUsd: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CostDayPoint } from "./CostDayPoint";
+import type { CostGroup } from "./CostGroup";
+import type { CostGroupBy } from "./CostGroupBy";
+
+/**
+ * Spend over a window split along one dimension, with a day-level series for
+ * the whole window and for each group. Simulations are excluded.
+ */
+export type CostBreakdown = { periodDays: number, groupBy: CostGroupBy, totalCostUsd: number, totalExecutions: number, 
+/**
+ * One point per day of the window (UTC), oldest first, zero-filled.
+ */
+daily: Array<CostDayPoint>, 
+/**
+ * Groups descending by cost.
+ */
+groups: Array<CostGroup>, };
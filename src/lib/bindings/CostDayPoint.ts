@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Spend and run count on one day. `date` is YYYY-MM-DD (UTC).
+ */
+export type CostDayPoint = { date: string, costUsd: number, executions: number, };
@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Predicted cost of one execution and where it would leave the persona's
+ * monthly budget. Produced before the execution record exists so the budget
+ * gate can refuse a run that would overshoot, instead of only noticing after
+ * the spend happened.
+ */
+export type CostEstimate = { persona_id: string, use_case_id: string | null, 
+/**
+ * Predicted cost of a single run (USD). `0.0` when there is no trusted
+ * history yet.
+ */
+estimated_cost_usd: number, 
+/**
+ * Which baseline produced the estimate: `"use_case"` (this capability's
+ * own history), `"persona"` (sample-weighted mean across the persona's
+ * baselines) or `"none"` (not enough history).
+ */
+basis: string, 
+/**
+ * Historical runs behind the chosen baseline.
+ */
+sample_count: number, 
+/**
+ * Spend so far this calendar month (USD), same figure as the budget UI.
+ */
+monthly_spend_usd: number, 
+/**
+ * The persona's monthly cap. `None` or `0.0` means unlimited.
+ */
+budget_usd: number | null, 
+/**
+ * `monthly_spend_usd + estimated_cost_usd`.
+ */
+projected_spend_usd: number, 
+/**
+ * True when admitting this run would meet or cross the budget.
+ */
+would_exceed_budget: boolean, };
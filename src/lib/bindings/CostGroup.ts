@@ -0,0 +1,29 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CostDayPoint } from "./CostDayPoint";
+
+/**
+ * One group's slice of a [`CostBreakdown`].
+ */
+export type CostGroup = { 
+/**
+ * Persona / team / use-case id, model name or connector name. `none` for
+ * runs with nothing to group by (no team, model, use case or connector).
+ */
+key: string, 
+/**
+ * Display name: persona, team or use-case name; the key otherwise.
+ */
+label: string, costUsd: number, 
+/**
+ * Runs in this group. A run that used several connectors counts toward
+ * each of them, so these can sum past `total_executions`.
+ */
+executions: number, 
+/**
+ * `cost_usd / total_cost_usd` (0.0 when nothing was spent).
+ */
+share: number, 
+/**
+ * Same days as `CostBreakdown::daily`.
+ */
+daily: Array<CostDayPoint>, };
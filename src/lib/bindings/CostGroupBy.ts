@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Dimension a cost breakdown is grouped by.
+ */
+export type CostGroupBy = "persona" | "group" | "model" | "connector" | "use_case";
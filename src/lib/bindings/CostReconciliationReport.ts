@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DailyCostReconciliation } from "./DailyCostReconciliation";
+import type { PersonaCostDiscrepancy } from "./PersonaCostDiscrepancy";
+
+export type CostReconciliationReport = { provider: string, 
+/**
+ * First and last day covered by the export.
+ */
+from: string, to: string, billed_usd: number, recorded_usd: number, 
+/**
+ * `billed / recorded`; `None` when nothing was recorded for the range.
+ */
+ratio: number | null, days: Array<DailyCostReconciliation>, 
+/**
+ * Largest absolute discrepancy first.
+ */
+personas: Array<PersonaCostDiscrepancy>, 
+/**
+ * CSV rows without a parseable day or cost.
+ */
+skipped_rows: number, 
+/**
+ * Billed cost on days with no recorded executions (usage outside the
+ * app, or executions since pruned by retention).
+ */
+unattributed_usd: number, previous_factor: number, 
+/**
+ * The factor now in effect, when the correction was applied.
+ */
+applied_factor: number | null, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PersonaExecuteHook } from "./PersonaExecuteHook";
+
+/**
+ * Returned only when a hook token is (re)generated — the one time the
+ * plaintext token leaves the backend.
+ */
+export type CreateExecuteHookResponse = { hook: PersonaExecuteHook, plaintext_token: string, 
+/**
+ * Full local URL to POST to, e.g. `http://127.0.0.1:9420/hooks/execute/ph_…`.
+ */
+url: string, };
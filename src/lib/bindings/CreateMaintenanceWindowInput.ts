@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Arguments to `create_maintenance_window`.
+ */
+export type CreateMaintenanceWindowInput = { 
+/**
+ * `None` for a global window.
+ */
+persona_id: string | null, 
+/**
+ * RFC 3339; `None` starts the window now.
+ */
+starts_at: string | null, 
+/**
+ * RFC 3339; `None` for an open-ended window.
+ */
+ends_at: string | null, reason: string | null, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreateNotificationRuleInput = { name: string, category: string, personaId: string | null, teamId: string | null, minSeverity: string | null, action: string, channelType: string | null, credentialId: string | null, channelConfig: string | null, quietHoursStart: string | null, quietHoursEnd: string | null, maxPerHour: number | null, enabled: boolean | null, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Unusual credential usage flagged by the detector in
+ * `commands::credentials::intelligence`.
+ */
+export type CredentialAnomaly = { id: string, credentialId: string, credentialName: string, personaId: string | null, personaName: string | null, 
+/**
+ * "new_persona", "off_hours_burst" or "use_after_rotation"
+ */
+kind: string, 
+/**
+ * "medium" or "high"
+ */
+severity: string, title: string, description: string, 
+/**
+ * JSON object with the counts and timestamps behind the flag.
+ */
+evidence: string, 
+/**
+ * Identifies the underlying event so repeated scans don't re-flag it.
+ */
+dedupeKey: string, 
+/**
+ * "open" or "acknowledged"
+ */
+status: string, detectedAt: string, acknowledgedAt: string | null, };
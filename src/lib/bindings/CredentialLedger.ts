@@ -11,7 +11,20 @@ import type { LedgerHealthEntry } from "./LedgerHealthEntry";
  * - **Health ring buffer**: `healthcheck_results`, `healthcheck_last_success`,
  *   `healthcheck_last_success_at`, `anomaly_score`, `anomaly_tolerance`, `environment`
  * - **OAuth lifecycle**: `oauth_token_expires_at`, `oauth_refresh_count`, etc.
+ * - **Expiry**: `expires_at`, `expiry_alerted`
  * - **Usage tracking**: `usage_count`, `last_used_at`
  * - **Custom hints**: any other keys (imported_from, source, auth_type, …)
  */
-export type CredentialLedger = { healthcheck_results: Array<LedgerHealthEntry>, healthcheck_last_success: boolean | null, healthcheck_last_success_at: string | null, anomaly_score: LedgerAnomalyScore | null, anomaly_tolerance: number | null, environment: string | null, oauth_token_expires_at: string | null, oauth_refresh_count: bigint | null, oauth_last_refresh_at: string | null, oauth_predicted_lifetime_secs: bigint | null, oauth_refresh_backoff_until: string | null, oauth_refresh_fail_count: bigint | null, needs_reauth: boolean | null, needs_reauth_at: string | null, usage_count: bigint | null, last_used_at: string | null, };
+export type CredentialLedger = { healthcheck_results: Array<LedgerHealthEntry>, healthcheck_last_success: boolean | null, healthcheck_last_success_at: string | null, anomaly_score: LedgerAnomalyScore | null, anomaly_tolerance: number | null, environment: string | null, oauth_token_expires_at: string | null, oauth_refresh_count: bigint | null, oauth_last_refresh_at: string | null, oauth_predicted_lifetime_secs: bigint | null, oauth_refresh_backoff_until: string | null, oauth_refresh_fail_count: bigint | null, needs_reauth: boolean | null, needs_reauth_at: string | null, 
+/**
+ * RFC 3339 instant the credential itself stops working: a PAT or API
+ * key's expiry, or the provider-reported refresh token lifetime for
+ * OAuth (unlike `oauth_token_expires_at`, which the refresh engine
+ * renews on its own).
+ */
+expires_at: string | null, 
+/**
+ * `"<stage>:<expires_at>"` of the last renewal alert sent (`stage` is
+ * `warning` or `expired`), so each stage alerts once per expiry date.
+ */
+expiry_alerted: string | null, usage_count: bigint | null, last_used_at: string | null, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One credential negotiation run: the provisioning plan the negotiator
+ * generated and the credential it ended up producing.
+ */
+export type CredentialNegotiation = { 
+/**
+ * The `negotiation_id` returned by `start_credential_negotiation`.
+ */
+id: string, service_name: string, 
+/**
+ * `planning` | `guiding` | `completed` | `failed` | `cancelled`.
+ */
+status: string, 
+/**
+ * JSON-serialized provisioning plan, once generated.
+ */
+plan_json: string | null, 
+/**
+ * Credential saved from this negotiation, if any.
+ */
+credential_id: string | null, error: string | null, created_at: string, updated_at: string, };
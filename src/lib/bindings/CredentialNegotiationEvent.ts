@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A transcript entry. `detail_json` never holds credential values -- only
+ * field keys, step titles and secret-masked help text.
+ */
+export type CredentialNegotiationEvent = { id: number, negotiation_id: string, 
+/**
+ * `plan_requested` | `plan_ready` | `plan_failed` | `step_completed` |
+ * `fields_captured` | `help_requested` | `cancelled` | `credential_linked`.
+ */
+kind: string, step_index: number | null, detail_json: string | null, created_at: string, };
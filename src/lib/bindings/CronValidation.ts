@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CronValidation = { valid: boolean, 
+/**
+ * Human-readable schedule, including the zone and any exclusions.
+ */
+description: string, 
+/**
+ * IANA zone the expression is evaluated in (the system zone when none
+ * was supplied).
+ */
+timezone: string, 
+/**
+ * Next fire times (RFC3339), with excluded slots already skipped.
+ */
+next_runs: Array<string>, error: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type DailyCostReconciliation = { 
+/**
+ * `YYYY-MM-DD` (UTC).
+ */
+date: string, billed_usd: number, recorded_usd: number, 
+/**
+ * `billed - recorded`; positive means costs were under-estimated.
+ */
+delta_usd: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One backup file on disk.
+ */
+export type DatabaseBackupInfo = { path: string, file_name: string, size_bytes: bigint, 
+/**
+ * RFC3339 modification time of the file.
+ */
+created_at: string, 
+/**
+ * `manual` | `auto` | `pre_migration`.
+ */
+kind: string, 
+/**
+ * True for passphrase-sealed `.pbak` files.
+ */
+encrypted: boolean, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DatabaseIntegrityReport } from "./DatabaseIntegrityReport";
+
+/**
+ * Response of `check_database_health`.
+ */
+export type DatabaseHealth = { 
+/**
+ * Fresh `quick_check` of the open database.
+ */
+live: DatabaseIntegrityReport, 
+/**
+ * What the startup pass found and did this session.
+ */
+startup: DatabaseIntegrityReport | null, };
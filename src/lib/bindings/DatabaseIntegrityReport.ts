@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of one integrity pass.
+ */
+export type DatabaseIntegrityReport = { 
+/**
+ * `ok` | `reindexed` | `restored` | `corrupt`.
+ */
+status: string, 
+/**
+ * First problems reported by `quick_check`, when there were any.
+ */
+detail: string | null, 
+/**
+ * Backup copied over the corrupt database (status `restored`).
+ */
+restored_from: string | null, 
+/**
+ * Where the corrupt database was moved aside (status `restored`).
+ */
+quarantined_to: string | null, checked_at: string, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of `restore_database`: the backup was validated and staged.
+ */
+export type DatabaseRestoreResult = { source_path: string, 
+/**
+ * Always true — the restore is applied on the next launch.
+ */
+restart_required: boolean, };
@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TableContentionStats } from "./TableContentionStats";
+
+export type DbContentionSnapshot = { 
+/**
+ * Start of the measurement window (process start or last reset).
+ */
+since: string, 
+/**
+ * Tables ordered by busy errors, worst first.
+ */
+tables: Array<TableContentionStats>, totalBusyErrors: number, totalRecovered: number, totalExhausted: number, 
+/**
+ * Connections currently open in the main pool.
+ */
+poolConnections: number, 
+/**
+ * Open connections not checked out right now.
+ */
+poolIdle: number, poolMaxSize: number, };
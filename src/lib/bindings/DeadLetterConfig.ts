@@ -15,4 +15,9 @@ export type DeadLetterConfig = {
  * Maximum number of *manual* retries from the DLQ. Mirrors
  * `repo::MAX_MANUAL_RETRIES`.
  */
-maxManualRetries: number, };
+maxManualRetries: number, 
+/**
+ * How many copies `replay_event` may make of one event. Mirrors
+ * `repo::MAX_EVENT_REPLAYS`.
+ */
+maxEventReplays: number, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Timing of one deferred startup job.
+ */
+export type DeferredStartupTask = { name: string, 
+/**
+ * "pending" | "running" | "completed" | "failed"
+ */
+status: string, 
+/**
+ * Milliseconds since process start when the job began.
+ */
+startedAtMs: number | null, durationMs: number | null, error: string | null, };
@@ -17,4 +17,9 @@ cadence: string,
  * JSON array of notification channels (same format as persona notification_channels).
  * When empty, only OS notifications are sent.
  */
-channels: string | null, };
+channels: string | null, 
+/**
+ * Limit the digest to one group (the personas' home team). `None` covers
+ * every persona.
+ */
+groupId: string | null, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Healing activity over the period.
+ */
+export type DigestHealingSummary = { 
+/**
+ * Issues raised in the period.
+ */
+opened: number, 
+/**
+ * Of those, issues the engine fixed on its own.
+ */
+autoFixed: number, 
+/**
+ * Issues resolved in the period, whenever they were raised.
+ */
+resolved: number, 
+/**
+ * Issues still open when the digest was generated.
+ */
+stillOpen: number, };
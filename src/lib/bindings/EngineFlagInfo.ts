@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A flag as listed to the UI.
+ */
+export type EngineFlagInfo = { name: string, description: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcomes of terminal runs that had one engine feature flag active, for
+ * comparing an experimental path against the default.
+ */
+export type EngineFlagStats = { flag: string, runs: number, completed: number, failed: number, avg_cost_usd: number, avg_duration_ms: number | null, };
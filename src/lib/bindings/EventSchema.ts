@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Optional JSON Schema for one event type (`event_schemas`). When present,
+ * `publish_event` and webhook ingestion reject payloads that do not match,
+ * so subscribers never see a malformed event of that type.
+ */
+export type EventSchema = { event_type: string, 
+/**
+ * JSON Schema document, stored as text.
+ */
+schema: string, description: string | null, created_at: string, updated_at: string, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A dataset batch (`execution_batches`): one execution of a persona per
+ * input item, fanned out through the normal execution queue under a shared
+ * batch id.
+ */
+export type ExecutionBatch = { id: string, persona_id: string, use_case_id: string | null, 
+/**
+ * Items of this batch allowed in flight at once.
+ */
+concurrency: number, total_items: number, 
+/**
+ * `running`, `completed`, `cancelled`, or `interrupted` (the app exited
+ * before every item was started).
+ */
+status: string, created_at: string, completed_at: string | null, };
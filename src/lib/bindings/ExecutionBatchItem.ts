@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One input item of a batch (`execution_batch_items`).
+ */
+export type ExecutionBatchItem = { batch_id: string, item_index: number, input_data: string | null, 
+/**
+ * Set once the item's execution has been created.
+ */
+execution_id: string | null, 
+/**
+ * `pending`, `running`, `completed`, `failed` or `cancelled`.
+ */
+status: string, error: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Aggregated batch progress; also the `batch-execution-progress` payload.
+ */
+export type ExecutionBatchProgress = { batch_id: string, persona_id: string, status: string, total: number, pending: number, running: number, completed: number, failed: number, cancelled: number, 
+/**
+ * Spend across the batch's executions so far.
+ */
+cost_usd: number, };
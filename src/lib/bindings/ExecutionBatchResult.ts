@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One row of a batch results export.
+ */
+export type ExecutionBatchResult = { item_index: number, input_data: string | null, status: string, execution_id: string | null, output_data: string | null, 
+/**
+ * The item's start error, or its execution's error message.
+ */
+error: string | null, cost_usd: number | null, duration_ms: number | null, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One page of an execution log.
+ */
+export type ExecutionLogChunk = { content: string, 
+/**
+ * Byte offset of `content` in the (uncompressed) log.
+ */
+offset: number, 
+/**
+ * Offset to request for the next page.
+ */
+next_offset: number, 
+/**
+ * `true` when `content` runs to the end of the log.
+ */
+eof: boolean, };
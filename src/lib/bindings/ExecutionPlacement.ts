@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where `execute_persona` runs a persona when a cloud orchestrator is
+ * connected (stored lowercase on `personas.execution_placement`; NULL means
+ * `PreferLocal`, the behaviour before cloud routing existed).
+ *
+ * - `PreferLocal` — always run locally.
+ * - `PreferCloud` — submit to the cloud orchestrator whenever it is
+ *                   connected; local when it is not or submission fails.
+ * - `Auto`        — local, except when local concurrency is saturated or
+ *                   the machine is running on battery.
+ */
+export type ExecutionPlacement = "prefer_local" | "prefer_cloud" | "auto";
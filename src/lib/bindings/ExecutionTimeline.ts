@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TimelineEntry } from "./TimelineEntry";
+
+export type ExecutionTimeline = { execution_id: string, persona_id: string, 
+/**
+ * Attempt execution ids, original first.
+ */
+attempts: Array<string>, entries: Array<TimelineEntry>, 
+/**
+ * From the first entry to the last.
+ */
+total_duration_ms: number, 
+/**
+ * Some stream lines were left out (see `MAX_STREAM_LINES`).
+ */
+stream_truncated: boolean, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PersonaExecution } from "./PersonaExecution";
+
+/**
+ * Outcome of a synchronous execution.
+ */
+export type ExecutionWaitResult = { 
+/**
+ * True when the run reached a terminal state within the wait.
+ */
+completed: boolean, 
+/**
+ * Final row when `completed`, otherwise the last state seen — output,
+ * cost, tokens and duration are populated once the run has finished.
+ */
+execution: PersonaExecution, };
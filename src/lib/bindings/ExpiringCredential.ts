@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A credential whose `expires_at` falls inside a look-ahead window (or has
+ * already passed).
+ */
+export type ExpiringCredential = { credential_id: string, name: string, service_type: string, expires_at: string, 
+/**
+ * Whole days until expiry; negative once expired.
+ */
+days_remaining: bigint, expired: boolean, };
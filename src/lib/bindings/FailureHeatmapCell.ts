@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Failures in one local hour of day attributed to one service.
+ */
+export type FailureHeatmapCell = { 
+/**
+ * Hour of day (0-23) in the client's timezone.
+ */
+hour: number, 
+/**
+ * Connector name the failure mentions, `provider` for AI-provider session
+ * limits and missing CLIs, or `unattributed`.
+ */
+service: string, failures: number, 
+/**
+ * Most frequent snake_case `ErrorCategory` token in this cell.
+ */
+topCategory: string, };
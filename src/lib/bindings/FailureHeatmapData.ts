@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FailureHeatmapCell } from "./FailureHeatmapCell";
+import type { FailureHeatmapService } from "./FailureHeatmapService";
+
+/**
+ * Failures from failed executions and healing issues bucketed by hour of
+ * day × connector, for spotting recurring windows (e.g. nightly rate limits).
+ */
+export type FailureHeatmapData = { 
+/**
+ * Non-empty cells, sorted by hour then service.
+ */
+cells: Array<FailureHeatmapCell>, 
+/**
+ * Per-service totals, descending by failures.
+ */
+services: Array<FailureHeatmapService>, totalFailures: number, windowDays: number, generatedAt: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One service's failures over the window and its worst hour.
+ */
+export type FailureHeatmapService = { service: string, failures: number, peakHour: number, peakHourFailures: number, };
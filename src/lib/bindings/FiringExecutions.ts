@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FiringRollup } from "./FiringRollup";
+import type { GlobalExecutionRow } from "./GlobalExecutionRow";
+
+/**
+ * The executions one firing fanned out to, with their roll-up.
+ */
+export type FiringExecutions = { rollup: FiringRollup, executions: Array<GlobalExecutionRow>, };
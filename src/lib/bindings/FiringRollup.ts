@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Roll-up of every execution one trigger firing fanned out to.
+ */
+export type FiringRollup = { firing_id: string, 
+/**
+ * `running` while any run is active, then `completed` when every run
+ * completed, `failed` when none did, `partial` otherwise.
+ */
+status: string, total: number, 
+/**
+ * Queued or running.
+ */
+active: number, completed: number, 
+/**
+ * Failed, incomplete or cancelled.
+ */
+failed: number, cost_usd: number, input_tokens: number, output_tokens: number, first_created_at: string | null, 
+/**
+ * Latest completion, once no run is active.
+ */
+last_completed_at: string | null, };
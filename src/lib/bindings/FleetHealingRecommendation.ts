@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An aggregated backoff recommendation downloaded from the fleet pattern
+ * endpoint. Only consulted when the local knowledge base has no delay of its
+ * own for the same service + pattern.
+ */
+export type FleetHealingRecommendation = { service_type: string, pattern_key: string, recommended_delay_secs: number, 
+/**
+ * How many installs contributed to this aggregate.
+ */
+install_count: number, fetched_at: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HygieneFinding } from "./HygieneFinding";
+
+/**
+ * Outcome of one review pass.
+ */
+export type FleetHygieneReport = { generatedAt: string, idleWeeks: number, findings: Array<HygieneFinding>, 
+/**
+ * Persona messages written by this pass (already-flagged personas are skipped).
+ */
+messagesCreated: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of one fleet pattern exchange (upload + download).
+ */
+export type FleetPatternSyncResult = { uploaded: number, downloaded: number, synced_at: string, };
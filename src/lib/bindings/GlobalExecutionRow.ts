@@ -18,4 +18,9 @@ log_truncated: boolean,
 /**
  * Phase C3 — simulation runs are excluded from the default activity feed.
  */
-is_simulation: boolean, business_outcome: string, persona_name: string | null, persona_icon: string | null, persona_color: string | null, };
+is_simulation: boolean, business_outcome: string, 
+/**
+ * Groups every execution one trigger firing fanned out to: the id of the
+ * event the bus dispatched. `None` for runs not started by the bus.
+ */
+firing_id: string | null, persona_name: string | null, persona_icon: string | null, persona_color: string | null, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Month-to-date spend rolled up across one workspace (team).
+ */
+export type GroupSpend = { team_id: string, team_name: string, 
+/**
+ * Billing period (`YYYY-MM`, UTC).
+ */
+period: string, 
+/**
+ * Workspace cap. `None` or `0.0` means unlimited.
+ */
+budget_usd: number | null, 
+/**
+ * Sum of every member persona's spend this month.
+ */
+spend_usd: number, 
+/**
+ * `budget - spend`, floored at zero. `None` when unlimited.
+ */
+remaining_usd: number | null, projected_month_end_usd: number, 
+/**
+ * Personas whose `home_team_id` is this team.
+ */
+member_count: bigint, over_budget: boolean, projected_over_budget: boolean, 
+/**
+ * `warn` | `block` | `pause`.
+ */
+enforcement_mode: string, };
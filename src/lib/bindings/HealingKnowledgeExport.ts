@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealingKnowledgeRecord } from "./HealingKnowledgeRecord";
+
+/**
+ * Portable knowledge base document produced by `export_healing_knowledge`.
+ */
+export type HealingKnowledgeExport = { version: number, exported_at: string, entries: Array<HealingKnowledgeRecord>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One entry of an exported knowledge base, free of install-specific ids.
+ */
+export type HealingKnowledgeRecord = { service_type: string, pattern_key: string, description: string, recommended_delay_secs: number | null, 
+/**
+ * Defaults to 1 when absent, so hand-written seed files stay short.
+ */
+occurrence_count: number | null, };
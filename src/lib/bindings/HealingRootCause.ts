@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Structured root-cause summary of an execution failure, generated from the
+ * run's error and log tail.
+ */
+export type HealingRootCause = { 
+/**
+ * One-sentence account of what went wrong.
+ */
+summary: string, 
+/**
+ * The underlying cause, as specific as the evidence allows.
+ */
+cause: string, 
+/**
+ * Log lines or error fragments the analysis rests on.
+ */
+evidence: Array<string>, 
+/**
+ * Concrete next step for the user. Also replaces the issue's
+ * `suggested_fix`.
+ */
+suggested_fix: string, 
+/**
+ * "low" | "medium" | "high"
+ */
+confidence: string, generated_at: string, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One thing the review thinks should be cleaned up.
+ */
+export type HygieneFinding = { 
+/**
+ * `idle_persona` | `untouched_budget` | `breaker_disabled` | `unused_credential`.
+ */
+kind: string, 
+/**
+ * Persona id, or credential id for `unused_credential`.
+ */
+subjectId: string, subjectName: string, detail: string, 
+/**
+ * `archive` | `review_and_archive` | `clear_budget` | `delete_credential`.
+ */
+suggestedAction: string, };
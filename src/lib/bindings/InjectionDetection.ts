@@ -0,0 +1,50 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One flagged inbound payload.
+ */
+export type InjectionDetection = { id: string, eventId: string, eventType: string, sourceType: string, sourceId: string | null, 
+/**
+ * Trigger whose policy applied; `None` when the global default did.
+ */
+triggerId: string | null, 
+/**
+ * Heuristic score in `0.0..=1.0`.
+ */
+score: number, 
+/**
+ * `"medium"` | `"high"`.
+ */
+level: string, 
+/**
+ * JSON array of the heuristic signal names that matched.
+ */
+signals: string, 
+/**
+ * `"injection"` | `"benign"` when the LLM check ran.
+ */
+llmVerdict: string | null, 
+/**
+ * Policy in force: `"flag"` | `"sanitize"` | `"block"` | `"require_approval"`.
+ */
+policy: string, 
+/**
+ * What happened: `"flagged"` | `"sanitized"` | `"blocked"` | `"held"`.
+ */
+action: string, 
+/**
+ * SHA-256 of the raw payload; a reviewed false positive clears the hash.
+ */
+payloadHash: string, 
+/**
+ * First few hundred characters of the payload, for review.
+ */
+excerpt: string | null, 
+/**
+ * Pending trigger fire created when the policy held the event.
+ */
+pendingFireId: string | null, 
+/**
+ * `"unreviewed"` | `"false_positive"` | `"confirmed"`.
+ */
+reviewStatus: string, createdAt: string, reviewedAt: string | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A maintenance window (`maintenance_windows`). While one is active, trigger
+ * fires for the covered personas are still received and recorded as pending
+ * events, but the event bus leaves them unclaimed until the window ends.
+ */
+export type MaintenanceWindow = { id: string, 
+/**
+ * `None` = global: every persona is in maintenance.
+ */
+persona_id: string | null, starts_at: string, 
+/**
+ * `None` = open-ended; lasts until `end_maintenance_window` is called.
+ */
+ends_at: string | null, reason: string | null, created_at: string, };
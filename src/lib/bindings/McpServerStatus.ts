@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpServerState } from "./McpServerState";
+
+/**
+ * Status of one connector's MCP server, as returned by `list_mcp_servers`.
+ */
+export type McpServerStatus = { connector: string, label: string, package: string, state: McpServerState, 
+/**
+ * Package present in the managed install directory.
+ */
+installed: boolean, 
+/**
+ * Credential whose fields supplied the env, if any.
+ */
+credential_id: string | null, pid: number | null, started_at: string | null, last_health_check_at: string | null, tool_count: number | null, last_error: string | null, };
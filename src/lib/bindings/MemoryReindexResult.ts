@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of [`reindex_memories`].
+ */
+export type MemoryReindexResult = { 
+/**
+ * Stored vectors dropped before re-embedding.
+ */
+cleared: bigint, 
+/**
+ * Memories embedded by this run.
+ */
+embedded: bigint, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Period length of a `persona_metrics_rollups` row.
+ */
+export type MetricsRollupGranularity = "week" | "month";
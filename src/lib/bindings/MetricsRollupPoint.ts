@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One week or month of rolled-up metrics, summed over the selected personas.
+ */
+export type MetricsRollupPoint = { 
+/**
+ * First day of the period (UTC, `YYYY-MM-DD`).
+ */
+period_start: string, cost: number, executions: number, success: number, failed: number, tokens: number, 
+/**
+ * Execution-weighted mean of the daily average durations.
+ */
+avg_duration_ms: number, 
+/**
+ * Number of distinct personas with executions in the period.
+ */
+active_personas: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of `rebuild_metrics_snapshots`.
+ */
+export type MetricsSnapshotRebuild = { 
+/**
+ * First and last day rebuilt (UTC, inclusive).
+ */
+start_date: string, end_date: string, days: number, 
+/**
+ * Daily snapshot rows written or refreshed.
+ */
+snapshots_written: number, 
+/**
+ * Weekly and monthly rollup rows rewritten.
+ */
+rollups_written: number, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One deprecated model and what replaces it.
+ */
+export type ModelDeprecation = { model: string, replacement: string, 
+/**
+ * `YYYY-MM-DD`; from this day on the provider rejects the model.
+ */
+retiresOn: string, note?: string, };
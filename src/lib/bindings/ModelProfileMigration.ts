@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A persona whose stored model profile was (or would be) rewritten.
+ */
+export type ModelProfileMigration = { persona_id: string, persona_name: string, from: string, to: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CredentialNegotiation } from "./CredentialNegotiation";
+import type { CredentialNegotiationEvent } from "./CredentialNegotiationEvent";
+
+/**
+ * A negotiation with its entries, oldest first.
+ */
+export type NegotiationTranscript = { negotiation: CredentialNegotiation, events: Array<CredentialNegotiationEvent>, };
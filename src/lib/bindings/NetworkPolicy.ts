@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Global network rules for every persona's HTTP calls, stored as JSON in
+ * [`settings_keys::NETWORK_POLICY`]. Empty lists mean unrestricted.
+ */
+export type NetworkPolicy = { allowed_domains: Array<string>, denied_domains: Array<string>, allowed_methods: Array<string>, 
+/**
+ * Reach private, loopback, link-local and metadata addresses. Off by
+ * default; a persona's `http_request` policy can override it.
+ */
+allow_private_network: boolean, };
@@ -0,0 +1,55 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A notification routing rule (`notification_rules`). Every notification the
+ * app raises (budget alerts, healing issues, execution completions, manual
+ * reviews, messages) is matched against the enabled rules; a matching
+ * `deliver` rule sends it to the rule's channel, and a matching `silent` rule
+ * suppresses the desktop toast and the persona's own channels for it.
+ */
+export type NotificationRule = { id: string, name: string, 
+/**
+ * `budget`, `healing`, `execution`, `manual_review`, `message`, or `*`
+ * for every category.
+ */
+category: string, 
+/**
+ * Only notifications about this persona match. `None` matches all.
+ */
+personaId: string | null, 
+/**
+ * Only notifications about members of this workspace (team) match.
+ */
+teamId: string | null, 
+/**
+ * Lowest severity that matches: `info`, `low`, `medium`, `high` or
+ * `critical`.
+ */
+minSeverity: string, 
+/**
+ * `deliver` (send to the rule's channel) or `silent`.
+ */
+action: string, 
+/**
+ * `slack`, `telegram`, `email`, `discord` or `teams`; required for
+ * `deliver` rules.
+ */
+channelType: string | null, 
+/**
+ * Vault credential holding the channel's auth (bot token, webhook URL…).
+ */
+credentialId: string | null, 
+/**
+ * JSON object layered over the credential's fields, e.g.
+ * `{"channel": "#finance"}` or `{"chat_id": "123"}`.
+ */
+channelConfig: string | null, 
+/**
+ * Local-time `HH:MM` window during which the rule does not deliver.
+ * May wrap midnight (`22:00`–`07:00`).
+ */
+quietHoursStart: string | null, quietHoursEnd: string | null, 
+/**
+ * Deliveries allowed per rolling hour; further matches are dropped.
+ */
+maxPerHour: number | null, enabled: boolean, createdAt: string, updatedAt: string, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * User wording for one notification type (`notification_templates`).
+ * `{{variable}}` placeholders are filled from the notification; see
+ * `notifications::TEMPLATE_TYPES` for the variables each type offers.
+ */
+export type NotificationTemplate = { id: string, 
+/**
+ * `execution_completed`, `execution_failed`, `healing_issue`,
+ * `budget_exceeded` or `manual_review`.
+ */
+notificationType: string, 
+/**
+ * UI language the template applies to (`en`, `pt-br`, ...), or `*` for
+ * every language without a template of its own.
+ */
+locale: string, titleTemplate: string, bodyTemplate: string, enabled: boolean, createdAt: string, updatedAt: string, };
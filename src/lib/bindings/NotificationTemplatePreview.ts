@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A rendered template preview.
+ */
+export type NotificationTemplatePreview = { title: string, body: string, 
+/**
+ * Every variable the notification type offers.
+ */
+availableVariables: Array<string>, 
+/**
+ * The variables the template references.
+ */
+usedVariables: Array<string>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ObserverModeConfig = { enabled: boolean, 
+/**
+ * Regular expressions for customer-identifying strings (names, emails,
+ * domains). Matched case-insensitively.
+ */
+redactPatterns: Array<string>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A model installed on the Ollama server.
+ */
+export type OllamaModel = { 
+/**
+ * Tag to put in `model_profile.model`, e.g. `"qwen3:8b"`.
+ */
+name: string, size_bytes: number, modified_at: string | null, family: string | null, 
+/**
+ * e.g. `"8.2B"`.
+ */
+parameter_size: string | null, 
+/**
+ * e.g. `"Q4_K_M"`.
+ */
+quantization: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One progress line of a model pull, emitted as `ollama-pull-progress`.
+ */
+export type OllamaPullProgress = { model: string, 
+/**
+ * Ollama's status text ("pulling manifest", "downloading …", "success").
+ */
+status: string, completed: number | null, total: number | null, done: boolean, };
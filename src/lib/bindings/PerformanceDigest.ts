@@ -2,6 +2,7 @@
 import type { DigestAnomaly } from "./DigestAnomaly";
 import type { DigestCredentialHealth } from "./DigestCredentialHealth";
 import type { DigestFailureCategory } from "./DigestFailureCategory";
+import type { DigestHealingSummary } from "./DigestHealingSummary";
 import type { DigestPersonaTrend } from "./DigestPersonaTrend";
 
 /**
@@ -20,6 +21,10 @@ period: string,
  * Number of days covered (1 or 7).
  */
 periodDays: number, 
+/**
+ * Group the digest is limited to; `None` when it covers every persona.
+ */
+groupId: string | null, groupName: string | null, 
 /**
  * Total executions in the period.
  */
@@ -57,7 +62,16 @@ personaTrends: Array<DigestPersonaTrend>,
  */
 topFailures: Array<DigestFailureCategory>, 
 /**
- * Credential health changes.
+ * Healing issues raised, auto-fixed and resolved.
+ */
+healing: DigestHealingSummary, 
+/**
+ * Manual reviews waiting on a decision.
+ */
+pendingReviews: number, 
+/**
+ * Credential health changes. Credentials are shared, so this is never
+ * limited to the group.
  */
 credentialHealth: Array<DigestCredentialHealth>, 
 /**
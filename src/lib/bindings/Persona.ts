@@ -80,6 +80,19 @@ setup_detail: string | null,
  * NULL = no disables; this is the default for fresh + legacy rows.
  */
 disabled_dims_json: string | null, 
+/**
+ * Per-persona CLI engine override (`EngineKind::as_setting` string).
+ * NULL = use the global `cli_engine` setting. Resolved per execution by
+ * `engine::provider::resolve_engine_kind`; an unknown value falls back
+ * to the global default rather than failing the run.
+ */
+engine: string | null, 
+/**
+ * Cloud/local routing policy (`ExecutionPlacement` string). NULL =
+ * `prefer_local`. Applied by `execute_persona` when a cloud
+ * orchestrator is connected; an unknown value runs locally.
+ */
+execution_placement: string | null, 
 /**
  * First-class lifecycle stage (`draft` | `active` | `archived`). Replaces
  * the old frontend draft heuristic. Defaults to `active`; the build-stub
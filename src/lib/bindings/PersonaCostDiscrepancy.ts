@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type PersonaCostDiscrepancy = { persona_id: string, persona_name: string, recorded_usd: number, 
+/**
+ * The persona's share of the billed total, by its recorded share per day.
+ */
+attributed_usd: number, delta_usd: number, };
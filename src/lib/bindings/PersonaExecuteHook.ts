@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A persona's execute hook. The token is stored hashed like API keys; only the
+ * prefix is ever shown again after creation.
+ */
+export type PersonaExecuteHook = { persona_id: string, 
+/**
+ * First several chars of the token (e.g. "ph_a1b2c3"); safe to display.
+ */
+token_prefix: string, 
+/**
+ * Default seconds a caller waits for the result before getting the
+ * execution id back. `0` = always respond immediately.
+ */
+wait_timeout_secs: number, created_at: string, last_used_at: string | null, };
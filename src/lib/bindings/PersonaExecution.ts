@@ -54,4 +54,21 @@ director_score: number | null,
  * summary + coaching verdicts). Backs the "Director" tab in the execution
  * detail modal. `None` until the Director reviews this execution.
  */
-director_review_md: string | null, };
+director_review_md: string | null, 
+/**
+ * Experimental engine flags active for this run (JSON array of names,
+ * see `engine::feature_flags`). `None` when none were enabled.
+ */
+feature_flags: string | null, 
+/**
+ * Set when the output was too large to keep inline: points at the
+ * gzip file in the log directory holding the full text, and
+ * `output_data` holds only a preview. `get_execution` reads the file
+ * back transparently. See `engine::output_store`.
+ */
+output_ref: string | null, 
+/**
+ * Groups every execution one trigger firing fanned out to: the id of the
+ * event the bus dispatched. `None` for runs not started by the bus.
+ */
+firing_id: string | null, };
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HealingRootCause } from "./HealingRootCause";
 
 export type PersonaHealingIssue = { id: string, persona_id: string, execution_id: string | null, title: string, description: string, is_circuit_breaker: boolean, severity: string, category: string, suggested_fix: string | null, auto_fixed: boolean, status: string, created_at: string, resolved_at: string | null, 
 /**
@@ -7,4 +8,10 @@ export type PersonaHealingIssue = { id: string, persona_id: string, execution_id
  * verdict in an auto-fixable category. Drives the health-UI origin badge
  * and the Director's own dedup against its open issues.
  */
-source: string | null, };
+source: string | null, 
+/**
+ * Model-written analysis of the failure behind the issue, when the
+ * persona opted into root-cause summaries (see
+ * `engine::healing_root_cause`).
+ */
+root_cause: HealingRootCause | null, };
@@ -76,4 +76,10 @@ derived_from: Array<string> | null,
  * scorer: disputed memories sink in recall (bounded tanh penalty) but are
  * never silently removed — resolution is a human decision.
  */
-open_claim_count: number, };
+open_claim_count: number, 
+/**
+ * When set (RFC 3339, UTC), the memory stops being injected once this
+ * instant passes and the hourly cleanup sweep moves it to the archive
+ * tier. For facts with a shelf life (prices, endpoints, deadlines).
+ */
+expires_at: string | null, };
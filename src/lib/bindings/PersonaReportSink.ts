@@ -0,0 +1,32 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where and how a persona's run outputs are written as report files.
+ * One sink per persona (`persona_report_sinks`).
+ */
+export type PersonaReportSink = { persona_id: string, enabled: boolean, 
+/**
+ * Absolute directory the reports are written into (created on demand).
+ */
+directory: string, 
+/**
+ * `markdown` | `html`.
+ */
+format: string, 
+/**
+ * Mustache-style template (`{{execution.output}}`, `{{persona.name}}`,
+ * `{{date}}`, ...). `None` uses the built-in report layout.
+ */
+template: string | null, 
+/**
+ * Filename stem before the date stamp. Defaults to the persona name.
+ */
+filename_prefix: string | null, 
+/**
+ * Only write reports for runs fired by a schedule trigger.
+ */
+scheduled_only: boolean, 
+/**
+ * Also print the report to PDF through a local Chromium-family browser.
+ */
+pdf: boolean, last_written_path: string | null, last_written_at: string | null, last_error: string | null, created_at: string, updated_at: string, };
@@ -0,0 +1,34 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A persona's service-level objectives (`persona_slos`). The SLO monitor
+ * checks them every few minutes over a rolling window; crossing into breach
+ * opens a healing issue and sends a notification, once per breach.
+ */
+export type PersonaSlo = { persona_id: string, 
+/**
+ * Lowest acceptable success rate (0.0--1.0, same denominator as
+ * `success_rate` above). `None` = not tracked.
+ */
+min_success_rate: number | null, 
+/**
+ * Highest acceptable p95 duration of completed runs. `None` = not tracked.
+ */
+max_p95_duration_ms: number | null, window_hours: number, 
+/**
+ * Fewest decided runs in the window before the SLO is judged at all.
+ */
+min_sample: number, enabled: boolean, 
+/**
+ * Violated objectives (`success_rate`, `p95_duration`, comma-separated)
+ * while in breach.
+ */
+breach_reason: string | null, 
+/**
+ * When the current breach began; `None` while the SLO is met.
+ */
+breached_at: string | null, 
+/**
+ * Healing issue opened for the current breach, resolved on recovery.
+ */
+breach_issue_id: string | null, last_evaluated_at: string | null, created_at: string, updated_at: string, };
@@ -6,4 +6,11 @@ export type PersonaTeam = { id: string, project_id: string | null, parent_team_i
  * appended to every member persona's prompt; defaults applied to new
  * personas. Ported from PersonaGroup.
  */
-shared_instructions: string | null, default_model_profile: string | null, default_max_budget_usd: number | null, default_max_turns: number | null, created_at: string, updated_at: string, };
+shared_instructions: string | null, default_model_profile: string | null, default_max_budget_usd: number | null, default_max_turns: number | null, 
+/**
+ * Workspace-wide monthly spend cap (USD) summed across every persona whose
+ * `home_team_id` is this team. Unlike `default_max_budget_usd` (a default
+ * copied onto each persona), this is enforced on the aggregate. `None` or
+ * `0.0` means unlimited.
+ */
+monthly_budget_usd: number | null, created_at: string, updated_at: string, };
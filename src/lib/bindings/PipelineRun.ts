@@ -1,3 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PipelineSimulation } from "./PipelineSimulation";
 
-export type PipelineRun = { id: string, team_id: string, status: string, node_statuses: string, input_data: string | null, started_at: string, completed_at: string | null, error_message: string | null, };
+export type PipelineRun = { id: string, team_id: string, status: string, node_statuses: string, input_data: string | null, started_at: string, completed_at: string | null, error_message: string | null, 
+/**
+ * Dry-run settings when the run came from `simulate_team_run`; `None`
+ * for real runs. Kept on the row so a resumed or retried simulation
+ * stays simulated.
+ */
+simulation: PipelineSimulation | null, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SimulatedToolResponse } from "./SimulatedToolResponse";
+
+/**
+ * How `simulate_team_run` runs a pipeline without real side effects.
+ *
+ * Persona nodes run flagged as simulations (outbound delivery is skipped)
+ * and are told not to call tools: they use the configured responses and
+ * report every call they would make, which lands on the node status as
+ * `tool_calls`. Command nodes never run their shell command; they output
+ * the configured text, or a placeholder. Approval gates pass automatically
+ * and no team memories are written.
+ */
+export type PipelineSimulation = { tool_responses: Array<SimulatedToolResponse>, 
+/**
+ * Fabricated output per command-node member id.
+ */
+command_outputs: { [key in string]?: string }, };
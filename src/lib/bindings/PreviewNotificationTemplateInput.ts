@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A template to render without saving it.
+ */
+export type PreviewNotificationTemplateInput = { notificationType: string, titleTemplate: string, bodyTemplate: string, 
+/**
+ * Values to render with; variables left out use sample values.
+ */
+variables: { [key in string]?: string } | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A reusable prompt section (tone guide, compliance disclaimer, output
+ * format) shared across personas. A structured prompt references it as a
+ * `customSections` entry `{"blockId": "<id>"}`; the current content is
+ * inlined at run time.
+ */
+export type PromptBlock = { id: string, 
+/**
+ * Unique; used as the section heading unless the reference sets a title.
+ */
+name: string, 
+/**
+ * `tone` | `compliance` | `output_format` | `other`.
+ */
+category: string, content: string, description: string | null, created_at: string, updated_at: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TemplateVariablePersonaRef } from "./TemplateVariablePersonaRef";
+
+/**
+ * Which personas reference a block from their structured prompt.
+ */
+export type PromptBlockUsage = { block_id: string, 
+/**
+ * `None` when personas reference a block that no longer exists.
+ */
+block_name: string | null, personas: Array<TemplateVariablePersonaRef>, };
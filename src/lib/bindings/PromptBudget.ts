@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptSectionTokens } from "./PromptSectionTokens";
+
+/**
+ * Token counts of a compiled prompt against a model's context window.
+ */
+export type PromptBudget = { model: string, context_window: number, 
+/**
+ * Tokens the prompt may use: the window less the output reserve.
+ */
+available_tokens: number, total_tokens: number, system_prompt_tokens: number, memory_tokens: number, tool_tokens: number, input_tokens: number, sections: Array<PromptSectionTokens>, 
+/**
+ * `ok` | `warning` (over 80% of the available tokens) | `over`.
+ */
+status: string, warnings: Array<string>, 
+/**
+ * Headings of the sections [`fit_to_budget`] would drop to bring the prompt
+ * under budget, in drop order.
+ */
+trimmable: Array<string>, };
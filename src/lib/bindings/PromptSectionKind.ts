@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What a prompt section is spent on.
+ */
+export type PromptSectionKind = "system_prompt" | "memories" | "tools" | "input";
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptSectionKind } from "./PromptSectionKind";
+
+/**
+ * One `## ` section of a compiled prompt.
+ */
+export type PromptSectionTokens = { 
+/**
+ * Header text without the `## `; empty for the preamble.
+ */
+heading: string, kind: PromptSectionKind, tokens: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A variable a persona's prompt can reference as `{{<name>}}`.
+ */
+export type PromptVariable = { 
+/**
+ * Placeholder name, e.g. `today`, `param.tone`, `var.company_name`.
+ */
+name: string, 
+/**
+ * `builtin` | `parameter` | `registry` | `setting` | `input`.
+ */
+source: string, 
+/**
+ * Substituted value; input values are shown sanitized.
+ */
+value: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackendCapabilities } from "./BackendCapabilities";
+import type { CompatibilityReport } from "./CompatibilityReport";
+
+/**
+ * Capability matrix for every backend, plus the compatibility report for a
+ * persona when one is given.
+ */
+export type ProviderCapabilities = { backends: Array<BackendCapabilities>, persona: CompatibilityReport | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ExecutionState } from "./ExecutionState";
+
+/**
+ * A local execution updated from the orchestrator.
+ */
+export type ReconciledCloudExecution = { executionId: string, personaId: string, status: ExecutionState, error: string | null, durationMs: number | null, costUsd: number | null, };
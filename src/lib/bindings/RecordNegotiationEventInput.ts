@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A step-level event reported by the negotiator UI. Carries field keys,
+ * never their values.
+ */
+export type RecordNegotiationEventInput = { negotiation_id: string, 
+/**
+ * `step_completed` | `fields_captured`.
+ */
+kind: string, step_index: number | null, step_title: string | null, 
+/**
+ * Keys of the fields the user filled in.
+ */
+field_keys: Array<string>, };
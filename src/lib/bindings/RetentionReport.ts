@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What one retention pass removed.
+ */
+export type RetentionReport = { executions_deleted: number, messages_deleted: number, log_files_deleted: number, 
+/**
+ * Bytes of execution log files removed from disk.
+ */
+log_bytes_reclaimed: number, 
+/**
+ * Offloaded execution outputs removed along with their rows.
+ */
+output_files_deleted: number, output_bytes_reclaimed: number, 
+/**
+ * Logs of finished executions compressed during the pass.
+ */
+log_files_compressed: number, 
+/**
+ * Disk space compression saved.
+ */
+log_bytes_compressed: number, 
+/**
+ * Growth of the SQLite free-page list during the pass. Pages are reused by
+ * later writes; the file itself only shrinks on `VACUUM`.
+ */
+db_bytes_freed: number, ran_at: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fields accepted by `save_prompt_block`. With an `id` the block is updated
+ * in place; without one a new block is created.
+ */
+export type SavePromptBlockInput = { id: string | null, name: string, category: string, content: string, description: string | null, };
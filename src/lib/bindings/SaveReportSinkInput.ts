@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Settings accepted by `set_persona_report_sink`.
+ */
+export type SaveReportSinkInput = { enabled: boolean, directory: string, format: string, template: string | null, filename_prefix: string | null, scheduled_only: boolean, pdf: boolean, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fields accepted by `save_template_variable`. Saving an existing
+ * `(scope, scope_id, name)` replaces its value.
+ */
+export type SaveTemplateVariableInput = { scope: string, scope_id: string | null, name: string, value: string, description: string | null, };
@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Blackout window for a schedule trigger: cron fire times that land inside it
+ * are skipped. Stored inside the trigger's `config` JSON under the
+ * `exclusions` array and evaluated in the schedule's timezone.
+ *
+ * The constraints combine with AND: `{"days": [0, 6]}` is "not on weekends",
+ * `{"days": [1], "start": "00:00", "end": "06:00"}` is "not before 6 AM on
+ * Mondays", `{"dates": ["2026-12-25"]}` is "not on Christmas".
+ */
+export type ScheduleExclusion = { 
+/**
+ * Days of the week the exclusion applies on (0 = Sunday .. 6 = Saturday).
+ * Empty = every day.
+ */
+days: Array<number>, 
+/**
+ * Calendar dates ("YYYY-MM-DD") the exclusion applies on. Empty = every date.
+ */
+dates: Array<string>, 
+/**
+ * Start of the excluded time of day ("HH:MM", inclusive). When both
+ * `start` and `end` are unset the whole day is excluded.
+ */
+start: string | null, 
+/**
+ * End of the excluded time of day ("HH:MM", exclusive). A window whose
+ * end is before its start wraps past midnight.
+ */
+end: string | null, };
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CatchUpPolicy } from "./CatchUpPolicy";
 
 /**
  * Per-trigger record of scheduled slots that were DISCARDED while the app was
@@ -18,4 +19,18 @@ statusReason: string | null,
  * Human-facing detail for `status_reason` (e.g. the offending timezone +
  * parser error). Not translated — a diagnostic string.
  */
-statusReasonDetail: string | null, };
+statusReasonDetail: string | null, 
+/**
+ * Catch-up report: the trigger's `catch_up` policy the last time the
+ * scheduler found a missed-fire gap. `None` when no report is stored.
+ */
+catchUpPolicy: CatchUpPolicy | null, catchUpAt: string | null, 
+/**
+ * Fire times of the runs the catch-up started: replayed slots first, then
+ * the catch-up fire itself. Empty for `skip`.
+ */
+catchUpFired: Array<string>, 
+/**
+ * Missed slots the catch-up dropped.
+ */
+catchUpSkipped: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * One artifact on a scope's blackboard.
+ */
+export type SharedContextEntry = { id: string, 
+/**
+ * `pipeline`, `chain`, or `execution` (a chain root not yet adopted).
+ */
+scopeType: string, 
+/**
+ * Pipeline run id, chain trace id, or execution id.
+ */
+scopeId: string, key: string, value: JsonValue, 
+/**
+ * Persona that last wrote the key (None when set by the user).
+ */
+personaId: string | null, executionId: string | null, createdAt: string, updatedAt: string, };
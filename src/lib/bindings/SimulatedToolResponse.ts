@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A canned result a simulated persona node uses in place of calling a tool.
+ */
+export type SimulatedToolResponse = { tool_name: string, response: unknown, };
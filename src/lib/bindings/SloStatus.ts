@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PersonaSlo } from "./PersonaSlo";
+
+/**
+ * An SLO with its live measurement over the window, for the UI.
+ */
+export type SloStatus = { slo: PersonaSlo, persona_name: string, 
+/**
+ * `ok` | `breached` | `insufficient_data` | `disabled`.
+ */
+state: string, 
+/**
+ * Objectives violated right now (`success_rate`, `p95_duration`).
+ */
+violations: Array<string>, 
+/**
+ * Completed + failed runs in the window.
+ */
+decided: number, 
+/**
+ * `None` when the window has no decided runs.
+ */
+success_rate: number | null, 
+/**
+ * `None` when the window has no timed completed runs.
+ */
+p95_duration_ms: number | null, };
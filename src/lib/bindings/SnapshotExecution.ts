@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SnapshotExecution = { executionId: string, personaId: string, 
+/**
+ * The persona's `max_concurrent` at capture time.
+ */
+personaMaxConcurrent: number, 
+/**
+ * "low" | "normal" | "urgent" (queued executions only).
+ */
+priority: string | null, 
+/**
+ * Seconds spent in the queue so far (queued executions only).
+ */
+waitedSecs: number | null, };
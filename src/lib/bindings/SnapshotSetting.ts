@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SnapshotSetting = { key: string, value: string, 
+/**
+ * True when the value was replaced or masked; replay skips these.
+ */
+redacted: boolean, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DeferredStartupTask } from "./DeferredStartupTask";
+import type { StartupPhase } from "./StartupPhase";
+import type { StartupTimingReport } from "./StartupTimingReport";
+
+/**
+ * Backend startup profile: the blocking setup phases, the slowest of them,
+ * and the work deferred past window creation.
+ */
+export type StartupProfile = { 
+/**
+ * None until setup() has finished.
+ */
+report: StartupTimingReport | null, 
+/**
+ * Up to five setup phases, longest first.
+ */
+slowestPhases: Array<StartupPhase>, deferred: Array<DeferredStartupTask>, };
@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackgroundJobStatus } from "./BackgroundJobStatus";
+import type { ProviderCircuitState } from "./ProviderCircuitState";
+import type { SnapshotExecution } from "./SnapshotExecution";
+import type { SnapshotSetting } from "./SnapshotSetting";
+
+export type StateSnapshot = { 
+/**
+ * ISO 8601 capture time.
+ */
+capturedAt: string, appVersion: string, 
+/**
+ * Row count per table (SQLite internal tables excluded).
+ */
+tableCounts: Record<string, number>, 
+/**
+ * App settings, sorted by key.
+ */
+settings: Array<SnapshotSetting>, runningExecutions: Array<SnapshotExecution>, 
+/**
+ * Queued executions, longest-waiting first.
+ */
+queuedExecutions: Array<SnapshotExecution>, globalMaxConcurrent: number, 
+/**
+ * ISO 8601 end of the provider-quota cooldown, when one is active.
+ */
+quotaCooldownUntil: string | null, resourceThrottled: boolean, backgroundJobs: Array<BackgroundJobStatus>, circuitBreakers: Array<ProviderCircuitState>, circuitGlobalPaused: boolean, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StatusNarrative = { 
+/**
+ * One short line, e.g. "2 running · 1 review waiting · 3 need attention".
+ */
+headline: string, 
+/**
+ * Full sentences suitable for a screen reader.
+ */
+summary: string, 
+/**
+ * One plain sentence per problem that needs the user.
+ */
+attention: Array<string>, needs_attention: boolean, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlockingExecution } from "./BlockingExecution";
+import type { StuckQueuedExecution } from "./StuckQueuedExecution";
+
+/**
+ * Why a persona's queue is not draining, with the suggested one-click fix.
+ */
+export type StuckQueueReport = { persona_id: string, persona_name: string, max_concurrent: number, threshold_secs: number, queued: Array<StuckQueuedExecution>, blocking: Array<BlockingExecution>, 
+/**
+ * "persona_limit" | "global_limit" | "quota_cooldown" | "resource_pressure"
+ */
+cause: string, 
+/**
+ * Suggested `max_concurrent` for a bump, when the persona limit is the
+ * cause and can still be raised.
+ */
+suggested_max_concurrent: number | null, 
+/**
+ * Oldest blocking execution — the suggested cancel target.
+ */
+suggested_cancel_execution_id: string | null, };
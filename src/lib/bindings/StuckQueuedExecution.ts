@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A queued execution that exceeded the wait threshold.
+ */
+export type StuckQueuedExecution = { execution_id: string, waited_secs: number, 
+/**
+ * "low" | "normal" | "urgent"
+ */
+priority: string, };
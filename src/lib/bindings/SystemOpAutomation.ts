@@ -13,7 +13,9 @@ opKind: string,
  */
 paramsJson: string, 
 /**
- * `"schedule"` (cron) | `"event"` (event-bus listener).
+ * `"schedule"` (cron) | `"event"` (event-bus listener) | `"webhook"`
+ * (`/webhook/<id>`, secret in the `webhookSecret` param) | `"chain"` (an
+ * execution of the `source_filter` persona finishing).
  */
 triggerKind: string, 
 /**
@@ -29,7 +31,8 @@ timezone: string | null,
  */
 listenEventType: string | null, 
 /**
- * Optional wildcard filter on the event `source_id`.
+ * Optional wildcard filter on the event `source_id`; for `chain`, the
+ * source persona id (required).
  */
 sourceFilter: string | null, enabled: boolean, 
 /**
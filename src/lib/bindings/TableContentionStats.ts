@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type TableContentionStats = { table: string, 
+/**
+ * Busy/locked errors seen, counting every failed attempt.
+ */
+busyErrors: number, 
+/**
+ * Operations that hit a busy error and then succeeded on retry.
+ */
+recovered: number, 
+/**
+ * Operations that were still busy after the last attempt.
+ */
+exhausted: number, 
+/**
+ * Time spent sleeping between retries.
+ */
+backoffMs: number, lastBusyAt: string | null, };
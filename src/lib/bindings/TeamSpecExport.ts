@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of `export_team_spec`.
+ */
+export type TeamSpecExport = { spec_yaml: string, mermaid: string, dot: string, };
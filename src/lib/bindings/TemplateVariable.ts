@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A named value substituted into persona prompts as `{{var.<name>}}`.
+ * Resolution order is persona, then the persona's home team, then global.
+ */
+export type TemplateVariable = { id: string, 
+/**
+ * `global` | `team` | `persona`.
+ */
+scope: string, 
+/**
+ * Team or persona id; empty for `global`.
+ */
+scope_id: string, name: string, value: string, description: string | null, created_at: string, updated_at: string, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TemplateVariablePersonaRef } from "./TemplateVariablePersonaRef";
+
+/**
+ * Which personas reference `{{var.<name>}}` in their prompt fields.
+ */
+export type TemplateVariableUsage = { name: string, 
+/**
+ * False when personas reference a name no scope defines.
+ */
+defined: boolean, personas: Array<TemplateVariablePersonaRef>, };
@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TimelineEntryKind } from "./TimelineEntryKind";
+
+/**
+ * One step of the replay.
+ */
+export type TimelineEntry = { index: bigint, 
+/**
+ * Milliseconds since the first entry.
+ */
+at_ms: number, 
+/**
+ * Milliseconds since the previous entry.
+ */
+gap_ms: number, timestamp: string, kind: TimelineEntryKind, 
+/**
+ * Execution (attempt) the entry belongs to.
+ */
+execution_id: string, 
+/**
+ * 0 for the original run, then one per healing retry.
+ */
+attempt: number, title: string, detail: string | null, duration_ms: number | null, error: string | null, };
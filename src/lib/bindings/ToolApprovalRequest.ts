@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Payload of `event_name::TOOL_APPROVAL_REQUIRED`.
+ */
+export type ToolApprovalRequest = { review_id: string, execution_id: string, persona_id: string, persona_name: string, tool: string, 
+/**
+ * JSON of the call's input, as shown in the review.
+ */
+input: string, reason: string | null, };
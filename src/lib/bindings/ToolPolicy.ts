@@ -0,0 +1,52 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sandbox limits for one builtin tool. Empty lists mean unrestricted.
+ */
+export type ToolPolicy = { 
+/**
+ * Directories the tool may touch. Relative entries resolve against the
+ * execution directory, `~/` against the home directory.
+ */
+allowed_dirs: Array<string>, 
+/**
+ * Hosts the tool may call; subdomains of a listed host also match.
+ */
+allowed_domains: Array<string>, 
+/**
+ * Largest file the tool may read or write, in bytes.
+ */
+max_file_bytes: number | null, 
+/**
+ * `file_write`: no writes at all. `http_request`: GET/HEAD only.
+ */
+read_only: boolean, 
+/**
+ * `http_request`: hosts the tool may never call, subdomains included.
+ */
+denied_domains: Array<string>, 
+/**
+ * `http_request`: HTTP methods the tool may use.
+ */
+allowed_methods: Array<string>, 
+/**
+ * `http_request`: overrides the global policy's `allow_private_network`.
+ */
+allow_private_network: boolean | null, 
+/**
+ * `http_request`: longest a request may take, in seconds.
+ */
+timeout_secs: number | null, 
+/**
+ * `sql_query`: most rows returned per call.
+ */
+max_rows: number | null, 
+/**
+ * `sql_query`: largest result returned per call, in bytes of JSON.
+ */
+max_result_bytes: number | null, 
+/**
+ * `sql_query`: permit writes. Each run that writes still needs a human
+ * approval (`sql_write`).
+ */
+allow_write: boolean, };
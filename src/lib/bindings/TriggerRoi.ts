@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Cost and outcome of the runs one trigger started over a window — what each
+ * automation costs per firing and how often it succeeds, so expensive
+ * low-value triggers can be pruned. Simulations (dry-run firings) excluded.
+ */
+export type TriggerRoi = { 
+/**
+ * `None` for the persona's runs no trigger started (manual, API, pipeline).
+ */
+triggerId: string | null, 
+/**
+ * `schedule`, `webhook`, `chain`, … or `untriggered`.
+ */
+triggerType: string, enabled: boolean, 
+/**
+ * Executions the trigger started in the window, healing retries included.
+ */
+firings: number, succeeded: number, 
+/**
+ * Failed or incomplete runs.
+ */
+failed: number, 
+/**
+ * Runs whose `business_outcome` is `value_delivered`.
+ */
+valueDelivered: number, 
+/**
+ * `succeeded / finished runs` (0.0 when none finished).
+ */
+successRate: number, totalCostUsd: number, 
+/**
+ * `total_cost_usd / firings` (0.0 when it never fired).
+ */
+costPerFiring: number, 
+/**
+ * `total_cost_usd / succeeded`. `None` when nothing succeeded.
+ */
+costPerSuccess: number | null, avgDurationMs: number | null, lastFiredAt: string | null, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Manual correction of a knowledge entry. Omitted fields are unchanged;
+ * `recommended_delay_secs: null` clears the learned delay.
+ */
+export type UpdateHealingKnowledgeInput = { description: string | null, recommended_delay_secs: number | null | undefined, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Partial update payload. `None` leaves a field untouched; an empty string
+ * clears an optional text field, and `0` clears `max_per_hour`.
+ */
+export type UpdateNotificationRuleInput = { name: string | null, category: string | null, personaId: string | null, teamId: string | null, minSeverity: string | null, action: string | null, channelType: string | null, credentialId: string | null, channelConfig: string | null, quietHoursStart: string | null, quietHoursEnd: string | null, maxPerHour: number | null, enabled: boolean | null, };
@@ -13,6 +13,18 @@ cli_awareness_enabled: boolean | null,
  * the column with `Some(None)`. Set by the View-mode SigilEditModal.
  */
 disabled_dims_json: string | null | null, 
+/**
+ * Per-persona CLI engine override. `Some(None)` clears it back to the
+ * global `cli_engine` default; the repo validates the value against
+ * `EngineKind`.
+ */
+engine: string | null | null, 
+/**
+ * Cloud/local routing policy. `Some(None)` clears it back to
+ * `prefer_local`; the repo validates the value against
+ * `ExecutionPlacement`.
+ */
+execution_placement: string | null | null, 
 /**
  * Lifecycle stage (`draft` | `active` | `archived`). Partial-update:
  * `None` = leave unchanged. Promote/archive/restore drive this; the repo
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Partial edit of an existing memory. Omitted fields are left unchanged;
+ * `expires_at: null` clears the expiry.
+ */
+export type UpdatePersonaMemoryInput = { title: string | null, content: string | null, category: string | null, importance: number | null, 
+/**
+ * Replaces the tag set wholesale.
+ */
+tags: Array<string> | null, expires_at: string | null | null, };
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type UpdateTeamInput = { name: string | null, description: string | null | null, canvas_data: string | null | null, team_config: string | null | null, icon: string | null | null, color: string | null, enabled: boolean | null, shared_instructions: string | null | null, default_model_profile: string | null | null, default_max_budget_usd: number | null | null, default_max_turns: number | null | null, };
+export type UpdateTeamInput = { name: string | null, description: string | null | null, canvas_data: string | null | null, team_config: string | null | null, icon: string | null | null, color: string | null, enabled: boolean | null, shared_instructions: string | null | null, default_model_profile: string | null | null, default_max_budget_usd: number | null | null, default_max_turns: number | null | null, monthly_budget_usd: number | null | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Arguments to `upsert_event_schema`.
+ */
+export type UpsertEventSchemaInput = { event_type: string, schema: string, description: string | null, };
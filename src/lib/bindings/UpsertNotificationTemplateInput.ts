@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Create the template for a type + locale, or replace the existing one.
+ */
+export type UpsertNotificationTemplateInput = { notificationType: string, 
+/**
+ * Defaults to `*`.
+ */
+locale: string | null, titleTemplate: string, bodyTemplate: string, enabled: boolean | null, };
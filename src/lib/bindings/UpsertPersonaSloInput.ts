@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Arguments to `upsert_persona_slo`. At least one objective must be set.
+ */
+export type UpsertPersonaSloInput = { persona_id: string, min_success_rate: number | null, max_p95_duration_ms: number | null, 
+/**
+ * Defaults to 24.
+ */
+window_hours: number | null, 
+/**
+ * Defaults to 5.
+ */
+min_sample: number | null, 
+/**
+ * Defaults to true.
+ */
+enabled: boolean | null, };
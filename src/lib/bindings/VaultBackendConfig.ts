@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VaultBackendKind } from "./VaultBackendKind";
+
+/**
+ * Persisted backend selection (JSON in the `vault_backend` setting).
+ */
+export type VaultBackendConfig = { backend: VaultBackendKind, 
+/**
+ * HashiCorp Vault address, e.g. `https://vault.example.com:8200`.
+ */
+vaultAddr: string | null, 
+/**
+ * KV v2 mount (default `secret`).
+ */
+vaultMount: string | null, 
+/**
+ * Secret path under the mount (default `personas/master-key`).
+ */
+vaultPath: string | null, 
+/**
+ * Field of the secret holding the base64 key (default `key`).
+ */
+vaultField: string | null, };
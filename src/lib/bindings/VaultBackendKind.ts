@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where the master key is stored.
+ */
+export type VaultBackendKind = "keychain" | "encrypted_file" | "hashicorp_vault";
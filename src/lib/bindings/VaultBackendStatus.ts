@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { VaultBackendConfig } from "./VaultBackendConfig";
+import type { VaultBackendKind } from "./VaultBackendKind";
+
+/**
+ * Current selection and lock state, for the settings UI.
+ */
+export type VaultBackendStatus = { 
+/**
+ * Backend in force for this process.
+ */
+active: VaultBackendKind, 
+/**
+ * Stored selection; differs from `active` until the next start.
+ */
+configured: VaultBackendConfig, 
+/**
+ * Whether the master key is not available yet.
+ */
+locked: boolean, keySource: string, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of [`import_key`].
+ */
+export type VaultKeyImportReport = { 
+/**
+ * The imported key is the one already in use; nothing was rewritten.
+ */
+sameKey: boolean, 
+/**
+ * Values moved from the imported key to the current one.
+ */
+reencrypted: bigint, 
+/**
+ * Values the current key already opens.
+ */
+alreadyCurrent: bigint, 
+/**
+ * Values neither key opens; left untouched.
+ */
+failed: bigint, };
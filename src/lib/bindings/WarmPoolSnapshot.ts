@@ -0,0 +1,40 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WarmPoolSnapshot = { 
+/**
+ * Start of the measurement window (process start).
+ */
+since: string, 
+/**
+ * Configured pool size; `0` when the pool is off.
+ */
+poolSize: number, 
+/**
+ * Personas with an idle session right now.
+ */
+idlePersonaIds: Array<string>, 
+/**
+ * Runs that attached to a warm session.
+ */
+hits: number, 
+/**
+ * Eligible runs that found no usable session and spawned cold.
+ */
+misses: number, 
+/**
+ * Sessions pre-spawned.
+ */
+spawned: number, 
+/**
+ * Sessions killed unused after the idle TTL or to make room.
+ */
+expired: number, 
+/**
+ * Sessions dropped because they had exited or no longer matched the
+ * persona's configuration.
+ */
+discarded: number, 
+/**
+ * Estimated start latency saved across all hits.
+ */
+savedMs: number, };
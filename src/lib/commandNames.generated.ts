@@ -1,7 +1,7 @@
 // AUTO-GENERATED by scripts/generate-command-names.mjs — DO NOT EDIT
 // Re-run: node scripts/generate-command-names.mjs
 //
-// Generated from src-tauri/src/lib.rs invoke_handler (1492 commands)
+// Generated from src-tauri/src/lib.rs invoke_handler (1636 commands)
 
 /**
  * Union of every Tauri command name registered in the invoke_handler.
@@ -13,6 +13,7 @@ export type CommandName =
   | "abort_team_assignment"
   | "accept_recipe_version"
   | "acknowledge_audit_incident"
+  | "acknowledge_credential_anomaly"
   | "add_annotation"
   | "add_context_rule"
   | "add_mcp_gateway_member"
@@ -77,6 +78,7 @@ export type CommandName =
   | "backfill_review_categories"
   | "backfill_schedule"
   | "backfill_service_flow"
+  | "backup_database"
   | "batch_delete_memories"
   | "batch_delete_team_memories"
   | "batch_import_design_reviews"
@@ -93,8 +95,10 @@ export type CommandName =
   | "bulk_resolve_audit_incidents"
   | "bulk_retry_dead_letter_events"
   | "bulk_unassign_tools"
+  | "bump_persona_concurrency"
   | "cancel_auto_cred_browser"
   | "cancel_automation_design"
+  | "cancel_batch_execution"
   | "cancel_build_session"
   | "cancel_credential_design"
   | "cancel_credential_negotiation"
@@ -117,9 +121,12 @@ export type CommandName =
   | "cancel_template_generate"
   | "cancel_test_run"
   | "cancel_workflow_job"
+  | "capture_state_snapshot"
   | "capture_validation_screenshot"
   | "check_auto_cred_playwright_available"
   | "check_claude_desktop_mcp"
+  | "check_database_health"
+  | "check_mcp_server_health"
   | "classify_db_query"
   | "cleanup_dead_trigger_events"
   | "cleanup_duplicate_reviews"
@@ -161,6 +168,7 @@ export type CommandName =
   | "cloud_oauth_refresh"
   | "cloud_oauth_status"
   | "cloud_pause_deployment"
+  | "cloud_reconcile_executions"
   | "cloud_reconnect_from_keyring"
   | "cloud_respond_to_review"
   | "cloud_resume_deployment"
@@ -275,6 +283,8 @@ export type CommandName =
   | "compile_from_intent"
   | "compile_persona_memories"
   | "compile_workflow"
+  | "configure_vault_backend"
+  | "confirm_agent_framework_import"
   | "confirm_n8n_persona_draft"
   | "connect_to_peer"
   | "connector_explorer_explore"
@@ -299,11 +309,14 @@ export type CommandName =
   | "create_external_api_key"
   | "create_fired_alert"
   | "create_knowledge_base"
+  | "create_maintenance_window"
   | "create_memory"
   | "create_n8n_session"
+  | "create_notification_rule"
   | "create_notification_subscription"
   | "create_output_assertion"
   | "create_persona"
+  | "create_persona_execute_hook"
   | "create_recipe"
   | "create_rotation_policy"
   | "create_saved_view"
@@ -344,17 +357,25 @@ export type CommandName =
   | "delete_design_conversation"
   | "delete_design_review"
   | "delete_document_signature"
+  | "delete_event_schema"
   | "delete_exposed_resource"
   | "delete_external_api_key"
+  | "delete_healing_knowledge"
   | "delete_knowledge_base"
+  | "delete_maintenance_window"
   | "delete_memory"
   | "delete_message"
   | "delete_n8n_session"
+  | "delete_notification_rule"
   | "delete_notification_subscription"
+  | "delete_notification_template"
   | "delete_ocr_document"
   | "delete_output_assertion"
   | "delete_persona"
   | "delete_persona_icon"
+  | "delete_persona_report_sink"
+  | "delete_persona_slo"
+  | "delete_prompt_block"
   | "delete_recipe"
   | "delete_rotation_policy"
   | "delete_saved_view"
@@ -364,6 +385,7 @@ export type CommandName =
   | "delete_team_assignment"
   | "delete_team_connection"
   | "delete_team_memory"
+  | "delete_template_variable"
   | "delete_test_run"
   | "delete_test_suite"
   | "delete_tool_definition"
@@ -371,6 +393,7 @@ export type CommandName =
   | "delete_trusted_peer"
   | "deploy_automation"
   | "derive_recipes_from_template"
+  | "describe_cron"
   | "detect_authenticated_services"
   | "dev_checkpoint_stage"
   | "dev_fork_from_checkpoint"
@@ -515,6 +538,7 @@ export type CommandName =
   | "dev_tools_reorder_goals"
   | "dev_tools_resolve_goal_acceptance"
   | "dev_tools_resolve_goal_progress"
+  | "dev_tools_revoke_project_data_key"
   | "dev_tools_run_goal_uat"
   | "dev_tools_run_scan"
   | "dev_tools_run_standards_scan"
@@ -586,9 +610,11 @@ export type CommandName =
   | "dry_run_persona"
   | "dry_run_trigger"
   | "duplicate_persona"
+  | "end_maintenance_window"
   | "enqueue_persona_memory_curation"
   | "enqueue_persona_memory_reflection"
   | "enqueue_team_memory_reflection"
+  | "estimate_execution_cost"
   | "evict_team_memories"
   | "evolution_check_eligibility"
   | "evolution_delete_policy"
@@ -603,17 +629,24 @@ export type CommandName =
   | "execute_desktop_plan"
   | "execute_mcp_tool"
   | "execute_persona"
+  | "execute_persona_and_wait"
   | "execute_recipe"
   | "execute_team"
+  | "export_batch_results"
   | "export_bundle_to_clipboard"
   | "export_credentials"
+  | "export_credentials_bundle"
   | "export_full"
+  | "export_healing_knowledge"
   | "export_identity_card"
   | "export_persona"
   | "export_persona_bundle"
+  | "export_persona_to_n8n"
   | "export_selective"
   | "export_selective_to_path"
   | "export_signature_sidecar"
+  | "export_team_spec"
+  | "export_vault_key"
   | "fetch_roadmap"
   | "file_memory_claim"
   | "fleet_check_hooks"
@@ -649,6 +682,8 @@ export type CommandName =
   | "gallery_publish_persona"
   | "gallery_publish_preset"
   | "gc_stale_manual_reviews"
+  | "generate_digest_now"
+  | "generate_healing_root_cause"
   | "generate_persona_icon"
   | "generate_signing_key"
   | "generate_template_background"
@@ -677,6 +712,8 @@ export type CommandName =
   | "get_auth_state"
   | "get_automation"
   | "get_automation_runs"
+  | "get_batch_execution_progress"
+  | "get_budget_status"
   | "get_build_status"
   | "get_bulk_delivery_summaries"
   | "get_byom_policy"
@@ -693,9 +730,13 @@ export type CommandName =
   | "get_connector"
   | "get_context_rule_matches"
   | "get_context_stream_stats"
+  | "get_cost_anomalies"
+  | "get_cost_breakdown"
+  | "get_cost_correction_factors"
   | "get_crash_logs"
   | "get_credential_recipe"
   | "get_credential_recipes"
+  | "get_db_contention_stats"
   | "get_db_performance"
   | "get_dead_letter_config"
   | "get_design_conversation"
@@ -712,22 +753,28 @@ export type CommandName =
   | "get_discovered_peers"
   | "get_document_signature"
   | "get_dream_replay"
+  | "get_engine_flag_stats"
   | "get_error_category_breakdown"
   | "get_eval_run"
+  | "get_event_schema"
   | "get_event_skipped_stats"
   | "get_execution"
   | "get_execution_dashboard"
   | "get_execution_heatmap"
   | "get_execution_log"
   | "get_execution_log_lines"
+  | "get_execution_log_range"
+  | "get_execution_timeline"
   | "get_execution_trace"
   | "get_export_stats"
   | "get_exposed_resource"
   | "get_exposure_manifest"
+  | "get_failure_heatmap"
   | "get_frontend_crash_count"
   | "get_frontend_crashes"
   | "get_google_credential_oauth_status"
   | "get_google_drive_status"
+  | "get_group_spend"
   | "get_healing_effectiveness"
   | "get_healing_issue"
   | "get_healing_timeline"
@@ -749,12 +796,15 @@ export type CommandName =
   | "get_messages_by_thread"
   | "get_messaging_metrics"
   | "get_metrics_chart_data"
+  | "get_metrics_rollups"
   | "get_metrics_summary"
+  | "get_model_deprecations"
   | "get_model_routing_rules"
   | "get_n8n_payload_limits"
   | "get_n8n_session"
   | "get_n8n_transform_snapshot"
   | "get_negotiation_step_help"
+  | "get_negotiation_transcript"
   | "get_network_snapshot"
   | "get_network_status"
   | "get_nl_query_snapshot"
@@ -763,6 +813,7 @@ export type CommandName =
   | "get_oauth_status"
   | "get_oauth_token_lifetime_summary"
   | "get_oauth_token_metrics"
+  | "get_observer_mode"
   | "get_ocr_document"
   | "get_output_assertion"
   | "get_overview_bundle"
@@ -773,10 +824,13 @@ export type CommandName =
   | "get_persona_config_warnings"
   | "get_persona_curation_schedule"
   | "get_persona_detail"
+  | "get_persona_engine_flags"
+  | "get_persona_execute_hook"
   | "get_persona_icon_gen_spend"
   | "get_persona_job"
   | "get_persona_memory_review_proposal"
   | "get_persona_recipes"
+  | "get_persona_report_sink"
   | "get_persona_summaries"
   | "get_pipeline_analytics"
   | "get_pipeline_run"
@@ -784,10 +838,13 @@ export type CommandName =
   | "get_playwright_procedure"
   | "get_policy_events_for_execution"
   | "get_preset_adoption_schema"
+  | "get_prompt_block_usage"
+  | "get_prompt_budget"
   | "get_prompt_error_rate"
   | "get_prompt_performance"
   | "get_prompt_versions"
   | "get_prompt_versions_bulk"
+  | "get_provider_capabilities"
   | "get_provider_usage_stats"
   | "get_provider_usage_timeseries"
   | "get_quality_gate_config"
@@ -809,11 +866,16 @@ export type CommandName =
   | "get_scheduler_status"
   | "get_schema_proposal_snapshot"
   | "get_scoped_resources"
+  | "get_seed_report"
   | "get_session_public_key"
+  | "get_shared_context"
   | "get_shared_knowledge_injection"
   | "get_simulation_artefacts"
   | "get_sla_dashboard"
+  | "get_slo_status"
+  | "get_startup_profile"
   | "get_startup_timing"
+  | "get_status_narrative"
   | "get_subscription_health"
   | "get_system_api_key"
   | "get_system_metrics"
@@ -828,6 +890,7 @@ export type CommandName =
   | "get_template_generate_snapshot"
   | "get_template_manifest_count"
   | "get_template_performance"
+  | "get_template_variable_usage"
   | "get_test_results"
   | "get_test_suite"
   | "get_thread_count"
@@ -841,11 +904,13 @@ export type CommandName =
   | "get_tool_usage_summary"
   | "get_trending_templates"
   | "get_trigger_health_map"
+  | "get_trigger_roi"
   | "get_unread_message_count"
   | "get_use_case_cascade"
   | "get_use_case_recipes"
   | "get_validation_rules"
   | "get_value_rollup"
+  | "get_warm_pool_stats"
   | "get_webhook_status"
   | "get_workflow_job_output"
   | "get_workflows_overview"
@@ -883,15 +948,20 @@ export type CommandName =
   | "healthcheck_mcp_preview"
   | "import_claude_mcp_servers"
   | "import_credentials"
+  | "import_credentials_bundle"
   | "import_design_review"
   | "import_foraged_credential"
   | "import_from_share_link"
+  | "import_healing_knowledge"
   | "import_persona"
   | "import_persona_icon"
   | "import_portability_bundle"
   | "import_portability_bundle_from_path"
+  | "import_team_spec"
   | "import_trusted_peer"
+  | "import_vault_key"
   | "initialize_event_handlers_for_persona"
+  | "install_mcp_server"
   | "instant_adopt_template"
   | "instantiate_assignment_template"
   | "introspect_db_columns"
@@ -947,6 +1017,7 @@ export type CommandName =
   | "lab_start_eval"
   | "lab_start_matrix"
   | "lab_tag_version"
+  | "link_negotiation_credential"
   | "link_persona_to_event"
   | "link_recipe_to_persona"
   | "list_active_chains"
@@ -960,6 +1031,9 @@ export type CommandName =
   | "list_assignment_templates"
   | "list_audit_incidents"
   | "list_automations"
+  | "list_background_jobs"
+  | "list_batch_execution_items"
+  | "list_batch_executions"
   | "list_build_sessions"
   | "list_chat_sessions"
   | "list_cli_capturable_services"
@@ -967,11 +1041,13 @@ export type CommandName =
   | "list_connector_resources"
   | "list_connectors"
   | "list_context_rules"
+  | "list_credential_anomalies"
   | "list_credential_events"
   | "list_credential_fields"
   | "list_credential_recipes"
   | "list_credentials"
   | "list_cron_agents"
+  | "list_database_backups"
   | "list_db_saved_queries"
   | "list_db_schema_tables"
   | "list_dead_letter_events"
@@ -985,27 +1061,35 @@ export type CommandName =
   | "list_director_score_trends"
   | "list_director_verdicts"
   | "list_document_signatures"
+  | "list_engine_flags"
   | "list_eval_runs"
+  | "list_event_schemas"
   | "list_events"
   | "list_events_in_range"
   | "list_execution_annotations"
   | "list_execution_knowledge"
   | "list_executions"
+  | "list_executions_by_firing"
   | "list_executions_by_trigger"
   | "list_executions_for_use_case"
   | "list_executions_summary"
+  | "list_expiring_credentials"
   | "list_exposed_resources"
   | "list_external_api_keys"
   | "list_fired_alerts"
+  | "list_fleet_healing_recommendations"
   | "list_healing_audit_log"
   | "list_healing_issues"
   | "list_healing_knowledge"
   | "list_image_gen_credentials"
+  | "list_injection_detections"
   | "list_knowledge_bases"
   | "list_known_event_types"
+  | "list_maintenance_windows"
   | "list_manual_reviews"
   | "list_manual_reviews_page"
   | "list_mcp_gateway_members"
+  | "list_mcp_servers"
   | "list_mcp_tools"
   | "list_memories"
   | "list_memories_by_execution"
@@ -1015,9 +1099,12 @@ export type CommandName =
   | "list_messages"
   | "list_n8n_session_summaries"
   | "list_n8n_sessions"
+  | "list_notification_rules"
   | "list_notification_subscriptions"
+  | "list_notification_templates"
   | "list_oauth_providers"
   | "list_ocr_documents"
+  | "list_ollama_models"
   | "list_output_assertions"
   | "list_owned_devices"
   | "list_pending_build_questions"
@@ -1032,6 +1119,7 @@ export type CommandName =
   | "list_personas_using_connector"
   | "list_pipeline_runs"
   | "list_platform_definitions"
+  | "list_prompt_blocks"
   | "list_provenance"
   | "list_provider_audit_by_persona"
   | "list_provider_audit_log"
@@ -1063,6 +1151,7 @@ export type CommandName =
   | "list_team_presets"
   | "list_teams"
   | "list_template_feedback"
+  | "list_template_variables"
   | "list_test_runs"
   | "list_test_suites"
   | "list_tool_definitions"
@@ -1085,6 +1174,7 @@ export type CommandName =
   | "memory_health_scan"
   | "merge_deliberation_tracks"
   | "merge_memories"
+  | "migrate_model_profiles"
   | "migrate_plaintext_credentials"
   | "n8n_activate_workflow"
   | "n8n_create_workflow"
@@ -1134,12 +1224,16 @@ export type CommandName =
   | "ocr_with_gemini"
   | "open_external_url"
   | "open_local_path"
+  | "open_negotiation_transcript"
   | "openapi_generate_connector"
   | "openapi_parse_from_content"
   | "openapi_parse_from_url"
   | "openapi_playground_test"
+  | "parse_agent_framework_config"
   | "parse_api_definition"
+  | "parse_gpt_export"
   | "patch_credential_metadata"
+  | "pause_pipeline_run"
   | "pause_team_assignment"
   | "persona_blast_radius"
   | "poll_deliberation_action"
@@ -1148,9 +1242,11 @@ export type CommandName =
   | "preview_bundle_from_clipboard"
   | "preview_bundle_import"
   | "preview_competitive_import"
+  | "preview_compiled_prompt"
   | "preview_cron_schedule"
   | "preview_digest"
   | "preview_execution"
+  | "preview_notification_template"
   | "preview_prompt"
   | "preview_share_link"
   | "probe_cli_capabilities"
@@ -1165,6 +1261,7 @@ export type CommandName =
   | "promote_use_case_to_recipe"
   | "prune_storage"
   | "publish_event"
+  | "pull_ollama_model"
   | "radio_fetch_somafm_metadata"
   | "radio_get_now_playing"
   | "radio_get_state"
@@ -1179,16 +1276,21 @@ export type CommandName =
   | "radio_track_ended"
   | "read_sidecar_file"
   | "rebuild_design_review"
+  | "rebuild_metrics_snapshots"
+  | "reconcile_provider_usage"
+  | "record_negotiation_event"
   | "record_referral"
   | "refine_design"
   | "reflect_memories_with_cli"
   | "reflect_team_memories_with_cli"
   | "refresh_credential_cli_now"
   | "refresh_credential_oauth_now"
+  | "refresh_model_deprecations"
   | "refresh_session"
   | "register_claude_desktop_mcp"
   | "register_imported_mcp_server"
   | "register_owned_device"
+  | "reindex_memories"
   | "reinitialize_identity"
   | "reject_pairing"
   | "reject_pipeline_node"
@@ -1203,6 +1305,8 @@ export type CommandName =
   | "rename_event_type"
   | "reopen_audit_incident"
   | "repair_team_handoff"
+  | "replay_dead_letters"
+  | "replay_event"
   | "replay_webhook_request"
   | "report_frontend_crash"
   | "report_frontend_ready"
@@ -1234,6 +1338,7 @@ export type CommandName =
   | "research_lab_update_project"
   | "research_lab_update_source_status"
   | "reset_build_session_phase"
+  | "reset_db_contention_stats"
   | "reset_quality_gate_config"
   | "resolve_audit_incident"
   | "resolve_deliberation_escalation"
@@ -1243,30 +1348,42 @@ export type CommandName =
   | "resolve_pending_trigger_fire"
   | "resolve_share_deep_link"
   | "resolve_team_assignment_review"
+  | "restart_mcp_server"
+  | "restore_database"
   | "restore_persona"
+  | "resume_pipeline_run"
   | "resume_team_assignment"
   | "retry_dead_letter_event"
+  | "retry_pipeline_node"
   | "retry_team_preset_members"
   | "revert_recipe_version"
+  | "review_injection_detection"
   | "review_memories_with_cli"
   | "revoke_desktop_approvals"
   | "revoke_external_api_key"
   | "revoke_pairing"
   | "revoke_peer_trust"
+  | "revoke_persona_execute_hook"
   | "rollback_prompt_version"
   | "rotate_credential_now"
+  | "run_background_job_now"
   | "run_director_batch"
   | "run_director_memory_cleanup"
   | "run_director_on_persona"
+  | "run_fleet_hygiene_now"
   | "run_healing_analysis"
   | "run_memory_lifecycle"
   | "run_prompt_ab_test"
+  | "run_retention_cleanup_now"
   | "save_adoption_answers"
   | "save_api_definition"
   | "save_chat_session_context"
   | "save_custom_template"
   | "save_playwright_procedure"
+  | "save_prompt_block"
   | "save_scoped_resources"
+  | "save_template_variable"
+  | "scan_credential_anomalies"
   | "scan_credential_sources"
   | "scraper_delete_config"
   | "scraper_generate_rules"
@@ -1280,7 +1397,9 @@ export type CommandName =
   | "seal_enclave"
   | "search_events"
   | "search_executions"
+  | "search_healing_knowledge"
   | "search_kb_for_clipboard_error"
+  | "search_negotiation_transcripts"
   | "seed_linked_message_and_review"
   | "seed_mock_cron_agent"
   | "seed_mock_event"
@@ -1294,20 +1413,29 @@ export type CommandName =
   | "set_ambient_context_enabled"
   | "set_ambient_sensory_policy"
   | "set_app_setting"
+  | "set_background_job_paused"
   | "set_byom_policy"
   | "set_credential_scope_enforcement"
   | "set_digest_config"
   | "set_director_brain_enabled"
   | "set_display_name"
+  | "set_fleet_pattern_sharing"
   | "set_incident_in_progress"
   | "set_mcp_gateway_member_enabled"
   | "set_model_routing_rules"
   | "set_network_config"
+  | "set_observer_mode"
   | "set_persona_curation_schedule"
+  | "set_persona_engine_flags"
+  | "set_persona_execute_hook_wait"
+  | "set_persona_ollama_model"
+  | "set_persona_report_sink"
   | "set_persona_starred"
   | "set_quality_gate_config"
   | "set_qwen_credentials"
+  | "set_shared_context"
   | "set_team_assignment_goal"
+  | "set_tool_policy"
   | "set_trigger_unattended_mode"
   | "set_use_case_enabled"
   | "set_use_case_generation_settings"
@@ -1320,6 +1448,7 @@ export type CommandName =
   | "shared_events_unsubscribe"
   | "sign_document"
   | "simulate_build_draft"
+  | "simulate_team_run"
   | "simulate_use_case"
   | "skill_files_install"
   | "skill_files_install_preview"
@@ -1339,6 +1468,7 @@ export type CommandName =
   | "split_team_deliberation"
   | "start_auto_cred_browser"
   | "start_automation_design"
+  | "start_batch_execution"
   | "start_build_session"
   | "start_build_session_headless"
   | "start_credential_design"
@@ -1346,6 +1476,7 @@ export type CommandName =
   | "start_design_analysis"
   | "start_design_review_run"
   | "start_google_credential_oauth"
+  | "start_mcp_server"
   | "start_n8n_transform_background"
   | "start_nl_query"
   | "start_oauth"
@@ -1358,11 +1489,13 @@ export type CommandName =
   | "start_setup_install"
   | "start_team_assignment"
   | "start_test_run"
+  | "stop_mcp_server"
   | "stop_scheduler"
   | "storage_usage"
   | "suggest_topology"
   | "suggest_topology_llm"
   | "sync_capability_parameters"
+  | "sync_fleet_patterns_now"
   | "sync_peer_manifest"
   | "synthesize_manual_review"
   | "synthesize_team_from_templates"
@@ -1383,6 +1516,7 @@ export type CommandName =
   | "test_n8n_draft"
   | "test_notification_channel"
   | "test_notification_subscription"
+  | "test_persona_report_sink"
   | "test_provider_connection"
   | "toggle_alert_rule"
   | "trigger_ai_healing"
@@ -1436,6 +1570,7 @@ export type CommandName =
   | "unassign_tool"
   | "unlink_persona_from_event"
   | "unlink_recipe_from_persona"
+  | "unlock_vault"
   | "unregister_claude_desktop_mcp"
   | "update_alert_rule"
   | "update_automation"
@@ -1448,12 +1583,15 @@ export type CommandName =
   | "update_db_schema_table"
   | "update_design_conversation_status"
   | "update_exposed_resource"
+  | "update_healing_knowledge"
   | "update_healing_status"
   | "update_manual_review_status"
+  | "update_memory"
   | "update_memory_content"
   | "update_memory_importance"
   | "update_memory_tier"
   | "update_n8n_session"
+  | "update_notification_rule"
   | "update_notification_subscription"
   | "update_output_assertion"
   | "update_persona"
@@ -1472,13 +1610,19 @@ export type CommandName =
   | "update_trigger"
   | "update_trusted_peer"
   | "upsert_credential_recipe"
+  | "upsert_event_schema"
   | "upsert_knowledge_annotation"
+  | "upsert_notification_template"
+  | "upsert_persona_slo"
   | "use_credential_recipe"
   | "validate_byom_policy"
+  | "validate_connection_condition"
+  | "validate_cron"
   | "validate_db_schema"
   | "validate_n8n_draft"
   | "validate_persona_contracts"
   | "validate_trigger"
+  | "vault_backend_status"
   | "vault_status"
   | "verify_bundle"
   | "verify_document"
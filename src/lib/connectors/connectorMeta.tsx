@@ -134,6 +134,8 @@ export const CONNECTOR_META: Record<string, ConnectorMeta> = {
   supabase:           { label: 'Supabase',           color: '#3FCF8E', iconUrl: 'https://cdn.simpleicons.org/supabase/3FCF8E',         Icon: Database },
   neon:               { label: 'Neon',               color: '#00E599', iconUrl: '/icons/connectors/neon.svg',                           Icon: Database },
   postgres_proxy:     { label: 'PostgreSQL',         color: '#4169E1', iconUrl: 'https://cdn.simpleicons.org/postgresql/4169E1',       Icon: Database },
+  mysql:              { label: 'MySQL',              color: '#00758F', iconUrl: '/icons/connectors/mysql.svg',                          Icon: Database },
+  sqlite:             { label: 'SQLite',             color: '#003B57', iconUrl: '/icons/connectors/sqlite.svg',                         Icon: Database },
   convex:             { label: 'Convex',             color: '#EE342F', iconUrl: '/icons/connectors/convex.svg',                         Icon: Database },
   upstash:            { label: 'Upstash',            color: '#00E9A3', iconUrl: '/icons/connectors/upstash.svg',                        Icon: Database },
 
@@ -160,6 +162,7 @@ export const CONNECTOR_META: Record<string, ConnectorMeta> = {
   metabase:           { label: 'Metabase',           color: '#509EE3', iconUrl: '/icons/connectors/metabase.svg',                       Icon: BarChart },
 
   // -- Email & SMS -----------------------------------------------
+  imap_smtp:          { label: 'Email (IMAP/SMTP)',  color: '#0F766E', iconUrl: '/icons/connectors/imap-smtp.svg',                      Icon: Mail },
   sendgrid:           { label: 'SendGrid',           color: '#1A82E2', iconUrl: '/icons/connectors/sendgrid.svg',                       Icon: Send },
   mailchimp:          { label: 'Mailchimp',          color: '#FFE01B', iconUrl: 'https://cdn.simpleicons.org/mailchimp/FFE01B',        Icon: Mail },
   twilio:             { label: 'Twilio',             color: '#F22F46', iconUrl: '/icons/connectors/twilio.svg',                         Icon: Phone },
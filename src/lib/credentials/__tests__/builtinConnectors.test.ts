@@ -48,13 +48,17 @@ describe('builtinConnectors — personas_messages (CONN-01..CONN-03 regression)'
     expect(src).toContain('r##"personas_messages"##');
   });
 
-  it('CONN-02 (existing install): both seed paths in db/mod.rs are idempotent — no new migration required', () => {
+  it('CONN-02 (existing install): both seed paths are idempotent — no new migration required', () => {
+    const seedSrc = readFileSync(
+      join(process.cwd(), 'src-tauri/src/db/connector_seed.rs'),
+      'utf8',
+    );
+    // connector_seed::seed uses INSERT OR IGNORE on connector_definitions
+    expect(seedSrc).toContain('INSERT OR IGNORE INTO connector_definitions');
     const src = readFileSync(
       join(process.cwd(), 'src-tauri/src/db/mod.rs'),
       'utf8',
     );
-    // seed_builtin_connectors uses INSERT OR IGNORE on connector_definitions
-    expect(src).toContain('INSERT OR IGNORE INTO connector_definitions');
     // seed_builtin_credentials guards with an existence check on persona_credentials
     expect(src).toMatch(/SELECT COUNT\(\*\) > 0 FROM persona_credentials WHERE id/);
   });
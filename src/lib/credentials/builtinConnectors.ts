@@ -62,12 +62,15 @@ import planetscale from '../../../scripts/connectors/builtin/planetscale.json';
 import dropbox from '../../../scripts/connectors/builtin/dropbox.json';
 import twilioSms from '../../../scripts/connectors/builtin/twilio-sms.json';
 import postgres from '../../../scripts/connectors/builtin/postgres.json';
+import mysql from '../../../scripts/connectors/builtin/mysql.json';
+import sqlite from '../../../scripts/connectors/builtin/sqlite.json';
 import mongodb from '../../../scripts/connectors/builtin/mongodb.json';
 import redis from '../../../scripts/connectors/builtin/redis.json';
 import duckdb from '../../../scripts/connectors/builtin/duckdb.json';
 import googleSheets from '../../../scripts/connectors/builtin/google-sheets.json';
 import gmail from '../../../scripts/connectors/builtin/gmail.json';
 import microsoftOutlook from '../../../scripts/connectors/builtin/microsoft-outlook.json';
+import imapSmtp from '../../../scripts/connectors/builtin/imap-smtp.json';
 import microsoftExcel from '../../../scripts/connectors/builtin/microsoft-excel.json';
 import microsoftCalendar from '../../../scripts/connectors/builtin/microsoft-calendar.json';
 import microsoftTeams from '../../../scripts/connectors/builtin/microsoft-teams.json';
@@ -171,12 +174,15 @@ export const BUILTIN_CONNECTORS: BuiltinConnectorDef[] = [
   dropbox,
   twilioSms,
   postgres,
+  mysql,
+  sqlite,
   mongodb,
   redis,
   duckdb,
   googleSheets,
   gmail,
   microsoftOutlook,
+  imapSmtp,
   microsoftExcel,
   microsoftCalendar,
   microsoftTeams,
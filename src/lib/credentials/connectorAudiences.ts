@@ -59,6 +59,8 @@ const FALLBACK_AUDIENCES: Readonly<Record<string, readonly Audience[]>> = Object
   desktop_browser: ['developer'],
   postgres: ['developer'],
   postgres_proxy: ['developer'],
+  mysql: ['developer'],
+  sqlite: ['developer'],
   mongodb: ['developer'],
   redis: ['developer'],
   duckdb: ['developer'],
@@ -106,6 +108,7 @@ const FALLBACK_AUDIENCES: Readonly<Record<string, readonly Audience[]>> = Object
   telegram: ['developer'],
   gmail: ['support', 'manager'],
   microsoft_outlook: ['support', 'manager'],
+  imap_smtp: ['support', 'manager'],
   google_calendar: ['manager'],
   microsoft_calendar: ['manager'],
   cal_com: ['manager'],
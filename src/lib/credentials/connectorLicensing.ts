@@ -96,6 +96,8 @@ const LICENSE_OVERRIDES: Record<string, LicenseTier> = {
   tracklight:                      'personal',
   huggingface:                     'personal',
   postgres:                        'personal',
+  mysql:                           'personal',
+  sqlite:                          'personal',
   mongodb:                         'personal',
   redis:                           'personal',
   duckdb:                          'personal',
@@ -104,6 +106,7 @@ const LICENSE_OVERRIDES: Record<string, LicenseTier> = {
   google_sheets:                   'personal',
   gmail:                           'personal',
   microsoft_outlook:               'personal',
+  imap_smtp:                       'personal',
   linkedin:                        'personal',
   reddit:                          'personal',
   mcp_gateway:                     'personal',
@@ -71,7 +71,7 @@ function role(key: string, members: string[]): ConnectorRole {
  */
 export const CONNECTOR_ROLES: ConnectorRole[] = [
   // -- Communication ---------------------------------------------
-  role('chat_messaging',      ['slack', 'discord', 'telegram', 'microsoft_teams', 'gmail', 'microsoft_outlook', 'personas_messages']),
+  role('chat_messaging',      ['slack', 'discord', 'telegram', 'microsoft_teams', 'gmail', 'microsoft_outlook', 'imap_smtp', 'personas_messages']),
   role('email_delivery',      ['sendgrid', 'resend', 'mailchimp']),
   role('sms',                 ['twilio_sms', 'twilio']),
 
@@ -86,7 +86,7 @@ export const CONNECTOR_ROLES: ConnectorRole[] = [
   // -- Infrastructure --------------------------------------------
   role('hosting',             ['vercel', 'netlify', 'cloudflare']),
   role('cloud_infra',         ['aws', 'firebase', 'kubernetes']),
-  role('database',            ['personas_database', 'personas_vector_db', 'supabase', 'neon', 'convex', 'planetscale', 'upstash', 'postgres_proxy', 'postgres', 'mongodb', 'redis', 'duckdb', 'notion', 'google_sheets']),
+  role('database',            ['personas_database', 'personas_vector_db', 'supabase', 'neon', 'convex', 'planetscale', 'upstash', 'postgres_proxy', 'postgres', 'mysql', 'sqlite', 'mongodb', 'redis', 'duckdb', 'notion', 'google_sheets']),
   role('cloud_storage',       ['dropbox', 'onedrive', 'sharepoint', 'aws_s3', 'cloudflare_r2', 'backblaze_b2']),
 
   // -- Monitoring & Security -------------------------------------
@@ -25,6 +25,7 @@ export type ErrorCategory =
   | 'tool_error'
   | 'api_error'
   | 'transient_process_failure'
+  | 'contract_violation'
   | 'unknown';
 
 // ---------------------------------------------------------------------------
@@ -88,6 +89,12 @@ export function classifyKind(kind: TauriErrorKind): ErrorCategory {
 export function classifyError(error: string): ErrorCategory {
   const lower = error.toLowerCase();
 
+  // Output contract violation — checked first because the schema errors it
+  // carries can contain any token matched below.
+  if (lower.startsWith('contract_violation')) {
+    return 'contract_violation';
+  }
+
   // Rate limit
   if (
     lower.includes('rate limit') ||
@@ -291,7 +298,8 @@ export function defaultSeverity(category: ErrorCategory): ErrorSeverity {
     case 'rate_limit':
     case 'timeout':
     case 'tool_error':
-    case 'network': return 'medium';
+    case 'network':
+    case 'contract_violation': return 'medium';
     case 'validation':
     case 'transient_process_failure': return 'low';
     case 'unknown': return 'medium';
@@ -337,6 +345,7 @@ export function categoryLabel(category: ErrorCategory): string {
     case 'tool_error': return 'Tool Error';
     case 'api_error': return 'API Error';
     case 'transient_process_failure': return 'Transient Process Failure';
+    case 'contract_violation': return 'Contract Violation';
     case 'unknown': return 'Unknown';
   }
 }
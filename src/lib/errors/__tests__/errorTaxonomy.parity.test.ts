@@ -55,6 +55,10 @@ const PARITY_FIXTURES: Array<[string, ErrorCategory]> = [
   ['Execution failed (exit code 137): Killed', 'transient_process_failure'],
   ['Execution failed (exit code 1): ', 'transient_process_failure'],
   ['App restarted while execution was running', 'transient_process_failure'],
+  [
+    'contract_violation: output does not conform to the persona\'s output contract: /score: "429" is not of type "integer"',
+    'contract_violation',
+  ],
   ['some entirely novel failure', 'unknown'],
 ];
 
@@ -22,10 +22,14 @@ import { useImproveActivityStore } from "@/stores/improveActivityStore";
 import { createLogger } from "@/lib/log";
 import { silentCatch } from '@/lib/silentCatch';
 import { getActiveTranslations } from "@/i18n/useTranslation";
+import type { OverviewTab } from "@/lib/types/types";
 
 
 const logger = createLogger("event-bridge");
 
+/** Overview tabs a `personas://open/<tab>` push-notification link may open. */
+const PUSH_DEEP_LINK_TABS: ReadonlySet<OverviewTab> = new Set<OverviewTab>(["health", "manual-review"]);
+
 // ---------------------------------------------------------------------------
 // Timing constants
 // ---------------------------------------------------------------------------
@@ -848,6 +852,23 @@ const registry: EventRegistration[] = [
     },
   },
 
+  // -- Push notification deep link (personas://open/<tab>) -------------------
+  {
+    event: EventName.OPEN_TAB_REQUESTED,
+    setup: async () => {
+      const unlisten = await typedListen(
+        EventName.OPEN_TAB_REQUESTED,
+        (payload) => {
+          const tab = payload?.tab;
+          if (!tab || !PUSH_DEEP_LINK_TABS.has(tab as OverviewTab)) return;
+          useSystemStore.getState().setSidebarSection("overview");
+          useOverviewStore.getState().setOverviewTab(tab as OverviewTab);
+        },
+      );
+      return [unlisten];
+    },
+  },
+
   // -- TitleBar notification (persona message delivery — v3.2 DELIV-04) ------
   {
     event: EventName.TITLEBAR_NOTIFICATION,
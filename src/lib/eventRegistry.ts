@@ -18,10 +18,13 @@ import type { CircuitBreakerStatus } from '@/lib/bindings/CircuitBreakerStatus';
 import type { PendingPairingView } from '@/lib/bindings/PendingPairingView';
 import type { CircuitTransitionEvent } from '@/lib/bindings/CircuitTransitionEvent';
 import type { TraceSpan } from '@/lib/bindings/TraceSpan';
+import type { StuckQueueReport } from '@/lib/bindings/StuckQueueReport';
+import type { ToolApprovalRequest } from '@/lib/bindings/ToolApprovalRequest';
 import type { ExecutionTrace } from '@/lib/bindings/ExecutionTrace';
 import type { AuthStateResponse } from '@/lib/bindings/AuthStateResponse';
 import type { TestScenario } from '@/lib/bindings/TestScenario';
 import type { TestScores } from '@/lib/bindings/TestScores';
+import type { ExecutionBatchProgress } from '@/lib/bindings/ExecutionBatchProgress';
 
 // ---------------------------------------------------------------------------
 // Event name constants (keep in sync with Rust event_registry::event_name)
@@ -38,6 +41,7 @@ export const EventName = {
   EXECUTION_FILE_CHANGE: 'execution-file-change',
   EXECUTION_PROGRESS: 'execution-progress',
   EXECUTION_REVIEW_REQUEST: 'execution-review-request',
+  BATCH_EXECUTION_PROGRESS: 'batch-execution-progress',
 
   // Queue
   QUEUE_STATUS: 'queue-status',
@@ -77,6 +81,7 @@ export const EventName = {
   MANUAL_REVIEW_RESOLVED: 'manual-review-resolved',
   REVIEW_DISPATCH_BLOCKED: 'review-dispatch-blocked',
   REVIEW_MESSAGE_ADDED: 'review-message-added',
+  TOOL_APPROVAL_REQUIRED: 'tool-approval-required',
   // Persona event-bus signals published when a human review is resolved (by the
   // user OR by Athena). Backend bus events personas subscribe to for
   // event-orchestrated continuation; mirrored here for Rust↔TS name parity.
@@ -113,6 +118,9 @@ export const EventName = {
   KB_INGEST_COMPLETE: 'kb:ingest_complete',
   KB_INGEST_ERROR: 'kb:ingest_error',
 
+  // Local models
+  OLLAMA_PULL_PROGRESS: 'ollama-pull-progress',
+
   // Credential automation
   AUTO_CRED_BROWSER_STATUS: 'auto-cred-browser-status',
   AUTO_CRED_BROWSER_PROGRESS: 'auto-cred-browser-progress',
@@ -150,6 +158,8 @@ export const EventName = {
   // writes), so triggers and the dispatch ops can route off them.
   SIGNAL_RAISED: 'signal.raised',
   SIGNAL_VERIFIED: 'signal.verified',
+  // A webhook or chain fired a system-op automation; the bus loop runs it.
+  SYSTEM_OP_FIRED: 'system_op.fired',
   IDEA_SCAN_STATUS: 'idea-scan-status',
   IDEA_SCAN_OUTPUT: 'idea-scan-output',
   IDEA_SCAN_COMPLETE: 'idea-scan-complete',
@@ -207,6 +217,7 @@ export const EventName = {
   OVERDUE_TRIGGERS_FIRED: 'overdue-triggers-fired',
   ZOMBIE_EXECUTIONS_DETECTED: 'zombie-executions-detected',
   EXECUTIONS_SILENT_DETECTED: 'executions-silent-detected',
+  QUEUE_STUCK_DETECTED: 'queue-stuck-detected',
   AUTO_ROLLBACK_TRIGGERED: 'auto-rollback-triggered',
   SUBSCRIPTION_CRASHED: 'subscription-crashed',
 
@@ -255,6 +266,9 @@ export const EventName = {
   // Cloud-app pairing request (personas://pair deep link or POST /pair/request)
   PAIRING_REQUESTED: 'pairing-requested',
 
+  // Push notification tapped on the phone (personas://open/<tab> deep link)
+  OPEN_TAB_REQUESTED: 'open-tab-requested',
+
   // Engine fallback (unrecognized engine setting)
   ENGINE_FALLBACK: 'engine-fallback',
 
@@ -739,6 +753,7 @@ export interface EventPayloadMap {
   };
   [EventName.EXECUTION_PROGRESS]: ExecutionProgressPayload;
   [EventName.EXECUTION_REVIEW_REQUEST]: ExecutionReviewRequestPayload;
+  [EventName.BATCH_EXECUTION_PROGRESS]: ExecutionBatchProgress;
 
   // Queue
   [EventName.QUEUE_STATUS]: {
@@ -795,6 +810,7 @@ export interface EventPayloadMap {
   [EventName.MANUAL_REVIEW_RESOLVED]: ManualReviewResolvedPayload;
   [EventName.REVIEW_DISPATCH_BLOCKED]: ReviewDispatchBlockedPayload;
   [EventName.REVIEW_MESSAGE_ADDED]: ReviewMessageAddedPayload;
+  [EventName.TOOL_APPROVAL_REQUIRED]: ToolApprovalRequest;
 
   // Build session
   [EventName.BUILD_SESSION_EVENT]: BuildSessionEventPayload;
@@ -822,6 +838,9 @@ export interface EventPayloadMap {
   [EventName.KB_INGEST_COMPLETE]: KbIngestProgressPayload;
   [EventName.KB_INGEST_ERROR]: { jobId: string; error: string };
 
+  // Local models
+  [EventName.OLLAMA_PULL_PROGRESS]: import('./bindings/OllamaPullProgress').OllamaPullProgress;
+
   // Credential automation
   [EventName.AUTO_CRED_BROWSER_STATUS]: { session_id: string; status: string };
   [EventName.AUTO_CRED_BROWSER_PROGRESS]: {
@@ -959,6 +978,7 @@ export interface EventPayloadMap {
     count: number;
     cutoff_secs: number;
   };
+  [EventName.QUEUE_STUCK_DETECTED]: StuckQueueReport;
   [EventName.AUTO_ROLLBACK_TRIGGERED]: {
     personaId: string;
     personaName: string;
@@ -1056,6 +1076,11 @@ export interface EventPayloadMap {
   // Cloud-app pairing request — emitted with the Rust `PendingPairingView`.
   [EventName.PAIRING_REQUESTED]: PendingPairingView;
 
+  // Push-notification deep link (overview tab to open)
+  [EventName.OPEN_TAB_REQUESTED]: {
+    tab: string;
+  };
+
   // Engine fallback
   [EventName.ENGINE_FALLBACK]: {
     requested: string;
@@ -0,0 +1,129 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import type { ObserverModeConfig } from "./bindings/ObserverModeConfig";
+import { createLogger } from "./log";
+
+/**
+ * Observer mode — the frontend half of the read-only screen-sharing mode
+ * (see `engine/observer_mode.rs`). The backend freezes mutating commands;
+ * this module redacts what read commands return before any component sees
+ * it. Tauri offers no hook to rewrite a response on the Rust side, so the
+ * invoke wrapper runs every result through {@link redactForObserver}.
+ *
+ * While on:
+ * - credential names and secret-looking fields are masked;
+ * - every string matching a configured pattern is replaced.
+ */
+
+const logger = createLogger("observerMode");
+
+const MARKER = "[REDACTED]";
+/** Must match `SETTINGS_CHANGED_EVENT` in `commands/infrastructure/settings.rs`. */
+const SETTINGS_CHANGED_EVENT = "settings-changed";
+/** Must match `settings_keys::OBSERVER_MODE`. */
+const OBSERVER_MODE_KEY = "observer_mode";
+
+/** Field names masked wherever they appear. */
+const SECRET_FIELD = /(secret|password|passphrase|token|api_?key|private_?key)$/i;
+/** Field names masked in the results of credential commands. */
+const CREDENTIAL_FIELD = /^(name|value|credential_?name|field_?value)$/i;
+/** Credential-name fields embedded in non-credential results (e.g. bindings). */
+const CREDENTIAL_NAME_FIELD = /^credential_?name$/i;
+
+let patterns: RegExp[] | null = null;
+let hydration: Promise<void> | null = null;
+let hydrated = false;
+
+/** Whether observer mode is on in this window. */
+export function isObserverModeActive(): boolean {
+  return patterns !== null;
+}
+
+/** Make `config` the live redaction state. Invalid patterns are skipped. */
+export function applyObserverMode(config: ObserverModeConfig): void {
+  if (!config.enabled) {
+    patterns = null;
+    return;
+  }
+  patterns = config.redactPatterns.flatMap((p) => {
+    try {
+      return [new RegExp(p, "gi")];
+    } catch (err) {
+      logger.warn("Skipping observer redaction pattern the webview can't compile", { pattern: p, err });
+      return [];
+    }
+  });
+}
+
+/**
+ * Load the persisted config and follow later changes. Called once at
+ * startup; until the first load settles, results are held back so nothing
+ * slips out unredacted on a relaunch into observer mode.
+ */
+export function hydrateObserverMode(): Promise<void> {
+  if (hydration) return hydration;
+  // Raw `invoke`, not the wrapper: the wrapper waits on this very promise.
+  const load = () =>
+    invoke<ObserverModeConfig>("get_observer_mode").then(applyObserverMode, (err) =>
+      logger.warn("Failed to load observer mode", { err }),
+    );
+  hydration = load().finally(() => {
+    hydrated = true;
+  });
+  void listen<{ key?: string }>(SETTINGS_CHANGED_EVENT, (event) => {
+    if (event.payload?.key === OBSERVER_MODE_KEY) void load();
+  });
+  return hydration;
+}
+
+/**
+ * Redact an IPC result for observer mode. Returns the value untouched when
+ * the mode is off.
+ */
+export function redactForObserver<T>(cmd: string, result: T): T | Promise<T> {
+  if (hydration && !hydrated) {
+    return hydration.then(() => redactValue(cmd, result));
+  }
+  return redactValue(cmd, result);
+}
+
+function redactValue<T>(cmd: string, result: T): T {
+  if (patterns === null) return result;
+  const credentialCommand = cmd.includes("credential");
+  return walk(result, credentialCommand, undefined) as T;
+}
+
+function walk(value: unknown, credentialCommand: boolean, field: string | undefined): unknown {
+  if (typeof value === "string") {
+    if (field !== undefined && isMaskedField(field, credentialCommand)) return MARKER;
+    return redactString(value);
+  }
+  if (Array.isArray(value)) return value.map((v) => walk(v, credentialCommand, field));
+  if (value !== null && typeof value === "object") {
+    const proto = Object.getPrototypeOf(value);
+    if (proto !== Object.prototype && proto !== null) return value;
+    const out: Record<string, unknown> = {};
+    for (const [k, v] of Object.entries(value as Record<string, unknown>)) {
+      out[k] = walk(v, credentialCommand, k);
+    }
+    return out;
+  }
+  return value;
+}
+
+function isMaskedField(field: string, credentialCommand: boolean): boolean {
+  return (
+    SECRET_FIELD.test(field) ||
+    CREDENTIAL_NAME_FIELD.test(field) ||
+    (credentialCommand && CREDENTIAL_FIELD.test(field))
+  );
+}
+
+function redactString(value: string): string {
+  let out = value;
+  for (const re of patterns ?? []) {
+    re.lastIndex = 0;
+    out = out.replace(re, MARKER);
+  }
+  return out;
+}
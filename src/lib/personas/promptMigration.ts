@@ -21,6 +21,9 @@ export interface StructuredPromptSection {
   id: string;
   title: string;
   content: string;
+  /** Prompt library block this section inlines at run time. Its title, when
+   *  set, overrides the block name; `content` is unused. */
+  blockId?: string;
 }
 
 let _sectionIdCounter = 0;
@@ -101,7 +104,7 @@ export function isStructuredPromptEmpty(sp: StructuredPrompt): boolean {
     !sp.examples.trim() &&
     !sp.errorHandling.trim() &&
     !sp.webSearch.trim() &&
-    sp.customSections.every(s => !s.content.trim())
+    sp.customSections.every(s => !s.blockId && !s.content.trim())
   );
 }
 
@@ -125,6 +128,7 @@ function normalizeCustomSection(s: Record<string, unknown>): StructuredPromptSec
     id,
     title,
     content: typeof s.content === 'string' ? s.content : '',
+    ...(typeof s.blockId === 'string' && s.blockId ? { blockId: s.blockId } : {}),
   };
 }
 
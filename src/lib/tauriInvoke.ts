@@ -1,6 +1,7 @@
 import { invoke, type InvokeArgs, type InvokeOptions } from "@tauri-apps/api/core";
 import * as Sentry from "@sentry/react";
 import { recordIpcCall } from "./ipcMetrics";
+import { redactForObserver } from "./observerMode";
 import type { CommandName as RegisteredCommand } from "./commandNames.generated";
 import type { UnregisteredCommand } from "./commandNames.overrides";
 import { createLogger } from "./log";
@@ -509,7 +510,8 @@ function _invokeCore<T>(
   return Promise.race([invocation, timeout]).then(
     (result) => {
       recordIpcCall({ command: cmd, durationMs: performance.now() - start, ok: true, timestamp: Date.now() });
-      return result;
+      // Observer mode: redact before any caller (or the dedup cache) sees it.
+      return redactForObserver(cmd, result);
     },
     (err) => {
       recordIpcCall({ command: cmd, durationMs: performance.now() - start, ok: false, timestamp: Date.now(), timedOut: err instanceof InvokeTimeoutError });
@@ -1,7 +1,9 @@
-import { Clock, Webhook, Play, Zap, Link, RefreshCw, Radio, FolderSearch, ClipboardPaste, AppWindow, Combine } from 'lucide-react';
+import { Clock, Webhook, Play, Zap, Link, RefreshCw, Radio, FolderSearch, Mail, ClipboardPaste, AppWindow, Combine } from 'lucide-react';
 import { createLogger } from "@/lib/log";
 import { en, type Translations } from '@/i18n/en';
 import type { Schedule } from '@/lib/types/schedule';
+import type { ScheduleExclusion } from '@/lib/bindings/ScheduleExclusion';
+import type { CatchUpPolicy } from '@/lib/bindings/CatchUpPolicy';
 
 const logger = createLogger("trigger-constants");
 
@@ -18,6 +20,7 @@ export const TRIGGER_TYPE_META: Record<string, TriggerTypeMeta> = {
   chain: { Icon: Link, color: 'text-purple-400' },
   event_listener: { Icon: Radio, color: 'text-cyan-400' },
   file_watcher: { Icon: FolderSearch, color: 'text-orange-400' },
+  email_inbound: { Icon: Mail, color: 'text-sky-400' },
   clipboard: { Icon: ClipboardPaste, color: 'text-pink-400' },
   app_focus: { Icon: AppWindow, color: 'text-indigo-400' },
   composite: { Icon: Combine, color: 'text-rose-400' },
@@ -27,8 +30,8 @@ export const DEFAULT_TRIGGER_META: TriggerTypeMeta = { Icon: Zap, color: 'text-p
 
 // -- Trigger category taxonomy ----------------------------------------
 //
-// The 10 trigger types decompose into 3 intuitive categories:
-//  - Pull (Watch): poll on intervals (schedule, polling, clipboard, app_focus, file_watcher)
+// The 11 trigger types decompose into 3 intuitive categories:
+//  - Pull (Watch): poll on intervals (schedule, polling, email_inbound, clipboard, app_focus, file_watcher)
 //  - Push (Listen): receive external events (webhook, event_listener)
 //  - Compose (Combine): combine other triggers (chain, composite)
 //  Manual is a degenerate case shown separately.
@@ -53,7 +56,7 @@ export const TRIGGER_CATEGORIES: TriggerCategoryMeta[] = [
     color: 'text-amber-400',
     bgColor: 'bg-amber-500/10',
     borderColor: 'border-amber-500/20',
-    types: ['schedule', 'polling', 'file_watcher', 'clipboard', 'app_focus'],
+    types: ['schedule', 'polling', 'email_inbound', 'file_watcher', 'clipboard', 'app_focus'],
   },
   {
     id: 'push',
@@ -105,6 +108,7 @@ export const TRIGGER_TYPE_OPTIONS: TriggerTypeOption[] = [
   { type: 'polling', label: 'Polling', description: 'Check an endpoint' },                        // i18n: triggers.type_polling / triggers.desc_polling
   { type: 'webhook', label: 'Webhook', description: 'HTTP webhook listener' },                    // i18n: triggers.type_webhook / triggers.desc_webhook
   { type: 'event_listener', label: 'Event Listener', description: 'React to internal events' },   // i18n: triggers.type_event_listener / triggers.desc_event_listener
+  { type: 'email_inbound', label: 'Inbound Email', description: 'React to new mail in an inbox' }, // i18n: triggers.type_email_inbound / triggers.desc_email_inbound
   { type: 'file_watcher', label: 'File Watcher', description: 'React to file system changes' },   // i18n: triggers.type_file_watcher / triggers.desc_file_watcher
   { type: 'clipboard', label: 'Clipboard', description: 'React to clipboard changes' },           // i18n: triggers.type_clipboard / triggers.desc_clipboard
   { type: 'app_focus', label: 'App Focus', description: 'React to app focus changes' },           // i18n: triggers.type_app_focus / triggers.desc_app_focus
@@ -121,6 +125,7 @@ export const TRIGGER_TYPE_I18N: Record<string, { label: string; desc: string }>
   polling:        { label: 'triggers.type_polling',        desc: 'triggers.desc_polling' },
   webhook:        { label: 'triggers.type_webhook',        desc: 'triggers.desc_webhook' },
   event_listener: { label: 'triggers.type_event_listener', desc: 'triggers.desc_event_listener' },
+  email_inbound:  { label: 'triggers.type_email_inbound',  desc: 'triggers.desc_email_inbound' },
   file_watcher:   { label: 'triggers.type_file_watcher',   desc: 'triggers.desc_file_watcher' },
   clipboard:      { label: 'triggers.type_clipboard',      desc: 'triggers.desc_clipboard' },
   app_focus:      { label: 'triggers.type_app_focus',      desc: 'triggers.desc_app_focus' },
@@ -226,6 +231,11 @@ export interface ScheduleConfig extends Schedule {
   /** Max catch-up executions to enqueue when the trigger is overdue. Undefined
    *  or 1 = current fire-once-on-overdue behavior. Backend hard-caps at 100. */
   max_backfill?: number;
+  /** Blackout windows; cron fire times inside any of them are skipped. */
+  exclusions?: ScheduleExclusion[];
+  /** What to do about fires missed while the app was closed. Undefined =
+   *  the `max_backfill` behavior above. */
+  catch_up?: CatchUpPolicy;
   event_type?: string;
 }
 
@@ -272,6 +282,22 @@ export interface FileWatcherConfig {
   events?: string[];
   recursive?: boolean;
   glob_filter?: string;
+  ignore_patterns?: string[];
+  debounce_ms?: number;
+  include_content?: boolean;
+  event_type?: string;
+}
+
+export interface EmailInboundConfig {
+  type: 'email_inbound';
+  credential_id?: string;
+  /** IMAP folder (default INBOX) or Gmail label. */
+  mailbox?: string;
+  from?: string;
+  subject?: string;
+  unread_only?: boolean;
+  mark_read?: boolean;
+  interval_seconds?: number;
   event_type?: string;
 }
 
@@ -312,6 +338,7 @@ export type TriggerConfig =
   | ManualConfig
   | EventListenerConfig
   | FileWatcherConfig
+  | EmailInboundConfig
   | ClipboardConfig
   | AppFocusConfig
   | CompositeConfig;
@@ -480,6 +507,8 @@ export function parseTriggerConfig(
         interval_seconds: raw.interval_seconds as number | undefined,
         timezone: raw.timezone as string | undefined,
         max_backfill: raw.max_backfill as number | undefined,
+        exclusions: raw.exclusions as ScheduleExclusion[] | undefined,
+        catch_up: raw.catch_up as CatchUpPolicy | undefined,
         event_type: raw.event_type as string | undefined,
       };
     case 'polling':
@@ -519,6 +548,21 @@ export function parseTriggerConfig(
         events: raw.events as string[] | undefined,
         recursive: raw.recursive as boolean | undefined,
         glob_filter: raw.glob_filter as string | undefined,
+        ignore_patterns: raw.ignore_patterns as string[] | undefined,
+        debounce_ms: raw.debounce_ms as number | undefined,
+        include_content: raw.include_content as boolean | undefined,
+        event_type: raw.event_type as string | undefined,
+      };
+    case 'email_inbound':
+      return {
+        type: 'email_inbound',
+        credential_id: raw.credential_id as string | undefined,
+        mailbox: (raw.mailbox ?? raw.label) as string | undefined,
+        from: raw.from as string | undefined,
+        subject: raw.subject as string | undefined,
+        unread_only: raw.unread_only as boolean | undefined,
+        mark_read: raw.mark_read as boolean | undefined,
+        interval_seconds: raw.interval_seconds as number | undefined,
         event_type: raw.event_type as string | undefined,
       };
     case 'clipboard':
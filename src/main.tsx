@@ -16,6 +16,7 @@ import { isTelemetryEnabled } from "./lib/telemetryPreference";
 import { persistCrash } from "./lib/utils/crashPersistence";
 import { createLogger } from "./lib/log";
 import { installPreloadErrorRecovery } from "./lib/recovery/preloadErrorRecovery";
+import { hydrateObserverMode } from "./lib/observerMode";
 import { preloadSectionsAsync } from "./i18n/useTranslation";
 import { isLocaleCode, type LocaleCode } from "./i18n/locales.manifest";
 import { sectionsForRoute } from "./i18n/routeSections";
@@ -146,6 +147,11 @@ window.addEventListener("unhandledrejection", (event) => {
 // (see preloadErrorRecovery.test.ts).
 installPreloadErrorRecovery();
 
+// Observer mode (read-only screen sharing): load before the first IPC result
+// is handed out, so a relaunch into observer mode never flashes unredacted
+// data. The invoke wrapper holds results until this settles.
+void hydrateObserverMode();
+
 function readPersistedLocale(): LocaleCode {
   try {
     const raw = localStorage.getItem("personas-i18n-storage");
@@ -219,6 +219,8 @@ export const createOverviewSlice: StateCreator<OverviewStore, [], [], OverviewSl
           // per-persona Activity list (full PersonaExecution) carries them.
           director_score: null,
           director_review_md: null,
+          feature_flags: null,
+          output_ref: null,
           // Cache-token breakdown isn't part of the global JOIN row either; the
           // per-execution detail (full PersonaExecution) carries the real values.
           cache_read_tokens: 0,